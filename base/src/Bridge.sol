@@ -5,10 +5,11 @@ import {OwnableRoles} from "solady/auth/OwnableRoles.sol";
 import {Initializable} from "solady/utils/Initializable.sol";
 import {LibClone} from "solady/utils/LibClone.sol";
 import {ReentrancyGuardTransient} from "solady/utils/ReentrancyGuardTransient.sol";
+import {SafeTransferLib} from "solady/utils/SafeTransferLib.sol";
 
 import {BridgeValidator} from "./BridgeValidator.sol";
 import {Twin} from "./Twin.sol";
-import {Call} from "./libraries/CallLib.sol";
+import {Call, SwapGuard} from "./libraries/CallLib.sol";
 import {IncomingMessage, MessageLib, MessageType} from "./libraries/MessageLib.sol";
 import {MessageStorageLib} from "./libraries/MessageStorageLib.sol";
 import {SVMBridgeLib} from "./libraries/SVMBridgeLib.sol";
@@ -51,6 +52,13 @@ contract Bridge is ReentrancyGuardTransient, Initializable, OwnableRoles {
     /// @notice Guardian Role to pause the bridge.
     uint256 public constant GUARDIAN_ROLE = 1 << 0;
 
+    /// @notice Offset of the call in the data of a `TransferAndCall` message carrying a `SwapGuard`.
+    ///
+    /// @dev Solana appends the swap guard after the memo (`abi.encode(transfer, call, memo, swapGuard)`), which
+    ///      pushes the call after the 9 head words of the encoding. Messages without a swap guard encode the call at
+    ///      offset 160 (`abi.encode(transfer, call)`) or 192 (`abi.encode(transfer, call, memo)`).
+    uint256 public constant SWAP_GUARD_CALL_OFFSET = 288;
+
     //////////////////////////////////////////////////////////////
     ///                       Storage                          ///
     //////////////////////////////////////////////////////////////
@@ -70,6 +78,11 @@ contract Bridge is ReentrancyGuardTransient, Initializable, OwnableRoles {
     /// @notice Whether the bridge is paused.
     bool public paused;
 
+    /// @notice Mapping of Twin contracts to the Solana accounts receiving, per remote token, the tokens of their
+    ///         transfers whose call reverted. Without a return recipient, the tokens are kept on Base: the message
+    ///         fails and can be retried.
+    mapping(address twin => mapping(Pubkey remoteToken => Pubkey to)) public returnRecipients;
+
     //////////////////////////////////////////////////////////////
     ///                       Events                           ///
     //////////////////////////////////////////////////////////////
@@ -91,6 +104,28 @@ contract Bridge is ReentrancyGuardTransient, Initializable, OwnableRoles {
     /// @param paused Whether the bridge is paused.
     event PauseSwitched(bool paused);
 
+    /// @notice Emitted whenever the call of a transfer is skipped because its swap guard expired. The transferred
+    ///         tokens are delivered without being swapped.
+    ///
+    /// @param twin     The Twin contract that would have executed the call.
+    /// @param tokenOut The token the call would have swapped into.
+    /// @param deadline The expired deadline of the swap guard.
+    event SwapGuardExpired(address indexed twin, address indexed tokenOut, uint64 deadline);
+
+    /// @notice Emitted whenever a Twin sets the Solana account receiving the tokens of its reverted transfers.
+    ///
+    /// @param twin        The Twin contract.
+    /// @param remoteToken The Solana token of the transfers.
+    /// @param to          The Solana account receiving the returned tokens, or the zero pubkey to keep them on Base.
+    event ReturnRecipientSet(address indexed twin, Pubkey indexed remoteToken, Pubkey to);
+
+    /// @notice Emitted whenever the tokens of a transfer whose call reverted are returned to Solana.
+    ///
+    /// @param twin          The Twin contract whose call reverted.
+    /// @param outgoingNonce The nonce of the Solana outgoing message whose transfer is returned.
+    /// @param to            The Solana account receiving the returned tokens.
+    event TransferReturned(address indexed twin, uint64 indexed outgoingNonce, Pubkey to);
+
     //////////////////////////////////////////////////////////////
     ///                       Errors                           ///
     //////////////////////////////////////////////////////////////
@@ -110,6 +145,9 @@ contract Bridge is ReentrancyGuardTransient, Initializable, OwnableRoles {
     /// @notice Thrown when the borsch-encoded message to bridge is too large to fit in a Solana account
     error SerializedMessageTooBig();
 
+    /// @notice Thrown when a guarded call credits less than the `minOut` of its swap guard to the Twin.
+    error InsufficientSwapOutput();
+
     //////////////////////////////////////////////////////////////
     ///                       Modifiers                        ///
     //////////////////////////////////////////////////////////////
@@ -198,6 +236,19 @@ contract Bridge is ReentrancyGuardTransient, Initializable, OwnableRoles {
         MessageStorageLib.sendMessage({sender: msg.sender, data: data});
     }
 
+    /// @notice Sets the Solana account receiving the tokens of the caller's transfers of `remoteToken` whose call
+    ///         reverts.
+    ///
+    /// @dev Meant to be called by Twin contracts. For SPL and wrapped tokens, `to` must be a token account of the
+    ///      Solana mint. Setting the zero pubkey keeps the tokens on Base, where the message can be retried.
+    ///
+    /// @param remoteToken The Solana token of the transfers.
+    /// @param to          The Solana account receiving the returned tokens.
+    function setReturnRecipient(Pubkey remoteToken, Pubkey to) external {
+        returnRecipients[msg.sender][remoteToken] = to;
+        emit ReturnRecipientSet({twin: msg.sender, remoteToken: remoteToken, to: to});
+    }
+
     /// @notice Relays messages sent from Solana to Base.
     ///
     /// @param messages The messages to relay.
@@ -227,6 +278,18 @@ contract Bridge is ReentrancyGuardTransient, Initializable, OwnableRoles {
             TokenLib.registerRemoteToken({
                 localToken: localToken, remoteToken: remoteToken, scalarExponent: scalarExponent
             });
+
+            // Echo the metadata of the local token back to Solana, which only activates the wrapped token if it
+            // matches. Tokens without readable ERC20 metadata are registered but never confirmed.
+            (bool hasMetadata, string memory name, string memory symbol, uint8 decimals) = _tokenMetadata(localToken);
+            if (hasMetadata) {
+                MessageStorageLib.sendMessage({
+                    sender: address(this),
+                    data: SVMBridgeLib.serializeConfirmWrappedToken({
+                        mint: remoteToken, name: name, symbol: symbol, decimals: decimals
+                    })
+                });
+            }
             return;
         }
 
@@ -238,6 +301,15 @@ contract Bridge is ReentrancyGuardTransient, Initializable, OwnableRoles {
             return;
         }
 
+        // Transfer batches distribute a single Solana lock to several recipients, without any call either.
+        if (message.ty == MessageType.TransferBatch) {
+            Transfer[] memory transfers = abi.decode(message.data, (Transfer[]));
+            for (uint256 i; i < transfers.length; i++) {
+                TokenLib.finalizeTransfer({transfer: transfers[i], crossChainErc20Factory: CROSS_CHAIN_ERC20_FACTORY});
+            }
+            return;
+        }
+
         // For calls, get (and deploy if needed) the Twin contract.
         address twinAddress = twins[message.sender];
         if (twinAddress == address(0)) {
@@ -251,12 +323,35 @@ contract Bridge is ReentrancyGuardTransient, Initializable, OwnableRoles {
             Call memory call = abi.decode(message.data, (Call));
             Twin(payable(twinAddress)).execute(call);
         } else if (message.ty == MessageType.TransferAndCall) {
-            (Transfer memory transfer, Call memory call) = abi.decode(message.data, (Transfer, Call));
-            TokenLib.finalizeTransfer({transfer: transfer, crossChainErc20Factory: CROSS_CHAIN_ERC20_FACTORY});
-            Twin(payable(twinAddress)).execute(call);
+            Transfer memory transfer = abi.decode(message.data, (Transfer));
+            Pubkey returnRecipient = returnRecipients[twinAddress][transfer.remoteToken];
+            if (Pubkey.unwrap(returnRecipient) == bytes32(0)) {
+                _finalizeTransferAndCall({data: message.data, twinAddress: twinAddress});
+                return;
+            }
+
+            // The Twin opted into returning the tokens: if the call reverts, the transfer is not finalized on Base
+            // and its tokens are sent back to Solana instead.
+            try this.__finalizeTransferAndCall({data: message.data, twinAddress: twinAddress}) {}
+            catch {
+                _returnTransfer({
+                    twinAddress: twinAddress, outgoingNonce: message.nonce, transfer: transfer, to: returnRecipient
+                });
+            }
         }
     }
 
+    /// @notice Finalizes the transfer of a `TransferAndCall` message and executes its call.
+    ///
+    /// @dev This function can only be called from `__relayMessage`.
+    ///
+    /// @param data        The data of the message.
+    /// @param twinAddress The Twin contract executing the call.
+    function __finalizeTransferAndCall(bytes calldata data, address twinAddress) external {
+        _assertSenderIsEntrypoint();
+        _finalizeTransferAndCall({data: data, twinAddress: twinAddress});
+    }
+
     /// @notice Pauses or unpauses the bridge.
     ///
     /// @dev This function can only be called by a guardian.
@@ -367,8 +462,117 @@ contract Bridge is ReentrancyGuardTransient, Initializable, OwnableRoles {
         }
     }
 
+    /// @notice Finalizes the transfer of a `TransferAndCall` message and executes its call.
+    ///
+    /// @param data        The data of the message.
+    /// @param twinAddress The Twin contract executing the call.
+    function _finalizeTransferAndCall(bytes calldata data, address twinAddress) private {
+        (Transfer memory transfer, Call memory call) = abi.decode(data, (Transfer, Call));
+        TokenLib.finalizeTransfer({transfer: transfer, crossChainErc20Factory: CROSS_CHAIN_ERC20_FACTORY});
+
+        if (_hasSwapGuard(data)) {
+            (,,, SwapGuard memory swapGuard) = abi.decode(data, (Transfer, Call, bytes, SwapGuard));
+            _executeGuardedCall({twinAddress: twinAddress, call: call, swapGuard: swapGuard});
+        } else {
+            Twin(payable(twinAddress)).execute(call);
+        }
+    }
+
+    /// @notice Sends the tokens of a transfer whose call reverted back to Solana.
+    ///
+    /// @dev The transfer was never finalized on Base: tokens locked on Solana are released from their vault and
+    ///      wrapped tokens burned on Solana are re-minted. Solana returns the transfer of an outgoing message at most
+    ///      once.
+    ///
+    /// @param twinAddress   The Twin contract whose call reverted.
+    /// @param outgoingNonce The nonce of the Solana outgoing message whose transfer is returned.
+    /// @param transfer      The transfer to return.
+    /// @param to            The Solana account receiving the returned tokens.
+    function _returnTransfer(address twinAddress, uint64 outgoingNonce, Transfer memory transfer, Pubkey to) private {
+        SolanaTokenType tokenType =
+            TokenLib.getSolanaTokenType({transfer: transfer, crossChainErc20Factory: CROSS_CHAIN_ERC20_FACTORY});
+        transfer.to = Pubkey.unwrap(to);
+
+        MessageStorageLib.sendMessage({
+            sender: address(this),
+            data: SVMBridgeLib.serializeReturnTransfer({
+                outgoingNonce: outgoingNonce, transfer: transfer, tokenType: tokenType
+            })
+        });
+
+        emit TransferReturned({twin: twinAddress, outgoingNonce: outgoingNonce, to: to});
+    }
+
     /// @notice Asserts that the caller is the entrypoint.
     function _assertSenderIsEntrypoint() private view {
         require(msg.sender == address(this), SenderIsNotEntrypoint());
     }
+
+    /// @notice Returns whether the data of a `TransferAndCall` message carries a `SwapGuard`.
+    ///
+    /// @param data The data of the message.
+    function _hasSwapGuard(bytes calldata data) private pure returns (bool) {
+        return data.length >= 160 && uint256(bytes32(data[128:160])) == SWAP_GUARD_CALL_OFFSET;
+    }
+
+    /// @notice Executes a call through a Twin within the bounds of its swap guard.
+    ///
+    /// @dev The call is skipped once the deadline passed. Otherwise it must credit at least `minOut` of `tokenOut`
+    ///      to the Twin, or the message fails and can be retried (and its call skipped) later.
+    ///
+    /// @param twinAddress The Twin contract executing the call.
+    /// @param call        The call to execute.
+    /// @param swapGuard   The swap guard bounding the call.
+    function _executeGuardedCall(address twinAddress, Call memory call, SwapGuard memory swapGuard) private {
+        if (block.timestamp > swapGuard.deadline) {
+            emit SwapGuardExpired({twin: twinAddress, tokenOut: swapGuard.tokenOut, deadline: swapGuard.deadline});
+            return;
+        }
+
+        uint256 balanceBefore = _balanceOf({token: swapGuard.tokenOut, account: twinAddress});
+        Twin(payable(twinAddress)).execute(call);
+        uint256 balanceAfter = _balanceOf({token: swapGuard.tokenOut, account: twinAddress});
+
+        require(balanceAfter >= balanceBefore + swapGuard.minOut, InsufficientSwapOutput());
+    }
+
+    /// @notice Reads the ERC20 name, symbol and decimals of `token`.
+    ///
+    /// @param token The token to query.
+    ///
+    /// @return hasMetadata Whether all three values could be read.
+    /// @return name        The name of the token.
+    /// @return symbol      The symbol of the token.
+    /// @return decimals    The decimals of the token.
+    function _tokenMetadata(address token)
+        private
+        view
+        returns (bool hasMetadata, string memory name, string memory symbol, uint8 decimals)
+    {
+        (bool nameSuccess, bytes memory nameData) = token.staticcall(abi.encodeWithSignature("name()"));
+        (bool symbolSuccess, bytes memory symbolData) = token.staticcall(abi.encodeWithSignature("symbol()"));
+        (bool decimalsSuccess, bytes memory decimalsData) = token.staticcall(abi.encodeWithSignature("decimals()"));
+
+        if (
+            !nameSuccess || !symbolSuccess || !decimalsSuccess || nameData.length < 64 || symbolData.length < 64
+                || decimalsData.length < 32
+        ) {
+            return (false, "", "", 0);
+        }
+
+        uint256 rawDecimals = abi.decode(decimalsData, (uint256));
+        if (rawDecimals > type(uint8).max) {
+            return (false, "", "", 0);
+        }
+
+        return (true, abi.decode(nameData, (string)), abi.decode(symbolData, (string)), uint8(rawDecimals));
+    }
+
+    /// @notice Returns the balance of `account` in `token`, or in ETH if `token` is the zero address.
+    ///
+    /// @param token   The token to query.
+    /// @param account The account to query.
+    function _balanceOf(address token, address account) private view returns (uint256) {
+        return token == address(0) ? account.balance : SafeTransferLib.balanceOf({token: token, account: account});
+    }
 }
@@ -23,6 +23,17 @@ struct Call {
     bytes data;
 }
 
+/// @notice Struct representing the slippage bounds of a call swapping bridged tokens.
+///
+/// @custom:field tokenOut The token the call swaps into, or the zero address for ETH.
+/// @custom:field minOut The minimum amount of `tokenOut` the call must credit to the Twin executing it.
+/// @custom:field deadline Timestamp after which the call is skipped.
+struct SwapGuard {
+    address tokenOut;
+    uint128 minOut;
+    uint64 deadline;
+}
+
 library CallLib {
     //////////////////////////////////////////////////////////////
     ///                       Errors                           ///
@@ -9,7 +9,8 @@ import {Pubkey} from "./SVMLib.sol";
 enum MessageType {
     Call,
     Transfer,
-    TransferAndCall
+    TransferAndCall,
+    TransferBatch
 }
 
 /// @notice Message sent from Solana to Base.
@@ -19,7 +20,7 @@ enum MessageType {
 /// @custom:field nonce Unique nonce for the message.
 /// @custom:field sender The Solana sender's pubkey.
 /// @custom:field gasLimit The gas limit for the message execution.
-/// @custom:field ty The message type to execute (Call, Transfer, or TransferAndCall).
+/// @custom:field ty The message type to execute (Call, Transfer, TransferAndCall, or TransferBatch).
 /// @custom:field data Encoded payload associated with the message type.
 struct IncomingMessage {
     Pubkey outgoingMessagePubkey;
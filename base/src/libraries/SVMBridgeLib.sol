@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: MIT
 pragma solidity ^0.8.28;
 
-import {Ix, SVMLib} from "./SVMLib.sol";
+import {Ix, Pubkey, SVMLib} from "./SVMLib.sol";
 import {SolanaTokenType, Transfer} from "./TokenLib.sol";
 
 library SVMBridgeLib {
@@ -31,37 +31,93 @@ library SVMBridgeLib {
         returns (bytes memory)
     {
         // Variant discriminator for Transfer (1)
-        bytes memory result = abi.encodePacked(uint8(1));
+        bytes memory result = abi.encodePacked(uint8(1), _serializeTokenTransfer(transfer, tokenType));
 
+        // Serialize the instructions array
+        result = abi.encodePacked(result, SVMLib.serializeIxs(ixs));
+
+        return result;
+    }
+
+    /// @notice Serializes a Message::ReturnTransfer variant to Borsh-compatible bytes.
+    ///
+    /// @param outgoingNonce The nonce of the Solana outgoing message whose transfer is returned.
+    /// @param transfer      The token transfer returning the tokens to Solana.
+    /// @param tokenType     The Solana token type.
+    ///
+    /// @return Serialized Message::ReturnTransfer bytes ready for Solana deserialization
+    function serializeReturnTransfer(uint64 outgoingNonce, Transfer memory transfer, SolanaTokenType tokenType)
+        internal
+        pure
+        returns (bytes memory)
+    {
+        return abi.encodePacked(
+            uint8(2), // ReturnTransfer
+            SVMLib.toU64LittleEndian(outgoingNonce), // outgoing_nonce
+            _serializeTokenTransfer(transfer, tokenType) // transfer
+        );
+    }
+
+    /// @notice Serializes a Message::ConfirmWrappedToken variant to Borsh-compatible bytes.
+    ///
+    /// @param mint     The wrapped token mint on Solana.
+    /// @param name     The name of the Base token.
+    /// @param symbol   The symbol of the Base token.
+    /// @param decimals The decimals of the Base token.
+    ///
+    /// @return Serialized Message::ConfirmWrappedToken bytes ready for Solana deserialization
+    function serializeConfirmWrappedToken(Pubkey mint, string memory name, string memory symbol, uint8 decimals)
+        internal
+        pure
+        returns (bytes memory)
+    {
+        return abi.encodePacked(
+            uint8(7), // ConfirmWrappedToken
+            mint, // mint
+            SVMLib.toU32LittleEndian(bytes(name).length),
+            name, // name
+            SVMLib.toU32LittleEndian(bytes(symbol).length),
+            symbol, // symbol
+            decimals // decimals
+        );
+    }
+
+    //////////////////////////////////////////////////////////////
+    ///                     Private Functions                  ///
+    //////////////////////////////////////////////////////////////
+
+    /// @notice Serializes a token transfer to a Borsh-compatible Solana `Transfer` enum.
+    ///
+    /// @param transfer  The token transfer to serialize.
+    /// @param tokenType The Solana token type.
+    ///
+    /// @return Serialized Transfer bytes ready for Solana deserialization
+    function _serializeTokenTransfer(Transfer memory transfer, SolanaTokenType tokenType)
+        private
+        pure
+        returns (bytes memory)
+    {
         if (tokenType == SolanaTokenType.Sol) {
-            result = abi.encodePacked(
-                result,
+            return abi.encodePacked(
                 uint8(0), // Sol
                 transfer.to, // to
                 SVMLib.toU64LittleEndian(transfer.remoteAmount) // amount
             );
         } else if (tokenType == SolanaTokenType.Spl) {
-            result = abi.encodePacked(
-                result,
+            return abi.encodePacked(
                 uint8(1), // Spl
                 transfer.localToken, // remote_token
                 transfer.remoteToken, // local_token
                 transfer.to, // to
                 SVMLib.toU64LittleEndian(transfer.remoteAmount) // amount
             );
-        } else if (tokenType == SolanaTokenType.WrappedToken) {
-            result = abi.encodePacked(
-                result,
-                uint8(2), // WrappedToken
-                transfer.remoteToken, // local_token
-                transfer.to, // to
-                SVMLib.toU64LittleEndian(transfer.remoteAmount) // amount
-            );
         }
 
-        // Serialize the instructions array
-        result = abi.encodePacked(result, SVMLib.serializeIxs(ixs));
-
-        return result;
+        return abi.encodePacked(
+            uint8(2), // WrappedToken
+            transfer.remoteToken, // local_token
+            transfer.to, // to
+            SVMLib.toU64LittleEndian(transfer.remoteAmount) // amount
+        );
     }
 }
@@ -260,6 +260,27 @@ library TokenLib {
         });
     }
 
+    /// @notice Returns the Solana token type of a transfer bridged from Solana.
+    ///
+    /// @param transfer The token transfer.
+    /// @param crossChainErc20Factory The address of the CrossChainERC20Factory.
+    ///
+    /// @return The Solana token type.
+    function getSolanaTokenType(Transfer memory transfer, address crossChainErc20Factory)
+        internal
+        view
+        returns (SolanaTokenType)
+    {
+        if (
+            transfer.localToken != ETH_ADDRESS
+                && CrossChainERC20Factory(crossChainErc20Factory).isCrossChainErc20(transfer.localToken)
+        ) {
+            return transfer.remoteToken == NATIVE_SOL_PUBKEY ? SolanaTokenType.Sol : SolanaTokenType.Spl;
+        }
+
+        return SolanaTokenType.WrappedToken;
+    }
+
     /// @notice Registers a remote token and its conversion scalar.
     ///
     /// @param localToken Address of the ERC20 token on this chain.
@@ -8,11 +8,11 @@ import {DeployScript} from "../script/Deploy.s.sol";
 
 import {Bridge} from "../src/Bridge.sol";
 import {CrossChainERC20} from "../src/CrossChainERC20.sol";
-import {Call, CallType} from "../src/libraries/CallLib.sol";
+import {Call, CallType, SwapGuard} from "../src/libraries/CallLib.sol";
 import {IncomingMessage, MessageType} from "../src/libraries/MessageLib.sol";
 import {SVMBridgeLib} from "../src/libraries/SVMBridgeLib.sol";
 import {Ix, Pubkey, SVMLib} from "../src/libraries/SVMLib.sol";
-import {TokenLib, Transfer} from "../src/libraries/TokenLib.sol";
+import {SolanaTokenType, TokenLib, Transfer} from "../src/libraries/TokenLib.sol";
 
 import {CommonTest} from "./CommonTest.t.sol";
 import {MockERC20} from "./mocks/MockERC20.sol";
@@ -25,6 +25,8 @@ contract BridgeTest is CommonTest {
         Pubkey.wrap(0x1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef);
     Pubkey public constant TEST_REMOTE_TOKEN =
         Pubkey.wrap(0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef1234567890);
+    Pubkey public constant TEST_RETURN_RECIPIENT =
+        Pubkey.wrap(0x5555555555555555555555555555555555555555555555555555555555555555);
 
     // Mock contracts
     MockERC20 public mockToken;
@@ -35,6 +37,8 @@ contract BridgeTest is CommonTest {
     event MessageSuccessfullyRelayed(address indexed submitter, bytes32 indexed messageHash);
     event FailedToRelayMessage(address indexed submitter, bytes32 indexed messageHash);
     event PauseSwitched(bool paused);
+    event SwapGuardExpired(address indexed twin, address indexed tokenOut, uint64 deadline);
+    event TransferReturned(address indexed twin, uint64 indexed outgoingNonce, Pubkey to);
 
     function setUp() public {
         DeployScript deployer = new DeployScript();
@@ -493,6 +497,42 @@ contract BridgeTest is CommonTest {
         assertEq(crossChainToken.balanceOf(user), 100e6);
     }
 
+    function test_relayMessage_transferBatchType() public {
+        address otherUser = makeAddr("otherUser");
+
+        // Use the crossChainToken already deployed in setUp
+        Transfer[] memory transfers = new Transfer[](2);
+        transfers[0] = Transfer({
+            localToken: address(crossChainToken),
+            remoteToken: TEST_REMOTE_TOKEN,
+            to: bytes32(bytes20(user)), // Left-align the address in bytes32
+            remoteAmount: 100e6
+        });
+        transfers[1] = Transfer({
+            localToken: address(crossChainToken),
+            remoteToken: TEST_REMOTE_TOKEN,
+            to: bytes32(bytes20(otherUser)),
+            remoteAmount: 50e6
+        });
+
+        IncomingMessage[] memory messages = new IncomingMessage[](1);
+        messages[0] = IncomingMessage({
+            outgoingMessagePubkey: TEST_OUTGOING_MESSAGE,
+            nonce: 0,
+            sender: TEST_SENDER,
+            gasLimit: GAS_LIMIT,
+            ty: MessageType.TransferBatch,
+            data: abi.encode(transfers)
+        });
+
+        _registerMessage(messages[0]);
+        bridge.relayMessages(messages);
+
+        assertEq(crossChainToken.balanceOf(user), 100e6);
+        assertEq(crossChainToken.balanceOf(otherUser), 50e6);
+        assertEq(bridge.twins(TEST_SENDER), address(0));
+    }
+
     function test_relayMessage_transferAndCallType() public {
         // Use the crossChainToken already deployed in setUp
         Transfer memory transfer = Transfer({
@@ -526,6 +566,98 @@ contract BridgeTest is CommonTest {
         assertEq(mockTarget.value(), 456);
     }
 
+    function test_relayMessage_transferAndCallType_withSwapGuard() public {
+        IncomingMessage memory message = _guardedSwapMessage({amountOut: 50e18, minOut: 50e18, deadline: 1000});
+        address twin = bridge.getPredictedTwinAddress(TEST_SENDER);
+
+        _registerMessage(message);
+        vm.warp(1000);
+        bridge.relayMessages(_single(message));
+
+        assertTrue(bridge.successes(bridge.getMessageHash(message)));
+        assertEq(crossChainToken.balanceOf(user), 100e6);
+        assertEq(mockToken.balanceOf(twin), 50e18);
+    }
+
+    function test_relayMessage_transferAndCallType_failsBelowSwapGuardMinOut() public {
+        IncomingMessage memory message = _guardedSwapMessage({amountOut: 49e18, minOut: 50e18, deadline: 1000});
+        bytes32 messageHash = bridge.getMessageHash(message);
+
+        _registerMessage(message);
+        vm.warp(1000);
+        vm.expectEmit(true, true, false, false);
+        emit FailedToRelayMessage(address(this), messageHash);
+        bridge.relayMessages(_single(message));
+
+        assertTrue(bridge.failures(messageHash));
+        assertEq(crossChainToken.balanceOf(user), 0);
+    }
+
+    function test_relayMessage_transferAndCallType_skipsCallAfterSwapGuardDeadline() public {
+        IncomingMessage memory message = _guardedSwapMessage({amountOut: 49e18, minOut: 50e18, deadline: 1000});
+        address twin = bridge.getPredictedTwinAddress(TEST_SENDER);
+
+        _registerMessage(message);
+        vm.warp(1001);
+        vm.expectEmit(true, true, false, true);
+        emit SwapGuardExpired(twin, address(mockToken), 1000);
+        bridge.relayMessages(_single(message));
+
+        assertTrue(bridge.successes(bridge.getMessageHash(message)));
+        assertEq(crossChainToken.balanceOf(user), 100e6);
+        assertEq(mockToken.balanceOf(twin), 0);
+    }
+
+    function test_relayMessage_transferAndCallType_returnsTransferWhenCallReverts() public {
+        IncomingMessage memory message = _guardedSwapMessage({amountOut: 49e18, minOut: 50e18, deadline: 1000});
+        address twin = bridge.getPredictedTwinAddress(TEST_SENDER);
+
+        vm.prank(twin);
+        bridge.setReturnRecipient({remoteToken: TEST_REMOTE_TOKEN, to: TEST_RETURN_RECIPIENT});
+
+        _registerMessage(message);
+        vm.warp(1000);
+        vm.expectEmit(true, true, false, true);
+        emit TransferReturned(twin, message.nonce, TEST_RETURN_RECIPIENT);
+        bridge.relayMessages(_single(message));
+
+        // The message succeeds without finalizing the transfer on Base
+        assertTrue(bridge.successes(bridge.getMessageHash(message)));
+        assertEq(crossChainToken.balanceOf(user), 0);
+        assertEq(mockToken.balanceOf(twin), 0);
+
+        // The tokens are sent back to the return recipient on Solana
+        assertEq(bridge.getNextNonce(), 1);
+        bytes memory returnTransfer = SVMBridgeLib.serializeReturnTransfer({
+            outgoingNonce: message.nonce,
+            transfer: Transfer({
+                localToken: address(crossChainToken),
+                remoteToken: TEST_REMOTE_TOKEN,
+                to: Pubkey.unwrap(TEST_RETURN_RECIPIENT),
+                remoteAmount: 100e6
+            }),
+            tokenType: SolanaTokenType.Spl
+        });
+        assertEq(bridge.getRoot(), keccak256(abi.encodePacked(uint64(0), address(bridge), returnTransfer)));
+    }
+
+    function test_relayMessage_transferAndCallType_withReturnRecipientFinalizesSuccessfulCall() public {
+        IncomingMessage memory message = _guardedSwapMessage({amountOut: 50e18, minOut: 50e18, deadline: 1000});
+        address twin = bridge.getPredictedTwinAddress(TEST_SENDER);
+
+        vm.prank(twin);
+        bridge.setReturnRecipient({remoteToken: TEST_REMOTE_TOKEN, to: TEST_RETURN_RECIPIENT});
+
+        _registerMessage(message);
+        vm.warp(1000);
+        bridge.relayMessages(_single(message));
+
+        assertTrue(bridge.successes(bridge.getMessageHash(message)));
+        assertEq(crossChainToken.balanceOf(user), 100e6);
+        assertEq(mockToken.balanceOf(twin), 50e18);
+        assertEq(bridge.getNextNonce(), 0);
+    }
+
     //////////////////////////////////////////////////////////////
     ///                 Constructor Validation Tests           ///
     //////////////////////////////////////////////////////////////
@@ -632,6 +764,26 @@ contract BridgeTest is CommonTest {
         assertEq(bridge.twins(cfg.remoteBridge), address(0));
     }
 
+    function test_relayMessage_remoteTokenRegistrationSendsConfirmation() public {
+        _registerTokenPair(address(mockToken), TEST_REMOTE_TOKEN, 12, 0);
+
+        // The confirmation echoes the metadata of the registered token
+        assertEq(bridge.getNextNonce(), 1);
+        bytes memory confirmation = SVMBridgeLib.serializeConfirmWrappedToken({
+            mint: TEST_REMOTE_TOKEN, name: "Mock Token", symbol: "MOCK", decimals: 18
+        });
+        assertEq(bridge.getRoot(), keccak256(abi.encodePacked(uint64(0), address(bridge), confirmation)));
+    }
+
+    function test_relayMessage_remoteTokenRegistrationWithoutMetadataSendsNoConfirmation() public {
+        address localToken = makeAddr("noMetadata");
+        _registerTokenPair(localToken, TEST_REMOTE_TOKEN, 12, 0);
+
+        // The token is registered but never confirmed
+        assertEq(bridge.scalars(localToken, TEST_REMOTE_TOKEN), 1e12);
+        assertEq(bridge.getNextNonce(), 0);
+    }
+
     //////////////////////////////////////////////////////////////
     ///                    View Function Tests                 ///
     //////////////////////////////////////////////////////////////
@@ -686,10 +838,10 @@ contract BridgeTest is CommonTest {
 
         Ix[] memory ixs = new Ix[](0);
 
-        // Register the token pair first (this processes an incoming message, doesn't affect MMR)
+        // Register the token pair first (this also sends the registration confirmation)
         _registerTokenPair(address(mockToken), TEST_REMOTE_TOKEN, 12, 0);
 
-        // Send first bridge token transaction (1st outgoing message - root still 0)
+        // Send first bridge token transaction
         vm.startPrank(user);
         mockToken.approve(address(bridge), 200e18);
         bridge.bridgeToken(transfer, ixs);
@@ -699,13 +851,13 @@ contract BridgeTest is CommonTest {
         bytes32 rootAfterFirst = bridge.getRoot();
         assertNotEq(rootAfterFirst, bytes32(0), "Single leaf should return leaf hash, not zero");
 
-        // Send second bridge token transaction (2nd outgoing message - root should be non-zero)
+        // Send second bridge token transaction
         vm.startPrank(user);
         mockToken.approve(address(bridge), 100e18);
         bridge.bridgeToken(transfer, ixs);
         vm.stopPrank();
 
-        // Root should now be non-zero since we have 2+ outgoing messages
+        // Root should now be non-zero since we have several outgoing messages
         bytes32 rootAfterSecond = bridge.getRoot();
         assertNotEq(rootAfterSecond, initialRoot);
         assertNotEq(rootAfterSecond, bytes32(0));
@@ -753,29 +905,29 @@ contract BridgeTest is CommonTest {
         assertEq(roots[0], bytes32(0), "Root should be 0 initially");
 
         Ix[] memory ixs = new Ix[](0);
-        // Register token pair (this processes an incoming message, doesn't affect outgoing MMR)
+        // Register token pair (this also sends the registration confirmation)
         _registerTokenPair(address(mockToken), TEST_REMOTE_TOKEN, 12, 0);
 
-        // 1. Bridge call (1st outgoing message - root still 0)
+        // 1. Bridge call
         vm.prank(user);
         bridge.bridgeCall(ixs);
         roots[1] = bridge.getRoot();
 
-        // 2. Bridge token (2nd outgoing message - root should be non-zero)
+        // 2. Bridge token
         vm.startPrank(user);
         mockToken.approve(address(bridge), 100e18);
         bridge.bridgeToken(transfer, ixs);
         vm.stopPrank();
         roots[2] = bridge.getRoot();
 
-        // 3. Another bridge call (3rd outgoing message)
+        // 3. Another bridge call
         Ix[] memory ixs2 = new Ix[](1);
         ixs2[0] = Ix({programId: TEST_SENDER, serializedAccounts: new bytes[](0), data: hex"abcdef"});
         vm.prank(user);
         bridge.bridgeCall(ixs2);
         roots[3] = bridge.getRoot();
 
-        // 4. Another bridge token (4th outgoing message - need more tokens)
+        // 4. Another bridge token (need more tokens)
         mockToken.mint(user, 1000e18);
         vm.startPrank(user);
         mockToken.approve(address(bridge), 100e18);
@@ -1310,4 +1462,42 @@ contract BridgeTest is CommonTest {
         _registerMessage(messages[0]);
         bridge.relayMessages(messages);
     }
+
+    /// @notice Builds a `TransferAndCall` message whose call credits `amountOut` of `mockToken` to the sender's
+    ///         Twin, guarded by a swap guard on `mockToken`.
+    function _guardedSwapMessage(uint256 amountOut, uint128 minOut, uint64 deadline)
+        internal
+        view
+        returns (IncomingMessage memory)
+    {
+        Transfer memory transfer = Transfer({
+            localToken: address(crossChainToken),
+            remoteToken: TEST_REMOTE_TOKEN,
+            to: bytes32(bytes20(user)),
+            remoteAmount: 100e6
+        });
+
+        Call memory call = Call({
+            ty: CallType.Call,
+            to: address(mockToken),
+            value: 0,
+            data: abi.encodeCall(MockERC20.mint, (bridge.getPredictedTwinAddress(TEST_SENDER), amountOut))
+        });
+
+        SwapGuard memory swapGuard = SwapGuard({tokenOut: address(mockToken), minOut: minOut, deadline: deadline});
+
+        return IncomingMessage({
+            outgoingMessagePubkey: TEST_OUTGOING_MESSAGE,
+            nonce: 0,
+            sender: TEST_SENDER,
+            gasLimit: GAS_LIMIT,
+            ty: MessageType.TransferAndCall,
+            data: abi.encode(transfer, call, bytes(""), swapGuard)
+        });
+    }
+
+    function _single(IncomingMessage memory message) internal pure returns (IncomingMessage[] memory messages) {
+        messages = new IncomingMessage[](1);
+        messages[0] = message;
+    }
 }
@@ -0,0 +1,167 @@
+// SPDX-License-Identifier: MIT
+pragma solidity 0.8.28;
+
+import {Test} from "forge-std/Test.sol";
+
+import {Call, CallType} from "../../src/libraries/CallLib.sol";
+import {IncomingMessage, MessageLib, MessageType} from "../../src/libraries/MessageLib.sol";
+import {Pubkey} from "../../src/libraries/SVMLib.sol";
+import {Transfer} from "../../src/libraries/TokenLib.sol";
+
+/// @notice Golden tests pinning the encoding of Solana to Base messages against the vectors in
+///         `golden/messages.json`, which are shared with the Solana bridge program (`golden.rs`).
+contract MessageLibTest is Test {
+    string internal golden;
+
+    function setUp() public {
+        golden = vm.readFile(string.concat(vm.projectRoot(), "/../golden/messages.json"));
+    }
+
+    //////////////////////////////////////////////////////////////
+    ///                    Golden Vector Tests                 ///
+    //////////////////////////////////////////////////////////////
+
+    function test_golden_call() public view {
+        string memory key = _vectorKey(0, "call");
+        assertEq(vm.parseJsonUint(golden, string.concat(key, ".ty")), uint8(MessageType.Call));
+
+        bytes memory data = abi.encode(_call(key));
+
+        _assertVector(key, data);
+    }
+
+    function test_golden_transfer() public view {
+        string memory key = _vectorKey(1, "transfer");
+        assertEq(vm.parseJsonUint(golden, string.concat(key, ".ty")), uint8(MessageType.Transfer));
+
+        bytes memory data = abi.encode(_transfer(key));
+
+        _assertVector(key, data);
+    }
+
+    function test_golden_transferAndCall() public view {
+        string memory key = _vectorKey(2, "transfer_and_call");
+        assertEq(vm.parseJsonUint(golden, string.concat(key, ".ty")), uint8(MessageType.TransferAndCall));
+
+        bytes memory data = abi.encode(_transfer(key), _call(key));
+
+        _assertVector(key, data);
+    }
+
+    function test_golden_transferWithMemo() public view {
+        string memory key = _vectorKey(3, "transfer_with_memo");
+        assertEq(vm.parseJsonUint(golden, string.concat(key, ".ty")), uint8(MessageType.Transfer));
+
+        bytes memory memo = vm.parseJsonBytes(golden, string.concat(key, ".transfer.memo"));
+        bytes memory data = abi.encode(_transfer(key), memo);
+
+        _assertVector(key, data);
+
+        // The appended memo does not affect the decoding of the transfer
+        Transfer memory transfer = abi.decode(data, (Transfer));
+        assertEq(abi.encode(transfer), abi.encode(_transfer(key)));
+    }
+
+    function test_golden_transferBatch() public view {
+        string memory key = _vectorKey(4, "transfer_batch");
+        assertEq(vm.parseJsonUint(golden, string.concat(key, ".ty")), uint8(MessageType.TransferBatch));
+
+        bytes memory data = abi.encode(_transferBatch(key));
+
+        _assertVector(key, data);
+    }
+
+    function test_golden_decodesData() public view {
+        Call memory call = abi.decode(vm.parseJsonBytes(golden, ".vectors[0].data"), (Call));
+        assertEq(call.to, vm.parseJsonAddress(golden, ".vectors[0].call.to"));
+
+        Transfer memory transfer = abi.decode(vm.parseJsonBytes(golden, ".vectors[1].data"), (Transfer));
+        assertEq(address(bytes20(transfer.to)), vm.parseJsonAddress(golden, ".vectors[1].transfer.to"));
+
+        (transfer, call) = abi.decode(vm.parseJsonBytes(golden, ".vectors[2].data"), (Transfer, Call));
+        assertEq(transfer.remoteAmount, vm.parseJsonUint(golden, ".vectors[2].transfer.amount"));
+        assertEq(call.data, vm.parseJsonBytes(golden, ".vectors[2].call.data"));
+
+        Transfer[] memory transfers = abi.decode(vm.parseJsonBytes(golden, ".vectors[4].data"), (Transfer[]));
+        assertEq(transfers.length, 2);
+        assertEq(
+            address(bytes20(transfers[1].to)),
+            vm.parseJsonAddress(golden, ".vectors[4].transfer_batch.recipients[1].to")
+        );
+    }
+
+    //////////////////////////////////////////////////////////////
+    ///                   Private Functions                    ///
+    //////////////////////////////////////////////////////////////
+
+    function _vectorKey(uint256 index, string memory name) private view returns (string memory key) {
+        key = string.concat(".vectors[", vm.toString(index), "]");
+        assertEq(vm.parseJsonString(golden, string.concat(key, ".name")), name, "Unexpected golden vector");
+    }
+
+    /// @dev Builds the `Call` of a vector from its Solana-side fields.
+    function _call(string memory key) private view returns (Call memory) {
+        return Call({
+            ty: CallType(vm.parseJsonUint(golden, string.concat(key, ".call.ty"))),
+            to: vm.parseJsonAddress(golden, string.concat(key, ".call.to")),
+            value: uint128(vm.parseJsonUint(golden, string.concat(key, ".call.value"))),
+            data: vm.parseJsonBytes(golden, string.concat(key, ".call.data"))
+        });
+    }
+
+    /// @dev Builds the `Transfer` of a vector from its Solana-side fields. The local and remote tokens are swapped
+    ///      and `to` is right padded so that `bytes20(to)` yields the recipient.
+    function _transfer(string memory key) private view returns (Transfer memory) {
+        return Transfer({
+            localToken: vm.parseJsonAddress(golden, string.concat(key, ".transfer.remote_token")),
+            remoteToken: Pubkey.wrap(vm.parseJsonBytes32(golden, string.concat(key, ".transfer.local_token"))),
+            to: bytes32(bytes20(vm.parseJsonAddress(golden, string.concat(key, ".transfer.to")))),
+            remoteAmount: uint64(vm.parseJsonUint(golden, string.concat(key, ".transfer.amount")))
+        });
+    }
+
+    /// @dev Builds the `Transfer` of each recipient of a batch vector, which share the tokens of the batch.
+    function _transferBatch(string memory key) private view returns (Transfer[] memory transfers) {
+        string memory batchKey = string.concat(key, ".transfer_batch");
+        address localToken = vm.parseJsonAddress(golden, string.concat(batchKey, ".remote_token"));
+        Pubkey remoteToken = Pubkey.wrap(vm.parseJsonBytes32(golden, string.concat(batchKey, ".local_token")));
+        address[] memory to = vm.parseJsonAddressArray(golden, string.concat(batchKey, ".recipients[*].to"));
+
+        transfers = new Transfer[](to.length);
+        for (uint256 i; i < to.length; i++) {
+            string memory recipientKey = string.concat(batchKey, ".recipients[", vm.toString(i), "]");
+            transfers[i] = Transfer({
+                localToken: localToken,
+                remoteToken: remoteToken,
+                to: bytes32(bytes20(to[i])),
+                remoteAmount: uint64(vm.parseJsonUint(golden, string.concat(recipientKey, ".amount")))
+            });
+        }
+    }
+
+    function _assertVector(string memory key, bytes memory data) private view {
+        assertEq(data, vm.parseJsonBytes(golden, string.concat(key, ".data")), "ABI encoding mismatch");
+
+        IncomingMessage memory message = IncomingMessage({
+            outgoingMessagePubkey: Pubkey.wrap(
+                vm.parseJsonBytes32(golden, string.concat(key, ".outgoing_message_pubkey"))
+            ),
+            nonce: uint64(vm.parseJsonUint(golden, string.concat(key, ".nonce"))),
+            sender: Pubkey.wrap(vm.parseJsonBytes32(golden, string.concat(key, ".sender"))),
+            gasLimit: 100_000,
+            ty: MessageType(vm.parseJsonUint(golden, string.concat(key, ".ty"))),
+            data: data
+        });
+
+        assertEq(
+            MessageLib.getInnerMessageHash(message),
+            vm.parseJsonBytes32(golden, string.concat(key, ".inner_hash")),
+            "Inner hash mismatch"
+        );
+        assertEq(
+            MessageLib.getMessageHash(message),
+            vm.parseJsonBytes32(golden, string.concat(key, ".message_hash")),
+            "Message hash mismatch"
+        );
+    }
+}
@@ -352,4 +352,104 @@ contract SVMBridgeLibTest is Test {
 
         assertEq(result, expected, "Large instruction data failed");
     }
+
+    //////////////////////////////////////////////////////////////
+    ///             SerializeReturnTransfer Tests              ///
+    //////////////////////////////////////////////////////////////
+
+    function test_serializeReturnTransfer_sol() public pure {
+        Transfer memory transfer = Transfer({
+            localToken: TEST_LOCAL_TOKEN,
+            remoteToken: TEST_NATIVE_SOL,
+            to: TEST_TO,
+            remoteAmount: 1000000000 // 1 SOL
+        });
+
+        bytes memory result = SVMBridgeLib.serializeReturnTransfer(42, transfer, SolanaTokenType.Sol);
+
+        bytes memory expected = abi.encodePacked(
+            uint8(2), // ReturnTransfer variant
+            SVMLib.toU64LittleEndian(42), // outgoing_nonce (8 bytes)
+            uint8(0), // Sol token type
+            transfer.to, // to (32 bytes)
+            SVMLib.toU64LittleEndian(transfer.remoteAmount) // amount (8 bytes)
+        );
+
+        assertEq(result, expected, "SOL return transfer serialization failed");
+    }
+
+    /// @dev The same bytes are decoded as a `Message::ReturnTransfer` by the Solana bridge tests.
+    function test_serializeReturnTransfer_spl_matchesSolanaEncoding() public pure {
+        Transfer memory transfer = Transfer({
+            localToken: 0x1111111111111111111111111111111111111111,
+            remoteToken: Pubkey.wrap(0x2222222222222222222222222222222222222222222222222222222222222222),
+            to: 0x3333333333333333333333333333333333333333333333333333333333333333,
+            remoteAmount: 1000000
+        });
+
+        bytes memory result = SVMBridgeLib.serializeReturnTransfer(7, transfer, SolanaTokenType.Spl);
+
+        bytes memory expected = hex"02" hex"0700000000000000" hex"01"
+            hex"1111111111111111111111111111111111111111"
+            hex"2222222222222222222222222222222222222222222222222222222222222222"
+            hex"3333333333333333333333333333333333333333333333333333333333333333" hex"40420f0000000000";
+
+        assertEq(result, expected, "SPL return transfer serialization failed");
+    }
+
+    function test_serializeReturnTransfer_wrappedToken() public pure {
+        Transfer memory transfer =
+            Transfer({localToken: TEST_LOCAL_TOKEN, remoteToken: TEST_REMOTE_TOKEN, to: TEST_TO, remoteAmount: 500});
+
+        bytes memory result =
+            SVMBridgeLib.serializeReturnTransfer(type(uint64).max, transfer, SolanaTokenType.WrappedToken);
+
+        bytes memory expected = abi.encodePacked(
+            uint8(2), // ReturnTransfer variant
+            SVMLib.toU64LittleEndian(type(uint64).max), // outgoing_nonce (8 bytes)
+            uint8(2), // WrappedToken type
+            transfer.remoteToken, // local_token (32 bytes)
+            transfer.to, // to (32 bytes)
+            SVMLib.toU64LittleEndian(transfer.remoteAmount) // amount (8 bytes)
+        );
+
+        assertEq(result, expected, "Wrapped token return transfer serialization failed");
+    }
+
+    //////////////////////////////////////////////////////////////
+    ///          SerializeConfirmWrappedToken Tests            ///
+    //////////////////////////////////////////////////////////////
+
+    function test_serializeConfirmWrappedToken() public pure {
+        bytes memory result =
+            SVMBridgeLib.serializeConfirmWrappedToken({mint: TEST_REMOTE_TOKEN, name: "AB", symbol: "C", decimals: 18});
+
+        // Expected: variant discriminator (7) + mint + borsh strings + decimals
+        bytes memory expected = abi.encodePacked(
+            uint8(7), // ConfirmWrappedToken variant
+            TEST_REMOTE_TOKEN,
+            hex"02000000",
+            "AB",
+            hex"01000000",
+            "C",
+            uint8(18)
+        );
+
+        assertEq(result, expected, "ConfirmWrappedToken serialization failed");
+    }
+
+    /// @dev The same bytes are decoded as a `Message::ConfirmWrappedToken` by the Solana bridge tests.
+    function test_serializeConfirmWrappedToken_matchesSolanaEncoding() public pure {
+        bytes memory result = SVMBridgeLib.serializeConfirmWrappedToken({
+            mint: Pubkey.wrap(0x2222222222222222222222222222222222222222222222222222222222222222),
+            name: "Mock Token",
+            symbol: "MOCK",
+            decimals: 18
+        });
+
+        bytes memory expected = hex"07" hex"2222222222222222222222222222222222222222222222222222222222222222"
+            hex"0a000000" hex"4d6f636b20546f6b656e" hex"04000000" hex"4d4f434b" hex"12";
+
+        assertEq(result, expected, "ConfirmWrappedToken serialization failed");
+    }
 }
@@ -159,5 +159,5 @@ export async function fetchAllMaybeCfg(
 }
 
 export function getCfgSize(): number {
-  return 168;
+  return 200;
 }
@@ -6,5 +6,10 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './calldataPricing';
 export * from './cfg';
+export * from './feeExemptions';
 export * from './messageToRelay';
+export * from './nonceMonitor';
+export * from './pendingRelayIndex';
+export * from './sponsorPolicy';
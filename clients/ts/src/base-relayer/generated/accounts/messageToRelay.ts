@@ -19,6 +19,10 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -26,14 +30,16 @@ import {
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from '@solana/kit';
 
@@ -52,36 +58,66 @@ export type MessageToRelay = {
   nonce: bigint;
   outgoingMessage: Address;
   gasLimit: bigint;
+  /** Lamports escrowed in this account for the relayer that executes the message on Base. */
+  tipLamports: bigint;
+  /** The payer of the relay request, the only account allowed to bump its tip. */
+  payer: Address;
+  /**
+   * Bridge nonce of the relayed `OutgoingMessage`, recorded when its account was provided.
+   * Required to escrow a tip, which is settled once the bridge confirms the nonce as relayed.
+   */
+  outgoingNonce: Option<bigint>;
+  /** Unix timestamp at which the relay was paid for, starting the tip refund delay. */
+  createdAt: bigint;
 };
 
 export type MessageToRelayArgs = {
   nonce: number | bigint;
   outgoingMessage: Address;
   gasLimit: number | bigint;
+  /** Lamports escrowed in this account for the relayer that executes the message on Base. */
+  tipLamports: number | bigint;
+  /** The payer of the relay request, the only account allowed to bump its tip. */
+  payer: Address;
+  /**
+   * Bridge nonce of the relayed `OutgoingMessage`, recorded when its account was provided.
+   * Required to escrow a tip, which is settled once the bridge confirms the nonce as relayed.
+   */
+  outgoingNonce: OptionOrNullable<number | bigint>;
+  /** Unix timestamp at which the relay was paid for, starting the tip refund delay. */
+  createdAt: number | bigint;
 };
 
-export function getMessageToRelayEncoder(): FixedSizeEncoder<MessageToRelayArgs> {
+export function getMessageToRelayEncoder(): Encoder<MessageToRelayArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['nonce', getU64Encoder()],
       ['outgoingMessage', getAddressEncoder()],
       ['gasLimit', getU64Encoder()],
+      ['tipLamports', getU64Encoder()],
+      ['payer', getAddressEncoder()],
+      ['outgoingNonce', getOptionEncoder(getU64Encoder())],
+      ['createdAt', getI64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: MESSAGE_TO_RELAY_DISCRIMINATOR })
   );
 }
 
-export function getMessageToRelayDecoder(): FixedSizeDecoder<MessageToRelay> {
+export function getMessageToRelayDecoder(): Decoder<MessageToRelay> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['nonce', getU64Decoder()],
     ['outgoingMessage', getAddressDecoder()],
     ['gasLimit', getU64Decoder()],
+    ['tipLamports', getU64Decoder()],
+    ['payer', getAddressDecoder()],
+    ['outgoingNonce', getOptionDecoder(getU64Decoder())],
+    ['createdAt', getI64Decoder()],
   ]);
 }
 
-export function getMessageToRelayCodec(): FixedSizeCodec<
+export function getMessageToRelayCodec(): Codec<
   MessageToRelayArgs,
   MessageToRelay
 > {
@@ -148,7 +184,3 @@ export async function fetchAllMaybeMessageToRelay(
     decodeMessageToRelay(maybeAccount)
   );
 }
-
-export function getMessageToRelaySize(): number {
-  return 56;
-}
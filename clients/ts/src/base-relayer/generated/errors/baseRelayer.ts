@@ -18,32 +18,148 @@ import { BASE_RELAYER_PROGRAM_ADDRESS } from '../programs';
 export const BASE_RELAYER_ERROR__UNAUTHORIZED_INITIALIZATION = 0x2ee0; // 12000
 /** IncorrectRelayerProgram: Incorrect relayer program */
 export const BASE_RELAYER_ERROR__INCORRECT_RELAYER_PROGRAM = 0x2ee1; // 12001
+/** UnauthorizedMigration: Only the upgrade authority can migrate the relayer config */
+export const BASE_RELAYER_ERROR__UNAUTHORIZED_MIGRATION = 0x2ee2; // 12002
+/** InvalidCfgLayout: Relayer config account has an unknown layout */
+export const BASE_RELAYER_ERROR__INVALID_CFG_LAYOUT = 0x2ee3; // 12003
+/** CfgAlreadyMigrated: Relayer config account is already migrated */
+export const BASE_RELAYER_ERROR__CFG_ALREADY_MIGRATED = 0x2ee4; // 12004
+/** EnvironmentMismatch: Program was not built for the expected environment */
+export const BASE_RELAYER_ERROR__ENVIRONMENT_MISMATCH = 0x2ee5; // 12005
 /** UnauthorizedConfigUpdate: Unauthorized to update configuration */
 export const BASE_RELAYER_ERROR__UNAUTHORIZED_CONFIG_UPDATE = 0x2f44; // 12100
+/** TooManySponsoredSenders: Too many sponsored senders */
+export const BASE_RELAYER_ERROR__TOO_MANY_SPONSORED_SENDERS = 0x2f45; // 12101
+/** TooManyFeeExemptSenders: Too many fee exempt senders */
+export const BASE_RELAYER_ERROR__TOO_MANY_FEE_EXEMPT_SENDERS = 0x2f46; // 12102
+/** InvalidFeeConfig: Invalid fee config */
+export const BASE_RELAYER_ERROR__INVALID_FEE_CONFIG = 0x2f47; // 12103
+/** InvalidGasTarget: EIP-1559 gas target must be non-zero */
+export const BASE_RELAYER_ERROR__INVALID_GAS_TARGET = 0x2f48; // 12104
+/** InvalidDenominator: EIP-1559 adjustment denominator must be non-zero */
+export const BASE_RELAYER_ERROR__INVALID_DENOMINATOR = 0x2f49; // 12105
+/** InvalidWindowDuration: EIP-1559 window duration must be non-zero */
+export const BASE_RELAYER_ERROR__INVALID_WINDOW_DURATION = 0x2f4a; // 12106
+/** InvalidMaxBaseFee: Maximum base fee must be zero or at least the minimum base fee */
+export const BASE_RELAYER_ERROR__INVALID_MAX_BASE_FEE = 0x2f4b; // 12107
+/** InvalidGasLimitBounds: Minimum gas limit per message must not exceed the maximum */
+export const BASE_RELAYER_ERROR__INVALID_GAS_LIMIT_BOUNDS = 0x2f4c; // 12108
+/** InvalidGasCostScalerDp: Gas cost scaler decimal precision must be non-zero */
+export const BASE_RELAYER_ERROR__INVALID_GAS_COST_SCALER_DP = 0x2f4d; // 12109
 /** GasLimitTooLow: Gas limit too low */
 export const BASE_RELAYER_ERROR__GAS_LIMIT_TOO_LOW = 0x2fa8; // 12200
 /** GasLimitExceeded: Gas limit exceeded */
 export const BASE_RELAYER_ERROR__GAS_LIMIT_EXCEEDED = 0x2fa9; // 12201
+/** GasLimitNotDerivable: Gas limit can only be derived for transfers without call */
+export const BASE_RELAYER_ERROR__GAS_LIMIT_NOT_DERIVABLE = 0x2faa; // 12202
 /** IncorrectGasFeeReceiver: Incorrect gas fee receiver */
 export const BASE_RELAYER_ERROR__INCORRECT_GAS_FEE_RECEIVER = 0x300c; // 12300
+/** NoRelayTip: No relay tip to settle */
+export const BASE_RELAYER_ERROR__NO_RELAY_TIP = 0x300d; // 12301
+/** SponsorSpendLimitExceeded: Sponsor spend limit exceeded */
+export const BASE_RELAYER_ERROR__SPONSOR_SPEND_LIMIT_EXCEEDED = 0x300e; // 12302
+/** SenderNotSponsored: Outgoing message sender is not sponsored */
+export const BASE_RELAYER_ERROR__SENDER_NOT_SPONSORED = 0x300f; // 12303
+/** MissingOutgoingMessage: Outgoing message account is required */
+export const BASE_RELAYER_ERROR__MISSING_OUTGOING_MESSAGE = 0x3010; // 12304
+/** InvalidOutgoingMessage: Invalid outgoing message account */
+export const BASE_RELAYER_ERROR__INVALID_OUTGOING_MESSAGE = 0x3011; // 12305
+/** UnauthorizedRelayFeeBump: Only the original payer can bump the relay fee */
+export const BASE_RELAYER_ERROR__UNAUTHORIZED_RELAY_FEE_BUMP = 0x3012; // 12306
+/** ZeroRelayFeeBump: Relay fee bump must be greater than zero */
+export const BASE_RELAYER_ERROR__ZERO_RELAY_FEE_BUMP = 0x3013; // 12307
+/** IncorrectRelayedNonces: Incorrect relayed nonces account */
+export const BASE_RELAYER_ERROR__INCORRECT_RELAYED_NONCES = 0x3014; // 12308
+/** RelayNotConfirmed: Outgoing message is not confirmed as relayed on Base */
+export const BASE_RELAYER_ERROR__RELAY_NOT_CONFIRMED = 0x3015; // 12309
+/** RelayAlreadyConfirmed: Outgoing message is already confirmed as relayed on Base */
+export const BASE_RELAYER_ERROR__RELAY_ALREADY_CONFIRMED = 0x3016; // 12310
+/** RelayTipRefundNotDue: Relay tip refund delay has not elapsed */
+export const BASE_RELAYER_ERROR__RELAY_TIP_REFUND_NOT_DUE = 0x3017; // 12311
+/** IncorrectRelayPayer: Incorrect relay request payer */
+export const BASE_RELAYER_ERROR__INCORRECT_RELAY_PAYER = 0x3018; // 12312
+/** IncorrectRelayExecution: Incorrect relay execution account */
+export const BASE_RELAYER_ERROR__INCORRECT_RELAY_EXECUTION = 0x3019; // 12313
+/** RelayExecutionNotRecorded: Relayer executing the outgoing message on Base is not recorded */
+export const BASE_RELAYER_ERROR__RELAY_EXECUTION_NOT_RECORDED = 0x301a; // 12314
+/** IncorrectRelayer: Relayer does not match the recorded relay execution */
+export const BASE_RELAYER_ERROR__INCORRECT_RELAYER = 0x301b; // 12315
 
 export type BaseRelayerError =
+  | typeof BASE_RELAYER_ERROR__CFG_ALREADY_MIGRATED
+  | typeof BASE_RELAYER_ERROR__ENVIRONMENT_MISMATCH
   | typeof BASE_RELAYER_ERROR__GAS_LIMIT_EXCEEDED
+  | typeof BASE_RELAYER_ERROR__GAS_LIMIT_NOT_DERIVABLE
   | typeof BASE_RELAYER_ERROR__GAS_LIMIT_TOO_LOW
   | typeof BASE_RELAYER_ERROR__INCORRECT_GAS_FEE_RECEIVER
+  | typeof BASE_RELAYER_ERROR__INCORRECT_RELAYED_NONCES
+  | typeof BASE_RELAYER_ERROR__INCORRECT_RELAYER
   | typeof BASE_RELAYER_ERROR__INCORRECT_RELAYER_PROGRAM
+  | typeof BASE_RELAYER_ERROR__INCORRECT_RELAY_EXECUTION
+  | typeof BASE_RELAYER_ERROR__INCORRECT_RELAY_PAYER
+  | typeof BASE_RELAYER_ERROR__INVALID_CFG_LAYOUT
+  | typeof BASE_RELAYER_ERROR__INVALID_DENOMINATOR
+  | typeof BASE_RELAYER_ERROR__INVALID_FEE_CONFIG
+  | typeof BASE_RELAYER_ERROR__INVALID_GAS_COST_SCALER_DP
+  | typeof BASE_RELAYER_ERROR__INVALID_GAS_LIMIT_BOUNDS
+  | typeof BASE_RELAYER_ERROR__INVALID_GAS_TARGET
+  | typeof BASE_RELAYER_ERROR__INVALID_MAX_BASE_FEE
+  | typeof BASE_RELAYER_ERROR__INVALID_OUTGOING_MESSAGE
+  | typeof BASE_RELAYER_ERROR__INVALID_WINDOW_DURATION
+  | typeof BASE_RELAYER_ERROR__MISSING_OUTGOING_MESSAGE
+  | typeof BASE_RELAYER_ERROR__NO_RELAY_TIP
+  | typeof BASE_RELAYER_ERROR__RELAY_ALREADY_CONFIRMED
+  | typeof BASE_RELAYER_ERROR__RELAY_EXECUTION_NOT_RECORDED
+  | typeof BASE_RELAYER_ERROR__RELAY_NOT_CONFIRMED
+  | typeof BASE_RELAYER_ERROR__RELAY_TIP_REFUND_NOT_DUE
+  | typeof BASE_RELAYER_ERROR__SENDER_NOT_SPONSORED
+  | typeof BASE_RELAYER_ERROR__SPONSOR_SPEND_LIMIT_EXCEEDED
+  | typeof BASE_RELAYER_ERROR__TOO_MANY_FEE_EXEMPT_SENDERS
+  | typeof BASE_RELAYER_ERROR__TOO_MANY_SPONSORED_SENDERS
   | typeof BASE_RELAYER_ERROR__UNAUTHORIZED_CONFIG_UPDATE
-  | typeof BASE_RELAYER_ERROR__UNAUTHORIZED_INITIALIZATION;
+  | typeof BASE_RELAYER_ERROR__UNAUTHORIZED_INITIALIZATION
+  | typeof BASE_RELAYER_ERROR__UNAUTHORIZED_MIGRATION
+  | typeof BASE_RELAYER_ERROR__UNAUTHORIZED_RELAY_FEE_BUMP
+  | typeof BASE_RELAYER_ERROR__ZERO_RELAY_FEE_BUMP;
 
 let baseRelayerErrorMessages: Record<BaseRelayerError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   baseRelayerErrorMessages = {
+    [BASE_RELAYER_ERROR__CFG_ALREADY_MIGRATED]: `Relayer config account is already migrated`,
+    [BASE_RELAYER_ERROR__ENVIRONMENT_MISMATCH]: `Program was not built for the expected environment`,
     [BASE_RELAYER_ERROR__GAS_LIMIT_EXCEEDED]: `Gas limit exceeded`,
+    [BASE_RELAYER_ERROR__GAS_LIMIT_NOT_DERIVABLE]: `Gas limit can only be derived for transfers without call`,
     [BASE_RELAYER_ERROR__GAS_LIMIT_TOO_LOW]: `Gas limit too low`,
     [BASE_RELAYER_ERROR__INCORRECT_GAS_FEE_RECEIVER]: `Incorrect gas fee receiver`,
+    [BASE_RELAYER_ERROR__INCORRECT_RELAYED_NONCES]: `Incorrect relayed nonces account`,
+    [BASE_RELAYER_ERROR__INCORRECT_RELAYER]: `Relayer does not match the recorded relay execution`,
     [BASE_RELAYER_ERROR__INCORRECT_RELAYER_PROGRAM]: `Incorrect relayer program`,
+    [BASE_RELAYER_ERROR__INCORRECT_RELAY_EXECUTION]: `Incorrect relay execution account`,
+    [BASE_RELAYER_ERROR__INCORRECT_RELAY_PAYER]: `Incorrect relay request payer`,
+    [BASE_RELAYER_ERROR__INVALID_CFG_LAYOUT]: `Relayer config account has an unknown layout`,
+    [BASE_RELAYER_ERROR__INVALID_DENOMINATOR]: `EIP-1559 adjustment denominator must be non-zero`,
+    [BASE_RELAYER_ERROR__INVALID_FEE_CONFIG]: `Invalid fee config`,
+    [BASE_RELAYER_ERROR__INVALID_GAS_COST_SCALER_DP]: `Gas cost scaler decimal precision must be non-zero`,
+    [BASE_RELAYER_ERROR__INVALID_GAS_LIMIT_BOUNDS]: `Minimum gas limit per message must not exceed the maximum`,
+    [BASE_RELAYER_ERROR__INVALID_GAS_TARGET]: `EIP-1559 gas target must be non-zero`,
+    [BASE_RELAYER_ERROR__INVALID_MAX_BASE_FEE]: `Maximum base fee must be zero or at least the minimum base fee`,
+    [BASE_RELAYER_ERROR__INVALID_OUTGOING_MESSAGE]: `Invalid outgoing message account`,
+    [BASE_RELAYER_ERROR__INVALID_WINDOW_DURATION]: `EIP-1559 window duration must be non-zero`,
+    [BASE_RELAYER_ERROR__MISSING_OUTGOING_MESSAGE]: `Outgoing message account is required`,
+    [BASE_RELAYER_ERROR__NO_RELAY_TIP]: `No relay tip to settle`,
+    [BASE_RELAYER_ERROR__RELAY_ALREADY_CONFIRMED]: `Outgoing message is already confirmed as relayed on Base`,
+    [BASE_RELAYER_ERROR__RELAY_EXECUTION_NOT_RECORDED]: `Relayer executing the outgoing message on Base is not recorded`,
+    [BASE_RELAYER_ERROR__RELAY_NOT_CONFIRMED]: `Outgoing message is not confirmed as relayed on Base`,
+    [BASE_RELAYER_ERROR__RELAY_TIP_REFUND_NOT_DUE]: `Relay tip refund delay has not elapsed`,
+    [BASE_RELAYER_ERROR__SENDER_NOT_SPONSORED]: `Outgoing message sender is not sponsored`,
+    [BASE_RELAYER_ERROR__SPONSOR_SPEND_LIMIT_EXCEEDED]: `Sponsor spend limit exceeded`,
+    [BASE_RELAYER_ERROR__TOO_MANY_FEE_EXEMPT_SENDERS]: `Too many fee exempt senders`,
+    [BASE_RELAYER_ERROR__TOO_MANY_SPONSORED_SENDERS]: `Too many sponsored senders`,
     [BASE_RELAYER_ERROR__UNAUTHORIZED_CONFIG_UPDATE]: `Unauthorized to update configuration`,
     [BASE_RELAYER_ERROR__UNAUTHORIZED_INITIALIZATION]: `Only the upgrade authority can initialize the relayer`,
+    [BASE_RELAYER_ERROR__UNAUTHORIZED_MIGRATION]: `Only the upgrade authority can migrate the relayer config`,
+    [BASE_RELAYER_ERROR__UNAUTHORIZED_RELAY_FEE_BUMP]: `Only the original payer can bump the relay fee`,
+    [BASE_RELAYER_ERROR__ZERO_RELAY_FEE_BUMP]: `Relay fee bump must be greater than zero`,
   };
 }
 
@@ -6,8 +6,21 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './bumpRelayFee';
+export * from './checkEnvironment';
+export * from './clearNonceAnomaly';
+export * from './createPendingRelayIndex';
+export * from './getEnvironment';
 export * from './initialize';
+export * from './migrateCfg';
 export * from './payForRelay';
+export * from './refundRelayTip';
+export * from './setCalldataPricing';
 export * from './setEip1559Config';
+export * from './setFeeConfig';
+export * from './setFeeExemptions';
 export * from './setGasConfig';
 export * from './setGuardian';
+export * from './setNonceMonitor';
+export * from './setSponsorPolicy';
+export * from './settleRelayTip';
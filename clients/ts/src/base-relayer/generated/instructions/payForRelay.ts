@@ -53,6 +53,13 @@ export type PayForRelayInstruction<
   TAccountCfg extends string | AccountMeta<string> = string,
   TAccountGasFeeReceiver extends string | AccountMeta<string> = string,
   TAccountMessageToRelay extends string | AccountMeta<string> = string,
+  TAccountFeeExemptions extends string | AccountMeta<string> = string,
+  TAccountSponsorPolicy extends string | AccountMeta<string> = string,
+  TAccountNonceMonitor extends string | AccountMeta<string> = string,
+  TAccountBridge extends string | AccountMeta<string> = string,
+  TAccountPendingRelayIndex extends string | AccountMeta<string> = string,
+  TAccountCalldataPricing extends string | AccountMeta<string> = string,
+  TAccountOutgoingMessageAccount extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -72,6 +79,27 @@ export type PayForRelayInstruction<
       TAccountMessageToRelay extends string
         ? WritableAccount<TAccountMessageToRelay>
         : TAccountMessageToRelay,
+      TAccountFeeExemptions extends string
+        ? ReadonlyAccount<TAccountFeeExemptions>
+        : TAccountFeeExemptions,
+      TAccountSponsorPolicy extends string
+        ? WritableAccount<TAccountSponsorPolicy>
+        : TAccountSponsorPolicy,
+      TAccountNonceMonitor extends string
+        ? WritableAccount<TAccountNonceMonitor>
+        : TAccountNonceMonitor,
+      TAccountBridge extends string
+        ? ReadonlyAccount<TAccountBridge>
+        : TAccountBridge,
+      TAccountPendingRelayIndex extends string
+        ? WritableAccount<TAccountPendingRelayIndex>
+        : TAccountPendingRelayIndex,
+      TAccountCalldataPricing extends string
+        ? ReadonlyAccount<TAccountCalldataPricing>
+        : TAccountCalldataPricing,
+      TAccountOutgoingMessageAccount extends string
+        ? ReadonlyAccount<TAccountOutgoingMessageAccount>
+        : TAccountOutgoingMessageAccount,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -84,12 +112,14 @@ export type PayForRelayInstructionData = {
   mtrSalt: ReadonlyUint8Array;
   outgoingMessage: Address;
   gasLimit: bigint;
+  tipLamports: bigint;
 };
 
 export type PayForRelayInstructionDataArgs = {
   mtrSalt: ReadonlyUint8Array;
   outgoingMessage: Address;
   gasLimit: number | bigint;
+  tipLamports: number | bigint;
 };
 
 export function getPayForRelayInstructionDataEncoder(): FixedSizeEncoder<PayForRelayInstructionDataArgs> {
@@ -99,6 +129,7 @@ export function getPayForRelayInstructionDataEncoder(): FixedSizeEncoder<PayForR
       ['mtrSalt', fixEncoderSize(getBytesEncoder(), 32)],
       ['outgoingMessage', getAddressEncoder()],
       ['gasLimit', getU64Encoder()],
+      ['tipLamports', getU64Encoder()],
     ]),
     (value) => ({ ...value, discriminator: PAY_FOR_RELAY_DISCRIMINATOR })
   );
@@ -110,6 +141,7 @@ export function getPayForRelayInstructionDataDecoder(): FixedSizeDecoder<PayForR
     ['mtrSalt', fixDecoderSize(getBytesDecoder(), 32)],
     ['outgoingMessage', getAddressDecoder()],
     ['gasLimit', getU64Decoder()],
+    ['tipLamports', getU64Decoder()],
   ]);
 }
 
@@ -128,6 +160,13 @@ export type PayForRelayInput<
   TAccountCfg extends string = string,
   TAccountGasFeeReceiver extends string = string,
   TAccountMessageToRelay extends string = string,
+  TAccountFeeExemptions extends string = string,
+  TAccountSponsorPolicy extends string = string,
+  TAccountNonceMonitor extends string = string,
+  TAccountBridge extends string = string,
+  TAccountPendingRelayIndex extends string = string,
+  TAccountCalldataPricing extends string = string,
+  TAccountOutgoingMessageAccount extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   /**
@@ -144,6 +183,29 @@ export type PayForRelayInput<
   /** The account that receives payment for the gas costs of bridging SOL to Base. */
   gasFeeReceiver: Address<TAccountGasFeeReceiver>;
   messageToRelay: Address<TAccountMessageToRelay>;
+  /**
+   * The guardian-managed list of outgoing message senders exempt from the gas fee transfer,
+   * checked against the sender of `outgoing_message_account`.
+   */
+  feeExemptions: Address<TAccountFeeExemptions>;
+  /**
+   * The payer's sponsor policy. Always required so a payer cannot bypass its own policy;
+   * enforced only once the payer has created it via `set_sponsor_policy`.
+   */
+  sponsorPolicy: Address<TAccountSponsorPolicy>;
+  /** The guardian-configured nonce monitor, flagged as degraded on nonce anomalies if configured. */
+  nonceMonitor: Address<TAccountNonceMonitor>;
+  /** The bridge state account, whose outgoing message nonce is checked by the nonce monitor. */
+  bridge: Address<TAccountBridge>;
+  /** The guardian-created index of the pending relay requests by fee bucket. */
+  pendingRelayIndex: Address<TAccountPendingRelayIndex>;
+  /** The guardian-configured pricing of the calldata of large payloads. */
+  calldataPricing: Address<TAccountCalldataPricing>;
+  /**
+   * The bridge `OutgoingMessage` being relayed, required when the sponsor policy restricts
+   * the senders it sponsors, when the gas limit is derived or when calldata is priced.
+   */
+  outgoingMessageAccount?: Address<TAccountOutgoingMessageAccount>;
   /**
    * System program required for creating new accounts.
    * Used internally by Anchor for account initialization.
@@ -152,6 +214,7 @@ export type PayForRelayInput<
   mtrSalt: PayForRelayInstructionDataArgs['mtrSalt'];
   outgoingMessage: PayForRelayInstructionDataArgs['outgoingMessage'];
   gasLimit: PayForRelayInstructionDataArgs['gasLimit'];
+  tipLamports: PayForRelayInstructionDataArgs['tipLamports'];
 };
 
 export function getPayForRelayInstruction<
@@ -159,6 +222,13 @@ export function getPayForRelayInstruction<
   TAccountCfg extends string,
   TAccountGasFeeReceiver extends string,
   TAccountMessageToRelay extends string,
+  TAccountFeeExemptions extends string,
+  TAccountSponsorPolicy extends string,
+  TAccountNonceMonitor extends string,
+  TAccountBridge extends string,
+  TAccountPendingRelayIndex extends string,
+  TAccountCalldataPricing extends string,
+  TAccountOutgoingMessageAccount extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof BASE_RELAYER_PROGRAM_ADDRESS,
 >(
@@ -167,6 +237,13 @@ export function getPayForRelayInstruction<
     TAccountCfg,
     TAccountGasFeeReceiver,
     TAccountMessageToRelay,
+    TAccountFeeExemptions,
+    TAccountSponsorPolicy,
+    TAccountNonceMonitor,
+    TAccountBridge,
+    TAccountPendingRelayIndex,
+    TAccountCalldataPricing,
+    TAccountOutgoingMessageAccount,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -176,6 +253,13 @@ export function getPayForRelayInstruction<
   TAccountCfg,
   TAccountGasFeeReceiver,
   TAccountMessageToRelay,
+  TAccountFeeExemptions,
+  TAccountSponsorPolicy,
+  TAccountNonceMonitor,
+  TAccountBridge,
+  TAccountPendingRelayIndex,
+  TAccountCalldataPricing,
+  TAccountOutgoingMessageAccount,
   TAccountSystemProgram
 > {
   // Program address.
@@ -187,6 +271,22 @@ export function getPayForRelayInstruction<
     cfg: { value: input.cfg ?? null, isWritable: true },
     gasFeeReceiver: { value: input.gasFeeReceiver ?? null, isWritable: true },
     messageToRelay: { value: input.messageToRelay ?? null, isWritable: true },
+    feeExemptions: { value: input.feeExemptions ?? null, isWritable: false },
+    sponsorPolicy: { value: input.sponsorPolicy ?? null, isWritable: true },
+    nonceMonitor: { value: input.nonceMonitor ?? null, isWritable: true },
+    bridge: { value: input.bridge ?? null, isWritable: false },
+    pendingRelayIndex: {
+      value: input.pendingRelayIndex ?? null,
+      isWritable: true,
+    },
+    calldataPricing: {
+      value: input.calldataPricing ?? null,
+      isWritable: false,
+    },
+    outgoingMessageAccount: {
+      value: input.outgoingMessageAccount ?? null,
+      isWritable: false,
+    },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -210,6 +310,13 @@ export function getPayForRelayInstruction<
       getAccountMeta(accounts.cfg),
       getAccountMeta(accounts.gasFeeReceiver),
       getAccountMeta(accounts.messageToRelay),
+      getAccountMeta(accounts.feeExemptions),
+      getAccountMeta(accounts.sponsorPolicy),
+      getAccountMeta(accounts.nonceMonitor),
+      getAccountMeta(accounts.bridge),
+      getAccountMeta(accounts.pendingRelayIndex),
+      getAccountMeta(accounts.calldataPricing),
+      getAccountMeta(accounts.outgoingMessageAccount),
       getAccountMeta(accounts.systemProgram),
     ],
     data: getPayForRelayInstructionDataEncoder().encode(
@@ -222,6 +329,13 @@ export function getPayForRelayInstruction<
     TAccountCfg,
     TAccountGasFeeReceiver,
     TAccountMessageToRelay,
+    TAccountFeeExemptions,
+    TAccountSponsorPolicy,
+    TAccountNonceMonitor,
+    TAccountBridge,
+    TAccountPendingRelayIndex,
+    TAccountCalldataPricing,
+    TAccountOutgoingMessageAccount,
     TAccountSystemProgram
   >);
 }
@@ -246,11 +360,34 @@ export type ParsedPayForRelayInstruction<
     /** The account that receives payment for the gas costs of bridging SOL to Base. */
     gasFeeReceiver: TAccountMetas[2];
     messageToRelay: TAccountMetas[3];
+    /**
+     * The guardian-managed list of outgoing message senders exempt from the gas fee transfer,
+     * checked against the sender of `outgoing_message_account`.
+     */
+    feeExemptions: TAccountMetas[4];
+    /**
+     * The payer's sponsor policy. Always required so a payer cannot bypass its own policy;
+     * enforced only once the payer has created it via `set_sponsor_policy`.
+     */
+    sponsorPolicy: TAccountMetas[5];
+    /** The guardian-configured nonce monitor, flagged as degraded on nonce anomalies if configured. */
+    nonceMonitor: TAccountMetas[6];
+    /** The bridge state account, whose outgoing message nonce is checked by the nonce monitor. */
+    bridge: TAccountMetas[7];
+    /** The guardian-created index of the pending relay requests by fee bucket. */
+    pendingRelayIndex: TAccountMetas[8];
+    /** The guardian-configured pricing of the calldata of large payloads. */
+    calldataPricing: TAccountMetas[9];
+    /**
+     * The bridge `OutgoingMessage` being relayed, required when the sponsor policy restricts
+     * the senders it sponsors, when the gas limit is derived or when calldata is priced.
+     */
+    outgoingMessageAccount?: TAccountMetas[10] | undefined;
     /**
      * System program required for creating new accounts.
      * Used internally by Anchor for account initialization.
      */
-    systemProgram: TAccountMetas[4];
+    systemProgram: TAccountMetas[11];
   };
   data: PayForRelayInstructionData;
 };
@@ -263,7 +400,7 @@ export function parsePayForRelayInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedPayForRelayInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 12) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -273,6 +410,12 @@ export function parsePayForRelayInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === BASE_RELAYER_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -280,6 +423,13 @@ export function parsePayForRelayInstruction<
       cfg: getNextAccount(),
       gasFeeReceiver: getNextAccount(),
       messageToRelay: getNextAccount(),
+      feeExemptions: getNextAccount(),
+      sponsorPolicy: getNextAccount(),
+      nonceMonitor: getNextAccount(),
+      bridge: getNextAccount(),
+      pendingRelayIndex: getNextAccount(),
+      calldataPricing: getNextAccount(),
+      outgoingMessageAccount: getNextOptionalAccount(),
       systemProgram: getNextAccount(),
     },
     data: getPayForRelayInstructionDataDecoder().decode(instruction.data),
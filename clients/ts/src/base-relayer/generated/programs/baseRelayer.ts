@@ -14,24 +14,53 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedBumpRelayFeeInstruction,
+  type ParsedCheckEnvironmentInstruction,
+  type ParsedClearNonceAnomalyInstruction,
+  type ParsedCreatePendingRelayIndexInstruction,
+  type ParsedGetEnvironmentInstruction,
   type ParsedInitializeInstruction,
+  type ParsedMigrateCfgInstruction,
   type ParsedPayForRelayInstruction,
+  type ParsedRefundRelayTipInstruction,
+  type ParsedSetCalldataPricingInstruction,
   type ParsedSetEip1559ConfigInstruction,
+  type ParsedSetFeeConfigInstruction,
+  type ParsedSetFeeExemptionsInstruction,
   type ParsedSetGasConfigInstruction,
   type ParsedSetGuardianInstruction,
+  type ParsedSetNonceMonitorInstruction,
+  type ParsedSetSponsorPolicyInstruction,
+  type ParsedSettleRelayTipInstruction,
 } from '../instructions';
 
 export const BASE_RELAYER_PROGRAM_ADDRESS = '' as Address<''>;
 
 export enum BaseRelayerAccount {
+  CalldataPricing,
   Cfg,
+  FeeExemptions,
   MessageToRelay,
+  NonceMonitor,
+  PendingRelayIndex,
+  SponsorPolicy,
 }
 
 export function identifyBaseRelayerAccount(
   account: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): BaseRelayerAccount {
   const data = 'data' in account ? account.data : account;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([228, 73, 73, 102, 154, 252, 252, 172])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerAccount.CalldataPricing;
+  }
   if (
     containsBytes(
       data,
@@ -43,6 +72,17 @@ export function identifyBaseRelayerAccount(
   ) {
     return BaseRelayerAccount.Cfg;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([174, 249, 50, 53, 54, 148, 29, 243])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerAccount.FeeExemptions;
+  }
   if (
     containsBytes(
       data,
@@ -54,23 +94,124 @@ export function identifyBaseRelayerAccount(
   ) {
     return BaseRelayerAccount.MessageToRelay;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([141, 227, 111, 94, 95, 124, 106, 146])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerAccount.NonceMonitor;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([91, 131, 88, 27, 31, 137, 94, 80])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerAccount.PendingRelayIndex;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([243, 45, 180, 191, 4, 144, 101, 21])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerAccount.SponsorPolicy;
+  }
   throw new Error(
     'The provided account could not be identified as a baseRelayer account.'
   );
 }
 
 export enum BaseRelayerInstruction {
+  BumpRelayFee,
+  CheckEnvironment,
+  ClearNonceAnomaly,
+  CreatePendingRelayIndex,
+  GetEnvironment,
   Initialize,
+  MigrateCfg,
   PayForRelay,
+  RefundRelayTip,
+  SetCalldataPricing,
   SetEip1559Config,
+  SetFeeConfig,
+  SetFeeExemptions,
   SetGasConfig,
   SetGuardian,
+  SetNonceMonitor,
+  SetSponsorPolicy,
+  SettleRelayTip,
 }
 
 export function identifyBaseRelayerInstruction(
   instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): BaseRelayerInstruction {
   const data = 'data' in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([77, 3, 53, 225, 212, 156, 205, 22])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerInstruction.BumpRelayFee;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([66, 105, 5, 163, 77, 91, 38, 191])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerInstruction.CheckEnvironment;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([139, 58, 18, 223, 41, 46, 82, 41])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerInstruction.ClearNonceAnomaly;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([90, 128, 39, 47, 251, 55, 58, 225])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerInstruction.CreatePendingRelayIndex;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([234, 169, 115, 3, 52, 113, 69, 198])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerInstruction.GetEnvironment;
+  }
   if (
     containsBytes(
       data,
@@ -82,6 +223,17 @@ export function identifyBaseRelayerInstruction(
   ) {
     return BaseRelayerInstruction.Initialize;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([61, 19, 130, 183, 107, 189, 39, 205])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerInstruction.MigrateCfg;
+  }
   if (
     containsBytes(
       data,
@@ -93,6 +245,28 @@ export function identifyBaseRelayerInstruction(
   ) {
     return BaseRelayerInstruction.PayForRelay;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([16, 104, 59, 79, 79, 95, 52, 168])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerInstruction.RefundRelayTip;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([76, 43, 186, 68, 19, 155, 47, 92])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerInstruction.SetCalldataPricing;
+  }
   if (
     containsBytes(
       data,
@@ -104,6 +278,28 @@ export function identifyBaseRelayerInstruction(
   ) {
     return BaseRelayerInstruction.SetEip1559Config;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([221, 222, 52, 206, 114, 198, 64, 91])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerInstruction.SetFeeConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([88, 69, 194, 44, 166, 7, 114, 202])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerInstruction.SetFeeExemptions;
+  }
   if (
     containsBytes(
       data,
@@ -126,24 +322,96 @@ export function identifyBaseRelayerInstruction(
   ) {
     return BaseRelayerInstruction.SetGuardian;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([180, 232, 142, 50, 241, 94, 151, 2])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerInstruction.SetNonceMonitor;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([232, 228, 255, 211, 254, 244, 227, 58])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerInstruction.SetSponsorPolicy;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([50, 11, 125, 254, 214, 30, 146, 4])
+      ),
+      0
+    )
+  ) {
+    return BaseRelayerInstruction.SettleRelayTip;
+  }
   throw new Error(
     'The provided instruction could not be identified as a baseRelayer instruction.'
   );
 }
 
 export type ParsedBaseRelayerInstruction<TProgram extends string = ''> =
+  | ({
+      instructionType: BaseRelayerInstruction.BumpRelayFee;
+    } & ParsedBumpRelayFeeInstruction<TProgram>)
+  | ({
+      instructionType: BaseRelayerInstruction.CheckEnvironment;
+    } & ParsedCheckEnvironmentInstruction<TProgram>)
+  | ({
+      instructionType: BaseRelayerInstruction.ClearNonceAnomaly;
+    } & ParsedClearNonceAnomalyInstruction<TProgram>)
+  | ({
+      instructionType: BaseRelayerInstruction.CreatePendingRelayIndex;
+    } & ParsedCreatePendingRelayIndexInstruction<TProgram>)
+  | ({
+      instructionType: BaseRelayerInstruction.GetEnvironment;
+    } & ParsedGetEnvironmentInstruction<TProgram>)
   | ({
       instructionType: BaseRelayerInstruction.Initialize;
     } & ParsedInitializeInstruction<TProgram>)
+  | ({
+      instructionType: BaseRelayerInstruction.MigrateCfg;
+    } & ParsedMigrateCfgInstruction<TProgram>)
   | ({
       instructionType: BaseRelayerInstruction.PayForRelay;
     } & ParsedPayForRelayInstruction<TProgram>)
+  | ({
+      instructionType: BaseRelayerInstruction.RefundRelayTip;
+    } & ParsedRefundRelayTipInstruction<TProgram>)
+  | ({
+      instructionType: BaseRelayerInstruction.SetCalldataPricing;
+    } & ParsedSetCalldataPricingInstruction<TProgram>)
   | ({
       instructionType: BaseRelayerInstruction.SetEip1559Config;
     } & ParsedSetEip1559ConfigInstruction<TProgram>)
+  | ({
+      instructionType: BaseRelayerInstruction.SetFeeConfig;
+    } & ParsedSetFeeConfigInstruction<TProgram>)
+  | ({
+      instructionType: BaseRelayerInstruction.SetFeeExemptions;
+    } & ParsedSetFeeExemptionsInstruction<TProgram>)
   | ({
       instructionType: BaseRelayerInstruction.SetGasConfig;
     } & ParsedSetGasConfigInstruction<TProgram>)
   | ({
       instructionType: BaseRelayerInstruction.SetGuardian;
-    } & ParsedSetGuardianInstruction<TProgram>);
+    } & ParsedSetGuardianInstruction<TProgram>)
+  | ({
+      instructionType: BaseRelayerInstruction.SetNonceMonitor;
+    } & ParsedSetNonceMonitorInstruction<TProgram>)
+  | ({
+      instructionType: BaseRelayerInstruction.SetSponsorPolicy;
+    } & ParsedSetSponsorPolicyInstruction<TProgram>)
+  | ({
+      instructionType: BaseRelayerInstruction.SettleRelayTip;
+    } & ParsedSettleRelayTipInstruction<TProgram>);
@@ -26,8 +26,12 @@ import {
 } from '.';
 
 export type Eip1559 = {
+  /** Configuration parameters for EIP-1559-inspired fee calculations */
   config: Eip1559Config;
-  /** Current base fee in gwei (runtime state) */
+  /**
+   * Current base fee used in fee computation (runtime state).
+   * Unitless value combined with the gas usage and gas cost scaler to produce lamports.
+   */
   currentBaseFee: bigint;
   /** Gas used in the current time window (runtime state) */
   currentWindowGasUsed: bigint;
@@ -36,8 +40,12 @@ export type Eip1559 = {
 };
 
 export type Eip1559Args = {
+  /** Configuration parameters for EIP-1559-inspired fee calculations */
   config: Eip1559ConfigArgs;
-  /** Current base fee in gwei (runtime state) */
+  /**
+   * Current base fee used in fee computation (runtime state).
+   * Unitless value combined with the gas usage and gas cost scaler to produce lamports.
+   */
   currentBaseFee: number | bigint;
   /** Gas used in the current time window (runtime state) */
   currentWindowGasUsed: number | bigint;
@@ -18,25 +18,47 @@ import {
 } from '@solana/kit';
 
 export type Eip1559Config = {
-  /** Gas target per window (configurable) */
+  /** Gas target per window */
   target: bigint;
-  /** Adjustment denominator (controls rate of change) (configurable) */
+  /** Adjustment denominator (controls rate of change) */
   denominator: bigint;
-  /** Window duration in seconds (configurable) */
+  /** Window duration in seconds */
   windowDurationSeconds: bigint;
-  /** Minimum base fee floor (configurable) */
+  /**
+   * Minimum base fee. Used to seed `current_base_fee` at initialization
+   * and as an underflow clamp during decreases; not enforced as a strict lower bound
+   * on every step.
+   */
   minimumBaseFee: bigint;
+  /** Maximum base fee. The base fee never grows above this value (0 disables the cap). */
+  maxBaseFee: bigint;
+  /**
+   * Maximum base fee increase per window, in basis points of the current base fee
+   * (0 disables the cap).
+   */
+  maxWindowIncreaseBps: bigint;
 };
 
 export type Eip1559ConfigArgs = {
-  /** Gas target per window (configurable) */
+  /** Gas target per window */
   target: number | bigint;
-  /** Adjustment denominator (controls rate of change) (configurable) */
+  /** Adjustment denominator (controls rate of change) */
   denominator: number | bigint;
-  /** Window duration in seconds (configurable) */
+  /** Window duration in seconds */
   windowDurationSeconds: number | bigint;
-  /** Minimum base fee floor (configurable) */
+  /**
+   * Minimum base fee. Used to seed `current_base_fee` at initialization
+   * and as an underflow clamp during decreases; not enforced as a strict lower bound
+   * on every step.
+   */
   minimumBaseFee: number | bigint;
+  /** Maximum base fee. The base fee never grows above this value (0 disables the cap). */
+  maxBaseFee: number | bigint;
+  /**
+   * Maximum base fee increase per window, in basis points of the current base fee
+   * (0 disables the cap).
+   */
+  maxWindowIncreaseBps: number | bigint;
 };
 
 export function getEip1559ConfigEncoder(): FixedSizeEncoder<Eip1559ConfigArgs> {
@@ -45,6 +67,8 @@ export function getEip1559ConfigEncoder(): FixedSizeEncoder<Eip1559ConfigArgs> {
     ['denominator', getU64Encoder()],
     ['windowDurationSeconds', getU64Encoder()],
     ['minimumBaseFee', getU64Encoder()],
+    ['maxBaseFee', getU64Encoder()],
+    ['maxWindowIncreaseBps', getU64Encoder()],
   ]);
 }
 
@@ -54,6 +78,8 @@ export function getEip1559ConfigDecoder(): FixedSizeDecoder<Eip1559Config> {
     ['denominator', getU64Decoder()],
     ['windowDurationSeconds', getU64Decoder()],
     ['minimumBaseFee', getU64Decoder()],
+    ['maxBaseFee', getU64Decoder()],
+    ['maxWindowIncreaseBps', getU64Decoder()],
   ]);
 }
 
@@ -31,6 +31,13 @@ export type GasConfig = {
   gasCostScalerDp: bigint;
   /** Account that receives gas fees */
   gasFeeReceiver: Address;
+  /**
+   * Gas limit derived for a transfer without call: the per-message overhead of `relayMessages`
+   * plus minting or unlocking the tokens on Base
+   */
+  defaultTransferGasLimit: bigint;
+  /** Additional gas limit derived for each recipient of a transfer batch beyond the first */
+  defaultTransferBatchRecipientGasLimit: bigint;
 };
 
 export type GasConfigArgs = {
@@ -44,6 +51,13 @@ export type GasConfigArgs = {
   gasCostScalerDp: number | bigint;
   /** Account that receives gas fees */
   gasFeeReceiver: Address;
+  /**
+   * Gas limit derived for a transfer without call: the per-message overhead of `relayMessages`
+   * plus minting or unlocking the tokens on Base
+   */
+  defaultTransferGasLimit: number | bigint;
+  /** Additional gas limit derived for each recipient of a transfer batch beyond the first */
+  defaultTransferBatchRecipientGasLimit: number | bigint;
 };
 
 export function getGasConfigEncoder(): FixedSizeEncoder<GasConfigArgs> {
@@ -53,6 +67,8 @@ export function getGasConfigEncoder(): FixedSizeEncoder<GasConfigArgs> {
     ['gasCostScaler', getU64Encoder()],
     ['gasCostScalerDp', getU64Encoder()],
     ['gasFeeReceiver', getAddressEncoder()],
+    ['defaultTransferGasLimit', getU64Encoder()],
+    ['defaultTransferBatchRecipientGasLimit', getU64Encoder()],
   ]);
 }
 
@@ -63,6 +79,8 @@ export function getGasConfigDecoder(): FixedSizeDecoder<GasConfig> {
     ['gasCostScaler', getU64Decoder()],
     ['gasCostScalerDp', getU64Decoder()],
     ['gasFeeReceiver', getAddressDecoder()],
+    ['defaultTransferGasLimit', getU64Decoder()],
+    ['defaultTransferBatchRecipientGasLimit', getU64Decoder()],
   ]);
 }
 
@@ -8,4 +8,8 @@
 
 export * from './eip1559';
 export * from './eip1559Config';
+export * from './environment';
 export * from './gasConfig';
+export * from './nonceAnomaly';
+export * from './pendingRelay';
+export * from './relayFeeBumped';
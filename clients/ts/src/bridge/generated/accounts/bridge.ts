@@ -25,6 +25,8 @@ import {
   getStructEncoder,
   getU64Decoder,
   getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type Account,
   type Address,
@@ -39,30 +41,62 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  getBaseGovernanceDecoder,
+  getBaseGovernanceEncoder,
   getBaseOracleConfigDecoder,
   getBaseOracleConfigEncoder,
   getBufferConfigDecoder,
   getBufferConfigEncoder,
   getEip1559Decoder,
   getEip1559Encoder,
+  getFeeQuoteGraceDecoder,
+  getFeeQuoteGraceEncoder,
+  getFinalizationDelayDecoder,
+  getFinalizationDelayEncoder,
   getGasConfigDecoder,
   getGasConfigEncoder,
+  getOracleFailoverDecoder,
+  getOracleFailoverEncoder,
+  getOracleHealthDecoder,
+  getOracleHealthEncoder,
+  getOutboxDecoder,
+  getOutboxEncoder,
+  getOutflowBreakerDecoder,
+  getOutflowBreakerEncoder,
   getPartnerOracleConfigDecoder,
   getPartnerOracleConfigEncoder,
   getProtocolConfigDecoder,
   getProtocolConfigEncoder,
+  getRootFreshnessConfigDecoder,
+  getRootFreshnessConfigEncoder,
+  type BaseGovernance,
+  type BaseGovernanceArgs,
   type BaseOracleConfig,
   type BaseOracleConfigArgs,
   type BufferConfig,
   type BufferConfigArgs,
   type Eip1559,
   type Eip1559Args,
+  type FeeQuoteGrace,
+  type FeeQuoteGraceArgs,
+  type FinalizationDelay,
+  type FinalizationDelayArgs,
   type GasConfig,
   type GasConfigArgs,
+  type OracleFailover,
+  type OracleFailoverArgs,
+  type OracleHealth,
+  type OracleHealthArgs,
+  type Outbox,
+  type OutboxArgs,
+  type OutflowBreaker,
+  type OutflowBreakerArgs,
   type PartnerOracleConfig,
   type PartnerOracleConfigArgs,
   type ProtocolConfig,
   type ProtocolConfigArgs,
+  type RootFreshnessConfig,
+  type RootFreshnessConfigArgs,
 } from '../types';
 
 export const BRIDGE_DISCRIMINATOR = new Uint8Array([
@@ -75,6 +109,11 @@ export function getBridgeDiscriminatorBytes() {
 
 export type Bridge = {
   discriminator: ReadonlyUint8Array;
+  /**
+   * Layout version of this account (see `BRIDGE_LAYOUT_VERSION`).
+   * Must remain the first field so older layouts can be detected before deserialization.
+   */
+  version: number;
   /** The Base block number associated with the latest registered output root. */
   baseBlockNumber: bigint;
   /** Incremental nonce assigned to each outgoing message. */
@@ -95,9 +134,46 @@ export type Bridge = {
   partnerOracleConfig: PartnerOracleConfig;
   /** Configuration parameters for Base oracle signers */
   baseOracleConfig: BaseOracleConfig;
+  /** Emergency oracle quorum used when output roots stop being registered */
+  oracleFailover: OracleFailover;
+  /** Maximum output root age accepted by `prove_message` for each message kind */
+  rootFreshnessConfig: RootFreshnessConfig;
+  /** Latest output root signed by each Base oracle signer */
+  oracleHealth: OracleHealth;
+  /** Circuit breaker pausing the bridge on anomalous outflows */
+  outflowBreaker: OutflowBreaker;
+  /** Delay before high-value incoming transfers can be relayed */
+  finalizationDelay: FinalizationDelay;
+  /** Governance contract on Base controlling the critical configuration */
+  baseGovernance: BaseGovernance;
+  /** Merkle mountain range committing to every outgoing message, proven against on Base */
+  outbox: Outbox;
+  /**
+   * Whether `bridge_spl` only accepts the mints of the guardian-maintained allow-list, while
+   * the bridge is gradually opened after launch
+   */
+  guardedLaunch: boolean;
+  /**
+   * EIP-1559 state and configuration pricing token transfers apart from calls, so that demand
+   * in one lane does not distort pricing in the other. Transfers are priced by `eip1559` while
+   * its target is zero.
+   */
+  transferGasLane: Eip1559;
+  /**
+   * Whether `bridge_wrapped_token` only accepts wrapped tokens whose registration was confirmed
+   * by the Base Bridge, so that tokens are not burned for a token Base does not know yet
+   */
+  requireWrappedTokenConfirmation: boolean;
+  /** Grace period keeping gas fee quotes valid across EIP-1559 window rollovers */
+  feeQuoteGrace: FeeQuoteGrace;
 };
 
 export type BridgeArgs = {
+  /**
+   * Layout version of this account (see `BRIDGE_LAYOUT_VERSION`).
+   * Must remain the first field so older layouts can be detected before deserialization.
+   */
+  version: number;
   /** The Base block number associated with the latest registered output root. */
   baseBlockNumber: number | bigint;
   /** Incremental nonce assigned to each outgoing message. */
@@ -118,12 +194,45 @@ export type BridgeArgs = {
   partnerOracleConfig: PartnerOracleConfigArgs;
   /** Configuration parameters for Base oracle signers */
   baseOracleConfig: BaseOracleConfigArgs;
+  /** Emergency oracle quorum used when output roots stop being registered */
+  oracleFailover: OracleFailoverArgs;
+  /** Maximum output root age accepted by `prove_message` for each message kind */
+  rootFreshnessConfig: RootFreshnessConfigArgs;
+  /** Latest output root signed by each Base oracle signer */
+  oracleHealth: OracleHealthArgs;
+  /** Circuit breaker pausing the bridge on anomalous outflows */
+  outflowBreaker: OutflowBreakerArgs;
+  /** Delay before high-value incoming transfers can be relayed */
+  finalizationDelay: FinalizationDelayArgs;
+  /** Governance contract on Base controlling the critical configuration */
+  baseGovernance: BaseGovernanceArgs;
+  /** Merkle mountain range committing to every outgoing message, proven against on Base */
+  outbox: OutboxArgs;
+  /**
+   * Whether `bridge_spl` only accepts the mints of the guardian-maintained allow-list, while
+   * the bridge is gradually opened after launch
+   */
+  guardedLaunch: boolean;
+  /**
+   * EIP-1559 state and configuration pricing token transfers apart from calls, so that demand
+   * in one lane does not distort pricing in the other. Transfers are priced by `eip1559` while
+   * its target is zero.
+   */
+  transferGasLane: Eip1559Args;
+  /**
+   * Whether `bridge_wrapped_token` only accepts wrapped tokens whose registration was confirmed
+   * by the Base Bridge, so that tokens are not burned for a token Base does not know yet
+   */
+  requireWrappedTokenConfirmation: boolean;
+  /** Grace period keeping gas fee quotes valid across EIP-1559 window rollovers */
+  feeQuoteGrace: FeeQuoteGraceArgs;
 };
 
 export function getBridgeEncoder(): FixedSizeEncoder<BridgeArgs> {
   return transformEncoder(
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
+      ['version', getU8Encoder()],
       ['baseBlockNumber', getU64Encoder()],
       ['nonce', getU64Encoder()],
       ['guardian', getAddressEncoder()],
@@ -134,6 +243,17 @@ export function getBridgeEncoder(): FixedSizeEncoder<BridgeArgs> {
       ['bufferConfig', getBufferConfigEncoder()],
       ['partnerOracleConfig', getPartnerOracleConfigEncoder()],
       ['baseOracleConfig', getBaseOracleConfigEncoder()],
+      ['oracleFailover', getOracleFailoverEncoder()],
+      ['rootFreshnessConfig', getRootFreshnessConfigEncoder()],
+      ['oracleHealth', getOracleHealthEncoder()],
+      ['outflowBreaker', getOutflowBreakerEncoder()],
+      ['finalizationDelay', getFinalizationDelayEncoder()],
+      ['baseGovernance', getBaseGovernanceEncoder()],
+      ['outbox', getOutboxEncoder()],
+      ['guardedLaunch', getBooleanEncoder()],
+      ['transferGasLane', getEip1559Encoder()],
+      ['requireWrappedTokenConfirmation', getBooleanEncoder()],
+      ['feeQuoteGrace', getFeeQuoteGraceEncoder()],
     ]),
     (value) => ({ ...value, discriminator: BRIDGE_DISCRIMINATOR })
   );
@@ -142,6 +262,7 @@ export function getBridgeEncoder(): FixedSizeEncoder<BridgeArgs> {
 export function getBridgeDecoder(): FixedSizeDecoder<Bridge> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
+    ['version', getU8Decoder()],
     ['baseBlockNumber', getU64Decoder()],
     ['nonce', getU64Decoder()],
     ['guardian', getAddressDecoder()],
@@ -152,6 +273,17 @@ export function getBridgeDecoder(): FixedSizeDecoder<Bridge> {
     ['bufferConfig', getBufferConfigDecoder()],
     ['partnerOracleConfig', getPartnerOracleConfigDecoder()],
     ['baseOracleConfig', getBaseOracleConfigDecoder()],
+    ['oracleFailover', getOracleFailoverDecoder()],
+    ['rootFreshnessConfig', getRootFreshnessConfigDecoder()],
+    ['oracleHealth', getOracleHealthDecoder()],
+    ['outflowBreaker', getOutflowBreakerDecoder()],
+    ['finalizationDelay', getFinalizationDelayDecoder()],
+    ['baseGovernance', getBaseGovernanceDecoder()],
+    ['outbox', getOutboxDecoder()],
+    ['guardedLaunch', getBooleanDecoder()],
+    ['transferGasLane', getEip1559Decoder()],
+    ['requireWrappedTokenConfirmation', getBooleanDecoder()],
+    ['feeQuoteGrace', getFeeQuoteGraceDecoder()],
   ]);
 }
 
@@ -213,5 +345,5 @@ export async function fetchAllMaybeBridge(
 }
 
 export function getBridgeSize(): number {
-  return 528;
+  return 3162;
 }
@@ -19,14 +19,20 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
   getU128Decoder,
   getU128Encoder,
   getU32Decoder,
   getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
   transformEncoder,
   type Account,
   type Address,
@@ -71,10 +77,23 @@ export type CallBuffer = {
   to: ReadonlyUint8Array;
   /** The amount of Base native currency (ETH) to send with this call, in wei. */
   value: bigint;
+  /**
+   * Unix timestamp of the last initialization, append or reuse.
+   * Used to expire abandoned buffers after `buffer_config.call_buffer_ttl_seconds`.
+   */
+  lastModified: bigint;
+  /**
+   * Whether buffered bridge instructions keep the buffer instead of closing it, so that the
+   * same call can be bridged repeatedly. Reusable buffers cannot be appended to.
+   */
+  reusable: boolean;
+  /** Number of times the buffer was read by a buffered bridge instruction. */
+  useCount: bigint;
   /**
    * The encoded function call data or contract bytecode.
    * For regular calls: ABI-encoded function signature and parameters.
    * For contract creation: the contract's initialization bytecode.
+   * `INIT_SPACE` only accounts for its length prefix, see `CallBuffer::space`.
    */
   data: ReadonlyUint8Array;
 };
@@ -94,10 +113,23 @@ export type CallBufferArgs = {
   to: ReadonlyUint8Array;
   /** The amount of Base native currency (ETH) to send with this call, in wei. */
   value: number | bigint;
+  /**
+   * Unix timestamp of the last initialization, append or reuse.
+   * Used to expire abandoned buffers after `buffer_config.call_buffer_ttl_seconds`.
+   */
+  lastModified: number | bigint;
+  /**
+   * Whether buffered bridge instructions keep the buffer instead of closing it, so that the
+   * same call can be bridged repeatedly. Reusable buffers cannot be appended to.
+   */
+  reusable: boolean;
+  /** Number of times the buffer was read by a buffered bridge instruction. */
+  useCount: number | bigint;
   /**
    * The encoded function call data or contract bytecode.
    * For regular calls: ABI-encoded function signature and parameters.
    * For contract creation: the contract's initialization bytecode.
+   * `INIT_SPACE` only accounts for its length prefix, see `CallBuffer::space`.
    */
   data: ReadonlyUint8Array;
 };
@@ -110,6 +142,9 @@ export function getCallBufferEncoder(): Encoder<CallBufferArgs> {
       ['ty', getCallTypeEncoder()],
       ['to', fixEncoderSize(getBytesEncoder(), 20)],
       ['value', getU128Encoder()],
+      ['lastModified', getI64Encoder()],
+      ['reusable', getBooleanEncoder()],
+      ['useCount', getU64Encoder()],
       ['data', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
     ]),
     (value) => ({ ...value, discriminator: CALL_BUFFER_DISCRIMINATOR })
@@ -123,6 +158,9 @@ export function getCallBufferDecoder(): Decoder<CallBuffer> {
     ['ty', getCallTypeDecoder()],
     ['to', fixDecoderSize(getBytesDecoder(), 20)],
     ['value', getU128Decoder()],
+    ['lastModified', getI64Decoder()],
+    ['reusable', getBooleanDecoder()],
+    ['useCount', getU64Decoder()],
     ['data', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
   ]);
 }
@@ -15,12 +15,22 @@ import {
   fetchEncodedAccounts,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressDecoder,
+  getAddressEncoder,
   getBooleanDecoder,
   getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
   transformEncoder,
   type Account,
   type Address,
@@ -59,6 +69,11 @@ export type IncomingMessage = {
    * This field does not restrict who can call the relay instruction.
    */
   sender: ReadonlyUint8Array;
+  /**
+   * Chain ID of the chain the message was sent from, recorded from the bridge configuration
+   * when the message is proven. The message can only be relayed while it is still configured.
+   */
+  remoteChainId: bigint;
   /**
    * The actual message payload containing either instruction calls or token transfer data.
    * This enum determines what type of operation will be executed on Solana.
@@ -69,6 +84,50 @@ export type IncomingMessage = {
    * Once set to true, the message cannot be executed again, preventing replay attacks.
    */
   executed: boolean;
+  /**
+   * Number of `ProvenChunk` accounts holding the instructions of a message proven with
+   * `prove_message_chunked`, executed after the instructions stored in `message`.
+   * Zero for messages proven in a single account.
+   */
+  chunkCount: number;
+  /** The `OutputRoot` account the message was proven against. */
+  outputRoot: Address;
+  /**
+   * Revision of `output_root` the message was proven against. The message can only be relayed
+   * while it is still the current revision.
+   */
+  outputRootRevision: number;
+  /**
+   * The account that paid the rent of this account when proving the message. The rent freed
+   * when the message is relayed is refunded to it.
+   */
+  prover: Address;
+  /**
+   * Nonce of the message on Base, i.e. its leaf index in the Base MMR. Reported in the
+   * `MessageRelayed` event so that indexers can match relays against proofs.
+   */
+  nonce: bigint;
+  /**
+   * Unix timestamp at which the message was proven, from which the finalization delay of
+   * high-value transfers runs.
+   */
+  provenAt: bigint;
+  /**
+   * Flag set by the guardian vetoing the message during its finalization delay. A vetoed
+   * message can never be relayed.
+   */
+  vetoed: boolean;
+  /**
+   * Flag set when relaying the transfer of the message tripped the outflow circuit breaker.
+   * A held message never trips the breaker again: it can only be relayed once the guardian
+   * approved its outflow.
+   */
+  outflowHeld: boolean;
+  /**
+   * Flag set by the guardian approving the outflow of a held message, which is then released
+   * regardless of the outflow circuit breaker.
+   */
+  outflowApproved: boolean;
 };
 
 export type IncomingMessageArgs = {
@@ -78,6 +137,11 @@ export type IncomingMessageArgs = {
    * This field does not restrict who can call the relay instruction.
    */
   sender: ReadonlyUint8Array;
+  /**
+   * Chain ID of the chain the message was sent from, recorded from the bridge configuration
+   * when the message is proven. The message can only be relayed while it is still configured.
+   */
+  remoteChainId: number | bigint;
   /**
    * The actual message payload containing either instruction calls or token transfer data.
    * This enum determines what type of operation will be executed on Solana.
@@ -88,6 +152,50 @@ export type IncomingMessageArgs = {
    * Once set to true, the message cannot be executed again, preventing replay attacks.
    */
   executed: boolean;
+  /**
+   * Number of `ProvenChunk` accounts holding the instructions of a message proven with
+   * `prove_message_chunked`, executed after the instructions stored in `message`.
+   * Zero for messages proven in a single account.
+   */
+  chunkCount: number;
+  /** The `OutputRoot` account the message was proven against. */
+  outputRoot: Address;
+  /**
+   * Revision of `output_root` the message was proven against. The message can only be relayed
+   * while it is still the current revision.
+   */
+  outputRootRevision: number;
+  /**
+   * The account that paid the rent of this account when proving the message. The rent freed
+   * when the message is relayed is refunded to it.
+   */
+  prover: Address;
+  /**
+   * Nonce of the message on Base, i.e. its leaf index in the Base MMR. Reported in the
+   * `MessageRelayed` event so that indexers can match relays against proofs.
+   */
+  nonce: number | bigint;
+  /**
+   * Unix timestamp at which the message was proven, from which the finalization delay of
+   * high-value transfers runs.
+   */
+  provenAt: number | bigint;
+  /**
+   * Flag set by the guardian vetoing the message during its finalization delay. A vetoed
+   * message can never be relayed.
+   */
+  vetoed: boolean;
+  /**
+   * Flag set when relaying the transfer of the message tripped the outflow circuit breaker.
+   * A held message never trips the breaker again: it can only be relayed once the guardian
+   * approved its outflow.
+   */
+  outflowHeld: boolean;
+  /**
+   * Flag set by the guardian approving the outflow of a held message, which is then released
+   * regardless of the outflow circuit breaker.
+   */
+  outflowApproved: boolean;
 };
 
 export function getIncomingMessageEncoder(): Encoder<IncomingMessageArgs> {
@@ -95,8 +203,18 @@ export function getIncomingMessageEncoder(): Encoder<IncomingMessageArgs> {
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['sender', fixEncoderSize(getBytesEncoder(), 20)],
+      ['remoteChainId', getU64Encoder()],
       ['message', getBridgeBaseToSolanaStateIncomingMessageMessageEncoder()],
       ['executed', getBooleanEncoder()],
+      ['chunkCount', getU8Encoder()],
+      ['outputRoot', getAddressEncoder()],
+      ['outputRootRevision', getU32Encoder()],
+      ['prover', getAddressEncoder()],
+      ['nonce', getU64Encoder()],
+      ['provenAt', getI64Encoder()],
+      ['vetoed', getBooleanEncoder()],
+      ['outflowHeld', getBooleanEncoder()],
+      ['outflowApproved', getBooleanEncoder()],
     ]),
     (value) => ({ ...value, discriminator: INCOMING_MESSAGE_DISCRIMINATOR })
   );
@@ -106,8 +224,18 @@ export function getIncomingMessageDecoder(): Decoder<IncomingMessage> {
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['sender', fixDecoderSize(getBytesDecoder(), 20)],
+    ['remoteChainId', getU64Decoder()],
     ['message', getBridgeBaseToSolanaStateIncomingMessageMessageDecoder()],
     ['executed', getBooleanDecoder()],
+    ['chunkCount', getU8Decoder()],
+    ['outputRoot', getAddressDecoder()],
+    ['outputRootRevision', getU32Decoder()],
+    ['prover', getAddressDecoder()],
+    ['nonce', getU64Decoder()],
+    ['provenAt', getI64Decoder()],
+    ['vetoed', getBooleanDecoder()],
+    ['outflowHeld', getBooleanDecoder()],
+    ['outflowApproved', getBooleanDecoder()],
   ]);
 }
 
@@ -6,9 +6,34 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './addressBook';
 export * from './bridge';
+export * from './bridgeSession';
+export * from './burnReceipt';
 export * from './callBuffer';
+export * from './configHistory';
+export * from './dexRouterList';
+export * from './emergencyExit';
+export * from './equivocationEvidence';
+export * from './handlerRegistration';
 export * from './incomingMessage';
+export * from './insuranceFund';
+export * from './liabilityRoot';
+export * from './maintenanceBudget';
+export * from './mintAllowList';
+export * from './mintPolicy';
+export * from './operationalVault';
 export * from './outgoingMessage';
 export * from './outputRoot';
+export * from './payerRateLimitConfig';
+export * from './protocolFeeConfig';
 export * from './proveBuffer';
+export * from './relayDenyList';
+export * from './relayExecution';
+export * from './relayFeeVault';
+export * from './relayedNonces';
+export * from './returnedTransfer';
+export * from './solVaultState';
+export * from './tokenDeposits';
+export * from './vaultDecimals';
+export * from './wrappedTokenSupplyCap';
@@ -19,6 +19,8 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
   getU64Decoder,
@@ -66,9 +68,14 @@ export type OutgoingMessage = {
    * via signer constraints.
    */
   sender: Address;
+  /** Unix timestamp at which the message was sent. */
+  createdAt: bigint;
+  /** Chain ID of the chain the message is sent to, taken from the bridge configuration. */
+  remoteChainId: bigint;
   /**
    * The actual message payload that will be executed on Base.
-   * Can be either a direct contract call or a token transfer (with optional call).
+   * Can be a direct contract call, a token transfer (with optional call), an encrypted call or a
+   * transfer batch.
    */
   message: BridgeSolanaToBaseStateOutgoingMessageMessage;
 };
@@ -85,9 +92,14 @@ export type OutgoingMessageArgs = {
    * via signer constraints.
    */
   sender: Address;
+  /** Unix timestamp at which the message was sent. */
+  createdAt: number | bigint;
+  /** Chain ID of the chain the message is sent to, taken from the bridge configuration. */
+  remoteChainId: number | bigint;
   /**
    * The actual message payload that will be executed on Base.
-   * Can be either a direct contract call or a token transfer (with optional call).
+   * Can be a direct contract call, a token transfer (with optional call), an encrypted call or a
+   * transfer batch.
    */
   message: BridgeSolanaToBaseStateOutgoingMessageMessageArgs;
 };
@@ -98,6 +110,8 @@ export function getOutgoingMessageEncoder(): Encoder<OutgoingMessageArgs> {
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['nonce', getU64Encoder()],
       ['sender', getAddressEncoder()],
+      ['createdAt', getI64Encoder()],
+      ['remoteChainId', getU64Encoder()],
       ['message', getBridgeSolanaToBaseStateOutgoingMessageMessageEncoder()],
     ]),
     (value) => ({ ...value, discriminator: OUTGOING_MESSAGE_DISCRIMINATOR })
@@ -109,6 +123,8 @@ export function getOutgoingMessageDecoder(): Decoder<OutgoingMessage> {
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['nonce', getU64Decoder()],
     ['sender', getAddressDecoder()],
+    ['createdAt', getI64Decoder()],
+    ['remoteChainId', getU64Decoder()],
     ['message', getBridgeSolanaToBaseStateOutgoingMessageMessageDecoder()],
   ]);
 }
@@ -17,8 +17,12 @@ import {
   fixEncoderSize,
   getBytesDecoder,
   getBytesEncoder,
+  getI64Decoder,
+  getI64Encoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   transformEncoder,
@@ -56,6 +60,18 @@ export type OutputRoot = {
    * mountain configuration at the time of proof validation.
    */
   totalLeafCount: bigint;
+  /**
+   * The Base block number the root was registered for, used to enforce the root freshness
+   * policy of `prove_message`.
+   */
+  baseBlockNumber: bigint;
+  /** Unix timestamp at which the root was registered, opening its challenge window. */
+  registeredAt: bigint;
+  /**
+   * Number of times the root was superseded by `supersede_output_root`. Messages record the
+   * revision they were proven against and can only be relayed while it is current.
+   */
+  revision: number;
 };
 
 export type OutputRootArgs = {
@@ -70,6 +86,18 @@ export type OutputRootArgs = {
    * mountain configuration at the time of proof validation.
    */
   totalLeafCount: number | bigint;
+  /**
+   * The Base block number the root was registered for, used to enforce the root freshness
+   * policy of `prove_message`.
+   */
+  baseBlockNumber: number | bigint;
+  /** Unix timestamp at which the root was registered, opening its challenge window. */
+  registeredAt: number | bigint;
+  /**
+   * Number of times the root was superseded by `supersede_output_root`. Messages record the
+   * revision they were proven against and can only be relayed while it is current.
+   */
+  revision: number;
 };
 
 export function getOutputRootEncoder(): FixedSizeEncoder<OutputRootArgs> {
@@ -78,6 +106,9 @@ export function getOutputRootEncoder(): FixedSizeEncoder<OutputRootArgs> {
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['root', fixEncoderSize(getBytesEncoder(), 32)],
       ['totalLeafCount', getU64Encoder()],
+      ['baseBlockNumber', getU64Encoder()],
+      ['registeredAt', getI64Encoder()],
+      ['revision', getU32Encoder()],
     ]),
     (value) => ({ ...value, discriminator: OUTPUT_ROOT_DISCRIMINATOR })
   );
@@ -88,6 +119,9 @@ export function getOutputRootDecoder(): FixedSizeDecoder<OutputRoot> {
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['root', fixDecoderSize(getBytesDecoder(), 32)],
     ['totalLeafCount', getU64Decoder()],
+    ['baseBlockNumber', getU64Decoder()],
+    ['registeredAt', getI64Decoder()],
+    ['revision', getU32Decoder()],
   ]);
 }
 
@@ -152,5 +186,5 @@ export async function fetchAllMaybeOutputRoot(
 }
 
 export function getOutputRootSize(): number {
-  return 48;
+  return 68;
 }
@@ -20,16 +20,52 @@ export const BRIDGE_ERROR__BRIDGE_PAUSED = 0x2ee0; // 12000
 export const BRIDGE_ERROR__INCORRECT_BRIDGE_PROGRAM = 0x2ee1; // 12001
 /** IncorrectGasFeeReceiver: Incorrect gas fee receiver */
 export const BRIDGE_ERROR__INCORRECT_GAS_FEE_RECEIVER = 0x2ee2; // 12002
+/** InvalidGasFeeReceiver: Gas fee receiver must be a system account */
+export const BRIDGE_ERROR__INVALID_GAS_FEE_RECEIVER = 0x2ee3; // 12003
+/** IncorrectGasFeeEscrow: Incorrect gas fee escrow */
+export const BRIDGE_ERROR__INCORRECT_GAS_FEE_ESCROW = 0x2ee4; // 12004
+/** MissingMemoProgram: SPL Memo program is required to log an SPL memo */
+export const BRIDGE_ERROR__MISSING_MEMO_PROGRAM = 0x2ee5; // 12005
+/** EnvironmentMismatch: Program was not built for the expected environment */
+export const BRIDGE_ERROR__ENVIRONMENT_MISMATCH = 0x2ee6; // 12006
+/** IncorrectPayerRateLimit: Incorrect payer rate limit */
+export const BRIDGE_ERROR__INCORRECT_PAYER_RATE_LIMIT = 0x2ee7; // 12007
+/** PayerRateLimited: Payer exceeded its outgoing message rate limit */
+export const BRIDGE_ERROR__PAYER_RATE_LIMITED = 0x2ee8; // 12008
 /** UnauthorizedInitialization: Only the upgrade authority can initialize the bridge */
 export const BRIDGE_ERROR__UNAUTHORIZED_INITIALIZATION = 0x2f44; // 12100
 /** UnauthorizedConfigUpdate: Unauthorized to update configuration */
 export const BRIDGE_ERROR__UNAUTHORIZED_CONFIG_UPDATE = 0x2f45; // 12101
+/** IncorrectSessionDelegate: Signer is not the delegate of the bridge session */
+export const BRIDGE_ERROR__INCORRECT_SESSION_DELEGATE = 0x2f46; // 12102
+/** IncorrectSessionOwner: Token account is not owned by the bridge session owner */
+export const BRIDGE_ERROR__INCORRECT_SESSION_OWNER = 0x2f47; // 12103
+/** SessionExpired: Bridge session has expired */
+export const BRIDGE_ERROR__SESSION_EXPIRED = 0x2f48; // 12104
+/** SessionRemoteTokenNotAllowed: Bridge session does not allow this remote token */
+export const BRIDGE_ERROR__SESSION_REMOTE_TOKEN_NOT_ALLOWED = 0x2f49; // 12105
+/** SessionBudgetExceeded: Amount exceeds the remaining bridge session budget */
+export const BRIDGE_ERROR__SESSION_BUDGET_EXCEEDED = 0x2f4a; // 12106
+/** TooManySessionRemoteTokens: Too many bridge session remote tokens */
+export const BRIDGE_ERROR__TOO_MANY_SESSION_REMOTE_TOKENS = 0x2f4b; // 12107
+/** InvalidSessionExpiry: Bridge session expiry must be in the future */
+export const BRIDGE_ERROR__INVALID_SESSION_EXPIRY = 0x2f4c; // 12108
 /** BufferUnauthorizedClose: Only the owner can close this buffer */
 export const BRIDGE_ERROR__BUFFER_UNAUTHORIZED_CLOSE = 0x2fa8; // 12200
 /** BufferUnauthorizedAppend: Only the owner can append to this buffer */
 export const BRIDGE_ERROR__BUFFER_UNAUTHORIZED_APPEND = 0x2fa9; // 12201
 /** BufferMaxSizeExceeded: Call buffer size exceeds maximum allowed size */
 export const BRIDGE_ERROR__BUFFER_MAX_SIZE_EXCEEDED = 0x2faa; // 12202
+/** BufferExpiryDisabled: Call buffer expiry is disabled */
+export const BRIDGE_ERROR__BUFFER_EXPIRY_DISABLED = 0x2fab; // 12203
+/** BufferNotExpired: Call buffer has not expired yet */
+export const BRIDGE_ERROR__BUFFER_NOT_EXPIRED = 0x2fac; // 12204
+/** IncorrectBufferOwner: Incorrect call buffer owner */
+export const BRIDGE_ERROR__INCORRECT_BUFFER_OWNER = 0x2fad; // 12205
+/** BufferReusable: Cannot append to a reusable call buffer */
+export const BRIDGE_ERROR__BUFFER_REUSABLE = 0x2fae; // 12206
+/** BufferNotCloneable: Only the owner can clone a call buffer that is not reusable */
+export const BRIDGE_ERROR__BUFFER_NOT_CLONEABLE = 0x2faf; // 12207
 /** InvalidRecoveryId: Invalid recovery ID */
 export const BRIDGE_ERROR__INVALID_RECOVERY_ID = 0x300c; // 12300
 /** SignatureVerificationFailed: Signature verification failed */
@@ -38,6 +74,16 @@ export const BRIDGE_ERROR__SIGNATURE_VERIFICATION_FAILED = 0x300d; // 12301
 export const BRIDGE_ERROR__INSUFFICIENT_BASE_SIGNATURES = 0x300e; // 12302
 /** InsufficientPartnerSignatures: Insufficient partner oracle signatures to meet threshold */
 export const BRIDGE_ERROR__INSUFFICIENT_PARTNER_SIGNATURES = 0x300f; // 12303
+/** MissingInstructionsSysvar: Instructions sysvar is required to verify signatures through the Secp256k1 program */
+export const BRIDGE_ERROR__MISSING_INSTRUCTIONS_SYSVAR = 0x3010; // 12304
+/** InvalidSecp256k1Instruction: Invalid Secp256k1 program instruction */
+export const BRIDGE_ERROR__INVALID_SECP256K1_INSTRUCTION = 0x3011; // 12305
+/** OutputRootsNotConflicting: Output roots are not conflicting */
+export const BRIDGE_ERROR__OUTPUT_ROOTS_NOT_CONFLICTING = 0x3012; // 12306
+/** NoEquivocatingSigner: No Base oracle signer attested both output roots */
+export const BRIDGE_ERROR__NO_EQUIVOCATING_SIGNER = 0x3013; // 12307
+/** TooManySignatures: Too many signatures (max 32) */
+export const BRIDGE_ERROR__TOO_MANY_SIGNATURES = 0x3014; // 12308
 /** InvalidProof: Invalid proof */
 export const BRIDGE_ERROR__INVALID_PROOF = 0x3070; // 12400
 /** MmrShouldBeEmpty: MMR should be empty */
@@ -54,12 +100,114 @@ export const BRIDGE_ERROR__INSUFFICIENT_PROOF_ELEMENTS_FOR_OTHER_MOUNTAIN_PEAKS
 export const BRIDGE_ERROR__UNUSED_PROOF_ELEMENTS_REMAINING = 0x3076; // 12406
 /** NoPeaksFoundForNonEmptyMmr: No peaks found for non-empty MMR */
 export const BRIDGE_ERROR__NO_PEAKS_FOUND_FOR_NON_EMPTY_MMR = 0x3077; // 12407
+/** OutboxFull: Outbox MMR is full */
+export const BRIDGE_ERROR__OUTBOX_FULL = 0x3078; // 12408
 /** InvalidMessageHash: Invalid message hash */
 export const BRIDGE_ERROR__INVALID_MESSAGE_HASH = 0x30d4; // 12500
 /** AlreadyExecuted: Message already executed */
 export const BRIDGE_ERROR__ALREADY_EXECUTED = 0x30d5; // 12501
 /** IncorrectBlockNumber: Incorrect block number */
 export const BRIDGE_ERROR__INCORRECT_BLOCK_NUMBER = 0x30d6; // 12502
+/** UnauthorizedReturnTransfer: Return transfers can only be sent by the Base Bridge */
+export const BRIDGE_ERROR__UNAUTHORIZED_RETURN_TRANSFER = 0x30d7; // 12503
+/** IncorrectReturnedTransfer: Incorrect returned transfer account */
+export const BRIDGE_ERROR__INCORRECT_RETURNED_TRANSFER = 0x30d8; // 12504
+/** IncorrectAddressBook: Incorrect address book account */
+export const BRIDGE_ERROR__INCORRECT_ADDRESS_BOOK = 0x30d9; // 12505
+/** TransferAlreadyReturned: Transfer already returned */
+export const BRIDGE_ERROR__TRANSFER_ALREADY_RETURNED = 0x30da; // 12506
+/** InvalidRelayedNonce: Relayed nonce is outside of the page or not yet assigned */
+export const BRIDGE_ERROR__INVALID_RELAYED_NONCE = 0x30db; // 12507
+/** MessageNotRelayed: Outgoing message has not been relayed on Base */
+export const BRIDGE_ERROR__MESSAGE_NOT_RELAYED = 0x30dc; // 12508
+/** IncorrectOutgoingMessageSender: Incorrect outgoing message sender */
+export const BRIDGE_ERROR__INCORRECT_OUTGOING_MESSAGE_SENDER = 0x30dd; // 12509
+/** TooManyExtraSigners: Too many extra signers */
+export const BRIDGE_ERROR__TOO_MANY_EXTRA_SIGNERS = 0x30de; // 12510
+/** TooManyExtraSignerSeeds: Too many seeds for an extra signer */
+export const BRIDGE_ERROR__TOO_MANY_EXTRA_SIGNER_SEEDS = 0x30df; // 12511
+/** ExtraSignerSeedTooLong: Extra signer seed too long */
+export const BRIDGE_ERROR__EXTRA_SIGNER_SEED_TOO_LONG = 0x30e0; // 12512
+/** EmptyOutputRootsBatch: Output roots batch is empty */
+export const BRIDGE_ERROR__EMPTY_OUTPUT_ROOTS_BATCH = 0x30e1; // 12513
+/** TooManyOutputRoots: Too many output roots in batch */
+export const BRIDGE_ERROR__TOO_MANY_OUTPUT_ROOTS = 0x30e2; // 12514
+/** IncorrectOutputRootAccount: Incorrect output root account */
+export const BRIDGE_ERROR__INCORRECT_OUTPUT_ROOT_ACCOUNT = 0x30e3; // 12515
+/** MissingAddressBook: Address book account is required to prove this message */
+export const BRIDGE_ERROR__MISSING_ADDRESS_BOOK = 0x30e4; // 12516
+/** InsufficientComputeBudget: Remaining compute units are below the amount required by the message */
+export const BRIDGE_ERROR__INSUFFICIENT_COMPUTE_BUDGET = 0x30e5; // 12517
+/** NestedComputeBudget: Compute budget messages cannot be nested */
+export const BRIDGE_ERROR__NESTED_COMPUTE_BUDGET = 0x30e6; // 12518
+/** IncorrectRemoteChainId: Message remote chain ID does not match the configured remote chain */
+export const BRIDGE_ERROR__INCORRECT_REMOTE_CHAIN_ID = 0x30e7; // 12519
+/** NestedRelayFee: Relay fee messages cannot wrap compute budget or relay fee messages */
+export const BRIDGE_ERROR__NESTED_RELAY_FEE = 0x30e8; // 12520
+/** MissingRelayFeeAccounts: Relay fee vault and relayer accounts are required to relay this message */
+export const BRIDGE_ERROR__MISSING_RELAY_FEE_ACCOUNTS = 0x30e9; // 12521
+/** InvalidRelayFeeSettlementNonce: Relay fee settlement nonce must follow the latest settlement */
+export const BRIDGE_ERROR__INVALID_RELAY_FEE_SETTLEMENT_NONCE = 0x30ea; // 12522
+/** StaleOutputRoot: Output root is older than the freshness window of the message kind */
+export const BRIDGE_ERROR__STALE_OUTPUT_ROOT = 0x30eb; // 12523
+/** TooManyMessageIxs: Too many instructions in message */
+export const BRIDGE_ERROR__TOO_MANY_MESSAGE_IXS = 0x30ec; // 12524
+/** TooManyIxAccounts: Too many accounts in message instruction */
+export const BRIDGE_ERROR__TOO_MANY_IX_ACCOUNTS = 0x30ed; // 12525
+/** MessageIxsDataTooLong: Message instruction data too long */
+export const BRIDGE_ERROR__MESSAGE_IXS_DATA_TOO_LONG = 0x30ee; // 12526
+/** InvalidIxProgramId: Message instruction targets a program that cannot be invoked */
+export const BRIDGE_ERROR__INVALID_IX_PROGRAM_ID = 0x30ef; // 12527
+/** TooManyProvenChunks: Message instructions span too many proven chunks */
+export const BRIDGE_ERROR__TOO_MANY_PROVEN_CHUNKS = 0x30f0; // 12528
+/** ProvenChunkTooLarge: Message instruction does not fit in a proven chunk */
+export const BRIDGE_ERROR__PROVEN_CHUNK_TOO_LARGE = 0x30f1; // 12529
+/** IncorrectProvenChunkAccount: Incorrect proven chunk account */
+export const BRIDGE_ERROR__INCORRECT_PROVEN_CHUNK_ACCOUNT = 0x30f2; // 12530
+/** MessageAlreadyRelayed: Outgoing message has been relayed on Base */
+export const BRIDGE_ERROR__MESSAGE_ALREADY_RELAYED = 0x30f3; // 12531
+/** NoLockedTokens: Outgoing message did not lock SOL or SPL tokens */
+export const BRIDGE_ERROR__NO_LOCKED_TOKENS = 0x30f4; // 12532
+/** OutputRootChallengeWindowClosed: Output root challenge window has closed */
+export const BRIDGE_ERROR__OUTPUT_ROOT_CHALLENGE_WINDOW_CLOSED = 0x30f5; // 12533
+/** SupersededOutputRoot: Message was proven against a superseded output root revision */
+export const BRIDGE_ERROR__SUPERSEDED_OUTPUT_ROOT = 0x30f6; // 12534
+/** OutputRootNotSuperseded: Message was proven against the current output root revision */
+export const BRIDGE_ERROR__OUTPUT_ROOT_NOT_SUPERSEDED = 0x30f7; // 12535
+/** IncorrectProver: Incorrect prover account */
+export const BRIDGE_ERROR__INCORRECT_PROVER = 0x30f8; // 12536
+/** FinalizationDelayNotElapsed: Transfer is still held by the finalization delay */
+export const BRIDGE_ERROR__FINALIZATION_DELAY_NOT_ELAPSED = 0x30f9; // 12537
+/** MessageVetoed: Message was vetoed by the guardian */
+export const BRIDGE_ERROR__MESSAGE_VETOED = 0x30fa; // 12538
+/** MessageNotInFinalizationDelay: Message is not held by the finalization delay */
+export const BRIDGE_ERROR__MESSAGE_NOT_IN_FINALIZATION_DELAY = 0x30fb; // 12539
+/** MessageNotCoveredByOutputRoot: Output root does not cover the message nonce */
+export const BRIDGE_ERROR__MESSAGE_NOT_COVERED_BY_OUTPUT_ROOT = 0x30fc; // 12540
+/** DeniedRelayProgram: Relayed message invokes a denied program */
+export const BRIDGE_ERROR__DENIED_RELAY_PROGRAM = 0x30fd; // 12541
+/** NestedLamportAllowance: Lamport allowance messages cannot wrap other wrapper messages */
+export const BRIDGE_ERROR__NESTED_LAMPORT_ALLOWANCE = 0x30fe; // 12542
+/** MissingLamportAllowanceAccounts: Operational vault and bridge CPI authority accounts are required to relay this message */
+export const BRIDGE_ERROR__MISSING_LAMPORT_ALLOWANCE_ACCOUNTS = 0x30ff; // 12543
+/** LamportAllowanceTooHigh: Lamport allowance exceeds the maximum allowed by the operational vault */
+export const BRIDGE_ERROR__LAMPORT_ALLOWANCE_TOO_HIGH = 0x3100; // 12544
+/** InsufficientOperationalVault: Operational vault balance is insufficient for the lamport allowance */
+export const BRIDGE_ERROR__INSUFFICIENT_OPERATIONAL_VAULT = 0x3101; // 12545
+/** UnauthorizedWrappedTokenConfirmation: Wrapped token registrations can only be confirmed by the Base Bridge */
+export const BRIDGE_ERROR__UNAUTHORIZED_WRAPPED_TOKEN_CONFIRMATION = 0x3102; // 12546
+/** HandlerPayloadTooLong: Handler payload exceeds MAX_HANDLER_PAYLOAD_LEN */
+export const BRIDGE_ERROR__HANDLER_PAYLOAD_TOO_LONG = 0x3103; // 12547
+/** HandlerNotRegistered: No handler is registered for the message sender */
+export const BRIDGE_ERROR__HANDLER_NOT_REGISTERED = 0x3104; // 12548
+/** IncorrectHandlerAccounts: Incorrect handler program or bridge CPI authority account */
+export const BRIDGE_ERROR__INCORRECT_HANDLER_ACCOUNTS = 0x3105; // 12549
+/** UnauthorizedHandlerRegistration: Handlers can only be registered by their Base sender */
+export const BRIDGE_ERROR__UNAUTHORIZED_HANDLER_REGISTRATION = 0x3106; // 12550
+/** OutflowApprovalRequired: Message tripped the outflow circuit breaker and awaits the guardian approval */
+export const BRIDGE_ERROR__OUTFLOW_APPROVAL_REQUIRED = 0x3107; // 12551
+/** OutflowNotHeld: Message is not held by the outflow circuit breaker */
+export const BRIDGE_ERROR__OUTFLOW_NOT_HELD = 0x3108; // 12552
 /** MintDoesNotMatchLocalToken: Mint does not match local token */
 export const BRIDGE_ERROR__MINT_DOES_NOT_MATCH_LOCAL_TOKEN = 0x3138; // 12600
 /** TokenAccountDoesNotMatchTo: Token account does not match to address */
@@ -72,6 +220,44 @@ export const BRIDGE_ERROR__MINT_IS_WRAPPED_TOKEN = 0x313b; // 12603
 export const BRIDGE_ERROR__INCORRECT_TO = 0x313c; // 12604
 /** IncorrectSolVault: Incorrect sol vault */
 export const BRIDGE_ERROR__INCORRECT_SOL_VAULT = 0x313d; // 12605
+/** IncorrectTokenDeposits: Incorrect token deposits account */
+export const BRIDGE_ERROR__INCORRECT_TOKEN_DEPOSITS = 0x313e; // 12606
+/** IncorrectProtocolFeeTreasury: Incorrect protocol fee treasury */
+export const BRIDGE_ERROR__INCORRECT_PROTOCOL_FEE_TREASURY = 0x313f; // 12607
+/** TransferMemoTooLong: Transfer memo exceeds MAX_TRANSFER_MEMO_LEN */
+export const BRIDGE_ERROR__TRANSFER_MEMO_TOO_LONG = 0x3140; // 12608
+/** StaleLiabilityRoot: Liability root must be for a newer Base block */
+export const BRIDGE_ERROR__STALE_LIABILITY_ROOT = 0x3141; // 12609
+/** ScaledAmountOverflow: Scaled wrapped token amount overflows */
+export const BRIDGE_ERROR__SCALED_AMOUNT_OVERFLOW = 0x3142; // 12610
+/** InexactScaledAmount: Remote amount is not a multiple of the wrapped token scalar */
+export const BRIDGE_ERROR__INEXACT_SCALED_AMOUNT = 0x3143; // 12611
+/** EmptyTransferBatch: Transfer batch has no recipients */
+export const BRIDGE_ERROR__EMPTY_TRANSFER_BATCH = 0x3144; // 12612
+/** TooManyBatchRecipients: Transfer batch exceeds MAX_TRANSFER_BATCH_RECIPIENTS */
+export const BRIDGE_ERROR__TOO_MANY_BATCH_RECIPIENTS = 0x3145; // 12613
+/** MintNotOwnedByTokenProgram: Mint is not owned by the token program */
+export const BRIDGE_ERROR__MINT_NOT_OWNED_BY_TOKEN_PROGRAM = 0x3146; // 12614
+/** FreezeAuthorityNotAllowed: Mint freeze authority is not allowed by the mint policy */
+export const BRIDGE_ERROR__FREEZE_AUTHORITY_NOT_ALLOWED = 0x3147; // 12615
+/** UnauthorizedCrosschainMint: Crosschain mints can only be requested by the Base Bridge */
+export const BRIDGE_ERROR__UNAUTHORIZED_CROSSCHAIN_MINT = 0x3148; // 12616
+/** WrappedSupplyCapExceeded: Mint would exceed the wrapped token max supply */
+export const BRIDGE_ERROR__WRAPPED_SUPPLY_CAP_EXCEEDED = 0x3149; // 12617
+/** IncorrectWrappedSupplyCapAccount: Incorrect wrapped token supply cap account */
+export const BRIDGE_ERROR__INCORRECT_WRAPPED_SUPPLY_CAP_ACCOUNT = 0x314a; // 12618
+/** UnauthorizedSupplyCapUpdate: Wrapped token max supplies can only be set by the Base Bridge */
+export const BRIDGE_ERROR__UNAUTHORIZED_SUPPLY_CAP_UPDATE = 0x314b; // 12619
+/** IncorrectVaultDecimalsAccount: Incorrect vault decimals account */
+export const BRIDGE_ERROR__INCORRECT_VAULT_DECIMALS_ACCOUNT = 0x314c; // 12620
+/** MintNotAllowListed: Mint is not on the allow-list during guarded launch */
+export const BRIDGE_ERROR__MINT_NOT_ALLOW_LISTED = 0x314d; // 12621
+/** IncorrectWrappedTokenConfirmation: Incorrect wrapped token confirmation account */
+export const BRIDGE_ERROR__INCORRECT_WRAPPED_TOKEN_CONFIRMATION = 0x314e; // 12622
+/** WrappedTokenNotConfirmed: Wrapped token registration has not been confirmed by Base */
+export const BRIDGE_ERROR__WRAPPED_TOKEN_NOT_CONFIRMED = 0x314f; // 12623
+/** WrappedTokenMetadataMismatch: Confirmed metadata does not match the wrapped token */
+export const BRIDGE_ERROR__WRAPPED_TOKEN_METADATA_MISMATCH = 0x3150; // 12624
 /** RemoteTokenNotFound: Remote token not found */
 export const BRIDGE_ERROR__REMOTE_TOKEN_NOT_FOUND = 0x319c; // 12700
 /** ScalerExponentNotFound: Scaler exponent not found */
@@ -84,6 +270,20 @@ export const BRIDGE_ERROR__INVALID_SCALER_EXPONENT = 0x319f; // 12703
 export const BRIDGE_ERROR__MINT_IS_NOT_FROM_TOKEN2022 = 0x31a0; // 12704
 /** MintIsNotWrappedTokenPda: Mint is not a valid wrapped token PDA */
 export const BRIDGE_ERROR__MINT_IS_NOT_WRAPPED_TOKEN_PDA = 0x31a1; // 12705
+/** IncorrectMetaplexMetadata: Incorrect Metaplex metadata account */
+export const BRIDGE_ERROR__INCORRECT_METAPLEX_METADATA = 0x31a2; // 12706
+/** MissingMetaplexProgram: Metaplex program is required to create Metaplex metadata */
+export const BRIDGE_ERROR__MISSING_METAPLEX_PROGRAM = 0x31a3; // 12707
+/** EmptyTokenMetadata: Token name and symbol must not be empty */
+export const BRIDGE_ERROR__EMPTY_TOKEN_METADATA = 0x31a4; // 12708
+/** TokenNameTooLong: Token name exceeds MAX_WRAPPED_TOKEN_NAME_LEN */
+export const BRIDGE_ERROR__TOKEN_NAME_TOO_LONG = 0x31a5; // 12709
+/** TokenSymbolTooLong: Token symbol exceeds MAX_WRAPPED_TOKEN_SYMBOL_LEN */
+export const BRIDGE_ERROR__TOKEN_SYMBOL_TOO_LONG = 0x31a6; // 12710
+/** UntrimmedTokenMetadata: Token name and symbol must not start or end with whitespace */
+export const BRIDGE_ERROR__UNTRIMMED_TOKEN_METADATA = 0x31a7; // 12711
+/** InvalidTokenMetadataCharacter: Token name or symbol contains a disallowed character */
+export const BRIDGE_ERROR__INVALID_TOKEN_METADATA_CHARACTER = 0x31a8; // 12712
 /** InvalidThreshold: Threshold must be <= number of signers */
 export const BRIDGE_ERROR__INVALID_THRESHOLD = 0x3200; // 12800
 /** TooManySigners: Too many signers (max 32) */
@@ -100,105 +300,485 @@ export const BRIDGE_ERROR__INVALID_WINDOW_DURATION_SECONDS = 0x3205; // 12805
 export const BRIDGE_ERROR__INVALID_GAS_COST_SCALER_DP = 0x3206; // 12806
 /** InvalidBlockIntervalRequirement: Invalid block interval requirement */
 export const BRIDGE_ERROR__INVALID_BLOCK_INTERVAL_REQUIREMENT = 0x3207; // 12807
+/** InvalidBridgeLayout: Unrecognized bridge account layout */
+export const BRIDGE_ERROR__INVALID_BRIDGE_LAYOUT = 0x3208; // 12808
+/** BridgeAlreadyMigrated: Bridge account is already at the current layout version */
+export const BRIDGE_ERROR__BRIDGE_ALREADY_MIGRATED = 0x3209; // 12809
+/** InvalidMaxBaseFee: Max base fee must be >= minimum base fee */
+export const BRIDGE_ERROR__INVALID_MAX_BASE_FEE = 0x320a; // 12810
+/** InvalidExpireBountyBps: Expire bounty must be <= 10000 bps */
+export const BRIDGE_ERROR__INVALID_EXPIRE_BOUNTY_BPS = 0x320b; // 12811
+/** TooManyAddressBookTokens: Too many address book tokens */
+export const BRIDGE_ERROR__TOO_MANY_ADDRESS_BOOK_TOKENS = 0x320c; // 12812
+/** DuplicateAddressBookToken: Duplicate address book token */
+export const BRIDGE_ERROR__DUPLICATE_ADDRESS_BOOK_TOKEN = 0x320d; // 12813
+/** AddressBookEntryNotSet: Address book entry is not set */
+export const BRIDGE_ERROR__ADDRESS_BOOK_ENTRY_NOT_SET = 0x320e; // 12814
+/** TooManyProtocolFeeOverrides: Too many protocol fee overrides */
+export const BRIDGE_ERROR__TOO_MANY_PROTOCOL_FEE_OVERRIDES = 0x320f; // 12815
+/** DuplicateProtocolFeeOverride: Duplicate protocol fee override */
+export const BRIDGE_ERROR__DUPLICATE_PROTOCOL_FEE_OVERRIDE = 0x3210; // 12816
+/** InvalidProtocolFeeBps: Protocol fee must be <= MAX_PROTOCOL_FEE_BPS */
+export const BRIDGE_ERROR__INVALID_PROTOCOL_FEE_BPS = 0x3211; // 12817
+/** OracleNotStalled: Output roots have not stalled long enough to activate the emergency quorum */
+export const BRIDGE_ERROR__ORACLE_NOT_STALLED = 0x3212; // 12818
+/** TooManyEmergencySigners: Too many emergency signers */
+export const BRIDGE_ERROR__TOO_MANY_EMERGENCY_SIGNERS = 0x3213; // 12819
+/** InvalidEmergencyThreshold: Emergency threshold must be > 0 and <= number of Base oracle and emergency signers */
+export const BRIDGE_ERROR__INVALID_EMERGENCY_THRESHOLD = 0x3214; // 12820
+/** InvalidEmergencyDuration: Emergency duration must be > 0 when the oracle failover is enabled */
+export const BRIDGE_ERROR__INVALID_EMERGENCY_DURATION = 0x3215; // 12821
+/** TooManyAllowedFreezeAuthorities: Too many allowed freeze authorities */
+export const BRIDGE_ERROR__TOO_MANY_ALLOWED_FREEZE_AUTHORITIES = 0x3216; // 12822
+/** EmergencyExitRequiresPause: Emergency exit requires the bridge to be paused */
+export const BRIDGE_ERROR__EMERGENCY_EXIT_REQUIRES_PAUSE = 0x3217; // 12823
+/** EmergencyExitTimelocked: Emergency exit timelock has not elapsed */
+export const BRIDGE_ERROR__EMERGENCY_EXIT_TIMELOCKED = 0x3218; // 12824
+/** TooManyOutflowThresholds: Too many outflow thresholds */
+export const BRIDGE_ERROR__TOO_MANY_OUTFLOW_THRESHOLDS = 0x3219; // 12825
+/** InvalidOutflowThreshold: Outflow thresholds must be > 0 and set once per token */
+export const BRIDGE_ERROR__INVALID_OUTFLOW_THRESHOLD = 0x321a; // 12826
+/** InvalidGasTarget: Invalid gas target */
+export const BRIDGE_ERROR__INVALID_GAS_TARGET = 0x321b; // 12827
+/** TooManyFinalizationThresholds: Too many finalization delay thresholds */
+export const BRIDGE_ERROR__TOO_MANY_FINALIZATION_THRESHOLDS = 0x321c; // 12828
+/** InvalidFinalizationThreshold: Finalization delay thresholds must be set once per token */
+export const BRIDGE_ERROR__INVALID_FINALIZATION_THRESHOLD = 0x321d; // 12829
+/** InvalidRemoteDecimals: Remote decimals cannot exceed the mint decimals */
+export const BRIDGE_ERROR__INVALID_REMOTE_DECIMALS = 0x321e; // 12830
+/** VaultHasDeposits: Vault decimals cannot change while the vault holds deposits */
+export const BRIDGE_ERROR__VAULT_HAS_DEPOSITS = 0x321f; // 12831
+/** BaseGovernanceNotSet: Base governance is not set */
+export const BRIDGE_ERROR__BASE_GOVERNANCE_NOT_SET = 0x3220; // 12832
+/** GovernedByBase: This configuration is governed from Base */
+export const BRIDGE_ERROR__GOVERNED_BY_BASE = 0x3221; // 12833
+/** InvalidInsuranceShare: Insurance share exceeds the maximum */
+export const BRIDGE_ERROR__INVALID_INSURANCE_SHARE = 0x3222; // 12834
+/** InsurancePayoutPending: An insurance payout is already pending */
+export const BRIDGE_ERROR__INSURANCE_PAYOUT_PENDING = 0x3223; // 12835
+/** NoPendingInsurancePayout: No insurance payout is pending */
+export const BRIDGE_ERROR__NO_PENDING_INSURANCE_PAYOUT = 0x3224; // 12836
+/** InsurancePayoutTimelocked: Insurance payout timelock has not elapsed */
+export const BRIDGE_ERROR__INSURANCE_PAYOUT_TIMELOCKED = 0x3225; // 12837
+/** InsufficientInsuranceFund: Insurance fund balance is insufficient for the payout */
+export const BRIDGE_ERROR__INSUFFICIENT_INSURANCE_FUND = 0x3226; // 12838
+/** IncorrectInsurancePayoutRecipient: Incorrect insurance payout recipient */
+export const BRIDGE_ERROR__INCORRECT_INSURANCE_PAYOUT_RECIPIENT = 0x3227; // 12839
+/** TooManyDeniedRelayPrograms: Too many denied relay programs */
+export const BRIDGE_ERROR__TOO_MANY_DENIED_RELAY_PROGRAMS = 0x3228; // 12840
+/** InvalidMaintenanceFeeReimbursement: Maintenance fee reimbursement exceeds the maximum */
+export const BRIDGE_ERROR__INVALID_MAINTENANCE_FEE_REIMBURSEMENT = 0x3229; // 12841
+/** InvalidMaxLamportAllowance: Maximum lamport allowance exceeds the limit */
+export const BRIDGE_ERROR__INVALID_MAX_LAMPORT_ALLOWANCE = 0x322a; // 12842
+/** TooManyAllowedMints: Too many allowed mints */
+export const BRIDGE_ERROR__TOO_MANY_ALLOWED_MINTS = 0x322b; // 12843
+/** TooManyDexRouters: Too many DEX routers */
+export const BRIDGE_ERROR__TOO_MANY_DEX_ROUTERS = 0x322c; // 12844
+/** TooManyRateLimitExemptPayers: Too many payers exempted from rate limiting */
+export const BRIDGE_ERROR__TOO_MANY_RATE_LIMIT_EXEMPT_PAYERS = 0x322d; // 12845
 /** CreationWithNonZeroTarget: Creation with non-zero target */
 export const BRIDGE_ERROR__CREATION_WITH_NON_ZERO_TARGET = 0x3264; // 12900
 /** ZeroAddress: Zero address */
 export const BRIDGE_ERROR__ZERO_ADDRESS = 0x3265; // 12901
+/** EmptyEncryptedCall: Encrypted call has no ciphertext */
+export const BRIDGE_ERROR__EMPTY_ENCRYPTED_CALL = 0x3266; // 12902
+/** MissingSwapGuard: Calls to a registered DEX router require a swap guard */
+export const BRIDGE_ERROR__MISSING_SWAP_GUARD = 0x3267; // 12903
+/** SwapGuardWithoutCall: Swap guard requires a call */
+export const BRIDGE_ERROR__SWAP_GUARD_WITHOUT_CALL = 0x3268; // 12904
+/** InvalidSwapGuardMinOut: Swap guard minimum output must be non-zero */
+export const BRIDGE_ERROR__INVALID_SWAP_GUARD_MIN_OUT = 0x3269; // 12905
+/** SwapGuardExpired: Swap guard deadline has passed */
+export const BRIDGE_ERROR__SWAP_GUARD_EXPIRED = 0x326a; // 12906
+/** ProtocolFeeExceedsAmount: Protocol fee exceeds the bridged amount */
+export const BRIDGE_ERROR__PROTOCOL_FEE_EXCEEDS_AMOUNT = 0x326b; // 12907
+/** MissingDexRouterList: DEX router list required to check the call */
+export const BRIDGE_ERROR__MISSING_DEX_ROUTER_LIST = 0x326c; // 12908
 
 export type BridgeError =
+  | typeof BRIDGE_ERROR__ADDRESS_BOOK_ENTRY_NOT_SET
   | typeof BRIDGE_ERROR__ALREADY_EXECUTED
+  | typeof BRIDGE_ERROR__BASE_GOVERNANCE_NOT_SET
+  | typeof BRIDGE_ERROR__BRIDGE_ALREADY_MIGRATED
   | typeof BRIDGE_ERROR__BRIDGE_PAUSED
+  | typeof BRIDGE_ERROR__BUFFER_EXPIRY_DISABLED
   | typeof BRIDGE_ERROR__BUFFER_MAX_SIZE_EXCEEDED
+  | typeof BRIDGE_ERROR__BUFFER_NOT_CLONEABLE
+  | typeof BRIDGE_ERROR__BUFFER_NOT_EXPIRED
+  | typeof BRIDGE_ERROR__BUFFER_REUSABLE
   | typeof BRIDGE_ERROR__BUFFER_UNAUTHORIZED_APPEND
   | typeof BRIDGE_ERROR__BUFFER_UNAUTHORIZED_CLOSE
   | typeof BRIDGE_ERROR__CREATION_WITH_NON_ZERO_TARGET
+  | typeof BRIDGE_ERROR__DENIED_RELAY_PROGRAM
+  | typeof BRIDGE_ERROR__DUPLICATE_ADDRESS_BOOK_TOKEN
+  | typeof BRIDGE_ERROR__DUPLICATE_PROTOCOL_FEE_OVERRIDE
   | typeof BRIDGE_ERROR__DUPLICATE_SIGNER
+  | typeof BRIDGE_ERROR__EMERGENCY_EXIT_REQUIRES_PAUSE
+  | typeof BRIDGE_ERROR__EMERGENCY_EXIT_TIMELOCKED
+  | typeof BRIDGE_ERROR__EMPTY_ENCRYPTED_CALL
   | typeof BRIDGE_ERROR__EMPTY_MMR
+  | typeof BRIDGE_ERROR__EMPTY_OUTPUT_ROOTS_BATCH
+  | typeof BRIDGE_ERROR__EMPTY_TOKEN_METADATA
+  | typeof BRIDGE_ERROR__EMPTY_TRANSFER_BATCH
+  | typeof BRIDGE_ERROR__ENVIRONMENT_MISMATCH
+  | typeof BRIDGE_ERROR__EXTRA_SIGNER_SEED_TOO_LONG
+  | typeof BRIDGE_ERROR__FINALIZATION_DELAY_NOT_ELAPSED
+  | typeof BRIDGE_ERROR__FREEZE_AUTHORITY_NOT_ALLOWED
+  | typeof BRIDGE_ERROR__GOVERNED_BY_BASE
+  | typeof BRIDGE_ERROR__HANDLER_NOT_REGISTERED
+  | typeof BRIDGE_ERROR__HANDLER_PAYLOAD_TOO_LONG
+  | typeof BRIDGE_ERROR__INCORRECT_ADDRESS_BOOK
   | typeof BRIDGE_ERROR__INCORRECT_BLOCK_NUMBER
   | typeof BRIDGE_ERROR__INCORRECT_BRIDGE_PROGRAM
+  | typeof BRIDGE_ERROR__INCORRECT_BUFFER_OWNER
+  | typeof BRIDGE_ERROR__INCORRECT_GAS_FEE_ESCROW
   | typeof BRIDGE_ERROR__INCORRECT_GAS_FEE_RECEIVER
+  | typeof BRIDGE_ERROR__INCORRECT_HANDLER_ACCOUNTS
+  | typeof BRIDGE_ERROR__INCORRECT_INSURANCE_PAYOUT_RECIPIENT
+  | typeof BRIDGE_ERROR__INCORRECT_METAPLEX_METADATA
+  | typeof BRIDGE_ERROR__INCORRECT_OUTGOING_MESSAGE_SENDER
+  | typeof BRIDGE_ERROR__INCORRECT_OUTPUT_ROOT_ACCOUNT
+  | typeof BRIDGE_ERROR__INCORRECT_PAYER_RATE_LIMIT
+  | typeof BRIDGE_ERROR__INCORRECT_PROTOCOL_FEE_TREASURY
+  | typeof BRIDGE_ERROR__INCORRECT_PROVEN_CHUNK_ACCOUNT
+  | typeof BRIDGE_ERROR__INCORRECT_PROVER
+  | typeof BRIDGE_ERROR__INCORRECT_REMOTE_CHAIN_ID
+  | typeof BRIDGE_ERROR__INCORRECT_RETURNED_TRANSFER
+  | typeof BRIDGE_ERROR__INCORRECT_SESSION_DELEGATE
+  | typeof BRIDGE_ERROR__INCORRECT_SESSION_OWNER
   | typeof BRIDGE_ERROR__INCORRECT_SOL_VAULT
   | typeof BRIDGE_ERROR__INCORRECT_TO
+  | typeof BRIDGE_ERROR__INCORRECT_TOKEN_DEPOSITS
   | typeof BRIDGE_ERROR__INCORRECT_TOKEN_VAULT
+  | typeof BRIDGE_ERROR__INCORRECT_VAULT_DECIMALS_ACCOUNT
+  | typeof BRIDGE_ERROR__INCORRECT_WRAPPED_SUPPLY_CAP_ACCOUNT
+  | typeof BRIDGE_ERROR__INCORRECT_WRAPPED_TOKEN_CONFIRMATION
+  | typeof BRIDGE_ERROR__INEXACT_SCALED_AMOUNT
   | typeof BRIDGE_ERROR__INSUFFICIENT_BASE_SIGNATURES
+  | typeof BRIDGE_ERROR__INSUFFICIENT_COMPUTE_BUDGET
+  | typeof BRIDGE_ERROR__INSUFFICIENT_INSURANCE_FUND
+  | typeof BRIDGE_ERROR__INSUFFICIENT_OPERATIONAL_VAULT
   | typeof BRIDGE_ERROR__INSUFFICIENT_PARTNER_SIGNATURES
   | typeof BRIDGE_ERROR__INSUFFICIENT_PROOF_ELEMENTS_FOR_INTRA_MOUNTAIN_PATH
   | typeof BRIDGE_ERROR__INSUFFICIENT_PROOF_ELEMENTS_FOR_OTHER_MOUNTAIN_PEAKS
+  | typeof BRIDGE_ERROR__INSURANCE_PAYOUT_PENDING
+  | typeof BRIDGE_ERROR__INSURANCE_PAYOUT_TIMELOCKED
   | typeof BRIDGE_ERROR__INVALID_BLOCK_INTERVAL_REQUIREMENT
+  | typeof BRIDGE_ERROR__INVALID_BRIDGE_LAYOUT
   | typeof BRIDGE_ERROR__INVALID_DENOMINATOR
+  | typeof BRIDGE_ERROR__INVALID_EMERGENCY_DURATION
+  | typeof BRIDGE_ERROR__INVALID_EMERGENCY_THRESHOLD
+  | typeof BRIDGE_ERROR__INVALID_EXPIRE_BOUNTY_BPS
+  | typeof BRIDGE_ERROR__INVALID_FINALIZATION_THRESHOLD
   | typeof BRIDGE_ERROR__INVALID_GAS_COST_SCALER_DP
+  | typeof BRIDGE_ERROR__INVALID_GAS_FEE_RECEIVER
+  | typeof BRIDGE_ERROR__INVALID_GAS_TARGET
+  | typeof BRIDGE_ERROR__INVALID_INSURANCE_SHARE
+  | typeof BRIDGE_ERROR__INVALID_IX_PROGRAM_ID
+  | typeof BRIDGE_ERROR__INVALID_MAINTENANCE_FEE_REIMBURSEMENT
+  | typeof BRIDGE_ERROR__INVALID_MAX_BASE_FEE
+  | typeof BRIDGE_ERROR__INVALID_MAX_LAMPORT_ALLOWANCE
   | typeof BRIDGE_ERROR__INVALID_MESSAGE_HASH
+  | typeof BRIDGE_ERROR__INVALID_OUTFLOW_THRESHOLD
   | typeof BRIDGE_ERROR__INVALID_PARTNER_THRESHOLD
   | typeof BRIDGE_ERROR__INVALID_PROOF
+  | typeof BRIDGE_ERROR__INVALID_PROTOCOL_FEE_BPS
   | typeof BRIDGE_ERROR__INVALID_RECOVERY_ID
+  | typeof BRIDGE_ERROR__INVALID_RELAYED_NONCE
+  | typeof BRIDGE_ERROR__INVALID_RELAY_FEE_SETTLEMENT_NONCE
+  | typeof BRIDGE_ERROR__INVALID_REMOTE_DECIMALS
   | typeof BRIDGE_ERROR__INVALID_REMOTE_TOKEN
   | typeof BRIDGE_ERROR__INVALID_SCALER_EXPONENT
+  | typeof BRIDGE_ERROR__INVALID_SECP256K1_INSTRUCTION
+  | typeof BRIDGE_ERROR__INVALID_SESSION_EXPIRY
+  | typeof BRIDGE_ERROR__INVALID_SWAP_GUARD_MIN_OUT
   | typeof BRIDGE_ERROR__INVALID_THRESHOLD
+  | typeof BRIDGE_ERROR__INVALID_TOKEN_METADATA_CHARACTER
   | typeof BRIDGE_ERROR__INVALID_WINDOW_DURATION_SECONDS
+  | typeof BRIDGE_ERROR__LAMPORT_ALLOWANCE_TOO_HIGH
   | typeof BRIDGE_ERROR__LEAF_MOUNTAIN_NOT_FOUND
+  | typeof BRIDGE_ERROR__MESSAGE_ALREADY_RELAYED
+  | typeof BRIDGE_ERROR__MESSAGE_IXS_DATA_TOO_LONG
+  | typeof BRIDGE_ERROR__MESSAGE_NOT_COVERED_BY_OUTPUT_ROOT
+  | typeof BRIDGE_ERROR__MESSAGE_NOT_IN_FINALIZATION_DELAY
+  | typeof BRIDGE_ERROR__MESSAGE_NOT_RELAYED
+  | typeof BRIDGE_ERROR__MESSAGE_VETOED
   | typeof BRIDGE_ERROR__MINT_DOES_NOT_MATCH_LOCAL_TOKEN
   | typeof BRIDGE_ERROR__MINT_IS_NOT_FROM_TOKEN2022
   | typeof BRIDGE_ERROR__MINT_IS_NOT_WRAPPED_TOKEN_PDA
   | typeof BRIDGE_ERROR__MINT_IS_WRAPPED_TOKEN
+  | typeof BRIDGE_ERROR__MINT_NOT_ALLOW_LISTED
+  | typeof BRIDGE_ERROR__MINT_NOT_OWNED_BY_TOKEN_PROGRAM
+  | typeof BRIDGE_ERROR__MISSING_ADDRESS_BOOK
+  | typeof BRIDGE_ERROR__MISSING_DEX_ROUTER_LIST
+  | typeof BRIDGE_ERROR__MISSING_INSTRUCTIONS_SYSVAR
+  | typeof BRIDGE_ERROR__MISSING_LAMPORT_ALLOWANCE_ACCOUNTS
+  | typeof BRIDGE_ERROR__MISSING_MEMO_PROGRAM
+  | typeof BRIDGE_ERROR__MISSING_METAPLEX_PROGRAM
+  | typeof BRIDGE_ERROR__MISSING_RELAY_FEE_ACCOUNTS
+  | typeof BRIDGE_ERROR__MISSING_SWAP_GUARD
   | typeof BRIDGE_ERROR__MMR_SHOULD_BE_EMPTY
+  | typeof BRIDGE_ERROR__NESTED_COMPUTE_BUDGET
+  | typeof BRIDGE_ERROR__NESTED_LAMPORT_ALLOWANCE
+  | typeof BRIDGE_ERROR__NESTED_RELAY_FEE
+  | typeof BRIDGE_ERROR__NO_EQUIVOCATING_SIGNER
+  | typeof BRIDGE_ERROR__NO_LOCKED_TOKENS
   | typeof BRIDGE_ERROR__NO_PEAKS_FOUND_FOR_NON_EMPTY_MMR
+  | typeof BRIDGE_ERROR__NO_PENDING_INSURANCE_PAYOUT
+  | typeof BRIDGE_ERROR__ORACLE_NOT_STALLED
+  | typeof BRIDGE_ERROR__OUTBOX_FULL
+  | typeof BRIDGE_ERROR__OUTFLOW_APPROVAL_REQUIRED
+  | typeof BRIDGE_ERROR__OUTFLOW_NOT_HELD
+  | typeof BRIDGE_ERROR__OUTPUT_ROOTS_NOT_CONFLICTING
+  | typeof BRIDGE_ERROR__OUTPUT_ROOT_CHALLENGE_WINDOW_CLOSED
+  | typeof BRIDGE_ERROR__OUTPUT_ROOT_NOT_SUPERSEDED
+  | typeof BRIDGE_ERROR__PAYER_RATE_LIMITED
+  | typeof BRIDGE_ERROR__PROTOCOL_FEE_EXCEEDS_AMOUNT
+  | typeof BRIDGE_ERROR__PROVEN_CHUNK_TOO_LARGE
   | typeof BRIDGE_ERROR__REMOTE_TOKEN_NOT_FOUND
+  | typeof BRIDGE_ERROR__SCALED_AMOUNT_OVERFLOW
   | typeof BRIDGE_ERROR__SCALER_EXPONENT_NOT_FOUND
+  | typeof BRIDGE_ERROR__SESSION_BUDGET_EXCEEDED
+  | typeof BRIDGE_ERROR__SESSION_EXPIRED
+  | typeof BRIDGE_ERROR__SESSION_REMOTE_TOKEN_NOT_ALLOWED
   | typeof BRIDGE_ERROR__SIGNATURE_VERIFICATION_FAILED
+  | typeof BRIDGE_ERROR__STALE_LIABILITY_ROOT
+  | typeof BRIDGE_ERROR__STALE_OUTPUT_ROOT
+  | typeof BRIDGE_ERROR__SUPERSEDED_OUTPUT_ROOT
+  | typeof BRIDGE_ERROR__SWAP_GUARD_EXPIRED
+  | typeof BRIDGE_ERROR__SWAP_GUARD_WITHOUT_CALL
   | typeof BRIDGE_ERROR__TOKEN_ACCOUNT_DOES_NOT_MATCH_TO
+  | typeof BRIDGE_ERROR__TOKEN_NAME_TOO_LONG
+  | typeof BRIDGE_ERROR__TOKEN_SYMBOL_TOO_LONG
+  | typeof BRIDGE_ERROR__TOO_MANY_ADDRESS_BOOK_TOKENS
+  | typeof BRIDGE_ERROR__TOO_MANY_ALLOWED_FREEZE_AUTHORITIES
+  | typeof BRIDGE_ERROR__TOO_MANY_ALLOWED_MINTS
+  | typeof BRIDGE_ERROR__TOO_MANY_BATCH_RECIPIENTS
+  | typeof BRIDGE_ERROR__TOO_MANY_DENIED_RELAY_PROGRAMS
+  | typeof BRIDGE_ERROR__TOO_MANY_DEX_ROUTERS
+  | typeof BRIDGE_ERROR__TOO_MANY_EMERGENCY_SIGNERS
+  | typeof BRIDGE_ERROR__TOO_MANY_EXTRA_SIGNERS
+  | typeof BRIDGE_ERROR__TOO_MANY_EXTRA_SIGNER_SEEDS
+  | typeof BRIDGE_ERROR__TOO_MANY_FINALIZATION_THRESHOLDS
+  | typeof BRIDGE_ERROR__TOO_MANY_IX_ACCOUNTS
+  | typeof BRIDGE_ERROR__TOO_MANY_MESSAGE_IXS
+  | typeof BRIDGE_ERROR__TOO_MANY_OUTFLOW_THRESHOLDS
+  | typeof BRIDGE_ERROR__TOO_MANY_OUTPUT_ROOTS
+  | typeof BRIDGE_ERROR__TOO_MANY_PROTOCOL_FEE_OVERRIDES
+  | typeof BRIDGE_ERROR__TOO_MANY_PROVEN_CHUNKS
+  | typeof BRIDGE_ERROR__TOO_MANY_RATE_LIMIT_EXEMPT_PAYERS
+  | typeof BRIDGE_ERROR__TOO_MANY_SESSION_REMOTE_TOKENS
+  | typeof BRIDGE_ERROR__TOO_MANY_SIGNATURES
   | typeof BRIDGE_ERROR__TOO_MANY_SIGNERS
+  | typeof BRIDGE_ERROR__TRANSFER_ALREADY_RETURNED
+  | typeof BRIDGE_ERROR__TRANSFER_MEMO_TOO_LONG
   | typeof BRIDGE_ERROR__UNAUTHORIZED_CONFIG_UPDATE
+  | typeof BRIDGE_ERROR__UNAUTHORIZED_CROSSCHAIN_MINT
+  | typeof BRIDGE_ERROR__UNAUTHORIZED_HANDLER_REGISTRATION
   | typeof BRIDGE_ERROR__UNAUTHORIZED_INITIALIZATION
+  | typeof BRIDGE_ERROR__UNAUTHORIZED_RETURN_TRANSFER
+  | typeof BRIDGE_ERROR__UNAUTHORIZED_SUPPLY_CAP_UPDATE
+  | typeof BRIDGE_ERROR__UNAUTHORIZED_WRAPPED_TOKEN_CONFIRMATION
+  | typeof BRIDGE_ERROR__UNTRIMMED_TOKEN_METADATA
   | typeof BRIDGE_ERROR__UNUSED_PROOF_ELEMENTS_REMAINING
+  | typeof BRIDGE_ERROR__VAULT_HAS_DEPOSITS
+  | typeof BRIDGE_ERROR__WRAPPED_SUPPLY_CAP_EXCEEDED
+  | typeof BRIDGE_ERROR__WRAPPED_TOKEN_METADATA_MISMATCH
+  | typeof BRIDGE_ERROR__WRAPPED_TOKEN_NOT_CONFIRMED
   | typeof BRIDGE_ERROR__ZERO_ADDRESS;
 
 let bridgeErrorMessages: Record<BridgeError, string> | undefined;
 if (process.env.NODE_ENV !== 'production') {
   bridgeErrorMessages = {
+    [BRIDGE_ERROR__ADDRESS_BOOK_ENTRY_NOT_SET]: `Address book entry is not set`,
     [BRIDGE_ERROR__ALREADY_EXECUTED]: `Message already executed`,
+    [BRIDGE_ERROR__BASE_GOVERNANCE_NOT_SET]: `Base governance is not set`,
+    [BRIDGE_ERROR__BRIDGE_ALREADY_MIGRATED]: `Bridge account is already at the current layout version`,
     [BRIDGE_ERROR__BRIDGE_PAUSED]: `Bridge is currently paused`,
+    [BRIDGE_ERROR__BUFFER_EXPIRY_DISABLED]: `Call buffer expiry is disabled`,
     [BRIDGE_ERROR__BUFFER_MAX_SIZE_EXCEEDED]: `Call buffer size exceeds maximum allowed size`,
+    [BRIDGE_ERROR__BUFFER_NOT_CLONEABLE]: `Only the owner can clone a call buffer that is not reusable`,
+    [BRIDGE_ERROR__BUFFER_NOT_EXPIRED]: `Call buffer has not expired yet`,
+    [BRIDGE_ERROR__BUFFER_REUSABLE]: `Cannot append to a reusable call buffer`,
     [BRIDGE_ERROR__BUFFER_UNAUTHORIZED_APPEND]: `Only the owner can append to this buffer`,
     [BRIDGE_ERROR__BUFFER_UNAUTHORIZED_CLOSE]: `Only the owner can close this buffer`,
     [BRIDGE_ERROR__CREATION_WITH_NON_ZERO_TARGET]: `Creation with non-zero target`,
+    [BRIDGE_ERROR__DENIED_RELAY_PROGRAM]: `Relayed message invokes a denied program`,
+    [BRIDGE_ERROR__DUPLICATE_ADDRESS_BOOK_TOKEN]: `Duplicate address book token`,
+    [BRIDGE_ERROR__DUPLICATE_PROTOCOL_FEE_OVERRIDE]: `Duplicate protocol fee override`,
     [BRIDGE_ERROR__DUPLICATE_SIGNER]: `Duplicate signer found`,
+    [BRIDGE_ERROR__EMERGENCY_EXIT_REQUIRES_PAUSE]: `Emergency exit requires the bridge to be paused`,
+    [BRIDGE_ERROR__EMERGENCY_EXIT_TIMELOCKED]: `Emergency exit timelock has not elapsed`,
+    [BRIDGE_ERROR__EMPTY_ENCRYPTED_CALL]: `Encrypted call has no ciphertext`,
     [BRIDGE_ERROR__EMPTY_MMR]: `MMR is empty`,
+    [BRIDGE_ERROR__EMPTY_OUTPUT_ROOTS_BATCH]: `Output roots batch is empty`,
+    [BRIDGE_ERROR__EMPTY_TOKEN_METADATA]: `Token name and symbol must not be empty`,
+    [BRIDGE_ERROR__EMPTY_TRANSFER_BATCH]: `Transfer batch has no recipients`,
+    [BRIDGE_ERROR__ENVIRONMENT_MISMATCH]: `Program was not built for the expected environment`,
+    [BRIDGE_ERROR__EXTRA_SIGNER_SEED_TOO_LONG]: `Extra signer seed too long`,
+    [BRIDGE_ERROR__FINALIZATION_DELAY_NOT_ELAPSED]: `Transfer is still held by the finalization delay`,
+    [BRIDGE_ERROR__FREEZE_AUTHORITY_NOT_ALLOWED]: `Mint freeze authority is not allowed by the mint policy`,
+    [BRIDGE_ERROR__GOVERNED_BY_BASE]: `This configuration is governed from Base`,
+    [BRIDGE_ERROR__HANDLER_NOT_REGISTERED]: `No handler is registered for the message sender`,
+    [BRIDGE_ERROR__HANDLER_PAYLOAD_TOO_LONG]: `Handler payload exceeds MAX_HANDLER_PAYLOAD_LEN`,
+    [BRIDGE_ERROR__INCORRECT_ADDRESS_BOOK]: `Incorrect address book account`,
     [BRIDGE_ERROR__INCORRECT_BLOCK_NUMBER]: `Incorrect block number`,
     [BRIDGE_ERROR__INCORRECT_BRIDGE_PROGRAM]: `Incorrect bridge program`,
+    [BRIDGE_ERROR__INCORRECT_BUFFER_OWNER]: `Incorrect call buffer owner`,
+    [BRIDGE_ERROR__INCORRECT_GAS_FEE_ESCROW]: `Incorrect gas fee escrow`,
     [BRIDGE_ERROR__INCORRECT_GAS_FEE_RECEIVER]: `Incorrect gas fee receiver`,
+    [BRIDGE_ERROR__INCORRECT_HANDLER_ACCOUNTS]: `Incorrect handler program or bridge CPI authority account`,
+    [BRIDGE_ERROR__INCORRECT_INSURANCE_PAYOUT_RECIPIENT]: `Incorrect insurance payout recipient`,
+    [BRIDGE_ERROR__INCORRECT_METAPLEX_METADATA]: `Incorrect Metaplex metadata account`,
+    [BRIDGE_ERROR__INCORRECT_OUTGOING_MESSAGE_SENDER]: `Incorrect outgoing message sender`,
+    [BRIDGE_ERROR__INCORRECT_OUTPUT_ROOT_ACCOUNT]: `Incorrect output root account`,
+    [BRIDGE_ERROR__INCORRECT_PAYER_RATE_LIMIT]: `Incorrect payer rate limit`,
+    [BRIDGE_ERROR__INCORRECT_PROTOCOL_FEE_TREASURY]: `Incorrect protocol fee treasury`,
+    [BRIDGE_ERROR__INCORRECT_PROVEN_CHUNK_ACCOUNT]: `Incorrect proven chunk account`,
+    [BRIDGE_ERROR__INCORRECT_PROVER]: `Incorrect prover account`,
+    [BRIDGE_ERROR__INCORRECT_REMOTE_CHAIN_ID]: `Message remote chain ID does not match the configured remote chain`,
+    [BRIDGE_ERROR__INCORRECT_RETURNED_TRANSFER]: `Incorrect returned transfer account`,
+    [BRIDGE_ERROR__INCORRECT_SESSION_DELEGATE]: `Signer is not the delegate of the bridge session`,
+    [BRIDGE_ERROR__INCORRECT_SESSION_OWNER]: `Token account is not owned by the bridge session owner`,
     [BRIDGE_ERROR__INCORRECT_SOL_VAULT]: `Incorrect sol vault`,
     [BRIDGE_ERROR__INCORRECT_TO]: `Incorrect to`,
+    [BRIDGE_ERROR__INCORRECT_TOKEN_DEPOSITS]: `Incorrect token deposits account`,
     [BRIDGE_ERROR__INCORRECT_TOKEN_VAULT]: `Incorrect token vault`,
+    [BRIDGE_ERROR__INCORRECT_VAULT_DECIMALS_ACCOUNT]: `Incorrect vault decimals account`,
+    [BRIDGE_ERROR__INCORRECT_WRAPPED_SUPPLY_CAP_ACCOUNT]: `Incorrect wrapped token supply cap account`,
+    [BRIDGE_ERROR__INCORRECT_WRAPPED_TOKEN_CONFIRMATION]: `Incorrect wrapped token confirmation account`,
+    [BRIDGE_ERROR__INEXACT_SCALED_AMOUNT]: `Remote amount is not a multiple of the wrapped token scalar`,
     [BRIDGE_ERROR__INSUFFICIENT_BASE_SIGNATURES]: `Insufficient base oracle signatures to meet threshold`,
+    [BRIDGE_ERROR__INSUFFICIENT_COMPUTE_BUDGET]: `Remaining compute units are below the amount required by the message`,
+    [BRIDGE_ERROR__INSUFFICIENT_INSURANCE_FUND]: `Insurance fund balance is insufficient for the payout`,
+    [BRIDGE_ERROR__INSUFFICIENT_OPERATIONAL_VAULT]: `Operational vault balance is insufficient for the lamport allowance`,
     [BRIDGE_ERROR__INSUFFICIENT_PARTNER_SIGNATURES]: `Insufficient partner oracle signatures to meet threshold`,
     [BRIDGE_ERROR__INSUFFICIENT_PROOF_ELEMENTS_FOR_INTRA_MOUNTAIN_PATH]: `Insufficient proof elements for intra-mountain path`,
     [BRIDGE_ERROR__INSUFFICIENT_PROOF_ELEMENTS_FOR_OTHER_MOUNTAIN_PEAKS]: `Insufficient proof elements for other mountain peaks`,
+    [BRIDGE_ERROR__INSURANCE_PAYOUT_PENDING]: `An insurance payout is already pending`,
+    [BRIDGE_ERROR__INSURANCE_PAYOUT_TIMELOCKED]: `Insurance payout timelock has not elapsed`,
     [BRIDGE_ERROR__INVALID_BLOCK_INTERVAL_REQUIREMENT]: `Invalid block interval requirement`,
+    [BRIDGE_ERROR__INVALID_BRIDGE_LAYOUT]: `Unrecognized bridge account layout`,
     [BRIDGE_ERROR__INVALID_DENOMINATOR]: `Invalid denominator`,
+    [BRIDGE_ERROR__INVALID_EMERGENCY_DURATION]: `Emergency duration must be > 0 when the oracle failover is enabled`,
+    [BRIDGE_ERROR__INVALID_EMERGENCY_THRESHOLD]: `Emergency threshold must be > 0 and <= number of Base oracle and emergency signers`,
+    [BRIDGE_ERROR__INVALID_EXPIRE_BOUNTY_BPS]: `Expire bounty must be <= 10000 bps`,
+    [BRIDGE_ERROR__INVALID_FINALIZATION_THRESHOLD]: `Finalization delay thresholds must be set once per token`,
     [BRIDGE_ERROR__INVALID_GAS_COST_SCALER_DP]: `Invalid gas cost scaler dp`,
+    [BRIDGE_ERROR__INVALID_GAS_FEE_RECEIVER]: `Gas fee receiver must be a system account`,
+    [BRIDGE_ERROR__INVALID_GAS_TARGET]: `Invalid gas target`,
+    [BRIDGE_ERROR__INVALID_INSURANCE_SHARE]: `Insurance share exceeds the maximum`,
+    [BRIDGE_ERROR__INVALID_IX_PROGRAM_ID]: `Message instruction targets a program that cannot be invoked`,
+    [BRIDGE_ERROR__INVALID_MAINTENANCE_FEE_REIMBURSEMENT]: `Maintenance fee reimbursement exceeds the maximum`,
+    [BRIDGE_ERROR__INVALID_MAX_BASE_FEE]: `Max base fee must be >= minimum base fee`,
+    [BRIDGE_ERROR__INVALID_MAX_LAMPORT_ALLOWANCE]: `Maximum lamport allowance exceeds the limit`,
     [BRIDGE_ERROR__INVALID_MESSAGE_HASH]: `Invalid message hash`,
+    [BRIDGE_ERROR__INVALID_OUTFLOW_THRESHOLD]: `Outflow thresholds must be > 0 and set once per token`,
     [BRIDGE_ERROR__INVALID_PARTNER_THRESHOLD]: `Invalid partner threshold`,
     [BRIDGE_ERROR__INVALID_PROOF]: `Invalid proof`,
+    [BRIDGE_ERROR__INVALID_PROTOCOL_FEE_BPS]: `Protocol fee must be <= MAX_PROTOCOL_FEE_BPS`,
     [BRIDGE_ERROR__INVALID_RECOVERY_ID]: `Invalid recovery ID`,
+    [BRIDGE_ERROR__INVALID_RELAYED_NONCE]: `Relayed nonce is outside of the page or not yet assigned`,
+    [BRIDGE_ERROR__INVALID_RELAY_FEE_SETTLEMENT_NONCE]: `Relay fee settlement nonce must follow the latest settlement`,
+    [BRIDGE_ERROR__INVALID_REMOTE_DECIMALS]: `Remote decimals cannot exceed the mint decimals`,
     [BRIDGE_ERROR__INVALID_REMOTE_TOKEN]: `Invalid remote token`,
     [BRIDGE_ERROR__INVALID_SCALER_EXPONENT]: `Invalid scaler exponent`,
+    [BRIDGE_ERROR__INVALID_SECP256K1_INSTRUCTION]: `Invalid Secp256k1 program instruction`,
+    [BRIDGE_ERROR__INVALID_SESSION_EXPIRY]: `Bridge session expiry must be in the future`,
+    [BRIDGE_ERROR__INVALID_SWAP_GUARD_MIN_OUT]: `Swap guard minimum output must be non-zero`,
     [BRIDGE_ERROR__INVALID_THRESHOLD]: `Threshold must be <= number of signers`,
+    [BRIDGE_ERROR__INVALID_TOKEN_METADATA_CHARACTER]: `Token name or symbol contains a disallowed character`,
     [BRIDGE_ERROR__INVALID_WINDOW_DURATION_SECONDS]: `Invalid window duration seconds`,
+    [BRIDGE_ERROR__LAMPORT_ALLOWANCE_TOO_HIGH]: `Lamport allowance exceeds the maximum allowed by the operational vault`,
     [BRIDGE_ERROR__LEAF_MOUNTAIN_NOT_FOUND]: `Leaf's mountain not found`,
+    [BRIDGE_ERROR__MESSAGE_ALREADY_RELAYED]: `Outgoing message has been relayed on Base`,
+    [BRIDGE_ERROR__MESSAGE_IXS_DATA_TOO_LONG]: `Message instruction data too long`,
+    [BRIDGE_ERROR__MESSAGE_NOT_COVERED_BY_OUTPUT_ROOT]: `Output root does not cover the message nonce`,
+    [BRIDGE_ERROR__MESSAGE_NOT_IN_FINALIZATION_DELAY]: `Message is not held by the finalization delay`,
+    [BRIDGE_ERROR__MESSAGE_NOT_RELAYED]: `Outgoing message has not been relayed on Base`,
+    [BRIDGE_ERROR__MESSAGE_VETOED]: `Message was vetoed by the guardian`,
     [BRIDGE_ERROR__MINT_DOES_NOT_MATCH_LOCAL_TOKEN]: `Mint does not match local token`,
     [BRIDGE_ERROR__MINT_IS_NOT_FROM_TOKEN2022]: `Mint is not a token 2022 mint`,
     [BRIDGE_ERROR__MINT_IS_NOT_WRAPPED_TOKEN_PDA]: `Mint is not a valid wrapped token PDA`,
     [BRIDGE_ERROR__MINT_IS_WRAPPED_TOKEN]: `Mint is a wrapped token`,
+    [BRIDGE_ERROR__MINT_NOT_ALLOW_LISTED]: `Mint is not on the allow-list during guarded launch`,
+    [BRIDGE_ERROR__MINT_NOT_OWNED_BY_TOKEN_PROGRAM]: `Mint is not owned by the token program`,
+    [BRIDGE_ERROR__MISSING_ADDRESS_BOOK]: `Address book account is required to prove this message`,
+    [BRIDGE_ERROR__MISSING_DEX_ROUTER_LIST]: `DEX router list required to check the call`,
+    [BRIDGE_ERROR__MISSING_INSTRUCTIONS_SYSVAR]: `Instructions sysvar is required to verify signatures through the Secp256k1 program`,
+    [BRIDGE_ERROR__MISSING_LAMPORT_ALLOWANCE_ACCOUNTS]: `Operational vault and bridge CPI authority accounts are required to relay this message`,
+    [BRIDGE_ERROR__MISSING_MEMO_PROGRAM]: `SPL Memo program is required to log an SPL memo`,
+    [BRIDGE_ERROR__MISSING_METAPLEX_PROGRAM]: `Metaplex program is required to create Metaplex metadata`,
+    [BRIDGE_ERROR__MISSING_RELAY_FEE_ACCOUNTS]: `Relay fee vault and relayer accounts are required to relay this message`,
+    [BRIDGE_ERROR__MISSING_SWAP_GUARD]: `Calls to a registered DEX router require a swap guard`,
     [BRIDGE_ERROR__MMR_SHOULD_BE_EMPTY]: `MMR should be empty`,
+    [BRIDGE_ERROR__NESTED_COMPUTE_BUDGET]: `Compute budget messages cannot be nested`,
+    [BRIDGE_ERROR__NESTED_LAMPORT_ALLOWANCE]: `Lamport allowance messages cannot wrap other wrapper messages`,
+    [BRIDGE_ERROR__NESTED_RELAY_FEE]: `Relay fee messages cannot wrap compute budget or relay fee messages`,
+    [BRIDGE_ERROR__NO_EQUIVOCATING_SIGNER]: `No Base oracle signer attested both output roots`,
+    [BRIDGE_ERROR__NO_LOCKED_TOKENS]: `Outgoing message did not lock SOL or SPL tokens`,
     [BRIDGE_ERROR__NO_PEAKS_FOUND_FOR_NON_EMPTY_MMR]: `No peaks found for non-empty MMR`,
+    [BRIDGE_ERROR__NO_PENDING_INSURANCE_PAYOUT]: `No insurance payout is pending`,
+    [BRIDGE_ERROR__ORACLE_NOT_STALLED]: `Output roots have not stalled long enough to activate the emergency quorum`,
+    [BRIDGE_ERROR__OUTBOX_FULL]: `Outbox MMR is full`,
+    [BRIDGE_ERROR__OUTFLOW_APPROVAL_REQUIRED]: `Message tripped the outflow circuit breaker and awaits the guardian approval`,
+    [BRIDGE_ERROR__OUTFLOW_NOT_HELD]: `Message is not held by the outflow circuit breaker`,
+    [BRIDGE_ERROR__OUTPUT_ROOTS_NOT_CONFLICTING]: `Output roots are not conflicting`,
+    [BRIDGE_ERROR__OUTPUT_ROOT_CHALLENGE_WINDOW_CLOSED]: `Output root challenge window has closed`,
+    [BRIDGE_ERROR__OUTPUT_ROOT_NOT_SUPERSEDED]: `Message was proven against the current output root revision`,
+    [BRIDGE_ERROR__PAYER_RATE_LIMITED]: `Payer exceeded its outgoing message rate limit`,
+    [BRIDGE_ERROR__PROTOCOL_FEE_EXCEEDS_AMOUNT]: `Protocol fee exceeds the bridged amount`,
+    [BRIDGE_ERROR__PROVEN_CHUNK_TOO_LARGE]: `Message instruction does not fit in a proven chunk`,
     [BRIDGE_ERROR__REMOTE_TOKEN_NOT_FOUND]: `Remote token not found`,
+    [BRIDGE_ERROR__SCALED_AMOUNT_OVERFLOW]: `Scaled wrapped token amount overflows`,
     [BRIDGE_ERROR__SCALER_EXPONENT_NOT_FOUND]: `Scaler exponent not found`,
+    [BRIDGE_ERROR__SESSION_BUDGET_EXCEEDED]: `Amount exceeds the remaining bridge session budget`,
+    [BRIDGE_ERROR__SESSION_EXPIRED]: `Bridge session has expired`,
+    [BRIDGE_ERROR__SESSION_REMOTE_TOKEN_NOT_ALLOWED]: `Bridge session does not allow this remote token`,
     [BRIDGE_ERROR__SIGNATURE_VERIFICATION_FAILED]: `Signature verification failed`,
+    [BRIDGE_ERROR__STALE_LIABILITY_ROOT]: `Liability root must be for a newer Base block`,
+    [BRIDGE_ERROR__STALE_OUTPUT_ROOT]: `Output root is older than the freshness window of the message kind`,
+    [BRIDGE_ERROR__SUPERSEDED_OUTPUT_ROOT]: `Message was proven against a superseded output root revision`,
+    [BRIDGE_ERROR__SWAP_GUARD_EXPIRED]: `Swap guard deadline has passed`,
+    [BRIDGE_ERROR__SWAP_GUARD_WITHOUT_CALL]: `Swap guard requires a call`,
     [BRIDGE_ERROR__TOKEN_ACCOUNT_DOES_NOT_MATCH_TO]: `Token account does not match to address`,
+    [BRIDGE_ERROR__TOKEN_NAME_TOO_LONG]: `Token name exceeds MAX_WRAPPED_TOKEN_NAME_LEN`,
+    [BRIDGE_ERROR__TOKEN_SYMBOL_TOO_LONG]: `Token symbol exceeds MAX_WRAPPED_TOKEN_SYMBOL_LEN`,
+    [BRIDGE_ERROR__TOO_MANY_ADDRESS_BOOK_TOKENS]: `Too many address book tokens`,
+    [BRIDGE_ERROR__TOO_MANY_ALLOWED_FREEZE_AUTHORITIES]: `Too many allowed freeze authorities`,
+    [BRIDGE_ERROR__TOO_MANY_ALLOWED_MINTS]: `Too many allowed mints`,
+    [BRIDGE_ERROR__TOO_MANY_BATCH_RECIPIENTS]: `Transfer batch exceeds MAX_TRANSFER_BATCH_RECIPIENTS`,
+    [BRIDGE_ERROR__TOO_MANY_DENIED_RELAY_PROGRAMS]: `Too many denied relay programs`,
+    [BRIDGE_ERROR__TOO_MANY_DEX_ROUTERS]: `Too many DEX routers`,
+    [BRIDGE_ERROR__TOO_MANY_EMERGENCY_SIGNERS]: `Too many emergency signers`,
+    [BRIDGE_ERROR__TOO_MANY_EXTRA_SIGNERS]: `Too many extra signers`,
+    [BRIDGE_ERROR__TOO_MANY_EXTRA_SIGNER_SEEDS]: `Too many seeds for an extra signer`,
+    [BRIDGE_ERROR__TOO_MANY_FINALIZATION_THRESHOLDS]: `Too many finalization delay thresholds`,
+    [BRIDGE_ERROR__TOO_MANY_IX_ACCOUNTS]: `Too many accounts in message instruction`,
+    [BRIDGE_ERROR__TOO_MANY_MESSAGE_IXS]: `Too many instructions in message`,
+    [BRIDGE_ERROR__TOO_MANY_OUTFLOW_THRESHOLDS]: `Too many outflow thresholds`,
+    [BRIDGE_ERROR__TOO_MANY_OUTPUT_ROOTS]: `Too many output roots in batch`,
+    [BRIDGE_ERROR__TOO_MANY_PROTOCOL_FEE_OVERRIDES]: `Too many protocol fee overrides`,
+    [BRIDGE_ERROR__TOO_MANY_PROVEN_CHUNKS]: `Message instructions span too many proven chunks`,
+    [BRIDGE_ERROR__TOO_MANY_RATE_LIMIT_EXEMPT_PAYERS]: `Too many payers exempted from rate limiting`,
+    [BRIDGE_ERROR__TOO_MANY_SESSION_REMOTE_TOKENS]: `Too many bridge session remote tokens`,
+    [BRIDGE_ERROR__TOO_MANY_SIGNATURES]: `Too many signatures (max 32)`,
     [BRIDGE_ERROR__TOO_MANY_SIGNERS]: `Too many signers (max 32)`,
+    [BRIDGE_ERROR__TRANSFER_ALREADY_RETURNED]: `Transfer already returned`,
+    [BRIDGE_ERROR__TRANSFER_MEMO_TOO_LONG]: `Transfer memo exceeds MAX_TRANSFER_MEMO_LEN`,
     [BRIDGE_ERROR__UNAUTHORIZED_CONFIG_UPDATE]: `Unauthorized to update configuration`,
+    [BRIDGE_ERROR__UNAUTHORIZED_CROSSCHAIN_MINT]: `Crosschain mints can only be requested by the Base Bridge`,
+    [BRIDGE_ERROR__UNAUTHORIZED_HANDLER_REGISTRATION]: `Handlers can only be registered by their Base sender`,
     [BRIDGE_ERROR__UNAUTHORIZED_INITIALIZATION]: `Only the upgrade authority can initialize the bridge`,
+    [BRIDGE_ERROR__UNAUTHORIZED_RETURN_TRANSFER]: `Return transfers can only be sent by the Base Bridge`,
+    [BRIDGE_ERROR__UNAUTHORIZED_SUPPLY_CAP_UPDATE]: `Wrapped token max supplies can only be set by the Base Bridge`,
+    [BRIDGE_ERROR__UNAUTHORIZED_WRAPPED_TOKEN_CONFIRMATION]: `Wrapped token registrations can only be confirmed by the Base Bridge`,
+    [BRIDGE_ERROR__UNTRIMMED_TOKEN_METADATA]: `Token name and symbol must not start or end with whitespace`,
     [BRIDGE_ERROR__UNUSED_PROOF_ELEMENTS_REMAINING]: `Unused proof elements remaining`,
+    [BRIDGE_ERROR__VAULT_HAS_DEPOSITS]: `Vault decimals cannot change while the vault holds deposits`,
+    [BRIDGE_ERROR__WRAPPED_SUPPLY_CAP_EXCEEDED]: `Mint would exceed the wrapped token max supply`,
+    [BRIDGE_ERROR__WRAPPED_TOKEN_METADATA_MISMATCH]: `Confirmed metadata does not match the wrapped token`,
+    [BRIDGE_ERROR__WRAPPED_TOKEN_NOT_CONFIRMED]: `Wrapped token registration has not been confirmed by Base`,
     [BRIDGE_ERROR__ZERO_ADDRESS]: `Zero address`,
   };
 }
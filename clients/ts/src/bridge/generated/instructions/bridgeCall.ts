@@ -10,6 +10,8 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
@@ -53,11 +55,16 @@ export type BridgeCallInstruction<
   TAccountPayer extends string | AccountMeta<string> = string,
   TAccountFrom extends string | AccountMeta<string> = string,
   TAccountGasFeeReceiver extends string | AccountMeta<string> = string,
+  TAccountGasFeeEscrow extends string | AccountMeta<string> = string,
+  TAccountInsuranceFund extends string | AccountMeta<string> = string,
+  TAccountPayerRateLimitConfig extends string | AccountMeta<string> = string,
+  TAccountPayerRateLimit extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountOutgoingMessage extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountMemoProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -73,6 +80,18 @@ export type BridgeCallInstruction<
       TAccountGasFeeReceiver extends string
         ? WritableAccount<TAccountGasFeeReceiver>
         : TAccountGasFeeReceiver,
+      TAccountGasFeeEscrow extends string
+        ? WritableAccount<TAccountGasFeeEscrow>
+        : TAccountGasFeeEscrow,
+      TAccountInsuranceFund extends string
+        ? WritableAccount<TAccountInsuranceFund>
+        : TAccountInsuranceFund,
+      TAccountPayerRateLimitConfig extends string
+        ? ReadonlyAccount<TAccountPayerRateLimitConfig>
+        : TAccountPayerRateLimitConfig,
+      TAccountPayerRateLimit extends string
+        ? WritableAccount<TAccountPayerRateLimit>
+        : TAccountPayerRateLimit,
       TAccountBridge extends string
         ? WritableAccount<TAccountBridge>
         : TAccountBridge,
@@ -82,6 +101,9 @@ export type BridgeCallInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountMemoProgram extends string
+        ? ReadonlyAccount<TAccountMemoProgram>
+        : TAccountMemoProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -90,11 +112,13 @@ export type BridgeCallInstructionData = {
   discriminator: ReadonlyUint8Array;
   outgoingMessageSalt: ReadonlyUint8Array;
   call: Call;
+  splMemo: boolean;
 };
 
 export type BridgeCallInstructionDataArgs = {
   outgoingMessageSalt: ReadonlyUint8Array;
   call: CallArgs;
+  splMemo: boolean;
 };
 
 export function getBridgeCallInstructionDataEncoder(): Encoder<BridgeCallInstructionDataArgs> {
@@ -103,6 +127,7 @@ export function getBridgeCallInstructionDataEncoder(): Encoder<BridgeCallInstruc
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['outgoingMessageSalt', fixEncoderSize(getBytesEncoder(), 32)],
       ['call', getCallEncoder()],
+      ['splMemo', getBooleanEncoder()],
     ]),
     (value) => ({ ...value, discriminator: BRIDGE_CALL_DISCRIMINATOR })
   );
@@ -113,6 +138,7 @@ export function getBridgeCallInstructionDataDecoder(): Decoder<BridgeCallInstruc
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['outgoingMessageSalt', fixDecoderSize(getBytesDecoder(), 32)],
     ['call', getCallDecoder()],
+    ['splMemo', getBooleanDecoder()],
   ]);
 }
 
@@ -130,9 +156,14 @@ export type BridgeCallInput<
   TAccountPayer extends string = string,
   TAccountFrom extends string = string,
   TAccountGasFeeReceiver extends string = string,
+  TAccountGasFeeEscrow extends string = string,
+  TAccountInsuranceFund extends string = string,
+  TAccountPayerRateLimitConfig extends string = string,
+  TAccountPayerRateLimit extends string = string,
   TAccountBridge extends string = string,
   TAccountOutgoingMessage extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountMemoProgram extends string = string,
 > = {
   /**
    * The account that pays for the transaction fees and outgoing message account creation.
@@ -146,6 +177,20 @@ export type BridgeCallInput<
   from: TransactionSigner<TAccountFrom>;
   /** The account that receives payment for the gas costs of bridging the call to Base. */
   gasFeeReceiver: Address<TAccountGasFeeReceiver>;
+  /** Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited. */
+  gasFeeEscrow: Address<TAccountGasFeeEscrow>;
+  /**
+   * Insurance fund credited with its share of the gas fee. No share is taken while it is
+   * uninitialized.
+   */
+  insuranceFund: Address<TAccountInsuranceFund>;
+  /** Payer rate limiting configuration. Payers are not rate limited while it is uninitialized. */
+  payerRateLimitConfig: Address<TAccountPayerRateLimitConfig>;
+  /**
+   * Outgoing messages paid for by the payer in the current rate limiting window, created on its
+   * first message while rate limiting is enabled.
+   */
+  payerRateLimit: Address<TAccountPayerRateLimit>;
   /**
    * The main bridge state account containing global bridge configuration.
    * - Uses PDA with BRIDGE_SEED for deterministic address
@@ -157,8 +202,8 @@ export type BridgeCallInput<
    * The outgoing message account that stores the cross-chain call data.
    * - Created fresh for each bridge call seeded by a client-provided salt
    * - Payer funds the account creation
-   * - Space is DISCRIMINATOR_LEN + OutgoingMessage::space(...)` and is sized using
-   * the worst-case message variant to ensure sufficient capacity even for large payloads
+   * - Space is `DISCRIMINATOR_LEN + OutgoingMessage::space(...)`, sized exactly for the call
+   * (an encrypted call serializes to the same size)
    * - Contains all information needed for execution on Base
    */
   outgoingMessage: Address<TAccountOutgoingMessage>;
@@ -167,26 +212,39 @@ export type BridgeCallInput<
    * Used internally by Anchor for account initialization.
    */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** SPL Memo program, required when `spl_memo` is set to log a summary of the operation. */
+  memoProgram?: Address<TAccountMemoProgram>;
   outgoingMessageSalt: BridgeCallInstructionDataArgs['outgoingMessageSalt'];
   call: BridgeCallInstructionDataArgs['call'];
+  splMemo: BridgeCallInstructionDataArgs['splMemo'];
 };
 
 export function getBridgeCallInstruction<
   TAccountPayer extends string,
   TAccountFrom extends string,
   TAccountGasFeeReceiver extends string,
+  TAccountGasFeeEscrow extends string,
+  TAccountInsuranceFund extends string,
+  TAccountPayerRateLimitConfig extends string,
+  TAccountPayerRateLimit extends string,
   TAccountBridge extends string,
   TAccountOutgoingMessage extends string,
   TAccountSystemProgram extends string,
+  TAccountMemoProgram extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
   input: BridgeCallInput<
     TAccountPayer,
     TAccountFrom,
     TAccountGasFeeReceiver,
+    TAccountGasFeeEscrow,
+    TAccountInsuranceFund,
+    TAccountPayerRateLimitConfig,
+    TAccountPayerRateLimit,
     TAccountBridge,
     TAccountOutgoingMessage,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMemoProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): BridgeCallInstruction<
@@ -194,9 +252,14 @@ export function getBridgeCallInstruction<
   TAccountPayer,
   TAccountFrom,
   TAccountGasFeeReceiver,
+  TAccountGasFeeEscrow,
+  TAccountInsuranceFund,
+  TAccountPayerRateLimitConfig,
+  TAccountPayerRateLimit,
   TAccountBridge,
   TAccountOutgoingMessage,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountMemoProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -206,9 +269,17 @@ export function getBridgeCallInstruction<
     payer: { value: input.payer ?? null, isWritable: true },
     from: { value: input.from ?? null, isWritable: false },
     gasFeeReceiver: { value: input.gasFeeReceiver ?? null, isWritable: true },
+    gasFeeEscrow: { value: input.gasFeeEscrow ?? null, isWritable: true },
+    insuranceFund: { value: input.insuranceFund ?? null, isWritable: true },
+    payerRateLimitConfig: {
+      value: input.payerRateLimitConfig ?? null,
+      isWritable: false,
+    },
+    payerRateLimit: { value: input.payerRateLimit ?? null, isWritable: true },
     bridge: { value: input.bridge ?? null, isWritable: true },
     outgoingMessage: { value: input.outgoingMessage ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    memoProgram: { value: input.memoProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -230,9 +301,14 @@ export function getBridgeCallInstruction<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.from),
       getAccountMeta(accounts.gasFeeReceiver),
+      getAccountMeta(accounts.gasFeeEscrow),
+      getAccountMeta(accounts.insuranceFund),
+      getAccountMeta(accounts.payerRateLimitConfig),
+      getAccountMeta(accounts.payerRateLimit),
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.outgoingMessage),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.memoProgram),
     ],
     data: getBridgeCallInstructionDataEncoder().encode(
       args as BridgeCallInstructionDataArgs
@@ -243,9 +319,14 @@ export function getBridgeCallInstruction<
     TAccountPayer,
     TAccountFrom,
     TAccountGasFeeReceiver,
+    TAccountGasFeeEscrow,
+    TAccountInsuranceFund,
+    TAccountPayerRateLimitConfig,
+    TAccountPayerRateLimit,
     TAccountBridge,
     TAccountOutgoingMessage,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMemoProgram
   >);
 }
 
@@ -267,27 +348,43 @@ export type ParsedBridgeCallInstruction<
     from: TAccountMetas[1];
     /** The account that receives payment for the gas costs of bridging the call to Base. */
     gasFeeReceiver: TAccountMetas[2];
+    /** Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited. */
+    gasFeeEscrow: TAccountMetas[3];
+    /**
+     * Insurance fund credited with its share of the gas fee. No share is taken while it is
+     * uninitialized.
+     */
+    insuranceFund: TAccountMetas[4];
+    /** Payer rate limiting configuration. Payers are not rate limited while it is uninitialized. */
+    payerRateLimitConfig: TAccountMetas[5];
+    /**
+     * Outgoing messages paid for by the payer in the current rate limiting window, created on its
+     * first message while rate limiting is enabled.
+     */
+    payerRateLimit: TAccountMetas[6];
     /**
      * The main bridge state account containing global bridge configuration.
      * - Uses PDA with BRIDGE_SEED for deterministic address
      * - Mutable to increment the nonce and update EIP-1559 gas pricing
      * - Provides the current nonce for message ordering
      */
-    bridge: TAccountMetas[3];
+    bridge: TAccountMetas[7];
     /**
      * The outgoing message account that stores the cross-chain call data.
      * - Created fresh for each bridge call seeded by a client-provided salt
      * - Payer funds the account creation
-     * - Space is DISCRIMINATOR_LEN + OutgoingMessage::space(...)` and is sized using
-     * the worst-case message variant to ensure sufficient capacity even for large payloads
+     * - Space is `DISCRIMINATOR_LEN + OutgoingMessage::space(...)`, sized exactly for the call
+     * (an encrypted call serializes to the same size)
      * - Contains all information needed for execution on Base
      */
-    outgoingMessage: TAccountMetas[4];
+    outgoingMessage: TAccountMetas[8];
     /**
      * System program required for creating the outgoing message account.
      * Used internally by Anchor for account initialization.
      */
-    systemProgram: TAccountMetas[5];
+    systemProgram: TAccountMetas[9];
+    /** SPL Memo program, required when `spl_memo` is set to log a summary of the operation. */
+    memoProgram?: TAccountMetas[10] | undefined;
   };
   data: BridgeCallInstructionData;
 };
@@ -300,7 +397,7 @@ export function parseBridgeCallInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedBridgeCallInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 11) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -310,15 +407,26 @@ export function parseBridgeCallInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === BRIDGE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       payer: getNextAccount(),
       from: getNextAccount(),
       gasFeeReceiver: getNextAccount(),
+      gasFeeEscrow: getNextAccount(),
+      insuranceFund: getNextAccount(),
+      payerRateLimitConfig: getNextAccount(),
+      payerRateLimit: getNextAccount(),
       bridge: getNextAccount(),
       outgoingMessage: getNextAccount(),
       systemProgram: getNextAccount(),
+      memoProgram: getNextOptionalAccount(),
     },
     data: getBridgeCallInstructionDataDecoder().decode(instruction.data),
   };
@@ -10,6 +10,8 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
@@ -49,6 +51,10 @@ export type BridgeCallBufferedInstruction<
   TAccountPayer extends string | AccountMeta<string> = string,
   TAccountFrom extends string | AccountMeta<string> = string,
   TAccountGasFeeReceiver extends string | AccountMeta<string> = string,
+  TAccountGasFeeEscrow extends string | AccountMeta<string> = string,
+  TAccountInsuranceFund extends string | AccountMeta<string> = string,
+  TAccountPayerRateLimitConfig extends string | AccountMeta<string> = string,
+  TAccountPayerRateLimit extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountOwner extends string | AccountMeta<string> = string,
   TAccountCallBuffer extends string | AccountMeta<string> = string,
@@ -56,6 +62,7 @@ export type BridgeCallBufferedInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountMemoProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -71,6 +78,18 @@ export type BridgeCallBufferedInstruction<
       TAccountGasFeeReceiver extends string
         ? WritableAccount<TAccountGasFeeReceiver>
         : TAccountGasFeeReceiver,
+      TAccountGasFeeEscrow extends string
+        ? WritableAccount<TAccountGasFeeEscrow>
+        : TAccountGasFeeEscrow,
+      TAccountInsuranceFund extends string
+        ? WritableAccount<TAccountInsuranceFund>
+        : TAccountInsuranceFund,
+      TAccountPayerRateLimitConfig extends string
+        ? ReadonlyAccount<TAccountPayerRateLimitConfig>
+        : TAccountPayerRateLimitConfig,
+      TAccountPayerRateLimit extends string
+        ? WritableAccount<TAccountPayerRateLimit>
+        : TAccountPayerRateLimit,
       TAccountBridge extends string
         ? WritableAccount<TAccountBridge>
         : TAccountBridge,
@@ -87,6 +106,9 @@ export type BridgeCallBufferedInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountMemoProgram extends string
+        ? ReadonlyAccount<TAccountMemoProgram>
+        : TAccountMemoProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -94,10 +116,12 @@ export type BridgeCallBufferedInstruction<
 export type BridgeCallBufferedInstructionData = {
   discriminator: ReadonlyUint8Array;
   outgoingMessageSalt: ReadonlyUint8Array;
+  splMemo: boolean;
 };
 
 export type BridgeCallBufferedInstructionDataArgs = {
   outgoingMessageSalt: ReadonlyUint8Array;
+  splMemo: boolean;
 };
 
 export function getBridgeCallBufferedInstructionDataEncoder(): FixedSizeEncoder<BridgeCallBufferedInstructionDataArgs> {
@@ -105,6 +129,7 @@ export function getBridgeCallBufferedInstructionDataEncoder(): FixedSizeEncoder<
     getStructEncoder([
       ['discriminator', fixEncoderSize(getBytesEncoder(), 8)],
       ['outgoingMessageSalt', fixEncoderSize(getBytesEncoder(), 32)],
+      ['splMemo', getBooleanEncoder()],
     ]),
     (value) => ({ ...value, discriminator: BRIDGE_CALL_BUFFERED_DISCRIMINATOR })
   );
@@ -114,6 +139,7 @@ export function getBridgeCallBufferedInstructionDataDecoder(): FixedSizeDecoder<
   return getStructDecoder([
     ['discriminator', fixDecoderSize(getBytesDecoder(), 8)],
     ['outgoingMessageSalt', fixDecoderSize(getBytesDecoder(), 32)],
+    ['splMemo', getBooleanDecoder()],
   ]);
 }
 
@@ -131,11 +157,16 @@ export type BridgeCallBufferedInput<
   TAccountPayer extends string = string,
   TAccountFrom extends string = string,
   TAccountGasFeeReceiver extends string = string,
+  TAccountGasFeeEscrow extends string = string,
+  TAccountInsuranceFund extends string = string,
+  TAccountPayerRateLimitConfig extends string = string,
+  TAccountPayerRateLimit extends string = string,
   TAccountBridge extends string = string,
   TAccountOwner extends string = string,
   TAccountCallBuffer extends string = string,
   TAccountOutgoingMessage extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountMemoProgram extends string = string,
 > = {
   /**
    * The account that pays for outgoing message account creation and the gas fee.
@@ -149,6 +180,20 @@ export type BridgeCallBufferedInput<
   from: TransactionSigner<TAccountFrom>;
   /** The account that receives payment for the gas costs of bridging the call to Base. */
   gasFeeReceiver: Address<TAccountGasFeeReceiver>;
+  /** Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited. */
+  gasFeeEscrow: Address<TAccountGasFeeEscrow>;
+  /**
+   * Insurance fund credited with its share of the gas fee. No share is taken while it is
+   * uninitialized.
+   */
+  insuranceFund: Address<TAccountInsuranceFund>;
+  /** Payer rate limiting configuration. Payers are not rate limited while it is uninitialized. */
+  payerRateLimitConfig: Address<TAccountPayerRateLimitConfig>;
+  /**
+   * Outgoing messages paid for by the payer in the current rate limiting window, created on its
+   * first message while rate limiting is enabled.
+   */
+  payerRateLimit: Address<TAccountPayerRateLimit>;
   /**
    * The main bridge state account containing global configuration and runtime state.
    * - PDA with `BRIDGE_SEED`
@@ -161,7 +206,7 @@ export type BridgeCallBufferedInput<
   /**
    * The call buffer account that stores the call parameters and data.
    * Its contents are copied into the outgoing message. The account is then
-   * closed by Anchor (via `close = owner`), refunding its rent to `owner`.
+   * closed, refunding its rent to `owner`, unless it is reusable.
    */
   callBuffer: Address<TAccountCallBuffer>;
   /**
@@ -169,9 +214,7 @@ export type BridgeCallBufferedInput<
    * - Created fresh for each call; the provided keypair determines its address
    * - Funded by `payer`
    * - Space: DISCRIMINATOR_LEN + serialized `OutgoingMessage`
-   * Sizing uses `OutgoingMessage::space(Some(call_buffer.data.len()))`, which
-   * intentionally allocates for the Transfer variant (worst case) to safely
-   * cover the Call variant
+   * Sizing uses `OutgoingMessage::space` for a call of `call_buffer.data.len()` bytes
    * - Includes `nonce` and `sender` metadata used on Base
    */
   outgoingMessage: Address<TAccountOutgoingMessage>;
@@ -180,29 +223,42 @@ export type BridgeCallBufferedInput<
    * Used internally by Anchor for account initialization.
    */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** SPL Memo program, required when `spl_memo` is set to log a summary of the operation. */
+  memoProgram?: Address<TAccountMemoProgram>;
   outgoingMessageSalt: BridgeCallBufferedInstructionDataArgs['outgoingMessageSalt'];
+  splMemo: BridgeCallBufferedInstructionDataArgs['splMemo'];
 };
 
 export function getBridgeCallBufferedInstruction<
   TAccountPayer extends string,
   TAccountFrom extends string,
   TAccountGasFeeReceiver extends string,
+  TAccountGasFeeEscrow extends string,
+  TAccountInsuranceFund extends string,
+  TAccountPayerRateLimitConfig extends string,
+  TAccountPayerRateLimit extends string,
   TAccountBridge extends string,
   TAccountOwner extends string,
   TAccountCallBuffer extends string,
   TAccountOutgoingMessage extends string,
   TAccountSystemProgram extends string,
+  TAccountMemoProgram extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
   input: BridgeCallBufferedInput<
     TAccountPayer,
     TAccountFrom,
     TAccountGasFeeReceiver,
+    TAccountGasFeeEscrow,
+    TAccountInsuranceFund,
+    TAccountPayerRateLimitConfig,
+    TAccountPayerRateLimit,
     TAccountBridge,
     TAccountOwner,
     TAccountCallBuffer,
     TAccountOutgoingMessage,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMemoProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): BridgeCallBufferedInstruction<
@@ -210,11 +266,16 @@ export function getBridgeCallBufferedInstruction<
   TAccountPayer,
   TAccountFrom,
   TAccountGasFeeReceiver,
+  TAccountGasFeeEscrow,
+  TAccountInsuranceFund,
+  TAccountPayerRateLimitConfig,
+  TAccountPayerRateLimit,
   TAccountBridge,
   TAccountOwner,
   TAccountCallBuffer,
   TAccountOutgoingMessage,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountMemoProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -224,11 +285,19 @@ export function getBridgeCallBufferedInstruction<
     payer: { value: input.payer ?? null, isWritable: true },
     from: { value: input.from ?? null, isWritable: false },
     gasFeeReceiver: { value: input.gasFeeReceiver ?? null, isWritable: true },
+    gasFeeEscrow: { value: input.gasFeeEscrow ?? null, isWritable: true },
+    insuranceFund: { value: input.insuranceFund ?? null, isWritable: true },
+    payerRateLimitConfig: {
+      value: input.payerRateLimitConfig ?? null,
+      isWritable: false,
+    },
+    payerRateLimit: { value: input.payerRateLimit ?? null, isWritable: true },
     bridge: { value: input.bridge ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: true },
     callBuffer: { value: input.callBuffer ?? null, isWritable: true },
     outgoingMessage: { value: input.outgoingMessage ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    memoProgram: { value: input.memoProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -250,11 +319,16 @@ export function getBridgeCallBufferedInstruction<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.from),
       getAccountMeta(accounts.gasFeeReceiver),
+      getAccountMeta(accounts.gasFeeEscrow),
+      getAccountMeta(accounts.insuranceFund),
+      getAccountMeta(accounts.payerRateLimitConfig),
+      getAccountMeta(accounts.payerRateLimit),
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.callBuffer),
       getAccountMeta(accounts.outgoingMessage),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.memoProgram),
     ],
     data: getBridgeCallBufferedInstructionDataEncoder().encode(
       args as BridgeCallBufferedInstructionDataArgs
@@ -265,11 +339,16 @@ export function getBridgeCallBufferedInstruction<
     TAccountPayer,
     TAccountFrom,
     TAccountGasFeeReceiver,
+    TAccountGasFeeEscrow,
+    TAccountInsuranceFund,
+    TAccountPayerRateLimitConfig,
+    TAccountPayerRateLimit,
     TAccountBridge,
     TAccountOwner,
     TAccountCallBuffer,
     TAccountOutgoingMessage,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMemoProgram
   >);
 }
 
@@ -291,37 +370,51 @@ export type ParsedBridgeCallBufferedInstruction<
     from: TAccountMetas[1];
     /** The account that receives payment for the gas costs of bridging the call to Base. */
     gasFeeReceiver: TAccountMetas[2];
+    /** Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited. */
+    gasFeeEscrow: TAccountMetas[3];
+    /**
+     * Insurance fund credited with its share of the gas fee. No share is taken while it is
+     * uninitialized.
+     */
+    insuranceFund: TAccountMetas[4];
+    /** Payer rate limiting configuration. Payers are not rate limited while it is uninitialized. */
+    payerRateLimitConfig: TAccountMetas[5];
+    /**
+     * Outgoing messages paid for by the payer in the current rate limiting window, created on its
+     * first message while rate limiting is enabled.
+     */
+    payerRateLimit: TAccountMetas[6];
     /**
      * The main bridge state account containing global configuration and runtime state.
      * - PDA with `BRIDGE_SEED`
      * - Mutable to charge gas (EIP-1559 accounting) and increment the message nonce
      * - Provides the current nonce for message ordering
      */
-    bridge: TAccountMetas[3];
+    bridge: TAccountMetas[7];
     /** The owner of the call buffer who will receive the rent refund. */
-    owner: TAccountMetas[4];
+    owner: TAccountMetas[8];
     /**
      * The call buffer account that stores the call parameters and data.
      * Its contents are copied into the outgoing message. The account is then
-     * closed by Anchor (via `close = owner`), refunding its rent to `owner`.
+     * closed, refunding its rent to `owner`, unless it is reusable.
      */
-    callBuffer: TAccountMetas[5];
+    callBuffer: TAccountMetas[9];
     /**
      * The outgoing message account that stores the cross-chain message (header + payload).
      * - Created fresh for each call; the provided keypair determines its address
      * - Funded by `payer`
      * - Space: DISCRIMINATOR_LEN + serialized `OutgoingMessage`
-     * Sizing uses `OutgoingMessage::space(Some(call_buffer.data.len()))`, which
-     * intentionally allocates for the Transfer variant (worst case) to safely
-     * cover the Call variant
+     * Sizing uses `OutgoingMessage::space` for a call of `call_buffer.data.len()` bytes
      * - Includes `nonce` and `sender` metadata used on Base
      */
-    outgoingMessage: TAccountMetas[6];
+    outgoingMessage: TAccountMetas[10];
     /**
      * System program required for creating the outgoing message account.
      * Used internally by Anchor for account initialization.
      */
-    systemProgram: TAccountMetas[7];
+    systemProgram: TAccountMetas[11];
+    /** SPL Memo program, required when `spl_memo` is set to log a summary of the operation. */
+    memoProgram?: TAccountMetas[12] | undefined;
   };
   data: BridgeCallBufferedInstructionData;
 };
@@ -334,7 +427,7 @@ export function parseBridgeCallBufferedInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedBridgeCallBufferedInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 8) {
+  if (instruction.accounts.length < 13) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -344,17 +437,28 @@ export function parseBridgeCallBufferedInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === BRIDGE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       payer: getNextAccount(),
       from: getNextAccount(),
       gasFeeReceiver: getNextAccount(),
+      gasFeeEscrow: getNextAccount(),
+      insuranceFund: getNextAccount(),
+      payerRateLimitConfig: getNextAccount(),
+      payerRateLimit: getNextAccount(),
       bridge: getNextAccount(),
       owner: getNextAccount(),
       callBuffer: getNextAccount(),
       outgoingMessage: getNextAccount(),
       systemProgram: getNextAccount(),
+      memoProgram: getNextOptionalAccount(),
     },
     data: getBridgeCallBufferedInstructionDataDecoder().decode(
       instruction.data
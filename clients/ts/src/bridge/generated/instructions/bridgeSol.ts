@@ -7,15 +7,21 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getOptionDecoder,
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   transformEncoder,
@@ -41,8 +47,12 @@ import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 import {
   getCallDecoder,
   getCallEncoder,
+  getSwapGuardDecoder,
+  getSwapGuardEncoder,
   type Call,
   type CallArgs,
+  type SwapGuard,
+  type SwapGuardArgs,
 } from '../types';
 
 export const BRIDGE_SOL_DISCRIMINATOR = new Uint8Array([
@@ -58,12 +68,22 @@ export type BridgeSolInstruction<
   TAccountPayer extends string | AccountMeta<string> = string,
   TAccountFrom extends string | AccountMeta<string> = string,
   TAccountGasFeeReceiver extends string | AccountMeta<string> = string,
+  TAccountGasFeeEscrow extends string | AccountMeta<string> = string,
+  TAccountInsuranceFund extends string | AccountMeta<string> = string,
+  TAccountPayerRateLimitConfig extends string | AccountMeta<string> = string,
+  TAccountPayerRateLimit extends string | AccountMeta<string> = string,
+  TAccountProtocolFeeConfig extends string | AccountMeta<string> = string,
+  TAccountProtocolFeeTreasury extends string | AccountMeta<string> = string,
+  TAccountDexRouterList extends string | AccountMeta<string> = string,
+  TAccountSolVaultState extends string | AccountMeta<string> = string,
   TAccountSolVault extends string | AccountMeta<string> = string,
+  TAccountTokenDeposits extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountOutgoingMessage extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountMemoProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -79,9 +99,36 @@ export type BridgeSolInstruction<
       TAccountGasFeeReceiver extends string
         ? WritableAccount<TAccountGasFeeReceiver>
         : TAccountGasFeeReceiver,
+      TAccountGasFeeEscrow extends string
+        ? WritableAccount<TAccountGasFeeEscrow>
+        : TAccountGasFeeEscrow,
+      TAccountInsuranceFund extends string
+        ? WritableAccount<TAccountInsuranceFund>
+        : TAccountInsuranceFund,
+      TAccountPayerRateLimitConfig extends string
+        ? ReadonlyAccount<TAccountPayerRateLimitConfig>
+        : TAccountPayerRateLimitConfig,
+      TAccountPayerRateLimit extends string
+        ? WritableAccount<TAccountPayerRateLimit>
+        : TAccountPayerRateLimit,
+      TAccountProtocolFeeConfig extends string
+        ? ReadonlyAccount<TAccountProtocolFeeConfig>
+        : TAccountProtocolFeeConfig,
+      TAccountProtocolFeeTreasury extends string
+        ? WritableAccount<TAccountProtocolFeeTreasury>
+        : TAccountProtocolFeeTreasury,
+      TAccountDexRouterList extends string
+        ? ReadonlyAccount<TAccountDexRouterList>
+        : TAccountDexRouterList,
+      TAccountSolVaultState extends string
+        ? ReadonlyAccount<TAccountSolVaultState>
+        : TAccountSolVaultState,
       TAccountSolVault extends string
         ? WritableAccount<TAccountSolVault>
         : TAccountSolVault,
+      TAccountTokenDeposits extends string
+        ? WritableAccount<TAccountTokenDeposits>
+        : TAccountTokenDeposits,
       TAccountBridge extends string
         ? WritableAccount<TAccountBridge>
         : TAccountBridge,
@@ -91,6 +138,9 @@ export type BridgeSolInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountMemoProgram extends string
+        ? ReadonlyAccount<TAccountMemoProgram>
+        : TAccountMemoProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -101,6 +151,9 @@ export type BridgeSolInstructionData = {
   to: ReadonlyUint8Array;
   amount: bigint;
   call: Option<Call>;
+  memo: Option<ReadonlyUint8Array>;
+  swapGuard: Option<SwapGuard>;
+  splMemo: boolean;
 };
 
 export type BridgeSolInstructionDataArgs = {
@@ -108,6 +161,9 @@ export type BridgeSolInstructionDataArgs = {
   to: ReadonlyUint8Array;
   amount: number | bigint;
   call: OptionOrNullable<CallArgs>;
+  memo: OptionOrNullable<ReadonlyUint8Array>;
+  swapGuard: OptionOrNullable<SwapGuardArgs>;
+  splMemo: boolean;
 };
 
 export function getBridgeSolInstructionDataEncoder(): Encoder<BridgeSolInstructionDataArgs> {
@@ -118,6 +174,14 @@ export function getBridgeSolInstructionDataEncoder(): Encoder<BridgeSolInstructi
       ['to', fixEncoderSize(getBytesEncoder(), 20)],
       ['amount', getU64Encoder()],
       ['call', getOptionEncoder(getCallEncoder())],
+      [
+        'memo',
+        getOptionEncoder(
+          addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())
+        ),
+      ],
+      ['swapGuard', getOptionEncoder(getSwapGuardEncoder())],
+      ['splMemo', getBooleanEncoder()],
     ]),
     (value) => ({ ...value, discriminator: BRIDGE_SOL_DISCRIMINATOR })
   );
@@ -130,6 +194,14 @@ export function getBridgeSolInstructionDataDecoder(): Decoder<BridgeSolInstructi
     ['to', fixDecoderSize(getBytesDecoder(), 20)],
     ['amount', getU64Decoder()],
     ['call', getOptionDecoder(getCallDecoder())],
+    [
+      'memo',
+      getOptionDecoder(
+        addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())
+      ),
+    ],
+    ['swapGuard', getOptionDecoder(getSwapGuardDecoder())],
+    ['splMemo', getBooleanDecoder()],
   ]);
 }
 
@@ -147,10 +219,20 @@ export type BridgeSolInput<
   TAccountPayer extends string = string,
   TAccountFrom extends string = string,
   TAccountGasFeeReceiver extends string = string,
+  TAccountGasFeeEscrow extends string = string,
+  TAccountInsuranceFund extends string = string,
+  TAccountPayerRateLimitConfig extends string = string,
+  TAccountPayerRateLimit extends string = string,
+  TAccountProtocolFeeConfig extends string = string,
+  TAccountProtocolFeeTreasury extends string = string,
+  TAccountDexRouterList extends string = string,
+  TAccountSolVaultState extends string = string,
   TAccountSolVault extends string = string,
+  TAccountTokenDeposits extends string = string,
   TAccountBridge extends string = string,
   TAccountOutgoingMessage extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountMemoProgram extends string = string,
 > = {
   /**
    * The account that pays for transaction fees and account creation.
@@ -164,14 +246,47 @@ export type BridgeSolInput<
   from: TransactionSigner<TAccountFrom>;
   /** The account that receives payment for the gas costs of bridging SOL to Base. */
   gasFeeReceiver: Address<TAccountGasFeeReceiver>;
+  /** Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited. */
+  gasFeeEscrow: Address<TAccountGasFeeEscrow>;
+  /**
+   * Insurance fund credited with its share of the gas fee. No share is taken while it is
+   * uninitialized.
+   */
+  insuranceFund: Address<TAccountInsuranceFund>;
+  /** Payer rate limiting configuration. Payers are not rate limited while it is uninitialized. */
+  payerRateLimitConfig: Address<TAccountPayerRateLimitConfig>;
   /**
-   * The SOL vault account that holds locked tokens for the specific remote token.
-   * - Uses PDA with SOL_VAULT_SEED for deterministic address
-   * - Mutable to receive the locked SOL tokens
-   * - Each remote token has its own dedicated vault
+   * Outgoing messages paid for by the payer in the current rate limiting window, created on its
+   * first message while rate limiting is enabled.
+   */
+  payerRateLimit: Address<TAccountPayerRateLimit>;
+  /** Protocol fee configuration. No protocol fee is charged while it is uninitialized. */
+  protocolFeeConfig: Address<TAccountProtocolFeeConfig>;
+  /** The treasury credited with the protocol fee. Ignored when no protocol fee is charged. */
+  protocolFeeTreasury: Address<TAccountProtocolFeeTreasury>;
+  /**
+   * DEX router list whose routers may only be called with a swap guard. Calls are not checked
+   * while it is uninitialized.
+   */
+  dexRouterList: Address<TAccountDexRouterList>;
+  /**
+   * The SOL vault state recording the vault bump.
+   * Bridging SOL requires the vault to have been initialized with `init_sol_vault`.
+   */
+  solVaultState: Address<TAccountSolVaultState>;
+  /**
+   * The SOL vault account that holds the locked SOL.
+   * - Uses PDA with SOL_VAULT_SEED and the bump recorded in `sol_vault_state`
+   * - Mutable to receive the locked SOL
    *
    */
   solVault: Address<TAccountSolVault>;
+  /**
+   * Accounting for the amount locked in the SOL vault.
+   * - PDA of this program using `[TOKEN_DEPOSITS_SEED, sol_vault]`
+   * - Created on the first deposit; `locked_amount` is incremented by the bridged amount
+   */
+  tokenDeposits: Address<TAccountTokenDeposits>;
   /**
    * The main bridge state account that tracks nonces and fee parameters.
    * - Uses PDA with BRIDGE_SEED for deterministic address
@@ -182,7 +297,7 @@ export type BridgeSolInput<
    * The outgoing message account that stores cross-chain transfer details.
    * - Created fresh for each bridge operation
    * - Payer funds the account creation
-   * - Space allocated dynamically based on optional call data size
+   * - Space allocated dynamically based on optional call data and memo sizes
    */
   outgoingMessage: Address<TAccountOutgoingMessage>;
   /**
@@ -190,30 +305,55 @@ export type BridgeSolInput<
    * Used for transferring SOL from user to vault and creating outgoing message accounts.
    */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** SPL Memo program, required when `spl_memo` is set to log a summary of the operation. */
+  memoProgram?: Address<TAccountMemoProgram>;
   outgoingMessageSalt: BridgeSolInstructionDataArgs['outgoingMessageSalt'];
   to: BridgeSolInstructionDataArgs['to'];
   amount: BridgeSolInstructionDataArgs['amount'];
   call: BridgeSolInstructionDataArgs['call'];
+  memo: BridgeSolInstructionDataArgs['memo'];
+  swapGuard: BridgeSolInstructionDataArgs['swapGuard'];
+  splMemo: BridgeSolInstructionDataArgs['splMemo'];
 };
 
 export function getBridgeSolInstruction<
   TAccountPayer extends string,
   TAccountFrom extends string,
   TAccountGasFeeReceiver extends string,
+  TAccountGasFeeEscrow extends string,
+  TAccountInsuranceFund extends string,
+  TAccountPayerRateLimitConfig extends string,
+  TAccountPayerRateLimit extends string,
+  TAccountProtocolFeeConfig extends string,
+  TAccountProtocolFeeTreasury extends string,
+  TAccountDexRouterList extends string,
+  TAccountSolVaultState extends string,
   TAccountSolVault extends string,
+  TAccountTokenDeposits extends string,
   TAccountBridge extends string,
   TAccountOutgoingMessage extends string,
   TAccountSystemProgram extends string,
+  TAccountMemoProgram extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
   input: BridgeSolInput<
     TAccountPayer,
     TAccountFrom,
     TAccountGasFeeReceiver,
+    TAccountGasFeeEscrow,
+    TAccountInsuranceFund,
+    TAccountPayerRateLimitConfig,
+    TAccountPayerRateLimit,
+    TAccountProtocolFeeConfig,
+    TAccountProtocolFeeTreasury,
+    TAccountDexRouterList,
+    TAccountSolVaultState,
     TAccountSolVault,
+    TAccountTokenDeposits,
     TAccountBridge,
     TAccountOutgoingMessage,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMemoProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): BridgeSolInstruction<
@@ -221,10 +361,20 @@ export function getBridgeSolInstruction<
   TAccountPayer,
   TAccountFrom,
   TAccountGasFeeReceiver,
+  TAccountGasFeeEscrow,
+  TAccountInsuranceFund,
+  TAccountPayerRateLimitConfig,
+  TAccountPayerRateLimit,
+  TAccountProtocolFeeConfig,
+  TAccountProtocolFeeTreasury,
+  TAccountDexRouterList,
+  TAccountSolVaultState,
   TAccountSolVault,
+  TAccountTokenDeposits,
   TAccountBridge,
   TAccountOutgoingMessage,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountMemoProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -234,10 +384,29 @@ export function getBridgeSolInstruction<
     payer: { value: input.payer ?? null, isWritable: true },
     from: { value: input.from ?? null, isWritable: true },
     gasFeeReceiver: { value: input.gasFeeReceiver ?? null, isWritable: true },
+    gasFeeEscrow: { value: input.gasFeeEscrow ?? null, isWritable: true },
+    insuranceFund: { value: input.insuranceFund ?? null, isWritable: true },
+    payerRateLimitConfig: {
+      value: input.payerRateLimitConfig ?? null,
+      isWritable: false,
+    },
+    payerRateLimit: { value: input.payerRateLimit ?? null, isWritable: true },
+    protocolFeeConfig: {
+      value: input.protocolFeeConfig ?? null,
+      isWritable: false,
+    },
+    protocolFeeTreasury: {
+      value: input.protocolFeeTreasury ?? null,
+      isWritable: true,
+    },
+    dexRouterList: { value: input.dexRouterList ?? null, isWritable: false },
+    solVaultState: { value: input.solVaultState ?? null, isWritable: false },
     solVault: { value: input.solVault ?? null, isWritable: true },
+    tokenDeposits: { value: input.tokenDeposits ?? null, isWritable: true },
     bridge: { value: input.bridge ?? null, isWritable: true },
     outgoingMessage: { value: input.outgoingMessage ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    memoProgram: { value: input.memoProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -259,10 +428,20 @@ export function getBridgeSolInstruction<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.from),
       getAccountMeta(accounts.gasFeeReceiver),
+      getAccountMeta(accounts.gasFeeEscrow),
+      getAccountMeta(accounts.insuranceFund),
+      getAccountMeta(accounts.payerRateLimitConfig),
+      getAccountMeta(accounts.payerRateLimit),
+      getAccountMeta(accounts.protocolFeeConfig),
+      getAccountMeta(accounts.protocolFeeTreasury),
+      getAccountMeta(accounts.dexRouterList),
+      getAccountMeta(accounts.solVaultState),
       getAccountMeta(accounts.solVault),
+      getAccountMeta(accounts.tokenDeposits),
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.outgoingMessage),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.memoProgram),
     ],
     data: getBridgeSolInstructionDataEncoder().encode(
       args as BridgeSolInstructionDataArgs
@@ -273,10 +452,20 @@ export function getBridgeSolInstruction<
     TAccountPayer,
     TAccountFrom,
     TAccountGasFeeReceiver,
+    TAccountGasFeeEscrow,
+    TAccountInsuranceFund,
+    TAccountPayerRateLimitConfig,
+    TAccountPayerRateLimit,
+    TAccountProtocolFeeConfig,
+    TAccountProtocolFeeTreasury,
+    TAccountDexRouterList,
+    TAccountSolVaultState,
     TAccountSolVault,
+    TAccountTokenDeposits,
     TAccountBridge,
     TAccountOutgoingMessage,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMemoProgram
   >);
 }
 
@@ -298,32 +487,67 @@ export type ParsedBridgeSolInstruction<
     from: TAccountMetas[1];
     /** The account that receives payment for the gas costs of bridging SOL to Base. */
     gasFeeReceiver: TAccountMetas[2];
+    /** Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited. */
+    gasFeeEscrow: TAccountMetas[3];
+    /**
+     * Insurance fund credited with its share of the gas fee. No share is taken while it is
+     * uninitialized.
+     */
+    insuranceFund: TAccountMetas[4];
+    /** Payer rate limiting configuration. Payers are not rate limited while it is uninitialized. */
+    payerRateLimitConfig: TAccountMetas[5];
     /**
-     * The SOL vault account that holds locked tokens for the specific remote token.
-     * - Uses PDA with SOL_VAULT_SEED for deterministic address
-     * - Mutable to receive the locked SOL tokens
-     * - Each remote token has its own dedicated vault
+     * Outgoing messages paid for by the payer in the current rate limiting window, created on its
+     * first message while rate limiting is enabled.
+     */
+    payerRateLimit: TAccountMetas[6];
+    /** Protocol fee configuration. No protocol fee is charged while it is uninitialized. */
+    protocolFeeConfig: TAccountMetas[7];
+    /** The treasury credited with the protocol fee. Ignored when no protocol fee is charged. */
+    protocolFeeTreasury: TAccountMetas[8];
+    /**
+     * DEX router list whose routers may only be called with a swap guard. Calls are not checked
+     * while it is uninitialized.
+     */
+    dexRouterList: TAccountMetas[9];
+    /**
+     * The SOL vault state recording the vault bump.
+     * Bridging SOL requires the vault to have been initialized with `init_sol_vault`.
+     */
+    solVaultState: TAccountMetas[10];
+    /**
+     * The SOL vault account that holds the locked SOL.
+     * - Uses PDA with SOL_VAULT_SEED and the bump recorded in `sol_vault_state`
+     * - Mutable to receive the locked SOL
      *
      */
-    solVault: TAccountMetas[3];
+    solVault: TAccountMetas[11];
+    /**
+     * Accounting for the amount locked in the SOL vault.
+     * - PDA of this program using `[TOKEN_DEPOSITS_SEED, sol_vault]`
+     * - Created on the first deposit; `locked_amount` is incremented by the bridged amount
+     */
+    tokenDeposits: TAccountMetas[12];
     /**
      * The main bridge state account that tracks nonces and fee parameters.
      * - Uses PDA with BRIDGE_SEED for deterministic address
      * - Mutable to increment nonce and update EIP1559 fee data
      */
-    bridge: TAccountMetas[4];
+    bridge: TAccountMetas[13];
     /**
      * The outgoing message account that stores cross-chain transfer details.
      * - Created fresh for each bridge operation
      * - Payer funds the account creation
-     * - Space allocated dynamically based on optional call data size
+     * - Space allocated dynamically based on optional call data and memo sizes
      */
-    outgoingMessage: TAccountMetas[5];
+    outgoingMessage: TAccountMetas[14];
     /**
      * System program required for SOL transfers and account creation.
      * Used for transferring SOL from user to vault and creating outgoing message accounts.
      */
-    systemProgram: TAccountMetas[6];
+    systemProgram: TAccountMetas[15];
+    /** SPL Memo program, required when `spl_memo` is set to log a summary of the operation. */
+    memoProgram?: TAccountMetas[16] | undefined;
   };
   data: BridgeSolInstructionData;
 };
@@ -336,7 +560,7 @@ export function parseBridgeSolInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedBridgeSolInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 17) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -346,16 +570,32 @@ export function parseBridgeSolInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === BRIDGE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       payer: getNextAccount(),
       from: getNextAccount(),
       gasFeeReceiver: getNextAccount(),
+      gasFeeEscrow: getNextAccount(),
+      insuranceFund: getNextAccount(),
+      payerRateLimitConfig: getNextAccount(),
+      payerRateLimit: getNextAccount(),
+      protocolFeeConfig: getNextAccount(),
+      protocolFeeTreasury: getNextAccount(),
+      dexRouterList: getNextAccount(),
+      solVaultState: getNextAccount(),
       solVault: getNextAccount(),
+      tokenDeposits: getNextAccount(),
       bridge: getNextAccount(),
       outgoingMessage: getNextAccount(),
       systemProgram: getNextAccount(),
+      memoProgram: getNextOptionalAccount(),
     },
     data: getBridgeSolInstructionDataDecoder().decode(instruction.data),
   };
@@ -10,6 +10,8 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
@@ -50,7 +52,16 @@ export type BridgeSolWithBufferedCallInstruction<
   TAccountPayer extends string | AccountMeta<string> = string,
   TAccountFrom extends string | AccountMeta<string> = string,
   TAccountGasFeeReceiver extends string | AccountMeta<string> = string,
+  TAccountGasFeeEscrow extends string | AccountMeta<string> = string,
+  TAccountInsuranceFund extends string | AccountMeta<string> = string,
+  TAccountPayerRateLimitConfig extends string | AccountMeta<string> = string,
+  TAccountPayerRateLimit extends string | AccountMeta<string> = string,
+  TAccountProtocolFeeConfig extends string | AccountMeta<string> = string,
+  TAccountProtocolFeeTreasury extends string | AccountMeta<string> = string,
+  TAccountDexRouterList extends string | AccountMeta<string> = string,
+  TAccountSolVaultState extends string | AccountMeta<string> = string,
   TAccountSolVault extends string | AccountMeta<string> = string,
+  TAccountTokenDeposits extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountOwner extends string | AccountMeta<string> = string,
   TAccountCallBuffer extends string | AccountMeta<string> = string,
@@ -58,6 +69,7 @@ export type BridgeSolWithBufferedCallInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountMemoProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -73,9 +85,36 @@ export type BridgeSolWithBufferedCallInstruction<
       TAccountGasFeeReceiver extends string
         ? WritableAccount<TAccountGasFeeReceiver>
         : TAccountGasFeeReceiver,
+      TAccountGasFeeEscrow extends string
+        ? WritableAccount<TAccountGasFeeEscrow>
+        : TAccountGasFeeEscrow,
+      TAccountInsuranceFund extends string
+        ? WritableAccount<TAccountInsuranceFund>
+        : TAccountInsuranceFund,
+      TAccountPayerRateLimitConfig extends string
+        ? ReadonlyAccount<TAccountPayerRateLimitConfig>
+        : TAccountPayerRateLimitConfig,
+      TAccountPayerRateLimit extends string
+        ? WritableAccount<TAccountPayerRateLimit>
+        : TAccountPayerRateLimit,
+      TAccountProtocolFeeConfig extends string
+        ? ReadonlyAccount<TAccountProtocolFeeConfig>
+        : TAccountProtocolFeeConfig,
+      TAccountProtocolFeeTreasury extends string
+        ? WritableAccount<TAccountProtocolFeeTreasury>
+        : TAccountProtocolFeeTreasury,
+      TAccountDexRouterList extends string
+        ? ReadonlyAccount<TAccountDexRouterList>
+        : TAccountDexRouterList,
+      TAccountSolVaultState extends string
+        ? ReadonlyAccount<TAccountSolVaultState>
+        : TAccountSolVaultState,
       TAccountSolVault extends string
         ? WritableAccount<TAccountSolVault>
         : TAccountSolVault,
+      TAccountTokenDeposits extends string
+        ? WritableAccount<TAccountTokenDeposits>
+        : TAccountTokenDeposits,
       TAccountBridge extends string
         ? WritableAccount<TAccountBridge>
         : TAccountBridge,
@@ -92,6 +131,9 @@ export type BridgeSolWithBufferedCallInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountMemoProgram extends string
+        ? ReadonlyAccount<TAccountMemoProgram>
+        : TAccountMemoProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -101,12 +143,14 @@ export type BridgeSolWithBufferedCallInstructionData = {
   outgoingMessageSalt: ReadonlyUint8Array;
   to: ReadonlyUint8Array;
   amount: bigint;
+  splMemo: boolean;
 };
 
 export type BridgeSolWithBufferedCallInstructionDataArgs = {
   outgoingMessageSalt: ReadonlyUint8Array;
   to: ReadonlyUint8Array;
   amount: number | bigint;
+  splMemo: boolean;
 };
 
 export function getBridgeSolWithBufferedCallInstructionDataEncoder(): FixedSizeEncoder<BridgeSolWithBufferedCallInstructionDataArgs> {
@@ -116,6 +160,7 @@ export function getBridgeSolWithBufferedCallInstructionDataEncoder(): FixedSizeE
       ['outgoingMessageSalt', fixEncoderSize(getBytesEncoder(), 32)],
       ['to', fixEncoderSize(getBytesEncoder(), 20)],
       ['amount', getU64Encoder()],
+      ['splMemo', getBooleanEncoder()],
     ]),
     (value) => ({
       ...value,
@@ -130,6 +175,7 @@ export function getBridgeSolWithBufferedCallInstructionDataDecoder(): FixedSizeD
     ['outgoingMessageSalt', fixDecoderSize(getBytesDecoder(), 32)],
     ['to', fixDecoderSize(getBytesDecoder(), 20)],
     ['amount', getU64Decoder()],
+    ['splMemo', getBooleanDecoder()],
   ]);
 }
 
@@ -147,12 +193,22 @@ export type BridgeSolWithBufferedCallInput<
   TAccountPayer extends string = string,
   TAccountFrom extends string = string,
   TAccountGasFeeReceiver extends string = string,
+  TAccountGasFeeEscrow extends string = string,
+  TAccountInsuranceFund extends string = string,
+  TAccountPayerRateLimitConfig extends string = string,
+  TAccountPayerRateLimit extends string = string,
+  TAccountProtocolFeeConfig extends string = string,
+  TAccountProtocolFeeTreasury extends string = string,
+  TAccountDexRouterList extends string = string,
+  TAccountSolVaultState extends string = string,
   TAccountSolVault extends string = string,
+  TAccountTokenDeposits extends string = string,
   TAccountBridge extends string = string,
   TAccountOwner extends string = string,
   TAccountCallBuffer extends string = string,
   TAccountOutgoingMessage extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountMemoProgram extends string = string,
 > = {
   /**
    * The account that pays for account creation and the gas fee (EIP-1559 based) on Solana.
@@ -166,14 +222,47 @@ export type BridgeSolWithBufferedCallInput<
   from: TransactionSigner<TAccountFrom>;
   /** The account that receives payment for the gas costs of bridging the SOL to Base. */
   gasFeeReceiver: Address<TAccountGasFeeReceiver>;
+  /** Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited. */
+  gasFeeEscrow: Address<TAccountGasFeeEscrow>;
   /**
-   * The SOL vault account that holds locked tokens for the specific remote token.
-   * - PDA of this program using `[SOL_VAULT_SEED]`
+   * Insurance fund credited with its share of the gas fee. No share is taken while it is
+   * uninitialized.
+   */
+  insuranceFund: Address<TAccountInsuranceFund>;
+  /** Payer rate limiting configuration. Payers are not rate limited while it is uninitialized. */
+  payerRateLimitConfig: Address<TAccountPayerRateLimitConfig>;
+  /**
+   * Outgoing messages paid for by the payer in the current rate limiting window, created on its
+   * first message while rate limiting is enabled.
+   */
+  payerRateLimit: Address<TAccountPayerRateLimit>;
+  /** Protocol fee configuration. No protocol fee is charged while it is uninitialized. */
+  protocolFeeConfig: Address<TAccountProtocolFeeConfig>;
+  /** The treasury credited with the protocol fee. Ignored when no protocol fee is charged. */
+  protocolFeeTreasury: Address<TAccountProtocolFeeTreasury>;
+  /**
+   * DEX router list whose routers may only be called with a swap guard. Calls are not checked
+   * while it is uninitialized.
+   */
+  dexRouterList: Address<TAccountDexRouterList>;
+  /**
+   * The SOL vault state recording the vault bump.
+   * Bridging SOL requires the vault to have been initialized with `init_sol_vault`.
+   */
+  solVaultState: Address<TAccountSolVaultState>;
+  /**
+   * The SOL vault account that holds the locked SOL.
+   * - Uses PDA with SOL_VAULT_SEED and the bump recorded in `sol_vault_state`
    * - Mutable to receive the locked SOL
-   * - Each remote token has its own dedicated vault
    *
    */
   solVault: Address<TAccountSolVault>;
+  /**
+   * Accounting for the amount locked in the SOL vault.
+   * - PDA of this program using `[TOKEN_DEPOSITS_SEED, sol_vault]`
+   * - Created on the first deposit; `locked_amount` is incremented by the bridged amount
+   */
+  tokenDeposits: Address<TAccountTokenDeposits>;
   /**
    * The main bridge state account that tracks nonces and fee parameters.
    * - PDA with `BRIDGE_SEED`
@@ -185,7 +274,7 @@ export type BridgeSolWithBufferedCallInput<
   /**
    * The call buffer account that stores the call parameters and data.
    * Its contents are copied into the outgoing message, then the account is closed
-   * (rent refunded to `owner`).
+   * (rent refunded to `owner`) unless it is reusable.
    */
   callBuffer: Address<TAccountCallBuffer>;
   /**
@@ -197,33 +286,56 @@ export type BridgeSolWithBufferedCallInput<
   outgoingMessage: Address<TAccountOutgoingMessage>;
   /** System program required for account creation and the SOL transfer CPI. */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** SPL Memo program, required when `spl_memo` is set to log a summary of the operation. */
+  memoProgram?: Address<TAccountMemoProgram>;
   outgoingMessageSalt: BridgeSolWithBufferedCallInstructionDataArgs['outgoingMessageSalt'];
   to: BridgeSolWithBufferedCallInstructionDataArgs['to'];
   amount: BridgeSolWithBufferedCallInstructionDataArgs['amount'];
+  splMemo: BridgeSolWithBufferedCallInstructionDataArgs['splMemo'];
 };
 
 export function getBridgeSolWithBufferedCallInstruction<
   TAccountPayer extends string,
   TAccountFrom extends string,
   TAccountGasFeeReceiver extends string,
+  TAccountGasFeeEscrow extends string,
+  TAccountInsuranceFund extends string,
+  TAccountPayerRateLimitConfig extends string,
+  TAccountPayerRateLimit extends string,
+  TAccountProtocolFeeConfig extends string,
+  TAccountProtocolFeeTreasury extends string,
+  TAccountDexRouterList extends string,
+  TAccountSolVaultState extends string,
   TAccountSolVault extends string,
+  TAccountTokenDeposits extends string,
   TAccountBridge extends string,
   TAccountOwner extends string,
   TAccountCallBuffer extends string,
   TAccountOutgoingMessage extends string,
   TAccountSystemProgram extends string,
+  TAccountMemoProgram extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
   input: BridgeSolWithBufferedCallInput<
     TAccountPayer,
     TAccountFrom,
     TAccountGasFeeReceiver,
+    TAccountGasFeeEscrow,
+    TAccountInsuranceFund,
+    TAccountPayerRateLimitConfig,
+    TAccountPayerRateLimit,
+    TAccountProtocolFeeConfig,
+    TAccountProtocolFeeTreasury,
+    TAccountDexRouterList,
+    TAccountSolVaultState,
     TAccountSolVault,
+    TAccountTokenDeposits,
     TAccountBridge,
     TAccountOwner,
     TAccountCallBuffer,
     TAccountOutgoingMessage,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMemoProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): BridgeSolWithBufferedCallInstruction<
@@ -231,12 +343,22 @@ export function getBridgeSolWithBufferedCallInstruction<
   TAccountPayer,
   TAccountFrom,
   TAccountGasFeeReceiver,
+  TAccountGasFeeEscrow,
+  TAccountInsuranceFund,
+  TAccountPayerRateLimitConfig,
+  TAccountPayerRateLimit,
+  TAccountProtocolFeeConfig,
+  TAccountProtocolFeeTreasury,
+  TAccountDexRouterList,
+  TAccountSolVaultState,
   TAccountSolVault,
+  TAccountTokenDeposits,
   TAccountBridge,
   TAccountOwner,
   TAccountCallBuffer,
   TAccountOutgoingMessage,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountMemoProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -246,12 +368,31 @@ export function getBridgeSolWithBufferedCallInstruction<
     payer: { value: input.payer ?? null, isWritable: true },
     from: { value: input.from ?? null, isWritable: true },
     gasFeeReceiver: { value: input.gasFeeReceiver ?? null, isWritable: true },
+    gasFeeEscrow: { value: input.gasFeeEscrow ?? null, isWritable: true },
+    insuranceFund: { value: input.insuranceFund ?? null, isWritable: true },
+    payerRateLimitConfig: {
+      value: input.payerRateLimitConfig ?? null,
+      isWritable: false,
+    },
+    payerRateLimit: { value: input.payerRateLimit ?? null, isWritable: true },
+    protocolFeeConfig: {
+      value: input.protocolFeeConfig ?? null,
+      isWritable: false,
+    },
+    protocolFeeTreasury: {
+      value: input.protocolFeeTreasury ?? null,
+      isWritable: true,
+    },
+    dexRouterList: { value: input.dexRouterList ?? null, isWritable: false },
+    solVaultState: { value: input.solVaultState ?? null, isWritable: false },
     solVault: { value: input.solVault ?? null, isWritable: true },
+    tokenDeposits: { value: input.tokenDeposits ?? null, isWritable: true },
     bridge: { value: input.bridge ?? null, isWritable: true },
     owner: { value: input.owner ?? null, isWritable: true },
     callBuffer: { value: input.callBuffer ?? null, isWritable: true },
     outgoingMessage: { value: input.outgoingMessage ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    memoProgram: { value: input.memoProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -273,12 +414,22 @@ export function getBridgeSolWithBufferedCallInstruction<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.from),
       getAccountMeta(accounts.gasFeeReceiver),
+      getAccountMeta(accounts.gasFeeEscrow),
+      getAccountMeta(accounts.insuranceFund),
+      getAccountMeta(accounts.payerRateLimitConfig),
+      getAccountMeta(accounts.payerRateLimit),
+      getAccountMeta(accounts.protocolFeeConfig),
+      getAccountMeta(accounts.protocolFeeTreasury),
+      getAccountMeta(accounts.dexRouterList),
+      getAccountMeta(accounts.solVaultState),
       getAccountMeta(accounts.solVault),
+      getAccountMeta(accounts.tokenDeposits),
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.callBuffer),
       getAccountMeta(accounts.outgoingMessage),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.memoProgram),
     ],
     data: getBridgeSolWithBufferedCallInstructionDataEncoder().encode(
       args as BridgeSolWithBufferedCallInstructionDataArgs
@@ -289,12 +440,22 @@ export function getBridgeSolWithBufferedCallInstruction<
     TAccountPayer,
     TAccountFrom,
     TAccountGasFeeReceiver,
+    TAccountGasFeeEscrow,
+    TAccountInsuranceFund,
+    TAccountPayerRateLimitConfig,
+    TAccountPayerRateLimit,
+    TAccountProtocolFeeConfig,
+    TAccountProtocolFeeTreasury,
+    TAccountDexRouterList,
+    TAccountSolVaultState,
     TAccountSolVault,
+    TAccountTokenDeposits,
     TAccountBridge,
     TAccountOwner,
     TAccountCallBuffer,
     TAccountOutgoingMessage,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMemoProgram
   >);
 }
 
@@ -316,37 +477,72 @@ export type ParsedBridgeSolWithBufferedCallInstruction<
     from: TAccountMetas[1];
     /** The account that receives payment for the gas costs of bridging the SOL to Base. */
     gasFeeReceiver: TAccountMetas[2];
+    /** Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited. */
+    gasFeeEscrow: TAccountMetas[3];
+    /**
+     * Insurance fund credited with its share of the gas fee. No share is taken while it is
+     * uninitialized.
+     */
+    insuranceFund: TAccountMetas[4];
+    /** Payer rate limiting configuration. Payers are not rate limited while it is uninitialized. */
+    payerRateLimitConfig: TAccountMetas[5];
+    /**
+     * Outgoing messages paid for by the payer in the current rate limiting window, created on its
+     * first message while rate limiting is enabled.
+     */
+    payerRateLimit: TAccountMetas[6];
+    /** Protocol fee configuration. No protocol fee is charged while it is uninitialized. */
+    protocolFeeConfig: TAccountMetas[7];
+    /** The treasury credited with the protocol fee. Ignored when no protocol fee is charged. */
+    protocolFeeTreasury: TAccountMetas[8];
+    /**
+     * DEX router list whose routers may only be called with a swap guard. Calls are not checked
+     * while it is uninitialized.
+     */
+    dexRouterList: TAccountMetas[9];
+    /**
+     * The SOL vault state recording the vault bump.
+     * Bridging SOL requires the vault to have been initialized with `init_sol_vault`.
+     */
+    solVaultState: TAccountMetas[10];
     /**
-     * The SOL vault account that holds locked tokens for the specific remote token.
-     * - PDA of this program using `[SOL_VAULT_SEED]`
+     * The SOL vault account that holds the locked SOL.
+     * - Uses PDA with SOL_VAULT_SEED and the bump recorded in `sol_vault_state`
      * - Mutable to receive the locked SOL
-     * - Each remote token has its own dedicated vault
      *
      */
-    solVault: TAccountMetas[3];
+    solVault: TAccountMetas[11];
+    /**
+     * Accounting for the amount locked in the SOL vault.
+     * - PDA of this program using `[TOKEN_DEPOSITS_SEED, sol_vault]`
+     * - Created on the first deposit; `locked_amount` is incremented by the bridged amount
+     */
+    tokenDeposits: TAccountMetas[12];
     /**
      * The main bridge state account that tracks nonces and fee parameters.
      * - PDA with `BRIDGE_SEED`
      * - Mutable to charge gas (EIP-1559 accounting) and increment the message nonce
      */
-    bridge: TAccountMetas[4];
+    bridge: TAccountMetas[13];
     /** The owner of the call buffer who will receive the rent refund. */
-    owner: TAccountMetas[5];
+    owner: TAccountMetas[14];
     /**
      * The call buffer account that stores the call parameters and data.
      * Its contents are copied into the outgoing message, then the account is closed
-     * (rent refunded to `owner`).
+     * (rent refunded to `owner`) unless it is reusable.
      */
-    callBuffer: TAccountMetas[6];
+    callBuffer: TAccountMetas[15];
     /**
      * The outgoing message account that stores the cross-chain transfer details.
      * - Created fresh for each bridge; address determined by the provided keypair
      * - Funded by `payer`
      * - Space: DISCRIMINATOR_LEN + serialized `OutgoingMessage`
      */
-    outgoingMessage: TAccountMetas[7];
+    outgoingMessage: TAccountMetas[16];
     /** System program required for account creation and the SOL transfer CPI. */
-    systemProgram: TAccountMetas[8];
+    systemProgram: TAccountMetas[17];
+    /** SPL Memo program, required when `spl_memo` is set to log a summary of the operation. */
+    memoProgram?: TAccountMetas[18] | undefined;
   };
   data: BridgeSolWithBufferedCallInstructionData;
 };
@@ -359,7 +555,7 @@ export function parseBridgeSolWithBufferedCallInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedBridgeSolWithBufferedCallInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 19) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -369,18 +565,34 @@ export function parseBridgeSolWithBufferedCallInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === BRIDGE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       payer: getNextAccount(),
       from: getNextAccount(),
       gasFeeReceiver: getNextAccount(),
+      gasFeeEscrow: getNextAccount(),
+      insuranceFund: getNextAccount(),
+      payerRateLimitConfig: getNextAccount(),
+      payerRateLimit: getNextAccount(),
+      protocolFeeConfig: getNextAccount(),
+      protocolFeeTreasury: getNextAccount(),
+      dexRouterList: getNextAccount(),
+      solVaultState: getNextAccount(),
       solVault: getNextAccount(),
+      tokenDeposits: getNextAccount(),
       bridge: getNextAccount(),
       owner: getNextAccount(),
       callBuffer: getNextAccount(),
       outgoingMessage: getNextAccount(),
       systemProgram: getNextAccount(),
+      memoProgram: getNextOptionalAccount(),
     },
     data: getBridgeSolWithBufferedCallInstructionDataDecoder().decode(
       instruction.data
@@ -7,15 +7,21 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getOptionDecoder,
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   transformEncoder,
@@ -31,6 +37,7 @@ import {
   type Option,
   type OptionOrNullable,
   type ReadonlyAccount,
+  type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -41,8 +48,12 @@ import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 import {
   getCallDecoder,
   getCallEncoder,
+  getSwapGuardDecoder,
+  getSwapGuardEncoder,
   type Call,
   type CallArgs,
+  type SwapGuard,
+  type SwapGuardArgs,
 } from '../types';
 
 export const BRIDGE_SPL_DISCRIMINATOR = new Uint8Array([
@@ -58,10 +69,21 @@ export type BridgeSplInstruction<
   TAccountPayer extends string | AccountMeta<string> = string,
   TAccountFrom extends string | AccountMeta<string> = string,
   TAccountGasFeeReceiver extends string | AccountMeta<string> = string,
+  TAccountGasFeeEscrow extends string | AccountMeta<string> = string,
+  TAccountInsuranceFund extends string | AccountMeta<string> = string,
+  TAccountPayerRateLimitConfig extends string | AccountMeta<string> = string,
+  TAccountPayerRateLimit extends string | AccountMeta<string> = string,
+  TAccountProtocolFeeConfig extends string | AccountMeta<string> = string,
+  TAccountProtocolFeeTreasury extends string | AccountMeta<string> = string,
+  TAccountDexRouterList extends string | AccountMeta<string> = string,
+  TAccountMintPolicy extends string | AccountMeta<string> = string,
+  TAccountMintAllowList extends string | AccountMeta<string> = string,
   TAccountMint extends string | AccountMeta<string> = string,
   TAccountFromTokenAccount extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountTokenVault extends string | AccountMeta<string> = string,
+  TAccountTokenDeposits extends string | AccountMeta<string> = string,
+  TAccountVaultDecimals extends string | AccountMeta<string> = string,
   TAccountOutgoingMessage extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends
     | string
@@ -69,6 +91,8 @@ export type BridgeSplInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountMemoProgram extends string | AccountMeta<string> = string,
+  TAccountBridgeSession extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -79,11 +103,38 @@ export type BridgeSplInstruction<
             AccountSignerMeta<TAccountPayer>
         : TAccountPayer,
       TAccountFrom extends string
-        ? WritableSignerAccount<TAccountFrom> & AccountSignerMeta<TAccountFrom>
+        ? ReadonlySignerAccount<TAccountFrom> & AccountSignerMeta<TAccountFrom>
         : TAccountFrom,
       TAccountGasFeeReceiver extends string
         ? WritableAccount<TAccountGasFeeReceiver>
         : TAccountGasFeeReceiver,
+      TAccountGasFeeEscrow extends string
+        ? WritableAccount<TAccountGasFeeEscrow>
+        : TAccountGasFeeEscrow,
+      TAccountInsuranceFund extends string
+        ? WritableAccount<TAccountInsuranceFund>
+        : TAccountInsuranceFund,
+      TAccountPayerRateLimitConfig extends string
+        ? ReadonlyAccount<TAccountPayerRateLimitConfig>
+        : TAccountPayerRateLimitConfig,
+      TAccountPayerRateLimit extends string
+        ? WritableAccount<TAccountPayerRateLimit>
+        : TAccountPayerRateLimit,
+      TAccountProtocolFeeConfig extends string
+        ? ReadonlyAccount<TAccountProtocolFeeConfig>
+        : TAccountProtocolFeeConfig,
+      TAccountProtocolFeeTreasury extends string
+        ? WritableAccount<TAccountProtocolFeeTreasury>
+        : TAccountProtocolFeeTreasury,
+      TAccountDexRouterList extends string
+        ? ReadonlyAccount<TAccountDexRouterList>
+        : TAccountDexRouterList,
+      TAccountMintPolicy extends string
+        ? ReadonlyAccount<TAccountMintPolicy>
+        : TAccountMintPolicy,
+      TAccountMintAllowList extends string
+        ? ReadonlyAccount<TAccountMintAllowList>
+        : TAccountMintAllowList,
       TAccountMint extends string
         ? WritableAccount<TAccountMint>
         : TAccountMint,
@@ -96,6 +147,12 @@ export type BridgeSplInstruction<
       TAccountTokenVault extends string
         ? WritableAccount<TAccountTokenVault>
         : TAccountTokenVault,
+      TAccountTokenDeposits extends string
+        ? WritableAccount<TAccountTokenDeposits>
+        : TAccountTokenDeposits,
+      TAccountVaultDecimals extends string
+        ? ReadonlyAccount<TAccountVaultDecimals>
+        : TAccountVaultDecimals,
       TAccountOutgoingMessage extends string
         ? WritableAccount<TAccountOutgoingMessage>
         : TAccountOutgoingMessage,
@@ -105,6 +162,12 @@ export type BridgeSplInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountMemoProgram extends string
+        ? ReadonlyAccount<TAccountMemoProgram>
+        : TAccountMemoProgram,
+      TAccountBridgeSession extends string
+        ? WritableAccount<TAccountBridgeSession>
+        : TAccountBridgeSession,
       ...TRemainingAccounts,
     ]
   >;
@@ -116,6 +179,9 @@ export type BridgeSplInstructionData = {
   remoteToken: ReadonlyUint8Array;
   amount: bigint;
   call: Option<Call>;
+  memo: Option<ReadonlyUint8Array>;
+  swapGuard: Option<SwapGuard>;
+  splMemo: boolean;
 };
 
 export type BridgeSplInstructionDataArgs = {
@@ -124,6 +190,9 @@ export type BridgeSplInstructionDataArgs = {
   remoteToken: ReadonlyUint8Array;
   amount: number | bigint;
   call: OptionOrNullable<CallArgs>;
+  memo: OptionOrNullable<ReadonlyUint8Array>;
+  swapGuard: OptionOrNullable<SwapGuardArgs>;
+  splMemo: boolean;
 };
 
 export function getBridgeSplInstructionDataEncoder(): Encoder<BridgeSplInstructionDataArgs> {
@@ -135,6 +204,14 @@ export function getBridgeSplInstructionDataEncoder(): Encoder<BridgeSplInstructi
       ['remoteToken', fixEncoderSize(getBytesEncoder(), 20)],
       ['amount', getU64Encoder()],
       ['call', getOptionEncoder(getCallEncoder())],
+      [
+        'memo',
+        getOptionEncoder(
+          addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())
+        ),
+      ],
+      ['swapGuard', getOptionEncoder(getSwapGuardEncoder())],
+      ['splMemo', getBooleanEncoder()],
     ]),
     (value) => ({ ...value, discriminator: BRIDGE_SPL_DISCRIMINATOR })
   );
@@ -148,6 +225,14 @@ export function getBridgeSplInstructionDataDecoder(): Decoder<BridgeSplInstructi
     ['remoteToken', fixDecoderSize(getBytesDecoder(), 20)],
     ['amount', getU64Decoder()],
     ['call', getOptionDecoder(getCallDecoder())],
+    [
+      'memo',
+      getOptionDecoder(
+        addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())
+      ),
+    ],
+    ['swapGuard', getOptionDecoder(getSwapGuardDecoder())],
+    ['splMemo', getBooleanDecoder()],
   ]);
 }
 
@@ -165,13 +250,26 @@ export type BridgeSplInput<
   TAccountPayer extends string = string,
   TAccountFrom extends string = string,
   TAccountGasFeeReceiver extends string = string,
+  TAccountGasFeeEscrow extends string = string,
+  TAccountInsuranceFund extends string = string,
+  TAccountPayerRateLimitConfig extends string = string,
+  TAccountPayerRateLimit extends string = string,
+  TAccountProtocolFeeConfig extends string = string,
+  TAccountProtocolFeeTreasury extends string = string,
+  TAccountDexRouterList extends string = string,
+  TAccountMintPolicy extends string = string,
+  TAccountMintAllowList extends string = string,
   TAccountMint extends string = string,
   TAccountFromTokenAccount extends string = string,
   TAccountBridge extends string = string,
   TAccountTokenVault extends string = string,
+  TAccountTokenDeposits extends string = string,
+  TAccountVaultDecimals extends string = string,
   TAccountOutgoingMessage extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountMemoProgram extends string = string,
+  TAccountBridgeSession extends string = string,
 > = {
   /**
    * The account that pays for transaction fees and account creation.
@@ -181,10 +279,50 @@ export type BridgeSplInput<
   /**
    * The token authority authorizing the transfer of SPL tokens.
    * This signer must be the owner or an approved delegate for the source token account.
+   * It pays for nothing, so it needs no lamports when `payer` sponsors the transaction.
    */
   from: TransactionSigner<TAccountFrom>;
   /** The account that receives payment for the gas costs of bridging the SPL token to Base. */
   gasFeeReceiver: Address<TAccountGasFeeReceiver>;
+  /**
+   * Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited.
+   * Only required when it is used.
+   */
+  gasFeeEscrow?: Address<TAccountGasFeeEscrow>;
+  /**
+   * Insurance fund credited with its share of the gas fee. No share is taken while it is
+   * uninitialized.
+   */
+  insuranceFund: Address<TAccountInsuranceFund>;
+  /** Payer rate limiting configuration. Payers are not rate limited while it is uninitialized. */
+  payerRateLimitConfig: Address<TAccountPayerRateLimitConfig>;
+  /**
+   * Outgoing messages paid for by the payer in the current rate limiting window, created on its
+   * first message while rate limiting is enabled. Only required while the payer is rate limited.
+   */
+  payerRateLimit?: Address<TAccountPayerRateLimit>;
+  /** Protocol fee configuration. No protocol fee is charged while it is uninitialized. */
+  protocolFeeConfig: Address<TAccountProtocolFeeConfig>;
+  /**
+   * The treasury token account of `mint` credited with the protocol fee.
+   * Only required when a protocol fee is charged.
+   */
+  protocolFeeTreasury?: Address<TAccountProtocolFeeTreasury>;
+  /**
+   * DEX router list whose routers may only be called with a swap guard. Calls are not checked
+   * while it is uninitialized. Only required for calls without a swap guard.
+   */
+  dexRouterList?: Address<TAccountDexRouterList>;
+  /**
+   * Guardian-maintained policy restricting the freeze authority of bridged mints.
+   * No freeze authority policy is enforced while it is uninitialized.
+   */
+  mintPolicy: Address<TAccountMintPolicy>;
+  /**
+   * Guardian-maintained allow-list of the mints accepted during guarded launch.
+   * Only required while `bridge.guarded_launch` is set.
+   */
+  mintAllowList?: Address<TAccountMintAllowList>;
   /**
    * The SPL token mint account for the token being bridged.
    * - Must not be a wrapped token (wrapped tokens use bridge_wrapped_token)
@@ -212,12 +350,26 @@ export type BridgeSplInput<
    * - Acts as the custody account for tokens being bridged to Base
    */
   tokenVault: Address<TAccountTokenVault>;
+  /**
+   * Accounting for the amount locked in the token vault.
+   * - PDA of this program using `[TOKEN_DEPOSITS_SEED, token_vault]`
+   * - Created on the first deposit; `locked_amount` is incremented by the received amount
+   */
+  tokenDeposits: Address<TAccountTokenDeposits>;
+  /**
+   * Decimals of the Base token the vault is bridged to.
+   * - The transfer amount is carried in the smallest unit of the remote token
+   * - Amounts are not scaled while it is uninitialized
+   *
+   */
+  vaultDecimals: Address<TAccountVaultDecimals>;
   /**
    * The outgoing message account that represents this bridge operation.
    * - Contains transfer details and optional call data for the destination chain
    * - Space is calculated based on the size of optional call data
    * - Used by relayers to execute the bridge operation on Base
-   * - The recorded transfer amount equals the net increase in `token_vault` balance
+   * - The recorded transfer amount equals the net increase in `token_vault` balance, scaled
+   * down to the remote token decimals
    */
   outgoingMessage: Address<TAccountOutgoingMessage>;
   /**
@@ -230,37 +382,73 @@ export type BridgeSplInput<
    * initializing the token vault when needed.
    */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** SPL Memo program, required when `spl_memo` is set to log a summary of the operation. */
+  memoProgram?: Address<TAccountMemoProgram>;
+  /**
+   * Optional session letting `from` bridge as the delegate of the `from_token_account` owner.
+   * The tokens are transferred by the session PDA and `amount` is debited from its budget.
+   */
+  bridgeSession?: Address<TAccountBridgeSession>;
   outgoingMessageSalt: BridgeSplInstructionDataArgs['outgoingMessageSalt'];
   to: BridgeSplInstructionDataArgs['to'];
   remoteToken: BridgeSplInstructionDataArgs['remoteToken'];
   amount: BridgeSplInstructionDataArgs['amount'];
   call: BridgeSplInstructionDataArgs['call'];
+  memo: BridgeSplInstructionDataArgs['memo'];
+  swapGuard: BridgeSplInstructionDataArgs['swapGuard'];
+  splMemo: BridgeSplInstructionDataArgs['splMemo'];
 };
 
 export function getBridgeSplInstruction<
   TAccountPayer extends string,
   TAccountFrom extends string,
   TAccountGasFeeReceiver extends string,
+  TAccountGasFeeEscrow extends string,
+  TAccountInsuranceFund extends string,
+  TAccountPayerRateLimitConfig extends string,
+  TAccountPayerRateLimit extends string,
+  TAccountProtocolFeeConfig extends string,
+  TAccountProtocolFeeTreasury extends string,
+  TAccountDexRouterList extends string,
+  TAccountMintPolicy extends string,
+  TAccountMintAllowList extends string,
   TAccountMint extends string,
   TAccountFromTokenAccount extends string,
   TAccountBridge extends string,
   TAccountTokenVault extends string,
+  TAccountTokenDeposits extends string,
+  TAccountVaultDecimals extends string,
   TAccountOutgoingMessage extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountMemoProgram extends string,
+  TAccountBridgeSession extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
   input: BridgeSplInput<
     TAccountPayer,
     TAccountFrom,
     TAccountGasFeeReceiver,
+    TAccountGasFeeEscrow,
+    TAccountInsuranceFund,
+    TAccountPayerRateLimitConfig,
+    TAccountPayerRateLimit,
+    TAccountProtocolFeeConfig,
+    TAccountProtocolFeeTreasury,
+    TAccountDexRouterList,
+    TAccountMintPolicy,
+    TAccountMintAllowList,
     TAccountMint,
     TAccountFromTokenAccount,
     TAccountBridge,
     TAccountTokenVault,
+    TAccountTokenDeposits,
+    TAccountVaultDecimals,
     TAccountOutgoingMessage,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMemoProgram,
+    TAccountBridgeSession
   >,
   config?: { programAddress?: TProgramAddress }
 ): BridgeSplInstruction<
@@ -268,13 +456,26 @@ export function getBridgeSplInstruction<
   TAccountPayer,
   TAccountFrom,
   TAccountGasFeeReceiver,
+  TAccountGasFeeEscrow,
+  TAccountInsuranceFund,
+  TAccountPayerRateLimitConfig,
+  TAccountPayerRateLimit,
+  TAccountProtocolFeeConfig,
+  TAccountProtocolFeeTreasury,
+  TAccountDexRouterList,
+  TAccountMintPolicy,
+  TAccountMintAllowList,
   TAccountMint,
   TAccountFromTokenAccount,
   TAccountBridge,
   TAccountTokenVault,
+  TAccountTokenDeposits,
+  TAccountVaultDecimals,
   TAccountOutgoingMessage,
   TAccountTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountMemoProgram,
+  TAccountBridgeSession
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -282,8 +483,26 @@ export function getBridgeSplInstruction<
   // Original accounts.
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
-    from: { value: input.from ?? null, isWritable: true },
+    from: { value: input.from ?? null, isWritable: false },
     gasFeeReceiver: { value: input.gasFeeReceiver ?? null, isWritable: true },
+    gasFeeEscrow: { value: input.gasFeeEscrow ?? null, isWritable: true },
+    insuranceFund: { value: input.insuranceFund ?? null, isWritable: true },
+    payerRateLimitConfig: {
+      value: input.payerRateLimitConfig ?? null,
+      isWritable: false,
+    },
+    payerRateLimit: { value: input.payerRateLimit ?? null, isWritable: true },
+    protocolFeeConfig: {
+      value: input.protocolFeeConfig ?? null,
+      isWritable: false,
+    },
+    protocolFeeTreasury: {
+      value: input.protocolFeeTreasury ?? null,
+      isWritable: true,
+    },
+    dexRouterList: { value: input.dexRouterList ?? null, isWritable: false },
+    mintPolicy: { value: input.mintPolicy ?? null, isWritable: false },
+    mintAllowList: { value: input.mintAllowList ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: true },
     fromTokenAccount: {
       value: input.fromTokenAccount ?? null,
@@ -291,9 +510,13 @@ export function getBridgeSplInstruction<
     },
     bridge: { value: input.bridge ?? null, isWritable: true },
     tokenVault: { value: input.tokenVault ?? null, isWritable: true },
+    tokenDeposits: { value: input.tokenDeposits ?? null, isWritable: true },
+    vaultDecimals: { value: input.vaultDecimals ?? null, isWritable: false },
     outgoingMessage: { value: input.outgoingMessage ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    memoProgram: { value: input.memoProgram ?? null, isWritable: false },
+    bridgeSession: { value: input.bridgeSession ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -319,13 +542,26 @@ export function getBridgeSplInstruction<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.from),
       getAccountMeta(accounts.gasFeeReceiver),
+      getAccountMeta(accounts.gasFeeEscrow),
+      getAccountMeta(accounts.insuranceFund),
+      getAccountMeta(accounts.payerRateLimitConfig),
+      getAccountMeta(accounts.payerRateLimit),
+      getAccountMeta(accounts.protocolFeeConfig),
+      getAccountMeta(accounts.protocolFeeTreasury),
+      getAccountMeta(accounts.dexRouterList),
+      getAccountMeta(accounts.mintPolicy),
+      getAccountMeta(accounts.mintAllowList),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.fromTokenAccount),
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.tokenVault),
+      getAccountMeta(accounts.tokenDeposits),
+      getAccountMeta(accounts.vaultDecimals),
       getAccountMeta(accounts.outgoingMessage),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.memoProgram),
+      getAccountMeta(accounts.bridgeSession),
     ],
     data: getBridgeSplInstructionDataEncoder().encode(
       args as BridgeSplInstructionDataArgs
@@ -336,13 +572,26 @@ export function getBridgeSplInstruction<
     TAccountPayer,
     TAccountFrom,
     TAccountGasFeeReceiver,
+    TAccountGasFeeEscrow,
+    TAccountInsuranceFund,
+    TAccountPayerRateLimitConfig,
+    TAccountPayerRateLimit,
+    TAccountProtocolFeeConfig,
+    TAccountProtocolFeeTreasury,
+    TAccountDexRouterList,
+    TAccountMintPolicy,
+    TAccountMintAllowList,
     TAccountMint,
     TAccountFromTokenAccount,
     TAccountBridge,
     TAccountTokenVault,
+    TAccountTokenDeposits,
+    TAccountVaultDecimals,
     TAccountOutgoingMessage,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMemoProgram,
+    TAccountBridgeSession
   >);
 }
 
@@ -360,29 +609,69 @@ export type ParsedBridgeSplInstruction<
     /**
      * The token authority authorizing the transfer of SPL tokens.
      * This signer must be the owner or an approved delegate for the source token account.
+     * It pays for nothing, so it needs no lamports when `payer` sponsors the transaction.
      */
     from: TAccountMetas[1];
     /** The account that receives payment for the gas costs of bridging the SPL token to Base. */
     gasFeeReceiver: TAccountMetas[2];
+    /**
+     * Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited.
+     * Only required when it is used.
+     */
+    gasFeeEscrow?: TAccountMetas[3] | undefined;
+    /**
+     * Insurance fund credited with its share of the gas fee. No share is taken while it is
+     * uninitialized.
+     */
+    insuranceFund: TAccountMetas[4];
+    /** Payer rate limiting configuration. Payers are not rate limited while it is uninitialized. */
+    payerRateLimitConfig: TAccountMetas[5];
+    /**
+     * Outgoing messages paid for by the payer in the current rate limiting window, created on its
+     * first message while rate limiting is enabled. Only required while the payer is rate limited.
+     */
+    payerRateLimit?: TAccountMetas[6] | undefined;
+    /** Protocol fee configuration. No protocol fee is charged while it is uninitialized. */
+    protocolFeeConfig: TAccountMetas[7];
+    /**
+     * The treasury token account of `mint` credited with the protocol fee.
+     * Only required when a protocol fee is charged.
+     */
+    protocolFeeTreasury?: TAccountMetas[8] | undefined;
+    /**
+     * DEX router list whose routers may only be called with a swap guard. Calls are not checked
+     * while it is uninitialized. Only required for calls without a swap guard.
+     */
+    dexRouterList?: TAccountMetas[9] | undefined;
+    /**
+     * Guardian-maintained policy restricting the freeze authority of bridged mints.
+     * No freeze authority policy is enforced while it is uninitialized.
+     */
+    mintPolicy: TAccountMetas[10];
+    /**
+     * Guardian-maintained allow-list of the mints accepted during guarded launch.
+     * Only required while `bridge.guarded_launch` is set.
+     */
+    mintAllowList?: TAccountMetas[11] | undefined;
     /**
      * The SPL token mint account for the token being bridged.
      * - Must not be a wrapped token (wrapped tokens use bridge_wrapped_token)
      * - Used to read token decimals and validate it is not a wrapped token
      */
-    mint: TAccountMetas[3];
+    mint: TAccountMetas[12];
     /**
      * The user's token account containing the SPL tokens to be bridged.
      * - Must be owned by, or delegated to, the `from` signer (transfer authority)
      * - Tokens will be transferred from this account to the token vault
      */
-    fromTokenAccount: TAccountMetas[4];
+    fromTokenAccount: TAccountMetas[13];
     /**
      * The main bridge state account containing global bridge configuration.
      * - PDA with BRIDGE_SEED for deterministic address
      * - Tracks nonce for message ordering and EIP-1559 gas pricing
      * - Nonce is incremented after successful bridge operations
      */
-    bridge: TAccountMetas[5];
+    bridge: TAccountMetas[14];
     /**
      * The token vault account that holds locked SPL tokens during the bridge process.
      * - PDA derived from TOKEN_VAULT_SEED, mint pubkey, and remote_token address
@@ -390,25 +679,46 @@ export type ParsedBridgeSplInstruction<
      * - Token account authority is set to this vault PDA; the program signs using the PDA seeds
      * - Acts as the custody account for tokens being bridged to Base
      */
-    tokenVault: TAccountMetas[6];
+    tokenVault: TAccountMetas[15];
+    /**
+     * Accounting for the amount locked in the token vault.
+     * - PDA of this program using `[TOKEN_DEPOSITS_SEED, token_vault]`
+     * - Created on the first deposit; `locked_amount` is incremented by the received amount
+     */
+    tokenDeposits: TAccountMetas[16];
+    /**
+     * Decimals of the Base token the vault is bridged to.
+     * - The transfer amount is carried in the smallest unit of the remote token
+     * - Amounts are not scaled while it is uninitialized
+     *
+     */
+    vaultDecimals: TAccountMetas[17];
     /**
      * The outgoing message account that represents this bridge operation.
      * - Contains transfer details and optional call data for the destination chain
      * - Space is calculated based on the size of optional call data
      * - Used by relayers to execute the bridge operation on Base
-     * - The recorded transfer amount equals the net increase in `token_vault` balance
+     * - The recorded transfer amount equals the net increase in `token_vault` balance, scaled
+     * down to the remote token decimals
      */
-    outgoingMessage: TAccountMetas[7];
+    outgoingMessage: TAccountMetas[18];
     /**
      * The SPL Token program interface for executing token transfers.
      * Used for the transfer_checked operation to move tokens to the vault.
      */
-    tokenProgram: TAccountMetas[8];
+    tokenProgram: TAccountMetas[19];
     /**
      * System program required for creating the outgoing message account and
      * initializing the token vault when needed.
      */
-    systemProgram: TAccountMetas[9];
+    systemProgram: TAccountMetas[20];
+    /** SPL Memo program, required when `spl_memo` is set to log a summary of the operation. */
+    memoProgram?: TAccountMetas[21] | undefined;
+    /**
+     * Optional session letting `from` bridge as the delegate of the `from_token_account` owner.
+     * The tokens are transferred by the session PDA and `amount` is debited from its budget.
+     */
+    bridgeSession?: TAccountMetas[22] | undefined;
   };
   data: BridgeSplInstructionData;
 };
@@ -421,7 +731,7 @@ export function parseBridgeSplInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedBridgeSplInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 10) {
+  if (instruction.accounts.length < 23) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -431,19 +741,38 @@ export function parseBridgeSplInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === BRIDGE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       payer: getNextAccount(),
       from: getNextAccount(),
       gasFeeReceiver: getNextAccount(),
+      gasFeeEscrow: getNextOptionalAccount(),
+      insuranceFund: getNextAccount(),
+      payerRateLimitConfig: getNextAccount(),
+      payerRateLimit: getNextOptionalAccount(),
+      protocolFeeConfig: getNextAccount(),
+      protocolFeeTreasury: getNextOptionalAccount(),
+      dexRouterList: getNextOptionalAccount(),
+      mintPolicy: getNextAccount(),
+      mintAllowList: getNextOptionalAccount(),
       mint: getNextAccount(),
       fromTokenAccount: getNextAccount(),
       bridge: getNextAccount(),
       tokenVault: getNextAccount(),
+      tokenDeposits: getNextAccount(),
+      vaultDecimals: getNextAccount(),
       outgoingMessage: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      memoProgram: getNextOptionalAccount(),
+      bridgeSession: getNextOptionalAccount(),
     },
     data: getBridgeSplInstructionDataDecoder().decode(instruction.data),
   };
@@ -10,6 +10,8 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
@@ -27,6 +29,7 @@ import {
   type InstructionWithAccounts,
   type InstructionWithData,
   type ReadonlyAccount,
+  type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
   type WritableAccount,
@@ -50,10 +53,21 @@ export type BridgeSplWithBufferedCallInstruction<
   TAccountPayer extends string | AccountMeta<string> = string,
   TAccountFrom extends string | AccountMeta<string> = string,
   TAccountGasFeeReceiver extends string | AccountMeta<string> = string,
+  TAccountGasFeeEscrow extends string | AccountMeta<string> = string,
+  TAccountInsuranceFund extends string | AccountMeta<string> = string,
+  TAccountPayerRateLimitConfig extends string | AccountMeta<string> = string,
+  TAccountPayerRateLimit extends string | AccountMeta<string> = string,
+  TAccountProtocolFeeConfig extends string | AccountMeta<string> = string,
+  TAccountProtocolFeeTreasury extends string | AccountMeta<string> = string,
+  TAccountDexRouterList extends string | AccountMeta<string> = string,
+  TAccountMintPolicy extends string | AccountMeta<string> = string,
+  TAccountMintAllowList extends string | AccountMeta<string> = string,
   TAccountMint extends string | AccountMeta<string> = string,
   TAccountFromTokenAccount extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountTokenVault extends string | AccountMeta<string> = string,
+  TAccountTokenDeposits extends string | AccountMeta<string> = string,
+  TAccountVaultDecimals extends string | AccountMeta<string> = string,
   TAccountOwner extends string | AccountMeta<string> = string,
   TAccountCallBuffer extends string | AccountMeta<string> = string,
   TAccountOutgoingMessage extends string | AccountMeta<string> = string,
@@ -63,6 +77,7 @@ export type BridgeSplWithBufferedCallInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountMemoProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -73,11 +88,38 @@ export type BridgeSplWithBufferedCallInstruction<
             AccountSignerMeta<TAccountPayer>
         : TAccountPayer,
       TAccountFrom extends string
-        ? WritableSignerAccount<TAccountFrom> & AccountSignerMeta<TAccountFrom>
+        ? ReadonlySignerAccount<TAccountFrom> & AccountSignerMeta<TAccountFrom>
         : TAccountFrom,
       TAccountGasFeeReceiver extends string
         ? WritableAccount<TAccountGasFeeReceiver>
         : TAccountGasFeeReceiver,
+      TAccountGasFeeEscrow extends string
+        ? WritableAccount<TAccountGasFeeEscrow>
+        : TAccountGasFeeEscrow,
+      TAccountInsuranceFund extends string
+        ? WritableAccount<TAccountInsuranceFund>
+        : TAccountInsuranceFund,
+      TAccountPayerRateLimitConfig extends string
+        ? ReadonlyAccount<TAccountPayerRateLimitConfig>
+        : TAccountPayerRateLimitConfig,
+      TAccountPayerRateLimit extends string
+        ? WritableAccount<TAccountPayerRateLimit>
+        : TAccountPayerRateLimit,
+      TAccountProtocolFeeConfig extends string
+        ? ReadonlyAccount<TAccountProtocolFeeConfig>
+        : TAccountProtocolFeeConfig,
+      TAccountProtocolFeeTreasury extends string
+        ? WritableAccount<TAccountProtocolFeeTreasury>
+        : TAccountProtocolFeeTreasury,
+      TAccountDexRouterList extends string
+        ? ReadonlyAccount<TAccountDexRouterList>
+        : TAccountDexRouterList,
+      TAccountMintPolicy extends string
+        ? ReadonlyAccount<TAccountMintPolicy>
+        : TAccountMintPolicy,
+      TAccountMintAllowList extends string
+        ? ReadonlyAccount<TAccountMintAllowList>
+        : TAccountMintAllowList,
       TAccountMint extends string
         ? WritableAccount<TAccountMint>
         : TAccountMint,
@@ -90,6 +132,12 @@ export type BridgeSplWithBufferedCallInstruction<
       TAccountTokenVault extends string
         ? WritableAccount<TAccountTokenVault>
         : TAccountTokenVault,
+      TAccountTokenDeposits extends string
+        ? WritableAccount<TAccountTokenDeposits>
+        : TAccountTokenDeposits,
+      TAccountVaultDecimals extends string
+        ? ReadonlyAccount<TAccountVaultDecimals>
+        : TAccountVaultDecimals,
       TAccountOwner extends string
         ? WritableSignerAccount<TAccountOwner> &
             AccountSignerMeta<TAccountOwner>
@@ -106,6 +154,9 @@ export type BridgeSplWithBufferedCallInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountMemoProgram extends string
+        ? ReadonlyAccount<TAccountMemoProgram>
+        : TAccountMemoProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -116,6 +167,7 @@ export type BridgeSplWithBufferedCallInstructionData = {
   to: ReadonlyUint8Array;
   remoteToken: ReadonlyUint8Array;
   amount: bigint;
+  splMemo: boolean;
 };
 
 export type BridgeSplWithBufferedCallInstructionDataArgs = {
@@ -123,6 +175,7 @@ export type BridgeSplWithBufferedCallInstructionDataArgs = {
   to: ReadonlyUint8Array;
   remoteToken: ReadonlyUint8Array;
   amount: number | bigint;
+  splMemo: boolean;
 };
 
 export function getBridgeSplWithBufferedCallInstructionDataEncoder(): FixedSizeEncoder<BridgeSplWithBufferedCallInstructionDataArgs> {
@@ -133,6 +186,7 @@ export function getBridgeSplWithBufferedCallInstructionDataEncoder(): FixedSizeE
       ['to', fixEncoderSize(getBytesEncoder(), 20)],
       ['remoteToken', fixEncoderSize(getBytesEncoder(), 20)],
       ['amount', getU64Encoder()],
+      ['splMemo', getBooleanEncoder()],
     ]),
     (value) => ({
       ...value,
@@ -148,6 +202,7 @@ export function getBridgeSplWithBufferedCallInstructionDataDecoder(): FixedSizeD
     ['to', fixDecoderSize(getBytesDecoder(), 20)],
     ['remoteToken', fixDecoderSize(getBytesDecoder(), 20)],
     ['amount', getU64Decoder()],
+    ['splMemo', getBooleanDecoder()],
   ]);
 }
 
@@ -165,15 +220,27 @@ export type BridgeSplWithBufferedCallInput<
   TAccountPayer extends string = string,
   TAccountFrom extends string = string,
   TAccountGasFeeReceiver extends string = string,
+  TAccountGasFeeEscrow extends string = string,
+  TAccountInsuranceFund extends string = string,
+  TAccountPayerRateLimitConfig extends string = string,
+  TAccountPayerRateLimit extends string = string,
+  TAccountProtocolFeeConfig extends string = string,
+  TAccountProtocolFeeTreasury extends string = string,
+  TAccountDexRouterList extends string = string,
+  TAccountMintPolicy extends string = string,
+  TAccountMintAllowList extends string = string,
   TAccountMint extends string = string,
   TAccountFromTokenAccount extends string = string,
   TAccountBridge extends string = string,
   TAccountTokenVault extends string = string,
+  TAccountTokenDeposits extends string = string,
+  TAccountVaultDecimals extends string = string,
   TAccountOwner extends string = string,
   TAccountCallBuffer extends string = string,
   TAccountOutgoingMessage extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountMemoProgram extends string = string,
 > = {
   /**
    * The account that pays for transaction fees and account creation.
@@ -183,10 +250,47 @@ export type BridgeSplWithBufferedCallInput<
   /**
    * The token authority authorizing the transfer of SPL tokens.
    * This signer must be the owner or an approved delegate for the source token account.
+   * It pays for nothing, so it needs no lamports when `payer` sponsors the transaction.
    */
   from: TransactionSigner<TAccountFrom>;
   /** The account that receives payment for the gas costs of bridging the SPL token to Base. */
   gasFeeReceiver: Address<TAccountGasFeeReceiver>;
+  /** Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited. */
+  gasFeeEscrow: Address<TAccountGasFeeEscrow>;
+  /**
+   * Insurance fund credited with its share of the gas fee. No share is taken while it is
+   * uninitialized.
+   */
+  insuranceFund: Address<TAccountInsuranceFund>;
+  /** Payer rate limiting configuration. Payers are not rate limited while it is uninitialized. */
+  payerRateLimitConfig: Address<TAccountPayerRateLimitConfig>;
+  /**
+   * Outgoing messages paid for by the payer in the current rate limiting window, created on its
+   * first message while rate limiting is enabled.
+   */
+  payerRateLimit: Address<TAccountPayerRateLimit>;
+  /** Protocol fee configuration. No protocol fee is charged while it is uninitialized. */
+  protocolFeeConfig: Address<TAccountProtocolFeeConfig>;
+  /**
+   * The treasury token account of `mint` credited with the protocol fee.
+   * Ignored when no protocol fee is charged.
+   */
+  protocolFeeTreasury: Address<TAccountProtocolFeeTreasury>;
+  /**
+   * DEX router list whose routers may only be called with a swap guard. Calls are not checked
+   * while it is uninitialized.
+   */
+  dexRouterList: Address<TAccountDexRouterList>;
+  /**
+   * Guardian-maintained policy restricting the freeze authority of bridged mints.
+   * No freeze authority policy is enforced while it is uninitialized.
+   */
+  mintPolicy: Address<TAccountMintPolicy>;
+  /**
+   * Guardian-maintained allow-list of the mints accepted during guarded launch.
+   * Only checked while `bridge.guarded_launch` is set.
+   */
+  mintAllowList: Address<TAccountMintAllowList>;
   /**
    * The SPL token mint account for the token being bridged.
    * - Must not be a wrapped token (wrapped tokens use bridge_wrapped_token)
@@ -214,11 +318,24 @@ export type BridgeSplWithBufferedCallInput<
    * - Acts as the custody account for tokens being bridged to Base
    */
   tokenVault: Address<TAccountTokenVault>;
+  /**
+   * Accounting for the amount locked in the token vault.
+   * - PDA of this program using `[TOKEN_DEPOSITS_SEED, token_vault]`
+   * - Created on the first deposit; `locked_amount` is incremented by the received amount
+   */
+  tokenDeposits: Address<TAccountTokenDeposits>;
+  /**
+   * Decimals of the Base token the vault is bridged to.
+   * - The transfer amount is carried in the smallest unit of the remote token
+   * - Amounts are not scaled while it is uninitialized
+   *
+   */
+  vaultDecimals: Address<TAccountVaultDecimals>;
   /** The owner of the call buffer who will receive the rent refund. */
   owner: TransactionSigner<TAccountOwner>;
   /**
    * The call buffer account that stores the call data.
-   * This account will be closed and rent returned to the owner.
+   * This account will be closed and rent returned to the owner, unless it is reusable.
    */
   callBuffer: Address<TAccountCallBuffer>;
   /** The outgoing message account that stores the cross-chain transfer details. */
@@ -233,40 +350,67 @@ export type BridgeSplWithBufferedCallInput<
    * initializing the token vault when needed.
    */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** SPL Memo program, required when `spl_memo` is set to log a summary of the operation. */
+  memoProgram?: Address<TAccountMemoProgram>;
   outgoingMessageSalt: BridgeSplWithBufferedCallInstructionDataArgs['outgoingMessageSalt'];
   to: BridgeSplWithBufferedCallInstructionDataArgs['to'];
   remoteToken: BridgeSplWithBufferedCallInstructionDataArgs['remoteToken'];
   amount: BridgeSplWithBufferedCallInstructionDataArgs['amount'];
+  splMemo: BridgeSplWithBufferedCallInstructionDataArgs['splMemo'];
 };
 
 export function getBridgeSplWithBufferedCallInstruction<
   TAccountPayer extends string,
   TAccountFrom extends string,
   TAccountGasFeeReceiver extends string,
+  TAccountGasFeeEscrow extends string,
+  TAccountInsuranceFund extends string,
+  TAccountPayerRateLimitConfig extends string,
+  TAccountPayerRateLimit extends string,
+  TAccountProtocolFeeConfig extends string,
+  TAccountProtocolFeeTreasury extends string,
+  TAccountDexRouterList extends string,
+  TAccountMintPolicy extends string,
+  TAccountMintAllowList extends string,
   TAccountMint extends string,
   TAccountFromTokenAccount extends string,
   TAccountBridge extends string,
   TAccountTokenVault extends string,
+  TAccountTokenDeposits extends string,
+  TAccountVaultDecimals extends string,
   TAccountOwner extends string,
   TAccountCallBuffer extends string,
   TAccountOutgoingMessage extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountMemoProgram extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
   input: BridgeSplWithBufferedCallInput<
     TAccountPayer,
     TAccountFrom,
     TAccountGasFeeReceiver,
+    TAccountGasFeeEscrow,
+    TAccountInsuranceFund,
+    TAccountPayerRateLimitConfig,
+    TAccountPayerRateLimit,
+    TAccountProtocolFeeConfig,
+    TAccountProtocolFeeTreasury,
+    TAccountDexRouterList,
+    TAccountMintPolicy,
+    TAccountMintAllowList,
     TAccountMint,
     TAccountFromTokenAccount,
     TAccountBridge,
     TAccountTokenVault,
+    TAccountTokenDeposits,
+    TAccountVaultDecimals,
     TAccountOwner,
     TAccountCallBuffer,
     TAccountOutgoingMessage,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMemoProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): BridgeSplWithBufferedCallInstruction<
@@ -274,15 +418,27 @@ export function getBridgeSplWithBufferedCallInstruction<
   TAccountPayer,
   TAccountFrom,
   TAccountGasFeeReceiver,
+  TAccountGasFeeEscrow,
+  TAccountInsuranceFund,
+  TAccountPayerRateLimitConfig,
+  TAccountPayerRateLimit,
+  TAccountProtocolFeeConfig,
+  TAccountProtocolFeeTreasury,
+  TAccountDexRouterList,
+  TAccountMintPolicy,
+  TAccountMintAllowList,
   TAccountMint,
   TAccountFromTokenAccount,
   TAccountBridge,
   TAccountTokenVault,
+  TAccountTokenDeposits,
+  TAccountVaultDecimals,
   TAccountOwner,
   TAccountCallBuffer,
   TAccountOutgoingMessage,
   TAccountTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountMemoProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -290,8 +446,26 @@ export function getBridgeSplWithBufferedCallInstruction<
   // Original accounts.
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
-    from: { value: input.from ?? null, isWritable: true },
+    from: { value: input.from ?? null, isWritable: false },
     gasFeeReceiver: { value: input.gasFeeReceiver ?? null, isWritable: true },
+    gasFeeEscrow: { value: input.gasFeeEscrow ?? null, isWritable: true },
+    insuranceFund: { value: input.insuranceFund ?? null, isWritable: true },
+    payerRateLimitConfig: {
+      value: input.payerRateLimitConfig ?? null,
+      isWritable: false,
+    },
+    payerRateLimit: { value: input.payerRateLimit ?? null, isWritable: true },
+    protocolFeeConfig: {
+      value: input.protocolFeeConfig ?? null,
+      isWritable: false,
+    },
+    protocolFeeTreasury: {
+      value: input.protocolFeeTreasury ?? null,
+      isWritable: true,
+    },
+    dexRouterList: { value: input.dexRouterList ?? null, isWritable: false },
+    mintPolicy: { value: input.mintPolicy ?? null, isWritable: false },
+    mintAllowList: { value: input.mintAllowList ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: true },
     fromTokenAccount: {
       value: input.fromTokenAccount ?? null,
@@ -299,11 +473,14 @@ export function getBridgeSplWithBufferedCallInstruction<
     },
     bridge: { value: input.bridge ?? null, isWritable: true },
     tokenVault: { value: input.tokenVault ?? null, isWritable: true },
+    tokenDeposits: { value: input.tokenDeposits ?? null, isWritable: true },
+    vaultDecimals: { value: input.vaultDecimals ?? null, isWritable: false },
     owner: { value: input.owner ?? null, isWritable: true },
     callBuffer: { value: input.callBuffer ?? null, isWritable: true },
     outgoingMessage: { value: input.outgoingMessage ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    memoProgram: { value: input.memoProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -329,15 +506,27 @@ export function getBridgeSplWithBufferedCallInstruction<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.from),
       getAccountMeta(accounts.gasFeeReceiver),
+      getAccountMeta(accounts.gasFeeEscrow),
+      getAccountMeta(accounts.insuranceFund),
+      getAccountMeta(accounts.payerRateLimitConfig),
+      getAccountMeta(accounts.payerRateLimit),
+      getAccountMeta(accounts.protocolFeeConfig),
+      getAccountMeta(accounts.protocolFeeTreasury),
+      getAccountMeta(accounts.dexRouterList),
+      getAccountMeta(accounts.mintPolicy),
+      getAccountMeta(accounts.mintAllowList),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.fromTokenAccount),
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.tokenVault),
+      getAccountMeta(accounts.tokenDeposits),
+      getAccountMeta(accounts.vaultDecimals),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.callBuffer),
       getAccountMeta(accounts.outgoingMessage),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.memoProgram),
     ],
     data: getBridgeSplWithBufferedCallInstructionDataEncoder().encode(
       args as BridgeSplWithBufferedCallInstructionDataArgs
@@ -348,15 +537,27 @@ export function getBridgeSplWithBufferedCallInstruction<
     TAccountPayer,
     TAccountFrom,
     TAccountGasFeeReceiver,
+    TAccountGasFeeEscrow,
+    TAccountInsuranceFund,
+    TAccountPayerRateLimitConfig,
+    TAccountPayerRateLimit,
+    TAccountProtocolFeeConfig,
+    TAccountProtocolFeeTreasury,
+    TAccountDexRouterList,
+    TAccountMintPolicy,
+    TAccountMintAllowList,
     TAccountMint,
     TAccountFromTokenAccount,
     TAccountBridge,
     TAccountTokenVault,
+    TAccountTokenDeposits,
+    TAccountVaultDecimals,
     TAccountOwner,
     TAccountCallBuffer,
     TAccountOutgoingMessage,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMemoProgram
   >);
 }
 
@@ -374,29 +575,66 @@ export type ParsedBridgeSplWithBufferedCallInstruction<
     /**
      * The token authority authorizing the transfer of SPL tokens.
      * This signer must be the owner or an approved delegate for the source token account.
+     * It pays for nothing, so it needs no lamports when `payer` sponsors the transaction.
      */
     from: TAccountMetas[1];
     /** The account that receives payment for the gas costs of bridging the SPL token to Base. */
     gasFeeReceiver: TAccountMetas[2];
+    /** Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited. */
+    gasFeeEscrow: TAccountMetas[3];
+    /**
+     * Insurance fund credited with its share of the gas fee. No share is taken while it is
+     * uninitialized.
+     */
+    insuranceFund: TAccountMetas[4];
+    /** Payer rate limiting configuration. Payers are not rate limited while it is uninitialized. */
+    payerRateLimitConfig: TAccountMetas[5];
+    /**
+     * Outgoing messages paid for by the payer in the current rate limiting window, created on its
+     * first message while rate limiting is enabled.
+     */
+    payerRateLimit: TAccountMetas[6];
+    /** Protocol fee configuration. No protocol fee is charged while it is uninitialized. */
+    protocolFeeConfig: TAccountMetas[7];
+    /**
+     * The treasury token account of `mint` credited with the protocol fee.
+     * Ignored when no protocol fee is charged.
+     */
+    protocolFeeTreasury: TAccountMetas[8];
+    /**
+     * DEX router list whose routers may only be called with a swap guard. Calls are not checked
+     * while it is uninitialized.
+     */
+    dexRouterList: TAccountMetas[9];
+    /**
+     * Guardian-maintained policy restricting the freeze authority of bridged mints.
+     * No freeze authority policy is enforced while it is uninitialized.
+     */
+    mintPolicy: TAccountMetas[10];
+    /**
+     * Guardian-maintained allow-list of the mints accepted during guarded launch.
+     * Only checked while `bridge.guarded_launch` is set.
+     */
+    mintAllowList: TAccountMetas[11];
     /**
      * The SPL token mint account for the token being bridged.
      * - Must not be a wrapped token (wrapped tokens use bridge_wrapped_token)
      * - Used to validate transfer amounts and get token metadata
      */
-    mint: TAccountMetas[3];
+    mint: TAccountMetas[12];
     /**
      * The user's token account containing the SPL tokens to be bridged.
      * - Must be owned by, or delegated to, the `from` signer (transfer authority)
      * - Tokens will be transferred from this account to the token vault
      */
-    fromTokenAccount: TAccountMetas[4];
+    fromTokenAccount: TAccountMetas[13];
     /**
      * The main bridge state account containing global bridge configuration.
      * - PDA with BRIDGE_SEED for deterministic address
      * - Tracks nonce for message ordering and EIP-1559 gas pricing
      * - Nonce is incremented after successful bridge operations
      */
-    bridge: TAccountMetas[5];
+    bridge: TAccountMetas[14];
     /**
      * The token vault account that holds locked SPL tokens during the bridge process.
      * - PDA derived from TOKEN_VAULT_SEED, mint pubkey, and remote_token address
@@ -404,26 +642,41 @@ export type ParsedBridgeSplWithBufferedCallInstruction<
      * - Token account authority is set to this vault PDA; the program signs using the PDA seeds
      * - Acts as the custody account for tokens being bridged to Base
      */
-    tokenVault: TAccountMetas[6];
+    tokenVault: TAccountMetas[15];
+    /**
+     * Accounting for the amount locked in the token vault.
+     * - PDA of this program using `[TOKEN_DEPOSITS_SEED, token_vault]`
+     * - Created on the first deposit; `locked_amount` is incremented by the received amount
+     */
+    tokenDeposits: TAccountMetas[16];
+    /**
+     * Decimals of the Base token the vault is bridged to.
+     * - The transfer amount is carried in the smallest unit of the remote token
+     * - Amounts are not scaled while it is uninitialized
+     *
+     */
+    vaultDecimals: TAccountMetas[17];
     /** The owner of the call buffer who will receive the rent refund. */
-    owner: TAccountMetas[7];
+    owner: TAccountMetas[18];
     /**
      * The call buffer account that stores the call data.
-     * This account will be closed and rent returned to the owner.
+     * This account will be closed and rent returned to the owner, unless it is reusable.
      */
-    callBuffer: TAccountMetas[8];
+    callBuffer: TAccountMetas[19];
     /** The outgoing message account that stores the cross-chain transfer details. */
-    outgoingMessage: TAccountMetas[9];
+    outgoingMessage: TAccountMetas[20];
     /**
      * The SPL Token program interface for executing token transfers.
      * Used for the transfer_checked operation to move tokens to the vault.
      */
-    tokenProgram: TAccountMetas[10];
+    tokenProgram: TAccountMetas[21];
     /**
      * System program required for creating the outgoing message account and
      * initializing the token vault when needed.
      */
-    systemProgram: TAccountMetas[11];
+    systemProgram: TAccountMetas[22];
+    /** SPL Memo program, required when `spl_memo` is set to log a summary of the operation. */
+    memoProgram?: TAccountMetas[23] | undefined;
   };
   data: BridgeSplWithBufferedCallInstructionData;
 };
@@ -436,7 +689,7 @@ export function parseBridgeSplWithBufferedCallInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedBridgeSplWithBufferedCallInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 12) {
+  if (instruction.accounts.length < 24) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -446,21 +699,39 @@ export function parseBridgeSplWithBufferedCallInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === BRIDGE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       payer: getNextAccount(),
       from: getNextAccount(),
       gasFeeReceiver: getNextAccount(),
+      gasFeeEscrow: getNextAccount(),
+      insuranceFund: getNextAccount(),
+      payerRateLimitConfig: getNextAccount(),
+      payerRateLimit: getNextAccount(),
+      protocolFeeConfig: getNextAccount(),
+      protocolFeeTreasury: getNextAccount(),
+      dexRouterList: getNextAccount(),
+      mintPolicy: getNextAccount(),
+      mintAllowList: getNextAccount(),
       mint: getNextAccount(),
       fromTokenAccount: getNextAccount(),
       bridge: getNextAccount(),
       tokenVault: getNextAccount(),
+      tokenDeposits: getNextAccount(),
+      vaultDecimals: getNextAccount(),
       owner: getNextAccount(),
       callBuffer: getNextAccount(),
       outgoingMessage: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      memoProgram: getNextOptionalAccount(),
     },
     data: getBridgeSplWithBufferedCallInstructionDataDecoder().decode(
       instruction.data
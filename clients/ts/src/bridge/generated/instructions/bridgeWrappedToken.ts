@@ -7,15 +7,21 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getOptionDecoder,
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   transformEncoder,
@@ -42,8 +48,12 @@ import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
 import {
   getCallDecoder,
   getCallEncoder,
+  getSwapGuardDecoder,
+  getSwapGuardEncoder,
   type Call,
   type CallArgs,
+  type SwapGuard,
+  type SwapGuardArgs,
 } from '../types';
 
 export const BRIDGE_WRAPPED_TOKEN_DISCRIMINATOR = new Uint8Array([
@@ -61,16 +71,29 @@ export type BridgeWrappedTokenInstruction<
   TAccountPayer extends string | AccountMeta<string> = string,
   TAccountFrom extends string | AccountMeta<string> = string,
   TAccountGasFeeReceiver extends string | AccountMeta<string> = string,
+  TAccountGasFeeEscrow extends string | AccountMeta<string> = string,
+  TAccountInsuranceFund extends string | AccountMeta<string> = string,
+  TAccountPayerRateLimitConfig extends string | AccountMeta<string> = string,
+  TAccountPayerRateLimit extends string | AccountMeta<string> = string,
+  TAccountProtocolFeeConfig extends string | AccountMeta<string> = string,
+  TAccountProtocolFeeTreasury extends string | AccountMeta<string> = string,
+  TAccountDexRouterList extends string | AccountMeta<string> = string,
   TAccountMint extends string | AccountMeta<string> = string,
+  TAccountWrappedTokenConfirmation extends
+    | string
+    | AccountMeta<string> = string,
   TAccountFromTokenAccount extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountOutgoingMessage extends string | AccountMeta<string> = string,
+  TAccountBurnReceipt extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends
     | string
     | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountMemoProgram extends string | AccountMeta<string> = string,
+  TAccountBridgeSession extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -86,9 +109,33 @@ export type BridgeWrappedTokenInstruction<
       TAccountGasFeeReceiver extends string
         ? WritableAccount<TAccountGasFeeReceiver>
         : TAccountGasFeeReceiver,
+      TAccountGasFeeEscrow extends string
+        ? WritableAccount<TAccountGasFeeEscrow>
+        : TAccountGasFeeEscrow,
+      TAccountInsuranceFund extends string
+        ? WritableAccount<TAccountInsuranceFund>
+        : TAccountInsuranceFund,
+      TAccountPayerRateLimitConfig extends string
+        ? ReadonlyAccount<TAccountPayerRateLimitConfig>
+        : TAccountPayerRateLimitConfig,
+      TAccountPayerRateLimit extends string
+        ? WritableAccount<TAccountPayerRateLimit>
+        : TAccountPayerRateLimit,
+      TAccountProtocolFeeConfig extends string
+        ? ReadonlyAccount<TAccountProtocolFeeConfig>
+        : TAccountProtocolFeeConfig,
+      TAccountProtocolFeeTreasury extends string
+        ? WritableAccount<TAccountProtocolFeeTreasury>
+        : TAccountProtocolFeeTreasury,
+      TAccountDexRouterList extends string
+        ? ReadonlyAccount<TAccountDexRouterList>
+        : TAccountDexRouterList,
       TAccountMint extends string
         ? WritableAccount<TAccountMint>
         : TAccountMint,
+      TAccountWrappedTokenConfirmation extends string
+        ? ReadonlyAccount<TAccountWrappedTokenConfirmation>
+        : TAccountWrappedTokenConfirmation,
       TAccountFromTokenAccount extends string
         ? WritableAccount<TAccountFromTokenAccount>
         : TAccountFromTokenAccount,
@@ -98,12 +145,21 @@ export type BridgeWrappedTokenInstruction<
       TAccountOutgoingMessage extends string
         ? WritableAccount<TAccountOutgoingMessage>
         : TAccountOutgoingMessage,
+      TAccountBurnReceipt extends string
+        ? WritableAccount<TAccountBurnReceipt>
+        : TAccountBurnReceipt,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountMemoProgram extends string
+        ? ReadonlyAccount<TAccountMemoProgram>
+        : TAccountMemoProgram,
+      TAccountBridgeSession extends string
+        ? WritableAccount<TAccountBridgeSession>
+        : TAccountBridgeSession,
       ...TRemainingAccounts,
     ]
   >;
@@ -114,6 +170,9 @@ export type BridgeWrappedTokenInstructionData = {
   to: ReadonlyUint8Array;
   amount: bigint;
   call: Option<Call>;
+  memo: Option<ReadonlyUint8Array>;
+  swapGuard: Option<SwapGuard>;
+  splMemo: boolean;
 };
 
 export type BridgeWrappedTokenInstructionDataArgs = {
@@ -121,6 +180,9 @@ export type BridgeWrappedTokenInstructionDataArgs = {
   to: ReadonlyUint8Array;
   amount: number | bigint;
   call: OptionOrNullable<CallArgs>;
+  memo: OptionOrNullable<ReadonlyUint8Array>;
+  swapGuard: OptionOrNullable<SwapGuardArgs>;
+  splMemo: boolean;
 };
 
 export function getBridgeWrappedTokenInstructionDataEncoder(): Encoder<BridgeWrappedTokenInstructionDataArgs> {
@@ -131,6 +193,14 @@ export function getBridgeWrappedTokenInstructionDataEncoder(): Encoder<BridgeWra
       ['to', fixEncoderSize(getBytesEncoder(), 20)],
       ['amount', getU64Encoder()],
       ['call', getOptionEncoder(getCallEncoder())],
+      [
+        'memo',
+        getOptionEncoder(
+          addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())
+        ),
+      ],
+      ['swapGuard', getOptionEncoder(getSwapGuardEncoder())],
+      ['splMemo', getBooleanEncoder()],
     ]),
     (value) => ({ ...value, discriminator: BRIDGE_WRAPPED_TOKEN_DISCRIMINATOR })
   );
@@ -143,6 +213,14 @@ export function getBridgeWrappedTokenInstructionDataDecoder(): Decoder<BridgeWra
     ['to', fixDecoderSize(getBytesDecoder(), 20)],
     ['amount', getU64Decoder()],
     ['call', getOptionDecoder(getCallDecoder())],
+    [
+      'memo',
+      getOptionDecoder(
+        addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())
+      ),
+    ],
+    ['swapGuard', getOptionDecoder(getSwapGuardDecoder())],
+    ['splMemo', getBooleanDecoder()],
   ]);
 }
 
@@ -160,12 +238,23 @@ export type BridgeWrappedTokenInput<
   TAccountPayer extends string = string,
   TAccountFrom extends string = string,
   TAccountGasFeeReceiver extends string = string,
+  TAccountGasFeeEscrow extends string = string,
+  TAccountInsuranceFund extends string = string,
+  TAccountPayerRateLimitConfig extends string = string,
+  TAccountPayerRateLimit extends string = string,
+  TAccountProtocolFeeConfig extends string = string,
+  TAccountProtocolFeeTreasury extends string = string,
+  TAccountDexRouterList extends string = string,
   TAccountMint extends string = string,
+  TAccountWrappedTokenConfirmation extends string = string,
   TAccountFromTokenAccount extends string = string,
   TAccountBridge extends string = string,
   TAccountOutgoingMessage extends string = string,
+  TAccountBurnReceipt extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountMemoProgram extends string = string,
+  TAccountBridgeSession extends string = string,
 > = {
   /**
    * The account that pays for transaction fees and outgoing message account creation.
@@ -179,12 +268,43 @@ export type BridgeWrappedTokenInput<
   from: TransactionSigner<TAccountFrom>;
   /** The account that receives payment for the gas costs of bridging the token on Base. */
   gasFeeReceiver: Address<TAccountGasFeeReceiver>;
+  /** Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited. */
+  gasFeeEscrow: Address<TAccountGasFeeEscrow>;
+  /**
+   * Insurance fund credited with its share of the gas fee. No share is taken while it is
+   * uninitialized.
+   */
+  insuranceFund: Address<TAccountInsuranceFund>;
+  /** Payer rate limiting configuration. Payers are not rate limited while it is uninitialized. */
+  payerRateLimitConfig: Address<TAccountPayerRateLimitConfig>;
+  /**
+   * Outgoing messages paid for by the payer in the current rate limiting window, created on its
+   * first message while rate limiting is enabled.
+   */
+  payerRateLimit: Address<TAccountPayerRateLimit>;
+  /** Protocol fee configuration. No protocol fee is charged while it is uninitialized. */
+  protocolFeeConfig: Address<TAccountProtocolFeeConfig>;
+  /**
+   * The treasury token account of `mint` credited with the protocol fee.
+   * Ignored when no protocol fee is charged.
+   */
+  protocolFeeTreasury: Address<TAccountProtocolFeeTreasury>;
+  /**
+   * DEX router list whose routers may only be called with a swap guard. Calls are not checked
+   * while it is uninitialized.
+   */
+  dexRouterList: Address<TAccountDexRouterList>;
   /**
    * The wrapped token mint account representing the original Base token.
    * - Contains metadata linking to the original token on Base
    * - Tokens will be burned from this mint
    */
   mint: Address<TAccountMint>;
+  /**
+   * Confirmation that Base registered the wrapped token.
+   * Only required to exist while `bridge.require_wrapped_token_confirmation` is set.
+   */
+  wrappedTokenConfirmation: Address<TAccountWrappedTokenConfirmation>;
   /**
    * The user's token account holding the wrapped tokens to be bridged.
    * - Must contain sufficient token balance for the bridge amount
@@ -200,10 +320,15 @@ export type BridgeWrappedTokenInput<
   /**
    * The outgoing message account being created to store bridge transfer data.
    * - Contains transfer details and optional call data for Base execution
-   * - Space allocated based on call data size
+   * - Space allocated based on call data and memo sizes
    * - Will be read by Base relayers to complete the bridge operation
    */
   outgoingMessage: Address<TAccountOutgoingMessage>;
+  /**
+   * Immutable receipt of the burn, linking it to the Base recipient for compliance accounting.
+   * - PDA with BURN_RECEIPT_SEED and the outgoing message address
+   */
+  burnReceipt: Address<TAccountBurnReceipt>;
   /**
    * Token2022 program used for burning the wrapped tokens.
    * Required for all token operations including burn_checked.
@@ -214,34 +339,66 @@ export type BridgeWrappedTokenInput<
    * and transferring the gas payment to the `gas_fee_receiver`.
    */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** SPL Memo program, required when `spl_memo` is set to log a summary of the operation. */
+  memoProgram?: Address<TAccountMemoProgram>;
+  /**
+   * Optional session letting `from` bridge as the delegate of the `from_token_account` owner.
+   * The tokens are burned by the session PDA and `amount` is debited from its budget.
+   */
+  bridgeSession?: Address<TAccountBridgeSession>;
   outgoingMessageSalt: BridgeWrappedTokenInstructionDataArgs['outgoingMessageSalt'];
   to: BridgeWrappedTokenInstructionDataArgs['to'];
   amount: BridgeWrappedTokenInstructionDataArgs['amount'];
   call: BridgeWrappedTokenInstructionDataArgs['call'];
+  memo: BridgeWrappedTokenInstructionDataArgs['memo'];
+  swapGuard: BridgeWrappedTokenInstructionDataArgs['swapGuard'];
+  splMemo: BridgeWrappedTokenInstructionDataArgs['splMemo'];
 };
 
 export function getBridgeWrappedTokenInstruction<
   TAccountPayer extends string,
   TAccountFrom extends string,
   TAccountGasFeeReceiver extends string,
+  TAccountGasFeeEscrow extends string,
+  TAccountInsuranceFund extends string,
+  TAccountPayerRateLimitConfig extends string,
+  TAccountPayerRateLimit extends string,
+  TAccountProtocolFeeConfig extends string,
+  TAccountProtocolFeeTreasury extends string,
+  TAccountDexRouterList extends string,
   TAccountMint extends string,
+  TAccountWrappedTokenConfirmation extends string,
   TAccountFromTokenAccount extends string,
   TAccountBridge extends string,
   TAccountOutgoingMessage extends string,
+  TAccountBurnReceipt extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountMemoProgram extends string,
+  TAccountBridgeSession extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
   input: BridgeWrappedTokenInput<
     TAccountPayer,
     TAccountFrom,
     TAccountGasFeeReceiver,
+    TAccountGasFeeEscrow,
+    TAccountInsuranceFund,
+    TAccountPayerRateLimitConfig,
+    TAccountPayerRateLimit,
+    TAccountProtocolFeeConfig,
+    TAccountProtocolFeeTreasury,
+    TAccountDexRouterList,
     TAccountMint,
+    TAccountWrappedTokenConfirmation,
     TAccountFromTokenAccount,
     TAccountBridge,
     TAccountOutgoingMessage,
+    TAccountBurnReceipt,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMemoProgram,
+    TAccountBridgeSession
   >,
   config?: { programAddress?: TProgramAddress }
 ): BridgeWrappedTokenInstruction<
@@ -249,12 +406,23 @@ export function getBridgeWrappedTokenInstruction<
   TAccountPayer,
   TAccountFrom,
   TAccountGasFeeReceiver,
+  TAccountGasFeeEscrow,
+  TAccountInsuranceFund,
+  TAccountPayerRateLimitConfig,
+  TAccountPayerRateLimit,
+  TAccountProtocolFeeConfig,
+  TAccountProtocolFeeTreasury,
+  TAccountDexRouterList,
   TAccountMint,
+  TAccountWrappedTokenConfirmation,
   TAccountFromTokenAccount,
   TAccountBridge,
   TAccountOutgoingMessage,
+  TAccountBurnReceipt,
   TAccountTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountMemoProgram,
+  TAccountBridgeSession
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -264,15 +432,38 @@ export function getBridgeWrappedTokenInstruction<
     payer: { value: input.payer ?? null, isWritable: true },
     from: { value: input.from ?? null, isWritable: false },
     gasFeeReceiver: { value: input.gasFeeReceiver ?? null, isWritable: true },
+    gasFeeEscrow: { value: input.gasFeeEscrow ?? null, isWritable: true },
+    insuranceFund: { value: input.insuranceFund ?? null, isWritable: true },
+    payerRateLimitConfig: {
+      value: input.payerRateLimitConfig ?? null,
+      isWritable: false,
+    },
+    payerRateLimit: { value: input.payerRateLimit ?? null, isWritable: true },
+    protocolFeeConfig: {
+      value: input.protocolFeeConfig ?? null,
+      isWritable: false,
+    },
+    protocolFeeTreasury: {
+      value: input.protocolFeeTreasury ?? null,
+      isWritable: true,
+    },
+    dexRouterList: { value: input.dexRouterList ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: true },
+    wrappedTokenConfirmation: {
+      value: input.wrappedTokenConfirmation ?? null,
+      isWritable: false,
+    },
     fromTokenAccount: {
       value: input.fromTokenAccount ?? null,
       isWritable: true,
     },
     bridge: { value: input.bridge ?? null, isWritable: true },
     outgoingMessage: { value: input.outgoingMessage ?? null, isWritable: true },
+    burnReceipt: { value: input.burnReceipt ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    memoProgram: { value: input.memoProgram ?? null, isWritable: false },
+    bridgeSession: { value: input.bridgeSession ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -298,12 +489,23 @@ export function getBridgeWrappedTokenInstruction<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.from),
       getAccountMeta(accounts.gasFeeReceiver),
+      getAccountMeta(accounts.gasFeeEscrow),
+      getAccountMeta(accounts.insuranceFund),
+      getAccountMeta(accounts.payerRateLimitConfig),
+      getAccountMeta(accounts.payerRateLimit),
+      getAccountMeta(accounts.protocolFeeConfig),
+      getAccountMeta(accounts.protocolFeeTreasury),
+      getAccountMeta(accounts.dexRouterList),
       getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.wrappedTokenConfirmation),
       getAccountMeta(accounts.fromTokenAccount),
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.outgoingMessage),
+      getAccountMeta(accounts.burnReceipt),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.memoProgram),
+      getAccountMeta(accounts.bridgeSession),
     ],
     data: getBridgeWrappedTokenInstructionDataEncoder().encode(
       args as BridgeWrappedTokenInstructionDataArgs
@@ -314,12 +516,23 @@ export function getBridgeWrappedTokenInstruction<
     TAccountPayer,
     TAccountFrom,
     TAccountGasFeeReceiver,
+    TAccountGasFeeEscrow,
+    TAccountInsuranceFund,
+    TAccountPayerRateLimitConfig,
+    TAccountPayerRateLimit,
+    TAccountProtocolFeeConfig,
+    TAccountProtocolFeeTreasury,
+    TAccountDexRouterList,
     TAccountMint,
+    TAccountWrappedTokenConfirmation,
     TAccountFromTokenAccount,
     TAccountBridge,
     TAccountOutgoingMessage,
+    TAccountBurnReceipt,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMemoProgram,
+    TAccountBridgeSession
   >);
 }
 
@@ -341,41 +554,84 @@ export type ParsedBridgeWrappedTokenInstruction<
     from: TAccountMetas[1];
     /** The account that receives payment for the gas costs of bridging the token on Base. */
     gasFeeReceiver: TAccountMetas[2];
+    /** Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited. */
+    gasFeeEscrow: TAccountMetas[3];
+    /**
+     * Insurance fund credited with its share of the gas fee. No share is taken while it is
+     * uninitialized.
+     */
+    insuranceFund: TAccountMetas[4];
+    /** Payer rate limiting configuration. Payers are not rate limited while it is uninitialized. */
+    payerRateLimitConfig: TAccountMetas[5];
+    /**
+     * Outgoing messages paid for by the payer in the current rate limiting window, created on its
+     * first message while rate limiting is enabled.
+     */
+    payerRateLimit: TAccountMetas[6];
+    /** Protocol fee configuration. No protocol fee is charged while it is uninitialized. */
+    protocolFeeConfig: TAccountMetas[7];
+    /**
+     * The treasury token account of `mint` credited with the protocol fee.
+     * Ignored when no protocol fee is charged.
+     */
+    protocolFeeTreasury: TAccountMetas[8];
+    /**
+     * DEX router list whose routers may only be called with a swap guard. Calls are not checked
+     * while it is uninitialized.
+     */
+    dexRouterList: TAccountMetas[9];
     /**
      * The wrapped token mint account representing the original Base token.
      * - Contains metadata linking to the original token on Base
      * - Tokens will be burned from this mint
      */
-    mint: TAccountMetas[3];
+    mint: TAccountMetas[10];
+    /**
+     * Confirmation that Base registered the wrapped token.
+     * Only required to exist while `bridge.require_wrapped_token_confirmation` is set.
+     */
+    wrappedTokenConfirmation: TAccountMetas[11];
     /**
      * The user's token account holding the wrapped tokens to be bridged.
      * - Must contain sufficient token balance for the bridge amount
      * - Tokens will be burned from this account
      */
-    fromTokenAccount: TAccountMetas[4];
+    fromTokenAccount: TAccountMetas[12];
     /**
      * The main bridge state account storing global bridge configuration.
      * - Uses PDA with BRIDGE_SEED for deterministic address
      * - Tracks nonce for message ordering and EIP-1559 gas pricing
      */
-    bridge: TAccountMetas[5];
+    bridge: TAccountMetas[13];
     /**
      * The outgoing message account being created to store bridge transfer data.
      * - Contains transfer details and optional call data for Base execution
-     * - Space allocated based on call data size
+     * - Space allocated based on call data and memo sizes
      * - Will be read by Base relayers to complete the bridge operation
      */
-    outgoingMessage: TAccountMetas[6];
+    outgoingMessage: TAccountMetas[14];
+    /**
+     * Immutable receipt of the burn, linking it to the Base recipient for compliance accounting.
+     * - PDA with BURN_RECEIPT_SEED and the outgoing message address
+     */
+    burnReceipt: TAccountMetas[15];
     /**
      * Token2022 program used for burning the wrapped tokens.
      * Required for all token operations including burn_checked.
      */
-    tokenProgram: TAccountMetas[7];
+    tokenProgram: TAccountMetas[16];
     /**
      * System program required for creating the outgoing message account
      * and transferring the gas payment to the `gas_fee_receiver`.
      */
-    systemProgram: TAccountMetas[8];
+    systemProgram: TAccountMetas[17];
+    /** SPL Memo program, required when `spl_memo` is set to log a summary of the operation. */
+    memoProgram?: TAccountMetas[18] | undefined;
+    /**
+     * Optional session letting `from` bridge as the delegate of the `from_token_account` owner.
+     * The tokens are burned by the session PDA and `amount` is debited from its budget.
+     */
+    bridgeSession?: TAccountMetas[19] | undefined;
   };
   data: BridgeWrappedTokenInstructionData;
 };
@@ -388,7 +644,7 @@ export function parseBridgeWrappedTokenInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedBridgeWrappedTokenInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 20) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -398,18 +654,35 @@ export function parseBridgeWrappedTokenInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === BRIDGE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       payer: getNextAccount(),
       from: getNextAccount(),
       gasFeeReceiver: getNextAccount(),
+      gasFeeEscrow: getNextAccount(),
+      insuranceFund: getNextAccount(),
+      payerRateLimitConfig: getNextAccount(),
+      payerRateLimit: getNextAccount(),
+      protocolFeeConfig: getNextAccount(),
+      protocolFeeTreasury: getNextAccount(),
+      dexRouterList: getNextAccount(),
       mint: getNextAccount(),
+      wrappedTokenConfirmation: getNextAccount(),
       fromTokenAccount: getNextAccount(),
       bridge: getNextAccount(),
       outgoingMessage: getNextAccount(),
+      burnReceipt: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      memoProgram: getNextOptionalAccount(),
+      bridgeSession: getNextOptionalAccount(),
     },
     data: getBridgeWrappedTokenInstructionDataDecoder().decode(
       instruction.data
@@ -10,6 +10,8 @@ import {
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
+  getBooleanDecoder,
+  getBooleanEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
@@ -50,18 +52,30 @@ export type BridgeWrappedTokenWithBufferedCallInstruction<
   TAccountPayer extends string | AccountMeta<string> = string,
   TAccountFrom extends string | AccountMeta<string> = string,
   TAccountGasFeeReceiver extends string | AccountMeta<string> = string,
+  TAccountGasFeeEscrow extends string | AccountMeta<string> = string,
+  TAccountInsuranceFund extends string | AccountMeta<string> = string,
+  TAccountPayerRateLimitConfig extends string | AccountMeta<string> = string,
+  TAccountPayerRateLimit extends string | AccountMeta<string> = string,
+  TAccountProtocolFeeConfig extends string | AccountMeta<string> = string,
+  TAccountProtocolFeeTreasury extends string | AccountMeta<string> = string,
+  TAccountDexRouterList extends string | AccountMeta<string> = string,
   TAccountMint extends string | AccountMeta<string> = string,
+  TAccountWrappedTokenConfirmation extends
+    | string
+    | AccountMeta<string> = string,
   TAccountFromTokenAccount extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountOwner extends string | AccountMeta<string> = string,
   TAccountCallBuffer extends string | AccountMeta<string> = string,
   TAccountOutgoingMessage extends string | AccountMeta<string> = string,
+  TAccountBurnReceipt extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends
     | string
     | AccountMeta<string> = 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA',
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountMemoProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -77,9 +91,33 @@ export type BridgeWrappedTokenWithBufferedCallInstruction<
       TAccountGasFeeReceiver extends string
         ? WritableAccount<TAccountGasFeeReceiver>
         : TAccountGasFeeReceiver,
+      TAccountGasFeeEscrow extends string
+        ? WritableAccount<TAccountGasFeeEscrow>
+        : TAccountGasFeeEscrow,
+      TAccountInsuranceFund extends string
+        ? WritableAccount<TAccountInsuranceFund>
+        : TAccountInsuranceFund,
+      TAccountPayerRateLimitConfig extends string
+        ? ReadonlyAccount<TAccountPayerRateLimitConfig>
+        : TAccountPayerRateLimitConfig,
+      TAccountPayerRateLimit extends string
+        ? WritableAccount<TAccountPayerRateLimit>
+        : TAccountPayerRateLimit,
+      TAccountProtocolFeeConfig extends string
+        ? ReadonlyAccount<TAccountProtocolFeeConfig>
+        : TAccountProtocolFeeConfig,
+      TAccountProtocolFeeTreasury extends string
+        ? WritableAccount<TAccountProtocolFeeTreasury>
+        : TAccountProtocolFeeTreasury,
+      TAccountDexRouterList extends string
+        ? ReadonlyAccount<TAccountDexRouterList>
+        : TAccountDexRouterList,
       TAccountMint extends string
         ? WritableAccount<TAccountMint>
         : TAccountMint,
+      TAccountWrappedTokenConfirmation extends string
+        ? ReadonlyAccount<TAccountWrappedTokenConfirmation>
+        : TAccountWrappedTokenConfirmation,
       TAccountFromTokenAccount extends string
         ? WritableAccount<TAccountFromTokenAccount>
         : TAccountFromTokenAccount,
@@ -96,12 +134,18 @@ export type BridgeWrappedTokenWithBufferedCallInstruction<
       TAccountOutgoingMessage extends string
         ? WritableAccount<TAccountOutgoingMessage>
         : TAccountOutgoingMessage,
+      TAccountBurnReceipt extends string
+        ? WritableAccount<TAccountBurnReceipt>
+        : TAccountBurnReceipt,
       TAccountTokenProgram extends string
         ? ReadonlyAccount<TAccountTokenProgram>
         : TAccountTokenProgram,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountMemoProgram extends string
+        ? ReadonlyAccount<TAccountMemoProgram>
+        : TAccountMemoProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -111,12 +155,14 @@ export type BridgeWrappedTokenWithBufferedCallInstructionData = {
   outgoingMessageSalt: ReadonlyUint8Array;
   to: ReadonlyUint8Array;
   amount: bigint;
+  splMemo: boolean;
 };
 
 export type BridgeWrappedTokenWithBufferedCallInstructionDataArgs = {
   outgoingMessageSalt: ReadonlyUint8Array;
   to: ReadonlyUint8Array;
   amount: number | bigint;
+  splMemo: boolean;
 };
 
 export function getBridgeWrappedTokenWithBufferedCallInstructionDataEncoder(): FixedSizeEncoder<BridgeWrappedTokenWithBufferedCallInstructionDataArgs> {
@@ -126,6 +172,7 @@ export function getBridgeWrappedTokenWithBufferedCallInstructionDataEncoder(): F
       ['outgoingMessageSalt', fixEncoderSize(getBytesEncoder(), 32)],
       ['to', fixEncoderSize(getBytesEncoder(), 20)],
       ['amount', getU64Encoder()],
+      ['splMemo', getBooleanEncoder()],
     ]),
     (value) => ({
       ...value,
@@ -140,6 +187,7 @@ export function getBridgeWrappedTokenWithBufferedCallInstructionDataDecoder(): F
     ['outgoingMessageSalt', fixDecoderSize(getBytesDecoder(), 32)],
     ['to', fixDecoderSize(getBytesDecoder(), 20)],
     ['amount', getU64Decoder()],
+    ['splMemo', getBooleanDecoder()],
   ]);
 }
 
@@ -157,14 +205,24 @@ export type BridgeWrappedTokenWithBufferedCallInput<
   TAccountPayer extends string = string,
   TAccountFrom extends string = string,
   TAccountGasFeeReceiver extends string = string,
+  TAccountGasFeeEscrow extends string = string,
+  TAccountInsuranceFund extends string = string,
+  TAccountPayerRateLimitConfig extends string = string,
+  TAccountPayerRateLimit extends string = string,
+  TAccountProtocolFeeConfig extends string = string,
+  TAccountProtocolFeeTreasury extends string = string,
+  TAccountDexRouterList extends string = string,
   TAccountMint extends string = string,
+  TAccountWrappedTokenConfirmation extends string = string,
   TAccountFromTokenAccount extends string = string,
   TAccountBridge extends string = string,
   TAccountOwner extends string = string,
   TAccountCallBuffer extends string = string,
   TAccountOutgoingMessage extends string = string,
+  TAccountBurnReceipt extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountMemoProgram extends string = string,
 > = {
   /**
    * The account that pays for transaction fees, gas fees, and outgoing message account creation.
@@ -181,12 +239,43 @@ export type BridgeWrappedTokenWithBufferedCallInput<
    * Mutable because lamports are transferred to this account.
    */
   gasFeeReceiver: Address<TAccountGasFeeReceiver>;
+  /** Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited. */
+  gasFeeEscrow: Address<TAccountGasFeeEscrow>;
+  /**
+   * Insurance fund credited with its share of the gas fee. No share is taken while it is
+   * uninitialized.
+   */
+  insuranceFund: Address<TAccountInsuranceFund>;
+  /** Payer rate limiting configuration. Payers are not rate limited while it is uninitialized. */
+  payerRateLimitConfig: Address<TAccountPayerRateLimitConfig>;
+  /**
+   * Outgoing messages paid for by the payer in the current rate limiting window, created on its
+   * first message while rate limiting is enabled.
+   */
+  payerRateLimit: Address<TAccountPayerRateLimit>;
+  /** Protocol fee configuration. No protocol fee is charged while it is uninitialized. */
+  protocolFeeConfig: Address<TAccountProtocolFeeConfig>;
+  /**
+   * The treasury token account of `mint` credited with the protocol fee.
+   * Ignored when no protocol fee is charged.
+   */
+  protocolFeeTreasury: Address<TAccountProtocolFeeTreasury>;
+  /**
+   * DEX router list whose routers may only be called with a swap guard. Calls are not checked
+   * while it is uninitialized.
+   */
+  dexRouterList: Address<TAccountDexRouterList>;
   /**
    * The wrapped token mint account representing the original Base token.
    * - Contains metadata linking to the original token on Base
    * - Supply will be reduced by burning tokens from the user's token account for this mint
    */
   mint: Address<TAccountMint>;
+  /**
+   * Confirmation that Base registered the wrapped token.
+   * Only required to exist while `bridge.require_wrapped_token_confirmation` is set.
+   */
+  wrappedTokenConfirmation: Address<TAccountWrappedTokenConfirmation>;
   /**
    * The user's token account holding the wrapped tokens to be bridged.
    * - Must contain sufficient token balance for the bridge amount
@@ -204,7 +293,7 @@ export type BridgeWrappedTokenWithBufferedCallInput<
   owner: TransactionSigner<TAccountOwner>;
   /**
    * The call buffer account that stores the call data.
-   * This account will be closed and rent returned to the owner.
+   * This account will be closed and rent returned to the owner, unless it is reusable.
    */
   callBuffer: Address<TAccountCallBuffer>;
   /**
@@ -212,41 +301,69 @@ export type BridgeWrappedTokenWithBufferedCallInput<
    * Space is sized based on the current call buffer length so the call data fits.
    */
   outgoingMessage: Address<TAccountOutgoingMessage>;
+  /**
+   * Immutable receipt of the burn, linking it to the Base recipient for compliance accounting.
+   * - PDA with BURN_RECEIPT_SEED and the outgoing message address
+   */
+  burnReceipt: Address<TAccountBurnReceipt>;
   /** Token2022 program used for burning the wrapped tokens (burn_checked). */
   tokenProgram?: Address<TAccountTokenProgram>;
   /** System program required for creating the outgoing message account and transferring gas fees. */
   systemProgram?: Address<TAccountSystemProgram>;
+  /** SPL Memo program, required when `spl_memo` is set to log a summary of the operation. */
+  memoProgram?: Address<TAccountMemoProgram>;
   outgoingMessageSalt: BridgeWrappedTokenWithBufferedCallInstructionDataArgs['outgoingMessageSalt'];
   to: BridgeWrappedTokenWithBufferedCallInstructionDataArgs['to'];
   amount: BridgeWrappedTokenWithBufferedCallInstructionDataArgs['amount'];
+  splMemo: BridgeWrappedTokenWithBufferedCallInstructionDataArgs['splMemo'];
 };
 
 export function getBridgeWrappedTokenWithBufferedCallInstruction<
   TAccountPayer extends string,
   TAccountFrom extends string,
   TAccountGasFeeReceiver extends string,
+  TAccountGasFeeEscrow extends string,
+  TAccountInsuranceFund extends string,
+  TAccountPayerRateLimitConfig extends string,
+  TAccountPayerRateLimit extends string,
+  TAccountProtocolFeeConfig extends string,
+  TAccountProtocolFeeTreasury extends string,
+  TAccountDexRouterList extends string,
   TAccountMint extends string,
+  TAccountWrappedTokenConfirmation extends string,
   TAccountFromTokenAccount extends string,
   TAccountBridge extends string,
   TAccountOwner extends string,
   TAccountCallBuffer extends string,
   TAccountOutgoingMessage extends string,
+  TAccountBurnReceipt extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountMemoProgram extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
   input: BridgeWrappedTokenWithBufferedCallInput<
     TAccountPayer,
     TAccountFrom,
     TAccountGasFeeReceiver,
+    TAccountGasFeeEscrow,
+    TAccountInsuranceFund,
+    TAccountPayerRateLimitConfig,
+    TAccountPayerRateLimit,
+    TAccountProtocolFeeConfig,
+    TAccountProtocolFeeTreasury,
+    TAccountDexRouterList,
     TAccountMint,
+    TAccountWrappedTokenConfirmation,
     TAccountFromTokenAccount,
     TAccountBridge,
     TAccountOwner,
     TAccountCallBuffer,
     TAccountOutgoingMessage,
+    TAccountBurnReceipt,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMemoProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): BridgeWrappedTokenWithBufferedCallInstruction<
@@ -254,14 +371,24 @@ export function getBridgeWrappedTokenWithBufferedCallInstruction<
   TAccountPayer,
   TAccountFrom,
   TAccountGasFeeReceiver,
+  TAccountGasFeeEscrow,
+  TAccountInsuranceFund,
+  TAccountPayerRateLimitConfig,
+  TAccountPayerRateLimit,
+  TAccountProtocolFeeConfig,
+  TAccountProtocolFeeTreasury,
+  TAccountDexRouterList,
   TAccountMint,
+  TAccountWrappedTokenConfirmation,
   TAccountFromTokenAccount,
   TAccountBridge,
   TAccountOwner,
   TAccountCallBuffer,
   TAccountOutgoingMessage,
+  TAccountBurnReceipt,
   TAccountTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountMemoProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -271,7 +398,27 @@ export function getBridgeWrappedTokenWithBufferedCallInstruction<
     payer: { value: input.payer ?? null, isWritable: true },
     from: { value: input.from ?? null, isWritable: false },
     gasFeeReceiver: { value: input.gasFeeReceiver ?? null, isWritable: true },
+    gasFeeEscrow: { value: input.gasFeeEscrow ?? null, isWritable: true },
+    insuranceFund: { value: input.insuranceFund ?? null, isWritable: true },
+    payerRateLimitConfig: {
+      value: input.payerRateLimitConfig ?? null,
+      isWritable: false,
+    },
+    payerRateLimit: { value: input.payerRateLimit ?? null, isWritable: true },
+    protocolFeeConfig: {
+      value: input.protocolFeeConfig ?? null,
+      isWritable: false,
+    },
+    protocolFeeTreasury: {
+      value: input.protocolFeeTreasury ?? null,
+      isWritable: true,
+    },
+    dexRouterList: { value: input.dexRouterList ?? null, isWritable: false },
     mint: { value: input.mint ?? null, isWritable: true },
+    wrappedTokenConfirmation: {
+      value: input.wrappedTokenConfirmation ?? null,
+      isWritable: false,
+    },
     fromTokenAccount: {
       value: input.fromTokenAccount ?? null,
       isWritable: true,
@@ -280,8 +427,10 @@ export function getBridgeWrappedTokenWithBufferedCallInstruction<
     owner: { value: input.owner ?? null, isWritable: true },
     callBuffer: { value: input.callBuffer ?? null, isWritable: true },
     outgoingMessage: { value: input.outgoingMessage ?? null, isWritable: true },
+    burnReceipt: { value: input.burnReceipt ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    memoProgram: { value: input.memoProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -307,14 +456,24 @@ export function getBridgeWrappedTokenWithBufferedCallInstruction<
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.from),
       getAccountMeta(accounts.gasFeeReceiver),
+      getAccountMeta(accounts.gasFeeEscrow),
+      getAccountMeta(accounts.insuranceFund),
+      getAccountMeta(accounts.payerRateLimitConfig),
+      getAccountMeta(accounts.payerRateLimit),
+      getAccountMeta(accounts.protocolFeeConfig),
+      getAccountMeta(accounts.protocolFeeTreasury),
+      getAccountMeta(accounts.dexRouterList),
       getAccountMeta(accounts.mint),
+      getAccountMeta(accounts.wrappedTokenConfirmation),
       getAccountMeta(accounts.fromTokenAccount),
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.callBuffer),
       getAccountMeta(accounts.outgoingMessage),
+      getAccountMeta(accounts.burnReceipt),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.memoProgram),
     ],
     data: getBridgeWrappedTokenWithBufferedCallInstructionDataEncoder().encode(
       args as BridgeWrappedTokenWithBufferedCallInstructionDataArgs
@@ -325,14 +484,24 @@ export function getBridgeWrappedTokenWithBufferedCallInstruction<
     TAccountPayer,
     TAccountFrom,
     TAccountGasFeeReceiver,
+    TAccountGasFeeEscrow,
+    TAccountInsuranceFund,
+    TAccountPayerRateLimitConfig,
+    TAccountPayerRateLimit,
+    TAccountProtocolFeeConfig,
+    TAccountProtocolFeeTreasury,
+    TAccountDexRouterList,
     TAccountMint,
+    TAccountWrappedTokenConfirmation,
     TAccountFromTokenAccount,
     TAccountBridge,
     TAccountOwner,
     TAccountCallBuffer,
     TAccountOutgoingMessage,
+    TAccountBurnReceipt,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMemoProgram
   >);
 }
 
@@ -357,41 +526,79 @@ export type ParsedBridgeWrappedTokenWithBufferedCallInstruction<
      * Mutable because lamports are transferred to this account.
      */
     gasFeeReceiver: TAccountMetas[2];
+    /** Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited. */
+    gasFeeEscrow: TAccountMetas[3];
+    /**
+     * Insurance fund credited with its share of the gas fee. No share is taken while it is
+     * uninitialized.
+     */
+    insuranceFund: TAccountMetas[4];
+    /** Payer rate limiting configuration. Payers are not rate limited while it is uninitialized. */
+    payerRateLimitConfig: TAccountMetas[5];
+    /**
+     * Outgoing messages paid for by the payer in the current rate limiting window, created on its
+     * first message while rate limiting is enabled.
+     */
+    payerRateLimit: TAccountMetas[6];
+    /** Protocol fee configuration. No protocol fee is charged while it is uninitialized. */
+    protocolFeeConfig: TAccountMetas[7];
+    /**
+     * The treasury token account of `mint` credited with the protocol fee.
+     * Ignored when no protocol fee is charged.
+     */
+    protocolFeeTreasury: TAccountMetas[8];
+    /**
+     * DEX router list whose routers may only be called with a swap guard. Calls are not checked
+     * while it is uninitialized.
+     */
+    dexRouterList: TAccountMetas[9];
     /**
      * The wrapped token mint account representing the original Base token.
      * - Contains metadata linking to the original token on Base
      * - Supply will be reduced by burning tokens from the user's token account for this mint
      */
-    mint: TAccountMetas[3];
+    mint: TAccountMetas[10];
+    /**
+     * Confirmation that Base registered the wrapped token.
+     * Only required to exist while `bridge.require_wrapped_token_confirmation` is set.
+     */
+    wrappedTokenConfirmation: TAccountMetas[11];
     /**
      * The user's token account holding the wrapped tokens to be bridged.
      * - Must contain sufficient token balance for the bridge amount
      * - Tokens will be burned from this account
      * - The burn authority must be the `from` signer (or a valid delegate)
      */
-    fromTokenAccount: TAccountMetas[4];
+    fromTokenAccount: TAccountMetas[12];
     /**
      * The main bridge state account storing global bridge configuration.
      * - Uses PDA with `BRIDGE_SEED` for deterministic address
      * - Tracks `nonce` for message ordering and maintains EIP-1559 fee state
      */
-    bridge: TAccountMetas[5];
+    bridge: TAccountMetas[13];
     /** The owner of the call buffer who will receive the rent refund. */
-    owner: TAccountMetas[6];
+    owner: TAccountMetas[14];
     /**
      * The call buffer account that stores the call data.
-     * This account will be closed and rent returned to the owner.
+     * This account will be closed and rent returned to the owner, unless it is reusable.
      */
-    callBuffer: TAccountMetas[7];
+    callBuffer: TAccountMetas[15];
     /**
      * The outgoing message account that stores the cross-chain transfer details.
      * Space is sized based on the current call buffer length so the call data fits.
      */
-    outgoingMessage: TAccountMetas[8];
+    outgoingMessage: TAccountMetas[16];
+    /**
+     * Immutable receipt of the burn, linking it to the Base recipient for compliance accounting.
+     * - PDA with BURN_RECEIPT_SEED and the outgoing message address
+     */
+    burnReceipt: TAccountMetas[17];
     /** Token2022 program used for burning the wrapped tokens (burn_checked). */
-    tokenProgram: TAccountMetas[9];
+    tokenProgram: TAccountMetas[18];
     /** System program required for creating the outgoing message account and transferring gas fees. */
-    systemProgram: TAccountMetas[10];
+    systemProgram: TAccountMetas[19];
+    /** SPL Memo program, required when `spl_memo` is set to log a summary of the operation. */
+    memoProgram?: TAccountMetas[20] | undefined;
   };
   data: BridgeWrappedTokenWithBufferedCallInstructionData;
 };
@@ -407,7 +614,7 @@ export function parseBridgeWrappedTokenWithBufferedCallInstruction<
   TProgram,
   TAccountMetas
 > {
-  if (instruction.accounts.length < 11) {
+  if (instruction.accounts.length < 21) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -417,20 +624,36 @@ export function parseBridgeWrappedTokenWithBufferedCallInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === BRIDGE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       payer: getNextAccount(),
       from: getNextAccount(),
       gasFeeReceiver: getNextAccount(),
+      gasFeeEscrow: getNextAccount(),
+      insuranceFund: getNextAccount(),
+      payerRateLimitConfig: getNextAccount(),
+      payerRateLimit: getNextAccount(),
+      protocolFeeConfig: getNextAccount(),
+      protocolFeeTreasury: getNextAccount(),
+      dexRouterList: getNextAccount(),
       mint: getNextAccount(),
+      wrappedTokenConfirmation: getNextAccount(),
       fromTokenAccount: getNextAccount(),
       bridge: getNextAccount(),
       owner: getNextAccount(),
       callBuffer: getNextAccount(),
       outgoingMessage: getNextAccount(),
+      burnReceipt: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      memoProgram: getNextOptionalAccount(),
     },
     data: getBridgeWrappedTokenWithBufferedCallInstructionDataDecoder().decode(
       instruction.data
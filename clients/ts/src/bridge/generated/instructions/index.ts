@@ -6,38 +6,113 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './activateOracleEmergency';
 export * from './appendToCallBuffer';
 export * from './appendToProveBufferData';
 export * from './appendToProveBufferProof';
+export * from './approveOutflow';
+export * from './auditVault';
 export * from './bridgeCall';
 export * from './bridgeCallBuffered';
+export * from './bridgeEncryptedCall';
 export * from './bridgeSol';
+export * from './bridgeSolBatch';
 export * from './bridgeSolWithBufferedCall';
 export * from './bridgeSpl';
 export * from './bridgeSplWithBufferedCall';
 export * from './bridgeWrappedToken';
 export * from './bridgeWrappedTokenWithBufferedCall';
+export * from './cancelEmergencyExit';
+export * from './cancelInsurancePayout';
+export * from './checkEnvironment';
+export * from './clearSolvencyAlert';
+export * from './cloneCallBuffer';
 export * from './closeCallBuffer';
 export * from './closeProveBuffer';
+export * from './closeRelayedOutgoingMessage';
+export * from './closeSupersededMessage';
+export * from './confirmRelayed';
+export * from './createBridgeSession';
+export * from './crosschainMint';
+export * from './deactivateOracleEmergency';
+export * from './deriveCpiAuthority';
+export * from './emergencyWithdrawSol';
+export * from './emergencyWithdrawSpl';
+export * from './enableEmergencyExit';
+export * from './executeInsurancePayout';
+export * from './expireCallBuffer';
+export * from './extendProveBuffer';
+export * from './getEnvironment';
+export * from './getOracleHealth';
+export * from './getVersion';
+export * from './initConfigHistory';
+export * from './initSolVault';
 export * from './initialize';
 export * from './initializeCallBuffer';
+export * from './initializeDevnetDefaults';
 export * from './initializeProveBuffer';
+export * from './migrateBridgeState';
+export * from './pauseFromGovernance';
+export * from './postLiabilityRoot';
 export * from './proveMessage';
 export * from './proveMessageBuffered';
+export * from './proveMessageChunked';
+export * from './reconcileVault';
+export * from './recordRelayExecution';
+export * from './registerHandler';
 export * from './registerOutputRoot';
+export * from './registerOutputRootsBatch';
 export * from './relayMessage';
+export * from './reportEquivocation';
+export * from './requestInsurancePayout';
+export * from './revokeBridgeSession';
+export * from './scaleWrappedTokenAmount';
+export * from './setAddressBook';
 export * from './setAdjustmentDenominator';
+export * from './setBaseGovernance';
 export * from './setBlockIntervalRequirement';
+export * from './setCallBufferReusable';
+export * from './setCallBufferTtl';
+export * from './setDexRouter';
+export * from './setExpireBountyBps';
+export * from './setFeeConfig';
+export * from './setFeeQuoteGracePeriod';
+export * from './setFinalizationDelay';
 export * from './setGasCostScaler';
 export * from './setGasCostScalerDp';
 export * from './setGasFeeReceiver';
 export * from './setGasPerCall';
 export * from './setGasTarget';
+export * from './setGovernanceMode';
+export * from './setGuardedLaunch';
+export * from './setInsuranceShare';
+export * from './setMaintenanceBudget';
+export * from './setMaxBaseFee';
 export * from './setMaxCallBufferSize';
+export * from './setMaxLamportAllowance';
+export * from './setMaxWindowIncreaseBps';
 export * from './setMinimumBaseFee';
+export * from './setMintAllowed';
+export * from './setMintPolicy';
+export * from './setOracleFailoverConfig';
 export * from './setOracleSigners';
+export * from './setOracleSignersFromGovernance';
+export * from './setOutflowBreaker';
 export * from './setPartnerOracleConfig';
 export * from './setPauseStatus';
+export * from './setPayerRateLimit';
+export * from './setProtocolFeeConfig';
+export * from './setRateLimitExemptPayer';
+export * from './setRelayDenyList';
+export * from './setRootFreshnessConfig';
+export * from './setTransferGasLane';
+export * from './setVaultDecimals';
 export * from './setWindowDuration';
+export * from './setWrappedTokenConfirmationRequired';
+export * from './setWrappedTokenMaxSupply';
+export * from './settleRelayFees';
+export * from './supersedeOutputRoot';
+export * from './sweepGasFeeEscrow';
 export * from './transferGuardian';
+export * from './vetoMessage';
 export * from './wrapToken';
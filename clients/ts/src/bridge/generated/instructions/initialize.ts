@@ -77,6 +77,7 @@ export type InitializeInstruction<
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountProgramData extends string | AccountMeta<string> = string,
   TAccountProgram extends string | AccountMeta<string> = string,
+  TAccountGasFeeReceiver extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -102,6 +103,9 @@ export type InitializeInstruction<
       TAccountProgram extends string
         ? ReadonlyAccount<TAccountProgram>
         : TAccountProgram,
+      TAccountGasFeeReceiver extends string
+        ? ReadonlyAccount<TAccountGasFeeReceiver>
+        : TAccountGasFeeReceiver,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -187,6 +191,7 @@ export type InitializeInput<
   TAccountBridge extends string = string,
   TAccountProgramData extends string = string,
   TAccountProgram extends string = string,
+  TAccountGasFeeReceiver extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   /**
@@ -217,6 +222,8 @@ export type InitializeInput<
    * Validates that program_data is the correct ProgramData account for this program.
    */
   program: Address<TAccountProgram>;
+  /** The gas fee receiver configured in `cfg.gas_config`. Must be a system account. */
+  gasFeeReceiver: Address<TAccountGasFeeReceiver>;
   /**
    * System program required for creating new accounts.
    * Used internally by Anchor for account initialization.
@@ -237,6 +244,7 @@ export function getInitializeInstruction<
   TAccountBridge extends string,
   TAccountProgramData extends string,
   TAccountProgram extends string,
+  TAccountGasFeeReceiver extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
@@ -246,6 +254,7 @@ export function getInitializeInstruction<
     TAccountBridge,
     TAccountProgramData,
     TAccountProgram,
+    TAccountGasFeeReceiver,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -256,6 +265,7 @@ export function getInitializeInstruction<
   TAccountBridge,
   TAccountProgramData,
   TAccountProgram,
+  TAccountGasFeeReceiver,
   TAccountSystemProgram
 > {
   // Program address.
@@ -271,6 +281,7 @@ export function getInitializeInstruction<
     bridge: { value: input.bridge ?? null, isWritable: true },
     programData: { value: input.programData ?? null, isWritable: false },
     program: { value: input.program ?? null, isWritable: false },
+    gasFeeReceiver: { value: input.gasFeeReceiver ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -295,6 +306,7 @@ export function getInitializeInstruction<
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.programData),
       getAccountMeta(accounts.program),
+      getAccountMeta(accounts.gasFeeReceiver),
       getAccountMeta(accounts.systemProgram),
     ],
     data: getInitializeInstructionDataEncoder().encode(
@@ -308,6 +320,7 @@ export function getInitializeInstruction<
     TAccountBridge,
     TAccountProgramData,
     TAccountProgram,
+    TAccountGasFeeReceiver,
     TAccountSystemProgram
   >);
 }
@@ -346,11 +359,13 @@ export type ParsedInitializeInstruction<
      * Validates that program_data is the correct ProgramData account for this program.
      */
     program: TAccountMetas[4];
+    /** The gas fee receiver configured in `cfg.gas_config`. Must be a system account. */
+    gasFeeReceiver: TAccountMetas[5];
     /**
      * System program required for creating new accounts.
      * Used internally by Anchor for account initialization.
      */
-    systemProgram: TAccountMetas[5];
+    systemProgram: TAccountMetas[6];
   };
   data: InitializeInstructionData;
 };
@@ -363,7 +378,7 @@ export function parseInitializeInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedInitializeInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 6) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -381,6 +396,7 @@ export function parseInitializeInstruction<
       bridge: getNextAccount(),
       programData: getNextAccount(),
       program: getNextAccount(),
+      gasFeeReceiver: getNextAccount(),
       systemProgram: getNextAccount(),
     },
     data: getInitializeInstructionDataDecoder().decode(instruction.data),
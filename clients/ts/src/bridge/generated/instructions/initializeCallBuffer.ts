@@ -159,6 +159,9 @@ export type InitializeCallBufferInput<
    * The call buffer account being initialized.
    * Space is allocated for up to `max_data_len` bytes of `data` (plus the Vec length prefix).
    * The bridge configuration enforces an upper bound via `buffer_config.max_call_buffer_size`.
+   * As a non-PDA `init` account it must sign the transaction, so no one else can create a
+   * buffer at its address, and accounts already owned by this program are rejected. Lamports
+   * sent to the address beforehand are kept and do not prevent initialization.
    */
   callBuffer: TransactionSigner<TAccountCallBuffer>;
   /** System program required for creating new accounts */
@@ -253,6 +256,9 @@ export type ParsedInitializeCallBufferInstruction<
      * The call buffer account being initialized.
      * Space is allocated for up to `max_data_len` bytes of `data` (plus the Vec length prefix).
      * The bridge configuration enforces an upper bound via `buffer_config.max_call_buffer_size`.
+     * As a non-PDA `init` account it must sign the transaction, so no one else can create a
+     * buffer at its address, and accounts already owned by this program are rejected. Lamports
+     * sent to the address beforehand are kept and do not prevent initialization.
      */
     callBuffer: TAccountMetas[2];
     /** System program required for creating new accounts */
@@ -57,6 +57,7 @@ export type ProveMessageInstruction<
   TAccountOutputRoot extends string | AccountMeta<string> = string,
   TAccountMessage extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
+  TAccountAddressBook extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
@@ -78,6 +79,9 @@ export type ProveMessageInstruction<
       TAccountBridge extends string
         ? ReadonlyAccount<TAccountBridge>
         : TAccountBridge,
+      TAccountAddressBook extends string
+        ? ReadonlyAccount<TAccountAddressBook>
+        : TAccountAddressBook,
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
@@ -142,17 +146,20 @@ export type ProveMessageInput<
   TAccountOutputRoot extends string = string,
   TAccountMessage extends string = string,
   TAccountBridge extends string = string,
+  TAccountAddressBook extends string = string,
   TAccountSystemProgram extends string = string,
 > = {
   /**
    * The account that pays for the transaction and incoming message account creation.
    * Must be mutable to deduct lamports for account rent.
+   * Recorded as the prover of the message, refunded the rent freed when it is relayed.
    */
   payer: TransactionSigner<TAccountPayer>;
   /**
    * The output root account containing the MMR root from Base.
    * Used to verify that the message proof is valid against the committed state.
-   * This root must have been previously registered via register_output_root instruction.
+   * Any root registered for the message's Base block or a later one can be used, with a proof
+   * built against its `total_leaf_count`.
    */
   outputRoot: Address<TAccountOutputRoot>;
   /**
@@ -168,6 +175,11 @@ export type ProveMessageInput<
    * - Uses PDA with BRIDGE_SEED for deterministic address
    */
   bridge: Address<TAccountBridge>;
+  /**
+   * The address book holding the canonical Base Bridge address.
+   * Required for `ReturnTransfer` messages, which are only accepted from the Base Bridge.
+   */
+  addressBook?: Address<TAccountAddressBook>;
   /**
    * System program required for creating new accounts.
    * Used internally by Anchor for account initialization.
@@ -185,6 +197,7 @@ export function getProveMessageInstruction<
   TAccountOutputRoot extends string,
   TAccountMessage extends string,
   TAccountBridge extends string,
+  TAccountAddressBook extends string,
   TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
@@ -193,6 +206,7 @@ export function getProveMessageInstruction<
     TAccountOutputRoot,
     TAccountMessage,
     TAccountBridge,
+    TAccountAddressBook,
     TAccountSystemProgram
   >,
   config?: { programAddress?: TProgramAddress }
@@ -202,6 +216,7 @@ export function getProveMessageInstruction<
   TAccountOutputRoot,
   TAccountMessage,
   TAccountBridge,
+  TAccountAddressBook,
   TAccountSystemProgram
 > {
   // Program address.
@@ -213,6 +228,7 @@ export function getProveMessageInstruction<
     outputRoot: { value: input.outputRoot ?? null, isWritable: false },
     message: { value: input.message ?? null, isWritable: true },
     bridge: { value: input.bridge ?? null, isWritable: false },
+    addressBook: { value: input.addressBook ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
@@ -236,6 +252,7 @@ export function getProveMessageInstruction<
       getAccountMeta(accounts.outputRoot),
       getAccountMeta(accounts.message),
       getAccountMeta(accounts.bridge),
+      getAccountMeta(accounts.addressBook),
       getAccountMeta(accounts.systemProgram),
     ],
     data: getProveMessageInstructionDataEncoder().encode(
@@ -248,6 +265,7 @@ export function getProveMessageInstruction<
     TAccountOutputRoot,
     TAccountMessage,
     TAccountBridge,
+    TAccountAddressBook,
     TAccountSystemProgram
   >);
 }
@@ -261,12 +279,14 @@ export type ParsedProveMessageInstruction<
     /**
      * The account that pays for the transaction and incoming message account creation.
      * Must be mutable to deduct lamports for account rent.
+     * Recorded as the prover of the message, refunded the rent freed when it is relayed.
      */
     payer: TAccountMetas[0];
     /**
      * The output root account containing the MMR root from Base.
      * Used to verify that the message proof is valid against the committed state.
-     * This root must have been previously registered via register_output_root instruction.
+     * Any root registered for the message's Base block or a later one can be used, with a proof
+     * built against its `total_leaf_count`.
      */
     outputRoot: TAccountMetas[1];
     /**
@@ -282,11 +302,16 @@ export type ParsedProveMessageInstruction<
      * - Uses PDA with BRIDGE_SEED for deterministic address
      */
     bridge: TAccountMetas[3];
+    /**
+     * The address book holding the canonical Base Bridge address.
+     * Required for `ReturnTransfer` messages, which are only accepted from the Base Bridge.
+     */
+    addressBook?: TAccountMetas[4] | undefined;
     /**
      * System program required for creating new accounts.
      * Used internally by Anchor for account initialization.
      */
-    systemProgram: TAccountMetas[4];
+    systemProgram: TAccountMetas[5];
   };
   data: ProveMessageInstructionData;
 };
@@ -299,7 +324,7 @@ export function parseProveMessageInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedProveMessageInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 6) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -309,6 +334,12 @@ export function parseProveMessageInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === BRIDGE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -316,6 +347,7 @@ export function parseProveMessageInstruction<
       outputRoot: getNextAccount(),
       message: getNextAccount(),
       bridge: getNextAccount(),
+      addressBook: getNextOptionalAccount(),
       systemProgram: getNextAccount(),
     },
     data: getProveMessageInstructionDataDecoder().decode(instruction.data),
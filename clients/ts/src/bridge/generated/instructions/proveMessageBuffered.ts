@@ -51,6 +51,7 @@ export type ProveMessageBufferedInstruction<
   TAccountOutputRoot extends string | AccountMeta<string> = string,
   TAccountMessage extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
+  TAccountAddressBook extends string | AccountMeta<string> = string,
   TAccountOwner extends string | AccountMeta<string> = string,
   TAccountProveBuffer extends string | AccountMeta<string> = string,
   TAccountSystemProgram extends
@@ -74,6 +75,9 @@ export type ProveMessageBufferedInstruction<
       TAccountBridge extends string
         ? ReadonlyAccount<TAccountBridge>
         : TAccountBridge,
+      TAccountAddressBook extends string
+        ? ReadonlyAccount<TAccountAddressBook>
+        : TAccountAddressBook,
       TAccountOwner extends string
         ? WritableSignerAccount<TAccountOwner> &
             AccountSignerMeta<TAccountOwner>
@@ -140,6 +144,7 @@ export type ProveMessageBufferedInput<
   TAccountOutputRoot extends string = string,
   TAccountMessage extends string = string,
   TAccountBridge extends string = string,
+  TAccountAddressBook extends string = string,
   TAccountOwner extends string = string,
   TAccountProveBuffer extends string = string,
   TAccountSystemProgram extends string = string,
@@ -152,6 +157,8 @@ export type ProveMessageBufferedInput<
   message: Address<TAccountMessage>;
   /** Bridge for pause check */
   bridge: Address<TAccountBridge>;
+  /** Address book holding the canonical Base Bridge, required for `ReturnTransfer` messages */
+  addressBook?: Address<TAccountAddressBook>;
   /** Owner receives rent when buffer is closed */
   owner: TransactionSigner<TAccountOwner>;
   /** Prove buffer containing data and proof; closed on success */
@@ -167,6 +174,7 @@ export function getProveMessageBufferedInstruction<
   TAccountOutputRoot extends string,
   TAccountMessage extends string,
   TAccountBridge extends string,
+  TAccountAddressBook extends string,
   TAccountOwner extends string,
   TAccountProveBuffer extends string,
   TAccountSystemProgram extends string,
@@ -177,6 +185,7 @@ export function getProveMessageBufferedInstruction<
     TAccountOutputRoot,
     TAccountMessage,
     TAccountBridge,
+    TAccountAddressBook,
     TAccountOwner,
     TAccountProveBuffer,
     TAccountSystemProgram
@@ -188,6 +197,7 @@ export function getProveMessageBufferedInstruction<
   TAccountOutputRoot,
   TAccountMessage,
   TAccountBridge,
+  TAccountAddressBook,
   TAccountOwner,
   TAccountProveBuffer,
   TAccountSystemProgram
@@ -201,6 +211,7 @@ export function getProveMessageBufferedInstruction<
     outputRoot: { value: input.outputRoot ?? null, isWritable: false },
     message: { value: input.message ?? null, isWritable: true },
     bridge: { value: input.bridge ?? null, isWritable: false },
+    addressBook: { value: input.addressBook ?? null, isWritable: false },
     owner: { value: input.owner ?? null, isWritable: true },
     proveBuffer: { value: input.proveBuffer ?? null, isWritable: true },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
@@ -226,6 +237,7 @@ export function getProveMessageBufferedInstruction<
       getAccountMeta(accounts.outputRoot),
       getAccountMeta(accounts.message),
       getAccountMeta(accounts.bridge),
+      getAccountMeta(accounts.addressBook),
       getAccountMeta(accounts.owner),
       getAccountMeta(accounts.proveBuffer),
       getAccountMeta(accounts.systemProgram),
@@ -240,6 +252,7 @@ export function getProveMessageBufferedInstruction<
     TAccountOutputRoot,
     TAccountMessage,
     TAccountBridge,
+    TAccountAddressBook,
     TAccountOwner,
     TAccountProveBuffer,
     TAccountSystemProgram
@@ -260,11 +273,13 @@ export type ParsedProveMessageBufferedInstruction<
     message: TAccountMetas[2];
     /** Bridge for pause check */
     bridge: TAccountMetas[3];
+    /** Address book holding the canonical Base Bridge, required for `ReturnTransfer` messages */
+    addressBook?: TAccountMetas[4] | undefined;
     /** Owner receives rent when buffer is closed */
-    owner: TAccountMetas[4];
+    owner: TAccountMetas[5];
     /** Prove buffer containing data and proof; closed on success */
-    proveBuffer: TAccountMetas[5];
-    systemProgram: TAccountMetas[6];
+    proveBuffer: TAccountMetas[6];
+    systemProgram: TAccountMetas[7];
   };
   data: ProveMessageBufferedInstructionData;
 };
@@ -277,7 +292,7 @@ export function parseProveMessageBufferedInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedProveMessageBufferedInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 8) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -287,6 +302,12 @@ export function parseProveMessageBufferedInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === BRIDGE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -294,6 +315,7 @@ export function parseProveMessageBufferedInstruction<
       outputRoot: getNextAccount(),
       message: getNextAccount(),
       bridge: getNextAccount(),
+      addressBook: getNextOptionalAccount(),
       owner: getNextAccount(),
       proveBuffer: getNextAccount(),
       systemProgram: getNextAccount(),
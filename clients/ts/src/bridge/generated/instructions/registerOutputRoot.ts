@@ -56,6 +56,8 @@ export type RegisterOutputRootInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountInstructionsSysvar extends string | AccountMeta<string> = string,
+  TAccountMaintenanceBudget extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -77,6 +79,12 @@ export type RegisterOutputRootInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountInstructionsSysvar extends string
+        ? ReadonlyAccount<TAccountInstructionsSysvar>
+        : TAccountInstructionsSysvar,
+      TAccountMaintenanceBudget extends string
+        ? WritableAccount<TAccountMaintenanceBudget>
+        : TAccountMaintenanceBudget,
       ...TRemainingAccounts,
     ]
   >;
@@ -135,8 +143,14 @@ export type RegisterOutputRootInput<
   TAccountBridge extends string = string,
   TAccountPartnerConfig extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountInstructionsSysvar extends string = string,
+  TAccountMaintenanceBudget extends string = string,
 > = {
-  /** Payer funds the account creation. Authorization is enforced via oracle EVM signature. */
+  /**
+   * Payer funds the account creation. Any account may pay: the payer is not pinned to an
+   * oracle key, so that oracle keys can be rotated through the signer configuration alone.
+   * Authorization is enforced via oracle EVM signature.
+   */
   payer: TransactionSigner<TAccountPayer>;
   /**
    * The output root account being created to store the Base MMR root and total leaf count.
@@ -163,6 +177,13 @@ export type RegisterOutputRootInput<
    * Used internally by Anchor for output root account initialization.
    */
   systemProgram?: Address<TAccountSystemProgram>;
+  /**
+   * Instructions sysvar, required when no `signatures` are passed so that the oracle signatures
+   * verified by the preceding Secp256k1 program instructions can be introspected.
+   */
+  instructionsSysvar?: Address<TAccountInstructionsSysvar>;
+  /** The maintenance budget reimbursing the payer, possibly not created yet. */
+  maintenanceBudget: Address<TAccountMaintenanceBudget>;
   outputRoot: RegisterOutputRootInstructionDataArgs['outputRoot'];
   baseBlockNumber: RegisterOutputRootInstructionDataArgs['baseBlockNumber'];
   totalLeafCount: RegisterOutputRootInstructionDataArgs['totalLeafCount'];
@@ -175,6 +196,8 @@ export function getRegisterOutputRootInstruction<
   TAccountBridge extends string,
   TAccountPartnerConfig extends string,
   TAccountSystemProgram extends string,
+  TAccountInstructionsSysvar extends string,
+  TAccountMaintenanceBudget extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
   input: RegisterOutputRootInput<
@@ -182,7 +205,9 @@ export function getRegisterOutputRootInstruction<
     TAccountRoot,
     TAccountBridge,
     TAccountPartnerConfig,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountInstructionsSysvar,
+    TAccountMaintenanceBudget
   >,
   config?: { programAddress?: TProgramAddress }
 ): RegisterOutputRootInstruction<
@@ -191,7 +216,9 @@ export function getRegisterOutputRootInstruction<
   TAccountRoot,
   TAccountBridge,
   TAccountPartnerConfig,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountInstructionsSysvar,
+  TAccountMaintenanceBudget
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -203,6 +230,14 @@ export function getRegisterOutputRootInstruction<
     bridge: { value: input.bridge ?? null, isWritable: true },
     partnerConfig: { value: input.partnerConfig ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    instructionsSysvar: {
+      value: input.instructionsSysvar ?? null,
+      isWritable: false,
+    },
+    maintenanceBudget: {
+      value: input.maintenanceBudget ?? null,
+      isWritable: true,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -226,6 +261,8 @@ export function getRegisterOutputRootInstruction<
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.partnerConfig),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.instructionsSysvar),
+      getAccountMeta(accounts.maintenanceBudget),
     ],
     data: getRegisterOutputRootInstructionDataEncoder().encode(
       args as RegisterOutputRootInstructionDataArgs
@@ -237,7 +274,9 @@ export function getRegisterOutputRootInstruction<
     TAccountRoot,
     TAccountBridge,
     TAccountPartnerConfig,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountInstructionsSysvar,
+    TAccountMaintenanceBudget
   >);
 }
 
@@ -247,7 +286,11 @@ export type ParsedRegisterOutputRootInstruction<
 > = {
   programAddress: Address<TProgram>;
   accounts: {
-    /** Payer funds the account creation. Authorization is enforced via oracle EVM signature. */
+    /**
+     * Payer funds the account creation. Any account may pay: the payer is not pinned to an
+     * oracle key, so that oracle keys can be rotated through the signer configuration alone.
+     * Authorization is enforced via oracle EVM signature.
+     */
     payer: TAccountMetas[0];
     /**
      * The output root account being created to store the Base MMR root and total leaf count.
@@ -274,6 +317,13 @@ export type ParsedRegisterOutputRootInstruction<
      * Used internally by Anchor for output root account initialization.
      */
     systemProgram: TAccountMetas[4];
+    /**
+     * Instructions sysvar, required when no `signatures` are passed so that the oracle signatures
+     * verified by the preceding Secp256k1 program instructions can be introspected.
+     */
+    instructionsSysvar?: TAccountMetas[5] | undefined;
+    /** The maintenance budget reimbursing the payer, possibly not created yet. */
+    maintenanceBudget: TAccountMetas[6];
   };
   data: RegisterOutputRootInstructionData;
 };
@@ -286,7 +336,7 @@ export function parseRegisterOutputRootInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedRegisterOutputRootInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 5) {
+  if (instruction.accounts.length < 7) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -296,6 +346,12 @@ export function parseRegisterOutputRootInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === BRIDGE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -304,6 +360,8 @@ export function parseRegisterOutputRootInstruction<
       bridge: getNextAccount(),
       partnerConfig: getNextAccount(),
       systemProgram: getNextAccount(),
+      instructionsSysvar: getNextOptionalAccount(),
+      maintenanceBudget: getNextAccount(),
     },
     data: getRegisterOutputRootInstructionDataDecoder().decode(
       instruction.data
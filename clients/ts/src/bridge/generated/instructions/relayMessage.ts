@@ -16,6 +16,7 @@ import {
   getStructEncoder,
   transformEncoder,
   type AccountMeta,
+  type AccountSignerMeta,
   type Address,
   type FixedSizeCodec,
   type FixedSizeDecoder,
@@ -25,7 +26,9 @@ import {
   type InstructionWithData,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
+  type TransactionSigner,
   type WritableAccount,
+  type WritableSignerAccount,
 } from '@solana/kit';
 import { BRIDGE_PROGRAM_ADDRESS } from '../programs';
 import { getAccountMetaFactory, type ResolvedAccount } from '../shared';
@@ -43,7 +46,15 @@ export function getRelayMessageDiscriminatorBytes() {
 export type RelayMessageInstruction<
   TProgram extends string = typeof BRIDGE_PROGRAM_ADDRESS,
   TAccountMessage extends string | AccountMeta<string> = string,
+  TAccountOutputRoot extends string | AccountMeta<string> = string,
+  TAccountProver extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
+  TAccountRelayDenyList extends string | AccountMeta<string> = string,
+  TAccountRelayFeeVault extends string | AccountMeta<string> = string,
+  TAccountRelayer extends string | AccountMeta<string> = string,
+  TAccountOperationalVault extends string | AccountMeta<string> = string,
+  TAccountBridgeCpiAuthority extends string | AccountMeta<string> = string,
+  TAccountSystemProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -52,9 +63,34 @@ export type RelayMessageInstruction<
       TAccountMessage extends string
         ? WritableAccount<TAccountMessage>
         : TAccountMessage,
+      TAccountOutputRoot extends string
+        ? ReadonlyAccount<TAccountOutputRoot>
+        : TAccountOutputRoot,
+      TAccountProver extends string
+        ? WritableAccount<TAccountProver>
+        : TAccountProver,
       TAccountBridge extends string
-        ? ReadonlyAccount<TAccountBridge>
+        ? WritableAccount<TAccountBridge>
         : TAccountBridge,
+      TAccountRelayDenyList extends string
+        ? ReadonlyAccount<TAccountRelayDenyList>
+        : TAccountRelayDenyList,
+      TAccountRelayFeeVault extends string
+        ? WritableAccount<TAccountRelayFeeVault>
+        : TAccountRelayFeeVault,
+      TAccountRelayer extends string
+        ? WritableSignerAccount<TAccountRelayer> &
+            AccountSignerMeta<TAccountRelayer>
+        : TAccountRelayer,
+      TAccountOperationalVault extends string
+        ? WritableAccount<TAccountOperationalVault>
+        : TAccountOperationalVault,
+      TAccountBridgeCpiAuthority extends string
+        ? WritableAccount<TAccountBridgeCpiAuthority>
+        : TAccountBridgeCpiAuthority,
+      TAccountSystemProgram extends string
+        ? ReadonlyAccount<TAccountSystemProgram>
+        : TAccountSystemProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -88,7 +124,15 @@ export function getRelayMessageInstructionDataCodec(): FixedSizeCodec<
 
 export type RelayMessageInput<
   TAccountMessage extends string = string,
+  TAccountOutputRoot extends string = string,
+  TAccountProver extends string = string,
   TAccountBridge extends string = string,
+  TAccountRelayDenyList extends string = string,
+  TAccountRelayFeeVault extends string = string,
+  TAccountRelayer extends string = string,
+  TAccountOperationalVault extends string = string,
+  TAccountBridgeCpiAuthority extends string = string,
+  TAccountSystemProgram extends string = string,
 > = {
   /**
    * The incoming message account containing the cross-chain message to be executed.
@@ -97,28 +141,111 @@ export type RelayMessageInput<
    * - Prevents replay attacks by tracking execution status
    */
   message: Address<TAccountMessage>;
+  /**
+   * The output root the message was proven against.
+   * Messages proven against a revision superseded by `supersede_output_root` cannot be relayed.
+   */
+  outputRoot: Address<TAccountOutputRoot>;
+  /**
+   * The prover that paid the rent of the message account, refunded the rent freed once the
+   * message is executed.
+   */
+  prover: Address<TAccountProver>;
   /**
    * The main bridge state account used to check pause status
    * - Uses PDA with BRIDGE_SEED for deterministic address
+   * - Must be mutable to track outflows and pause the bridge when they are anomalous
    */
   bridge: Address<TAccountBridge>;
+  /** Programs denied by the guardian on top of the built-in `DENIED_RELAY_PROGRAMS`. */
+  relayDenyList: Address<TAccountRelayDenyList>;
+  /**
+   * The relay fee vault paying the relay fee of `WithRelayFee` messages.
+   * Only required to relay `WithRelayFee` messages.
+   */
+  relayFeeVault?: Address<TAccountRelayFeeVault>;
+  /**
+   * The relayer credited with the relay fee of `WithRelayFee` messages.
+   * Only required to relay `WithRelayFee` messages.
+   */
+  relayer?: TransactionSigner<TAccountRelayer>;
+  /**
+   * The operational vault lending the lamport allowance of `WithLamportAllowance` messages.
+   * Only required to relay `WithLamportAllowance` messages.
+   */
+  operationalVault?: Address<TAccountOperationalVault>;
+  /**
+   * The bridge CPI authority of the message sender, lent the lamport allowance of
+   * `WithLamportAllowance` messages. Only required to relay `WithLamportAllowance` messages.
+   */
+  bridgeCpiAuthority?: Address<TAccountBridgeCpiAuthority>;
+  /**
+   * System program returning the unspent lamport allowance to the operational vault.
+   * Only required to relay `WithLamportAllowance` messages.
+   */
+  systemProgram?: Address<TAccountSystemProgram>;
 };
 
 export function getRelayMessageInstruction<
   TAccountMessage extends string,
+  TAccountOutputRoot extends string,
+  TAccountProver extends string,
   TAccountBridge extends string,
+  TAccountRelayDenyList extends string,
+  TAccountRelayFeeVault extends string,
+  TAccountRelayer extends string,
+  TAccountOperationalVault extends string,
+  TAccountBridgeCpiAuthority extends string,
+  TAccountSystemProgram extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
-  input: RelayMessageInput<TAccountMessage, TAccountBridge>,
+  input: RelayMessageInput<
+    TAccountMessage,
+    TAccountOutputRoot,
+    TAccountProver,
+    TAccountBridge,
+    TAccountRelayDenyList,
+    TAccountRelayFeeVault,
+    TAccountRelayer,
+    TAccountOperationalVault,
+    TAccountBridgeCpiAuthority,
+    TAccountSystemProgram
+  >,
   config?: { programAddress?: TProgramAddress }
-): RelayMessageInstruction<TProgramAddress, TAccountMessage, TAccountBridge> {
+): RelayMessageInstruction<
+  TProgramAddress,
+  TAccountMessage,
+  TAccountOutputRoot,
+  TAccountProver,
+  TAccountBridge,
+  TAccountRelayDenyList,
+  TAccountRelayFeeVault,
+  TAccountRelayer,
+  TAccountOperationalVault,
+  TAccountBridgeCpiAuthority,
+  TAccountSystemProgram
+> {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
 
   // Original accounts.
   const originalAccounts = {
     message: { value: input.message ?? null, isWritable: true },
-    bridge: { value: input.bridge ?? null, isWritable: false },
+    outputRoot: { value: input.outputRoot ?? null, isWritable: false },
+    prover: { value: input.prover ?? null, isWritable: true },
+    bridge: { value: input.bridge ?? null, isWritable: true },
+    relayDenyList: { value: input.relayDenyList ?? null, isWritable: false },
+    relayFeeVault: { value: input.relayFeeVault ?? null, isWritable: true },
+    relayer: { value: input.relayer ?? null, isWritable: true },
+    operationalVault: {
+      value: input.operationalVault ?? null,
+      isWritable: true,
+    },
+    bridgeCpiAuthority: {
+      value: input.bridgeCpiAuthority ?? null,
+      isWritable: true,
+    },
+    systemProgram: { value: input.systemProgram ?? null, isWritable: false },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -129,14 +256,30 @@ export function getRelayMessageInstruction<
   return Object.freeze({
     accounts: [
       getAccountMeta(accounts.message),
+      getAccountMeta(accounts.outputRoot),
+      getAccountMeta(accounts.prover),
       getAccountMeta(accounts.bridge),
+      getAccountMeta(accounts.relayDenyList),
+      getAccountMeta(accounts.relayFeeVault),
+      getAccountMeta(accounts.relayer),
+      getAccountMeta(accounts.operationalVault),
+      getAccountMeta(accounts.bridgeCpiAuthority),
+      getAccountMeta(accounts.systemProgram),
     ],
     data: getRelayMessageInstructionDataEncoder().encode({}),
     programAddress,
   } as RelayMessageInstruction<
     TProgramAddress,
     TAccountMessage,
-    TAccountBridge
+    TAccountOutputRoot,
+    TAccountProver,
+    TAccountBridge,
+    TAccountRelayDenyList,
+    TAccountRelayFeeVault,
+    TAccountRelayer,
+    TAccountOperationalVault,
+    TAccountBridgeCpiAuthority,
+    TAccountSystemProgram
   >);
 }
 
@@ -153,11 +296,49 @@ export type ParsedRelayMessageInstruction<
      * - Prevents replay attacks by tracking execution status
      */
     message: TAccountMetas[0];
+    /**
+     * The output root the message was proven against.
+     * Messages proven against a revision superseded by `supersede_output_root` cannot be relayed.
+     */
+    outputRoot: TAccountMetas[1];
+    /**
+     * The prover that paid the rent of the message account, refunded the rent freed once the
+     * message is executed.
+     */
+    prover: TAccountMetas[2];
     /**
      * The main bridge state account used to check pause status
      * - Uses PDA with BRIDGE_SEED for deterministic address
+     * - Must be mutable to track outflows and pause the bridge when they are anomalous
+     */
+    bridge: TAccountMetas[3];
+    /** Programs denied by the guardian on top of the built-in `DENIED_RELAY_PROGRAMS`. */
+    relayDenyList: TAccountMetas[4];
+    /**
+     * The relay fee vault paying the relay fee of `WithRelayFee` messages.
+     * Only required to relay `WithRelayFee` messages.
+     */
+    relayFeeVault?: TAccountMetas[5] | undefined;
+    /**
+     * The relayer credited with the relay fee of `WithRelayFee` messages.
+     * Only required to relay `WithRelayFee` messages.
      */
-    bridge: TAccountMetas[1];
+    relayer?: TAccountMetas[6] | undefined;
+    /**
+     * The operational vault lending the lamport allowance of `WithLamportAllowance` messages.
+     * Only required to relay `WithLamportAllowance` messages.
+     */
+    operationalVault?: TAccountMetas[7] | undefined;
+    /**
+     * The bridge CPI authority of the message sender, lent the lamport allowance of
+     * `WithLamportAllowance` messages. Only required to relay `WithLamportAllowance` messages.
+     */
+    bridgeCpiAuthority?: TAccountMetas[8] | undefined;
+    /**
+     * System program returning the unspent lamport allowance to the operational vault.
+     * Only required to relay `WithLamportAllowance` messages.
+     */
+    systemProgram?: TAccountMetas[9] | undefined;
   };
   data: RelayMessageInstructionData;
 };
@@ -170,7 +351,7 @@ export function parseRelayMessageInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedRelayMessageInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -180,9 +361,26 @@ export function parseRelayMessageInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === BRIDGE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
-    accounts: { message: getNextAccount(), bridge: getNextAccount() },
+    accounts: {
+      message: getNextAccount(),
+      outputRoot: getNextAccount(),
+      prover: getNextAccount(),
+      bridge: getNextAccount(),
+      relayDenyList: getNextAccount(),
+      relayFeeVault: getNextOptionalAccount(),
+      relayer: getNextOptionalAccount(),
+      operationalVault: getNextOptionalAccount(),
+      bridgeCpiAuthority: getNextOptionalAccount(),
+      systemProgram: getNextOptionalAccount(),
+    },
     data: getRelayMessageInstructionDataDecoder().decode(instruction.data),
   };
 }
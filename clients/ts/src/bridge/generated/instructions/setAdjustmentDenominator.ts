@@ -48,6 +48,7 @@ export type SetAdjustmentDenominatorInstruction<
   TProgram extends string = typeof BRIDGE_PROGRAM_ADDRESS,
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountGuardian extends string | AccountMeta<string> = string,
+  TAccountConfigHistory extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -60,6 +61,9 @@ export type SetAdjustmentDenominatorInstruction<
         ? ReadonlySignerAccount<TAccountGuardian> &
             AccountSignerMeta<TAccountGuardian>
         : TAccountGuardian,
+      TAccountConfigHistory extends string
+        ? WritableAccount<TAccountConfigHistory>
+        : TAccountConfigHistory,
       ...TRemainingAccounts,
     ]
   >;
@@ -106,25 +110,34 @@ export function getSetAdjustmentDenominatorInstructionDataCodec(): FixedSizeCode
 export type SetAdjustmentDenominatorInput<
   TAccountBridge extends string = string,
   TAccountGuardian extends string = string,
+  TAccountConfigHistory extends string = string,
 > = {
   /** The bridge account containing configuration */
   bridge: Address<TAccountBridge>;
   /** The guardian account authorized to update configuration */
   guardian: TransactionSigner<TAccountGuardian>;
+  /** Log recording the configuration change */
+  configHistory: Address<TAccountConfigHistory>;
   newDenominator: SetAdjustmentDenominatorInstructionDataArgs['newDenominator'];
 };
 
 export function getSetAdjustmentDenominatorInstruction<
   TAccountBridge extends string,
   TAccountGuardian extends string,
+  TAccountConfigHistory extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
-  input: SetAdjustmentDenominatorInput<TAccountBridge, TAccountGuardian>,
+  input: SetAdjustmentDenominatorInput<
+    TAccountBridge,
+    TAccountGuardian,
+    TAccountConfigHistory
+  >,
   config?: { programAddress?: TProgramAddress }
 ): SetAdjustmentDenominatorInstruction<
   TProgramAddress,
   TAccountBridge,
-  TAccountGuardian
+  TAccountGuardian,
+  TAccountConfigHistory
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -133,6 +146,7 @@ export function getSetAdjustmentDenominatorInstruction<
   const originalAccounts = {
     bridge: { value: input.bridge ?? null, isWritable: true },
     guardian: { value: input.guardian ?? null, isWritable: false },
+    configHistory: { value: input.configHistory ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -147,6 +161,7 @@ export function getSetAdjustmentDenominatorInstruction<
     accounts: [
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.guardian),
+      getAccountMeta(accounts.configHistory),
     ],
     data: getSetAdjustmentDenominatorInstructionDataEncoder().encode(
       args as SetAdjustmentDenominatorInstructionDataArgs
@@ -155,7 +170,8 @@ export function getSetAdjustmentDenominatorInstruction<
   } as SetAdjustmentDenominatorInstruction<
     TProgramAddress,
     TAccountBridge,
-    TAccountGuardian
+    TAccountGuardian,
+    TAccountConfigHistory
   >);
 }
 
@@ -169,6 +185,8 @@ export type ParsedSetAdjustmentDenominatorInstruction<
     bridge: TAccountMetas[0];
     /** The guardian account authorized to update configuration */
     guardian: TAccountMetas[1];
+    /** Log recording the configuration change */
+    configHistory: TAccountMetas[2];
   };
   data: SetAdjustmentDenominatorInstructionData;
 };
@@ -181,7 +199,7 @@ export function parseSetAdjustmentDenominatorInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSetAdjustmentDenominatorInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 3) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -193,7 +211,11 @@ export function parseSetAdjustmentDenominatorInstruction<
   };
   return {
     programAddress: instruction.programAddress,
-    accounts: { bridge: getNextAccount(), guardian: getNextAccount() },
+    accounts: {
+      bridge: getNextAccount(),
+      guardian: getNextAccount(),
+      configHistory: getNextAccount(),
+    },
     data: getSetAdjustmentDenominatorInstructionDataDecoder().decode(
       instruction.data
     ),
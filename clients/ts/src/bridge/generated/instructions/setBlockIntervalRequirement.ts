@@ -48,6 +48,7 @@ export type SetBlockIntervalRequirementInstruction<
   TProgram extends string = typeof BRIDGE_PROGRAM_ADDRESS,
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountGuardian extends string | AccountMeta<string> = string,
+  TAccountConfigHistory extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -60,6 +61,9 @@ export type SetBlockIntervalRequirementInstruction<
         ? ReadonlySignerAccount<TAccountGuardian> &
             AccountSignerMeta<TAccountGuardian>
         : TAccountGuardian,
+      TAccountConfigHistory extends string
+        ? WritableAccount<TAccountConfigHistory>
+        : TAccountConfigHistory,
       ...TRemainingAccounts,
     ]
   >;
@@ -106,25 +110,34 @@ export function getSetBlockIntervalRequirementInstructionDataCodec(): FixedSizeC
 export type SetBlockIntervalRequirementInput<
   TAccountBridge extends string = string,
   TAccountGuardian extends string = string,
+  TAccountConfigHistory extends string = string,
 > = {
   /** The bridge account containing configuration */
   bridge: Address<TAccountBridge>;
   /** The guardian account authorized to update configuration */
   guardian: TransactionSigner<TAccountGuardian>;
+  /** Log recording the configuration change */
+  configHistory: Address<TAccountConfigHistory>;
   newInterval: SetBlockIntervalRequirementInstructionDataArgs['newInterval'];
 };
 
 export function getSetBlockIntervalRequirementInstruction<
   TAccountBridge extends string,
   TAccountGuardian extends string,
+  TAccountConfigHistory extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
-  input: SetBlockIntervalRequirementInput<TAccountBridge, TAccountGuardian>,
+  input: SetBlockIntervalRequirementInput<
+    TAccountBridge,
+    TAccountGuardian,
+    TAccountConfigHistory
+  >,
   config?: { programAddress?: TProgramAddress }
 ): SetBlockIntervalRequirementInstruction<
   TProgramAddress,
   TAccountBridge,
-  TAccountGuardian
+  TAccountGuardian,
+  TAccountConfigHistory
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -133,6 +146,7 @@ export function getSetBlockIntervalRequirementInstruction<
   const originalAccounts = {
     bridge: { value: input.bridge ?? null, isWritable: true },
     guardian: { value: input.guardian ?? null, isWritable: false },
+    configHistory: { value: input.configHistory ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -147,6 +161,7 @@ export function getSetBlockIntervalRequirementInstruction<
     accounts: [
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.guardian),
+      getAccountMeta(accounts.configHistory),
     ],
     data: getSetBlockIntervalRequirementInstructionDataEncoder().encode(
       args as SetBlockIntervalRequirementInstructionDataArgs
@@ -155,7 +170,8 @@ export function getSetBlockIntervalRequirementInstruction<
   } as SetBlockIntervalRequirementInstruction<
     TProgramAddress,
     TAccountBridge,
-    TAccountGuardian
+    TAccountGuardian,
+    TAccountConfigHistory
   >);
 }
 
@@ -169,6 +185,8 @@ export type ParsedSetBlockIntervalRequirementInstruction<
     bridge: TAccountMetas[0];
     /** The guardian account authorized to update configuration */
     guardian: TAccountMetas[1];
+    /** Log recording the configuration change */
+    configHistory: TAccountMetas[2];
   };
   data: SetBlockIntervalRequirementInstructionData;
 };
@@ -181,7 +199,7 @@ export function parseSetBlockIntervalRequirementInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSetBlockIntervalRequirementInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 3) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -193,7 +211,11 @@ export function parseSetBlockIntervalRequirementInstruction<
   };
   return {
     programAddress: instruction.programAddress,
-    accounts: { bridge: getNextAccount(), guardian: getNextAccount() },
+    accounts: {
+      bridge: getNextAccount(),
+      guardian: getNextAccount(),
+      configHistory: getNextAccount(),
+    },
     data: getSetBlockIntervalRequirementInstructionDataDecoder().decode(
       instruction.data
     ),
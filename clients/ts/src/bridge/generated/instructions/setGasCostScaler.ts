@@ -48,6 +48,7 @@ export type SetGasCostScalerInstruction<
   TProgram extends string = typeof BRIDGE_PROGRAM_ADDRESS,
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountGuardian extends string | AccountMeta<string> = string,
+  TAccountConfigHistory extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -60,6 +61,9 @@ export type SetGasCostScalerInstruction<
         ? ReadonlySignerAccount<TAccountGuardian> &
             AccountSignerMeta<TAccountGuardian>
         : TAccountGuardian,
+      TAccountConfigHistory extends string
+        ? WritableAccount<TAccountConfigHistory>
+        : TAccountConfigHistory,
       ...TRemainingAccounts,
     ]
   >;
@@ -103,25 +107,34 @@ export function getSetGasCostScalerInstructionDataCodec(): FixedSizeCodec<
 export type SetGasCostScalerInput<
   TAccountBridge extends string = string,
   TAccountGuardian extends string = string,
+  TAccountConfigHistory extends string = string,
 > = {
   /** The bridge account containing configuration */
   bridge: Address<TAccountBridge>;
   /** The guardian account authorized to update configuration */
   guardian: TransactionSigner<TAccountGuardian>;
+  /** Log recording the configuration change */
+  configHistory: Address<TAccountConfigHistory>;
   newScaler: SetGasCostScalerInstructionDataArgs['newScaler'];
 };
 
 export function getSetGasCostScalerInstruction<
   TAccountBridge extends string,
   TAccountGuardian extends string,
+  TAccountConfigHistory extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
-  input: SetGasCostScalerInput<TAccountBridge, TAccountGuardian>,
+  input: SetGasCostScalerInput<
+    TAccountBridge,
+    TAccountGuardian,
+    TAccountConfigHistory
+  >,
   config?: { programAddress?: TProgramAddress }
 ): SetGasCostScalerInstruction<
   TProgramAddress,
   TAccountBridge,
-  TAccountGuardian
+  TAccountGuardian,
+  TAccountConfigHistory
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -130,6 +143,7 @@ export function getSetGasCostScalerInstruction<
   const originalAccounts = {
     bridge: { value: input.bridge ?? null, isWritable: true },
     guardian: { value: input.guardian ?? null, isWritable: false },
+    configHistory: { value: input.configHistory ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -144,6 +158,7 @@ export function getSetGasCostScalerInstruction<
     accounts: [
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.guardian),
+      getAccountMeta(accounts.configHistory),
     ],
     data: getSetGasCostScalerInstructionDataEncoder().encode(
       args as SetGasCostScalerInstructionDataArgs
@@ -152,7 +167,8 @@ export function getSetGasCostScalerInstruction<
   } as SetGasCostScalerInstruction<
     TProgramAddress,
     TAccountBridge,
-    TAccountGuardian
+    TAccountGuardian,
+    TAccountConfigHistory
   >);
 }
 
@@ -166,6 +182,8 @@ export type ParsedSetGasCostScalerInstruction<
     bridge: TAccountMetas[0];
     /** The guardian account authorized to update configuration */
     guardian: TAccountMetas[1];
+    /** Log recording the configuration change */
+    configHistory: TAccountMetas[2];
   };
   data: SetGasCostScalerInstructionData;
 };
@@ -178,7 +196,7 @@ export function parseSetGasCostScalerInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSetGasCostScalerInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 3) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -190,7 +208,11 @@ export function parseSetGasCostScalerInstruction<
   };
   return {
     programAddress: instruction.programAddress,
-    accounts: { bridge: getNextAccount(), guardian: getNextAccount() },
+    accounts: {
+      bridge: getNextAccount(),
+      guardian: getNextAccount(),
+      configHistory: getNextAccount(),
+    },
     data: getSetGasCostScalerInstructionDataDecoder().decode(instruction.data),
   };
 }
@@ -48,6 +48,7 @@ export type SetGasCostScalerDpInstruction<
   TProgram extends string = typeof BRIDGE_PROGRAM_ADDRESS,
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountGuardian extends string | AccountMeta<string> = string,
+  TAccountConfigHistory extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -60,6 +61,9 @@ export type SetGasCostScalerDpInstruction<
         ? ReadonlySignerAccount<TAccountGuardian> &
             AccountSignerMeta<TAccountGuardian>
         : TAccountGuardian,
+      TAccountConfigHistory extends string
+        ? WritableAccount<TAccountConfigHistory>
+        : TAccountConfigHistory,
       ...TRemainingAccounts,
     ]
   >;
@@ -104,25 +108,34 @@ export function getSetGasCostScalerDpInstructionDataCodec(): FixedSizeCodec<
 export type SetGasCostScalerDpInput<
   TAccountBridge extends string = string,
   TAccountGuardian extends string = string,
+  TAccountConfigHistory extends string = string,
 > = {
   /** The bridge account containing configuration */
   bridge: Address<TAccountBridge>;
   /** The guardian account authorized to update configuration */
   guardian: TransactionSigner<TAccountGuardian>;
+  /** Log recording the configuration change */
+  configHistory: Address<TAccountConfigHistory>;
   newDp: SetGasCostScalerDpInstructionDataArgs['newDp'];
 };
 
 export function getSetGasCostScalerDpInstruction<
   TAccountBridge extends string,
   TAccountGuardian extends string,
+  TAccountConfigHistory extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
-  input: SetGasCostScalerDpInput<TAccountBridge, TAccountGuardian>,
+  input: SetGasCostScalerDpInput<
+    TAccountBridge,
+    TAccountGuardian,
+    TAccountConfigHistory
+  >,
   config?: { programAddress?: TProgramAddress }
 ): SetGasCostScalerDpInstruction<
   TProgramAddress,
   TAccountBridge,
-  TAccountGuardian
+  TAccountGuardian,
+  TAccountConfigHistory
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -131,6 +144,7 @@ export function getSetGasCostScalerDpInstruction<
   const originalAccounts = {
     bridge: { value: input.bridge ?? null, isWritable: true },
     guardian: { value: input.guardian ?? null, isWritable: false },
+    configHistory: { value: input.configHistory ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -145,6 +159,7 @@ export function getSetGasCostScalerDpInstruction<
     accounts: [
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.guardian),
+      getAccountMeta(accounts.configHistory),
     ],
     data: getSetGasCostScalerDpInstructionDataEncoder().encode(
       args as SetGasCostScalerDpInstructionDataArgs
@@ -153,7 +168,8 @@ export function getSetGasCostScalerDpInstruction<
   } as SetGasCostScalerDpInstruction<
     TProgramAddress,
     TAccountBridge,
-    TAccountGuardian
+    TAccountGuardian,
+    TAccountConfigHistory
   >);
 }
 
@@ -167,6 +183,8 @@ export type ParsedSetGasCostScalerDpInstruction<
     bridge: TAccountMetas[0];
     /** The guardian account authorized to update configuration */
     guardian: TAccountMetas[1];
+    /** Log recording the configuration change */
+    configHistory: TAccountMetas[2];
   };
   data: SetGasCostScalerDpInstructionData;
 };
@@ -179,7 +197,7 @@ export function parseSetGasCostScalerDpInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSetGasCostScalerDpInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 3) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -191,7 +209,11 @@ export function parseSetGasCostScalerDpInstruction<
   };
   return {
     programAddress: instruction.programAddress,
-    accounts: { bridge: getNextAccount(), guardian: getNextAccount() },
+    accounts: {
+      bridge: getNextAccount(),
+      guardian: getNextAccount(),
+      configHistory: getNextAccount(),
+    },
     data: getSetGasCostScalerDpInstructionDataDecoder().decode(
       instruction.data
     ),
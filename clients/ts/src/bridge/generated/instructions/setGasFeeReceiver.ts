@@ -26,6 +26,7 @@ import {
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type ReadonlyAccount,
   type ReadonlySignerAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -48,6 +49,8 @@ export type SetGasFeeReceiverInstruction<
   TProgram extends string = typeof BRIDGE_PROGRAM_ADDRESS,
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountGuardian extends string | AccountMeta<string> = string,
+  TAccountGasFeeReceiver extends string | AccountMeta<string> = string,
+  TAccountConfigHistory extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -60,6 +63,12 @@ export type SetGasFeeReceiverInstruction<
         ? ReadonlySignerAccount<TAccountGuardian> &
             AccountSignerMeta<TAccountGuardian>
         : TAccountGuardian,
+      TAccountGasFeeReceiver extends string
+        ? ReadonlyAccount<TAccountGasFeeReceiver>
+        : TAccountGasFeeReceiver,
+      TAccountConfigHistory extends string
+        ? WritableAccount<TAccountConfigHistory>
+        : TAccountConfigHistory,
       ...TRemainingAccounts,
     ]
   >;
@@ -101,25 +110,40 @@ export function getSetGasFeeReceiverInstructionDataCodec(): FixedSizeCodec<
 export type SetGasFeeReceiverInput<
   TAccountBridge extends string = string,
   TAccountGuardian extends string = string,
+  TAccountGasFeeReceiver extends string = string,
+  TAccountConfigHistory extends string = string,
 > = {
   /** The bridge account containing configuration */
   bridge: Address<TAccountBridge>;
   /** The guardian account authorized to update configuration */
   guardian: TransactionSigner<TAccountGuardian>;
+  /** The new gas fee receiver. Must be a system account. */
+  gasFeeReceiver: Address<TAccountGasFeeReceiver>;
+  /** Log recording the configuration change */
+  configHistory: Address<TAccountConfigHistory>;
   newReceiver: SetGasFeeReceiverInstructionDataArgs['newReceiver'];
 };
 
 export function getSetGasFeeReceiverInstruction<
   TAccountBridge extends string,
   TAccountGuardian extends string,
+  TAccountGasFeeReceiver extends string,
+  TAccountConfigHistory extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
-  input: SetGasFeeReceiverInput<TAccountBridge, TAccountGuardian>,
+  input: SetGasFeeReceiverInput<
+    TAccountBridge,
+    TAccountGuardian,
+    TAccountGasFeeReceiver,
+    TAccountConfigHistory
+  >,
   config?: { programAddress?: TProgramAddress }
 ): SetGasFeeReceiverInstruction<
   TProgramAddress,
   TAccountBridge,
-  TAccountGuardian
+  TAccountGuardian,
+  TAccountGasFeeReceiver,
+  TAccountConfigHistory
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -128,6 +152,8 @@ export function getSetGasFeeReceiverInstruction<
   const originalAccounts = {
     bridge: { value: input.bridge ?? null, isWritable: true },
     guardian: { value: input.guardian ?? null, isWritable: false },
+    gasFeeReceiver: { value: input.gasFeeReceiver ?? null, isWritable: false },
+    configHistory: { value: input.configHistory ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -142,6 +168,8 @@ export function getSetGasFeeReceiverInstruction<
     accounts: [
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.guardian),
+      getAccountMeta(accounts.gasFeeReceiver),
+      getAccountMeta(accounts.configHistory),
     ],
     data: getSetGasFeeReceiverInstructionDataEncoder().encode(
       args as SetGasFeeReceiverInstructionDataArgs
@@ -150,7 +178,9 @@ export function getSetGasFeeReceiverInstruction<
   } as SetGasFeeReceiverInstruction<
     TProgramAddress,
     TAccountBridge,
-    TAccountGuardian
+    TAccountGuardian,
+    TAccountGasFeeReceiver,
+    TAccountConfigHistory
   >);
 }
 
@@ -164,6 +194,10 @@ export type ParsedSetGasFeeReceiverInstruction<
     bridge: TAccountMetas[0];
     /** The guardian account authorized to update configuration */
     guardian: TAccountMetas[1];
+    /** The new gas fee receiver. Must be a system account. */
+    gasFeeReceiver: TAccountMetas[2];
+    /** Log recording the configuration change */
+    configHistory: TAccountMetas[3];
   };
   data: SetGasFeeReceiverInstructionData;
 };
@@ -176,7 +210,7 @@ export function parseSetGasFeeReceiverInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSetGasFeeReceiverInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 4) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -188,7 +222,12 @@ export function parseSetGasFeeReceiverInstruction<
   };
   return {
     programAddress: instruction.programAddress,
-    accounts: { bridge: getNextAccount(), guardian: getNextAccount() },
+    accounts: {
+      bridge: getNextAccount(),
+      guardian: getNextAccount(),
+      gasFeeReceiver: getNextAccount(),
+      configHistory: getNextAccount(),
+    },
     data: getSetGasFeeReceiverInstructionDataDecoder().decode(instruction.data),
   };
 }
@@ -48,6 +48,7 @@ export type SetGasPerCallInstruction<
   TProgram extends string = typeof BRIDGE_PROGRAM_ADDRESS,
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountGuardian extends string | AccountMeta<string> = string,
+  TAccountConfigHistory extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -60,6 +61,9 @@ export type SetGasPerCallInstruction<
         ? ReadonlySignerAccount<TAccountGuardian> &
             AccountSignerMeta<TAccountGuardian>
         : TAccountGuardian,
+      TAccountConfigHistory extends string
+        ? WritableAccount<TAccountConfigHistory>
+        : TAccountConfigHistory,
       ...TRemainingAccounts,
     ]
   >;
@@ -101,22 +105,35 @@ export function getSetGasPerCallInstructionDataCodec(): FixedSizeCodec<
 export type SetGasPerCallInput<
   TAccountBridge extends string = string,
   TAccountGuardian extends string = string,
+  TAccountConfigHistory extends string = string,
 > = {
   /** The bridge account containing configuration */
   bridge: Address<TAccountBridge>;
   /** The guardian account authorized to update configuration */
   guardian: TransactionSigner<TAccountGuardian>;
+  /** Log recording the configuration change */
+  configHistory: Address<TAccountConfigHistory>;
   newVal: SetGasPerCallInstructionDataArgs['newVal'];
 };
 
 export function getSetGasPerCallInstruction<
   TAccountBridge extends string,
   TAccountGuardian extends string,
+  TAccountConfigHistory extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
-  input: SetGasPerCallInput<TAccountBridge, TAccountGuardian>,
+  input: SetGasPerCallInput<
+    TAccountBridge,
+    TAccountGuardian,
+    TAccountConfigHistory
+  >,
   config?: { programAddress?: TProgramAddress }
-): SetGasPerCallInstruction<TProgramAddress, TAccountBridge, TAccountGuardian> {
+): SetGasPerCallInstruction<
+  TProgramAddress,
+  TAccountBridge,
+  TAccountGuardian,
+  TAccountConfigHistory
+> {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
 
@@ -124,6 +141,7 @@ export function getSetGasPerCallInstruction<
   const originalAccounts = {
     bridge: { value: input.bridge ?? null, isWritable: true },
     guardian: { value: input.guardian ?? null, isWritable: false },
+    configHistory: { value: input.configHistory ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -138,6 +156,7 @@ export function getSetGasPerCallInstruction<
     accounts: [
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.guardian),
+      getAccountMeta(accounts.configHistory),
     ],
     data: getSetGasPerCallInstructionDataEncoder().encode(
       args as SetGasPerCallInstructionDataArgs
@@ -146,7 +165,8 @@ export function getSetGasPerCallInstruction<
   } as SetGasPerCallInstruction<
     TProgramAddress,
     TAccountBridge,
-    TAccountGuardian
+    TAccountGuardian,
+    TAccountConfigHistory
   >);
 }
 
@@ -160,6 +180,8 @@ export type ParsedSetGasPerCallInstruction<
     bridge: TAccountMetas[0];
     /** The guardian account authorized to update configuration */
     guardian: TAccountMetas[1];
+    /** Log recording the configuration change */
+    configHistory: TAccountMetas[2];
   };
   data: SetGasPerCallInstructionData;
 };
@@ -172,7 +194,7 @@ export function parseSetGasPerCallInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSetGasPerCallInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 2) {
+  if (instruction.accounts.length < 3) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -184,7 +206,11 @@ export function parseSetGasPerCallInstruction<
   };
   return {
     programAddress: instruction.programAddress,
-    accounts: { bridge: getNextAccount(), guardian: getNextAccount() },
+    accounts: {
+      bridge: getNextAccount(),
+      guardian: getNextAccount(),
+      configHistory: getNextAccount(),
+    },
     data: getSetGasPerCallInstructionDataDecoder().decode(instruction.data),
   };
 }
@@ -55,6 +55,7 @@ export type SetOracleSignersInstruction<
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountProgramData extends string | AccountMeta<string> = string,
   TAccountProgram extends string | AccountMeta<string> = string,
+  TAccountConfigHistory extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -73,6 +74,9 @@ export type SetOracleSignersInstruction<
       TAccountProgram extends string
         ? ReadonlyAccount<TAccountProgram>
         : TAccountProgram,
+      TAccountConfigHistory extends string
+        ? WritableAccount<TAccountConfigHistory>
+        : TAccountConfigHistory,
       ...TRemainingAccounts,
     ]
   >;
@@ -116,6 +120,7 @@ export type SetOracleSignersInput<
   TAccountBridge extends string = string,
   TAccountProgramData extends string = string,
   TAccountProgram extends string = string,
+  TAccountConfigHistory extends string = string,
 > = {
   /** The upgrade authority account */
   upgradeAuthority: TransactionSigner<TAccountUpgradeAuthority>;
@@ -123,6 +128,8 @@ export type SetOracleSignersInput<
   bridge: Address<TAccountBridge>;
   programData: Address<TAccountProgramData>;
   program: Address<TAccountProgram>;
+  /** Log recording the configuration change */
+  configHistory: Address<TAccountConfigHistory>;
   cfg: SetOracleSignersInstructionDataArgs['cfg'];
 };
 
@@ -131,13 +138,15 @@ export function getSetOracleSignersInstruction<
   TAccountBridge extends string,
   TAccountProgramData extends string,
   TAccountProgram extends string,
+  TAccountConfigHistory extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
   input: SetOracleSignersInput<
     TAccountUpgradeAuthority,
     TAccountBridge,
     TAccountProgramData,
-    TAccountProgram
+    TAccountProgram,
+    TAccountConfigHistory
   >,
   config?: { programAddress?: TProgramAddress }
 ): SetOracleSignersInstruction<
@@ -145,7 +154,8 @@ export function getSetOracleSignersInstruction<
   TAccountUpgradeAuthority,
   TAccountBridge,
   TAccountProgramData,
-  TAccountProgram
+  TAccountProgram,
+  TAccountConfigHistory
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -159,6 +169,7 @@ export function getSetOracleSignersInstruction<
     bridge: { value: input.bridge ?? null, isWritable: true },
     programData: { value: input.programData ?? null, isWritable: false },
     program: { value: input.program ?? null, isWritable: false },
+    configHistory: { value: input.configHistory ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -175,6 +186,7 @@ export function getSetOracleSignersInstruction<
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.programData),
       getAccountMeta(accounts.program),
+      getAccountMeta(accounts.configHistory),
     ],
     data: getSetOracleSignersInstructionDataEncoder().encode(
       args as SetOracleSignersInstructionDataArgs
@@ -185,7 +197,8 @@ export function getSetOracleSignersInstruction<
     TAccountUpgradeAuthority,
     TAccountBridge,
     TAccountProgramData,
-    TAccountProgram
+    TAccountProgram,
+    TAccountConfigHistory
   >);
 }
 
@@ -201,6 +214,8 @@ export type ParsedSetOracleSignersInstruction<
     bridge: TAccountMetas[1];
     programData: TAccountMetas[2];
     program: TAccountMetas[3];
+    /** Log recording the configuration change */
+    configHistory: TAccountMetas[4];
   };
   data: SetOracleSignersInstructionData;
 };
@@ -213,7 +228,7 @@ export function parseSetOracleSignersInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSetOracleSignersInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 5) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -230,6 +245,7 @@ export function parseSetOracleSignersInstruction<
       bridge: getNextAccount(),
       programData: getNextAccount(),
       program: getNextAccount(),
+      configHistory: getNextAccount(),
     },
     data: getSetOracleSignersInstructionDataDecoder().decode(instruction.data),
   };
@@ -55,6 +55,7 @@ export type SetPartnerOracleConfigInstruction<
   TAccountBridge extends string | AccountMeta<string> = string,
   TAccountProgramData extends string | AccountMeta<string> = string,
   TAccountProgram extends string | AccountMeta<string> = string,
+  TAccountConfigHistory extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -73,6 +74,9 @@ export type SetPartnerOracleConfigInstruction<
       TAccountProgram extends string
         ? ReadonlyAccount<TAccountProgram>
         : TAccountProgram,
+      TAccountConfigHistory extends string
+        ? WritableAccount<TAccountConfigHistory>
+        : TAccountConfigHistory,
       ...TRemainingAccounts,
     ]
   >;
@@ -121,6 +125,7 @@ export type SetPartnerOracleConfigInput<
   TAccountBridge extends string = string,
   TAccountProgramData extends string = string,
   TAccountProgram extends string = string,
+  TAccountConfigHistory extends string = string,
 > = {
   /** The upgrade authority account */
   upgradeAuthority: TransactionSigner<TAccountUpgradeAuthority>;
@@ -128,6 +133,8 @@ export type SetPartnerOracleConfigInput<
   bridge: Address<TAccountBridge>;
   programData: Address<TAccountProgramData>;
   program: Address<TAccountProgram>;
+  /** Log recording the configuration change */
+  configHistory: Address<TAccountConfigHistory>;
   newConfig: SetPartnerOracleConfigInstructionDataArgs['newConfig'];
 };
 
@@ -136,13 +143,15 @@ export function getSetPartnerOracleConfigInstruction<
   TAccountBridge extends string,
   TAccountProgramData extends string,
   TAccountProgram extends string,
+  TAccountConfigHistory extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
   input: SetPartnerOracleConfigInput<
     TAccountUpgradeAuthority,
     TAccountBridge,
     TAccountProgramData,
-    TAccountProgram
+    TAccountProgram,
+    TAccountConfigHistory
   >,
   config?: { programAddress?: TProgramAddress }
 ): SetPartnerOracleConfigInstruction<
@@ -150,7 +159,8 @@ export function getSetPartnerOracleConfigInstruction<
   TAccountUpgradeAuthority,
   TAccountBridge,
   TAccountProgramData,
-  TAccountProgram
+  TAccountProgram,
+  TAccountConfigHistory
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -164,6 +174,7 @@ export function getSetPartnerOracleConfigInstruction<
     bridge: { value: input.bridge ?? null, isWritable: true },
     programData: { value: input.programData ?? null, isWritable: false },
     program: { value: input.program ?? null, isWritable: false },
+    configHistory: { value: input.configHistory ?? null, isWritable: true },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -180,6 +191,7 @@ export function getSetPartnerOracleConfigInstruction<
       getAccountMeta(accounts.bridge),
       getAccountMeta(accounts.programData),
       getAccountMeta(accounts.program),
+      getAccountMeta(accounts.configHistory),
     ],
     data: getSetPartnerOracleConfigInstructionDataEncoder().encode(
       args as SetPartnerOracleConfigInstructionDataArgs
@@ -190,7 +202,8 @@ export function getSetPartnerOracleConfigInstruction<
     TAccountUpgradeAuthority,
     TAccountBridge,
     TAccountProgramData,
-    TAccountProgram
+    TAccountProgram,
+    TAccountConfigHistory
   >);
 }
 
@@ -206,6 +219,8 @@ export type ParsedSetPartnerOracleConfigInstruction<
     bridge: TAccountMetas[1];
     programData: TAccountMetas[2];
     program: TAccountMetas[3];
+    /** Log recording the configuration change */
+    configHistory: TAccountMetas[4];
   };
   data: SetPartnerOracleConfigInstructionData;
 };
@@ -218,7 +233,7 @@ export function parseSetPartnerOracleConfigInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedSetPartnerOracleConfigInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 4) {
+  if (instruction.accounts.length < 5) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -235,6 +250,7 @@ export function parseSetPartnerOracleConfigInstruction<
       bridge: getNextAccount(),
       programData: getNextAccount(),
       program: getNextAccount(),
+      configHistory: getNextAccount(),
     },
     data: getSetPartnerOracleConfigInstructionDataDecoder().decode(
       instruction.data
@@ -53,8 +53,13 @@ export type WrapTokenInstruction<
   TProgram extends string = typeof BRIDGE_PROGRAM_ADDRESS,
   TAccountPayer extends string | AccountMeta<string> = string,
   TAccountGasFeeReceiver extends string | AccountMeta<string> = string,
+  TAccountGasFeeEscrow extends string | AccountMeta<string> = string,
+  TAccountInsuranceFund extends string | AccountMeta<string> = string,
+  TAccountPayerRateLimitConfig extends string | AccountMeta<string> = string,
+  TAccountPayerRateLimit extends string | AccountMeta<string> = string,
   TAccountMint extends string | AccountMeta<string> = string,
   TAccountBridge extends string | AccountMeta<string> = string,
+  TAccountAddressBook extends string | AccountMeta<string> = string,
   TAccountOutgoingMessage extends string | AccountMeta<string> = string,
   TAccountTokenProgram extends
     | string
@@ -62,6 +67,8 @@ export type WrapTokenInstruction<
   TAccountSystemProgram extends
     | string
     | AccountMeta<string> = '11111111111111111111111111111111',
+  TAccountMetaplexMetadata extends string | AccountMeta<string> = string,
+  TAccountMetaplexProgram extends string | AccountMeta<string> = string,
   TRemainingAccounts extends readonly AccountMeta<string>[] = [],
 > = Instruction<TProgram> &
   InstructionWithData<ReadonlyUint8Array> &
@@ -74,12 +81,27 @@ export type WrapTokenInstruction<
       TAccountGasFeeReceiver extends string
         ? WritableAccount<TAccountGasFeeReceiver>
         : TAccountGasFeeReceiver,
+      TAccountGasFeeEscrow extends string
+        ? WritableAccount<TAccountGasFeeEscrow>
+        : TAccountGasFeeEscrow,
+      TAccountInsuranceFund extends string
+        ? WritableAccount<TAccountInsuranceFund>
+        : TAccountInsuranceFund,
+      TAccountPayerRateLimitConfig extends string
+        ? ReadonlyAccount<TAccountPayerRateLimitConfig>
+        : TAccountPayerRateLimitConfig,
+      TAccountPayerRateLimit extends string
+        ? WritableAccount<TAccountPayerRateLimit>
+        : TAccountPayerRateLimit,
       TAccountMint extends string
         ? WritableAccount<TAccountMint>
         : TAccountMint,
       TAccountBridge extends string
         ? WritableAccount<TAccountBridge>
         : TAccountBridge,
+      TAccountAddressBook extends string
+        ? ReadonlyAccount<TAccountAddressBook>
+        : TAccountAddressBook,
       TAccountOutgoingMessage extends string
         ? WritableAccount<TAccountOutgoingMessage>
         : TAccountOutgoingMessage,
@@ -89,6 +111,12 @@ export type WrapTokenInstruction<
       TAccountSystemProgram extends string
         ? ReadonlyAccount<TAccountSystemProgram>
         : TAccountSystemProgram,
+      TAccountMetaplexMetadata extends string
+        ? WritableAccount<TAccountMetaplexMetadata>
+        : TAccountMetaplexMetadata,
+      TAccountMetaplexProgram extends string
+        ? ReadonlyAccount<TAccountMetaplexProgram>
+        : TAccountMetaplexProgram,
       ...TRemainingAccounts,
     ]
   >;
@@ -111,8 +139,8 @@ export type WrapTokenInstructionData = {
    * This handles cases where tokens have differing decimal precision on Base vs Solana.
    * For example, when Base token has 18 decimals and the Solana wrapped mint has 9,
    * this value conveys the decimal relationship so bridging logic can scale amounts.
-   * The exact conversion is performed by the EVM-side contract; Solana propagates this
-   * value but does not apply arithmetic with it.
+   * The conversion is applied by the EVM-side contract; Solana only uses it to validate
+   * amounts (see `common::internal::scaling`).
    */
   scalerExponent: number;
 };
@@ -134,8 +162,8 @@ export type WrapTokenInstructionDataArgs = {
    * This handles cases where tokens have differing decimal precision on Base vs Solana.
    * For example, when Base token has 18 decimals and the Solana wrapped mint has 9,
    * this value conveys the decimal relationship so bridging logic can scale amounts.
-   * The exact conversion is performed by the EVM-side contract; Solana propagates this
-   * value but does not apply arithmetic with it.
+   * The conversion is applied by the EVM-side contract; Solana only uses it to validate
+   * amounts (see `common::internal::scaling`).
    */
   scalerExponent: number;
 };
@@ -180,24 +208,48 @@ export function getWrapTokenInstructionDataCodec(): Codec<
 export type WrapTokenInput<
   TAccountPayer extends string = string,
   TAccountGasFeeReceiver extends string = string,
+  TAccountGasFeeEscrow extends string = string,
+  TAccountInsuranceFund extends string = string,
+  TAccountPayerRateLimitConfig extends string = string,
+  TAccountPayerRateLimit extends string = string,
   TAccountMint extends string = string,
   TAccountBridge extends string = string,
+  TAccountAddressBook extends string = string,
   TAccountOutgoingMessage extends string = string,
   TAccountTokenProgram extends string = string,
   TAccountSystemProgram extends string = string,
+  TAccountMetaplexMetadata extends string = string,
+  TAccountMetaplexProgram extends string = string,
 > = {
   /**
    * The account that pays for the transaction and all account creation costs.
    * Must be mutable to deduct lamports for mint creation, metadata storage, and gas fees.
+   * Unlike the bridging instructions, there is no `from`: no asset is moved and the
+   * registration message is sent by the bridge program itself, so any payer can wrap a token.
    */
   payer: TransactionSigner<TAccountPayer>;
   /** The account that receives payment for the gas costs of registering the token on Base. */
   gasFeeReceiver: Address<TAccountGasFeeReceiver>;
+  /** Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited. */
+  gasFeeEscrow: Address<TAccountGasFeeEscrow>;
+  /**
+   * Insurance fund credited with its share of the gas fee. No share is taken while it is
+   * uninitialized.
+   */
+  insuranceFund: Address<TAccountInsuranceFund>;
+  /** Payer rate limiting configuration. Payers are not rate limited while it is uninitialized. */
+  payerRateLimitConfig: Address<TAccountPayerRateLimitConfig>;
+  /**
+   * Outgoing messages paid for by the payer in the current rate limiting window, created on its
+   * first message while rate limiting is enabled.
+   */
+  payerRateLimit: Address<TAccountPayerRateLimit>;
   /**
    * The new SPL Token-2022 mint being created for the wrapped token.
    * - Uses PDA with token metadata hash and decimals for deterministic address
    * - Mint authority set to itself (mint account) for controlled minting
    * - Includes metadata pointer extension to store token information onchain
+   * - Left untouched if it already exists
    */
   mint: Address<TAccountMint>;
   /**
@@ -206,10 +258,13 @@ export type WrapTokenInput<
    * Must be mutable to update the nonce after creating the outgoing message.
    */
   bridge: Address<TAccountBridge>;
+  /** The address book providing the Base Bridge address targeted by the registration call. */
+  addressBook: Address<TAccountAddressBook>;
   /**
    * The outgoing message account that stores the cross-chain call to register
    * the wrapped token on the Base blockchain. Contains the encoded function call
    * with token address, local mint address, and scaling parameters.
+   * already wrapped token does not send a duplicate registration.
    */
   outgoingMessage: Address<TAccountOutgoingMessage>;
   /**
@@ -222,6 +277,13 @@ export type WrapTokenInput<
    * Used internally by Anchor for account initialization and rent payments.
    */
   systemProgram?: Address<TAccountSystemProgram>;
+  /**
+   * Optional Metaplex Token Metadata account mirroring the Token-2022 metadata, for wallets
+   * that do not read Token-2022 TLV metadata. Only created when provided.
+   */
+  metaplexMetadata?: Address<TAccountMetaplexMetadata>;
+  /** Metaplex Token Metadata program, required when `metaplex_metadata` is provided. */
+  metaplexProgram?: Address<TAccountMetaplexProgram>;
   outgoingMessageSalt: WrapTokenInstructionDataArgs['outgoingMessageSalt'];
   decimals: WrapTokenInstructionDataArgs['decimals'];
   name: WrapTokenInstructionDataArgs['name'];
@@ -233,32 +295,53 @@ export type WrapTokenInput<
 export function getWrapTokenInstruction<
   TAccountPayer extends string,
   TAccountGasFeeReceiver extends string,
+  TAccountGasFeeEscrow extends string,
+  TAccountInsuranceFund extends string,
+  TAccountPayerRateLimitConfig extends string,
+  TAccountPayerRateLimit extends string,
   TAccountMint extends string,
   TAccountBridge extends string,
+  TAccountAddressBook extends string,
   TAccountOutgoingMessage extends string,
   TAccountTokenProgram extends string,
   TAccountSystemProgram extends string,
+  TAccountMetaplexMetadata extends string,
+  TAccountMetaplexProgram extends string,
   TProgramAddress extends Address = typeof BRIDGE_PROGRAM_ADDRESS,
 >(
   input: WrapTokenInput<
     TAccountPayer,
     TAccountGasFeeReceiver,
+    TAccountGasFeeEscrow,
+    TAccountInsuranceFund,
+    TAccountPayerRateLimitConfig,
+    TAccountPayerRateLimit,
     TAccountMint,
     TAccountBridge,
+    TAccountAddressBook,
     TAccountOutgoingMessage,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMetaplexMetadata,
+    TAccountMetaplexProgram
   >,
   config?: { programAddress?: TProgramAddress }
 ): WrapTokenInstruction<
   TProgramAddress,
   TAccountPayer,
   TAccountGasFeeReceiver,
+  TAccountGasFeeEscrow,
+  TAccountInsuranceFund,
+  TAccountPayerRateLimitConfig,
+  TAccountPayerRateLimit,
   TAccountMint,
   TAccountBridge,
+  TAccountAddressBook,
   TAccountOutgoingMessage,
   TAccountTokenProgram,
-  TAccountSystemProgram
+  TAccountSystemProgram,
+  TAccountMetaplexMetadata,
+  TAccountMetaplexProgram
 > {
   // Program address.
   const programAddress = config?.programAddress ?? BRIDGE_PROGRAM_ADDRESS;
@@ -267,11 +350,27 @@ export function getWrapTokenInstruction<
   const originalAccounts = {
     payer: { value: input.payer ?? null, isWritable: true },
     gasFeeReceiver: { value: input.gasFeeReceiver ?? null, isWritable: true },
+    gasFeeEscrow: { value: input.gasFeeEscrow ?? null, isWritable: true },
+    insuranceFund: { value: input.insuranceFund ?? null, isWritable: true },
+    payerRateLimitConfig: {
+      value: input.payerRateLimitConfig ?? null,
+      isWritable: false,
+    },
+    payerRateLimit: { value: input.payerRateLimit ?? null, isWritable: true },
     mint: { value: input.mint ?? null, isWritable: true },
     bridge: { value: input.bridge ?? null, isWritable: true },
+    addressBook: { value: input.addressBook ?? null, isWritable: false },
     outgoingMessage: { value: input.outgoingMessage ?? null, isWritable: true },
     tokenProgram: { value: input.tokenProgram ?? null, isWritable: false },
     systemProgram: { value: input.systemProgram ?? null, isWritable: false },
+    metaplexMetadata: {
+      value: input.metaplexMetadata ?? null,
+      isWritable: true,
+    },
+    metaplexProgram: {
+      value: input.metaplexProgram ?? null,
+      isWritable: false,
+    },
   };
   const accounts = originalAccounts as Record<
     keyof typeof originalAccounts,
@@ -296,11 +395,18 @@ export function getWrapTokenInstruction<
     accounts: [
       getAccountMeta(accounts.payer),
       getAccountMeta(accounts.gasFeeReceiver),
+      getAccountMeta(accounts.gasFeeEscrow),
+      getAccountMeta(accounts.insuranceFund),
+      getAccountMeta(accounts.payerRateLimitConfig),
+      getAccountMeta(accounts.payerRateLimit),
       getAccountMeta(accounts.mint),
       getAccountMeta(accounts.bridge),
+      getAccountMeta(accounts.addressBook),
       getAccountMeta(accounts.outgoingMessage),
       getAccountMeta(accounts.tokenProgram),
       getAccountMeta(accounts.systemProgram),
+      getAccountMeta(accounts.metaplexMetadata),
+      getAccountMeta(accounts.metaplexProgram),
     ],
     data: getWrapTokenInstructionDataEncoder().encode(
       args as WrapTokenInstructionDataArgs
@@ -310,11 +416,18 @@ export function getWrapTokenInstruction<
     TProgramAddress,
     TAccountPayer,
     TAccountGasFeeReceiver,
+    TAccountGasFeeEscrow,
+    TAccountInsuranceFund,
+    TAccountPayerRateLimitConfig,
+    TAccountPayerRateLimit,
     TAccountMint,
     TAccountBridge,
+    TAccountAddressBook,
     TAccountOutgoingMessage,
     TAccountTokenProgram,
-    TAccountSystemProgram
+    TAccountSystemProgram,
+    TAccountMetaplexMetadata,
+    TAccountMetaplexProgram
   >);
 }
 
@@ -327,39 +440,66 @@ export type ParsedWrapTokenInstruction<
     /**
      * The account that pays for the transaction and all account creation costs.
      * Must be mutable to deduct lamports for mint creation, metadata storage, and gas fees.
+     * Unlike the bridging instructions, there is no `from`: no asset is moved and the
+     * registration message is sent by the bridge program itself, so any payer can wrap a token.
      */
     payer: TAccountMetas[0];
     /** The account that receives payment for the gas costs of registering the token on Base. */
     gasFeeReceiver: TAccountMetas[1];
+    /** Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited. */
+    gasFeeEscrow: TAccountMetas[2];
+    /**
+     * Insurance fund credited with its share of the gas fee. No share is taken while it is
+     * uninitialized.
+     */
+    insuranceFund: TAccountMetas[3];
+    /** Payer rate limiting configuration. Payers are not rate limited while it is uninitialized. */
+    payerRateLimitConfig: TAccountMetas[4];
+    /**
+     * Outgoing messages paid for by the payer in the current rate limiting window, created on its
+     * first message while rate limiting is enabled.
+     */
+    payerRateLimit: TAccountMetas[5];
     /**
      * The new SPL Token-2022 mint being created for the wrapped token.
      * - Uses PDA with token metadata hash and decimals for deterministic address
      * - Mint authority set to itself (mint account) for controlled minting
      * - Includes metadata pointer extension to store token information onchain
+     * - Left untouched if it already exists
      */
-    mint: TAccountMetas[2];
+    mint: TAccountMetas[6];
     /**
      * The main bridge state account that tracks cross-chain operations.
      * Used to increment the nonce counter and manage EIP-1559 gas pricing.
      * Must be mutable to update the nonce after creating the outgoing message.
      */
-    bridge: TAccountMetas[3];
+    bridge: TAccountMetas[7];
+    /** The address book providing the Base Bridge address targeted by the registration call. */
+    addressBook: TAccountMetas[8];
     /**
      * The outgoing message account that stores the cross-chain call to register
      * the wrapped token on the Base blockchain. Contains the encoded function call
      * with token address, local mint address, and scaling parameters.
+     * already wrapped token does not send a duplicate registration.
      */
-    outgoingMessage: TAccountMetas[4];
+    outgoingMessage: TAccountMetas[9];
     /**
      * SPL Token-2022 program for creating the mint with metadata extensions.
      * Required for initializing tokens with advanced features like metadata pointers.
      */
-    tokenProgram: TAccountMetas[5];
+    tokenProgram: TAccountMetas[10];
     /**
      * System program required for creating new accounts and transferring lamports.
      * Used internally by Anchor for account initialization and rent payments.
      */
-    systemProgram: TAccountMetas[6];
+    systemProgram: TAccountMetas[11];
+    /**
+     * Optional Metaplex Token Metadata account mirroring the Token-2022 metadata, for wallets
+     * that do not read Token-2022 TLV metadata. Only created when provided.
+     */
+    metaplexMetadata?: TAccountMetas[12] | undefined;
+    /** Metaplex Token Metadata program, required when `metaplex_metadata` is provided. */
+    metaplexProgram?: TAccountMetas[13] | undefined;
   };
   data: WrapTokenInstructionData;
 };
@@ -372,7 +512,7 @@ export function parseWrapTokenInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>
 ): ParsedWrapTokenInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 7) {
+  if (instruction.accounts.length < 14) {
     // TODO: Coded error.
     throw new Error('Not enough accounts');
   }
@@ -382,16 +522,29 @@ export function parseWrapTokenInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === BRIDGE_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
       payer: getNextAccount(),
       gasFeeReceiver: getNextAccount(),
+      gasFeeEscrow: getNextAccount(),
+      insuranceFund: getNextAccount(),
+      payerRateLimitConfig: getNextAccount(),
+      payerRateLimit: getNextAccount(),
       mint: getNextAccount(),
       bridge: getNextAccount(),
+      addressBook: getNextAccount(),
       outgoingMessage: getNextAccount(),
       tokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
+      metaplexMetadata: getNextOptionalAccount(),
+      metaplexProgram: getNextOptionalAccount(),
     },
     data: getWrapTokenInstructionDataDecoder().decode(instruction.data),
   };
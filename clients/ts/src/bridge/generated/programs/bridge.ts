@@ -14,58 +14,169 @@ import {
   type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  type ParsedActivateOracleEmergencyInstruction,
   type ParsedAppendToCallBufferInstruction,
   type ParsedAppendToProveBufferDataInstruction,
   type ParsedAppendToProveBufferProofInstruction,
+  type ParsedApproveOutflowInstruction,
+  type ParsedAuditVaultInstruction,
   type ParsedBridgeCallBufferedInstruction,
   type ParsedBridgeCallInstruction,
+  type ParsedBridgeEncryptedCallInstruction,
+  type ParsedBridgeSolBatchInstruction,
   type ParsedBridgeSolInstruction,
   type ParsedBridgeSolWithBufferedCallInstruction,
   type ParsedBridgeSplInstruction,
   type ParsedBridgeSplWithBufferedCallInstruction,
   type ParsedBridgeWrappedTokenInstruction,
   type ParsedBridgeWrappedTokenWithBufferedCallInstruction,
+  type ParsedCancelEmergencyExitInstruction,
+  type ParsedCancelInsurancePayoutInstruction,
+  type ParsedCheckEnvironmentInstruction,
+  type ParsedClearSolvencyAlertInstruction,
+  type ParsedCloneCallBufferInstruction,
   type ParsedCloseCallBufferInstruction,
   type ParsedCloseProveBufferInstruction,
+  type ParsedCloseRelayedOutgoingMessageInstruction,
+  type ParsedCloseSupersededMessageInstruction,
+  type ParsedConfirmRelayedInstruction,
+  type ParsedCreateBridgeSessionInstruction,
+  type ParsedCrosschainMintInstruction,
+  type ParsedDeactivateOracleEmergencyInstruction,
+  type ParsedDeriveCpiAuthorityInstruction,
+  type ParsedEmergencyWithdrawSolInstruction,
+  type ParsedEmergencyWithdrawSplInstruction,
+  type ParsedEnableEmergencyExitInstruction,
+  type ParsedExecuteInsurancePayoutInstruction,
+  type ParsedExpireCallBufferInstruction,
+  type ParsedExtendProveBufferInstruction,
+  type ParsedGetEnvironmentInstruction,
+  type ParsedGetOracleHealthInstruction,
+  type ParsedGetVersionInstruction,
+  type ParsedInitConfigHistoryInstruction,
+  type ParsedInitSolVaultInstruction,
   type ParsedInitializeCallBufferInstruction,
+  type ParsedInitializeDevnetDefaultsInstruction,
   type ParsedInitializeInstruction,
   type ParsedInitializeProveBufferInstruction,
+  type ParsedMigrateBridgeStateInstruction,
+  type ParsedPauseFromGovernanceInstruction,
+  type ParsedPostLiabilityRootInstruction,
   type ParsedProveMessageBufferedInstruction,
+  type ParsedProveMessageChunkedInstruction,
   type ParsedProveMessageInstruction,
+  type ParsedReconcileVaultInstruction,
+  type ParsedRecordRelayExecutionInstruction,
+  type ParsedRegisterHandlerInstruction,
   type ParsedRegisterOutputRootInstruction,
+  type ParsedRegisterOutputRootsBatchInstruction,
   type ParsedRelayMessageInstruction,
+  type ParsedReportEquivocationInstruction,
+  type ParsedRequestInsurancePayoutInstruction,
+  type ParsedRevokeBridgeSessionInstruction,
+  type ParsedScaleWrappedTokenAmountInstruction,
+  type ParsedSetAddressBookInstruction,
   type ParsedSetAdjustmentDenominatorInstruction,
+  type ParsedSetBaseGovernanceInstruction,
   type ParsedSetBlockIntervalRequirementInstruction,
+  type ParsedSetCallBufferReusableInstruction,
+  type ParsedSetCallBufferTtlInstruction,
+  type ParsedSetDexRouterInstruction,
+  type ParsedSetExpireBountyBpsInstruction,
+  type ParsedSetFeeConfigInstruction,
+  type ParsedSetFeeQuoteGracePeriodInstruction,
+  type ParsedSetFinalizationDelayInstruction,
   type ParsedSetGasCostScalerDpInstruction,
   type ParsedSetGasCostScalerInstruction,
   type ParsedSetGasFeeReceiverInstruction,
   type ParsedSetGasPerCallInstruction,
   type ParsedSetGasTargetInstruction,
+  type ParsedSetGovernanceModeInstruction,
+  type ParsedSetGuardedLaunchInstruction,
+  type ParsedSetInsuranceShareInstruction,
+  type ParsedSetMaintenanceBudgetInstruction,
+  type ParsedSetMaxBaseFeeInstruction,
   type ParsedSetMaxCallBufferSizeInstruction,
+  type ParsedSetMaxLamportAllowanceInstruction,
+  type ParsedSetMaxWindowIncreaseBpsInstruction,
   type ParsedSetMinimumBaseFeeInstruction,
+  type ParsedSetMintAllowedInstruction,
+  type ParsedSetMintPolicyInstruction,
+  type ParsedSetOracleFailoverConfigInstruction,
+  type ParsedSetOracleSignersFromGovernanceInstruction,
   type ParsedSetOracleSignersInstruction,
+  type ParsedSetOutflowBreakerInstruction,
   type ParsedSetPartnerOracleConfigInstruction,
   type ParsedSetPauseStatusInstruction,
+  type ParsedSetPayerRateLimitInstruction,
+  type ParsedSetProtocolFeeConfigInstruction,
+  type ParsedSetRateLimitExemptPayerInstruction,
+  type ParsedSetRelayDenyListInstruction,
+  type ParsedSetRootFreshnessConfigInstruction,
+  type ParsedSetTransferGasLaneInstruction,
+  type ParsedSetVaultDecimalsInstruction,
   type ParsedSetWindowDurationInstruction,
+  type ParsedSetWrappedTokenConfirmationRequiredInstruction,
+  type ParsedSetWrappedTokenMaxSupplyInstruction,
+  type ParsedSettleRelayFeesInstruction,
+  type ParsedSupersedeOutputRootInstruction,
+  type ParsedSweepGasFeeEscrowInstruction,
   type ParsedTransferGuardianInstruction,
+  type ParsedVetoMessageInstruction,
   type ParsedWrapTokenInstruction,
 } from '../instructions';
 
 export const BRIDGE_PROGRAM_ADDRESS = '' as Address<''>;
 
 export enum BridgeAccount {
+  AddressBook,
   Bridge,
+  BridgeSession,
+  BurnReceipt,
   CallBuffer,
+  ConfigHistory,
+  DexRouterList,
+  EmergencyExit,
+  EquivocationEvidence,
+  HandlerRegistration,
   IncomingMessage,
+  InsuranceFund,
+  LiabilityRoot,
+  MaintenanceBudget,
+  MintAllowList,
+  MintPolicy,
+  OperationalVault,
   OutgoingMessage,
   OutputRoot,
+  PayerRateLimitConfig,
+  ProtocolFeeConfig,
   ProveBuffer,
+  RelayDenyList,
+  RelayExecution,
+  RelayFeeVault,
+  RelayedNonces,
+  ReturnedTransfer,
+  SolVaultState,
+  TokenDeposits,
+  VaultDecimals,
+  WrappedTokenSupplyCap,
 }
 
 export function identifyBridgeAccount(
   account: { data: ReadonlyUint8Array } | ReadonlyUint8Array
 ): BridgeAccount {
   const data = 'data' in account ? account.data : account;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([112, 200, 175, 39, 249, 237, 44, 141])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.AddressBook;
+  }
   if (
     containsBytes(
       data,
@@ -75,472 +186,1625 @@ export function identifyBridgeAccount(
       0
     )
   ) {
-    return BridgeAccount.Bridge;
+    return BridgeAccount.Bridge;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([235, 118, 90, 227, 230, 173, 141, 184])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.BridgeSession;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([209, 39, 231, 253, 164, 70, 105, 174])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.BurnReceipt;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([134, 143, 168, 251, 163, 216, 180, 113])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.CallBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([228, 148, 11, 248, 20, 187, 94, 127])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.ConfigHistory;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([67, 61, 221, 98, 51, 77, 110, 141])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.DexRouterList;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([87, 180, 23, 110, 235, 1, 235, 126])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.EmergencyExit;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([70, 115, 183, 155, 104, 245, 215, 118])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.EquivocationEvidence;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([244, 68, 84, 81, 139, 64, 75, 70])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.HandlerRegistration;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([30, 144, 125, 111, 211, 223, 91, 170])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.IncomingMessage;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([43, 134, 170, 87, 102, 16, 142, 147])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.InsuranceFund;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([136, 64, 12, 73, 131, 121, 226, 74])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.LiabilityRoot;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([10, 93, 117, 190, 152, 237, 50, 134])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.MaintenanceBudget;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([248, 183, 35, 91, 119, 253, 109, 22])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.MintAllowList;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([191, 78, 230, 47, 121, 49, 66, 222])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.MintPolicy;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([192, 181, 243, 150, 176, 228, 45, 226])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.OperationalVault;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([150, 255, 197, 226, 200, 215, 31, 29])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.OutgoingMessage;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([11, 31, 168, 201, 229, 8, 180, 198])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.OutputRoot;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([56, 1, 177, 164, 58, 43, 214, 135])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.PayerRateLimitConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([81, 109, 12, 19, 140, 182, 21, 238])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.ProtocolFeeConfig;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([78, 160, 227, 163, 97, 140, 40, 149])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.ProveBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([75, 12, 144, 243, 127, 216, 45, 173])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.RelayDenyList;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([164, 61, 70, 13, 77, 65, 148, 150])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.RelayExecution;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([27, 215, 66, 254, 221, 129, 165, 143])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.RelayFeeVault;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([245, 149, 24, 172, 58, 163, 78, 141])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.RelayedNonces;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([243, 207, 182, 175, 153, 226, 153, 17])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.ReturnedTransfer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([73, 192, 113, 251, 77, 219, 213, 95])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.SolVaultState;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([217, 76, 219, 239, 3, 87, 134, 38])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.TokenDeposits;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([94, 21, 18, 166, 217, 100, 206, 203])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.VaultDecimals;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([106, 219, 43, 96, 118, 136, 251, 165])
+      ),
+      0
+    )
+  ) {
+    return BridgeAccount.WrappedTokenSupplyCap;
+  }
+  throw new Error(
+    'The provided account could not be identified as a bridge account.'
+  );
+}
+
+export enum BridgeInstruction {
+  ActivateOracleEmergency,
+  AppendToCallBuffer,
+  AppendToProveBufferData,
+  AppendToProveBufferProof,
+  ApproveOutflow,
+  AuditVault,
+  BridgeCall,
+  BridgeCallBuffered,
+  BridgeEncryptedCall,
+  BridgeSol,
+  BridgeSolBatch,
+  BridgeSolWithBufferedCall,
+  BridgeSpl,
+  BridgeSplWithBufferedCall,
+  BridgeWrappedToken,
+  BridgeWrappedTokenWithBufferedCall,
+  CancelEmergencyExit,
+  CancelInsurancePayout,
+  CheckEnvironment,
+  ClearSolvencyAlert,
+  CloneCallBuffer,
+  CloseCallBuffer,
+  CloseProveBuffer,
+  CloseRelayedOutgoingMessage,
+  CloseSupersededMessage,
+  ConfirmRelayed,
+  CreateBridgeSession,
+  CrosschainMint,
+  DeactivateOracleEmergency,
+  DeriveCpiAuthority,
+  EmergencyWithdrawSol,
+  EmergencyWithdrawSpl,
+  EnableEmergencyExit,
+  ExecuteInsurancePayout,
+  ExpireCallBuffer,
+  ExtendProveBuffer,
+  GetEnvironment,
+  GetOracleHealth,
+  GetVersion,
+  InitConfigHistory,
+  InitSolVault,
+  Initialize,
+  InitializeCallBuffer,
+  InitializeDevnetDefaults,
+  InitializeProveBuffer,
+  MigrateBridgeState,
+  PauseFromGovernance,
+  PostLiabilityRoot,
+  ProveMessage,
+  ProveMessageBuffered,
+  ProveMessageChunked,
+  ReconcileVault,
+  RecordRelayExecution,
+  RegisterHandler,
+  RegisterOutputRoot,
+  RegisterOutputRootsBatch,
+  RelayMessage,
+  ReportEquivocation,
+  RequestInsurancePayout,
+  RevokeBridgeSession,
+  ScaleWrappedTokenAmount,
+  SetAddressBook,
+  SetAdjustmentDenominator,
+  SetBaseGovernance,
+  SetBlockIntervalRequirement,
+  SetCallBufferReusable,
+  SetCallBufferTtl,
+  SetDexRouter,
+  SetExpireBountyBps,
+  SetFeeConfig,
+  SetFeeQuoteGracePeriod,
+  SetFinalizationDelay,
+  SetGasCostScaler,
+  SetGasCostScalerDp,
+  SetGasFeeReceiver,
+  SetGasPerCall,
+  SetGasTarget,
+  SetGovernanceMode,
+  SetGuardedLaunch,
+  SetInsuranceShare,
+  SetMaintenanceBudget,
+  SetMaxBaseFee,
+  SetMaxCallBufferSize,
+  SetMaxLamportAllowance,
+  SetMaxWindowIncreaseBps,
+  SetMinimumBaseFee,
+  SetMintAllowed,
+  SetMintPolicy,
+  SetOracleFailoverConfig,
+  SetOracleSigners,
+  SetOracleSignersFromGovernance,
+  SetOutflowBreaker,
+  SetPartnerOracleConfig,
+  SetPauseStatus,
+  SetPayerRateLimit,
+  SetProtocolFeeConfig,
+  SetRateLimitExemptPayer,
+  SetRelayDenyList,
+  SetRootFreshnessConfig,
+  SetTransferGasLane,
+  SetVaultDecimals,
+  SetWindowDuration,
+  SetWrappedTokenConfirmationRequired,
+  SetWrappedTokenMaxSupply,
+  SettleRelayFees,
+  SupersedeOutputRoot,
+  SweepGasFeeEscrow,
+  TransferGuardian,
+  VetoMessage,
+  WrapToken,
+}
+
+export function identifyBridgeInstruction(
+  instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
+): BridgeInstruction {
+  const data = 'data' in instruction ? instruction.data : instruction;
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([60, 35, 68, 160, 82, 210, 139, 228])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.ActivateOracleEmergency;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([113, 115, 232, 194, 248, 32, 39, 21])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.AppendToCallBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([4, 153, 157, 132, 144, 253, 235, 44])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.AppendToProveBufferData;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([234, 13, 59, 98, 112, 207, 250, 184])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.AppendToProveBufferProof;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([156, 58, 247, 91, 95, 53, 102, 99])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.ApproveOutflow;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([130, 167, 148, 121, 116, 63, 191, 131])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.AuditVault;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([90, 23, 83, 238, 200, 18, 111, 95])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.BridgeCall;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([138, 112, 52, 204, 33, 68, 62, 85])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.BridgeCallBuffered;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([238, 83, 137, 129, 168, 158, 120, 182])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.BridgeEncryptedCall;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([190, 190, 32, 158, 75, 153, 32, 86])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.BridgeSol;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([21, 165, 169, 61, 148, 144, 208, 160])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.BridgeSolBatch;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([52, 106, 74, 190, 246, 31, 157, 12])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.BridgeSolWithBufferedCall;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([87, 109, 172, 103, 8, 187, 223, 126])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.BridgeSpl;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([86, 187, 229, 4, 110, 8, 116, 153])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.BridgeSplWithBufferedCall;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([55, 201, 139, 188, 226, 16, 136, 143])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.BridgeWrappedToken;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([117, 175, 150, 237, 216, 76, 56, 5])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.BridgeWrappedTokenWithBufferedCall;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([116, 103, 71, 148, 211, 57, 195, 218])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.CancelEmergencyExit;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([88, 159, 20, 114, 216, 176, 169, 130])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.CancelInsurancePayout;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([66, 105, 5, 163, 77, 91, 38, 191])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.CheckEnvironment;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([235, 20, 82, 87, 228, 123, 54, 163])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.ClearSolvencyAlert;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([230, 208, 140, 80, 195, 213, 190, 158])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.CloneCallBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([132, 188, 7, 198, 64, 178, 62, 29])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.CloseCallBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([79, 14, 144, 49, 3, 124, 210, 74])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.CloseProveBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([167, 21, 71, 219, 95, 206, 117, 154])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.CloseRelayedOutgoingMessage;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([112, 114, 96, 242, 189, 158, 235, 210])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.CloseSupersededMessage;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([65, 89, 205, 73, 164, 223, 6, 39])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.ConfirmRelayed;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([129, 157, 128, 13, 113, 164, 221, 16])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.CreateBridgeSession;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([1, 24, 112, 214, 96, 100, 3, 12])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.CrosschainMint;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([27, 223, 171, 253, 157, 181, 48, 100])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.DeactivateOracleEmergency;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([45, 177, 142, 97, 182, 30, 61, 247])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.DeriveCpiAuthority;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([219, 156, 123, 176, 91, 105, 30, 160])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.EmergencyWithdrawSol;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([113, 252, 205, 153, 77, 124, 82, 31])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.EmergencyWithdrawSpl;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([10, 76, 250, 43, 190, 163, 62, 42])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.EnableEmergencyExit;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([217, 105, 57, 198, 179, 226, 72, 97])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.ExecuteInsurancePayout;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([52, 71, 212, 109, 123, 63, 136, 228])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.ExpireCallBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([118, 6, 185, 25, 111, 96, 10, 159])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.ExtendProveBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([234, 169, 115, 3, 52, 113, 69, 198])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.GetEnvironment;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([248, 80, 2, 228, 55, 96, 102, 32])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.GetOracleHealth;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([168, 85, 244, 45, 81, 56, 130, 50])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.GetVersion;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([148, 127, 241, 17, 70, 93, 161, 148])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.InitConfigHistory;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([78, 34, 100, 179, 84, 60, 13, 232])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.InitSolVault;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([175, 175, 109, 31, 13, 152, 155, 237])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.Initialize;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([85, 68, 100, 234, 255, 226, 95, 72])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.InitializeCallBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([239, 222, 145, 96, 204, 90, 35, 188])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.InitializeDevnetDefaults;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([20, 200, 91, 188, 138, 211, 253, 222])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.InitializeProveBuffer;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([196, 193, 143, 108, 71, 132, 75, 181])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.MigrateBridgeState;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([54, 128, 175, 15, 180, 51, 43, 99])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.PauseFromGovernance;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([119, 146, 146, 161, 74, 173, 185, 103])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.PostLiabilityRoot;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([172, 66, 78, 136, 158, 187, 47, 115])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.ProveMessage;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([211, 48, 193, 183, 2, 51, 102, 159])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.ProveMessageBuffered;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([70, 150, 162, 72, 135, 210, 161, 218])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.ProveMessageChunked;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([155, 3, 193, 142, 152, 183, 87, 196])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.ReconcileVault;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([195, 100, 156, 188, 128, 85, 30, 122])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.RecordRelayExecution;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([16, 136, 86, 127, 98, 105, 181, 81])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.RegisterHandler;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([215, 66, 12, 154, 4, 123, 196, 66])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.RegisterOutputRoot;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([75, 145, 129, 168, 219, 158, 241, 47])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.RegisterOutputRootsBatch;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([187, 90, 182, 138, 51, 248, 175, 98])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.RelayMessage;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([140, 250, 136, 199, 9, 49, 41, 16])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.ReportEquivocation;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([207, 233, 236, 9, 26, 22, 157, 161])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.RequestInsurancePayout;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([65, 247, 109, 149, 94, 100, 82, 4])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.RevokeBridgeSession;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([116, 191, 53, 160, 202, 142, 182, 62])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.ScaleWrappedTokenAmount;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([104, 182, 87, 189, 19, 242, 165, 123])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.SetAddressBook;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([31, 91, 190, 63, 164, 7, 31, 150])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.SetAdjustmentDenominator;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([49, 63, 19, 144, 133, 10, 240, 239])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.SetBaseGovernance;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([76, 70, 237, 100, 33, 108, 19, 42])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.SetBlockIntervalRequirement;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([139, 175, 188, 146, 41, 246, 53, 6])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.SetCallBufferReusable;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([244, 233, 178, 75, 206, 114, 130, 71])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.SetCallBufferTtl;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([134, 143, 168, 251, 163, 216, 180, 113])
+        new Uint8Array([172, 98, 218, 114, 119, 50, 241, 81])
       ),
       0
     )
   ) {
-    return BridgeAccount.CallBuffer;
+    return BridgeInstruction.SetDexRouter;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([30, 144, 125, 111, 211, 223, 91, 170])
+        new Uint8Array([83, 55, 122, 189, 119, 156, 57, 26])
       ),
       0
     )
   ) {
-    return BridgeAccount.IncomingMessage;
+    return BridgeInstruction.SetExpireBountyBps;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([150, 255, 197, 226, 200, 215, 31, 29])
+        new Uint8Array([221, 222, 52, 206, 114, 198, 64, 91])
       ),
       0
     )
   ) {
-    return BridgeAccount.OutgoingMessage;
+    return BridgeInstruction.SetFeeConfig;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([11, 31, 168, 201, 229, 8, 180, 198])
+        new Uint8Array([198, 115, 33, 23, 24, 4, 155, 89])
       ),
       0
     )
   ) {
-    return BridgeAccount.OutputRoot;
+    return BridgeInstruction.SetFeeQuoteGracePeriod;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([78, 160, 227, 163, 97, 140, 40, 149])
+        new Uint8Array([196, 149, 104, 148, 56, 147, 197, 70])
       ),
       0
     )
   ) {
-    return BridgeAccount.ProveBuffer;
+    return BridgeInstruction.SetFinalizationDelay;
   }
-  throw new Error(
-    'The provided account could not be identified as a bridge account.'
-  );
-}
-
-export enum BridgeInstruction {
-  AppendToCallBuffer,
-  AppendToProveBufferData,
-  AppendToProveBufferProof,
-  BridgeCall,
-  BridgeCallBuffered,
-  BridgeSol,
-  BridgeSolWithBufferedCall,
-  BridgeSpl,
-  BridgeSplWithBufferedCall,
-  BridgeWrappedToken,
-  BridgeWrappedTokenWithBufferedCall,
-  CloseCallBuffer,
-  CloseProveBuffer,
-  Initialize,
-  InitializeCallBuffer,
-  InitializeProveBuffer,
-  ProveMessage,
-  ProveMessageBuffered,
-  RegisterOutputRoot,
-  RelayMessage,
-  SetAdjustmentDenominator,
-  SetBlockIntervalRequirement,
-  SetGasCostScaler,
-  SetGasCostScalerDp,
-  SetGasFeeReceiver,
-  SetGasPerCall,
-  SetGasTarget,
-  SetMaxCallBufferSize,
-  SetMinimumBaseFee,
-  SetOracleSigners,
-  SetPartnerOracleConfig,
-  SetPauseStatus,
-  SetWindowDuration,
-  TransferGuardian,
-  WrapToken,
-}
-
-export function identifyBridgeInstruction(
-  instruction: { data: ReadonlyUint8Array } | ReadonlyUint8Array
-): BridgeInstruction {
-  const data = 'data' in instruction ? instruction.data : instruction;
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([113, 115, 232, 194, 248, 32, 39, 21])
+        new Uint8Array([148, 146, 101, 170, 5, 6, 222, 119])
       ),
       0
     )
   ) {
-    return BridgeInstruction.AppendToCallBuffer;
+    return BridgeInstruction.SetGasCostScaler;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([4, 153, 157, 132, 144, 253, 235, 44])
+        new Uint8Array([198, 111, 160, 55, 172, 138, 99, 164])
       ),
       0
     )
   ) {
-    return BridgeInstruction.AppendToProveBufferData;
+    return BridgeInstruction.SetGasCostScalerDp;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([234, 13, 59, 98, 112, 207, 250, 184])
+        new Uint8Array([58, 188, 230, 188, 47, 188, 79, 154])
       ),
       0
     )
   ) {
-    return BridgeInstruction.AppendToProveBufferProof;
+    return BridgeInstruction.SetGasFeeReceiver;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([90, 23, 83, 238, 200, 18, 111, 95])
+        new Uint8Array([164, 95, 213, 130, 26, 69, 82, 127])
       ),
       0
     )
   ) {
-    return BridgeInstruction.BridgeCall;
+    return BridgeInstruction.SetGasPerCall;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([138, 112, 52, 204, 33, 68, 62, 85])
+        new Uint8Array([132, 25, 19, 13, 118, 63, 167, 102])
       ),
       0
     )
   ) {
-    return BridgeInstruction.BridgeCallBuffered;
+    return BridgeInstruction.SetGasTarget;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([190, 190, 32, 158, 75, 153, 32, 86])
+        new Uint8Array([72, 173, 60, 97, 144, 133, 127, 125])
       ),
       0
     )
   ) {
-    return BridgeInstruction.BridgeSol;
+    return BridgeInstruction.SetGovernanceMode;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([52, 106, 74, 190, 246, 31, 157, 12])
+        new Uint8Array([85, 148, 178, 81, 119, 150, 35, 126])
       ),
       0
     )
   ) {
-    return BridgeInstruction.BridgeSolWithBufferedCall;
+    return BridgeInstruction.SetGuardedLaunch;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([87, 109, 172, 103, 8, 187, 223, 126])
+        new Uint8Array([172, 243, 185, 104, 97, 46, 98, 180])
       ),
       0
     )
   ) {
-    return BridgeInstruction.BridgeSpl;
+    return BridgeInstruction.SetInsuranceShare;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([86, 187, 229, 4, 110, 8, 116, 153])
+        new Uint8Array([51, 172, 252, 22, 29, 67, 32, 176])
       ),
       0
     )
   ) {
-    return BridgeInstruction.BridgeSplWithBufferedCall;
+    return BridgeInstruction.SetMaintenanceBudget;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([55, 201, 139, 188, 226, 16, 136, 143])
+        new Uint8Array([5, 254, 242, 93, 48, 17, 187, 212])
       ),
       0
     )
   ) {
-    return BridgeInstruction.BridgeWrappedToken;
+    return BridgeInstruction.SetMaxBaseFee;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([117, 175, 150, 237, 216, 76, 56, 5])
+        new Uint8Array([140, 178, 4, 238, 245, 66, 117, 189])
       ),
       0
     )
   ) {
-    return BridgeInstruction.BridgeWrappedTokenWithBufferedCall;
+    return BridgeInstruction.SetMaxCallBufferSize;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([132, 188, 7, 198, 64, 178, 62, 29])
+        new Uint8Array([228, 107, 134, 81, 152, 253, 69, 60])
       ),
       0
     )
   ) {
-    return BridgeInstruction.CloseCallBuffer;
+    return BridgeInstruction.SetMaxLamportAllowance;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([79, 14, 144, 49, 3, 124, 210, 74])
+        new Uint8Array([70, 54, 126, 87, 192, 157, 8, 94])
       ),
       0
     )
   ) {
-    return BridgeInstruction.CloseProveBuffer;
+    return BridgeInstruction.SetMaxWindowIncreaseBps;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([175, 175, 109, 31, 13, 152, 155, 237])
+        new Uint8Array([56, 95, 58, 94, 221, 136, 138, 156])
       ),
       0
     )
   ) {
-    return BridgeInstruction.Initialize;
+    return BridgeInstruction.SetMinimumBaseFee;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([85, 68, 100, 234, 255, 226, 95, 72])
+        new Uint8Array([245, 141, 124, 184, 161, 169, 177, 171])
       ),
       0
     )
   ) {
-    return BridgeInstruction.InitializeCallBuffer;
+    return BridgeInstruction.SetMintAllowed;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([20, 200, 91, 188, 138, 211, 253, 222])
+        new Uint8Array([12, 208, 252, 52, 166, 250, 137, 169])
       ),
       0
     )
   ) {
-    return BridgeInstruction.InitializeProveBuffer;
+    return BridgeInstruction.SetMintPolicy;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([172, 66, 78, 136, 158, 187, 47, 115])
+        new Uint8Array([0, 208, 186, 0, 105, 73, 109, 235])
       ),
       0
     )
   ) {
-    return BridgeInstruction.ProveMessage;
+    return BridgeInstruction.SetOracleFailoverConfig;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([211, 48, 193, 183, 2, 51, 102, 159])
+        new Uint8Array([38, 183, 212, 67, 28, 241, 25, 160])
       ),
       0
     )
   ) {
-    return BridgeInstruction.ProveMessageBuffered;
+    return BridgeInstruction.SetOracleSigners;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([215, 66, 12, 154, 4, 123, 196, 66])
+        new Uint8Array([147, 246, 10, 198, 114, 206, 79, 67])
       ),
       0
     )
   ) {
-    return BridgeInstruction.RegisterOutputRoot;
+    return BridgeInstruction.SetOracleSignersFromGovernance;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([187, 90, 182, 138, 51, 248, 175, 98])
+        new Uint8Array([229, 198, 179, 252, 5, 220, 25, 30])
       ),
       0
     )
   ) {
-    return BridgeInstruction.RelayMessage;
+    return BridgeInstruction.SetOutflowBreaker;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([31, 91, 190, 63, 164, 7, 31, 150])
+        new Uint8Array([34, 48, 231, 135, 42, 113, 217, 157])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetAdjustmentDenominator;
+    return BridgeInstruction.SetPartnerOracleConfig;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([76, 70, 237, 100, 33, 108, 19, 42])
+        new Uint8Array([118, 25, 145, 217, 114, 209, 236, 145])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetBlockIntervalRequirement;
+    return BridgeInstruction.SetPauseStatus;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([148, 146, 101, 170, 5, 6, 222, 119])
+        new Uint8Array([23, 255, 179, 254, 32, 116, 238, 242])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetGasCostScaler;
+    return BridgeInstruction.SetPayerRateLimit;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([198, 111, 160, 55, 172, 138, 99, 164])
+        new Uint8Array([143, 126, 0, 121, 246, 128, 169, 45])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetGasCostScalerDp;
+    return BridgeInstruction.SetProtocolFeeConfig;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([58, 188, 230, 188, 47, 188, 79, 154])
+        new Uint8Array([20, 167, 148, 153, 121, 65, 190, 191])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetGasFeeReceiver;
+    return BridgeInstruction.SetRateLimitExemptPayer;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([164, 95, 213, 130, 26, 69, 82, 127])
+        new Uint8Array([11, 128, 83, 65, 154, 51, 71, 31])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetGasPerCall;
+    return BridgeInstruction.SetRelayDenyList;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([132, 25, 19, 13, 118, 63, 167, 102])
+        new Uint8Array([37, 162, 209, 203, 207, 194, 133, 198])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetGasTarget;
+    return BridgeInstruction.SetRootFreshnessConfig;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([140, 178, 4, 238, 245, 66, 117, 189])
+        new Uint8Array([212, 145, 147, 135, 71, 157, 141, 172])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetMaxCallBufferSize;
+    return BridgeInstruction.SetTransferGasLane;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([56, 95, 58, 94, 221, 136, 138, 156])
+        new Uint8Array([74, 13, 97, 20, 246, 82, 233, 139])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetMinimumBaseFee;
+    return BridgeInstruction.SetVaultDecimals;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([38, 183, 212, 67, 28, 241, 25, 160])
+        new Uint8Array([229, 2, 41, 119, 55, 255, 252, 205])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetOracleSigners;
+    return BridgeInstruction.SetWindowDuration;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([34, 48, 231, 135, 42, 113, 217, 157])
+        new Uint8Array([94, 202, 19, 237, 187, 194, 5, 60])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetPartnerOracleConfig;
+    return BridgeInstruction.SetWrappedTokenConfirmationRequired;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([118, 25, 145, 217, 114, 209, 236, 145])
+        new Uint8Array([211, 225, 119, 165, 13, 0, 28, 228])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetPauseStatus;
+    return BridgeInstruction.SetWrappedTokenMaxSupply;
   }
   if (
     containsBytes(
       data,
       fixEncoderSize(getBytesEncoder(), 8).encode(
-        new Uint8Array([229, 2, 41, 119, 55, 255, 252, 205])
+        new Uint8Array([198, 95, 239, 232, 222, 62, 36, 51])
       ),
       0
     )
   ) {
-    return BridgeInstruction.SetWindowDuration;
+    return BridgeInstruction.SettleRelayFees;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([64, 109, 12, 138, 234, 30, 189, 89])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.SupersedeOutputRoot;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([174, 228, 122, 93, 87, 218, 203, 200])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.SweepGasFeeEscrow;
   }
   if (
     containsBytes(
@@ -553,6 +1817,17 @@ export function identifyBridgeInstruction(
   ) {
     return BridgeInstruction.TransferGuardian;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([73, 237, 9, 76, 137, 196, 203, 25])
+      ),
+      0
+    )
+  ) {
+    return BridgeInstruction.VetoMessage;
+  }
   if (
     containsBytes(
       data,
@@ -570,6 +1845,9 @@ export function identifyBridgeInstruction(
 }
 
 export type ParsedBridgeInstruction<TProgram extends string = ''> =
+  | ({
+      instructionType: BridgeInstruction.ActivateOracleEmergency;
+    } & ParsedActivateOracleEmergencyInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.AppendToCallBuffer;
     } & ParsedAppendToCallBufferInstruction<TProgram>)
@@ -579,15 +1857,27 @@ export type ParsedBridgeInstruction<TProgram extends string = ''> =
   | ({
       instructionType: BridgeInstruction.AppendToProveBufferProof;
     } & ParsedAppendToProveBufferProofInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.ApproveOutflow;
+    } & ParsedApproveOutflowInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.AuditVault;
+    } & ParsedAuditVaultInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.BridgeCall;
     } & ParsedBridgeCallInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.BridgeCallBuffered;
     } & ParsedBridgeCallBufferedInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.BridgeEncryptedCall;
+    } & ParsedBridgeEncryptedCallInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.BridgeSol;
     } & ParsedBridgeSolInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.BridgeSolBatch;
+    } & ParsedBridgeSolBatchInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.BridgeSolWithBufferedCall;
     } & ParsedBridgeSolWithBufferedCallInstruction<TProgram>)
@@ -603,39 +1893,174 @@ export type ParsedBridgeInstruction<TProgram extends string = ''> =
   | ({
       instructionType: BridgeInstruction.BridgeWrappedTokenWithBufferedCall;
     } & ParsedBridgeWrappedTokenWithBufferedCallInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.CancelEmergencyExit;
+    } & ParsedCancelEmergencyExitInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.CancelInsurancePayout;
+    } & ParsedCancelInsurancePayoutInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.CheckEnvironment;
+    } & ParsedCheckEnvironmentInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.ClearSolvencyAlert;
+    } & ParsedClearSolvencyAlertInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.CloneCallBuffer;
+    } & ParsedCloneCallBufferInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.CloseCallBuffer;
     } & ParsedCloseCallBufferInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.CloseProveBuffer;
     } & ParsedCloseProveBufferInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.CloseRelayedOutgoingMessage;
+    } & ParsedCloseRelayedOutgoingMessageInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.CloseSupersededMessage;
+    } & ParsedCloseSupersededMessageInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.ConfirmRelayed;
+    } & ParsedConfirmRelayedInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.CreateBridgeSession;
+    } & ParsedCreateBridgeSessionInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.CrosschainMint;
+    } & ParsedCrosschainMintInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.DeactivateOracleEmergency;
+    } & ParsedDeactivateOracleEmergencyInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.DeriveCpiAuthority;
+    } & ParsedDeriveCpiAuthorityInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.EmergencyWithdrawSol;
+    } & ParsedEmergencyWithdrawSolInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.EmergencyWithdrawSpl;
+    } & ParsedEmergencyWithdrawSplInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.EnableEmergencyExit;
+    } & ParsedEnableEmergencyExitInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.ExecuteInsurancePayout;
+    } & ParsedExecuteInsurancePayoutInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.ExpireCallBuffer;
+    } & ParsedExpireCallBufferInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.ExtendProveBuffer;
+    } & ParsedExtendProveBufferInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.GetEnvironment;
+    } & ParsedGetEnvironmentInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.GetOracleHealth;
+    } & ParsedGetOracleHealthInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.GetVersion;
+    } & ParsedGetVersionInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.InitConfigHistory;
+    } & ParsedInitConfigHistoryInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.InitSolVault;
+    } & ParsedInitSolVaultInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.Initialize;
     } & ParsedInitializeInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.InitializeCallBuffer;
     } & ParsedInitializeCallBufferInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.InitializeDevnetDefaults;
+    } & ParsedInitializeDevnetDefaultsInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.InitializeProveBuffer;
     } & ParsedInitializeProveBufferInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.MigrateBridgeState;
+    } & ParsedMigrateBridgeStateInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.PauseFromGovernance;
+    } & ParsedPauseFromGovernanceInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.PostLiabilityRoot;
+    } & ParsedPostLiabilityRootInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.ProveMessage;
     } & ParsedProveMessageInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.ProveMessageBuffered;
     } & ParsedProveMessageBufferedInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.ProveMessageChunked;
+    } & ParsedProveMessageChunkedInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.ReconcileVault;
+    } & ParsedReconcileVaultInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.RecordRelayExecution;
+    } & ParsedRecordRelayExecutionInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.RegisterHandler;
+    } & ParsedRegisterHandlerInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.RegisterOutputRoot;
     } & ParsedRegisterOutputRootInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.RegisterOutputRootsBatch;
+    } & ParsedRegisterOutputRootsBatchInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.RelayMessage;
     } & ParsedRelayMessageInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.ReportEquivocation;
+    } & ParsedReportEquivocationInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.RequestInsurancePayout;
+    } & ParsedRequestInsurancePayoutInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.RevokeBridgeSession;
+    } & ParsedRevokeBridgeSessionInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.ScaleWrappedTokenAmount;
+    } & ParsedScaleWrappedTokenAmountInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetAddressBook;
+    } & ParsedSetAddressBookInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.SetAdjustmentDenominator;
     } & ParsedSetAdjustmentDenominatorInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetBaseGovernance;
+    } & ParsedSetBaseGovernanceInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.SetBlockIntervalRequirement;
     } & ParsedSetBlockIntervalRequirementInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetCallBufferReusable;
+    } & ParsedSetCallBufferReusableInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetCallBufferTtl;
+    } & ParsedSetCallBufferTtlInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetDexRouter;
+    } & ParsedSetDexRouterInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetExpireBountyBps;
+    } & ParsedSetExpireBountyBpsInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetFeeConfig;
+    } & ParsedSetFeeConfigInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetFeeQuoteGracePeriod;
+    } & ParsedSetFeeQuoteGracePeriodInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetFinalizationDelay;
+    } & ParsedSetFinalizationDelayInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.SetGasCostScaler;
     } & ParsedSetGasCostScalerInstruction<TProgram>)
@@ -651,27 +2076,102 @@ export type ParsedBridgeInstruction<TProgram extends string = ''> =
   | ({
       instructionType: BridgeInstruction.SetGasTarget;
     } & ParsedSetGasTargetInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetGovernanceMode;
+    } & ParsedSetGovernanceModeInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetGuardedLaunch;
+    } & ParsedSetGuardedLaunchInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetInsuranceShare;
+    } & ParsedSetInsuranceShareInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetMaintenanceBudget;
+    } & ParsedSetMaintenanceBudgetInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetMaxBaseFee;
+    } & ParsedSetMaxBaseFeeInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.SetMaxCallBufferSize;
     } & ParsedSetMaxCallBufferSizeInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetMaxLamportAllowance;
+    } & ParsedSetMaxLamportAllowanceInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetMaxWindowIncreaseBps;
+    } & ParsedSetMaxWindowIncreaseBpsInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.SetMinimumBaseFee;
     } & ParsedSetMinimumBaseFeeInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetMintAllowed;
+    } & ParsedSetMintAllowedInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetMintPolicy;
+    } & ParsedSetMintPolicyInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetOracleFailoverConfig;
+    } & ParsedSetOracleFailoverConfigInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.SetOracleSigners;
     } & ParsedSetOracleSignersInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetOracleSignersFromGovernance;
+    } & ParsedSetOracleSignersFromGovernanceInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetOutflowBreaker;
+    } & ParsedSetOutflowBreakerInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.SetPartnerOracleConfig;
     } & ParsedSetPartnerOracleConfigInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.SetPauseStatus;
     } & ParsedSetPauseStatusInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetPayerRateLimit;
+    } & ParsedSetPayerRateLimitInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetProtocolFeeConfig;
+    } & ParsedSetProtocolFeeConfigInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetRateLimitExemptPayer;
+    } & ParsedSetRateLimitExemptPayerInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetRelayDenyList;
+    } & ParsedSetRelayDenyListInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetRootFreshnessConfig;
+    } & ParsedSetRootFreshnessConfigInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetTransferGasLane;
+    } & ParsedSetTransferGasLaneInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetVaultDecimals;
+    } & ParsedSetVaultDecimalsInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.SetWindowDuration;
     } & ParsedSetWindowDurationInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetWrappedTokenConfirmationRequired;
+    } & ParsedSetWrappedTokenConfirmationRequiredInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SetWrappedTokenMaxSupply;
+    } & ParsedSetWrappedTokenMaxSupplyInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SettleRelayFees;
+    } & ParsedSettleRelayFeesInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SupersedeOutputRoot;
+    } & ParsedSupersedeOutputRootInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.SweepGasFeeEscrow;
+    } & ParsedSweepGasFeeEscrowInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.TransferGuardian;
     } & ParsedTransferGuardianInstruction<TProgram>)
+  | ({
+      instructionType: BridgeInstruction.VetoMessage;
+    } & ParsedVetoMessageInstruction<TProgram>)
   | ({
       instructionType: BridgeInstruction.WrapToken;
     } & ParsedWrapTokenInstruction<TProgram>);
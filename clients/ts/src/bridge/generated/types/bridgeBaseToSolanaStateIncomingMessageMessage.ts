@@ -7,26 +7,46 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
+  getAddressDecoder,
+  getAddressEncoder,
   getArrayDecoder,
   getArrayEncoder,
+  getBytesDecoder,
+  getBytesEncoder,
   getDiscriminatedUnionDecoder,
   getDiscriminatedUnionEncoder,
   getStructDecoder,
   getStructEncoder,
   getTupleDecoder,
   getTupleEncoder,
+  getU32Decoder,
+  getU32Encoder,
+  getU64Decoder,
+  getU64Encoder,
+  getU8Decoder,
+  getU8Encoder,
+  getUtf8Decoder,
+  getUtf8Encoder,
+  type Address,
   type Codec,
   type Decoder,
   type Encoder,
   type GetDiscriminatedUnionVariant,
   type GetDiscriminatedUnionVariantContent,
+  type ReadonlyUint8Array,
 } from '@solana/kit';
 import {
+  getBridgeBaseToSolanaStateIncomingMessageMessageDecoder,
+  getBridgeBaseToSolanaStateIncomingMessageMessageEncoder,
   getBridgeBaseToSolanaStateIncomingMessageTransferDecoder,
   getBridgeBaseToSolanaStateIncomingMessageTransferEncoder,
   getIxDecoder,
   getIxEncoder,
+  type BridgeBaseToSolanaStateIncomingMessageMessage,
+  type BridgeBaseToSolanaStateIncomingMessageMessageArgs,
   type BridgeBaseToSolanaStateIncomingMessageTransfer,
   type BridgeBaseToSolanaStateIncomingMessageTransferArgs,
   type Ix,
@@ -36,8 +56,8 @@ import {
 /**
  * Defines the type of cross-chain operation being performed from Base to Solana.
  *
- * This enum encapsulates the two main categories of bridge operations:
- * general instruction calls and token transfers with optional additional instructions.
+ * This enum encapsulates the main categories of bridge operations: general instruction calls,
+ * token transfers with optional additional instructions, and returns of Solana → Base transfers.
  */
 export type BridgeBaseToSolanaStateIncomingMessageMessage =
   | { __kind: 'Call'; fields: readonly [Array<Ix>] }
@@ -47,6 +67,60 @@ export type BridgeBaseToSolanaStateIncomingMessageMessage =
       transfer: BridgeBaseToSolanaStateIncomingMessageTransfer;
       /** Additional Solana instructions to execute after the transfer is finalized */
       ixs: Array<Ix>;
+    }
+  | {
+      __kind: 'ReturnTransfer';
+      /** Nonce of the original outgoing message. A transfer can be returned at most once. */
+      outgoingNonce: bigint;
+      /** The transfer returning the tokens to the original Solana sender */
+      transfer: BridgeBaseToSolanaStateIncomingMessageTransfer;
+    }
+  | {
+      __kind: 'CallWithSigners';
+      /** The Solana instructions to execute */
+      ixs: Array<Ix>;
+      /** The custom seeds of each extra signer, namespaced under the sender's bridge CPI authority */
+      signerSeeds: Array<Array<ReadonlyUint8Array>>;
+    }
+  | {
+      __kind: 'WithComputeBudget';
+      /** The compute units that must remain when `relay_message` starts executing the message */
+      computeUnits: number;
+      /** The message to execute, which cannot itself be a `WithComputeBudget` message */
+      message: BridgeBaseToSolanaStateIncomingMessageMessage;
+    }
+  | {
+      __kind: 'WithRelayFee';
+      /** The relay fee in lamports, prepaid by the sender on Base */
+      fee: bigint;
+      /** The message to execute, which cannot be a `WithComputeBudget` or `WithRelayFee` message */
+      message: BridgeBaseToSolanaStateIncomingMessageMessage;
+    }
+  | {
+      __kind: 'WithLamportAllowance';
+      /** The lamports lent to the bridge CPI authority, bounded by the operational vault */
+      lamports: bigint;
+      /**
+       * The message to execute, which cannot be a `WithComputeBudget`, `WithRelayFee` or
+       * `WithLamportAllowance` message
+       */
+      message: BridgeBaseToSolanaStateIncomingMessageMessage;
+    }
+  | {
+      __kind: 'ConfirmWrappedToken';
+      /** The confirmed wrapped token mint */
+      mint: Address;
+      /** The name of the Base token */
+      name: string;
+      /** The symbol of the Base token */
+      symbol: string;
+      /** The decimals of the Base token */
+      decimals: number;
+    }
+  | {
+      __kind: 'CallHandler';
+      /** The payload passed to the handler, of at most MAX_HANDLER_PAYLOAD_LEN bytes */
+      payload: ReadonlyUint8Array;
     };
 
 export type BridgeBaseToSolanaStateIncomingMessageMessageArgs =
@@ -57,6 +131,60 @@ export type BridgeBaseToSolanaStateIncomingMessageMessageArgs =
       transfer: BridgeBaseToSolanaStateIncomingMessageTransferArgs;
       /** Additional Solana instructions to execute after the transfer is finalized */
       ixs: Array<IxArgs>;
+    }
+  | {
+      __kind: 'ReturnTransfer';
+      /** Nonce of the original outgoing message. A transfer can be returned at most once. */
+      outgoingNonce: number | bigint;
+      /** The transfer returning the tokens to the original Solana sender */
+      transfer: BridgeBaseToSolanaStateIncomingMessageTransferArgs;
+    }
+  | {
+      __kind: 'CallWithSigners';
+      /** The Solana instructions to execute */
+      ixs: Array<IxArgs>;
+      /** The custom seeds of each extra signer, namespaced under the sender's bridge CPI authority */
+      signerSeeds: Array<Array<ReadonlyUint8Array>>;
+    }
+  | {
+      __kind: 'WithComputeBudget';
+      /** The compute units that must remain when `relay_message` starts executing the message */
+      computeUnits: number;
+      /** The message to execute, which cannot itself be a `WithComputeBudget` message */
+      message: BridgeBaseToSolanaStateIncomingMessageMessageArgs;
+    }
+  | {
+      __kind: 'WithRelayFee';
+      /** The relay fee in lamports, prepaid by the sender on Base */
+      fee: number | bigint;
+      /** The message to execute, which cannot be a `WithComputeBudget` or `WithRelayFee` message */
+      message: BridgeBaseToSolanaStateIncomingMessageMessageArgs;
+    }
+  | {
+      __kind: 'WithLamportAllowance';
+      /** The lamports lent to the bridge CPI authority, bounded by the operational vault */
+      lamports: number | bigint;
+      /**
+       * The message to execute, which cannot be a `WithComputeBudget`, `WithRelayFee` or
+       * `WithLamportAllowance` message
+       */
+      message: BridgeBaseToSolanaStateIncomingMessageMessageArgs;
+    }
+  | {
+      __kind: 'ConfirmWrappedToken';
+      /** The confirmed wrapped token mint */
+      mint: Address;
+      /** The name of the Base token */
+      name: string;
+      /** The symbol of the Base token */
+      symbol: string;
+      /** The decimals of the Base token */
+      decimals: number;
+    }
+  | {
+      __kind: 'CallHandler';
+      /** The payload passed to the handler, of at most MAX_HANDLER_PAYLOAD_LEN bytes */
+      payload: ReadonlyUint8Array;
     };
 
 export function getBridgeBaseToSolanaStateIncomingMessageMessageEncoder(): Encoder<BridgeBaseToSolanaStateIncomingMessageMessageArgs> {
@@ -77,6 +205,66 @@ export function getBridgeBaseToSolanaStateIncomingMessageMessageEncoder(): Encod
         ['ixs', getArrayEncoder(getIxEncoder())],
       ]),
     ],
+    [
+      'ReturnTransfer',
+      getStructEncoder([
+        ['outgoingNonce', getU64Encoder()],
+        [
+          'transfer',
+          getBridgeBaseToSolanaStateIncomingMessageTransferEncoder(),
+        ],
+      ]),
+    ],
+    [
+      'CallWithSigners',
+      getStructEncoder([
+        ['ixs', getArrayEncoder(getIxEncoder())],
+        [
+          'signerSeeds',
+          getArrayEncoder(
+            getArrayEncoder(
+              addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())
+            )
+          ),
+        ],
+      ]),
+    ],
+    [
+      'WithComputeBudget',
+      getStructEncoder([
+        ['computeUnits', getU32Encoder()],
+        ['message', getBridgeBaseToSolanaStateIncomingMessageMessageEncoder()],
+      ]),
+    ],
+    [
+      'WithRelayFee',
+      getStructEncoder([
+        ['fee', getU64Encoder()],
+        ['message', getBridgeBaseToSolanaStateIncomingMessageMessageEncoder()],
+      ]),
+    ],
+    [
+      'WithLamportAllowance',
+      getStructEncoder([
+        ['lamports', getU64Encoder()],
+        ['message', getBridgeBaseToSolanaStateIncomingMessageMessageEncoder()],
+      ]),
+    ],
+    [
+      'ConfirmWrappedToken',
+      getStructEncoder([
+        ['mint', getAddressEncoder()],
+        ['name', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+        ['symbol', addEncoderSizePrefix(getUtf8Encoder(), getU32Encoder())],
+        ['decimals', getU8Encoder()],
+      ]),
+    ],
+    [
+      'CallHandler',
+      getStructEncoder([
+        ['payload', addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
+      ]),
+    ],
   ]);
 }
 
@@ -98,6 +286,66 @@ export function getBridgeBaseToSolanaStateIncomingMessageMessageDecoder(): Decod
         ['ixs', getArrayDecoder(getIxDecoder())],
       ]),
     ],
+    [
+      'ReturnTransfer',
+      getStructDecoder([
+        ['outgoingNonce', getU64Decoder()],
+        [
+          'transfer',
+          getBridgeBaseToSolanaStateIncomingMessageTransferDecoder(),
+        ],
+      ]),
+    ],
+    [
+      'CallWithSigners',
+      getStructDecoder([
+        ['ixs', getArrayDecoder(getIxDecoder())],
+        [
+          'signerSeeds',
+          getArrayDecoder(
+            getArrayDecoder(
+              addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())
+            )
+          ),
+        ],
+      ]),
+    ],
+    [
+      'WithComputeBudget',
+      getStructDecoder([
+        ['computeUnits', getU32Decoder()],
+        ['message', getBridgeBaseToSolanaStateIncomingMessageMessageDecoder()],
+      ]),
+    ],
+    [
+      'WithRelayFee',
+      getStructDecoder([
+        ['fee', getU64Decoder()],
+        ['message', getBridgeBaseToSolanaStateIncomingMessageMessageDecoder()],
+      ]),
+    ],
+    [
+      'WithLamportAllowance',
+      getStructDecoder([
+        ['lamports', getU64Decoder()],
+        ['message', getBridgeBaseToSolanaStateIncomingMessageMessageDecoder()],
+      ]),
+    ],
+    [
+      'ConfirmWrappedToken',
+      getStructDecoder([
+        ['mint', getAddressDecoder()],
+        ['name', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+        ['symbol', addDecoderSizePrefix(getUtf8Decoder(), getU32Decoder())],
+        ['decimals', getU8Decoder()],
+      ]),
+    ],
+    [
+      'CallHandler',
+      getStructDecoder([
+        ['payload', addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
+      ]),
+    ],
   ]);
 }
 
@@ -136,6 +384,90 @@ export function bridgeBaseToSolanaStateIncomingMessageMessage(
   '__kind',
   'Transfer'
 >;
+export function bridgeBaseToSolanaStateIncomingMessageMessage(
+  kind: 'ReturnTransfer',
+  data: GetDiscriminatedUnionVariantContent<
+    BridgeBaseToSolanaStateIncomingMessageMessageArgs,
+    '__kind',
+    'ReturnTransfer'
+  >
+): GetDiscriminatedUnionVariant<
+  BridgeBaseToSolanaStateIncomingMessageMessageArgs,
+  '__kind',
+  'ReturnTransfer'
+>;
+export function bridgeBaseToSolanaStateIncomingMessageMessage(
+  kind: 'CallWithSigners',
+  data: GetDiscriminatedUnionVariantContent<
+    BridgeBaseToSolanaStateIncomingMessageMessageArgs,
+    '__kind',
+    'CallWithSigners'
+  >
+): GetDiscriminatedUnionVariant<
+  BridgeBaseToSolanaStateIncomingMessageMessageArgs,
+  '__kind',
+  'CallWithSigners'
+>;
+export function bridgeBaseToSolanaStateIncomingMessageMessage(
+  kind: 'WithComputeBudget',
+  data: GetDiscriminatedUnionVariantContent<
+    BridgeBaseToSolanaStateIncomingMessageMessageArgs,
+    '__kind',
+    'WithComputeBudget'
+  >
+): GetDiscriminatedUnionVariant<
+  BridgeBaseToSolanaStateIncomingMessageMessageArgs,
+  '__kind',
+  'WithComputeBudget'
+>;
+export function bridgeBaseToSolanaStateIncomingMessageMessage(
+  kind: 'WithRelayFee',
+  data: GetDiscriminatedUnionVariantContent<
+    BridgeBaseToSolanaStateIncomingMessageMessageArgs,
+    '__kind',
+    'WithRelayFee'
+  >
+): GetDiscriminatedUnionVariant<
+  BridgeBaseToSolanaStateIncomingMessageMessageArgs,
+  '__kind',
+  'WithRelayFee'
+>;
+export function bridgeBaseToSolanaStateIncomingMessageMessage(
+  kind: 'WithLamportAllowance',
+  data: GetDiscriminatedUnionVariantContent<
+    BridgeBaseToSolanaStateIncomingMessageMessageArgs,
+    '__kind',
+    'WithLamportAllowance'
+  >
+): GetDiscriminatedUnionVariant<
+  BridgeBaseToSolanaStateIncomingMessageMessageArgs,
+  '__kind',
+  'WithLamportAllowance'
+>;
+export function bridgeBaseToSolanaStateIncomingMessageMessage(
+  kind: 'ConfirmWrappedToken',
+  data: GetDiscriminatedUnionVariantContent<
+    BridgeBaseToSolanaStateIncomingMessageMessageArgs,
+    '__kind',
+    'ConfirmWrappedToken'
+  >
+): GetDiscriminatedUnionVariant<
+  BridgeBaseToSolanaStateIncomingMessageMessageArgs,
+  '__kind',
+  'ConfirmWrappedToken'
+>;
+export function bridgeBaseToSolanaStateIncomingMessageMessage(
+  kind: 'CallHandler',
+  data: GetDiscriminatedUnionVariantContent<
+    BridgeBaseToSolanaStateIncomingMessageMessageArgs,
+    '__kind',
+    'CallHandler'
+  >
+): GetDiscriminatedUnionVariant<
+  BridgeBaseToSolanaStateIncomingMessageMessageArgs,
+  '__kind',
+  'CallHandler'
+>;
 export function bridgeBaseToSolanaStateIncomingMessageMessage<
   K extends BridgeBaseToSolanaStateIncomingMessageMessageArgs['__kind'],
   Data,
@@ -25,30 +25,39 @@ import {
   getBridgeSolanaToBaseStateOutgoingMessageTransferEncoder,
   getCallDecoder,
   getCallEncoder,
+  getTransferBatchDecoder,
+  getTransferBatchEncoder,
   type BridgeSolanaToBaseStateOutgoingMessageTransfer,
   type BridgeSolanaToBaseStateOutgoingMessageTransferArgs,
   type Call,
   type CallArgs,
+  type TransferBatch,
+  type TransferBatchArgs,
 } from '.';
 
 /**
  * Represents the type of cross-chain operation to be executed on Base.
- * This enum encapsulates the two main types of operations supported by the bridge:
- * direct contract calls and token transfers with optional contract calls.
+ * This enum encapsulates the main types of operations supported by the bridge:
+ * direct contract calls, token transfers with optional contract calls, encrypted calls and
+ * transfer batches.
  */
 export type BridgeSolanaToBaseStateOutgoingMessageMessage =
   | { __kind: 'Call'; fields: readonly [Call] }
   | {
       __kind: 'Transfer';
       fields: readonly [BridgeSolanaToBaseStateOutgoingMessageTransfer];
-    };
+    }
+  | { __kind: 'Encrypted'; fields: readonly [Call] }
+  | { __kind: 'TransferBatch'; fields: readonly [TransferBatch] };
 
 export type BridgeSolanaToBaseStateOutgoingMessageMessageArgs =
   | { __kind: 'Call'; fields: readonly [CallArgs] }
   | {
       __kind: 'Transfer';
       fields: readonly [BridgeSolanaToBaseStateOutgoingMessageTransferArgs];
-    };
+    }
+  | { __kind: 'Encrypted'; fields: readonly [CallArgs] }
+  | { __kind: 'TransferBatch'; fields: readonly [TransferBatchArgs] };
 
 export function getBridgeSolanaToBaseStateOutgoingMessageMessageEncoder(): Encoder<BridgeSolanaToBaseStateOutgoingMessageMessageArgs> {
   return getDiscriminatedUnionEncoder([
@@ -67,6 +76,16 @@ export function getBridgeSolanaToBaseStateOutgoingMessageMessageEncoder(): Encod
         ],
       ]),
     ],
+    [
+      'Encrypted',
+      getStructEncoder([['fields', getTupleEncoder([getCallEncoder()])]]),
+    ],
+    [
+      'TransferBatch',
+      getStructEncoder([
+        ['fields', getTupleEncoder([getTransferBatchEncoder()])],
+      ]),
+    ],
   ]);
 }
 
@@ -87,6 +106,16 @@ export function getBridgeSolanaToBaseStateOutgoingMessageMessageDecoder(): Decod
         ],
       ]),
     ],
+    [
+      'Encrypted',
+      getStructDecoder([['fields', getTupleDecoder([getCallDecoder()])]]),
+    ],
+    [
+      'TransferBatch',
+      getStructDecoder([
+        ['fields', getTupleDecoder([getTransferBatchDecoder()])],
+      ]),
+    ],
   ]);
 }
 
@@ -125,6 +154,30 @@ export function bridgeSolanaToBaseStateOutgoingMessageMessage(
   '__kind',
   'Transfer'
 >;
+export function bridgeSolanaToBaseStateOutgoingMessageMessage(
+  kind: 'Encrypted',
+  data: GetDiscriminatedUnionVariantContent<
+    BridgeSolanaToBaseStateOutgoingMessageMessageArgs,
+    '__kind',
+    'Encrypted'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  BridgeSolanaToBaseStateOutgoingMessageMessageArgs,
+  '__kind',
+  'Encrypted'
+>;
+export function bridgeSolanaToBaseStateOutgoingMessageMessage(
+  kind: 'TransferBatch',
+  data: GetDiscriminatedUnionVariantContent<
+    BridgeSolanaToBaseStateOutgoingMessageMessageArgs,
+    '__kind',
+    'TransferBatch'
+  >['fields']
+): GetDiscriminatedUnionVariant<
+  BridgeSolanaToBaseStateOutgoingMessageMessageArgs,
+  '__kind',
+  'TransferBatch'
+>;
 export function bridgeSolanaToBaseStateOutgoingMessageMessage<
   K extends BridgeSolanaToBaseStateOutgoingMessageMessageArgs['__kind'],
   Data,
@@ -7,6 +7,8 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   combineCodec,
   fixDecoderSize,
   fixEncoderSize,
@@ -18,6 +20,8 @@ import {
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   type Address,
@@ -28,7 +32,16 @@ import {
   type OptionOrNullable,
   type ReadonlyUint8Array,
 } from '@solana/kit';
-import { getCallDecoder, getCallEncoder, type Call, type CallArgs } from '.';
+import {
+  getCallDecoder,
+  getCallEncoder,
+  getSwapGuardDecoder,
+  getSwapGuardEncoder,
+  type Call,
+  type CallArgs,
+  type SwapGuard,
+  type SwapGuardArgs,
+} from '.';
 
 /**
  * Represents a token transfer from Solana to Base with optional contract execution.
@@ -59,6 +72,20 @@ export type BridgeSolanaToBaseStateOutgoingMessageTransfer = {
    * Allows for complex cross-chain operations that combine token transfers with logic execution.
    */
   call: Option<Call>;
+  /**
+   * Optional opaque payment memo of at most `MAX_TRANSFER_MEMO_LEN` bytes, used by payment
+   * processors to match invoices. It is appended to the message data relayed to Base
+   * (`abi.encode(transfer, memo)` or `abi.encode(transfer, call, memo)`), where it is covered
+   * by the message hash and ignored when decoding the transfer.
+   */
+  memo: Option<ReadonlyUint8Array>;
+  /**
+   * Optional slippage protection for a `call` swapping the bridged tokens on Base, required
+   * when the call targets a router of the `DexRouterList`. It is appended to the message data
+   * relayed to Base after the memo (`abi.encode(transfer, call, memo, swap_guard)`, with an
+   * empty memo if there is none), where the executor enforces it around the call.
+   */
+  swapGuard: Option<SwapGuard>;
 };
 
 export type BridgeSolanaToBaseStateOutgoingMessageTransferArgs = {
@@ -85,6 +112,20 @@ export type BridgeSolanaToBaseStateOutgoingMessageTransferArgs = {
    * Allows for complex cross-chain operations that combine token transfers with logic execution.
    */
   call: OptionOrNullable<CallArgs>;
+  /**
+   * Optional opaque payment memo of at most `MAX_TRANSFER_MEMO_LEN` bytes, used by payment
+   * processors to match invoices. It is appended to the message data relayed to Base
+   * (`abi.encode(transfer, memo)` or `abi.encode(transfer, call, memo)`), where it is covered
+   * by the message hash and ignored when decoding the transfer.
+   */
+  memo: OptionOrNullable<ReadonlyUint8Array>;
+  /**
+   * Optional slippage protection for a `call` swapping the bridged tokens on Base, required
+   * when the call targets a router of the `DexRouterList`. It is appended to the message data
+   * relayed to Base after the memo (`abi.encode(transfer, call, memo, swap_guard)`, with an
+   * empty memo if there is none), where the executor enforces it around the call.
+   */
+  swapGuard: OptionOrNullable<SwapGuardArgs>;
 };
 
 export function getBridgeSolanaToBaseStateOutgoingMessageTransferEncoder(): Encoder<BridgeSolanaToBaseStateOutgoingMessageTransferArgs> {
@@ -94,6 +135,13 @@ export function getBridgeSolanaToBaseStateOutgoingMessageTransferEncoder(): Enco
     ['remoteToken', fixEncoderSize(getBytesEncoder(), 20)],
     ['amount', getU64Encoder()],
     ['call', getOptionEncoder(getCallEncoder())],
+    [
+      'memo',
+      getOptionEncoder(
+        addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())
+      ),
+    ],
+    ['swapGuard', getOptionEncoder(getSwapGuardEncoder())],
   ]);
 }
 
@@ -104,6 +152,13 @@ export function getBridgeSolanaToBaseStateOutgoingMessageTransferDecoder(): Deco
     ['remoteToken', fixDecoderSize(getBytesDecoder(), 20)],
     ['amount', getU64Decoder()],
     ['call', getOptionDecoder(getCallDecoder())],
+    [
+      'memo',
+      getOptionDecoder(
+        addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())
+      ),
+    ],
+    ['swapGuard', getOptionDecoder(getSwapGuardDecoder())],
   ]);
 }
 
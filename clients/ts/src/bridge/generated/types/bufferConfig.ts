@@ -20,19 +20,35 @@ import {
 export type BufferConfig = {
   /** Maximum call buffer size. This caps the max size of a Solana → Base message. */
   maxCallBufferSize: bigint;
+  /** Seconds a call buffer must stay unmodified before anyone can expire it (0 disables expiry). */
+  callBufferTtlSeconds: bigint;
+  /** Share of an expired call buffer's rent paid to the caller, in basis points. */
+  expireBountyBps: bigint;
 };
 
 export type BufferConfigArgs = {
   /** Maximum call buffer size. This caps the max size of a Solana → Base message. */
   maxCallBufferSize: number | bigint;
+  /** Seconds a call buffer must stay unmodified before anyone can expire it (0 disables expiry). */
+  callBufferTtlSeconds: number | bigint;
+  /** Share of an expired call buffer's rent paid to the caller, in basis points. */
+  expireBountyBps: number | bigint;
 };
 
 export function getBufferConfigEncoder(): FixedSizeEncoder<BufferConfigArgs> {
-  return getStructEncoder([['maxCallBufferSize', getU64Encoder()]]);
+  return getStructEncoder([
+    ['maxCallBufferSize', getU64Encoder()],
+    ['callBufferTtlSeconds', getU64Encoder()],
+    ['expireBountyBps', getU64Encoder()],
+  ]);
 }
 
 export function getBufferConfigDecoder(): FixedSizeDecoder<BufferConfig> {
-  return getStructDecoder([['maxCallBufferSize', getU64Decoder()]]);
+  return getStructDecoder([
+    ['maxCallBufferSize', getU64Decoder()],
+    ['callBufferTtlSeconds', getU64Decoder()],
+    ['expireBountyBps', getU64Decoder()],
+  ]);
 }
 
 export function getBufferConfigCodec(): FixedSizeCodec<
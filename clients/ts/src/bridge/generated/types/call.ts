@@ -54,6 +54,7 @@ export type Call = {
    * The encoded function call data or contract bytecode.
    * For regular calls: ABI-encoded function signature and parameters.
    * For contract creation: the contract's initialization bytecode.
+   * `INIT_SPACE` only accounts for its length prefix, see `Call::space`.
    */
   data: ReadonlyUint8Array;
 };
@@ -75,6 +76,7 @@ export type CallArgs = {
    * The encoded function call data or contract bytecode.
    * For regular calls: ABI-encoded function signature and parameters.
    * For contract creation: the contract's initialization bytecode.
+   * `INIT_SPACE` only accounts for its length prefix, see `Call::space`.
    */
   data: ReadonlyUint8Array;
 };
@@ -30,7 +30,7 @@ export type Eip1559 = {
   config: Eip1559Config;
   /**
    * Current base fee used in fee computation (runtime state).
-   * Unitless value combined with `gas_per_call` and gas cost scaler to produce lamports.
+   * Unitless value combined with the gas usage and gas cost scaler to produce lamports.
    */
   currentBaseFee: bigint;
   /** Gas used in the current time window (runtime state) */
@@ -44,7 +44,7 @@ export type Eip1559Args = {
   config: Eip1559ConfigArgs;
   /**
    * Current base fee used in fee computation (runtime state).
-   * Unitless value combined with `gas_per_call` and gas cost scaler to produce lamports.
+   * Unitless value combined with the gas usage and gas cost scaler to produce lamports.
    */
   currentBaseFee: number | bigint;
   /** Gas used in the current time window (runtime state) */
@@ -30,6 +30,13 @@ export type Eip1559Config = {
    * on every step.
    */
   minimumBaseFee: bigint;
+  /** Maximum base fee. The base fee never grows above this value (0 disables the cap). */
+  maxBaseFee: bigint;
+  /**
+   * Maximum base fee increase per window, in basis points of the current base fee
+   * (0 disables the cap).
+   */
+  maxWindowIncreaseBps: bigint;
 };
 
 export type Eip1559ConfigArgs = {
@@ -45,6 +52,13 @@ export type Eip1559ConfigArgs = {
    * on every step.
    */
   minimumBaseFee: number | bigint;
+  /** Maximum base fee. The base fee never grows above this value (0 disables the cap). */
+  maxBaseFee: number | bigint;
+  /**
+   * Maximum base fee increase per window, in basis points of the current base fee
+   * (0 disables the cap).
+   */
+  maxWindowIncreaseBps: number | bigint;
 };
 
 export function getEip1559ConfigEncoder(): FixedSizeEncoder<Eip1559ConfigArgs> {
@@ -53,6 +67,8 @@ export function getEip1559ConfigEncoder(): FixedSizeEncoder<Eip1559ConfigArgs> {
     ['denominator', getU64Encoder()],
     ['windowDurationSeconds', getU64Encoder()],
     ['minimumBaseFee', getU64Encoder()],
+    ['maxBaseFee', getU64Encoder()],
+    ['maxWindowIncreaseBps', getU64Encoder()],
   ]);
 }
 
@@ -62,6 +78,8 @@ export function getEip1559ConfigDecoder(): FixedSizeDecoder<Eip1559Config> {
     ['denominator', getU64Decoder()],
     ['windowDurationSeconds', getU64Decoder()],
     ['minimumBaseFee', getU64Decoder()],
+    ['maxBaseFee', getU64Decoder()],
+    ['maxWindowIncreaseBps', getU64Decoder()],
   ]);
 }
 
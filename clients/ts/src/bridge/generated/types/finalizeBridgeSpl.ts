@@ -48,8 +48,9 @@ export type FinalizeBridgeSpl = {
    */
   to: Address;
   /**
-   * The amount to transfer, in base units of the mint (respecting mint decimals).
-   * `transfer_checked` enforces that the destination account's mint matches and
+   * The amount to transfer, in the smallest unit of the remote token. It is scaled up to the
+   * mint decimals when the vault decimals are configured, and is in base units of the mint
+   * otherwise. `transfer_checked` enforces that the destination account's mint matches and
    * the decimals are correct.
    */
   amount: bigint;
@@ -72,8 +73,9 @@ export type FinalizeBridgeSplArgs = {
    */
   to: Address;
   /**
-   * The amount to transfer, in base units of the mint (respecting mint decimals).
-   * `transfer_checked` enforces that the destination account's mint matches and
+   * The amount to transfer, in the smallest unit of the remote token. It is scaled up to the
+   * mint decimals when the vault decimals are configured, and is in base units of the mint
+   * otherwise. `transfer_checked` enforces that the destination account's mint matches and
    * the decimals are correct.
    */
   amount: number | bigint;
@@ -6,21 +6,89 @@
  * @see https://github.com/codama-idl/codama
  */
 
+export * from './addressBookToken';
+export * from './auditReport';
+export * from './baseFeeCapped';
+export * from './baseGovernance';
 export * from './baseOracleConfig';
+export * from './batchRecipient';
 export * from './bridgeBaseToSolanaStateIncomingMessageMessage';
 export * from './bridgeBaseToSolanaStateIncomingMessageTransfer';
 export * from './bridgeSolanaToBaseStateOutgoingMessageMessage';
 export * from './bridgeSolanaToBaseStateOutgoingMessageTransfer';
 export * from './bufferConfig';
 export * from './call';
+export * from './callBufferAppended';
+export * from './callBufferCloned';
+export * from './callBufferClosed';
+export * from './callBufferExpired';
+export * from './callBufferInitialized';
+export * from './callBufferReusableSet';
+export * from './callBufferUsed';
 export * from './callType';
+export * from './configChange';
+export * from './crosschainBurn';
+export * from './crosschainMint';
+export * from './dexRouterListUpdated';
 export * from './eip1559';
 export * from './eip1559Config';
+export * from './emergencyExitCancelled';
+export * from './emergencyExitEnabled';
+export * from './emergencyQuorumUsed';
+export * from './emergencyWithdrawal';
+export * from './environment';
+export * from './feeQuoteGrace';
+export * from './finalizationDelay';
+export * from './finalizationThreshold';
 export * from './finalizeBridgeSol';
 export * from './finalizeBridgeSpl';
 export * from './finalizeBridgeWrappedToken';
 export * from './gasConfig';
+export * from './guardedLaunchSet';
+export * from './handlerRegistered';
+export * from './insurancePayout';
+export * from './insurancePayoutCancelled';
+export * from './insurancePayoutExecuted';
+export * from './insurancePayoutRequested';
 export * from './ix';
 export * from './ixAccount';
+export * from './lamportAllowanceReturned';
+export * from './messageProven';
+export * from './messageRelayed';
+export * from './messageVetoed';
+export * from './mintAllowListUpdated';
+export * from './oracleEmergencyActivated';
+export * from './oracleEmergencyDeactivated';
+export * from './oracleEquivocationReported';
+export * from './oracleFailover';
+export * from './oracleHealth';
+export * from './oracleHealthReport';
+export * from './oracleHeartbeat';
+export * from './outbox';
+export * from './outflowApproved';
+export * from './outflowBreaker';
+export * from './outflowCircuitBreakerTripped';
+export * from './outflowLimit';
+export * from './outflowThreshold';
+export * from './outgoingMessageCommitted';
+export * from './outgoingMessageReceipt';
+export * from './outputRootEntry';
+export * from './outputRootSuperseded';
 export * from './partnerOracleConfig';
+export * from './payerRateLimitExemptionUpdated';
+export * from './payerRateLimitSet';
 export * from './protocolConfig';
+export * from './protocolFeeCharged';
+export * from './protocolFeeOverride';
+export * from './relayFeePaid';
+export * from './relayFeesSettled';
+export * from './rootFreshnessConfig';
+export * from './swapGuard';
+export * from './transferBatch';
+export * from './transferMemo';
+export * from './vaultReconciled';
+export * from './version';
+export * from './wrapTokenResult';
+export * from './wrappedTokenConfirmationRequiredSet';
+export * from './wrappedTokenConfirmed';
+export * from './wrappedTokenMaxSupplyUpdated';
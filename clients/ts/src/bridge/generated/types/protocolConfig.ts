@@ -30,6 +30,11 @@ export type ProtocolConfig = {
   blockIntervalRequirement: bigint;
   /** The Base evm address of SOL */
   remoteSolAddress: ReadonlyUint8Array;
+  /**
+   * Chain ID of the remote chain messages are sent to and received from.
+   * 0 selects the `BASE_CHAIN_ID` of the compiled environment (see `Bridge::remote_chain_id`).
+   */
+  remoteChainId: bigint;
 };
 
 export type ProtocolConfigArgs = {
@@ -40,12 +45,18 @@ export type ProtocolConfigArgs = {
   blockIntervalRequirement: number | bigint;
   /** The Base evm address of SOL */
   remoteSolAddress: ReadonlyUint8Array;
+  /**
+   * Chain ID of the remote chain messages are sent to and received from.
+   * 0 selects the `BASE_CHAIN_ID` of the compiled environment (see `Bridge::remote_chain_id`).
+   */
+  remoteChainId: number | bigint;
 };
 
 export function getProtocolConfigEncoder(): FixedSizeEncoder<ProtocolConfigArgs> {
   return getStructEncoder([
     ['blockIntervalRequirement', getU64Encoder()],
     ['remoteSolAddress', fixEncoderSize(getBytesEncoder(), 20)],
+    ['remoteChainId', getU64Encoder()],
   ]);
 }
 
@@ -53,6 +64,7 @@ export function getProtocolConfigDecoder(): FixedSizeDecoder<ProtocolConfig> {
   return getStructDecoder([
     ['blockIntervalRequirement', getU64Decoder()],
     ['remoteSolAddress', fixDecoderSize(getBytesDecoder(), 20)],
+    ['remoteChainId', getU64Decoder()],
   ]);
 }
 
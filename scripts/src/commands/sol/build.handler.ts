@@ -33,7 +33,7 @@ type ProgramContext = {
   dir: ProgramDir;
   kpArg: ProgramKpArg;
   configKeypairPath: string;
-  libRsPath: string;
+  envRsPath: string;
   backupPath: string;
   isRestored: boolean;
 };
@@ -53,7 +53,7 @@ export async function handleBuild(args: Args): Promise<void> {
     const programContexts: ProgramContext[] = [];
 
     const programDefinitions: Array<
-      Omit<ProgramContext, "libRsPath" | "backupPath" | "isRestored">
+      Omit<ProgramContext, "envRsPath" | "backupPath" | "isRestored">
     > = [
       {
         name: "Bridge",
@@ -70,16 +70,16 @@ export async function handleBuild(args: Args): Promise<void> {
     ];
 
     for (const definition of programDefinitions) {
-      const libRsPath = await findLibRs(projectRoot, definition.dir);
-      logger.info(`[${definition.name}] Found lib.rs at: ${libRsPath}`);
+      const envRsPath = await findEnvRs(projectRoot, definition.dir);
+      logger.info(`[${definition.name}] Found env.rs at: ${envRsPath}`);
 
-      const backupPath = `${libRsPath}.backup`;
-      await $`cp ${libRsPath} ${backupPath}`;
-      logger.info(`[${definition.name}] Backed up lib.rs`);
+      const backupPath = `${envRsPath}.backup`;
+      await $`cp ${envRsPath} ${backupPath}`;
+      logger.info(`[${definition.name}] Backed up env.rs`);
 
       programContexts.push({
         ...definition,
-        libRsPath,
+        envRsPath,
         backupPath,
         isRestored: false,
       });
@@ -89,8 +89,8 @@ export async function handleBuild(args: Args): Promise<void> {
     const restoreAllLibs = async () => {
       for (const context of programContexts) {
         if (!context.isRestored && existsSync(context.backupPath)) {
-          await $`mv ${context.backupPath} ${context.libRsPath}`;
-          logger.info(`[${context.name}] Restored lib.rs`);
+          await $`mv ${context.backupPath} ${context.envRsPath}`;
+          logger.info(`[${context.name}] Restored env.rs`);
           context.isRestored = true;
         }
       }
@@ -107,8 +107,10 @@ export async function handleBuild(args: Args): Promise<void> {
     process.on("SIGTERM", () => signalHandler("SIGTERM")); // Kill
     process.on("SIGHUP", () => signalHandler("SIGHUP")); // Terminal closed
 
+    const programIds: Partial<Record<ProgramDir, string>> = {};
+
     try {
-      // Update declare_id in lib.rs for each program
+      // Update declare_id in env.rs for each program
       for (const context of programContexts) {
         const programId = await resolveProgramId(
           projectRoot,
@@ -118,24 +120,40 @@ export async function handleBuild(args: Args): Promise<void> {
         );
         logger.info(`[${context.name}] Program ID: ${programId}`);
 
-        const libContent = await Bun.file(context.libRsPath).text();
-        const updatedContent = libContent.replace(
-          /declare_id!\("([^"]+)"\)/,
+        const envContent = await Bun.file(context.envRsPath).text();
+        const updatedContent = envContent.replace(
+          /declare_id!\("([^"]+)"\)/g,
           `declare_id!("${programId}")`
         );
 
-        await Bun.write(context.libRsPath, updatedContent);
-        logger.info(`[${context.name}] Updated declare_id in lib.rs`);
+        await Bun.write(context.envRsPath, updatedContent);
+        logger.info(`[${context.name}] Updated declare_id in env.rs`);
+        programIds[context.dir] = programId;
       }
 
-      // Build program with cargo-build-sbf
-      logger.info("Running cargo-build-sbf...");
+      // Point the relayer at the bridge program being built
+      const relayerContext = programContexts.find(
+        (context) => context.dir === "base_relayer"
+      )!;
+      const relayerContent = await Bun.file(relayerContext.envRsPath).text();
+      await Bun.write(
+        relayerContext.envRsPath,
+        relayerContent.replace(
+          /BRIDGE_PROGRAM_ID: Pubkey = pubkey!\("([^"]+)"\)/g,
+          `BRIDGE_PROGRAM_ID: Pubkey = pubkey!("${programIds.bridge}")`
+        )
+      );
+      logger.info(`[${relayerContext.name}] Updated BRIDGE_PROGRAM_ID in env.rs`);
+
+      // Build program with cargo-build-sbf for the selected environment
+      const feature = config.solana.programFeature;
+      logger.info(`Running cargo-build-sbf with feature ${feature}...`);
       const solanaDir = join(projectRoot, "solana");
-      await $`cargo-build-sbf`.cwd(solanaDir);
+      await $`cargo-build-sbf --features ${feature}`.cwd(solanaDir);
 
       logger.success("Program build completed!");
     } finally {
-      // Always restore lib.rs
+      // Always restore env.rs
       await restoreAllLibs();
 
       // Remove signal handlers
@@ -149,19 +167,19 @@ export async function handleBuild(args: Args): Promise<void> {
   }
 }
 
-async function findLibRs(
+async function findEnvRs(
   projectRoot: string,
   programDir: ProgramDir
 ): Promise<string> {
-  const libRsPath = join(
+  const envRsPath = join(
     projectRoot,
-    `solana/programs/${programDir}/src/lib.rs`
+    `solana/programs/${programDir}/src/env.rs`
   );
-  if (!existsSync(libRsPath)) {
-    throw new Error(`lib.rs not found at: ${libRsPath}`);
+  if (!existsSync(envRsPath)) {
+    throw new Error(`env.rs not found at: ${envRsPath}`);
   }
 
-  return libRsPath;
+  return envRsPath;
 }
 
 async function resolveProgramId(
@@ -21,6 +21,9 @@ export type Config = {
     cluster: string;
     rpcUrl: string;
 
+    // Cargo feature selecting the programs' environment constants
+    programFeature: string;
+
     // Keypairs
     deployerKpPath: string;
     bridgeKpPath: string;
@@ -60,6 +63,7 @@ export const CONFIGS = {
     solana: {
       cluster: "devnet",
       rpcUrl: "https://api.devnet.solana.com",
+      programFeature: "devnet-alpha",
 
       // Keypairs
       deployerKpPath: "keypairs/deployer.devnet.alpha.json",
@@ -100,6 +104,7 @@ export const CONFIGS = {
     solana: {
       cluster: "devnet",
       rpcUrl: "https://api.devnet.solana.com",
+      programFeature: "devnet-prod",
 
       // Keypairs
       deployerKpPath: "keypairs/deployer.devnet.prod.json",
@@ -140,6 +145,7 @@ export const CONFIGS = {
     solana: {
       cluster: "mainnet",
       rpcUrl: "https://api.mainnet-beta.solana.com",
+      programFeature: "mainnet",
 
       // Keypairs
       deployerKpPath: "keypairs/deployer.mainnet.json",
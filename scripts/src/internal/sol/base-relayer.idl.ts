@@ -9,6 +9,239 @@ export const IDL = {
     "description": "Created with Anchor"
   },
   "instructions": [
+    {
+      "name": "bump_relay_fee",
+      "docs": [
+        "Raises the tip escrowed in an existing `MessageToRelay`, re-pricing a paid",
+        "message stuck behind better priced ones instead of paying for a duplicate.",
+        "Emits a `RelayFeeBumped` event for the orchestrator. Only the original",
+        "payer may call this instruction.",
+        "",
+        "# Arguments",
+        "* `ctx`                 - The context containing the `payer` signer, the",
+        "`message_to_relay` escrow and the payer's",
+        "`sponsor_policy` PDA.",
+        "* `additional_lamports` - Lamports added to the escrowed tip.",
+        "",
+        "# Errors",
+        "Returns an error if the signer is not the original payer, if",
+        "`additional_lamports` is zero, or if the bump exceeds the payer's sponsor",
+        "daily limit."
+      ],
+      "discriminator": [
+        77,
+        3,
+        53,
+        225,
+        212,
+        156,
+        205,
+        22
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "The original payer of the relay request. Funds the additional tip."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "message_to_relay",
+          "docs": [
+            "The paid relay request holding the escrowed tip."
+          ],
+          "writable": true
+        },
+        {
+          "name": "sponsor_policy",
+          "docs": [
+            "The payer's sponsor policy, whose daily limit also covers fee bumps."
+          ],
+          "writable": true
+        },
+        {
+          "name": "pending_relay_index",
+          "docs": [
+            "The guardian-created index of the pending relay requests by fee bucket."
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for transferring the additional tip."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "additional_lamports",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "check_environment",
+      "docs": [
+        "Fails with `EnvironmentMismatch` unless the program was compiled for",
+        "`expected` and is deployed at the program ID of that environment,",
+        "letting deployment scripts catch builds made for another environment.",
+        "",
+        "# Arguments",
+        "* `ctx`      - The context; no accounts are required.",
+        "* `expected` - The environment the program is expected to be built for."
+      ],
+      "discriminator": [
+        66,
+        105,
+        5,
+        163,
+        77,
+        91,
+        38,
+        191
+      ],
+      "accounts": [],
+      "args": [
+        {
+          "name": "expected",
+          "type": {
+            "defined": {
+              "name": "Environment"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "clear_nonce_anomaly",
+      "docs": [
+        "Clears the `degraded` flag set on the nonce monitor by a nonce anomaly.",
+        "Only the recorded `guardian` may call this instruction.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the `cfg` PDA, the `guardian` signer and",
+        "the `nonce_monitor` PDA."
+      ],
+      "discriminator": [
+        139,
+        58,
+        18,
+        223,
+        41,
+        46,
+        82,
+        41
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "cfg",
+          "docs": [
+            "The relayer config state account recording the guardian"
+          ]
+        },
+        {
+          "name": "nonce_monitor",
+          "docs": [
+            "The nonce monitor flagged as degraded"
+          ],
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "create_pending_relay_index",
+      "docs": [
+        "Creates the index of the pending relay requests escrowing a tip, sorted by",
+        "fee bucket, that `pay_for_relay`, `bump_relay_fee` and `settle_relay_tip`",
+        "maintain so that relayers fetch the best paid work from a single account.",
+        "Only the recorded `guardian` may call this instruction.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the `cfg` PDA, the `guardian` signer",
+        "(funding the index creation) and the `pending_relay_index` PDA."
+      ],
+      "discriminator": [
+        90,
+        128,
+        39,
+        47,
+        251,
+        55,
+        58,
+        225
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration. Funds the index account creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "cfg",
+          "docs": [
+            "The relayer config state account recording the guardian"
+          ]
+        },
+        {
+          "name": "pending_relay_index",
+          "docs": [
+            "The pending relay index.",
+            "- Uses PDA with PENDING_RELAY_INDEX_SEED for deterministic address",
+            "- Updated by `pay_for_relay`, `bump_relay_fee` and `settle_relay_tip` once created"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating new accounts.",
+            "Used internally by Anchor for account initialization."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "get_environment",
+      "docs": [
+        "Returns the environment (localnet, devnet-alpha, devnet-prod or mainnet)",
+        "the program was compiled for, letting clients detect mixed deployments.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context; no accounts are required."
+      ],
+      "discriminator": [
+        234,
+        169,
+        115,
+        3,
+        52,
+        113,
+        69,
+        198
+      ],
+      "accounts": [],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "Environment"
+        }
+      }
+    },
     {
       "name": "initialize",
       "docs": [
@@ -109,6 +342,73 @@ export const IDL = {
         }
       ]
     },
+    {
+      "name": "migrate_cfg",
+      "docs": [
+        "Migrates the `Cfg` PDA written before `FeeConfigV2` to the current layout.",
+        "Inserts the EIP-1559 base fee caps, disabled until set through",
+        "`set_fee_config`. Only the upgrade authority may call this instruction.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the `cfg` PDA, the `upgrade_authority`",
+        "signer and the `payer` funding the additional rent."
+      ],
+      "discriminator": [
+        61,
+        19,
+        130,
+        183,
+        107,
+        189,
+        39,
+        205
+      ],
+      "accounts": [
+        {
+          "name": "upgrade_authority",
+          "docs": [
+            "The upgrade authority that is authorized to migrate the config."
+          ],
+          "signer": true
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "The account that pays for the additional rent required by the new layout."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "cfg",
+          "docs": [
+            "because its data is still in the older layout. The PDA and owner are validated here and",
+            "the layout is validated by the handler."
+          ],
+          "writable": true
+        },
+        {
+          "name": "program_data",
+          "docs": [
+            "Program data account containing the upgrade authority."
+          ]
+        },
+        {
+          "name": "program",
+          "docs": [
+            "The base_relayer program itself.",
+            "Validates that program_data is the correct ProgramData account for this program."
+          ]
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for topping up the config account rent."
+          ]
+        }
+      ],
+      "args": []
+    },
     {
       "name": "pay_for_relay",
       "docs": [
@@ -127,12 +427,35 @@ export const IDL = {
         "* `mtr_salt`         - 32-byte salt used to derive the `message_to_relay`",
         "PDA address, enabling unique messages per request.",
         "* `outgoing_message` - The Base-side message identifier to be executed.",
-        "* `gas_limit`        - Maximum gas units to budget for execution on Base.",
+        "* `gas_limit`        - Maximum gas units to budget for execution on Base, or",
+        "0 to derive it from the relayed message.",
+        "* `tip_lamports`     - Optional tip escrowed in `message_to_relay` for the",
+        "relayer that executes the message (0 for none).",
+        "A tip requires the `outgoing_message_account`,",
+        "whose nonce settles it.",
+        "",
+        "Messages whose sender is on the guardian-managed fee exemption list skip",
+        "the gas fee transfer; the exemption requires the `outgoing_message_account`.",
+        "Tipped requests are indexed by fee bucket in the pending relay index, once",
+        "the guardian created it.",
+        "The bridge nonce, read from the `bridge` account, is checked against the",
+        "relayer nonce by the guardian-configured nonce monitor.",
+        "If the payer configured a `SponsorPolicy`, the gas fee and tip count",
+        "against its daily limit and, when it restricts senders, the",
+        "`outgoing_message_account` must be provided to check the message sender.",
+        "A derived gas limit requires the `outgoing_message_account` and is only",
+        "available for transfers without call and transfer batches; it is the",
+        "per-kind default of `cfg.gas_config`, at least the configured minimum,",
+        "and is recorded in `message_to_relay`.",
+        "Once the guardian configured the calldata pricing, the",
+        "`outgoing_message_account` is required and the calldata gas of its",
+        "payload is charged on top of the gas limit.",
         "",
         "# Errors",
         "Returns an error if the `gas_fee_receiver` does not match the configured",
-        "receiver or if the payer lacks sufficient lamports to cover the computed",
-        "fee."
+        "receiver, if the payer lacks sufficient lamports to cover the computed",
+        "fee, if the payment violates the payer's sponsor policy, or if the gas",
+        "limit cannot be derived from the message."
       ],
       "discriminator": [
         41,
@@ -174,6 +497,55 @@ export const IDL = {
           "name": "message_to_relay",
           "writable": true
         },
+        {
+          "name": "fee_exemptions",
+          "docs": [
+            "The guardian-managed list of outgoing message senders exempt from the gas fee transfer,",
+            "checked against the sender of `outgoing_message_account`."
+          ]
+        },
+        {
+          "name": "sponsor_policy",
+          "docs": [
+            "The payer's sponsor policy. Always required so a payer cannot bypass its own policy;",
+            "enforced only once the payer has created it via `set_sponsor_policy`."
+          ],
+          "writable": true
+        },
+        {
+          "name": "nonce_monitor",
+          "docs": [
+            "The guardian-configured nonce monitor, flagged as degraded on nonce anomalies if configured."
+          ],
+          "writable": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge state account, whose outgoing message nonce is checked by the nonce monitor."
+          ]
+        },
+        {
+          "name": "pending_relay_index",
+          "docs": [
+            "The guardian-created index of the pending relay requests by fee bucket."
+          ],
+          "writable": true
+        },
+        {
+          "name": "calldata_pricing",
+          "docs": [
+            "The guardian-configured pricing of the calldata of large payloads."
+          ]
+        },
+        {
+          "name": "outgoing_message_account",
+          "docs": [
+            "The bridge `OutgoingMessage` being relayed, required when the sponsor policy restricts",
+            "the senders it sponsors, when the gas limit is derived or when calldata is priced."
+          ],
+          "optional": true
+        },
         {
           "name": "system_program",
           "docs": [
@@ -199,47 +571,175 @@ export const IDL = {
         {
           "name": "gas_limit",
           "type": "u64"
+        },
+        {
+          "name": "tip_lamports",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "set_eip1559_config",
+      "name": "refund_relay_tip",
       "docs": [
-        "Updates the EIP1559 configuration.",
-        "Only the recorded `guardian` may call this instruction.",
+        "Refunds the tip escrowed for a paid relay request to its payer when the",
+        "bridge has not confirmed the relayed outgoing message as executed on Base",
+        "`RELAY_TIP_REFUND_DELAY_SECONDS` after it was paid for.",
+        "Anyone may call this instruction.",
         "",
         "# Arguments",
-        "* `ctx` - The context containing the `cfg` PDA and the `guardian` signer.",
-        "Authorization is enforced via an Anchor `has_one` constraint.",
-        "* `cfg` - The new EIP1559 configuration to write in full."
+        "* `ctx` - The context containing the `message_to_relay` escrow, its `payer`",
+        "receiving the refund and the bridge `relayed_nonces` PDA covering",
+        "the outgoing message nonce."
       ],
       "discriminator": [
-        197,
-        222,
-        225,
-        215,
-        15,
-        3,
-        82,
-        102
+        16,
+        104,
+        59,
+        79,
+        79,
+        95,
+        52,
+        168
       ],
       "accounts": [
         {
-          "name": "cfg",
+          "name": "message_to_relay",
           "docs": [
-            "The bridge account containing configuration"
+            "The paid relay request holding the escrowed tip."
           ],
           "writable": true
         },
         {
-          "name": "guardian",
+          "name": "payer",
           "docs": [
-            "The guardian account authorized to update configuration"
+            "The payer of the relay request, receiving the refunded tip."
           ],
-          "signer": true
+          "writable": true
+        },
+        {
+          "name": "relayed_nonces",
+          "docs": [
+            "The bridge `RelayedNonces` PDA covering the nonce of the relayed outgoing message."
+          ]
+        },
+        {
+          "name": "pending_relay_index",
+          "docs": [
+            "The guardian-created index of the pending relay requests by fee bucket."
+          ],
+          "writable": true
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "set_calldata_pricing",
+      "docs": [
+        "Configures the calldata cost charged by `pay_for_relay` on top of the gas",
+        "limit, so that large payloads pay for the calldata floor price of Base:",
+        "`gas_per_calldata_byte` gas for each payload byte beyond",
+        "`free_calldata_bytes`, at the current base fee.",
+        "Only the recorded `guardian` may call this instruction.",
+        "",
+        "# Arguments",
+        "* `ctx`                   - The context containing the `cfg` PDA, the",
+        "`guardian` signer (funding the pricing creation)",
+        "and the `calldata_pricing` PDA.",
+        "* `free_calldata_bytes`   - The payload bytes covered by the gas limit.",
+        "* `gas_per_calldata_byte` - The gas charged for each additional byte."
+      ],
+      "discriminator": [
+        76,
+        43,
+        186,
+        68,
+        19,
+        155,
+        47,
+        92
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration. Funds the pricing account",
+            "creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "cfg",
+          "docs": [
+            "The relayer config state account recording the guardian"
+          ]
+        },
+        {
+          "name": "calldata_pricing",
+          "docs": [
+            "The calldata pricing.",
+            "- Uses PDA with CALLDATA_PRICING_SEED for deterministic address",
+            "- Created on first use and updated afterwards"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating new accounts.",
+            "Used internally by Anchor for account initialization."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "free_calldata_bytes",
+          "type": "u64"
+        },
+        {
+          "name": "gas_per_calldata_byte",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_eip1559_config",
+      "docs": [
+        "Updates the EIP1559 configuration.",
+        "Only the recorded `guardian` may call this instruction.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the `cfg` PDA and the `guardian` signer.",
+        "Authorization is enforced via an Anchor `has_one` constraint.",
+        "* `cfg` - The new EIP1559 configuration to write in full."
+      ],
+      "discriminator": [
+        197,
+        222,
+        225,
+        215,
+        15,
+        3,
+        82,
+        102
+      ],
+      "accounts": [
+        {
+          "name": "cfg",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
         {
           "name": "eip1559_config",
           "type": {
@@ -250,6 +750,120 @@ export const IDL = {
         }
       ]
     },
+    {
+      "name": "set_fee_config",
+      "docs": [
+        "Updates every pricing parameter shared with the bridge program (EIP-1559",
+        "controller, gas cost scaler and gas fee receiver) at once. The",
+        "per-message gas limit bounds are left unchanged.",
+        "Only the recorded `guardian` may call this instruction.",
+        "",
+        "# Arguments",
+        "* `ctx`        - The context containing the `cfg` PDA and the `guardian` signer.",
+        "Authorization is enforced via an Anchor `has_one` constraint.",
+        "* `fee_config` - The new fee configuration."
+      ],
+      "discriminator": [
+        221,
+        222,
+        52,
+        206,
+        114,
+        198,
+        64,
+        91
+      ],
+      "accounts": [
+        {
+          "name": "cfg",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        }
+      ],
+      "args": [
+        {
+          "name": "fee_config",
+          "type": {
+            "defined": {
+              "name": "FeeConfigV2"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "set_fee_exemptions",
+      "docs": [
+        "Replaces the list of senders exempt from the gas fee transfer in",
+        "`pay_for_relay`, used by protocol-owned keepers. Their gas usage is still",
+        "recorded for the EIP-1559 controller.",
+        "Only the recorded `guardian` may call this instruction.",
+        "",
+        "# Arguments",
+        "* `ctx`     - The context containing the `cfg` PDA, the `guardian` signer",
+        "(funding the list creation) and the `fee_exemptions` PDA.",
+        "* `senders` - The full list of fee exempt senders."
+      ],
+      "discriminator": [
+        88,
+        69,
+        194,
+        44,
+        166,
+        7,
+        114,
+        202
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration. Funds the list account creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "cfg",
+          "docs": [
+            "The relayer config state account recording the guardian"
+          ]
+        },
+        {
+          "name": "fee_exemptions",
+          "docs": [
+            "The fee exemption list.",
+            "- Uses PDA with FEE_EXEMPTIONS_SEED for deterministic address",
+            "- Created on first use and overwritten afterwards"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating new accounts.",
+            "Used internally by Anchor for account initialization."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "senders",
+          "type": {
+            "vec": "pubkey"
+          }
+        }
+      ]
+    },
     {
       "name": "set_gas_config",
       "docs": [
@@ -341,265 +955,1223 @@ export const IDL = {
           "type": "pubkey"
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "Cfg",
-      "discriminator": [
-        236,
-        69,
-        240,
-        199,
-        189,
-        123,
-        35,
-        99
-      ]
     },
     {
-      "name": "MessageToRelay",
+      "name": "set_nonce_monitor",
+      "docs": [
+        "Configures the nonce monitor checking, in `pay_for_relay`, that the relayer",
+        "nonce does not diverge from the nonce of the bridge `bridge` account by",
+        "more than `max_nonce_gap`. Anomalies emit a `NonceAnomaly` event.",
+        "Only the recorded `guardian` may call this instruction.",
+        "",
+        "# Arguments",
+        "* `ctx`                - The context containing the `cfg` PDA, the `guardian`",
+        "signer (funding the monitor creation) and the",
+        "`nonce_monitor` PDA.",
+        "* `max_nonce_gap`      - The maximum tolerated nonce divergence.",
+        "* `degrade_on_anomaly` - Whether anomalies flag the monitor as degraded."
+      ],
       "discriminator": [
-        194,
-        113,
-        145,
-        222,
-        76,
-        51,
-        252,
-        102
+        180,
+        232,
+        142,
+        50,
+        241,
+        94,
+        151,
+        2
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration. Funds the monitor account creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "cfg",
+          "docs": [
+            "The relayer config state account recording the guardian"
+          ]
+        },
+        {
+          "name": "nonce_monitor",
+          "docs": [
+            "The nonce monitor.",
+            "- Uses PDA with NONCE_MONITOR_SEED for deterministic address",
+            "- Created on first use and updated afterwards"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating new accounts.",
+            "Used internally by Anchor for account initialization."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "max_nonce_gap",
+          "type": "u64"
+        },
+        {
+          "name": "degrade_on_anomaly",
+          "type": "bool"
+        }
       ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 12000,
-      "name": "UnauthorizedInitialization",
-      "msg": "Only the upgrade authority can initialize the relayer"
-    },
-    {
-      "code": 12001,
-      "name": "IncorrectRelayerProgram",
-      "msg": "Incorrect relayer program"
     },
     {
-      "code": 12100,
-      "name": "UnauthorizedConfigUpdate",
-      "msg": "Unauthorized to update configuration"
+      "name": "set_sponsor_policy",
+      "docs": [
+        "Creates or updates the payer's sponsor policy, restricting what the payer",
+        "sponsors through `pay_for_relay`. Configured by the payer itself; the",
+        "guardian is not involved.",
+        "",
+        "# Arguments",
+        "* `ctx`                  - The context containing the `payer` signer and its",
+        "`sponsor_policy` PDA (created if needed).",
+        "* `daily_limit_lamports` - Maximum lamports (gas fees and tips) the payer",
+        "spends per day (0 for no limit).",
+        "* `sponsored_senders`    - Outgoing message senders the payer sponsors",
+        "(empty to sponsor every sender)."
+      ],
+      "discriminator": [
+        232,
+        228,
+        255,
+        211,
+        254,
+        244,
+        227,
+        58
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "The payer configuring its own policy. Funds the policy account creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "sponsor_policy",
+          "docs": [
+            "The payer's sponsor policy.",
+            "- Uses PDA with SPONSOR_POLICY_SEED and the payer key for deterministic address",
+            "- Created on first use and updated in place afterwards"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating new accounts.",
+            "Used internally by Anchor for account initialization."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "daily_limit_lamports",
+          "type": "u64"
+        },
+        {
+          "name": "sponsored_senders",
+          "type": {
+            "vec": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "settle_relay_tip",
+      "docs": [
+        "Releases the tip escrowed for a paid relay request to the relayer that",
+        "executed it, once the bridge confirmed the relayed outgoing message as",
+        "executed on Base in its oracle-attested `RelayedNonces`. The relayer is the",
+        "one the oracles attested in the bridge `RelayExecution` record of the",
+        "message nonce. Tips let payers bid for relay ordering without",
+        "protocol-level scheduling.",
+        "Anyone may call this instruction.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the `message_to_relay` escrow, the",
+        "`relayer` receiving the tip, the bridge `relayed_nonces` PDA",
+        "covering the outgoing message nonce and its bridge",
+        "`relay_execution` PDA."
+      ],
+      "discriminator": [
+        50,
+        11,
+        125,
+        254,
+        214,
+        30,
+        146,
+        4
+      ],
+      "accounts": [
+        {
+          "name": "message_to_relay",
+          "docs": [
+            "The paid relay request holding the escrowed tip."
+          ],
+          "writable": true
+        },
+        {
+          "name": "relayer",
+          "docs": [
+            "The account of the relayer that executed the message on Base, receiving the tip."
+          ],
+          "writable": true
+        },
+        {
+          "name": "relayed_nonces",
+          "docs": [
+            "The bridge `RelayedNonces` PDA covering the nonce of the relayed outgoing message."
+          ]
+        },
+        {
+          "name": "relay_execution",
+          "docs": [
+            "The bridge `RelayExecution` PDA recording the relayer that executed the outgoing message."
+          ]
+        },
+        {
+          "name": "pending_relay_index",
+          "docs": [
+            "The guardian-created index of the pending relay requests by fee bucket."
+          ],
+          "writable": true
+        }
+      ],
+      "args": []
+    }
+  ],
+  "accounts": [
+    {
+      "name": "CalldataPricing",
+      "discriminator": [
+        228,
+        73,
+        73,
+        102,
+        154,
+        252,
+        252,
+        172
+      ]
+    },
+    {
+      "name": "Cfg",
+      "discriminator": [
+        236,
+        69,
+        240,
+        199,
+        189,
+        123,
+        35,
+        99
+      ]
+    },
+    {
+      "name": "FeeExemptions",
+      "discriminator": [
+        174,
+        249,
+        50,
+        53,
+        54,
+        148,
+        29,
+        243
+      ]
+    },
+    {
+      "name": "MessageToRelay",
+      "discriminator": [
+        194,
+        113,
+        145,
+        222,
+        76,
+        51,
+        252,
+        102
+      ]
+    },
+    {
+      "name": "NonceMonitor",
+      "discriminator": [
+        141,
+        227,
+        111,
+        94,
+        95,
+        124,
+        106,
+        146
+      ]
+    },
+    {
+      "name": "PendingRelayIndex",
+      "discriminator": [
+        91,
+        131,
+        88,
+        27,
+        31,
+        137,
+        94,
+        80
+      ]
+    },
+    {
+      "name": "SponsorPolicy",
+      "discriminator": [
+        243,
+        45,
+        180,
+        191,
+        4,
+        144,
+        101,
+        21
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "NonceAnomaly",
+      "discriminator": [
+        118,
+        237,
+        172,
+        111,
+        242,
+        162,
+        142,
+        78
+      ]
+    },
+    {
+      "name": "RelayFeeBumped",
+      "discriminator": [
+        131,
+        168,
+        196,
+        249,
+        212,
+        198,
+        120,
+        196
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 12000,
+      "name": "UnauthorizedInitialization",
+      "msg": "Only the upgrade authority can initialize the relayer"
+    },
+    {
+      "code": 12001,
+      "name": "IncorrectRelayerProgram",
+      "msg": "Incorrect relayer program"
+    },
+    {
+      "code": 12002,
+      "name": "UnauthorizedMigration",
+      "msg": "Only the upgrade authority can migrate the relayer config"
+    },
+    {
+      "code": 12003,
+      "name": "InvalidCfgLayout",
+      "msg": "Relayer config account has an unknown layout"
+    },
+    {
+      "code": 12004,
+      "name": "CfgAlreadyMigrated",
+      "msg": "Relayer config account is already migrated"
+    },
+    {
+      "code": 12005,
+      "name": "EnvironmentMismatch",
+      "msg": "Program was not built for the expected environment"
+    },
+    {
+      "code": 12100,
+      "name": "UnauthorizedConfigUpdate",
+      "msg": "Unauthorized to update configuration"
+    },
+    {
+      "code": 12101,
+      "name": "TooManySponsoredSenders",
+      "msg": "Too many sponsored senders"
+    },
+    {
+      "code": 12102,
+      "name": "TooManyFeeExemptSenders",
+      "msg": "Too many fee exempt senders"
+    },
+    {
+      "code": 12103,
+      "name": "InvalidFeeConfig",
+      "msg": "Invalid fee config"
+    },
+    {
+      "code": 12104,
+      "name": "InvalidGasTarget",
+      "msg": "EIP-1559 gas target must be non-zero"
+    },
+    {
+      "code": 12105,
+      "name": "InvalidDenominator",
+      "msg": "EIP-1559 adjustment denominator must be non-zero"
+    },
+    {
+      "code": 12106,
+      "name": "InvalidWindowDuration",
+      "msg": "EIP-1559 window duration must be non-zero"
+    },
+    {
+      "code": 12107,
+      "name": "InvalidMaxBaseFee",
+      "msg": "Maximum base fee must be zero or at least the minimum base fee"
+    },
+    {
+      "code": 12108,
+      "name": "InvalidGasLimitBounds",
+      "msg": "Minimum gas limit per message must not exceed the maximum"
+    },
+    {
+      "code": 12109,
+      "name": "InvalidGasCostScalerDp",
+      "msg": "Gas cost scaler decimal precision must be non-zero"
+    },
+    {
+      "code": 12200,
+      "name": "GasLimitTooLow",
+      "msg": "Gas limit too low"
+    },
+    {
+      "code": 12201,
+      "name": "GasLimitExceeded",
+      "msg": "Gas limit exceeded"
+    },
+    {
+      "code": 12202,
+      "name": "GasLimitNotDerivable",
+      "msg": "Gas limit can only be derived for transfers without call"
+    },
+    {
+      "code": 12300,
+      "name": "IncorrectGasFeeReceiver",
+      "msg": "Incorrect gas fee receiver"
+    },
+    {
+      "code": 12301,
+      "name": "NoRelayTip",
+      "msg": "No relay tip to settle"
+    },
+    {
+      "code": 12302,
+      "name": "SponsorSpendLimitExceeded",
+      "msg": "Sponsor spend limit exceeded"
+    },
+    {
+      "code": 12303,
+      "name": "SenderNotSponsored",
+      "msg": "Outgoing message sender is not sponsored"
+    },
+    {
+      "code": 12304,
+      "name": "MissingOutgoingMessage",
+      "msg": "Outgoing message account is required"
+    },
+    {
+      "code": 12305,
+      "name": "InvalidOutgoingMessage",
+      "msg": "Invalid outgoing message account"
+    },
+    {
+      "code": 12306,
+      "name": "UnauthorizedRelayFeeBump",
+      "msg": "Only the original payer can bump the relay fee"
+    },
+    {
+      "code": 12307,
+      "name": "ZeroRelayFeeBump",
+      "msg": "Relay fee bump must be greater than zero"
+    },
+    {
+      "code": 12308,
+      "name": "IncorrectRelayedNonces",
+      "msg": "Incorrect relayed nonces account"
+    },
+    {
+      "code": 12309,
+      "name": "RelayNotConfirmed",
+      "msg": "Outgoing message is not confirmed as relayed on Base"
+    },
+    {
+      "code": 12310,
+      "name": "RelayAlreadyConfirmed",
+      "msg": "Outgoing message is already confirmed as relayed on Base"
+    },
+    {
+      "code": 12311,
+      "name": "RelayTipRefundNotDue",
+      "msg": "Relay tip refund delay has not elapsed"
+    },
+    {
+      "code": 12312,
+      "name": "IncorrectRelayPayer",
+      "msg": "Incorrect relay request payer"
+    },
+    {
+      "code": 12313,
+      "name": "IncorrectRelayExecution",
+      "msg": "Incorrect relay execution account"
+    },
+    {
+      "code": 12314,
+      "name": "RelayExecutionNotRecorded",
+      "msg": "Relayer executing the outgoing message on Base is not recorded"
+    },
+    {
+      "code": 12315,
+      "name": "IncorrectRelayer",
+      "msg": "Relayer does not match the recorded relay execution"
+    }
+  ],
+  "types": [
+    {
+      "name": "CalldataPricing",
+      "docs": [
+        "Guardian-configured pricing of the calldata posted to Base when relaying a message. Since",
+        "EIP-7623, the calldata of large payloads is charged at a floor price independent of the gas",
+        "they consume, which the gas limit does not account for. Payloads are charged",
+        "`gas_per_calldata_byte` gas for each byte beyond `free_calldata_bytes`, at the current base",
+        "fee. Calldata is not priced until this account is created."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "free_calldata_bytes",
+            "docs": [
+              "Payload bytes covered by the gas limit of the message"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gas_per_calldata_byte",
+            "docs": [
+              "Gas charged for each payload byte beyond `free_calldata_bytes`"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Cfg",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nonce",
+            "docs": [
+              "Canonical nonce"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "guardian",
+            "docs": [
+              "Guardian pubkey authorized to update configuration"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "eip1559",
+            "docs": [
+              "EIP-1559 state and configuration for dynamic pricing."
+            ],
+            "type": {
+              "defined": {
+                "name": "Eip1559"
+              }
+            }
+          },
+          {
+            "name": "gas_config",
+            "docs": [
+              "Gas configuration"
+            ],
+            "type": {
+              "defined": {
+                "name": "GasConfig"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Eip1559",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "docs": [
+              "Configuration parameters for EIP-1559-inspired fee calculations"
+            ],
+            "type": {
+              "defined": {
+                "name": "Eip1559Config"
+              }
+            }
+          },
+          {
+            "name": "current_base_fee",
+            "docs": [
+              "Current base fee used in fee computation (runtime state).",
+              "Unitless value combined with the gas usage and gas cost scaler to produce lamports."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "current_window_gas_used",
+            "docs": [
+              "Gas used in the current time window (runtime state)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "window_start_time",
+            "docs": [
+              "Unix timestamp when the current window started (runtime state)"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Eip1559Config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "target",
+            "docs": [
+              "Gas target per window"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "denominator",
+            "docs": [
+              "Adjustment denominator (controls rate of change)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "window_duration_seconds",
+            "docs": [
+              "Window duration in seconds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minimum_base_fee",
+            "docs": [
+              "Minimum base fee. Used to seed `current_base_fee` at initialization",
+              "and as an underflow clamp during decreases; not enforced as a strict lower bound",
+              "on every step."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "max_base_fee",
+            "docs": [
+              "Maximum base fee. The base fee never grows above this value (0 disables the cap)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "max_window_increase_bps",
+            "docs": [
+              "Maximum base fee increase per window, in basis points of the current base fee",
+              "(0 disables the cap)."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Environment",
+      "docs": [
+        "The environment the program was compiled for."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Localnet"
+          },
+          {
+            "name": "DevnetAlpha"
+          },
+          {
+            "name": "DevnetProd"
+          },
+          {
+            "name": "Mainnet"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeConfigV2",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "target",
+            "docs": [
+              "Gas target per window"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "denominator",
+            "docs": [
+              "Adjustment denominator (controls rate of change)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "window_duration_seconds",
+            "docs": [
+              "Window duration in seconds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minimum_base_fee",
+            "docs": [
+              "Minimum base fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "max_base_fee",
+            "docs": [
+              "Maximum base fee (0 disables the cap)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "max_window_increase_bps",
+            "docs": [
+              "Maximum base fee increase per window, in basis points of the current base fee",
+              "(0 disables the cap)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gas_cost_scaler",
+            "docs": [
+              "Scaling factor applied when converting (gas * base_fee) into lamports"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gas_cost_scaler_dp",
+            "docs": [
+              "Decimal precision for the gas cost scaler (denominator)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gas_fee_receiver",
+            "docs": [
+              "Account that receives gas fees"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 12200,
-      "name": "GasLimitTooLow",
-      "msg": "Gas limit too low"
+      "name": "FeeExemptions",
+      "docs": [
+        "Guardian-managed list of protocol-owned senders (e.g. keeper program PDAs) exempt from the",
+        "gas fee transfer in `pay_for_relay`. Their gas usage is still recorded for the EIP-1559",
+        "controller."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sender_count",
+            "docs": [
+              "Number of senders in `senders` array"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "senders",
+            "docs": [
+              "Senders exempt from paying gas fees"
+            ],
+            "type": {
+              "array": [
+                "pubkey",
+                16
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12201,
-      "name": "GasLimitExceeded",
-      "msg": "Gas limit exceeded"
+      "name": "GasConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "min_gas_limit_per_message",
+            "docs": [
+              "Minimum gas limit per cross-chain message"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "max_gas_limit_per_message",
+            "docs": [
+              "Maximum gas limit per cross-chain message"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gas_cost_scaler",
+            "docs": [
+              "Scaling factor for gas cost calculations"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gas_cost_scaler_dp",
+            "docs": [
+              "Decimal precision for gas cost calculations"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gas_fee_receiver",
+            "docs": [
+              "Account that receives gas fees"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "default_transfer_gas_limit",
+            "docs": [
+              "Gas limit derived for a transfer without call: the per-message overhead of `relayMessages`",
+              "plus minting or unlocking the tokens on Base"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "default_transfer_batch_recipient_gas_limit",
+            "docs": [
+              "Additional gas limit derived for each recipient of a transfer batch beyond the first"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12300,
-      "name": "IncorrectGasFeeReceiver",
-      "msg": "Incorrect gas fee receiver"
-    }
-  ],
-  "types": [
-    {
-      "name": "Cfg",
+      "name": "MessageToRelay",
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "nonce",
+            "type": "u64"
+          },
+          {
+            "name": "outgoing_message",
+            "type": "pubkey"
+          },
+          {
+            "name": "gas_limit",
+            "type": "u64"
+          },
+          {
+            "name": "tip_lamports",
             "docs": [
-              "Canonical nonce"
+              "Lamports escrowed in this account for the relayer that executes the message on Base."
             ],
             "type": "u64"
           },
           {
-            "name": "guardian",
+            "name": "payer",
             "docs": [
-              "Guardian pubkey authorized to update configuration"
+              "The payer of the relay request, the only account allowed to bump its tip."
             ],
             "type": "pubkey"
           },
           {
-            "name": "eip1559",
+            "name": "outgoing_nonce",
             "docs": [
-              "EIP-1559 state and configuration for dynamic pricing."
+              "Bridge nonce of the relayed `OutgoingMessage`, recorded when its account was provided.",
+              "Required to escrow a tip, which is settled once the bridge confirms the nonce as relayed."
             ],
             "type": {
-              "defined": {
-                "name": "Eip1559"
-              }
+              "option": "u64"
             }
           },
           {
-            "name": "gas_config",
+            "name": "created_at",
             "docs": [
-              "Gas configuration"
+              "Unix timestamp at which the relay was paid for, starting the tip refund delay."
             ],
-            "type": {
-              "defined": {
-                "name": "GasConfig"
-              }
-            }
+            "type": "i64"
           }
         ]
       }
     },
     {
-      "name": "Eip1559",
+      "name": "NonceAnomaly",
+      "docs": [
+        "Emitted by `pay_for_relay` when the relayer nonce diverges from the bridge outgoing message",
+        "nonce by more than `NonceMonitor::max_nonce_gap`."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "config",
-            "type": {
-              "defined": {
-                "name": "Eip1559Config"
-              }
-            }
+            "name": "outgoing_message",
+            "docs": [
+              "The bridge outgoing message being paid for."
+            ],
+            "type": "pubkey"
           },
           {
-            "name": "current_base_fee",
+            "name": "relayer_nonce",
             "docs": [
-              "Current base fee in gwei (runtime state)"
+              "The relayer nonce assigned to the message."
             ],
             "type": "u64"
           },
           {
-            "name": "current_window_gas_used",
+            "name": "bridge_nonce",
             "docs": [
-              "Gas used in the current time window (runtime state)"
+              "The nonce of the next bridge outgoing message."
             ],
             "type": "u64"
           },
           {
-            "name": "window_start_time",
+            "name": "gap",
             "docs": [
-              "Unix timestamp when the current window started (runtime state)"
+              "Divergence between `relayer_nonce` and `bridge_nonce`."
             ],
-            "type": "i64"
+            "type": "u64"
+          },
+          {
+            "name": "degraded",
+            "docs": [
+              "Whether the nonce monitor is now flagged as degraded."
+            ],
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "Eip1559Config",
+      "name": "NonceMonitor",
+      "docs": [
+        "Guardian-configured monitor comparing, in `pay_for_relay`, the relayer nonce with the nonce of",
+        "the next bridge outgoing message. Monitoring is disabled until this account is created."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "target",
+            "name": "max_nonce_gap",
             "docs": [
-              "Gas target per window (configurable)"
+              "Maximum tolerated divergence between the relayer nonce and the bridge nonce"
             ],
             "type": "u64"
           },
           {
-            "name": "denominator",
+            "name": "degrade_on_anomaly",
             "docs": [
-              "Adjustment denominator (controls rate of change) (configurable)"
+              "Whether an observed anomaly sets `degraded`"
             ],
-            "type": "u64"
+            "type": "bool"
           },
           {
-            "name": "window_duration_seconds",
+            "name": "degraded",
             "docs": [
-              "Window duration in seconds (configurable)"
+              "Set when an anomaly is observed with `degrade_on_anomaly` enabled. Monitoring alarms on",
+              "it until the guardian clears it."
             ],
-            "type": "u64"
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PendingRelay",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "message_to_relay",
+            "docs": [
+              "The pending relay request"
+            ],
+            "type": "pubkey"
           },
           {
-            "name": "minimum_base_fee",
+            "name": "fee_bucket",
             "docs": [
-              "Minimum base fee floor (configurable)"
+              "Fee bucket of its escrowed tip, see `PendingRelayIndex::fee_bucket`"
             ],
-            "type": "u64"
+            "type": "u8"
           }
         ]
       }
     },
     {
-      "name": "GasConfig",
+      "name": "PendingRelayIndex",
+      "docs": [
+        "Index of the pending relay requests escrowing a tip, sorted by fee bucket from the highest, so",
+        "that third-party relayers fetch the best paid work from this single account instead of scanning",
+        "every `MessageToRelay`. Updated when a relay is paid for, its tip bumped or settled.",
+        "When the index is full, a request only displaces the last entry if it is in a higher bucket.",
+        "Nothing is indexed until the guardian creates this account."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "min_gas_limit_per_message",
+            "name": "count",
             "docs": [
-              "Minimum gas limit per cross-chain message"
+              "Number of entries in `entries` array"
             ],
-            "type": "u64"
+            "type": "u8"
           },
           {
-            "name": "max_gas_limit_per_message",
+            "name": "entries",
             "docs": [
-              "Maximum gas limit per cross-chain message"
+              "Pending relay requests, sorted by fee bucket from the highest and by age within a bucket"
             ],
-            "type": "u64"
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "PendingRelay"
+                  }
+                },
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RelayFeeBumped",
+      "docs": [
+        "Emitted by `bump_relay_fee` when the payer of a relay request raises its escrowed tip,",
+        "letting the orchestrator re-prioritize the message instead of waiting for a duplicate."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "message_to_relay",
+            "docs": [
+              "The paid relay request being re-priced."
+            ],
+            "type": "pubkey"
           },
           {
-            "name": "gas_cost_scaler",
+            "name": "outgoing_message",
             "docs": [
-              "Scaling factor for gas cost calculations"
+              "The bridge outgoing message being relayed."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "The relayer nonce assigned to the message."
             ],
             "type": "u64"
           },
           {
-            "name": "gas_cost_scaler_dp",
+            "name": "added_lamports",
             "docs": [
-              "Decimal precision for gas cost calculations"
+              "Lamports added to the escrowed tip."
             ],
             "type": "u64"
           },
           {
-            "name": "gas_fee_receiver",
+            "name": "tip_lamports",
             "docs": [
-              "Account that receives gas fees"
+              "The escrowed tip after the bump, i.e. the new effective priority."
             ],
-            "type": "pubkey"
+            "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "MessageToRelay",
+      "name": "SponsorPolicy",
+      "docs": [
+        "Guardrails configured by a payer sponsoring relays, enforced in `pay_for_relay`."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "nonce",
+            "name": "payer",
+            "docs": [
+              "The payer this policy applies to"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "daily_limit_lamports",
+            "docs": [
+              "Maximum lamports (gas fees and tips) the payer spends per day (0 for no limit)"
+            ],
             "type": "u64"
           },
           {
-            "name": "outgoing_message",
-            "type": "pubkey"
+            "name": "window_start_time",
+            "docs": [
+              "Start timestamp of the current spend window"
+            ],
+            "type": "i64"
           },
           {
-            "name": "gas_limit",
+            "name": "window_spent_lamports",
+            "docs": [
+              "Lamports spent in the current window"
+            ],
             "type": "u64"
+          },
+          {
+            "name": "sponsored_sender_count",
+            "docs": [
+              "Number of senders in `sponsored_senders` array (0 sponsors every sender)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "sponsored_senders",
+            "docs": [
+              "Outgoing message senders the payer sponsors"
+            ],
+            "type": {
+              "array": [
+                "pubkey",
+                16
+              ]
+            }
           }
         ]
       }
     }
   ],
   "constants": [
+    {
+      "name": "CALLDATA_PRICING_SEED",
+      "type": "bytes",
+      "value": "[99, 97, 108, 108, 100, 97, 116, 97, 95, 112, 114, 105, 99, 105, 110, 103]"
+    },
     {
       "name": "CFG_SEED",
       "type": "bytes",
       "value": "[99, 111, 110, 102, 105, 103]"
     },
+    {
+      "name": "FEE_EXEMPTIONS_SEED",
+      "type": "bytes",
+      "value": "[102, 101, 101, 95, 101, 120, 101, 109, 112, 116, 105, 111, 110, 115]"
+    },
+    {
+      "name": "MAX_FEE_EXEMPT_SENDERS",
+      "type": "u8",
+      "value": "16"
+    },
+    {
+      "name": "MAX_PENDING_RELAYS",
+      "docs": [
+        "Maximum number of pending relay requests tracked by the pending relay index."
+      ],
+      "type": "u8",
+      "value": "64"
+    },
+    {
+      "name": "MAX_SPONSORED_SENDERS",
+      "type": "u8",
+      "value": "16"
+    },
     {
       "name": "MTR_SEED",
       "type": "bytes",
       "value": "[109, 116, 114]"
     },
     {
-      "name": "SCALE",
-      "type": "u128",
-      "value": "1000000"
+      "name": "NONCE_MONITOR_SEED",
+      "type": "bytes",
+      "value": "[110, 111, 110, 99, 101, 95, 109, 111, 110, 105, 116, 111, 114]"
+    },
+    {
+      "name": "PENDING_RELAY_INDEX_SEED",
+      "type": "bytes",
+      "value": "[112, 101, 110, 100, 105, 110, 103, 95, 114, 101, 108, 97, 121, 95, 105, 110, 100, 101, 120]"
+    },
+    {
+      "name": "RELAY_TIP_REFUND_DELAY_SECONDS",
+      "docs": [
+        "Delay after which the tip of a relay request not confirmed as relayed by the bridge can be",
+        "refunded to its payer (one week)."
+      ],
+      "type": "i64",
+      "value": "604800"
+    },
+    {
+      "name": "SPONSOR_POLICY_SEED",
+      "type": "bytes",
+      "value": "[115, 112, 111, 110, 115, 111, 114, 95, 112, 111, 108, 105, 99, 121]"
+    },
+    {
+      "name": "SPONSOR_POLICY_WINDOW_SECONDS",
+      "docs": [
+        "Duration of the window over which a sponsor policy spend limit applies (one day)."
+      ],
+      "type": "i64",
+      "value": "86400"
     }
   ]
 } as const;
\ No newline at end of file
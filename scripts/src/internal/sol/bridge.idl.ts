@@ -9,6 +9,69 @@ export const IDL = {
     "description": "Created with Anchor"
   },
   "instructions": [
+    {
+      "name": "activate_oracle_emergency",
+      "docs": [
+        "Temporarily lower the Base oracle threshold and accept emergency signers after output",
+        "roots stalled. Expires automatically after the configured emergency duration.",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`               - The context containing the bridge account and guardian",
+        "* `threshold`         - Required unique Base oracle or emergency signer approvals",
+        "* `emergency_signers` - EVM addresses accepted in addition to the Base oracle signers"
+      ],
+      "discriminator": [
+        60,
+        35,
+        68,
+        160,
+        82,
+        210,
+        139,
+        228
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "threshold",
+          "type": "u8"
+        },
+        {
+          "name": "emergency_signers",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          }
+        }
+      ]
+    },
     {
       "name": "append_to_call_buffer",
       "docs": [
@@ -149,6 +212,96 @@ export const IDL = {
         }
       ]
     },
+    {
+      "name": "approve_outflow",
+      "docs": [
+        "Approve the outflow of an incoming message held by the outflow circuit breaker, so that it",
+        "is relayed regardless of the outflow threshold of its token. Emits `OutflowApproved`.",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`          - The context containing the message, bridge account and guardian",
+        "* `message_hash` - The hash of the approved message"
+      ],
+      "discriminator": [
+        156,
+        58,
+        247,
+        91,
+        95,
+        53,
+        102,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to approve outflows"
+          ],
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the guardian"
+          ]
+        },
+        {
+          "name": "message",
+          "docs": [
+            "The incoming message whose outflow is approved."
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "message_hash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "audit_vault",
+      "docs": [
+        "Permissionlessly audits a bridge vault by comparing its actual balance with the amount",
+        "recorded in its `TokenDeposits` accounting, emitting an `AuditReport` event.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the vault (SOL vault or SPL token vault) and its accounting"
+      ],
+      "discriminator": [
+        130,
+        167,
+        148,
+        121,
+        116,
+        63,
+        191,
+        131
+      ],
+      "accounts": [
+        {
+          "name": "vault",
+          "docs": [
+            "The audited vault: either the SOL vault or an SPL token vault."
+          ]
+        },
+        {
+          "name": "token_deposits",
+          "docs": [
+            "The accounting account of the audited vault."
+          ]
+        }
+      ],
+      "args": []
+    },
     {
       "name": "bridge_call",
       "docs": [
@@ -159,7 +312,11 @@ export const IDL = {
         "# Arguments",
         "* `ctx`                   - The context containing accounts for the bridge operation",
         "* `outgoing_message_salt` - The salt for the outgoing message account",
-        "* `call`                  - The contract call details including call type, target address, value, and calldata"
+        "* `call`                  - The contract call details including call type, target address, value, and calldata",
+        "* `spl_memo`              - Whether to log an SPL memo summarizing the operation, requires the SPL Memo program account",
+        "",
+        "# Returns",
+        "* The `OutgoingMessageReceipt` with the assigned nonce and the fees charged"
       ],
       "discriminator": [
         90,
@@ -196,6 +353,35 @@ export const IDL = {
           ],
           "writable": true
         },
+        {
+          "name": "gas_fee_escrow",
+          "docs": [
+            "Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited."
+          ],
+          "writable": true
+        },
+        {
+          "name": "insurance_fund",
+          "docs": [
+            "Insurance fund credited with its share of the gas fee. No share is taken while it is",
+            "uninitialized."
+          ],
+          "writable": true
+        },
+        {
+          "name": "payer_rate_limit_config",
+          "docs": [
+            "Payer rate limiting configuration. Payers are not rate limited while it is uninitialized."
+          ]
+        },
+        {
+          "name": "payer_rate_limit",
+          "docs": [
+            "Outgoing messages paid for by the payer in the current rate limiting window, created on its",
+            "first message while rate limiting is enabled."
+          ],
+          "writable": true
+        },
         {
           "name": "bridge",
           "docs": [
@@ -212,8 +398,8 @@ export const IDL = {
             "The outgoing message account that stores the cross-chain call data.",
             "- Created fresh for each bridge call seeded by a client-provided salt",
             "- Payer funds the account creation",
-            "- Space is DISCRIMINATOR_LEN + OutgoingMessage::space(...)` and is sized using",
-            "the worst-case message variant to ensure sufficient capacity even for large payloads",
+            "- Space is `DISCRIMINATOR_LEN + OutgoingMessage::space(...)`, sized exactly for the call",
+            "(an encrypted call serializes to the same size)",
             "- Contains all information needed for execution on Base"
           ],
           "writable": true
@@ -224,6 +410,13 @@ export const IDL = {
             "System program required for creating the outgoing message account.",
             "Used internally by Anchor for account initialization."
           ]
+        },
+        {
+          "name": "memo_program",
+          "docs": [
+            "SPL Memo program, required when `spl_memo` is set to log a summary of the operation."
+          ],
+          "optional": true
         }
       ],
       "args": [
@@ -243,19 +436,32 @@ export const IDL = {
               "name": "Call"
             }
           }
+        },
+        {
+          "name": "spl_memo",
+          "type": "bool"
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "OutgoingMessageReceipt"
+        }
+      }
     },
     {
       "name": "bridge_call_buffered",
       "docs": [
         "Bridges a call using data from a call buffer account.",
-        "This instruction consumes the call buffer and creates an outgoing message",
-        "for execution on Base.",
+        "This instruction consumes the call buffer, unless it is reusable, and creates an outgoing",
+        "message for execution on Base.",
         "",
         "# Arguments",
         "* `ctx`                   - The context containing accounts for the bridge operation",
-        "* `outgoing_message_salt` - The salt for the outgoing message account"
+        "* `outgoing_message_salt` - The salt for the outgoing message account",
+        "* `spl_memo`              - Whether to log an SPL memo summarizing the operation, requires the SPL Memo program account",
+        "",
+        "# Returns",
+        "* The `OutgoingMessageReceipt` with the assigned nonce and the fees charged"
       ],
       "discriminator": [
         138,
@@ -292,6 +498,35 @@ export const IDL = {
           ],
           "writable": true
         },
+        {
+          "name": "gas_fee_escrow",
+          "docs": [
+            "Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited."
+          ],
+          "writable": true
+        },
+        {
+          "name": "insurance_fund",
+          "docs": [
+            "Insurance fund credited with its share of the gas fee. No share is taken while it is",
+            "uninitialized."
+          ],
+          "writable": true
+        },
+        {
+          "name": "payer_rate_limit_config",
+          "docs": [
+            "Payer rate limiting configuration. Payers are not rate limited while it is uninitialized."
+          ]
+        },
+        {
+          "name": "payer_rate_limit",
+          "docs": [
+            "Outgoing messages paid for by the payer in the current rate limiting window, created on its",
+            "first message while rate limiting is enabled."
+          ],
+          "writable": true
+        },
         {
           "name": "bridge",
           "docs": [
@@ -315,7 +550,7 @@ export const IDL = {
           "docs": [
             "The call buffer account that stores the call parameters and data.",
             "Its contents are copied into the outgoing message. The account is then",
-            "closed by Anchor (via `close = owner`), refunding its rent to `owner`."
+            "closed, refunding its rent to `owner`, unless it is reusable."
           ],
           "writable": true
         },
@@ -326,9 +561,7 @@ export const IDL = {
             "- Created fresh for each call; the provided keypair determines its address",
             "- Funded by `payer`",
             "- Space: DISCRIMINATOR_LEN + serialized `OutgoingMessage`",
-            "Sizing uses `OutgoingMessage::space(Some(call_buffer.data.len()))`, which",
-            "intentionally allocates for the Transfer variant (worst case) to safely",
-            "cover the Call variant",
+            "Sizing uses `OutgoingMessage::space` for a call of `call_buffer.data.len()` bytes",
             "- Includes `nonce` and `sender` metadata used on Base"
           ],
           "writable": true
@@ -339,6 +572,13 @@ export const IDL = {
             "System program required for creating the outgoing message account.",
             "Used internally by Anchor for account initialization."
           ]
+        },
+        {
+          "name": "memo_program",
+          "docs": [
+            "SPL Memo program, required when `spl_memo` is set to log a summary of the operation."
+          ],
+          "optional": true
         }
       ],
       "args": [
@@ -350,38 +590,49 @@ export const IDL = {
               32
             ]
           }
+        },
+        {
+          "name": "spl_memo",
+          "type": "bool"
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "OutgoingMessageReceipt"
+        }
+      }
     },
     {
-      "name": "bridge_sol",
+      "name": "bridge_encrypted_call",
       "docs": [
-        "Bridges native SOL tokens from Solana to Base.",
-        "This function locks SOL on Solana and initiates a message to mint equivalent",
-        "tokens on Base for the specified recipient.",
+        "Initiates a cross-chain function call from Solana to Base with encrypted calldata.",
+        "The ciphertext is stored as-is and decrypted off-chain by the Base-side executor holding",
+        "the key; it is charged ENCRYPTED_CALL_GAS_PER_BYTE gas per byte on top of the call gas.",
         "",
         "# Arguments",
-        "* `ctx`                   - The context containing accounts for the SOL bridge operation",
+        "* `ctx`                   - The context containing accounts for the bridge operation",
         "* `outgoing_message_salt` - The salt for the outgoing message account",
-        "* `to`                    - The 20-byte Ethereum address that will receive tokens on Base",
-        "* `amount`                - Amount of SOL to bridge (in lamports)",
-        "* `call`                  - Optional additional contract call to execute with the token transfer"
+        "* `call`                  - The contract call details, whose `data` is the ciphertext of the calldata",
+        "* `spl_memo`              - Whether to log an SPL memo summarizing the operation, requires the SPL Memo program account",
+        "",
+        "# Returns",
+        "* The `OutgoingMessageReceipt` with the assigned nonce and the fees charged"
       ],
       "discriminator": [
-        190,
-        190,
-        32,
+        238,
+        83,
+        137,
+        129,
+        168,
         158,
-        75,
-        153,
-        32,
-        86
+        120,
+        182
       ],
       "accounts": [
         {
           "name": "payer",
           "docs": [
-            "The account that pays for transaction fees and account creation.",
+            "The account that pays for the transaction fees and outgoing message account creation.",
             "Must be mutable to deduct lamports for account rent and gas fees."
           ],
           "writable": true,
@@ -390,55 +641,82 @@ export const IDL = {
         {
           "name": "from",
           "docs": [
-            "The account that owns the SOL tokens being bridged.",
-            "Must sign the transaction to authorize the transfer of their SOL."
+            "The account initiating the bridge call on Solana.",
+            "This account's public key will be used as the sender in the cross-chain message."
           ],
-          "writable": true,
           "signer": true
         },
         {
           "name": "gas_fee_receiver",
           "docs": [
-            "The account that receives payment for the gas costs of bridging SOL to Base."
+            "The account that receives payment for the gas costs of bridging the call to Base."
           ],
           "writable": true
         },
         {
-          "name": "sol_vault",
+          "name": "gas_fee_escrow",
           "docs": [
-            "The SOL vault account that holds locked tokens for the specific remote token.",
-            "- Uses PDA with SOL_VAULT_SEED for deterministic address",
-            "- Mutable to receive the locked SOL tokens",
-            "- Each remote token has its own dedicated vault",
-            ""
+            "Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited."
+          ],
+          "writable": true
+        },
+        {
+          "name": "insurance_fund",
+          "docs": [
+            "Insurance fund credited with its share of the gas fee. No share is taken while it is",
+            "uninitialized."
+          ],
+          "writable": true
+        },
+        {
+          "name": "payer_rate_limit_config",
+          "docs": [
+            "Payer rate limiting configuration. Payers are not rate limited while it is uninitialized."
+          ]
+        },
+        {
+          "name": "payer_rate_limit",
+          "docs": [
+            "Outgoing messages paid for by the payer in the current rate limiting window, created on its",
+            "first message while rate limiting is enabled."
           ],
           "writable": true
         },
         {
           "name": "bridge",
           "docs": [
-            "The main bridge state account that tracks nonces and fee parameters.",
+            "The main bridge state account containing global bridge configuration.",
             "- Uses PDA with BRIDGE_SEED for deterministic address",
-            "- Mutable to increment nonce and update EIP1559 fee data"
+            "- Mutable to increment the nonce and update EIP-1559 gas pricing",
+            "- Provides the current nonce for message ordering"
           ],
           "writable": true
         },
         {
           "name": "outgoing_message",
           "docs": [
-            "The outgoing message account that stores cross-chain transfer details.",
-            "- Created fresh for each bridge operation",
+            "The outgoing message account that stores the cross-chain call data.",
+            "- Created fresh for each bridge call seeded by a client-provided salt",
             "- Payer funds the account creation",
-            "- Space allocated dynamically based on optional call data size"
+            "- Space is `DISCRIMINATOR_LEN + OutgoingMessage::space(...)`, sized exactly for the call",
+            "(an encrypted call serializes to the same size)",
+            "- Contains all information needed for execution on Base"
           ],
           "writable": true
         },
         {
           "name": "system_program",
           "docs": [
-            "System program required for SOL transfers and account creation.",
-            "Used for transferring SOL from user to vault and creating outgoing message accounts."
+            "System program required for creating the outgoing message account.",
+            "Used internally by Anchor for account initialization."
           ]
+        },
+        {
+          "name": "memo_program",
+          "docs": [
+            "SPL Memo program, required when `spl_memo` is set to log a summary of the operation."
+          ],
+          "optional": true
         }
       ],
       "args": [
@@ -452,59 +730,61 @@ export const IDL = {
           }
         },
         {
-          "name": "to",
+          "name": "call",
           "type": {
-            "array": [
-              "u8",
-              20
-            ]
+            "defined": {
+              "name": "Call"
+            }
           }
         },
         {
-          "name": "amount",
-          "type": "u64"
-        },
-        {
-          "name": "call",
-          "type": {
-            "option": {
-              "defined": {
-                "name": "Call"
-              }
-            }
-          }
+          "name": "spl_memo",
+          "type": "bool"
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "OutgoingMessageReceipt"
+        }
+      }
     },
     {
-      "name": "bridge_sol_with_buffered_call",
+      "name": "bridge_sol",
       "docs": [
-        "Bridges native SOL tokens from Solana to Base with a call using buffered data.",
+        "Bridges native SOL tokens from Solana to Base.",
         "This function locks SOL on Solana and initiates a message to mint equivalent",
-        "tokens on Base, then executes a call using data from a call buffer.",
+        "tokens on Base for the specified recipient.",
         "",
         "# Arguments",
         "* `ctx`                   - The context containing accounts for the SOL bridge operation",
         "* `outgoing_message_salt` - The salt for the outgoing message account",
         "* `to`                    - The 20-byte Ethereum address that will receive tokens on Base",
-        "* `amount`                - Amount of SOL to bridge (in lamports)"
+        "* `amount`                - Amount of SOL to bridge (in lamports), including the protocol fee",
+        "* `call`                  - Optional additional contract call to execute with the token transfer",
+        "* `memo`                  - Optional payment memo of at most MAX_TRANSFER_MEMO_LEN bytes, relayed to Base and emitted in `TransferMemo`",
+        "* `swap_guard`            - Optional minimum output and deadline enforced on Base around `call`, required when it targets a",
+        "router of the DEX router list",
+        "* `spl_memo`              - Whether to log an SPL memo summarizing the operation, requires the SPL Memo program account",
+        "",
+        "# Returns",
+        "* The `OutgoingMessageReceipt` with the assigned nonce and the fees charged"
       ],
       "discriminator": [
-        52,
-        106,
-        74,
         190,
-        246,
-        31,
-        157,
-        12
+        190,
+        32,
+        158,
+        75,
+        153,
+        32,
+        86
       ],
       "accounts": [
         {
           "name": "payer",
           "docs": [
-            "The account that pays for account creation and the gas fee (EIP-1559 based) on Solana.",
-            "Must be mutable to deduct lamports for rent and to transfer the gas fee to `gas_fee_receiver`."
+            "The account that pays for transaction fees and account creation.",
+            "Must be mutable to deduct lamports for account rent and gas fees."
           ],
           "writable": true,
           "signer": true
@@ -521,203 +801,117 @@ export const IDL = {
         {
           "name": "gas_fee_receiver",
           "docs": [
-            "The account that receives payment for the gas costs of bridging the SOL to Base."
+            "The account that receives payment for the gas costs of bridging SOL to Base."
           ],
           "writable": true
         },
         {
-          "name": "sol_vault",
+          "name": "gas_fee_escrow",
           "docs": [
-            "The SOL vault account that holds locked tokens for the specific remote token.",
-            "- PDA of this program using `[SOL_VAULT_SEED]`",
-            "- Mutable to receive the locked SOL",
-            "- Each remote token has its own dedicated vault",
-            ""
+            "Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited."
           ],
           "writable": true
         },
         {
-          "name": "bridge",
+          "name": "insurance_fund",
           "docs": [
-            "The main bridge state account that tracks nonces and fee parameters.",
-            "- PDA with `BRIDGE_SEED`",
-            "- Mutable to charge gas (EIP-1559 accounting) and increment the message nonce"
+            "Insurance fund credited with its share of the gas fee. No share is taken while it is",
+            "uninitialized."
           ],
           "writable": true
         },
         {
-          "name": "owner",
-          "docs": [
-            "The owner of the call buffer who will receive the rent refund."
-          ],
-          "writable": true,
-          "signer": true
-        },
-        {
-          "name": "call_buffer",
+          "name": "payer_rate_limit_config",
           "docs": [
-            "The call buffer account that stores the call parameters and data.",
-            "Its contents are copied into the outgoing message, then the account is closed",
-            "(rent refunded to `owner`)."
-          ],
-          "writable": true
+            "Payer rate limiting configuration. Payers are not rate limited while it is uninitialized."
+          ]
         },
         {
-          "name": "outgoing_message",
+          "name": "payer_rate_limit",
           "docs": [
-            "The outgoing message account that stores the cross-chain transfer details.",
-            "- Created fresh for each bridge; address determined by the provided keypair",
-            "- Funded by `payer`",
-            "- Space: DISCRIMINATOR_LEN + serialized `OutgoingMessage`"
+            "Outgoing messages paid for by the payer in the current rate limiting window, created on its",
+            "first message while rate limiting is enabled."
           ],
           "writable": true
         },
         {
-          "name": "system_program",
+          "name": "protocol_fee_config",
           "docs": [
-            "System program required for account creation and the SOL transfer CPI."
+            "Protocol fee configuration. No protocol fee is charged while it is uninitialized."
           ]
-        }
-      ],
-      "args": [
-        {
-          "name": "outgoing_message_salt",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          }
-        },
-        {
-          "name": "to",
-          "type": {
-            "array": [
-              "u8",
-              20
-            ]
-          }
         },
         {
-          "name": "amount",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "bridge_spl",
-      "docs": [
-        "Bridges SPL tokens from Solana to Base.",
-        "This function burns or locks SPL tokens on Solana and initiates a message to mint",
-        "equivalent ERC20 tokens on Base for the specified recipient.",
-        "",
-        "# Arguments",
-        "* `ctx`                   - The context containing accounts for the SPL token bridge operation",
-        "* `outgoing_message_salt` - The salt for the outgoing message account",
-        "* `to`                    - The 20-byte Ethereum address that will receive tokens on Base",
-        "* `remote_token`          - The 20-byte address of the ERC20 token contract on Base",
-        "* `amount`                - Amount of SPL tokens to bridge (in the token's smallest units)",
-        "* `call`                  - Optional additional contract call to execute with the token transfer"
-      ],
-      "discriminator": [
-        87,
-        109,
-        172,
-        103,
-        8,
-        187,
-        223,
-        126
-      ],
-      "accounts": [
-        {
-          "name": "payer",
+          "name": "protocol_fee_treasury",
           "docs": [
-            "The account that pays for transaction fees and account creation.",
-            "Must be mutable to deduct lamports for gas fees and new account rent."
+            "The treasury credited with the protocol fee. Ignored when no protocol fee is charged."
           ],
-          "writable": true,
-          "signer": true
+          "writable": true
         },
         {
-          "name": "from",
+          "name": "dex_router_list",
           "docs": [
-            "The token authority authorizing the transfer of SPL tokens.",
-            "This signer must be the owner or an approved delegate for the source token account."
-          ],
-          "writable": true,
-          "signer": true
+            "DEX router list whose routers may only be called with a swap guard. Calls are not checked",
+            "while it is uninitialized."
+          ]
         },
         {
-          "name": "gas_fee_receiver",
+          "name": "sol_vault_state",
           "docs": [
-            "The account that receives payment for the gas costs of bridging the SPL token to Base."
-          ],
-          "writable": true
+            "The SOL vault state recording the vault bump.",
+            "Bridging SOL requires the vault to have been initialized with `init_sol_vault`."
+          ]
         },
         {
-          "name": "mint",
+          "name": "sol_vault",
           "docs": [
-            "The SPL token mint account for the token being bridged.",
-            "- Must not be a wrapped token (wrapped tokens use bridge_wrapped_token)",
-            "- Used to read token decimals and validate it is not a wrapped token"
+            "The SOL vault account that holds the locked SOL.",
+            "- Uses PDA with SOL_VAULT_SEED and the bump recorded in `sol_vault_state`",
+            "- Mutable to receive the locked SOL",
+            ""
           ],
           "writable": true
         },
         {
-          "name": "from_token_account",
+          "name": "token_deposits",
           "docs": [
-            "The user's token account containing the SPL tokens to be bridged.",
-            "- Must be owned by, or delegated to, the `from` signer (transfer authority)",
-            "- Tokens will be transferred from this account to the token vault"
+            "Accounting for the amount locked in the SOL vault.",
+            "- PDA of this program using `[TOKEN_DEPOSITS_SEED, sol_vault]`",
+            "- Created on the first deposit; `locked_amount` is incremented by the bridged amount"
           ],
           "writable": true
         },
         {
           "name": "bridge",
           "docs": [
-            "The main bridge state account containing global bridge configuration.",
-            "- PDA with BRIDGE_SEED for deterministic address",
-            "- Tracks nonce for message ordering and EIP-1559 gas pricing",
-            "- Nonce is incremented after successful bridge operations"
-          ],
-          "writable": true
-        },
-        {
-          "name": "token_vault",
-          "docs": [
-            "The token vault account that holds locked SPL tokens during the bridge process.",
-            "- PDA derived from TOKEN_VAULT_SEED, mint pubkey, and remote_token address",
-            "- Created if it doesn't exist for this mint/remote_token pair",
-            "- Token account authority is set to this vault PDA; the program signs using the PDA seeds",
-            "- Acts as the custody account for tokens being bridged to Base"
+            "The main bridge state account that tracks nonces and fee parameters.",
+            "- Uses PDA with BRIDGE_SEED for deterministic address",
+            "- Mutable to increment nonce and update EIP1559 fee data"
           ],
           "writable": true
         },
         {
           "name": "outgoing_message",
           "docs": [
-            "The outgoing message account that represents this bridge operation.",
-            "- Contains transfer details and optional call data for the destination chain",
-            "- Space is calculated based on the size of optional call data",
-            "- Used by relayers to execute the bridge operation on Base",
-            "- The recorded transfer amount equals the net increase in `token_vault` balance"
+            "The outgoing message account that stores cross-chain transfer details.",
+            "- Created fresh for each bridge operation",
+            "- Payer funds the account creation",
+            "- Space allocated dynamically based on optional call data and memo sizes"
           ],
           "writable": true
         },
         {
-          "name": "token_program",
+          "name": "system_program",
           "docs": [
-            "The SPL Token program interface for executing token transfers.",
-            "Used for the transfer_checked operation to move tokens to the vault."
+            "System program required for SOL transfers and account creation.",
+            "Used for transferring SOL from user to vault and creating outgoing message accounts."
           ]
         },
         {
-          "name": "system_program",
+          "name": "memo_program",
           "docs": [
-            "System program required for creating the outgoing message account and",
-            "initializing the token vault when needed."
-          ]
+            "SPL Memo program, required when `spl_memo` is set to log a summary of the operation."
+          ],
+          "optional": true
         }
       ],
       "args": [
@@ -739,15 +933,6 @@ export const IDL = {
             ]
           }
         },
-        {
-          "name": "remote_token",
-          "type": {
-            "array": [
-              "u8",
-              20
-            ]
-          }
-        },
         {
           "name": "amount",
           "type": "u64"
@@ -761,39 +946,66 @@ export const IDL = {
               }
             }
           }
+        },
+        {
+          "name": "memo",
+          "type": {
+            "option": "bytes"
+          }
+        },
+        {
+          "name": "swap_guard",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "SwapGuard"
+              }
+            }
+          }
+        },
+        {
+          "name": "spl_memo",
+          "type": "bool"
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "OutgoingMessageReceipt"
+        }
+      }
     },
     {
-      "name": "bridge_spl_with_buffered_call",
+      "name": "bridge_sol_batch",
       "docs": [
-        "Bridges SPL tokens from Solana to Base with a call using buffered data.",
-        "This function locks SPL tokens on Solana and initiates a message to mint equivalent",
-        "tokens on Base, then executes a call using data from a call buffer.",
+        "Bridges native SOL tokens from Solana to several recipients on Base at once.",
+        "This function locks the SOL of every recipient on Solana in a single transfer and initiates",
+        "a single message to mint the equivalent tokens to each recipient on Base.",
         "",
         "# Arguments",
-        "* `ctx`                   - The context containing accounts for the SPL token bridge operation",
+        "* `ctx`                   - The context containing accounts for the SOL batch bridge operation",
         "* `outgoing_message_salt` - The salt for the outgoing message account",
-        "* `to`                    - The 20-byte Ethereum address that will receive tokens on Base",
-        "* `remote_token`          - The 20-byte address of the ERC20 token contract on Base",
-        "* `amount`                - Amount of SPL tokens to bridge (in the token's smallest units)"
+        "* `recipients`            - The Base recipients and the amount of SOL (in lamports) each of them receives,",
+        "including the protocol fee; at most MAX_TRANSFER_BATCH_RECIPIENTS",
+        "",
+        "# Returns",
+        "* The `OutgoingMessageReceipt` with the assigned nonce and the fees charged"
       ],
       "discriminator": [
-        86,
-        187,
-        229,
-        4,
-        110,
-        8,
-        116,
-        153
+        21,
+        165,
+        169,
+        61,
+        148,
+        144,
+        208,
+        160
       ],
       "accounts": [
         {
           "name": "payer",
           "docs": [
             "The account that pays for transaction fees and account creation.",
-            "Must be mutable to deduct lamports for gas fees and new account rent."
+            "Must be mutable to deduct lamports for account rent and gas fees."
           ],
           "writable": true,
           "signer": true
@@ -801,8 +1013,8 @@ export const IDL = {
         {
           "name": "from",
           "docs": [
-            "The token authority authorizing the transfer of SPL tokens.",
-            "This signer must be the owner or an approved delegate for the source token account."
+            "The account that owns the SOL tokens being bridged.",
+            "Must sign the transaction to authorize the transfer of their SOL."
           ],
           "writable": true,
           "signer": true
@@ -810,84 +1022,101 @@ export const IDL = {
         {
           "name": "gas_fee_receiver",
           "docs": [
-            "The account that receives payment for the gas costs of bridging the SPL token to Base."
+            "The account that receives payment for the gas costs of bridging SOL to Base."
           ],
           "writable": true
         },
         {
-          "name": "mint",
+          "name": "gas_fee_escrow",
           "docs": [
-            "The SPL token mint account for the token being bridged.",
-            "- Must not be a wrapped token (wrapped tokens use bridge_wrapped_token)",
-            "- Used to validate transfer amounts and get token metadata"
+            "Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited."
           ],
           "writable": true
         },
         {
-          "name": "from_token_account",
+          "name": "insurance_fund",
           "docs": [
-            "The user's token account containing the SPL tokens to be bridged.",
-            "- Must be owned by, or delegated to, the `from` signer (transfer authority)",
-            "- Tokens will be transferred from this account to the token vault"
+            "Insurance fund credited with its share of the gas fee. No share is taken while it is",
+            "uninitialized."
           ],
           "writable": true
         },
         {
-          "name": "bridge",
+          "name": "payer_rate_limit_config",
           "docs": [
-            "The main bridge state account containing global bridge configuration.",
-            "- PDA with BRIDGE_SEED for deterministic address",
-            "- Tracks nonce for message ordering and EIP-1559 gas pricing",
-            "- Nonce is incremented after successful bridge operations"
-          ],
-          "writable": true
+            "Payer rate limiting configuration. Payers are not rate limited while it is uninitialized."
+          ]
         },
         {
-          "name": "token_vault",
+          "name": "payer_rate_limit",
           "docs": [
-            "The token vault account that holds locked SPL tokens during the bridge process.",
-            "- PDA derived from TOKEN_VAULT_SEED, mint pubkey, and remote_token address",
-            "- Created if it doesn't exist for this mint/remote_token pair",
-            "- Token account authority is set to this vault PDA; the program signs using the PDA seeds",
-            "- Acts as the custody account for tokens being bridged to Base"
+            "Outgoing messages paid for by the payer in the current rate limiting window, created on its",
+            "first message while rate limiting is enabled."
           ],
           "writable": true
         },
         {
-          "name": "owner",
+          "name": "protocol_fee_config",
           "docs": [
-            "The owner of the call buffer who will receive the rent refund."
+            "Protocol fee configuration. No protocol fee is charged while it is uninitialized."
+          ]
+        },
+        {
+          "name": "protocol_fee_treasury",
+          "docs": [
+            "The treasury credited with the protocol fee. Ignored when no protocol fee is charged."
           ],
-          "writable": true,
-          "signer": true
+          "writable": true
         },
         {
-          "name": "call_buffer",
+          "name": "sol_vault_state",
           "docs": [
-            "The call buffer account that stores the call data.",
-            "This account will be closed and rent returned to the owner."
+            "The SOL vault state recording the vault bump.",
+            "Bridging SOL requires the vault to have been initialized with `init_sol_vault`."
+          ]
+        },
+        {
+          "name": "sol_vault",
+          "docs": [
+            "The SOL vault account that holds the locked SOL.",
+            "- Uses PDA with SOL_VAULT_SEED and the bump recorded in `sol_vault_state`",
+            "- Mutable to receive the locked SOL",
+            ""
           ],
           "writable": true
         },
         {
-          "name": "outgoing_message",
+          "name": "token_deposits",
           "docs": [
-            "The outgoing message account that stores the cross-chain transfer details."
+            "Accounting for the amount locked in the SOL vault.",
+            "- PDA of this program using `[TOKEN_DEPOSITS_SEED, sol_vault]`",
+            "- Created on the first deposit; `locked_amount` is incremented by the bridged amount"
           ],
           "writable": true
         },
         {
-          "name": "token_program",
+          "name": "bridge",
           "docs": [
-            "The SPL Token program interface for executing token transfers.",
-            "Used for the transfer_checked operation to move tokens to the vault."
-          ]
+            "The main bridge state account that tracks nonces and fee parameters.",
+            "- Uses PDA with BRIDGE_SEED for deterministic address",
+            "- Mutable to increment nonce and update EIP1559 fee data"
+          ],
+          "writable": true
+        },
+        {
+          "name": "outgoing_message",
+          "docs": [
+            "The outgoing message account that stores the transfer batch.",
+            "- Created fresh for each bridge operation",
+            "- Payer funds the account creation",
+            "- Space allocated dynamically based on the number of recipients"
+          ],
+          "writable": true
         },
         {
           "name": "system_program",
           "docs": [
-            "System program required for creating the outgoing message account and",
-            "initializing the token vault when needed."
+            "System program required for SOL transfers and account creation."
           ]
         }
       ],
@@ -902,59 +1131,55 @@ export const IDL = {
           }
         },
         {
-          "name": "to",
-          "type": {
-            "array": [
-              "u8",
-              20
-            ]
-          }
-        },
-        {
-          "name": "remote_token",
+          "name": "recipients",
           "type": {
-            "array": [
-              "u8",
-              20
-            ]
+            "vec": {
+              "defined": {
+                "name": "BatchRecipient"
+              }
+            }
           }
-        },
-        {
-          "name": "amount",
-          "type": "u64"
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "OutgoingMessageReceipt"
+        }
+      }
     },
     {
-      "name": "bridge_wrapped_token",
+      "name": "bridge_sol_with_buffered_call",
       "docs": [
-        "Bridges wrapped tokens from Solana back to their native form on Base.",
-        "This function burns wrapped tokens on Solana and initiates a message to release",
-        "or mint the original tokens on Base for the specified recipient.",
+        "Bridges native SOL tokens from Solana to Base with a call using buffered data.",
+        "This function locks SOL on Solana and initiates a message to mint equivalent",
+        "tokens on Base, then executes a call using data from a call buffer.",
         "",
         "# Arguments",
-        "* `ctx`                   - The context containing accounts for the wrapped token bridge operation",
+        "* `ctx`                   - The context containing accounts for the SOL bridge operation",
         "* `outgoing_message_salt` - The salt for the outgoing message account",
-        "* `to`                    - The 20-byte Ethereum address that will receive the original tokens on Base",
-        "* `amount`                - Amount of wrapped tokens to bridge back (in the token's smallest units)",
-        "* `call`                  - Optional additional contract call to execute with the token transfer"
+        "* `to`                    - The 20-byte Ethereum address that will receive tokens on Base",
+        "* `amount`                - Amount of SOL to bridge (in lamports), including the protocol fee",
+        "* `spl_memo`              - Whether to log an SPL memo summarizing the operation, requires the SPL Memo program account",
+        "",
+        "# Returns",
+        "* The `OutgoingMessageReceipt` with the assigned nonce and the fees charged"
       ],
       "discriminator": [
-        55,
-        201,
-        139,
-        188,
-        226,
-        16,
-        136,
-        143
+        52,
+        106,
+        74,
+        190,
+        246,
+        31,
+        157,
+        12
       ],
       "accounts": [
         {
           "name": "payer",
           "docs": [
-            "The account that pays for transaction fees and outgoing message account creation.",
-            "Must be mutable to deduct lamports for account rent and gas fees."
+            "The account that pays for account creation and the gas fee (EIP-1559 based) on Solana.",
+            "Must be mutable to deduct lamports for rent and to transfer the gas fee to `gas_fee_receiver`."
           ],
           "writable": true,
           "signer": true
@@ -962,179 +1187,100 @@ export const IDL = {
         {
           "name": "from",
           "docs": [
-            "The token owner who is bridging their wrapped tokens back to Base.",
-            "Must sign the transaction to authorize burning their tokens."
+            "The account that owns the SOL tokens being bridged.",
+            "Must sign the transaction to authorize the transfer of their SOL."
           ],
+          "writable": true,
           "signer": true
         },
         {
           "name": "gas_fee_receiver",
           "docs": [
-            "The account that receives payment for the gas costs of bridging the token on Base."
+            "The account that receives payment for the gas costs of bridging the SOL to Base."
           ],
           "writable": true
         },
         {
-          "name": "mint",
+          "name": "gas_fee_escrow",
           "docs": [
-            "The wrapped token mint account representing the original Base token.",
-            "- Contains metadata linking to the original token on Base",
-            "- Tokens will be burned from this mint"
+            "Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited."
           ],
           "writable": true
         },
         {
-          "name": "from_token_account",
+          "name": "insurance_fund",
           "docs": [
-            "The user's token account holding the wrapped tokens to be bridged.",
-            "- Must contain sufficient token balance for the bridge amount",
-            "- Tokens will be burned from this account"
+            "Insurance fund credited with its share of the gas fee. No share is taken while it is",
+            "uninitialized."
           ],
           "writable": true
         },
         {
-          "name": "bridge",
+          "name": "payer_rate_limit_config",
           "docs": [
-            "The main bridge state account storing global bridge configuration.",
-            "- Uses PDA with BRIDGE_SEED for deterministic address",
-            "- Tracks nonce for message ordering and EIP-1559 gas pricing"
-          ],
-          "writable": true
+            "Payer rate limiting configuration. Payers are not rate limited while it is uninitialized."
+          ]
         },
         {
-          "name": "outgoing_message",
+          "name": "payer_rate_limit",
           "docs": [
-            "The outgoing message account being created to store bridge transfer data.",
-            "- Contains transfer details and optional call data for Base execution",
-            "- Space allocated based on call data size",
-            "- Will be read by Base relayers to complete the bridge operation"
+            "Outgoing messages paid for by the payer in the current rate limiting window, created on its",
+            "first message while rate limiting is enabled."
           ],
           "writable": true
         },
         {
-          "name": "token_program",
-          "docs": [
-            "Token2022 program used for burning the wrapped tokens.",
-            "Required for all token operations including burn_checked."
-          ]
-        },
-        {
-          "name": "system_program",
+          "name": "protocol_fee_config",
           "docs": [
-            "System program required for creating the outgoing message account",
-            "and transferring the gas payment to the `gas_fee_receiver`."
+            "Protocol fee configuration. No protocol fee is charged while it is uninitialized."
           ]
-        }
-      ],
-      "args": [
-        {
-          "name": "outgoing_message_salt",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          }
-        },
-        {
-          "name": "to",
-          "type": {
-            "array": [
-              "u8",
-              20
-            ]
-          }
-        },
-        {
-          "name": "amount",
-          "type": "u64"
         },
         {
-          "name": "call",
-          "type": {
-            "option": {
-              "defined": {
-                "name": "Call"
-              }
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "bridge_wrapped_token_with_buffered_call",
-      "docs": [
-        "Bridges wrapped tokens from Solana back to Base with a call using buffered data.",
-        "This function burns wrapped tokens on Solana and initiates a message to release",
-        "the original tokens on Base, then executes a call using data from a call buffer.",
-        "",
-        "# Arguments",
-        "* `ctx`                   - The context containing accounts for the wrapped token bridge operation",
-        "* `outgoing_message_salt` - The salt for the outgoing message account",
-        "* `to`                    - The 20-byte Ethereum address that will receive tokens on Base",
-        "* `amount`                - Amount of wrapped tokens to bridge back (in the token's smallest units)"
-      ],
-      "discriminator": [
-        117,
-        175,
-        150,
-        237,
-        216,
-        76,
-        56,
-        5
-      ],
-      "accounts": [
-        {
-          "name": "payer",
+          "name": "protocol_fee_treasury",
           "docs": [
-            "The account that pays for transaction fees, gas fees, and outgoing message account creation.",
-            "Must be mutable to deduct lamports for account rent and gas fees (sent to `gas_fee_receiver`)."
+            "The treasury credited with the protocol fee. Ignored when no protocol fee is charged."
           ],
-          "writable": true,
-          "signer": true
+          "writable": true
         },
         {
-          "name": "from",
+          "name": "dex_router_list",
           "docs": [
-            "The token owner who is bridging their wrapped tokens back to Base.",
-            "Must sign the transaction to authorize burning their tokens."
-          ],
-          "signer": true
+            "DEX router list whose routers may only be called with a swap guard. Calls are not checked",
+            "while it is uninitialized."
+          ]
         },
         {
-          "name": "gas_fee_receiver",
+          "name": "sol_vault_state",
           "docs": [
-            "The account that receives payment for the gas costs of bridging the wrapped token to Base.",
-            "Mutable because lamports are transferred to this account."
-          ],
-          "writable": true
+            "The SOL vault state recording the vault bump.",
+            "Bridging SOL requires the vault to have been initialized with `init_sol_vault`."
+          ]
         },
         {
-          "name": "mint",
+          "name": "sol_vault",
           "docs": [
-            "The wrapped token mint account representing the original Base token.",
-            "- Contains metadata linking to the original token on Base",
-            "- Supply will be reduced by burning tokens from the user's token account for this mint"
+            "The SOL vault account that holds the locked SOL.",
+            "- Uses PDA with SOL_VAULT_SEED and the bump recorded in `sol_vault_state`",
+            "- Mutable to receive the locked SOL",
+            ""
           ],
           "writable": true
         },
         {
-          "name": "from_token_account",
+          "name": "token_deposits",
           "docs": [
-            "The user's token account holding the wrapped tokens to be bridged.",
-            "- Must contain sufficient token balance for the bridge amount",
-            "- Tokens will be burned from this account",
-            "- The burn authority must be the `from` signer (or a valid delegate)"
+            "Accounting for the amount locked in the SOL vault.",
+            "- PDA of this program using `[TOKEN_DEPOSITS_SEED, sol_vault]`",
+            "- Created on the first deposit; `locked_amount` is incremented by the bridged amount"
           ],
           "writable": true
         },
         {
           "name": "bridge",
           "docs": [
-            "The main bridge state account storing global bridge configuration.",
-            "- Uses PDA with `BRIDGE_SEED` for deterministic address",
-            "- Tracks `nonce` for message ordering and maintains EIP-1559 fee state"
+            "The main bridge state account that tracks nonces and fee parameters.",
+            "- PDA with `BRIDGE_SEED`",
+            "- Mutable to charge gas (EIP-1559 accounting) and increment the message nonce"
           ],
           "writable": true
         },
@@ -1149,8 +1295,9 @@ export const IDL = {
         {
           "name": "call_buffer",
           "docs": [
-            "The call buffer account that stores the call data.",
-            "This account will be closed and rent returned to the owner."
+            "The call buffer account that stores the call parameters and data.",
+            "Its contents are copied into the outgoing message, then the account is closed",
+            "(rent refunded to `owner`) unless it is reusable."
           ],
           "writable": true
         },
@@ -1158,21 +1305,24 @@ export const IDL = {
           "name": "outgoing_message",
           "docs": [
             "The outgoing message account that stores the cross-chain transfer details.",
-            "Space is sized based on the current call buffer length so the call data fits."
+            "- Created fresh for each bridge; address determined by the provided keypair",
+            "- Funded by `payer`",
+            "- Space: DISCRIMINATOR_LEN + serialized `OutgoingMessage`"
           ],
           "writable": true
         },
         {
-          "name": "token_program",
+          "name": "system_program",
           "docs": [
-            "Token2022 program used for burning the wrapped tokens (burn_checked)."
+            "System program required for account creation and the SOL transfer CPI."
           ]
         },
         {
-          "name": "system_program",
+          "name": "memo_program",
           "docs": [
-            "System program required for creating the outgoing message account and transferring gas fees."
-          ]
+            "SPL Memo program, required when `spl_memo` is set to log a summary of the operation."
+          ],
+          "optional": true
         }
       ],
       "args": [
@@ -1197,239 +1347,259 @@ export const IDL = {
         {
           "name": "amount",
           "type": "u64"
+        },
+        {
+          "name": "spl_memo",
+          "type": "bool"
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "OutgoingMessageReceipt"
+        }
+      }
     },
     {
-      "name": "close_call_buffer",
+      "name": "bridge_spl",
       "docs": [
-        "Closes a call buffer account and returns the rent to the specified receiver.",
-        "Only the owner of the call buffer can close it. This is useful if the user",
-        "changed their mind or made a mistake and wants to recover the rent.",
+        "Bridges SPL tokens from Solana to Base.",
+        "This function burns or locks SPL tokens on Solana and initiates a message to mint",
+        "equivalent ERC20 tokens on Base for the specified recipient.",
         "",
         "# Arguments",
-        "* `ctx` - The context containing the call buffer to close and rent receiver (owner)"
+        "* `ctx`                   - The context containing accounts for the SPL token bridge operation",
+        "* `outgoing_message_salt` - The salt for the outgoing message account",
+        "* `to`                    - The 20-byte Ethereum address that will receive tokens on Base",
+        "* `remote_token`          - The 20-byte address of the ERC20 token contract on Base",
+        "* `amount`                - Amount of SPL tokens to bridge (in the token's smallest units), including the protocol fee,",
+        "or `BRIDGE_FULL_BALANCE` to bridge the whole balance of `from_token_account`",
+        "* `call`                  - Optional additional contract call to execute with the token transfer",
+        "* `memo`                  - Optional payment memo of at most MAX_TRANSFER_MEMO_LEN bytes, relayed to Base and emitted in `TransferMemo`",
+        "* `swap_guard`            - Optional minimum output and deadline enforced on Base around `call`, required when it targets a",
+        "router of the DEX router list",
+        "* `spl_memo`              - Whether to log an SPL memo summarizing the operation, requires the SPL Memo program account",
+        "",
+        "The mint must be owned by `token_program` and, once the guardian configured a mint policy,",
+        "its freeze authority must be unset or allowed by the policy. During guarded launch, the mint",
+        "must also be on the guardian's mint allow-list.",
+        "",
+        "# Returns",
+        "* The `OutgoingMessageReceipt` with the assigned nonce and the fees charged"
       ],
       "discriminator": [
-        132,
-        188,
-        7,
-        198,
-        64,
-        178,
-        62,
-        29
+        87,
+        109,
+        172,
+        103,
+        8,
+        187,
+        223,
+        126
       ],
       "accounts": [
         {
-          "name": "owner",
+          "name": "payer",
           "docs": [
-            "The account paying for the transaction fees and receiving the rent back.",
-            "It must be the owner of the call buffer account."
+            "The account that pays for transaction fees and account creation.",
+            "Must be mutable to deduct lamports for gas fees and new account rent."
           ],
+          "writable": true,
           "signer": true
         },
         {
-          "name": "call_buffer",
+          "name": "from",
           "docs": [
-            "The call buffer account to close"
+            "The token authority authorizing the transfer of SPL tokens.",
+            "This signer must be the owner or an approved delegate for the source token account.",
+            "It pays for nothing, so it needs no lamports when `payer` sponsors the transaction."
+          ],
+          "signer": true
+        },
+        {
+          "name": "gas_fee_receiver",
+          "docs": [
+            "The account that receives payment for the gas costs of bridging the SPL token to Base."
           ],
           "writable": true
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "close_prove_buffer",
-      "docs": [
-        "Closes a prove buffer account and returns the rent to the owner.",
-        "Only the owner of the prove buffer can close it. This is useful if the user",
-        "cannot complete proving and wants to recover the rent.",
-        "",
-        "# Arguments",
-        "* `ctx` - The context containing the prove buffer to close and rent receiver (owner)"
-      ],
-      "discriminator": [
-        79,
-        14,
-        144,
-        49,
-        3,
-        124,
-        210,
-        74
-      ],
-      "accounts": [
+        },
         {
-          "name": "owner",
+          "name": "gas_fee_escrow",
           "docs": [
-            "The account paying for the transaction fees and receiving the rent back.",
-            "It must be the owner of the prove buffer account."
+            "Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited.",
+            "Only required when it is used."
           ],
-          "signer": true
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "prove_buffer",
+          "name": "insurance_fund",
           "docs": [
-            "The prove buffer account to close"
+            "Insurance fund credited with its share of the gas fee. No share is taken while it is",
+            "uninitialized."
           ],
           "writable": true
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "initialize",
-      "docs": [
-        "Initializes the bridge program with required state accounts.",
-        "This function sets up the initial bridge configuration and must be called once during deployment.",
-        "",
-        "# Arguments",
-        "* `ctx`      - The context containing all accounts needed for initialization, including the guardian signer",
-        "* `guardian` - The guardian account that will have administrative authority over the bridge",
-        "* `cfg`      - All the configuration parameters needed to initialize the bridge"
-      ],
-      "discriminator": [
-        175,
-        175,
-        109,
-        31,
-        13,
-        152,
-        155,
-        237
-      ],
-      "accounts": [
+        },
         {
-          "name": "upgrade_authority",
+          "name": "payer_rate_limit_config",
           "docs": [
-            "The upgrade authority that is authorized to initialize the bridge.",
-            "This ensures only the program deployer can set the initial configuration."
+            "Payer rate limiting configuration. Payers are not rate limited while it is uninitialized."
+          ]
+        },
+        {
+          "name": "payer_rate_limit",
+          "docs": [
+            "Outgoing messages paid for by the payer in the current rate limiting window, created on its",
+            "first message while rate limiting is enabled. Only required while the payer is rate limited."
           ],
-          "signer": true
+          "writable": true,
+          "optional": true
         },
         {
-          "name": "payer",
+          "name": "protocol_fee_config",
           "docs": [
-            "The account that pays for the transaction and bridge account creation.",
-            "Must be mutable to deduct lamports for account rent.",
-            "Can be different from the upgrade_authority."
+            "Protocol fee configuration. No protocol fee is charged while it is uninitialized."
+          ]
+        },
+        {
+          "name": "protocol_fee_treasury",
+          "docs": [
+            "The treasury token account of `mint` credited with the protocol fee.",
+            "Only required when a protocol fee is charged."
           ],
           "writable": true,
-          "signer": true
+          "optional": true
         },
         {
-          "name": "bridge",
+          "name": "dex_router_list",
           "docs": [
-            "The bridge state account being initialized.",
-            "- Uses PDA with BRIDGE_SEED for deterministic address",
-            "- Payer funds the account creation",
-            "- Space allocated for bridge state (DISCRIMINATOR_LEN + Bridge::INIT_SPACE)"
+            "DEX router list whose routers may only be called with a swap guard. Calls are not checked",
+            "while it is uninitialized. Only required for calls without a swap guard."
           ],
-          "writable": true
+          "optional": true
         },
         {
-          "name": "program_data",
+          "name": "mint_policy",
           "docs": [
-            "Program data account containing the upgrade authority.",
-            "Validates that the signer is indeed the upgrade authority."
+            "Guardian-maintained policy restricting the freeze authority of bridged mints.",
+            "No freeze authority policy is enforced while it is uninitialized."
           ]
         },
         {
-          "name": "program",
+          "name": "mint_allow_list",
           "docs": [
-            "The bridge program itself.",
-            "Validates that program_data is the correct ProgramData account for this program."
-          ]
+            "Guardian-maintained allow-list of the mints accepted during guarded launch.",
+            "Only required while `bridge.guarded_launch` is set."
+          ],
+          "optional": true
         },
         {
-          "name": "system_program",
+          "name": "mint",
           "docs": [
-            "System program required for creating new accounts.",
-            "Used internally by Anchor for account initialization."
-          ]
-        }
-      ],
-      "args": [
+            "The SPL token mint account for the token being bridged.",
+            "- Must not be a wrapped token (wrapped tokens use bridge_wrapped_token)",
+            "- Used to read token decimals and validate it is not a wrapped token"
+          ],
+          "writable": true
+        },
         {
-          "name": "guardian",
-          "type": "pubkey"
+          "name": "from_token_account",
+          "docs": [
+            "The user's token account containing the SPL tokens to be bridged.",
+            "- Must be owned by, or delegated to, the `from` signer (transfer authority)",
+            "- Tokens will be transferred from this account to the token vault"
+          ],
+          "writable": true
         },
         {
-          "name": "cfg",
-          "type": {
-            "defined": {
-              "name": "Config"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "initialize_call_buffer",
-      "docs": [
-        "Initializes a call buffer account that can store large call data.",
-        "This account can be used to build up call data over multiple transactions",
-        "before using it in a bridge operation.",
-        "",
-        "# Arguments",
-        "* `ctx`          - The context containing accounts for initialization (including bridge config)",
-        "* `ty`           - The type of call (Call, DelegateCall, Create, Create2)",
-        "* `to`           - The target contract address on Base",
-        "* `value`        - The amount of ETH to send with the call (in wei)",
-        "* `initial_data` - Initial call data to store",
-        "* `max_data_len` - Maximum total length of data that will be stored"
-      ],
-      "discriminator": [
-        85,
-        68,
-        100,
-        234,
-        255,
-        226,
-        95,
-        72
-      ],
-      "accounts": [
+          "name": "bridge",
+          "docs": [
+            "The main bridge state account containing global bridge configuration.",
+            "- PDA with BRIDGE_SEED for deterministic address",
+            "- Tracks nonce for message ordering and EIP-1559 gas pricing",
+            "- Nonce is incremented after successful bridge operations"
+          ],
+          "writable": true
+        },
         {
-          "name": "payer",
+          "name": "token_vault",
           "docs": [
-            "The account that pays for the transaction and call buffer account creation.",
-            "This signer becomes the `CallBuffer.owner`."
+            "The token vault account that holds locked SPL tokens during the bridge process.",
+            "- PDA derived from TOKEN_VAULT_SEED, mint pubkey, and remote_token address",
+            "- Created if it doesn't exist for this mint/remote_token pair",
+            "- Token account authority is set to this vault PDA; the program signs using the PDA seeds",
+            "- Acts as the custody account for tokens being bridged to Base"
           ],
-          "writable": true,
-          "signer": true
+          "writable": true
         },
         {
-          "name": "bridge",
+          "name": "token_deposits",
           "docs": [
-            "The bridge account containing configuration including max buffer size"
+            "Accounting for the amount locked in the token vault.",
+            "- PDA of this program using `[TOKEN_DEPOSITS_SEED, token_vault]`",
+            "- Created on the first deposit; `locked_amount` is incremented by the received amount"
+          ],
+          "writable": true
+        },
+        {
+          "name": "vault_decimals",
+          "docs": [
+            "Decimals of the Base token the vault is bridged to.",
+            "- The transfer amount is carried in the smallest unit of the remote token",
+            "- Amounts are not scaled while it is uninitialized",
+            ""
           ]
         },
         {
-          "name": "call_buffer",
+          "name": "outgoing_message",
           "docs": [
-            "The call buffer account being initialized.",
-            "Space is allocated for up to `max_data_len` bytes of `data` (plus the Vec length prefix).",
-            "The bridge configuration enforces an upper bound via `buffer_config.max_call_buffer_size`."
+            "The outgoing message account that represents this bridge operation.",
+            "- Contains transfer details and optional call data for the destination chain",
+            "- Space is calculated based on the size of optional call data",
+            "- Used by relayers to execute the bridge operation on Base",
+            "- The recorded transfer amount equals the net increase in `token_vault` balance, scaled",
+            "down to the remote token decimals"
           ],
-          "writable": true,
-          "signer": true
+          "writable": true
+        },
+        {
+          "name": "token_program",
+          "docs": [
+            "The SPL Token program interface for executing token transfers.",
+            "Used for the transfer_checked operation to move tokens to the vault."
+          ]
         },
         {
           "name": "system_program",
           "docs": [
-            "System program required for creating new accounts"
+            "System program required for creating the outgoing message account and",
+            "initializing the token vault when needed."
           ]
+        },
+        {
+          "name": "memo_program",
+          "docs": [
+            "SPL Memo program, required when `spl_memo` is set to log a summary of the operation."
+          ],
+          "optional": true
+        },
+        {
+          "name": "bridge_session",
+          "docs": [
+            "Optional session letting `from` bridge as the delegate of the `from_token_account` owner.",
+            "The tokens are transferred by the session PDA and `amount` is debited from its budget."
+          ],
+          "writable": true,
+          "optional": true
         }
       ],
       "args": [
         {
-          "name": "ty",
+          "name": "outgoing_message_salt",
           "type": {
-            "defined": {
-              "name": "CallType"
-            }
+            "array": [
+              "u8",
+              32
+            ]
           }
         },
         {
@@ -1442,358 +1612,279 @@ export const IDL = {
           }
         },
         {
-          "name": "value",
-          "type": "u128"
-        },
-        {
-          "name": "initial_data",
-          "type": "bytes"
+          "name": "remote_token",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
         },
         {
-          "name": "max_data_len",
+          "name": "amount",
           "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "initialize_prove_buffer",
-      "docs": [
-        "Initializes a prove buffer account that can store large prove inputs.",
-        "This account can be used to build up serialized message data and MMR proof nodes",
-        "over multiple transactions before calling `prove_message_buffered`.",
-        "",
-        "# Arguments",
-        "* `ctx`           - The context containing accounts for initialization (payer, bridge, buffer)",
-        "* `max_data_len`  - Maximum total length of serialized `Message` data that will be stored",
-        "* `max_proof_len` - Maximum number of 32-byte MMR proof nodes that will be stored"
-      ],
-      "discriminator": [
-        20,
-        200,
-        91,
-        188,
-        138,
-        211,
-        253,
-        222
-      ],
-      "accounts": [
+        },
         {
-          "name": "payer",
-          "docs": [
-            "Payer funds the buffer account creation"
-          ],
-          "writable": true,
-          "signer": true
+          "name": "call",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "Call"
+              }
+            }
+          }
         },
         {
-          "name": "bridge",
-          "docs": [
-            "Bridge for pause checks (future use); also a consistent pattern like call buffers"
-          ]
+          "name": "memo",
+          "type": {
+            "option": "bytes"
+          }
         },
         {
-          "name": "prove_buffer",
-          "docs": [
-            "Prove buffer to be created with capacity sized by the provided max lengths"
-          ],
-          "writable": true,
-          "signer": true
+          "name": "swap_guard",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "SwapGuard"
+              }
+            }
+          }
         },
         {
-          "name": "system_program"
+          "name": "spl_memo",
+          "type": "bool"
         }
       ],
-      "args": [
-        {
-          "name": "max_data_len",
-          "type": "u64"
-        },
-        {
-          "name": "max_proof_len",
-          "type": "u64"
+      "returns": {
+        "defined": {
+          "name": "OutgoingMessageReceipt"
         }
-      ]
+      }
     },
     {
-      "name": "prove_message",
+      "name": "bridge_spl_with_buffered_call",
       "docs": [
-        "Proves that a cross-chain message exists in the Base Bridge contract using an MMR proof.",
-        "This function verifies the message was included in a previously registered output root",
-        "and stores the proven message state for later relay execution.",
+        "Bridges SPL tokens from Solana to Base with a call using buffered data.",
+        "This function locks SPL tokens on Solana and initiates a message to mint equivalent",
+        "tokens on Base, then executes a call using data from a call buffer.",
         "",
         "# Arguments",
-        "* `ctx`          - The transaction context",
-        "* `nonce`        - Unique identifier for the cross-chain message",
-        "* `sender`       - The 20-byte Ethereum address that sent the message on Base",
-        "* `data`         - The message payload/calldata to be executed on Solana",
-        "* `proof`        - MMR proof demonstrating message inclusion in the output root",
-        "* `message_hash` - The 32-byte hash of the message for verification"
+        "* `ctx`                   - The context containing accounts for the SPL token bridge operation",
+        "* `outgoing_message_salt` - The salt for the outgoing message account",
+        "* `to`                    - The 20-byte Ethereum address that will receive tokens on Base",
+        "* `remote_token`          - The 20-byte address of the ERC20 token contract on Base",
+        "* `amount`                - Amount of SPL tokens to bridge (in the token's smallest units), including the protocol fee,",
+        "or `BRIDGE_FULL_BALANCE` to bridge the whole balance of `from_token_account`",
+        "* `spl_memo`              - Whether to log an SPL memo summarizing the operation, requires the SPL Memo program account",
+        "",
+        "# Returns",
+        "* The `OutgoingMessageReceipt` with the assigned nonce and the fees charged"
       ],
       "discriminator": [
-        172,
-        66,
-        78,
-        136,
-        158,
+        86,
         187,
-        47,
-        115
+        229,
+        4,
+        110,
+        8,
+        116,
+        153
       ],
       "accounts": [
         {
           "name": "payer",
           "docs": [
-            "The account that pays for the transaction and incoming message account creation.",
-            "Must be mutable to deduct lamports for account rent."
+            "The account that pays for transaction fees and account creation.",
+            "Must be mutable to deduct lamports for gas fees and new account rent."
           ],
           "writable": true,
           "signer": true
         },
         {
-          "name": "output_root",
+          "name": "from",
           "docs": [
-            "The output root account containing the MMR root from Base.",
-            "Used to verify that the message proof is valid against the committed state.",
-            "This root must have been previously registered via register_output_root instruction."
-          ]
+            "The token authority authorizing the transfer of SPL tokens.",
+            "This signer must be the owner or an approved delegate for the source token account.",
+            "It pays for nothing, so it needs no lamports when `payer` sponsors the transaction."
+          ],
+          "signer": true
         },
         {
-          "name": "message",
+          "name": "gas_fee_receiver",
           "docs": [
-            "The incoming message account being created to store the proven message.",
-            "- Uses PDA with INCOMING_MESSAGE_SEED and message hash for deterministic address",
-            "- Payer funds the account creation",
-            "- Space dynamically allocated based on message data length",
-            "- Once created, this account can be used by relay instructions to execute the message"
+            "The account that receives payment for the gas costs of bridging the SPL token to Base."
           ],
           "writable": true
         },
         {
-          "name": "bridge",
+          "name": "gas_fee_escrow",
           "docs": [
-            "The main bridge state account used to check pause status",
-            "- Uses PDA with BRIDGE_SEED for deterministic address"
-          ]
+            "Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited."
+          ],
+          "writable": true
         },
         {
-          "name": "system_program",
+          "name": "insurance_fund",
           "docs": [
-            "System program required for creating new accounts.",
-            "Used internally by Anchor for account initialization."
-          ]
-        }
-      ],
-      "args": [
-        {
-          "name": "nonce",
-          "type": "u64"
+            "Insurance fund credited with its share of the gas fee. No share is taken while it is",
+            "uninitialized."
+          ],
+          "writable": true
         },
         {
-          "name": "sender",
-          "type": {
-            "array": [
-              "u8",
-              20
-            ]
-          }
+          "name": "payer_rate_limit_config",
+          "docs": [
+            "Payer rate limiting configuration. Payers are not rate limited while it is uninitialized."
+          ]
         },
         {
-          "name": "data",
-          "type": "bytes"
+          "name": "payer_rate_limit",
+          "docs": [
+            "Outgoing messages paid for by the payer in the current rate limiting window, created on its",
+            "first message while rate limiting is enabled."
+          ],
+          "writable": true
         },
         {
-          "name": "proof",
-          "type": {
-            "vec": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          }
+          "name": "protocol_fee_config",
+          "docs": [
+            "Protocol fee configuration. No protocol fee is charged while it is uninitialized."
+          ]
         },
         {
-          "name": "message_hash",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          }
-        }
-      ]
-    },
-    {
-      "name": "prove_message_buffered",
-      "docs": [
-        "Proves that a cross-chain message exists using buffered data and proof.",
-        "This function reads the serialized message and MMR proof from a `ProveBuffer`,",
-        "verifies inclusion against a previously registered output root, and stores the",
-        "proven message for later relay execution. The prove buffer is closed on success.",
-        "",
-        "# Arguments",
-        "* `ctx`          - The context containing accounts for verification and message creation",
-        "* `nonce`        - Unique identifier for the cross-chain message",
-        "* `sender`       - The 20-byte Ethereum address that sent the message on Base",
-        "* `message_hash` - The 32-byte hash of the message for verification"
-      ],
-      "discriminator": [
-        211,
-        48,
-        193,
-        183,
-        2,
-        51,
-        102,
-        159
-      ],
-      "accounts": [
-        {
-          "name": "payer",
+          "name": "protocol_fee_treasury",
           "docs": [
-            "Payer funds the IncomingMessage account"
+            "The treasury token account of `mint` credited with the protocol fee.",
+            "Ignored when no protocol fee is charged."
           ],
-          "writable": true,
-          "signer": true
+          "writable": true
         },
         {
-          "name": "output_root",
+          "name": "dex_router_list",
           "docs": [
-            "Output root to verify the proof against"
+            "DEX router list whose routers may only be called with a swap guard. Calls are not checked",
+            "while it is uninitialized."
           ]
         },
         {
-          "name": "message",
+          "name": "mint_policy",
           "docs": [
-            "The incoming message account created if proof verifies"
-          ],
-          "writable": true
+            "Guardian-maintained policy restricting the freeze authority of bridged mints.",
+            "No freeze authority policy is enforced while it is uninitialized."
+          ]
         },
         {
-          "name": "bridge",
+          "name": "mint_allow_list",
           "docs": [
-            "Bridge for pause check"
+            "Guardian-maintained allow-list of the mints accepted during guarded launch.",
+            "Only checked while `bridge.guarded_launch` is set."
           ]
         },
         {
-          "name": "owner",
+          "name": "mint",
           "docs": [
-            "Owner receives rent when buffer is closed"
+            "The SPL token mint account for the token being bridged.",
+            "- Must not be a wrapped token (wrapped tokens use bridge_wrapped_token)",
+            "- Used to validate transfer amounts and get token metadata"
           ],
-          "writable": true,
-          "signer": true
+          "writable": true
         },
         {
-          "name": "prove_buffer",
+          "name": "from_token_account",
           "docs": [
-            "Prove buffer containing data and proof; closed on success"
+            "The user's token account containing the SPL tokens to be bridged.",
+            "- Must be owned by, or delegated to, the `from` signer (transfer authority)",
+            "- Tokens will be transferred from this account to the token vault"
           ],
           "writable": true
         },
         {
-          "name": "system_program"
-        }
-      ],
-      "args": [
+          "name": "bridge",
+          "docs": [
+            "The main bridge state account containing global bridge configuration.",
+            "- PDA with BRIDGE_SEED for deterministic address",
+            "- Tracks nonce for message ordering and EIP-1559 gas pricing",
+            "- Nonce is incremented after successful bridge operations"
+          ],
+          "writable": true
+        },
         {
-          "name": "nonce",
-          "type": "u64"
+          "name": "token_vault",
+          "docs": [
+            "The token vault account that holds locked SPL tokens during the bridge process.",
+            "- PDA derived from TOKEN_VAULT_SEED, mint pubkey, and remote_token address",
+            "- Created if it doesn't exist for this mint/remote_token pair",
+            "- Token account authority is set to this vault PDA; the program signs using the PDA seeds",
+            "- Acts as the custody account for tokens being bridged to Base"
+          ],
+          "writable": true
         },
         {
-          "name": "sender",
-          "type": {
-            "array": [
-              "u8",
-              20
-            ]
-          }
+          "name": "token_deposits",
+          "docs": [
+            "Accounting for the amount locked in the token vault.",
+            "- PDA of this program using `[TOKEN_DEPOSITS_SEED, token_vault]`",
+            "- Created on the first deposit; `locked_amount` is incremented by the received amount"
+          ],
+          "writable": true
         },
         {
-          "name": "message_hash",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          }
-        }
-      ]
-    },
-    {
-      "name": "register_output_root",
-      "docs": [
-        "Registers an output root from Base to enable message verification.",
-        "This function stores the MMR root of Base message state at a specific block number,",
-        "which is required before any messages from that block can be proven and relayed.",
-        "Authorization is enforced via EVM signatures from authorized Base oracles and partner",
-        "signers per configured thresholds; the Solana payer only funds account creation.",
-        "",
-        "# Arguments",
-        "* `ctx`               - The context containing accounts for storing the output root (payer signs for fees; authorization is provided via EVM signatures)",
-        "* `output_root`       - The 32-byte MMR root of Base messages for the given block",
-        "* `base_block_number` - The Base block number this output root corresponds to",
-        "* `total_leaf_count`  - The total number of leaves in the MMR with this root",
-        "* `signatures`        - A list of ECDSA signatures from authorized oracles attesting to the output root"
-      ],
-      "discriminator": [
-        215,
-        66,
-        12,
-        154,
-        4,
-        123,
-        196,
-        66
-      ],
-      "accounts": [
+          "name": "vault_decimals",
+          "docs": [
+            "Decimals of the Base token the vault is bridged to.",
+            "- The transfer amount is carried in the smallest unit of the remote token",
+            "- Amounts are not scaled while it is uninitialized",
+            ""
+          ]
+        },
         {
-          "name": "payer",
+          "name": "owner",
           "docs": [
-            "Payer funds the account creation. Authorization is enforced via oracle EVM signature."
+            "The owner of the call buffer who will receive the rent refund."
           ],
           "writable": true,
           "signer": true
         },
         {
-          "name": "root",
+          "name": "call_buffer",
           "docs": [
-            "The output root account being created to store the Base MMR root and total leaf count.",
-            "- Uses PDA with OUTPUT_ROOT_SEED and base_block_number for deterministic address",
-            "- Payer funds the account creation (authorization is enforced via EVM signatures)",
-            "- Space allocated for output root state (DISCRIMINATOR_LEN + OutputRoot::INIT_SPACE)",
-            "- Each output root corresponds to a specific Base block number"
+            "The call buffer account that stores the call data.",
+            "This account will be closed and rent returned to the owner, unless it is reusable."
           ],
           "writable": true
         },
         {
-          "name": "bridge",
+          "name": "outgoing_message",
           "docs": [
-            "The main bridge state account that tracks the latest registered Base block number.",
-            "- Uses PDA with BRIDGE_SEED",
-            "- Must be mutable to update the base_block_number field",
-            "- Enforces registrations are monotonic and aligned to the configured interval"
+            "The outgoing message account that stores the cross-chain transfer details."
           ],
           "writable": true
         },
         {
-          "name": "partner_config",
+          "name": "token_program",
           "docs": [
-            "Partner `Config` account (PDA with seed \"config\") owned by partner program.",
-            "Unchecked to avoid Anchor pre-handler owner checks; PDA address is validated in the handler."
+            "The SPL Token program interface for executing token transfers.",
+            "Used for the transfer_checked operation to move tokens to the vault."
           ]
         },
         {
           "name": "system_program",
           "docs": [
-            "System program required for creating new accounts.",
-            "Used internally by Anchor for output root account initialization."
+            "System program required for creating the outgoing message account and",
+            "initializing the token vault when needed."
           ]
+        },
+        {
+          "name": "memo_program",
+          "docs": [
+            "SPL Memo program, required when `spl_memo` is set to log a summary of the operation."
+          ],
+          "optional": true
         }
       ],
       "args": [
         {
-          "name": "output_root",
+          "name": "outgoing_message_salt",
           "type": {
             "array": [
               "u8",
@@ -1802,908 +1893,7697 @@ export const IDL = {
           }
         },
         {
-          "name": "base_block_number",
-          "type": "u64"
+          "name": "to",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
         },
         {
-          "name": "total_leaf_count",
+          "name": "remote_token",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        },
+        {
+          "name": "amount",
           "type": "u64"
         },
         {
-          "name": "signatures",
-          "type": {
-            "vec": {
-              "array": [
-                "u8",
-                65
-              ]
-            }
-          }
+          "name": "spl_memo",
+          "type": "bool"
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "OutgoingMessageReceipt"
+        }
+      }
     },
     {
-      "name": "relay_message",
+      "name": "bridge_wrapped_token",
       "docs": [
-        "Executes a previously proven cross-chain message on Solana.",
-        "This function takes a message that has been proven via `prove_message` and executes",
-        "its payload using a bridge CPI authority derived from the message sender.",
+        "Bridges wrapped tokens from Solana back to their native form on Base.",
+        "This function burns wrapped tokens on Solana and initiates a message to release",
+        "or mint the original tokens on Base for the specified recipient.",
+        "The burn is recorded in an immutable `BurnReceipt` derived from the outgoing message.",
         "",
         "# Arguments",
-        "* `ctx` - The transaction context"
+        "* `ctx`                   - The context containing accounts for the wrapped token bridge operation",
+        "* `outgoing_message_salt` - The salt for the outgoing message account",
+        "* `to`                    - The 20-byte Ethereum address that will receive the original tokens on Base",
+        "* `amount`                - Amount of wrapped tokens to bridge back (in the token's smallest units), including the protocol fee,",
+        "or `BRIDGE_FULL_BALANCE` to bridge the whole balance of `from_token_account`",
+        "* `call`                  - Optional additional contract call to execute with the token transfer",
+        "* `memo`                  - Optional payment memo of at most MAX_TRANSFER_MEMO_LEN bytes, relayed to Base and emitted in `TransferMemo`",
+        "* `swap_guard`            - Optional minimum output and deadline enforced on Base around `call`, required when it targets a",
+        "router of the DEX router list",
+        "* `spl_memo`              - Whether to log an SPL memo summarizing the operation, requires the SPL Memo program account",
+        "",
+        "# Returns",
+        "* The `OutgoingMessageReceipt` with the assigned nonce and the fees charged"
       ],
       "discriminator": [
-        187,
-        90,
-        182,
-        138,
-        51,
-        248,
-        175,
-        98
+        55,
+        201,
+        139,
+        188,
+        226,
+        16,
+        136,
+        143
       ],
       "accounts": [
         {
-          "name": "message",
+          "name": "payer",
           "docs": [
-            "The incoming message account containing the cross-chain message to be executed.",
-            "- Contains either a pure call message or a transfer message with additional instructions",
-            "- Must be mutable to mark the message as executed after processing",
-            "- Prevents replay attacks by tracking execution status"
+            "The account that pays for transaction fees and outgoing message account creation.",
+            "Must be mutable to deduct lamports for account rent and gas fees."
           ],
-          "writable": true
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "bridge",
+          "name": "from",
           "docs": [
-            "The main bridge state account used to check pause status",
-            "- Uses PDA with BRIDGE_SEED for deterministic address"
-          ]
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "set_adjustment_denominator",
-      "docs": [
-        "Set the adjustment denominator for EIP-1559 pricing",
-        "Only the guardian can call this function",
-        "",
-        "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_denominator` - The new adjustment denominator"
-      ],
-      "discriminator": [
-        31,
-        91,
-        190,
-        63,
-        164,
-        7,
-        31,
-        150
-      ],
-      "accounts": [
+            "The token owner who is bridging their wrapped tokens back to Base.",
+            "Must sign the transaction to authorize burning their tokens."
+          ],
+          "signer": true
+        },
         {
-          "name": "bridge",
+          "name": "gas_fee_receiver",
           "docs": [
-            "The bridge account containing configuration"
+            "The account that receives payment for the gas costs of bridging the token on Base."
           ],
           "writable": true
         },
         {
-          "name": "guardian",
+          "name": "gas_fee_escrow",
           "docs": [
-            "The guardian account authorized to update configuration"
+            "Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited."
           ],
-          "signer": true
-        }
-      ],
-      "args": [
-        {
-          "name": "new_denominator",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "set_block_interval_requirement",
-      "docs": [
-        "Set the block interval requirement for Protocol Config",
-        "Only the guardian can call this function",
-        "",
-        "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_interval` - The new block interval requirement value"
-      ],
-      "discriminator": [
-        76,
-        70,
-        237,
-        100,
-        33,
-        108,
-        19,
-        42
-      ],
-      "accounts": [
+          "writable": true
+        },
         {
-          "name": "bridge",
+          "name": "insurance_fund",
           "docs": [
-            "The bridge account containing configuration"
+            "Insurance fund credited with its share of the gas fee. No share is taken while it is",
+            "uninitialized."
           ],
           "writable": true
         },
         {
-          "name": "guardian",
+          "name": "payer_rate_limit_config",
           "docs": [
-            "The guardian account authorized to update configuration"
-          ],
-          "signer": true
-        }
-      ],
-      "args": [
-        {
-          "name": "new_interval",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "set_gas_cost_scaler",
-      "docs": [
-        "Set the gas cost scaler for Gas Cost Config",
-        "Only the guardian can call this function",
-        "",
-        "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_scaler` - The new gas cost scaler value"
-      ],
-      "discriminator": [
-        148,
-        146,
-        101,
-        170,
-        5,
-        6,
-        222,
-        119
-      ],
-      "accounts": [
+            "Payer rate limiting configuration. Payers are not rate limited while it is uninitialized."
+          ]
+        },
         {
-          "name": "bridge",
+          "name": "payer_rate_limit",
           "docs": [
-            "The bridge account containing configuration"
+            "Outgoing messages paid for by the payer in the current rate limiting window, created on its",
+            "first message while rate limiting is enabled."
           ],
           "writable": true
         },
         {
-          "name": "guardian",
+          "name": "protocol_fee_config",
           "docs": [
-            "The guardian account authorized to update configuration"
-          ],
-          "signer": true
-        }
-      ],
-      "args": [
-        {
-          "name": "new_scaler",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "set_gas_cost_scaler_dp",
-      "docs": [
-        "Set the gas cost scaler DP for Gas Cost Config",
-        "Only the guardian can call this function",
-        "",
-        "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_dp` - The new gas cost scaler DP value"
-      ],
-      "discriminator": [
-        198,
-        111,
-        160,
-        55,
-        172,
-        138,
-        99,
-        164
-      ],
-      "accounts": [
+            "Protocol fee configuration. No protocol fee is charged while it is uninitialized."
+          ]
+        },
         {
-          "name": "bridge",
+          "name": "protocol_fee_treasury",
           "docs": [
-            "The bridge account containing configuration"
+            "The treasury token account of `mint` credited with the protocol fee.",
+            "Ignored when no protocol fee is charged."
           ],
           "writable": true
         },
         {
-          "name": "guardian",
+          "name": "dex_router_list",
           "docs": [
-            "The guardian account authorized to update configuration"
-          ],
-          "signer": true
-        }
-      ],
-      "args": [
-        {
-          "name": "new_dp",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "set_gas_fee_receiver",
-      "docs": [
-        "Set the gas fee receiver for Gas Cost Config",
-        "Only the guardian can call this function",
-        "",
-        "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_receiver` - The new gas fee receiver"
-      ],
-      "discriminator": [
-        58,
-        188,
-        230,
-        188,
-        47,
-        188,
-        79,
-        154
-      ],
-      "accounts": [
+            "DEX router list whose routers may only be called with a swap guard. Calls are not checked",
+            "while it is uninitialized."
+          ]
+        },
         {
-          "name": "bridge",
+          "name": "mint",
           "docs": [
-            "The bridge account containing configuration"
+            "The wrapped token mint account representing the original Base token.",
+            "- Contains metadata linking to the original token on Base",
+            "- Tokens will be burned from this mint"
           ],
           "writable": true
         },
         {
-          "name": "guardian",
+          "name": "wrapped_token_confirmation",
           "docs": [
-            "The guardian account authorized to update configuration"
-          ],
-          "signer": true
-        }
-      ],
-      "args": [
+            "Confirmation that Base registered the wrapped token.",
+            "Only required to exist while `bridge.require_wrapped_token_confirmation` is set."
+          ]
+        },
         {
-          "name": "new_receiver",
-          "type": "pubkey"
-        }
-      ]
-    },
-    {
-      "name": "set_gas_per_call",
-      "docs": [
-        "Set the gas amount per call for Gas Config",
-        "Only the guardian can call this function",
-        "",
-        "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_val` - The new gas amount per call value"
-      ],
-      "discriminator": [
-        164,
-        95,
-        213,
-        130,
-        26,
-        69,
-        82,
-        127
-      ],
-      "accounts": [
+          "name": "from_token_account",
+          "docs": [
+            "The user's token account holding the wrapped tokens to be bridged.",
+            "- Must contain sufficient token balance for the bridge amount",
+            "- Tokens will be burned from this account"
+          ],
+          "writable": true
+        },
         {
           "name": "bridge",
           "docs": [
-            "The bridge account containing configuration"
+            "The main bridge state account storing global bridge configuration.",
+            "- Uses PDA with BRIDGE_SEED for deterministic address",
+            "- Tracks nonce for message ordering and EIP-1559 gas pricing"
           ],
           "writable": true
         },
         {
-          "name": "guardian",
+          "name": "outgoing_message",
           "docs": [
-            "The guardian account authorized to update configuration"
+            "The outgoing message account being created to store bridge transfer data.",
+            "- Contains transfer details and optional call data for Base execution",
+            "- Space allocated based on call data and memo sizes",
+            "- Will be read by Base relayers to complete the bridge operation"
           ],
-          "signer": true
-        }
-      ],
-      "args": [
-        {
-          "name": "new_val",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "set_gas_target",
-      "docs": [
-        "Set the gas target for EIP-1559 pricing",
-        "Only the guardian can call this function",
-        "",
-        "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_target` - The new gas target value"
-      ],
-      "discriminator": [
-        132,
-        25,
-        19,
-        13,
-        118,
-        63,
-        167,
-        102
-      ],
-      "accounts": [
+          "writable": true
+        },
         {
-          "name": "bridge",
+          "name": "burn_receipt",
           "docs": [
-            "The bridge account containing configuration"
+            "Immutable receipt of the burn, linking it to the Base recipient for compliance accounting.",
+            "- PDA with BURN_RECEIPT_SEED and the outgoing message address"
           ],
           "writable": true
         },
         {
-          "name": "guardian",
+          "name": "token_program",
           "docs": [
-            "The guardian account authorized to update configuration"
-          ],
-          "signer": true
-        }
-      ],
-      "args": [
+            "Token2022 program used for burning the wrapped tokens.",
+            "Required for all token operations including burn_checked."
+          ]
+        },
         {
-          "name": "new_target",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "set_max_call_buffer_size",
-      "docs": [
-        "Set the max call buffer size for Buffer Config",
-        "Only the guardian can call this function",
-        "",
-        "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_size` - The new max call buffer size value"
-      ],
-      "discriminator": [
-        140,
-        178,
-        4,
-        238,
-        245,
-        66,
-        117,
-        189
-      ],
-      "accounts": [
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the outgoing message account",
+            "and transferring the gas payment to the `gas_fee_receiver`."
+          ]
+        },
         {
-          "name": "bridge",
+          "name": "memo_program",
           "docs": [
-            "The bridge account containing configuration"
+            "SPL Memo program, required when `spl_memo` is set to log a summary of the operation."
           ],
-          "writable": true
+          "optional": true
         },
         {
-          "name": "guardian",
+          "name": "bridge_session",
           "docs": [
-            "The guardian account authorized to update configuration"
+            "Optional session letting `from` bridge as the delegate of the `from_token_account` owner.",
+            "The tokens are burned by the session PDA and `amount` is debited from its budget."
           ],
-          "signer": true
+          "writable": true,
+          "optional": true
         }
       ],
       "args": [
         {
-          "name": "new_size",
+          "name": "outgoing_message_salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "to",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        },
+        {
+          "name": "amount",
           "type": "u64"
+        },
+        {
+          "name": "call",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "Call"
+              }
+            }
+          }
+        },
+        {
+          "name": "memo",
+          "type": {
+            "option": "bytes"
+          }
+        },
+        {
+          "name": "swap_guard",
+          "type": {
+            "option": {
+              "defined": {
+                "name": "SwapGuard"
+              }
+            }
+          }
+        },
+        {
+          "name": "spl_memo",
+          "type": "bool"
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "OutgoingMessageReceipt"
+        }
+      }
     },
     {
-      "name": "set_minimum_base_fee",
+      "name": "bridge_wrapped_token_with_buffered_call",
       "docs": [
-        "Set the minimum base fee for EIP-1559 pricing",
-        "Only the guardian can call this function",
+        "Bridges wrapped tokens from Solana back to Base with a call using buffered data.",
+        "This function burns wrapped tokens on Solana and initiates a message to release",
+        "the original tokens on Base, then executes a call using data from a call buffer.",
+        "The burn is recorded in an immutable `BurnReceipt` derived from the outgoing message.",
         "",
         "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_fee` - The new minimum base fee value"
+        "* `ctx`                   - The context containing accounts for the wrapped token bridge operation",
+        "* `outgoing_message_salt` - The salt for the outgoing message account",
+        "* `to`                    - The 20-byte Ethereum address that will receive tokens on Base",
+        "* `amount`                - Amount of wrapped tokens to bridge back (in the token's smallest units), including the protocol fee,",
+        "or `BRIDGE_FULL_BALANCE` to bridge the whole balance of `from_token_account`",
+        "* `spl_memo`              - Whether to log an SPL memo summarizing the operation, requires the SPL Memo program account",
+        "",
+        "# Returns",
+        "* The `OutgoingMessageReceipt` with the assigned nonce and the fees charged"
       ],
       "discriminator": [
+        117,
+        175,
+        150,
+        237,
+        216,
+        76,
         56,
-        95,
-        58,
-        94,
-        221,
-        136,
-        138,
-        156
+        5
       ],
       "accounts": [
         {
-          "name": "bridge",
+          "name": "payer",
           "docs": [
-            "The bridge account containing configuration"
+            "The account that pays for transaction fees, gas fees, and outgoing message account creation.",
+            "Must be mutable to deduct lamports for account rent and gas fees (sent to `gas_fee_receiver`)."
           ],
-          "writable": true
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "guardian",
+          "name": "from",
           "docs": [
-            "The guardian account authorized to update configuration"
+            "The token owner who is bridging their wrapped tokens back to Base.",
+            "Must sign the transaction to authorize burning their tokens."
           ],
           "signer": true
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "new_fee",
-          "type": "u64"
-        }
-      ]
-    },
-    {
-      "name": "set_oracle_signers",
-      "docs": [
-        "Sets the authorized oracle EVM signer addresses and the signature threshold used",
-        "when registering output roots. This function updates the `OracleSigners` account",
-        "and can only be called by the guardian.",
-        "",
-        "# Arguments",
-        "* `ctx` - The context containing the bridge, guardian signer, and oracle signers accounts",
-        "* `cfg` - Configuration parameters for Base oracle signers"
-      ],
-      "discriminator": [
-        38,
-        183,
-        212,
-        67,
-        28,
-        241,
-        25,
-        160
-      ],
-      "accounts": [
+          "name": "gas_fee_receiver",
+          "docs": [
+            "The account that receives payment for the gas costs of bridging the wrapped token to Base.",
+            "Mutable because lamports are transferred to this account."
+          ],
+          "writable": true
+        },
         {
-          "name": "upgrade_authority",
+          "name": "gas_fee_escrow",
           "docs": [
-            "The upgrade authority account"
+            "Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited."
           ],
-          "signer": true
+          "writable": true
         },
         {
-          "name": "bridge",
+          "name": "insurance_fund",
           "docs": [
-            "The bridge account containing configuration"
+            "Insurance fund credited with its share of the gas fee. No share is taken while it is",
+            "uninitialized."
           ],
           "writable": true
         },
         {
-          "name": "program_data"
+          "name": "payer_rate_limit_config",
+          "docs": [
+            "Payer rate limiting configuration. Payers are not rate limited while it is uninitialized."
+          ]
         },
         {
-          "name": "program"
-        }
-      ],
-      "args": [
+          "name": "payer_rate_limit",
+          "docs": [
+            "Outgoing messages paid for by the payer in the current rate limiting window, created on its",
+            "first message while rate limiting is enabled."
+          ],
+          "writable": true
+        },
         {
-          "name": "cfg",
-          "type": {
-            "defined": {
-              "name": "BaseOracleConfig"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "set_partner_oracle_config",
-      "docs": [
-        "Update the partner oracle configuration containing the required signature threshold",
-        "",
-        "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_config` - The new partner oracle config"
-      ],
-      "discriminator": [
-        34,
-        48,
-        231,
-        135,
-        42,
-        113,
-        217,
-        157
-      ],
-      "accounts": [
+          "name": "protocol_fee_config",
+          "docs": [
+            "Protocol fee configuration. No protocol fee is charged while it is uninitialized."
+          ]
+        },
         {
-          "name": "upgrade_authority",
+          "name": "protocol_fee_treasury",
           "docs": [
-            "The upgrade authority account"
+            "The treasury token account of `mint` credited with the protocol fee.",
+            "Ignored when no protocol fee is charged."
           ],
-          "signer": true
+          "writable": true
         },
         {
-          "name": "bridge",
+          "name": "dex_router_list",
           "docs": [
-            "The bridge account containing configuration"
+            "DEX router list whose routers may only be called with a swap guard. Calls are not checked",
+            "while it is uninitialized."
+          ]
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "The wrapped token mint account representing the original Base token.",
+            "- Contains metadata linking to the original token on Base",
+            "- Supply will be reduced by burning tokens from the user's token account for this mint"
           ],
           "writable": true
         },
         {
-          "name": "program_data"
+          "name": "wrapped_token_confirmation",
+          "docs": [
+            "Confirmation that Base registered the wrapped token.",
+            "Only required to exist while `bridge.require_wrapped_token_confirmation` is set."
+          ]
         },
         {
-          "name": "program"
-        }
-      ],
-      "args": [
-        {
-          "name": "new_config",
-          "type": {
-            "defined": {
-              "name": "PartnerOracleConfig"
-            }
-          }
-        }
-      ]
-    },
-    {
-      "name": "set_pause_status",
-      "docs": [
-        "Set the pause status for the bridge",
-        "Only the guardian can call this function",
-        "",
-        "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_paused` - The new pause status (true for paused, false for unpaused)"
-      ],
-      "discriminator": [
-        118,
-        25,
-        145,
-        217,
-        114,
-        209,
-        236,
-        145
-      ],
-      "accounts": [
+          "name": "from_token_account",
+          "docs": [
+            "The user's token account holding the wrapped tokens to be bridged.",
+            "- Must contain sufficient token balance for the bridge amount",
+            "- Tokens will be burned from this account",
+            "- The burn authority must be the `from` signer (or a valid delegate)"
+          ],
+          "writable": true
+        },
         {
           "name": "bridge",
           "docs": [
-            "The bridge account containing configuration"
+            "The main bridge state account storing global bridge configuration.",
+            "- Uses PDA with `BRIDGE_SEED` for deterministic address",
+            "- Tracks `nonce` for message ordering and maintains EIP-1559 fee state"
           ],
           "writable": true
         },
         {
-          "name": "guardian",
+          "name": "owner",
           "docs": [
-            "The guardian account authorized to update configuration"
+            "The owner of the call buffer who will receive the rent refund."
           ],
+          "writable": true,
           "signer": true
-        }
-      ],
-      "args": [
+        },
         {
-          "name": "new_paused",
-          "type": "bool"
-        }
-      ]
-    },
-    {
-      "name": "set_window_duration",
-      "docs": [
-        "Set the window duration for EIP-1559 pricing",
-        "Only the guardian can call this function",
-        "",
-        "# Arguments",
-        "* `ctx` - The context containing the bridge account and guardian",
-        "* `new_duration` - The new window duration in seconds"
-      ],
-      "discriminator": [
-        229,
-        2,
-        41,
-        119,
-        55,
-        255,
-        252,
-        205
-      ],
-      "accounts": [
+          "name": "call_buffer",
+          "docs": [
+            "The call buffer account that stores the call data.",
+            "This account will be closed and rent returned to the owner, unless it is reusable."
+          ],
+          "writable": true
+        },
         {
-          "name": "bridge",
+          "name": "outgoing_message",
           "docs": [
-            "The bridge account containing configuration"
+            "The outgoing message account that stores the cross-chain transfer details.",
+            "Space is sized based on the current call buffer length so the call data fits."
           ],
           "writable": true
         },
         {
-          "name": "guardian",
+          "name": "burn_receipt",
           "docs": [
-            "The guardian account authorized to update configuration"
+            "Immutable receipt of the burn, linking it to the Base recipient for compliance accounting.",
+            "- PDA with BURN_RECEIPT_SEED and the outgoing message address"
           ],
-          "signer": true
+          "writable": true
+        },
+        {
+          "name": "token_program",
+          "docs": [
+            "Token2022 program used for burning the wrapped tokens (burn_checked)."
+          ]
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the outgoing message account and transferring gas fees."
+          ]
+        },
+        {
+          "name": "memo_program",
+          "docs": [
+            "SPL Memo program, required when `spl_memo` is set to log a summary of the operation."
+          ],
+          "optional": true
         }
       ],
       "args": [
         {
-          "name": "new_duration",
+          "name": "outgoing_message_salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "to",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        },
+        {
+          "name": "amount",
           "type": "u64"
+        },
+        {
+          "name": "spl_memo",
+          "type": "bool"
         }
-      ]
+      ],
+      "returns": {
+        "defined": {
+          "name": "OutgoingMessageReceipt"
+        }
+      }
     },
     {
-      "name": "transfer_guardian",
+      "name": "cancel_emergency_exit",
       "docs": [
-        "Transfer guardian authority to a new pubkey",
-        "Only the current guardian can call this function",
+        "Cancel the emergency exit",
+        "Only the guardian can call this function",
         "",
         "# Arguments",
-        "* `ctx` - The context containing the bridge account and current guardian",
-        "* `new_guardian` - The pubkey of the new guardian"
+        "* `ctx` - The context containing the emergency exit, bridge account and guardian"
       ],
       "discriminator": [
-        118,
-        250,
-        162,
-        85,
-        197,
-        130,
         116,
-        123
+        103,
+        71,
+        148,
+        211,
+        57,
+        195,
+        218
       ],
       "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to cancel the emergency exit.",
+            "Receives the rent of the emergency exit account."
+          ],
+          "writable": true,
+          "signer": true
+        },
         {
           "name": "bridge",
           "docs": [
-            "The bridge account containing configuration"
+            "The bridge account holding the guardian"
+          ]
+        },
+        {
+          "name": "emergency_exit",
+          "docs": [
+            "The emergency exit account, closed by this instruction"
           ],
           "writable": true
         },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancel_insurance_payout",
+      "docs": [
+        "Cancel the pending insurance payout",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the insurance fund, bridge account and guardian"
+      ],
+      "discriminator": [
+        88,
+        159,
+        20,
+        114,
+        216,
+        176,
+        169,
+        130
+      ],
+      "accounts": [
         {
           "name": "guardian",
           "docs": [
-            "The guardian account authorized to update configuration"
+            "The guardian account authorized to manage the insurance payouts."
           ],
           "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the guardian"
+          ]
+        },
+        {
+          "name": "insurance_fund",
+          "docs": [
+            "The insurance fund account"
+          ],
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "check_environment",
+      "docs": [
+        "Fails with `EnvironmentMismatch` unless the program was compiled for `expected` and is",
+        "deployed at the program ID of that environment, letting deployment scripts catch builds",
+        "made for another environment.",
+        "",
+        "# Arguments",
+        "* `ctx`      - The context; no accounts are required",
+        "* `expected` - The environment the program is expected to be built for"
+      ],
+      "discriminator": [
+        66,
+        105,
+        5,
+        163,
+        77,
+        91,
+        38,
+        191
+      ],
+      "accounts": [
+        {
+          "name": "program",
+          "docs": [
+            "The bridge program"
+          ]
         }
       ],
       "args": [
         {
-          "name": "new_guardian",
-          "type": "pubkey"
+          "name": "expected",
+          "type": {
+            "defined": {
+              "name": "Environment"
+            }
+          }
         }
       ]
     },
     {
-      "name": "wrap_token",
+      "name": "clear_solvency_alert",
       "docs": [
-        "Creates a wrapped version of a Base token.",
-        "This function creates a new SPL mint account on Solana that represents the Base token,",
-        "enabling users to bridge the token between the two chains. It will also trigger a message",
-        "to Base to register the wrapped token in the Base Bridge contract.",
+        "Clear the solvency alert raised by `reconcile_vault`",
+        "Only the guardian can call this function",
         "",
         "# Arguments",
-        "* `ctx`                    - The transaction context",
-        "* `outgoing_message_salt`  - The salt for the outgoing message account",
-        "* `decimals`               - Number of decimal places for the token",
-        "* `partial_token_metadata` - Token name, symbol, remote Base token address, and scaler exponent"
+        "* `ctx` - The context containing the liability root, bridge account and guardian"
       ],
       "discriminator": [
-        203,
-        83,
-        204,
-        83,
-        225,
-        109,
-        44,
-        6
+        235,
+        20,
+        82,
+        87,
+        228,
+        123,
+        54,
+        163
       ],
       "accounts": [
         {
-          "name": "payer",
+          "name": "guardian",
           "docs": [
-            "The account that pays for the transaction and all account creation costs.",
-            "Must be mutable to deduct lamports for mint creation, metadata storage, and gas fees."
+            "The guardian account authorized to update configuration"
           ],
-          "writable": true,
           "signer": true
         },
         {
-          "name": "gas_fee_receiver",
+          "name": "bridge",
           "docs": [
-            "The account that receives payment for the gas costs of registering the token on Base."
-          ],
-          "writable": true
+            "The bridge account holding the guardian"
+          ]
         },
         {
-          "name": "mint",
+          "name": "liability_root",
           "docs": [
-            "The new SPL Token-2022 mint being created for the wrapped token.",
-            "- Uses PDA with token metadata hash and decimals for deterministic address",
-            "- Mint authority set to itself (mint account) for controlled minting",
-            "- Includes metadata pointer extension to store token information onchain"
+            "The liability root flagged with a solvency alert"
           ],
           "writable": true
-        },
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "clone_call_buffer",
+      "docs": [
+        "Copies a call buffer into a new single-use call buffer owned by the payer. Reusable",
+        "buffers can be copied by any account; other buffers only by their owner.",
+        "",
+        "# Arguments",
+        "* `ctx`          - The context containing the source buffer and the new call buffer",
+        "* `max_data_len` - Maximum total length of data that will be stored in the copy"
+      ],
+      "discriminator": [
+        230,
+        208,
+        140,
+        80,
+        195,
+        213,
+        190,
+        158
+      ],
+      "accounts": [
         {
-          "name": "bridge",
+          "name": "payer",
           "docs": [
-            "The main bridge state account that tracks cross-chain operations.",
-            "Used to increment the nonce counter and manage EIP-1559 gas pricing.",
-            "Must be mutable to update the nonce after creating the outgoing message."
+            "The account that pays for the new call buffer account creation.",
+            "This signer becomes the owner of the new buffer."
           ],
-          "writable": true
+          "writable": true,
+          "signer": true
         },
         {
-          "name": "outgoing_message",
+          "name": "bridge",
           "docs": [
-            "The outgoing message account that stores the cross-chain call to register",
-            "the wrapped token on the Base blockchain. Contains the encoded function call",
-            "with token address, local mint address, and scaling parameters."
-          ],
-          "writable": true
+            "The bridge account containing configuration including max buffer size"
+          ]
         },
         {
-          "name": "token_program",
+          "name": "source",
           "docs": [
-            "SPL Token-2022 program for creating the mint with metadata extensions.",
-            "Required for initializing tokens with advanced features like metadata pointers."
+            "The call buffer to copy. Buffers that are not reusable can only be copied by their owner."
           ]
         },
+        {
+          "name": "call_buffer",
+          "docs": [
+            "The call buffer account being initialized with the contents of `source`.",
+            "Space is allocated for up to `max_data_len` bytes of `data`, which must fit the copied data."
+          ],
+          "writable": true,
+          "signer": true
+        },
         {
           "name": "system_program",
           "docs": [
-            "System program required for creating new accounts and transferring lamports.",
-            "Used internally by Anchor for account initialization and rent payments."
+            "System program required for creating new accounts"
           ]
         }
       ],
       "args": [
         {
-          "name": "outgoing_message_salt",
-          "type": {
-            "array": [
-              "u8",
-              32
-            ]
-          }
-        },
-        {
-          "name": "decimals",
-          "type": "u8"
+          "name": "max_data_len",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "close_call_buffer",
+      "docs": [
+        "Closes a call buffer account and returns the rent to the specified receiver.",
+        "Only the owner of the call buffer can close it. This is useful if the user",
+        "changed their mind or made a mistake and wants to recover the rent.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the call buffer to close and rent receiver (owner)"
+      ],
+      "discriminator": [
+        132,
+        188,
+        7,
+        198,
+        64,
+        178,
+        62,
+        29
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "The account paying for the transaction fees and receiving the rent back.",
+            "It must be the owner of the call buffer account."
+          ],
+          "signer": true
         },
         {
-          "name": "partial_token_metadata",
-          "type": {
-            "defined": {
-              "name": "PartialTokenMetadata"
-            }
-          }
+          "name": "call_buffer",
+          "docs": [
+            "The call buffer account to close"
+          ],
+          "writable": true
         }
-      ]
-    }
-  ],
-  "accounts": [
+      ],
+      "args": []
+    },
     {
-      "name": "Bridge",
+      "name": "close_prove_buffer",
+      "docs": [
+        "Closes a prove buffer account and returns the rent to the owner.",
+        "Only the owner of the prove buffer can close it. This is useful if the user",
+        "cannot complete proving and wants to recover the rent.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the prove buffer to close and rent receiver (owner)"
+      ],
       "discriminator": [
-        231,
-        232,
-        31,
-        98,
-        110,
+        79,
+        14,
+        144,
+        49,
         3,
-        23,
-        59
-      ]
+        124,
+        210,
+        74
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "The account paying for the transaction fees and receiving the rent back.",
+            "It must be the owner of the prove buffer account."
+          ],
+          "signer": true
+        },
+        {
+          "name": "prove_buffer",
+          "docs": [
+            "The prove buffer account to close"
+          ],
+          "writable": true
+        }
+      ],
+      "args": []
     },
     {
-      "name": "CallBuffer",
+      "name": "close_relayed_outgoing_message",
+      "docs": [
+        "Permissionlessly closes an outgoing message once the relayed nonces registry confirms it",
+        "was executed on Base, returning its rent to the message sender.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the outgoing message, its sender and the registry account"
+      ],
       "discriminator": [
-        134,
-        143,
-        168,
-        251,
-        163,
-        216,
-        180,
-        113
-      ]
+        167,
+        21,
+        71,
+        219,
+        95,
+        206,
+        117,
+        154
+      ],
+      "accounts": [
+        {
+          "name": "sender",
+          "docs": [
+            "The sender of the outgoing message, receiving its rent.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "outgoing_message",
+          "docs": [
+            "The outgoing message to close"
+          ],
+          "writable": true
+        },
+        {
+          "name": "relayed_nonces",
+          "docs": [
+            "The relayed nonces registry covering the outgoing message nonce"
+          ]
+        }
+      ],
+      "args": []
     },
     {
-      "name": "IncomingMessage",
+      "name": "close_superseded_message",
+      "docs": [
+        "Permissionlessly closes an unexecuted message proven against a superseded output root",
+        "revision, together with its `ProvenChunk` accounts passed as remaining accounts in index",
+        "order, so that the message can be proven again against the current revision.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the prover receiving the rent, the message and its output root"
+      ],
       "discriminator": [
-        30,
-        144,
-        125,
-        111,
-        211,
-        223,
-        91,
-        170
-      ]
+        112,
+        114,
+        96,
+        242,
+        189,
+        158,
+        235,
+        210
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Pays the transaction fees."
+          ],
+          "signer": true
+        },
+        {
+          "name": "prover",
+          "docs": [
+            "The prover recorded in the message, receiving the rent of the closed accounts."
+          ],
+          "writable": true
+        },
+        {
+          "name": "message",
+          "docs": [
+            "The incoming message proven against a superseded output root revision."
+          ],
+          "writable": true
+        },
+        {
+          "name": "output_root",
+          "docs": [
+            "The output root the message was proven against."
+          ]
+        }
+      ],
+      "args": []
     },
     {
-      "name": "OutgoingMessage",
+      "name": "confirm_relayed",
+      "docs": [
+        "Records outgoing message nonces executed on Base in the relayed nonces registry.",
+        "Base may execute messages out of order, so each nonce is tracked individually in a bitmap",
+        "account covering `RELAYED_NONCES_PER_ACCOUNT` nonces.",
+        "Authorization is enforced via EVM signatures from authorized Base oracles and partner",
+        "signers per configured thresholds; the Solana payer only funds account creation.",
+        "",
+        "# Arguments",
+        "* `ctx`        - The context containing the payer, the bridge and the registry account for `page`",
+        "* `page`       - The registry page covering every confirmed nonce",
+        "* `nonces`     - The outgoing message nonces executed on Base",
+        "* `signatures` - A list of ECDSA signatures from authorized oracles attesting to the nonces"
+      ],
       "discriminator": [
-        150,
-        255,
-        197,
-        226,
-        200,
-        215,
-        31,
-        29
+        65,
+        89,
+        205,
+        73,
+        164,
+        223,
+        6,
+        39
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Payer funds the registry account creation. Authorization is enforced via oracle EVM",
+            "signatures."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The main bridge state account providing the oracle configuration and the next nonce."
+          ]
+        },
+        {
+          "name": "relayed_nonces",
+          "docs": [
+            "The registry account covering the confirmed nonces.",
+            "- Uses PDA with RELAYED_NONCES_SEED and `page` for deterministic address",
+            "- Created on the first confirmation of a nonce in the page"
+          ],
+          "writable": true
+        },
+        {
+          "name": "partner_config",
+          "docs": [
+            "Partner `Config` account (PDA with seed \"config\") owned by partner program.",
+            "Unchecked to avoid Anchor pre-handler owner checks; PDA address is validated in the handler."
+          ]
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the registry account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "page",
+          "type": "u64"
+        },
+        {
+          "name": "nonces",
+          "type": {
+            "vec": "u64"
+          }
+        },
+        {
+          "name": "signatures",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                65
+              ]
+            }
+          }
+        }
       ]
     },
     {
-      "name": "OutputRoot",
+      "name": "create_bridge_session",
+      "docs": [
+        "Creates or replaces the bridge session authorizing `delegate` to bridge the owner's SPL",
+        "and wrapped tokens through `bridge_spl` and `bridge_wrapped_token` without the owner's",
+        "signature. The owner must approve the session PDA as SPL delegate of the bridged token",
+        "accounts.",
+        "",
+        "# Arguments",
+        "* `ctx`                   - The context containing the owner and the session account",
+        "* `delegate`              - The key allowed to bridge on behalf of the owner",
+        "* `budget`                - Total amount that can be bridged through the session (in the tokens' smallest units), including protocol fees",
+        "* `expires_at`            - Unix timestamp from which the session can no longer be used",
+        "* `allowed_remote_tokens` - The Base tokens the session can bridge to, at most MAX_SESSION_REMOTE_TOKENS"
+      ],
       "discriminator": [
-        11,
-        31,
-        168,
-        201,
-        229,
-        8,
-        180,
-        198
+        129,
+        157,
+        128,
+        13,
+        113,
+        164,
+        221,
+        16
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "The owner of the bridged tokens, funding the session account."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge_session",
+          "docs": [
+            "The session account.",
+            "- Uses PDA with BRIDGE_SESSION_SEED, the owner and the delegate for deterministic address",
+            "- Must be approved by the owner as SPL delegate of the token accounts to bridge from"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the session account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "pubkey"
+        },
+        {
+          "name": "budget",
+          "type": "u64"
+        },
+        {
+          "name": "expires_at",
+          "type": "i64"
+        },
+        {
+          "name": "allowed_remote_tokens",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          }
+        }
       ]
     },
     {
-      "name": "ProveBuffer",
+      "name": "crosschain_mint",
+      "docs": [
+        "Mints wrapped tokens on behalf of the Base Bridge, the Solana counterpart of the ERC-7802",
+        "`crosschainMint` function. Only reachable through `relay_message` relaying a message sent",
+        "by the Base Bridge, whose bridge CPI authority must sign.",
+        "Emits `CrosschainMint`, as do wrapped token transfers from Base, while wrapped tokens",
+        "bridged back to Base emit `CrosschainBurn`.",
+        "",
+        "# Arguments",
+        "* `ctx`    - The context containing the bridge CPI authority, the wrapped mint and the",
+        "recipient token account",
+        "* `amount` - The amount of wrapped tokens to mint"
+      ],
       "discriminator": [
-        78,
-        160,
-        227,
-        163,
-        97,
-        140,
-        40,
-        149
+        1,
+        24,
+        112,
+        214,
+        96,
+        100,
+        3,
+        12
+      ],
+      "accounts": [
+        {
+          "name": "bridge_cpi_authority",
+          "docs": [
+            "The bridge CPI authority of the Base Bridge, signing when `relay_message` relays a message",
+            "sent by the Base Bridge."
+          ],
+          "signer": true
+        },
+        {
+          "name": "address_book",
+          "docs": [
+            "The address book holding the Base Bridge address."
+          ]
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "The wrapped token mint, which is its own mint authority."
+          ],
+          "writable": true
+        },
+        {
+          "name": "to_token_account",
+          "docs": [
+            "The token account credited with the minted tokens."
+          ],
+          "writable": true
+        },
+        {
+          "name": "token_program",
+          "docs": [
+            "Token2022 program used for minting the wrapped tokens."
+          ]
+        },
+        {
+          "name": "supply_cap",
+          "docs": [
+            "The max supply of the wrapped token, enforced if initialized."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
       ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 12000,
-      "name": "BridgePaused",
-      "msg": "Bridge is currently paused"
-    },
-    {
-      "code": 12001,
-      "name": "IncorrectBridgeProgram",
-      "msg": "Incorrect bridge program"
-    },
-    {
-      "code": 12002,
-      "name": "IncorrectGasFeeReceiver",
-      "msg": "Incorrect gas fee receiver"
     },
     {
-      "code": 12100,
-      "name": "UnauthorizedInitialization",
-      "msg": "Only the upgrade authority can initialize the bridge"
+      "name": "deactivate_oracle_emergency",
+      "docs": [
+        "Deactivate the oracle emergency quorum before it expires",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian"
+      ],
+      "discriminator": [
+        27,
+        223,
+        171,
+        253,
+        157,
+        181,
+        48,
+        100
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": []
     },
     {
-      "code": 12101,
-      "name": "UnauthorizedConfigUpdate",
-      "msg": "Unauthorized to update configuration"
+      "name": "derive_cpi_authority",
+      "docs": [
+        "Returns the bridge CPI authority of a Base sender, i.e. the PDA signing the instructions",
+        "relayed from its messages, letting target programs pre-authorize it.",
+        "",
+        "# Arguments",
+        "* `ctx`    - The context; no accounts are required",
+        "* `sender` - The 20-byte address of the Base contract sending messages"
+      ],
+      "discriminator": [
+        45,
+        177,
+        142,
+        97,
+        182,
+        30,
+        61,
+        247
+      ],
+      "accounts": [
+        {
+          "name": "program",
+          "docs": [
+            "The bridge program"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "sender",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        }
+      ],
+      "returns": "pubkey"
     },
     {
-      "code": 12200,
-      "name": "BufferUnauthorizedClose",
-      "msg": "Only the owner can close this buffer"
+      "name": "emergency_withdraw_sol",
+      "docs": [
+        "Withdraws the SOL locked by an outgoing message not relayed to Base back to its sender,",
+        "once the emergency exit timelock of a paused bridge elapsed. Closes the message.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the outgoing message, its sender and the SOL vault"
+      ],
+      "discriminator": [
+        219,
+        156,
+        123,
+        176,
+        91,
+        105,
+        30,
+        160
+      ],
+      "accounts": [
+        {
+          "name": "sender",
+          "docs": [
+            "The sender of the outgoing message, receiving the withdrawn SOL and the message rent.",
+            "Pays for the returned transfer marker."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The main bridge state account, which must be paused"
+          ]
+        },
+        {
+          "name": "emergency_exit",
+          "docs": [
+            "The emergency exit enabled by the guardian"
+          ]
+        },
+        {
+          "name": "outgoing_message",
+          "docs": [
+            "The outgoing message whose SOL is withdrawn, closed by this instruction"
+          ],
+          "writable": true
+        },
+        {
+          "name": "relayed_nonces",
+          "docs": [
+            "The relayed nonces registry covering the outgoing message nonce, possibly not created yet.",
+            ""
+          ]
+        },
+        {
+          "name": "returned_transfer",
+          "docs": [
+            "Marker shared with `ReturnTransfer` messages so that the transfer of the outgoing message",
+            "is returned to Solana at most once."
+          ],
+          "writable": true
+        },
+        {
+          "name": "sol_vault_state",
+          "docs": [
+            "The SOL vault state recording the vault bump"
+          ]
+        },
+        {
+          "name": "sol_vault",
+          "docs": [
+            "The SOL vault holding the locked SOL.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "token_deposits",
+          "docs": [
+            "Accounting for the amount locked in the SOL vault"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for the SOL transfer and the marker creation"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "emergency_withdraw_spl",
+      "docs": [
+        "Withdraws the SPL tokens locked by an outgoing message not relayed to Base back to its",
+        "sender, once the emergency exit timelock of a paused bridge elapsed. Closes the message.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the outgoing message, its sender and the token vault"
+      ],
+      "discriminator": [
+        113,
+        252,
+        205,
+        153,
+        77,
+        124,
+        82,
+        31
+      ],
+      "accounts": [
+        {
+          "name": "sender",
+          "docs": [
+            "The sender of the outgoing message, receiving the message rent.",
+            "Pays for the returned transfer marker."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The main bridge state account, which must be paused"
+          ]
+        },
+        {
+          "name": "emergency_exit",
+          "docs": [
+            "The emergency exit enabled by the guardian"
+          ]
+        },
+        {
+          "name": "outgoing_message",
+          "docs": [
+            "The outgoing message whose tokens are withdrawn, closed by this instruction"
+          ],
+          "writable": true
+        },
+        {
+          "name": "relayed_nonces",
+          "docs": [
+            "The relayed nonces registry covering the outgoing message nonce, possibly not created yet.",
+            ""
+          ]
+        },
+        {
+          "name": "returned_transfer",
+          "docs": [
+            "Marker shared with `ReturnTransfer` messages so that the transfer of the outgoing message",
+            "is returned to Solana at most once."
+          ],
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "The SPL mint locked by the outgoing message"
+          ]
+        },
+        {
+          "name": "token_vault",
+          "docs": [
+            "The token vault holding the locked tokens.",
+            "- PDA with TOKEN_VAULT_SEED, the mint and the remote token of the message, validated in",
+            "the handler"
+          ],
+          "writable": true
+        },
+        {
+          "name": "to_token_account",
+          "docs": [
+            "The sender token account receiving the withdrawn tokens"
+          ],
+          "writable": true
+        },
+        {
+          "name": "token_deposits",
+          "docs": [
+            "Accounting for the amount locked in the token vault"
+          ],
+          "writable": true
+        },
+        {
+          "name": "vault_decimals",
+          "docs": [
+            "Decimals of the Base token the vault is bridged to, scaling the message amount back to",
+            "the mint decimals.",
+            ""
+          ]
+        },
+        {
+          "name": "token_program",
+          "docs": [
+            "Token program of the mint"
+          ]
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for the marker creation"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "enable_emergency_exit",
+      "docs": [
+        "Enable the emergency exit of a paused bridge, opening withdrawals of unrelayed deposits",
+        "after `EMERGENCY_EXIT_TIMELOCK_SECONDS`",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the emergency exit, bridge account and guardian"
+      ],
+      "discriminator": [
+        10,
+        76,
+        250,
+        43,
+        190,
+        163,
+        62,
+        42
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to enable the emergency exit.",
+            "Pays for the emergency exit creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the guardian, which must be paused"
+          ]
+        },
+        {
+          "name": "emergency_exit",
+          "docs": [
+            "The emergency exit account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the emergency exit account"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "execute_insurance_payout",
+      "docs": [
+        "Permissionlessly pays out the insurance payout requested by the guardian, once",
+        "`INSURANCE_PAYOUT_TIMELOCK_SECONDS` have elapsed since the request.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the insurance fund and the payout recipient"
+      ],
+      "discriminator": [
+        217,
+        105,
+        57,
+        198,
+        179,
+        226,
+        72,
+        97
+      ],
+      "accounts": [
+        {
+          "name": "insurance_fund",
+          "docs": [
+            "The insurance fund holding the pending payout"
+          ],
+          "writable": true
+        },
+        {
+          "name": "recipient",
+          "docs": [
+            "The recipient of the pending payout."
+          ],
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "expire_call_buffer",
+      "docs": [
+        "Permissionlessly closes a call buffer that has not been modified for longer than the",
+        "configured TTL. Most of the rent is returned to the owner and a bounty is paid to the caller.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the caller, the call buffer to expire and its owner"
+      ],
+      "discriminator": [
+        52,
+        71,
+        212,
+        109,
+        123,
+        63,
+        136,
+        228
+      ],
+      "accounts": [
+        {
+          "name": "caller",
+          "docs": [
+            "The account expiring the buffer. Receives `expire_bounty_bps` of the buffer rent."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "owner",
+          "docs": [
+            "The owner of the call buffer, receiving the remaining rent.",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing the buffer expiry configuration"
+          ]
+        },
+        {
+          "name": "call_buffer",
+          "docs": [
+            "The call buffer account to expire"
+          ],
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "extend_prove_buffer",
+      "docs": [
+        "Grows a prove buffer beyond the capacity it was initialized with, by at most 10 KiB per",
+        "call. Only the owner of the prove buffer can extend it and pays the added rent.",
+        "",
+        "# Arguments",
+        "* `ctx`            - The context containing the prove buffer account (owned by signer)",
+        "* `additional_len` - Number of bytes to add to the buffer account"
+      ],
+      "discriminator": [
+        118,
+        6,
+        185,
+        25,
+        111,
+        96,
+        10,
+        159
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "Owner authorized to modify the buffer; pays the rent of the added capacity"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "prove_buffer",
+          "docs": [
+            "Prove buffer account to grow"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "additional_len",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "get_environment",
+      "docs": [
+        "Returns the environment (localnet, devnet-alpha, devnet-prod or mainnet) the program was",
+        "compiled for, letting clients detect mixed deployments.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context; no accounts are required"
+      ],
+      "discriminator": [
+        234,
+        169,
+        115,
+        3,
+        52,
+        113,
+        69,
+        198
+      ],
+      "accounts": [
+        {
+          "name": "program",
+          "docs": [
+            "The bridge program"
+          ]
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "Environment"
+        }
+      }
+    },
+    {
+      "name": "get_oracle_health",
+      "docs": [
+        "Returns the last time each Base oracle signer signed a registered output root and whether",
+        "enough of them signed within `max_age_seconds` to meet the threshold, letting monitoring",
+        "detect a degrading quorum before output roots stop being registered.",
+        "",
+        "# Arguments",
+        "* `ctx`             - The context containing the bridge account",
+        "* `max_age_seconds` - Maximum age of a heartbeat for its signer to be considered live"
+      ],
+      "discriminator": [
+        248,
+        80,
+        2,
+        228,
+        55,
+        96,
+        102,
+        32
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the Base oracle signers and their heartbeats"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "max_age_seconds",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "OracleHealthReport"
+        }
+      }
+    },
+    {
+      "name": "get_version",
+      "docs": [
+        "Returns the program version and a bitmask of the capabilities it supports, letting SDKs",
+        "branch on features instead of guessing from the program ID.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context; no accounts are required"
+      ],
+      "discriminator": [
+        168,
+        85,
+        244,
+        45,
+        81,
+        56,
+        130,
+        50
+      ],
+      "accounts": [
+        {
+          "name": "program",
+          "docs": [
+            "The bridge program"
+          ]
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": {
+          "name": "Version"
+        }
+      }
+    },
+    {
+      "name": "init_config_history",
+      "docs": [
+        "Permissionlessly initializes the configuration history log.",
+        "Must be called once before any configuration setter can be called.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the payer and the config history account"
+      ],
+      "discriminator": [
+        148,
+        127,
+        241,
+        17,
+        70,
+        93,
+        161,
+        148
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "The account that pays for the config history account creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "The config history account.",
+            "Can only be created once."
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the config history account."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "init_sol_vault",
+      "docs": [
+        "Permissionlessly initializes the SOL vault and records its bump.",
+        "Must be called once before any SOL can be bridged.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the payer, the SOL vault and its state account"
+      ],
+      "discriminator": [
+        78,
+        34,
+        100,
+        179,
+        84,
+        60,
+        13,
+        232
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "The account that pays for the vault rent and the state account creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "sol_vault",
+          "docs": [
+            "The SOL vault that holds the locked SOL.",
+            "- Funded up to rent exemption so it always exists once initialized",
+            ""
+          ],
+          "writable": true
+        },
+        {
+          "name": "sol_vault_state",
+          "docs": [
+            "The SOL vault state recording the vault bump.",
+            "Can only be created once."
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for funding the vault and creating the state account."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initialize",
+      "docs": [
+        "Initializes the bridge program with required state accounts.",
+        "This function sets up the initial bridge configuration and must be called once during deployment.",
+        "",
+        "# Arguments",
+        "* `ctx`      - The context containing all accounts needed for initialization, including the guardian signer",
+        "* `guardian` - The guardian account that will have administrative authority over the bridge",
+        "* `cfg`      - All the configuration parameters needed to initialize the bridge"
+      ],
+      "discriminator": [
+        175,
+        175,
+        109,
+        31,
+        13,
+        152,
+        155,
+        237
+      ],
+      "accounts": [
+        {
+          "name": "upgrade_authority",
+          "docs": [
+            "The upgrade authority that is authorized to initialize the bridge.",
+            "This ensures only the program deployer can set the initial configuration."
+          ],
+          "signer": true
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "The account that pays for the transaction and bridge account creation.",
+            "Must be mutable to deduct lamports for account rent.",
+            "Can be different from the upgrade_authority."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge state account being initialized.",
+            "- Uses PDA with BRIDGE_SEED for deterministic address",
+            "- Payer funds the account creation",
+            "- Space allocated for bridge state (DISCRIMINATOR_LEN + Bridge::INIT_SPACE)"
+          ],
+          "writable": true
+        },
+        {
+          "name": "program_data",
+          "docs": [
+            "Program data account containing the upgrade authority.",
+            "Validates that the signer is indeed the upgrade authority."
+          ]
+        },
+        {
+          "name": "program",
+          "docs": [
+            "The bridge program itself.",
+            "Validates that program_data is the correct ProgramData account for this program."
+          ]
+        },
+        {
+          "name": "gas_fee_receiver",
+          "docs": [
+            "The gas fee receiver configured in `cfg.gas_config`. Must be a system account."
+          ]
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating new accounts.",
+            "Used internally by Anchor for account initialization."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "guardian",
+          "type": "pubkey"
+        },
+        {
+          "name": "cfg",
+          "type": {
+            "defined": {
+              "name": "Config"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "initialize_call_buffer",
+      "docs": [
+        "Initializes a call buffer account that can store large call data.",
+        "This account can be used to build up call data over multiple transactions",
+        "before using it in a bridge operation.",
+        "",
+        "# Arguments",
+        "* `ctx`          - The context containing accounts for initialization (including bridge config)",
+        "* `ty`           - The type of call (Call, DelegateCall, Create, Create2)",
+        "* `to`           - The target contract address on Base",
+        "* `value`        - The amount of ETH to send with the call (in wei)",
+        "* `initial_data` - Initial call data to store",
+        "* `max_data_len` - Maximum total length of data that will be stored"
+      ],
+      "discriminator": [
+        85,
+        68,
+        100,
+        234,
+        255,
+        226,
+        95,
+        72
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "The account that pays for the transaction and call buffer account creation.",
+            "This signer becomes the `CallBuffer.owner`."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration including max buffer size"
+          ]
+        },
+        {
+          "name": "call_buffer",
+          "docs": [
+            "The call buffer account being initialized.",
+            "Space is allocated for up to `max_data_len` bytes of `data` (plus the Vec length prefix).",
+            "The bridge configuration enforces an upper bound via `buffer_config.max_call_buffer_size`.",
+            "As a non-PDA `init` account it must sign the transaction, so no one else can create a",
+            "buffer at its address, and accounts already owned by this program are rejected. Lamports",
+            "sent to the address beforehand are kept and do not prevent initialization."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating new accounts"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "ty",
+          "type": {
+            "defined": {
+              "name": "CallType"
+            }
+          }
+        },
+        {
+          "name": "to",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        },
+        {
+          "name": "value",
+          "type": "u128"
+        },
+        {
+          "name": "initial_data",
+          "type": "bytes"
+        },
+        {
+          "name": "max_data_len",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "initialize_devnet_defaults",
+      "docs": [
+        "Bootstraps a devnet bridge in a single transaction: initializes the bridge with well-known",
+        "devnet defaults, the SOL vault, the config history and the address book, so that no",
+        "partially configured state is left behind. The payer receives the gas fees. Test wrapped",
+        "tokens can be created with `wrap_token` in the same transaction. Not available in mainnet",
+        "builds.",
+        "",
+        "# Arguments",
+        "* `ctx`                 - The context containing the upgrade authority, the payer and every",
+        "account being created",
+        "* `guardian`            - The guardian account that will have administrative authority over the bridge",
+        "* `base_oracle_signers` - The Base oracle signers, any one of which can attest output roots",
+        "* `base_bridge`         - The Base Bridge contract registered in the address book",
+        "* `remote_sol_address`  - The Base token representing SOL"
+      ],
+      "discriminator": [
+        239,
+        222,
+        145,
+        96,
+        204,
+        90,
+        35,
+        188
+      ],
+      "accounts": [
+        {
+          "name": "upgrade_authority",
+          "docs": [
+            "The upgrade authority that is authorized to initialize the bridge."
+          ],
+          "signer": true
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "The account that pays for every account creation.",
+            "Also configured as the gas fee receiver."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge state account being initialized."
+          ],
+          "writable": true
+        },
+        {
+          "name": "sol_vault",
+          "docs": [
+            "The SOL vault that holds the locked SOL."
+          ],
+          "writable": true
+        },
+        {
+          "name": "sol_vault_state",
+          "docs": [
+            "The SOL vault state recording the vault bump."
+          ],
+          "writable": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "The config history account."
+          ],
+          "writable": true
+        },
+        {
+          "name": "address_book",
+          "docs": [
+            "The address book, registering the Base Bridge and the SOL remote token."
+          ],
+          "writable": true
+        },
+        {
+          "name": "program_data",
+          "docs": [
+            "Program data account containing the upgrade authority."
+          ]
+        },
+        {
+          "name": "program",
+          "docs": [
+            "The bridge program itself.",
+            "Validates that program_data is the correct ProgramData account for this program."
+          ]
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating new accounts and funding the SOL vault."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "guardian",
+          "type": "pubkey"
+        },
+        {
+          "name": "base_oracle_signers",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          }
+        },
+        {
+          "name": "base_bridge",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        },
+        {
+          "name": "remote_sol_address",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "initialize_prove_buffer",
+      "docs": [
+        "Initializes a prove buffer account that can store large prove inputs.",
+        "This account can be used to build up serialized message data and MMR proof nodes",
+        "over multiple transactions before calling `prove_message_buffered`.",
+        "",
+        "# Arguments",
+        "* `ctx`           - The context containing accounts for initialization (payer, bridge, buffer)",
+        "* `max_data_len`  - Maximum total length of serialized `Message` data that will be stored",
+        "* `max_proof_len` - Maximum number of 32-byte MMR proof nodes that will be stored"
+      ],
+      "discriminator": [
+        20,
+        200,
+        91,
+        188,
+        138,
+        211,
+        253,
+        222
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Payer funds the buffer account creation"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "Bridge for pause checks (future use); also a consistent pattern like call buffers"
+          ]
+        },
+        {
+          "name": "prove_buffer",
+          "docs": [
+            "Prove buffer to be created with capacity sized by the provided max lengths"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "system_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "max_data_len",
+          "type": "u64"
+        },
+        {
+          "name": "max_proof_len",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "migrate_bridge_state",
+      "docs": [
+        "Migrates the bridge state account to the layout expected by the deployed program.",
+        "This function must be called by the upgrade authority after any program upgrade that",
+        "changes the `Bridge` account layout, and reallocs the account as needed.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account, upgrade authority and rent payer"
+      ],
+      "discriminator": [
+        196,
+        193,
+        143,
+        108,
+        71,
+        132,
+        75,
+        181
+      ],
+      "accounts": [
+        {
+          "name": "upgrade_authority",
+          "docs": [
+            "The upgrade authority that is authorized to migrate the bridge state."
+          ],
+          "signer": true
+        },
+        {
+          "name": "payer",
+          "docs": [
+            "The account that pays for the additional rent required by the new layout."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "`Account<Bridge>` because its data may still be in an older layout. The PDA and",
+            "owner are validated here and the layout is validated by the handler."
+          ],
+          "writable": true
+        },
+        {
+          "name": "program_data",
+          "docs": [
+            "Program data account containing the upgrade authority."
+          ]
+        },
+        {
+          "name": "program",
+          "docs": [
+            "The bridge program itself.",
+            "Validates that program_data is the correct ProgramData account for this program."
+          ]
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for topping up the bridge account rent."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pause_from_governance",
+      "docs": [
+        "Pauses the bridge from the Base governance contract",
+        "Only reachable through a message relayed from the Base governance contract",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge and the governance bridge CPI authority"
+      ],
+      "discriminator": [
+        54,
+        128,
+        175,
+        15,
+        180,
+        51,
+        43,
+        99
+      ],
+      "accounts": [
+        {
+          "name": "governance_cpi_authority",
+          "docs": [
+            "The bridge CPI authority of the Base governance contract"
+          ],
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "post_liability_root",
+      "docs": [
+        "Posts the oracle-attested Merkle root of the outstanding Base-side supply of the tokens",
+        "locked in the bridge vaults, against which `reconcile_vault` checks vault balances.",
+        "Authorization is enforced via EVM signatures from authorized Base oracles and partner",
+        "signers per configured thresholds; the Solana payer only funds account creation.",
+        "",
+        "# Arguments",
+        "* `ctx`               - The context containing the payer, the bridge and the liability root account",
+        "* `root`              - The Merkle root of the per-token liabilities",
+        "* `base_block_number` - The Base block number at which the liabilities were measured, newer than the current one",
+        "* `signatures`        - A list of ECDSA signatures from authorized oracles attesting to the root"
+      ],
+      "discriminator": [
+        119,
+        146,
+        146,
+        161,
+        74,
+        173,
+        185,
+        103
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Payer funds the liability root account creation. Authorization is enforced via oracle EVM",
+            "signatures."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The main bridge state account providing the oracle configuration."
+          ]
+        },
+        {
+          "name": "liability_root",
+          "docs": [
+            "The liability root account.",
+            "- Uses PDA with LIABILITY_ROOT_SEED for deterministic address",
+            "- Created on first use and overwritten by every newer commitment"
+          ],
+          "writable": true
+        },
+        {
+          "name": "partner_config",
+          "docs": [
+            "Partner `Config` account (PDA with seed \"config\") owned by partner program.",
+            "Unchecked to avoid Anchor pre-handler owner checks; PDA address is validated in the handler."
+          ]
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the liability root account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "root",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "base_block_number",
+          "type": "u64"
+        },
+        {
+          "name": "signatures",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                65
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "prove_message",
+      "docs": [
+        "Proves that a cross-chain message exists in the Base Bridge contract using an MMR proof.",
+        "This function verifies the message was included in a previously registered output root",
+        "and stores the proven message state for later relay execution.",
+        "`ReturnTransfer` and `ConfirmWrappedToken` messages are rejected unless sent by the Base",
+        "Bridge recorded in the address book.",
+        "",
+        "# Arguments",
+        "* `ctx`          - The transaction context",
+        "* `nonce`        - Unique identifier for the cross-chain message",
+        "* `sender`       - The 20-byte Ethereum address that sent the message on Base",
+        "* `data`         - The message payload/calldata to be executed on Solana",
+        "* `proof`        - MMR proof demonstrating message inclusion in the output root",
+        "* `message_hash` - The 32-byte hash of the message for verification"
+      ],
+      "discriminator": [
+        172,
+        66,
+        78,
+        136,
+        158,
+        187,
+        47,
+        115
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "The account that pays for the transaction and incoming message account creation.",
+            "Must be mutable to deduct lamports for account rent.",
+            "Recorded as the prover of the message, refunded the rent freed when it is relayed."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "output_root",
+          "docs": [
+            "The output root account containing the MMR root from Base.",
+            "Used to verify that the message proof is valid against the committed state.",
+            "Any root registered for the message's Base block or a later one can be used, with a proof",
+            "built against its `total_leaf_count`."
+          ]
+        },
+        {
+          "name": "message",
+          "docs": [
+            "The incoming message account being created to store the proven message.",
+            "- Uses PDA with INCOMING_MESSAGE_SEED and message hash for deterministic address",
+            "- Payer funds the account creation",
+            "- Space dynamically allocated based on message data length",
+            "- Once created, this account can be used by relay instructions to execute the message"
+          ],
+          "writable": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The main bridge state account used to check pause status",
+            "- Uses PDA with BRIDGE_SEED for deterministic address"
+          ]
+        },
+        {
+          "name": "address_book",
+          "docs": [
+            "The address book holding the canonical Base Bridge address.",
+            "Required for `ReturnTransfer` messages, which are only accepted from the Base Bridge."
+          ],
+          "optional": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating new accounts.",
+            "Used internally by Anchor for account initialization."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u64"
+        },
+        {
+          "name": "sender",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        },
+        {
+          "name": "data",
+          "type": "bytes"
+        },
+        {
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        },
+        {
+          "name": "message_hash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "prove_message_buffered",
+      "docs": [
+        "Proves that a cross-chain message exists using buffered data and proof.",
+        "This function reads the serialized message and MMR proof from a `ProveBuffer`,",
+        "verifies inclusion against a previously registered output root, and stores the",
+        "proven message for later relay execution. The prove buffer is closed on success.",
+        "`ReturnTransfer` and `ConfirmWrappedToken` messages are rejected unless sent by the Base",
+        "Bridge recorded in the address book.",
+        "",
+        "# Arguments",
+        "* `ctx`          - The context containing accounts for verification and message creation",
+        "* `nonce`        - Unique identifier for the cross-chain message",
+        "* `sender`       - The 20-byte Ethereum address that sent the message on Base",
+        "* `message_hash` - The 32-byte hash of the message for verification"
+      ],
+      "discriminator": [
+        211,
+        48,
+        193,
+        183,
+        2,
+        51,
+        102,
+        159
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Payer funds the IncomingMessage account"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "output_root",
+          "docs": [
+            "Output root to verify the proof against"
+          ]
+        },
+        {
+          "name": "message",
+          "docs": [
+            "The incoming message account created if proof verifies"
+          ],
+          "writable": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "Bridge for pause check"
+          ]
+        },
+        {
+          "name": "address_book",
+          "docs": [
+            "Address book holding the canonical Base Bridge, required for `ReturnTransfer` messages"
+          ],
+          "optional": true
+        },
+        {
+          "name": "owner",
+          "docs": [
+            "Owner receives rent when buffer is closed"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "prove_buffer",
+          "docs": [
+            "Prove buffer containing data and proof; closed on success"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program"
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u64"
+        },
+        {
+          "name": "sender",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        },
+        {
+          "name": "message_hash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "prove_message_chunked",
+      "docs": [
+        "Proves a cross-chain message from a `ProveBuffer` like `prove_message_buffered`, storing",
+        "its instructions in `ProvenChunk` accounts instead of the incoming message. This delivers",
+        "messages whose instructions exceed a single account or the per-message instruction limits.",
+        "The chunk PDAs are passed as remaining accounts, in index order.",
+        "",
+        "# Arguments",
+        "* `ctx`          - The context containing accounts for verification and message creation",
+        "* `nonce`        - Unique identifier for the cross-chain message",
+        "* `sender`       - The 20-byte Ethereum address that sent the message on Base",
+        "* `message_hash` - The 32-byte hash of the message for verification"
+      ],
+      "discriminator": [
+        70,
+        150,
+        162,
+        72,
+        135,
+        210,
+        161,
+        218
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Payer funds the IncomingMessage and ProvenChunk accounts"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "output_root",
+          "docs": [
+            "Output root to verify the proof against"
+          ]
+        },
+        {
+          "name": "message",
+          "docs": [
+            "The incoming message account created if proof verifies, without its instructions.",
+            "Unchecked as its size is only known once the message is split; created in the handler."
+          ],
+          "writable": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "Bridge for pause check"
+          ]
+        },
+        {
+          "name": "address_book",
+          "docs": [
+            "Address book holding the canonical Base Bridge, required for `ReturnTransfer` messages"
+          ],
+          "optional": true
+        },
+        {
+          "name": "owner",
+          "docs": [
+            "Owner receives rent when buffer is closed"
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "prove_buffer",
+          "docs": [
+            "Prove buffer containing data and proof; closed on success"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the message and chunk accounts"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u64"
+        },
+        {
+          "name": "sender",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        },
+        {
+          "name": "message_hash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "reconcile_vault",
+      "docs": [
+        "Permissionlessly reconciles a bridge vault against its liability proven in the",
+        "oracle-posted liability root, emitting a `VaultReconciled` event and raising the",
+        "solvency alert when the vault balance does not cover the liability.",
+        "",
+        "# Arguments",
+        "* `ctx`       - The context containing the vault, its accounting and the liability root",
+        "* `liability` - The outstanding Base-side supply of the vault token, in local units",
+        "* `proof`     - Merkle proof of the liability leaf against the liability root"
+      ],
+      "discriminator": [
+        155,
+        3,
+        193,
+        142,
+        152,
+        183,
+        87,
+        196
+      ],
+      "accounts": [
+        {
+          "name": "vault",
+          "docs": [
+            "The reconciled vault: either the SOL vault or an SPL token vault."
+          ]
+        },
+        {
+          "name": "token_deposits",
+          "docs": [
+            "The accounting account of the reconciled vault, only used to authenticate it."
+          ]
+        },
+        {
+          "name": "liability_root",
+          "docs": [
+            "The oracle-posted liability commitment, flagged on shortfall."
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "liability",
+          "type": "u64"
+        },
+        {
+          "name": "proof",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "record_relay_execution",
+      "docs": [
+        "Records the relayer that executed an outgoing message on Base, so that relay tips escrowed",
+        "for the message can be settled to it. The record of a nonce is created once and never",
+        "changes.",
+        "Authorization is enforced via EVM signatures from authorized Base oracles and partner",
+        "signers per configured thresholds; the Solana payer only funds account creation.",
+        "",
+        "# Arguments",
+        "* `ctx`        - The context containing the payer, the bridge and the record account for `nonce`",
+        "* `nonce`      - The outgoing message nonce executed on Base",
+        "* `relayer`    - The Solana account of the relayer that executed the message",
+        "* `signatures` - A list of ECDSA signatures from authorized oracles attesting to the relayer"
+      ],
+      "discriminator": [
+        195,
+        100,
+        156,
+        188,
+        128,
+        85,
+        30,
+        122
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Payer funds the record account creation. Authorization is enforced via oracle EVM",
+            "signatures."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The main bridge state account providing the oracle configuration and the next nonce."
+          ]
+        },
+        {
+          "name": "relay_execution",
+          "docs": [
+            "The record of the relayer that executed the message.",
+            "- Uses PDA with RELAY_EXECUTION_SEED and `nonce` for deterministic address",
+            "- Created once, so the executing relayer of a nonce can never be changed"
+          ],
+          "writable": true
+        },
+        {
+          "name": "partner_config",
+          "docs": [
+            "Partner `Config` account (PDA with seed \"config\") owned by partner program.",
+            "Unchecked to avoid Anchor pre-handler owner checks; PDA address is validated in the handler."
+          ]
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the record account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "nonce",
+          "type": "u64"
+        },
+        {
+          "name": "relayer",
+          "type": "pubkey"
+        },
+        {
+          "name": "signatures",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                65
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "register_handler",
+      "docs": [
+        "Registers the handler invoked with the payload of the `CallHandler` messages of `sender`,",
+        "replacing any previous registration. Only reachable through `relay_message` relaying a",
+        "message sent by `sender`, whose bridge CPI authority must sign and pays for the handler",
+        "registration account on first use. The bridge and the privileged programs denied to relayed",
+        "messages cannot be registered. Emits `HandlerRegistered`.",
+        "",
+        "# Arguments",
+        "* `ctx`           - The context containing the bridge CPI authority and the handler",
+        "registration account",
+        "* `sender`        - The Base address registering the handler",
+        "* `program_id`    - The program invoked with the payload of the messages of `sender`",
+        "* `discriminator` - The discriminator of the handler instruction"
+      ],
+      "discriminator": [
+        16,
+        136,
+        86,
+        127,
+        98,
+        105,
+        181,
+        81
+      ],
+      "accounts": [
+        {
+          "name": "bridge_cpi_authority",
+          "docs": [
+            "The bridge CPI authority of `sender`, signing when `relay_message` relays a message sent by",
+            "`sender`. Pays for the handler registration creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "handler_registration",
+          "docs": [
+            "The handler registration of `sender`.",
+            "- Uses PDA with HANDLER_REGISTRATION_SEED and the sender for deterministic address",
+            "- Created on first use, overwritten by later registrations"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the handler registration account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "sender",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        },
+        {
+          "name": "program_id",
+          "type": "pubkey"
+        },
+        {
+          "name": "discriminator",
+          "type": {
+            "array": [
+              "u8",
+              8
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "register_output_root",
+      "docs": [
+        "Registers an output root from Base to enable message verification.",
+        "This function stores the MMR root of Base message state at a specific block number,",
+        "which is required before any messages from that block can be proven and relayed.",
+        "Authorization is enforced via EVM signatures from authorized Base oracles and partner",
+        "signers per configured thresholds; the Solana payer only funds account creation.",
+        "",
+        "# Arguments",
+        "* `ctx`               - The context containing accounts for storing the output root (payer signs for fees; authorization is provided via EVM signatures)",
+        "* `output_root`       - The 32-byte MMR root of Base messages for the given block",
+        "* `base_block_number` - The Base block number this output root corresponds to",
+        "* `total_leaf_count`  - The total number of leaves in the MMR with this root",
+        "* `signatures`        - A list of ECDSA signatures from authorized oracles attesting to the output root,",
+        "or empty when the signatures are verified by Secp256k1 program instructions",
+        "preceding this one in the transaction (requires `instructions_sysvar`)"
+      ],
+      "discriminator": [
+        215,
+        66,
+        12,
+        154,
+        4,
+        123,
+        196,
+        66
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Payer funds the account creation. Any account may pay: the payer is not pinned to an",
+            "oracle key, so that oracle keys can be rotated through the signer configuration alone.",
+            "Authorization is enforced via oracle EVM signature."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "root",
+          "docs": [
+            "The output root account being created to store the Base MMR root and total leaf count.",
+            "- Uses PDA with OUTPUT_ROOT_SEED and base_block_number for deterministic address",
+            "- Payer funds the account creation (authorization is enforced via EVM signatures)",
+            "- Space allocated for output root state (DISCRIMINATOR_LEN + OutputRoot::INIT_SPACE)",
+            "- Each output root corresponds to a specific Base block number"
+          ],
+          "writable": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The main bridge state account that tracks the latest registered Base block number.",
+            "- Uses PDA with BRIDGE_SEED",
+            "- Must be mutable to update the base_block_number field",
+            "- Enforces registrations are monotonic and aligned to the configured interval"
+          ],
+          "writable": true
+        },
+        {
+          "name": "partner_config",
+          "docs": [
+            "Partner `Config` account (PDA with seed \"config\") owned by partner program.",
+            "Unchecked to avoid Anchor pre-handler owner checks; PDA address is validated in the handler."
+          ]
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating new accounts.",
+            "Used internally by Anchor for output root account initialization."
+          ]
+        },
+        {
+          "name": "instructions_sysvar",
+          "docs": [
+            "Instructions sysvar, required when no `signatures` are passed so that the oracle signatures",
+            "verified by the preceding Secp256k1 program instructions can be introspected."
+          ],
+          "optional": true
+        },
+        {
+          "name": "maintenance_budget",
+          "docs": [
+            "The maintenance budget reimbursing the payer, possibly not created yet."
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "output_root",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "base_block_number",
+          "type": "u64"
+        },
+        {
+          "name": "total_leaf_count",
+          "type": "u64"
+        },
+        {
+          "name": "signatures",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                65
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "register_output_roots_batch",
+      "docs": [
+        "Registers several output roots from Base, covered by a single set of oracle signatures.",
+        "Lets the oracle register multiple block intervals in one transaction (e.g. after downtime).",
+        "The `OutputRoot` PDAs to create are passed as remaining accounts, in the order of `entries`.",
+        "",
+        "# Arguments",
+        "* `ctx`        - The context containing accounts for storing the output roots (payer signs for fees; authorization is provided via EVM signatures)",
+        "* `entries`    - The output roots to register, in strictly increasing Base block number order",
+        "* `signatures` - A list of ECDSA signatures from authorized oracles attesting to the whole batch"
+      ],
+      "discriminator": [
+        75,
+        145,
+        129,
+        168,
+        219,
+        158,
+        241,
+        47
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Payer funds the accounts creation. Authorization is enforced via oracle EVM signature."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The main bridge state account that tracks the latest registered Base block number.",
+            "- Uses PDA with BRIDGE_SEED",
+            "- Must be mutable to update the base_block_number field",
+            "- Enforces registrations are monotonic and aligned to the configured interval"
+          ],
+          "writable": true
+        },
+        {
+          "name": "partner_config",
+          "docs": [
+            "Partner `Config` account (PDA with seed \"config\") owned by partner program.",
+            "Unchecked to avoid Anchor pre-handler owner checks; PDA address is validated in the handler."
+          ]
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the output root accounts."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "entries",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "OutputRootEntry"
+              }
+            }
+          }
+        },
+        {
+          "name": "signatures",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                65
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "relay_message",
+      "docs": [
+        "Executes a previously proven cross-chain message on Solana.",
+        "This function takes a message that has been proven via `prove_message` and executes",
+        "its payload using a bridge CPI authority derived from the message sender.",
+        "`CallWithSigners` messages are additionally signed by PDAs derived from that authority",
+        "with the custom seeds declared in the message.",
+        "`WithComputeBudget` messages fail fast with `InsufficientComputeBudget` when fewer compute",
+        "units than they declare remain, letting relayers retry with a higher compute unit limit.",
+        "`WithRelayFee` messages pay their prepaid relay fee from the relay fee vault to the relayer.",
+        "`WithLamportAllowance` messages are lent their lamport allowance from the operational vault",
+        "for the duration of their execution; the lamports left unspent are returned to the vault.",
+        "Messages proven with `prove_message_chunked` take their `ProvenChunk` accounts as the last",
+        "remaining accounts, in index order; large chunked messages may require a larger heap frame.",
+        "Messages proven against an output root revision superseded since are rejected.",
+        "Once executed, the message payload is dropped and the freed rent refunded to its prover.",
+        "Transfers exceeding the outflow threshold of their token pause the bridge instead, holding",
+        "the message until the guardian approves its outflow with `approve_outflow`.",
+        "Transfers exceeding the finalization threshold of their token can only be relayed once the",
+        "finalization delay elapsed since they were proven, and never if the guardian vetoed them.",
+        "`ConfirmWrappedToken` messages record the `WrappedTokenConfirmation` of their mint once",
+        "the echoed Base token metadata matches it, from the address book, confirmation PDA, mint,",
+        "payer and system program remaining accounts.",
+        "`CallHandler` messages invoke the handler registered by the sender with `register_handler`,",
+        "passing the `HandlerCall` arguments after the handler discriminator. Their remaining",
+        "accounts are the handler registration, the handler program, then the handler accounts,",
+        "starting with the bridge CPI authority of the sender which signs the handler instruction.",
+        "",
+        "# Arguments",
+        "* `ctx` - The transaction context"
+      ],
+      "discriminator": [
+        187,
+        90,
+        182,
+        138,
+        51,
+        248,
+        175,
+        98
+      ],
+      "accounts": [
+        {
+          "name": "message",
+          "docs": [
+            "The incoming message account containing the cross-chain message to be executed.",
+            "- Contains either a pure call message or a transfer message with additional instructions",
+            "- Must be mutable to mark the message as executed after processing",
+            "- Prevents replay attacks by tracking execution status"
+          ],
+          "writable": true
+        },
+        {
+          "name": "output_root",
+          "docs": [
+            "The output root the message was proven against.",
+            "Messages proven against a revision superseded by `supersede_output_root` cannot be relayed."
+          ]
+        },
+        {
+          "name": "prover",
+          "docs": [
+            "The prover that paid the rent of the message account, refunded the rent freed once the",
+            "message is executed."
+          ],
+          "writable": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The main bridge state account used to check pause status",
+            "- Uses PDA with BRIDGE_SEED for deterministic address",
+            "- Must be mutable to track outflows and pause the bridge when they are anomalous"
+          ],
+          "writable": true
+        },
+        {
+          "name": "relay_deny_list",
+          "docs": [
+            "Programs denied by the guardian on top of the built-in `DENIED_RELAY_PROGRAMS`."
+          ]
+        },
+        {
+          "name": "relay_fee_vault",
+          "docs": [
+            "The relay fee vault paying the relay fee of `WithRelayFee` messages.",
+            "Only required to relay `WithRelayFee` messages."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "relayer",
+          "docs": [
+            "The relayer credited with the relay fee of `WithRelayFee` messages.",
+            "Only required to relay `WithRelayFee` messages."
+          ],
+          "writable": true,
+          "signer": true,
+          "optional": true
+        },
+        {
+          "name": "operational_vault",
+          "docs": [
+            "The operational vault lending the lamport allowance of `WithLamportAllowance` messages.",
+            "Only required to relay `WithLamportAllowance` messages."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "bridge_cpi_authority",
+          "docs": [
+            "The bridge CPI authority of the message sender, lent the lamport allowance of",
+            "`WithLamportAllowance` messages. Only required to relay `WithLamportAllowance` messages."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program returning the unspent lamport allowance to the operational vault.",
+            "Only required to relay `WithLamportAllowance` messages."
+          ],
+          "optional": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "report_equivocation",
+      "docs": [
+        "Permissionlessly reports Base oracle signers that attested two conflicting output roots",
+        "for the same Base block. Records their signatures over both roots in an",
+        "`EquivocationEvidence` PDA and removes them from the Base oracle signers pending guardian",
+        "review; the threshold is clamped to the remaining signers.",
+        "",
+        "# Arguments",
+        "* `ctx`               - The context containing the reporter paying for the evidence, the bridge,",
+        "the evidence account and the config history",
+        "* `first`             - The first attested output root",
+        "* `first_signatures`  - Oracle signatures over `first`",
+        "* `second`            - The conflicting output root attested for the same Base block",
+        "* `second_signatures` - Oracle signatures over `second`"
+      ],
+      "discriminator": [
+        140,
+        250,
+        136,
+        199,
+        9,
+        49,
+        41,
+        16
+      ],
+      "accounts": [
+        {
+          "name": "reporter",
+          "docs": [
+            "The reporter, paying for the evidence account."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The main bridge state account whose Base oracle signers are disabled."
+          ],
+          "writable": true
+        },
+        {
+          "name": "evidence",
+          "docs": [
+            "The evidence of the equivocation.",
+            "- Uses PDA with EQUIVOCATION_EVIDENCE_SEED, the Base block number and the",
+            "`equivocation_hash` of the conflicting output roots",
+            "- Can only be recorded once per pair of conflicting output roots"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the change of the Base oracle configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the evidence account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "first",
+          "type": {
+            "defined": {
+              "name": "OutputRootEntry"
+            }
+          }
+        },
+        {
+          "name": "first_signatures",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                65
+              ]
+            }
+          }
+        },
+        {
+          "name": "second",
+          "type": {
+            "defined": {
+              "name": "OutputRootEntry"
+            }
+          }
+        },
+        {
+          "name": "second_signatures",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                65
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "request_insurance_payout",
+      "docs": [
+        "Request a payout from the insurance fund, executable after",
+        "`INSURANCE_PAYOUT_TIMELOCK_SECONDS`",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`       - The context containing the insurance fund, bridge account and guardian",
+        "* `recipient` - The account receiving the payout",
+        "* `amount`    - The lamports paid out"
+      ],
+      "discriminator": [
+        207,
+        233,
+        236,
+        9,
+        26,
+        22,
+        157,
+        161
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to manage the insurance payouts."
+          ],
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the guardian"
+          ]
+        },
+        {
+          "name": "insurance_fund",
+          "docs": [
+            "The insurance fund account"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "recipient",
+          "type": "pubkey"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "revoke_bridge_session",
+      "docs": [
+        "Revokes a bridge session, returning its rent to the owner.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the owner and the session account"
+      ],
+      "discriminator": [
+        65,
+        247,
+        109,
+        149,
+        94,
+        100,
+        82,
+        4
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "The owner of the session."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge_session",
+          "docs": [
+            "The revoked session account."
+          ],
+          "writable": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "scale_wrapped_token_amount",
+      "docs": [
+        "Returns the big-endian u256 amount credited on Base for `amount` of a wrapped token,",
+        "scaled by the `scaler_exponent` of its metadata. Fails when the scaled amount overflows.",
+        "",
+        "# Arguments",
+        "* `ctx`    - The context containing the wrapped token mint",
+        "* `amount` - Amount of wrapped tokens (in the token's smallest units)"
+      ],
+      "discriminator": [
+        116,
+        191,
+        53,
+        160,
+        202,
+        142,
+        182,
+        62
+      ],
+      "accounts": [
+        {
+          "name": "mint",
+          "docs": [
+            "The wrapped token mint whose metadata provides the scaler exponent.",
+            "Validated to be a wrapped token PDA of this program when reading its metadata."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ],
+      "returns": {
+        "array": [
+          "u8",
+          32
+        ]
+      }
+    },
+    {
+      "name": "set_address_book",
+      "docs": [
+        "Set the canonical Base addresses stored in the address book, creating it on first use",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`                  - The context containing the address book, bridge account and guardian",
+        "* `bridge`               - The Base Bridge contract address",
+        "* `relayer_orchestrator` - The Base RelayerOrchestrator contract address",
+        "* `tokens`               - The standard tokens and their Base addresses"
+      ],
+      "discriminator": [
+        104,
+        182,
+        87,
+        189,
+        19,
+        242,
+        165,
+        123
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to maintain the address book.",
+            "Pays for the address book creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the guardian"
+          ]
+        },
+        {
+          "name": "address_book",
+          "docs": [
+            "The address book account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the address book account"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "bridge",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        },
+        {
+          "name": "relayer_orchestrator",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        },
+        {
+          "name": "tokens",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "AddressBookToken"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "set_adjustment_denominator",
+      "docs": [
+        "Set the adjustment denominator for EIP-1559 pricing",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_denominator` - The new adjustment denominator"
+      ],
+      "discriminator": [
+        31,
+        91,
+        190,
+        63,
+        164,
+        7,
+        31,
+        150
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_denominator",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_base_governance",
+      "docs": [
+        "Designates the Base governance contract allowed to update the critical configuration",
+        "(the Base oracle signers and the pause) through relayed messages",
+        "Only the upgrade authority can call this function, until the governance contract enforces",
+        "its control with `set_governance_mode`",
+        "",
+        "# Arguments",
+        "* `ctx`        - The context containing the bridge and upgrade authority",
+        "* `governance` - The governance contract on Base"
+      ],
+      "discriminator": [
+        49,
+        63,
+        19,
+        144,
+        133,
+        10,
+        240,
+        239
+      ],
+      "accounts": [
+        {
+          "name": "upgrade_authority",
+          "docs": [
+            "The upgrade authority account"
+          ],
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "program_data"
+        },
+        {
+          "name": "program"
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "governance",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "set_block_interval_requirement",
+      "docs": [
+        "Set the block interval requirement for Protocol Config",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_interval` - The new block interval requirement value"
+      ],
+      "discriminator": [
+        76,
+        70,
+        237,
+        100,
+        33,
+        108,
+        19,
+        42
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_interval",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_call_buffer_reusable",
+      "docs": [
+        "Marks a call buffer as reusable, so that the buffered bridge instructions read it without",
+        "closing it, or back as single-use. Only the owner of the call buffer can update it, and",
+        "reusable buffers cannot be appended to.",
+        "",
+        "# Arguments",
+        "* `ctx`      - The context containing the call buffer and its owner",
+        "* `reusable` - Whether the buffer is kept after being bridged"
+      ],
+      "discriminator": [
+        139,
+        175,
+        188,
+        146,
+        41,
+        246,
+        53,
+        6
+      ],
+      "accounts": [
+        {
+          "name": "owner",
+          "docs": [
+            "The owner of the call buffer."
+          ],
+          "signer": true
+        },
+        {
+          "name": "call_buffer",
+          "docs": [
+            "The call buffer account to update"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "reusable",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "set_call_buffer_ttl",
+      "docs": [
+        "Set the call buffer TTL for Buffer Config (0 disables call buffer expiry)",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_ttl_seconds` - The new idle time, in seconds, after which a call buffer can be expired"
+      ],
+      "discriminator": [
+        244,
+        233,
+        178,
+        75,
+        206,
+        114,
+        130,
+        71
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_ttl_seconds",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_dex_router",
+      "docs": [
+        "Register a Base DEX router, or unregister it, creating the DEX router list on first use.",
+        "Transfers whose call targets a registered router must carry a swap guard",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`        - The context containing the DEX router list, bridge account and guardian",
+        "* `router`     - The router address on Base",
+        "* `registered` - Whether calls to the router require a swap guard"
+      ],
+      "discriminator": [
+        172,
+        98,
+        218,
+        114,
+        119,
+        50,
+        241,
+        81
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to maintain the DEX router list.",
+            "Pays for the DEX router list creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the guardian"
+          ]
+        },
+        {
+          "name": "dex_router_list",
+          "docs": [
+            "The DEX router list account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the DEX router list account"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "router",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        },
+        {
+          "name": "registered",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "set_expire_bounty_bps",
+      "docs": [
+        "Set the expire bounty for Buffer Config",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_bps` - The new share of an expired call buffer's rent paid to the caller, in basis points"
+      ],
+      "discriminator": [
+        83,
+        55,
+        122,
+        189,
+        119,
+        156,
+        57,
+        26
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_bps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_fee_config",
+      "docs": [
+        "Set every pricing parameter shared with the relayer program (EIP-1559 controller, gas",
+        "cost scaler and gas fee receiver) at once. `gas_per_call` is left unchanged.",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`        - The context containing the bridge account, guardian and new gas fee",
+        "receiver account",
+        "* `fee_config` - The new fee configuration"
+      ],
+      "discriminator": [
+        221,
+        222,
+        52,
+        206,
+        114,
+        198,
+        64,
+        91
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "gas_fee_receiver",
+          "docs": [
+            "The gas fee receiver of the new configuration. Must be a system account."
+          ]
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "fee_config",
+          "type": {
+            "defined": {
+              "name": "FeeConfigV2"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "set_fee_quote_grace_period",
+      "docs": [
+        "Set the grace period during which gas fee quotes remain valid after an EIP-1559 window",
+        "rollover, messages being charged the lower of the current and previous base fees meanwhile",
+        "(0 disables the grace period)",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_grace_period_seconds` - The new grace period, capped at the window duration"
+      ],
+      "discriminator": [
+        198,
+        115,
+        33,
+        23,
+        24,
+        4,
+        155,
+        89
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_grace_period_seconds",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_finalization_delay",
+      "docs": [
+        "Set the delay and the per-token thresholds of the finalization delay, which holds transfers",
+        "exceeding the threshold of their token between `prove_message` and `relay_message`",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`           - The context containing the bridge account and guardian",
+        "* `delay_seconds` - The delay after proving a high-value transfer (0 disables the delay)",
+        "* `thresholds`    - The amount above which transfers of each token are delayed"
+      ],
+      "discriminator": [
+        196,
+        149,
+        104,
+        148,
+        56,
+        147,
+        197,
+        70
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "delay_seconds",
+          "type": "u64"
+        },
+        {
+          "name": "thresholds",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "FinalizationThreshold"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "set_gas_cost_scaler",
+      "docs": [
+        "Set the gas cost scaler for Gas Cost Config",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_scaler` - The new gas cost scaler value"
+      ],
+      "discriminator": [
+        148,
+        146,
+        101,
+        170,
+        5,
+        6,
+        222,
+        119
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_scaler",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_gas_cost_scaler_dp",
+      "docs": [
+        "Set the gas cost scaler DP for Gas Cost Config",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_dp` - The new gas cost scaler DP value"
+      ],
+      "discriminator": [
+        198,
+        111,
+        160,
+        55,
+        172,
+        138,
+        99,
+        164
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_dp",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_gas_fee_receiver",
+      "docs": [
+        "Set the gas fee receiver for Gas Cost Config",
+        "Only the guardian can call this function. The new receiver must be a system account.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account, guardian and new gas fee receiver account",
+        "* `new_receiver` - The new gas fee receiver"
+      ],
+      "discriminator": [
+        58,
+        188,
+        230,
+        188,
+        47,
+        188,
+        79,
+        154
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "gas_fee_receiver",
+          "docs": [
+            "The new gas fee receiver. Must be a system account."
+          ]
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_receiver",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "set_gas_per_call",
+      "docs": [
+        "Set the gas amount per call for Gas Config",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_val` - The new gas amount per call value"
+      ],
+      "discriminator": [
+        164,
+        95,
+        213,
+        130,
+        26,
+        69,
+        82,
+        127
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_val",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_gas_target",
+      "docs": [
+        "Set the gas target for EIP-1559 pricing",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_target` - The new gas target value"
+      ],
+      "discriminator": [
+        132,
+        25,
+        19,
+        13,
+        118,
+        63,
+        167,
+        102
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_target",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_governance_mode",
+      "docs": [
+        "Enforces or releases the control of the Base governance contract over the critical",
+        "configuration. While enforced, `set_oracle_signers` and `set_base_governance` are rejected",
+        "Only reachable through a message relayed from the Base governance contract",
+        "",
+        "# Arguments",
+        "* `ctx`      - The context containing the bridge and the governance bridge CPI authority",
+        "* `enforced` - Whether only the governance contract can update the critical configuration"
+      ],
+      "discriminator": [
+        72,
+        173,
+        60,
+        97,
+        144,
+        133,
+        127,
+        125
+      ],
+      "accounts": [
+        {
+          "name": "governance_cpi_authority",
+          "docs": [
+            "The bridge CPI authority of the Base governance contract"
+          ],
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "enforced",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "set_guarded_launch",
+      "docs": [
+        "Turn guarded launch on or off. While it is on, `bridge_spl` only accepts the mints of the",
+        "mint allow-list",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`     - The context containing the bridge account and guardian",
+        "* `enabled` - Whether guarded launch is on"
+      ],
+      "discriminator": [
+        85,
+        148,
+        178,
+        81,
+        119,
+        150,
+        35,
+        126
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "enabled",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "set_insurance_share",
+      "docs": [
+        "Set the share of every gas fee credited to the insurance fund, creating the fund on first",
+        "use",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`       - The context containing the insurance fund, bridge account and guardian",
+        "* `share_bps` - The share of the gas fees credited to the fund, in basis points"
+      ],
+      "discriminator": [
+        172,
+        243,
+        185,
+        104,
+        97,
+        46,
+        98,
+        180
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to configure the insurance fund.",
+            "Pays for the insurance fund creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the guardian"
+          ]
+        },
+        {
+          "name": "insurance_fund",
+          "docs": [
+            "The insurance fund account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the insurance fund account"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "share_bps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "set_maintenance_budget",
+      "docs": [
+        "Set the lamports reimbursed by the maintenance budget to the payer of each output root",
+        "registration on top of its rent, creating the budget on first use",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`               - The context containing the maintenance budget, bridge account and",
+        "guardian",
+        "* `fee_reimbursement` - The lamports reimbursed for the transaction fees"
+      ],
+      "discriminator": [
+        51,
+        172,
+        252,
+        22,
+        29,
+        67,
+        32,
+        176
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to configure the maintenance budget.",
+            "Pays for the maintenance budget creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the guardian"
+          ]
+        },
+        {
+          "name": "maintenance_budget",
+          "docs": [
+            "The maintenance budget account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the maintenance budget account"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "fee_reimbursement",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_max_base_fee",
+      "docs": [
+        "Set the maximum base fee for EIP-1559 pricing (0 disables the cap)",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_max_fee` - The new maximum base fee value"
+      ],
+      "discriminator": [
+        5,
+        254,
+        242,
+        93,
+        48,
+        17,
+        187,
+        212
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_max_fee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_max_call_buffer_size",
+      "docs": [
+        "Set the max call buffer size for Buffer Config",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_size` - The new max call buffer size value"
+      ],
+      "discriminator": [
+        140,
+        178,
+        4,
+        238,
+        245,
+        66,
+        117,
+        189
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_size",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_max_lamport_allowance",
+      "docs": [
+        "Set the maximum lamport allowance the operational vault lends to the bridge CPI authority",
+        "of a single `WithLamportAllowance` message, creating the vault on first use",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`           - The context containing the operational vault, bridge account and guardian",
+        "* `max_allowance` - The maximum lamport allowance of a single message"
+      ],
+      "discriminator": [
+        228,
+        107,
+        134,
+        81,
+        152,
+        253,
+        69,
+        60
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to configure the operational vault.",
+            "Pays for the operational vault creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the guardian"
+          ]
+        },
+        {
+          "name": "operational_vault",
+          "docs": [
+            "The operational vault account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the operational vault account"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "max_allowance",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_max_window_increase_bps",
+      "docs": [
+        "Set the maximum per-window base fee increase for EIP-1559 pricing (0 disables the cap)",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_bps` - The new maximum increase, in basis points of the current base fee"
+      ],
+      "discriminator": [
+        70,
+        54,
+        126,
+        87,
+        192,
+        157,
+        8,
+        94
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_bps",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_minimum_base_fee",
+      "docs": [
+        "Set the minimum base fee for EIP-1559 pricing",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_fee` - The new minimum base fee value"
+      ],
+      "discriminator": [
+        56,
+        95,
+        58,
+        94,
+        221,
+        136,
+        138,
+        156
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_fee",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_mint_allowed",
+      "docs": [
+        "Add a mint to, or remove it from, the mints accepted by `bridge_spl` during guarded launch,",
+        "creating the allow-list on first use",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`     - The context containing the mint allow-list, bridge account and guardian",
+        "* `mint`    - The mint to add or remove",
+        "* `allowed` - Whether the mint is allow-listed"
+      ],
+      "discriminator": [
+        245,
+        141,
+        124,
+        184,
+        161,
+        169,
+        177,
+        171
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to maintain the mint allow-list.",
+            "Pays for the mint allow-list creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the guardian"
+          ]
+        },
+        {
+          "name": "mint_allow_list",
+          "docs": [
+            "The mint allow-list account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the mint allow-list account"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "mint",
+          "type": "pubkey"
+        },
+        {
+          "name": "allowed",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "set_mint_policy",
+      "docs": [
+        "Set the policy restricting the freeze authority of mints bridged with `bridge_spl`,",
+        "creating it on first use",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`                        - The context containing the mint policy, bridge account and guardian",
+        "* `reject_freeze_authority`    - Whether mints with a freeze authority are rejected",
+        "* `allowed_freeze_authorities` - The freeze authorities still accepted while rejecting"
+      ],
+      "discriminator": [
+        12,
+        208,
+        252,
+        52,
+        166,
+        250,
+        137,
+        169
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to configure the mint policy.",
+            "Pays for the mint policy creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the guardian"
+          ]
+        },
+        {
+          "name": "mint_policy",
+          "docs": [
+            "The mint policy account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the mint policy account"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "reject_freeze_authority",
+          "type": "bool"
+        },
+        {
+          "name": "allowed_freeze_authorities",
+          "type": {
+            "vec": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "set_oracle_failover_config",
+      "docs": [
+        "Set the oracle failover configuration",
+        "Only the upgrade authority can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`                        - The context containing the bridge account and upgrade authority",
+        "* `stall_seconds`              - Seconds without a new output root before the guardian can",
+        "activate the emergency quorum (0 disables it)",
+        "* `emergency_duration_seconds` - Seconds the emergency quorum stays active"
+      ],
+      "discriminator": [
+        0,
+        208,
+        186,
+        0,
+        105,
+        73,
+        109,
+        235
+      ],
+      "accounts": [
+        {
+          "name": "upgrade_authority",
+          "docs": [
+            "The upgrade authority account"
+          ],
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "program_data"
+        },
+        {
+          "name": "program"
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "stall_seconds",
+          "type": "u64"
+        },
+        {
+          "name": "emergency_duration_seconds",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_oracle_signers",
+      "docs": [
+        "Sets the authorized oracle EVM signer addresses and the signature threshold used",
+        "when registering output roots. This function updates the `OracleSigners` account",
+        "and can only be called by the guardian.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge, guardian signer, and oracle signers accounts",
+        "* `cfg` - Configuration parameters for Base oracle signers"
+      ],
+      "discriminator": [
+        38,
+        183,
+        212,
+        67,
+        28,
+        241,
+        25,
+        160
+      ],
+      "accounts": [
+        {
+          "name": "upgrade_authority",
+          "docs": [
+            "The upgrade authority account"
+          ],
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "program_data"
+        },
+        {
+          "name": "program"
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "cfg",
+          "type": {
+            "defined": {
+              "name": "BaseOracleConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "set_oracle_signers_from_governance",
+      "docs": [
+        "Sets the Base oracle signers from the Base governance contract",
+        "Only reachable through a message relayed from the Base governance contract",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge and the governance bridge CPI authority",
+        "* `cfg` - Configuration parameters for Base oracle signers"
+      ],
+      "discriminator": [
+        147,
+        246,
+        10,
+        198,
+        114,
+        206,
+        79,
+        67
+      ],
+      "accounts": [
+        {
+          "name": "governance_cpi_authority",
+          "docs": [
+            "The bridge CPI authority of the Base governance contract"
+          ],
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "cfg",
+          "type": {
+            "defined": {
+              "name": "BaseOracleConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "set_outflow_breaker",
+      "docs": [
+        "Set the sliding window and the per-token thresholds of the outflow circuit breaker, which",
+        "pauses the bridge when relaying a transfer would exceed the threshold of its token",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`            - The context containing the bridge account and guardian",
+        "* `window_seconds` - The length of the sliding window (0 disables the breaker)",
+        "* `thresholds`     - The maximum outflow over the window of each tracked token"
+      ],
+      "discriminator": [
+        229,
+        198,
+        179,
+        252,
+        5,
+        220,
+        25,
+        30
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "window_seconds",
+          "type": "u64"
+        },
+        {
+          "name": "thresholds",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "OutflowThreshold"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "set_partner_oracle_config",
+      "docs": [
+        "Update the partner oracle configuration containing the required signature threshold",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_config` - The new partner oracle config"
+      ],
+      "discriminator": [
+        34,
+        48,
+        231,
+        135,
+        42,
+        113,
+        217,
+        157
+      ],
+      "accounts": [
+        {
+          "name": "upgrade_authority",
+          "docs": [
+            "The upgrade authority account"
+          ],
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "program_data"
+        },
+        {
+          "name": "program"
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_config",
+          "type": {
+            "defined": {
+              "name": "PartnerOracleConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "set_pause_status",
+      "docs": [
+        "Set the pause status for the bridge",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_paused` - The new pause status (true for paused, false for unpaused)"
+      ],
+      "discriminator": [
+        118,
+        25,
+        145,
+        217,
+        114,
+        209,
+        236,
+        145
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_paused",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "set_payer_rate_limit",
+      "docs": [
+        "Set the outgoing messages and gas each payer can pay for per rate limiting window, creating",
+        "the payer rate limit config on first use. A zero window disables rate limiting",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`            - The context containing the payer rate limit config, bridge account and",
+        "guardian",
+        "* `window_seconds` - The duration of a rate limiting window, in seconds",
+        "* `max_messages`   - The outgoing messages a payer can pay for per window (0 for no limit)",
+        "* `max_gas`        - The gas a payer can pay for per window (0 for no limit)"
+      ],
+      "discriminator": [
+        23,
+        255,
+        179,
+        254,
+        32,
+        116,
+        238,
+        242
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to maintain the payer rate limit config.",
+            "Pays for the payer rate limit config creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the guardian"
+          ]
+        },
+        {
+          "name": "payer_rate_limit_config",
+          "docs": [
+            "The payer rate limit config account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the payer rate limit config account"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "window_seconds",
+          "type": "u64"
+        },
+        {
+          "name": "max_messages",
+          "type": "u64"
+        },
+        {
+          "name": "max_gas",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_protocol_fee_config",
+      "docs": [
+        "Set the protocol fee skimmed from bridged token amounts, creating its config on first use",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`         - The context containing the protocol fee config, bridge account and guardian",
+        "* `treasury`    - The treasury collecting SOL fees and owning the token accounts collecting token fees",
+        "* `default_bps` - The protocol fee in basis points applied to tokens without an override",
+        "* `overrides`   - The per-token protocol fees taking precedence over `default_bps`"
+      ],
+      "discriminator": [
+        143,
+        126,
+        0,
+        121,
+        246,
+        128,
+        169,
+        45
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to configure the protocol fee.",
+            "Pays for the protocol fee config creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the guardian"
+          ]
+        },
+        {
+          "name": "protocol_fee_config",
+          "docs": [
+            "The protocol fee config account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the protocol fee config account"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "treasury",
+          "type": "pubkey"
+        },
+        {
+          "name": "default_bps",
+          "type": "u16"
+        },
+        {
+          "name": "overrides",
+          "type": {
+            "vec": {
+              "defined": {
+                "name": "ProtocolFeeOverride"
+              }
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "set_rate_limit_exempt_payer",
+      "docs": [
+        "Exempt a payer from the payer rate limits, or subject it to them again, creating the payer",
+        "rate limit config on first use",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`    - The context containing the payer rate limit config, bridge account and guardian",
+        "* `payer`  - The payer",
+        "* `exempt` - Whether the payer is exempt from the rate limits"
+      ],
+      "discriminator": [
+        20,
+        167,
+        148,
+        153,
+        121,
+        65,
+        190,
+        191
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to maintain the payer rate limit config.",
+            "Pays for the payer rate limit config creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the guardian"
+          ]
+        },
+        {
+          "name": "payer_rate_limit_config",
+          "docs": [
+            "The payer rate limit config account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the payer rate limit config account"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "payer",
+          "type": "pubkey"
+        },
+        {
+          "name": "exempt",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "set_relay_deny_list",
+      "docs": [
+        "Set the programs that relayed messages can never invoke, on top of the built-in",
+        "`DENIED_RELAY_PROGRAMS`, creating the deny-list on first use",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`      - The context containing the relay deny-list, bridge account and guardian",
+        "* `programs` - The programs denied in addition to the built-in ones"
+      ],
+      "discriminator": [
+        11,
+        128,
+        83,
+        65,
+        154,
+        51,
+        71,
+        31
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to configure the relay deny-list.",
+            "Pays for the relay deny-list creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the guardian"
+          ]
+        },
+        {
+          "name": "relay_deny_list",
+          "docs": [
+            "The relay deny-list account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the relay deny-list account"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "programs",
+          "type": {
+            "vec": "pubkey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "set_root_freshness_config",
+      "docs": [
+        "Set the maximum age, in Base blocks, of the output roots each message kind can be proven",
+        "against (0 accepts any root)",
+        "Only the upgrade authority can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`        - The context containing the bridge account and upgrade authority",
+        "* `new_config` - The new root freshness configuration"
+      ],
+      "discriminator": [
+        37,
+        162,
+        209,
+        203,
+        207,
+        194,
+        133,
+        198
+      ],
+      "accounts": [
+        {
+          "name": "upgrade_authority",
+          "docs": [
+            "The upgrade authority account"
+          ],
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "program_data"
+        },
+        {
+          "name": "program"
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_config",
+          "type": {
+            "defined": {
+              "name": "RootFreshnessConfig"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "set_transfer_gas_lane",
+      "docs": [
+        "Set the EIP-1559 configuration of the gas lane pricing token transfers apart from calls,",
+        "so that demand in one lane does not distort pricing in the other (a zero target makes",
+        "transfers share the base fee of calls again)",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_config` - The new transfer gas lane configuration"
+      ],
+      "discriminator": [
+        212,
+        145,
+        147,
+        135,
+        71,
+        157,
+        141,
+        172
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_config",
+          "type": {
+            "defined": {
+              "name": "Eip1559Config"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "set_vault_decimals",
+      "docs": [
+        "Set the decimals of the Base token that an SPL token vault is bridged to, when lower than",
+        "the mint decimals, so that amounts are carried in the remote token's smallest unit",
+        "Only the guardian can call this function, while the vault holds no deposits",
+        "",
+        "# Arguments",
+        "* `ctx`             - The context containing the vault decimals, bridge account and guardian",
+        "* `remote_token`    - The Base token the vault is bridged to",
+        "* `remote_decimals` - The decimals of the remote token"
+      ],
+      "discriminator": [
+        74,
+        13,
+        97,
+        20,
+        246,
+        82,
+        233,
+        139
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to configure the vault decimals.",
+            "Pays for the vault decimals creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the guardian"
+          ]
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "The SPL mint locked in the token vault"
+          ]
+        },
+        {
+          "name": "token_vault",
+          "docs": [
+            "The token vault of the mint and the remote token, possibly not created yet.",
+            ""
+          ]
+        },
+        {
+          "name": "token_deposits",
+          "docs": [
+            "Accounting for the amount locked in the token vault, possibly not created yet.",
+            ""
+          ]
+        },
+        {
+          "name": "vault_decimals",
+          "docs": [
+            "The vault decimals account"
+          ],
+          "writable": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the vault decimals account"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "remote_token",
+          "type": {
+            "array": [
+              "u8",
+              20
+            ]
+          }
+        },
+        {
+          "name": "remote_decimals",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "set_window_duration",
+      "docs": [
+        "Set the window duration for EIP-1559 pricing",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and guardian",
+        "* `new_duration` - The new window duration in seconds"
+      ],
+      "discriminator": [
+        229,
+        2,
+        41,
+        119,
+        55,
+        255,
+        252,
+        205
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_duration",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "set_wrapped_token_confirmation_required",
+      "docs": [
+        "Require, or no longer require, wrapped tokens to be confirmed by the Base Bridge before",
+        "`bridge_wrapped_token` accepts them",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`      - The context containing the bridge account and guardian",
+        "* `required` - Whether wrapped tokens must be confirmed before being bridged"
+      ],
+      "discriminator": [
+        94,
+        202,
+        19,
+        237,
+        187,
+        194,
+        5,
+        60
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "required",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "set_wrapped_token_max_supply",
+      "docs": [
+        "Mirrors the max supply of a wrapped token set on Base. Only reachable through",
+        "`relay_message` relaying a message sent by the Base Bridge, whose bridge CPI authority must",
+        "sign and pays for the supply cap account on first use. Mints of the wrapped token, both",
+        "wrapped token transfers and `crosschain_mint`, are then rejected past the max supply.",
+        "Emits `WrappedTokenMaxSupplyUpdated`.",
+        "",
+        "# Arguments",
+        "* `ctx`        - The context containing the bridge CPI authority, the wrapped mint and its",
+        "supply cap account",
+        "* `max_supply` - The max supply of the wrapped token, or `None` to lift the cap"
+      ],
+      "discriminator": [
+        211,
+        225,
+        119,
+        165,
+        13,
+        0,
+        28,
+        228
+      ],
+      "accounts": [
+        {
+          "name": "bridge_cpi_authority",
+          "docs": [
+            "The bridge CPI authority of the Base Bridge, signing when `relay_message` relays a message",
+            "sent by the Base Bridge. Pays for the supply cap account creation."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "address_book",
+          "docs": [
+            "The address book holding the Base Bridge address."
+          ]
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "The wrapped token mint whose max supply is set."
+          ]
+        },
+        {
+          "name": "supply_cap",
+          "docs": [
+            "The supply cap account of the wrapped token.",
+            "- Uses PDA with WRAPPED_TOKEN_SUPPLY_CAP_SEED and the mint for deterministic address",
+            "- Created on first use"
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating the supply cap account."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "max_supply",
+          "type": {
+            "option": "u64"
+          }
+        }
+      ]
+    },
+    {
+      "name": "settle_relay_fees",
+      "docs": [
+        "Deposits the relay fees collected on Base into the relay fee vault, from which",
+        "`relay_message` pays the relayers of `WithRelayFee` messages.",
+        "Authorization is enforced via EVM signatures from authorized Base oracles and partner",
+        "signers per configured thresholds; the Solana payer provides the settled lamports.",
+        "",
+        "# Arguments",
+        "* `ctx`              - The context containing the payer, the bridge and the relay fee vault",
+        "* `settlement_nonce` - The nonce of the settlement, following the latest applied one",
+        "* `amount`           - The lamports deposited into the relay fee vault",
+        "* `signatures`       - A list of ECDSA signatures from authorized oracles attesting to the settlement"
+      ],
+      "discriminator": [
+        198,
+        95,
+        239,
+        232,
+        222,
+        62,
+        36,
+        51
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "Payer depositing the settled lamports. Authorization is enforced via oracle EVM signatures."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The main bridge state account providing the oracle configuration."
+          ]
+        },
+        {
+          "name": "relay_fee_vault",
+          "docs": [
+            "The relay fee vault.",
+            "- Uses PDA with RELAY_FEE_VAULT_SEED for deterministic address",
+            "- Created by the first settlement"
+          ],
+          "writable": true
+        },
+        {
+          "name": "partner_config",
+          "docs": [
+            "Partner `Config` account (PDA with seed \"config\") owned by partner program.",
+            "Unchecked to avoid Anchor pre-handler owner checks; PDA address is validated in the handler."
+          ]
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating and funding the relay fee vault."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "settlement_nonce",
+          "type": "u64"
+        },
+        {
+          "name": "amount",
+          "type": "u64"
+        },
+        {
+          "name": "signatures",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                65
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "supersede_output_root",
+      "docs": [
+        "Replaces a registered output root within `OUTPUT_ROOT_CHALLENGE_WINDOW_SECONDS` of its",
+        "registration, e.g. after Base reorged below it, and increments its revision. Messages",
+        "proven against a superseded revision can no longer be relayed.",
+        "Authorization is enforced via EVM signatures from authorized Base oracles and partner",
+        "signers per configured thresholds over the new root and revision.",
+        "",
+        "# Arguments",
+        "* `ctx`               - The context containing the output root, the bridge and the partner config",
+        "* `output_root`       - The new 32-byte MMR root of Base messages for the given block",
+        "* `base_block_number` - The Base block number of the superseded output root",
+        "* `total_leaf_count`  - The total number of leaves in the MMR with the new root",
+        "* `signatures`        - A list of ECDSA signatures from authorized oracles attesting to the new root and revision"
+      ],
+      "discriminator": [
+        64,
+        109,
+        12,
+        138,
+        234,
+        30,
+        189,
+        89
+      ],
+      "accounts": [
+        {
+          "name": "root",
+          "docs": [
+            "The output root account being superseded."
+          ],
+          "writable": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The main bridge state account providing the oracle configuration."
+          ]
+        },
+        {
+          "name": "partner_config",
+          "docs": [
+            "Partner `Config` account (PDA with seed \"config\") owned by partner program.",
+            "Unchecked to avoid Anchor pre-handler owner checks; PDA address is validated in the handler."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "output_root",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "base_block_number",
+          "type": "u64"
+        },
+        {
+          "name": "total_leaf_count",
+          "type": "u64"
+        },
+        {
+          "name": "signatures",
+          "type": {
+            "vec": {
+              "array": [
+                "u8",
+                65
+              ]
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "sweep_gas_fee_escrow",
+      "docs": [
+        "Permissionlessly forwards the gas fees collected by the fallback gas fee escrow (used when",
+        "the gas fee receiver cannot be credited) to the configured gas fee receiver.",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge, the gas fee receiver and the gas fee escrow"
+      ],
+      "discriminator": [
+        174,
+        228,
+        122,
+        93,
+        87,
+        218,
+        203,
+        200
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the gas fee receiver"
+          ]
+        },
+        {
+          "name": "gas_fee_receiver",
+          "docs": [
+            "The configured gas fee receiver, credited with the whole escrow balance."
+          ],
+          "writable": true
+        },
+        {
+          "name": "gas_fee_escrow",
+          "docs": [
+            "The fallback gas fee escrow being emptied."
+          ],
+          "writable": true
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for transferring the escrowed lamports."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transfer_guardian",
+      "docs": [
+        "Transfer guardian authority to a new pubkey",
+        "Only the current guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx` - The context containing the bridge account and current guardian",
+        "* `new_guardian` - The pubkey of the new guardian"
+      ],
+      "discriminator": [
+        118,
+        250,
+        162,
+        85,
+        197,
+        130,
+        116,
+        123
+      ],
+      "accounts": [
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account containing configuration"
+          ],
+          "writable": true
+        },
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to update configuration"
+          ],
+          "signer": true
+        },
+        {
+          "name": "config_history",
+          "docs": [
+            "Log recording the configuration change"
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "new_guardian",
+          "type": "pubkey"
+        }
+      ]
+    },
+    {
+      "name": "veto_message",
+      "docs": [
+        "Veto an incoming message held by the finalization delay, so that it can never be relayed.",
+        "Emits `MessageVetoed`.",
+        "Only the guardian can call this function",
+        "",
+        "# Arguments",
+        "* `ctx`          - The context containing the message, bridge account and guardian",
+        "* `message_hash` - The hash of the vetoed message"
+      ],
+      "discriminator": [
+        73,
+        237,
+        9,
+        76,
+        137,
+        196,
+        203,
+        25
+      ],
+      "accounts": [
+        {
+          "name": "guardian",
+          "docs": [
+            "The guardian account authorized to veto messages"
+          ],
+          "signer": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The bridge account holding the guardian and the finalization delay"
+          ]
+        },
+        {
+          "name": "message",
+          "docs": [
+            "The incoming message being vetoed."
+          ],
+          "writable": true
+        }
+      ],
+      "args": [
+        {
+          "name": "message_hash",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        }
+      ]
+    },
+    {
+      "name": "wrap_token",
+      "docs": [
+        "Creates a wrapped version of a Base token.",
+        "This function creates a new SPL mint account on Solana that represents the Base token,",
+        "enabling users to bridge the token between the two chains. It will also trigger a message",
+        "to Base to register the wrapped token in the Base Bridge contract.",
+        "If the optional Metaplex accounts are provided, a Metaplex metadata account mirroring",
+        "the token name and symbol is also created for wallets that don't read Token-2022 metadata.",
+        "",
+        "# Arguments",
+        "* `ctx`                    - The transaction context",
+        "* `outgoing_message_salt`  - The salt for the outgoing message account",
+        "* `decimals`               - Number of decimal places for the token",
+        "* `partial_token_metadata` - Token name, symbol, remote Base token address, and scaler exponent",
+        "",
+        "# Returns",
+        "* `WrapTokenResult::Wrapped` with the new mint, or `WrapTokenResult::AlreadyWrapped` with",
+        "the existing mint, without sending a duplicate registration message, if the token was",
+        "already wrapped with the same decimals and metadata"
+      ],
+      "discriminator": [
+        203,
+        83,
+        204,
+        83,
+        225,
+        109,
+        44,
+        6
+      ],
+      "accounts": [
+        {
+          "name": "payer",
+          "docs": [
+            "The account that pays for the transaction and all account creation costs.",
+            "Must be mutable to deduct lamports for mint creation, metadata storage, and gas fees.",
+            "Unlike the bridging instructions, there is no `from`: no asset is moved and the",
+            "registration message is sent by the bridge program itself, so any payer can wrap a token."
+          ],
+          "writable": true,
+          "signer": true
+        },
+        {
+          "name": "gas_fee_receiver",
+          "docs": [
+            "The account that receives payment for the gas costs of registering the token on Base."
+          ],
+          "writable": true
+        },
+        {
+          "name": "gas_fee_escrow",
+          "docs": [
+            "Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited."
+          ],
+          "writable": true
+        },
+        {
+          "name": "insurance_fund",
+          "docs": [
+            "Insurance fund credited with its share of the gas fee. No share is taken while it is",
+            "uninitialized."
+          ],
+          "writable": true
+        },
+        {
+          "name": "payer_rate_limit_config",
+          "docs": [
+            "Payer rate limiting configuration. Payers are not rate limited while it is uninitialized."
+          ]
+        },
+        {
+          "name": "payer_rate_limit",
+          "docs": [
+            "Outgoing messages paid for by the payer in the current rate limiting window, created on its",
+            "first message while rate limiting is enabled."
+          ],
+          "writable": true
+        },
+        {
+          "name": "mint",
+          "docs": [
+            "The new SPL Token-2022 mint being created for the wrapped token.",
+            "- Uses PDA with token metadata hash and decimals for deterministic address",
+            "- Mint authority set to itself (mint account) for controlled minting",
+            "- Includes metadata pointer extension to store token information onchain",
+            "- Left untouched if it already exists"
+          ],
+          "writable": true
+        },
+        {
+          "name": "bridge",
+          "docs": [
+            "The main bridge state account that tracks cross-chain operations.",
+            "Used to increment the nonce counter and manage EIP-1559 gas pricing.",
+            "Must be mutable to update the nonce after creating the outgoing message."
+          ],
+          "writable": true
+        },
+        {
+          "name": "address_book",
+          "docs": [
+            "The address book providing the Base Bridge address targeted by the registration call."
+          ]
+        },
+        {
+          "name": "outgoing_message",
+          "docs": [
+            "The outgoing message account that stores the cross-chain call to register",
+            "the wrapped token on the Base blockchain. Contains the encoded function call",
+            "with token address, local mint address, and scaling parameters.",
+            "already wrapped token does not send a duplicate registration."
+          ],
+          "writable": true
+        },
+        {
+          "name": "token_program",
+          "docs": [
+            "SPL Token-2022 program for creating the mint with metadata extensions.",
+            "Required for initializing tokens with advanced features like metadata pointers."
+          ]
+        },
+        {
+          "name": "system_program",
+          "docs": [
+            "System program required for creating new accounts and transferring lamports.",
+            "Used internally by Anchor for account initialization and rent payments."
+          ]
+        },
+        {
+          "name": "metaplex_metadata",
+          "docs": [
+            "Optional Metaplex Token Metadata account mirroring the Token-2022 metadata, for wallets",
+            "that do not read Token-2022 TLV metadata. Only created when provided."
+          ],
+          "writable": true,
+          "optional": true
+        },
+        {
+          "name": "metaplex_program",
+          "docs": [
+            "Metaplex Token Metadata program, required when `metaplex_metadata` is provided."
+          ],
+          "optional": true
+        }
+      ],
+      "args": [
+        {
+          "name": "outgoing_message_salt",
+          "type": {
+            "array": [
+              "u8",
+              32
+            ]
+          }
+        },
+        {
+          "name": "decimals",
+          "type": "u8"
+        },
+        {
+          "name": "partial_token_metadata",
+          "type": {
+            "defined": {
+              "name": "PartialTokenMetadata"
+            }
+          }
+        }
+      ],
+      "returns": {
+        "defined": {
+          "name": "WrapTokenResult"
+        }
+      }
+    }
+  ],
+  "accounts": [
+    {
+      "name": "AddressBook",
+      "discriminator": [
+        112,
+        200,
+        175,
+        39,
+        249,
+        237,
+        44,
+        141
+      ]
+    },
+    {
+      "name": "Bridge",
+      "discriminator": [
+        231,
+        232,
+        31,
+        98,
+        110,
+        3,
+        23,
+        59
+      ]
+    },
+    {
+      "name": "BridgeSession",
+      "discriminator": [
+        235,
+        118,
+        90,
+        227,
+        230,
+        173,
+        141,
+        184
+      ]
+    },
+    {
+      "name": "BurnReceipt",
+      "discriminator": [
+        209,
+        39,
+        231,
+        253,
+        164,
+        70,
+        105,
+        174
+      ]
+    },
+    {
+      "name": "CallBuffer",
+      "discriminator": [
+        134,
+        143,
+        168,
+        251,
+        163,
+        216,
+        180,
+        113
+      ]
+    },
+    {
+      "name": "ConfigHistory",
+      "discriminator": [
+        228,
+        148,
+        11,
+        248,
+        20,
+        187,
+        94,
+        127
+      ]
+    },
+    {
+      "name": "DexRouterList",
+      "discriminator": [
+        67,
+        61,
+        221,
+        98,
+        51,
+        77,
+        110,
+        141
+      ]
+    },
+    {
+      "name": "EmergencyExit",
+      "discriminator": [
+        87,
+        180,
+        23,
+        110,
+        235,
+        1,
+        235,
+        126
+      ]
+    },
+    {
+      "name": "EquivocationEvidence",
+      "discriminator": [
+        70,
+        115,
+        183,
+        155,
+        104,
+        245,
+        215,
+        118
+      ]
+    },
+    {
+      "name": "HandlerRegistration",
+      "discriminator": [
+        244,
+        68,
+        84,
+        81,
+        139,
+        64,
+        75,
+        70
+      ]
+    },
+    {
+      "name": "IncomingMessage",
+      "discriminator": [
+        30,
+        144,
+        125,
+        111,
+        211,
+        223,
+        91,
+        170
+      ]
+    },
+    {
+      "name": "InsuranceFund",
+      "discriminator": [
+        43,
+        134,
+        170,
+        87,
+        102,
+        16,
+        142,
+        147
+      ]
+    },
+    {
+      "name": "LiabilityRoot",
+      "discriminator": [
+        136,
+        64,
+        12,
+        73,
+        131,
+        121,
+        226,
+        74
+      ]
+    },
+    {
+      "name": "MaintenanceBudget",
+      "discriminator": [
+        10,
+        93,
+        117,
+        190,
+        152,
+        237,
+        50,
+        134
+      ]
+    },
+    {
+      "name": "MintAllowList",
+      "discriminator": [
+        248,
+        183,
+        35,
+        91,
+        119,
+        253,
+        109,
+        22
+      ]
+    },
+    {
+      "name": "MintPolicy",
+      "discriminator": [
+        191,
+        78,
+        230,
+        47,
+        121,
+        49,
+        66,
+        222
+      ]
+    },
+    {
+      "name": "OperationalVault",
+      "discriminator": [
+        192,
+        181,
+        243,
+        150,
+        176,
+        228,
+        45,
+        226
+      ]
+    },
+    {
+      "name": "OutgoingMessage",
+      "discriminator": [
+        150,
+        255,
+        197,
+        226,
+        200,
+        215,
+        31,
+        29
+      ]
+    },
+    {
+      "name": "OutputRoot",
+      "discriminator": [
+        11,
+        31,
+        168,
+        201,
+        229,
+        8,
+        180,
+        198
+      ]
+    },
+    {
+      "name": "PayerRateLimitConfig",
+      "discriminator": [
+        56,
+        1,
+        177,
+        164,
+        58,
+        43,
+        214,
+        135
+      ]
+    },
+    {
+      "name": "ProtocolFeeConfig",
+      "discriminator": [
+        81,
+        109,
+        12,
+        19,
+        140,
+        182,
+        21,
+        238
+      ]
+    },
+    {
+      "name": "ProveBuffer",
+      "discriminator": [
+        78,
+        160,
+        227,
+        163,
+        97,
+        140,
+        40,
+        149
+      ]
+    },
+    {
+      "name": "RelayDenyList",
+      "discriminator": [
+        75,
+        12,
+        144,
+        243,
+        127,
+        216,
+        45,
+        173
+      ]
+    },
+    {
+      "name": "RelayExecution",
+      "discriminator": [
+        164,
+        61,
+        70,
+        13,
+        77,
+        65,
+        148,
+        150
+      ]
+    },
+    {
+      "name": "RelayFeeVault",
+      "discriminator": [
+        27,
+        215,
+        66,
+        254,
+        221,
+        129,
+        165,
+        143
+      ]
+    },
+    {
+      "name": "RelayedNonces",
+      "discriminator": [
+        245,
+        149,
+        24,
+        172,
+        58,
+        163,
+        78,
+        141
+      ]
+    },
+    {
+      "name": "ReturnedTransfer",
+      "discriminator": [
+        243,
+        207,
+        182,
+        175,
+        153,
+        226,
+        153,
+        17
+      ]
+    },
+    {
+      "name": "SolVaultState",
+      "discriminator": [
+        73,
+        192,
+        113,
+        251,
+        77,
+        219,
+        213,
+        95
+      ]
+    },
+    {
+      "name": "TokenDeposits",
+      "discriminator": [
+        217,
+        76,
+        219,
+        239,
+        3,
+        87,
+        134,
+        38
+      ]
+    },
+    {
+      "name": "VaultDecimals",
+      "discriminator": [
+        94,
+        21,
+        18,
+        166,
+        217,
+        100,
+        206,
+        203
+      ]
+    },
+    {
+      "name": "WrappedTokenSupplyCap",
+      "discriminator": [
+        106,
+        219,
+        43,
+        96,
+        118,
+        136,
+        251,
+        165
+      ]
+    }
+  ],
+  "events": [
+    {
+      "name": "AuditReport",
+      "discriminator": [
+        235,
+        78,
+        176,
+        4,
+        235,
+        223,
+        250,
+        32
+      ]
+    },
+    {
+      "name": "BaseFeeCapped",
+      "discriminator": [
+        54,
+        223,
+        253,
+        208,
+        158,
+        21,
+        150,
+        245
+      ]
+    },
+    {
+      "name": "CallBufferAppended",
+      "discriminator": [
+        56,
+        122,
+        155,
+        188,
+        137,
+        178,
+        13,
+        31
+      ]
+    },
+    {
+      "name": "CallBufferCloned",
+      "discriminator": [
+        0,
+        195,
+        130,
+        62,
+        67,
+        59,
+        62,
+        147
+      ]
+    },
+    {
+      "name": "CallBufferClosed",
+      "discriminator": [
+        196,
+        92,
+        80,
+        105,
+        65,
+        250,
+        51,
+        114
+      ]
+    },
+    {
+      "name": "CallBufferExpired",
+      "discriminator": [
+        147,
+        6,
+        156,
+        80,
+        97,
+        198,
+        72,
+        237
+      ]
+    },
+    {
+      "name": "CallBufferInitialized",
+      "discriminator": [
+        8,
+        225,
+        102,
+        138,
+        32,
+        66,
+        225,
+        33
+      ]
+    },
+    {
+      "name": "CallBufferReusableSet",
+      "discriminator": [
+        34,
+        242,
+        212,
+        84,
+        234,
+        171,
+        59,
+        28
+      ]
+    },
+    {
+      "name": "CallBufferUsed",
+      "discriminator": [
+        221,
+        207,
+        212,
+        79,
+        222,
+        57,
+        249,
+        115
+      ]
+    },
+    {
+      "name": "CrosschainBurn",
+      "discriminator": [
+        253,
+        51,
+        170,
+        221,
+        9,
+        30,
+        171,
+        18
+      ]
+    },
+    {
+      "name": "CrosschainMint",
+      "discriminator": [
+        188,
+        72,
+        109,
+        27,
+        247,
+        103,
+        49,
+        48
+      ]
+    },
+    {
+      "name": "DexRouterListUpdated",
+      "discriminator": [
+        192,
+        187,
+        245,
+        136,
+        50,
+        162,
+        32,
+        168
+      ]
+    },
+    {
+      "name": "EmergencyExitCancelled",
+      "discriminator": [
+        65,
+        165,
+        194,
+        10,
+        128,
+        105,
+        232,
+        56
+      ]
+    },
+    {
+      "name": "EmergencyExitEnabled",
+      "discriminator": [
+        54,
+        28,
+        85,
+        137,
+        62,
+        131,
+        252,
+        83
+      ]
+    },
+    {
+      "name": "EmergencyQuorumUsed",
+      "discriminator": [
+        79,
+        108,
+        77,
+        9,
+        170,
+        153,
+        169,
+        74
+      ]
+    },
+    {
+      "name": "EmergencyWithdrawal",
+      "discriminator": [
+        225,
+        77,
+        96,
+        117,
+        149,
+        211,
+        83,
+        71
+      ]
+    },
+    {
+      "name": "GuardedLaunchSet",
+      "discriminator": [
+        43,
+        197,
+        184,
+        8,
+        124,
+        30,
+        169,
+        245
+      ]
+    },
+    {
+      "name": "HandlerRegistered",
+      "discriminator": [
+        41,
+        125,
+        137,
+        16,
+        204,
+        77,
+        248,
+        232
+      ]
+    },
+    {
+      "name": "InsurancePayoutCancelled",
+      "discriminator": [
+        54,
+        215,
+        223,
+        152,
+        254,
+        198,
+        14,
+        116
+      ]
+    },
+    {
+      "name": "InsurancePayoutExecuted",
+      "discriminator": [
+        242,
+        99,
+        213,
+        6,
+        226,
+        178,
+        33,
+        115
+      ]
+    },
+    {
+      "name": "InsurancePayoutRequested",
+      "discriminator": [
+        249,
+        135,
+        9,
+        75,
+        52,
+        204,
+        130,
+        176
+      ]
+    },
+    {
+      "name": "LamportAllowanceReturned",
+      "discriminator": [
+        53,
+        133,
+        197,
+        142,
+        69,
+        10,
+        113,
+        53
+      ]
+    },
+    {
+      "name": "MessageProven",
+      "discriminator": [
+        206,
+        109,
+        226,
+        130,
+        138,
+        255,
+        244,
+        207
+      ]
+    },
+    {
+      "name": "MessageRelayed",
+      "discriminator": [
+        138,
+        235,
+        65,
+        100,
+        158,
+        104,
+        211,
+        183
+      ]
+    },
+    {
+      "name": "MessageVetoed",
+      "discriminator": [
+        178,
+        214,
+        215,
+        117,
+        222,
+        120,
+        224,
+        75
+      ]
+    },
+    {
+      "name": "MintAllowListUpdated",
+      "discriminator": [
+        35,
+        182,
+        159,
+        212,
+        217,
+        76,
+        217,
+        112
+      ]
+    },
+    {
+      "name": "OracleEmergencyActivated",
+      "discriminator": [
+        115,
+        123,
+        246,
+        104,
+        190,
+        46,
+        94,
+        81
+      ]
+    },
+    {
+      "name": "OracleEmergencyDeactivated",
+      "discriminator": [
+        25,
+        82,
+        40,
+        73,
+        96,
+        26,
+        202,
+        242
+      ]
+    },
+    {
+      "name": "OracleEquivocationReported",
+      "discriminator": [
+        138,
+        174,
+        226,
+        213,
+        12,
+        3,
+        140,
+        78
+      ]
+    },
+    {
+      "name": "OutflowApproved",
+      "discriminator": [
+        6,
+        159,
+        234,
+        5,
+        224,
+        133,
+        91,
+        154
+      ]
+    },
+    {
+      "name": "OutflowCircuitBreakerTripped",
+      "discriminator": [
+        73,
+        114,
+        8,
+        53,
+        64,
+        176,
+        147,
+        111
+      ]
+    },
+    {
+      "name": "OutgoingMessageCommitted",
+      "discriminator": [
+        52,
+        52,
+        196,
+        69,
+        150,
+        241,
+        137,
+        206
+      ]
+    },
+    {
+      "name": "OutputRootSuperseded",
+      "discriminator": [
+        8,
+        201,
+        214,
+        42,
+        34,
+        143,
+        122,
+        96
+      ]
+    },
+    {
+      "name": "PayerRateLimitExemptionUpdated",
+      "discriminator": [
+        10,
+        177,
+        99,
+        161,
+        103,
+        165,
+        198,
+        18
+      ]
+    },
+    {
+      "name": "PayerRateLimitSet",
+      "discriminator": [
+        210,
+        96,
+        219,
+        29,
+        112,
+        1,
+        93,
+        76
+      ]
+    },
+    {
+      "name": "ProtocolFeeCharged",
+      "discriminator": [
+        185,
+        201,
+        45,
+        28,
+        181,
+        182,
+        41,
+        47
+      ]
+    },
+    {
+      "name": "RelayFeePaid",
+      "discriminator": [
+        24,
+        187,
+        32,
+        236,
+        145,
+        210,
+        7,
+        176
+      ]
+    },
+    {
+      "name": "RelayFeesSettled",
+      "discriminator": [
+        110,
+        19,
+        246,
+        102,
+        87,
+        30,
+        139,
+        44
+      ]
+    },
+    {
+      "name": "TransferMemo",
+      "discriminator": [
+        9,
+        95,
+        235,
+        65,
+        182,
+        90,
+        207,
+        104
+      ]
+    },
+    {
+      "name": "VaultReconciled",
+      "discriminator": [
+        242,
+        195,
+        108,
+        56,
+        98,
+        221,
+        225,
+        142
+      ]
+    },
+    {
+      "name": "WrappedTokenConfirmationRequiredSet",
+      "discriminator": [
+        203,
+        56,
+        96,
+        200,
+        1,
+        70,
+        204,
+        180
+      ]
+    },
+    {
+      "name": "WrappedTokenConfirmed",
+      "discriminator": [
+        223,
+        151,
+        185,
+        204,
+        135,
+        188,
+        99,
+        87
+      ]
+    },
+    {
+      "name": "WrappedTokenMaxSupplyUpdated",
+      "discriminator": [
+        191,
+        65,
+        227,
+        115,
+        68,
+        84,
+        77,
+        83
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 12000,
+      "name": "BridgePaused",
+      "msg": "Bridge is currently paused"
+    },
+    {
+      "code": 12001,
+      "name": "IncorrectBridgeProgram",
+      "msg": "Incorrect bridge program"
+    },
+    {
+      "code": 12002,
+      "name": "IncorrectGasFeeReceiver",
+      "msg": "Incorrect gas fee receiver"
+    },
+    {
+      "code": 12003,
+      "name": "InvalidGasFeeReceiver",
+      "msg": "Gas fee receiver must be a system account"
+    },
+    {
+      "code": 12004,
+      "name": "IncorrectGasFeeEscrow",
+      "msg": "Incorrect gas fee escrow"
+    },
+    {
+      "code": 12005,
+      "name": "MissingMemoProgram",
+      "msg": "SPL Memo program is required to log an SPL memo"
+    },
+    {
+      "code": 12006,
+      "name": "EnvironmentMismatch",
+      "msg": "Program was not built for the expected environment"
+    },
+    {
+      "code": 12007,
+      "name": "IncorrectPayerRateLimit",
+      "msg": "Incorrect payer rate limit"
+    },
+    {
+      "code": 12008,
+      "name": "PayerRateLimited",
+      "msg": "Payer exceeded its outgoing message rate limit"
+    },
+    {
+      "code": 12100,
+      "name": "UnauthorizedInitialization",
+      "msg": "Only the upgrade authority can initialize the bridge"
+    },
+    {
+      "code": 12101,
+      "name": "UnauthorizedConfigUpdate",
+      "msg": "Unauthorized to update configuration"
+    },
+    {
+      "code": 12102,
+      "name": "IncorrectSessionDelegate",
+      "msg": "Signer is not the delegate of the bridge session"
+    },
+    {
+      "code": 12103,
+      "name": "IncorrectSessionOwner",
+      "msg": "Token account is not owned by the bridge session owner"
+    },
+    {
+      "code": 12104,
+      "name": "SessionExpired",
+      "msg": "Bridge session has expired"
+    },
+    {
+      "code": 12105,
+      "name": "SessionRemoteTokenNotAllowed",
+      "msg": "Bridge session does not allow this remote token"
+    },
+    {
+      "code": 12106,
+      "name": "SessionBudgetExceeded",
+      "msg": "Amount exceeds the remaining bridge session budget"
+    },
+    {
+      "code": 12107,
+      "name": "TooManySessionRemoteTokens",
+      "msg": "Too many bridge session remote tokens"
+    },
+    {
+      "code": 12108,
+      "name": "InvalidSessionExpiry",
+      "msg": "Bridge session expiry must be in the future"
+    },
+    {
+      "code": 12200,
+      "name": "BufferUnauthorizedClose",
+      "msg": "Only the owner can close this buffer"
     },
     {
       "code": 12201,
@@ -2711,650 +9591,6077 @@ export const IDL = {
       "msg": "Only the owner can append to this buffer"
     },
     {
-      "code": 12202,
-      "name": "BufferMaxSizeExceeded",
-      "msg": "Call buffer size exceeds maximum allowed size"
+      "code": 12202,
+      "name": "BufferMaxSizeExceeded",
+      "msg": "Call buffer size exceeds maximum allowed size"
+    },
+    {
+      "code": 12203,
+      "name": "BufferExpiryDisabled",
+      "msg": "Call buffer expiry is disabled"
+    },
+    {
+      "code": 12204,
+      "name": "BufferNotExpired",
+      "msg": "Call buffer has not expired yet"
+    },
+    {
+      "code": 12205,
+      "name": "IncorrectBufferOwner",
+      "msg": "Incorrect call buffer owner"
+    },
+    {
+      "code": 12206,
+      "name": "BufferReusable",
+      "msg": "Cannot append to a reusable call buffer"
+    },
+    {
+      "code": 12207,
+      "name": "BufferNotCloneable",
+      "msg": "Only the owner can clone a call buffer that is not reusable"
+    },
+    {
+      "code": 12300,
+      "name": "InvalidRecoveryId",
+      "msg": "Invalid recovery ID"
+    },
+    {
+      "code": 12301,
+      "name": "SignatureVerificationFailed",
+      "msg": "Signature verification failed"
+    },
+    {
+      "code": 12302,
+      "name": "InsufficientBaseSignatures",
+      "msg": "Insufficient base oracle signatures to meet threshold"
+    },
+    {
+      "code": 12303,
+      "name": "InsufficientPartnerSignatures",
+      "msg": "Insufficient partner oracle signatures to meet threshold"
+    },
+    {
+      "code": 12304,
+      "name": "MissingInstructionsSysvar",
+      "msg": "Instructions sysvar is required to verify signatures through the Secp256k1 program"
+    },
+    {
+      "code": 12305,
+      "name": "InvalidSecp256k1Instruction",
+      "msg": "Invalid Secp256k1 program instruction"
+    },
+    {
+      "code": 12306,
+      "name": "OutputRootsNotConflicting",
+      "msg": "Output roots are not conflicting"
+    },
+    {
+      "code": 12307,
+      "name": "NoEquivocatingSigner",
+      "msg": "No Base oracle signer attested both output roots"
+    },
+    {
+      "code": 12308,
+      "name": "TooManySignatures",
+      "msg": "Too many signatures (max 32)"
+    },
+    {
+      "code": 12400,
+      "name": "InvalidProof",
+      "msg": "Invalid proof"
+    },
+    {
+      "code": 12401,
+      "name": "MmrShouldBeEmpty",
+      "msg": "MMR should be empty"
+    },
+    {
+      "code": 12402,
+      "name": "EmptyMmr",
+      "msg": "MMR is empty"
+    },
+    {
+      "code": 12403,
+      "name": "LeafMountainNotFound",
+      "msg": "Leaf's mountain not found"
+    },
+    {
+      "code": 12404,
+      "name": "InsufficientProofElementsForIntraMountainPath",
+      "msg": "Insufficient proof elements for intra-mountain path"
+    },
+    {
+      "code": 12405,
+      "name": "InsufficientProofElementsForOtherMountainPeaks",
+      "msg": "Insufficient proof elements for other mountain peaks"
+    },
+    {
+      "code": 12406,
+      "name": "UnusedProofElementsRemaining",
+      "msg": "Unused proof elements remaining"
+    },
+    {
+      "code": 12407,
+      "name": "NoPeaksFoundForNonEmptyMmr",
+      "msg": "No peaks found for non-empty MMR"
+    },
+    {
+      "code": 12408,
+      "name": "OutboxFull",
+      "msg": "Outbox MMR is full"
+    },
+    {
+      "code": 12500,
+      "name": "InvalidMessageHash",
+      "msg": "Invalid message hash"
+    },
+    {
+      "code": 12501,
+      "name": "AlreadyExecuted",
+      "msg": "Message already executed"
+    },
+    {
+      "code": 12502,
+      "name": "IncorrectBlockNumber",
+      "msg": "Incorrect block number"
+    },
+    {
+      "code": 12503,
+      "name": "UnauthorizedReturnTransfer",
+      "msg": "Return transfers can only be sent by the Base Bridge"
+    },
+    {
+      "code": 12504,
+      "name": "IncorrectReturnedTransfer",
+      "msg": "Incorrect returned transfer account"
+    },
+    {
+      "code": 12505,
+      "name": "IncorrectAddressBook",
+      "msg": "Incorrect address book account"
+    },
+    {
+      "code": 12506,
+      "name": "TransferAlreadyReturned",
+      "msg": "Transfer already returned"
+    },
+    {
+      "code": 12507,
+      "name": "InvalidRelayedNonce",
+      "msg": "Relayed nonce is outside of the page or not yet assigned"
+    },
+    {
+      "code": 12508,
+      "name": "MessageNotRelayed",
+      "msg": "Outgoing message has not been relayed on Base"
+    },
+    {
+      "code": 12509,
+      "name": "IncorrectOutgoingMessageSender",
+      "msg": "Incorrect outgoing message sender"
+    },
+    {
+      "code": 12510,
+      "name": "TooManyExtraSigners",
+      "msg": "Too many extra signers"
+    },
+    {
+      "code": 12511,
+      "name": "TooManyExtraSignerSeeds",
+      "msg": "Too many seeds for an extra signer"
+    },
+    {
+      "code": 12512,
+      "name": "ExtraSignerSeedTooLong",
+      "msg": "Extra signer seed too long"
+    },
+    {
+      "code": 12513,
+      "name": "EmptyOutputRootsBatch",
+      "msg": "Output roots batch is empty"
+    },
+    {
+      "code": 12514,
+      "name": "TooManyOutputRoots",
+      "msg": "Too many output roots in batch"
+    },
+    {
+      "code": 12515,
+      "name": "IncorrectOutputRootAccount",
+      "msg": "Incorrect output root account"
+    },
+    {
+      "code": 12516,
+      "name": "MissingAddressBook",
+      "msg": "Address book account is required to prove this message"
+    },
+    {
+      "code": 12517,
+      "name": "InsufficientComputeBudget",
+      "msg": "Remaining compute units are below the amount required by the message"
+    },
+    {
+      "code": 12518,
+      "name": "NestedComputeBudget",
+      "msg": "Compute budget messages cannot be nested"
+    },
+    {
+      "code": 12519,
+      "name": "IncorrectRemoteChainId",
+      "msg": "Message remote chain ID does not match the configured remote chain"
+    },
+    {
+      "code": 12520,
+      "name": "NestedRelayFee",
+      "msg": "Relay fee messages cannot wrap compute budget or relay fee messages"
+    },
+    {
+      "code": 12521,
+      "name": "MissingRelayFeeAccounts",
+      "msg": "Relay fee vault and relayer accounts are required to relay this message"
+    },
+    {
+      "code": 12522,
+      "name": "InvalidRelayFeeSettlementNonce",
+      "msg": "Relay fee settlement nonce must follow the latest settlement"
+    },
+    {
+      "code": 12523,
+      "name": "StaleOutputRoot",
+      "msg": "Output root is older than the freshness window of the message kind"
+    },
+    {
+      "code": 12524,
+      "name": "TooManyMessageIxs",
+      "msg": "Too many instructions in message"
+    },
+    {
+      "code": 12525,
+      "name": "TooManyIxAccounts",
+      "msg": "Too many accounts in message instruction"
+    },
+    {
+      "code": 12526,
+      "name": "MessageIxsDataTooLong",
+      "msg": "Message instruction data too long"
+    },
+    {
+      "code": 12527,
+      "name": "InvalidIxProgramId",
+      "msg": "Message instruction targets a program that cannot be invoked"
+    },
+    {
+      "code": 12528,
+      "name": "TooManyProvenChunks",
+      "msg": "Message instructions span too many proven chunks"
+    },
+    {
+      "code": 12529,
+      "name": "ProvenChunkTooLarge",
+      "msg": "Message instruction does not fit in a proven chunk"
+    },
+    {
+      "code": 12530,
+      "name": "IncorrectProvenChunkAccount",
+      "msg": "Incorrect proven chunk account"
+    },
+    {
+      "code": 12531,
+      "name": "MessageAlreadyRelayed",
+      "msg": "Outgoing message has been relayed on Base"
+    },
+    {
+      "code": 12532,
+      "name": "NoLockedTokens",
+      "msg": "Outgoing message did not lock SOL or SPL tokens"
+    },
+    {
+      "code": 12533,
+      "name": "OutputRootChallengeWindowClosed",
+      "msg": "Output root challenge window has closed"
+    },
+    {
+      "code": 12534,
+      "name": "SupersededOutputRoot",
+      "msg": "Message was proven against a superseded output root revision"
+    },
+    {
+      "code": 12535,
+      "name": "OutputRootNotSuperseded",
+      "msg": "Message was proven against the current output root revision"
+    },
+    {
+      "code": 12536,
+      "name": "IncorrectProver",
+      "msg": "Incorrect prover account"
+    },
+    {
+      "code": 12537,
+      "name": "FinalizationDelayNotElapsed",
+      "msg": "Transfer is still held by the finalization delay"
+    },
+    {
+      "code": 12538,
+      "name": "MessageVetoed",
+      "msg": "Message was vetoed by the guardian"
+    },
+    {
+      "code": 12539,
+      "name": "MessageNotInFinalizationDelay",
+      "msg": "Message is not held by the finalization delay"
+    },
+    {
+      "code": 12540,
+      "name": "MessageNotCoveredByOutputRoot",
+      "msg": "Output root does not cover the message nonce"
+    },
+    {
+      "code": 12541,
+      "name": "DeniedRelayProgram",
+      "msg": "Relayed message invokes a denied program"
+    },
+    {
+      "code": 12542,
+      "name": "NestedLamportAllowance",
+      "msg": "Lamport allowance messages cannot wrap other wrapper messages"
+    },
+    {
+      "code": 12543,
+      "name": "MissingLamportAllowanceAccounts",
+      "msg": "Operational vault and bridge CPI authority accounts are required to relay this message"
+    },
+    {
+      "code": 12544,
+      "name": "LamportAllowanceTooHigh",
+      "msg": "Lamport allowance exceeds the maximum allowed by the operational vault"
+    },
+    {
+      "code": 12545,
+      "name": "InsufficientOperationalVault",
+      "msg": "Operational vault balance is insufficient for the lamport allowance"
+    },
+    {
+      "code": 12546,
+      "name": "UnauthorizedWrappedTokenConfirmation",
+      "msg": "Wrapped token registrations can only be confirmed by the Base Bridge"
+    },
+    {
+      "code": 12547,
+      "name": "HandlerPayloadTooLong",
+      "msg": "Handler payload exceeds MAX_HANDLER_PAYLOAD_LEN"
+    },
+    {
+      "code": 12548,
+      "name": "HandlerNotRegistered",
+      "msg": "No handler is registered for the message sender"
+    },
+    {
+      "code": 12549,
+      "name": "IncorrectHandlerAccounts",
+      "msg": "Incorrect handler program or bridge CPI authority account"
+    },
+    {
+      "code": 12550,
+      "name": "UnauthorizedHandlerRegistration",
+      "msg": "Handlers can only be registered by their Base sender"
+    },
+    {
+      "code": 12551,
+      "name": "OutflowApprovalRequired",
+      "msg": "Message tripped the outflow circuit breaker and awaits the guardian approval"
+    },
+    {
+      "code": 12552,
+      "name": "OutflowNotHeld",
+      "msg": "Message is not held by the outflow circuit breaker"
+    },
+    {
+      "code": 12600,
+      "name": "MintDoesNotMatchLocalToken",
+      "msg": "Mint does not match local token"
+    },
+    {
+      "code": 12601,
+      "name": "TokenAccountDoesNotMatchTo",
+      "msg": "Token account does not match to address"
+    },
+    {
+      "code": 12602,
+      "name": "IncorrectTokenVault",
+      "msg": "Incorrect token vault"
+    },
+    {
+      "code": 12603,
+      "name": "MintIsWrappedToken",
+      "msg": "Mint is a wrapped token"
+    },
+    {
+      "code": 12604,
+      "name": "IncorrectTo",
+      "msg": "Incorrect to"
+    },
+    {
+      "code": 12605,
+      "name": "IncorrectSolVault",
+      "msg": "Incorrect sol vault"
+    },
+    {
+      "code": 12606,
+      "name": "IncorrectTokenDeposits",
+      "msg": "Incorrect token deposits account"
+    },
+    {
+      "code": 12607,
+      "name": "IncorrectProtocolFeeTreasury",
+      "msg": "Incorrect protocol fee treasury"
+    },
+    {
+      "code": 12608,
+      "name": "TransferMemoTooLong",
+      "msg": "Transfer memo exceeds MAX_TRANSFER_MEMO_LEN"
+    },
+    {
+      "code": 12609,
+      "name": "StaleLiabilityRoot",
+      "msg": "Liability root must be for a newer Base block"
+    },
+    {
+      "code": 12610,
+      "name": "ScaledAmountOverflow",
+      "msg": "Scaled wrapped token amount overflows"
+    },
+    {
+      "code": 12611,
+      "name": "InexactScaledAmount",
+      "msg": "Remote amount is not a multiple of the wrapped token scalar"
+    },
+    {
+      "code": 12612,
+      "name": "EmptyTransferBatch",
+      "msg": "Transfer batch has no recipients"
+    },
+    {
+      "code": 12613,
+      "name": "TooManyBatchRecipients",
+      "msg": "Transfer batch exceeds MAX_TRANSFER_BATCH_RECIPIENTS"
+    },
+    {
+      "code": 12614,
+      "name": "MintNotOwnedByTokenProgram",
+      "msg": "Mint is not owned by the token program"
+    },
+    {
+      "code": 12615,
+      "name": "FreezeAuthorityNotAllowed",
+      "msg": "Mint freeze authority is not allowed by the mint policy"
+    },
+    {
+      "code": 12616,
+      "name": "UnauthorizedCrosschainMint",
+      "msg": "Crosschain mints can only be requested by the Base Bridge"
+    },
+    {
+      "code": 12617,
+      "name": "WrappedSupplyCapExceeded",
+      "msg": "Mint would exceed the wrapped token max supply"
+    },
+    {
+      "code": 12618,
+      "name": "IncorrectWrappedSupplyCapAccount",
+      "msg": "Incorrect wrapped token supply cap account"
+    },
+    {
+      "code": 12619,
+      "name": "UnauthorizedSupplyCapUpdate",
+      "msg": "Wrapped token max supplies can only be set by the Base Bridge"
+    },
+    {
+      "code": 12620,
+      "name": "IncorrectVaultDecimalsAccount",
+      "msg": "Incorrect vault decimals account"
+    },
+    {
+      "code": 12621,
+      "name": "MintNotAllowListed",
+      "msg": "Mint is not on the allow-list during guarded launch"
+    },
+    {
+      "code": 12622,
+      "name": "IncorrectWrappedTokenConfirmation",
+      "msg": "Incorrect wrapped token confirmation account"
+    },
+    {
+      "code": 12623,
+      "name": "WrappedTokenNotConfirmed",
+      "msg": "Wrapped token registration has not been confirmed by Base"
+    },
+    {
+      "code": 12624,
+      "name": "WrappedTokenMetadataMismatch",
+      "msg": "Confirmed metadata does not match the wrapped token"
+    },
+    {
+      "code": 12700,
+      "name": "RemoteTokenNotFound",
+      "msg": "Remote token not found"
+    },
+    {
+      "code": 12701,
+      "name": "ScalerExponentNotFound",
+      "msg": "Scaler exponent not found"
+    },
+    {
+      "code": 12702,
+      "name": "InvalidRemoteToken",
+      "msg": "Invalid remote token"
+    },
+    {
+      "code": 12703,
+      "name": "InvalidScalerExponent",
+      "msg": "Invalid scaler exponent"
+    },
+    {
+      "code": 12704,
+      "name": "MintIsNotFromToken2022",
+      "msg": "Mint is not a token 2022 mint"
+    },
+    {
+      "code": 12705,
+      "name": "MintIsNotWrappedTokenPda",
+      "msg": "Mint is not a valid wrapped token PDA"
+    },
+    {
+      "code": 12706,
+      "name": "IncorrectMetaplexMetadata",
+      "msg": "Incorrect Metaplex metadata account"
+    },
+    {
+      "code": 12707,
+      "name": "MissingMetaplexProgram",
+      "msg": "Metaplex program is required to create Metaplex metadata"
+    },
+    {
+      "code": 12708,
+      "name": "EmptyTokenMetadata",
+      "msg": "Token name and symbol must not be empty"
+    },
+    {
+      "code": 12709,
+      "name": "TokenNameTooLong",
+      "msg": "Token name exceeds MAX_WRAPPED_TOKEN_NAME_LEN"
+    },
+    {
+      "code": 12710,
+      "name": "TokenSymbolTooLong",
+      "msg": "Token symbol exceeds MAX_WRAPPED_TOKEN_SYMBOL_LEN"
+    },
+    {
+      "code": 12711,
+      "name": "UntrimmedTokenMetadata",
+      "msg": "Token name and symbol must not start or end with whitespace"
+    },
+    {
+      "code": 12712,
+      "name": "InvalidTokenMetadataCharacter",
+      "msg": "Token name or symbol contains a disallowed character"
+    },
+    {
+      "code": 12800,
+      "name": "InvalidThreshold",
+      "msg": "Threshold must be <= number of signers"
+    },
+    {
+      "code": 12801,
+      "name": "TooManySigners",
+      "msg": "Too many signers (max 32)"
+    },
+    {
+      "code": 12802,
+      "name": "DuplicateSigner",
+      "msg": "Duplicate signer found"
+    },
+    {
+      "code": 12803,
+      "name": "InvalidPartnerThreshold",
+      "msg": "Invalid partner threshold"
+    },
+    {
+      "code": 12804,
+      "name": "InvalidDenominator",
+      "msg": "Invalid denominator"
+    },
+    {
+      "code": 12805,
+      "name": "InvalidWindowDurationSeconds",
+      "msg": "Invalid window duration seconds"
+    },
+    {
+      "code": 12806,
+      "name": "InvalidGasCostScalerDp",
+      "msg": "Invalid gas cost scaler dp"
+    },
+    {
+      "code": 12807,
+      "name": "InvalidBlockIntervalRequirement",
+      "msg": "Invalid block interval requirement"
+    },
+    {
+      "code": 12808,
+      "name": "InvalidBridgeLayout",
+      "msg": "Unrecognized bridge account layout"
+    },
+    {
+      "code": 12809,
+      "name": "BridgeAlreadyMigrated",
+      "msg": "Bridge account is already at the current layout version"
+    },
+    {
+      "code": 12810,
+      "name": "InvalidMaxBaseFee",
+      "msg": "Max base fee must be >= minimum base fee"
+    },
+    {
+      "code": 12811,
+      "name": "InvalidExpireBountyBps",
+      "msg": "Expire bounty must be <= 10000 bps"
+    },
+    {
+      "code": 12812,
+      "name": "TooManyAddressBookTokens",
+      "msg": "Too many address book tokens"
+    },
+    {
+      "code": 12813,
+      "name": "DuplicateAddressBookToken",
+      "msg": "Duplicate address book token"
+    },
+    {
+      "code": 12814,
+      "name": "AddressBookEntryNotSet",
+      "msg": "Address book entry is not set"
+    },
+    {
+      "code": 12815,
+      "name": "TooManyProtocolFeeOverrides",
+      "msg": "Too many protocol fee overrides"
+    },
+    {
+      "code": 12816,
+      "name": "DuplicateProtocolFeeOverride",
+      "msg": "Duplicate protocol fee override"
+    },
+    {
+      "code": 12817,
+      "name": "InvalidProtocolFeeBps",
+      "msg": "Protocol fee must be <= MAX_PROTOCOL_FEE_BPS"
+    },
+    {
+      "code": 12818,
+      "name": "OracleNotStalled",
+      "msg": "Output roots have not stalled long enough to activate the emergency quorum"
+    },
+    {
+      "code": 12819,
+      "name": "TooManyEmergencySigners",
+      "msg": "Too many emergency signers"
+    },
+    {
+      "code": 12820,
+      "name": "InvalidEmergencyThreshold",
+      "msg": "Emergency threshold must be > 0 and <= number of Base oracle and emergency signers"
+    },
+    {
+      "code": 12821,
+      "name": "InvalidEmergencyDuration",
+      "msg": "Emergency duration must be > 0 when the oracle failover is enabled"
+    },
+    {
+      "code": 12822,
+      "name": "TooManyAllowedFreezeAuthorities",
+      "msg": "Too many allowed freeze authorities"
+    },
+    {
+      "code": 12823,
+      "name": "EmergencyExitRequiresPause",
+      "msg": "Emergency exit requires the bridge to be paused"
+    },
+    {
+      "code": 12824,
+      "name": "EmergencyExitTimelocked",
+      "msg": "Emergency exit timelock has not elapsed"
+    },
+    {
+      "code": 12825,
+      "name": "TooManyOutflowThresholds",
+      "msg": "Too many outflow thresholds"
+    },
+    {
+      "code": 12826,
+      "name": "InvalidOutflowThreshold",
+      "msg": "Outflow thresholds must be > 0 and set once per token"
+    },
+    {
+      "code": 12827,
+      "name": "InvalidGasTarget",
+      "msg": "Invalid gas target"
+    },
+    {
+      "code": 12828,
+      "name": "TooManyFinalizationThresholds",
+      "msg": "Too many finalization delay thresholds"
+    },
+    {
+      "code": 12829,
+      "name": "InvalidFinalizationThreshold",
+      "msg": "Finalization delay thresholds must be set once per token"
+    },
+    {
+      "code": 12830,
+      "name": "InvalidRemoteDecimals",
+      "msg": "Remote decimals cannot exceed the mint decimals"
+    },
+    {
+      "code": 12831,
+      "name": "VaultHasDeposits",
+      "msg": "Vault decimals cannot change while the vault holds deposits"
+    },
+    {
+      "code": 12832,
+      "name": "BaseGovernanceNotSet",
+      "msg": "Base governance is not set"
+    },
+    {
+      "code": 12833,
+      "name": "GovernedByBase",
+      "msg": "This configuration is governed from Base"
+    },
+    {
+      "code": 12834,
+      "name": "InvalidInsuranceShare",
+      "msg": "Insurance share exceeds the maximum"
+    },
+    {
+      "code": 12835,
+      "name": "InsurancePayoutPending",
+      "msg": "An insurance payout is already pending"
+    },
+    {
+      "code": 12836,
+      "name": "NoPendingInsurancePayout",
+      "msg": "No insurance payout is pending"
+    },
+    {
+      "code": 12837,
+      "name": "InsurancePayoutTimelocked",
+      "msg": "Insurance payout timelock has not elapsed"
+    },
+    {
+      "code": 12838,
+      "name": "InsufficientInsuranceFund",
+      "msg": "Insurance fund balance is insufficient for the payout"
+    },
+    {
+      "code": 12839,
+      "name": "IncorrectInsurancePayoutRecipient",
+      "msg": "Incorrect insurance payout recipient"
+    },
+    {
+      "code": 12840,
+      "name": "TooManyDeniedRelayPrograms",
+      "msg": "Too many denied relay programs"
+    },
+    {
+      "code": 12841,
+      "name": "InvalidMaintenanceFeeReimbursement",
+      "msg": "Maintenance fee reimbursement exceeds the maximum"
+    },
+    {
+      "code": 12842,
+      "name": "InvalidMaxLamportAllowance",
+      "msg": "Maximum lamport allowance exceeds the limit"
+    },
+    {
+      "code": 12843,
+      "name": "TooManyAllowedMints",
+      "msg": "Too many allowed mints"
+    },
+    {
+      "code": 12844,
+      "name": "TooManyDexRouters",
+      "msg": "Too many DEX routers"
+    },
+    {
+      "code": 12845,
+      "name": "TooManyRateLimitExemptPayers",
+      "msg": "Too many payers exempted from rate limiting"
+    },
+    {
+      "code": 12900,
+      "name": "CreationWithNonZeroTarget",
+      "msg": "Creation with non-zero target"
+    },
+    {
+      "code": 12901,
+      "name": "ZeroAddress",
+      "msg": "Zero address"
+    },
+    {
+      "code": 12902,
+      "name": "EmptyEncryptedCall",
+      "msg": "Encrypted call has no ciphertext"
+    },
+    {
+      "code": 12903,
+      "name": "MissingSwapGuard",
+      "msg": "Calls to a registered DEX router require a swap guard"
+    },
+    {
+      "code": 12904,
+      "name": "SwapGuardWithoutCall",
+      "msg": "Swap guard requires a call"
+    },
+    {
+      "code": 12905,
+      "name": "InvalidSwapGuardMinOut",
+      "msg": "Swap guard minimum output must be non-zero"
+    },
+    {
+      "code": 12906,
+      "name": "SwapGuardExpired",
+      "msg": "Swap guard deadline has passed"
+    },
+    {
+      "code": 12907,
+      "name": "ProtocolFeeExceedsAmount",
+      "msg": "Protocol fee exceeds the bridged amount"
+    },
+    {
+      "code": 12908,
+      "name": "MissingDexRouterList",
+      "msg": "DEX router list required to check the call"
+    }
+  ],
+  "types": [
+    {
+      "name": "AddressBook",
+      "docs": [
+        "Guardian-maintained registry of canonical Base addresses, so that instructions and SDKs",
+        "share a single on-chain source of truth instead of hardcoded constants.",
+        "Unset addresses are zero."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bridge",
+            "docs": [
+              "The Base Bridge contract."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "relayer_orchestrator",
+            "docs": [
+              "The Base RelayerOrchestrator contract."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "token_count",
+            "docs": [
+              "Number of tokens in `tokens` array"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "tokens",
+            "docs": [
+              "Standard tokens bridged between Solana and Base"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "AddressBookToken"
+                  }
+                },
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AddressBookToken",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "local_token",
+            "docs": [
+              "The token on Solana (a mint, or `NATIVE_SOL_PUBKEY` for SOL)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "remote_token",
+            "docs": [
+              "The token address on Base"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AuditReport",
+      "docs": [
+        "Emitted by `audit_vault` with the expected and actual balances of a bridge vault."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "docs": [
+              "The audited vault (the SOL vault or an SPL token vault)."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "expected_amount",
+            "docs": [
+              "Amount expected to be locked according to the `TokenDeposits` accounting."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "actual_amount",
+            "docs": [
+              "Amount actually held by the vault."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "discrepancy",
+            "docs": [
+              "`actual_amount - expected_amount`; negative values indicate a shortfall."
+            ],
+            "type": "i128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BaseFeeCapped",
+      "docs": [
+        "Emitted when the EIP-1559 base fee adjustment is bounded by `max_base_fee` or",
+        "`max_window_increase_bps`, signalling abnormal demand."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "uncapped_base_fee",
+            "docs": [
+              "Base fee the adjustment would have produced without caps."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "base_fee",
+            "docs": [
+              "Base fee after applying the caps."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BaseGovernance",
+      "docs": [
+        "Governance contract on Base (e.g. the security council) controlling the critical configuration",
+        "of the bridge through relayed messages, so that both sides are governed consistently. The",
+        "upgrade authority designates the contract, which then enforces its control by relaying",
+        "`set_governance_mode`.",
+        "",
+        "While enforced, the Base oracle signers can only be set by the contract. The guardian keeps",
+        "its pause switch: nothing can be proven or relayed while the bridge is paused, so the contract",
+        "can pause the bridge but not unpause it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "governance",
+            "docs": [
+              "The governance contract on Base (zero when unset)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "enforced",
+            "docs": [
+              "Whether the critical configuration can only be updated by the governance contract"
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BaseOracleConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "threshold",
+            "docs": [
+              "Number of required valid unique signatures"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "signer_count",
+            "docs": [
+              "Number of signers in `signers` array"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "signers",
+            "docs": [
+              "Static list of authorized signer addresses"
+            ],
+            "type": {
+              "array": [
+                {
+                  "array": [
+                    "u8",
+                    20
+                  ]
+                },
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BatchRecipient",
+      "docs": [
+        "A recipient on Base of a `TransferBatch`, along with the amount it receives."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "to",
+            "docs": [
+              "The recipient address on Base."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount the recipient receives, in the token's smallest unit."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Bridge",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "docs": [
+              "Layout version of this account (see `BRIDGE_LAYOUT_VERSION`).",
+              "Must remain the first field so older layouts can be detected before deserialization."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "base_block_number",
+            "docs": [
+              "The Base block number associated with the latest registered output root."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Incremental nonce assigned to each outgoing message."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "guardian",
+            "docs": [
+              "Guardian pubkey authorized to update bridge configuration parameters"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Whether the bridge is paused (emergency stop mechanism)"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "eip1559",
+            "docs": [
+              "EIP-1559 state and configuration for dynamic pricing."
+            ],
+            "type": {
+              "defined": {
+                "name": "Eip1559"
+              }
+            }
+          },
+          {
+            "name": "gas_config",
+            "docs": [
+              "Configuration parameters for outgoing message pricing"
+            ],
+            "type": {
+              "defined": {
+                "name": "GasConfig"
+              }
+            }
+          },
+          {
+            "name": "protocol_config",
+            "docs": [
+              "Configuration parameters for bridge protocol"
+            ],
+            "type": {
+              "defined": {
+                "name": "ProtocolConfig"
+              }
+            }
+          },
+          {
+            "name": "buffer_config",
+            "docs": [
+              "Configuration parameters for pre-loading Solana --> Base messages in buffer accounts"
+            ],
+            "type": {
+              "defined": {
+                "name": "BufferConfig"
+              }
+            }
+          },
+          {
+            "name": "partner_oracle_config",
+            "docs": [
+              "Partner oracle configuration containing the required signature threshold"
+            ],
+            "type": {
+              "defined": {
+                "name": "PartnerOracleConfig"
+              }
+            }
+          },
+          {
+            "name": "base_oracle_config",
+            "docs": [
+              "Configuration parameters for Base oracle signers"
+            ],
+            "type": {
+              "defined": {
+                "name": "BaseOracleConfig"
+              }
+            }
+          },
+          {
+            "name": "oracle_failover",
+            "docs": [
+              "Emergency oracle quorum used when output roots stop being registered"
+            ],
+            "type": {
+              "defined": {
+                "name": "OracleFailover"
+              }
+            }
+          },
+          {
+            "name": "root_freshness_config",
+            "docs": [
+              "Maximum output root age accepted by `prove_message` for each message kind"
+            ],
+            "type": {
+              "defined": {
+                "name": "RootFreshnessConfig"
+              }
+            }
+          },
+          {
+            "name": "oracle_health",
+            "docs": [
+              "Latest output root signed by each Base oracle signer"
+            ],
+            "type": {
+              "defined": {
+                "name": "OracleHealth"
+              }
+            }
+          },
+          {
+            "name": "outflow_breaker",
+            "docs": [
+              "Circuit breaker pausing the bridge on anomalous outflows"
+            ],
+            "type": {
+              "defined": {
+                "name": "OutflowBreaker"
+              }
+            }
+          },
+          {
+            "name": "finalization_delay",
+            "docs": [
+              "Delay before high-value incoming transfers can be relayed"
+            ],
+            "type": {
+              "defined": {
+                "name": "FinalizationDelay"
+              }
+            }
+          },
+          {
+            "name": "base_governance",
+            "docs": [
+              "Governance contract on Base controlling the critical configuration"
+            ],
+            "type": {
+              "defined": {
+                "name": "BaseGovernance"
+              }
+            }
+          },
+          {
+            "name": "outbox",
+            "docs": [
+              "Merkle mountain range committing to every outgoing message, proven against on Base"
+            ],
+            "type": {
+              "defined": {
+                "name": "Outbox"
+              }
+            }
+          },
+          {
+            "name": "guarded_launch",
+            "docs": [
+              "Whether `bridge_spl` only accepts the mints of the guardian-maintained allow-list, while",
+              "the bridge is gradually opened after launch"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "transfer_gas_lane",
+            "docs": [
+              "EIP-1559 state and configuration pricing token transfers apart from calls, so that demand",
+              "in one lane does not distort pricing in the other. Transfers are priced by `eip1559` while",
+              "its target is zero."
+            ],
+            "type": {
+              "defined": {
+                "name": "Eip1559"
+              }
+            }
+          },
+          {
+            "name": "require_wrapped_token_confirmation",
+            "docs": [
+              "Whether `bridge_wrapped_token` only accepts wrapped tokens whose registration was confirmed",
+              "by the Base Bridge, so that tokens are not burned for a token Base does not know yet"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "fee_quote_grace",
+            "docs": [
+              "Grace period keeping gas fee quotes valid across EIP-1559 window rollovers"
+            ],
+            "type": {
+              "defined": {
+                "name": "FeeQuoteGrace"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "BridgeSession",
+      "docs": [
+        "Pre-authorized bridging budget letting `delegate` bridge the SPL and wrapped tokens of `owner`",
+        "without the owner signing each transaction.",
+        "",
+        "The session PDA, derived from `[BRIDGE_SESSION_SEED, owner, delegate]`, must be approved by the",
+        "owner as the SPL delegate of the bridged token accounts, as it signs the token transfers and",
+        "burns on behalf of the owner. Messages bridged through a session are sent by `owner`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "docs": [
+              "The owner of the bridged tokens and sender of the outgoing messages."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "delegate",
+            "docs": [
+              "The key allowed to bridge on behalf of `owner`."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "remaining_budget",
+            "docs": [
+              "Amount, in the smallest units of the bridged token and including the protocol fee, that",
+              "can still be bridged through the session."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expires_at",
+            "docs": [
+              "Unix timestamp from which the session can no longer be used."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "allowed_remote_tokens",
+            "docs": [
+              "Base tokens the session can bridge to, at most `MAX_SESSION_REMOTE_TOKENS`."
+            ],
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  20
+                ]
+              }
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Bump of the session PDA, used to sign as the SPL delegate."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BufferConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "max_call_buffer_size",
+            "docs": [
+              "Maximum call buffer size. This caps the max size of a Solana → Base message."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "call_buffer_ttl_seconds",
+            "docs": [
+              "Seconds a call buffer must stay unmodified before anyone can expire it (0 disables expiry)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "expire_bounty_bps",
+            "docs": [
+              "Share of an expired call buffer's rent paid to the caller, in basis points."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BurnReceipt",
+      "docs": [
+        "Immutable record of a wrapped token burn, linking it to the Base address the tokens are",
+        "released to. Created by `bridge_wrapped_token` and never closed, so that auditors can account",
+        "for burns without relying on event retention or on the outgoing message, which is closed once",
+        "relayed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "burner",
+            "docs": [
+              "The owner of the burned tokens (the session owner when bridging through a session)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "mint",
+            "docs": [
+              "The wrapped token mint"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount burned, net of the protocol fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "to",
+            "docs": [
+              "The Base address receiving the tokens"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Nonce of the outgoing message bridging the burned tokens"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "docs": [
+              "Unix timestamp of the burn"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Call",
+      "docs": [
+        "Represents a contract call to be executed on Base.",
+        "Contains all the necessary information to perform various types of contract interactions,",
+        "including regular calls, delegate calls, and contract creation operations."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "ty",
+            "docs": [
+              "The type of call operation to perform (Call, DelegateCall, Create, or Create2).",
+              "Determines how the call will be executed on the Base side."
+            ],
+            "type": {
+              "defined": {
+                "name": "CallType"
+              }
+            }
+          },
+          {
+            "name": "to",
+            "docs": [
+              "The target address on Base (20 bytes for Ethereum-compatible address).",
+              "Must be set to zero for Create and Create2 operations."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "value",
+            "docs": [
+              "Amount of ETH to send with this call on Base, in wei."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "data",
+            "docs": [
+              "The encoded function call data or contract bytecode.",
+              "For regular calls: ABI-encoded function signature and parameters.",
+              "For contract creation: the contract's initialization bytecode.",
+              "`INIT_SPACE` only accounts for its length prefix, see `Call::space`."
+            ],
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CallBuffer",
+      "docs": [
+        "A buffer account that stores call parameters which can be built up over multiple transactions",
+        "to bypass Solana's transaction size limits. The `data` field can be appended incrementally, and",
+        "the account is consumed (closed to `owner`) by the buffered bridge instructions when the call is",
+        "bridged to Base, unless the owner marked it `reusable`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "docs": [
+              "The owner who can modify this call buffer"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "ty",
+            "docs": [
+              "The type of call operation to perform (Call, DelegateCall, Create, or Create2).",
+              "Determines how the call will be executed on the Base side."
+            ],
+            "type": {
+              "defined": {
+                "name": "CallType"
+              }
+            }
+          },
+          {
+            "name": "to",
+            "docs": [
+              "The target address on Base (20 bytes for Ethereum-compatible address).",
+              "Must be set to zero for Create and Create2 operations."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "value",
+            "docs": [
+              "The amount of Base native currency (ETH) to send with this call, in wei."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "last_modified",
+            "docs": [
+              "Unix timestamp of the last initialization, append or reuse.",
+              "Used to expire abandoned buffers after `buffer_config.call_buffer_ttl_seconds`."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "reusable",
+            "docs": [
+              "Whether buffered bridge instructions keep the buffer instead of closing it, so that the",
+              "same call can be bridged repeatedly. Reusable buffers cannot be appended to."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "use_count",
+            "docs": [
+              "Number of times the buffer was read by a buffered bridge instruction."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "data",
+            "docs": [
+              "The encoded function call data or contract bytecode.",
+              "For regular calls: ABI-encoded function signature and parameters.",
+              "For contract creation: the contract's initialization bytecode.",
+              "`INIT_SPACE` only accounts for its length prefix, see `CallBuffer::space`."
+            ],
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CallBufferAppended",
+      "docs": [
+        "Emitted by `append_to_call_buffer` so clients can track upload progress."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "call_buffer",
+            "docs": [
+              "The call buffer account."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the call buffer."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bytes_appended",
+            "docs": [
+              "Number of bytes appended by this instruction."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "total_len",
+            "docs": [
+              "Total length of the buffered call data after the append."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CallBufferCloned",
+      "docs": [
+        "Emitted by `clone_call_buffer` when a call buffer is copied into a new one."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "source",
+            "docs": [
+              "The copied call buffer."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "call_buffer",
+            "docs": [
+              "The new call buffer account."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the new call buffer."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "total_len",
+            "docs": [
+              "Length of the copied call data."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "max_data_len",
+            "docs": [
+              "Maximum data length the new buffer was allocated for."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CallBufferClosed",
+      "docs": [
+        "Emitted by `close_call_buffer` when a call buffer is closed without being bridged."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "call_buffer",
+            "docs": [
+              "The call buffer account."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the call buffer, which receives the rent refund."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "total_len",
+            "docs": [
+              "Total length of the buffered call data at close time."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CallBufferExpired",
+      "docs": [
+        "Emitted by `expire_call_buffer` when an idle call buffer is garbage collected."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "call_buffer",
+            "docs": [
+              "The call buffer account."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the call buffer, which receives the remaining rent."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "caller",
+            "docs": [
+              "Account that expired the buffer and received the bounty."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bounty",
+            "docs": [
+              "Lamports paid to the caller."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CallBufferInitialized",
+      "docs": [
+        "Emitted by `initialize_call_buffer` when a new call buffer is created."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "call_buffer",
+            "docs": [
+              "The call buffer account."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the call buffer."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bytes_appended",
+            "docs": [
+              "Number of bytes written by the initial data."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "total_len",
+            "docs": [
+              "Total length of the buffered call data."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "max_data_len",
+            "docs": [
+              "Maximum data length the buffer was allocated for."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CallBufferReusableSet",
+      "docs": [
+        "Emitted by `set_call_buffer_reusable` when a call buffer is marked or unmarked as reusable."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "call_buffer",
+            "docs": [
+              "The call buffer account."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the call buffer."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "reusable",
+            "docs": [
+              "Whether the buffer is now reusable."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CallBufferUsed",
+      "docs": [
+        "Emitted by the buffered bridge instructions when a call buffer is read."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "call_buffer",
+            "docs": [
+              "The call buffer account."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Owner of the call buffer."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "use_count",
+            "docs": [
+              "Number of times the buffer was used, including this use."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "total_len",
+            "docs": [
+              "Length of the bridged call data."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "reusable",
+            "docs": [
+              "Whether the buffer was kept for reuse instead of being closed."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CallType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Call"
+          },
+          {
+            "name": "DelegateCall"
+          },
+          {
+            "name": "Create"
+          },
+          {
+            "name": "Create2"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "eip1559_config",
+            "docs": [
+              "Configuration parameters for EIP-1559-inspired fee calculations"
+            ],
+            "type": {
+              "defined": {
+                "name": "Eip1559Config"
+              }
+            }
+          },
+          {
+            "name": "gas_config",
+            "docs": [
+              "Configuration parameters for outgoing message pricing"
+            ],
+            "type": {
+              "defined": {
+                "name": "GasConfig"
+              }
+            }
+          },
+          {
+            "name": "protocol_config",
+            "docs": [
+              "Configuration parameters for bridge protocol"
+            ],
+            "type": {
+              "defined": {
+                "name": "ProtocolConfig"
+              }
+            }
+          },
+          {
+            "name": "buffer_config",
+            "docs": [
+              "Configuration parameters for pre-loading Solana --> Base messages in buffer accounts"
+            ],
+            "type": {
+              "defined": {
+                "name": "BufferConfig"
+              }
+            }
+          },
+          {
+            "name": "partner_oracle_config",
+            "docs": [
+              "Partner oracle configuration containing the required signature threshold"
+            ],
+            "type": {
+              "defined": {
+                "name": "PartnerOracleConfig"
+              }
+            }
+          },
+          {
+            "name": "base_oracle_config",
+            "docs": [
+              "Configuration parameters for Base oracle signers"
+            ],
+            "type": {
+              "defined": {
+                "name": "BaseOracleConfig"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigChange",
+      "docs": [
+        "A configuration change recorded in the `ConfigHistory`."
+      ],
+      "serialization": "bytemuck",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "slot",
+            "docs": [
+              "Slot at which the change was made"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "The guardian or upgrade authority that made the change"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "old_value",
+            "docs": [
+              "Value before the change (see `ConfigHistory::encode_value`)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "new_value",
+            "docs": [
+              "Value after the change (see `ConfigHistory::encode_value`)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "param",
+            "docs": [
+              "The updated parameter (a `ConfigParam`)"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "_padding",
+            "type": {
+              "array": [
+                "u8",
+                7
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "ConfigHistory",
+      "docs": [
+        "Append-only ring buffer of the last `CONFIG_HISTORY_LEN` configuration changes, letting",
+        "auditors reconstruct every change without relying on RPC log retention. Accessed zero-copy so",
+        "that recording a change has a small fixed cost."
+      ],
+      "serialization": "bytemuck",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "count",
+            "docs": [
+              "Total number of recorded changes. The latest change is at `(count - 1) % CONFIG_HISTORY_LEN`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "changes",
+            "docs": [
+              "The recorded changes, overwritten oldest first once full"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "ConfigChange"
+                  }
+                },
+                64
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CrosschainBurn",
+      "docs": [
+        "Emitted when the bridge burns wrapped tokens, named after the ERC-7802 `CrosschainBurn` event."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The wrapped token mint."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "from",
+            "docs": [
+              "Owner of the token account debited with the burned tokens."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The burned amount."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "The bridge program, which requested the burn."
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CrosschainMint",
+      "docs": [
+        "Emitted when the bridge mints wrapped tokens, named after the ERC-7802 `CrosschainMint` event",
+        "so that token issuers can track bridged supply the same way on both chains."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The wrapped token mint."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "to",
+            "docs": [
+              "Owner of the token account credited with the minted tokens."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The minted amount."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "The bridge program, which requested the mint."
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DexRouterList",
+      "docs": [
+        "Guardian-maintained list of the Base DEX routers whose calls must carry a `SwapGuard`, so that",
+        "bridge-and-swap transfers cannot be sent without a minimum output and a deadline. Calls are not",
+        "checked while the list is uninitialized."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "router_count",
+            "docs": [
+              "Number of routers in `routers` array"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "routers",
+            "docs": [
+              "Router addresses on Base"
+            ],
+            "type": {
+              "array": [
+                {
+                  "array": [
+                    "u8",
+                    20
+                  ]
+                },
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "DexRouterListUpdated",
+      "docs": [
+        "Emitted by the guardian registering a Base DEX router, or unregistering it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "router",
+            "docs": [
+              "The router address on Base."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "registered",
+            "docs": [
+              "Whether calls to the router now require a swap guard."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Eip1559",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "config",
+            "docs": [
+              "Configuration parameters for EIP-1559-inspired fee calculations"
+            ],
+            "type": {
+              "defined": {
+                "name": "Eip1559Config"
+              }
+            }
+          },
+          {
+            "name": "current_base_fee",
+            "docs": [
+              "Current base fee used in fee computation (runtime state).",
+              "Unitless value combined with the gas usage and gas cost scaler to produce lamports."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "current_window_gas_used",
+            "docs": [
+              "Gas used in the current time window (runtime state)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "window_start_time",
+            "docs": [
+              "Unix timestamp when the current window started (runtime state)"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Eip1559Config",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "target",
+            "docs": [
+              "Gas target per window"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "denominator",
+            "docs": [
+              "Adjustment denominator (controls rate of change)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "window_duration_seconds",
+            "docs": [
+              "Window duration in seconds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minimum_base_fee",
+            "docs": [
+              "Minimum base fee. Used to seed `current_base_fee` at initialization",
+              "and as an underflow clamp during decreases; not enforced as a strict lower bound",
+              "on every step."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "max_base_fee",
+            "docs": [
+              "Maximum base fee. The base fee never grows above this value (0 disables the cap)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "max_window_increase_bps",
+            "docs": [
+              "Maximum base fee increase per window, in basis points of the current base fee",
+              "(0 disables the cap)."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EmergencyExit",
+      "docs": [
+        "Emergency exit enabled by the guardian when the bridge is sunset or catastrophically paused.",
+        "Once `withdrawable_at` is reached, and while the bridge stays paused, depositors can withdraw",
+        "the SOL and SPL tokens locked by their outgoing messages not relayed to Base, without the",
+        "Base side. The timelock leaves time to confirm every message relayed before the pause."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "enabled_at",
+            "docs": [
+              "Unix timestamp at which the guardian enabled the emergency exit"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "withdrawable_at",
+            "docs": [
+              "Unix timestamp from which depositors can withdraw"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EmergencyExitCancelled",
+      "docs": [
+        "Emitted by the guardian cancelling the emergency exit."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "withdrawable_at",
+            "docs": [
+              "Unix timestamp from which depositors could have withdrawn."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EmergencyExitEnabled",
+      "docs": [
+        "Emitted by the guardian enabling the emergency exit of a paused bridge."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "withdrawable_at",
+            "docs": [
+              "Unix timestamp from which depositors can withdraw."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EmergencyQuorumUsed",
+      "docs": [
+        "Emitted for every oracle attestation accepted by the emergency quorum rather than the Base",
+        "oracle threshold."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "message_hash",
+            "docs": [
+              "The attested message hash."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "approvals",
+            "docs": [
+              "Number of Base oracle and emergency signers that approved the message."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "The emergency threshold in effect."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "expires_at",
+            "docs": [
+              "Unix timestamp at which the emergency quorum expires."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EmergencyWithdrawal",
+      "docs": [
+        "Emitted when a depositor withdraws the tokens locked by an outgoing message not relayed to",
+        "Base during the emergency exit."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nonce",
+            "docs": [
+              "Nonce of the outgoing message."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "The depositor, sender of the outgoing message."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "local_token",
+            "docs": [
+              "The withdrawn token, `NATIVE_SOL_PUBKEY` for SOL."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The withdrawn amount."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Environment",
+      "docs": [
+        "The environment the program was compiled for."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Localnet"
+          },
+          {
+            "name": "DevnetAlpha"
+          },
+          {
+            "name": "DevnetProd"
+          },
+          {
+            "name": "Mainnet"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EquivocationEvidence",
+      "docs": [
+        "Evidence that Base oracle signers attested two conflicting output roots for the same Base",
+        "block, recorded by `report_equivocation`.",
+        "",
+        "Each offender is stored with its signatures over both output roots, so that the equivocation",
+        "stays provable on-chain (e.g. for slashing) after the offenders have been disabled."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "base_block_number",
+            "docs": [
+              "The Base block number both output roots were attested for."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "first_root",
+            "docs": [
+              "The first attested output root and its total leaf count."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "first_total_leaf_count",
+            "type": "u64"
+          },
+          {
+            "name": "second_root",
+            "docs": [
+              "The second, conflicting, attested output root and its total leaf count."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "second_total_leaf_count",
+            "type": "u64"
+          },
+          {
+            "name": "offender_count",
+            "docs": [
+              "Number of offenders in `offenders`."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "offenders",
+            "docs": [
+              "EVM addresses of the Base oracle signers that attested both output roots."
+            ],
+            "type": {
+              "array": [
+                {
+                  "array": [
+                    "u8",
+                    20
+                  ]
+                },
+                16
+              ]
+            }
+          },
+          {
+            "name": "first_signatures",
+            "docs": [
+              "Signature of each offender over the first output root."
+            ],
+            "type": {
+              "array": [
+                {
+                  "array": [
+                    "u8",
+                    65
+                  ]
+                },
+                16
+              ]
+            }
+          },
+          {
+            "name": "second_signatures",
+            "docs": [
+              "Signature of each offender over the second output root."
+            ],
+            "type": {
+              "array": [
+                {
+                  "array": [
+                    "u8",
+                    65
+                  ]
+                },
+                16
+              ]
+            }
+          },
+          {
+            "name": "reporter",
+            "docs": [
+              "The account that reported the equivocation."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "reported_at",
+            "docs": [
+              "Unix timestamp at which the equivocation was reported."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeConfigV2",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "target",
+            "docs": [
+              "Gas target per window"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "denominator",
+            "docs": [
+              "Adjustment denominator (controls rate of change)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "window_duration_seconds",
+            "docs": [
+              "Window duration in seconds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minimum_base_fee",
+            "docs": [
+              "Minimum base fee"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "max_base_fee",
+            "docs": [
+              "Maximum base fee (0 disables the cap)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "max_window_increase_bps",
+            "docs": [
+              "Maximum base fee increase per window, in basis points of the current base fee",
+              "(0 disables the cap)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gas_cost_scaler",
+            "docs": [
+              "Scaling factor applied when converting (gas * base_fee) into lamports"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gas_cost_scaler_dp",
+            "docs": [
+              "Decimal precision for the gas cost scaler (denominator)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gas_fee_receiver",
+            "docs": [
+              "Account that receives gas fees"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeQuoteGrace",
+      "docs": [
+        "Grace period keeping gas fee quotes valid across EIP-1559 window rollovers.",
+        "",
+        "The base fee is fixed within a window, so a fee simulated in one window only goes stale once",
+        "the next window starts. For `grace_period_seconds` after a rollover, messages are charged the",
+        "lower of the base fees of the current and previous windows, so that a quote remains an upper",
+        "bound of the fee until the end of its window plus the grace period."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "grace_period_seconds",
+            "docs": [
+              "Seconds after a window rollover during which the previous base fee still applies, capped",
+              "at the window duration of the gas lane (0 disables the grace period)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "previous_base_fee",
+            "docs": [
+              "Base fee of the window preceding the current window of `eip1559` (runtime state)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "previous_transfer_base_fee",
+            "docs": [
+              "Base fee of the window preceding the current window of `transfer_gas_lane` (runtime state)"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FinalizationDelay",
+      "docs": [
+        "Delay between proving and relaying transfers whose amount exceeds the threshold set by the",
+        "guardian for their token, during which the guardian can veto the message. It gives monitoring",
+        "time to catch a fraudulent output root before the funds leave. A zero delay disables it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "delay_seconds",
+            "docs": [
+              "Delay in seconds after the message is proven"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "thresholds",
+            "docs": [
+              "Delayed tokens (zero `local_token` for an unused slot)"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "FinalizationThreshold"
+                  }
+                },
+                8
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "FinalizationThreshold",
+      "docs": [
+        "Threshold of a token whose larger transfers are held by the finalization delay."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "local_token",
+            "docs": [
+              "The delayed token, `NATIVE_SOL_PUBKEY` for SOL"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "Transfers of a larger amount are delayed"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FinalizeBridgeSol",
+      "docs": [
+        "Instruction data for finalizing a native SOL transfer from Base to Solana.",
+        "",
+        "Contains the data needed to release escrowed SOL on Solana that corresponds",
+        "to a transfer initiated on Base. SOL is held in a PDA vault and released to",
+        "the recipient when finalized."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "to",
+            "docs": [
+              "The Solana public key of the recipient who will receive the SOL.",
+              "This must match the intended recipient specified in the original bridge message."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount of SOL to transfer, denominated in lamports (1 SOL = 1_000_000_000 lamports).",
+              "This amount will be transferred from the SOL vault to the recipient."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FinalizeBridgeSpl",
+      "docs": [
+        "Instruction data for finalizing a bridged SPL token transfer from Base to Solana.",
+        "",
+        "Releases tokens from a program-controlled vault PDA to the specified recipient",
+        "token account on Solana."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "remote_token",
+            "docs": [
+              "The 20-byte ERC-20 contract address on Base that corresponds to the SPL mint.",
+              "Used, together with the SPL mint, to derive the token-vault PDA for this mapping."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "local_token",
+            "docs": [
+              "The SPL token mint on Solana that mirrors the `remote_token`."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "to",
+            "docs": [
+              "The recipient SPL token account address on Solana that will receive tokens.",
+              "This must be a valid token account for `local_token`.",
+              "Note: this program does not enforce ownership or ATA semantics; the account",
+              "is authenticated by address equality (`self.to`) and `transfer_checked`",
+              "enforces the mint match."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount to transfer, in the smallest unit of the remote token. It is scaled up to the",
+              "mint decimals when the vault decimals are configured, and is in base units of the mint",
+              "otherwise. `transfer_checked` enforces that the destination account's mint matches and",
+              "the decimals are correct."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FinalizeBridgeWrappedToken",
+      "docs": [
+        "Instruction data for finalizing a wrapped token transfer from Base to Solana.",
+        "",
+        "This struct represents the final step in a cross-chain bridge operation where tokens",
+        "that were originally on Base are being bridged to Solana as wrapped tokens. The",
+        "finalization process mints the appropriate amount of wrapped tokens to the recipient's",
+        "token account on Solana.",
+        "",
+        "The wrapped token mint is derived deterministically from the original token's metadata",
+        "and decimals, ensuring consistency across bridge operations."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "local_token",
+            "docs": [
+              "The mint address of the wrapped token on Solana.",
+              "This is a PDA that represents the Solana version",
+              "of a token that originally exists on Base. The mint address is derived",
+              "deterministically from the original token's metadata and decimals."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "to",
+            "docs": [
+              "The destination token account that will receive the wrapped tokens.",
+              "This must be a valid token account that is associated with the wrapped",
+              "token mint. It is expected to be controlled by the intended recipient of",
+              "the bridged tokens; recipient ownership is not enforced by this",
+              "instruction."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The amount of wrapped tokens to mint to the recipient.",
+              "The amount is specified in the token's smallest unit."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GasConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "gas_cost_scaler",
+            "docs": [
+              "Scaling factor applied when converting (gas_per_call * base_fee) into lamports"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gas_cost_scaler_dp",
+            "docs": [
+              "Decimal precision for the gas cost scaler (denominator)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gas_fee_receiver",
+            "docs": [
+              "Account that receives gas fees collected on Solana"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "gas_per_call",
+            "docs": [
+              "Amount of gas per Solana --> Base message"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "GuardedLaunchSet",
+      "docs": [
+        "Emitted by the guardian turning guarded launch on or off."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "enabled",
+            "docs": [
+              "Whether `bridge_spl` only accepts allow-listed mints."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "HandlerRegistered",
+      "docs": [
+        "Emitted when a Base sender registers the handler of its `CallHandler` messages."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sender",
+            "docs": [
+              "The Base address that registered the handler."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "program_id",
+            "docs": [
+              "The program invoked with the payload of the `CallHandler` messages of the sender."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "discriminator",
+            "docs": [
+              "Discriminator of the handler instruction."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "HandlerRegistration",
+      "docs": [
+        "Handler registered by a Base sender, invoked by `relay_message` with the payload of the",
+        "`CallHandler` messages of the sender instead of a list of raw instructions.",
+        "Registered through `register_handler`, relayed from a message of the sender."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "program_id",
+            "docs": [
+              "The program invoked with the payload of the `CallHandler` messages of the sender"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "discriminator",
+            "docs": [
+              "Discriminator of the handler instruction, prepended to its `HandlerCall` arguments"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "IncomingMessage",
+      "docs": [
+        "Represents a cross-chain message sent from Base to Solana",
+        "that is waiting to be processed or has already been executed.",
+        "",
+        "This struct stores the essential information needed to validate and execute",
+        "bridge operations from Base to Solana, including both simple calls and token transfers.",
+        "",
+        "Messages are created by the `prove_message` instruction (after MMR verification)",
+        "and executed by the `relay_message` instruction."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "sender",
+            "docs": [
+              "The 20-byte EVM address of the sender on Base who initiated this bridge operation.",
+              "Used to derive the bridge CPI authority PDA that signs downstream CPIs during relay.",
+              "This field does not restrict who can call the relay instruction."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "remote_chain_id",
+            "docs": [
+              "Chain ID of the chain the message was sent from, recorded from the bridge configuration",
+              "when the message is proven. The message can only be relayed while it is still configured."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "message",
+            "docs": [
+              "The actual message payload containing either instruction calls or token transfer data.",
+              "This enum determines what type of operation will be executed on Solana."
+            ],
+            "type": {
+              "defined": {
+                "name": "bridge::base_to_solana::state::incoming_message::Message"
+              }
+            }
+          },
+          {
+            "name": "executed",
+            "docs": [
+              "Flag indicating whether this message has been successfully executed on Solana.",
+              "Once set to true, the message cannot be executed again, preventing replay attacks."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "chunk_count",
+            "docs": [
+              "Number of `ProvenChunk` accounts holding the instructions of a message proven with",
+              "`prove_message_chunked`, executed after the instructions stored in `message`.",
+              "Zero for messages proven in a single account."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "output_root",
+            "docs": [
+              "The `OutputRoot` account the message was proven against."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "output_root_revision",
+            "docs": [
+              "Revision of `output_root` the message was proven against. The message can only be relayed",
+              "while it is still the current revision."
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "prover",
+            "docs": [
+              "The account that paid the rent of this account when proving the message. The rent freed",
+              "when the message is relayed is refunded to it."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Nonce of the message on Base, i.e. its leaf index in the Base MMR. Reported in the",
+              "`MessageRelayed` event so that indexers can match relays against proofs."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "proven_at",
+            "docs": [
+              "Unix timestamp at which the message was proven, from which the finalization delay of",
+              "high-value transfers runs."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "vetoed",
+            "docs": [
+              "Flag set by the guardian vetoing the message during its finalization delay. A vetoed",
+              "message can never be relayed."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "outflow_held",
+            "docs": [
+              "Flag set when relaying the transfer of the message tripped the outflow circuit breaker.",
+              "A held message never trips the breaker again: it can only be relayed once the guardian",
+              "approved its outflow."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "outflow_approved",
+            "docs": [
+              "Flag set by the guardian approving the outflow of a held message, which is then released",
+              "regardless of the outflow circuit breaker."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InsuranceFund",
+      "docs": [
+        "Bridge-held fund covering losses of bridge users, credited with `share_bps` of every gas fee",
+        "charged on outgoing messages. The lamports above its rent-exempt minimum can be paid out by the",
+        "guardian after `INSURANCE_PAYOUT_TIMELOCK_SECONDS`. No share is taken until this account is",
+        "initialized."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "share_bps",
+            "docs": [
+              "Share of the gas fees credited to the fund, in basis points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "total_accrued",
+            "docs": [
+              "Total lamports credited from gas fees"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "total_paid",
+            "docs": [
+              "Total lamports paid out"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pending_payout",
+            "docs": [
+              "Payout requested by the guardian, executable once its timelock elapsed"
+            ],
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "InsurancePayout"
+                }
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "InsurancePayout",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "docs": [
+              "The account receiving the payout"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Lamports paid out"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "executable_at",
+            "docs": [
+              "Unix timestamp from which the payout can be executed"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InsurancePayoutCancelled",
+      "docs": [
+        "Emitted by the guardian cancelling the pending insurance payout."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "docs": [
+              "The account that would have received the payout."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Lamports that would have been paid out."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InsurancePayoutExecuted",
+      "docs": [
+        "Emitted when the pending insurance payout is executed after its timelock."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "docs": [
+              "The account receiving the payout."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Lamports paid out."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "total_paid",
+            "docs": [
+              "Total lamports paid out by the insurance fund."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "InsurancePayoutRequested",
+      "docs": [
+        "Emitted by the guardian requesting a payout from the insurance fund."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "recipient",
+            "docs": [
+              "The account receiving the payout."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Lamports paid out."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "executable_at",
+            "docs": [
+              "Unix timestamp from which the payout can be executed."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Ix",
+      "docs": [
+        "Instruction to be executed by the bridge program via signed CPI during message relay.",
+        "Functionally equivalent to a Solana `Instruction`, but serialized with Anchor for cross-program messaging."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "program_id",
+            "docs": [
+              "Program that will process this instruction."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "accounts",
+            "docs": [
+              "Accounts required for this instruction."
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "IxAccount"
+                }
+              }
+            }
+          },
+          {
+            "name": "data",
+            "docs": [
+              "Instruction data."
+            ],
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "IxAccount",
+      "docs": [
+        "Account used in an instruction.",
+        "Similar to Solana's `AccountMeta`, but serializable with Anchor and supports PDAs via `PubkeyOrPda`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pubkey",
+            "docs": [
+              "Public key of the account."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "is_writable",
+            "docs": [
+              "Whether the account is writable."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "is_signer",
+            "docs": [
+              "Whether the account is a signer."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LamportAllowanceReturned",
+      "docs": [
+        "Emitted by `relay_message` when the unspent lamport allowance of a `WithLamportAllowance`",
+        "message is returned to the operational vault."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "message",
+            "docs": [
+              "The relayed incoming message."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bridge_cpi_authority",
+            "docs": [
+              "The bridge CPI authority lent the allowance."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "lamports",
+            "docs": [
+              "Lamports lent to the bridge CPI authority."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "returned",
+            "docs": [
+              "Lamports returned unspent to the operational vault."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "LiabilityRoot",
+      "docs": [
+        "Oracle-posted commitment to the outstanding supply on Base of the tokens locked in the bridge",
+        "vaults, i.e. the liabilities the vaults must cover.",
+        "",
+        "`root` is the root of a Merkle tree whose leaves are `keccak256(local_token || liability_be)`",
+        "(see `LiabilityRoot::leaf_hash`), `liability` being expressed in the vault's local units."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "docs": [
+              "Merkle root of the per-token liabilities"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "base_block_number",
+            "docs": [
+              "The Base block number at which the liabilities were measured"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "solvency_alert",
+            "docs": [
+              "Set by `reconcile_vault` when a vault holds less than its proven liability. Monitoring",
+              "alarms on it until the guardian clears it."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MaintenanceBudget",
+      "docs": [
+        "Protocol-funded budget reimbursing the payer of `register_output_root` for the rent of the",
+        "output root account plus `fee_reimbursement` lamports of transaction fees, so that registrations",
+        "keep up during fee spikes. It is funded by transferring lamports to it and only the lamports",
+        "above its rent-exempt minimum are paid out. Nothing is reimbursed until this account is",
+        "initialized."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "fee_reimbursement",
+            "docs": [
+              "Lamports reimbursed per registration on top of the output root rent"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "total_reimbursed",
+            "docs": [
+              "Total lamports reimbursed"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MessageProven",
+      "docs": [
+        "Emitted when a message from Base is proven. Fields are fixed-size so that indexers can filter",
+        "logs on the nonce and sender at fixed offsets."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nonce",
+            "docs": [
+              "Nonce of the message on Base."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sender_base",
+            "docs": [
+              "The Base address that sent the message."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "message_hash",
+            "docs": [
+              "Hash of the message, seed of its `IncomingMessage` account."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "prover",
+            "docs": [
+              "The account that proved the message and paid its rent."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "finalizable_at",
+            "docs": [
+              "Unix timestamp from which the message can be relayed under the finalization delay",
+              "configured when it was proven. Later than the proving time for high-value transfers."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "cpi_authority",
+            "docs": [
+              "Bridge CPI authority of `sender_base`, signing the instructions relayed from the message."
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MessageRelayed",
+      "docs": [
+        "Emitted by `relay_message` with the execution receipt of a message from Base. Fields are",
+        "fixed-size and laid out like `MessageProven` so that both events filter on the same offsets."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nonce",
+            "docs": [
+              "Nonce of the message on Base."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sender_base",
+            "docs": [
+              "The Base address that sent the message."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "success",
+            "docs": [
+              "Whether the message was executed. False when it was withheld by the outflow circuit",
+              "breaker and is left to be relayed again."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "return_data_hash",
+            "docs": [
+              "Keccak256 hash of the return data left by the last executed CPI, zero when none."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "relayer",
+            "docs": [
+              "The relayer that signed the relay, the default pubkey when none did."
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MessageVetoed",
+      "docs": [
+        "Emitted when the guardian vetoes a message held by the finalization delay."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "message_hash",
+            "docs": [
+              "Hash of the message, seed of its `IncomingMessage` account."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "guardian",
+            "docs": [
+              "The guardian that vetoed the message."
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "MintAllowList",
+      "docs": [
+        "Guardian-maintained list of the SPL mints accepted by `bridge_spl` while the bridge is in",
+        "guarded launch (see `Bridge::guarded_launch`). It is ignored once guarded launch is turned off,",
+        "so the bridge can be opened to every mint without a program upgrade."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint_count",
+            "docs": [
+              "Number of mints in `mints` array"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "mints",
+            "docs": [
+              "Mints accepted by `bridge_spl` during guarded launch"
+            ],
+            "type": {
+              "array": [
+                "pubkey",
+                32
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12300,
-      "name": "InvalidRecoveryId",
-      "msg": "Invalid recovery ID"
+      "name": "MintAllowListUpdated",
+      "docs": [
+        "Emitted by the guardian adding a mint to, or removing it from, the guarded launch allow-list."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "docs": [
+              "The mint."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "allowed",
+            "docs": [
+              "Whether the mint is now allow-listed."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 12301,
-      "name": "SignatureVerificationFailed",
-      "msg": "Signature verification failed"
+      "name": "MintPolicy",
+      "docs": [
+        "Guardian-maintained policy restricting the SPL mints accepted by `bridge_spl`. A mint with a",
+        "hostile freeze authority could freeze the token vault after tokens are locked, stranding the",
+        "bridged users. No freeze authority policy is enforced until this account is initialized."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "reject_freeze_authority",
+            "docs": [
+              "Whether mints with a freeze authority are rejected, unless it is allow-listed"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "allowed_freeze_authority_count",
+            "docs": [
+              "Number of authorities in `allowed_freeze_authorities` array"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "allowed_freeze_authorities",
+            "docs": [
+              "Freeze authorities accepted while `reject_freeze_authority` is set"
+            ],
+            "type": {
+              "array": [
+                "pubkey",
+                16
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12302,
-      "name": "InsufficientBaseSignatures",
-      "msg": "Insufficient base oracle signatures to meet threshold"
+      "name": "OperationalVault",
+      "docs": [
+        "Protocol-funded vault lending lamports to the bridge CPI authority of `WithLamportAllowance`",
+        "messages for the duration of their execution, e.g. to fund the accounts created by the target",
+        "programs. The lamports the CPI authority did not spend are returned once the message executed.",
+        "It is funded by transferring lamports to it and only the lamports above its rent-exempt minimum",
+        "can be lent. No allowance is granted until this account is initialized."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "max_allowance",
+            "docs": [
+              "Maximum lamport allowance of a single message"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "total_lent",
+            "docs": [
+              "Total lamports lent to bridge CPI authorities"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "total_returned",
+            "docs": [
+              "Total lamports returned unspent"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OracleEmergencyActivated",
+      "docs": [
+        "Emitted by the guardian activating the oracle emergency quorum after output roots stalled."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "threshold",
+            "docs": [
+              "Number of required valid unique signatures while the emergency quorum is active."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "emergency_signers",
+            "docs": [
+              "Signers accepted in addition to the Base oracle signers."
+            ],
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  20
+                ]
+              }
+            }
+          },
+          {
+            "name": "last_output_root_at",
+            "docs": [
+              "Unix timestamp of the latest registered output root."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "expires_at",
+            "docs": [
+              "Unix timestamp at which the emergency quorum expires."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OracleEmergencyDeactivated",
+      "docs": [
+        "Emitted by the guardian deactivating the oracle emergency quorum before it expires."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "expires_at",
+            "docs": [
+              "Unix timestamp at which the emergency quorum would have expired."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OracleEquivocationReported",
+      "docs": [
+        "Emitted by `report_equivocation` when Base oracle signers are disabled for attesting two",
+        "conflicting output roots for the same Base block."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "base_block_number",
+            "docs": [
+              "The Base block number both output roots were attested for."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "offenders",
+            "docs": [
+              "EVM addresses of the disabled Base oracle signers."
+            ],
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  20
+                ]
+              }
+            }
+          },
+          {
+            "name": "reporter",
+            "docs": [
+              "The account that reported the equivocation."
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OracleFailover",
+      "docs": [
+        "Failover for the Base oracle quorum. When no output root has been registered for",
+        "`stall_seconds`, the guardian can temporarily lower the Base oracle threshold and accept",
+        "additional emergency signers until `emergency_expires_at`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "last_output_root_at",
+            "docs": [
+              "Unix timestamp at which the latest output root was registered (0 before the first one)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "stall_seconds",
+            "docs": [
+              "Seconds without a new output root after which the emergency quorum can be activated",
+              "(0 disables emergency mode)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "emergency_duration_seconds",
+            "docs": [
+              "Seconds the emergency quorum stays active once activated"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "emergency_expires_at",
+            "docs": [
+              "Unix timestamp at which the emergency quorum expires (0 if it was never activated)"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "emergency_threshold",
+            "docs": [
+              "Number of required valid unique signatures while the emergency quorum is active"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "emergency_signer_count",
+            "docs": [
+              "Number of signers in `emergency_signers` array"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "emergency_signers",
+            "docs": [
+              "Signers accepted in addition to the Base oracle signers while the emergency quorum is active"
+            ],
+            "type": {
+              "array": [
+                {
+                  "array": [
+                    "u8",
+                    20
+                  ]
+                },
+                4
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "OracleHealth",
+      "docs": [
+        "Liveness of the Base oracle signers: the last time each of them signed a registered output root.",
+        "Heartbeats are keyed by signer so they survive signer set updates; the slots of removed signers",
+        "are reused by new ones."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "heartbeats",
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "OracleHeartbeat"
+                  }
+                },
+                16
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "OracleHealthReport",
+      "docs": [
+        "Liveness of the Base oracle quorum returned by `get_oracle_health`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "threshold",
+            "docs": [
+              "Number of required valid unique Base oracle signatures"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "live_signer_count",
+            "docs": [
+              "Number of Base oracle signers that signed an output root within the requested age"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "quorum_live",
+            "docs": [
+              "Whether the live signers alone still meet the threshold"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "heartbeats",
+            "docs": [
+              "Last heartbeat of each Base oracle signer, in signer order (0 if it never signed)"
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "OracleHeartbeat"
+                }
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12303,
-      "name": "InsufficientPartnerSignatures",
-      "msg": "Insufficient partner oracle signatures to meet threshold"
+      "name": "OracleHeartbeat",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "signer",
+            "docs": [
+              "EVM address of the signer (zero for an unused slot)"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "last_signed_at",
+            "docs": [
+              "Unix timestamp at which the latest output root signed by `signer` was registered"
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 12400,
-      "name": "InvalidProof",
-      "msg": "Invalid proof"
+      "name": "Outbox",
+      "docs": [
+        "Merkle mountain range (MMR) of the outgoing message hashes, mirroring the MMR of the output",
+        "roots proven against in the Base to Solana direction: mountains hash their nodes commutatively",
+        "and the root bags the peaks left to right, so that Base can verify a message against a root",
+        "attested once rather than attesting every message account.",
+        "",
+        "Only the peaks are stored: appending a leaf merges the mountains of equal height in place.",
+        "Leaves are appended from the layout upgrade introducing the outbox onwards, so the leaf index",
+        "of a message is not its nonce on bridges deployed before it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "leaf_count",
+            "docs": [
+              "Number of leaves appended"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "peaks",
+            "docs": [
+              "Peaks of the mountains from the highest to the lowest, the first `leaf_count.count_ones()`",
+              "being set"
+            ],
+            "type": {
+              "array": [
+                {
+                  "array": [
+                    "u8",
+                    32
+                  ]
+                },
+                32
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12401,
-      "name": "MmrShouldBeEmpty",
-      "msg": "MMR should be empty"
+      "name": "OutflowApproved",
+      "docs": [
+        "Emitted when the guardian approves the outflow of a message held by the outflow circuit",
+        "breaker."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "message_hash",
+            "docs": [
+              "Hash of the message, seed of its `IncomingMessage` account."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "guardian",
+            "docs": [
+              "The guardian that approved the outflow."
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 12402,
-      "name": "EmptyMmr",
-      "msg": "MMR is empty"
+      "name": "OutflowBreaker",
+      "docs": [
+        "Circuit breaker pausing the bridge when the outflow of a token over a sliding window exceeds the",
+        "threshold set by the guardian, limiting the damage of a key compromise before anyone can react.",
+        "Only tokens with a threshold are tracked and a zero window disables the breaker."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "window_seconds",
+            "docs": [
+              "Length of the sliding window in seconds"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "limits",
+            "docs": [
+              "Tracked tokens (zero `local_token` for an unused slot)"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "OutflowLimit"
+                  }
+                },
+                8
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12403,
-      "name": "LeafMountainNotFound",
-      "msg": "Leaf's mountain not found"
+      "name": "OutflowCircuitBreakerTripped",
+      "docs": [
+        "Emitted by `relay_message` when releasing a transfer would exceed the outflow threshold of its",
+        "token. The bridge is paused and the message held until the guardian approves its outflow with",
+        "`approve_outflow`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "message",
+            "docs": [
+              "The incoming message whose transfer was withheld."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "local_token",
+            "docs": [
+              "The transferred token, `NATIVE_SOL_PUBKEY` for SOL."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "The withheld amount."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "window_outflow",
+            "docs": [
+              "Outflow of the token over the sliding window, `amount` included."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12404,
-      "name": "InsufficientProofElementsForIntraMountainPath",
-      "msg": "Insufficient proof elements for intra-mountain path"
+      "name": "OutflowLimit",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "local_token",
+            "docs": [
+              "The tracked token, `NATIVE_SOL_PUBKEY` for SOL"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "Maximum outflow over the sliding window"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "window_start",
+            "docs": [
+              "Unix timestamp at which the current window started"
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "current_outflow",
+            "docs": [
+              "Outflow of the current window"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "previous_outflow",
+            "docs": [
+              "Outflow of the previous window"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12405,
-      "name": "InsufficientProofElementsForOtherMountainPeaks",
-      "msg": "Insufficient proof elements for other mountain peaks"
+      "name": "OutflowThreshold",
+      "docs": [
+        "Threshold of a token tracked by the outflow circuit breaker."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "local_token",
+            "docs": [
+              "The tracked token, `NATIVE_SOL_PUBKEY` for SOL"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "threshold",
+            "docs": [
+              "Maximum outflow over the sliding window"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12406,
-      "name": "UnusedProofElementsRemaining",
-      "msg": "Unused proof elements remaining"
+      "name": "OutgoingMessage",
+      "docs": [
+        "Represents a message being sent from Solana to Base through the bridge.",
+        "This struct contains all the necessary information to execute a cross-chain operation",
+        "on the Base side, including the message content and execution parameters.",
+        "",
+        "The fixed-size fields form a header at stable offsets, ahead of the variable-length message,",
+        "so that relayers can discover messages with `getProgramAccounts` memcmp filters and data",
+        "slices without decoding the message itself. The offsets are part of the account API:",
+        "",
+        "| Field           | Offset                                    | Size |",
+        "| --------------- | ----------------------------------------- | ---- |",
+        "| nonce           | `OUTGOING_MESSAGE_NONCE_OFFSET`           | 8    |",
+        "| sender          | `OUTGOING_MESSAGE_SENDER_OFFSET`          | 32   |",
+        "| created_at      | `OUTGOING_MESSAGE_CREATED_AT_OFFSET`      | 8    |",
+        "| remote_chain_id | `OUTGOING_MESSAGE_REMOTE_CHAIN_ID_OFFSET` | 8    |",
+        "| message         | `OUTGOING_MESSAGE_HEADER_LEN`             | ..   |",
+        "",
+        "The relay status is not stored in the message: relayed nonces are tracked by the",
+        "`RelayedNonces` registry, after which the message can be closed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nonce",
+            "docs": [
+              "Monotonic message nonce used for ordering and replay protection on Base.",
+              "Starts at 0 and is incremented by the `Bridge` for each new message."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "sender",
+            "docs": [
+              "The Solana public key of the signer that initiated this cross-chain message.",
+              "Carried to Base for use by destination logic; Solana-side authentication is enforced",
+              "via signer constraints."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "created_at",
+            "docs": [
+              "Unix timestamp at which the message was sent."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "remote_chain_id",
+            "docs": [
+              "Chain ID of the chain the message is sent to, taken from the bridge configuration."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "message",
+            "docs": [
+              "The actual message payload that will be executed on Base.",
+              "Can be a direct contract call, a token transfer (with optional call), an encrypted call or a",
+              "transfer batch."
+            ],
+            "type": {
+              "defined": {
+                "name": "bridge::solana_to_base::state::outgoing_message::Message"
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12407,
-      "name": "NoPeaksFoundForNonEmptyMmr",
-      "msg": "No peaks found for non-empty MMR"
+      "name": "OutgoingMessageCommitted",
+      "docs": [
+        "Emitted when an outgoing message is appended to the outbox MMR, with the root committing to it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "outgoing_message",
+            "docs": [
+              "The outgoing message account."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Nonce of the message."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "leaf_index",
+            "docs": [
+              "Index of the message leaf in the outbox MMR."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "root",
+            "docs": [
+              "Root of the outbox MMR after appending the message."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12500,
-      "name": "InvalidMessageHash",
-      "msg": "Invalid message hash"
+      "name": "OutgoingMessageReceipt",
+      "docs": [
+        "Receipt of an outgoing message, returned by the instructions sending one so that programs",
+        "invoking them through CPI can read the assigned nonce and the fees charged from the return data."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "outgoing_message",
+            "docs": [
+              "The outgoing message account"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Nonce assigned to the message"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "leaf_index",
+            "docs": [
+              "Index of the message leaf in the outbox MMR"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "gas_fee",
+            "docs": [
+              "Gas fee charged to the payer, in lamports"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "protocol_fee",
+            "docs": [
+              "Protocol fee skimmed from the bridged amount, in the smallest unit of the local token"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12501,
-      "name": "AlreadyExecuted",
-      "msg": "Message already executed"
+      "name": "OutputRoot",
+      "docs": [
+        "Represents a cryptographic commitment to the set of Base L2 bridge messages",
+        "at a specific Base block number.",
+        "",
+        "Output roots are registered on Solana by a trusted oracle and serve as",
+        "checkpoints that allow messages from Base to be proven and relayed to",
+        "Solana. Each output root contains an MMR root that commits to all bridge",
+        "messages as of a particular Base block number.",
+        "",
+        "This account is used in the Base → Solana message passing flow, where:",
+        "1. A trusted oracle registers output roots for specific Base blocks",
+        "2. Users prove their messages were included on Base using these roots and an MMR proof",
+        "3. Proven messages are then relayed and executed on Solana"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "root",
+            "docs": [
+              "The 32-byte MMR root that commits to all outgoing bridge messages on Base",
+              "as of the specified Base block number."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "total_leaf_count",
+            "docs": [
+              "The total number of leaves that were present in the MMR when this root",
+              "was generated. This is crucial for determining the MMR structure and",
+              "mountain configuration at the time of proof validation."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "base_block_number",
+            "docs": [
+              "The Base block number the root was registered for, used to enforce the root freshness",
+              "policy of `prove_message`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "registered_at",
+            "docs": [
+              "Unix timestamp at which the root was registered, opening its challenge window."
+            ],
+            "type": "i64"
+          },
+          {
+            "name": "revision",
+            "docs": [
+              "Number of times the root was superseded by `supersede_output_root`. Messages record the",
+              "revision they were proven against and can only be relayed while it is current."
+            ],
+            "type": "u32"
+          }
+        ]
+      }
     },
     {
-      "code": 12502,
-      "name": "IncorrectBlockNumber",
-      "msg": "Incorrect block number"
+      "name": "OutputRootEntry",
+      "docs": [
+        "A single output root registered by the `register_output_roots_batch` instruction."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "output_root",
+            "docs": [
+              "The 32-byte MMR root of Base messages for the given block"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "base_block_number",
+            "docs": [
+              "The Base block number this output root corresponds to"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "total_leaf_count",
+            "docs": [
+              "The total number of leaves in the MMR with this root"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12600,
-      "name": "MintDoesNotMatchLocalToken",
-      "msg": "Mint does not match local token"
+      "name": "OutputRootSuperseded",
+      "docs": [
+        "Emitted by `supersede_output_root` when the oracles replace a registered output root within",
+        "its challenge window, e.g. after a Base reorg below it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "base_block_number",
+            "docs": [
+              "The Base block number of the output root."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "previous_root",
+            "docs": [
+              "The replaced MMR root."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "root",
+            "docs": [
+              "The new MMR root."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                32
+              ]
+            }
+          },
+          {
+            "name": "revision",
+            "docs": [
+              "The new revision of the output root."
+            ],
+            "type": "u32"
+          }
+        ]
+      }
     },
     {
-      "code": 12601,
-      "name": "TokenAccountDoesNotMatchTo",
-      "msg": "Token account does not match to address"
+      "name": "PartialTokenMetadata",
+      "docs": [
+        "Represents token metadata for tokens that are bridged between Base and Solana.",
+        "",
+        "This struct contains metadata needed to represent a token that exists on both",
+        "chains, including information about its remote counterpart and any scaling factors needed",
+        "to handle differences between the chains (such as decimal precision).",
+        "",
+        "The metadata is stored using the SPL Token-2022 metadata interface's",
+        "`additional_metadata` key/value field and can be used to reconstruct the relationship",
+        "between tokens on both sides of the bridge."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "name",
+            "docs": [
+              "The human-readable name of the token (e.g., \"Wrapped Bitcoin\")"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "symbol",
+            "docs": [
+              "The symbol/ticker of the token (e.g., \"WBTC\")"
+            ],
+            "type": "string"
+          },
+          {
+            "name": "remote_token",
+            "docs": [
+              "The 20-byte address of the corresponding token contract on Base (EVM address bytes).",
+              "This allows the bridge to identify which Base token this Solana token represents."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "scaler_exponent",
+            "docs": [
+              "The scaling exponent used to convert between token amounts on different chains.",
+              "This handles cases where tokens have differing decimal precision on Base vs Solana.",
+              "For example, when Base token has 18 decimals and the Solana wrapped mint has 9,",
+              "this value conveys the decimal relationship so bridging logic can scale amounts.",
+              "The conversion is applied by the EVM-side contract; Solana only uses it to validate",
+              "amounts (see `common::internal::scaling`)."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 12602,
-      "name": "IncorrectTokenVault",
-      "msg": "Incorrect token vault"
+      "name": "PartnerOracleConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "required_threshold",
+            "docs": [
+              "Partner signatures required by our bridge to accept an output root"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 12603,
-      "name": "MintIsWrappedToken",
-      "msg": "Mint is a wrapped token"
+      "name": "PayerRateLimitConfig",
+      "docs": [
+        "Guardian-maintained limits on the outgoing messages each payer can pay for per window, so that",
+        "a single spammer cannot flood the outbox and drive up the base fee for everyone. Payers are not",
+        "limited while the config is uninitialized or its window is zero."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "window_seconds",
+            "docs": [
+              "Duration of a rate limiting window, in seconds (0 disables rate limiting)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "max_messages",
+            "docs": [
+              "Maximum number of outgoing messages a payer can pay for per window (0 for no limit)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "max_gas",
+            "docs": [
+              "Maximum gas a payer can pay for per window (0 for no limit)"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "exempt_payer_count",
+            "docs": [
+              "Number of payers in `exempt_payers` array"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "exempt_payers",
+            "docs": [
+              "Payers exempted from the limits, e.g. integrators sending messages on behalf of their users"
+            ],
+            "type": {
+              "array": [
+                "pubkey",
+                16
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12604,
-      "name": "IncorrectTo",
-      "msg": "Incorrect to"
+      "name": "PayerRateLimitExemptionUpdated",
+      "docs": [
+        "Emitted by the guardian exempting a payer from the payer rate limits, or subjecting it to them",
+        "again."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "payer",
+            "docs": [
+              "The payer."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "exempt",
+            "docs": [
+              "Whether the payer is now exempt from the rate limits."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
     },
     {
-      "code": 12605,
-      "name": "IncorrectSolVault",
-      "msg": "Incorrect sol vault"
+      "name": "PayerRateLimitSet",
+      "docs": [
+        "Emitted when the guardian sets the outgoing messages and gas each payer can pay for per window."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "window_seconds",
+            "docs": [
+              "Duration of a rate limiting window, in seconds (0 disables rate limiting)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "max_messages",
+            "docs": [
+              "Maximum number of outgoing messages a payer can pay for per window (0 for no limit)."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "max_gas",
+            "docs": [
+              "Maximum gas a payer can pay for per window (0 for no limit)."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12700,
-      "name": "RemoteTokenNotFound",
-      "msg": "Remote token not found"
+      "name": "ProtocolConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "block_interval_requirement",
+            "docs": [
+              "Block interval requirement for output root registration. Every Base block associated with a",
+              "submitted output root must be a multiple of this number."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "remote_sol_address",
+            "docs": [
+              "The Base evm address of SOL"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "remote_chain_id",
+            "docs": [
+              "Chain ID of the remote chain messages are sent to and received from.",
+              "0 selects the `BASE_CHAIN_ID` of the compiled environment (see `Bridge::remote_chain_id`)."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12701,
-      "name": "ScalerExponentNotFound",
-      "msg": "Scaler exponent not found"
+      "name": "ProtocolFeeCharged",
+      "docs": [
+        "Emitted when a protocol fee is skimmed from a bridged amount."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "from",
+            "docs": [
+              "The account bridging the tokens."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "local_token",
+            "docs": [
+              "The bridged token (a mint, or `NATIVE_SOL_PUBKEY` for SOL)."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "treasury",
+            "docs": [
+              "The account credited with the fee (the treasury, or one of its token accounts)."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Amount requested to be bridged, before the protocol fee."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Protocol fee deducted from `amount`."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "net_amount",
+            "docs": [
+              "Amount actually bridged after the protocol fee, net of any token transfer fee."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bps",
+            "docs": [
+              "Protocol fee rate in basis points."
+            ],
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 12702,
-      "name": "InvalidRemoteToken",
-      "msg": "Invalid remote token"
+      "name": "ProtocolFeeConfig",
+      "docs": [
+        "Guardian-maintained protocol fee skimmed from bridged token amounts (SOL, SPL and wrapped",
+        "tokens) into the treasury. Protocol fees are disabled until this account is initialized."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "treasury",
+            "docs": [
+              "Treasury collecting protocol fees: receives SOL fees directly and token fees in its",
+              "token accounts."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "default_bps",
+            "docs": [
+              "Protocol fee in basis points applied to tokens without an override"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "override_count",
+            "docs": [
+              "Number of overrides in `overrides` array"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "overrides",
+            "docs": [
+              "Per-token protocol fees taking precedence over `default_bps`"
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": {
+                    "name": "ProtocolFeeOverride"
+                  }
+                },
+                16
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12703,
-      "name": "InvalidScalerExponent",
-      "msg": "Invalid scaler exponent"
+      "name": "ProtocolFeeOverride",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "local_token",
+            "docs": [
+              "The token on Solana (a mint, or `NATIVE_SOL_PUBKEY` for SOL)"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "bps",
+            "docs": [
+              "Protocol fee in basis points for this token"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "code": 12704,
-      "name": "MintIsNotFromToken2022",
-      "msg": "Mint is not a token 2022 mint"
+      "name": "ProveBuffer",
+      "docs": [
+        "A buffer account to stage large Base → Solana prove inputs over multiple transactions.",
+        "Stores the serialized `Message` bytes (`data`) and the MMR `proof` nodes."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "owner",
+            "docs": [
+              "The owner who can modify and eventually consume this buffer"
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "data",
+            "docs": [
+              "Serialized `Message` data (Anchor-serialized)"
+            ],
+            "type": "bytes"
+          },
+          {
+            "name": "proof",
+            "docs": [
+              "MMR proof nodes used to validate inclusion against an OutputRoot"
+            ],
+            "type": {
+              "vec": {
+                "array": [
+                  "u8",
+                  32
+                ]
+              }
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12705,
-      "name": "MintIsNotWrappedTokenPda",
-      "msg": "Mint is not a valid wrapped token PDA"
+      "name": "RelayDenyList",
+      "docs": [
+        "Guardian-maintained programs the bridge CPI authority never invokes from a relayed message, on",
+        "top of the built-in `DENIED_RELAY_PROGRAMS`. Only the built-in programs are denied until this",
+        "account is initialized."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "program_count",
+            "docs": [
+              "Number of programs in `programs` array"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "programs",
+            "docs": [
+              "Programs denied in addition to the built-in ones"
+            ],
+            "type": {
+              "array": [
+                "pubkey",
+                16
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12800,
-      "name": "InvalidThreshold",
-      "msg": "Threshold must be <= number of signers"
+      "name": "RelayExecution",
+      "docs": [
+        "Oracle-attested record of the relayer that executed an outgoing message on Base, so that relay",
+        "tips escrowed on Solana can be settled to the account that did the work. Created once per nonce",
+        "by `record_relay_execution` and never closed."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "nonce",
+            "docs": [
+              "Nonce of the executed outgoing message"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "relayer",
+            "docs": [
+              "The Solana account of the relayer that executed the message on Base"
+            ],
+            "type": "pubkey"
+          }
+        ]
+      }
     },
     {
-      "code": 12801,
-      "name": "TooManySigners",
-      "msg": "Too many signers (max 32)"
+      "name": "RelayFeePaid",
+      "docs": [
+        "Emitted by `relay_message` when the relay fee of a `WithRelayFee` message is paid."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "message",
+            "docs": [
+              "The relayed incoming message."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "relayer",
+            "docs": [
+              "The relayer credited with the fee."
+            ],
+            "type": "pubkey"
+          },
+          {
+            "name": "fee",
+            "docs": [
+              "Relay fee prepaid on Base."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "paid",
+            "docs": [
+              "Lamports actually paid, lower than `fee` when the vault is short of funds."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12802,
-      "name": "DuplicateSigner",
-      "msg": "Duplicate signer found"
+      "name": "RelayFeeVault",
+      "docs": [
+        "Bridge-held vault paying the relay fees prepaid by the Base senders of `WithRelayFee` messages",
+        "to the relayers executing them. Funded by oracle-attested settlements of the fees collected on",
+        "Base; the lamports above its rent-exempt minimum are available for payouts."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "settlement_nonce",
+            "docs": [
+              "Nonce of the latest applied settlement. Settlements are applied in order starting at 1."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "total_settled",
+            "docs": [
+              "Total lamports deposited by settlements."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "total_paid",
+            "docs": [
+              "Total lamports paid to relayers."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "total_unpaid",
+            "docs": [
+              "Total relay fees left unpaid because the vault was short of funds."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12803,
-      "name": "InvalidPartnerThreshold",
-      "msg": "Invalid partner threshold"
+      "name": "RelayFeesSettled",
+      "docs": [
+        "Emitted by `settle_relay_fees` when relay fees collected on Base are deposited into the relay",
+        "fee vault."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "settlement_nonce",
+            "docs": [
+              "Nonce of the settlement."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "amount",
+            "docs": [
+              "Lamports deposited by the settlement."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "total_settled",
+            "docs": [
+              "Total lamports deposited by all settlements."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12804,
-      "name": "InvalidDenominator",
-      "msg": "Invalid denominator"
+      "name": "RelayedNonces",
+      "docs": [
+        "Bitmap of the outgoing message nonces confirmed as relayed on Base.",
+        "",
+        "Each account covers `RELAYED_NONCES_PER_ACCOUNT` consecutive nonces starting at",
+        "`page * RELAYED_NONCES_PER_ACCOUNT`, so relays executed out of order on Base can be tracked",
+        "individually. The bitmap is too large for the stack and is therefore accessed zero-copy."
+      ],
+      "serialization": "bytemuck",
+      "repr": {
+        "kind": "c"
+      },
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "page",
+            "docs": [
+              "Index of the nonce range covered by this account"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bitmap",
+            "docs": [
+              "One bit per nonce of the page, set once the nonce is confirmed as relayed"
+            ],
+            "type": {
+              "array": [
+                "u64",
+                1024
+              ]
+            }
+          }
+        ]
+      }
     },
     {
-      "code": 12805,
-      "name": "InvalidWindowDurationSeconds",
-      "msg": "Invalid window duration seconds"
+      "name": "ReturnedTransfer",
+      "docs": [
+        "Marker recording that the tokens of a Solana → Base transfer were returned to Solana.",
+        "Derived from the original outgoing message nonce so that a transfer is returned at most once."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "outgoing_nonce",
+            "docs": [
+              "Nonce of the returned outgoing message."
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12806,
-      "name": "InvalidGasCostScalerDp",
-      "msg": "Invalid gas cost scaler dp"
+      "name": "RootFreshnessConfig",
+      "docs": [
+        "Freshness policy of the output roots messages are proven against, per message kind.",
+        "Each field is the maximum number of Base blocks between the output root a message is proven",
+        "against and the latest registered output root (0 accepts any root). `WithComputeBudget`,",
+        "`WithRelayFee` and `WithLamportAllowance` messages follow the policy of the message they wrap."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "call_max_root_age",
+            "docs": [
+              "Maximum output root age of `Call` messages"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "transfer_max_root_age",
+            "docs": [
+              "Maximum output root age of `Transfer` messages"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "return_transfer_max_root_age",
+            "docs": [
+              "Maximum output root age of `ReturnTransfer` messages"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "call_with_signers_max_root_age",
+            "docs": [
+              "Maximum output root age of `CallWithSigners` messages"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
     },
     {
-      "code": 12807,
-      "name": "InvalidBlockIntervalRequirement",
-      "msg": "Invalid block interval requirement"
+      "name": "SolVaultState",
+      "docs": [
+        "State of the SOL vault, created once by `init_sol_vault`.",
+        "Bridging instructions require it so the vault is always rent-exempt and derived from the",
+        "recorded bump rather than searching for it."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "docs": [
+              "Bump of the SOL vault PDA (`[SOL_VAULT_SEED]`)."
+            ],
+            "type": "u8"
+          }
+        ]
+      }
     },
     {
-      "code": 12900,
-      "name": "CreationWithNonZeroTarget",
-      "msg": "Creation with non-zero target"
+      "name": "SwapGuard",
+      "docs": [
+        "Bounds enforced by the Base executor around the call of a bridge-and-swap `Transfer`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "token_out",
+            "docs": [
+              "The token the call swaps into on Base, or the zero address for ETH."
+            ],
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
+          },
+          {
+            "name": "min_out",
+            "docs": [
+              "The minimum amount of `token_out` the call must credit to the sender's Twin on Base, in",
+              "the token's smallest unit. Must be non-zero."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "deadline",
+            "docs": [
+              "Unix timestamp after which the call is skipped on Base, the tokens being delivered to",
+              "`to` without being swapped. Must be in the future when the transfer is sent."
+            ],
+            "type": "i64"
+          }
+        ]
+      }
     },
     {
-      "code": 12901,
-      "name": "ZeroAddress",
-      "msg": "Zero address"
-    }
-  ],
-  "types": [
-    {
-      "name": "BaseOracleConfig",
+      "name": "TokenDeposits",
+      "docs": [
+        "Accounting for the amount locked in a bridge vault (the SOL vault or an SPL token vault).",
+        "Incremented when tokens are locked for Base and decremented when they are released back",
+        "to Solana, so that it can be compared against the actual vault balance by `audit_vault`."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "threshold",
+            "name": "vault",
             "docs": [
-              "Number of required valid unique signatures"
+              "The vault this accounting is tracking."
             ],
-            "type": "u8"
+            "type": "pubkey"
           },
           {
-            "name": "signer_count",
+            "name": "locked_amount",
             "docs": [
-              "Number of signers in `signers` array"
+              "Amount the vault is expected to hold (lamports for the SOL vault, base units otherwise)."
             ],
-            "type": "u8"
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "TransferBatch",
+      "docs": [
+        "Represents transfers of a single token from Solana to several recipients on Base, funded by a",
+        "single lock on Solana. Each recipient is credited on Base as if it received its own `Transfer`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "local_token",
+            "docs": [
+              "The token mint address on Solana that is being bridged.",
+              "For SOL this is `NATIVE_SOL_PUBKEY`."
+            ],
+            "type": "pubkey"
           },
           {
-            "name": "signers",
+            "name": "remote_token",
             "docs": [
-              "Static list of authorized signer addresses"
+              "The corresponding token contract address on Base."
             ],
             "type": {
               "array": [
-                {
-                  "array": [
-                    "u8",
-                    20
-                  ]
-                },
-                16
+                "u8",
+                20
               ]
             }
+          },
+          {
+            "name": "recipients",
+            "docs": [
+              "The recipients on Base, at most `MAX_TRANSFER_BATCH_RECIPIENTS`."
+            ],
+            "type": {
+              "vec": {
+                "defined": {
+                  "name": "BatchRecipient"
+                }
+              }
+            }
           }
         ]
       }
     },
     {
-      "name": "Bridge",
+      "name": "TransferMemo",
+      "docs": [
+        "Emitted when a transfer carries a payment memo, so that payment processors can match the",
+        "outgoing message against their invoices."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "base_block_number",
+            "name": "outgoing_message",
             "docs": [
-              "The Base block number associated with the latest registered output root."
+              "The outgoing message carrying the transfer."
             ],
-            "type": "u64"
+            "type": "pubkey"
           },
           {
             "name": "nonce",
             "docs": [
-              "Incremental nonce assigned to each outgoing message."
+              "Nonce of the outgoing message."
             ],
             "type": "u64"
           },
           {
-            "name": "guardian",
+            "name": "from",
             "docs": [
-              "Guardian pubkey authorized to update bridge configuration parameters"
+              "The account bridging the tokens."
             ],
             "type": "pubkey"
           },
           {
-            "name": "paused",
+            "name": "local_token",
             "docs": [
-              "Whether the bridge is paused (emergency stop mechanism)"
+              "The bridged token (a mint, or `NATIVE_SOL_PUBKEY` for SOL)."
             ],
-            "type": "bool"
+            "type": "pubkey"
           },
           {
-            "name": "eip1559",
+            "name": "amount",
             "docs": [
-              "EIP-1559 state and configuration for dynamic pricing."
+              "Amount bridged to Base, after the protocol fee."
             ],
-            "type": {
-              "defined": {
-                "name": "Eip1559"
-              }
-            }
+            "type": "u64"
           },
           {
-            "name": "gas_config",
+            "name": "memo",
             "docs": [
-              "Configuration parameters for outgoing message pricing"
+              "The memo, also appended to the message data relayed to Base."
             ],
-            "type": {
-              "defined": {
-                "name": "GasConfig"
-              }
-            }
+            "type": "bytes"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VaultDecimals",
+      "docs": [
+        "Decimals of the Base token an SPL token vault is bridged to, when lower than the mint",
+        "decimals. Amounts leaving the vault are carried in the remote token's smallest unit, i.e.",
+        "divided by `10^(local_decimals - remote_decimals)`. Amounts are not scaled until this account",
+        "is initialized."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "local_decimals",
+            "docs": [
+              "Decimals of the SPL mint locked in the vault."
+            ],
+            "type": "u8"
           },
           {
-            "name": "protocol_config",
+            "name": "remote_decimals",
             "docs": [
-              "Configuration parameters for bridge protocol"
+              "Decimals in which the amounts are carried to Base."
             ],
-            "type": {
-              "defined": {
-                "name": "ProtocolConfig"
-              }
-            }
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VaultReconciled",
+      "docs": [
+        "Emitted by `reconcile_vault` with the balance of a bridge vault and its liability proven",
+        "against the posted `LiabilityRoot`."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "vault",
+            "docs": [
+              "The reconciled vault (the SOL vault or an SPL token vault)."
+            ],
+            "type": "pubkey"
           },
           {
-            "name": "buffer_config",
+            "name": "local_token",
             "docs": [
-              "Configuration parameters for pre-loading Solana --> Base messages in buffer accounts"
+              "The vault token (a mint, or `NATIVE_SOL_PUBKEY` for SOL)."
             ],
-            "type": {
-              "defined": {
-                "name": "BufferConfig"
-              }
-            }
+            "type": "pubkey"
           },
           {
-            "name": "partner_oracle_config",
+            "name": "base_block_number",
             "docs": [
-              "Partner oracle configuration containing the required signature threshold"
+              "The Base block number at which the liability was measured."
             ],
-            "type": {
-              "defined": {
-                "name": "PartnerOracleConfig"
-              }
-            }
+            "type": "u64"
           },
           {
-            "name": "base_oracle_config",
+            "name": "liability",
             "docs": [
-              "Configuration parameters for Base oracle signers"
+              "Outstanding supply on Base the vault must cover."
             ],
-            "type": {
-              "defined": {
-                "name": "BaseOracleConfig"
-              }
-            }
+            "type": "u64"
+          },
+          {
+            "name": "actual_amount",
+            "docs": [
+              "Amount actually held by the vault."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "solvent",
+            "docs": [
+              "Whether `actual_amount` covers `liability`; raises the solvency alert otherwise."
+            ],
+            "type": "bool"
           }
         ]
       }
     },
     {
-      "name": "BufferConfig",
+      "name": "Version",
+      "docs": [
+        "The version and capabilities the program was compiled with."
+      ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "max_call_buffer_size",
-            "docs": [
-              "Maximum call buffer size. This caps the max size of a Solana → Base message."
-            ],
+            "name": "major",
+            "type": "u16"
+          },
+          {
+            "name": "minor",
+            "type": "u16"
+          },
+          {
+            "name": "patch",
+            "type": "u16"
+          },
+          {
+            "name": "capabilities",
             "type": "u64"
           }
         ]
       }
     },
     {
-      "name": "Call",
+      "name": "WrapTokenResult",
       "docs": [
-        "Represents a contract call to be executed on Base.",
-        "Contains all the necessary information to perform various types of contract interactions,",
-        "including regular calls, delegate calls, and contract creation operations."
+        "Outcome of the wrap token instruction, returned to the caller."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Wrapped",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "pubkey"
+              }
+            ]
+          },
+          {
+            "name": "AlreadyWrapped",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "pubkey"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "WrappedTokenConfirmationRequiredSet",
+      "docs": [
+        "Emitted by the guardian requiring, or no longer requiring, wrapped tokens to be confirmed by",
+        "Base before they can be bridged."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "required",
+            "docs": [
+              "Whether `bridge_wrapped_token` only accepts confirmed wrapped tokens."
+            ],
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "WrappedTokenConfirmed",
+      "docs": [
+        "Emitted when a message relayed from the Base Bridge confirms that Base registered a wrapped",
+        "token created by `wrap_token`."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "ty",
-            "docs": [
-              "The type of call operation to perform (Call, DelegateCall, Create, or Create2).",
-              "Determines how the call will be executed on the Base side."
-            ],
-            "type": {
-              "defined": {
-                "name": "CallType"
-              }
-            }
-          },
-          {
-            "name": "to",
-            "docs": [
-              "The target address on Base (20 bytes for Ethereum-compatible address).",
-              "Must be set to zero for Create and Create2 operations."
-            ],
-            "type": {
-              "array": [
-                "u8",
-                20
-              ]
-            }
-          },
-          {
-            "name": "value",
+            "name": "mint",
             "docs": [
-              "Amount of ETH to send with this call on Base, in wei."
+              "The wrapped token mint."
             ],
-            "type": "u128"
+            "type": "pubkey"
           },
           {
-            "name": "data",
+            "name": "message",
             "docs": [
-              "The encoded function call data or contract bytecode.",
-              "For regular calls: ABI-encoded function signature and parameters.",
-              "For contract creation: the contract's initialization bytecode."
+              "The incoming message carrying the confirmation."
             ],
-            "type": "bytes"
+            "type": "pubkey"
           }
         ]
       }
     },
     {
-      "name": "CallBuffer",
+      "name": "WrappedTokenMaxSupplyUpdated",
       "docs": [
-        "A buffer account that stores call parameters which can be built up over multiple transactions",
-        "to bypass Solana's transaction size limits. The `data` field can be appended incrementally, and",
-        "the account is typically consumed (closed via `close = owner`) by the buffered bridge",
-        "instructions when the call is bridged to Base."
+        "Emitted when the max supply of a wrapped token is mirrored from Base."
       ],
       "type": {
         "kind": "struct",
         "fields": [
           {
-            "name": "owner",
+            "name": "mint",
             "docs": [
-              "The owner who can modify this call buffer"
+              "The wrapped token mint."
             ],
             "type": "pubkey"
           },
           {
-            "name": "ty",
+            "name": "max_supply",
             "docs": [
-              "The type of call operation to perform (Call, DelegateCall, Create, or Create2).",
-              "Determines how the call will be executed on the Base side."
+              "The new max supply, `None` when the cap is lifted."
             ],
             "type": {
-              "defined": {
-                "name": "CallType"
-              }
+              "option": "u64"
             }
-          },
+          }
+        ]
+      }
+    },
+    {
+      "name": "WrappedTokenSupplyCap",
+      "docs": [
+        "Maximum supply of a wrapped token mirrored from Base, set through a message relayed from the",
+        "Base Bridge. Mints of a wrapped token are uncapped until this account is initialized."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
           {
-            "name": "to",
+            "name": "max_supply",
             "docs": [
-              "The target address on Base (20 bytes for Ethereum-compatible address).",
-              "Must be set to zero for Create and Create2 operations."
+              "Maximum supply of the wrapped token, in its local units. `None` lifts the cap."
             ],
             "type": {
-              "array": [
-                "u8",
-                20
-              ]
+              "option": "u64"
             }
-          },
-          {
-            "name": "value",
-            "docs": [
-              "The amount of Base native currency (ETH) to send with this call, in wei."
-            ],
-            "type": "u128"
-          },
-          {
-            "name": "data",
-            "docs": [
-              "The encoded function call data or contract bytecode.",
-              "For regular calls: ABI-encoded function signature and parameters.",
-              "For contract creation: the contract's initialization bytecode."
-            ],
-            "type": "bytes"
           }
         ]
       }
     },
     {
-      "name": "CallType",
+      "name": "bridge::base_to_solana::state::incoming_message::Message",
+      "docs": [
+        "Defines the type of cross-chain operation being performed from Base to Solana.",
+        "",
+        "This enum encapsulates the main categories of bridge operations: general instruction calls,",
+        "token transfers with optional additional instructions, and returns of Solana → Base transfers."
+      ],
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "Call"
+            "name": "Call",
+            "fields": [
+              {
+                "vec": {
+                  "defined": {
+                    "name": "Ix"
+                  }
+                }
+              }
+            ]
           },
           {
-            "name": "DelegateCall"
+            "name": "Transfer",
+            "fields": [
+              {
+                "name": "transfer",
+                "docs": [
+                  "The specific type of token transfer (SOL, SPL token, or wrapped token)"
+                ],
+                "type": {
+                  "defined": {
+                    "name": "bridge::base_to_solana::state::incoming_message::Transfer"
+                  }
+                }
+              },
+              {
+                "name": "ixs",
+                "docs": [
+                  "Additional Solana instructions to execute after the transfer is finalized"
+                ],
+                "type": {
+                  "vec": {
+                    "defined": {
+                      "name": "Ix"
+                    }
+                  }
+                }
+              }
+            ]
           },
           {
-            "name": "Create"
+            "name": "ReturnTransfer",
+            "fields": [
+              {
+                "name": "outgoing_nonce",
+                "docs": [
+                  "Nonce of the original outgoing message. A transfer can be returned at most once."
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "transfer",
+                "docs": [
+                  "The transfer returning the tokens to the original Solana sender"
+                ],
+                "type": {
+                  "defined": {
+                    "name": "bridge::base_to_solana::state::incoming_message::Transfer"
+                  }
+                }
+              }
+            ]
           },
           {
-            "name": "Create2"
-          }
-        ]
-      }
-    },
-    {
-      "name": "Config",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "eip1559_config",
-            "docs": [
-              "Configuration parameters for EIP-1559-inspired fee calculations"
-            ],
-            "type": {
-              "defined": {
-                "name": "Eip1559Config"
+            "name": "CallWithSigners",
+            "fields": [
+              {
+                "name": "ixs",
+                "docs": [
+                  "The Solana instructions to execute"
+                ],
+                "type": {
+                  "vec": {
+                    "defined": {
+                      "name": "Ix"
+                    }
+                  }
+                }
+              },
+              {
+                "name": "signer_seeds",
+                "docs": [
+                  "The custom seeds of each extra signer, namespaced under the sender's bridge CPI authority"
+                ],
+                "type": {
+                  "vec": {
+                    "vec": "bytes"
+                  }
+                }
               }
-            }
+            ]
           },
           {
-            "name": "gas_config",
-            "docs": [
-              "Configuration parameters for outgoing message pricing"
-            ],
-            "type": {
-              "defined": {
-                "name": "GasConfig"
+            "name": "WithComputeBudget",
+            "fields": [
+              {
+                "name": "compute_units",
+                "docs": [
+                  "The compute units that must remain when `relay_message` starts executing the message"
+                ],
+                "type": "u32"
+              },
+              {
+                "name": "message",
+                "docs": [
+                  "The message to execute, which cannot itself be a `WithComputeBudget` message"
+                ],
+                "type": {
+                  "defined": {
+                    "name": "bridge::base_to_solana::state::incoming_message::Message"
+                  }
+                }
               }
-            }
+            ]
           },
           {
-            "name": "protocol_config",
-            "docs": [
-              "Configuration parameters for bridge protocol"
-            ],
-            "type": {
-              "defined": {
-                "name": "ProtocolConfig"
+            "name": "WithRelayFee",
+            "fields": [
+              {
+                "name": "fee",
+                "docs": [
+                  "The relay fee in lamports, prepaid by the sender on Base"
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "message",
+                "docs": [
+                  "The message to execute, which cannot be a `WithComputeBudget` or `WithRelayFee` message"
+                ],
+                "type": {
+                  "defined": {
+                    "name": "bridge::base_to_solana::state::incoming_message::Message"
+                  }
+                }
               }
-            }
+            ]
           },
           {
-            "name": "buffer_config",
-            "docs": [
-              "Configuration parameters for pre-loading Solana --> Base messages in buffer accounts"
-            ],
-            "type": {
-              "defined": {
-                "name": "BufferConfig"
+            "name": "WithLamportAllowance",
+            "fields": [
+              {
+                "name": "lamports",
+                "docs": [
+                  "The lamports lent to the bridge CPI authority, bounded by the operational vault"
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "message",
+                "docs": [
+                  "The message to execute, which cannot be a `WithComputeBudget`, `WithRelayFee` or",
+                  "`WithLamportAllowance` message"
+                ],
+                "type": {
+                  "defined": {
+                    "name": "bridge::base_to_solana::state::incoming_message::Message"
+                  }
+                }
               }
-            }
+            ]
           },
           {
-            "name": "partner_oracle_config",
-            "docs": [
-              "Partner oracle configuration containing the required signature threshold"
-            ],
-            "type": {
-              "defined": {
-                "name": "PartnerOracleConfig"
+            "name": "ConfirmWrappedToken",
+            "fields": [
+              {
+                "name": "mint",
+                "docs": [
+                  "The confirmed wrapped token mint"
+                ],
+                "type": "pubkey"
+              },
+              {
+                "name": "name",
+                "docs": [
+                  "The name of the Base token"
+                ],
+                "type": "string"
+              },
+              {
+                "name": "symbol",
+                "docs": [
+                  "The symbol of the Base token"
+                ],
+                "type": "string"
+              },
+              {
+                "name": "decimals",
+                "docs": [
+                  "The decimals of the Base token"
+                ],
+                "type": "u8"
               }
-            }
+            ]
           },
           {
-            "name": "base_oracle_config",
-            "docs": [
-              "Configuration parameters for Base oracle signers"
-            ],
-            "type": {
-              "defined": {
-                "name": "BaseOracleConfig"
+            "name": "CallHandler",
+            "fields": [
+              {
+                "name": "payload",
+                "docs": [
+                  "The payload passed to the handler, of at most MAX_HANDLER_PAYLOAD_LEN bytes"
+                ],
+                "type": "bytes"
               }
-            }
+            ]
           }
         ]
       }
     },
     {
-      "name": "Eip1559",
+      "name": "bridge::base_to_solana::state::incoming_message::Transfer",
+      "docs": [
+        "Specifies the type of token being finalized on Solana for a Base→Solana bridge",
+        "and contains the necessary data to complete the transfer on the Solana side.",
+        "",
+        "Each variant corresponds to a different token type that can be bridged,",
+        "with variant-specific data needed to complete the transfer operation."
+      ],
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "config",
-            "docs": [
-              "Configuration parameters for EIP-1559-inspired fee calculations"
-            ],
-            "type": {
-              "defined": {
-                "name": "Eip1559Config"
+            "name": "Sol",
+            "fields": [
+              {
+                "defined": {
+                  "name": "FinalizeBridgeSol"
+                }
               }
-            }
-          },
-          {
-            "name": "current_base_fee",
-            "docs": [
-              "Current base fee used in fee computation (runtime state).",
-              "Unitless value combined with `gas_per_call` and gas cost scaler to produce lamports."
-            ],
-            "type": "u64"
+            ]
           },
           {
-            "name": "current_window_gas_used",
-            "docs": [
-              "Gas used in the current time window (runtime state)"
-            ],
-            "type": "u64"
+            "name": "Spl",
+            "fields": [
+              {
+                "defined": {
+                  "name": "FinalizeBridgeSpl"
+                }
+              }
+            ]
           },
           {
-            "name": "window_start_time",
-            "docs": [
-              "Unix timestamp when the current window started (runtime state)"
-            ],
-            "type": "i64"
+            "name": "WrappedToken",
+            "fields": [
+              {
+                "defined": {
+                  "name": "FinalizeBridgeWrappedToken"
+                }
+              }
+            ]
           }
         ]
       }
     },
     {
-      "name": "Eip1559Config",
+      "name": "bridge::solana_to_base::state::outgoing_message::Message",
+      "docs": [
+        "Represents the type of cross-chain operation to be executed on Base.",
+        "This enum encapsulates the main types of operations supported by the bridge:",
+        "direct contract calls, token transfers with optional contract calls, encrypted calls and",
+        "transfer batches."
+      ],
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "target",
-            "docs": [
-              "Gas target per window"
-            ],
-            "type": "u64"
+            "name": "Call",
+            "fields": [
+              {
+                "defined": {
+                  "name": "Call"
+                }
+              }
+            ]
           },
           {
-            "name": "denominator",
-            "docs": [
-              "Adjustment denominator (controls rate of change)"
-            ],
-            "type": "u64"
+            "name": "Transfer",
+            "fields": [
+              {
+                "defined": {
+                  "name": "bridge::solana_to_base::state::outgoing_message::Transfer"
+                }
+              }
+            ]
           },
           {
-            "name": "window_duration_seconds",
-            "docs": [
-              "Window duration in seconds"
-            ],
-            "type": "u64"
+            "name": "Encrypted",
+            "fields": [
+              {
+                "defined": {
+                  "name": "Call"
+                }
+              }
+            ]
           },
           {
-            "name": "minimum_base_fee",
-            "docs": [
-              "Minimum base fee. Used to seed `current_base_fee` at initialization",
-              "and as an underflow clamp during decreases; not enforced as a strict lower bound",
-              "on every step."
-            ],
-            "type": "u64"
+            "name": "TransferBatch",
+            "fields": [
+              {
+                "defined": {
+                  "name": "TransferBatch"
+                }
+              }
+            ]
           }
         ]
       }
     },
     {
-      "name": "FinalizeBridgeSol",
+      "name": "bridge::solana_to_base::state::outgoing_message::Transfer",
       "docs": [
-        "Instruction data for finalizing a native SOL transfer from Base to Solana.",
-        "",
-        "Contains the data needed to release escrowed SOL on Solana that corresponds",
-        "to a transfer initiated on Base. SOL is held in a PDA vault and released to",
-        "the recipient when finalized."
+        "Represents a token transfer from Solana to Base with optional contract execution.",
+        "This struct contains all the information needed to bridge tokens between chains",
+        "and optionally execute additional logic on the destination chain after the transfer."
       ],
       "type": {
         "kind": "struct",
@@ -3362,38 +15669,7 @@ export const IDL = {
           {
             "name": "to",
             "docs": [
-              "The Solana public key of the recipient who will receive the SOL.",
-              "This must match the intended recipient specified in the original bridge message."
-            ],
-            "type": "pubkey"
-          },
-          {
-            "name": "amount",
-            "docs": [
-              "The amount of SOL to transfer, denominated in lamports (1 SOL = 1_000_000_000 lamports).",
-              "This amount will be transferred from the SOL vault to the recipient."
-            ],
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "FinalizeBridgeSpl",
-      "docs": [
-        "Instruction data for finalizing a bridged SPL token transfer from Base to Solana.",
-        "",
-        "Releases tokens from a program-controlled vault PDA to the specified recipient",
-        "token account on Solana."
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "remote_token",
-            "docs": [
-              "The 20-byte ERC-20 contract address on Base that corresponds to the SPL mint.",
-              "Used, together with the SPL mint, to derive the token-vault PDA for this mapping."
+              "The recipient address on Base that will receive the bridged tokens."
             ],
             "type": {
               "array": [
@@ -3405,707 +15681,956 @@ export const IDL = {
           {
             "name": "local_token",
             "docs": [
-              "The SPL token mint on Solana that mirrors the `remote_token`."
-            ],
-            "type": "pubkey"
-          },
-          {
-            "name": "to",
-            "docs": [
-              "The recipient SPL token account address on Solana that will receive tokens.",
-              "This must be a valid token account for `local_token`.",
-              "Note: this program does not enforce ownership or ATA semantics; the account",
-              "is authenticated by address equality (`self.to`) and `transfer_checked`",
-              "enforces the mint match."
-            ],
-            "type": "pubkey"
-          },
-          {
-            "name": "amount",
-            "docs": [
-              "The amount to transfer, in base units of the mint (respecting mint decimals).",
-              "`transfer_checked` enforces that the destination account's mint matches and",
-              "the decimals are correct."
-            ],
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "FinalizeBridgeWrappedToken",
-      "docs": [
-        "Instruction data for finalizing a wrapped token transfer from Base to Solana.",
-        "",
-        "This struct represents the final step in a cross-chain bridge operation where tokens",
-        "that were originally on Base are being bridged to Solana as wrapped tokens. The",
-        "finalization process mints the appropriate amount of wrapped tokens to the recipient's",
-        "token account on Solana.",
-        "",
-        "The wrapped token mint is derived deterministically from the original token's metadata",
-        "and decimals, ensuring consistency across bridge operations."
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "local_token",
-            "docs": [
-              "The mint address of the wrapped token on Solana.",
-              "This is a PDA that represents the Solana version",
-              "of a token that originally exists on Base. The mint address is derived",
-              "deterministically from the original token's metadata and decimals."
+              "The token mint address on Solana that is being bridged.",
+              "For SOL this is `NATIVE_SOL_PUBKEY`."
             ],
             "type": "pubkey"
           },
           {
-            "name": "to",
+            "name": "remote_token",
             "docs": [
-              "The destination token account that will receive the wrapped tokens.",
-              "This must be a valid token account that is associated with the wrapped",
-              "token mint. It is expected to be controlled by the intended recipient of",
-              "the bridged tokens; recipient ownership is not enforced by this",
-              "instruction."
+              "The corresponding token contract address on Base.",
+              "This is the token that will be minted or unlocked on the Base side."
             ],
-            "type": "pubkey"
+            "type": {
+              "array": [
+                "u8",
+                20
+              ]
+            }
           },
           {
             "name": "amount",
             "docs": [
-              "The amount of wrapped tokens to mint to the recipient.",
-              "The amount is specified in the token's smallest unit."
-            ],
-            "type": "u64"
-          }
-        ]
-      }
-    },
-    {
-      "name": "GasConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "gas_cost_scaler",
-            "docs": [
-              "Scaling factor applied when converting (gas_per_call * base_fee) into lamports"
+              "The amount to transfer, in the token's smallest unit.",
+              "For SPL tokens and SOL, funds are locked on Solana; for wrapped tokens, they are burned.",
+              "On Base, the corresponding amount is minted or unlocked."
             ],
             "type": "u64"
           },
           {
-            "name": "gas_cost_scaler_dp",
+            "name": "call",
             "docs": [
-              "Decimal precision for the gas cost scaler (denominator)"
+              "Optional contract call to execute on Base after the token transfer completes.",
+              "Allows for complex cross-chain operations that combine token transfers with logic execution."
             ],
-            "type": "u64"
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "Call"
+                }
+              }
+            }
           },
           {
-            "name": "gas_fee_receiver",
+            "name": "memo",
             "docs": [
-              "Account that receives gas fees collected on Solana"
+              "Optional opaque payment memo of at most `MAX_TRANSFER_MEMO_LEN` bytes, used by payment",
+              "processors to match invoices. It is appended to the message data relayed to Base",
+              "(`abi.encode(transfer, memo)` or `abi.encode(transfer, call, memo)`), where it is covered",
+              "by the message hash and ignored when decoding the transfer."
             ],
-            "type": "pubkey"
+            "type": {
+              "option": "bytes"
+            }
           },
           {
-            "name": "gas_per_call",
+            "name": "swap_guard",
             "docs": [
-              "Amount of gas per Solana --> Base message"
+              "Optional slippage protection for a `call` swapping the bridged tokens on Base, required",
+              "when the call targets a router of the `DexRouterList`. It is appended to the message data",
+              "relayed to Base after the memo (`abi.encode(transfer, call, memo, swap_guard)`, with an",
+              "empty memo if there is none), where the executor enforces it around the call."
             ],
-            "type": "u64"
+            "type": {
+              "option": {
+                "defined": {
+                  "name": "SwapGuard"
+                }
+              }
+            }
           }
         ]
       }
+    }
+  ],
+  "constants": [
+    {
+      "name": "ADDRESS_BOOK_SEED",
+      "type": "bytes",
+      "value": "[97, 100, 100, 114, 101, 115, 115, 95, 98, 111, 111, 107]"
+    },
+    {
+      "name": "BRIDGE_CPI_AUTHORITY_SEED",
+      "type": "bytes",
+      "value": "[98, 114, 105, 100, 103, 101, 95, 99, 112, 105, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]"
+    },
+    {
+      "name": "BRIDGE_FULL_BALANCE",
+      "docs": [
+        "Sentinel `amount` for `bridge_spl` and `bridge_wrapped_token` that bridges the whole balance of",
+        "the source token account, resolved at execution time."
+      ],
+      "type": "u64",
+      "value": "18446744073709551615"
+    },
+    {
+      "name": "BRIDGE_LAYOUT_VERSION",
+      "type": "u8",
+      "value": "16"
+    },
+    {
+      "name": "BRIDGE_SEED",
+      "type": "bytes",
+      "value": "[98, 114, 105, 100, 103, 101]"
+    },
+    {
+      "name": "BRIDGE_SESSION_SEED",
+      "type": "bytes",
+      "value": "[98, 114, 105, 100, 103, 101, 95, 115, 101, 115, 115, 105, 111, 110]"
+    },
+    {
+      "name": "BURN_RECEIPT_SEED",
+      "type": "bytes",
+      "value": "[98, 117, 114, 110, 95, 114, 101, 99, 101, 105, 112, 116]"
+    },
+    {
+      "name": "CAPABILITIES",
+      "docs": [
+        "Capabilities of this build. Bits are only ever added, so clients must ignore unknown ones."
+      ],
+      "type": "u64",
+      "value": "18012199486226431"
+    },
+    {
+      "name": "CAPABILITY_BASE_GOVERNANCE",
+      "docs": [
+        "Critical configuration governed from Base through `set_base_governance`,",
+        "`set_governance_mode`, `set_oracle_signers_from_governance` and `pause_from_governance`."
+      ],
+      "type": "u64",
+      "value": "17179869184"
+    },
+    {
+      "name": "CAPABILITY_BATCHED_OUTPUT_ROOTS",
+      "docs": [
+        "`register_output_roots_batch`."
+      ],
+      "type": "u64",
+      "value": "4"
+    },
+    {
+      "name": "CAPABILITY_BRIDGE_FULL_BALANCE",
+      "docs": [
+        "`BRIDGE_FULL_BALANCE` amounts in `bridge_spl` and `bridge_wrapped_token`."
+      ],
+      "type": "u64",
+      "value": "524288"
+    },
+    {
+      "name": "CAPABILITY_BRIDGE_SESSIONS",
+      "docs": [
+        "`create_bridge_session`, `revoke_bridge_session` and session delegates in `bridge_spl` and",
+        "`bridge_wrapped_token`."
+      ],
+      "type": "u64",
+      "value": "131072"
+    },
+    {
+      "name": "CAPABILITY_BUFFERED_CALLS",
+      "docs": [
+        "`bridge_call_buffered` and the `*_with_buffered_call` instructions."
+      ],
+      "type": "u64",
+      "value": "1"
+    },
+    {
+      "name": "CAPABILITY_BUFFERED_PROVE",
+      "docs": [
+        "`prove_message_buffered` and the prove buffer instructions."
+      ],
+      "type": "u64",
+      "value": "2"
+    },
+    {
+      "name": "CAPABILITY_BURN_RECEIPTS",
+      "docs": [
+        "`bridge_wrapped_token` writes an immutable `BurnReceipt` (PDA with `BURN_RECEIPT_SEED` and the",
+        "outgoing message) linking each burn to its Base recipient."
+      ],
+      "type": "u64",
+      "value": "562949953421312"
+    },
+    {
+      "name": "CAPABILITY_CALL_BUFFER_EXPIRY",
+      "docs": [
+        "`expire_call_buffer` with a caller bounty."
+      ],
+      "type": "u64",
+      "value": "8"
+    },
+    {
+      "name": "CAPABILITY_CHUNKED_MESSAGES",
+      "docs": [
+        "`prove_message_chunked`, `extend_prove_buffer` and the `ProvenChunk` accounts executed by",
+        "`relay_message`."
+      ],
+      "type": "u64",
+      "value": "8388608"
+    },
+    {
+      "name": "CAPABILITY_COMPUTE_BUDGET_HINTS",
+      "docs": [
+        "`WithComputeBudget` incoming messages."
+      ],
+      "type": "u64",
+      "value": "256"
+    },
+    {
+      "name": "CAPABILITY_CONFIG_HISTORY",
+      "docs": [
+        "`init_config_history` and the `ConfigHistory` log written by every configuration setter."
+      ],
+      "type": "u64",
+      "value": "16384"
+    },
+    {
+      "name": "CAPABILITY_CPI_AUTHORITY_DERIVATION",
+      "docs": [
+        "`derive_cpi_authority` and the `cpi_authority` reported in `MessageProven`."
+      ],
+      "type": "u64",
+      "value": "35184372088832"
+    },
+    {
+      "name": "CAPABILITY_CROSSCHAIN_TOKEN",
+      "docs": [
+        "`crosschain_mint` and the ERC-7802 style `CrosschainMint` and `CrosschainBurn` events."
+      ],
+      "type": "u64",
+      "value": "536870912"
+    },
+    {
+      "name": "CAPABILITY_EMERGENCY_EXIT",
+      "docs": [
+        "`enable_emergency_exit`, `cancel_emergency_exit`, `emergency_withdraw_sol` and",
+        "`emergency_withdraw_spl`."
+      ],
+      "type": "u64",
+      "value": "16777216"
+    },
+    {
+      "name": "CAPABILITY_ENCRYPTED_CALLS",
+      "docs": [
+        "`bridge_encrypted_call` and `Encrypted` outgoing messages."
+      ],
+      "type": "u64",
+      "value": "2048"
+    },
+    {
+      "name": "CAPABILITY_EQUIVOCATION_REPORTS",
+      "docs": [
+        "`report_equivocation` and the `EquivocationEvidence` it records."
+      ],
+      "type": "u64",
+      "value": "2097152"
+    },
+    {
+      "name": "CAPABILITY_FEE_QUOTE_GRACE",
+      "docs": [
+        "`set_fee_quote_grace_period`: gas fees charged right after an EIP-1559 window rollover do not",
+        "exceed the base fee of the previous window during the configured grace period."
+      ],
+      "type": "u64",
+      "value": "281474976710656"
+    },
+    {
+      "name": "CAPABILITY_FINALIZATION_DELAY",
+      "docs": [
+        "The finalization delay of high-value transfers set with `set_finalization_delay`,",
+        "`veto_message` and the `finalizable_at` reported in `MessageProven`."
+      ],
+      "type": "u64",
+      "value": "4294967296"
+    },
+    {
+      "name": "CAPABILITY_GUARDED_LAUNCH",
+      "docs": [
+        "Guarded launch, restricting `bridge_spl` to the mints allow-listed through `set_mint_allowed`."
+      ],
+      "type": "u64",
+      "value": "1099511627776"
+    },
+    {
+      "name": "CAPABILITY_HANDLER_CALLS",
+      "docs": [
+        "`register_handler` and `CallHandler` incoming messages, relayed by invoking the handler",
+        "registered by the sender with the message payload (PDA with `HANDLER_REGISTRATION_SEED` and the",
+        "sender, handler program and handler accounts remaining accounts)."
+      ],
+      "type": "u64",
+      "value": "2251799813685248"
+    },
+    {
+      "name": "CAPABILITY_IDEMPOTENT_WRAP_TOKEN",
+      "docs": [
+        "`wrap_token` returning `WrapTokenResult::AlreadyWrapped` for already wrapped tokens."
+      ],
+      "type": "u64",
+      "value": "262144"
+    },
+    {
+      "name": "CAPABILITY_INSURANCE_FUND",
+      "docs": [
+        "Share of the gas fees credited to an insurance fund through `set_insurance_share`, paid out by",
+        "the guardian after a timelock through `request_insurance_payout` and `execute_insurance_payout`."
+      ],
+      "type": "u64",
+      "value": "34359738368"
+    },
+    {
+      "name": "CAPABILITY_LAMPORT_ALLOWANCE",
+      "docs": [
+        "`WithLamportAllowance` incoming messages, lent lamports from the operational vault configured",
+        "through `set_max_lamport_allowance`."
+      ],
+      "type": "u64",
+      "value": "549755813888"
+    },
+    {
+      "name": "CAPABILITY_MAINTENANCE_BUDGET",
+      "docs": [
+        "The maintenance budget reimburses the payer of `register_output_root` for its rent and fees."
+      ],
+      "type": "u64",
+      "value": "274877906944"
+    },
+    {
+      "name": "CAPABILITY_MESSAGE_RECEIPTS",
+      "docs": [
+        "The `MessageProven` and `MessageRelayed` events and the `nonce` recorded in incoming messages."
+      ],
+      "type": "u64",
+      "value": "1073741824"
+    },
+    {
+      "name": "CAPABILITY_MINT_POLICY",
+      "docs": [
+        "`set_mint_policy` and the freeze authority checks of `bridge_spl`."
+      ],
+      "type": "u64",
+      "value": "4194304"
+    },
+    {
+      "name": "CAPABILITY_ORACLE_FAILOVER",
+      "docs": [
+        "`set_oracle_failover_config`, `activate_oracle_emergency` and `deactivate_oracle_emergency`."
+      ],
+      "type": "u64",
+      "value": "4096"
+    },
+    {
+      "name": "CAPABILITY_ORACLE_HEALTH",
+      "docs": [
+        "Oracle signer heartbeats recorded by output root registrations and `get_oracle_health`."
+      ],
+      "type": "u64",
+      "value": "33554432"
+    },
+    {
+      "name": "CAPABILITY_OUTBOX_MMR",
+      "docs": [
+        "Outgoing messages are appended to the outbox MMR stored in the bridge, whose root Base can",
+        "verify them against."
+      ],
+      "type": "u64",
+      "value": "137438953472"
+    },
+    {
+      "name": "CAPABILITY_OUTFLOW_APPROVAL",
+      "docs": [
+        "Transfers tripping the outflow circuit breaker held until the guardian releases them with",
+        "`approve_outflow`, instead of tripping the breaker again once the bridge is unpaused."
+      ],
+      "type": "u64",
+      "value": "4503599627370496"
+    },
+    {
+      "name": "CAPABILITY_OUTFLOW_BREAKER",
+      "docs": [
+        "The outflow circuit breaker configured with `set_outflow_breaker`."
+      ],
+      "type": "u64",
+      "value": "268435456"
+    },
+    {
+      "name": "CAPABILITY_OUTPUT_ROOT_SUPERSESSION",
+      "docs": [
+        "`supersede_output_root`, `close_superseded_message` and the output root revision checked by",
+        "`relay_message`."
+      ],
+      "type": "u64",
+      "value": "67108864"
+    },
+    {
+      "name": "CAPABILITY_PAYER_RATE_LIMIT",
+      "docs": [
+        "`set_payer_rate_limit`: instructions sending outgoing messages take the",
+        "`payer_rate_limit_config` and `payer_rate_limit` (PDA with `PAYER_RATE_LIMIT_SEED` and the",
+        "payer) accounts, bounding the messages and gas each payer can pay for per window."
+      ],
+      "type": "u64",
+      "value": "1125899906842624"
+    },
+    {
+      "name": "CAPABILITY_PROTOCOL_FEE",
+      "docs": [
+        "Protocol fee charged on bridged amounts."
+      ],
+      "type": "u64",
+      "value": "64"
+    },
+    {
+      "name": "CAPABILITY_PROVER_RENT_REFUND",
+      "docs": [
+        "The `prover` account of `relay_message`, refunded the rent freed by executed messages."
+      ],
+      "type": "u64",
+      "value": "134217728"
+    },
+    {
+      "name": "CAPABILITY_RELAYED_NONCES",
+      "docs": [
+        "`confirm_relayed` and `close_relayed_outgoing_message`."
+      ],
+      "type": "u64",
+      "value": "16"
+    },
+    {
+      "name": "CAPABILITY_RELAY_DENY_LIST",
+      "docs": [
+        "Relayed messages cannot invoke the loaders and other privileged programs, nor the programs",
+        "denied through `set_relay_deny_list`."
+      ],
+      "type": "u64",
+      "value": "68719476736"
+    },
+    {
+      "name": "CAPABILITY_RELAY_EXECUTION",
+      "docs": [
+        "`record_relay_execution` and `RelayExecution` records of the relayer that executed an outgoing",
+        "message on Base."
+      ],
+      "type": "u64",
+      "value": "9007199254740992"
+    },
+    {
+      "name": "CAPABILITY_RELAY_FEES",
+      "docs": [
+        "`settle_relay_fees` and `WithRelayFee` incoming messages."
+      ],
+      "type": "u64",
+      "value": "32768"
+    },
+    {
+      "name": "CAPABILITY_RETURN_TRANSFER",
+      "docs": [
+        "`ReturnTransfer` incoming messages."
+      ],
+      "type": "u64",
+      "value": "32"
+    },
+    {
+      "name": "CAPABILITY_REUSABLE_CALL_BUFFERS",
+      "docs": [
+        "Reusable call buffers, marked through `set_call_buffer_reusable` and copied through",
+        "`clone_call_buffer`, which buffered bridge instructions read without closing."
+      ],
+      "type": "u64",
+      "value": "17592186044416"
+    },
+    {
+      "name": "CAPABILITY_ROOT_FRESHNESS",
+      "docs": [
+        "`set_root_freshness_config` and the per-message-kind output root freshness policy."
+      ],
+      "type": "u64",
+      "value": "65536"
+    },
+    {
+      "name": "CAPABILITY_SPL_DECIMAL_SCALING",
+      "docs": [
+        "SPL transfer amounts carried in the decimals of the Base token set with `set_vault_decimals`."
+      ],
+      "type": "u64",
+      "value": "8589934592"
+    },
+    {
+      "name": "CAPABILITY_SPL_MEMO",
+      "docs": [
+        "`spl_memo` argument of the bridge instructions."
+      ],
+      "type": "u64",
+      "value": "512"
     },
     {
-      "name": "IncomingMessage",
+      "name": "CAPABILITY_SWAP_GUARD",
       "docs": [
-        "Represents a cross-chain message sent from Base to Solana",
-        "that is waiting to be processed or has already been executed.",
-        "",
-        "This struct stores the essential information needed to validate and execute",
-        "bridge operations from Base to Solana, including both simple calls and token transfers.",
-        "",
-        "Messages are created by the `prove_message` instruction (after MMR verification)",
-        "and executed by the `relay_message` instruction."
+        "`SwapGuard`s on transfers, required for calls to the routers registered through",
+        "`set_dex_router`."
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "sender",
-            "docs": [
-              "The 20-byte EVM address of the sender on Base who initiated this bridge operation.",
-              "Used to derive the bridge CPI authority PDA that signs downstream CPIs during relay.",
-              "This field does not restrict who can call the relay instruction."
-            ],
-            "type": {
-              "array": [
-                "u8",
-                20
-              ]
-            }
-          },
-          {
-            "name": "message",
-            "docs": [
-              "The actual message payload containing either instruction calls or token transfer data.",
-              "This enum determines what type of operation will be executed on Solana."
-            ],
-            "type": {
-              "defined": {
-                "name": "bridge::base_to_solana::state::incoming_message::Message"
-              }
-            }
-          },
-          {
-            "name": "executed",
-            "docs": [
-              "Flag indicating whether this message has been successfully executed on Solana.",
-              "Once set to true, the message cannot be executed again, preventing replay attacks."
-            ],
-            "type": "bool"
-          }
-        ]
-      }
+      "type": "u64",
+      "value": "70368744177664"
     },
     {
-      "name": "Ix",
+      "name": "CAPABILITY_TRANSFER_BATCH",
       "docs": [
-        "Instruction to be executed by the bridge program via signed CPI during message relay.",
-        "Functionally equivalent to a Solana `Instruction`, but serialized with Anchor for cross-program messaging."
+        "`bridge_sol_batch` and `TransferBatch` outgoing messages."
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "program_id",
-            "docs": [
-              "Program that will process this instruction."
-            ],
-            "type": "pubkey"
-          },
-          {
-            "name": "accounts",
-            "docs": [
-              "Accounts required for this instruction."
-            ],
-            "type": {
-              "vec": {
-                "defined": {
-                  "name": "IxAccount"
-                }
-              }
-            }
-          },
-          {
-            "name": "data",
-            "docs": [
-              "Instruction data."
-            ],
-            "type": "bytes"
-          }
-        ]
-      }
+      "type": "u64",
+      "value": "1048576"
     },
     {
-      "name": "IxAccount",
+      "name": "CAPABILITY_TRANSFER_GAS_LANE",
       "docs": [
-        "Account used in an instruction.",
-        "Similar to Solana's `AccountMeta`, but serializable with Anchor and supports PDAs via `PubkeyOrPda`."
+        "Separate EIP-1559 gas lane pricing token transfers apart from calls, configured through",
+        "`set_transfer_gas_lane`."
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "pubkey",
-            "docs": [
-              "Public key of the account."
-            ],
-            "type": "pubkey"
-          },
-          {
-            "name": "is_writable",
-            "docs": [
-              "Whether the account is writable."
-            ],
-            "type": "bool"
-          },
-          {
-            "name": "is_signer",
-            "docs": [
-              "Whether the account is a signer."
-            ],
-            "type": "bool"
-          }
-        ]
-      }
+      "type": "u64",
+      "value": "4398046511104"
     },
     {
-      "name": "OutgoingMessage",
+      "name": "CAPABILITY_TRANSFER_MEMO",
       "docs": [
-        "Represents a message being sent from Solana to Base through the bridge.",
-        "This struct contains all the necessary information to execute a cross-chain operation",
-        "on the Base side, including the message content and execution parameters."
+        "Optional memo on transfers."
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "nonce",
-            "docs": [
-              "Monotonic message nonce used for ordering and replay protection on Base.",
-              "Starts at 0 and is incremented by the `Bridge` for each new message."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "sender",
-            "docs": [
-              "The Solana public key of the signer that initiated this cross-chain message.",
-              "Carried to Base for use by destination logic; Solana-side authentication is enforced",
-              "via signer constraints."
-            ],
-            "type": "pubkey"
-          },
-          {
-            "name": "message",
-            "docs": [
-              "The actual message payload that will be executed on Base.",
-              "Can be either a direct contract call or a token transfer (with optional call)."
-            ],
-            "type": {
-              "defined": {
-                "name": "bridge::solana_to_base::state::outgoing_message::Message"
-              }
-            }
-          }
-        ]
-      }
+      "type": "u64",
+      "value": "128"
     },
     {
-      "name": "OutputRoot",
+      "name": "CAPABILITY_VAULT_RECONCILIATION",
       "docs": [
-        "Represents a cryptographic commitment to the set of Base L2 bridge messages",
-        "at a specific Base block number.",
-        "",
-        "Output roots are registered on Solana by a trusted oracle and serve as",
-        "checkpoints that allow messages from Base to be proven and relayed to",
-        "Solana. Each output root contains an MMR root that commits to all bridge",
-        "messages as of a particular Base block number.",
-        "",
-        "This account is used in the Base → Solana message passing flow, where:",
-        "1. A trusted oracle registers output roots for specific Base blocks",
-        "2. Users prove their messages were included on Base using these roots and an MMR proof",
-        "3. Proven messages are then relayed and executed on Solana"
+        "`post_liability_root`, `reconcile_vault` and `clear_solvency_alert`."
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "root",
-            "docs": [
-              "The 32-byte MMR root that commits to all outgoing bridge messages on Base",
-              "as of the specified Base block number."
-            ],
-            "type": {
-              "array": [
-                "u8",
-                32
-              ]
-            }
-          },
-          {
-            "name": "total_leaf_count",
-            "docs": [
-              "The total number of leaves that were present in the MMR when this root",
-              "was generated. This is crucial for determining the MMR structure and",
-              "mountain configuration at the time of proof validation."
-            ],
-            "type": "u64"
-          }
-        ]
-      }
+      "type": "u64",
+      "value": "1024"
+    },
+    {
+      "name": "CAPABILITY_WRAPPED_SUPPLY_CAP",
+      "docs": [
+        "`set_wrapped_token_max_supply` and the wrapped token max supply enforced on mints."
+      ],
+      "type": "u64",
+      "value": "2147483648"
+    },
+    {
+      "name": "CAPABILITY_WRAPPED_TOKEN_CONFIRMATION",
+      "docs": [
+        "`ConfirmWrappedToken` incoming messages confirming wrapped token registrations, which",
+        "`bridge_wrapped_token` can be required to wait for through",
+        "`set_wrapped_token_confirmation_required`."
+      ],
+      "type": "u64",
+      "value": "8796093022208"
+    },
+    {
+      "name": "CAPABILITY_WRAPPED_TOKEN_METADATA_CONFIRMATION",
+      "docs": [
+        "`ConfirmWrappedToken` incoming messages echoing the name, symbol and decimals of the Base",
+        "token, which must match the metadata of the confirmed wrapped mint."
+      ],
+      "type": "u64",
+      "value": "140737488355328"
+    },
+    {
+      "name": "CAPABILITY_WRAPPED_TOKEN_SCALING",
+      "docs": [
+        "`scale_wrapped_token_amount`."
+      ],
+      "type": "u64",
+      "value": "8192"
+    },
+    {
+      "name": "CONFIG_HISTORY_LEN",
+      "type": "u64",
+      "value": "64"
+    },
+    {
+      "name": "CONFIG_HISTORY_SEED",
+      "type": "bytes",
+      "value": "[99, 111, 110, 102, 105, 103, 95, 104, 105, 115, 116, 111, 114, 121]"
+    },
+    {
+      "name": "DEVNET_ADJUSTMENT_DENOMINATOR",
+      "type": "u64",
+      "value": "2"
+    },
+    {
+      "name": "DEVNET_BLOCK_INTERVAL_REQUIREMENT",
+      "type": "u64",
+      "value": "300"
+    },
+    {
+      "name": "DEVNET_GAS_COST_SCALER",
+      "type": "u64",
+      "value": "1000000"
+    },
+    {
+      "name": "DEVNET_GAS_COST_SCALER_DP",
+      "type": "u64",
+      "value": "1000000"
+    },
+    {
+      "name": "DEVNET_GAS_PER_CALL",
+      "type": "u64",
+      "value": "100000"
+    },
+    {
+      "name": "DEVNET_GAS_TARGET",
+      "type": "u64",
+      "value": "5000000"
+    },
+    {
+      "name": "DEVNET_MAX_CALL_BUFFER_SIZE",
+      "type": "u64",
+      "value": "8192"
+    },
+    {
+      "name": "DEVNET_MINIMUM_BASE_FEE",
+      "type": "u64",
+      "value": "1"
+    },
+    {
+      "name": "DEVNET_WINDOW_DURATION_SECONDS",
+      "type": "u64",
+      "value": "1"
+    },
+    {
+      "name": "DEX_ROUTER_LIST_SEED",
+      "type": "bytes",
+      "value": "[100, 101, 120, 95, 114, 111, 117, 116, 101, 114, 95, 108, 105, 115, 116]"
+    },
+    {
+      "name": "EMERGENCY_EXIT_SEED",
+      "type": "bytes",
+      "value": "[101, 109, 101, 114, 103, 101, 110, 99, 121, 95, 101, 120, 105, 116]"
+    },
+    {
+      "name": "EMERGENCY_EXIT_TIMELOCK_SECONDS",
+      "docs": [
+        "Delay between `enable_emergency_exit` and the first emergency withdrawal."
+      ],
+      "type": "i64",
+      "value": "2592000"
+    },
+    {
+      "name": "ENCRYPTED_CALL_GAS_PER_BYTE",
+      "docs": [
+        "Gas charged per ciphertext byte of an encrypted call, on top of `gas_per_call`: ciphertext is",
+        "incompressible and costs the non-zero calldata byte price on Base."
+      ],
+      "type": "u64",
+      "value": "16"
+    },
+    {
+      "name": "EQUIVOCATION_EVIDENCE_SEED",
+      "type": "bytes",
+      "value": "[101, 113, 117, 105, 118, 111, 99, 97, 116, 105, 111, 110, 95, 101, 118, 105, 100, 101, 110, 99, 101]"
+    },
+    {
+      "name": "GAS_FEE_ESCROW_SEED",
+      "type": "bytes",
+      "value": "[103, 97, 115, 95, 102, 101, 101, 95, 101, 115, 99, 114, 111, 119]"
+    },
+    {
+      "name": "HANDLER_REGISTRATION_SEED",
+      "type": "bytes",
+      "value": "[104, 97, 110, 100, 108, 101, 114, 95, 114, 101, 103, 105, 115, 116, 114, 97, 116, 105, 111, 110]"
+    },
+    {
+      "name": "INCOMING_MESSAGE_SEED",
+      "type": "bytes",
+      "value": "[105, 110, 99, 111, 109, 105, 110, 103, 95, 109, 101, 115, 115, 97, 103, 101]"
     },
     {
-      "name": "PartialTokenMetadata",
+      "name": "INSURANCE_FUND_SEED",
+      "type": "bytes",
+      "value": "[105, 110, 115, 117, 114, 97, 110, 99, 101, 95, 102, 117, 110, 100]"
+    },
+    {
+      "name": "INSURANCE_PAYOUT_TIMELOCK_SECONDS",
       "docs": [
-        "Represents token metadata for tokens that are bridged between Base and Solana.",
-        "",
-        "This struct contains metadata needed to represent a token that exists on both",
-        "chains, including information about its remote counterpart and any scaling factors needed",
-        "to handle differences between the chains (such as decimal precision).",
-        "",
-        "The metadata is stored using the SPL Token-2022 metadata interface's",
-        "`additional_metadata` key/value field and can be used to reconstruct the relationship",
-        "between tokens on both sides of the bridge."
+        "Delay between `request_insurance_payout` and the payout."
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "name",
-            "docs": [
-              "The human-readable name of the token (e.g., \"Wrapped Bitcoin\")"
-            ],
-            "type": "string"
-          },
-          {
-            "name": "symbol",
-            "docs": [
-              "The symbol/ticker of the token (e.g., \"WBTC\")"
-            ],
-            "type": "string"
-          },
-          {
-            "name": "remote_token",
-            "docs": [
-              "The 20-byte address of the corresponding token contract on Base (EVM address bytes).",
-              "This allows the bridge to identify which Base token this Solana token represents."
-            ],
-            "type": {
-              "array": [
-                "u8",
-                20
-              ]
-            }
-          },
-          {
-            "name": "scaler_exponent",
-            "docs": [
-              "The scaling exponent used to convert between token amounts on different chains.",
-              "This handles cases where tokens have differing decimal precision on Base vs Solana.",
-              "For example, when Base token has 18 decimals and the Solana wrapped mint has 9,",
-              "this value conveys the decimal relationship so bridging logic can scale amounts.",
-              "The exact conversion is performed by the EVM-side contract; Solana propagates this",
-              "value but does not apply arithmetic with it."
-            ],
-            "type": "u8"
-          }
-        ]
-      }
+      "type": "i64",
+      "value": "604800"
     },
     {
-      "name": "PartnerOracleConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "required_threshold",
-            "docs": [
-              "Partner signatures required by our bridge to accept an output root"
-            ],
-            "type": "u8"
-          }
-        ]
-      }
+      "name": "LIABILITY_ROOT_SEED",
+      "type": "bytes",
+      "value": "[108, 105, 97, 98, 105, 108, 105, 116, 121, 95, 114, 111, 111, 116]"
     },
     {
-      "name": "ProtocolConfig",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "block_interval_requirement",
-            "docs": [
-              "Block interval requirement for output root registration. Every Base block associated with a",
-              "submitted output root must be a multiple of this number."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "remote_sol_address",
-            "docs": [
-              "The Base evm address of SOL"
-            ],
-            "type": {
-              "array": [
-                "u8",
-                20
-              ]
-            }
-          }
-        ]
-      }
+      "name": "MAINTENANCE_BUDGET_SEED",
+      "type": "bytes",
+      "value": "[109, 97, 105, 110, 116, 101, 110, 97, 110, 99, 101, 95, 98, 117, 100, 103, 101, 116]"
     },
     {
-      "name": "ProveBuffer",
+      "name": "MAX_ADDRESS_BOOK_TOKENS",
+      "type": "u8",
+      "value": "16"
+    },
+    {
+      "name": "MAX_ALLOWED_FREEZE_AUTHORITIES",
+      "type": "u8",
+      "value": "16"
+    },
+    {
+      "name": "MAX_ALLOWED_MINTS",
+      "type": "u8",
+      "value": "32"
+    },
+    {
+      "name": "MAX_DEX_ROUTERS",
+      "type": "u8",
+      "value": "16"
+    },
+    {
+      "name": "MAX_EMERGENCY_SIGNER_COUNT",
+      "type": "u8",
+      "value": "4"
+    },
+    {
+      "name": "MAX_EXTRA_SIGNERS",
       "docs": [
-        "A buffer account to stage large Base → Solana prove inputs over multiple transactions.",
-        "Stores the serialized `Message` bytes (`data`) and the MMR `proof` nodes."
+        "Maximum number of extra PDAs (derived from the bridge CPI authority) a message can request to sign with."
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "owner",
-            "docs": [
-              "The owner who can modify and eventually consume this buffer"
-            ],
-            "type": "pubkey"
-          },
-          {
-            "name": "data",
-            "docs": [
-              "Serialized `Message` data (Anchor-serialized)"
-            ],
-            "type": "bytes"
-          },
-          {
-            "name": "proof",
-            "docs": [
-              "MMR proof nodes used to validate inclusion against an OutputRoot"
-            ],
-            "type": {
-              "vec": {
-                "array": [
-                  "u8",
-                  32
-                ]
-              }
-            }
-          }
-        ]
-      }
+      "type": "u8",
+      "value": "4"
     },
     {
-      "name": "bridge::base_to_solana::state::incoming_message::Message",
+      "name": "MAX_EXTRA_SIGNER_SEEDS",
       "docs": [
-        "Defines the type of cross-chain operation being performed from Base to Solana.",
-        "",
-        "This enum encapsulates the two main categories of bridge operations:",
-        "general instruction calls and token transfers with optional additional instructions."
+        "Maximum number of custom seeds per extra signer. The bridge CPI authority seed, the sender and the bump",
+        "take 3 of the 16 seeds allowed by the runtime."
       ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Call",
-            "fields": [
-              {
-                "vec": {
-                  "defined": {
-                    "name": "Ix"
-                  }
-                }
-              }
-            ]
-          },
-          {
-            "name": "Transfer",
-            "fields": [
-              {
-                "name": "transfer",
-                "docs": [
-                  "The specific type of token transfer (SOL, SPL token, or wrapped token)"
-                ],
-                "type": {
-                  "defined": {
-                    "name": "bridge::base_to_solana::state::incoming_message::Transfer"
-                  }
-                }
-              },
-              {
-                "name": "ixs",
-                "docs": [
-                  "Additional Solana instructions to execute after the transfer is finalized"
-                ],
-                "type": {
-                  "vec": {
-                    "defined": {
-                      "name": "Ix"
-                    }
-                  }
-                }
-              }
-            ]
-          }
-        ]
-      }
+      "type": "u8",
+      "value": "13"
     },
     {
-      "name": "bridge::base_to_solana::state::incoming_message::Transfer",
+      "name": "MAX_FINALIZATION_THRESHOLDS",
+      "type": "u8",
+      "value": "8"
+    },
+    {
+      "name": "MAX_HANDLER_PAYLOAD_LEN",
       "docs": [
-        "Specifies the type of token being finalized on Solana for a Base→Solana bridge",
-        "and contains the necessary data to complete the transfer on the Solana side.",
-        "",
-        "Each variant corresponds to a different token type that can be bridged,",
-        "with variant-specific data needed to complete the transfer operation."
+        "Maximum payload, in bytes, of a `CallHandler` message, so that the handler instruction data (its",
+        "discriminator, then the sender, nonce and length-prefixed payload) fits the runtime CPI limit."
       ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Sol",
-            "fields": [
-              {
-                "defined": {
-                  "name": "FinalizeBridgeSol"
-                }
-              }
-            ]
-          },
-          {
-            "name": "Spl",
-            "fields": [
-              {
-                "defined": {
-                  "name": "FinalizeBridgeSpl"
-                }
-              }
-            ]
-          },
-          {
-            "name": "WrappedToken",
-            "fields": [
-              {
-                "defined": {
-                  "name": "FinalizeBridgeWrappedToken"
-                }
-              }
-            ]
-          }
-        ]
-      }
+      "type": "u16",
+      "value": "10200"
+    },
+    {
+      "name": "MAX_INSURANCE_SHARE_BPS",
+      "type": "u16",
+      "value": "5000"
+    },
+    {
+      "name": "MAX_IX_ACCOUNTS",
+      "docs": [
+        "Maximum number of accounts of a single incoming message instruction."
+      ],
+      "type": "u8",
+      "value": "64"
+    },
+    {
+      "name": "MAX_LAMPORT_ALLOWANCE",
+      "docs": [
+        "Maximum lamport allowance the guardian can let a single `WithLamportAllowance` message draw",
+        "from the operational vault (10 SOL)."
+      ],
+      "type": "u64",
+      "value": "10000000000"
     },
     {
-      "name": "bridge::solana_to_base::state::outgoing_message::Message",
+      "name": "MAX_MAINTENANCE_FEE_REIMBURSEMENT",
       "docs": [
-        "Represents the type of cross-chain operation to be executed on Base.",
-        "This enum encapsulates the two main types of operations supported by the bridge:",
-        "direct contract calls and token transfers with optional contract calls."
+        "Maximum fee reimbursement paid by the maintenance budget per output root registration."
       ],
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Call",
-            "fields": [
-              {
-                "defined": {
-                  "name": "Call"
-                }
-              }
-            ]
-          },
-          {
-            "name": "Transfer",
-            "fields": [
-              {
-                "defined": {
-                  "name": "bridge::solana_to_base::state::outgoing_message::Transfer"
-                }
-              }
-            ]
-          }
-        ]
-      }
+      "type": "u64",
+      "value": "1000000"
     },
     {
-      "name": "bridge::solana_to_base::state::outgoing_message::Transfer",
+      "name": "MAX_MESSAGE_IXS",
       "docs": [
-        "Represents a token transfer from Solana to Base with optional contract execution.",
-        "This struct contains all the information needed to bridge tokens between chains",
-        "and optionally execute additional logic on the destination chain after the transfer."
+        "Maximum number of instructions executed by a single incoming message."
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "to",
-            "docs": [
-              "The recipient address on Base that will receive the bridged tokens."
-            ],
-            "type": {
-              "array": [
-                "u8",
-                20
-              ]
-            }
-          },
-          {
-            "name": "local_token",
-            "docs": [
-              "The token mint address on Solana that is being bridged.",
-              "For SOL this is `NATIVE_SOL_PUBKEY`."
-            ],
-            "type": "pubkey"
-          },
-          {
-            "name": "remote_token",
-            "docs": [
-              "The corresponding token contract address on Base.",
-              "This is the token that will be minted or unlocked on the Base side."
-            ],
-            "type": {
-              "array": [
-                "u8",
-                20
-              ]
-            }
-          },
-          {
-            "name": "amount",
-            "docs": [
-              "The amount to transfer, in the token's smallest unit.",
-              "For SPL tokens and SOL, funds are locked on Solana; for wrapped tokens, they are burned.",
-              "On Base, the corresponding amount is minted or unlocked."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "call",
-            "docs": [
-              "Optional contract call to execute on Base after the token transfer completes.",
-              "Allows for complex cross-chain operations that combine token transfers with logic execution."
-            ],
-            "type": {
-              "option": {
-                "defined": {
-                  "name": "Call"
-                }
-              }
-            }
-          }
-        ]
-      }
-    }
-  ],
-  "constants": [
+      "type": "u8",
+      "value": "16"
+    },
     {
-      "name": "BRIDGE_CPI_AUTHORITY_SEED",
-      "type": "bytes",
-      "value": "[98, 114, 105, 100, 103, 101, 95, 99, 112, 105, 95, 97, 117, 116, 104, 111, 114, 105, 116, 121]"
+      "name": "MAX_MESSAGE_IXS_DATA_LEN",
+      "docs": [
+        "Maximum total instruction data, in bytes, of an incoming message: the runtime limit of a single CPI."
+      ],
+      "type": "u16",
+      "value": "10240"
     },
     {
-      "name": "BRIDGE_SEED",
-      "type": "bytes",
-      "value": "[98, 114, 105, 100, 103, 101]"
+      "name": "MAX_OUTBOX_PEAKS",
+      "docs": [
+        "Maximum number of mountains of the outbox MMR, bounding it to `2^MAX_OUTBOX_PEAKS - 1` messages."
+      ],
+      "type": "u8",
+      "value": "32"
     },
     {
-      "name": "INCOMING_MESSAGE_SEED",
-      "type": "bytes",
-      "value": "[105, 110, 99, 111, 109, 105, 110, 103, 95, 109, 101, 115, 115, 97, 103, 101]"
+      "name": "MAX_OUTFLOW_LIMITS",
+      "type": "u8",
+      "value": "8"
+    },
+    {
+      "name": "MAX_OUTPUT_ROOTS_PER_BATCH",
+      "docs": [
+        "Maximum number of output roots registered by a single `register_output_roots_batch` call."
+      ],
+      "type": "u8",
+      "value": "8"
     },
     {
       "name": "MAX_PARTNER_VALIDATOR_THRESHOLD",
       "type": "u8",
       "value": "5"
     },
+    {
+      "name": "MAX_PROTOCOL_FEE_BPS",
+      "type": "u16",
+      "value": "1000"
+    },
+    {
+      "name": "MAX_PROTOCOL_FEE_OVERRIDES",
+      "type": "u8",
+      "value": "16"
+    },
+    {
+      "name": "MAX_PROVEN_CHUNKS",
+      "docs": [
+        "Maximum number of `ProvenChunk` accounts a single chunked incoming message is split into."
+      ],
+      "type": "u8",
+      "value": "16"
+    },
+    {
+      "name": "MAX_RATE_LIMIT_EXEMPT_PAYERS",
+      "type": "u8",
+      "value": "16"
+    },
+    {
+      "name": "MAX_RELAY_DENY_LIST_PROGRAMS",
+      "docs": [
+        "Maximum number of programs the guardian can add to the built-in `DENIED_RELAY_PROGRAMS`."
+      ],
+      "type": "u8",
+      "value": "16"
+    },
+    {
+      "name": "MAX_SESSION_REMOTE_TOKENS",
+      "docs": [
+        "Maximum number of remote tokens a bridge session can be restricted to."
+      ],
+      "type": "u8",
+      "value": "8"
+    },
+    {
+      "name": "MAX_SIGNATURE_COUNT",
+      "docs": [
+        "Maximum number of oracle signatures accepted by an instruction: a full Base oracle signer set",
+        "plus as many partner signers."
+      ],
+      "type": "u8",
+      "value": "32"
+    },
     {
       "name": "MAX_SIGNER_COUNT",
       "type": "u8",
       "value": "16"
     },
+    {
+      "name": "MAX_TRANSFER_BATCH_RECIPIENTS",
+      "docs": [
+        "Maximum number of recipients of a transfer batch."
+      ],
+      "type": "u8",
+      "value": "16"
+    },
+    {
+      "name": "MAX_TRANSFER_MEMO_LEN",
+      "docs": [
+        "Maximum length of the optional payment memo attached to a transfer."
+      ],
+      "type": "u8",
+      "value": "64"
+    },
+    {
+      "name": "MAX_WRAPPED_TOKEN_NAME_LEN",
+      "docs": [
+        "Maximum length, in bytes, of a wrapped token name (the Metaplex metadata limit)."
+      ],
+      "type": "u8",
+      "value": "32"
+    },
+    {
+      "name": "MAX_WRAPPED_TOKEN_SYMBOL_LEN",
+      "docs": [
+        "Maximum length, in bytes, of a wrapped token symbol (the Metaplex metadata limit)."
+      ],
+      "type": "u8",
+      "value": "10"
+    },
+    {
+      "name": "METAPLEX_METADATA_SEED",
+      "type": "bytes",
+      "value": "[109, 101, 116, 97, 100, 97, 116, 97]"
+    },
+    {
+      "name": "METAPLEX_TOKEN_METADATA_PROGRAM_ID",
+      "type": "pubkey",
+      "value": "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s"
+    },
+    {
+      "name": "MINT_ALLOW_LIST_SEED",
+      "type": "bytes",
+      "value": "[109, 105, 110, 116, 95, 97, 108, 108, 111, 119, 95, 108, 105, 115, 116]"
+    },
+    {
+      "name": "MINT_POLICY_SEED",
+      "type": "bytes",
+      "value": "[109, 105, 110, 116, 95, 112, 111, 108, 105, 99, 121]"
+    },
     {
       "name": "NATIVE_SOL_PUBKEY",
       "type": "pubkey",
       "value": "SoL1111111111111111111111111111111111111111"
     },
+    {
+      "name": "OPERATIONAL_VAULT_SEED",
+      "type": "bytes",
+      "value": "[111, 112, 101, 114, 97, 116, 105, 111, 110, 97, 108, 95, 118, 97, 117, 108, 116]"
+    },
     {
       "name": "OUTGOING_MESSAGE_SEED",
       "type": "bytes",
       "value": "[111, 117, 116, 103, 111, 105, 110, 103, 95, 109, 101, 115, 115, 97, 103, 101]"
     },
+    {
+      "name": "OUTPUT_ROOT_CHALLENGE_WINDOW_SECONDS",
+      "docs": [
+        "Seconds after its registration during which an output root can be superseded by the oracles."
+      ],
+      "type": "i64",
+      "value": "3600"
+    },
     {
       "name": "OUTPUT_ROOT_SEED",
       "type": "bytes",
@@ -4121,13 +16646,69 @@ export const IDL = {
       "type": "bytes",
       "value": "[115, 105, 103, 110, 101, 114, 115]"
     },
+    {
+      "name": "PAYER_RATE_LIMIT_CONFIG_SEED",
+      "type": "bytes",
+      "value": "[112, 97, 121, 101, 114, 95, 114, 97, 116, 101, 95, 108, 105, 109, 105, 116, 95, 99, 111, 110, 102, 105, 103]"
+    },
+    {
+      "name": "PAYER_RATE_LIMIT_SEED",
+      "type": "bytes",
+      "value": "[112, 97, 121, 101, 114, 95, 114, 97, 116, 101, 95, 108, 105, 109, 105, 116]"
+    },
+    {
+      "name": "PROTOCOL_FEE_CONFIG_SEED",
+      "type": "bytes",
+      "value": "[112, 114, 111, 116, 111, 99, 111, 108, 95, 102, 101, 101, 95, 99, 111, 110, 102, 105, 103]"
+    },
+    {
+      "name": "PROVEN_CHUNK_SEED",
+      "type": "bytes",
+      "value": "[112, 114, 111, 118, 101, 110, 95, 99, 104, 117, 110, 107]"
+    },
+    {
+      "name": "RELAYED_NONCES_PER_ACCOUNT",
+      "type": "u64",
+      "value": "65536"
+    },
+    {
+      "name": "RELAYED_NONCES_SEED",
+      "type": "bytes",
+      "value": "[114, 101, 108, 97, 121, 101, 100, 95, 110, 111, 110, 99, 101, 115]"
+    },
+    {
+      "name": "RELAY_DENY_LIST_SEED",
+      "type": "bytes",
+      "value": "[114, 101, 108, 97, 121, 95, 100, 101, 110, 121, 95, 108, 105, 115, 116]"
+    },
+    {
+      "name": "RELAY_EXECUTION_SEED",
+      "type": "bytes",
+      "value": "[114, 101, 108, 97, 121, 95, 101, 120, 101, 99, 117, 116, 105, 111, 110]"
+    },
+    {
+      "name": "RELAY_FEE_VAULT_SEED",
+      "type": "bytes",
+      "value": "[114, 101, 108, 97, 121, 95, 102, 101, 101, 95, 118, 97, 117, 108, 116]"
+    },
     {
       "name": "REMOTE_TOKEN_METADATA_KEY",
+      "docs": [
+        "Key used in `additional_metadata` for the Base (EVM) token address bytes, hex-encoded."
+      ],
       "type": "string",
       "value": "\"remote_token\""
     },
+    {
+      "name": "RETURNED_TRANSFER_SEED",
+      "type": "bytes",
+      "value": "[114, 101, 116, 117, 114, 110, 101, 100, 95, 116, 114, 97, 110, 115, 102, 101, 114]"
+    },
     {
       "name": "SCALER_EXPONENT_METADATA_KEY",
+      "docs": [
+        "Key used in `additional_metadata` for the decimal scaling exponent."
+      ],
       "type": "string",
       "value": "\"scaler_exponent\""
     },
@@ -4136,15 +16717,72 @@ export const IDL = {
       "type": "bytes",
       "value": "[115, 111, 108, 95, 118, 97, 117, 108, 116]"
     },
+    {
+      "name": "SOL_VAULT_STATE_SEED",
+      "type": "bytes",
+      "value": "[115, 111, 108, 95, 118, 97, 117, 108, 116, 95, 115, 116, 97, 116, 101]"
+    },
+    {
+      "name": "SPL_MEMO_PROGRAM_ID",
+      "type": "pubkey",
+      "value": "MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr"
+    },
+    {
+      "name": "TOKEN_DEPOSITS_SEED",
+      "type": "bytes",
+      "value": "[116, 111, 107, 101, 110, 95, 100, 101, 112, 111, 115, 105, 116, 115]"
+    },
     {
       "name": "TOKEN_VAULT_SEED",
       "type": "bytes",
       "value": "[116, 111, 107, 101, 110, 95, 118, 97, 117, 108, 116]"
     },
+    {
+      "name": "TRANSFER_BATCH_GAS_PER_RECIPIENT",
+      "docs": [
+        "Gas charged per recipient of a transfer batch beyond the first, on top of `gas_per_call`: an",
+        "extra recipient only costs a token transfer on Base rather than the relay of a whole message."
+      ],
+      "type": "u64",
+      "value": "30000"
+    },
+    {
+      "name": "VAULT_DECIMALS_SEED",
+      "type": "bytes",
+      "value": "[118, 97, 117, 108, 116, 95, 100, 101, 99, 105, 109, 97, 108, 115]"
+    },
+    {
+      "name": "VERSION_MAJOR",
+      "docs": [
+        "Semantic version of the program, kept in sync with the crate version."
+      ],
+      "type": "u16",
+      "value": "0"
+    },
+    {
+      "name": "VERSION_MINOR",
+      "type": "u16",
+      "value": "3"
+    },
+    {
+      "name": "VERSION_PATCH",
+      "type": "u16",
+      "value": "0"
+    },
+    {
+      "name": "WRAPPED_TOKEN_CONFIRMATION_SEED",
+      "type": "bytes",
+      "value": "[119, 114, 97, 112, 112, 101, 100, 95, 116, 111, 107, 101, 110, 95, 99, 111, 110, 102, 105, 114, 109, 97, 116, 105, 111, 110]"
+    },
     {
       "name": "WRAPPED_TOKEN_SEED",
       "type": "bytes",
       "value": "[119, 114, 97, 112, 112, 101, 100, 95, 116, 111, 107, 101, 110]"
+    },
+    {
+      "name": "WRAPPED_TOKEN_SUPPLY_CAP_SEED",
+      "type": "bytes",
+      "value": "[119, 114, 97, 112, 112, 101, 100, 95, 116, 111, 107, 101, 110, 95, 115, 117, 112, 112, 108, 121, 95, 99, 97, 112]"
     }
   ]
 } as const;
\ No newline at end of file
@@ -0,0 +1,91 @@
+//! Transaction builders for the common flows of the `bridge` program.
+//!
+//! Services bridging between Solana and Base all need to derive the same PDAs, generate a fresh
+//! outgoing message salt and look up the configured gas fee receiver before sending anything.
+//! The builders of this crate do that work and return signed `Transaction`s ready to be sent
+//! (convert them with `VersionedTransaction::from` where a versioned transaction is expected).
+//!
+//! Onchain state is read through `AccountSource`, so the crate does not depend on a particular
+//! RPC client.
+//!
+//! The bridge program ID is selected with the same environment features as the program
+//! (`devnet-alpha`, `devnet-prod`, `mainnet`; localnet by default).
+
+use std::{collections::HashMap, fmt};
+
+use anchor_lang::prelude::*;
+use bridge::common::{Bridge, ProtocolFeeConfig};
+use solana_signer::SignerError;
+
+mod pda;
+mod transactions;
+
+pub use pda::*;
+pub use transactions::*;
+
+/// Errors returned by the transaction builders.
+#[derive(Debug)]
+pub enum ClientError {
+    /// A required bridge account does not exist.
+    AccountNotFound(Pubkey),
+    /// An account could not be deserialized as the expected bridge account.
+    InvalidAccountData(Pubkey),
+    /// The transaction could not be signed by the provided signers.
+    Signing(SignerError),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::AccountNotFound(address) => write!(f, "account {address} not found"),
+            ClientError::InvalidAccountData(address) => {
+                write!(f, "account {address} has invalid data")
+            }
+            ClientError::Signing(err) => write!(f, "failed to sign transaction: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<SignerError> for ClientError {
+    fn from(err: SignerError) -> Self {
+        ClientError::Signing(err)
+    }
+}
+
+/// Result type returned by the transaction builders.
+pub type ClientResult<T> = std::result::Result<T, ClientError>;
+
+/// Read access to onchain account data, implemented by services for their RPC client of choice.
+pub trait AccountSource {
+    /// Returns the data of the account at `address`, or `None` if it does not exist.
+    fn account_data(&self, address: &Pubkey) -> Option<Vec<u8>>;
+}
+
+impl AccountSource for HashMap<Pubkey, Vec<u8>> {
+    fn account_data(&self, address: &Pubkey) -> Option<Vec<u8>> {
+        self.get(address).cloned()
+    }
+}
+
+/// Fetches and deserializes the bridge state account.
+pub fn fetch_bridge(account_source: &impl AccountSource) -> ClientResult<Bridge> {
+    let address = bridge_pda();
+    let data = account_source
+        .account_data(&address)
+        .ok_or(ClientError::AccountNotFound(address))?;
+    Bridge::try_deserialize(&mut &data[..]).map_err(|_| ClientError::InvalidAccountData(address))
+}
+
+/// Returns the treasury credited with the protocol fee, or `fallback` while no protocol fee is
+/// configured (the account is then ignored by the program).
+pub fn fetch_protocol_fee_treasury(
+    account_source: &impl AccountSource,
+    fallback: Pubkey,
+) -> Pubkey {
+    account_source
+        .account_data(&protocol_fee_config_pda())
+        .and_then(|data| ProtocolFeeConfig::try_deserialize(&mut &data[..]).ok())
+        .map_or(fallback, |protocol_fee_config| protocol_fee_config.treasury)
+}
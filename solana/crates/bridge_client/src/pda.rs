@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use bridge::{
+    base_to_solana::constants::{INCOMING_MESSAGE_SEED, OUTPUT_ROOT_SEED, RELAY_DENY_LIST_SEED},
+    common::{
+        ADDRESS_BOOK_SEED, BRIDGE_SEED, DEX_ROUTER_LIST_SEED, GAS_FEE_ESCROW_SEED,
+        INSURANCE_FUND_SEED, PAYER_RATE_LIMIT_CONFIG_SEED, PROTOCOL_FEE_CONFIG_SEED,
+        SOL_VAULT_SEED, SOL_VAULT_STATE_SEED, TOKEN_DEPOSITS_SEED, WRAPPED_TOKEN_SEED,
+    },
+    solana_to_base::{OUTGOING_MESSAGE_SEED, PAYER_RATE_LIMIT_SEED},
+    ID,
+};
+
+pub fn bridge_pda() -> Pubkey {
+    Pubkey::find_program_address(&[BRIDGE_SEED], &ID).0
+}
+
+pub fn address_book_pda() -> Pubkey {
+    Pubkey::find_program_address(&[ADDRESS_BOOK_SEED], &ID).0
+}
+
+pub fn gas_fee_escrow_pda() -> Pubkey {
+    Pubkey::find_program_address(&[GAS_FEE_ESCROW_SEED], &ID).0
+}
+
+pub fn insurance_fund_pda() -> Pubkey {
+    Pubkey::find_program_address(&[INSURANCE_FUND_SEED], &ID).0
+}
+
+pub fn payer_rate_limit_config_pda() -> Pubkey {
+    Pubkey::find_program_address(&[PAYER_RATE_LIMIT_CONFIG_SEED], &ID).0
+}
+
+pub fn payer_rate_limit_pda(payer: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[PAYER_RATE_LIMIT_SEED, payer.as_ref()], &ID).0
+}
+
+pub fn protocol_fee_config_pda() -> Pubkey {
+    Pubkey::find_program_address(&[PROTOCOL_FEE_CONFIG_SEED], &ID).0
+}
+
+pub fn dex_router_list_pda() -> Pubkey {
+    Pubkey::find_program_address(&[DEX_ROUTER_LIST_SEED], &ID).0
+}
+
+pub fn sol_vault_pda() -> Pubkey {
+    Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0
+}
+
+pub fn sol_vault_state_pda() -> Pubkey {
+    Pubkey::find_program_address(&[SOL_VAULT_STATE_SEED], &ID).0
+}
+
+pub fn token_deposits_pda(vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[TOKEN_DEPOSITS_SEED, vault.as_ref()], &ID).0
+}
+
+pub fn wrapped_token_pda(decimals: u8, metadata_hash: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            WRAPPED_TOKEN_SEED,
+            decimals.to_le_bytes().as_ref(),
+            metadata_hash.as_ref(),
+        ],
+        &ID,
+    )
+    .0
+}
+
+pub fn outgoing_message_pda(outgoing_message_salt: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(
+        &[OUTGOING_MESSAGE_SEED, outgoing_message_salt.as_ref()],
+        &ID,
+    )
+    .0
+}
+
+pub fn output_root_pda(base_block_number: u64) -> Pubkey {
+    Pubkey::find_program_address(&[OUTPUT_ROOT_SEED, &base_block_number.to_le_bytes()], &ID).0
+}
+
+pub fn incoming_message_pda(message_hash: &[u8; 32]) -> Pubkey {
+    Pubkey::find_program_address(&[INCOMING_MESSAGE_SEED, message_hash.as_ref()], &ID).0
+}
+
+pub fn relay_deny_list_pda() -> Pubkey {
+    Pubkey::find_program_address(&[RELAY_DENY_LIST_SEED], &ID).0
+}
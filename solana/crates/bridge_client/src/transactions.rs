@@ -0,0 +1,286 @@
+use anchor_lang::{
+    prelude::*,
+    solana_program::{hash::Hash, instruction::Instruction, keccak, system_program},
+    InstructionData,
+};
+use bridge::{accounts, common::PartialTokenMetadata, instruction, ID};
+use solana_keypair::Keypair;
+use solana_message::Message;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+use crate::{
+    address_book_pda, bridge_pda, dex_router_list_pda, fetch_bridge, fetch_protocol_fee_treasury,
+    gas_fee_escrow_pda, incoming_message_pda, insurance_fund_pda, outgoing_message_pda,
+    output_root_pda, payer_rate_limit_config_pda, payer_rate_limit_pda, protocol_fee_config_pda,
+    relay_deny_list_pda, sol_vault_pda, sol_vault_state_pda, token_deposits_pda, wrapped_token_pda,
+    AccountSource, ClientResult,
+};
+
+/// A Solana → Base transaction together with the outgoing message it creates, which identifies
+/// the transfer when tracking its relay to Base.
+pub struct OutgoingMessageTransaction {
+    pub transaction: Transaction,
+    pub outgoing_message: Pubkey,
+}
+
+/// Base message proven and relayed on Solana. `nonce`, `sender` and `data` are the fields of
+/// the message as registered on Base; `proof` is its MMR proof against the output root of
+/// `base_block_number`.
+pub struct IncomingMessageArgs {
+    pub base_block_number: u64,
+    pub nonce: u64,
+    pub sender: [u8; 20],
+    pub data: Vec<u8>,
+    pub proof: Vec<[u8; 32]>,
+}
+
+/// Returns a fresh random salt for the outgoing message PDA of a Solana → Base operation.
+pub fn new_outgoing_message_salt() -> [u8; 32] {
+    Keypair::new().pubkey().to_bytes()
+}
+
+/// Computes the hash of a Base message as keccak256(nonce || sender || data), with `nonce`
+/// encoded as big-endian bytes, matching `prove_message`.
+pub fn hash_incoming_message(nonce: u64, sender: &[u8; 20], data: &[u8]) -> [u8; 32] {
+    keccak::hashv(&[&nonce.to_be_bytes(), sender, data]).0
+}
+
+/// Builds a `bridge_sol` transaction locking `amount` lamports of `from` to be minted to `to` on
+/// Base. The gas fee receiver and protocol fee treasury are read from the bridge configuration.
+pub fn build_bridge_sol_tx(
+    account_source: &impl AccountSource,
+    payer: &dyn Signer,
+    from: &dyn Signer,
+    to: [u8; 20],
+    amount: u64,
+    recent_blockhash: Hash,
+) -> ClientResult<OutgoingMessageTransaction> {
+    let bridge = fetch_bridge(account_source)?;
+
+    let outgoing_message_salt = new_outgoing_message_salt();
+    let outgoing_message = outgoing_message_pda(&outgoing_message_salt);
+    let sol_vault = sol_vault_pda();
+
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::BridgeSol {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: bridge.gas_config.gas_fee_receiver,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: fetch_protocol_fee_treasury(account_source, payer.pubkey()),
+            dex_router_list: dex_router_list_pda(),
+            sol_vault_state: sol_vault_state_pda(),
+            sol_vault,
+            token_deposits: token_deposits_pda(&sol_vault),
+            bridge: bridge_pda(),
+            outgoing_message,
+            system_program: system_program::ID,
+            memo_program: None,
+        }
+        .to_account_metas(None),
+        data: instruction::BridgeSol {
+            outgoing_message_salt,
+            to,
+            amount,
+            call: None,
+            memo: None,
+            swap_guard: None,
+            spl_memo: false,
+        }
+        .data(),
+    };
+
+    let transaction = Transaction::try_new(
+        &[payer, from],
+        Message::new(&[ix], Some(&payer.pubkey())),
+        recent_blockhash,
+    )?;
+
+    Ok(OutgoingMessageTransaction {
+        transaction,
+        outgoing_message,
+    })
+}
+
+/// Builds a `wrap_token` transaction creating the wrapped mint of the Base token described by
+/// `metadata` and registering it on Base. The mint address is derived by the program from
+/// `decimals` and `metadata`, see `wrapped_token_pda`.
+pub fn build_wrap_token_tx(
+    account_source: &impl AccountSource,
+    payer: &dyn Signer,
+    decimals: u8,
+    metadata: PartialTokenMetadata,
+    recent_blockhash: Hash,
+) -> ClientResult<OutgoingMessageTransaction> {
+    let bridge = fetch_bridge(account_source)?;
+
+    let outgoing_message_salt = new_outgoing_message_salt();
+    let outgoing_message = outgoing_message_pda(&outgoing_message_salt);
+
+    let ix = Instruction {
+        program_id: ID,
+        accounts: accounts::WrapToken {
+            payer: payer.pubkey(),
+            gas_fee_receiver: bridge.gas_config.gas_fee_receiver,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            mint: wrapped_token_pda(decimals, &metadata.hash()),
+            bridge: bridge_pda(),
+            address_book: address_book_pda(),
+            outgoing_message,
+            token_program: anchor_spl::token_2022::ID,
+            system_program: system_program::ID,
+            metaplex_metadata: None,
+            metaplex_program: None,
+        }
+        .to_account_metas(None),
+        data: instruction::WrapToken {
+            outgoing_message_salt,
+            decimals,
+            partial_token_metadata: metadata,
+        }
+        .data(),
+    };
+
+    let transaction = Transaction::try_new(
+        &[payer],
+        Message::new(&[ix], Some(&payer.pubkey())),
+        recent_blockhash,
+    )?;
+
+    Ok(OutgoingMessageTransaction {
+        transaction,
+        outgoing_message,
+    })
+}
+
+/// Builds the `prove_message` and `relay_message` transactions of a Base message, to be sent in
+/// that order. `relay_accounts` are the remaining accounts of `relay_message`: the accounts of the
+/// transfer (if any) followed by the accounts of every instruction of the message. Messages
+/// paying a relay fee are relayed without claiming it.
+pub fn build_prove_and_relay_txs(
+    payer: &dyn Signer,
+    message: IncomingMessageArgs,
+    relay_accounts: Vec<AccountMeta>,
+    recent_blockhash: Hash,
+) -> ClientResult<(Transaction, Transaction)> {
+    let message_hash = hash_incoming_message(message.nonce, &message.sender, &message.data);
+    let incoming_message = incoming_message_pda(&message_hash);
+    let output_root = output_root_pda(message.base_block_number);
+
+    let prove_ix = Instruction {
+        program_id: ID,
+        accounts: accounts::ProveMessage {
+            payer: payer.pubkey(),
+            output_root,
+            message: incoming_message,
+            bridge: bridge_pda(),
+            address_book: Some(address_book_pda()),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::ProveMessage {
+            nonce: message.nonce,
+            sender: message.sender,
+            data: message.data,
+            proof: message.proof,
+            message_hash,
+        }
+        .data(),
+    };
+
+    let mut relay_ix = Instruction {
+        program_id: ID,
+        accounts: accounts::RelayMessage {
+            message: incoming_message,
+            output_root,
+            prover: payer.pubkey(),
+            bridge: bridge_pda(),
+            relay_deny_list: relay_deny_list_pda(),
+            relay_fee_vault: None,
+            relayer: None,
+        }
+        .to_account_metas(None),
+        data: instruction::RelayMessage {}.data(),
+    };
+    relay_ix.accounts.extend(relay_accounts);
+
+    let prove_tx = Transaction::try_new(
+        &[payer],
+        Message::new(&[prove_ix], Some(&payer.pubkey())),
+        recent_blockhash,
+    )?;
+    let relay_tx = Transaction::try_new(
+        &[payer],
+        Message::new(&[relay_ix], Some(&payer.pubkey())),
+        recent_blockhash,
+    )?;
+
+    Ok((prove_tx, relay_tx))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::ClientError;
+
+    #[test]
+    fn test_build_bridge_sol_tx_without_bridge_fails() {
+        let payer = Keypair::new();
+        let result = build_bridge_sol_tx(
+            &HashMap::new(),
+            &payer,
+            &payer,
+            [1u8; 20],
+            1_000_000,
+            Hash::default(),
+        );
+
+        assert!(
+            matches!(result, Err(ClientError::AccountNotFound(address)) if address == bridge_pda())
+        );
+    }
+
+    #[test]
+    fn test_build_prove_and_relay_txs_targets_incoming_message() {
+        let payer = Keypair::new();
+        let relay_account = AccountMeta::new(Pubkey::new_unique(), false);
+        let message = IncomingMessageArgs {
+            base_block_number: 42,
+            nonce: 7,
+            sender: [2u8; 20],
+            data: vec![1, 2, 3],
+            proof: vec![],
+        };
+
+        let (prove_tx, relay_tx) = build_prove_and_relay_txs(
+            &payer,
+            message,
+            vec![relay_account.clone()],
+            Hash::default(),
+        )
+        .unwrap();
+
+        let incoming_message =
+            incoming_message_pda(&hash_incoming_message(7, &[2u8; 20], &[1, 2, 3]));
+        let prove_keys = &prove_tx.message.account_keys;
+        assert!(prove_keys.contains(&output_root_pda(42)));
+        assert!(prove_keys.contains(&incoming_message));
+
+        let relay_keys = &relay_tx.message.account_keys;
+        assert!(relay_keys.contains(&incoming_message));
+        assert!(relay_keys.contains(&output_root_pda(42)));
+        assert!(relay_keys.contains(&relay_account.pubkey));
+        assert!(relay_tx.is_signed());
+    }
+}
@@ -0,0 +1,397 @@
+//! EIP-1559-inspired base fee controller shared by the `bridge` and `base_relayer` programs.
+//!
+//! Both programs price cross-chain messages with a base fee that tracks gas usage per time
+//! window. `Eip1559` holds that controller state with its `Eip1559Config` embedded, so both
+//! programs store the exact same account layout and run the exact same fee computation.
+//!
+//! The controller only relies on `core` integer arithmetic; `anchor-lang` is used solely for the
+//! account (de)serialization derives.
+
+use anchor_lang::prelude::*;
+
+pub mod math;
+
+use math::{fixed_pow, SCALE};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct Eip1559 {
+    /// Configuration parameters for EIP-1559-inspired fee calculations
+    pub config: Eip1559Config,
+    /// Current base fee used in fee computation (runtime state).
+    /// Unitless value combined with the gas usage and gas cost scaler to produce lamports.
+    pub current_base_fee: u64,
+    /// Gas used in the current time window (runtime state)
+    pub current_window_gas_used: u64,
+    /// Unix timestamp when the current window started (runtime state)
+    pub window_start_time: i64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct Eip1559Config {
+    /// Gas target per window
+    pub target: u64,
+    /// Adjustment denominator (controls rate of change)
+    pub denominator: u64,
+    /// Window duration in seconds
+    pub window_duration_seconds: u64,
+    /// Minimum base fee. Used to seed `current_base_fee` at initialization
+    /// and as an underflow clamp during decreases; not enforced as a strict lower bound
+    /// on every step.
+    pub minimum_base_fee: u64,
+    /// Maximum base fee. The base fee never grows above this value (0 disables the cap).
+    pub max_base_fee: u64,
+    /// Maximum base fee increase per window, in basis points of the current base fee
+    /// (0 disables the cap).
+    pub max_window_increase_bps: u64,
+}
+
+/// Reasons an `Eip1559Config` is rejected by `Eip1559Config::validate`.
+/// Each program maps them to its own error type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Eip1559ConfigError {
+    InvalidGasTarget,
+    InvalidDenominator,
+    InvalidWindowDuration,
+    InvalidMaxBaseFee,
+}
+
+impl Eip1559Config {
+    /// Checks the invariants the base fee computation relies on: the target, the denominator and
+    /// the window duration are divisors, and an enabled `max_base_fee` cannot be below the floor.
+    pub fn validate(&self) -> core::result::Result<(), Eip1559ConfigError> {
+        if self.target == 0 {
+            return Err(Eip1559ConfigError::InvalidGasTarget);
+        }
+        if self.denominator == 0 {
+            return Err(Eip1559ConfigError::InvalidDenominator);
+        }
+        if self.window_duration_seconds == 0 {
+            return Err(Eip1559ConfigError::InvalidWindowDuration);
+        }
+        if self.max_base_fee != 0 && self.max_base_fee < self.minimum_base_fee {
+            return Err(Eip1559ConfigError::InvalidMaxBaseFee);
+        }
+        Ok(())
+    }
+
+    #[cfg(any(test, feature = "test-utils"))]
+    pub fn test_new() -> Self {
+        Self {
+            target: 5_000_000,
+            denominator: 2,
+            window_duration_seconds: 1,
+            minimum_base_fee: 1,
+            max_base_fee: 0,
+            max_window_increase_bps: 0,
+        }
+    }
+}
+
+impl Eip1559 {
+    /// Refresh the base fee if window has expired, reset window tracking
+    /// Handles multiple expired windows by processing each empty window
+    pub fn refresh_base_fee(&mut self, current_timestamp: i64) -> u64 {
+        self.refresh_base_fee_with(current_timestamp, |_, _| {})
+    }
+
+    /// Same as `refresh_base_fee`, calling `on_capped(uncapped_base_fee, base_fee)` whenever
+    /// `max_window_increase_bps` or `max_base_fee` binds, e.g. to emit an event.
+    pub fn refresh_base_fee_with(
+        &mut self,
+        current_timestamp: i64,
+        on_capped: impl FnOnce(u64, u64),
+    ) -> u64 {
+        let expired_windows_count = self.expired_windows_count(current_timestamp);
+        if expired_windows_count == 0 {
+            return self.current_base_fee;
+        }
+
+        // Process the first window with actual gas usage
+        let uncapped_base_fee = self.calc_base_fee(self.current_window_gas_used);
+        let mut current_base_fee = self.cap_base_fee(uncapped_base_fee);
+        if current_base_fee != uncapped_base_fee {
+            on_capped(uncapped_base_fee, current_base_fee);
+        }
+        let remaining_windows_count = expired_windows_count - 1;
+
+        // Process the remaining empty windows (if any)
+        //
+        // This corresponds to applying this formula (because gas_used is 0):
+        //      base_fee_n+1 = base_fee_n - (base_fee_n / denom)
+        //                   = base_fee_n * (1 - 1 / denom)
+        //                   = base_fee_n * (denom - 1) / denom
+        // Thus:
+        //      base_fee_n = base_fee_0 * [(denom - 1) / denom]^n
+        if remaining_windows_count > 0 {
+            // Scale up as we're going to do some arithmetic
+            let scaled_denominator = self.config.denominator as u128 * SCALE;
+
+            // [(denom - 1) / denom]
+            // Guaranteed to be < SCALE.
+            // NOTE: scaled_denominator is in SCALE units while self.denominator is not
+            //       so the returned ratio is also in SCALE units
+            let ratio = (scaled_denominator - SCALE) / (self.config.denominator as u128);
+
+            // [(denom - 1) / denom]^(n-1)
+            // Guaranteed to be < SCALE because ratio < SCALE.
+            let factor = fixed_pow(ratio, remaining_windows_count);
+
+            // base_fee_0 * [(denom - 1) / denom]^n
+            // NOTE: multiply first in u128 and divide to scale back and fit into u64 while
+            //       preserving the best precision
+            current_base_fee = ((current_base_fee as u128 * factor) / SCALE) as u64;
+        }
+
+        // Update state for new window
+        self.current_base_fee = current_base_fee.max(self.config.minimum_base_fee);
+        self.current_window_gas_used = 0;
+        self.window_start_time +=
+            (expired_windows_count * self.config.window_duration_seconds) as i64;
+
+        self.current_base_fee
+    }
+
+    /// Add gas usage to current window
+    pub fn add_gas_usage(&mut self, gas_amount: u64) {
+        self.current_window_gas_used += gas_amount;
+    }
+
+    /// Calculate the uncapped base fee for the next window based on current window gas usage.
+    fn calc_base_fee(&self, gas_used: u64) -> u64 {
+        if gas_used == self.config.target {
+            self.current_base_fee
+        } else if gas_used > self.config.target {
+            // If the current window used more gas than target, the base fee should increase.
+            // max(1, baseFee * gasUsedDelta / target / denominator)
+            let gas_used_delta = gas_used - self.config.target;
+            let base_fee_delta = (gas_used_delta * self.current_base_fee)
+                / self.config.target
+                / self.config.denominator;
+
+            // Ensure minimum increase of 1
+            let base_fee_delta = base_fee_delta.max(1);
+            self.current_base_fee + base_fee_delta
+        } else {
+            // If the current window used less gas than target, the base fee should decrease.
+            // max(0, baseFee - (baseFee * gasUsedDelta / target / denominator))
+            let gas_used_delta = self.config.target - gas_used;
+            let base_fee_delta = (gas_used_delta * self.current_base_fee)
+                / self.config.target
+                / self.config.denominator;
+
+            self.current_base_fee.saturating_sub(base_fee_delta)
+        }
+    }
+
+    /// Apply the per-window increase cap and the absolute `max_base_fee` cap
+    fn cap_base_fee(&self, base_fee: u64) -> u64 {
+        let mut base_fee = base_fee;
+
+        if self.config.max_window_increase_bps > 0 && base_fee > self.current_base_fee {
+            let max_increase = (self.current_base_fee as u128
+                * self.config.max_window_increase_bps as u128
+                / 10_000)
+                .max(1) as u64;
+            base_fee = base_fee.min(self.current_base_fee.saturating_add(max_increase));
+        }
+
+        if self.config.max_base_fee > 0 {
+            base_fee = base_fee.min(self.config.max_base_fee);
+        }
+
+        base_fee
+    }
+
+    /// Check if the current window has expired based on current timestamp
+    fn expired_windows_count(&self, current_timestamp: i64) -> u64 {
+        (current_timestamp as u64 - self.window_start_time as u64)
+            / self.config.window_duration_seconds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_eip() -> Eip1559 {
+        Eip1559 {
+            config: Eip1559Config::test_new(),
+            current_base_fee: 1000,
+            current_window_gas_used: 0,
+            window_start_time: 0,
+        }
+    }
+
+    #[test]
+    fn validate_accepts_test_config() {
+        assert_eq!(Eip1559Config::test_new().validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_rejects_invalid_fields() {
+        let cases = [
+            (
+                Eip1559Config {
+                    target: 0,
+                    ..Eip1559Config::test_new()
+                },
+                Eip1559ConfigError::InvalidGasTarget,
+            ),
+            (
+                Eip1559Config {
+                    denominator: 0,
+                    ..Eip1559Config::test_new()
+                },
+                Eip1559ConfigError::InvalidDenominator,
+            ),
+            (
+                Eip1559Config {
+                    window_duration_seconds: 0,
+                    ..Eip1559Config::test_new()
+                },
+                Eip1559ConfigError::InvalidWindowDuration,
+            ),
+            (
+                Eip1559Config {
+                    minimum_base_fee: 10,
+                    max_base_fee: 5,
+                    ..Eip1559Config::test_new()
+                },
+                Eip1559ConfigError::InvalidMaxBaseFee,
+            ),
+        ];
+
+        for (config, expected) in cases {
+            assert_eq!(config.validate(), Err(expected));
+        }
+    }
+
+    #[test]
+    fn calc_base_fee_unchanged_when_gas_equals_target() {
+        let eip = new_eip();
+
+        assert_eq!(eip.calc_base_fee(eip.config.target), eip.current_base_fee);
+    }
+
+    #[test]
+    fn calc_base_fee_increases_above_target() {
+        let eip = new_eip();
+
+        // (3_000_000 * 1000) / 5_000_000 / 2 = 300
+        assert_eq!(eip.calc_base_fee(eip.config.target + 3_000_000), 1300);
+    }
+
+    #[test]
+    fn calc_base_fee_decreases_below_target() {
+        let eip = new_eip();
+
+        assert_eq!(eip.calc_base_fee(eip.config.target - 3_000_000), 700);
+    }
+
+    #[test]
+    fn calc_base_fee_increase_has_min_step_one() {
+        let mut eip = new_eip();
+        eip.current_base_fee = 1;
+
+        assert_eq!(eip.calc_base_fee(eip.config.target + 1), 2);
+    }
+
+    #[test]
+    fn cap_base_fee_bounds_window_increase() {
+        let mut eip = new_eip();
+        eip.config.max_window_increase_bps = 1_000; // 10%
+
+        assert_eq!(eip.cap_base_fee(1300), 1100);
+        assert_eq!(eip.cap_base_fee(700), 700);
+    }
+
+    #[test]
+    fn cap_base_fee_bounds_by_max_base_fee() {
+        let mut eip = new_eip();
+        eip.config.max_base_fee = 1200;
+
+        assert_eq!(eip.cap_base_fee(1300), 1200);
+    }
+
+    #[test]
+    fn expired_windows_count_counts_whole_windows() {
+        let mut eip = new_eip();
+        eip.window_start_time = 1000;
+
+        assert_eq!(eip.expired_windows_count(1000), 0);
+        assert_eq!(eip.expired_windows_count(1001), 1);
+        assert_eq!(eip.expired_windows_count(1010), 10);
+    }
+
+    #[test]
+    fn add_gas_usage_accumulates() {
+        let mut eip = new_eip();
+        eip.add_gas_usage(1000);
+        eip.add_gas_usage(500);
+
+        assert_eq!(eip.current_window_gas_used, 1500);
+    }
+
+    #[test]
+    fn refresh_base_fee_no_expiry_keeps_state() {
+        let mut eip = new_eip();
+        eip.add_gas_usage(2_000_000);
+
+        assert_eq!(eip.refresh_base_fee(0), 1000);
+        assert_eq!(eip.current_window_gas_used, 2_000_000);
+        assert_eq!(eip.window_start_time, 0);
+    }
+
+    #[test]
+    fn refresh_base_fee_single_window_starts_new_window() {
+        let mut eip = new_eip();
+        eip.add_gas_usage(8_000_000);
+
+        assert_eq!(eip.refresh_base_fee(1), 1300);
+        assert_eq!(eip.current_window_gas_used, 0);
+        assert_eq!(eip.window_start_time, 1);
+    }
+
+    #[test]
+    fn refresh_base_fee_multiple_windows_apply_decay_factor() {
+        let mut eip = new_eip();
+        eip.current_base_fee = 100;
+        eip.add_gas_usage(eip.config.target); // first window keeps base fee
+
+        assert_eq!(eip.refresh_base_fee(3), 25);
+        assert_eq!(eip.window_start_time, 3);
+    }
+
+    #[test]
+    fn refresh_base_fee_bounded_by_max_base_fee() {
+        let mut eip = new_eip();
+        eip.config.max_base_fee = 2000;
+
+        // A long sequence of full windows cannot push the base fee above the cap
+        for window in 1..=50 {
+            eip.add_gas_usage(eip.config.target * 2);
+            eip.refresh_base_fee(window);
+            assert!(eip.current_base_fee <= 2000);
+        }
+        assert_eq!(eip.current_base_fee, 2000);
+    }
+
+    #[test]
+    fn refresh_base_fee_with_reports_binding_cap() {
+        let mut eip = new_eip();
+        eip.config.max_window_increase_bps = 1_000; // 10%
+        eip.add_gas_usage(8_000_000);
+
+        let mut capped = None;
+        let base_fee =
+            eip.refresh_base_fee_with(1, |uncapped, base_fee| capped = Some((uncapped, base_fee)));
+
+        assert_eq!(base_fee, 1100);
+        assert_eq!(capped, Some((1300, 1100)));
+
+        // No callback when no cap binds
+        let mut called = false;
+        eip.refresh_base_fee_with(2, |_, _| called = true);
+        assert!(!called);
+    }
+}
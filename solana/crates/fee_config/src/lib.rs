@@ -0,0 +1,121 @@
+//! Pricing configuration shared by the `bridge` and `base_relayer` programs.
+//!
+//! Both programs price cross-chain messages with an EIP-1559-inspired base fee converted into
+//! lamports by a gas cost scaler. `FeeConfigV2` groups every operator-facing knob of that
+//! pipeline so that both programs are configured from the same typed value.
+
+use anchor_lang::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct FeeConfigV2 {
+    /// Gas target per window
+    pub target: u64,
+    /// Adjustment denominator (controls rate of change)
+    pub denominator: u64,
+    /// Window duration in seconds
+    pub window_duration_seconds: u64,
+    /// Minimum base fee
+    pub minimum_base_fee: u64,
+    /// Maximum base fee (0 disables the cap)
+    pub max_base_fee: u64,
+    /// Maximum base fee increase per window, in basis points of the current base fee
+    /// (0 disables the cap)
+    pub max_window_increase_bps: u64,
+    /// Scaling factor applied when converting (gas * base_fee) into lamports
+    pub gas_cost_scaler: u64,
+    /// Decimal precision for the gas cost scaler (denominator)
+    pub gas_cost_scaler_dp: u64,
+    /// Account that receives gas fees
+    pub gas_fee_receiver: Pubkey,
+}
+
+/// Reasons a `FeeConfigV2` is rejected by `FeeConfigV2::validate`.
+/// Each program maps them to its own error type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeeConfigError {
+    InvalidDenominator,
+    InvalidWindowDurationSeconds,
+    InvalidMaxBaseFee,
+    InvalidGasCostScalerDp,
+}
+
+impl FeeConfigV2 {
+    pub fn validate(&self) -> std::result::Result<(), FeeConfigError> {
+        if self.denominator == 0 {
+            return Err(FeeConfigError::InvalidDenominator);
+        }
+        if self.window_duration_seconds == 0 {
+            return Err(FeeConfigError::InvalidWindowDurationSeconds);
+        }
+        if self.max_base_fee != 0 && self.max_base_fee < self.minimum_base_fee {
+            return Err(FeeConfigError::InvalidMaxBaseFee);
+        }
+        if self.gas_cost_scaler_dp == 0 {
+            return Err(FeeConfigError::InvalidGasCostScalerDp);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fee_config() -> FeeConfigV2 {
+        FeeConfigV2 {
+            target: 5_000_000,
+            denominator: 2,
+            window_duration_seconds: 1,
+            minimum_base_fee: 1,
+            max_base_fee: 0,
+            max_window_increase_bps: 0,
+            gas_cost_scaler: 1_000_000,
+            gas_cost_scaler_dp: 10u64.pow(6),
+            gas_fee_receiver: Pubkey::new_unique(),
+        }
+    }
+
+    #[test]
+    fn validate_accepts_valid_config() {
+        assert_eq!(fee_config().validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_rejects_invalid_fields() {
+        let cases = [
+            (
+                FeeConfigV2 {
+                    denominator: 0,
+                    ..fee_config()
+                },
+                FeeConfigError::InvalidDenominator,
+            ),
+            (
+                FeeConfigV2 {
+                    window_duration_seconds: 0,
+                    ..fee_config()
+                },
+                FeeConfigError::InvalidWindowDurationSeconds,
+            ),
+            (
+                FeeConfigV2 {
+                    minimum_base_fee: 10,
+                    max_base_fee: 5,
+                    ..fee_config()
+                },
+                FeeConfigError::InvalidMaxBaseFee,
+            ),
+            (
+                FeeConfigV2 {
+                    gas_cost_scaler_dp: 0,
+                    ..fee_config()
+                },
+                FeeConfigError::InvalidGasCostScalerDp,
+            ),
+        ];
+
+        for (config, expected) in cases {
+            assert_eq!(config.validate(), Err(expected));
+        }
+    }
+}
@@ -2,11 +2,42 @@ use anchor_lang::prelude::*;
 
 pub const DISCRIMINATOR_LEN: usize = 8;
 
-#[constant]
-pub const SCALE: u128 = 1_000_000;
-
 #[constant]
 pub const CFG_SEED: &[u8] = b"config";
 
 #[constant]
 pub const MTR_SEED: &[u8] = b"mtr";
+
+#[constant]
+pub const SPONSOR_POLICY_SEED: &[u8] = b"sponsor_policy";
+
+/// Duration of the window over which a sponsor policy spend limit applies (one day).
+#[constant]
+pub const SPONSOR_POLICY_WINDOW_SECONDS: i64 = 86_400;
+
+/// Delay after which the tip of a relay request not confirmed as relayed by the bridge can be
+/// refunded to its payer (one week).
+#[constant]
+pub const RELAY_TIP_REFUND_DELAY_SECONDS: i64 = 604_800;
+
+#[constant]
+pub const MAX_SPONSORED_SENDERS: u8 = 16;
+
+#[constant]
+pub const FEE_EXEMPTIONS_SEED: &[u8] = b"fee_exemptions";
+
+#[constant]
+pub const MAX_FEE_EXEMPT_SENDERS: u8 = 16;
+
+#[constant]
+pub const NONCE_MONITOR_SEED: &[u8] = b"nonce_monitor";
+
+#[constant]
+pub const PENDING_RELAY_INDEX_SEED: &[u8] = b"pending_relay_index";
+
+#[constant]
+pub const CALLDATA_PRICING_SEED: &[u8] = b"calldata_pricing";
+
+/// Maximum number of pending relay requests tracked by the pending relay index.
+#[constant]
+pub const MAX_PENDING_RELAYS: u8 = 64;
@@ -0,0 +1,128 @@
+//! Environment specific constants, selected at compile time through the `devnet-alpha`,
+//! `devnet-prod` and `mainnet` cargo features. Building without any of them targets a local
+//! test validator.
+
+use anchor_lang::prelude::*;
+
+#[cfg(any(
+    all(feature = "devnet-alpha", feature = "devnet-prod"),
+    all(feature = "devnet-alpha", feature = "mainnet"),
+    all(feature = "devnet-prod", feature = "mainnet"),
+))]
+compile_error!(
+    "At most one of the `devnet-alpha`, `devnet-prod` and `mainnet` features can be enabled"
+);
+
+/// The environment the program was compiled for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum Environment {
+    Localnet,
+    DevnetAlpha,
+    DevnetProd,
+    Mainnet,
+}
+
+impl Environment {
+    /// Returns the address the relayer program is deployed at in this environment, matching the
+    /// program keypair of the environment. The `declare_id!` of the compiled environment is
+    /// checked against it by the tests, so that a mismatched build fails before it is deployed.
+    pub const fn program_id(self) -> Pubkey {
+        match self {
+            Environment::Localnet => pubkey!("HPLodLSVpcUX73cXxT7NNss1frnr2XWf6yK3KPChRTjJ"),
+            Environment::DevnetAlpha => pubkey!("ETsFnoWdJK8N7VJW6XXjiciyB2xeQfCXMQWNa85Zi9cn"),
+            Environment::DevnetProd => pubkey!("56MBBEYAtQAdjT4e1NzHD8XaoyRSTvfgbSVVcEcHj51H"),
+            Environment::Mainnet => pubkey!("g1et5VenhfJHJwsdJsDbxWZuotD5H4iELNG61kS4fb9"),
+        }
+    }
+
+    /// Returns the address of the bridge program of this environment, whose `OutgoingMessage`
+    /// accounts are relayed.
+    pub const fn bridge_program_id(self) -> Pubkey {
+        match self {
+            Environment::Localnet => pubkey!("GaxAZQ3BSYjfG65e8mGnBnNpmhqRHDJ33aKEASHh3A3P"),
+            Environment::DevnetAlpha => pubkey!("6YpL1h2a9u6LuNVi55vAes36xNszt2UDm3Zk1kj4WSBm"),
+            Environment::DevnetProd => pubkey!("7c6mteAcTXaQ1MFBCrnuzoZVTTAEfZwa6wgy4bqX3KXC"),
+            Environment::Mainnet => pubkey!("HNCne2FkVaNghhjKXapxJzPaBvAKDG1Ge3gqhZyfVWLM"),
+        }
+    }
+}
+
+#[cfg(feature = "devnet-alpha")]
+mod selected {
+    use super::*;
+
+    pub const ENVIRONMENT: Environment = Environment::DevnetAlpha;
+    declare_id!("ETsFnoWdJK8N7VJW6XXjiciyB2xeQfCXMQWNa85Zi9cn");
+
+    /// The bridge program owning the `OutgoingMessage` accounts relayed to Base.
+    pub const BRIDGE_PROGRAM_ID: Pubkey = pubkey!("6YpL1h2a9u6LuNVi55vAes36xNszt2UDm3Zk1kj4WSBm");
+}
+
+#[cfg(feature = "devnet-prod")]
+mod selected {
+    use super::*;
+
+    pub const ENVIRONMENT: Environment = Environment::DevnetProd;
+    declare_id!("56MBBEYAtQAdjT4e1NzHD8XaoyRSTvfgbSVVcEcHj51H");
+
+    /// The bridge program owning the `OutgoingMessage` accounts relayed to Base.
+    pub const BRIDGE_PROGRAM_ID: Pubkey = pubkey!("7c6mteAcTXaQ1MFBCrnuzoZVTTAEfZwa6wgy4bqX3KXC");
+}
+
+#[cfg(feature = "mainnet")]
+mod selected {
+    use super::*;
+
+    pub const ENVIRONMENT: Environment = Environment::Mainnet;
+    declare_id!("g1et5VenhfJHJwsdJsDbxWZuotD5H4iELNG61kS4fb9");
+
+    /// The bridge program owning the `OutgoingMessage` accounts relayed to Base.
+    pub const BRIDGE_PROGRAM_ID: Pubkey = pubkey!("HNCne2FkVaNghhjKXapxJzPaBvAKDG1Ge3gqhZyfVWLM");
+}
+
+#[cfg(not(any(feature = "devnet-alpha", feature = "devnet-prod", feature = "mainnet")))]
+mod selected {
+    use super::*;
+
+    pub const ENVIRONMENT: Environment = Environment::Localnet;
+    declare_id!("HPLodLSVpcUX73cXxT7NNss1frnr2XWf6yK3KPChRTjJ");
+
+    /// The bridge program owning the `OutgoingMessage` accounts relayed to Base.
+    pub const BRIDGE_PROGRAM_ID: Pubkey = pubkey!("GaxAZQ3BSYjfG65e8mGnBnNpmhqRHDJ33aKEASHh3A3P");
+}
+
+pub use selected::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENVIRONMENTS: [Environment; 4] = [
+        Environment::Localnet,
+        Environment::DevnetAlpha,
+        Environment::DevnetProd,
+        Environment::Mainnet,
+    ];
+
+    #[test]
+    fn test_declared_ids_match_environment() {
+        assert_eq!(ID, ENVIRONMENT.program_id());
+        assert_eq!(BRIDGE_PROGRAM_ID, ENVIRONMENT.bridge_program_id());
+    }
+
+    #[test]
+    fn test_environment_program_ids_are_distinct() {
+        for (i, a) in ENVIRONMENTS.iter().enumerate() {
+            for b in &ENVIRONMENTS[i + 1..] {
+                assert_ne!(a.program_id(), b.program_id(), "{:?} and {:?}", a, b);
+                assert_ne!(
+                    a.bridge_program_id(),
+                    b.bridge_program_id(),
+                    "{:?} and {:?}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use eip1559::Eip1559ConfigError;
 
 #[error_code]
 pub enum RelayerError {
@@ -9,10 +10,49 @@ pub enum RelayerError {
     #[msg("Incorrect relayer program")]
     IncorrectRelayerProgram,
 
+    #[msg("Only the upgrade authority can migrate the relayer config")]
+    UnauthorizedMigration,
+
+    #[msg("Relayer config account has an unknown layout")]
+    InvalidCfgLayout,
+
+    #[msg("Relayer config account is already migrated")]
+    CfgAlreadyMigrated,
+
+    #[msg("Program was not built for the expected environment")]
+    EnvironmentMismatch,
+
     // Configuration (6100-6199)
     #[msg("Unauthorized to update configuration")]
     UnauthorizedConfigUpdate = 6100,
 
+    #[msg("Too many sponsored senders")]
+    TooManySponsoredSenders,
+
+    #[msg("Too many fee exempt senders")]
+    TooManyFeeExemptSenders,
+
+    #[msg("Invalid fee config")]
+    InvalidFeeConfig,
+
+    #[msg("EIP-1559 gas target must be non-zero")]
+    InvalidGasTarget,
+
+    #[msg("EIP-1559 adjustment denominator must be non-zero")]
+    InvalidDenominator,
+
+    #[msg("EIP-1559 window duration must be non-zero")]
+    InvalidWindowDuration,
+
+    #[msg("Maximum base fee must be zero or at least the minimum base fee")]
+    InvalidMaxBaseFee,
+
+    #[msg("Minimum gas limit per message must not exceed the maximum")]
+    InvalidGasLimitBounds,
+
+    #[msg("Gas cost scaler decimal precision must be non-zero")]
+    InvalidGasCostScalerDp,
+
     // Gas Validation (6200-6299)
     #[msg("Gas limit too low")]
     GasLimitTooLow = 6200,
@@ -20,7 +60,66 @@ pub enum RelayerError {
     #[msg("Gas limit exceeded")]
     GasLimitExceeded,
 
+    #[msg("Gas limit can only be derived for transfers without call")]
+    GasLimitNotDerivable,
+
     // Payment (6300-6399)
     #[msg("Incorrect gas fee receiver")]
     IncorrectGasFeeReceiver = 6300,
+
+    #[msg("No relay tip to settle")]
+    NoRelayTip,
+
+    #[msg("Sponsor spend limit exceeded")]
+    SponsorSpendLimitExceeded,
+
+    #[msg("Outgoing message sender is not sponsored")]
+    SenderNotSponsored,
+
+    #[msg("Outgoing message account is required")]
+    MissingOutgoingMessage,
+
+    #[msg("Invalid outgoing message account")]
+    InvalidOutgoingMessage,
+
+    #[msg("Only the original payer can bump the relay fee")]
+    UnauthorizedRelayFeeBump,
+
+    #[msg("Relay fee bump must be greater than zero")]
+    ZeroRelayFeeBump,
+
+    #[msg("Incorrect relayed nonces account")]
+    IncorrectRelayedNonces,
+
+    #[msg("Outgoing message is not confirmed as relayed on Base")]
+    RelayNotConfirmed,
+
+    #[msg("Outgoing message is already confirmed as relayed on Base")]
+    RelayAlreadyConfirmed,
+
+    #[msg("Relay tip refund delay has not elapsed")]
+    RelayTipRefundNotDue,
+
+    #[msg("Incorrect relay request payer")]
+    IncorrectRelayPayer,
+
+    #[msg("Incorrect relay execution account")]
+    IncorrectRelayExecution,
+
+    #[msg("Relayer executing the outgoing message on Base is not recorded")]
+    RelayExecutionNotRecorded,
+
+    #[msg("Relayer does not match the recorded relay execution")]
+    IncorrectRelayer,
+}
+
+impl From<Eip1559ConfigError> for RelayerError {
+    fn from(error: Eip1559ConfigError) -> Self {
+        match error {
+            Eip1559ConfigError::InvalidGasTarget => RelayerError::InvalidGasTarget,
+            Eip1559ConfigError::InvalidDenominator => RelayerError::InvalidDenominator,
+            Eip1559ConfigError::InvalidWindowDuration => RelayerError::InvalidWindowDuration,
+            Eip1559ConfigError::InvalidMaxBaseFee => RelayerError::InvalidMaxBaseFee,
+        }
+    }
 }
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+/// Emitted by `pay_for_relay` when the relayer nonce diverges from the bridge outgoing message
+/// nonce by more than `NonceMonitor::max_nonce_gap`.
+#[event]
+pub struct NonceAnomaly {
+    /// The bridge outgoing message being paid for.
+    pub outgoing_message: Pubkey,
+    /// The relayer nonce assigned to the message.
+    pub relayer_nonce: u64,
+    /// The nonce of the next bridge outgoing message.
+    pub bridge_nonce: u64,
+    /// Divergence between `relayer_nonce` and `bridge_nonce`.
+    pub gap: u64,
+    /// Whether the nonce monitor is now flagged as degraded.
+    pub degraded: bool,
+}
+
+/// Emitted by `bump_relay_fee` when the payer of a relay request raises its escrowed tip,
+/// letting the orchestrator re-prioritize the message instead of waiting for a duplicate.
+#[event]
+pub struct RelayFeeBumped {
+    /// The paid relay request being re-priced.
+    pub message_to_relay: Pubkey,
+    /// The bridge outgoing message being relayed.
+    pub outgoing_message: Pubkey,
+    /// The relayer nonce assigned to the message.
+    pub nonce: u64,
+    /// Lamports added to the escrowed tip.
+    pub added_lamports: u64,
+    /// The escrowed tip after the bump, i.e. the new effective priority.
+    pub tip_lamports: u64,
+}
@@ -0,0 +1,249 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+};
+
+use crate::{
+    constants::{PENDING_RELAY_INDEX_SEED, SPONSOR_POLICY_SEED},
+    state::{MessageToRelay, PendingRelayIndex, SponsorPolicy},
+    RelayFeeBumped, RelayerError, ID,
+};
+
+/// Accounts for the bump_relay_fee instruction that lets the payer of a relay request raise its
+/// escrowed tip instead of paying for a duplicate message.
+#[derive(Accounts)]
+pub struct BumpRelayFee<'info> {
+    /// The original payer of the relay request. Funds the additional tip.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The paid relay request holding the escrowed tip.
+    #[account(mut, has_one = payer @ RelayerError::UnauthorizedRelayFeeBump)]
+    pub message_to_relay: Account<'info, MessageToRelay>,
+
+    /// The payer's sponsor policy, whose daily limit also covers fee bumps.
+    /// CHECK: Validated by seeds and only deserialized when owned by this program.
+    #[account(mut, seeds = [SPONSOR_POLICY_SEED, payer.key().as_ref()], bump)]
+    pub sponsor_policy: UncheckedAccount<'info>,
+
+    /// The guardian-created index of the pending relay requests by fee bucket.
+    /// CHECK: Validated by seeds and only deserialized when owned by this program.
+    #[account(mut, seeds = [PENDING_RELAY_INDEX_SEED], bump)]
+    pub pending_relay_index: UncheckedAccount<'info>,
+
+    /// System program required for transferring the additional tip.
+    pub system_program: Program<'info, System>,
+}
+
+/// Escrows `additional_lamports` on top of the tip already held by `message_to_relay`, raising
+/// the effective priority of the request. The bump is settled to the relayer together with the
+/// original tip through `settle_relay_tip`, or refunded with it through `refund_relay_tip`.
+pub fn bump_relay_fee_handler(ctx: Context<BumpRelayFee>, additional_lamports: u64) -> Result<()> {
+    require!(additional_lamports > 0, RelayerError::ZeroRelayFeeBump);
+    require!(
+        ctx.accounts.message_to_relay.outgoing_nonce.is_some(),
+        RelayerError::MissingOutgoingMessage
+    );
+
+    record_sponsor_spend(&ctx.accounts.sponsor_policy, additional_lamports)?;
+
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.payer.to_account_info(),
+            to: ctx.accounts.message_to_relay.to_account_info(),
+        },
+    );
+    system_program::transfer(cpi_ctx, additional_lamports)?;
+
+    let message_to_relay = &mut ctx.accounts.message_to_relay;
+    message_to_relay.tip_lamports += additional_lamports;
+
+    PendingRelayIndex::update(&ctx.accounts.pending_relay_index, |index| {
+        index.remove(&message_to_relay.key());
+        index.insert(message_to_relay.key(), message_to_relay.tip_lamports);
+    })?;
+
+    emit!(RelayFeeBumped {
+        message_to_relay: message_to_relay.key(),
+        outgoing_message: message_to_relay.outgoing_message,
+        nonce: message_to_relay.nonce,
+        added_lamports: additional_lamports,
+        tip_lamports: message_to_relay.tip_lamports,
+    });
+
+    Ok(())
+}
+
+/// Records `lamports` against the payer's sponsor policy spend window, if the payer created one.
+/// The sponsored senders were already checked when the relay was paid for.
+fn record_sponsor_spend(sponsor_policy_info: &AccountInfo, lamports: u64) -> Result<()> {
+    if *sponsor_policy_info.owner != ID {
+        return Ok(());
+    }
+
+    let mut data = sponsor_policy_info.try_borrow_mut_data()?;
+    let mut sponsor_policy = SponsorPolicy::try_deserialize(&mut &data[..])?;
+    sponsor_policy.record_spend(lamports, Clock::get()?.unix_timestamp)?;
+    sponsor_policy.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{accounts, constants::MTR_SEED, instruction, test_utils::*};
+
+    const TIP: u64 = 1_000_000;
+    const BUMP: u64 = 2_000_000;
+
+    fn pay_for_relay_with_tip(svm: &mut LiteSVM, payer: &Keypair, cfg_pda: Pubkey) -> Pubkey {
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, 1).unwrap();
+
+        let mtr_salt = Pubkey::new_unique().to_bytes();
+        let (message_to_relay, _) =
+            Pubkey::find_program_address(&[MTR_SEED, mtr_salt.as_ref()], &ID);
+        let outgoing_message = mock_outgoing_message(svm, 0, Pubkey::new_unique());
+
+        let accounts = accounts::PayForRelay {
+            payer: payer.pubkey(),
+            cfg: cfg_pda,
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            message_to_relay,
+            fee_exemptions: fee_exemptions_pda(),
+            sponsor_policy: sponsor_policy_pda(&payer.pubkey()),
+            nonce_monitor: nonce_monitor_pda(),
+            bridge: bridge_pda(),
+            pending_relay_index: pending_relay_index_pda(),
+            calldata_pricing: calldata_pricing_pda(),
+            outgoing_message_account: Some(outgoing_message),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: instruction::PayForRelay {
+                mtr_salt,
+                outgoing_message,
+                gas_limit: 123_456,
+                tip_lamports: TIP,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+
+        message_to_relay
+    }
+
+    fn bump_relay_fee(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        message_to_relay: Pubkey,
+        additional_lamports: u64,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::BumpRelayFee {
+                payer: payer.pubkey(),
+                message_to_relay,
+                sponsor_policy: sponsor_policy_pda(&payer.pubkey()),
+                pending_relay_index: pending_relay_index_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::BumpRelayFee {
+                additional_lamports,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_bump_relay_fee_raises_escrowed_tip() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian: _,
+            cfg_pda,
+        } = setup_relayer();
+
+        let message_to_relay = pay_for_relay_with_tip(&mut svm, &payer, cfg_pda);
+        let escrow_balance = svm.get_account(&message_to_relay).unwrap().lamports;
+
+        bump_relay_fee(&mut svm, &payer, message_to_relay, BUMP)
+            .expect("Payer should be able to bump the relay fee");
+
+        let mtr_account = svm.get_account(&message_to_relay).unwrap();
+        assert_eq!(mtr_account.lamports, escrow_balance + BUMP);
+        let mtr = MessageToRelay::try_deserialize(&mut &mtr_account.data[..]).unwrap();
+        assert_eq!(mtr.tip_lamports, TIP + BUMP);
+    }
+
+    #[test]
+    fn test_bump_relay_fee_by_non_payer_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian: _,
+            cfg_pda,
+        } = setup_relayer();
+
+        let message_to_relay = pay_for_relay_with_tip(&mut svm, &payer, cfg_pda);
+        let other = Keypair::new();
+        svm.airdrop(&other.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let error_string = bump_relay_fee(&mut svm, &other, message_to_relay, BUMP).unwrap_err();
+        assert!(
+            error_string.contains("UnauthorizedRelayFeeBump"),
+            "Expected UnauthorizedRelayFeeBump error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_bump_relay_fee_with_zero_lamports_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian: _,
+            cfg_pda,
+        } = setup_relayer();
+
+        let message_to_relay = pay_for_relay_with_tip(&mut svm, &payer, cfg_pda);
+
+        let error_string = bump_relay_fee(&mut svm, &payer, message_to_relay, 0).unwrap_err();
+        assert!(
+            error_string.contains("ZeroRelayFeeBump"),
+            "Expected ZeroRelayFeeBump error, got: {}",
+            error_string
+        );
+    }
+}
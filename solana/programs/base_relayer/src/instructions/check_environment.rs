@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{Environment, RelayerError, ENVIRONMENT};
+
+/// Accounts for the check_environment view instruction. No accounts are required.
+#[derive(Accounts)]
+pub struct CheckEnvironment {}
+
+/// Fails unless the program was built for `expected` and is deployed at its program ID.
+pub fn check_environment_handler(
+    ctx: Context<CheckEnvironment>,
+    expected: Environment,
+) -> Result<()> {
+    require!(ENVIRONMENT == expected, RelayerError::EnvironmentMismatch);
+    require_keys_eq!(
+        *ctx.program_id,
+        expected.program_id(),
+        RelayerError::EnvironmentMismatch
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{accounts, instruction, test_utils::*, ID};
+
+    #[test]
+    fn test_check_environment() {
+        let SetupRelayerResult { mut svm, payer, .. } = setup_relayer();
+
+        let mut check = |expected: Environment| {
+            let ix = Instruction {
+                program_id: ID,
+                accounts: accounts::CheckEnvironment {}.to_account_metas(None),
+                data: instruction::CheckEnvironment { expected }.data(),
+            };
+            let tx = Transaction::new(
+                &[&payer],
+                Message::new(&[ix], Some(&payer.pubkey())),
+                svm.latest_blockhash(),
+            );
+            svm.send_transaction(tx)
+                .map(|_| ())
+                .map_err(|e| format!("{:?}", e))
+        };
+
+        check(Environment::Localnet).expect("Failed to check the compiled environment");
+
+        let error = check(Environment::DevnetAlpha).unwrap_err();
+        assert!(
+            error.contains("EnvironmentMismatch"),
+            "Expected EnvironmentMismatch error, got: {}",
+            error
+        );
+    }
+}
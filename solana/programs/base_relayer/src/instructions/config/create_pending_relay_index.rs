@@ -0,0 +1,218 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{CFG_SEED, DISCRIMINATOR_LEN, PENDING_RELAY_INDEX_SEED},
+    state::{Cfg, PendingRelayIndex},
+    RelayerError,
+};
+
+/// Accounts for the create_pending_relay_index instruction that enables the indexing of pending
+/// relay requests by fee bucket. Only the guardian can create the index.
+#[derive(Accounts)]
+pub struct CreatePendingRelayIndex<'info> {
+    /// The guardian account authorized to update configuration. Funds the index account creation.
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The relayer config state account recording the guardian
+    #[account(
+        has_one = guardian @ RelayerError::UnauthorizedConfigUpdate,
+        seeds = [CFG_SEED],
+        bump
+    )]
+    pub cfg: Account<'info, Cfg>,
+
+    /// The pending relay index.
+    /// - Uses PDA with PENDING_RELAY_INDEX_SEED for deterministic address
+    /// - Updated by `pay_for_relay`, `bump_relay_fee` and `settle_relay_tip` once created
+    #[account(
+        init,
+        payer = guardian,
+        seeds = [PENDING_RELAY_INDEX_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + PendingRelayIndex::INIT_SPACE
+    )]
+    pub pending_relay_index: Account<'info, PendingRelayIndex>,
+
+    /// System program required for creating new accounts.
+    /// Used internally by Anchor for account initialization.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_pending_relay_index_handler(_ctx: Context<CreatePendingRelayIndex>) -> Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, system_program},
+        InstructionData,
+    };
+    use bridge::solana_to_base::RelayedNonces;
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{accounts, constants::MTR_SEED, instruction, test_utils::*, ID};
+
+    fn send(
+        svm: &mut LiteSVM,
+        signers: &[&Keypair],
+        ix: Instruction,
+    ) -> std::result::Result<(), String> {
+        let tx = Transaction::new(
+            signers,
+            Message::new(&[ix], Some(&signers[0].pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn create_pending_relay_index(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        cfg_pda: Pubkey,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::CreatePendingRelayIndex {
+                guardian: guardian.pubkey(),
+                cfg: cfg_pda,
+                pending_relay_index: pending_relay_index_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::CreatePendingRelayIndex {}.data(),
+        };
+        send(svm, &[guardian], ix)
+    }
+
+    fn pay_for_relay_with_tip(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        cfg_pda: Pubkey,
+        outgoing_nonce: u64,
+        tip_lamports: u64,
+    ) -> Pubkey {
+        let mtr_salt = Pubkey::new_unique().to_bytes();
+        let (message_to_relay, _) =
+            Pubkey::find_program_address(&[MTR_SEED, mtr_salt.as_ref()], &ID);
+        let outgoing_message = mock_outgoing_message(svm, outgoing_nonce, Pubkey::new_unique());
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::PayForRelay {
+                payer: payer.pubkey(),
+                cfg: cfg_pda,
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                message_to_relay,
+                fee_exemptions: fee_exemptions_pda(),
+                sponsor_policy: sponsor_policy_pda(&payer.pubkey()),
+                nonce_monitor: nonce_monitor_pda(),
+                bridge: bridge_pda(),
+                pending_relay_index: pending_relay_index_pda(),
+                calldata_pricing: calldata_pricing_pda(),
+                outgoing_message_account: Some(outgoing_message),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::PayForRelay {
+                mtr_salt,
+                outgoing_message,
+                gas_limit: 123_456,
+                tip_lamports,
+            }
+            .data(),
+        };
+        send(svm, &[payer], ix).unwrap();
+
+        message_to_relay
+    }
+
+    fn read_pending_relays(svm: &LiteSVM) -> Vec<Pubkey> {
+        let account = svm.get_account(&pending_relay_index_pda()).unwrap();
+        let index = PendingRelayIndex::try_deserialize(&mut &account.data[..]).unwrap();
+        index.entries[..index.count as usize]
+            .iter()
+            .map(|entry| entry.message_to_relay)
+            .collect()
+    }
+
+    #[test]
+    fn test_pending_relay_index_follows_pay_bump_and_settle() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+        } = setup_relayer();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, 1).unwrap();
+        create_pending_relay_index(&mut svm, &guardian, cfg_pda).unwrap();
+
+        let low = pay_for_relay_with_tip(&mut svm, &payer, cfg_pda, 1, 1_000);
+        let high = pay_for_relay_with_tip(&mut svm, &payer, cfg_pda, 2, 1_000_000);
+        pay_for_relay_with_tip(&mut svm, &payer, cfg_pda, 3, 0);
+        assert_eq!(read_pending_relays(&svm), vec![high, low]);
+
+        // Bumping the low tip above the high one moves it to the top bucket
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::BumpRelayFee {
+                payer: payer.pubkey(),
+                message_to_relay: low,
+                sponsor_policy: sponsor_policy_pda(&payer.pubkey()),
+                pending_relay_index: pending_relay_index_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::BumpRelayFee {
+                additional_lamports: 10_000_000,
+            }
+            .data(),
+        };
+        send(&mut svm, &[&payer], ix).unwrap();
+        assert_eq!(read_pending_relays(&svm), vec![low, high]);
+
+        // Settled requests leave the index
+        let page = RelayedNonces::page_of(1);
+        mock_relayed_nonces(&mut svm, page, &[1]);
+        mock_relay_execution(&mut svm, 1, TEST_GAS_FEE_RECEIVER);
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SettleRelayTip {
+                message_to_relay: low,
+                relayer: TEST_GAS_FEE_RECEIVER,
+                relayed_nonces: relayed_nonces_pda(page),
+                relay_execution: relay_execution_pda(1),
+                pending_relay_index: pending_relay_index_pda(),
+            }
+            .to_account_metas(None),
+            data: instruction::SettleRelayTip {}.data(),
+        };
+        send(&mut svm, &[&payer], ix).unwrap();
+        assert_eq!(read_pending_relays(&svm), vec![high]);
+    }
+
+    #[test]
+    fn test_create_pending_relay_index_with_non_guardian_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+
+        let error_string = create_pending_relay_index(&mut svm, &payer, cfg_pda).unwrap_err();
+        assert!(
+            error_string.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error_string
+        );
+    }
+}
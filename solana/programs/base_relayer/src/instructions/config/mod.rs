@@ -19,10 +19,20 @@ pub struct SetConfig<'info> {
     pub guardian: Signer<'info>,
 }
 
+pub mod create_pending_relay_index;
+pub mod set_calldata_pricing;
 pub mod set_eip1559_config;
+pub mod set_fee_config;
+pub mod set_fee_exemptions;
 pub mod set_gas_config;
 pub mod set_guardian;
+pub mod set_nonce_monitor;
 
+pub use create_pending_relay_index::*;
+pub use set_calldata_pricing::*;
 pub use set_eip1559_config::*;
+pub use set_fee_config::*;
+pub use set_fee_exemptions::*;
 pub use set_gas_config::*;
 pub use set_guardian::*;
+pub use set_nonce_monitor::*;
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{CALLDATA_PRICING_SEED, CFG_SEED, DISCRIMINATOR_LEN},
+    state::{CalldataPricing, Cfg},
+    RelayerError,
+};
+
+/// Accounts for the set_calldata_pricing instruction that configures the calldata cost charged by
+/// `pay_for_relay` for large payloads. Only the guardian can update the pricing.
+#[derive(Accounts)]
+pub struct SetCalldataPricing<'info> {
+    /// The guardian account authorized to update configuration. Funds the pricing account
+    /// creation.
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The relayer config state account recording the guardian
+    #[account(
+        has_one = guardian @ RelayerError::UnauthorizedConfigUpdate,
+        seeds = [CFG_SEED],
+        bump
+    )]
+    pub cfg: Account<'info, Cfg>,
+
+    /// The calldata pricing.
+    /// - Uses PDA with CALLDATA_PRICING_SEED for deterministic address
+    /// - Created on first use and updated afterwards
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        seeds = [CALLDATA_PRICING_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + CalldataPricing::INIT_SPACE
+    )]
+    pub calldata_pricing: Account<'info, CalldataPricing>,
+
+    /// System program required for creating new accounts.
+    /// Used internally by Anchor for account initialization.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_calldata_pricing_handler(
+    ctx: Context<SetCalldataPricing>,
+    free_calldata_bytes: u64,
+    gas_per_calldata_byte: u64,
+) -> Result<()> {
+    let calldata_pricing = &mut ctx.accounts.calldata_pricing;
+    calldata_pricing.free_calldata_bytes = free_calldata_bytes;
+    calldata_pricing.gas_per_calldata_byte = gas_per_calldata_byte;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, system_program},
+        InstructionData,
+    };
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{accounts, instruction, test_utils::*, ID};
+
+    fn set_calldata_pricing(
+        svm: &mut litesvm::LiteSVM,
+        guardian: &Keypair,
+        cfg_pda: Pubkey,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetCalldataPricing {
+                guardian: guardian.pubkey(),
+                cfg: cfg_pda,
+                calldata_pricing: calldata_pricing_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::SetCalldataPricing {
+                free_calldata_bytes: 1_000,
+                gas_per_calldata_byte: 40,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_set_calldata_pricing_with_guardian_succeeds() {
+        let SetupRelayerResult {
+            mut svm,
+            guardian,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+
+        set_calldata_pricing(&mut svm, &guardian, cfg_pda).unwrap();
+
+        let account = svm.get_account(&calldata_pricing_pda()).unwrap();
+        let calldata_pricing = CalldataPricing::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(
+            calldata_pricing,
+            CalldataPricing {
+                free_calldata_bytes: 1_000,
+                gas_per_calldata_byte: 40,
+            }
+        );
+    }
+
+    #[test]
+    fn test_set_calldata_pricing_with_non_guardian_fails() {
+        let SetupRelayerResult {
+            mut svm, cfg_pda, ..
+        } = setup_relayer();
+
+        let fake_guardian = Keypair::new();
+        svm.airdrop(&fake_guardian.pubkey(), 1_000_000_000).unwrap();
+
+        let error = set_calldata_pricing(&mut svm, &fake_guardian, cfg_pda).unwrap_err();
+        assert!(
+            error.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error
+        );
+    }
+}
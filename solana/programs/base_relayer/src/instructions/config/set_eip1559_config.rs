@@ -1,11 +1,13 @@
 use anchor_lang::prelude::*;
 
-use crate::{instructions::SetConfig, internal::Eip1559Config};
+use crate::{instructions::SetConfig, internal::Eip1559Config, RelayerError};
 
 pub fn set_eip1559_config_handler(
     ctx: Context<SetConfig>,
     eip1559_config: Eip1559Config,
 ) -> Result<()> {
+    eip1559_config.validate().map_err(RelayerError::from)?;
+
     ctx.accounts.cfg.eip1559.config = eip1559_config;
     Ok(())
 }
@@ -35,6 +37,8 @@ mod tests {
             denominator: 4,
             window_duration_seconds: 10,
             minimum_base_fee: 5,
+            max_base_fee: 0,
+            max_window_increase_bps: 0,
         };
 
         let accounts = accounts::SetConfig {
@@ -85,6 +89,8 @@ mod tests {
             denominator: 4,
             window_duration_seconds: 10,
             minimum_base_fee: 5,
+            max_base_fee: 0,
+            max_window_increase_bps: 0,
         };
 
         let accounts = accounts::SetConfig {
@@ -115,4 +121,54 @@ mod tests {
         let error_string = format!("{:?}", result.unwrap_err());
         assert!(error_string.contains("UnauthorizedConfigUpdate"));
     }
+
+    #[test]
+    fn test_set_eip1559_config_with_zero_window_duration_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+        } = setup_relayer();
+
+        let new_config = Eip1559Config {
+            target: 10_000_000,
+            denominator: 4,
+            window_duration_seconds: 0,
+            minimum_base_fee: 5,
+            max_base_fee: 0,
+            max_window_increase_bps: 0,
+        };
+
+        let accounts = accounts::SetConfig {
+            cfg: cfg_pda,
+            guardian: guardian.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: instruction::SetEip1559Config {
+                eip1559_config: new_config,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer, &guardian],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("InvalidWindowDuration"),
+            "Expected InvalidWindowDuration error, got: {}",
+            error_string
+        );
+    }
 }
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use fee_config::FeeConfigV2;
+
+use crate::{instructions::SetConfig, RelayerError};
+
+pub fn set_fee_config_handler(ctx: Context<SetConfig>, fee_config: FeeConfigV2) -> Result<()> {
+    require!(
+        fee_config.validate().is_ok(),
+        RelayerError::InvalidFeeConfig
+    );
+
+    ctx.accounts.cfg.apply_fee_config(&fee_config);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData, ToAccountMetas};
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{accounts, instruction, test_utils::*, Cfg, ID};
+
+    fn new_fee_config() -> FeeConfigV2 {
+        FeeConfigV2 {
+            target: 10_000_000,
+            denominator: 4,
+            window_duration_seconds: 10,
+            minimum_base_fee: 5,
+            max_base_fee: 1_000,
+            max_window_increase_bps: 2_500,
+            gas_cost_scaler: 2_000_000,
+            gas_cost_scaler_dp: 10u64.pow(7),
+            gas_fee_receiver: Pubkey::new_unique(),
+        }
+    }
+
+    fn set_fee_config_ix(
+        cfg_pda: Pubkey,
+        guardian: Pubkey,
+        fee_config: FeeConfigV2,
+    ) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::SetConfig {
+                cfg: cfg_pda,
+                guardian,
+            }
+            .to_account_metas(None),
+            data: instruction::SetFeeConfig { fee_config }.data(),
+        }
+    }
+
+    #[test]
+    fn test_set_fee_config_with_guardian_succeeds() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+        } = setup_relayer();
+
+        let cfg_account = svm.get_account(&cfg_pda).unwrap();
+        let old_gas_config = Cfg::try_deserialize(&mut &cfg_account.data[..])
+            .unwrap()
+            .gas_config;
+
+        let new_config = new_fee_config();
+        let tx = Transaction::new(
+            &[&payer, &guardian],
+            Message::new(
+                &[set_fee_config_ix(
+                    cfg_pda,
+                    guardian.pubkey(),
+                    new_config.clone(),
+                )],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Guardian should be able to update fee config");
+
+        let cfg_account = svm.get_account(&cfg_pda).unwrap();
+        let cfg = Cfg::try_deserialize(&mut &cfg_account.data[..]).unwrap();
+        assert_eq!(cfg.fee_config(), new_config);
+        assert_eq!(
+            cfg.gas_config.min_gas_limit_per_message,
+            old_gas_config.min_gas_limit_per_message
+        );
+        assert_eq!(
+            cfg.gas_config.max_gas_limit_per_message,
+            old_gas_config.max_gas_limit_per_message
+        );
+    }
+
+    #[test]
+    fn test_set_fee_config_invalid_config_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+        } = setup_relayer();
+
+        let new_config = FeeConfigV2 {
+            gas_cost_scaler_dp: 0,
+            ..new_fee_config()
+        };
+        let tx = Transaction::new(
+            &[&payer, &guardian],
+            Message::new(
+                &[set_fee_config_ix(cfg_pda, guardian.pubkey(), new_config)],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(error_string.contains("InvalidFeeConfig"));
+    }
+}
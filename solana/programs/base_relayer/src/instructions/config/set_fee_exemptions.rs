@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{CFG_SEED, DISCRIMINATOR_LEN, FEE_EXEMPTIONS_SEED, MAX_FEE_EXEMPT_SENDERS},
+    state::{Cfg, FeeExemptions},
+    RelayerError,
+};
+
+/// Accounts for the set_fee_exemptions instruction that replaces the list of senders exempt from
+/// gas fees. Only the guardian can update the list.
+#[derive(Accounts)]
+pub struct SetFeeExemptions<'info> {
+    /// The guardian account authorized to update configuration. Funds the list account creation.
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The relayer config state account recording the guardian
+    #[account(
+        has_one = guardian @ RelayerError::UnauthorizedConfigUpdate,
+        seeds = [CFG_SEED],
+        bump
+    )]
+    pub cfg: Account<'info, Cfg>,
+
+    /// The fee exemption list.
+    /// - Uses PDA with FEE_EXEMPTIONS_SEED for deterministic address
+    /// - Created on first use and overwritten afterwards
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        seeds = [FEE_EXEMPTIONS_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + FeeExemptions::INIT_SPACE
+    )]
+    pub fee_exemptions: Account<'info, FeeExemptions>,
+
+    /// System program required for creating new accounts.
+    /// Used internally by Anchor for account initialization.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_fee_exemptions_handler(
+    ctx: Context<SetFeeExemptions>,
+    senders: Vec<Pubkey>,
+) -> Result<()> {
+    require!(
+        senders.len() <= MAX_FEE_EXEMPT_SENDERS as usize,
+        RelayerError::TooManyFeeExemptSenders
+    );
+
+    let fee_exemptions = &mut ctx.accounts.fee_exemptions;
+    fee_exemptions.sender_count = senders.len() as u8;
+    fee_exemptions.senders = [Pubkey::default(); MAX_FEE_EXEMPT_SENDERS as usize];
+    fee_exemptions.senders[..senders.len()].copy_from_slice(&senders);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, system_program},
+        InstructionData,
+    };
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{accounts, instruction, test_utils::*, ID};
+
+    #[test]
+    fn test_set_fee_exemptions_with_guardian_succeeds() {
+        let SetupRelayerResult {
+            mut svm,
+            guardian,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+
+        let sender = Pubkey::new_unique();
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetFeeExemptions {
+                guardian: guardian.pubkey(),
+                cfg: cfg_pda,
+                fee_exemptions: fee_exemptions_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::SetFeeExemptions {
+                senders: vec![sender],
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+
+        let account = svm.get_account(&fee_exemptions_pda()).unwrap();
+        let fee_exemptions = FeeExemptions::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(fee_exemptions.sender_count, 1);
+        assert!(fee_exemptions.is_exempt(&sender));
+        assert!(!fee_exemptions.is_exempt(&Pubkey::default()));
+    }
+
+    #[test]
+    fn test_set_fee_exemptions_with_non_guardian_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetFeeExemptions {
+                guardian: payer.pubkey(),
+                cfg: cfg_pda,
+                fee_exemptions: fee_exemptions_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::SetFeeExemptions {
+                senders: vec![payer.pubkey()],
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error_string
+        );
+    }
+}
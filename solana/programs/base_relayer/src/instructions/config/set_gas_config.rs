@@ -3,6 +3,8 @@ use anchor_lang::prelude::*;
 use crate::{instructions::SetConfig, internal::GasConfig};
 
 pub fn set_gas_config_handler(ctx: Context<SetConfig>, gas_config: GasConfig) -> Result<()> {
+    gas_config.validate()?;
+
     ctx.accounts.cfg.gas_config = gas_config;
     Ok(())
 }
@@ -34,6 +36,8 @@ mod tests {
             gas_cost_scaler: 2_000_000,
             gas_cost_scaler_dp: 10u64.pow(7),
             gas_fee_receiver: new_gas_receiver,
+            default_transfer_gas_limit: 200_000,
+            default_transfer_batch_recipient_gas_limit: 60_000,
         };
 
         let accounts = accounts::SetConfig {
@@ -86,6 +90,8 @@ mod tests {
             gas_cost_scaler: 2_000_000,
             gas_cost_scaler_dp: 10u64.pow(7),
             gas_fee_receiver: new_gas_receiver,
+            default_transfer_gas_limit: 200_000,
+            default_transfer_batch_recipient_gas_limit: 60_000,
         };
 
         let accounts = accounts::SetConfig {
@@ -116,4 +122,55 @@ mod tests {
         let error_string = format!("{:?}", result.unwrap_err());
         assert!(error_string.contains("UnauthorizedConfigUpdate"));
     }
+
+    #[test]
+    fn test_set_gas_config_with_inverted_gas_limits_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+        } = setup_relayer();
+
+        let new_config = GasConfig {
+            min_gas_limit_per_message: 200_000_000,
+            max_gas_limit_per_message: 200_000,
+            gas_cost_scaler: 2_000_000,
+            gas_cost_scaler_dp: 10u64.pow(7),
+            gas_fee_receiver: Pubkey::new_unique(),
+            default_transfer_gas_limit: 200_000,
+            default_transfer_batch_recipient_gas_limit: 60_000,
+        };
+
+        let accounts = accounts::SetConfig {
+            cfg: cfg_pda,
+            guardian: guardian.pubkey(),
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: instruction::SetGasConfig {
+                gas_config: new_config,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer, &guardian],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("InvalidGasLimitBounds"),
+            "Expected InvalidGasLimitBounds error, got: {}",
+            error_string
+        );
+    }
 }
@@ -0,0 +1,194 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{CFG_SEED, DISCRIMINATOR_LEN, NONCE_MONITOR_SEED},
+    state::{Cfg, NonceMonitor},
+    RelayerError,
+};
+
+/// Accounts for the set_nonce_monitor instruction that configures the nonce gap detection of
+/// `pay_for_relay`. Only the guardian can update the monitor.
+#[derive(Accounts)]
+pub struct SetNonceMonitor<'info> {
+    /// The guardian account authorized to update configuration. Funds the monitor account creation.
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The relayer config state account recording the guardian
+    #[account(
+        has_one = guardian @ RelayerError::UnauthorizedConfigUpdate,
+        seeds = [CFG_SEED],
+        bump
+    )]
+    pub cfg: Account<'info, Cfg>,
+
+    /// The nonce monitor.
+    /// - Uses PDA with NONCE_MONITOR_SEED for deterministic address
+    /// - Created on first use and updated afterwards
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        seeds = [NONCE_MONITOR_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + NonceMonitor::INIT_SPACE
+    )]
+    pub nonce_monitor: Account<'info, NonceMonitor>,
+
+    /// System program required for creating new accounts.
+    /// Used internally by Anchor for account initialization.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts for the clear_nonce_anomaly instruction that resets the `degraded` flag of the nonce
+/// monitor once the guardian investigated the anomaly.
+#[derive(Accounts)]
+pub struct ClearNonceAnomaly<'info> {
+    /// The guardian account authorized to update configuration
+    pub guardian: Signer<'info>,
+
+    /// The relayer config state account recording the guardian
+    #[account(
+        has_one = guardian @ RelayerError::UnauthorizedConfigUpdate,
+        seeds = [CFG_SEED],
+        bump
+    )]
+    pub cfg: Account<'info, Cfg>,
+
+    /// The nonce monitor flagged as degraded
+    #[account(mut, seeds = [NONCE_MONITOR_SEED], bump)]
+    pub nonce_monitor: Account<'info, NonceMonitor>,
+}
+
+pub fn set_nonce_monitor_handler(
+    ctx: Context<SetNonceMonitor>,
+    max_nonce_gap: u64,
+    degrade_on_anomaly: bool,
+) -> Result<()> {
+    let nonce_monitor = &mut ctx.accounts.nonce_monitor;
+    nonce_monitor.max_nonce_gap = max_nonce_gap;
+    nonce_monitor.degrade_on_anomaly = degrade_on_anomaly;
+
+    Ok(())
+}
+
+pub fn clear_nonce_anomaly_handler(ctx: Context<ClearNonceAnomaly>) -> Result<()> {
+    ctx.accounts.nonce_monitor.degraded = false;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, system_program},
+        InstructionData,
+    };
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{accounts, instruction, test_utils::*, ID};
+
+    #[test]
+    fn test_set_nonce_monitor_and_clear_anomaly_with_guardian_succeeds() {
+        let SetupRelayerResult {
+            mut svm,
+            guardian,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetNonceMonitor {
+                guardian: guardian.pubkey(),
+                cfg: cfg_pda,
+                nonce_monitor: nonce_monitor_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::SetNonceMonitor {
+                max_nonce_gap: 10,
+                degrade_on_anomaly: true,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+
+        let account = svm.get_account(&nonce_monitor_pda()).unwrap();
+        let nonce_monitor = NonceMonitor::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(nonce_monitor.max_nonce_gap, 10);
+        assert!(nonce_monitor.degrade_on_anomaly);
+        assert!(!nonce_monitor.degraded);
+        assert_eq!(nonce_monitor.anomaly(5, 15), None);
+        assert_eq!(nonce_monitor.anomaly(20, 5), Some(15));
+
+        // Flag the monitor as degraded, then clear it
+        mock_nonce_monitor(&mut svm, 10, true, true);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::ClearNonceAnomaly {
+                guardian: guardian.pubkey(),
+                cfg: cfg_pda,
+                nonce_monitor: nonce_monitor_pda(),
+            }
+            .to_account_metas(None),
+            data: instruction::ClearNonceAnomaly {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+
+        let account = svm.get_account(&nonce_monitor_pda()).unwrap();
+        let nonce_monitor = NonceMonitor::try_deserialize(&mut &account.data[..]).unwrap();
+        assert!(!nonce_monitor.degraded);
+        assert_eq!(nonce_monitor.max_nonce_gap, 10);
+    }
+
+    #[test]
+    fn test_clear_nonce_anomaly_with_non_guardian_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+        mock_nonce_monitor(&mut svm, 10, true, true);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::ClearNonceAnomaly {
+                guardian: payer.pubkey(),
+                cfg: cfg_pda,
+                nonce_monitor: nonce_monitor_pda(),
+            }
+            .to_account_metas(None),
+            data: instruction::ClearNonceAnomaly {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error_string
+        );
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{Environment, ENVIRONMENT};
+
+/// Accounts for the get_environment view instruction. No accounts are required.
+#[derive(Accounts)]
+pub struct GetEnvironment {}
+
+pub fn get_environment_handler(_ctx: Context<GetEnvironment>) -> Result<Environment> {
+    Ok(ENVIRONMENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{accounts, instruction, test_utils::*, ID};
+
+    #[test]
+    fn test_get_environment_returns_compiled_environment() {
+        let SetupRelayerResult { mut svm, payer, .. } = setup_relayer();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::GetEnvironment {}.to_account_metas(None),
+            data: instruction::GetEnvironment {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let meta = svm.send_transaction(tx).unwrap();
+
+        assert_eq!(meta.return_data.program_id, ID);
+        let environment = Environment::try_from_slice(&meta.return_data.data).unwrap();
+        assert_eq!(environment, Environment::Localnet);
+    }
+}
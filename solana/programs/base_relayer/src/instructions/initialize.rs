@@ -56,6 +56,9 @@ pub fn initialize_handler(
     eip1559_config: Eip1559Config,
     gas_config: GasConfig,
 ) -> Result<()> {
+    eip1559_config.validate().map_err(RelayerError::from)?;
+    gas_config.validate()?;
+
     let current_timestamp = Clock::get()?.unix_timestamp;
     let minimum_base_fee = eip1559_config.minimum_base_fee;
 
@@ -213,4 +216,56 @@ mod tests {
             error_string
         );
     }
+
+    #[test]
+    fn test_initialize_zero_denominator_fails() {
+        let DeployRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+            program_data_pda,
+        } = deploy_relayer();
+        let payer_pk = payer.pubkey();
+
+        let accounts = accounts::Initialize {
+            upgrade_authority: payer_pk,
+            payer: payer_pk,
+            cfg: cfg_pda,
+            program_data: program_data_pda,
+            program: ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: instruction::Initialize {
+                guardian: guardian.pubkey(),
+                eip1559_config: Eip1559Config {
+                    denominator: 0,
+                    ..Eip1559Config::test_new()
+                },
+                gas_config: GasConfig::test_new(Pubkey::new_unique()),
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer_pk)),
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("InvalidDenominator"),
+            "Expected InvalidDenominator error, got: {}",
+            error_string
+        );
+    }
 }
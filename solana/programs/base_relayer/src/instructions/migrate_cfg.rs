@@ -0,0 +1,235 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+};
+
+use crate::{
+    constants::{CFG_SEED, DISCRIMINATOR_LEN},
+    program::BaseRelayer as BaseRelayerProgram,
+    Cfg, RelayerError, ID,
+};
+
+/// Offset, after the discriminator, of the `Eip1559Config` cap fields (`max_base_fee` and
+/// `max_window_increase_bps`) added with `FeeConfigV2`: they follow `nonce`, `guardian` and the
+/// four original `Eip1559Config` fields.
+const FEE_CAPS_OFFSET: usize = 8 + 32 + 4 * 8;
+
+/// Size of the `Eip1559Config` cap fields added with `FeeConfigV2`.
+const FEE_CAPS_LEN: usize = 2 * 8;
+
+/// Accounts for the migrate_cfg instruction that upgrades the config account written before
+/// `FeeConfigV2` to the current `Cfg` layout.
+/// Only the upgrade authority can migrate the config.
+#[derive(Accounts)]
+pub struct MigrateCfg<'info> {
+    /// The upgrade authority that is authorized to migrate the config.
+    pub upgrade_authority: Signer<'info>,
+
+    /// The account that pays for the additional rent required by the new layout.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: The relayer config account being migrated. It cannot be loaded as `Account<Cfg>`
+    /// because its data is still in the older layout. The PDA and owner are validated here and
+    /// the layout is validated by the handler.
+    #[account(mut, seeds = [CFG_SEED], bump, owner = ID)]
+    pub cfg: UncheckedAccount<'info>,
+
+    /// Program data account containing the upgrade authority.
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key())
+            @ RelayerError::UnauthorizedMigration
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// The base_relayer program itself.
+    /// Validates that program_data is the correct ProgramData account for this program.
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+            @ RelayerError::IncorrectRelayerProgram
+    )]
+    pub program: Program<'info, BaseRelayerProgram>,
+
+    /// System program required for topping up the config account rent.
+    pub system_program: Program<'info, System>,
+}
+
+/// Reallocs the `Cfg` account to its current size and inserts the EIP-1559 caps, zeroed so that
+/// they stay disabled until configured through `set_fee_config`.
+pub fn migrate_cfg_handler(ctx: Context<MigrateCfg>) -> Result<()> {
+    let cfg = ctx.accounts.cfg.to_account_info();
+    let new_len = DISCRIMINATOR_LEN + Cfg::INIT_SPACE;
+    let old_len = new_len - FEE_CAPS_LEN;
+
+    {
+        let data = cfg.try_borrow_data()?;
+        require!(
+            data.len() > DISCRIMINATOR_LEN && data[..DISCRIMINATOR_LEN] == *Cfg::DISCRIMINATOR,
+            RelayerError::InvalidCfgLayout
+        );
+        require!(data.len() != new_len, RelayerError::CfgAlreadyMigrated);
+        require!(data.len() == old_len, RelayerError::InvalidCfgLayout);
+    }
+
+    let required_lamports = Rent::get()?.minimum_balance(new_len);
+    let current_lamports = cfg.lamports();
+    if required_lamports > current_lamports {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: cfg.clone(),
+            },
+        );
+        system_program::transfer(cpi_ctx, required_lamports - current_lamports)?;
+    }
+
+    cfg.realloc(new_len, true)?;
+
+    let mut data = cfg.try_borrow_mut_data()?;
+    let start = DISCRIMINATOR_LEN + FEE_CAPS_OFFSET;
+    data.copy_within(start..old_len, start + FEE_CAPS_LEN);
+    data[start..start + FEE_CAPS_LEN].fill(0);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{
+        solana_program::{bpf_loader_upgradeable, instruction::Instruction},
+        InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_account::Account as SvmAccount;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{accounts, instruction, test_utils::*};
+
+    /// Rewrites the initialized config account into the layout preceding `FeeConfigV2` by
+    /// stripping the EIP-1559 caps, which must be zero in the initialized config.
+    fn downgrade_cfg(svm: &mut LiteSVM, cfg_pda: Pubkey) -> Cfg {
+        let account = svm.get_account(&cfg_pda).unwrap();
+        let cfg = Cfg::try_deserialize(&mut &account.data[..]).unwrap();
+
+        let mut data = account.data.clone();
+        let start = DISCRIMINATOR_LEN + FEE_CAPS_OFFSET;
+        data.drain(start..start + FEE_CAPS_LEN);
+        let lamports = svm.minimum_balance_for_rent_exemption(data.len());
+
+        svm.set_account(
+            cfg_pda,
+            SvmAccount {
+                lamports,
+                data,
+                ..account
+            },
+        )
+        .unwrap();
+
+        cfg
+    }
+
+    fn migrate_cfg_ix(upgrade_authority: Pubkey, cfg_pda: Pubkey) -> Instruction {
+        let (program_data_pda, _) =
+            Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID);
+
+        Instruction {
+            program_id: ID,
+            accounts: accounts::MigrateCfg {
+                upgrade_authority,
+                payer: upgrade_authority,
+                cfg: cfg_pda,
+                program_data: program_data_pda,
+                program: ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::MigrateCfg {}.data(),
+        }
+    }
+
+    #[test]
+    fn test_migrate_cfg_succeeds() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+
+        let expected = downgrade_cfg(&mut svm, cfg_pda);
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(
+                &[migrate_cfg_ix(payer.pubkey(), cfg_pda)],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Failed to migrate config");
+
+        let account = svm.get_account(&cfg_pda).unwrap();
+        assert_eq!(account.data.len(), DISCRIMINATOR_LEN + Cfg::INIT_SPACE);
+        assert!(account.lamports >= svm.minimum_balance_for_rent_exemption(account.data.len()));
+
+        let cfg = Cfg::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(cfg, expected);
+    }
+
+    #[test]
+    fn test_migrate_cfg_already_migrated_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(
+                &[migrate_cfg_ix(payer.pubkey(), cfg_pda)],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let error_string = format!("{:?}", svm.send_transaction(tx).unwrap_err());
+        assert!(
+            error_string.contains("CfgAlreadyMigrated"),
+            "Expected CfgAlreadyMigrated error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_migrate_cfg_unauthorized_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            guardian,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+
+        downgrade_cfg(&mut svm, cfg_pda);
+
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(
+                &[migrate_cfg_ix(guardian.pubkey(), cfg_pda)],
+                Some(&guardian.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let error_string = format!("{:?}", svm.send_transaction(tx).unwrap_err());
+        assert!(
+            error_string.contains("UnauthorizedMigration"),
+            "Expected UnauthorizedMigration error, got: {}",
+            error_string
+        );
+    }
+}
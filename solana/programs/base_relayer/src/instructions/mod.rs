@@ -1,7 +1,21 @@
+pub mod bump_relay_fee;
+pub mod check_environment;
 pub mod config;
+pub mod get_environment;
 pub mod initialize;
+pub mod migrate_cfg;
 pub mod pay_for_relay;
+pub mod refund_relay_tip;
+pub mod set_sponsor_policy;
+pub mod settle_relay_tip;
 
+pub use bump_relay_fee::*;
+pub use check_environment::*;
 pub use config::*;
+pub use get_environment::*;
 pub use initialize::*;
+pub use migrate_cfg::*;
 pub use pay_for_relay::*;
+pub use refund_relay_tip::*;
+pub use set_sponsor_policy::*;
+pub use settle_relay_tip::*;
@@ -1,14 +1,27 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+};
+use bridge::{
+    common::{bridge::Bridge, BRIDGE_SEED},
+    solana_to_base::{Message, MessageKind, OutgoingMessage},
+};
 
 use crate::{
-    constants::{CFG_SEED, DISCRIMINATOR_LEN, MTR_SEED},
-    internal::check_and_pay_for_gas,
-    state::{Cfg, MessageToRelay},
-    RelayerError,
+    constants::{
+        CALLDATA_PRICING_SEED, CFG_SEED, DISCRIMINATOR_LEN, FEE_EXEMPTIONS_SEED, MTR_SEED,
+        NONCE_MONITOR_SEED, PENDING_RELAY_INDEX_SEED, SPONSOR_POLICY_SEED,
+    },
+    internal::{check_and_pay_for_gas, GasConfig},
+    state::{
+        CalldataPricing, Cfg, FeeExemptions, MessageToRelay, NonceMonitor, PendingRelayIndex,
+        SponsorPolicy,
+    },
+    NonceAnomaly, RelayerError, BRIDGE_PROGRAM_ID, ID,
 };
 
 #[derive(Accounts)]
-#[instruction(mtr_salt: [u8; 32])]
+#[instruction(mtr_salt: [u8; 32], outgoing_message: Pubkey)]
 pub struct PayForRelay<'info> {
     /// The account that pays for transaction fees and account creation.
     /// Must be mutable to deduct lamports for account rent and gas fees.
@@ -29,6 +42,44 @@ pub struct PayForRelay<'info> {
     #[account(init, payer = payer, seeds = [MTR_SEED, mtr_salt.as_ref()], bump, space = DISCRIMINATOR_LEN + MessageToRelay::INIT_SPACE)]
     pub message_to_relay: Account<'info, MessageToRelay>,
 
+    /// The guardian-managed list of outgoing message senders exempt from the gas fee transfer,
+    /// checked against the sender of `outgoing_message_account`.
+    /// CHECK: Validated by seeds and only deserialized when owned by this program.
+    #[account(seeds = [FEE_EXEMPTIONS_SEED], bump)]
+    pub fee_exemptions: UncheckedAccount<'info>,
+
+    /// The payer's sponsor policy. Always required so a payer cannot bypass its own policy;
+    /// enforced only once the payer has created it via `set_sponsor_policy`.
+    /// CHECK: Validated by seeds and only deserialized when owned by this program.
+    #[account(mut, seeds = [SPONSOR_POLICY_SEED, payer.key().as_ref()], bump)]
+    pub sponsor_policy: UncheckedAccount<'info>,
+
+    /// The guardian-configured nonce monitor, flagged as degraded on nonce anomalies if configured.
+    /// CHECK: Validated by seeds and only deserialized when owned by this program.
+    #[account(mut, seeds = [NONCE_MONITOR_SEED], bump)]
+    pub nonce_monitor: UncheckedAccount<'info>,
+
+    /// The bridge state account, whose outgoing message nonce is checked by the nonce monitor.
+    /// CHECK: Validated by seeds under the bridge program; only read once the monitor exists.
+    #[account(seeds = [BRIDGE_SEED], bump, seeds::program = BRIDGE_PROGRAM_ID)]
+    pub bridge: UncheckedAccount<'info>,
+
+    /// The guardian-created index of the pending relay requests by fee bucket.
+    /// CHECK: Validated by seeds and only deserialized when owned by this program.
+    #[account(mut, seeds = [PENDING_RELAY_INDEX_SEED], bump)]
+    pub pending_relay_index: UncheckedAccount<'info>,
+
+    /// The guardian-configured pricing of the calldata of large payloads.
+    /// CHECK: Validated by seeds and only deserialized when owned by this program.
+    #[account(seeds = [CALLDATA_PRICING_SEED], bump)]
+    pub calldata_pricing: UncheckedAccount<'info>,
+
+    /// The bridge `OutgoingMessage` being relayed, required when the sponsor policy restricts
+    /// the senders it sponsors, when the gas limit is derived or when calldata is priced.
+    /// CHECK: Validated to match `outgoing_message`; owner checked and deserialized in handler.
+    #[account(address = outgoing_message @ RelayerError::InvalidOutgoingMessage)]
+    pub outgoing_message_account: Option<UncheckedAccount<'info>>,
+
     /// System program required for creating new accounts.
     /// Used internally by Anchor for account initialization.
     pub system_program: Program<'info, System>,
@@ -39,38 +90,417 @@ pub fn pay_for_relay_handler(
     _mtr_salt: [u8; 32],
     outgoing_message: Pubkey,
     gas_limit: u64,
+    tip_lamports: u64,
 ) -> Result<()> {
-    check_and_pay_for_gas(
+    let relayed_message = ctx
+        .accounts
+        .outgoing_message_account
+        .as_ref()
+        .map(|outgoing_message_account| load_outgoing_message(outgoing_message_account))
+        .transpose()?;
+
+    let gas_limit = match gas_limit {
+        0 => derive_gas_limit(relayed_message.as_ref(), &ctx.accounts.cfg.gas_config)?,
+        gas_limit => gas_limit,
+    };
+
+    let calldata_gas = calldata_gas(&ctx.accounts.calldata_pricing, relayed_message.as_ref())?;
+
+    // Exemptions apply to the message sender, only known from the outgoing message account
+    let fee_exempt = match &relayed_message {
+        Some(message) => is_fee_exempt(&ctx.accounts.fee_exemptions, &message.sender)?,
+        None => false,
+    };
+    let gas_cost = check_and_pay_for_gas(
         &ctx.accounts.system_program,
         &ctx.accounts.payer,
         &ctx.accounts.gas_fee_receiver,
         &mut ctx.accounts.cfg,
         gas_limit,
+        calldata_gas,
+        fee_exempt,
+    )?;
+
+    enforce_sponsor_policy(
+        &ctx.accounts.sponsor_policy,
+        relayed_message.as_ref(),
+        gas_cost.saturating_add(tip_lamports),
     )?;
 
+    check_nonce_gap(
+        &ctx.accounts.nonce_monitor,
+        &ctx.accounts.bridge,
+        outgoing_message,
+        ctx.accounts.cfg.nonce,
+    )?;
+
+    // Tips are settled against the bridge confirmation of the outgoing message nonce
+    let outgoing_nonce = relayed_message.as_ref().map(|message| message.nonce);
+    require!(
+        tip_lamports == 0 || outgoing_nonce.is_some(),
+        RelayerError::MissingOutgoingMessage
+    );
+
+    // The tip is escrowed on top of rent in the message account itself until settled
+    if tip_lamports > 0 {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: ctx.accounts.message_to_relay.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_ctx, tip_lamports)?;
+    }
+
     *ctx.accounts.message_to_relay = MessageToRelay {
         nonce: ctx.accounts.cfg.nonce,
         outgoing_message,
         gas_limit,
+        tip_lamports,
+        payer: ctx.accounts.payer.key(),
+        outgoing_nonce,
+        created_at: Clock::get()?.unix_timestamp,
     };
     ctx.accounts.cfg.nonce += 1;
 
+    PendingRelayIndex::update(&ctx.accounts.pending_relay_index, |index| {
+        index.insert(ctx.accounts.message_to_relay.key(), tip_lamports)
+    })?;
+
+    Ok(())
+}
+
+/// Returns whether `sender` is on the fee exemption list, if the guardian created one.
+fn is_fee_exempt(fee_exemptions_info: &AccountInfo, sender: &Pubkey) -> Result<bool> {
+    if *fee_exemptions_info.owner != ID {
+        return Ok(false);
+    }
+
+    let fee_exemptions =
+        FeeExemptions::try_deserialize(&mut &fee_exemptions_info.data.borrow()[..])?;
+    Ok(fee_exemptions.is_exempt(sender))
+}
+
+/// Enforces the payer's sponsor policy, if any, recording `lamports` against its spend window.
+fn enforce_sponsor_policy(
+    sponsor_policy_info: &AccountInfo,
+    outgoing_message: Option<&OutgoingMessage>,
+    lamports: u64,
+) -> Result<()> {
+    // Payers that never configured a policy are not restricted
+    if *sponsor_policy_info.owner != ID {
+        return Ok(());
+    }
+
+    let mut data = sponsor_policy_info.try_borrow_mut_data()?;
+    let mut sponsor_policy = SponsorPolicy::try_deserialize(&mut &data[..])?;
+
+    if sponsor_policy.has_allow_list() {
+        let outgoing_message = outgoing_message.ok_or(RelayerError::MissingOutgoingMessage)?;
+        require!(
+            sponsor_policy.is_sponsored(&outgoing_message.sender),
+            RelayerError::SenderNotSponsored
+        );
+    }
+
+    sponsor_policy.record_spend(lamports, Clock::get()?.unix_timestamp)?;
+    sponsor_policy.try_serialize(&mut &mut data[..])?;
+
+    Ok(())
+}
+
+/// Compares the relayer nonce with the bridge nonce, emitting a `NonceAnomaly` (and flagging the
+/// monitor as degraded if configured) when they diverge by more than the tolerated gap. Both
+/// nonces are read from program state, so the payer cannot forge an anomaly. Does nothing until
+/// the guardian creates the nonce monitor.
+fn check_nonce_gap(
+    nonce_monitor_info: &AccountInfo,
+    bridge_info: &AccountInfo,
+    outgoing_message: Pubkey,
+    relayer_nonce: u64,
+) -> Result<()> {
+    if *nonce_monitor_info.owner != ID {
+        return Ok(());
+    }
+
+    let mut data = nonce_monitor_info.try_borrow_mut_data()?;
+    let mut nonce_monitor = NonceMonitor::try_deserialize(&mut &data[..])?;
+
+    let bridge_nonce = Bridge::nonce_in(bridge_info)?;
+    let Some(gap) = nonce_monitor.anomaly(relayer_nonce, bridge_nonce) else {
+        return Ok(());
+    };
+
+    if nonce_monitor.degrade_on_anomaly && !nonce_monitor.degraded {
+        nonce_monitor.degraded = true;
+        nonce_monitor.try_serialize(&mut &mut data[..])?;
+    }
+
+    emit!(NonceAnomaly {
+        outgoing_message,
+        relayer_nonce,
+        bridge_nonce,
+        gap,
+        degraded: nonce_monitor.degraded,
+    });
+
     Ok(())
 }
 
+/// Deserializes the bridge `OutgoingMessage` being relayed, checking it is owned by the bridge.
+fn load_outgoing_message(outgoing_message_info: &AccountInfo) -> Result<OutgoingMessage> {
+    require_keys_eq!(
+        *outgoing_message_info.owner,
+        BRIDGE_PROGRAM_ID,
+        RelayerError::InvalidOutgoingMessage
+    );
+
+    OutgoingMessage::try_deserialize(&mut &outgoing_message_info.try_borrow_data()?[..])
+        .map_err(|_| error!(RelayerError::InvalidOutgoingMessage))
+}
+
+/// Returns the calldata gas charged for the payload of the relayed bridge `OutgoingMessage`, or 0
+/// until the guardian creates the calldata pricing. Once it exists, the message is required so
+/// that large payloads cannot be relayed without paying for their calldata.
+fn calldata_gas(
+    calldata_pricing_info: &AccountInfo,
+    outgoing_message: Option<&OutgoingMessage>,
+) -> Result<u64> {
+    let Some(calldata_pricing) = CalldataPricing::load(calldata_pricing_info)? else {
+        return Ok(0);
+    };
+
+    let outgoing_message = outgoing_message.ok_or(RelayerError::MissingOutgoingMessage)?;
+    let payload_len = MessageKind::from(&outgoing_message.message).space();
+    Ok(calldata_pricing.calldata_gas(payload_len as u64))
+}
+
+/// Derives the gas limit of a simple transfer from its bridge `OutgoingMessage`, using the gas
+/// limits configured by the guardian. Transfers without call and transfer batches only mint or
+/// unlock tokens on Base, so their cost is known upfront; calls depend on their target and always
+/// need an explicit gas limit.
+fn derive_gas_limit(
+    outgoing_message: Option<&OutgoingMessage>,
+    gas_config: &GasConfig,
+) -> Result<u64> {
+    let outgoing_message = outgoing_message.ok_or(RelayerError::MissingOutgoingMessage)?;
+
+    let gas_limit = match &outgoing_message.message {
+        Message::Transfer(transfer) if transfer.call.is_none() => {
+            gas_config.default_transfer_gas_limit
+        }
+        Message::TransferBatch(batch) => gas_config.default_transfer_gas_limit.saturating_add(
+            (batch.recipients.len() as u64)
+                .saturating_sub(1)
+                .saturating_mul(gas_config.default_transfer_batch_recipient_gas_limit),
+        ),
+        Message::Transfer(_) | Message::Call(_) | Message::Encrypted(_) => {
+            return err!(RelayerError::GasLimitNotDerivable)
+        }
+    };
+
+    Ok(gas_limit.max(gas_config.min_gas_limit_per_message))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::{setup_relayer, SetupRelayerResult, TEST_GAS_FEE_RECEIVER};
-    use crate::{accounts, state::MessageToRelay};
+    use crate::test_utils::{
+        bridge_pda, calldata_pricing_pda, fee_exemptions_pda, mock_bridge, mock_calldata_pricing,
+        mock_clock, mock_nonce_monitor, mock_outgoing_message, mock_outgoing_message_with,
+        nonce_monitor_pda, pending_relay_index_pda, setup_relayer, sponsor_policy_pda,
+        SetupRelayerResult, TEST_GAS_FEE_RECEIVER,
+    };
+    use crate::{accounts, constants::SPONSOR_POLICY_WINDOW_SECONDS, state::MessageToRelay};
     use anchor_lang::{
         solana_program::{instruction::Instruction, system_program},
         InstructionData,
     };
+    use bridge::solana_to_base::{BatchRecipient, Call, CallType, TransferBatch};
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
     use solana_message::Message;
     use solana_signer::Signer;
     use solana_transaction::Transaction;
 
+    const GAS_LIMIT: u64 = 100_000;
+
+    fn set_sponsor_policy(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        daily_limit_lamports: u64,
+        sponsored_senders: Vec<Pubkey>,
+    ) {
+        let ix = Instruction {
+            program_id: crate::ID,
+            accounts: accounts::SetSponsorPolicy {
+                payer: payer.pubkey(),
+                sponsor_policy: sponsor_policy_pda(&payer.pubkey()),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crate::instruction::SetSponsorPolicy {
+                daily_limit_lamports,
+                sponsored_senders,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("failed to set sponsor policy");
+    }
+
+    fn set_fee_exemptions(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        cfg_pda: Pubkey,
+        senders: Vec<Pubkey>,
+    ) {
+        let ix = Instruction {
+            program_id: crate::ID,
+            accounts: accounts::SetFeeExemptions {
+                guardian: guardian.pubkey(),
+                cfg: cfg_pda,
+                fee_exemptions: fee_exemptions_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crate::instruction::SetFeeExemptions { senders }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("failed to set fee exemptions");
+    }
+
+    fn pay_for_relay(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        cfg_pda: Pubkey,
+        outgoing_message: Pubkey,
+        outgoing_message_account: Option<Pubkey>,
+    ) -> std::result::Result<(), String> {
+        pay_for_relay_with_gas_limit(
+            svm,
+            payer,
+            cfg_pda,
+            outgoing_message,
+            outgoing_message_account,
+            GAS_LIMIT,
+        )
+        .map(|_| ())
+    }
+
+    /// Pays for relaying `outgoing_message` and returns the created `MessageToRelay` address.
+    fn pay_for_relay_with_gas_limit(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        cfg_pda: Pubkey,
+        outgoing_message: Pubkey,
+        outgoing_message_account: Option<Pubkey>,
+        gas_limit: u64,
+    ) -> std::result::Result<Pubkey, String> {
+        let mtr_salt = Pubkey::new_unique().to_bytes();
+        let (message_to_relay, _) = Pubkey::find_program_address(
+            &[crate::constants::MTR_SEED, mtr_salt.as_ref()],
+            &crate::ID,
+        );
+
+        let ix = Instruction {
+            program_id: crate::ID,
+            accounts: accounts::PayForRelay {
+                payer: payer.pubkey(),
+                cfg: cfg_pda,
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                message_to_relay,
+                fee_exemptions: fee_exemptions_pda(),
+                sponsor_policy: sponsor_policy_pda(&payer.pubkey()),
+                nonce_monitor: nonce_monitor_pda(),
+                bridge: bridge_pda(),
+                pending_relay_index: pending_relay_index_pda(),
+                calldata_pricing: calldata_pricing_pda(),
+                outgoing_message_account,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: crate::instruction::PayForRelay {
+                mtr_salt,
+                outgoing_message,
+                gas_limit,
+                tip_lamports: 0,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| message_to_relay)
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    /// Returns a bridge `Message::Call` carrying `data_len` bytes of calldata.
+    fn call_message(data_len: usize) -> bridge::solana_to_base::Message {
+        bridge::solana_to_base::Message::Call(call(data_len))
+    }
+
+    fn call(data_len: usize) -> Call {
+        Call {
+            ty: CallType::Call,
+            to: [1u8; 20],
+            value: 0,
+            data: vec![1u8; data_len],
+        }
+    }
+
+    /// Returns a bridge `Message::Transfer`, with a call if `with_call` is set.
+    fn transfer_message(with_call: bool) -> bridge::solana_to_base::Message {
+        bridge::solana_to_base::Message::Transfer(bridge::solana_to_base::Transfer {
+            to: [1u8; 20],
+            local_token: Pubkey::new_unique(),
+            remote_token: [2u8; 20],
+            amount: 1_000,
+            call: with_call.then(|| call(4)),
+            memo: None,
+            swap_guard: None,
+        })
+    }
+
+    /// Returns a bridge `Message::TransferBatch` with `recipients` recipients.
+    fn transfer_batch_message(recipients: usize) -> bridge::solana_to_base::Message {
+        bridge::solana_to_base::Message::TransferBatch(TransferBatch {
+            local_token: Pubkey::new_unique(),
+            remote_token: [2u8; 20],
+            recipients: vec![
+                BatchRecipient {
+                    to: [1u8; 20],
+                    amount: 1_000,
+                };
+                recipients
+            ],
+        })
+    }
+
+    fn read_gas_limit(svm: &LiteSVM, message_to_relay: Pubkey) -> u64 {
+        let account = svm.get_account(&message_to_relay).unwrap();
+        MessageToRelay::try_deserialize(&mut &account.data[..])
+            .unwrap()
+            .gas_limit
+    }
+
     #[test]
     fn pay_for_relay_initializes_message_and_transfers_gas() {
         let SetupRelayerResult {
@@ -100,6 +530,13 @@ mod tests {
             cfg: cfg_pda,
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
             message_to_relay,
+            fee_exemptions: fee_exemptions_pda(),
+            sponsor_policy: sponsor_policy_pda(&payer_pk),
+            nonce_monitor: nonce_monitor_pda(),
+            bridge: bridge_pda(),
+            pending_relay_index: pending_relay_index_pda(),
+            calldata_pricing: calldata_pricing_pda(),
+            outgoing_message_account: None,
             system_program: system_program::ID,
         }
         .to_account_metas(None);
@@ -111,6 +548,7 @@ mod tests {
                 mtr_salt,
                 outgoing_message,
                 gas_limit,
+                tip_lamports: 0,
             }
             .data(),
         };
@@ -129,9 +567,316 @@ mod tests {
         let msg = MessageToRelay::try_deserialize(&mut &msg_account.data[..]).unwrap();
         assert_eq!(msg.outgoing_message, outgoing_message);
         assert_eq!(msg.gas_limit, gas_limit);
+        assert_eq!(msg.tip_lamports, 0);
+        assert_eq!(msg.payer, payer_pk);
+        assert_eq!(msg.outgoing_nonce, None);
 
         // With base_fee = 1 in tests, gas_cost == gas_limit
         let final_receiver_balance = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
         assert_eq!(final_receiver_balance - initial_receiver_balance, gas_limit);
     }
+
+    #[test]
+    fn pay_for_relay_charges_calldata_of_large_payloads() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, 1).unwrap();
+        mock_calldata_pricing(&mut svm, 1_000, 40);
+
+        // The payload size is required once calldata is priced
+        let error =
+            pay_for_relay(&mut svm, &payer, cfg_pda, Pubkey::new_unique(), None).unwrap_err();
+        assert!(
+            error.contains("MissingOutgoingMessage"),
+            "Expected MissingOutgoingMessage error, got: {}",
+            error
+        );
+
+        // Payloads within the free allowance only pay for their gas limit (a call message adds 42
+        // bytes to its calldata)
+        let small =
+            mock_outgoing_message_with(&mut svm, 0, Pubkey::new_unique(), call_message(958));
+        let initial_receiver_balance = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
+        pay_for_relay(&mut svm, &payer, cfg_pda, small, Some(small)).unwrap();
+        let receiver_balance = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
+        assert_eq!(receiver_balance - initial_receiver_balance, GAS_LIMIT);
+
+        // With base_fee = 1 in tests, each byte beyond the allowance costs 40 lamports
+        let large =
+            mock_outgoing_message_with(&mut svm, 1, Pubkey::new_unique(), call_message(2_958));
+        let message_to_relay =
+            pay_for_relay_with_gas_limit(&mut svm, &payer, cfg_pda, large, Some(large), GAS_LIMIT)
+                .unwrap();
+        assert_eq!(
+            svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports - receiver_balance,
+            GAS_LIMIT + 2_000 * 40
+        );
+        assert_eq!(read_gas_limit(&svm, message_to_relay), GAS_LIMIT);
+    }
+
+    #[test]
+    fn pay_for_relay_enforces_sponsor_daily_limit() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, 1).unwrap();
+
+        // With base_fee = 1 in tests, each relay costs GAS_LIMIT lamports
+        set_sponsor_policy(&mut svm, &payer, GAS_LIMIT * 2, vec![]);
+
+        for _ in 0..2 {
+            pay_for_relay(&mut svm, &payer, cfg_pda, Pubkey::new_unique(), None)
+                .expect("relay within the daily limit should succeed");
+        }
+
+        let error_string = pay_for_relay(&mut svm, &payer, cfg_pda, Pubkey::new_unique(), None)
+            .expect_err("relay above the daily limit should fail");
+        assert!(
+            error_string.contains("SponsorSpendLimitExceeded"),
+            "Expected SponsorSpendLimitExceeded error, got: {}",
+            error_string
+        );
+
+        // A new window restores the full daily limit
+        let clock = svm.get_sysvar::<Clock>();
+        mock_clock(
+            &mut svm,
+            clock.unix_timestamp + SPONSOR_POLICY_WINDOW_SECONDS,
+        );
+        pay_for_relay(&mut svm, &payer, cfg_pda, Pubkey::new_unique(), None)
+            .expect("relay in a new window should succeed");
+
+        let account = svm
+            .get_account(&sponsor_policy_pda(&payer.pubkey()))
+            .unwrap();
+        let policy = SponsorPolicy::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(policy.window_spent_lamports, GAS_LIMIT);
+    }
+
+    #[test]
+    fn pay_for_relay_enforces_sponsored_senders() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, 1).unwrap();
+
+        let sponsored_sender = Pubkey::new_unique();
+        set_sponsor_policy(&mut svm, &payer, 0, vec![sponsored_sender]);
+
+        let sponsored_message = mock_outgoing_message(&mut svm, 0, sponsored_sender);
+        pay_for_relay(
+            &mut svm,
+            &payer,
+            cfg_pda,
+            sponsored_message,
+            Some(sponsored_message),
+        )
+        .expect("relay of a sponsored sender should succeed");
+
+        let error_string = pay_for_relay(&mut svm, &payer, cfg_pda, sponsored_message, None)
+            .expect_err("relay without the outgoing message account should fail");
+        assert!(
+            error_string.contains("MissingOutgoingMessage"),
+            "Expected MissingOutgoingMessage error, got: {}",
+            error_string
+        );
+
+        let other_message = mock_outgoing_message(&mut svm, 1, Pubkey::new_unique());
+        let error_string = pay_for_relay(
+            &mut svm,
+            &payer,
+            cfg_pda,
+            other_message,
+            Some(other_message),
+        )
+        .expect_err("relay of an unsponsored sender should fail");
+        assert!(
+            error_string.contains("SenderNotSponsored"),
+            "Expected SenderNotSponsored error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn pay_for_relay_skips_gas_fee_for_exempt_sender() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+        } = setup_relayer();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, 1).unwrap();
+        let initial_receiver_balance = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
+
+        // A keeper PDA sends the message while an unlisted payer signs the relay request
+        let keeper = Pubkey::new_unique();
+        set_fee_exemptions(&mut svm, &guardian, cfg_pda, vec![keeper]);
+        let outgoing_message = mock_outgoing_message(&mut svm, 0, keeper);
+
+        pay_for_relay(
+            &mut svm,
+            &payer,
+            cfg_pda,
+            outgoing_message,
+            Some(outgoing_message),
+        )
+        .expect("relay of an exempt sender should succeed");
+
+        // No gas fee was paid, but the gas usage was recorded
+        let final_receiver_balance = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
+        assert_eq!(final_receiver_balance, initial_receiver_balance);
+
+        let cfg_account = svm.get_account(&cfg_pda).unwrap();
+        let cfg = Cfg::try_deserialize(&mut &cfg_account.data[..]).unwrap();
+        assert_eq!(cfg.eip1559.current_window_gas_used, GAS_LIMIT);
+    }
+
+    #[test]
+    fn pay_for_relay_charges_gas_fee_for_exempt_payer() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+        } = setup_relayer();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, 1).unwrap();
+        let initial_receiver_balance = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
+
+        // Listing the payer does not exempt the messages of other senders it pays for
+        set_fee_exemptions(&mut svm, &guardian, cfg_pda, vec![payer.pubkey()]);
+        let outgoing_message = mock_outgoing_message(&mut svm, 0, Pubkey::new_unique());
+
+        pay_for_relay(
+            &mut svm,
+            &payer,
+            cfg_pda,
+            outgoing_message,
+            Some(outgoing_message),
+        )
+        .expect("relay of a non-exempt sender should succeed");
+
+        let final_receiver_balance = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
+        assert!(final_receiver_balance > initial_receiver_balance);
+    }
+
+    #[test]
+    fn pay_for_relay_flags_nonce_anomaly() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, 1).unwrap();
+        mock_nonce_monitor(&mut svm, 2, true, false);
+
+        let read_nonce_monitor = |svm: &LiteSVM| {
+            let account = svm.get_account(&nonce_monitor_pda()).unwrap();
+            NonceMonitor::try_deserialize(&mut &account.data[..]).unwrap()
+        };
+
+        // Relayer nonce 0 vs bridge nonce 2: within the tolerated gap, whatever the nonce of the
+        // outgoing message chosen by the payer
+        mock_bridge(&mut svm, 2);
+        let outgoing_message = mock_outgoing_message(&mut svm, 100, Pubkey::new_unique());
+        pay_for_relay(
+            &mut svm,
+            &payer,
+            cfg_pda,
+            outgoing_message,
+            Some(outgoing_message),
+        )
+        .expect("relay within the tolerated nonce gap should succeed");
+        assert!(!read_nonce_monitor(&svm).degraded);
+
+        // Relayer nonce 1 vs bridge nonce 10: the relay still succeeds but flags the monitor
+        mock_bridge(&mut svm, 10);
+        pay_for_relay(&mut svm, &payer, cfg_pda, Pubkey::new_unique(), None)
+            .expect("relay with a nonce anomaly should succeed");
+        assert!(read_nonce_monitor(&svm).degraded);
+    }
+
+    #[test]
+    fn pay_for_relay_derives_gas_limit_for_transfers() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, 1).unwrap();
+        let gas_config = GasConfig::test_new(TEST_GAS_FEE_RECEIVER);
+
+        let transfer =
+            mock_outgoing_message_with(&mut svm, 0, Pubkey::new_unique(), transfer_message(false));
+        let message_to_relay =
+            pay_for_relay_with_gas_limit(&mut svm, &payer, cfg_pda, transfer, Some(transfer), 0)
+                .expect("gas limit of a transfer should be derived");
+        assert_eq!(
+            read_gas_limit(&svm, message_to_relay),
+            gas_config.default_transfer_gas_limit
+        );
+
+        let batch = mock_outgoing_message_with(
+            &mut svm,
+            1,
+            Pubkey::new_unique(),
+            transfer_batch_message(3),
+        );
+        let message_to_relay =
+            pay_for_relay_with_gas_limit(&mut svm, &payer, cfg_pda, batch, Some(batch), 0)
+                .expect("gas limit of a transfer batch should be derived");
+        assert_eq!(
+            read_gas_limit(&svm, message_to_relay),
+            gas_config.default_transfer_gas_limit
+                + 2 * gas_config.default_transfer_batch_recipient_gas_limit
+        );
+    }
+
+    #[test]
+    fn pay_for_relay_rejects_underivable_gas_limit() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, 1).unwrap();
+
+        let error_string =
+            pay_for_relay_with_gas_limit(&mut svm, &payer, cfg_pda, Pubkey::new_unique(), None, 0)
+                .expect_err("deriving the gas limit without the message should fail");
+        assert!(
+            error_string.contains("MissingOutgoingMessage"),
+            "Expected MissingOutgoingMessage error, got: {}",
+            error_string
+        );
+
+        let transfer_and_call =
+            mock_outgoing_message_with(&mut svm, 0, Pubkey::new_unique(), transfer_message(true));
+        let error_string = pay_for_relay_with_gas_limit(
+            &mut svm,
+            &payer,
+            cfg_pda,
+            transfer_and_call,
+            Some(transfer_and_call),
+            0,
+        )
+        .expect_err("deriving the gas limit of a call should fail");
+        assert!(
+            error_string.contains("GasLimitNotDerivable"),
+            "Expected GasLimitNotDerivable error, got: {}",
+            error_string
+        );
+    }
 }
@@ -0,0 +1,213 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{PENDING_RELAY_INDEX_SEED, RELAY_TIP_REFUND_DELAY_SECONDS},
+    state::{MessageToRelay, PendingRelayIndex},
+    RelayerError,
+};
+
+#[derive(Accounts)]
+pub struct RefundRelayTip<'info> {
+    /// The paid relay request holding the escrowed tip.
+    #[account(mut, has_one = payer @ RelayerError::IncorrectRelayPayer)]
+    pub message_to_relay: Account<'info, MessageToRelay>,
+
+    /// The payer of the relay request, receiving the refunded tip.
+    #[account(mut)]
+    pub payer: SystemAccount<'info>,
+
+    /// The bridge `RelayedNonces` PDA covering the nonce of the relayed outgoing message.
+    /// CHECK: Address validated against the outgoing nonce in the handler.
+    pub relayed_nonces: UncheckedAccount<'info>,
+
+    /// The guardian-created index of the pending relay requests by fee bucket.
+    /// CHECK: Validated by seeds and only deserialized when owned by this program.
+    #[account(mut, seeds = [PENDING_RELAY_INDEX_SEED], bump)]
+    pub pending_relay_index: UncheckedAccount<'info>,
+}
+
+/// Returns the tip escrowed in `message_to_relay` to its payer when the bridge has not confirmed
+/// the message as executed on Base `RELAY_TIP_REFUND_DELAY_SECONDS` after it was paid for.
+/// Permissionless; the tip can only be refunded once.
+pub fn refund_relay_tip_handler(ctx: Context<RefundRelayTip>) -> Result<()> {
+    let message_to_relay = &ctx.accounts.message_to_relay;
+    let tip_lamports = message_to_relay.tip_lamports;
+    require!(tip_lamports > 0, RelayerError::NoRelayTip);
+    require!(
+        Clock::get()?.unix_timestamp
+            >= message_to_relay
+                .created_at
+                .saturating_add(RELAY_TIP_REFUND_DELAY_SECONDS),
+        RelayerError::RelayTipRefundNotDue
+    );
+    require!(
+        !message_to_relay.is_relayed(&ctx.accounts.relayed_nonces)?,
+        RelayerError::RelayAlreadyConfirmed
+    );
+
+    ctx.accounts.message_to_relay.tip_lamports = 0;
+    ctx.accounts.message_to_relay.sub_lamports(tip_lamports)?;
+    ctx.accounts.payer.add_lamports(tip_lamports)?;
+
+    PendingRelayIndex::update(&ctx.accounts.pending_relay_index, |index| {
+        index.remove(&ctx.accounts.message_to_relay.key())
+    })?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, system_program},
+        InstructionData,
+    };
+    use bridge::solana_to_base::RelayedNonces;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{accounts, constants::MTR_SEED, instruction, test_utils::*, ID};
+
+    const TIP: u64 = 1_000_000;
+    const OUTGOING_NONCE: u64 = 7;
+    const PAID_AT: i64 = 1_747_440_000;
+
+    fn pay_for_relay_with_tip(
+        svm: &mut litesvm::LiteSVM,
+        payer: &Keypair,
+        cfg_pda: Pubkey,
+    ) -> Pubkey {
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, 1).unwrap();
+
+        let mtr_salt = Pubkey::new_unique().to_bytes();
+        let (message_to_relay, _) =
+            Pubkey::find_program_address(&[MTR_SEED, mtr_salt.as_ref()], &ID);
+        let outgoing_message = mock_outgoing_message(svm, OUTGOING_NONCE, Pubkey::new_unique());
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::PayForRelay {
+                payer: payer.pubkey(),
+                cfg: cfg_pda,
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                message_to_relay,
+                fee_exemptions: fee_exemptions_pda(),
+                sponsor_policy: sponsor_policy_pda(&payer.pubkey()),
+                nonce_monitor: nonce_monitor_pda(),
+                bridge: bridge_pda(),
+                pending_relay_index: pending_relay_index_pda(),
+                calldata_pricing: calldata_pricing_pda(),
+                outgoing_message_account: Some(outgoing_message),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::PayForRelay {
+                mtr_salt,
+                outgoing_message,
+                gas_limit: 123_456,
+                tip_lamports: TIP,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+
+        message_to_relay
+    }
+
+    fn refund_relay_tip(
+        svm: &mut litesvm::LiteSVM,
+        caller: &Keypair,
+        message_to_relay: Pubkey,
+        payer: Pubkey,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::RefundRelayTip {
+                message_to_relay,
+                payer,
+                relayed_nonces: relayed_nonces_pda(RelayedNonces::page_of(OUTGOING_NONCE)),
+                pending_relay_index: pending_relay_index_pda(),
+            }
+            .to_account_metas(None),
+            data: instruction::RefundRelayTip {}.data(),
+        };
+        let tx = Transaction::new(
+            &[caller],
+            Message::new(&[ix], Some(&caller.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_refund_relay_tip_after_delay() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+        } = setup_relayer();
+
+        let message_to_relay = pay_for_relay_with_tip(&mut svm, &payer, cfg_pda);
+
+        mock_clock(&mut svm, PAID_AT + RELAY_TIP_REFUND_DELAY_SECONDS - 1);
+        let error_string =
+            refund_relay_tip(&mut svm, &guardian, message_to_relay, payer.pubkey()).unwrap_err();
+        assert!(
+            error_string.contains("RelayTipRefundNotDue"),
+            "Expected RelayTipRefundNotDue error, got: {}",
+            error_string
+        );
+
+        let payer_balance = svm.get_account(&payer.pubkey()).unwrap().lamports;
+        mock_clock(&mut svm, PAID_AT + RELAY_TIP_REFUND_DELAY_SECONDS);
+        svm.expire_blockhash();
+        refund_relay_tip(&mut svm, &guardian, message_to_relay, payer.pubkey())
+            .expect("Unrelayed message tip should be refunded");
+
+        assert_eq!(
+            svm.get_account(&payer.pubkey()).unwrap().lamports,
+            payer_balance + TIP
+        );
+        let mtr_account = svm.get_account(&message_to_relay).unwrap();
+        let mtr = MessageToRelay::try_deserialize(&mut &mtr_account.data[..]).unwrap();
+        assert_eq!(mtr.tip_lamports, 0);
+    }
+
+    #[test]
+    fn test_refund_relay_tip_of_relayed_message_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            guardian,
+            cfg_pda,
+        } = setup_relayer();
+
+        let message_to_relay = pay_for_relay_with_tip(&mut svm, &payer, cfg_pda);
+        mock_relayed_nonces(
+            &mut svm,
+            RelayedNonces::page_of(OUTGOING_NONCE),
+            &[OUTGOING_NONCE],
+        );
+
+        mock_clock(&mut svm, PAID_AT + RELAY_TIP_REFUND_DELAY_SECONDS);
+        let error_string =
+            refund_relay_tip(&mut svm, &guardian, message_to_relay, payer.pubkey()).unwrap_err();
+        assert!(
+            error_string.contains("RelayAlreadyConfirmed"),
+            "Expected RelayAlreadyConfirmed error, got: {}",
+            error_string
+        );
+    }
+}
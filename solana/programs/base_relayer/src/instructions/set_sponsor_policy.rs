@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{DISCRIMINATOR_LEN, MAX_SPONSORED_SENDERS, SPONSOR_POLICY_SEED},
+    state::SponsorPolicy,
+    RelayerError,
+};
+
+/// Accounts for the set_sponsor_policy instruction that lets a payer restrict what it sponsors
+/// through `pay_for_relay`. The policy is owned by the payer; the guardian has no say over it.
+#[derive(Accounts)]
+pub struct SetSponsorPolicy<'info> {
+    /// The payer configuring its own policy. Funds the policy account creation.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The payer's sponsor policy.
+    /// - Uses PDA with SPONSOR_POLICY_SEED and the payer key for deterministic address
+    /// - Created on first use and updated in place afterwards
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [SPONSOR_POLICY_SEED, payer.key().as_ref()],
+        bump,
+        space = DISCRIMINATOR_LEN + SponsorPolicy::INIT_SPACE
+    )]
+    pub sponsor_policy: Account<'info, SponsorPolicy>,
+
+    /// System program required for creating new accounts.
+    /// Used internally by Anchor for account initialization.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_sponsor_policy_handler(
+    ctx: Context<SetSponsorPolicy>,
+    daily_limit_lamports: u64,
+    sponsored_senders: Vec<Pubkey>,
+) -> Result<()> {
+    require!(
+        sponsored_senders.len() <= MAX_SPONSORED_SENDERS as usize,
+        RelayerError::TooManySponsoredSenders
+    );
+
+    let sponsor_policy = &mut ctx.accounts.sponsor_policy;
+    sponsor_policy.payer = ctx.accounts.payer.key();
+    sponsor_policy.daily_limit_lamports = daily_limit_lamports;
+    sponsor_policy.sponsored_sender_count = sponsored_senders.len() as u8;
+    sponsor_policy.sponsored_senders = [Pubkey::default(); MAX_SPONSORED_SENDERS as usize];
+    sponsor_policy.sponsored_senders[..sponsored_senders.len()].copy_from_slice(&sponsored_senders);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, system_program},
+        InstructionData,
+    };
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{accounts, instruction, test_utils::*, ID};
+
+    #[test]
+    fn test_set_sponsor_policy_creates_and_updates_policy() {
+        let SetupRelayerResult { mut svm, payer, .. } = setup_relayer();
+        let payer_pk = payer.pubkey();
+        let sponsor_policy = sponsor_policy_pda(&payer_pk);
+
+        let sender = Pubkey::new_unique();
+        for (daily_limit_lamports, sponsored_senders) in [(1_000, vec![sender]), (2_000, vec![])] {
+            let ix = Instruction {
+                program_id: ID,
+                accounts: accounts::SetSponsorPolicy {
+                    payer: payer_pk,
+                    sponsor_policy,
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: instruction::SetSponsorPolicy {
+                    daily_limit_lamports,
+                    sponsored_senders: sponsored_senders.clone(),
+                }
+                .data(),
+            };
+
+            let tx = Transaction::new(
+                &[&payer],
+                Message::new(&[ix], Some(&payer_pk)),
+                svm.latest_blockhash(),
+            );
+            svm.send_transaction(tx).unwrap();
+
+            let account = svm.get_account(&sponsor_policy).unwrap();
+            let policy = SponsorPolicy::try_deserialize(&mut &account.data[..]).unwrap();
+            assert_eq!(policy.payer, payer_pk);
+            assert_eq!(policy.daily_limit_lamports, daily_limit_lamports);
+            assert_eq!(
+                policy.sponsored_sender_count as usize,
+                sponsored_senders.len()
+            );
+            assert_eq!(policy.is_sponsored(&sender), !sponsored_senders.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_set_sponsor_policy_too_many_senders_fails() {
+        let SetupRelayerResult { mut svm, payer, .. } = setup_relayer();
+        let payer_pk = payer.pubkey();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetSponsorPolicy {
+                payer: payer_pk,
+                sponsor_policy: sponsor_policy_pda(&payer_pk),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::SetSponsorPolicy {
+                daily_limit_lamports: 0,
+                sponsored_senders: (0..=MAX_SPONSORED_SENDERS)
+                    .map(|_| Pubkey::new_unique())
+                    .collect(),
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer_pk)),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("TooManySponsoredSenders"),
+            "Expected TooManySponsoredSenders error, got: {}",
+            error_string
+        );
+    }
+}
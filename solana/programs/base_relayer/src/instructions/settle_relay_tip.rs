@@ -0,0 +1,366 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::PENDING_RELAY_INDEX_SEED,
+    state::{MessageToRelay, PendingRelayIndex},
+    RelayerError,
+};
+
+#[derive(Accounts)]
+pub struct SettleRelayTip<'info> {
+    /// The paid relay request holding the escrowed tip.
+    #[account(mut)]
+    pub message_to_relay: Account<'info, MessageToRelay>,
+
+    /// The account of the relayer that executed the message on Base, receiving the tip.
+    /// CHECK: Validated against the relay execution recorded by the bridge in the handler.
+    #[account(mut)]
+    pub relayer: AccountInfo<'info>,
+
+    /// The bridge `RelayedNonces` PDA covering the nonce of the relayed outgoing message.
+    /// CHECK: Address validated against the outgoing nonce in the handler.
+    pub relayed_nonces: UncheckedAccount<'info>,
+
+    /// The bridge `RelayExecution` PDA recording the relayer that executed the outgoing message.
+    /// CHECK: Address validated against the outgoing nonce in the handler.
+    pub relay_execution: UncheckedAccount<'info>,
+
+    /// The guardian-created index of the pending relay requests by fee bucket.
+    /// CHECK: Validated by seeds and only deserialized when owned by this program.
+    #[account(mut, seeds = [PENDING_RELAY_INDEX_SEED], bump)]
+    pub pending_relay_index: UncheckedAccount<'info>,
+}
+
+/// Releases the tip escrowed in `message_to_relay` to the relayer once the bridge confirmed, from
+/// the oracle attestation recorded in its `RelayedNonces`, that the message was executed on Base.
+/// The tip goes to the relayer the oracles attested as executing the message in its bridge
+/// `RelayExecution` record. Permissionless; the tip can only be settled once.
+pub fn settle_relay_tip_handler(ctx: Context<SettleRelayTip>) -> Result<()> {
+    let tip_lamports = ctx.accounts.message_to_relay.tip_lamports;
+    require!(tip_lamports > 0, RelayerError::NoRelayTip);
+    require!(
+        ctx.accounts
+            .message_to_relay
+            .is_relayed(&ctx.accounts.relayed_nonces)?,
+        RelayerError::RelayNotConfirmed
+    );
+
+    let executing_relayer = ctx
+        .accounts
+        .message_to_relay
+        .executing_relayer(&ctx.accounts.relay_execution)?;
+    require_keys_eq!(
+        ctx.accounts.relayer.key(),
+        executing_relayer,
+        RelayerError::IncorrectRelayer
+    );
+
+    ctx.accounts.message_to_relay.tip_lamports = 0;
+    ctx.accounts.message_to_relay.sub_lamports(tip_lamports)?;
+    ctx.accounts.relayer.add_lamports(tip_lamports)?;
+
+    PendingRelayIndex::update(&ctx.accounts.pending_relay_index, |index| {
+        index.remove(&ctx.accounts.message_to_relay.key())
+    })?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, system_program},
+        InstructionData,
+    };
+    use bridge::solana_to_base::RelayedNonces;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{accounts, constants::MTR_SEED, instruction, test_utils::*, ID};
+
+    const TIP: u64 = 1_000_000;
+    const OUTGOING_NONCE: u64 = 7;
+
+    fn pay_for_relay_with_tip(
+        svm: &mut litesvm::LiteSVM,
+        payer: &Keypair,
+        cfg_pda: Pubkey,
+        tip_lamports: u64,
+    ) -> Pubkey {
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, 1).unwrap();
+
+        let mtr_salt = Pubkey::new_unique().to_bytes();
+        let (message_to_relay, _) =
+            Pubkey::find_program_address(&[MTR_SEED, mtr_salt.as_ref()], &ID);
+        let outgoing_message = mock_outgoing_message(svm, OUTGOING_NONCE, Pubkey::new_unique());
+
+        let accounts = accounts::PayForRelay {
+            payer: payer.pubkey(),
+            cfg: cfg_pda,
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            message_to_relay,
+            fee_exemptions: fee_exemptions_pda(),
+            sponsor_policy: sponsor_policy_pda(&payer.pubkey()),
+            nonce_monitor: nonce_monitor_pda(),
+            bridge: bridge_pda(),
+            pending_relay_index: pending_relay_index_pda(),
+            calldata_pricing: calldata_pricing_pda(),
+            outgoing_message_account: Some(outgoing_message),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: instruction::PayForRelay {
+                mtr_salt,
+                outgoing_message,
+                gas_limit: 123_456,
+                tip_lamports,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+
+        message_to_relay
+    }
+
+    fn settle_relay_tip(
+        svm: &mut litesvm::LiteSVM,
+        payer: &Keypair,
+        message_to_relay: Pubkey,
+        relayer: Pubkey,
+        relayed_nonces: Pubkey,
+        relay_execution: Pubkey,
+    ) -> std::result::Result<(), String> {
+        let accounts = accounts::SettleRelayTip {
+            message_to_relay,
+            relayer,
+            relayed_nonces,
+            relay_execution,
+            pending_relay_index: pending_relay_index_pda(),
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: instruction::SettleRelayTip {}.data(),
+        };
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_settle_relay_tip_pays_executing_relayer_once_relayed() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+
+        let message_to_relay = pay_for_relay_with_tip(&mut svm, &payer, cfg_pda, TIP);
+        let escrow_balance = svm.get_account(&message_to_relay).unwrap().lamports;
+        let receiver_balance = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
+
+        // The message was executed by a relayer other than the gas fee receiver
+        let relayer = Pubkey::new_unique();
+        let page = RelayedNonces::page_of(OUTGOING_NONCE);
+        mock_relayed_nonces(&mut svm, page, &[OUTGOING_NONCE]);
+        mock_relay_execution(&mut svm, OUTGOING_NONCE, relayer);
+        settle_relay_tip(
+            &mut svm,
+            &payer,
+            message_to_relay,
+            relayer,
+            relayed_nonces_pda(page),
+            relay_execution_pda(OUTGOING_NONCE),
+        )
+        .expect("Relayed message tip should be settled");
+
+        assert_eq!(svm.get_account(&relayer).unwrap().lamports, TIP);
+        assert_eq!(
+            svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports,
+            receiver_balance
+        );
+        let mtr_account = svm.get_account(&message_to_relay).unwrap();
+        assert_eq!(mtr_account.lamports, escrow_balance - TIP);
+        let mtr = MessageToRelay::try_deserialize(&mut &mtr_account.data[..]).unwrap();
+        assert_eq!(mtr.tip_lamports, 0);
+
+        // The tip can only be settled once
+        svm.expire_blockhash();
+        let error_string = settle_relay_tip(
+            &mut svm,
+            &payer,
+            message_to_relay,
+            relayer,
+            relayed_nonces_pda(page),
+            relay_execution_pda(OUTGOING_NONCE),
+        )
+        .unwrap_err();
+        assert!(
+            error_string.contains("NoRelayTip"),
+            "Expected NoRelayTip error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_settle_relay_tip_of_unconfirmed_message_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+
+        let message_to_relay = pay_for_relay_with_tip(&mut svm, &payer, cfg_pda, TIP);
+
+        let relayer = Pubkey::new_unique();
+        let page = RelayedNonces::page_of(OUTGOING_NONCE);
+        mock_relayed_nonces(&mut svm, page, &[OUTGOING_NONCE + 1]);
+        mock_relay_execution(&mut svm, OUTGOING_NONCE, relayer);
+        let error_string = settle_relay_tip(
+            &mut svm,
+            &payer,
+            message_to_relay,
+            relayer,
+            relayed_nonces_pda(page),
+            relay_execution_pda(OUTGOING_NONCE),
+        )
+        .unwrap_err();
+        assert!(
+            error_string.contains("RelayNotConfirmed"),
+            "Expected RelayNotConfirmed error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_settle_relay_tip_with_incorrect_relayed_nonces_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+
+        let message_to_relay = pay_for_relay_with_tip(&mut svm, &payer, cfg_pda, TIP);
+
+        let relayer = Pubkey::new_unique();
+        let other_page = RelayedNonces::page_of(OUTGOING_NONCE) + 1;
+        mock_relayed_nonces(&mut svm, other_page, &[]);
+        mock_relay_execution(&mut svm, OUTGOING_NONCE, relayer);
+        let error_string = settle_relay_tip(
+            &mut svm,
+            &payer,
+            message_to_relay,
+            relayer,
+            relayed_nonces_pda(other_page),
+            relay_execution_pda(OUTGOING_NONCE),
+        )
+        .unwrap_err();
+        assert!(
+            error_string.contains("IncorrectRelayedNonces"),
+            "Expected IncorrectRelayedNonces error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_settle_relay_tip_to_other_relayer_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+
+        let message_to_relay = pay_for_relay_with_tip(&mut svm, &payer, cfg_pda, TIP);
+
+        // The gas fee receiver cannot claim the tip of a message executed by another relayer
+        let page = RelayedNonces::page_of(OUTGOING_NONCE);
+        mock_relayed_nonces(&mut svm, page, &[OUTGOING_NONCE]);
+        mock_relay_execution(&mut svm, OUTGOING_NONCE, Pubkey::new_unique());
+        let error_string = settle_relay_tip(
+            &mut svm,
+            &payer,
+            message_to_relay,
+            TEST_GAS_FEE_RECEIVER,
+            relayed_nonces_pda(page),
+            relay_execution_pda(OUTGOING_NONCE),
+        )
+        .unwrap_err();
+        assert!(
+            error_string.contains("IncorrectRelayer"),
+            "Expected IncorrectRelayer error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_settle_relay_tip_without_relay_execution_fails() {
+        let SetupRelayerResult {
+            mut svm,
+            payer,
+            cfg_pda,
+            ..
+        } = setup_relayer();
+
+        let message_to_relay = pay_for_relay_with_tip(&mut svm, &payer, cfg_pda, TIP);
+
+        let relayer = Pubkey::new_unique();
+        let page = RelayedNonces::page_of(OUTGOING_NONCE);
+        mock_relayed_nonces(&mut svm, page, &[OUTGOING_NONCE]);
+
+        // The record of another nonce does not attest the executing relayer
+        mock_relay_execution(&mut svm, OUTGOING_NONCE + 1, relayer);
+        let error_string = settle_relay_tip(
+            &mut svm,
+            &payer,
+            message_to_relay,
+            relayer,
+            relayed_nonces_pda(page),
+            relay_execution_pda(OUTGOING_NONCE + 1),
+        )
+        .unwrap_err();
+        assert!(
+            error_string.contains("IncorrectRelayExecution"),
+            "Expected IncorrectRelayExecution error, got: {}",
+            error_string
+        );
+
+        let error_string = settle_relay_tip(
+            &mut svm,
+            &payer,
+            message_to_relay,
+            relayer,
+            relayed_nonces_pda(page),
+            relay_execution_pda(OUTGOING_NONCE),
+        )
+        .unwrap_err();
+        assert!(
+            error_string.contains("RelayExecutionNotRecorded"),
+            "Expected RelayExecutionNotRecorded error, got: {}",
+            error_string
+        );
+    }
+}
@@ -14,17 +14,50 @@ pub struct GasConfig {
     pub gas_cost_scaler_dp: u64,
     /// Account that receives gas fees
     pub gas_fee_receiver: Pubkey,
+    /// Gas limit derived for a transfer without call: the per-message overhead of `relayMessages`
+    /// plus minting or unlocking the tokens on Base
+    pub default_transfer_gas_limit: u64,
+    /// Additional gas limit derived for each recipient of a transfer batch beyond the first
+    pub default_transfer_batch_recipient_gas_limit: u64,
 }
 
+impl GasConfig {
+    /// Checks that the gas limit bounds admit at least one gas limit and that the gas cost
+    /// scaler precision, a divisor of the gas cost, is non-zero.
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.min_gas_limit_per_message <= self.max_gas_limit_per_message,
+            RelayerError::InvalidGasLimitBounds
+        );
+        require!(
+            self.gas_cost_scaler_dp > 0,
+            RelayerError::InvalidGasCostScalerDp
+        );
+        Ok(())
+    }
+}
+
+/// Checks `gas_limit` against the configured bounds and pays for it along with the `calldata_gas`
+/// of the message payload, which is not bounded by the gas limit. Returns the gas cost paid.
+/// Fee exempt payers skip the transfer (paying 0) but their gas usage is still recorded.
 pub fn check_and_pay_for_gas<'info>(
     system_program: &Program<'info, System>,
     payer: &Signer<'info>,
     gas_fee_receiver: &AccountInfo<'info>,
     cfg: &mut Cfg,
     gas_limit: u64,
-) -> Result<()> {
+    calldata_gas: u64,
+    fee_exempt: bool,
+) -> Result<u64> {
     check_gas_limit(gas_limit, cfg)?;
-    pay_for_gas(system_program, payer, gas_fee_receiver, cfg, gas_limit)
+    pay_for_gas(
+        system_program,
+        payer,
+        gas_fee_receiver,
+        cfg,
+        gas_limit.saturating_add(calldata_gas),
+        fee_exempt,
+    )
 }
 
 fn check_gas_limit(gas_limit: u64, cfg: &Cfg) -> Result<()> {
@@ -45,17 +78,22 @@ fn pay_for_gas<'info>(
     payer: &Signer<'info>,
     gas_fee_receiver: &AccountInfo<'info>,
     cfg: &mut Cfg,
-    gas_limit: u64,
-) -> Result<()> {
+    gas: u64,
+    fee_exempt: bool,
+) -> Result<u64> {
     // Get the base fee for the current window
     let current_timestamp = Clock::get()?.unix_timestamp;
     let base_fee = cfg.eip1559.refresh_base_fee(current_timestamp);
 
     // Record gas usage for this transaction
-    cfg.eip1559.add_gas_usage(gas_limit);
+    cfg.eip1559.add_gas_usage(gas);
+
+    if fee_exempt {
+        return Ok(0);
+    }
 
     let gas_cost =
-        gas_limit * base_fee * cfg.gas_config.gas_cost_scaler / cfg.gas_config.gas_cost_scaler_dp;
+        gas * base_fee * cfg.gas_config.gas_cost_scaler / cfg.gas_config.gas_cost_scaler_dp;
 
     let cpi_ctx = CpiContext::new(
         system_program.to_account_info(),
@@ -67,7 +105,7 @@ fn pay_for_gas<'info>(
 
     anchor_lang::system_program::transfer(cpi_ctx, gas_cost)?;
 
-    Ok(())
+    Ok(gas_cost)
 }
 
 #[cfg(test)]
@@ -75,7 +113,11 @@ mod tests {
     use super::*;
     use crate::internal::{Eip1559, Eip1559Config};
     use crate::state::Cfg;
-    use crate::test_utils::{mock_clock, setup_relayer, SetupRelayerResult, TEST_GAS_FEE_RECEIVER};
+    use crate::test_utils::{
+        bridge_pda, calldata_pricing_pda, fee_exemptions_pda, mock_clock, nonce_monitor_pda,
+        pending_relay_index_pda, setup_relayer, sponsor_policy_pda, SetupRelayerResult,
+        TEST_GAS_FEE_RECEIVER,
+    };
     use crate::{accounts, instruction};
     use anchor_lang::solana_program::{instruction::Instruction, system_program};
     use anchor_lang::InstructionData;
@@ -124,6 +166,27 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[test]
+    fn validate_rejects_invalid_fields() {
+        assert!(GasConfig::test_new(TEST_GAS_FEE_RECEIVER)
+            .validate()
+            .is_ok());
+
+        let mut gas_config = GasConfig::test_new(TEST_GAS_FEE_RECEIVER);
+        gas_config.min_gas_limit_per_message = gas_config.max_gas_limit_per_message + 1;
+        assert_eq!(
+            gas_config.validate().unwrap_err(),
+            RelayerError::InvalidGasLimitBounds.into()
+        );
+
+        let mut gas_config = GasConfig::test_new(TEST_GAS_FEE_RECEIVER);
+        gas_config.gas_cost_scaler_dp = 0;
+        assert_eq!(
+            gas_config.validate().unwrap_err(),
+            RelayerError::InvalidGasCostScalerDp.into()
+        );
+    }
+
     #[test]
     fn check_and_pay_transfers_scaled_amount() {
         let SetupRelayerResult {
@@ -178,6 +241,13 @@ mod tests {
             cfg: cfg_pda,
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
             message_to_relay,
+            fee_exemptions: fee_exemptions_pda(),
+            sponsor_policy: sponsor_policy_pda(&payer_pk),
+            nonce_monitor: nonce_monitor_pda(),
+            bridge: bridge_pda(),
+            pending_relay_index: pending_relay_index_pda(),
+            calldata_pricing: calldata_pricing_pda(),
+            outgoing_message_account: None,
             system_program: system_program::ID,
         }
         .to_account_metas(None);
@@ -190,6 +260,7 @@ mod tests {
                 mtr_salt,
                 outgoing_message,
                 gas_limit,
+                tip_lamports: 0,
             }
             .data(),
         };
@@ -227,6 +298,8 @@ mod tests {
             denominator: 2,
             window_duration_seconds: 1,
             minimum_base_fee: 1,
+            max_base_fee: 0,
+            max_window_increase_bps: 0,
         };
 
         let mut new_gas = original.gas_config.clone();
@@ -278,6 +351,13 @@ mod tests {
             cfg: cfg_pda,
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
             message_to_relay,
+            fee_exemptions: fee_exemptions_pda(),
+            sponsor_policy: sponsor_policy_pda(&payer_pk),
+            nonce_monitor: nonce_monitor_pda(),
+            bridge: bridge_pda(),
+            pending_relay_index: pending_relay_index_pda(),
+            calldata_pricing: calldata_pricing_pda(),
+            outgoing_message_account: None,
             system_program: system_program::ID,
         }
         .to_account_metas(None);
@@ -289,6 +369,7 @@ mod tests {
                 mtr_salt,
                 outgoing_message,
                 gas_limit,
+                tip_lamports: 0,
             }
             .data(),
         };
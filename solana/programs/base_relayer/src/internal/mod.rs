@@ -1,7 +1,4 @@
-pub mod eip_1559;
 pub mod gas_config;
-pub mod math;
 
-pub use eip_1559::*;
+pub use eip1559::{Eip1559, Eip1559Config};
 pub use gas_config::*;
-pub use math::*;
@@ -1,14 +1,19 @@
 #![allow(unexpected_cfgs)]
 
 use anchor_lang::prelude::*;
+use fee_config::FeeConfigV2;
 
 mod constants;
+mod env;
 mod errors;
+mod events;
 mod instructions;
 mod internal;
 mod state;
 
+pub use env::*;
 pub use errors::*;
+pub use events::*;
 use instructions::*;
 use internal::*;
 use state::*;
@@ -16,8 +21,6 @@ use state::*;
 #[cfg(test)]
 mod test_utils;
 
-declare_id!("HPLodLSVpcUX73cXxT7NNss1frnr2XWf6yK3KPChRTjJ");
-
 #[program]
 pub mod base_relayer {
 
@@ -44,6 +47,17 @@ pub mod base_relayer {
         initialize_handler(ctx, guardian, eip1559_config, gas_config)
     }
 
+    /// Migrates the `Cfg` PDA written before `FeeConfigV2` to the current layout.
+    /// Inserts the EIP-1559 base fee caps, disabled until set through
+    /// `set_fee_config`. Only the upgrade authority may call this instruction.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the `cfg` PDA, the `upgrade_authority`
+    ///           signer and the `payer` funding the additional rent.
+    pub fn migrate_cfg(ctx: Context<MigrateCfg>) -> Result<()> {
+        migrate_cfg_handler(ctx)
+    }
+
     /// Updates the EIP1559 configuration.
     /// Only the recorded `guardian` may call this instruction.
     ///
@@ -69,6 +83,19 @@ pub mod base_relayer {
         set_gas_config_handler(ctx, gas_config)
     }
 
+    /// Updates every pricing parameter shared with the bridge program (EIP-1559
+    /// controller, gas cost scaler and gas fee receiver) at once. The
+    /// per-message gas limit bounds are left unchanged.
+    /// Only the recorded `guardian` may call this instruction.
+    ///
+    /// # Arguments
+    /// * `ctx`        - The context containing the `cfg` PDA and the `guardian` signer.
+    ///                  Authorization is enforced via an Anchor `has_one` constraint.
+    /// * `fee_config` - The new fee configuration.
+    pub fn set_fee_config(ctx: Context<SetConfig>, fee_config: FeeConfigV2) -> Result<()> {
+        set_fee_config_handler(ctx, fee_config)
+    }
+
     /// Updates the configured guardian.
     /// Only the current `guardian` may call this instruction.
     ///
@@ -80,6 +107,80 @@ pub mod base_relayer {
         set_guardian_handler(ctx, new_guardian)
     }
 
+    /// Replaces the list of senders exempt from the gas fee transfer in
+    /// `pay_for_relay`, used by protocol-owned keepers. Their gas usage is still
+    /// recorded for the EIP-1559 controller.
+    /// Only the recorded `guardian` may call this instruction.
+    ///
+    /// # Arguments
+    /// * `ctx`     - The context containing the `cfg` PDA, the `guardian` signer
+    ///               (funding the list creation) and the `fee_exemptions` PDA.
+    /// * `senders` - The full list of fee exempt senders.
+    pub fn set_fee_exemptions(ctx: Context<SetFeeExemptions>, senders: Vec<Pubkey>) -> Result<()> {
+        set_fee_exemptions_handler(ctx, senders)
+    }
+
+    /// Configures the nonce monitor checking, in `pay_for_relay`, that the relayer
+    /// nonce does not diverge from the nonce of the bridge `bridge` account by
+    /// more than `max_nonce_gap`. Anomalies emit a `NonceAnomaly` event.
+    /// Only the recorded `guardian` may call this instruction.
+    ///
+    /// # Arguments
+    /// * `ctx`                - The context containing the `cfg` PDA, the `guardian`
+    ///                          signer (funding the monitor creation) and the
+    ///                          `nonce_monitor` PDA.
+    /// * `max_nonce_gap`      - The maximum tolerated nonce divergence.
+    /// * `degrade_on_anomaly` - Whether anomalies flag the monitor as degraded.
+    pub fn set_nonce_monitor(
+        ctx: Context<SetNonceMonitor>,
+        max_nonce_gap: u64,
+        degrade_on_anomaly: bool,
+    ) -> Result<()> {
+        set_nonce_monitor_handler(ctx, max_nonce_gap, degrade_on_anomaly)
+    }
+
+    /// Configures the calldata cost charged by `pay_for_relay` on top of the gas
+    /// limit, so that large payloads pay for the calldata floor price of Base:
+    /// `gas_per_calldata_byte` gas for each payload byte beyond
+    /// `free_calldata_bytes`, at the current base fee.
+    /// Only the recorded `guardian` may call this instruction.
+    ///
+    /// # Arguments
+    /// * `ctx`                   - The context containing the `cfg` PDA, the
+    ///                             `guardian` signer (funding the pricing creation)
+    ///                             and the `calldata_pricing` PDA.
+    /// * `free_calldata_bytes`   - The payload bytes covered by the gas limit.
+    /// * `gas_per_calldata_byte` - The gas charged for each additional byte.
+    pub fn set_calldata_pricing(
+        ctx: Context<SetCalldataPricing>,
+        free_calldata_bytes: u64,
+        gas_per_calldata_byte: u64,
+    ) -> Result<()> {
+        set_calldata_pricing_handler(ctx, free_calldata_bytes, gas_per_calldata_byte)
+    }
+
+    /// Creates the index of the pending relay requests escrowing a tip, sorted by
+    /// fee bucket, that `pay_for_relay`, `bump_relay_fee` and `settle_relay_tip`
+    /// maintain so that relayers fetch the best paid work from a single account.
+    /// Only the recorded `guardian` may call this instruction.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the `cfg` PDA, the `guardian` signer
+    ///           (funding the index creation) and the `pending_relay_index` PDA.
+    pub fn create_pending_relay_index(ctx: Context<CreatePendingRelayIndex>) -> Result<()> {
+        create_pending_relay_index_handler(ctx)
+    }
+
+    /// Clears the `degraded` flag set on the nonce monitor by a nonce anomaly.
+    /// Only the recorded `guardian` may call this instruction.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the `cfg` PDA, the `guardian` signer and
+    ///           the `nonce_monitor` PDA.
+    pub fn clear_nonce_anomaly(ctx: Context<ClearNonceAnomaly>) -> Result<()> {
+        clear_nonce_anomaly_handler(ctx)
+    }
+
     /// Pays the gas cost for relaying a message to Base and records the request.
     /// Transfers lamports from `payer` to `cfg.gas_config.gas_fee_receiver` using
     /// the current EIP-1559 pricing and the provided `gas_limit`. Also initializes
@@ -95,18 +196,130 @@ pub mod base_relayer {
     /// * `mtr_salt`         - 32-byte salt used to derive the `message_to_relay`
     ///                         PDA address, enabling unique messages per request.
     /// * `outgoing_message` - The Base-side message identifier to be executed.
-    /// * `gas_limit`        - Maximum gas units to budget for execution on Base.
+    /// * `gas_limit`        - Maximum gas units to budget for execution on Base, or
+    ///                         0 to derive it from the relayed message.
+    /// * `tip_lamports`     - Optional tip escrowed in `message_to_relay` for the
+    ///                         relayer that executes the message (0 for none).
+    ///                         A tip requires the `outgoing_message_account`,
+    ///                         whose nonce settles it.
+    ///
+    /// Messages whose sender is on the guardian-managed fee exemption list skip
+    /// the gas fee transfer; the exemption requires the `outgoing_message_account`.
+    /// Tipped requests are indexed by fee bucket in the pending relay index, once
+    /// the guardian created it.
+    /// The bridge nonce, read from the `bridge` account, is checked against the
+    /// relayer nonce by the guardian-configured nonce monitor.
+    /// If the payer configured a `SponsorPolicy`, the gas fee and tip count
+    /// against its daily limit and, when it restricts senders, the
+    /// `outgoing_message_account` must be provided to check the message sender.
+    /// A derived gas limit requires the `outgoing_message_account` and is only
+    /// available for transfers without call and transfer batches; it is the
+    /// per-kind default of `cfg.gas_config`, at least the configured minimum,
+    /// and is recorded in `message_to_relay`.
+    /// Once the guardian configured the calldata pricing, the
+    /// `outgoing_message_account` is required and the calldata gas of its
+    /// payload is charged on top of the gas limit.
     ///
     /// # Errors
     /// Returns an error if the `gas_fee_receiver` does not match the configured
-    /// receiver or if the payer lacks sufficient lamports to cover the computed
-    /// fee.
+    /// receiver, if the payer lacks sufficient lamports to cover the computed
+    /// fee, if the payment violates the payer's sponsor policy, or if the gas
+    /// limit cannot be derived from the message.
     pub fn pay_for_relay(
         ctx: Context<PayForRelay>,
         mtr_salt: [u8; 32],
         outgoing_message: Pubkey,
         gas_limit: u64,
+        tip_lamports: u64,
+    ) -> Result<()> {
+        pay_for_relay_handler(ctx, mtr_salt, outgoing_message, gas_limit, tip_lamports)
+    }
+
+    /// Raises the tip escrowed in an existing `MessageToRelay`, re-pricing a paid
+    /// message stuck behind better priced ones instead of paying for a duplicate.
+    /// Emits a `RelayFeeBumped` event for the orchestrator. Only the original
+    /// payer may call this instruction.
+    ///
+    /// # Arguments
+    /// * `ctx`                 - The context containing the `payer` signer, the
+    ///                            `message_to_relay` escrow and the payer's
+    ///                            `sponsor_policy` PDA.
+    /// * `additional_lamports` - Lamports added to the escrowed tip.
+    ///
+    /// # Errors
+    /// Returns an error if the signer is not the original payer, if
+    /// `additional_lamports` is zero, or if the bump exceeds the payer's sponsor
+    /// daily limit.
+    pub fn bump_relay_fee(ctx: Context<BumpRelayFee>, additional_lamports: u64) -> Result<()> {
+        bump_relay_fee_handler(ctx, additional_lamports)
+    }
+
+    /// Creates or updates the payer's sponsor policy, restricting what the payer
+    /// sponsors through `pay_for_relay`. Configured by the payer itself; the
+    /// guardian is not involved.
+    ///
+    /// # Arguments
+    /// * `ctx`                  - The context containing the `payer` signer and its
+    ///                             `sponsor_policy` PDA (created if needed).
+    /// * `daily_limit_lamports` - Maximum lamports (gas fees and tips) the payer
+    ///                             spends per day (0 for no limit).
+    /// * `sponsored_senders`    - Outgoing message senders the payer sponsors
+    ///                             (empty to sponsor every sender).
+    pub fn set_sponsor_policy(
+        ctx: Context<SetSponsorPolicy>,
+        daily_limit_lamports: u64,
+        sponsored_senders: Vec<Pubkey>,
     ) -> Result<()> {
-        pay_for_relay_handler(ctx, mtr_salt, outgoing_message, gas_limit)
+        set_sponsor_policy_handler(ctx, daily_limit_lamports, sponsored_senders)
+    }
+
+    /// Returns the environment (localnet, devnet-alpha, devnet-prod or mainnet)
+    /// the program was compiled for, letting clients detect mixed deployments.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context; no accounts are required.
+    pub fn get_environment(ctx: Context<GetEnvironment>) -> Result<Environment> {
+        get_environment_handler(ctx)
+    }
+
+    /// Fails with `EnvironmentMismatch` unless the program was compiled for
+    /// `expected` and is deployed at the program ID of that environment,
+    /// letting deployment scripts catch builds made for another environment.
+    ///
+    /// # Arguments
+    /// * `ctx`      - The context; no accounts are required.
+    /// * `expected` - The environment the program is expected to be built for.
+    pub fn check_environment(ctx: Context<CheckEnvironment>, expected: Environment) -> Result<()> {
+        check_environment_handler(ctx, expected)
+    }
+
+    /// Releases the tip escrowed for a paid relay request to the relayer that
+    /// executed it, once the bridge confirmed the relayed outgoing message as
+    /// executed on Base in its oracle-attested `RelayedNonces`. The relayer is the
+    /// one the oracles attested in the bridge `RelayExecution` record of the
+    /// message nonce. Tips let payers bid for relay ordering without
+    /// protocol-level scheduling.
+    /// Anyone may call this instruction.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the `message_to_relay` escrow, the
+    ///           `relayer` receiving the tip, the bridge `relayed_nonces` PDA
+    ///           covering the outgoing message nonce and its bridge
+    ///           `relay_execution` PDA.
+    pub fn settle_relay_tip(ctx: Context<SettleRelayTip>) -> Result<()> {
+        settle_relay_tip_handler(ctx)
+    }
+
+    /// Refunds the tip escrowed for a paid relay request to its payer when the
+    /// bridge has not confirmed the relayed outgoing message as executed on Base
+    /// `RELAY_TIP_REFUND_DELAY_SECONDS` after it was paid for.
+    /// Anyone may call this instruction.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the `message_to_relay` escrow, its `payer`
+    ///           receiving the refund and the bridge `relayed_nonces` PDA covering
+    ///           the outgoing message nonce.
+    pub fn refund_relay_tip(ctx: Context<RefundRelayTip>) -> Result<()> {
+        refund_relay_tip_handler(ctx)
     }
 }
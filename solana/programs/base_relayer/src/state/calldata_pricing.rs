@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::ID;
+
+/// Guardian-configured pricing of the calldata posted to Base when relaying a message. Since
+/// EIP-7623, the calldata of large payloads is charged at a floor price independent of the gas
+/// they consume, which the gas limit does not account for. Payloads are charged
+/// `gas_per_calldata_byte` gas for each byte beyond `free_calldata_bytes`, at the current base
+/// fee. Calldata is not priced until this account is created.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct CalldataPricing {
+    /// Payload bytes covered by the gas limit of the message
+    pub free_calldata_bytes: u64,
+    /// Gas charged for each payload byte beyond `free_calldata_bytes`
+    pub gas_per_calldata_byte: u64,
+}
+
+impl CalldataPricing {
+    /// Returns the calldata gas charged for a payload of `payload_len` bytes.
+    pub fn calldata_gas(&self, payload_len: u64) -> u64 {
+        payload_len
+            .saturating_sub(self.free_calldata_bytes)
+            .saturating_mul(self.gas_per_calldata_byte)
+    }
+
+    /// Loads the calldata pricing, returning `None` if the guardian did not create it.
+    pub fn load(calldata_pricing_info: &AccountInfo) -> Result<Option<Self>> {
+        if *calldata_pricing_info.owner != ID {
+            return Ok(None);
+        }
+
+        Ok(Some(Self::try_deserialize(
+            &mut &calldata_pricing_info.try_borrow_data()?[..],
+        )?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calldata_gas_charges_bytes_beyond_free_allowance() {
+        let calldata_pricing = CalldataPricing {
+            free_calldata_bytes: 1_000,
+            gas_per_calldata_byte: 40,
+        };
+
+        assert_eq!(calldata_pricing.calldata_gas(0), 0);
+        assert_eq!(calldata_pricing.calldata_gas(1_000), 0);
+        assert_eq!(calldata_pricing.calldata_gas(61_000), 2_400_000);
+        assert_eq!(calldata_pricing.calldata_gas(u64::MAX), u64::MAX);
+    }
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
+use fee_config::FeeConfigV2;
 
-use crate::internal::{Eip1559, GasConfig};
+use crate::internal::{Eip1559, Eip1559Config, GasConfig};
 
 #[account]
 #[derive(Debug, PartialEq, Eq, InitSpace)]
@@ -14,3 +15,37 @@ pub struct Cfg {
     /// Gas configuration
     pub gas_config: GasConfig,
 }
+
+impl Cfg {
+    /// Returns the pricing parameters shared with the bridge program.
+    pub fn fee_config(&self) -> FeeConfigV2 {
+        let config = &self.eip1559.config;
+        FeeConfigV2 {
+            target: config.target,
+            denominator: config.denominator,
+            window_duration_seconds: config.window_duration_seconds,
+            minimum_base_fee: config.minimum_base_fee,
+            max_base_fee: config.max_base_fee,
+            max_window_increase_bps: config.max_window_increase_bps,
+            gas_cost_scaler: self.gas_config.gas_cost_scaler,
+            gas_cost_scaler_dp: self.gas_config.gas_cost_scaler_dp,
+            gas_fee_receiver: self.gas_config.gas_fee_receiver,
+        }
+    }
+
+    /// Writes `fee_config` into the EIP-1559 and gas configurations. The per-message gas limit
+    /// bounds are relayer specific and left unchanged.
+    pub fn apply_fee_config(&mut self, fee_config: &FeeConfigV2) {
+        self.eip1559.config = Eip1559Config {
+            target: fee_config.target,
+            denominator: fee_config.denominator,
+            window_duration_seconds: fee_config.window_duration_seconds,
+            minimum_base_fee: fee_config.minimum_base_fee,
+            max_base_fee: fee_config.max_base_fee,
+            max_window_increase_bps: fee_config.max_window_increase_bps,
+        };
+        self.gas_config.gas_cost_scaler = fee_config.gas_cost_scaler;
+        self.gas_config.gas_cost_scaler_dp = fee_config.gas_cost_scaler_dp;
+        self.gas_config.gas_fee_receiver = fee_config.gas_fee_receiver;
+    }
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_FEE_EXEMPT_SENDERS;
+
+/// Guardian-managed list of protocol-owned senders (e.g. keeper program PDAs) exempt from the
+/// gas fee transfer in `pay_for_relay`. Their gas usage is still recorded for the EIP-1559
+/// controller.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct FeeExemptions {
+    /// Number of senders in `senders` array
+    pub sender_count: u8,
+    /// Senders exempt from paying gas fees
+    pub senders: [Pubkey; MAX_FEE_EXEMPT_SENDERS as usize],
+}
+
+impl FeeExemptions {
+    pub fn is_exempt(&self, sender: &Pubkey) -> bool {
+        self.senders[..self.sender_count as usize].contains(sender)
+    }
+}
@@ -1,4 +1,9 @@
 use anchor_lang::prelude::*;
+use bridge::solana_to_base::{
+    RelayExecution, RelayedNonces, RELAYED_NONCES_SEED, RELAY_EXECUTION_SEED,
+};
+
+use crate::{RelayerError, BRIDGE_PROGRAM_ID};
 
 #[account]
 #[derive(Debug, PartialEq, Eq, InitSpace)]
@@ -6,4 +11,59 @@ pub struct MessageToRelay {
     pub nonce: u64,
     pub outgoing_message: Pubkey,
     pub gas_limit: u64,
+    /// Lamports escrowed in this account for the relayer that executes the message on Base.
+    pub tip_lamports: u64,
+    /// The payer of the relay request, the only account allowed to bump its tip.
+    pub payer: Pubkey,
+    /// Bridge nonce of the relayed `OutgoingMessage`, recorded when its account was provided.
+    /// Required to escrow a tip, which is settled once the bridge confirms the nonce as relayed.
+    pub outgoing_nonce: Option<u64>,
+    /// Unix timestamp at which the relay was paid for, starting the tip refund delay.
+    pub created_at: i64,
+}
+
+impl MessageToRelay {
+    /// Returns whether the bridge confirmed the relayed outgoing message as executed on Base,
+    /// from `relayed_nonces_info`, the bridge `RelayedNonces` PDA covering its nonce.
+    pub fn is_relayed(&self, relayed_nonces_info: &AccountInfo) -> Result<bool> {
+        let outgoing_nonce = self
+            .outgoing_nonce
+            .ok_or(RelayerError::MissingOutgoingMessage)?;
+
+        let (relayed_nonces, _) = Pubkey::find_program_address(
+            &[
+                RELAYED_NONCES_SEED,
+                &RelayedNonces::page_of(outgoing_nonce).to_le_bytes(),
+            ],
+            &BRIDGE_PROGRAM_ID,
+        );
+        require_keys_eq!(
+            relayed_nonces_info.key(),
+            relayed_nonces,
+            RelayerError::IncorrectRelayedNonces
+        );
+
+        RelayedNonces::is_relayed_in(relayed_nonces_info, outgoing_nonce)
+    }
+
+    /// Returns the relayer that executed the relayed outgoing message on Base, from
+    /// `relay_execution_info`, the oracle-attested bridge `RelayExecution` PDA of its nonce.
+    pub fn executing_relayer(&self, relay_execution_info: &AccountInfo) -> Result<Pubkey> {
+        let outgoing_nonce = self
+            .outgoing_nonce
+            .ok_or(RelayerError::MissingOutgoingMessage)?;
+
+        let (relay_execution, _) = Pubkey::find_program_address(
+            &[RELAY_EXECUTION_SEED, &outgoing_nonce.to_le_bytes()],
+            &BRIDGE_PROGRAM_ID,
+        );
+        require_keys_eq!(
+            relay_execution_info.key(),
+            relay_execution,
+            RelayerError::IncorrectRelayExecution
+        );
+
+        RelayExecution::relayer_in(relay_execution_info)?
+            .ok_or_else(|| error!(RelayerError::RelayExecutionNotRecorded))
+    }
 }
@@ -1,5 +1,15 @@
+pub mod calldata_pricing;
 pub mod cfg;
+pub mod fee_exemptions;
 pub mod message_to_relay;
+pub mod nonce_monitor;
+pub mod pending_relay_index;
+pub mod sponsor_policy;
 
+pub use calldata_pricing::*;
 pub use cfg::*;
+pub use fee_exemptions::*;
 pub use message_to_relay::*;
+pub use nonce_monitor::*;
+pub use pending_relay_index::*;
+pub use sponsor_policy::*;
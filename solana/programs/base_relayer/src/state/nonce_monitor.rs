@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+/// Guardian-configured monitor comparing, in `pay_for_relay`, the relayer nonce with the nonce of
+/// the next bridge outgoing message. Monitoring is disabled until this account is created.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct NonceMonitor {
+    /// Maximum tolerated divergence between the relayer nonce and the bridge nonce
+    pub max_nonce_gap: u64,
+    /// Whether an observed anomaly sets `degraded`
+    pub degrade_on_anomaly: bool,
+    /// Set when an anomaly is observed with `degrade_on_anomaly` enabled. Monitoring alarms on
+    /// it until the guardian clears it.
+    pub degraded: bool,
+}
+
+impl NonceMonitor {
+    /// Returns the divergence between the relayer and bridge nonces if it exceeds `max_nonce_gap`.
+    pub fn anomaly(&self, relayer_nonce: u64, bridge_nonce: u64) -> Option<u64> {
+        let gap = relayer_nonce.abs_diff(bridge_nonce);
+        (gap > self.max_nonce_gap).then_some(gap)
+    }
+}
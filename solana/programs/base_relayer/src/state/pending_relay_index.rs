@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::MAX_PENDING_RELAYS, ID};
+
+/// Index of the pending relay requests escrowing a tip, sorted by fee bucket from the highest, so
+/// that third-party relayers fetch the best paid work from this single account instead of scanning
+/// every `MessageToRelay`. Updated when a relay is paid for, its tip bumped or settled.
+/// When the index is full, a request only displaces the last entry if it is in a higher bucket.
+/// Nothing is indexed until the guardian creates this account.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct PendingRelayIndex {
+    /// Number of entries in `entries` array
+    pub count: u8,
+    /// Pending relay requests, sorted by fee bucket from the highest and by age within a bucket
+    pub entries: [PendingRelay; MAX_PENDING_RELAYS as usize],
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize,
+)]
+pub struct PendingRelay {
+    /// The pending relay request
+    pub message_to_relay: Pubkey,
+    /// Fee bucket of its escrowed tip, see `PendingRelayIndex::fee_bucket`
+    pub fee_bucket: u8,
+}
+
+impl PendingRelayIndex {
+    /// Returns the fee bucket of a request escrowing `tip_lamports`: the bit length of the tip, so
+    /// that each bucket spans a doubling of the tip. Untipped requests are in bucket 0.
+    pub fn fee_bucket(tip_lamports: u64) -> u8 {
+        (u64::BITS - tip_lamports.leading_zeros()) as u8
+    }
+
+    /// Returns the entries of the highest non-empty fee bucket.
+    pub fn top_bucket(&self) -> &[PendingRelay] {
+        let entries = &self.entries[..self.count as usize];
+        let Some(top) = entries.first() else {
+            return entries;
+        };
+        let len = entries
+            .iter()
+            .take_while(|entry| entry.fee_bucket == top.fee_bucket)
+            .count();
+        &entries[..len]
+    }
+
+    /// Indexes `message_to_relay` after the entries of the same or higher fee buckets. Untipped
+    /// requests are not indexed.
+    pub fn insert(&mut self, message_to_relay: Pubkey, tip_lamports: u64) {
+        let fee_bucket = Self::fee_bucket(tip_lamports);
+        if fee_bucket == 0 {
+            return;
+        }
+
+        let len = self.count as usize;
+        let position = self.entries[..len]
+            .iter()
+            .position(|entry| entry.fee_bucket < fee_bucket)
+            .unwrap_or(len);
+        if len < MAX_PENDING_RELAYS as usize {
+            self.count += 1;
+        } else if position == len {
+            return;
+        }
+
+        let len = self.count as usize;
+        self.entries.copy_within(position..len - 1, position + 1);
+        self.entries[position] = PendingRelay {
+            message_to_relay,
+            fee_bucket,
+        };
+    }
+
+    /// Removes `message_to_relay` from the index, if indexed.
+    pub fn remove(&mut self, message_to_relay: &Pubkey) {
+        let len = self.count as usize;
+        let Some(position) = self.entries[..len]
+            .iter()
+            .position(|entry| entry.message_to_relay == *message_to_relay)
+        else {
+            return;
+        };
+
+        self.entries.copy_within(position + 1..len, position);
+        self.entries[len - 1] = PendingRelay::default();
+        self.count -= 1;
+    }
+
+    /// Applies `update` to the index held by `pending_relay_index_info`, if the guardian created it.
+    pub fn update(
+        pending_relay_index_info: &AccountInfo,
+        update: impl FnOnce(&mut Self),
+    ) -> Result<()> {
+        if *pending_relay_index_info.owner != ID {
+            return Ok(());
+        }
+
+        let mut data = pending_relay_index_info.try_borrow_mut_data()?;
+        let mut pending_relay_index = Self::try_deserialize(&mut &data[..])?;
+        update(&mut pending_relay_index);
+        pending_relay_index.try_serialize(&mut &mut data[..])?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_index() -> PendingRelayIndex {
+        PendingRelayIndex {
+            count: 0,
+            entries: [PendingRelay::default(); MAX_PENDING_RELAYS as usize],
+        }
+    }
+
+    #[test]
+    fn test_insert_sorts_by_fee_bucket() {
+        let mut index = empty_index();
+        let low = Pubkey::new_unique();
+        let high = Pubkey::new_unique();
+        let high_later = Pubkey::new_unique();
+
+        index.insert(low, 1_000);
+        index.insert(high, 1_000_000);
+        index.insert(Pubkey::new_unique(), 0);
+        index.insert(high_later, 1_000_001);
+
+        assert_eq!(index.count, 3);
+        let top_bucket = index
+            .top_bucket()
+            .iter()
+            .map(|entry| entry.message_to_relay)
+            .collect::<Vec<_>>();
+        assert_eq!(top_bucket, vec![high, high_later]);
+        assert_eq!(index.entries[2].message_to_relay, low);
+
+        index.remove(&high);
+        assert_eq!(index.count, 2);
+        assert_eq!(index.entries[0].message_to_relay, high_later);
+        assert_eq!(index.entries[2], PendingRelay::default());
+    }
+
+    #[test]
+    fn test_insert_into_full_index_displaces_lowest_bucket() {
+        let mut index = empty_index();
+        for _ in 0..MAX_PENDING_RELAYS {
+            index.insert(Pubkey::new_unique(), 1_000);
+        }
+        let last = index.entries[MAX_PENDING_RELAYS as usize - 1];
+
+        // A request in the same bucket is not indexed
+        let same_bucket = Pubkey::new_unique();
+        index.insert(same_bucket, 1_000);
+        assert!(!index
+            .entries
+            .iter()
+            .any(|entry| entry.message_to_relay == same_bucket));
+
+        // A request in a higher bucket displaces the last entry
+        let higher_bucket = Pubkey::new_unique();
+        index.insert(higher_bucket, 1_000_000);
+        assert_eq!(index.count, MAX_PENDING_RELAYS);
+        assert_eq!(index.entries[0].message_to_relay, higher_bucket);
+        assert!(!index.entries.contains(&last));
+    }
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{MAX_SPONSORED_SENDERS, SPONSOR_POLICY_WINDOW_SECONDS},
+    RelayerError,
+};
+
+/// Guardrails configured by a payer sponsoring relays, enforced in `pay_for_relay`.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct SponsorPolicy {
+    /// The payer this policy applies to
+    pub payer: Pubkey,
+    /// Maximum lamports (gas fees and tips) the payer spends per day (0 for no limit)
+    pub daily_limit_lamports: u64,
+    /// Start timestamp of the current spend window
+    pub window_start_time: i64,
+    /// Lamports spent in the current window
+    pub window_spent_lamports: u64,
+    /// Number of senders in `sponsored_senders` array (0 sponsors every sender)
+    pub sponsored_sender_count: u8,
+    /// Outgoing message senders the payer sponsors
+    pub sponsored_senders: [Pubkey; MAX_SPONSORED_SENDERS as usize],
+}
+
+impl SponsorPolicy {
+    /// Records `lamports` spent at `current_timestamp`, starting a new window if the current one
+    /// has elapsed, and enforces the daily limit.
+    pub fn record_spend(&mut self, lamports: u64, current_timestamp: i64) -> Result<()> {
+        if current_timestamp - self.window_start_time >= SPONSOR_POLICY_WINDOW_SECONDS {
+            self.window_start_time = current_timestamp;
+            self.window_spent_lamports = 0;
+        }
+
+        self.window_spent_lamports = self.window_spent_lamports.saturating_add(lamports);
+        require!(
+            self.daily_limit_lamports == 0
+                || self.window_spent_lamports <= self.daily_limit_lamports,
+            RelayerError::SponsorSpendLimitExceeded
+        );
+
+        Ok(())
+    }
+
+    pub fn has_allow_list(&self) -> bool {
+        self.sponsored_sender_count > 0
+    }
+
+    pub fn is_sponsored(&self, sender: &Pubkey) -> bool {
+        self.sponsored_senders[..self.sponsored_sender_count as usize].contains(sender)
+    }
+}
@@ -5,6 +5,13 @@ use anchor_lang::{
     },
     system_program, InstructionData,
 };
+use bridge::{
+    common::{bridge::Bridge, BRIDGE_LAYOUT_VERSION, BRIDGE_SEED},
+    solana_to_base::{
+        Call, CallType, Message as BridgeMessage, OutgoingMessage, RelayExecution, RelayedNonces,
+        RELAYED_NONCES_PER_ACCOUNT, RELAYED_NONCES_SEED, RELAY_EXECUTION_SEED,
+    },
+};
 use litesvm::LiteSVM;
 use solana_account::Account;
 use solana_keypair::Keypair;
@@ -15,25 +22,18 @@ use solana_transaction::Transaction;
 
 use crate::{
     accounts,
-    constants::CFG_SEED,
+    constants::{
+        CALLDATA_PRICING_SEED, CFG_SEED, DISCRIMINATOR_LEN, FEE_EXEMPTIONS_SEED,
+        NONCE_MONITOR_SEED, PENDING_RELAY_INDEX_SEED, SPONSOR_POLICY_SEED,
+    },
     instruction::Initialize,
     internal::{Eip1559Config, GasConfig},
-    ID,
+    state::{CalldataPricing, NonceMonitor},
+    BRIDGE_PROGRAM_ID, ID,
 };
 
 pub const TEST_GAS_FEE_RECEIVER: Pubkey = pubkey!("eEwCrQLBdQchykrkYitkYUZskd7MPrU2YxBXcPDPnMt");
 
-impl Eip1559Config {
-    pub fn test_new() -> Self {
-        Self {
-            target: 5_000_000,
-            denominator: 2,
-            window_duration_seconds: 1,
-            minimum_base_fee: 1,
-        }
-    }
-}
-
 impl GasConfig {
     pub fn test_new(gas_fee_receiver: Pubkey) -> Self {
         Self {
@@ -42,6 +42,8 @@ impl GasConfig {
             gas_cost_scaler: 1_000_000,
             gas_cost_scaler_dp: 10u64.pow(6),
             gas_fee_receiver,
+            default_transfer_gas_limit: 150_000,
+            default_transfer_batch_recipient_gas_limit: 50_000,
         }
     }
 }
@@ -209,6 +211,216 @@ pub fn setup_relayer() -> SetupRelayerResult {
     }
 }
 
+pub fn fee_exemptions_pda() -> Pubkey {
+    Pubkey::find_program_address(&[FEE_EXEMPTIONS_SEED], &ID).0
+}
+
+pub fn sponsor_policy_pda(payer: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[SPONSOR_POLICY_SEED, payer.as_ref()], &ID).0
+}
+
+pub fn nonce_monitor_pda() -> Pubkey {
+    Pubkey::find_program_address(&[NONCE_MONITOR_SEED], &ID).0
+}
+
+pub fn pending_relay_index_pda() -> Pubkey {
+    Pubkey::find_program_address(&[PENDING_RELAY_INDEX_SEED], &ID).0
+}
+
+pub fn calldata_pricing_pda() -> Pubkey {
+    Pubkey::find_program_address(&[CALLDATA_PRICING_SEED], &ID).0
+}
+
+/// Writes the calldata pricing account with the given configuration.
+pub fn mock_calldata_pricing(
+    svm: &mut LiteSVM,
+    free_calldata_bytes: u64,
+    gas_per_calldata_byte: u64,
+) {
+    let mut data = Vec::with_capacity(DISCRIMINATOR_LEN + CalldataPricing::INIT_SPACE);
+    CalldataPricing {
+        free_calldata_bytes,
+        gas_per_calldata_byte,
+    }
+    .try_serialize(&mut data)
+    .unwrap();
+
+    svm.set_account(
+        calldata_pricing_pda(),
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+/// Writes the nonce monitor account with the given configuration and `degraded` flag.
+pub fn mock_nonce_monitor(
+    svm: &mut LiteSVM,
+    max_nonce_gap: u64,
+    degrade_on_anomaly: bool,
+    degraded: bool,
+) {
+    let mut data = Vec::with_capacity(DISCRIMINATOR_LEN + NonceMonitor::INIT_SPACE);
+    NonceMonitor {
+        max_nonce_gap,
+        degrade_on_anomaly,
+        degraded,
+    }
+    .try_serialize(&mut data)
+    .unwrap();
+
+    svm.set_account(
+        nonce_monitor_pda(),
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+pub fn bridge_pda() -> Pubkey {
+    Pubkey::find_program_address(&[BRIDGE_SEED], &BRIDGE_PROGRAM_ID).0
+}
+
+/// Mocks the header of the bridge `Bridge` account, with `nonce` as its next outgoing nonce.
+pub fn mock_bridge(svm: &mut LiteSVM, nonce: u64) {
+    let mut data = vec![0u8; DISCRIMINATOR_LEN + 1 + 8 + 8];
+    data[..8].copy_from_slice(Bridge::DISCRIMINATOR);
+    data[8] = BRIDGE_LAYOUT_VERSION;
+    data[17..25].copy_from_slice(&nonce.to_le_bytes());
+
+    svm.set_account(
+        bridge_pda(),
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: BRIDGE_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+/// Mocks a bridge `OutgoingMessage` account with the given `nonce` sent by `sender`, carrying an
+/// empty call.
+pub fn mock_outgoing_message(svm: &mut LiteSVM, nonce: u64, sender: Pubkey) -> Pubkey {
+    mock_outgoing_message_with(
+        svm,
+        nonce,
+        sender,
+        BridgeMessage::Call(Call {
+            ty: CallType::Call,
+            to: [0u8; 20],
+            value: 0,
+            data: vec![],
+        }),
+    )
+}
+
+/// Mocks a bridge `OutgoingMessage` account carrying `message`.
+pub fn mock_outgoing_message_with(
+    svm: &mut LiteSVM,
+    nonce: u64,
+    sender: Pubkey,
+    message: BridgeMessage,
+) -> Pubkey {
+    let outgoing_message = Pubkey::new_unique();
+
+    let mut data = vec![];
+    OutgoingMessage {
+        nonce,
+        sender,
+        created_at: 0,
+        remote_chain_id: 0,
+        message,
+    }
+    .try_serialize(&mut data)
+    .unwrap();
+
+    svm.set_account(
+        outgoing_message,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: BRIDGE_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+
+    outgoing_message
+}
+
+pub fn relayed_nonces_pda(page: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[RELAYED_NONCES_SEED, &page.to_le_bytes()],
+        &BRIDGE_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Writes the bridge relayed nonces registry account covering `nonces`, marked as relayed.
+pub fn mock_relayed_nonces(svm: &mut LiteSVM, page: u64, nonces: &[u64]) {
+    let mut data = vec![0u8; DISCRIMINATOR_LEN + std::mem::size_of::<RelayedNonces>()];
+    data[..8].copy_from_slice(RelayedNonces::DISCRIMINATOR);
+    data[8..16].copy_from_slice(&page.to_le_bytes());
+    for nonce in nonces {
+        let index = nonce % RELAYED_NONCES_PER_ACCOUNT;
+        data[16 + (index / 64) as usize * 8 + (index % 64 / 8) as usize] |= 1 << (index % 8);
+    }
+
+    svm.set_account(
+        relayed_nonces_pda(page),
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: BRIDGE_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+pub fn relay_execution_pda(nonce: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[RELAY_EXECUTION_SEED, &nonce.to_le_bytes()],
+        &BRIDGE_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Writes the bridge record of `relayer` executing the outgoing message of `nonce` on Base.
+pub fn mock_relay_execution(svm: &mut LiteSVM, nonce: u64, relayer: Pubkey) {
+    let mut data = vec![];
+    RelayExecution { nonce, relayer }
+        .try_serialize(&mut data)
+        .unwrap();
+
+    svm.set_account(
+        relay_execution_pda(nonce),
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: BRIDGE_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
 pub fn mock_clock(svm: &mut LiteSVM, timestamp: i64) {
     let mut clock = svm.get_sysvar::<Clock>();
     clock.unix_timestamp = timestamp;
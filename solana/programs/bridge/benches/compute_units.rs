@@ -0,0 +1,534 @@
+//! Compute unit budgets of the bridge instructions.
+//!
+//! Runs each instruction against the compiled program in LiteSVM, prints the compute units it
+//! consumed and exits with an error when any of them exceeds its budget. Requires the program to
+//! be built first (`cargo-build-sbf`), then run with `cargo bench --bench compute_units`.
+//!
+//! Budgets include headroom so that only meaningful regressions fail; raise them deliberately when a
+//! change is expected to cost more compute.
+
+use std::process::ExitCode;
+
+use anchor_lang::{
+    prelude::*,
+    solana_program::{
+        bpf_loader_upgradeable, instruction::Instruction, native_token::LAMPORTS_PER_SOL,
+        program_option::COption, program_pack::Pack,
+    },
+    system_program, InstructionData,
+};
+use anchor_spl::token_interface::spl_token_2022::{
+    self,
+    state::{Account as TokenAccount, AccountState, Mint},
+};
+use bridge::{
+    accounts,
+    base_to_solana::{
+        constants::RELAY_DENY_LIST_SEED, IncomingMessage, Message as IncomingPayload, OutputRoot,
+    },
+    common::{
+        bridge::{BufferConfig, Eip1559Config, GasConfig, PartnerOracleConfig, ProtocolConfig},
+        BaseOracleConfig, Config, BRIDGE_SEED, DEX_ROUTER_LIST_SEED, GAS_FEE_ESCROW_SEED,
+        INSURANCE_FUND_SEED, MAX_SIGNER_COUNT, MINT_POLICY_SEED, PAYER_RATE_LIMIT_CONFIG_SEED,
+        PROTOCOL_FEE_CONFIG_SEED, SOL_VAULT_SEED, SOL_VAULT_STATE_SEED, TOKEN_DEPOSITS_SEED,
+        TOKEN_VAULT_SEED, VAULT_DECIMALS_SEED,
+    },
+    instruction,
+    solana_to_base::{Call, CallBuffer, CallType, OUTGOING_MESSAGE_SEED, PAYER_RATE_LIMIT_SEED},
+    BASE_CHAIN_ID, ID,
+};
+use litesvm::LiteSVM;
+use solana_account::Account;
+use solana_keypair::Keypair;
+use solana_loader_v3_interface::state::UpgradeableLoaderState;
+use solana_message::Message;
+use solana_signer::Signer;
+use solana_transaction::Transaction;
+
+/// Maximum compute units of `bridge_sol` without a call.
+const BRIDGE_SOL_BUDGET: u64 = 80_000;
+/// Maximum compute units of `bridge_spl` without a call, creating the token vault.
+const BRIDGE_SPL_BUDGET: u64 = 120_000;
+/// Maximum compute units of `bridge_call` with 1 KiB of call data.
+const BRIDGE_CALL_BUDGET: u64 = 60_000;
+/// Maximum compute units of `bridge_call_buffered` with a full call buffer (worst case).
+const BRIDGE_CALL_BUFFERED_BUDGET: u64 = 150_000;
+/// Maximum compute units of `relay_message` for a call without instructions.
+const RELAY_MESSAGE_BUDGET: u64 = 50_000;
+
+/// `max_call_buffer_size` of the benchmarked bridge.
+const MAX_CALL_BUFFER_SIZE: u64 = 8 * 1024;
+
+const GAS_FEE_RECEIVER: Pubkey = pubkey!("eEwCrQLBdQchykrkYitkYUZskd7MPrU2YxBXcPDPnMt");
+
+/// Name, runner returning the consumed compute units, and compute unit budget of a bench.
+type BenchCase = (&'static str, fn(&mut Bench) -> u64, u64);
+
+struct Bench {
+    svm: LiteSVM,
+    payer: Keypair,
+    bridge_pda: Pubkey,
+}
+
+impl Bench {
+    /// Deploys the bridge program as upgradeable and initializes it with the SOL vault.
+    fn new() -> Self {
+        let mut svm = LiteSVM::new();
+
+        let payer = Keypair::new();
+        svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL * 100)
+            .unwrap();
+
+        let mut clock = svm.get_sysvar::<Clock>();
+        clock.unix_timestamp = 1747440000;
+        svm.set_sysvar::<Clock>(&clock);
+
+        let program_bytes = include_bytes!("../../../target/deploy/bridge.so");
+        let (program_data_pda, _) =
+            Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID);
+
+        let mut program_data = bincode::serialize(&UpgradeableLoaderState::ProgramData {
+            slot: 0,
+            upgrade_authority_address: Some(payer.pubkey()),
+        })
+        .unwrap();
+        program_data.extend_from_slice(program_bytes);
+        set_account(
+            &mut svm,
+            program_data_pda,
+            bpf_loader_upgradeable::ID,
+            program_data,
+            false,
+        );
+
+        let program = bincode::serialize(&UpgradeableLoaderState::Program {
+            programdata_address: program_data_pda,
+        })
+        .unwrap();
+        set_account(&mut svm, ID, bpf_loader_upgradeable::ID, program, true);
+
+        let bridge_pda = Pubkey::find_program_address(&[BRIDGE_SEED], &ID).0;
+        let mut signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+        signers[0] = [1u8; 20];
+
+        let initialize_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::Initialize {
+                upgrade_authority: payer.pubkey(),
+                payer: payer.pubkey(),
+                bridge: bridge_pda,
+                program_data: program_data_pda,
+                program: ID,
+                gas_fee_receiver: GAS_FEE_RECEIVER,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::Initialize {
+                guardian: payer.pubkey(),
+                cfg: Config {
+                    eip1559_config: Eip1559Config {
+                        target: 5_000_000,
+                        denominator: 2,
+                        window_duration_seconds: 1,
+                        minimum_base_fee: 1,
+                        max_base_fee: 0,
+                        max_window_increase_bps: 0,
+                    },
+                    gas_config: GasConfig {
+                        gas_cost_scaler: 1_000_000,
+                        gas_cost_scaler_dp: 10u64.pow(6),
+                        gas_fee_receiver: GAS_FEE_RECEIVER,
+                        gas_per_call: 100_000,
+                    },
+                    protocol_config: ProtocolConfig {
+                        block_interval_requirement: 300,
+                        remote_sol_address: [1u8; 20],
+                        remote_chain_id: 0,
+                    },
+                    buffer_config: BufferConfig {
+                        max_call_buffer_size: MAX_CALL_BUFFER_SIZE,
+                        call_buffer_ttl_seconds: 0,
+                        expire_bounty_bps: 0,
+                    },
+                    partner_oracle_config: PartnerOracleConfig::default(),
+                    base_oracle_config: BaseOracleConfig {
+                        threshold: 1,
+                        signer_count: 1,
+                        signers,
+                    },
+                },
+            }
+            .data(),
+        };
+
+        let init_sol_vault_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::InitSolVault {
+                payer: payer.pubkey(),
+                sol_vault: sol_vault(),
+                sol_vault_state: pda(&[SOL_VAULT_STATE_SEED]),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: instruction::InitSolVault {}.data(),
+        };
+
+        let mut bench = Self {
+            svm,
+            payer,
+            bridge_pda,
+        };
+        bench.send(&[initialize_ix, init_sol_vault_ix], &[]);
+        bench
+            .svm
+            .airdrop(&GAS_FEE_RECEIVER, LAMPORTS_PER_SOL)
+            .unwrap();
+        bench
+    }
+
+    /// Sends `ixs` signed by the payer and `signers`, returning the consumed compute units.
+    fn send(&mut self, ixs: &[Instruction], signers: &[&Keypair]) -> u64 {
+        let mut all_signers = vec![&self.payer];
+        all_signers.extend_from_slice(signers);
+
+        let tx = Transaction::new(
+            all_signers.as_slice(),
+            Message::new(ixs, Some(&self.payer.pubkey())),
+            self.svm.latest_blockhash(),
+        );
+        self.svm
+            .send_transaction(tx)
+            .expect("Benchmarked transaction failed")
+            .compute_units_consumed
+    }
+
+    fn funded_keypair(&mut self) -> Keypair {
+        let keypair = Keypair::new();
+        self.svm
+            .airdrop(&keypair.pubkey(), LAMPORTS_PER_SOL * 5)
+            .unwrap();
+        keypair
+    }
+
+    fn bridge_sol(&mut self) -> u64 {
+        let from = self.funded_keypair();
+        let salt = [1u8; 32];
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::BridgeSol {
+                payer: self.payer.pubkey(),
+                from: from.pubkey(),
+                gas_fee_receiver: GAS_FEE_RECEIVER,
+                gas_fee_escrow: pda(&[GAS_FEE_ESCROW_SEED]),
+                insurance_fund: pda(&[INSURANCE_FUND_SEED]),
+                payer_rate_limit_config: pda(&[PAYER_RATE_LIMIT_CONFIG_SEED]),
+                payer_rate_limit: pda(&[PAYER_RATE_LIMIT_SEED, self.payer.pubkey().as_ref()]),
+                protocol_fee_config: pda(&[PROTOCOL_FEE_CONFIG_SEED]),
+                protocol_fee_treasury: Pubkey::new_unique(),
+                dex_router_list: pda(&[DEX_ROUTER_LIST_SEED]),
+                sol_vault_state: pda(&[SOL_VAULT_STATE_SEED]),
+                sol_vault: sol_vault(),
+                token_deposits: pda(&[TOKEN_DEPOSITS_SEED, sol_vault().as_ref()]),
+                bridge: self.bridge_pda,
+                outgoing_message: outgoing_message(&salt),
+                system_program: system_program::ID,
+                memo_program: None,
+            }
+            .to_account_metas(None),
+            data: instruction::BridgeSol {
+                outgoing_message_salt: salt,
+                to: [1u8; 20],
+                amount: LAMPORTS_PER_SOL,
+                call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
+            }
+            .data(),
+        };
+        self.send(&[ix], &[&from])
+    }
+
+    fn bridge_spl(&mut self) -> u64 {
+        let from = self.funded_keypair();
+        let salt = [2u8; 32];
+        let remote_token = [2u8; 20];
+
+        let mint = Pubkey::new_unique();
+        let mut mint_data = vec![0u8; Mint::LEN];
+        Mint {
+            mint_authority: COption::Some(mint),
+            supply: 1_000_000_000,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        }
+        .pack_into_slice(&mut mint_data);
+        set_account(&mut self.svm, mint, spl_token_2022::ID, mint_data, false);
+
+        let from_token_account = Pubkey::new_unique();
+        let mut token_account_data = vec![0u8; TokenAccount::LEN];
+        TokenAccount {
+            mint,
+            owner: from.pubkey(),
+            amount: 1_000_000,
+            delegate: COption::None,
+            state: AccountState::Initialized,
+            is_native: COption::None,
+            delegated_amount: 0,
+            close_authority: COption::None,
+        }
+        .pack_into_slice(&mut token_account_data);
+        set_account(
+            &mut self.svm,
+            from_token_account,
+            spl_token_2022::ID,
+            token_account_data,
+            false,
+        );
+
+        let token_vault = pda(&[TOKEN_VAULT_SEED, mint.as_ref(), remote_token.as_ref()]);
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::BridgeSpl {
+                payer: self.payer.pubkey(),
+                from: from.pubkey(),
+                gas_fee_receiver: GAS_FEE_RECEIVER,
+                gas_fee_escrow: None,
+                insurance_fund: pda(&[INSURANCE_FUND_SEED]),
+                payer_rate_limit_config: pda(&[PAYER_RATE_LIMIT_CONFIG_SEED]),
+                payer_rate_limit: None,
+                protocol_fee_config: pda(&[PROTOCOL_FEE_CONFIG_SEED]),
+                protocol_fee_treasury: None,
+                dex_router_list: None,
+                mint_policy: pda(&[MINT_POLICY_SEED]),
+                mint_allow_list: None,
+                mint,
+                from_token_account,
+                bridge: self.bridge_pda,
+                token_vault,
+                token_deposits: pda(&[TOKEN_DEPOSITS_SEED, token_vault.as_ref()]),
+                vault_decimals: pda(&[VAULT_DECIMALS_SEED, token_vault.as_ref()]),
+                outgoing_message: outgoing_message(&salt),
+                token_program: spl_token_2022::ID,
+                system_program: system_program::ID,
+                memo_program: None,
+                bridge_session: None,
+            }
+            .to_account_metas(None),
+            data: instruction::BridgeSpl {
+                outgoing_message_salt: salt,
+                to: [1u8; 20],
+                remote_token,
+                amount: 500_000,
+                call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
+            }
+            .data(),
+        };
+        self.send(&[ix], &[&from])
+    }
+
+    fn bridge_call(&mut self) -> u64 {
+        let from = self.funded_keypair();
+        let salt = [3u8; 32];
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::BridgeCall {
+                payer: self.payer.pubkey(),
+                from: from.pubkey(),
+                gas_fee_receiver: GAS_FEE_RECEIVER,
+                gas_fee_escrow: pda(&[GAS_FEE_ESCROW_SEED]),
+                insurance_fund: pda(&[INSURANCE_FUND_SEED]),
+                payer_rate_limit_config: pda(&[PAYER_RATE_LIMIT_CONFIG_SEED]),
+                payer_rate_limit: pda(&[PAYER_RATE_LIMIT_SEED, self.payer.pubkey().as_ref()]),
+                bridge: self.bridge_pda,
+                outgoing_message: outgoing_message(&salt),
+                system_program: system_program::ID,
+                memo_program: None,
+            }
+            .to_account_metas(None),
+            data: instruction::BridgeCall {
+                outgoing_message_salt: salt,
+                call: Call {
+                    ty: CallType::Call,
+                    to: [1u8; 20],
+                    value: 0,
+                    data: vec![0xab; 1024],
+                },
+                spl_memo: false,
+            }
+            .data(),
+        };
+        self.send(&[ix], &[&from])
+    }
+
+    /// Bridges a call buffer filled up to `max_call_buffer_size`. Buffers cannot be filled in a
+    /// single transaction, so the buffer account is written directly.
+    fn bridge_call_buffered(&mut self) -> u64 {
+        let from = self.funded_keypair();
+        let owner = self.funded_keypair();
+        let salt = [4u8; 32];
+
+        let call_buffer = Pubkey::new_unique();
+        let mut data = Vec::new();
+        CallBuffer {
+            owner: owner.pubkey(),
+            ty: CallType::Call,
+            to: [1u8; 20],
+            value: 0,
+            last_modified: 0,
+            reusable: false,
+            use_count: 0,
+            data: vec![0xab; MAX_CALL_BUFFER_SIZE as usize],
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+        set_account(&mut self.svm, call_buffer, ID, data, false);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::BridgeCallBuffered {
+                payer: self.payer.pubkey(),
+                from: from.pubkey(),
+                gas_fee_receiver: GAS_FEE_RECEIVER,
+                gas_fee_escrow: pda(&[GAS_FEE_ESCROW_SEED]),
+                insurance_fund: pda(&[INSURANCE_FUND_SEED]),
+                payer_rate_limit_config: pda(&[PAYER_RATE_LIMIT_CONFIG_SEED]),
+                payer_rate_limit: pda(&[PAYER_RATE_LIMIT_SEED, self.payer.pubkey().as_ref()]),
+                bridge: self.bridge_pda,
+                owner: owner.pubkey(),
+                call_buffer,
+                outgoing_message: outgoing_message(&salt),
+                system_program: system_program::ID,
+                memo_program: None,
+            }
+            .to_account_metas(None),
+            data: instruction::BridgeCallBuffered {
+                outgoing_message_salt: salt,
+                spl_memo: false,
+            }
+            .data(),
+        };
+        self.send(&[ix], &[&from, &owner])
+    }
+
+    /// Relays an already proven call message. Proving is benchmarked separately as it depends
+    /// on the MMR proof size.
+    fn relay_message(&mut self) -> u64 {
+        let output_root = Pubkey::new_unique();
+        let mut data = Vec::new();
+        OutputRoot {
+            root: [0u8; 32],
+            total_leaf_count: 1,
+            base_block_number: 0,
+            registered_at: 0,
+            revision: 0,
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+        set_account(&mut self.svm, output_root, ID, data, false);
+
+        let message = Pubkey::new_unique();
+        let mut data = Vec::new();
+        IncomingMessage {
+            sender: [1u8; 20],
+            remote_chain_id: BASE_CHAIN_ID,
+            message: IncomingPayload::Call(vec![]),
+            executed: false,
+            chunk_count: 0,
+            output_root,
+            output_root_revision: 0,
+            prover: self.payer.pubkey(),
+            nonce: 0,
+            proven_at: 0,
+            vetoed: false,
+            outflow_held: false,
+            outflow_approved: false,
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+        set_account(&mut self.svm, message, ID, data, false);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::RelayMessage {
+                message,
+                output_root,
+                prover: self.payer.pubkey(),
+                bridge: self.bridge_pda,
+                relay_deny_list: pda(&[RELAY_DENY_LIST_SEED]),
+                relay_fee_vault: None,
+                relayer: None,
+                operational_vault: None,
+                bridge_cpi_authority: None,
+                system_program: None,
+            }
+            .to_account_metas(None),
+            data: instruction::RelayMessage {}.data(),
+        };
+        self.send(&[ix], &[])
+    }
+}
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &ID).0
+}
+
+fn sol_vault() -> Pubkey {
+    pda(&[SOL_VAULT_SEED])
+}
+
+fn outgoing_message(salt: &[u8; 32]) -> Pubkey {
+    pda(&[OUTGOING_MESSAGE_SEED, salt.as_ref()])
+}
+
+fn set_account(svm: &mut LiteSVM, key: Pubkey, owner: Pubkey, data: Vec<u8>, executable: bool) {
+    svm.set_account(
+        key,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner,
+            executable,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+fn main() -> ExitCode {
+    let benches: [BenchCase; 5] = [
+        ("bridge_sol", Bench::bridge_sol, BRIDGE_SOL_BUDGET),
+        ("bridge_spl", Bench::bridge_spl, BRIDGE_SPL_BUDGET),
+        ("bridge_call", Bench::bridge_call, BRIDGE_CALL_BUDGET),
+        (
+            "bridge_call_buffered",
+            Bench::bridge_call_buffered,
+            BRIDGE_CALL_BUFFERED_BUDGET,
+        ),
+        ("relay_message", Bench::relay_message, RELAY_MESSAGE_BUDGET),
+    ];
+
+    let mut regressions = 0;
+    for (name, bench, budget) in benches {
+        let consumed = bench(&mut Bench::new());
+        let status = if consumed <= budget {
+            "ok"
+        } else {
+            regressions += 1;
+            "OVER BUDGET"
+        };
+        println!("{name:<24} {consumed:>8} / {budget:>8} CU  {status}");
+    }
+
+    if regressions > 0 {
+        eprintln!("{regressions} instruction(s) exceeded their compute unit budget");
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
@@ -0,0 +1,6 @@
+#![no_main]
+
+use bridge::base_to_solana::internal::mmr::fuzzing::check_proof_verification;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| check_proof_verification(data));
@@ -1,12 +1,79 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program};
 
 #[constant]
 pub const INCOMING_MESSAGE_SEED: &[u8] = b"incoming_message";
 #[constant]
+pub const PROVEN_CHUNK_SEED: &[u8] = b"proven_chunk";
+/// Maximum number of `ProvenChunk` accounts a single chunked incoming message is split into.
+#[constant]
+pub const MAX_PROVEN_CHUNKS: u8 = 16;
+#[constant]
 pub const OUTPUT_ROOT_SEED: &[u8] = b"output_root";
+/// Seconds after its registration during which an output root can be superseded by the oracles.
+#[constant]
+pub const OUTPUT_ROOT_CHALLENGE_WINDOW_SECONDS: i64 = 60 * 60;
+/// Maximum number of output roots registered by a single `register_output_roots_batch` call.
+#[constant]
+pub const MAX_OUTPUT_ROOTS_PER_BATCH: u8 = 8;
+#[constant]
+pub const EQUIVOCATION_EVIDENCE_SEED: &[u8] = b"equivocation_evidence";
+#[constant]
+pub const RETURNED_TRANSFER_SEED: &[u8] = b"returned_transfer";
+#[constant]
+pub const RELAY_FEE_VAULT_SEED: &[u8] = b"relay_fee_vault";
 #[constant]
 pub const BRIDGE_CPI_AUTHORITY_SEED: &[u8] = b"bridge_cpi_authority";
 #[constant]
+pub const MAINTENANCE_BUDGET_SEED: &[u8] = b"maintenance_budget";
+/// Maximum fee reimbursement paid by the maintenance budget per output root registration.
+#[constant]
+pub const MAX_MAINTENANCE_FEE_REIMBURSEMENT: u64 = 1_000_000;
+#[constant]
+pub const OPERATIONAL_VAULT_SEED: &[u8] = b"operational_vault";
+/// Maximum lamport allowance the guardian can let a single `WithLamportAllowance` message draw
+/// from the operational vault (10 SOL).
+#[constant]
+pub const MAX_LAMPORT_ALLOWANCE: u64 = 10 * solana_program::native_token::LAMPORTS_PER_SOL;
+#[constant]
+pub const RELAY_DENY_LIST_SEED: &[u8] = b"relay_deny_list";
+/// Maximum number of programs the guardian can add to the built-in `DENIED_RELAY_PROGRAMS`.
+#[constant]
+pub const MAX_RELAY_DENY_LIST_PROGRAMS: u8 = 16;
+/// Privileged programs the bridge CPI authority never invokes from a relayed message: the program
+/// loaders, which could deploy or upgrade programs it is the authority of, and the native stake,
+/// vote and config programs.
+pub const DENIED_RELAY_PROGRAMS: [Pubkey; 7] = [
+    solana_program::bpf_loader_upgradeable::ID,
+    solana_program::bpf_loader::ID,
+    solana_program::bpf_loader_deprecated::ID,
+    pubkey!("LoaderV411111111111111111111111111111111111"),
+    pubkey!("Stake11111111111111111111111111111111111111"),
+    pubkey!("Vote111111111111111111111111111111111111111"),
+    pubkey!("Config1111111111111111111111111111111111111"),
+];
+/// Maximum number of extra PDAs (derived from the bridge CPI authority) a message can request to sign with.
+#[constant]
+pub const MAX_EXTRA_SIGNERS: u8 = 4;
+/// Maximum number of custom seeds per extra signer. The bridge CPI authority seed, the sender and the bump
+/// take 3 of the 16 seeds allowed by the runtime.
+#[constant]
+pub const MAX_EXTRA_SIGNER_SEEDS: u8 = 13;
+/// Maximum number of instructions executed by a single incoming message.
+#[constant]
+pub const MAX_MESSAGE_IXS: u8 = 16;
+/// Maximum number of accounts of a single incoming message instruction.
+#[constant]
+pub const MAX_IX_ACCOUNTS: u8 = 64;
+/// Maximum total instruction data, in bytes, of an incoming message: the runtime limit of a single CPI.
+#[constant]
+pub const MAX_MESSAGE_IXS_DATA_LEN: u16 = 10 * 1024;
+#[constant]
+pub const HANDLER_REGISTRATION_SEED: &[u8] = b"handler_registration";
+/// Maximum payload, in bytes, of a `CallHandler` message, so that the handler instruction data (its
+/// discriminator, then the sender, nonce and length-prefixed payload) fits the runtime CPI limit.
+#[constant]
+pub const MAX_HANDLER_PAYLOAD_LEN: u16 = MAX_MESSAGE_IXS_DATA_LEN - (8 + 20 + 8 + 4);
+#[constant]
 pub const PARTNER_SIGNERS_ACCOUNT_SEED: &[u8] = b"signers";
 #[constant]
 pub const PARTNER_PROGRAM_ID: Pubkey = pubkey!("S1GN4jus9XzKVVnoHqfkjo1GN8bX46gjXZQwsdGBPHE");
@@ -0,0 +1,278 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::{constants::INCOMING_MESSAGE_SEED, state::IncomingMessage},
+    common::{bridge::Bridge, BRIDGE_SEED},
+    BridgeError, OutflowApproved,
+};
+
+/// Accounts struct for the `approve_outflow` instruction that lets the guardian release a transfer
+/// held by the outflow circuit breaker.
+#[derive(Accounts)]
+#[instruction(message_hash: [u8; 32])]
+pub struct ApproveOutflow<'info> {
+    /// The guardian account authorized to approve outflows
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The incoming message whose outflow is approved.
+    #[account(
+        mut,
+        seeds = [INCOMING_MESSAGE_SEED, &message_hash],
+        bump,
+        constraint = !message.executed @ BridgeError::AlreadyExecuted,
+        constraint = message.outflow_held @ BridgeError::OutflowNotHeld,
+    )]
+    pub message: Account<'info, IncomingMessage>,
+}
+
+pub fn approve_outflow_handler(ctx: Context<ApproveOutflow>, message_hash: [u8; 32]) -> Result<()> {
+    ctx.accounts.message.outflow_approved = true;
+
+    emit!(OutflowApproved {
+        message_hash,
+        guardian: ctx.accounts.guardian.key(),
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{
+            instruction::{AccountMeta, Instruction},
+            native_token::LAMPORTS_PER_SOL,
+            system_program,
+        },
+        InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message as SvmMessage;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::{token::FinalizeBridgeSol, Message, Transfer},
+        common::{bridge::OutflowThreshold, TokenDeposits, SOL_VAULT_SEED},
+        instruction::{ApproveOutflow as ApproveOutflowIx, RelayMessage as RelayMessageIx},
+        solana_to_base::NATIVE_SOL_PUBKEY,
+        test_utils::{
+            mock_output_root, relay_deny_list_pda, set_mock_pause, set_program_account,
+            setup_bridge, token_deposits_pda, SetupBridgeResult,
+        },
+        BASE_CHAIN_ID, ID,
+    };
+
+    /// Writes a SOL transfer of `amount` to `to` tripping the outflow circuit breaker, returning
+    /// its hash and address.
+    fn mock_transfer(
+        svm: &mut LiteSVM,
+        bridge_pda: Pubkey,
+        prover: Pubkey,
+        to: Pubkey,
+        amount: u64,
+    ) -> ([u8; 32], Pubkey) {
+        let account = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &account.data[..]).unwrap();
+        bridge
+            .outflow_breaker
+            .set(
+                3_600,
+                &[OutflowThreshold {
+                    local_token: NATIVE_SOL_PUBKEY,
+                    threshold: amount - 1,
+                }],
+            )
+            .unwrap();
+        set_program_account(svm, bridge_pda, &bridge);
+
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        let mut vault = svm.get_account(&sol_vault).unwrap();
+        vault.lamports += amount;
+        svm.set_account(sol_vault, vault).unwrap();
+        set_program_account(
+            svm,
+            token_deposits_pda(&sol_vault),
+            &TokenDeposits {
+                vault: sol_vault,
+                locked_amount: amount,
+            },
+        );
+
+        let message_hash = [5u8; 32];
+        let message = Pubkey::find_program_address(&[INCOMING_MESSAGE_SEED, &message_hash], &ID).0;
+        let output_root = mock_output_root(svm, 0, 0);
+        set_program_account(
+            svm,
+            message,
+            &IncomingMessage {
+                sender: [7u8; 20],
+                remote_chain_id: BASE_CHAIN_ID,
+                message: Message::Transfer {
+                    transfer: Transfer::Sol(FinalizeBridgeSol { to, amount }),
+                    ixs: vec![],
+                },
+                executed: false,
+                chunk_count: 0,
+                output_root,
+                output_root_revision: 0,
+                prover,
+                nonce: 0,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+        (message_hash, message)
+    }
+
+    fn relay_transfer(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        message: Pubkey,
+        to: Pubkey,
+    ) -> std::result::Result<(), String> {
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        let mut accounts = accounts::RelayMessage {
+            message,
+            output_root: mock_output_root(svm, 0, 0),
+            prover: payer.pubkey(),
+            bridge: bridge_pda,
+            relay_deny_list: relay_deny_list_pda(),
+            relay_fee_vault: None,
+            relayer: None,
+            operational_vault: None,
+            bridge_cpi_authority: None,
+            system_program: None,
+        }
+        .to_account_metas(None);
+        accounts.extend([
+            AccountMeta::new(sol_vault, false),
+            AccountMeta::new(to, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(token_deposits_pda(&sol_vault), false),
+        ]);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: RelayMessageIx {}.data(),
+        };
+        let tx = Transaction::new(
+            &[payer],
+            SvmMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn approve_outflow(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        message_hash: [u8; 32],
+        message: Pubkey,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::ApproveOutflow {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                message,
+            }
+            .to_account_metas(None),
+            data: ApproveOutflowIx { message_hash }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            SvmMessage::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn is_paused(svm: &LiteSVM, bridge_pda: Pubkey) -> bool {
+        let account = svm.get_account(&bridge_pda).unwrap();
+        Bridge::try_deserialize(&mut &account.data[..])
+            .unwrap()
+            .paused
+    }
+
+    #[test]
+    fn test_approve_outflow_releases_tripped_transfer_after_unpause() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let to = Pubkey::new_unique();
+        let (message_hash, message) =
+            mock_transfer(&mut svm, bridge_pda, payer.pubkey(), to, LAMPORTS_PER_SOL);
+
+        // The first relay trips the breaker and holds the message
+        relay_transfer(&mut svm, &payer, bridge_pda, message, to)
+            .expect("Failed to relay transfer");
+        assert!(is_paused(&svm, bridge_pda));
+        assert!(svm.get_account(&to).is_none());
+
+        // Once unpaused, the held message cannot trip the breaker again
+        set_mock_pause(&mut svm, bridge_pda, false);
+        let error = relay_transfer(&mut svm, &payer, bridge_pda, message, to).unwrap_err();
+        assert!(
+            error.contains("OutflowApprovalRequired"),
+            "Expected OutflowApprovalRequired error, got: {}",
+            error
+        );
+        assert!(!is_paused(&svm, bridge_pda));
+
+        approve_outflow(&mut svm, &guardian, bridge_pda, message_hash, message)
+            .expect("Failed to approve outflow");
+        relay_transfer(&mut svm, &payer, bridge_pda, message, to)
+            .expect("Failed to relay approved transfer");
+        assert_eq!(svm.get_account(&to).unwrap().lamports, LAMPORTS_PER_SOL);
+        assert!(!is_paused(&svm, bridge_pda));
+    }
+
+    #[test]
+    fn test_approve_outflow_of_message_not_held_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let to = Pubkey::new_unique();
+        let (message_hash, message) =
+            mock_transfer(&mut svm, bridge_pda, payer.pubkey(), to, LAMPORTS_PER_SOL);
+
+        let error =
+            approve_outflow(&mut svm, &guardian, bridge_pda, message_hash, message).unwrap_err();
+        assert!(
+            error.contains("OutflowNotHeld"),
+            "Expected OutflowNotHeld error, got: {}",
+            error
+        );
+    }
+}
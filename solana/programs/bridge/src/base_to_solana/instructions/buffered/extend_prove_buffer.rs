@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+
+use crate::base_to_solana::ProveBuffer;
+use crate::BridgeError;
+
+/// Grows a `ProveBuffer` beyond the capacity it was initialized with, for messages larger than an
+/// account can be created with. Each call can add at most `MAX_PERMITTED_DATA_INCREASE` bytes.
+#[derive(Accounts)]
+#[instruction(additional_len: u64)]
+pub struct ExtendProveBuffer<'info> {
+    /// Owner authorized to modify the buffer; pays the rent of the added capacity
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Prove buffer account to grow
+    #[account(
+        mut,
+        has_one = owner @ BridgeError::BufferUnauthorizedAppend,
+        realloc = prove_buffer.to_account_info().data_len() + additional_len as usize,
+        realloc::payer = owner,
+        realloc::zero = false,
+    )]
+    pub prove_buffer: Account<'info, ProveBuffer>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn extend_prove_buffer_handler(
+    _ctx: Context<ExtendProveBuffer>,
+    _additional_len: u64,
+) -> Result<()> {
+    // The account is grown by Anchor due to the `realloc` constraint
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, system_program, InstructionData};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::{ExtendProveBuffer as ExtendProveBufferIx, InitializeProveBuffer},
+        test_utils::{setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    #[test]
+    fn test_extend_prove_buffer_grows_capacity() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let prove_buffer = Keypair::new();
+        let init_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::InitializeProveBuffer {
+                payer: payer.pubkey(),
+                bridge: bridge_pda,
+                prove_buffer: prove_buffer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: InitializeProveBuffer {
+                max_data_len: 1024,
+                max_proof_len: 8,
+            }
+            .data(),
+        };
+        let tx = Transaction::new(
+            &[&payer, &prove_buffer],
+            Message::new(&[init_ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+        let initial_len = svm.get_account(&prove_buffer.pubkey()).unwrap().data.len();
+
+        // Extend twice, each by the maximum increase allowed in a single instruction
+        for _ in 0..2 {
+            let ix = Instruction {
+                program_id: ID,
+                accounts: accounts::ExtendProveBuffer {
+                    owner: payer.pubkey(),
+                    prove_buffer: prove_buffer.pubkey(),
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: ExtendProveBufferIx {
+                    additional_len: 10 * 1024,
+                }
+                .data(),
+            };
+            let tx = Transaction::new(
+                &[&payer],
+                Message::new(&[ix], Some(&payer.pubkey())),
+                svm.latest_blockhash(),
+            );
+            svm.send_transaction(tx).unwrap();
+            svm.expire_blockhash();
+        }
+
+        let account = svm.get_account(&prove_buffer.pubkey()).unwrap();
+        assert_eq!(account.data.len(), initial_len + 20 * 1024);
+        assert_eq!(
+            account.lamports,
+            svm.minimum_balance_for_rent_exemption(account.data.len())
+        );
+
+        let buffer = ProveBuffer::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(buffer.owner, payer.pubkey());
+        assert!(buffer.data.is_empty());
+    }
+
+    #[test]
+    fn test_extend_prove_buffer_unauthorized() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let prove_buffer = Keypair::new();
+        let init_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::InitializeProveBuffer {
+                payer: payer.pubkey(),
+                bridge: bridge_pda,
+                prove_buffer: prove_buffer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: InitializeProveBuffer {
+                max_data_len: 1024,
+                max_proof_len: 8,
+            }
+            .data(),
+        };
+        let tx = Transaction::new(
+            &[&payer, &prove_buffer],
+            Message::new(&[init_ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+
+        let attacker = Keypair::new();
+        svm.airdrop(&attacker.pubkey(), 1_000_000_000).unwrap();
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::ExtendProveBuffer {
+                owner: attacker.pubkey(),
+                prove_buffer: prove_buffer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: ExtendProveBufferIx {
+                additional_len: 1024,
+            }
+            .data(),
+        };
+        let tx = Transaction::new(
+            &[&attacker],
+            Message::new(&[ix], Some(&attacker.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let err = format!("{:?}", svm.send_transaction(tx).unwrap_err());
+        assert!(err.contains("Unauthorized"), "Unexpected error: {}", err);
+    }
+}
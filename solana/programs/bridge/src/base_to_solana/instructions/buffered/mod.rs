@@ -1,11 +1,15 @@
 pub mod append_to_prove_buffer_data;
 pub mod append_to_prove_buffer_proof;
 pub mod close_prove_buffer;
+pub mod extend_prove_buffer;
 pub mod initialize_prove_buffer;
 pub mod prove_message_buffered;
+pub mod prove_message_chunked;
 
 pub use append_to_prove_buffer_data::*;
 pub use append_to_prove_buffer_proof::*;
 pub use close_prove_buffer::*;
+pub use extend_prove_buffer::*;
 pub use initialize_prove_buffer::*;
 pub use prove_message_buffered::*;
+pub use prove_message_chunked::*;
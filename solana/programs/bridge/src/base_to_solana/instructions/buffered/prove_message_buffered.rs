@@ -1,13 +1,13 @@
 use anchor_lang::{prelude::*, solana_program::keccak};
 
-use crate::BridgeError;
 use crate::{
     base_to_solana::{
-        constants::INCOMING_MESSAGE_SEED, internal::mmr, state::IncomingMessage, Message,
+        bridge_cpi_authority, constants::INCOMING_MESSAGE_SEED, state::IncomingMessage, Message,
         OutputRoot, ProveBuffer,
     },
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    common::{bridge::Bridge, AddressBook, ADDRESS_BOOK_SEED, BRIDGE_SEED, DISCRIMINATOR_LEN},
 };
+use crate::{BridgeError, MessageProven};
 
 /// Buffered variant of `prove_message` that reads data/proof from a `ProveBuffer` and closes it.
 #[derive(Accounts)]
@@ -34,6 +34,10 @@ pub struct ProveMessageBuffered<'info> {
     #[account(seeds = [BRIDGE_SEED], bump)]
     pub bridge: Account<'info, Bridge>,
 
+    /// Address book holding the canonical Base Bridge, required for `ReturnTransfer` messages
+    #[account(seeds = [ADDRESS_BOOK_SEED], bump)]
+    pub address_book: Option<Account<'info, AddressBook>>,
+
     /// Owner receives rent when buffer is closed
     #[account(mut)]
     pub owner: Signer<'info>,
@@ -67,26 +71,50 @@ pub fn prove_message_buffered_handler(
     );
 
     // Verify proof
-    mmr::verify_proof(
-        &ctx.accounts.output_root.root,
+    ctx.accounts.output_root.verify_message_proof(
         &message_hash,
-        &nonce,
+        nonce,
         &ctx.accounts.prove_buffer.proof,
-        ctx.accounts.output_root.total_leaf_count,
     )?;
 
     // Deserialize and save
     let message_enum = Message::try_from_slice(data)?;
+    message_enum.validate(&sender, ctx.accounts.address_book.as_deref())?;
+    ctx.accounts
+        .output_root
+        .check_freshness(&ctx.accounts.bridge, &message_enum)?;
     *ctx.accounts.message = IncomingMessage {
         executed: false,
+        chunk_count: 0,
         sender,
+        remote_chain_id: ctx.accounts.bridge.remote_chain_id(),
         message: message_enum,
+        output_root: ctx.accounts.output_root.key(),
+        output_root_revision: ctx.accounts.output_root.revision,
+        prover: ctx.accounts.payer.key(),
+        nonce,
+        proven_at: Clock::get()?.unix_timestamp,
+        vetoed: false,
+        outflow_held: false,
+        outflow_approved: false,
     };
 
+    emit!(MessageProven {
+        nonce,
+        sender_base: sender,
+        message_hash,
+        prover: ctx.accounts.payer.key(),
+        finalizable_at: ctx
+            .accounts
+            .message
+            .finalizable_at(&ctx.accounts.bridge.finalization_delay),
+        cpi_authority: bridge_cpi_authority(&sender).0,
+    });
+
     Ok(())
 }
 
-fn hash_message(nonce: &[u8], sender: &[u8; 20], data: &[u8]) -> [u8; 32] {
+pub(super) fn hash_message(nonce: &[u8], sender: &[u8; 20], data: &[u8]) -> [u8; 32] {
     let mut data_to_hash = Vec::new();
     data_to_hash.extend_from_slice(nonce);
     data_to_hash.extend_from_slice(sender);
@@ -110,13 +138,15 @@ mod tests {
 
     use crate::{
         accounts,
-        base_to_solana::{state::IncomingMessage, Message as BridgeMessage},
+        base_to_solana::{
+            state::IncomingMessage, token::FinalizeBridgeSol, Message as BridgeMessage, Transfer,
+        },
         common::bridge::Bridge,
         instruction::{
             AppendToProveBufferData, AppendToProveBufferProof, InitializeProveBuffer,
             ProveMessageBuffered as ProveMessageBufferedIx,
         },
-        test_utils::{setup_bridge, SetupBridgeResult},
+        test_utils::{address_book_pda, setup_bridge, SetupBridgeResult},
         ID,
     };
 
@@ -129,6 +159,9 @@ mod tests {
         let output_root = crate::base_to_solana::state::OutputRoot {
             root,
             total_leaf_count,
+            base_block_number: 0,
+            registered_at: 0,
+            revision: 0,
         };
         let mut data = Vec::new();
         output_root.try_serialize(&mut data).unwrap();
@@ -156,6 +189,7 @@ mod tests {
     fn buffered_message_setup(
         svm: &mut LiteSVM,
         bridge_pda: Pubkey,
+        message: BridgeMessage,
     ) -> ([u8; 32], Pubkey, Keypair, Keypair, u64, [u8; 20], Vec<u8>) {
         // Owner of the prove buffer
         let owner = Keypair::new();
@@ -190,7 +224,6 @@ mod tests {
             .expect("initialize_prove_buffer should succeed");
 
         // Build message data and append to buffer
-        let message = BridgeMessage::Call(vec![]);
         let message_bytes = message.try_to_vec().unwrap();
 
         let append_data_accounts = accounts::AppendToProveBufferData {
@@ -265,7 +298,7 @@ mod tests {
         } = setup_bridge();
 
         let (message_hash, output_root_pk, owner, prove_buffer, nonce, sender, message_bytes) =
-            buffered_message_setup(&mut svm, bridge_pda);
+            buffered_message_setup(&mut svm, bridge_pda, BridgeMessage::Call(vec![]));
 
         // Incoming message PDA derived from seed and message_hash
         let incoming_pda = Pubkey::find_program_address(
@@ -283,6 +316,7 @@ mod tests {
             output_root: output_root_pk,
             message: incoming_pda,
             bridge: bridge_pda,
+            address_book: None,
             owner: owner.pubkey(),
             prove_buffer: prove_buffer.pubkey(),
             system_program: system_program::ID,
@@ -334,7 +368,7 @@ mod tests {
         } = setup_bridge();
 
         let (message_hash, output_root_pk, _, prove_buffer, nonce, sender, _) =
-            buffered_message_setup(&mut svm, bridge_pda);
+            buffered_message_setup(&mut svm, bridge_pda, BridgeMessage::Call(vec![]));
 
         let incoming_pda = Pubkey::find_program_address(
             &[
@@ -354,6 +388,7 @@ mod tests {
             output_root: output_root_pk,
             message: incoming_pda,
             bridge: bridge_pda,
+            address_book: None,
             owner: unauthorized.pubkey(), // wrong owner
             prove_buffer: prove_buffer.pubkey(),
             system_program: system_program::ID,
@@ -460,6 +495,7 @@ mod tests {
             output_root: output_root_pk,
             message: incoming_pda,
             bridge: bridge_pda,
+            address_book: None,
             owner: owner.pubkey(),
             prove_buffer: prove_buffer.pubkey(),
             system_program: system_program::ID,
@@ -498,7 +534,7 @@ mod tests {
         } = setup_bridge();
 
         let (_, output_root_pk, owner, prove_buffer, _, _, _) =
-            buffered_message_setup(&mut svm, bridge_pda);
+            buffered_message_setup(&mut svm, bridge_pda, BridgeMessage::Call(vec![]));
 
         // Pause the bridge
         let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
@@ -523,6 +559,7 @@ mod tests {
             output_root: output_root_pk,
             message: incoming_pda,
             bridge: bridge_pda,
+            address_book: None,
             owner: owner.pubkey(),
             prove_buffer: prove_buffer.pubkey(),
             system_program: system_program::ID,
@@ -550,4 +587,185 @@ mod tests {
         let err = format!("{:?}", result.unwrap_err());
         assert!(err.contains("BridgePaused"), "unexpected error: {}", err);
     }
+
+    #[test]
+    fn test_prove_message_buffered_return_transfer_requires_base_bridge_sender() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let message = BridgeMessage::ReturnTransfer {
+            outgoing_nonce: 0,
+            transfer: Transfer::Sol(FinalizeBridgeSol {
+                to: Pubkey::new_unique(),
+                amount: 1_000,
+            }),
+        };
+        let (message_hash, output_root_pk, owner, prove_buffer, nonce, sender, _) =
+            buffered_message_setup(&mut svm, bridge_pda, message);
+
+        let incoming_pda = Pubkey::find_program_address(
+            &[
+                crate::base_to_solana::constants::INCOMING_MESSAGE_SEED,
+                &message_hash,
+            ],
+            &ID,
+        )
+        .0;
+
+        let set_address_book_bridge = |svm: &mut LiteSVM, bridge: [u8; 20]| {
+            let mut data = Vec::new();
+            AddressBook {
+                bridge,
+                relayer_orchestrator: [0u8; 20],
+                token_count: 0,
+                tokens: Default::default(),
+            }
+            .try_serialize(&mut data)
+            .unwrap();
+            svm.set_account(
+                address_book_pda(),
+                SvmAccount {
+                    lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+                    data,
+                    owner: ID,
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            )
+            .unwrap();
+        };
+
+        let prove = |svm: &mut LiteSVM, address_book: Option<Pubkey>| {
+            svm.expire_blockhash();
+
+            let prove_ix = Instruction {
+                program_id: ID,
+                accounts: accounts::ProveMessageBuffered {
+                    payer: payer.pubkey(),
+                    output_root: output_root_pk,
+                    message: incoming_pda,
+                    bridge: bridge_pda,
+                    address_book,
+                    owner: owner.pubkey(),
+                    prove_buffer: prove_buffer.pubkey(),
+                    system_program: system_program::ID,
+                }
+                .to_account_metas(None),
+                data: ProveMessageBufferedIx {
+                    nonce,
+                    sender,
+                    message_hash,
+                }
+                .data(),
+            };
+
+            let tx = Transaction::new(
+                &[&payer, &owner],
+                SolMessage::new(&[prove_ix], Some(&payer.pubkey())),
+                svm.latest_blockhash(),
+            );
+            svm.send_transaction(tx)
+                .map(|_| ())
+                .map_err(|e| format!("{:?}", e))
+        };
+
+        // The address book is required to check the sender
+        let err = prove(&mut svm, None).unwrap_err();
+        assert!(
+            err.contains("MissingAddressBook"),
+            "unexpected error: {}",
+            err
+        );
+
+        // A sender other than the Base Bridge is rejected at prove time
+        set_address_book_bridge(&mut svm, [9u8; 20]);
+        let err = prove(&mut svm, Some(address_book_pda())).unwrap_err();
+        assert!(
+            err.contains("UnauthorizedReturnTransfer"),
+            "unexpected error: {}",
+            err
+        );
+
+        // The Base Bridge can prove return transfers
+        set_address_book_bridge(&mut svm, sender);
+        prove(&mut svm, Some(address_book_pda()))
+            .expect("prove_message_buffered should succeed for the Base Bridge sender");
+    }
+
+    #[test]
+    fn test_prove_message_buffered_rejects_stale_root() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // The output root was registered at block 0 and the latest one at block 100
+        let (message_hash, output_root_pk, owner, prove_buffer, nonce, sender, _) =
+            buffered_message_setup(&mut svm, bridge_pda, BridgeMessage::Call(vec![]));
+        let set_call_max_root_age = |svm: &mut LiteSVM, call_max_root_age: u64| {
+            let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
+            let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+            bridge.base_block_number = 100;
+            bridge.root_freshness_config.call_max_root_age = call_max_root_age;
+            let mut data = Vec::new();
+            bridge.try_serialize(&mut data).unwrap();
+            bridge_account.data = data;
+            svm.set_account(bridge_pda, bridge_account).unwrap();
+        };
+
+        let incoming_pda = Pubkey::find_program_address(
+            &[
+                crate::base_to_solana::constants::INCOMING_MESSAGE_SEED,
+                &message_hash,
+            ],
+            &ID,
+        )
+        .0;
+        let prove_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::ProveMessageBuffered {
+                payer: payer.pubkey(),
+                output_root: output_root_pk,
+                message: incoming_pda,
+                bridge: bridge_pda,
+                address_book: None,
+                owner: owner.pubkey(),
+                prove_buffer: prove_buffer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: ProveMessageBufferedIx {
+                nonce,
+                sender,
+                message_hash,
+            }
+            .data(),
+        };
+
+        set_call_max_root_age(&mut svm, 50);
+        let tx = Transaction::new(
+            &[&payer, &owner],
+            SolMessage::new(std::slice::from_ref(&prove_ix), Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let err = format!("{:?}", svm.send_transaction(tx).unwrap_err());
+        assert!(err.contains("StaleOutputRoot"), "unexpected error: {}", err);
+
+        // The same root is fresh enough once the window covers it
+        set_call_max_root_age(&mut svm, 100);
+        svm.expire_blockhash();
+        let tx = Transaction::new(
+            &[&payer, &owner],
+            SolMessage::new(&[prove_ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("prove_message_buffered should succeed within the freshness window");
+    }
 }
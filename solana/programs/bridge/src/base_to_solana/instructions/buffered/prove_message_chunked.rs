@@ -0,0 +1,490 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, CreateAccount},
+};
+
+use super::prove_message_buffered::hash_message;
+use crate::{
+    base_to_solana::{
+        bridge_cpi_authority,
+        constants::{INCOMING_MESSAGE_SEED, PROVEN_CHUNK_SEED},
+        state::IncomingMessage,
+        Message, OutputRoot, ProveBuffer, ProvenChunk,
+    },
+    common::{bridge::Bridge, AddressBook, ADDRESS_BOOK_SEED, BRIDGE_SEED, DISCRIMINATOR_LEN},
+};
+use crate::{BridgeError, MessageProven};
+
+/// Chunked variant of `prove_message_buffered` for messages whose instructions do not fit in a
+/// single account or exceed the limits of a single message. The instructions are split into
+/// `ProvenChunk` accounts, executed in order by `relay_message` after the message itself.
+///
+/// The `ProvenChunk` PDAs to create are passed as writable remaining accounts, in index order.
+/// Their number is given by `ProvenChunk::pack` applied to the instructions of the message.
+#[derive(Accounts)]
+#[instruction(nonce: u64, sender: [u8; 20], message_hash: [u8; 32])]
+pub struct ProveMessageChunked<'info> {
+    /// Payer funds the IncomingMessage and ProvenChunk accounts
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Output root to verify the proof against
+    pub output_root: Account<'info, OutputRoot>,
+
+    /// The incoming message account created if proof verifies, without its instructions.
+    /// Unchecked as its size is only known once the message is split; created in the handler.
+    /// CHECK: This is created in the handler.
+    #[account(
+        mut,
+        seeds = [INCOMING_MESSAGE_SEED, &message_hash],
+        bump
+    )]
+    pub message: UncheckedAccount<'info>,
+
+    /// Bridge for pause check
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// Address book holding the canonical Base Bridge, required for `ReturnTransfer` messages
+    #[account(seeds = [ADDRESS_BOOK_SEED], bump)]
+    pub address_book: Option<Account<'info, AddressBook>>,
+
+    /// Owner receives rent when buffer is closed
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// Prove buffer containing data and proof; closed on success
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ BridgeError::BufferUnauthorizedClose,
+    )]
+    pub prove_buffer: Account<'info, ProveBuffer>,
+
+    /// System program required for creating the message and chunk accounts
+    pub system_program: Program<'info, System>,
+}
+
+pub fn prove_message_chunked_handler<'a, 'info>(
+    ctx: Context<'a, '_, 'info, 'info, ProveMessageChunked<'info>>,
+    nonce: u64,
+    sender: [u8; 20],
+    message_hash: [u8; 32],
+) -> Result<()> {
+    // Pause
+    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+
+    // Verify hash
+    let data = &ctx.accounts.prove_buffer.data;
+    let computed_hash = hash_message(&nonce.to_be_bytes(), &sender, data);
+    require!(
+        message_hash == computed_hash,
+        BridgeError::InvalidMessageHash
+    );
+
+    // Verify proof
+    ctx.accounts.output_root.verify_message_proof(
+        &message_hash,
+        nonce,
+        &ctx.accounts.prove_buffer.proof,
+    )?;
+
+    // Deserialize and split the instructions off the message
+    let mut message_enum = Message::try_from_slice(data)?;
+    let ixs = message_enum.take_ixs();
+    message_enum.validate(&sender, ctx.accounts.address_book.as_deref())?;
+    ctx.accounts
+        .output_root
+        .check_freshness(&ctx.accounts.bridge, &message_enum)?;
+
+    let chunks = ProvenChunk::pack(ixs)?;
+    require!(
+        ctx.remaining_accounts.len() == chunks.len(),
+        BridgeError::IncorrectProvenChunkAccount
+    );
+
+    // Save the message
+    let message_key = ctx.accounts.message.key();
+    let message_space =
+        DISCRIMINATOR_LEN + IncomingMessage::space(message_enum.try_to_vec()?.len());
+    let message = IncomingMessage {
+        executed: false,
+        chunk_count: chunks.len() as u8,
+        sender,
+        remote_chain_id: ctx.accounts.bridge.remote_chain_id(),
+        message: message_enum,
+        output_root: ctx.accounts.output_root.key(),
+        output_root_revision: ctx.accounts.output_root.revision,
+        prover: ctx.accounts.payer.key(),
+        nonce,
+        proven_at: Clock::get()?.unix_timestamp,
+        vetoed: false,
+        outflow_held: false,
+        outflow_approved: false,
+    };
+    let finalizable_at = message.finalizable_at(&ctx.accounts.bridge.finalization_delay);
+    create_program_account(
+        &ctx.accounts.message.to_account_info(),
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        &[INCOMING_MESSAGE_SEED, &message_hash, &[ctx.bumps.message]],
+        message_space,
+        &message,
+    )?;
+
+    // Save the chunks
+    for (index, (ixs, chunk_info)) in chunks.into_iter().zip(ctx.remaining_accounts).enumerate() {
+        let index = index as u8;
+        let (chunk_pda, bump) = Pubkey::find_program_address(
+            &[PROVEN_CHUNK_SEED, message_key.as_ref(), &[index]],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            chunk_info.key(),
+            chunk_pda,
+            BridgeError::IncorrectProvenChunkAccount
+        );
+
+        create_program_account(
+            chunk_info,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            &[PROVEN_CHUNK_SEED, message_key.as_ref(), &[index], &[bump]],
+            DISCRIMINATOR_LEN + ProvenChunk::space(&ixs),
+            &ProvenChunk {
+                message: message_key,
+                index,
+                ixs,
+            },
+        )?;
+    }
+
+    emit!(MessageProven {
+        nonce,
+        sender_base: sender,
+        message_hash,
+        prover: ctx.accounts.payer.key(),
+        finalizable_at,
+        cpi_authority: bridge_cpi_authority(&sender).0,
+    });
+
+    Ok(())
+}
+
+/// Creates the PDA `account` with `space` bytes and stores `value` in it.
+fn create_program_account<'info, T: AccountSerialize>(
+    account: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    signer_seeds: &[&[u8]],
+    space: usize,
+    value: &T,
+) -> Result<()> {
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            CreateAccount {
+                from: payer.to_account_info(),
+                to: account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &crate::ID,
+    )?;
+
+    let mut data = account.try_borrow_mut_data()?;
+    value.try_serialize(&mut &mut data[..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message as SolMessage;
+    use solana_signer::Signer as _;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::constants::{BRIDGE_CPI_AUTHORITY_SEED, MAX_MESSAGE_IXS},
+        instruction::{ProveMessageChunked as ProveMessageChunkedIx, RelayMessage},
+        test_utils::{relay_deny_list_pda, set_program_account, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    const SENDER: [u8; 20] = [7u8; 20];
+
+    fn incoming_message_pda(message_hash: &[u8; 32]) -> Pubkey {
+        Pubkey::find_program_address(&[INCOMING_MESSAGE_SEED, message_hash], &ID).0
+    }
+
+    fn proven_chunk_pda(message: &Pubkey, index: u8) -> Pubkey {
+        Pubkey::find_program_address(&[PROVEN_CHUNK_SEED, message.as_ref(), &[index]], &ID).0
+    }
+
+    /// Builds a System Program transfer instruction (`SystemInstruction::Transfer` has index 2).
+    fn system_transfer_ix(from: &Pubkey, to: &Pubkey, lamports: u64) -> Instruction {
+        let mut data = 2u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&lamports.to_le_bytes());
+        Instruction {
+            program_id: system_program::ID,
+            accounts: vec![AccountMeta::new(*from, true), AccountMeta::new(*to, false)],
+            data,
+        }
+    }
+
+    /// Stages `message` in a prove buffer owned by `owner` and registers an output root of which
+    /// it is the only leaf. Returns the message hash, the output root and the prove buffer.
+    fn stage_message(
+        svm: &mut LiteSVM,
+        owner: &Keypair,
+        message: &Message,
+    ) -> ([u8; 32], Pubkey, Pubkey) {
+        let data = message.try_to_vec().unwrap();
+        let message_hash = hash_message(&0u64.to_be_bytes(), &SENDER, &data);
+
+        let prove_buffer = Pubkey::new_unique();
+        set_program_account(
+            svm,
+            prove_buffer,
+            &ProveBuffer {
+                owner: owner.pubkey(),
+                data,
+                proof: vec![],
+            },
+        );
+
+        let output_root = Pubkey::new_unique();
+        set_program_account(
+            svm,
+            output_root,
+            &OutputRoot {
+                root: message_hash,
+                total_leaf_count: 1,
+                base_block_number: 0,
+                registered_at: 0,
+                revision: 0,
+            },
+        );
+
+        (message_hash, output_root, prove_buffer)
+    }
+
+    fn prove_message_chunked(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        (message_hash, output_root, prove_buffer): ([u8; 32], Pubkey, Pubkey),
+        chunk_count: u8,
+    ) -> std::result::Result<(), String> {
+        let message = incoming_message_pda(&message_hash);
+        let mut accounts = accounts::ProveMessageChunked {
+            payer: payer.pubkey(),
+            output_root,
+            message,
+            bridge: bridge_pda,
+            address_book: None,
+            owner: payer.pubkey(),
+            prove_buffer,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+        accounts.extend(
+            (0..chunk_count)
+                .map(|index| AccountMeta::new(proven_chunk_pda(&message, index), false)),
+        );
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: ProveMessageChunkedIx {
+                nonce: 0,
+                sender: SENDER,
+                message_hash,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            SolMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn relay_message(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        message: Pubkey,
+        remaining_accounts: Vec<AccountMeta>,
+    ) -> std::result::Result<(), String> {
+        let account = svm.get_account(&message).unwrap();
+        let incoming = IncomingMessage::try_deserialize(&mut &account.data[..]).unwrap();
+
+        let mut accounts = accounts::RelayMessage {
+            message,
+            output_root: incoming.output_root,
+            prover: incoming.prover,
+            bridge: bridge_pda,
+            relay_deny_list: relay_deny_list_pda(),
+            relay_fee_vault: None,
+            relayer: None,
+            operational_vault: None,
+            bridge_cpi_authority: None,
+            system_program: None,
+        }
+        .to_account_metas(None);
+        accounts.extend(remaining_accounts);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: RelayMessage {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            SolMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    /// Returns a call of more instructions than a single message allows, transferring one
+    /// lamport each from the sender's bridge CPI authority to `to`.
+    fn large_call(svm: &mut LiteSVM, to: &Pubkey) -> (Message, Pubkey) {
+        let cpi_authority =
+            Pubkey::find_program_address(&[BRIDGE_CPI_AUTHORITY_SEED, SENDER.as_ref()], &ID).0;
+        svm.airdrop(&cpi_authority, LAMPORTS_PER_SOL).unwrap();
+
+        let ixs = (0..MAX_MESSAGE_IXS as u64 + 4)
+            .map(|_| system_transfer_ix(&cpi_authority, to, 1).into())
+            .collect();
+        (Message::Call(ixs), cpi_authority)
+    }
+
+    #[test]
+    fn test_prove_message_chunked_splits_and_relays_message() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let to = Pubkey::new_unique();
+        svm.airdrop(&to, LAMPORTS_PER_SOL).unwrap();
+        let (call, cpi_authority) = large_call(&mut svm, &to);
+        let staged = stage_message(&mut svm, &payer, &call);
+        let message = incoming_message_pda(&staged.0);
+
+        prove_message_chunked(&mut svm, &payer, bridge_pda, staged, 2)
+            .expect("prove_message_chunked should succeed");
+
+        let account = svm.get_account(&message).unwrap();
+        let incoming = IncomingMessage::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(incoming.chunk_count, 2);
+        assert!(matches!(incoming.message, Message::Call(ref ixs) if ixs.is_empty()));
+
+        let account = svm.get_account(&proven_chunk_pda(&message, 1)).unwrap();
+        let chunk = ProvenChunk::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(chunk.message, message);
+        assert_eq!(chunk.index, 1);
+        assert_eq!(chunk.ixs.len(), 4);
+
+        relay_message(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            message,
+            vec![
+                AccountMeta::new_readonly(system_program::ID, false),
+                AccountMeta::new(cpi_authority, false),
+                AccountMeta::new(to, false),
+                AccountMeta::new_readonly(proven_chunk_pda(&message, 0), false),
+                AccountMeta::new_readonly(proven_chunk_pda(&message, 1), false),
+            ],
+        )
+        .expect("relay_message should succeed");
+
+        assert_eq!(
+            svm.get_account(&to).unwrap().lamports,
+            LAMPORTS_PER_SOL + MAX_MESSAGE_IXS as u64 + 4
+        );
+        let account = svm.get_account(&message).unwrap();
+        let incoming = IncomingMessage::try_deserialize(&mut &account.data[..]).unwrap();
+        assert!(incoming.executed);
+    }
+
+    #[test]
+    fn test_prove_message_chunked_with_missing_chunk_account_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let (call, _) = large_call(&mut svm, &Pubkey::new_unique());
+        let staged = stage_message(&mut svm, &payer, &call);
+
+        let error = prove_message_chunked(&mut svm, &payer, bridge_pda, staged, 1).unwrap_err();
+        assert!(
+            error.contains("IncorrectProvenChunkAccount"),
+            "Expected IncorrectProvenChunkAccount error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_relay_chunked_message_with_wrong_chunk_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let to = Pubkey::new_unique();
+        svm.airdrop(&to, LAMPORTS_PER_SOL).unwrap();
+        let (call, cpi_authority) = large_call(&mut svm, &to);
+        let staged = stage_message(&mut svm, &payer, &call);
+        let message = incoming_message_pda(&staged.0);
+        prove_message_chunked(&mut svm, &payer, bridge_pda, staged, 2).unwrap();
+
+        // Chunks passed out of order
+        let error = relay_message(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            message,
+            vec![
+                AccountMeta::new_readonly(system_program::ID, false),
+                AccountMeta::new(cpi_authority, false),
+                AccountMeta::new(to, false),
+                AccountMeta::new_readonly(proven_chunk_pda(&message, 1), false),
+                AccountMeta::new_readonly(proven_chunk_pda(&message, 0), false),
+            ],
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("IncorrectProvenChunkAccount"),
+            "Expected IncorrectProvenChunkAccount error, got: {}",
+            error
+        );
+    }
+}
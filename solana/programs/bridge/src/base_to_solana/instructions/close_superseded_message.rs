@@ -0,0 +1,180 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::{
+        constants::PROVEN_CHUNK_SEED,
+        state::{IncomingMessage, OutputRoot, ProvenChunk},
+    },
+    BridgeError,
+};
+
+/// Accounts struct for the permissionless `close_superseded_message` instruction that closes an
+/// unexecuted incoming message proven against a superseded output root revision, so that the
+/// message can be proven again against the current revision.
+///
+/// The `ProvenChunk` accounts of a chunked message are passed, in index order, as writable
+/// remaining accounts and closed as well. The rent of all closed accounts goes back to the prover.
+#[derive(Accounts)]
+pub struct CloseSupersededMessage<'info> {
+    /// Pays the transaction fees.
+    pub payer: Signer<'info>,
+
+    /// The prover recorded in the message, receiving the rent of the closed accounts.
+    /// CHECK: Validated to be the prover recorded in the message.
+    #[account(mut, address = message.prover @ BridgeError::IncorrectProver)]
+    pub prover: UncheckedAccount<'info>,
+
+    /// The incoming message proven against a superseded output root revision.
+    #[account(
+        mut,
+        close = prover,
+        constraint = !message.executed @ BridgeError::AlreadyExecuted,
+    )]
+    pub message: Account<'info, IncomingMessage>,
+
+    /// The output root the message was proven against.
+    #[account(
+        address = message.output_root @ BridgeError::IncorrectOutputRootAccount,
+        constraint = output_root.revision != message.output_root_revision
+            @ BridgeError::OutputRootNotSuperseded,
+    )]
+    pub output_root: Account<'info, OutputRoot>,
+}
+
+pub fn close_superseded_message_handler<'a, 'info>(
+    ctx: Context<'a, '_, 'info, 'info, CloseSupersededMessage<'info>>,
+) -> Result<()> {
+    require!(
+        ctx.remaining_accounts.len() == ctx.accounts.message.chunk_count as usize,
+        BridgeError::IncorrectProvenChunkAccount
+    );
+
+    let message_key = ctx.accounts.message.key();
+    for (index, chunk_info) in ctx.remaining_accounts.iter().enumerate() {
+        let chunk_pda = Pubkey::find_program_address(
+            &[PROVEN_CHUNK_SEED, message_key.as_ref(), &[index as u8]],
+            &crate::ID,
+        )
+        .0;
+        require_keys_eq!(
+            chunk_info.key(),
+            chunk_pda,
+            BridgeError::IncorrectProvenChunkAccount
+        );
+
+        Account::<ProvenChunk>::try_from(chunk_info)?
+            .close(ctx.accounts.prover.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, system_program, InstructionData};
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message as SvmMessage;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::Message,
+        instruction::CloseSupersededMessage as CloseSupersededMessageIx,
+        test_utils::{mock_output_root, set_program_account, setup_bridge, SetupBridgeResult},
+        BASE_CHAIN_ID, ID,
+    };
+
+    /// Writes an unexecuted message proven by `prover` against revision 0 of `output_root`.
+    fn mock_message(svm: &mut LiteSVM, output_root: Pubkey, prover: Pubkey) -> Pubkey {
+        let message = Pubkey::new_unique();
+        set_program_account(
+            svm,
+            message,
+            &IncomingMessage {
+                sender: [7u8; 20],
+                remote_chain_id: BASE_CHAIN_ID,
+                message: Message::Call(vec![]),
+                executed: false,
+                chunk_count: 0,
+                output_root,
+                output_root_revision: 0,
+                prover,
+                nonce: 0,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+        message
+    }
+
+    fn close_superseded_message(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        prover: Pubkey,
+        message: Pubkey,
+        output_root: Pubkey,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::CloseSupersededMessage {
+                payer: payer.pubkey(),
+                prover,
+                message,
+                output_root,
+            }
+            .to_account_metas(None),
+            data: CloseSupersededMessageIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            SvmMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_close_superseded_message_closes_message() {
+        let SetupBridgeResult { mut svm, payer, .. } = setup_bridge();
+
+        let prover = Pubkey::new_unique();
+        let output_root = mock_output_root(&mut svm, 300, 0);
+        let message = mock_message(&mut svm, output_root, prover);
+        let rent = svm.get_account(&message).unwrap().lamports;
+        mock_output_root(&mut svm, 300, 1);
+
+        close_superseded_message(&mut svm, &payer, prover, message, output_root)
+            .expect("Failed to close superseded message");
+
+        let account = svm.get_account(&message).unwrap();
+        assert_eq!(account.lamports, 0);
+        assert_eq!(account.owner, system_program::ID);
+        assert_eq!(svm.get_account(&prover).unwrap().lamports, rent);
+    }
+
+    #[test]
+    fn test_close_message_of_current_revision_fails() {
+        let SetupBridgeResult { mut svm, payer, .. } = setup_bridge();
+
+        let output_root = mock_output_root(&mut svm, 300, 0);
+        let message = mock_message(&mut svm, output_root, payer.pubkey());
+
+        let error =
+            close_superseded_message(&mut svm, &payer, payer.pubkey(), message, output_root)
+                .unwrap_err();
+        assert!(
+            error.contains("OutputRootNotSuperseded"),
+            "Expected OutputRootNotSuperseded error, got: {}",
+            error
+        );
+    }
+}
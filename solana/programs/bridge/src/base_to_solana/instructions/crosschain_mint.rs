@@ -0,0 +1,289 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount},
+};
+
+use crate::{
+    common::{mint_wrapped_tokens, AddressBook, ADDRESS_BOOK_SEED, WRAPPED_TOKEN_SUPPLY_CAP_SEED},
+    BridgeError,
+};
+
+/// Accounts struct for the ERC-7802 style `crosschain_mint` instruction minting wrapped tokens on
+/// behalf of the Base Bridge. It can only be reached through `relay_message`, which signs with the
+/// bridge CPI authority of the message sender.
+#[derive(Accounts)]
+pub struct RelayedCrosschainMint<'info> {
+    /// The bridge CPI authority of the Base Bridge, signing when `relay_message` relays a message
+    /// sent by the Base Bridge.
+    pub bridge_cpi_authority: Signer<'info>,
+
+    /// The address book holding the Base Bridge address.
+    #[account(seeds = [ADDRESS_BOOK_SEED], bump)]
+    pub address_book: Account<'info, AddressBook>,
+
+    /// The wrapped token mint, which is its own mint authority.
+    #[account(mut, mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The token account credited with the minted tokens.
+    #[account(mut, token::mint = mint, token::token_program = token_program)]
+    pub to_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token2022 program used for minting the wrapped tokens.
+    pub token_program: Program<'info, Token2022>,
+
+    /// The max supply of the wrapped token, enforced if initialized.
+    /// CHECK: PDA with WRAPPED_TOKEN_SUPPLY_CAP_SEED, deserialized only if owned by this program.
+    #[account(seeds = [WRAPPED_TOKEN_SUPPLY_CAP_SEED, mint.key().as_ref()], bump)]
+    pub supply_cap: UncheckedAccount<'info>,
+}
+
+pub fn crosschain_mint_handler(ctx: Context<RelayedCrosschainMint>, amount: u64) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.bridge_cpi_authority.key(),
+        ctx.accounts.address_book.bridge_cpi_authority()?,
+        BridgeError::UnauthorizedCrosschainMint
+    );
+
+    mint_wrapped_tokens(
+        &ctx.accounts.mint,
+        &ctx.accounts.to_token_account,
+        &ctx.accounts.token_program,
+        &ctx.accounts.supply_cap,
+        amount,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        InstructionData,
+    };
+    use anchor_spl::token_interface::spl_token_2022::{
+        extension::StateWithExtensions, state::Account as SplTokenAccount,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message as SvmMessage;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::{constants::BRIDGE_CPI_AUTHORITY_SEED, state::IncomingMessage, Message},
+        common::{PartialTokenMetadata, WrappedTokenSupplyCap},
+        instruction::{CrosschainMint as CrosschainMintIx, RelayMessage as RelayMessageIx},
+        test_utils::{
+            address_book_pda, create_mock_token_account, create_mock_wrapped_mint,
+            mock_output_root, relay_deny_list_pda, set_program_account, setup_bridge,
+            SetupBridgeResult,
+        },
+        BASE_CHAIN_ID, ID,
+    };
+
+    const BASE_BRIDGE: [u8; 20] = [9u8; 20];
+
+    /// Registers the Base Bridge and creates a wrapped mint with an empty token account.
+    fn setup_wrapped_token(svm: &mut LiteSVM) -> (Pubkey, Pubkey) {
+        set_program_account(
+            svm,
+            address_book_pda(),
+            &AddressBook {
+                bridge: BASE_BRIDGE,
+                relayer_orchestrator: [0u8; 20],
+                token_count: 0,
+                tokens: Default::default(),
+            },
+        );
+
+        let partial_token_metadata = PartialTokenMetadata {
+            name: "Test Token".to_string(),
+            symbol: "TEST".to_string(),
+            remote_token: [1u8; 20],
+            scaler_exponent: 0,
+        };
+        let mint = create_mock_wrapped_mint(svm, 0, 6, &partial_token_metadata);
+        let to_token_account = Pubkey::new_unique();
+        create_mock_token_account(svm, to_token_account, mint, Pubkey::new_unique(), 0);
+
+        (mint, to_token_account)
+    }
+
+    fn crosschain_mint_ix(
+        bridge_cpi_authority: Pubkey,
+        mint: Pubkey,
+        to_token_account: Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::RelayedCrosschainMint {
+                bridge_cpi_authority,
+                address_book: address_book_pda(),
+                mint,
+                to_token_account,
+                token_program: anchor_spl::token_2022::ID,
+                supply_cap: WrappedTokenSupplyCap::pda(&mint),
+            }
+            .to_account_metas(None),
+            data: CrosschainMintIx { amount }.data(),
+        }
+    }
+
+    fn token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+        let account = svm.get_account(token_account).unwrap();
+        StateWithExtensions::<SplTokenAccount>::unpack(&account.data)
+            .unwrap()
+            .base
+            .amount
+    }
+
+    /// Relays a message from the Base Bridge calling `crosschain_mint`.
+    fn relay_crosschain_mint(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        mint: Pubkey,
+        to_token_account: Pubkey,
+        amount: u64,
+    ) -> std::result::Result<(), String> {
+        let bridge_cpi_authority =
+            Pubkey::find_program_address(&[BRIDGE_CPI_AUTHORITY_SEED, BASE_BRIDGE.as_ref()], &ID).0;
+        let mint_ix = crosschain_mint_ix(bridge_cpi_authority, mint, to_token_account, amount);
+
+        let message = Pubkey::new_unique();
+        let output_root = mock_output_root(svm, 0, 0);
+        set_program_account(
+            svm,
+            message,
+            &IncomingMessage {
+                sender: BASE_BRIDGE,
+                remote_chain_id: BASE_CHAIN_ID,
+                message: Message::Call(vec![mint_ix.clone().into()]),
+                executed: false,
+                chunk_count: 0,
+                output_root,
+                output_root_revision: 0,
+                prover: payer.pubkey(),
+                nonce: 0,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+
+        let mut accounts = accounts::RelayMessage {
+            message,
+            output_root: mock_output_root(svm, 0, 0),
+            prover: payer.pubkey(),
+            bridge: bridge_pda,
+            relay_deny_list: relay_deny_list_pda(),
+            relay_fee_vault: None,
+            relayer: None,
+            operational_vault: None,
+            bridge_cpi_authority: None,
+            system_program: None,
+        }
+        .to_account_metas(None);
+        accounts.push(AccountMeta::new_readonly(ID, false));
+        accounts.extend(mint_ix.accounts.into_iter().map(|account| AccountMeta {
+            is_signer: false,
+            ..account
+        }));
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: RelayMessageIx {}.data(),
+        };
+        let tx = Transaction::new(
+            &[payer],
+            SvmMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_crosschain_mint_via_relay_from_base_bridge() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let (mint, to_token_account) = setup_wrapped_token(&mut svm);
+
+        relay_crosschain_mint(&mut svm, &payer, bridge_pda, mint, to_token_account, 1_000)
+            .expect("Failed to relay crosschain mint");
+
+        assert_eq!(token_balance(&svm, &to_token_account), 1_000);
+    }
+
+    #[test]
+    fn test_crosschain_mint_over_max_supply_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let (mint, to_token_account) = setup_wrapped_token(&mut svm);
+        set_program_account(
+            &mut svm,
+            WrappedTokenSupplyCap::pda(&mint),
+            &WrappedTokenSupplyCap {
+                max_supply: Some(1_500),
+            },
+        );
+
+        relay_crosschain_mint(&mut svm, &payer, bridge_pda, mint, to_token_account, 1_000)
+            .expect("Failed to relay crosschain mint");
+
+        let error =
+            relay_crosschain_mint(&mut svm, &payer, bridge_pda, mint, to_token_account, 1_000)
+                .unwrap_err();
+        assert!(
+            error.contains("WrappedSupplyCapExceeded"),
+            "Expected WrappedSupplyCapExceeded error, got: {}",
+            error
+        );
+        assert_eq!(token_balance(&svm, &to_token_account), 1_000);
+    }
+
+    #[test]
+    fn test_crosschain_mint_from_other_signer_fails() {
+        let SetupBridgeResult { mut svm, .. } = setup_bridge();
+        let (mint, to_token_account) = setup_wrapped_token(&mut svm);
+
+        let signer = Keypair::new();
+        svm.airdrop(&signer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let tx = Transaction::new(
+            &[&signer],
+            SvmMessage::new(
+                &[crosschain_mint_ix(
+                    signer.pubkey(),
+                    mint,
+                    to_token_account,
+                    1_000,
+                )],
+                Some(&signer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let error = format!("{:?}", svm.send_transaction(tx).unwrap_err());
+        assert!(
+            error.contains("UnauthorizedCrosschainMint"),
+            "Expected UnauthorizedCrosschainMint error, got: {}",
+            error
+        );
+    }
+}
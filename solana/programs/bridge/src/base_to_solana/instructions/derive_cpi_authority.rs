@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{base_to_solana::bridge_cpi_authority, program::Bridge as BridgeProgram};
+
+/// Accounts struct for the derive_cpi_authority view instruction. Only the bridge program itself is
+/// required, as Anchor cannot generate the CPI client of an instruction without accounts.
+#[derive(Accounts)]
+pub struct DeriveCpiAuthority<'info> {
+    /// The bridge program
+    pub program: Program<'info, BridgeProgram>,
+}
+
+pub fn derive_cpi_authority_handler(
+    _ctx: Context<DeriveCpiAuthority>,
+    sender: [u8; 20],
+) -> Result<Pubkey> {
+    Ok(bridge_cpi_authority(&sender).0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::constants::BRIDGE_CPI_AUTHORITY_SEED,
+        instruction::DeriveCpiAuthority as DeriveCpiAuthorityIx,
+        test_utils::{setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    #[test]
+    fn test_derive_cpi_authority_returns_relay_signer() {
+        let SetupBridgeResult { mut svm, payer, .. } = setup_bridge();
+
+        let sender = [7u8; 20];
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::DeriveCpiAuthority { program: ID }.to_account_metas(None),
+            data: DeriveCpiAuthorityIx { sender }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("Failed to derive CPI authority");
+
+        assert_eq!(meta.return_data.program_id, ID);
+        let cpi_authority = Pubkey::try_from_slice(&meta.return_data.data).unwrap();
+        assert_eq!(
+            cpi_authority,
+            Pubkey::find_program_address(&[BRIDGE_CPI_AUTHORITY_SEED, sender.as_ref()], &ID).0
+        );
+        assert_ne!(cpi_authority, bridge_cpi_authority(&[8u8; 20]).0);
+    }
+}
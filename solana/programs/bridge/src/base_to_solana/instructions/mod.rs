@@ -1,10 +1,34 @@
+pub mod approve_outflow;
 pub mod buffered;
+pub mod close_superseded_message;
+pub mod crosschain_mint;
+pub mod derive_cpi_authority;
+pub mod post_liability_root;
 pub mod prove_message;
+pub mod register_handler;
 pub mod register_output_root;
+pub mod register_output_roots_batch;
 pub mod relay_message;
+pub mod report_equivocation;
+pub mod set_wrapped_token_max_supply;
+pub mod settle_relay_fees;
+pub mod supersede_output_root;
 pub mod token;
+pub mod veto_message;
 
+pub use approve_outflow::*;
 pub use buffered::*;
+pub use close_superseded_message::*;
+pub use crosschain_mint::*;
+pub use derive_cpi_authority::*;
+pub use post_liability_root::*;
 pub use prove_message::*;
+pub use register_handler::*;
 pub use register_output_root::*;
+pub use register_output_roots_batch::*;
 pub use relay_message::*;
+pub use report_equivocation::*;
+pub use set_wrapped_token_max_supply::*;
+pub use settle_relay_fees::*;
+pub use supersede_output_root::*;
+pub use veto_message::*;
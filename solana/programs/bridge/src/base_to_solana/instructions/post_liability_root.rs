@@ -0,0 +1,248 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::{compute_liability_root_message_hash, verify_oracle_approvals},
+    common::{bridge::Bridge, LiabilityRoot, BRIDGE_SEED, DISCRIMINATOR_LEN, LIABILITY_ROOT_SEED},
+    BridgeError,
+};
+
+/// Accounts struct for the `post_liability_root` instruction that stores the oracle-attested
+/// commitment to the outstanding Base-side supply of the tokens locked in the bridge vaults.
+/// Authorization is enforced via oracle EVM signatures; the Solana payer only funds account
+/// creation.
+#[derive(Accounts)]
+pub struct PostLiabilityRoot<'info> {
+    /// Payer funds the liability root account creation. Authorization is enforced via oracle EVM
+    /// signatures.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The main bridge state account providing the oracle configuration.
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The liability root account.
+    /// - Uses PDA with LIABILITY_ROOT_SEED for deterministic address
+    /// - Created on first use and overwritten by every newer commitment
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = DISCRIMINATOR_LEN + LiabilityRoot::INIT_SPACE,
+        seeds = [LIABILITY_ROOT_SEED],
+        bump
+    )]
+    pub liability_root: Account<'info, LiabilityRoot>,
+
+    /// Partner `Config` account (PDA with seed "config") owned by partner program.
+    /// Unchecked to avoid Anchor pre-handler owner checks; PDA address is validated in the handler.
+    /// CHECK: This is validated in the handler.
+    pub partner_config: AccountInfo<'info>,
+
+    /// System program required for creating the liability root account.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn post_liability_root_handler(
+    ctx: Context<PostLiabilityRoot>,
+    root: [u8; 32],
+    base_block_number: u64,
+    signatures: Vec<[u8; 65]>,
+) -> Result<()> {
+    let message_hash = compute_liability_root_message_hash(&root, base_block_number);
+    verify_oracle_approvals(
+        &ctx.accounts.bridge,
+        &ctx.accounts.partner_config,
+        &signatures,
+        &message_hash,
+    )?;
+
+    let liability_root = &mut ctx.accounts.liability_root;
+    require!(
+        base_block_number > liability_root.base_block_number,
+        BridgeError::StaleLiabilityRoot
+    );
+
+    // The solvency alert is sticky across commitments and only cleared by the guardian
+    liability_root.root = root;
+    liability_root.base_block_number = base_block_number;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, keccak::hash as keccak_hash},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use secp256k1::{Message as SecpMessage, Secp256k1, SecretKey};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::MAX_SIGNER_COUNT,
+        instruction::PostLiabilityRoot as PostLiabilityRootIx,
+        test_utils::{liability_root_pda, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    const ORACLE_SK: [u8; 32] = [7u8; 32];
+
+    /// Registers a single Base oracle with threshold one.
+    fn setup_oracle(svm: &mut LiteSVM, bridge_pda: Pubkey) {
+        let secp = Secp256k1::new();
+        let sk = SecretKey::from_slice(&ORACLE_SK).unwrap();
+        let pk = secp256k1::PublicKey::from_secret_key(&secp, &sk);
+        let hashed = keccak_hash(&pk.serialize_uncompressed()[1..]);
+        let mut addr = [0u8; 20];
+        addr.copy_from_slice(&hashed.to_bytes()[12..]);
+
+        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
+        bridge.base_oracle_config.threshold = 1;
+        bridge.base_oracle_config.signer_count = 1;
+        bridge.base_oracle_config.signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+        bridge.base_oracle_config.signers[0] = addr;
+        let mut new_data = Vec::new();
+        bridge.try_serialize(&mut new_data).unwrap();
+        bridge_acc.data = new_data;
+        svm.set_account(bridge_pda, bridge_acc).unwrap();
+    }
+
+    fn sign_liability_root(
+        sk_bytes: [u8; 32],
+        root: &[u8; 32],
+        base_block_number: u64,
+    ) -> [u8; 65] {
+        let msg_hash = compute_liability_root_message_hash(root, base_block_number);
+
+        let secp = Secp256k1::new();
+        let sk = SecretKey::from_slice(&sk_bytes).unwrap();
+        let msg = SecpMessage::from_digest_slice(&msg_hash).unwrap();
+        let (rec_id, sig_bytes64) = secp.sign_ecdsa_recoverable(&msg, &sk).serialize_compact();
+
+        let mut sig65 = [0u8; 65];
+        sig65[..64].copy_from_slice(&sig_bytes64);
+        sig65[64] = 27 + rec_id.to_i32() as u8;
+        sig65
+    }
+
+    fn post_liability_root(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        root: [u8; 32],
+        base_block_number: u64,
+        sk_bytes: [u8; 32],
+    ) -> std::result::Result<(), String> {
+        let signatures = vec![sign_liability_root(sk_bytes, &root, base_block_number)];
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::PostLiabilityRoot {
+                payer: payer.pubkey(),
+                bridge: bridge_pda,
+                liability_root: liability_root_pda(),
+                partner_config: Pubkey::new_unique(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: PostLiabilityRootIx {
+                root,
+                base_block_number,
+                signatures,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn read_liability_root(svm: &LiteSVM) -> LiabilityRoot {
+        let account = svm.get_account(&liability_root_pda()).unwrap();
+        LiabilityRoot::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    #[test]
+    fn test_post_liability_root_success() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_oracle(&mut svm, bridge_pda);
+
+        post_liability_root(&mut svm, &payer, bridge_pda, [1u8; 32], 100, ORACLE_SK)
+            .expect("Failed to post liability root");
+        assert_eq!(
+            read_liability_root(&svm),
+            LiabilityRoot {
+                root: [1u8; 32],
+                base_block_number: 100,
+                solvency_alert: false,
+            }
+        );
+
+        post_liability_root(&mut svm, &payer, bridge_pda, [2u8; 32], 200, ORACLE_SK)
+            .expect("Failed to post newer liability root");
+        assert_eq!(read_liability_root(&svm).root, [2u8; 32]);
+        assert_eq!(read_liability_root(&svm).base_block_number, 200);
+    }
+
+    #[test]
+    fn test_post_liability_root_rejects_stale_block() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_oracle(&mut svm, bridge_pda);
+
+        post_liability_root(&mut svm, &payer, bridge_pda, [1u8; 32], 100, ORACLE_SK)
+            .expect("Failed to post liability root");
+
+        let error_string =
+            post_liability_root(&mut svm, &payer, bridge_pda, [2u8; 32], 100, ORACLE_SK)
+                .unwrap_err();
+        assert!(
+            error_string.contains("StaleLiabilityRoot"),
+            "Expected StaleLiabilityRoot error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_post_liability_root_requires_oracle_signature() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_oracle(&mut svm, bridge_pda);
+
+        let error_string =
+            post_liability_root(&mut svm, &payer, bridge_pda, [1u8; 32], 100, [9u8; 32])
+                .unwrap_err();
+        assert!(
+            error_string.contains("InsufficientBaseSignatures"),
+            "Expected InsufficientBaseSignatures error, got: {}",
+            error_string
+        );
+    }
+}
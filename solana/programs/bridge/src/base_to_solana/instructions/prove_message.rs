@@ -1,16 +1,16 @@
 use anchor_lang::{prelude::*, solana_program::keccak};
 
-use crate::common::{bridge::Bridge, BRIDGE_SEED};
-use crate::BridgeError;
+use crate::common::{bridge::Bridge, AddressBook, ADDRESS_BOOK_SEED, BRIDGE_SEED};
 use crate::{
     base_to_solana::{
+        bridge_cpi_authority,
         constants::INCOMING_MESSAGE_SEED,
-        internal::mmr::{self},
         state::{IncomingMessage, OutputRoot},
         Message,
     },
     common::DISCRIMINATOR_LEN,
 };
+use crate::{BridgeError, MessageProven};
 
 /// Accounts struct for the prove_message instruction that verifies a message exists on Base.
 /// This instruction creates a proven message account after validating the message against an MMR proof
@@ -20,12 +20,14 @@ use crate::{
 pub struct ProveMessage<'info> {
     /// The account that pays for the transaction and incoming message account creation.
     /// Must be mutable to deduct lamports for account rent.
+    /// Recorded as the prover of the message, refunded the rent freed when it is relayed.
     #[account(mut)]
     pub payer: Signer<'info>,
 
     /// The output root account containing the MMR root from Base.
     /// Used to verify that the message proof is valid against the committed state.
-    /// This root must have been previously registered via register_output_root instruction.
+    /// Any root registered for the message's Base block or a later one can be used, with a proof
+    /// built against its `total_leaf_count`.
     pub output_root: Account<'info, OutputRoot>,
 
     /// The incoming message account being created to store the proven message.
@@ -47,6 +49,11 @@ pub struct ProveMessage<'info> {
     #[account(seeds = [BRIDGE_SEED], bump)]
     pub bridge: Account<'info, Bridge>,
 
+    /// The address book holding the canonical Base Bridge address.
+    /// Required for `ReturnTransfer` messages, which are only accepted from the Base Bridge.
+    #[account(seeds = [ADDRESS_BOOK_SEED], bump)]
+    pub address_book: Option<Account<'info, AddressBook>>,
+
     /// System program required for creating new accounts.
     /// Used internally by Anchor for account initialization.
     pub system_program: Program<'info, System>,
@@ -71,20 +78,47 @@ pub fn prove_message_handler(
     );
 
     // Verify the MMR proof to ensure the message was included on the source chain
-    mmr::verify_proof(
-        &ctx.accounts.output_root.root,
-        &message_hash,
-        &nonce,
-        &proof,
-        ctx.accounts.output_root.total_leaf_count,
-    )?;
+    ctx.accounts
+        .output_root
+        .verify_message_proof(&message_hash, nonce, &proof)?;
+
+    // Reject spoofed senders for messages restricted to the Base Bridge and nested compute budgets
+    let message = Message::try_from_slice(&data)?;
+    message.validate(&sender, ctx.accounts.address_book.as_deref())?;
+
+    // Reject roots older than the freshness window of the message kind
+    ctx.accounts
+        .output_root
+        .check_freshness(&ctx.accounts.bridge, &message)?;
 
     *ctx.accounts.message = IncomingMessage {
         executed: false,
+        chunk_count: 0,
         sender,
-        message: Message::try_from_slice(&data)?,
+        remote_chain_id: ctx.accounts.bridge.remote_chain_id(),
+        message,
+        output_root: ctx.accounts.output_root.key(),
+        output_root_revision: ctx.accounts.output_root.revision,
+        prover: ctx.accounts.payer.key(),
+        nonce,
+        proven_at: Clock::get()?.unix_timestamp,
+        vetoed: false,
+        outflow_held: false,
+        outflow_approved: false,
     };
 
+    emit!(MessageProven {
+        nonce,
+        sender_base: sender,
+        message_hash,
+        prover: ctx.accounts.payer.key(),
+        finalizable_at: ctx
+            .accounts
+            .message
+            .finalizable_at(&ctx.accounts.bridge.finalization_delay),
+        cpi_authority: bridge_cpi_authority(&sender).0,
+    });
+
     Ok(())
 }
 
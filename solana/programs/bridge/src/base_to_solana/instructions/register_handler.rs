@@ -0,0 +1,246 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::{
+        bridge_cpi_authority, constants::HANDLER_REGISTRATION_SEED, HandlerRegistration,
+        RelayDenyList,
+    },
+    common::DISCRIMINATOR_LEN,
+    BridgeError, HandlerRegistered,
+};
+
+/// Accounts struct for the `register_handler` instruction registering the handler invoked with the
+/// payload of the `CallHandler` messages of a Base sender. It can only be reached through
+/// `relay_message`, which signs with the bridge CPI authority of the message sender.
+#[derive(Accounts)]
+#[instruction(sender: [u8; 20])]
+pub struct RelayedRegisterHandler<'info> {
+    /// The bridge CPI authority of `sender`, signing when `relay_message` relays a message sent by
+    /// `sender`. Pays for the handler registration creation.
+    #[account(mut)]
+    pub bridge_cpi_authority: Signer<'info>,
+
+    /// The handler registration of `sender`.
+    /// - Uses PDA with HANDLER_REGISTRATION_SEED and the sender for deterministic address
+    /// - Created on first use, overwritten by later registrations
+    #[account(
+        init_if_needed,
+        payer = bridge_cpi_authority,
+        space = DISCRIMINATOR_LEN + HandlerRegistration::INIT_SPACE,
+        seeds = [HANDLER_REGISTRATION_SEED, sender.as_ref()],
+        bump
+    )]
+    pub handler_registration: Account<'info, HandlerRegistration>,
+
+    /// System program required for creating the handler registration account.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_handler_handler(
+    ctx: Context<RelayedRegisterHandler>,
+    sender: [u8; 20],
+    program_id: Pubkey,
+    discriminator: [u8; 8],
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.bridge_cpi_authority.key(),
+        bridge_cpi_authority(&sender).0,
+        BridgeError::UnauthorizedHandlerRegistration
+    );
+
+    // The handler accounts are chosen by the relayer, so the bridge must never be its own handler
+    require_keys_neq!(program_id, crate::ID, BridgeError::DeniedRelayProgram);
+    RelayDenyList::check_program(None, &program_id)?;
+
+    let handler_registration = &mut ctx.accounts.handler_registration;
+    handler_registration.program_id = program_id;
+    handler_registration.discriminator = discriminator;
+
+    emit!(HandlerRegistered {
+        sender,
+        program_id,
+        discriminator,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message as SvmMessage;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::{constants::DENIED_RELAY_PROGRAMS, state::IncomingMessage, Message},
+        instruction::{RegisterHandler as RegisterHandlerIx, RelayMessage as RelayMessageIx},
+        test_utils::{
+            mock_output_root, relay_deny_list_pda, set_program_account, setup_bridge,
+            SetupBridgeResult,
+        },
+        BASE_CHAIN_ID, ID,
+    };
+
+    const SENDER: [u8; 20] = [7u8; 20];
+
+    fn register_handler_ix(
+        bridge_cpi_authority: Pubkey,
+        sender: [u8; 20],
+        program_id: Pubkey,
+    ) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::RelayedRegisterHandler {
+                bridge_cpi_authority,
+                handler_registration: HandlerRegistration::pda(&sender),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: RegisterHandlerIx {
+                sender,
+                program_id,
+                discriminator: [1u8; 8],
+            }
+            .data(),
+        }
+    }
+
+    /// Relays a message sent by `SENDER` registering `program_id` as its handler.
+    fn relay_register_handler(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        program_id: Pubkey,
+    ) -> std::result::Result<(), String> {
+        let register_ix = register_handler_ix(bridge_cpi_authority(&SENDER).0, SENDER, program_id);
+
+        let output_root = mock_output_root(svm, 0, 0);
+        let message = Pubkey::new_unique();
+        set_program_account(
+            svm,
+            message,
+            &IncomingMessage {
+                sender: SENDER,
+                remote_chain_id: BASE_CHAIN_ID,
+                message: Message::Call(vec![register_ix.clone().into()]),
+                executed: false,
+                chunk_count: 0,
+                output_root,
+                output_root_revision: 0,
+                prover: payer.pubkey(),
+                nonce: 0,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+
+        let mut accounts = accounts::RelayMessage {
+            message,
+            output_root,
+            prover: payer.pubkey(),
+            bridge: bridge_pda,
+            relay_deny_list: relay_deny_list_pda(),
+            relay_fee_vault: None,
+            relayer: None,
+            operational_vault: None,
+            bridge_cpi_authority: None,
+            system_program: None,
+        }
+        .to_account_metas(None);
+        accounts.push(AccountMeta::new_readonly(ID, false));
+        accounts.extend(register_ix.accounts.into_iter().map(|account| AccountMeta {
+            is_signer: false,
+            ..account
+        }));
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: RelayMessageIx {}.data(),
+        };
+        let tx = Transaction::new(
+            &[payer],
+            SvmMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_register_handler_via_relay_from_sender() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // The bridge CPI authority pays for the handler registration account
+        svm.airdrop(&bridge_cpi_authority(&SENDER).0, LAMPORTS_PER_SOL)
+            .unwrap();
+        let handler_program = Pubkey::new_unique();
+        relay_register_handler(&mut svm, &payer, bridge_pda, handler_program)
+            .expect("Failed to relay handler registration");
+
+        let account = svm.get_account(&HandlerRegistration::pda(&SENDER)).unwrap();
+        let registration = HandlerRegistration::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(
+            registration,
+            HandlerRegistration {
+                program_id: handler_program,
+                discriminator: [1u8; 8],
+            }
+        );
+
+        // Neither the bridge nor the privileged programs can be registered
+        for program_id in [ID, DENIED_RELAY_PROGRAMS[0]] {
+            let error =
+                relay_register_handler(&mut svm, &payer, bridge_pda, program_id).unwrap_err();
+            assert!(
+                error.contains("DeniedRelayProgram"),
+                "Expected DeniedRelayProgram error, got: {}",
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn test_register_handler_from_other_signer_fails() {
+        let SetupBridgeResult { mut svm, .. } = setup_bridge();
+
+        let signer = Keypair::new();
+        svm.airdrop(&signer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let tx = Transaction::new(
+            &[&signer],
+            SvmMessage::new(
+                &[register_handler_ix(
+                    signer.pubkey(),
+                    SENDER,
+                    Pubkey::new_unique(),
+                )],
+                Some(&signer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let error = format!("{:?}", svm.send_transaction(tx).unwrap_err());
+        assert!(
+            error.contains("UnauthorizedHandlerRegistration"),
+            "Expected UnauthorizedHandlerRegistration error, got: {}",
+            error
+        );
+    }
+}
@@ -1,23 +1,30 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::sysvar};
 
-use crate::base_to_solana::constants::{PARTNER_PROGRAM_ID, PARTNER_SIGNERS_ACCOUNT_SEED};
-use crate::base_to_solana::state::Signers;
-use crate::base_to_solana::{compute_output_root_message_hash, recover_unique_evm_addresses};
+use crate::base_to_solana::{
+    compute_output_root_message_hash, precompile_evm_addresses, recover_quorum_evm_addresses,
+    verify_signer_approvals,
+};
 use crate::BridgeError;
 use crate::{
-    base_to_solana::{constants::OUTPUT_ROOT_SEED, state::OutputRoot},
+    base_to_solana::{
+        constants::{MAINTENANCE_BUDGET_SEED, OUTPUT_ROOT_SEED},
+        state::{MaintenanceBudget, OutputRoot},
+    },
     common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
 };
 
 /// Accounts struct for the `register_output_root` instruction that stores Base MMR roots
-/// on Solana for cross-chain message verification. This instruction allows a trusted oracle to
-/// register output roots from Base at specific block intervals, enabling subsequent message
-/// proofs and cross-chain operations. The instruction also records the MMR's total leaf count
-/// needed for proof verification at that checkpoint.
+/// on Solana for cross-chain message verification. This instruction allows anyone submitting
+/// enough Base oracle and partner signatures to register output roots from Base at specific
+/// block intervals, enabling subsequent message proofs and cross-chain operations. The
+/// instruction also records the MMR's total leaf count needed for proof verification at that
+/// checkpoint.
 #[derive(Accounts)]
 #[instruction(output_root: [u8; 32], base_block_number: u64)]
 pub struct RegisterOutputRoot<'info> {
-    /// Payer funds the account creation. Authorization is enforced via oracle EVM signature.
+    /// Payer funds the account creation. Any account may pay: the payer is not pinned to an
+    /// oracle key, so that oracle keys can be rotated through the signer configuration alone.
+    /// Authorization is enforced via oracle EVM signature.
     #[account(mut)]
     pub payer: Signer<'info>,
 
@@ -50,6 +57,17 @@ pub struct RegisterOutputRoot<'info> {
     /// System program required for creating new accounts.
     /// Used internally by Anchor for output root account initialization.
     pub system_program: Program<'info, System>,
+
+    /// Instructions sysvar, required when no `signatures` are passed so that the oracle signatures
+    /// verified by the preceding Secp256k1 program instructions can be introspected.
+    /// CHECK: Validated to be the instructions sysvar.
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    /// The maintenance budget reimbursing the payer, possibly not created yet.
+    /// CHECK: PDA with MAINTENANCE_BUDGET_SEED, deserialized only if owned by this program.
+    #[account(mut, seeds = [MAINTENANCE_BUDGET_SEED], bump)]
+    pub maintenance_budget: AccountInfo<'info>,
 }
 
 pub fn register_output_root_handler(
@@ -66,41 +84,30 @@ pub fn register_output_root_handler(
     let message_hash =
         compute_output_root_message_hash(&output_root, base_block_number, total_leaf_count);
 
-    // Recover unique EVM signers from provided signatures
-    let unique_signers = recover_unique_evm_addresses(&signatures, &message_hash)?;
-
-    // Verify Base oracle approvals
-    let base_approved_count = ctx
-        .accounts
-        .bridge
-        .base_oracle_config
-        .count_approvals(&unique_signers);
-
-    require!(
-        base_approved_count as u8 >= ctx.accounts.bridge.base_oracle_config.threshold,
-        BridgeError::InsufficientBaseSignatures
-    );
+    // Without signatures, they must have been verified by the Secp256k1 program in this transaction
+    let unique_signers = if signatures.is_empty() {
+        let instructions_sysvar = ctx
+            .accounts
+            .instructions_sysvar
+            .as_ref()
+            .ok_or(BridgeError::MissingInstructionsSysvar)?;
+        precompile_evm_addresses(instructions_sysvar, &message_hash)?
+    } else {
+        // Stop recovering once the quorum is met, large quorums only paying for what they need
+        recover_quorum_evm_addresses(
+            &ctx.accounts.bridge,
+            &ctx.accounts.partner_config,
+            &signatures,
+            &message_hash,
+        )?
+    };
 
-    if ctx.accounts.bridge.partner_oracle_config.required_threshold > 0 {
-        // Validate partner_config PDA using seed with the partner program id
-        let expected_partner_cfg =
-            Pubkey::find_program_address(&[PARTNER_SIGNERS_ACCOUNT_SEED], &PARTNER_PROGRAM_ID).0;
-        require_keys_eq!(
-            ctx.accounts.partner_config.key(),
-            expected_partner_cfg,
-            anchor_lang::error::ErrorCode::ConstraintSeeds
-        );
-
-        // Verify partner approvals using partner's signers (deserialize manually)
-        let partner_oracle_config = &ctx.accounts.bridge.partner_oracle_config;
-        let partner_config =
-            Signers::try_deserialize(&mut &ctx.accounts.partner_config.data.borrow()[..])?;
-        let partner_approved_count = partner_config.count_approvals(&unique_signers);
-        require!(
-            partner_approved_count as u8 >= partner_oracle_config.required_threshold,
-            BridgeError::InsufficientPartnerSignatures
-        );
-    }
+    verify_signer_approvals(
+        &ctx.accounts.bridge,
+        &ctx.accounts.partner_config,
+        &unique_signers,
+        &message_hash,
+    )?;
 
     require!(
         base_block_number > ctx.accounts.bridge.base_block_number
@@ -114,10 +121,26 @@ pub fn register_output_root_handler(
         BridgeError::IncorrectBlockNumber
     );
 
+    let now = Clock::get()?.unix_timestamp;
+
     ctx.accounts.root.root = output_root;
     ctx.accounts.root.total_leaf_count = total_leaf_count;
+    ctx.accounts.root.base_block_number = base_block_number;
+    ctx.accounts.root.registered_at = now;
     ctx.accounts.bridge.base_block_number = base_block_number;
 
+    let bridge: &mut Bridge = &mut ctx.accounts.bridge;
+    bridge.oracle_failover.last_output_root_at = now;
+    bridge
+        .oracle_health
+        .record(&bridge.base_oracle_config, &unique_signers, now);
+
+    MaintenanceBudget::reimburse(
+        &ctx.accounts.maintenance_budget,
+        &ctx.accounts.payer,
+        ctx.accounts.root.to_account_info().lamports(),
+    )?;
+
     Ok(())
 }
 
@@ -126,7 +149,9 @@ mod tests {
     use super::*;
 
     use anchor_lang::{
-        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        solana_program::{
+            instruction::Instruction, native_token::LAMPORTS_PER_SOL, secp256k1_program,
+        },
         system_program, InstructionData,
     };
     use litesvm::LiteSVM;
@@ -140,12 +165,15 @@ mod tests {
         accounts,
         base_to_solana::state::signers::{PartnerSigner, Signers},
         base_to_solana::{
-            constants::{OUTPUT_ROOT_SEED, PARTNER_SIGNERS_ACCOUNT_SEED},
-            internal::compute_output_root_message_hash,
+            constants::{OUTPUT_ROOT_SEED, PARTNER_PROGRAM_ID, PARTNER_SIGNERS_ACCOUNT_SEED},
+            internal::{compute_output_root_message_hash, recover_unique_evm_addresses},
         },
-        common::{bridge::Bridge, MAX_SIGNER_COUNT},
+        common::{bridge::Bridge, MAX_SIGNATURE_COUNT, MAX_SIGNER_COUNT},
         instruction::RegisterOutputRoot as RegisterOutputRootIx,
-        test_utils::{setup_bridge, SetupBridgeResult},
+        test_utils::{
+            maintenance_budget_pda, mock_clock, set_program_account, setup_bridge,
+            SetupBridgeResult,
+        },
         ID,
     };
 
@@ -186,27 +214,28 @@ mod tests {
     }
 
     #[allow(clippy::too_many_arguments)]
-    fn send_register(
-        svm: &mut LiteSVM,
-        payer: &Keypair,
+    fn register_ix(
+        payer: Pubkey,
         bridge_pda: Pubkey,
         partner_cfg_pda: Pubkey,
         output_root: [u8; 32],
         base_block_number: u64,
         total_leaf_count: u64,
         signatures: Vec<[u8; 65]>,
-    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
-        let root_pda = output_root_pda(base_block_number);
+        instructions_sysvar: Option<Pubkey>,
+    ) -> Instruction {
         let accounts = accounts::RegisterOutputRoot {
-            payer: payer.pubkey(),
-            root: root_pda,
+            payer,
+            root: output_root_pda(base_block_number),
             bridge: bridge_pda,
             partner_config: partner_cfg_pda,
             system_program: system_program::ID,
+            instructions_sysvar,
+            maintenance_budget: maintenance_budget_pda(),
         }
         .to_account_metas(None);
 
-        let ix = Instruction {
+        Instruction {
             program_id: ID,
             accounts,
             data: RegisterOutputRootIx {
@@ -216,11 +245,17 @@ mod tests {
                 signatures,
             }
             .data(),
-        };
+        }
+    }
 
+    fn send_ixs(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        ixs: &[Instruction],
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
         let tx = Transaction::new(
             &[payer],
-            Message::new(&[ix], Some(&payer.pubkey())),
+            Message::new(ixs, Some(&payer.pubkey())),
             svm.latest_blockhash(),
         );
 
@@ -228,6 +263,84 @@ mod tests {
         Ok(())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn send_register(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        partner_cfg_pda: Pubkey,
+        output_root: [u8; 32],
+        base_block_number: u64,
+        total_leaf_count: u64,
+        signatures: Vec<[u8; 65]>,
+    ) -> std::result::Result<(), Box<litesvm::types::FailedTransactionMetadata>> {
+        let ix = register_ix(
+            payer.pubkey(),
+            bridge_pda,
+            partner_cfg_pda,
+            output_root,
+            base_block_number,
+            total_leaf_count,
+            signatures,
+            None,
+        );
+        send_ixs(svm, payer, &[ix])
+    }
+
+    /// Builds the EIP-191 prefixed message whose keccak256 hash is the output root message hash.
+    fn output_root_eth_message(
+        output_root: [u8; 32],
+        base_block_number: u64,
+        total_leaf_count: u64,
+    ) -> Vec<u8> {
+        let mut message_bytes = output_root.to_vec();
+        message_bytes.extend_from_slice(&base_block_number.to_be_bytes());
+        message_bytes.extend_from_slice(&total_leaf_count.to_be_bytes());
+
+        let mut message =
+            format!("\x19Ethereum Signed Message:\n{}", message_bytes.len()).into_bytes();
+        message.extend_from_slice(&message_bytes);
+        message
+    }
+
+    /// Builds a Secp256k1 program instruction verifying the signature of `message` by `sk_bytes`,
+    /// with all its data inside the instruction itself (expected at index 0 of the transaction).
+    fn secp256k1_ix(sk_bytes: [u8; 32], message: &[u8]) -> (Instruction, [u8; 20]) {
+        let secp = Secp256k1::new();
+        let sk = SecretKey::from_slice(&sk_bytes).unwrap();
+        let msg = SecpMessage::from_digest_slice(&keccak_hash(message).to_bytes()).unwrap();
+        let (rec_id, sig_bytes64) = secp.sign_ecdsa_recoverable(&msg, &sk).serialize_compact();
+
+        let pk = secp256k1::PublicKey::from_secret_key(&secp, &sk);
+        let mut addr = [0u8; 20];
+        addr.copy_from_slice(&keccak_hash(&pk.serialize_uncompressed()[1..]).to_bytes()[12..]);
+
+        // count | offsets | eth_address (20) | signature (64) | recovery_id (1) | message
+        let eth_address_offset: u16 = 12;
+        let signature_offset: u16 = eth_address_offset + 20;
+        let message_offset: u16 = signature_offset + 65;
+
+        let mut data = vec![1u8];
+        data.extend_from_slice(&signature_offset.to_le_bytes());
+        data.push(0);
+        data.extend_from_slice(&eth_address_offset.to_le_bytes());
+        data.push(0);
+        data.extend_from_slice(&message_offset.to_le_bytes());
+        data.extend_from_slice(&(message.len() as u16).to_le_bytes());
+        data.push(0);
+        data.extend_from_slice(&addr);
+        data.extend_from_slice(&sig_bytes64);
+        data.push(rec_id.to_i32() as u8);
+        data.extend_from_slice(message);
+
+        let ix = Instruction {
+            program_id: secp256k1_program::ID,
+            accounts: vec![],
+            data,
+        };
+        (ix, addr)
+    }
+
     fn make_eth_sig_and_addr(
         sk_bytes: [u8; 32],
         output_root: [u8; 32],
@@ -336,6 +449,49 @@ mod tests {
         assert_eq!(root.root, output_root);
     }
 
+    #[test]
+    fn test_register_output_root_accepts_any_payer() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let partner_cfg = write_partner_config_account(&mut svm, &[]);
+
+        let output_root = [1u8; 32];
+        let base_block_number = 600;
+        let total_leaf_count = 42;
+        let sig = prepare_base_sig_and_set_oracle(
+            &mut svm,
+            bridge_pda,
+            [42u8; 32],
+            output_root,
+            base_block_number,
+            total_leaf_count,
+        );
+
+        // The signatures authorize the registration, whoever pays for it
+        let payer = Keypair::new();
+        svm.airdrop(&payer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            base_block_number,
+            total_leaf_count,
+            vec![sig],
+        )
+        .expect("register_output_root should succeed with any payer");
+
+        let root_account = svm
+            .get_account(&output_root_pda(base_block_number))
+            .unwrap();
+        let root = OutputRoot::try_deserialize(&mut &root_account.data[..]).unwrap();
+        assert_eq!(root.root, output_root);
+    }
+
     #[test]
     fn test_register_output_root_success_sets_total_leaf_count() {
         let SetupBridgeResult {
@@ -377,6 +533,7 @@ mod tests {
             .unwrap();
         let root = OutputRoot::try_deserialize(&mut &root_account.data[..]).unwrap();
         assert_eq!(root.total_leaf_count, total_leaf_count);
+        assert_eq!(root.base_block_number, base_block_number);
     }
 
     #[test]
@@ -420,6 +577,100 @@ mod tests {
         assert_eq!(bridge.base_block_number, base_block_number);
     }
 
+    #[test]
+    fn test_register_output_root_records_signer_heartbeat() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let partner_cfg = write_partner_config_account(&mut svm, &[]);
+
+        let output_root = [10u8; 32];
+        let base_block_number = 1500;
+        let total_leaf_count = 3;
+
+        let (sig, addr) =
+            make_eth_sig_and_addr([45u8; 32], output_root, base_block_number, total_leaf_count);
+        set_base_oracle_signers_threshold_one(&mut svm, bridge_pda, addr);
+        mock_clock(&mut svm, 1_000);
+
+        send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            base_block_number,
+            total_leaf_count,
+            vec![sig],
+        )
+        .expect("register_output_root should succeed");
+
+        let bridge_acc = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
+        assert_eq!(bridge.oracle_health.last_signed_at(&addr), 1_000);
+    }
+
+    #[test]
+    fn test_register_output_root_reimbursed_by_maintenance_budget() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let partner_cfg = write_partner_config_account(&mut svm, &[]);
+
+        // The budget covers the rent and the 5000 lamports fee of the single signature
+        set_program_account(
+            &mut svm,
+            maintenance_budget_pda(),
+            &MaintenanceBudget {
+                fee_reimbursement: 5_000,
+                total_reimbursed: 0,
+            },
+        );
+        svm.airdrop(&maintenance_budget_pda(), LAMPORTS_PER_SOL)
+            .unwrap();
+
+        let output_root = [3u8; 32];
+        let base_block_number = 600;
+        let total_leaf_count = 7;
+        let sig = prepare_base_sig_and_set_oracle(
+            &mut svm,
+            bridge_pda,
+            [44u8; 32],
+            output_root,
+            base_block_number,
+            total_leaf_count,
+        );
+
+        let payer_before = svm.get_balance(&payer.pubkey()).unwrap();
+        send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            base_block_number,
+            total_leaf_count,
+            vec![sig],
+        )
+        .expect("register_output_root should succeed");
+        assert_eq!(svm.get_balance(&payer.pubkey()).unwrap(), payer_before);
+
+        let rent = svm
+            .get_account(&output_root_pda(base_block_number))
+            .unwrap()
+            .lamports;
+        let account = svm.get_account(&maintenance_budget_pda()).unwrap();
+        let maintenance_budget =
+            MaintenanceBudget::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(maintenance_budget.total_reimbursed, rent + 5_000);
+    }
+
     #[test]
     fn test_register_output_root_fails_when_paused() {
         let SetupBridgeResult {
@@ -497,6 +748,66 @@ mod tests {
         assert!(err_str.contains("IncorrectBlockNumber"));
     }
 
+    #[test]
+    fn test_register_output_root_stops_recovering_once_quorum_met() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let partner_cfg = write_partner_config_account(&mut svm, &[]);
+
+        let output_root = [1u8; 32];
+        let base_block_number = 600;
+        let total_leaf_count = 42;
+        let sig = prepare_base_sig_and_set_oracle(
+            &mut svm,
+            bridge_pda,
+            [42u8; 32],
+            output_root,
+            base_block_number,
+            total_leaf_count,
+        );
+
+        // The malformed signature follows the quorum and is never recovered
+        let mut malformed_sig = sig;
+        malformed_sig[64] = 29;
+        send_register(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            base_block_number,
+            total_leaf_count,
+            vec![sig, sig, malformed_sig],
+        )
+        .expect("register_output_root should succeed once the quorum is met");
+
+        let root_account = svm
+            .get_account(&output_root_pda(base_block_number))
+            .unwrap();
+        let root = OutputRoot::try_deserialize(&mut &root_account.data[..]).unwrap();
+        assert_eq!(root.root, output_root);
+    }
+
+    #[test]
+    fn test_recover_unique_evm_addresses_bounds_signature_count() {
+        let (sig, addr) = make_eth_sig_and_addr([42u8; 32], [1u8; 32], 600, 42);
+        let message_hash = compute_output_root_message_hash(&[1u8; 32], 600, 42);
+
+        let signers =
+            recover_unique_evm_addresses(&[sig; MAX_SIGNATURE_COUNT as usize], &message_hash)
+                .unwrap();
+        assert_eq!(signers, vec![addr]);
+
+        let error =
+            recover_unique_evm_addresses(&[sig; MAX_SIGNATURE_COUNT as usize + 1], &message_hash)
+                .unwrap_err();
+        assert!(format!("{:?}", error).contains("TooManySignatures"));
+    }
+
     #[test]
     fn test_register_output_root_fails_when_not_monotonic() {
         let SetupBridgeResult {
@@ -806,4 +1117,113 @@ mod tests {
                 || err_str.contains("custom program error")
         );
     }
+
+    #[test]
+    fn test_register_output_root_with_secp256k1_instruction_succeeds() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let partner_cfg = write_partner_config_account(&mut svm, &[]);
+
+        let output_root = [21u8; 32];
+        let base_block_number = 600;
+        let total_leaf_count = 5;
+
+        let message = output_root_eth_message(output_root, base_block_number, total_leaf_count);
+        let (secp_ix, addr) = secp256k1_ix([45u8; 32], &message);
+        set_base_oracle_signers_threshold_one(&mut svm, bridge_pda, addr);
+
+        let register = register_ix(
+            payer.pubkey(),
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            base_block_number,
+            total_leaf_count,
+            vec![],
+            Some(sysvar::instructions::ID),
+        );
+        send_ixs(&mut svm, &payer, &[secp_ix, register])
+            .expect("register_output_root with a Secp256k1 instruction should succeed");
+
+        let root_account = svm
+            .get_account(&output_root_pda(base_block_number))
+            .unwrap();
+        let root = OutputRoot::try_deserialize(&mut &root_account.data[..]).unwrap();
+        assert_eq!(root.root, output_root);
+        assert_eq!(root.total_leaf_count, total_leaf_count);
+    }
+
+    #[test]
+    fn test_register_output_root_with_secp256k1_instruction_for_other_message_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let partner_cfg = write_partner_config_account(&mut svm, &[]);
+
+        let output_root = [22u8; 32];
+        let base_block_number = 600;
+
+        // The oracle signed a different total leaf count than the one registered
+        let message = output_root_eth_message(output_root, base_block_number, 5);
+        let (secp_ix, addr) = secp256k1_ix([46u8; 32], &message);
+        set_base_oracle_signers_threshold_one(&mut svm, bridge_pda, addr);
+
+        let register = register_ix(
+            payer.pubkey(),
+            bridge_pda,
+            partner_cfg,
+            output_root,
+            base_block_number,
+            6,
+            vec![],
+            Some(sysvar::instructions::ID),
+        );
+        let err_str = format!(
+            "{:?}",
+            send_ixs(&mut svm, &payer, &[secp_ix, register]).unwrap_err()
+        );
+        assert!(
+            err_str.contains("InvalidMessageHash"),
+            "Expected InvalidMessageHash error, got: {}",
+            err_str
+        );
+    }
+
+    #[test]
+    fn test_register_output_root_without_signatures_requires_instructions_sysvar() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let partner_cfg = write_partner_config_account(&mut svm, &[]);
+
+        let err_str = format!(
+            "{:?}",
+            send_register(
+                &mut svm,
+                &payer,
+                bridge_pda,
+                partner_cfg,
+                [23u8; 32],
+                600,
+                5,
+                vec![],
+            )
+            .unwrap_err()
+        );
+        assert!(
+            err_str.contains("MissingInstructionsSysvar"),
+            "Expected MissingInstructionsSysvar error, got: {}",
+            err_str
+        );
+    }
 }
@@ -0,0 +1,370 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, CreateAccount},
+};
+
+use crate::base_to_solana::{
+    compute_output_roots_batch_message_hash, recover_unique_evm_addresses, verify_signer_approvals,
+};
+use crate::BridgeError;
+use crate::{
+    base_to_solana::{
+        constants::{MAX_OUTPUT_ROOTS_PER_BATCH, OUTPUT_ROOT_SEED},
+        state::{OutputRoot, OutputRootEntry},
+    },
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+};
+
+/// Accounts struct for the `register_output_roots_batch` instruction that registers several
+/// Base output roots, covered by a single set of oracle signatures, in one transaction.
+/// This lets the oracle catch up on multiple block intervals at once (e.g. after downtime).
+///
+/// The `OutputRoot` PDAs to create are passed as writable remaining accounts, in the same
+/// order as the registered entries.
+#[derive(Accounts)]
+pub struct RegisterOutputRootsBatch<'info> {
+    /// Payer funds the accounts creation. Authorization is enforced via oracle EVM signature.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The main bridge state account that tracks the latest registered Base block number.
+    /// - Uses PDA with BRIDGE_SEED
+    /// - Must be mutable to update the base_block_number field
+    /// - Enforces registrations are monotonic and aligned to the configured interval
+    #[account(mut, seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// Partner `Config` account (PDA with seed "config") owned by partner program.
+    /// Unchecked to avoid Anchor pre-handler owner checks; PDA address is validated in the handler.
+    /// CHECK: This is validated in the handler.
+    pub partner_config: AccountInfo<'info>,
+
+    /// System program required for creating the output root accounts.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn register_output_roots_batch_handler<'a, 'info>(
+    ctx: Context<'a, '_, 'info, 'info, RegisterOutputRootsBatch<'info>>,
+    entries: Vec<OutputRootEntry>,
+    signatures: Vec<[u8; 65]>,
+) -> Result<()> {
+    // Check if bridge is paused
+    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+
+    require!(!entries.is_empty(), BridgeError::EmptyOutputRootsBatch);
+    require!(
+        entries.len() <= MAX_OUTPUT_ROOTS_PER_BATCH as usize,
+        BridgeError::TooManyOutputRoots
+    );
+    require!(
+        ctx.remaining_accounts.len() == entries.len(),
+        BridgeError::IncorrectOutputRootAccount
+    );
+
+    // Build message hash for signatures
+    let message_hash = compute_output_roots_batch_message_hash(&entries);
+
+    let unique_signers = recover_unique_evm_addresses(&signatures, &message_hash)?;
+    verify_signer_approvals(
+        &ctx.accounts.bridge,
+        &ctx.accounts.partner_config,
+        &unique_signers,
+        &message_hash,
+    )?;
+
+    let block_interval_requirement = ctx
+        .accounts
+        .bridge
+        .protocol_config
+        .block_interval_requirement;
+    let mut base_block_number = ctx.accounts.bridge.base_block_number;
+    let now = Clock::get()?.unix_timestamp;
+
+    for (entry, root_info) in entries.iter().zip(ctx.remaining_accounts.iter()) {
+        require!(
+            entry.base_block_number > base_block_number
+                && entry.base_block_number % block_interval_requirement == 0,
+            BridgeError::IncorrectBlockNumber
+        );
+
+        create_output_root(
+            root_info,
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+            entry,
+            now,
+        )?;
+
+        base_block_number = entry.base_block_number;
+    }
+
+    ctx.accounts.bridge.base_block_number = base_block_number;
+
+    let bridge: &mut Bridge = &mut ctx.accounts.bridge;
+    bridge.oracle_failover.last_output_root_at = now;
+    bridge
+        .oracle_health
+        .record(&bridge.base_oracle_config, &unique_signers, now);
+
+    Ok(())
+}
+
+/// Creates the `OutputRoot` PDA of `entry` and stores its root, total leaf count and block number,
+/// registered at `now`.
+fn create_output_root<'info>(
+    root_info: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    entry: &OutputRootEntry,
+    now: i64,
+) -> Result<()> {
+    let block_number_bytes = entry.base_block_number.to_le_bytes();
+    let (root_pda, bump) =
+        Pubkey::find_program_address(&[OUTPUT_ROOT_SEED, &block_number_bytes], &crate::ID);
+    require_keys_eq!(
+        root_info.key(),
+        root_pda,
+        BridgeError::IncorrectOutputRootAccount
+    );
+
+    let space = DISCRIMINATOR_LEN + OutputRoot::INIT_SPACE;
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            CreateAccount {
+                from: payer.to_account_info(),
+                to: root_info.clone(),
+            },
+            &[&[OUTPUT_ROOT_SEED, &block_number_bytes, &[bump]]],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &crate::ID,
+    )?;
+
+    let mut data = root_info.try_borrow_mut_data()?;
+    OutputRoot {
+        root: entry.output_root,
+        total_leaf_count: entry.total_leaf_count,
+        base_block_number: entry.base_block_number,
+        registered_at: now,
+        revision: 0,
+    }
+    .try_serialize(&mut &mut data[..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, keccak::hash as keccak_hash},
+        InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use secp256k1::{Message as SecpMessage, Secp256k1, SecretKey};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::constants::{PARTNER_PROGRAM_ID, PARTNER_SIGNERS_ACCOUNT_SEED},
+        common::MAX_SIGNER_COUNT,
+        instruction::RegisterOutputRootsBatch as RegisterOutputRootsBatchIx,
+        test_utils::{setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    const ORACLE_SK: [u8; 32] = [42u8; 32];
+
+    fn output_root_pda(base_block_number: u64) -> Pubkey {
+        Pubkey::find_program_address(&[OUTPUT_ROOT_SEED, &base_block_number.to_le_bytes()], &ID).0
+    }
+
+    fn entry(base_block_number: u64) -> OutputRootEntry {
+        OutputRootEntry {
+            output_root: [base_block_number as u8; 32],
+            base_block_number,
+            total_leaf_count: base_block_number / 100,
+        }
+    }
+
+    /// Signs the batch with `ORACLE_SK` and sets its address as the only Base oracle signer.
+    fn sign_batch_and_set_oracle(
+        svm: &mut LiteSVM,
+        bridge_pda: Pubkey,
+        entries: &[OutputRootEntry],
+    ) -> [u8; 65] {
+        let msg_hash = compute_output_roots_batch_message_hash(entries);
+
+        let secp = Secp256k1::new();
+        let sk = SecretKey::from_slice(&ORACLE_SK).unwrap();
+        let msg = SecpMessage::from_digest_slice(&msg_hash).unwrap();
+        let (rec_id, sig_bytes64) = secp.sign_ecdsa_recoverable(&msg, &sk).serialize_compact();
+
+        let mut sig65 = [0u8; 65];
+        sig65[..64].copy_from_slice(&sig_bytes64);
+        sig65[64] = 27 + rec_id.to_i32() as u8;
+
+        let pk = secp256k1::PublicKey::from_secret_key(&secp, &sk);
+        let hashed = keccak_hash(&pk.serialize_uncompressed()[1..]);
+        let mut addr = [0u8; 20];
+        addr.copy_from_slice(&hashed.to_bytes()[12..]);
+
+        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
+        bridge.base_oracle_config.threshold = 1;
+        bridge.base_oracle_config.signer_count = 1;
+        let mut signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+        signers[0] = addr;
+        bridge.base_oracle_config.signers = signers;
+        let mut data = Vec::new();
+        bridge.try_serialize(&mut data).unwrap();
+        bridge_acc.data = data;
+        svm.set_account(bridge_pda, bridge_acc).unwrap();
+
+        sig65
+    }
+
+    fn send_register_batch(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        entries: Vec<OutputRootEntry>,
+        signatures: Vec<[u8; 65]>,
+    ) -> std::result::Result<(), String> {
+        let mut accounts = accounts::RegisterOutputRootsBatch {
+            payer: payer.pubkey(),
+            bridge: bridge_pda,
+            partner_config: Pubkey::find_program_address(
+                &[PARTNER_SIGNERS_ACCOUNT_SEED],
+                &PARTNER_PROGRAM_ID,
+            )
+            .0,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+        accounts.extend(
+            entries
+                .iter()
+                .map(|entry| AccountMeta::new(output_root_pda(entry.base_block_number), false)),
+        );
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: RegisterOutputRootsBatchIx {
+                entries,
+                signatures,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_register_output_roots_batch_success() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let entries = vec![entry(300), entry(600), entry(1200)];
+        let sig = sign_batch_and_set_oracle(&mut svm, bridge_pda, &entries);
+
+        send_register_batch(&mut svm, &payer, bridge_pda, entries.clone(), vec![sig])
+            .expect("register_output_roots_batch should succeed");
+
+        for entry in entries {
+            let root_account = svm
+                .get_account(&output_root_pda(entry.base_block_number))
+                .unwrap();
+            assert_eq!(root_account.owner, ID);
+            let root = OutputRoot::try_deserialize(&mut &root_account.data[..]).unwrap();
+            assert_eq!(root.root, entry.output_root);
+            assert_eq!(root.total_leaf_count, entry.total_leaf_count);
+            assert_eq!(root.base_block_number, entry.base_block_number);
+        }
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(bridge.base_block_number, 1200);
+    }
+
+    #[test]
+    fn test_register_output_roots_batch_fails_when_not_monotonic() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let entries = vec![entry(600), entry(300)];
+        let sig = sign_batch_and_set_oracle(&mut svm, bridge_pda, &entries);
+
+        let error =
+            send_register_batch(&mut svm, &payer, bridge_pda, entries, vec![sig]).unwrap_err();
+        assert!(
+            error.contains("IncorrectBlockNumber"),
+            "Expected IncorrectBlockNumber error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_register_output_roots_batch_fails_with_tampered_entry() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let mut entries = vec![entry(300), entry(600)];
+        let sig = sign_batch_and_set_oracle(&mut svm, bridge_pda, &entries);
+        entries[1].total_leaf_count += 1;
+
+        let error =
+            send_register_batch(&mut svm, &payer, bridge_pda, entries, vec![sig]).unwrap_err();
+        assert!(
+            error.contains("InsufficientBaseSignatures"),
+            "Expected InsufficientBaseSignatures error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_register_output_roots_batch_fails_when_too_many_entries() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let entries = (1..=MAX_OUTPUT_ROOTS_PER_BATCH as u64 + 1)
+            .map(|i| entry(i * 300))
+            .collect::<Vec<_>>();
+        let sig = sign_batch_and_set_oracle(&mut svm, bridge_pda, &entries);
+
+        let error =
+            send_register_batch(&mut svm, &payer, bridge_pda, entries, vec![sig]).unwrap_err();
+        assert!(
+            error.contains("TooManyOutputRoots"),
+            "Expected TooManyOutputRoots error, got: {}",
+            error
+        );
+    }
+}
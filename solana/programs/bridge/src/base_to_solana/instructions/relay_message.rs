@@ -1,13 +1,29 @@
 use anchor_lang::{
     prelude::*,
-    solana_program::{self},
+    solana_program::{
+        self, compute_units::sol_remaining_compute_units, keccak, pubkey::MAX_SEED_LEN,
+    },
+    system_program,
 };
 
 use crate::base_to_solana::{
-    constants::BRIDGE_CPI_AUTHORITY_SEED, state::IncomingMessage, Message, Transfer,
+    bridge_cpi_authority,
+    constants::{
+        BRIDGE_CPI_AUTHORITY_SEED, MAX_EXTRA_SIGNERS, MAX_EXTRA_SIGNER_SEEDS,
+        OPERATIONAL_VAULT_SEED, RELAY_DENY_LIST_SEED, RELAY_FEE_VAULT_SEED,
+    },
+    state::{
+        HandlerRegistration, IncomingMessage, OperationalVault, OutputRoot, ProvenChunk,
+        RelayDenyList, RelayFeeVault,
+    },
+    token::{record_returned_transfer, record_wrapped_token_confirmation},
+    Ix, IxAccount, Message,
+};
+use crate::common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN};
+use crate::{
+    BridgeError, LamportAllowanceReturned, MessageRelayed, OutflowCircuitBreakerTripped,
+    RelayFeePaid,
 };
-use crate::common::{bridge::Bridge, BRIDGE_SEED};
-use crate::BridgeError;
 
 /// Accounts struct for the relay message instruction that executes cross-chain messages from Base to Solana.
 /// This instruction processes incoming messages that contain either pure instruction calls or token transfers
@@ -21,10 +37,56 @@ pub struct RelayMessage<'info> {
     #[account(mut)]
     pub message: Account<'info, IncomingMessage>,
 
+    /// The output root the message was proven against.
+    /// Messages proven against a revision superseded by `supersede_output_root` cannot be relayed.
+    #[account(
+        address = message.output_root @ BridgeError::IncorrectOutputRootAccount,
+        constraint = output_root.revision == message.output_root_revision
+            @ BridgeError::SupersededOutputRoot,
+    )]
+    pub output_root: Account<'info, OutputRoot>,
+
+    /// The prover that paid the rent of the message account, refunded the rent freed once the
+    /// message is executed.
+    /// CHECK: Validated to be the prover recorded in the message.
+    #[account(mut, address = message.prover @ BridgeError::IncorrectProver)]
+    pub prover: UncheckedAccount<'info>,
+
     /// The main bridge state account used to check pause status
     /// - Uses PDA with BRIDGE_SEED for deterministic address
-    #[account(seeds = [BRIDGE_SEED], bump)]
+    /// - Must be mutable to track outflows and pause the bridge when they are anomalous
+    #[account(mut, seeds = [BRIDGE_SEED], bump)]
     pub bridge: Account<'info, Bridge>,
+
+    /// Programs denied by the guardian on top of the built-in `DENIED_RELAY_PROGRAMS`.
+    /// CHECK: PDA with RELAY_DENY_LIST_SEED, deserialized only if owned by this program.
+    #[account(seeds = [RELAY_DENY_LIST_SEED], bump)]
+    pub relay_deny_list: AccountInfo<'info>,
+
+    /// The relay fee vault paying the relay fee of `WithRelayFee` messages.
+    /// Only required to relay `WithRelayFee` messages.
+    #[account(mut, seeds = [RELAY_FEE_VAULT_SEED], bump)]
+    pub relay_fee_vault: Option<Account<'info, RelayFeeVault>>,
+
+    /// The relayer credited with the relay fee of `WithRelayFee` messages.
+    /// Only required to relay `WithRelayFee` messages.
+    #[account(mut)]
+    pub relayer: Option<Signer<'info>>,
+
+    /// The operational vault lending the lamport allowance of `WithLamportAllowance` messages.
+    /// Only required to relay `WithLamportAllowance` messages.
+    #[account(mut, seeds = [OPERATIONAL_VAULT_SEED], bump)]
+    pub operational_vault: Option<Account<'info, OperationalVault>>,
+
+    /// The bridge CPI authority of the message sender, lent the lamport allowance of
+    /// `WithLamportAllowance` messages. Only required to relay `WithLamportAllowance` messages.
+    /// CHECK: PDA with BRIDGE_CPI_AUTHORITY_SEED and the message sender.
+    #[account(mut, seeds = [BRIDGE_CPI_AUTHORITY_SEED, message.sender.as_ref()], bump)]
+    pub bridge_cpi_authority: Option<UncheckedAccount<'info>>,
+
+    /// System program returning the unspent lamport allowance to the operational vault.
+    /// Only required to relay `WithLamportAllowance` messages.
+    pub system_program: Option<Program<'info, System>>,
 }
 
 pub fn relay_message_handler<'a, 'info>(
@@ -35,47 +97,2264 @@ pub fn relay_message_handler<'a, 'info>(
 
     require!(!ctx.accounts.message.executed, BridgeError::AlreadyExecuted);
 
-    let message = ctx.accounts.message.message.clone();
-    let (transfer, ixs) = match message {
-        Message::Call(ixs) => (None, ixs),
-        Message::Transfer { transfer, ixs } => (Some(transfer), ixs),
+    // High-value transfers are held by the finalization delay, during which the guardian can veto
+    require!(!ctx.accounts.message.vetoed, BridgeError::MessageVetoed);
+    require!(
+        Clock::get()?.unix_timestamp
+            >= ctx
+                .accounts
+                .message
+                .finalizable_at(&ctx.accounts.bridge.finalization_delay),
+        BridgeError::FinalizationDelayNotElapsed
+    );
+
+    require!(
+        ctx.accounts.message.remote_chain_id == ctx.accounts.bridge.remote_chain_id(),
+        BridgeError::IncorrectRemoteChainId
+    );
+
+    // Fail fast when the message declares more compute units than remain, before any transfer
+    // or CPI is executed
+    let (compute_units, message) = ctx.accounts.message.message.clone().split_compute_budget();
+    if let Some(compute_units) = compute_units {
+        require!(
+            sol_remaining_compute_units() >= compute_units as u64,
+            BridgeError::InsufficientComputeBudget
+        );
+    }
+    let (relay_fee, message) = message.split_relay_fee();
+    let (lamport_allowance, message) = message.split_lamport_allowance();
+
+    // Pause the bridge instead of releasing an anomalous outflow. The transaction succeeds so the
+    // pause sticks, and the message is held until the guardian approves its outflow, so that it
+    // cannot trip the breaker again once the bridge is unpaused.
+    if let Message::Transfer { transfer, .. } | Message::ReturnTransfer { transfer, .. } = &message
+    {
+        let (local_token, amount) = transfer.outflow();
+        let bridge = &mut ctx.accounts.bridge;
+        let now = Clock::get()?.unix_timestamp;
+        let message_account = &ctx.accounts.message;
+        require!(
+            !message_account.outflow_held || message_account.outflow_approved,
+            BridgeError::OutflowApprovalRequired
+        );
+        if message_account.outflow_approved {
+            bridge
+                .outflow_breaker
+                .record_approved(&local_token, amount, now);
+        } else if let Some(window_outflow) =
+            bridge.outflow_breaker.record(&local_token, amount, now)
+        {
+            bridge.paused = true;
+            ctx.accounts.message.outflow_held = true;
+            emit!(OutflowCircuitBreakerTripped {
+                message: ctx.accounts.message.key(),
+                local_token,
+                amount,
+                window_outflow,
+            });
+            emit_message_relayed(ctx.accounts, false);
+            return Ok(());
+        }
+    }
+
+    // The proven chunks of a chunked message are the last remaining accounts, after the accounts
+    // of the message itself
+    let (message_accounts, chunk_accounts) =
+        split_chunk_accounts(&ctx.accounts.message, ctx.remaining_accounts)?;
+
+    let (mut ixs, signer_seeds) = match message {
+        Message::Call(ixs) => (ixs, vec![]),
+        Message::Transfer { transfer, ixs } => {
+            transfer.finalize(ctx.remaining_accounts)?;
+            (ixs, vec![])
+        }
+        Message::ReturnTransfer {
+            outgoing_nonce,
+            transfer,
+        } => {
+            let transfer_accounts = record_returned_transfer(
+                &ctx.accounts.message.sender,
+                outgoing_nonce,
+                ctx.remaining_accounts,
+            )?;
+            transfer.finalize(transfer_accounts)?;
+            (vec![], vec![])
+        }
+        Message::CallWithSigners { ixs, signer_seeds } => (ixs, signer_seeds),
+        Message::ConfirmWrappedToken {
+            mint,
+            name,
+            symbol,
+            decimals,
+        } => {
+            record_wrapped_token_confirmation(
+                &ctx.accounts.message.sender,
+                &mint,
+                &name,
+                &symbol,
+                decimals,
+                &ctx.accounts.message.key(),
+                ctx.remaining_accounts,
+            )?;
+            (vec![], vec![])
+        }
+        Message::CallHandler { payload } => (
+            vec![handler_ix(
+                &ctx.accounts.message,
+                payload,
+                message_accounts,
+            )?],
+            vec![],
+        ),
+        Message::WithComputeBudget { .. } => return err!(BridgeError::NestedComputeBudget),
+        Message::WithRelayFee { .. } => return err!(BridgeError::NestedRelayFee),
+        Message::WithLamportAllowance { .. } => return err!(BridgeError::NestedLamportAllowance),
     };
 
-    // Process the transfer if it exists
-    if let Some(transfer) = transfer {
-        match transfer {
-            Transfer::Sol(transfer) => transfer.finalize(ctx.remaining_accounts)?,
-            Transfer::Spl(transfer) => transfer.finalize(ctx.remaining_accounts)?,
-            Transfer::WrappedToken(transfer) => transfer.finalize(ctx.remaining_accounts)?,
-        };
+    // The instructions of a chunked message continue in its proven chunks
+    ixs.extend(proven_chunk_ixs(&ctx.accounts.message, chunk_accounts)?);
+
+    // The bridge CPI authority must never invoke privileged programs, whatever the sender
+    let relay_deny_list = RelayDenyList::load(&ctx.accounts.relay_deny_list)?;
+    for ix in &ixs {
+        RelayDenyList::check_program(relay_deny_list.as_ref(), &ix.program_id)?;
     }
 
     ctx.accounts.message.executed = true;
+    refund_prover_rent(&mut ctx.accounts.message, &ctx.accounts.prover)?;
+
+    if let Some(fee) = relay_fee {
+        pay_relay_fee(ctx.accounts, fee)?;
+    }
+
+    let balance_before_allowance = match lamport_allowance {
+        Some(lamports) => Some(lend_lamport_allowance(ctx.accounts, lamports)?),
+        None => None,
+    };
+
+    let sender = ctx.accounts.message.sender;
+    log_event!(
+        info,
+        op = "relay_message",
+        message = ctx.accounts.message.key(),
+        sender = hex::encode(sender),
+    );
 
     // Derive the bridge CPI authority PDA tied to the message sender; used to sign all downstream CPIs.
     let (_, bump) = Pubkey::find_program_address(
-        &[
-            BRIDGE_CPI_AUTHORITY_SEED,
-            ctx.accounts.message.sender.as_ref(),
-        ],
+        &[BRIDGE_CPI_AUTHORITY_SEED, sender.as_ref()],
         ctx.program_id,
     );
 
-    let bridge_cpi_authority_seeds: &[&[u8]] = &[
-        BRIDGE_CPI_AUTHORITY_SEED,
-        ctx.accounts.message.sender.as_ref(),
-        &[bump],
-    ];
+    let bridge_cpi_authority_seeds: &[&[u8]] =
+        &[BRIDGE_CPI_AUTHORITY_SEED, sender.as_ref(), &[bump]];
+
+    // Derive the extra signers requested by the message, namespaced under the bridge CPI authority.
+    let extra_bumps = extra_signer_bumps(&sender, &signer_seeds, ctx.program_id)?;
+    let extra_signers_seeds = signer_seeds
+        .iter()
+        .zip(extra_bumps.iter())
+        .map(|(seeds, bump)| {
+            let mut seeds_with_bump: Vec<&[u8]> = vec![BRIDGE_CPI_AUTHORITY_SEED, sender.as_ref()];
+            seeds_with_bump.extend(seeds.iter().map(Vec::as_slice));
+            seeds_with_bump.push(bump);
+            seeds_with_bump
+        })
+        .collect::<Vec<_>>();
+
+    let mut signers_seeds = vec![bridge_cpi_authority_seeds];
+    signers_seeds.extend(extra_signers_seeds.iter().map(Vec::as_slice));
+
+    // Instructions relayed to this program (e.g. from the Base governance) may update the bridge:
+    // write it before the CPIs and reload it after, so that exiting does not overwrite them.
+    ctx.accounts.bridge.exit(ctx.program_id)?;
 
     // Execute the provided downstream instructions via signed CPI
     for ix in ixs {
+        log_event!(debug, op = "relay_cpi", program_id = ix.program_id);
         // NOTE: We always do a signed CPI even if the actual program CPIed into might not require the bridge authority signer.
-        solana_program::program::invoke_signed(
-            &ix.into(),
-            ctx.remaining_accounts,
-            &[bridge_cpi_authority_seeds],
+        solana_program::program::invoke_signed(&ix.into(), ctx.remaining_accounts, &signers_seeds)?;
+    }
+
+    ctx.accounts.bridge.reload()?;
+
+    emit_message_relayed(ctx.accounts, true);
+
+    // Take back the unspent allowance once the receipt hashed the return data of the last CPI
+    if let (Some(lamports), Some(balance_before)) = (lamport_allowance, balance_before_allowance) {
+        return_lamport_allowance(
+            ctx.accounts,
+            lamports,
+            balance_before,
+            bridge_cpi_authority_seeds,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Splits `remaining_accounts` into the accounts of the message instructions and the
+/// `ProvenChunk` accounts of a message proven with `prove_message_chunked`, passed in index order
+/// as the last remaining accounts.
+fn split_chunk_accounts<'b, 'info>(
+    message: &Account<IncomingMessage>,
+    remaining_accounts: &'b [AccountInfo<'info>],
+) -> Result<(&'b [AccountInfo<'info>], &'b [AccountInfo<'info>])> {
+    let chunk_count = message.chunk_count as usize;
+    require!(
+        remaining_accounts.len() >= chunk_count,
+        BridgeError::IncorrectProvenChunkAccount
+    );
+
+    Ok(remaining_accounts.split_at(remaining_accounts.len() - chunk_count))
+}
+
+/// Loads the instructions of the `ProvenChunk` accounts of a chunked message, in index order.
+fn proven_chunk_ixs(
+    message: &Account<IncomingMessage>,
+    chunk_infos: &[AccountInfo],
+) -> Result<Vec<Ix>> {
+    let mut ixs = vec![];
+    for (index, chunk_info) in chunk_infos.iter().enumerate() {
+        require_keys_eq!(
+            *chunk_info.owner,
+            crate::ID,
+            BridgeError::IncorrectProvenChunkAccount
+        );
+        let chunk = ProvenChunk::try_deserialize(&mut &chunk_info.try_borrow_data()?[..])?;
+        require!(
+            chunk.message == message.key() && chunk.index as usize == index,
+            BridgeError::IncorrectProvenChunkAccount
+        );
+        ixs.extend(chunk.ixs);
+    }
+
+    Ok(ixs)
+}
+
+/// Builds the instruction invoking the handler registered by the sender of a `CallHandler` message,
+/// from the handler registration, handler program and handler accounts of the message, i.e. the
+/// remaining accounts before its proven chunks.
+/// The handler accounts start with the bridge CPI authority of the sender, the only signer of the
+/// handler instruction; the other accounts are chosen by the relayer and validated by the handler.
+fn handler_ix(
+    message: &Account<IncomingMessage>,
+    payload: Vec<u8>,
+    message_accounts: &[AccountInfo],
+) -> Result<Ix> {
+    let [registration_info, handler_program, handler_accounts @ ..] = message_accounts else {
+        return err!(BridgeError::IncorrectHandlerAccounts);
+    };
+    require!(
+        registration_info.key() == HandlerRegistration::pda(&message.sender)
+            && *registration_info.owner == crate::ID,
+        BridgeError::HandlerNotRegistered
+    );
+    let registration =
+        HandlerRegistration::try_deserialize(&mut &registration_info.try_borrow_data()?[..])?;
+
+    let (cpi_authority, _) = bridge_cpi_authority(&message.sender);
+    require!(
+        handler_program.key() == registration.program_id
+            && handler_accounts.first().map(|account| account.key()) == Some(cpi_authority),
+        BridgeError::IncorrectHandlerAccounts
+    );
+
+    Ok(Ix {
+        program_id: registration.program_id,
+        accounts: handler_accounts
+            .iter()
+            .enumerate()
+            .map(|(index, account)| IxAccount {
+                pubkey: account.key(),
+                is_writable: account.is_writable,
+                is_signer: index == 0,
+            })
+            .collect(),
+        data: registration.handler_data(message.sender, message.nonce, payload)?,
+    })
+}
+
+/// Shrinks an executed message to the size needed to keep its replay protection and refunds the
+/// rent freed to its prover. The message payload is no longer needed once executed.
+fn refund_prover_rent(
+    message: &mut Account<IncomingMessage>,
+    prover: &UncheckedAccount,
+) -> Result<()> {
+    message.message = Message::Call(vec![]);
+    let new_len = DISCRIMINATOR_LEN + IncomingMessage::space(message.message.try_to_vec()?.len());
+
+    let message_info = message.to_account_info();
+    message_info.realloc(new_len, false)?;
+
+    let refund = message_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(new_len));
+    **message_info.try_borrow_mut_lamports()? -= refund;
+    **prover.try_borrow_mut_lamports()? += refund;
+
+    Ok(())
+}
+
+/// Emits the `MessageRelayed` receipt of the message, hashing the return data left by the last
+/// executed CPI.
+fn emit_message_relayed(accounts: &RelayMessage, success: bool) {
+    let return_data_hash = solana_program::program::get_return_data()
+        .filter(|_| success)
+        .map(|(_, data)| keccak::hash(&data).to_bytes())
+        .unwrap_or_default();
+
+    emit!(MessageRelayed {
+        nonce: accounts.message.nonce,
+        sender_base: accounts.message.sender,
+        success,
+        return_data_hash,
+        relayer: accounts
+            .relayer
+            .as_ref()
+            .map(|relayer| relayer.key())
+            .unwrap_or_default(),
+    });
+}
+
+/// Pays the relay fee prepaid on Base from the relay fee vault to the relayer. The payout is capped
+/// by the vault lamports above its rent-exempt minimum; the unpaid remainder is only recorded.
+fn pay_relay_fee(accounts: &mut RelayMessage, fee: u64) -> Result<()> {
+    let (Some(relay_fee_vault), Some(relayer)) =
+        (accounts.relay_fee_vault.as_mut(), accounts.relayer.as_ref())
+    else {
+        return err!(BridgeError::MissingRelayFeeAccounts);
+    };
+
+    let vault_info = relay_fee_vault.to_account_info();
+    let available = vault_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(vault_info.data_len()));
+    let paid = fee.min(available);
+
+    **vault_info.try_borrow_mut_lamports()? -= paid;
+    **relayer.try_borrow_mut_lamports()? += paid;
+
+    relay_fee_vault.total_paid = relay_fee_vault.total_paid.saturating_add(paid);
+    relay_fee_vault.total_unpaid = relay_fee_vault.total_unpaid.saturating_add(fee - paid);
+
+    emit!(RelayFeePaid {
+        message: accounts.message.key(),
+        relayer: relayer.key(),
+        fee,
+        paid,
+    });
+
+    Ok(())
+}
+
+/// Lends the lamport allowance of a `WithLamportAllowance` message from the operational vault to the
+/// sender's bridge CPI authority. Returns the balance of the bridge CPI authority before the loan.
+fn lend_lamport_allowance(accounts: &mut RelayMessage, lamports: u64) -> Result<u64> {
+    let (Some(operational_vault), Some(bridge_cpi_authority), Some(_)) = (
+        accounts.operational_vault.as_mut(),
+        accounts.bridge_cpi_authority.as_ref(),
+        accounts.system_program.as_ref(),
+    ) else {
+        return err!(BridgeError::MissingLamportAllowanceAccounts);
+    };
+
+    require!(
+        lamports <= operational_vault.max_allowance,
+        BridgeError::LamportAllowanceTooHigh
+    );
+
+    let vault_info = operational_vault.to_account_info();
+    let available = vault_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(vault_info.data_len()));
+    require!(
+        lamports <= available,
+        BridgeError::InsufficientOperationalVault
+    );
+
+    let balance_before = bridge_cpi_authority.lamports();
+    vault_info.sub_lamports(lamports)?;
+    bridge_cpi_authority.add_lamports(lamports)?;
+
+    operational_vault.total_lent = operational_vault.total_lent.saturating_add(lamports);
+
+    Ok(balance_before)
+}
+
+/// Returns the lamports of the allowance the bridge CPI authority did not spend to the operational
+/// vault. Lamports the executed instructions credited to the authority beyond the allowance stay.
+fn return_lamport_allowance(
+    accounts: &mut RelayMessage,
+    lamports: u64,
+    balance_before: u64,
+    bridge_cpi_authority_seeds: &[&[u8]],
+) -> Result<()> {
+    let (Some(operational_vault), Some(bridge_cpi_authority), Some(system_program)) = (
+        accounts.operational_vault.as_mut(),
+        accounts.bridge_cpi_authority.as_ref(),
+        accounts.system_program.as_ref(),
+    ) else {
+        return err!(BridgeError::MissingLamportAllowanceAccounts);
+    };
+
+    let returned = bridge_cpi_authority
+        .lamports()
+        .saturating_sub(balance_before)
+        .min(lamports);
+    if returned > 0 {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: bridge_cpi_authority.to_account_info(),
+                    to: operational_vault.to_account_info(),
+                },
+                &[bridge_cpi_authority_seeds],
+            ),
+            returned,
         )?;
     }
 
+    operational_vault.total_returned = operational_vault.total_returned.saturating_add(returned);
+
+    emit!(LamportAllowanceReturned {
+        message: accounts.message.key(),
+        bridge_cpi_authority: bridge_cpi_authority.key(),
+        lamports,
+        returned,
+    });
+
     Ok(())
 }
+
+/// Validates the custom seeds of the extra signers and returns the bump of each extra signer PDA
+/// derived from `[BRIDGE_CPI_AUTHORITY_SEED, sender, ..seeds]`.
+fn extra_signer_bumps(
+    sender: &[u8; 20],
+    signer_seeds: &[Vec<Vec<u8>>],
+    program_id: &Pubkey,
+) -> Result<Vec<[u8; 1]>> {
+    require!(
+        signer_seeds.len() <= MAX_EXTRA_SIGNERS as usize,
+        BridgeError::TooManyExtraSigners
+    );
+
+    signer_seeds
+        .iter()
+        .map(|seeds| {
+            require!(
+                seeds.len() <= MAX_EXTRA_SIGNER_SEEDS as usize,
+                BridgeError::TooManyExtraSignerSeeds
+            );
+            require!(
+                seeds.iter().all(|seed| seed.len() <= MAX_SEED_LEN),
+                BridgeError::ExtraSignerSeedTooLong
+            );
+
+            let mut pda_seeds: Vec<&[u8]> = vec![BRIDGE_CPI_AUTHORITY_SEED, sender.as_ref()];
+            pda_seeds.extend(seeds.iter().map(Vec::as_slice));
+            let (_, bump) = Pubkey::find_program_address(&pda_seeds, program_id);
+            Ok([bump])
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message as SvmMessage;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::{
+            constants::{
+                DENIED_RELAY_PROGRAMS, MAX_HANDLER_PAYLOAD_LEN, MAX_RELAY_DENY_LIST_PROGRAMS,
+                RETURNED_TRANSFER_SEED,
+            },
+            token::FinalizeBridgeSol,
+            ReturnedTransfer, Transfer,
+        },
+        common::{
+            bridge::{FinalizationThreshold, OutflowThreshold},
+            AddressBook, PartialTokenMetadata, TokenDeposits, WrappedTokenConfirmation,
+            SOL_VAULT_SEED,
+        },
+        instruction::RelayMessage as RelayMessageIx,
+        solana_to_base::{NATIVE_SOL_PUBKEY, SPL_MEMO_PROGRAM_ID},
+        test_utils::{
+            address_book_pda, create_mock_wrapped_mint, mock_clock, mock_output_root,
+            operational_vault_pda, output_root_pda, relay_deny_list_pda, relay_fee_vault_pda,
+            set_program_account, setup_bridge, token_deposits_pda, SetupBridgeResult,
+        },
+        BASE_CHAIN_ID, ID,
+    };
+
+    const BASE_BRIDGE: [u8; 20] = [9u8; 20];
+
+    /// Sets the Base Bridge in the address book and locks `amount` lamports in the SOL vault.
+    fn setup_return_transfer(svm: &mut LiteSVM, amount: u64) {
+        set_program_account(
+            svm,
+            address_book_pda(),
+            &AddressBook {
+                bridge: BASE_BRIDGE,
+                relayer_orchestrator: [0u8; 20],
+                token_count: 0,
+                tokens: Default::default(),
+            },
+        );
+
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        let mut vault = svm.get_account(&sol_vault).unwrap();
+        vault.lamports += amount;
+        svm.set_account(sol_vault, vault).unwrap();
+
+        set_program_account(
+            svm,
+            token_deposits_pda(&sol_vault),
+            &TokenDeposits {
+                vault: sol_vault,
+                locked_amount: amount,
+            },
+        );
+    }
+
+    fn relay_return_transfer(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        sender: [u8; 20],
+        outgoing_nonce: u64,
+        to: Pubkey,
+        amount: u64,
+    ) -> std::result::Result<(), String> {
+        let message = Pubkey::new_unique();
+        set_program_account(
+            svm,
+            message,
+            &IncomingMessage {
+                sender,
+                remote_chain_id: BASE_CHAIN_ID,
+                message: Message::ReturnTransfer {
+                    outgoing_nonce,
+                    transfer: Transfer::Sol(FinalizeBridgeSol { to, amount }),
+                },
+                executed: false,
+                chunk_count: 0,
+                output_root: output_root_pda(0),
+                output_root_revision: 0,
+                prover: payer.pubkey(),
+                nonce: 0,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        let returned_transfer = Pubkey::find_program_address(
+            &[RETURNED_TRANSFER_SEED, &outgoing_nonce.to_le_bytes()],
+            &ID,
+        )
+        .0;
+
+        let mut accounts = accounts::RelayMessage {
+            message,
+            output_root: mock_output_root(svm, 0, 0),
+            prover: payer.pubkey(),
+            bridge: bridge_pda,
+            relay_deny_list: relay_deny_list_pda(),
+            relay_fee_vault: None,
+            relayer: None,
+            operational_vault: None,
+            bridge_cpi_authority: None,
+            system_program: None,
+        }
+        .to_account_metas(None);
+        accounts.extend([
+            AccountMeta::new_readonly(address_book_pda(), false),
+            AccountMeta::new(returned_transfer, false),
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(sol_vault, false),
+            AccountMeta::new(to, false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(token_deposits_pda(&sol_vault), false),
+        ]);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: RelayMessageIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            SvmMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn relay_confirm_wrapped_token(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        sender: [u8; 20],
+        mint: Pubkey,
+        name: &str,
+        symbol: &str,
+        decimals: u8,
+    ) -> std::result::Result<(), String> {
+        let message = Pubkey::new_unique();
+        set_program_account(
+            svm,
+            message,
+            &IncomingMessage {
+                sender,
+                remote_chain_id: BASE_CHAIN_ID,
+                message: Message::ConfirmWrappedToken {
+                    mint,
+                    name: name.to_string(),
+                    symbol: symbol.to_string(),
+                    decimals,
+                },
+                executed: false,
+                chunk_count: 0,
+                output_root: output_root_pda(0),
+                output_root_revision: 0,
+                prover: payer.pubkey(),
+                nonce: 0,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+
+        let mut accounts = accounts::RelayMessage {
+            message,
+            output_root: mock_output_root(svm, 0, 0),
+            prover: payer.pubkey(),
+            bridge: bridge_pda,
+            relay_deny_list: relay_deny_list_pda(),
+            relay_fee_vault: None,
+            relayer: None,
+            operational_vault: None,
+            bridge_cpi_authority: None,
+            system_program: None,
+        }
+        .to_account_metas(None);
+        accounts.extend([
+            AccountMeta::new_readonly(address_book_pda(), false),
+            AccountMeta::new(WrappedTokenConfirmation::pda(&mint), false),
+            AccountMeta::new_readonly(mint, false),
+            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ]);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: RelayMessageIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            SvmMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    /// Metadata of a wrapped mint with 6 decimals representing a Base token with 18 decimals.
+    fn wrapped_token_metadata() -> PartialTokenMetadata {
+        PartialTokenMetadata {
+            name: "Wrapped Token".to_string(),
+            symbol: "WTK".to_string(),
+            remote_token: [7u8; 20],
+            scaler_exponent: 12,
+        }
+    }
+
+    /// Builds a System Program transfer instruction (`SystemInstruction::Transfer` has index 2).
+    fn system_transfer_ix(from: &Pubkey, to: &Pubkey, lamports: u64) -> Instruction {
+        let mut data = 2u32.to_le_bytes().to_vec();
+        data.extend_from_slice(&lamports.to_le_bytes());
+        Instruction {
+            program_id: system_program::ID,
+            accounts: vec![AccountMeta::new(*from, true), AccountMeta::new(*to, false)],
+            data,
+        }
+    }
+
+    fn relay_call_with_signers(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        sender: [u8; 20],
+        ixs: Vec<Instruction>,
+        signer_seeds: Vec<Vec<Vec<u8>>>,
+    ) -> std::result::Result<(), String> {
+        let message = Pubkey::new_unique();
+        let remaining_accounts = ixs
+            .iter()
+            .flat_map(|ix| {
+                std::iter::once(AccountMeta::new_readonly(ix.program_id, false)).chain(
+                    ix.accounts.iter().map(|account| AccountMeta {
+                        is_signer: false,
+                        ..account.clone()
+                    }),
+                )
+            })
+            .collect::<Vec<_>>();
+        set_program_account(
+            svm,
+            message,
+            &IncomingMessage {
+                sender,
+                remote_chain_id: BASE_CHAIN_ID,
+                message: Message::CallWithSigners {
+                    ixs: ixs.into_iter().map(Into::into).collect(),
+                    signer_seeds,
+                },
+                executed: false,
+                chunk_count: 0,
+                output_root: output_root_pda(0),
+                output_root_revision: 0,
+                prover: payer.pubkey(),
+                nonce: 0,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+
+        let mut accounts = accounts::RelayMessage {
+            message,
+            output_root: mock_output_root(svm, 0, 0),
+            prover: payer.pubkey(),
+            bridge: bridge_pda,
+            relay_deny_list: relay_deny_list_pda(),
+            relay_fee_vault: None,
+            relayer: None,
+            operational_vault: None,
+            bridge_cpi_authority: None,
+            system_program: None,
+        }
+        .to_account_metas(None);
+        accounts.extend(remaining_accounts);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: RelayMessageIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            SvmMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    /// Builds a Compute Budget `SetComputeUnitLimit` instruction (index 2).
+    fn set_compute_unit_limit_ix(units: u32) -> Instruction {
+        let mut data = vec![2u8];
+        data.extend_from_slice(&units.to_le_bytes());
+        Instruction {
+            program_id: pubkey!("ComputeBudget111111111111111111111111111111"),
+            accounts: vec![],
+            data,
+        }
+    }
+
+    fn relay_message(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        message: Pubkey,
+        compute_unit_limit: Option<u32>,
+    ) -> std::result::Result<Vec<String>, String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::RelayMessage {
+                message,
+                output_root: mock_output_root(svm, 0, 0),
+                prover: payer.pubkey(),
+                bridge: bridge_pda,
+                relay_deny_list: relay_deny_list_pda(),
+                relay_fee_vault: None,
+                relayer: None,
+                operational_vault: None,
+                bridge_cpi_authority: None,
+                system_program: None,
+            }
+            .to_account_metas(None),
+            data: RelayMessageIx {}.data(),
+        };
+        let ixs = compute_unit_limit
+            .map(set_compute_unit_limit_ix)
+            .into_iter()
+            .chain([ix])
+            .collect::<Vec<_>>();
+
+        let tx = Transaction::new(
+            &[payer],
+            SvmMessage::new(&ixs, Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|meta| meta.logs)
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_relay_call_to_denied_program_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let guardian_denied = Pubkey::new_unique();
+        let mut programs = [Pubkey::default(); MAX_RELAY_DENY_LIST_PROGRAMS as usize];
+        programs[0] = guardian_denied;
+        set_program_account(
+            &mut svm,
+            relay_deny_list_pda(),
+            &RelayDenyList {
+                program_count: 1,
+                programs,
+            },
+        );
+
+        for program_id in DENIED_RELAY_PROGRAMS.into_iter().chain([guardian_denied]) {
+            let ix = Instruction {
+                program_id,
+                accounts: vec![],
+                data: vec![],
+            };
+            let error =
+                relay_call_with_signers(&mut svm, &payer, bridge_pda, [1u8; 20], vec![ix], vec![])
+                    .unwrap_err();
+            assert!(
+                error.contains("DeniedRelayProgram"),
+                "Expected DeniedRelayProgram error for {}, got: {}",
+                program_id,
+                error
+            );
+        }
+    }
+
+    #[test]
+    fn test_relay_call_with_signers_signs_with_extra_pda() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let sender = [7u8; 20];
+        let custom_seeds = vec![b"vault".to_vec(), 1u64.to_le_bytes().to_vec()];
+        let extra_signer = Pubkey::find_program_address(
+            &[
+                BRIDGE_CPI_AUTHORITY_SEED,
+                sender.as_ref(),
+                custom_seeds[0].as_slice(),
+                custom_seeds[1].as_slice(),
+            ],
+            &ID,
+        )
+        .0;
+        svm.airdrop(&extra_signer, LAMPORTS_PER_SOL).unwrap();
+
+        let to = Pubkey::new_unique();
+        let transfer_ix = system_transfer_ix(&extra_signer, &to, LAMPORTS_PER_SOL / 2);
+
+        relay_call_with_signers(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            sender,
+            vec![transfer_ix],
+            vec![custom_seeds],
+        )
+        .expect("Failed to relay call with signers");
+
+        assert_eq!(svm.get_account(&to).unwrap().lamports, LAMPORTS_PER_SOL / 2);
+    }
+
+    #[test]
+    fn test_relay_call_with_signers_of_other_sender_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // The extra signer is derived under another sender's bridge CPI authority
+        let custom_seeds = vec![b"vault".to_vec()];
+        let extra_signer = Pubkey::find_program_address(
+            &[
+                BRIDGE_CPI_AUTHORITY_SEED,
+                [8u8; 20].as_ref(),
+                custom_seeds[0].as_slice(),
+            ],
+            &ID,
+        )
+        .0;
+        svm.airdrop(&extra_signer, LAMPORTS_PER_SOL).unwrap();
+
+        let transfer_ix =
+            system_transfer_ix(&extra_signer, &Pubkey::new_unique(), LAMPORTS_PER_SOL / 2);
+
+        let error = relay_call_with_signers(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            [7u8; 20],
+            vec![transfer_ix],
+            vec![custom_seeds],
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("PrivilegeEscalation") || error.contains("MissingRequiredSignature"),
+            "Expected a missing signature error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_relay_call_with_too_many_signers_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let signer_seeds = (0..=MAX_EXTRA_SIGNERS)
+            .map(|i| vec![vec![i]])
+            .collect::<Vec<_>>();
+
+        let error = relay_call_with_signers(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            [7u8; 20],
+            vec![],
+            signer_seeds,
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("TooManyExtraSigners"),
+            "Expected TooManyExtraSigners error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_relay_return_transfer_releases_sol_once() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let amount = LAMPORTS_PER_SOL;
+        setup_return_transfer(&mut svm, amount);
+
+        let to = Pubkey::new_unique();
+        relay_return_transfer(&mut svm, &payer, bridge_pda, BASE_BRIDGE, 7, to, amount)
+            .expect("Failed to relay return transfer");
+
+        assert_eq!(svm.get_account(&to).unwrap().lamports, amount);
+
+        let returned_transfer =
+            Pubkey::find_program_address(&[RETURNED_TRANSFER_SEED, &7u64.to_le_bytes()], &ID).0;
+        let returned_transfer = svm.get_account(&returned_transfer).unwrap();
+        assert_eq!(
+            ReturnedTransfer::try_deserialize(&mut &returned_transfer.data[..]).unwrap(),
+            ReturnedTransfer { outgoing_nonce: 7 }
+        );
+
+        // A second return for the same outgoing nonce is rejected
+        setup_return_transfer(&mut svm, amount);
+        let error = relay_return_transfer(&mut svm, &payer, bridge_pda, BASE_BRIDGE, 7, to, amount)
+            .unwrap_err();
+        assert!(
+            error.contains("TransferAlreadyReturned"),
+            "Expected TransferAlreadyReturned error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_relay_transfer_above_outflow_threshold_pauses_bridge() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let amount = LAMPORTS_PER_SOL;
+        setup_return_transfer(&mut svm, amount);
+
+        let account = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &account.data[..]).unwrap();
+        bridge
+            .outflow_breaker
+            .set(
+                3_600,
+                &[OutflowThreshold {
+                    local_token: NATIVE_SOL_PUBKEY,
+                    threshold: amount - 1,
+                }],
+            )
+            .unwrap();
+        set_program_account(&mut svm, bridge_pda, &bridge);
+
+        // The relay succeeds so that the pause sticks, but the SOL is withheld
+        let to = Pubkey::new_unique();
+        relay_return_transfer(&mut svm, &payer, bridge_pda, BASE_BRIDGE, 7, to, amount)
+            .expect("Failed to relay return transfer");
+
+        assert!(svm.get_account(&to).is_none());
+        let account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &account.data[..]).unwrap();
+        assert!(bridge.paused);
+    }
+
+    #[test]
+    fn test_relay_transfer_above_finalization_threshold_waits_for_delay() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let amount = LAMPORTS_PER_SOL;
+        setup_return_transfer(&mut svm, amount);
+
+        let account = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &account.data[..]).unwrap();
+        bridge
+            .finalization_delay
+            .set(
+                1_800,
+                &[FinalizationThreshold {
+                    local_token: NATIVE_SOL_PUBKEY,
+                    threshold: amount - 1,
+                }],
+            )
+            .unwrap();
+        set_program_account(&mut svm, bridge_pda, &bridge);
+
+        // The message is proven at 0 and held until the delay elapsed
+        let to = Pubkey::new_unique();
+        mock_clock(&mut svm, 1_799);
+        let error = relay_return_transfer(&mut svm, &payer, bridge_pda, BASE_BRIDGE, 7, to, amount)
+            .unwrap_err();
+        assert!(
+            error.contains("FinalizationDelayNotElapsed"),
+            "Expected FinalizationDelayNotElapsed error, got: {}",
+            error
+        );
+
+        mock_clock(&mut svm, 1_800);
+        relay_return_transfer(&mut svm, &payer, bridge_pda, BASE_BRIDGE, 7, to, amount)
+            .expect("Failed to relay return transfer");
+        assert_eq!(svm.get_account(&to).unwrap().lamports, amount);
+    }
+
+    #[test]
+    fn test_relay_return_transfer_from_other_sender_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let amount = LAMPORTS_PER_SOL;
+        setup_return_transfer(&mut svm, amount);
+
+        let error = relay_return_transfer(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            [1u8; 20],
+            7,
+            Pubkey::new_unique(),
+            amount,
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("UnauthorizedReturnTransfer"),
+            "Expected UnauthorizedReturnTransfer error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_return_transfer_decodes_base_encoding() {
+        // Bytes of `SVMBridgeLib.serializeReturnTransfer` in
+        // base/test/libraries/SVMBridgeLib.t.sol::test_serializeReturnTransfer_spl_matchesSolanaEncoding
+        let data = hex_literal::hex!(
+            "02"
+            "0700000000000000"
+            "01"
+            "1111111111111111111111111111111111111111"
+            "2222222222222222222222222222222222222222222222222222222222222222"
+            "3333333333333333333333333333333333333333333333333333333333333333"
+            "40420f0000000000"
+        );
+
+        let Message::ReturnTransfer {
+            outgoing_nonce,
+            transfer: Transfer::Spl(transfer),
+        } = Message::try_from_slice(&data).unwrap()
+        else {
+            panic!("Expected a ReturnTransfer of SPL tokens");
+        };
+        assert_eq!(outgoing_nonce, 7);
+        assert_eq!(transfer.remote_token, [0x11; 20]);
+        assert_eq!(transfer.local_token, Pubkey::new_from_array([0x22; 32]));
+        assert_eq!(transfer.to, Pubkey::new_from_array([0x33; 32]));
+        assert_eq!(transfer.amount, 1_000_000);
+    }
+
+    #[test]
+    fn test_confirm_wrapped_token_decodes_base_encoding() {
+        // Bytes of `SVMBridgeLib.serializeConfirmWrappedToken` in
+        // base/test/libraries/SVMBridgeLib.t.sol::test_serializeConfirmWrappedToken_matchesSolanaEncoding
+        let data = hex_literal::hex!(
+            "07"
+            "2222222222222222222222222222222222222222222222222222222222222222"
+            "0a000000"
+            "4d6f636b20546f6b656e"
+            "04000000"
+            "4d4f434b"
+            "12"
+        );
+
+        let Message::ConfirmWrappedToken {
+            mint,
+            name,
+            symbol,
+            decimals,
+        } = Message::try_from_slice(&data).unwrap()
+        else {
+            panic!("Expected a ConfirmWrappedToken message");
+        };
+        assert_eq!(mint, Pubkey::new_from_array([0x22; 32]));
+        assert_eq!(name, "Mock Token");
+        assert_eq!(symbol, "MOCK");
+        assert_eq!(decimals, 18);
+    }
+
+    #[test]
+    fn test_relay_confirm_wrapped_token_records_confirmation() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_return_transfer(&mut svm, 0);
+        let mint = create_mock_wrapped_mint(&mut svm, 0, 6, &wrapped_token_metadata());
+
+        let error = relay_confirm_wrapped_token(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            [1u8; 20],
+            mint,
+            "Wrapped Token",
+            "WTK",
+            18,
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("UnauthorizedWrappedTokenConfirmation"),
+            "Expected UnauthorizedWrappedTokenConfirmation error, got: {}",
+            error
+        );
+
+        relay_confirm_wrapped_token(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            BASE_BRIDGE,
+            mint,
+            "Wrapped Token",
+            "WTK",
+            18,
+        )
+        .expect("Failed to relay wrapped token confirmation");
+        let account = svm
+            .get_account(&WrappedTokenConfirmation::pda(&mint))
+            .unwrap();
+        assert_eq!(account.owner, ID);
+        let confirmation =
+            WrappedTokenConfirmation::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(confirmation.mint, mint);
+
+        // A duplicate confirmation is relayed without effect
+        relay_confirm_wrapped_token(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            BASE_BRIDGE,
+            mint,
+            "Wrapped Token",
+            "WTK",
+            18,
+        )
+        .expect("Failed to relay duplicate wrapped token confirmation");
+    }
+
+    #[test]
+    fn test_relay_confirm_wrapped_token_fails_on_metadata_mismatch() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_return_transfer(&mut svm, 0);
+        let mint = create_mock_wrapped_mint(&mut svm, 0, 6, &wrapped_token_metadata());
+
+        for (name, symbol, decimals) in [
+            ("Fake Token", "WTK", 18),
+            ("Wrapped Token", "FAKE", 18),
+            ("Wrapped Token", "WTK", 6),
+        ] {
+            let error = relay_confirm_wrapped_token(
+                &mut svm,
+                &payer,
+                bridge_pda,
+                BASE_BRIDGE,
+                mint,
+                name,
+                symbol,
+                decimals,
+            )
+            .unwrap_err();
+            assert!(
+                error.contains("WrappedTokenMetadataMismatch"),
+                "Expected WrappedTokenMetadataMismatch error, got: {}",
+                error
+            );
+        }
+        assert!(svm
+            .get_account(&WrappedTokenConfirmation::pda(&mint))
+            .is_none());
+
+        // Mints that were not created by `wrap_token` cannot be confirmed
+        let error = relay_confirm_wrapped_token(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            BASE_BRIDGE,
+            Pubkey::new_unique(),
+            "Wrapped Token",
+            "WTK",
+            18,
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("MintIsNotFromToken2022"),
+            "Expected MintIsNotFromToken2022 error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_relay_with_compute_budget_fails_fast_below_budget() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let message = Pubkey::new_unique();
+        set_program_account(
+            &mut svm,
+            message,
+            &IncomingMessage {
+                sender: [7u8; 20],
+                remote_chain_id: BASE_CHAIN_ID,
+                message: Message::WithComputeBudget {
+                    compute_units: 400_000,
+                    message: Message::Call(vec![]).into(),
+                },
+                executed: false,
+                chunk_count: 0,
+                output_root: output_root_pda(0),
+                output_root_revision: 0,
+                prover: payer.pubkey(),
+                nonce: 0,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+
+        // The default limit of 200k compute units is below the declared budget
+        let error = relay_message(&mut svm, &payer, bridge_pda, message, None).unwrap_err();
+        assert!(
+            error.contains("InsufficientComputeBudget"),
+            "Expected InsufficientComputeBudget error, got: {}",
+            error
+        );
+
+        // The relayer retries with a higher compute unit limit
+        relay_message(&mut svm, &payer, bridge_pda, message, Some(1_000_000))
+            .expect("Failed to relay message");
+
+        let account = svm.get_account(&message).unwrap();
+        let incoming_message = IncomingMessage::try_deserialize(&mut &account.data[..]).unwrap();
+        assert!(incoming_message.executed);
+    }
+
+    #[test]
+    fn test_relay_message_refunds_prover_rent() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let prover = Pubkey::new_unique();
+        svm.airdrop(&prover, LAMPORTS_PER_SOL).unwrap();
+
+        let message = Pubkey::new_unique();
+        set_program_account(
+            &mut svm,
+            message,
+            &IncomingMessage {
+                sender: [7u8; 20],
+                remote_chain_id: BASE_CHAIN_ID,
+                message: Message::WithComputeBudget {
+                    compute_units: 1,
+                    message: Message::Call(vec![]).into(),
+                },
+                executed: false,
+                chunk_count: 0,
+                output_root: output_root_pda(0),
+                output_root_revision: 0,
+                prover,
+                nonce: 0,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+        let rent_before = svm.get_account(&message).unwrap().lamports;
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::RelayMessage {
+                message,
+                output_root: mock_output_root(&mut svm, 0, 0),
+                prover,
+                bridge: bridge_pda,
+                relay_deny_list: relay_deny_list_pda(),
+                relay_fee_vault: None,
+                relayer: None,
+                operational_vault: None,
+                bridge_cpi_authority: None,
+                system_program: None,
+            }
+            .to_account_metas(None),
+            data: RelayMessageIx {}.data(),
+        };
+        let tx = Transaction::new(
+            &[&payer],
+            SvmMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Failed to relay message");
+
+        // Only the replay protection is kept, the freed rent goes back to the prover
+        let account = svm.get_account(&message).unwrap();
+        let new_len = DISCRIMINATOR_LEN
+            + IncomingMessage::space(Message::Call(vec![]).try_to_vec().unwrap().len());
+        assert_eq!(account.data.len(), new_len);
+        assert_eq!(
+            account.lamports,
+            svm.minimum_balance_for_rent_exemption(new_len)
+        );
+        assert_eq!(
+            svm.get_account(&prover).unwrap().lamports,
+            LAMPORTS_PER_SOL + rent_before - account.lamports
+        );
+
+        let incoming_message = IncomingMessage::try_deserialize(&mut &account.data[..]).unwrap();
+        assert!(incoming_message.executed);
+    }
+
+    #[test]
+    fn test_relay_message_emits_receipt() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let message = Pubkey::new_unique();
+        set_program_account(
+            &mut svm,
+            message,
+            &IncomingMessage {
+                sender: [7u8; 20],
+                remote_chain_id: BASE_CHAIN_ID,
+                message: Message::Call(vec![]),
+                executed: false,
+                chunk_count: 0,
+                output_root: output_root_pda(0),
+                output_root_revision: 0,
+                prover: payer.pubkey(),
+                nonce: 42,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+
+        let logs = relay_message(&mut svm, &payer, bridge_pda, message, None)
+            .expect("Failed to relay message");
+
+        let data = logs
+            .iter()
+            .filter_map(|log| log.strip_prefix("Program data: "))
+            .map(|data| STANDARD.decode(data).unwrap())
+            .find(|data| data.starts_with(MessageRelayed::DISCRIMINATOR))
+            .expect("MessageRelayed event not emitted");
+        let receipt =
+            MessageRelayed::try_from_slice(&data[MessageRelayed::DISCRIMINATOR.len()..]).unwrap();
+        assert_eq!(receipt.nonce, 42);
+        assert_eq!(receipt.sender_base, [7u8; 20]);
+        assert!(receipt.success);
+        assert_eq!(receipt.return_data_hash, [0u8; 32]);
+        assert_eq!(receipt.relayer, Pubkey::default());
+    }
+
+    #[test]
+    fn test_relay_nested_compute_budget_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let nested = Message::WithComputeBudget {
+            compute_units: 1,
+            message: Message::WithComputeBudget {
+                compute_units: 1,
+                message: Message::Call(vec![]).into(),
+            }
+            .into(),
+        };
+
+        let error = nested.validate(&[7u8; 20], None).unwrap_err();
+        assert_eq!(error, BridgeError::NestedComputeBudget.into());
+
+        let message = Pubkey::new_unique();
+        set_program_account(
+            &mut svm,
+            message,
+            &IncomingMessage {
+                sender: [7u8; 20],
+                remote_chain_id: BASE_CHAIN_ID,
+                message: nested,
+                executed: false,
+                chunk_count: 0,
+                output_root: output_root_pda(0),
+                output_root_revision: 0,
+                prover: payer.pubkey(),
+                nonce: 0,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+
+        let error = relay_message(&mut svm, &payer, bridge_pda, message, None).unwrap_err();
+        assert!(
+            error.contains("NestedComputeBudget"),
+            "Expected NestedComputeBudget error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_relay_message_from_other_chain_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let message = Pubkey::new_unique();
+        set_program_account(
+            &mut svm,
+            message,
+            &IncomingMessage {
+                sender: [7u8; 20],
+                remote_chain_id: BASE_CHAIN_ID + 1,
+                message: Message::Call(vec![]),
+                executed: false,
+                chunk_count: 0,
+                output_root: output_root_pda(0),
+                output_root_revision: 0,
+                prover: payer.pubkey(),
+                nonce: 0,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+
+        let error = relay_message(&mut svm, &payer, bridge_pda, message, None).unwrap_err();
+        assert!(
+            error.contains("IncorrectRemoteChainId"),
+            "Expected IncorrectRemoteChainId error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_relay_message_against_superseded_output_root_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let message = Pubkey::new_unique();
+        set_program_account(
+            &mut svm,
+            message,
+            &IncomingMessage {
+                sender: [7u8; 20],
+                remote_chain_id: BASE_CHAIN_ID,
+                message: Message::Call(vec![]),
+                executed: false,
+                chunk_count: 0,
+                output_root: output_root_pda(0),
+                output_root_revision: 0,
+                prover: payer.pubkey(),
+                nonce: 0,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+        let output_root = mock_output_root(&mut svm, 0, 1);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::RelayMessage {
+                message,
+                output_root,
+                prover: payer.pubkey(),
+                bridge: bridge_pda,
+                relay_deny_list: relay_deny_list_pda(),
+                relay_fee_vault: None,
+                relayer: None,
+                operational_vault: None,
+                bridge_cpi_authority: None,
+                system_program: None,
+            }
+            .to_account_metas(None),
+            data: RelayMessageIx {}.data(),
+        };
+        let tx = Transaction::new(
+            &[&payer],
+            SvmMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let error = format!("{:?}", svm.send_transaction(tx).unwrap_err());
+        assert!(
+            error.contains("SupersededOutputRoot"),
+            "Expected SupersededOutputRoot error, got: {}",
+            error
+        );
+    }
+
+    /// Writes a relay fee vault holding `balance` lamports above its rent-exempt minimum.
+    fn mock_relay_fee_vault(svm: &mut LiteSVM, balance: u64) {
+        set_program_account(
+            svm,
+            relay_fee_vault_pda(),
+            &RelayFeeVault {
+                settlement_nonce: 1,
+                total_settled: balance,
+                total_paid: 0,
+                total_unpaid: 0,
+            },
+        );
+        let mut vault = svm.get_account(&relay_fee_vault_pda()).unwrap();
+        vault.lamports += balance;
+        svm.set_account(relay_fee_vault_pda(), vault).unwrap();
+    }
+
+    fn relay_message_with_fee(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        fee: u64,
+    ) -> std::result::Result<(), String> {
+        let message = Pubkey::new_unique();
+        set_program_account(
+            svm,
+            message,
+            &IncomingMessage {
+                sender: [7u8; 20],
+                remote_chain_id: BASE_CHAIN_ID,
+                message: Message::WithRelayFee {
+                    fee,
+                    message: Message::Call(vec![]).into(),
+                },
+                executed: false,
+                chunk_count: 0,
+                output_root: output_root_pda(0),
+                output_root_revision: 0,
+                prover: payer.pubkey(),
+                nonce: 0,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::RelayMessage {
+                message,
+                output_root: mock_output_root(svm, 0, 0),
+                prover: payer.pubkey(),
+                bridge: bridge_pda,
+                relay_deny_list: relay_deny_list_pda(),
+                relay_fee_vault: Some(relay_fee_vault_pda()),
+                relayer: Some(payer.pubkey()),
+                operational_vault: None,
+                bridge_cpi_authority: None,
+                system_program: None,
+            }
+            .to_account_metas(None),
+            data: RelayMessageIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            SvmMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn read_relay_fee_vault(svm: &LiteSVM) -> RelayFeeVault {
+        let account = svm.get_account(&relay_fee_vault_pda()).unwrap();
+        RelayFeeVault::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    #[test]
+    fn test_relay_with_relay_fee_pays_relayer() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        mock_relay_fee_vault(&mut svm, LAMPORTS_PER_SOL);
+
+        let vault_before = svm.get_balance(&relay_fee_vault_pda()).unwrap();
+        relay_message_with_fee(&mut svm, &payer, bridge_pda, LAMPORTS_PER_SOL / 4)
+            .expect("Failed to relay message");
+        assert_eq!(
+            svm.get_balance(&relay_fee_vault_pda()).unwrap(),
+            vault_before - LAMPORTS_PER_SOL / 4
+        );
+
+        // The vault only pays out the remaining funds and records the shortfall
+        relay_message_with_fee(&mut svm, &payer, bridge_pda, LAMPORTS_PER_SOL)
+            .expect("Failed to relay message exceeding the vault funds");
+        assert_eq!(
+            read_relay_fee_vault(&svm),
+            RelayFeeVault {
+                settlement_nonce: 1,
+                total_settled: LAMPORTS_PER_SOL,
+                total_paid: LAMPORTS_PER_SOL,
+                total_unpaid: LAMPORTS_PER_SOL / 4,
+            }
+        );
+    }
+
+    #[test]
+    fn test_relay_with_relay_fee_requires_vault() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let message = Pubkey::new_unique();
+        set_program_account(
+            &mut svm,
+            message,
+            &IncomingMessage {
+                sender: [7u8; 20],
+                remote_chain_id: BASE_CHAIN_ID,
+                message: Message::WithRelayFee {
+                    fee: 1,
+                    message: Message::Call(vec![]).into(),
+                },
+                executed: false,
+                chunk_count: 0,
+                output_root: output_root_pda(0),
+                output_root_revision: 0,
+                prover: payer.pubkey(),
+                nonce: 0,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+
+        let error = relay_message(&mut svm, &payer, bridge_pda, message, None).unwrap_err();
+        assert!(
+            error.contains("MissingRelayFeeAccounts"),
+            "Expected MissingRelayFeeAccounts error, got: {}",
+            error
+        );
+
+        let nested = Message::WithRelayFee {
+            fee: 1,
+            message: Message::WithComputeBudget {
+                compute_units: 1,
+                message: Message::Call(vec![]).into(),
+            }
+            .into(),
+        };
+        let error = nested.validate(&[7u8; 20], None).unwrap_err();
+        assert_eq!(error, BridgeError::NestedRelayFee.into());
+    }
+
+    /// Writes an operational vault lending up to `max_allowance` lamports per message and holding
+    /// `balance` lamports above its rent-exempt minimum.
+    fn mock_operational_vault(svm: &mut LiteSVM, max_allowance: u64, balance: u64) {
+        set_program_account(
+            svm,
+            operational_vault_pda(),
+            &OperationalVault {
+                max_allowance,
+                total_lent: 0,
+                total_returned: 0,
+            },
+        );
+        let mut vault = svm.get_account(&operational_vault_pda()).unwrap();
+        vault.lamports += balance;
+        svm.set_account(operational_vault_pda(), vault).unwrap();
+    }
+
+    fn relay_message_with_lamport_allowance(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        lamports: u64,
+        ixs: Vec<Instruction>,
+    ) -> std::result::Result<(), String> {
+        let sender = [7u8; 20];
+        let message = Pubkey::new_unique();
+        let remaining_accounts = ixs
+            .iter()
+            .flat_map(|ix| {
+                std::iter::once(AccountMeta::new_readonly(ix.program_id, false)).chain(
+                    ix.accounts.iter().map(|account| AccountMeta {
+                        is_signer: false,
+                        ..account.clone()
+                    }),
+                )
+            })
+            .collect::<Vec<_>>();
+        set_program_account(
+            svm,
+            message,
+            &IncomingMessage {
+                sender,
+                remote_chain_id: BASE_CHAIN_ID,
+                message: Message::WithLamportAllowance {
+                    lamports,
+                    message: Message::Call(ixs.into_iter().map(Into::into).collect()).into(),
+                },
+                executed: false,
+                chunk_count: 0,
+                output_root: output_root_pda(0),
+                output_root_revision: 0,
+                prover: payer.pubkey(),
+                nonce: 0,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+
+        let mut accounts = accounts::RelayMessage {
+            message,
+            output_root: mock_output_root(svm, 0, 0),
+            prover: payer.pubkey(),
+            bridge: bridge_pda,
+            relay_deny_list: relay_deny_list_pda(),
+            relay_fee_vault: None,
+            relayer: None,
+            operational_vault: Some(operational_vault_pda()),
+            bridge_cpi_authority: Some(
+                Pubkey::find_program_address(&[BRIDGE_CPI_AUTHORITY_SEED, sender.as_ref()], &ID).0,
+            ),
+            system_program: Some(system_program::ID),
+        }
+        .to_account_metas(None);
+        accounts.extend(remaining_accounts);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: RelayMessageIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            SvmMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_relay_with_lamport_allowance_returns_unspent_lamports() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        mock_operational_vault(&mut svm, LAMPORTS_PER_SOL, LAMPORTS_PER_SOL);
+
+        let bridge_cpi_authority =
+            Pubkey::find_program_address(&[BRIDGE_CPI_AUTHORITY_SEED, [7u8; 20].as_ref()], &ID).0;
+        let recipient = Pubkey::new_unique();
+        let vault_before = svm.get_balance(&operational_vault_pda()).unwrap();
+
+        // The bridge CPI authority spends a quarter of its allowance to fund a new account
+        relay_message_with_lamport_allowance(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            LAMPORTS_PER_SOL / 2,
+            vec![system_transfer_ix(
+                &bridge_cpi_authority,
+                &recipient,
+                LAMPORTS_PER_SOL / 4,
+            )],
+        )
+        .expect("Failed to relay message");
+
+        assert_eq!(svm.get_balance(&recipient).unwrap(), LAMPORTS_PER_SOL / 4);
+        assert_eq!(svm.get_balance(&bridge_cpi_authority).unwrap_or(0), 0);
+        assert_eq!(
+            svm.get_balance(&operational_vault_pda()).unwrap(),
+            vault_before - LAMPORTS_PER_SOL / 4
+        );
+        let account = svm.get_account(&operational_vault_pda()).unwrap();
+        assert_eq!(
+            OperationalVault::try_deserialize(&mut &account.data[..]).unwrap(),
+            OperationalVault {
+                max_allowance: LAMPORTS_PER_SOL,
+                total_lent: LAMPORTS_PER_SOL / 2,
+                total_returned: LAMPORTS_PER_SOL / 4,
+            }
+        );
+
+        // The allowance is bounded by the configured maximum, then by the vault funds
+        let error = relay_message_with_lamport_allowance(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            LAMPORTS_PER_SOL + 1,
+            vec![],
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("LamportAllowanceTooHigh"),
+            "Expected LamportAllowanceTooHigh error, got: {}",
+            error
+        );
+
+        let error = relay_message_with_lamport_allowance(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            LAMPORTS_PER_SOL,
+            vec![],
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("InsufficientOperationalVault"),
+            "Expected InsufficientOperationalVault error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_relay_with_lamport_allowance_requires_vault() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let message = Pubkey::new_unique();
+        set_program_account(
+            &mut svm,
+            message,
+            &IncomingMessage {
+                sender: [7u8; 20],
+                remote_chain_id: BASE_CHAIN_ID,
+                message: Message::WithLamportAllowance {
+                    lamports: 1,
+                    message: Message::Call(vec![]).into(),
+                },
+                executed: false,
+                chunk_count: 0,
+                output_root: output_root_pda(0),
+                output_root_revision: 0,
+                prover: payer.pubkey(),
+                nonce: 0,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+
+        let error = relay_message(&mut svm, &payer, bridge_pda, message, None).unwrap_err();
+        assert!(
+            error.contains("MissingLamportAllowanceAccounts"),
+            "Expected MissingLamportAllowanceAccounts error, got: {}",
+            error
+        );
+
+        let nested = Message::WithLamportAllowance {
+            lamports: 1,
+            message: Message::WithRelayFee {
+                fee: 1,
+                message: Message::Call(vec![]).into(),
+            }
+            .into(),
+        };
+        let error = nested.validate(&[7u8; 20], None).unwrap_err();
+        assert_eq!(error, BridgeError::NestedLamportAllowance.into());
+    }
+
+    const HANDLER_SENDER: [u8; 20] = *b"handler-sender-20-b!";
+
+    /// Relays a `CallHandler` message carrying `payload`, proven with one `ProvenChunk` per entry
+    /// of `chunks`, passed after `remaining_accounts`.
+    fn relay_call_handler(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        payload: Vec<u8>,
+        remaining_accounts: Vec<AccountMeta>,
+        chunks: Vec<Vec<Ix>>,
+    ) -> std::result::Result<Vec<String>, String> {
+        let message = Pubkey::new_unique();
+        let chunk_count = chunks.len() as u8;
+        let chunk_accounts = chunks
+            .into_iter()
+            .enumerate()
+            .map(|(index, ixs)| {
+                let chunk = Pubkey::new_unique();
+                set_program_account(
+                    svm,
+                    chunk,
+                    &ProvenChunk {
+                        message,
+                        index: index as u8,
+                        ixs,
+                    },
+                );
+                AccountMeta::new_readonly(chunk, false)
+            })
+            .collect::<Vec<_>>();
+
+        set_program_account(
+            svm,
+            message,
+            &IncomingMessage {
+                sender: HANDLER_SENDER,
+                remote_chain_id: BASE_CHAIN_ID,
+                message: Message::CallHandler { payload },
+                executed: false,
+                chunk_count,
+                output_root: output_root_pda(0),
+                output_root_revision: 0,
+                prover: payer.pubkey(),
+                nonce: 42,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+
+        let mut accounts = accounts::RelayMessage {
+            message,
+            output_root: mock_output_root(svm, 0, 0),
+            prover: payer.pubkey(),
+            bridge: bridge_pda,
+            relay_deny_list: relay_deny_list_pda(),
+            relay_fee_vault: None,
+            relayer: None,
+            operational_vault: None,
+            bridge_cpi_authority: None,
+            system_program: None,
+        }
+        .to_account_metas(None);
+        accounts.extend(remaining_accounts);
+        accounts.extend(chunk_accounts);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: RelayMessageIx {}.data(),
+        };
+        let tx = Transaction::new(
+            &[payer],
+            SvmMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|meta| meta.logs)
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_relay_call_handler_invokes_registered_handler() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // The memo program logs the handler instruction data, which is valid UTF-8 here
+        let registration = HandlerRegistration::pda(&HANDLER_SENDER);
+        set_program_account(
+            &mut svm,
+            registration,
+            &HandlerRegistration {
+                program_id: SPL_MEMO_PROGRAM_ID,
+                discriminator: *b"handler!",
+            },
+        );
+
+        let logs = relay_call_handler(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            b"hello from base".to_vec(),
+            vec![
+                AccountMeta::new_readonly(registration, false),
+                AccountMeta::new_readonly(SPL_MEMO_PROGRAM_ID, false),
+                AccountMeta::new_readonly(bridge_cpi_authority(&HANDLER_SENDER).0, false),
+            ],
+            vec![],
+        )
+        .expect("Failed to relay handler call");
+
+        assert!(
+            logs.iter()
+                .any(|log| log.contains("handler!handler-sender-20-b!*")
+                    && log.contains("hello from base")),
+            "Handler not invoked with the message payload: {:?}",
+            logs
+        );
+    }
+
+    #[test]
+    fn test_relay_chunked_call_handler_excludes_chunk_accounts() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let registration = HandlerRegistration::pda(&HANDLER_SENDER);
+        set_program_account(
+            &mut svm,
+            registration,
+            &HandlerRegistration {
+                program_id: SPL_MEMO_PROGRAM_ID,
+                discriminator: *b"handler!",
+            },
+        );
+        let cpi_authority = bridge_cpi_authority(&HANDLER_SENDER).0;
+
+        // The memo program requires every account it receives to sign and logs each of them, so
+        // the handler instruction only succeeds with the CPI authority as its single account
+        let chunk_ix = Instruction {
+            program_id: SPL_MEMO_PROGRAM_ID,
+            accounts: vec![],
+            data: b"hello from the chunk".to_vec(),
+        };
+        let logs = relay_call_handler(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            b"hello from base".to_vec(),
+            vec![
+                AccountMeta::new_readonly(registration, false),
+                AccountMeta::new_readonly(SPL_MEMO_PROGRAM_ID, false),
+                AccountMeta::new_readonly(cpi_authority, false),
+            ],
+            vec![vec![chunk_ix.into()]],
+        )
+        .expect("Failed to relay chunked handler call");
+
+        let signed_by = logs
+            .iter()
+            .filter(|log| log.contains("Signed by"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            signed_by.len(),
+            1,
+            "Handler invoked with extra accounts: {:?}",
+            logs
+        );
+        assert!(signed_by[0].contains(&cpi_authority.to_string()));
+        assert!(
+            logs.iter().any(|log| log.contains("hello from the chunk")),
+            "Chunk instructions not executed: {:?}",
+            logs
+        );
+    }
+
+    #[test]
+    fn test_relay_call_handler_requires_registered_handler() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let registration = HandlerRegistration::pda(&HANDLER_SENDER);
+        let cpi_authority = bridge_cpi_authority(&HANDLER_SENDER).0;
+
+        let error = relay_call_handler(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            vec![],
+            vec![
+                AccountMeta::new_readonly(registration, false),
+                AccountMeta::new_readonly(SPL_MEMO_PROGRAM_ID, false),
+                AccountMeta::new_readonly(cpi_authority, false),
+            ],
+            vec![],
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("HandlerNotRegistered"),
+            "Expected HandlerNotRegistered error, got: {}",
+            error
+        );
+
+        set_program_account(
+            &mut svm,
+            registration,
+            &HandlerRegistration {
+                program_id: SPL_MEMO_PROGRAM_ID,
+                discriminator: *b"handler!",
+            },
+        );
+        // The handler accounts must start with the bridge CPI authority of the sender
+        let error = relay_call_handler(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            vec![],
+            vec![
+                AccountMeta::new_readonly(registration, false),
+                AccountMeta::new_readonly(SPL_MEMO_PROGRAM_ID, false),
+                AccountMeta::new_readonly(bridge_cpi_authority(&[7u8; 20]).0, false),
+            ],
+            vec![],
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("IncorrectHandlerAccounts"),
+            "Expected IncorrectHandlerAccounts error, got: {}",
+            error
+        );
+
+        let too_long = Message::CallHandler {
+            payload: vec![0u8; MAX_HANDLER_PAYLOAD_LEN as usize + 1],
+        };
+        let error = too_long.validate(&HANDLER_SENDER, None).unwrap_err();
+        assert_eq!(error, BridgeError::HandlerPayloadTooLong.into());
+    }
+}
@@ -0,0 +1,444 @@
+use anchor_lang::{prelude::*, solana_program::keccak};
+
+use crate::{
+    base_to_solana::{
+        compute_output_root_message_hash,
+        constants::EQUIVOCATION_EVIDENCE_SEED,
+        recover_eth_address,
+        state::{EquivocationEvidence, OutputRootEntry},
+    },
+    common::{
+        bridge::Bridge, record_config_change, ConfigHistory, ConfigParam, BRIDGE_SEED,
+        CONFIG_HISTORY_SEED, DISCRIMINATOR_LEN,
+    },
+    BridgeError, OracleEquivocationReported,
+};
+
+/// Accounts struct for the permissionless `report_equivocation` instruction that records
+/// evidence of Base oracle signers attesting two conflicting output roots for the same Base block
+/// and disables them pending guardian review.
+#[derive(Accounts)]
+#[instruction(
+    first: OutputRootEntry,
+    first_signatures: Vec<[u8; 65]>,
+    second: OutputRootEntry
+)]
+pub struct ReportEquivocation<'info> {
+    /// The reporter, paying for the evidence account.
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    /// The main bridge state account whose Base oracle signers are disabled.
+    #[account(mut, seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The evidence of the equivocation.
+    /// - Uses PDA with EQUIVOCATION_EVIDENCE_SEED, the Base block number and the
+    ///   `equivocation_hash` of the conflicting output roots
+    /// - Can only be recorded once per pair of conflicting output roots
+    #[account(
+        init,
+        payer = reporter,
+        space = DISCRIMINATOR_LEN + EquivocationEvidence::INIT_SPACE,
+        seeds = [
+            EQUIVOCATION_EVIDENCE_SEED,
+            &first.base_block_number.to_le_bytes(),
+            &equivocation_hash(&first, &second),
+        ],
+        bump
+    )]
+    pub evidence: Account<'info, EquivocationEvidence>,
+
+    /// Log recording the change of the Base oracle configuration
+    #[account(mut, seeds = [CONFIG_HISTORY_SEED], bump)]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+
+    /// System program required for creating the evidence account.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn report_equivocation_handler(
+    ctx: Context<ReportEquivocation>,
+    first: OutputRootEntry,
+    first_signatures: Vec<[u8; 65]>,
+    second: OutputRootEntry,
+    second_signatures: Vec<[u8; 65]>,
+) -> Result<()> {
+    require!(
+        first.base_block_number == second.base_block_number
+            && (first.output_root, first.total_leaf_count)
+                != (second.output_root, second.total_leaf_count),
+        BridgeError::OutputRootsNotConflicting
+    );
+
+    let first_hash = compute_output_root_message_hash(
+        &first.output_root,
+        first.base_block_number,
+        first.total_leaf_count,
+    );
+    let second_hash = compute_output_root_message_hash(
+        &second.output_root,
+        second.base_block_number,
+        second.total_leaf_count,
+    );
+
+    let second_signers = second_signatures
+        .iter()
+        .map(|signature| Ok((recover_eth_address(signature, &second_hash)?, *signature)))
+        .collect::<Result<Vec<_>>>()?;
+
+    let bridge = &mut ctx.accounts.bridge;
+    let evidence = &mut ctx.accounts.evidence;
+    evidence.base_block_number = first.base_block_number;
+    evidence.first_root = first.output_root;
+    evidence.first_total_leaf_count = first.total_leaf_count;
+    evidence.second_root = second.output_root;
+    evidence.second_total_leaf_count = second.total_leaf_count;
+    evidence.reporter = ctx.accounts.reporter.key();
+    evidence.reported_at = Clock::get()?.unix_timestamp;
+
+    // Only active Base oracle signers can be offenders, which bounds them by `MAX_SIGNER_COUNT`
+    for first_signature in &first_signatures {
+        let signer = recover_eth_address(first_signature, &first_hash)?;
+        if !bridge.base_oracle_config.contains(&signer) {
+            continue;
+        }
+
+        if let Some((_, second_signature)) = second_signers.iter().find(|(s, _)| *s == signer) {
+            evidence.add_offender(signer, *first_signature, *second_signature);
+        }
+    }
+    require!(
+        evidence.offender_count > 0,
+        BridgeError::NoEquivocatingSigner
+    );
+
+    // The threshold is clamped to the remaining signers so that the quorum stays reachable
+    let old_cfg = bridge.base_oracle_config.clone();
+    bridge
+        .base_oracle_config
+        .remove_signers(evidence.offenders());
+
+    emit!(OracleEquivocationReported {
+        base_block_number: evidence.base_block_number,
+        offenders: evidence.offenders().to_vec(),
+        reporter: evidence.reporter,
+    });
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.reporter,
+        ConfigParam::BaseOracleConfig,
+        &old_cfg,
+        &bridge.base_oracle_config,
+    )
+}
+
+/// Returns the hash identifying a pair of conflicting output roots, whatever their order:
+/// keccak256 of their sorted oracle message hashes.
+pub fn equivocation_hash(first: &OutputRootEntry, second: &OutputRootEntry) -> [u8; 32] {
+    let mut hashes = [first, second].map(|entry| {
+        compute_output_root_message_hash(
+            &entry.output_root,
+            entry.base_block_number,
+            entry.total_leaf_count,
+        )
+    });
+    hashes.sort();
+    keccak::hashv(&[&hashes[0], &hashes[1]]).0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, keccak::hash as keccak_hash},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use secp256k1::{Message as SecpMessage, Secp256k1, SecretKey};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::MAX_SIGNER_COUNT,
+        instruction::ReportEquivocation as ReportEquivocationIx,
+        test_utils::{config_history_pda, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    const ORACLE_SKS: [[u8; 32]; 3] = [[7u8; 32], [8u8; 32], [9u8; 32]];
+
+    fn evm_address(sk_bytes: [u8; 32]) -> [u8; 20] {
+        let secp = Secp256k1::new();
+        let sk = SecretKey::from_slice(&sk_bytes).unwrap();
+        let pk = secp256k1::PublicKey::from_secret_key(&secp, &sk);
+        let hashed = keccak_hash(&pk.serialize_uncompressed()[1..]);
+        let mut addr = [0u8; 20];
+        addr.copy_from_slice(&hashed.to_bytes()[12..]);
+        addr
+    }
+
+    /// Registers the `ORACLE_SKS` signers as Base oracles with threshold two.
+    fn setup_oracles(svm: &mut LiteSVM, bridge_pda: Pubkey) {
+        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
+        bridge.base_oracle_config.threshold = 2;
+        bridge.base_oracle_config.signer_count = ORACLE_SKS.len() as u8;
+        bridge.base_oracle_config.signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+        for (i, sk) in ORACLE_SKS.iter().enumerate() {
+            bridge.base_oracle_config.signers[i] = evm_address(*sk);
+        }
+        let mut new_data = Vec::new();
+        bridge.try_serialize(&mut new_data).unwrap();
+        bridge_acc.data = new_data;
+        svm.set_account(bridge_pda, bridge_acc).unwrap();
+    }
+
+    fn sign_output_root(sk_bytes: [u8; 32], entry: &OutputRootEntry) -> [u8; 65] {
+        let msg_hash = compute_output_root_message_hash(
+            &entry.output_root,
+            entry.base_block_number,
+            entry.total_leaf_count,
+        );
+
+        let secp = Secp256k1::new();
+        let sk = SecretKey::from_slice(&sk_bytes).unwrap();
+        let msg = SecpMessage::from_digest_slice(&msg_hash).unwrap();
+        let (rec_id, sig_bytes64) = secp.sign_ecdsa_recoverable(&msg, &sk).serialize_compact();
+
+        let mut sig65 = [0u8; 65];
+        sig65[..64].copy_from_slice(&sig_bytes64);
+        sig65[64] = 27 + rec_id.to_i32() as u8;
+        sig65
+    }
+
+    fn evidence_pda(first: &OutputRootEntry, second: &OutputRootEntry) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                EQUIVOCATION_EVIDENCE_SEED,
+                &first.base_block_number.to_le_bytes(),
+                &equivocation_hash(first, second),
+            ],
+            &ID,
+        )
+        .0
+    }
+
+    fn entry(output_root: [u8; 32], total_leaf_count: u64) -> OutputRootEntry {
+        OutputRootEntry {
+            output_root,
+            base_block_number: 600,
+            total_leaf_count,
+        }
+    }
+
+    fn report_equivocation(
+        svm: &mut LiteSVM,
+        reporter: &Keypair,
+        bridge_pda: Pubkey,
+        (first, first_signers): (OutputRootEntry, &[[u8; 32]]),
+        (second, second_signers): (OutputRootEntry, &[[u8; 32]]),
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::ReportEquivocation {
+                reporter: reporter.pubkey(),
+                bridge: bridge_pda,
+                evidence: evidence_pda(&first, &second),
+                config_history: config_history_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: ReportEquivocationIx {
+                first,
+                first_signatures: first_signers
+                    .iter()
+                    .map(|sk| sign_output_root(*sk, &first))
+                    .collect(),
+                second,
+                second_signatures: second_signers
+                    .iter()
+                    .map(|sk| sign_output_root(*sk, &second))
+                    .collect(),
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[reporter],
+            Message::new(&[ix], Some(&reporter.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_report_equivocation_records_evidence_and_disables_offender() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_oracles(&mut svm, bridge_pda);
+
+        let first = entry([1u8; 32], 10);
+        let second = entry([2u8; 32], 10);
+        report_equivocation(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            (first, &[ORACLE_SKS[0], ORACLE_SKS[1]]),
+            (second, &[ORACLE_SKS[1], ORACLE_SKS[2]]),
+        )
+        .expect("report_equivocation should succeed");
+
+        let offender = evm_address(ORACLE_SKS[1]);
+        let account = svm.get_account(&evidence_pda(&first, &second)).unwrap();
+        let evidence = EquivocationEvidence::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(evidence.offenders(), &[offender]);
+        assert_eq!(evidence.first_root, [1u8; 32]);
+        assert_eq!(evidence.second_root, [2u8; 32]);
+        assert_eq!(
+            evidence.first_signatures[0],
+            sign_output_root(ORACLE_SKS[1], &first)
+        );
+        assert_eq!(
+            evidence.second_signatures[0],
+            sign_output_root(ORACLE_SKS[1], &second)
+        );
+        assert_eq!(evidence.reporter, payer.pubkey());
+
+        let account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(bridge.base_oracle_config.signer_count, 2);
+        assert_eq!(bridge.base_oracle_config.threshold, 2);
+        assert!(!bridge.base_oracle_config.contains(&offender));
+        assert!(bridge
+            .base_oracle_config
+            .contains(&evm_address(ORACLE_SKS[0])));
+        assert!(bridge
+            .base_oracle_config
+            .contains(&evm_address(ORACLE_SKS[2])));
+    }
+
+    #[test]
+    fn test_report_equivocation_rejects_identical_roots() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_oracles(&mut svm, bridge_pda);
+
+        let error_string = report_equivocation(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            (entry([1u8; 32], 10), &[ORACLE_SKS[0]]),
+            (entry([1u8; 32], 10), &[ORACLE_SKS[0]]),
+        )
+        .unwrap_err();
+        assert!(
+            error_string.contains("OutputRootsNotConflicting"),
+            "Expected OutputRootsNotConflicting error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_report_equivocation_requires_common_signer() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_oracles(&mut svm, bridge_pda);
+
+        let error_string = report_equivocation(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            (entry([1u8; 32], 10), &[ORACLE_SKS[0]]),
+            (entry([1u8; 32], 11), &[ORACLE_SKS[1]]),
+        )
+        .unwrap_err();
+        assert!(
+            error_string.contains("NoEquivocatingSigner"),
+            "Expected NoEquivocatingSigner error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_report_equivocation_clamps_threshold_to_remaining_signers() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_oracles(&mut svm, bridge_pda);
+
+        report_equivocation(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            (entry([1u8; 32], 10), &[ORACLE_SKS[0], ORACLE_SKS[1]]),
+            (entry([2u8; 32], 10), &[ORACLE_SKS[0], ORACLE_SKS[1]]),
+        )
+        .expect("report_equivocation should succeed");
+
+        let account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(bridge.base_oracle_config.signer_count, 1);
+        assert_eq!(bridge.base_oracle_config.threshold, 1);
+        bridge.base_oracle_config.validate().unwrap();
+    }
+
+    #[test]
+    fn test_report_equivocation_twice_at_same_block() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_oracles(&mut svm, bridge_pda);
+
+        report_equivocation(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            (entry([1u8; 32], 10), &[ORACLE_SKS[0]]),
+            (entry([2u8; 32], 10), &[ORACLE_SKS[0]]),
+        )
+        .expect("first report_equivocation should succeed");
+
+        // Another signer equivocating at the same Base block over other roots is still reported
+        report_equivocation(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            (entry([3u8; 32], 10), &[ORACLE_SKS[1]]),
+            (entry([4u8; 32], 10), &[ORACLE_SKS[1]]),
+        )
+        .expect("second report_equivocation should succeed");
+
+        let account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(bridge.base_oracle_config.signer_count, 1);
+        assert!(bridge
+            .base_oracle_config
+            .contains(&evm_address(ORACLE_SKS[2])));
+    }
+}
@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    common::{
+        AddressBook, WrappedTokenSupplyCap, ADDRESS_BOOK_SEED, DISCRIMINATOR_LEN,
+        WRAPPED_TOKEN_SUPPLY_CAP_SEED,
+    },
+    BridgeError, WrappedTokenMaxSupplyUpdated,
+};
+
+/// Accounts struct for the `set_wrapped_token_max_supply` instruction mirroring on Solana the max
+/// supply of a wrapped token set on Base. It can only be reached through `relay_message`, which
+/// signs with the bridge CPI authority of the message sender.
+#[derive(Accounts)]
+pub struct RelayedSetWrappedTokenMaxSupply<'info> {
+    /// The bridge CPI authority of the Base Bridge, signing when `relay_message` relays a message
+    /// sent by the Base Bridge. Pays for the supply cap account creation.
+    #[account(mut)]
+    pub bridge_cpi_authority: Signer<'info>,
+
+    /// The address book holding the Base Bridge address.
+    #[account(seeds = [ADDRESS_BOOK_SEED], bump)]
+    pub address_book: Account<'info, AddressBook>,
+
+    /// The wrapped token mint whose max supply is set.
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The supply cap account of the wrapped token.
+    /// - Uses PDA with WRAPPED_TOKEN_SUPPLY_CAP_SEED and the mint for deterministic address
+    /// - Created on first use
+    #[account(
+        init_if_needed,
+        payer = bridge_cpi_authority,
+        space = DISCRIMINATOR_LEN + WrappedTokenSupplyCap::INIT_SPACE,
+        seeds = [WRAPPED_TOKEN_SUPPLY_CAP_SEED, mint.key().as_ref()],
+        bump
+    )]
+    pub supply_cap: Account<'info, WrappedTokenSupplyCap>,
+
+    /// System program required for creating the supply cap account.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn set_wrapped_token_max_supply_handler(
+    ctx: Context<RelayedSetWrappedTokenMaxSupply>,
+    max_supply: Option<u64>,
+) -> Result<()> {
+    require_keys_eq!(
+        ctx.accounts.bridge_cpi_authority.key(),
+        ctx.accounts.address_book.bridge_cpi_authority()?,
+        BridgeError::UnauthorizedSupplyCapUpdate
+    );
+
+    ctx.accounts.supply_cap.max_supply = max_supply;
+
+    emit!(WrappedTokenMaxSupplyUpdated {
+        mint: ctx.accounts.mint.key(),
+        max_supply,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message as SvmMessage;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::{constants::BRIDGE_CPI_AUTHORITY_SEED, state::IncomingMessage, Message},
+        common::PartialTokenMetadata,
+        instruction::{
+            RelayMessage as RelayMessageIx, SetWrappedTokenMaxSupply as SetWrappedTokenMaxSupplyIx,
+        },
+        test_utils::{
+            address_book_pda, create_mock_wrapped_mint, mock_output_root, relay_deny_list_pda,
+            set_program_account, setup_bridge, SetupBridgeResult,
+        },
+        BASE_CHAIN_ID, ID,
+    };
+
+    const BASE_BRIDGE: [u8; 20] = [9u8; 20];
+
+    /// Registers the Base Bridge and creates a wrapped mint.
+    fn setup_wrapped_token(svm: &mut LiteSVM) -> Pubkey {
+        set_program_account(
+            svm,
+            address_book_pda(),
+            &AddressBook {
+                bridge: BASE_BRIDGE,
+                relayer_orchestrator: [0u8; 20],
+                token_count: 0,
+                tokens: Default::default(),
+            },
+        );
+
+        let partial_token_metadata = PartialTokenMetadata {
+            name: "Test Token".to_string(),
+            symbol: "TEST".to_string(),
+            remote_token: [1u8; 20],
+            scaler_exponent: 0,
+        };
+        create_mock_wrapped_mint(svm, 0, 6, &partial_token_metadata)
+    }
+
+    fn set_max_supply_ix(
+        bridge_cpi_authority: Pubkey,
+        mint: Pubkey,
+        max_supply: Option<u64>,
+    ) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::RelayedSetWrappedTokenMaxSupply {
+                bridge_cpi_authority,
+                address_book: address_book_pda(),
+                mint,
+                supply_cap: WrappedTokenSupplyCap::pda(&mint),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: SetWrappedTokenMaxSupplyIx { max_supply }.data(),
+        }
+    }
+
+    #[test]
+    fn test_set_wrapped_token_max_supply_via_relay_from_base_bridge() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let mint = setup_wrapped_token(&mut svm);
+
+        // The bridge CPI authority pays for the supply cap account
+        let bridge_cpi_authority =
+            Pubkey::find_program_address(&[BRIDGE_CPI_AUTHORITY_SEED, BASE_BRIDGE.as_ref()], &ID).0;
+        svm.airdrop(&bridge_cpi_authority, LAMPORTS_PER_SOL)
+            .unwrap();
+        let set_ix = set_max_supply_ix(bridge_cpi_authority, mint, Some(5_000));
+
+        let message = Pubkey::new_unique();
+        let output_root = mock_output_root(&mut svm, 0, 0);
+        set_program_account(
+            &mut svm,
+            message,
+            &IncomingMessage {
+                sender: BASE_BRIDGE,
+                remote_chain_id: BASE_CHAIN_ID,
+                message: Message::Call(vec![set_ix.clone().into()]),
+                executed: false,
+                chunk_count: 0,
+                output_root,
+                output_root_revision: 0,
+                prover: payer.pubkey(),
+                nonce: 0,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+
+        let mut accounts = accounts::RelayMessage {
+            message,
+            output_root: mock_output_root(&mut svm, 0, 0),
+            prover: payer.pubkey(),
+            bridge: bridge_pda,
+            relay_deny_list: relay_deny_list_pda(),
+            relay_fee_vault: None,
+            relayer: None,
+            operational_vault: None,
+            bridge_cpi_authority: None,
+            system_program: None,
+        }
+        .to_account_metas(None);
+        accounts.push(AccountMeta::new_readonly(ID, false));
+        accounts.extend(set_ix.accounts.into_iter().map(|account| AccountMeta {
+            is_signer: false,
+            ..account
+        }));
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: RelayMessageIx {}.data(),
+        };
+        let tx = Transaction::new(
+            &[&payer],
+            SvmMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to relay max supply update");
+
+        let account = svm.get_account(&WrappedTokenSupplyCap::pda(&mint)).unwrap();
+        let supply_cap = WrappedTokenSupplyCap::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(supply_cap.max_supply, Some(5_000));
+    }
+
+    #[test]
+    fn test_set_wrapped_token_max_supply_from_other_signer_fails() {
+        let SetupBridgeResult { mut svm, .. } = setup_bridge();
+        let mint = setup_wrapped_token(&mut svm);
+
+        let signer = Keypair::new();
+        svm.airdrop(&signer.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let tx = Transaction::new(
+            &[&signer],
+            SvmMessage::new(
+                &[set_max_supply_ix(signer.pubkey(), mint, None)],
+                Some(&signer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let error = format!("{:?}", svm.send_transaction(tx).unwrap_err());
+        assert!(
+            error.contains("UnauthorizedSupplyCapUpdate"),
+            "Expected UnauthorizedSupplyCapUpdate error, got: {}",
+            error
+        );
+    }
+}
@@ -0,0 +1,272 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+};
+
+use crate::{
+    base_to_solana::{
+        compute_relay_fee_settlement_message_hash, constants::RELAY_FEE_VAULT_SEED,
+        verify_oracle_approvals, RelayFeeVault,
+    },
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    BridgeError, RelayFeesSettled,
+};
+
+/// Accounts struct for the `settle_relay_fees` instruction that deposits the relay fees collected
+/// on Base into the relay fee vault. The amount of every settlement is attested by the oracles;
+/// the Solana payer provides the lamports and funds the vault creation.
+#[derive(Accounts)]
+pub struct SettleRelayFees<'info> {
+    /// Payer depositing the settled lamports. Authorization is enforced via oracle EVM signatures.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The main bridge state account providing the oracle configuration.
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The relay fee vault.
+    /// - Uses PDA with RELAY_FEE_VAULT_SEED for deterministic address
+    /// - Created by the first settlement
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = DISCRIMINATOR_LEN + RelayFeeVault::INIT_SPACE,
+        seeds = [RELAY_FEE_VAULT_SEED],
+        bump
+    )]
+    pub relay_fee_vault: Account<'info, RelayFeeVault>,
+
+    /// Partner `Config` account (PDA with seed "config") owned by partner program.
+    /// Unchecked to avoid Anchor pre-handler owner checks; PDA address is validated in the handler.
+    /// CHECK: This is validated in the handler.
+    pub partner_config: AccountInfo<'info>,
+
+    /// System program required for creating and funding the relay fee vault.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn settle_relay_fees_handler(
+    ctx: Context<SettleRelayFees>,
+    settlement_nonce: u64,
+    amount: u64,
+    signatures: Vec<[u8; 65]>,
+) -> Result<()> {
+    let message_hash = compute_relay_fee_settlement_message_hash(settlement_nonce, amount);
+    verify_oracle_approvals(
+        &ctx.accounts.bridge,
+        &ctx.accounts.partner_config,
+        &signatures,
+        &message_hash,
+    )?;
+
+    let relay_fee_vault = &mut ctx.accounts.relay_fee_vault;
+    require!(
+        relay_fee_vault.settlement_nonce.checked_add(1) == Some(settlement_nonce),
+        BridgeError::InvalidRelayFeeSettlementNonce
+    );
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: relay_fee_vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    relay_fee_vault.settlement_nonce = settlement_nonce;
+    relay_fee_vault.total_settled = relay_fee_vault.total_settled.saturating_add(amount);
+
+    emit!(RelayFeesSettled {
+        settlement_nonce,
+        amount,
+        total_settled: relay_fee_vault.total_settled,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{
+            instruction::Instruction, keccak::hash as keccak_hash, native_token::LAMPORTS_PER_SOL,
+        },
+        InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use secp256k1::{Message as SecpMessage, Secp256k1, SecretKey};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::MAX_SIGNER_COUNT,
+        instruction::SettleRelayFees as SettleRelayFeesIx,
+        test_utils::{relay_fee_vault_pda, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    const ORACLE_SK: [u8; 32] = [7u8; 32];
+
+    /// Registers a single Base oracle with threshold one.
+    fn setup_oracle(svm: &mut LiteSVM, bridge_pda: Pubkey) {
+        let secp = Secp256k1::new();
+        let sk = SecretKey::from_slice(&ORACLE_SK).unwrap();
+        let pk = secp256k1::PublicKey::from_secret_key(&secp, &sk);
+        let hashed = keccak_hash(&pk.serialize_uncompressed()[1..]);
+        let mut addr = [0u8; 20];
+        addr.copy_from_slice(&hashed.to_bytes()[12..]);
+
+        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
+        bridge.base_oracle_config.threshold = 1;
+        bridge.base_oracle_config.signer_count = 1;
+        bridge.base_oracle_config.signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+        bridge.base_oracle_config.signers[0] = addr;
+        let mut new_data = Vec::new();
+        bridge.try_serialize(&mut new_data).unwrap();
+        bridge_acc.data = new_data;
+        svm.set_account(bridge_pda, bridge_acc).unwrap();
+    }
+
+    fn sign_settlement(sk_bytes: [u8; 32], settlement_nonce: u64, amount: u64) -> [u8; 65] {
+        let msg_hash = compute_relay_fee_settlement_message_hash(settlement_nonce, amount);
+
+        let secp = Secp256k1::new();
+        let sk = SecretKey::from_slice(&sk_bytes).unwrap();
+        let msg = SecpMessage::from_digest_slice(&msg_hash).unwrap();
+        let (rec_id, sig_bytes64) = secp.sign_ecdsa_recoverable(&msg, &sk).serialize_compact();
+
+        let mut sig65 = [0u8; 65];
+        sig65[..64].copy_from_slice(&sig_bytes64);
+        sig65[64] = 27 + rec_id.to_i32() as u8;
+        sig65
+    }
+
+    fn settle_relay_fees(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        settlement_nonce: u64,
+        amount: u64,
+        sk_bytes: [u8; 32],
+    ) -> std::result::Result<(), String> {
+        let signatures = vec![sign_settlement(sk_bytes, settlement_nonce, amount)];
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SettleRelayFees {
+                payer: payer.pubkey(),
+                bridge: bridge_pda,
+                relay_fee_vault: relay_fee_vault_pda(),
+                partner_config: Pubkey::new_unique(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: SettleRelayFeesIx {
+                settlement_nonce,
+                amount,
+                signatures,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_settle_relay_fees_success() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_oracle(&mut svm, bridge_pda);
+
+        settle_relay_fees(&mut svm, &payer, bridge_pda, 1, LAMPORTS_PER_SOL, ORACLE_SK)
+            .expect("Failed to settle relay fees");
+        settle_relay_fees(&mut svm, &payer, bridge_pda, 2, LAMPORTS_PER_SOL, ORACLE_SK)
+            .expect("Failed to settle relay fees again");
+
+        let account = svm.get_account(&relay_fee_vault_pda()).unwrap();
+        assert_eq!(
+            account.lamports,
+            svm.minimum_balance_for_rent_exemption(account.data.len()) + 2 * LAMPORTS_PER_SOL
+        );
+        assert_eq!(
+            RelayFeeVault::try_deserialize(&mut &account.data[..]).unwrap(),
+            RelayFeeVault {
+                settlement_nonce: 2,
+                total_settled: 2 * LAMPORTS_PER_SOL,
+                total_paid: 0,
+                total_unpaid: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_settle_relay_fees_rejects_out_of_order_nonce() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_oracle(&mut svm, bridge_pda);
+
+        settle_relay_fees(&mut svm, &payer, bridge_pda, 1, LAMPORTS_PER_SOL, ORACLE_SK)
+            .expect("Failed to settle relay fees");
+
+        // Replaying a settlement is rejected
+        let error_string = settle_relay_fees(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            1,
+            2 * LAMPORTS_PER_SOL,
+            ORACLE_SK,
+        )
+        .unwrap_err();
+        assert!(
+            error_string.contains("InvalidRelayFeeSettlementNonce"),
+            "Expected InvalidRelayFeeSettlementNonce error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_settle_relay_fees_requires_oracle_signature() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_oracle(&mut svm, bridge_pda);
+
+        let error_string =
+            settle_relay_fees(&mut svm, &payer, bridge_pda, 1, LAMPORTS_PER_SOL, [9u8; 32])
+                .unwrap_err();
+        assert!(
+            error_string.contains("InsufficientBaseSignatures"),
+            "Expected InsufficientBaseSignatures error, got: {}",
+            error_string
+        );
+    }
+}
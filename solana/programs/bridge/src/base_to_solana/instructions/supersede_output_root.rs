@@ -0,0 +1,252 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::{
+        compute_supersede_output_root_message_hash, constants::OUTPUT_ROOT_SEED, state::OutputRoot,
+        verify_oracle_approvals,
+    },
+    common::{bridge::Bridge, BRIDGE_SEED},
+    BridgeError, OutputRootSuperseded,
+};
+
+/// Accounts struct for the `supersede_output_root` instruction that replaces a registered output
+/// root within its challenge window, e.g. after Base reorged below it. Authorization is enforced
+/// via oracle EVM signatures over the new root and revision.
+#[derive(Accounts)]
+#[instruction(output_root: [u8; 32], base_block_number: u64)]
+pub struct SupersedeOutputRoot<'info> {
+    /// The output root account being superseded.
+    #[account(
+        mut,
+        seeds = [OUTPUT_ROOT_SEED, &base_block_number.to_le_bytes()],
+        bump
+    )]
+    pub root: Account<'info, OutputRoot>,
+
+    /// The main bridge state account providing the oracle configuration.
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// Partner `Config` account (PDA with seed "config") owned by partner program.
+    /// Unchecked to avoid Anchor pre-handler owner checks; PDA address is validated in the handler.
+    /// CHECK: This is validated in the handler.
+    pub partner_config: AccountInfo<'info>,
+}
+
+pub fn supersede_output_root_handler(
+    ctx: Context<SupersedeOutputRoot>,
+    output_root: [u8; 32],
+    base_block_number: u64,
+    total_leaf_count: u64,
+    signatures: Vec<[u8; 65]>,
+) -> Result<()> {
+    // Not gated by the pause: the bridge is typically paused while a reorg is remediated
+    require!(
+        ctx.accounts
+            .root
+            .is_challengeable(Clock::get()?.unix_timestamp),
+        BridgeError::OutputRootChallengeWindowClosed
+    );
+
+    let revision = ctx.accounts.root.revision + 1;
+    let message_hash = compute_supersede_output_root_message_hash(
+        &output_root,
+        base_block_number,
+        total_leaf_count,
+        revision,
+    );
+    verify_oracle_approvals(
+        &ctx.accounts.bridge,
+        &ctx.accounts.partner_config,
+        &signatures,
+        &message_hash,
+    )?;
+
+    let root = &mut ctx.accounts.root;
+    emit!(OutputRootSuperseded {
+        base_block_number,
+        previous_root: root.root,
+        root: output_root,
+        revision,
+    });
+
+    root.root = output_root;
+    root.total_leaf_count = total_leaf_count;
+    root.revision = revision;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, keccak::hash as keccak_hash},
+        InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use secp256k1::{Message as SecpMessage, Secp256k1, SecretKey};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::constants::OUTPUT_ROOT_CHALLENGE_WINDOW_SECONDS,
+        common::MAX_SIGNER_COUNT,
+        instruction::SupersedeOutputRoot as SupersedeOutputRootIx,
+        test_utils::{
+            mock_clock, mock_output_root, output_root_pda, setup_bridge, SetupBridgeResult,
+        },
+        ID,
+    };
+
+    const ORACLE_SK: [u8; 32] = [7u8; 32];
+
+    /// Registers a single Base oracle with threshold one.
+    fn setup_oracle(svm: &mut LiteSVM, bridge_pda: Pubkey) {
+        let secp = Secp256k1::new();
+        let sk = SecretKey::from_slice(&ORACLE_SK).unwrap();
+        let pk = secp256k1::PublicKey::from_secret_key(&secp, &sk);
+        let hashed = keccak_hash(&pk.serialize_uncompressed()[1..]);
+        let mut addr = [0u8; 20];
+        addr.copy_from_slice(&hashed.to_bytes()[12..]);
+
+        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
+        bridge.base_oracle_config.threshold = 1;
+        bridge.base_oracle_config.signer_count = 1;
+        bridge.base_oracle_config.signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+        bridge.base_oracle_config.signers[0] = addr;
+        let mut new_data = Vec::new();
+        bridge.try_serialize(&mut new_data).unwrap();
+        bridge_acc.data = new_data;
+        svm.set_account(bridge_pda, bridge_acc).unwrap();
+    }
+
+    fn sign_supersede(
+        root: &[u8; 32],
+        base_block_number: u64,
+        total_leaf_count: u64,
+        revision: u32,
+    ) -> [u8; 65] {
+        let msg_hash = compute_supersede_output_root_message_hash(
+            root,
+            base_block_number,
+            total_leaf_count,
+            revision,
+        );
+
+        let secp = Secp256k1::new();
+        let sk = SecretKey::from_slice(&ORACLE_SK).unwrap();
+        let msg = SecpMessage::from_digest_slice(&msg_hash).unwrap();
+        let (rec_id, sig_bytes64) = secp.sign_ecdsa_recoverable(&msg, &sk).serialize_compact();
+
+        let mut sig65 = [0u8; 65];
+        sig65[..64].copy_from_slice(&sig_bytes64);
+        sig65[64] = 27 + rec_id.to_i32() as u8;
+        sig65
+    }
+
+    fn supersede_output_root(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        output_root: [u8; 32],
+        base_block_number: u64,
+        signatures: Vec<[u8; 65]>,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SupersedeOutputRoot {
+                root: output_root_pda(base_block_number),
+                bridge: bridge_pda,
+                partner_config: Pubkey::new_unique(),
+            }
+            .to_account_metas(None),
+            data: SupersedeOutputRootIx {
+                output_root,
+                base_block_number,
+                total_leaf_count: 2,
+                signatures,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_supersede_output_root_bumps_revision() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_oracle(&mut svm, bridge_pda);
+        let root_pda = mock_output_root(&mut svm, 300, 0);
+
+        let signatures = vec![sign_supersede(&[5u8; 32], 300, 2, 1)];
+        supersede_output_root(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            [5u8; 32],
+            300,
+            signatures.clone(),
+        )
+        .expect("Failed to supersede output root");
+
+        let account = svm.get_account(&root_pda).unwrap();
+        let root = OutputRoot::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(root.root, [5u8; 32]);
+        assert_eq!(root.total_leaf_count, 2);
+        assert_eq!(root.revision, 1);
+
+        // Signatures over a previous revision cannot be replayed
+        svm.expire_blockhash();
+        let error = supersede_output_root(&mut svm, &payer, bridge_pda, [5u8; 32], 300, signatures)
+            .unwrap_err();
+        assert!(
+            error.contains("InsufficientBaseSignatures"),
+            "Expected InsufficientBaseSignatures error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_supersede_output_root_after_challenge_window_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_oracle(&mut svm, bridge_pda);
+        let registered_at = svm.get_sysvar::<Clock>().unix_timestamp;
+        mock_output_root(&mut svm, 300, 0);
+        mock_clock(
+            &mut svm,
+            registered_at + OUTPUT_ROOT_CHALLENGE_WINDOW_SECONDS,
+        );
+
+        let signatures = vec![sign_supersede(&[5u8; 32], 300, 2, 1)];
+        let error = supersede_output_root(&mut svm, &payer, bridge_pda, [5u8; 32], 300, signatures)
+            .unwrap_err();
+        assert!(
+            error.contains("OutputRootChallengeWindowClosed"),
+            "Expected OutputRootChallengeWindowClosed error, got: {}",
+            error
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::token::create_marker,
+    common::{
+        wrapped_token_metadata, AddressBook, WrappedTokenConfirmation, ADDRESS_BOOK_SEED,
+        DISCRIMINATOR_LEN, WRAPPED_TOKEN_CONFIRMATION_SEED,
+    },
+    BridgeError, WrappedTokenConfirmed, ID,
+};
+
+/// Validates a `ConfirmWrappedToken` message and records the `WrappedTokenConfirmation` of
+/// `mint`. Confirming an already confirmed mint is a no-op, so that a duplicate confirmation
+/// cannot leave its message unrelayable.
+///
+/// The `name`, `symbol` and `decimals` echoed by the Base Bridge must match the metadata of the
+/// wrapped mint, whose decimals plus scaler exponent must equal the decimals of the Base token.
+///
+/// Expects the accounts `[address_book, wrapped_token_confirmation, mint, payer, system_program, ..]`.
+pub fn record_wrapped_token_confirmation<'info>(
+    sender: &[u8; 20],
+    mint: &Pubkey,
+    name: &str,
+    symbol: &str,
+    decimals: u8,
+    message: &Pubkey,
+    account_infos: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    // Read the accounts in the expected order
+    let mut iter = account_infos.iter();
+    let address_book_info = next_account_info(&mut iter)?;
+    let confirmation_info = next_account_info(&mut iter)?;
+    let mint_info = next_account_info(&mut iter)?;
+    let payer_info = next_account_info(&mut iter)?;
+    let system_program_info = Program::<System>::try_from(next_account_info(&mut iter)?)?;
+
+    // Only the Base Bridge itself can confirm wrapped token registrations
+    let (address_book_pda, _) = Pubkey::find_program_address(&[ADDRESS_BOOK_SEED], &ID);
+    require_keys_eq!(
+        address_book_info.key(),
+        address_book_pda,
+        BridgeError::IncorrectAddressBook
+    );
+    let address_book = Account::<AddressBook>::try_from(address_book_info)?;
+    require!(
+        *sender == address_book.bridge()?,
+        BridgeError::UnauthorizedWrappedTokenConfirmation
+    );
+
+    if WrappedTokenConfirmation::is_confirmed(confirmation_info, mint)? {
+        return Ok(());
+    }
+
+    // The Base token must match the metadata the wrapped mint was created with
+    require_keys_eq!(
+        mint_info.key(),
+        *mint,
+        BridgeError::MintDoesNotMatchLocalToken
+    );
+    let (metadata, mint_decimals) = wrapped_token_metadata(mint_info)?;
+    require!(
+        metadata.name == name
+            && metadata.symbol == symbol
+            && mint_decimals.checked_add(metadata.scaler_exponent) == Some(decimals),
+        BridgeError::WrappedTokenMetadataMismatch
+    );
+
+    let (_, bump) =
+        Pubkey::find_program_address(&[WRAPPED_TOKEN_CONFIRMATION_SEED, mint.as_ref()], &ID);
+    create_marker(
+        confirmation_info,
+        payer_info,
+        &system_program_info,
+        &[WRAPPED_TOKEN_CONFIRMATION_SEED, mint.as_ref(), &[bump]],
+        DISCRIMINATOR_LEN + WrappedTokenConfirmation::INIT_SPACE,
+    )?;
+
+    let mut data = confirmation_info.try_borrow_mut_data()?;
+    WrappedTokenConfirmation {
+        mint: *mint,
+        confirmed_at: Clock::get()?.unix_timestamp,
+    }
+    .try_serialize(&mut &mut data[..])?;
+
+    emit!(WrappedTokenConfirmed {
+        mint: *mint,
+        message: *message,
+    });
+
+    Ok(())
+}
@@ -3,6 +3,7 @@ use anchor_lang::{
     system_program::{self, Transfer},
 };
 
+use super::release_token_deposits;
 use crate::BridgeError;
 use crate::{common::SOL_VAULT_SEED, ID};
 
@@ -29,6 +30,7 @@ impl FinalizeBridgeSol {
         let sol_vault_info = next_account_info(&mut iter)?;
         let to_info = next_account_info(&mut iter)?;
         let system_program_info = Program::<System>::try_from(next_account_info(&mut iter)?)?;
+        let token_deposits_info = next_account_info(&mut iter)?;
 
         // Verify the recipient matches the instruction data
         require_keys_eq!(to_info.key(), self.to, BridgeError::IncorrectTo);
@@ -43,6 +45,9 @@ impl FinalizeBridgeSol {
             BridgeError::IncorrectSolVault
         );
 
+        // Release the amount from the SOL vault accounting
+        release_token_deposits(token_deposits_info, &sol_vault_pda, self.amount)?;
+
         // Transfer SOL from the SOL vault to the recipient
         let seeds: &[&[&[u8]]] = &[&[SOL_VAULT_SEED, &[sol_vault_bump]]];
         let cpi_ctx = CpiContext::new_with_signer(
@@ -1,8 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 
+use super::release_token_deposits;
 use crate::BridgeError;
-use crate::{common::TOKEN_VAULT_SEED, ID};
+use crate::{
+    common::{internal::scaling::to_local_spl_amount, VaultDecimals, TOKEN_VAULT_SEED},
+    ID,
+};
 
 /// Instruction data for finalizing a bridged SPL token transfer from Base to Solana.
 ///
@@ -24,8 +28,9 @@ pub struct FinalizeBridgeSpl {
     /// enforces the mint match.
     pub to: Pubkey,
 
-    /// The amount to transfer, in base units of the mint (respecting mint decimals).
-    /// `transfer_checked` enforces that the destination account's mint matches and
+    /// The amount to transfer, in the smallest unit of the remote token. It is scaled up to the
+    /// mint decimals when the vault decimals are configured, and is in base units of the mint
+    /// otherwise. `transfer_checked` enforces that the destination account's mint matches and
     /// the decimals are correct.
     pub amount: u64,
 }
@@ -40,6 +45,8 @@ impl FinalizeBridgeSpl {
         let to_token_account =
             InterfaceAccount::<TokenAccount>::try_from(next_account_info(&mut iter)?)?;
         let token_program = Interface::<TokenInterface>::try_from(next_account_info(&mut iter)?)?;
+        let token_deposits_info = next_account_info(&mut iter)?;
+        let vault_decimals_info = next_account_info(&mut iter)?;
 
         // Check that the mint is correct given the local token
         require_keys_eq!(
@@ -71,6 +78,14 @@ impl FinalizeBridgeSpl {
             BridgeError::IncorrectTokenVault
         );
 
+        // Scale the amount back to the mint decimals
+        let scaler_exponent =
+            VaultDecimals::load_scaler_exponent(vault_decimals_info, &token_vault_pda)?;
+        let amount = to_local_spl_amount(self.amount, scaler_exponent)?;
+
+        // Release the amount from the token vault accounting
+        release_token_deposits(token_deposits_info, &token_vault_pda, amount)?;
+
         let seeds: &[&[&[u8]]] = &[&[
             TOKEN_VAULT_SEED,
             mint_key.as_ref(),
@@ -89,7 +104,7 @@ impl FinalizeBridgeSpl {
             },
             seeds,
         );
-        token_interface::transfer_checked(cpi_ctx, self.amount, mint.decimals)?;
+        token_interface::transfer_checked(cpi_ctx, amount, mint.decimals)?;
 
         Ok(())
     }
@@ -1,14 +1,10 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
-    token_2022::{MintToChecked, Token2022},
-    token_interface::{self, Mint, TokenAccount},
+    token_2022::Token2022,
+    token_interface::{Mint, TokenAccount},
 };
 
-use crate::BridgeError;
-use crate::{
-    common::{PartialTokenMetadata, WRAPPED_TOKEN_SEED},
-    ID,
-};
+use crate::{common::mint_wrapped_tokens, BridgeError};
 
 /// Instruction data for finalizing a wrapped token transfer from Base to Solana.
 ///
@@ -47,6 +43,7 @@ impl FinalizeBridgeWrappedToken {
         let to_token_account =
             InterfaceAccount::<TokenAccount>::try_from(next_account_info(&mut iter)?)?;
         let token_program_2022 = Program::<Token2022>::try_from(next_account_info(&mut iter)?)?;
+        let supply_cap = next_account_info(&mut iter)?;
 
         // Check that the mint is correct given the local token
         require_keys_eq!(
@@ -62,38 +59,13 @@ impl FinalizeBridgeWrappedToken {
             BridgeError::TokenAccountDoesNotMatchTo,
         );
 
-        // Get the partial token metadata
-        let partial_token_metadata = PartialTokenMetadata::try_from(&mint.to_account_info())?;
-
-        // Derive the seeds for the wrapped token mint
-        let decimals_bytes = mint.decimals.to_le_bytes();
-        let metadata_hash = partial_token_metadata.hash();
-        let seeds: &[&[u8]] = &[
-            WRAPPED_TOKEN_SEED,
-            decimals_bytes.as_ref(),
-            metadata_hash.as_ref(),
-        ];
-        let (_, mint_bump) = Pubkey::find_program_address(seeds, &ID);
-
-        let seeds: &[&[&[u8]]] = &[&[
-            WRAPPED_TOKEN_SEED,
-            decimals_bytes.as_ref(),
-            metadata_hash.as_ref(),
-            &[mint_bump],
-        ]];
-
         // Mint the wrapped token to the recipient
-        let cpi_ctx = CpiContext::new_with_signer(
-            token_program_2022.to_account_info(),
-            MintToChecked {
-                mint: mint.to_account_info(),
-                to: to_token_account.to_account_info(),
-                authority: mint.to_account_info(),
-            },
-            seeds,
-        );
-        token_interface::mint_to_checked(cpi_ctx, self.amount, mint.decimals)?;
-
-        Ok(())
+        mint_wrapped_tokens(
+            &mint,
+            &to_token_account,
+            &token_program_2022,
+            supply_cap,
+            self.amount,
+        )
     }
 }
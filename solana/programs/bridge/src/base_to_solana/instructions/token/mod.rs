@@ -1,7 +1,106 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Allocate, Assign, CreateAccount, Transfer},
+};
+
+use crate::{
+    common::{TokenDeposits, TOKEN_DEPOSITS_SEED},
+    BridgeError, ID,
+};
+
+pub mod confirm_wrapped_token;
 pub mod finalize_sol_transfer;
 pub mod finalize_spl_transfer;
 pub mod finalize_wrapped_token_transfer;
+pub mod return_transfer;
 
+pub use confirm_wrapped_token::*;
 pub use finalize_sol_transfer::*;
 pub use finalize_spl_transfer::*;
 pub use finalize_wrapped_token_transfer::*;
+pub use return_transfer::*;
+
+/// Decrements the `TokenDeposits` accounting of `vault` by `amount`.
+///
+/// Saturates at zero since vaults may hold deposits made before the accounting existed.
+pub fn release_token_deposits<'info>(
+    token_deposits_info: &'info AccountInfo<'info>,
+    vault: &Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let (token_deposits_pda, _) =
+        Pubkey::find_program_address(&[TOKEN_DEPOSITS_SEED, vault.as_ref()], &ID);
+    require_keys_eq!(
+        token_deposits_info.key(),
+        token_deposits_pda,
+        BridgeError::IncorrectTokenDeposits
+    );
+
+    let mut token_deposits = Account::<TokenDeposits>::try_from(token_deposits_info)?;
+    token_deposits.locked_amount = token_deposits.locked_amount.saturating_sub(amount);
+    token_deposits.exit(&ID)
+}
+
+/// Creates a marker PDA of `space` bytes owned by this program, tolerating lamports sent to its
+/// address beforehand.
+pub fn create_marker<'info>(
+    marker_info: &AccountInfo<'info>,
+    payer_info: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    seeds: &[&[u8]],
+    space: usize,
+) -> Result<()> {
+    let rent = Rent::get()?.minimum_balance(space);
+    let current_lamports = marker_info.lamports();
+
+    if current_lamports == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                system_program.to_account_info(),
+                CreateAccount {
+                    from: payer_info.to_account_info(),
+                    to: marker_info.to_account_info(),
+                },
+                &[seeds],
+            ),
+            rent,
+            space as u64,
+            &ID,
+        );
+    }
+
+    if rent > current_lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                Transfer {
+                    from: payer_info.to_account_info(),
+                    to: marker_info.to_account_info(),
+                },
+            ),
+            rent - current_lamports,
+        )?;
+    }
+
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Allocate {
+                account_to_allocate: marker_info.to_account_info(),
+            },
+            &[seeds],
+        ),
+        space as u64,
+    )?;
+
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            Assign {
+                account_to_assign: marker_info.to_account_info(),
+            },
+            &[seeds],
+        ),
+        &ID,
+    )
+}
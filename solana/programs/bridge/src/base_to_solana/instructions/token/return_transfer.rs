@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::{constants::RETURNED_TRANSFER_SEED, token::create_marker, ReturnedTransfer},
+    common::{AddressBook, ADDRESS_BOOK_SEED, DISCRIMINATOR_LEN},
+    BridgeError, ID,
+};
+
+/// Validates a `ReturnTransfer` message and records its `ReturnedTransfer` marker so that the
+/// transfer of outgoing message `outgoing_nonce` can only be returned once.
+///
+/// Expects the accounts `[address_book, returned_transfer, payer, system_program, ..]` and
+/// returns the remaining accounts, used to finalize the returned transfer.
+pub fn record_returned_transfer<'info>(
+    sender: &[u8; 20],
+    outgoing_nonce: u64,
+    account_infos: &'info [AccountInfo<'info>],
+) -> Result<&'info [AccountInfo<'info>]> {
+    // Read the accounts in the expected order
+    let mut iter = account_infos.iter();
+    let address_book_info = next_account_info(&mut iter)?;
+    let returned_transfer_info = next_account_info(&mut iter)?;
+    let payer_info = next_account_info(&mut iter)?;
+    let system_program_info = Program::<System>::try_from(next_account_info(&mut iter)?)?;
+
+    // Only the Base Bridge itself can return transfers
+    let (address_book_pda, _) = Pubkey::find_program_address(&[ADDRESS_BOOK_SEED], &ID);
+    require_keys_eq!(
+        address_book_info.key(),
+        address_book_pda,
+        BridgeError::IncorrectAddressBook
+    );
+    let address_book = Account::<AddressBook>::try_from(address_book_info)?;
+    require!(
+        *sender == address_book.bridge()?,
+        BridgeError::UnauthorizedReturnTransfer
+    );
+
+    // Verify the returned transfer PDA is correct and not already recorded
+    let nonce_bytes = outgoing_nonce.to_le_bytes();
+    let (returned_transfer_pda, bump) =
+        Pubkey::find_program_address(&[RETURNED_TRANSFER_SEED, &nonce_bytes], &ID);
+    require_keys_eq!(
+        returned_transfer_info.key(),
+        returned_transfer_pda,
+        BridgeError::IncorrectReturnedTransfer
+    );
+    require_keys_neq!(
+        *returned_transfer_info.owner,
+        ID,
+        BridgeError::TransferAlreadyReturned
+    );
+
+    create_marker(
+        returned_transfer_info,
+        payer_info,
+        &system_program_info,
+        &[RETURNED_TRANSFER_SEED, &nonce_bytes, &[bump]],
+        DISCRIMINATOR_LEN + ReturnedTransfer::INIT_SPACE,
+    )?;
+
+    let mut data = returned_transfer_info.try_borrow_mut_data()?;
+    ReturnedTransfer { outgoing_nonce }.try_serialize(&mut &mut data[..])?;
+
+    Ok(iter.as_slice())
+}
@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::{constants::INCOMING_MESSAGE_SEED, state::IncomingMessage},
+    common::{bridge::Bridge, BRIDGE_SEED},
+    BridgeError, MessageVetoed,
+};
+
+/// Accounts struct for the `veto_message` instruction that lets the guardian veto a high-value
+/// transfer held by the finalization delay, e.g. one proven against a fraudulent output root.
+#[derive(Accounts)]
+#[instruction(message_hash: [u8; 32])]
+pub struct VetoMessage<'info> {
+    /// The guardian account authorized to veto messages
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian and the finalization delay
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The incoming message being vetoed.
+    #[account(
+        mut,
+        seeds = [INCOMING_MESSAGE_SEED, &message_hash],
+        bump,
+        constraint = !message.executed @ BridgeError::AlreadyExecuted,
+    )]
+    pub message: Account<'info, IncomingMessage>,
+}
+
+pub fn veto_message_handler(ctx: Context<VetoMessage>, message_hash: [u8; 32]) -> Result<()> {
+    let message = &mut ctx.accounts.message;
+    require!(
+        Clock::get()?.unix_timestamp
+            < message.finalizable_at(&ctx.accounts.bridge.finalization_delay),
+        BridgeError::MessageNotInFinalizationDelay
+    );
+
+    message.vetoed = true;
+
+    emit!(MessageVetoed {
+        message_hash,
+        guardian: ctx.accounts.guardian.key(),
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message as SvmMessage;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::{token::FinalizeBridgeSol, Message, Transfer},
+        common::bridge::FinalizationThreshold,
+        instruction::{RelayMessage as RelayMessageIx, VetoMessage as VetoMessageIx},
+        solana_to_base::NATIVE_SOL_PUBKEY,
+        test_utils::{
+            mock_clock, mock_output_root, relay_deny_list_pda, set_program_account, setup_bridge,
+            SetupBridgeResult,
+        },
+        BASE_CHAIN_ID, ID,
+    };
+
+    /// Delays SOL transfers above one SOL by 30 minutes.
+    fn setup_finalization_delay(svm: &mut LiteSVM, bridge_pda: Pubkey) {
+        let account = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &account.data[..]).unwrap();
+        bridge
+            .finalization_delay
+            .set(
+                1_800,
+                &[FinalizationThreshold {
+                    local_token: NATIVE_SOL_PUBKEY,
+                    threshold: LAMPORTS_PER_SOL,
+                }],
+            )
+            .unwrap();
+        set_program_account(svm, bridge_pda, &bridge);
+    }
+
+    /// Writes a SOL transfer of `amount` proven at 0 by `prover`, returning its hash and address.
+    fn mock_transfer(svm: &mut LiteSVM, prover: Pubkey, amount: u64) -> ([u8; 32], Pubkey) {
+        let message_hash = [5u8; 32];
+        let message = Pubkey::find_program_address(&[INCOMING_MESSAGE_SEED, &message_hash], &ID).0;
+        let output_root = mock_output_root(svm, 0, 0);
+        set_program_account(
+            svm,
+            message,
+            &IncomingMessage {
+                sender: [7u8; 20],
+                remote_chain_id: BASE_CHAIN_ID,
+                message: Message::Transfer {
+                    transfer: Transfer::Sol(FinalizeBridgeSol {
+                        to: Pubkey::new_unique(),
+                        amount,
+                    }),
+                    ixs: vec![],
+                },
+                executed: false,
+                chunk_count: 0,
+                output_root,
+                output_root_revision: 0,
+                prover,
+                nonce: 0,
+                proven_at: 0,
+                vetoed: false,
+                outflow_held: false,
+                outflow_approved: false,
+            },
+        );
+        (message_hash, message)
+    }
+
+    fn veto_message(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        message_hash: [u8; 32],
+        message: Pubkey,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::VetoMessage {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                message,
+            }
+            .to_account_metas(None),
+            data: VetoMessageIx { message_hash }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            SvmMessage::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_veto_message_blocks_relay() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_finalization_delay(&mut svm, bridge_pda);
+        let (message_hash, message) = mock_transfer(&mut svm, payer.pubkey(), 2 * LAMPORTS_PER_SOL);
+
+        mock_clock(&mut svm, 1_799);
+        veto_message(&mut svm, &guardian, bridge_pda, message_hash, message)
+            .expect("Failed to veto message");
+
+        let account = svm.get_account(&message).unwrap();
+        let incoming_message = IncomingMessage::try_deserialize(&mut &account.data[..]).unwrap();
+        assert!(incoming_message.vetoed);
+
+        // The message stays unrelayable once the delay elapsed
+        mock_clock(&mut svm, 1_800);
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::RelayMessage {
+                message,
+                output_root: incoming_message.output_root,
+                prover: payer.pubkey(),
+                bridge: bridge_pda,
+                relay_deny_list: relay_deny_list_pda(),
+                relay_fee_vault: None,
+                relayer: None,
+                operational_vault: None,
+                bridge_cpi_authority: None,
+                system_program: None,
+            }
+            .to_account_metas(None),
+            data: RelayMessageIx {}.data(),
+        };
+        let tx = Transaction::new(
+            &[&payer],
+            SvmMessage::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let error = format!("{:?}", svm.send_transaction(tx).unwrap_err());
+        assert!(
+            error.contains("MessageVetoed"),
+            "Expected MessageVetoed error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_veto_message_below_threshold_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_finalization_delay(&mut svm, bridge_pda);
+        let (message_hash, message) = mock_transfer(&mut svm, payer.pubkey(), LAMPORTS_PER_SOL);
+
+        let error =
+            veto_message(&mut svm, &guardian, bridge_pda, message_hash, message).unwrap_err();
+        assert!(
+            error.contains("MessageNotInFinalizationDelay"),
+            "Expected MessageNotInFinalizationDelay error, got: {}",
+            error
+        );
+    }
+}
@@ -0,0 +1,9 @@
+use anchor_lang::prelude::*;
+
+use crate::{base_to_solana::constants::BRIDGE_CPI_AUTHORITY_SEED, ID};
+
+/// Returns the bridge CPI authority of the Base `sender`, i.e. the PDA signing the instructions
+/// relayed from its messages, and its bump.
+pub fn bridge_cpi_authority(sender: &[u8; 20]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BRIDGE_CPI_AUTHORITY_SEED, sender.as_ref()], &ID)
+}
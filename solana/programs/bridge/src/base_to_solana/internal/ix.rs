@@ -1,4 +1,15 @@
-use anchor_lang::{prelude::*, solana_program::instruction::Instruction};
+use anchor_lang::{
+    prelude::*,
+    solana_program::{instruction::Instruction, sysvar},
+};
+
+use crate::{
+    base_to_solana::constants::{MAX_IX_ACCOUNTS, MAX_MESSAGE_IXS, MAX_MESSAGE_IXS_DATA_LEN},
+    BridgeError,
+};
+
+/// Owner of the builtin programs, which is not itself an executable program.
+const NATIVE_LOADER_ID: Pubkey = pubkey!("NativeLoader1111111111111111111111111111111");
 
 /// Instruction to be executed by the bridge program via signed CPI during message relay.
 /// Functionally equivalent to a Solana `Instruction`, but serialized with Anchor for cross-program messaging.
@@ -24,6 +35,36 @@ pub struct IxAccount {
     pub is_signer: bool,
 }
 
+/// Validates the structural limits of the instructions of an incoming message, so that payloads that
+/// could never be relayed are rejected when proven rather than when relayed.
+pub fn validate_ixs(ixs: &[Ix]) -> Result<()> {
+    require!(
+        ixs.len() <= MAX_MESSAGE_IXS as usize,
+        BridgeError::TooManyMessageIxs
+    );
+
+    let mut data_len = 0;
+    for ix in ixs {
+        // Neither the native loader nor the sysvar owner are executable programs.
+        require!(
+            ix.program_id != NATIVE_LOADER_ID && ix.program_id != sysvar::ID,
+            BridgeError::InvalidIxProgramId
+        );
+        require!(
+            ix.accounts.len() <= MAX_IX_ACCOUNTS as usize,
+            BridgeError::TooManyIxAccounts
+        );
+        data_len += ix.data.len();
+    }
+
+    require!(
+        data_len <= MAX_MESSAGE_IXS_DATA_LEN as usize,
+        BridgeError::MessageIxsDataTooLong
+    );
+
+    Ok(())
+}
+
 /// Converts an Ix to a Solana Instruction.
 impl From<Ix> for Instruction {
     fn from(ix: Ix) -> Instruction {
@@ -68,3 +109,68 @@ impl From<AccountMeta> for IxAccount {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ix(accounts: usize, data_len: usize) -> Ix {
+        Ix {
+            program_id: Pubkey::new_unique(),
+            accounts: (0..accounts)
+                .map(|_| IxAccount {
+                    pubkey: Pubkey::new_unique(),
+                    is_writable: false,
+                    is_signer: false,
+                })
+                .collect(),
+            data: vec![0u8; data_len],
+        }
+    }
+
+    #[test]
+    fn test_validate_ixs_at_limits() {
+        let mut ixs = vec![ix(MAX_IX_ACCOUNTS as usize, 0); MAX_MESSAGE_IXS as usize];
+        ixs[0].data = vec![0u8; MAX_MESSAGE_IXS_DATA_LEN as usize];
+
+        assert!(validate_ixs(&ixs).is_ok());
+    }
+
+    #[test]
+    fn test_validate_ixs_too_many_ixs() {
+        let ixs = vec![ix(1, 1); MAX_MESSAGE_IXS as usize + 1];
+
+        let err = validate_ixs(&ixs).unwrap_err();
+        assert_eq!(err, BridgeError::TooManyMessageIxs.into());
+    }
+
+    #[test]
+    fn test_validate_ixs_too_many_accounts() {
+        let ixs = vec![ix(1, 1), ix(MAX_IX_ACCOUNTS as usize + 1, 1)];
+
+        let err = validate_ixs(&ixs).unwrap_err();
+        assert_eq!(err, BridgeError::TooManyIxAccounts.into());
+    }
+
+    #[test]
+    fn test_validate_ixs_data_too_long() {
+        let half = MAX_MESSAGE_IXS_DATA_LEN as usize / 2;
+        let ixs = vec![ix(1, half), ix(1, half + 1)];
+
+        let err = validate_ixs(&ixs).unwrap_err();
+        assert_eq!(err, BridgeError::MessageIxsDataTooLong.into());
+    }
+
+    #[test]
+    fn test_validate_ixs_invalid_program_id() {
+        for program_id in [NATIVE_LOADER_ID, sysvar::ID] {
+            let ixs = vec![Ix {
+                program_id,
+                ..ix(1, 1)
+            }];
+
+            let err = validate_ixs(&ixs).unwrap_err();
+            assert_eq!(err, BridgeError::InvalidIxProgramId.into());
+        }
+    }
+}
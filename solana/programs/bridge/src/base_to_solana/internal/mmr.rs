@@ -42,6 +42,27 @@ pub fn verify_proof(
     Ok(())
 }
 
+/// Verifies a plain Merkle proof (e.g. OpenZeppelin `MerkleProof`), hashing each level with
+/// the same commutative pair hash as intra-mountain MMR paths.
+///
+/// Arguments:
+/// - `expected_root`: The expected Merkle root.
+/// - `leaf_hash`: The hash of the leaf being verified.
+/// - `proof`: The sibling hashes along the path from the leaf to the root (bottom-up).
+pub fn verify_merkle_proof(
+    expected_root: &[u8; 32],
+    leaf_hash: &[u8; 32],
+    proof: &[[u8; 32]],
+) -> Result<()> {
+    let calculated_root = proof.iter().fold(*leaf_hash, |hash, sibling| {
+        commutative_keccak256(hash, *sibling)
+    });
+
+    require!(calculated_root == *expected_root, BridgeError::InvalidProof);
+
+    Ok(())
+}
+
 /// Calculates the MMR root given a leaf, its proof, and the MMR structure.
 ///
 /// This function reconstructs the peaks of the MMR based on the provided leaf and its proof,
@@ -156,7 +177,7 @@ fn calculate_root_from_proof(
 // Commutative Keccak256 of a pair of bytes32 by sorting the inputs first
 // and hashing their concatenation. Used for intra-mountain Merkle paths
 // where left/right orientation is not required.
-fn commutative_keccak256(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+pub fn commutative_keccak256(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
     if a < b {
         efficient_keccak256(&a, &b)
     } else {
@@ -166,7 +187,7 @@ fn commutative_keccak256(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
 
 // Ordered (non-commutative) Keccak256: left || right
 // Used for bagging peaks to bind the order/position of mountains.
-fn ordered_keccak256(left: [u8; 32], right: [u8; 32]) -> [u8; 32] {
+pub fn ordered_keccak256(left: [u8; 32], right: [u8; 32]) -> [u8; 32] {
     efficient_keccak256(&left, &right)
 }
 
@@ -177,3 +198,220 @@ fn efficient_keccak256(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
     data_to_hash.extend_from_slice(b);
     keccak::hash(&data_to_hash).to_bytes()
 }
+
+/// Reference MMR builder and proof verification harness shared by the unit tests and the
+/// `mmr_verify_proof` fuzz target (`programs/bridge/fuzz`).
+#[cfg(any(test, feature = "fuzzing"))]
+pub mod fuzzing {
+    use super::*;
+
+    /// Upper bound on the number of leaves of the MMRs built from fuzz inputs.
+    pub const MAX_FUZZ_LEAF_COUNT: u16 = 1024;
+
+    /// Builds the MMR over `leaves` and returns its root along with the proof of the leaf at
+    /// `leaf_index`, laid out as expected by `verify_proof`.
+    pub fn build_proof(leaves: &[[u8; 32]], leaf_index: usize) -> ([u8; 32], Vec<[u8; 32]>) {
+        let mut peaks = Vec::new();
+        let mut proof = Vec::new();
+        let mut other_peaks = Vec::new();
+
+        let mut offset = 0;
+        for h in (0..usize::BITS).rev() {
+            let size = 1usize << h;
+            if leaves.len() & size == 0 {
+                continue;
+            }
+
+            // Climb the mountain level by level, collecting the siblings of the proven leaf
+            let mut level = leaves[offset..offset + size].to_vec();
+            let contains_leaf = (offset..offset + size).contains(&leaf_index);
+            let mut idx = leaf_index.wrapping_sub(offset);
+            while level.len() > 1 {
+                if contains_leaf {
+                    proof.push(level[idx ^ 1]);
+                    idx /= 2;
+                }
+                level = level
+                    .chunks(2)
+                    .map(|pair| commutative_keccak256(pair[0], pair[1]))
+                    .collect();
+            }
+
+            if !contains_leaf {
+                other_peaks.push(level[0]);
+            }
+            peaks.push(level[0]);
+            offset += size;
+        }
+
+        let root = peaks
+            .iter()
+            .skip(1)
+            .fold(peaks[0], |root, peak| ordered_keccak256(root, *peak));
+        proof.extend(other_peaks);
+
+        (root, proof)
+    }
+
+    /// Reads fuzz input bytes, yielding zeros once the input is exhausted.
+    struct Input<'a>(&'a [u8]);
+
+    impl Input<'_> {
+        fn u8(&mut self) -> u8 {
+            match self.0.split_first() {
+                Some((byte, rest)) => {
+                    self.0 = rest;
+                    *byte
+                }
+                None => 0,
+            }
+        }
+
+        fn u16(&mut self) -> u16 {
+            u16::from_le_bytes([self.u8(), self.u8()])
+        }
+
+        fn u64(&mut self) -> u64 {
+            u64::from_le_bytes(core::array::from_fn(|_| self.u8()))
+        }
+
+        fn bytes32(&mut self) -> [u8; 32] {
+            core::array::from_fn(|_| self.u8())
+        }
+    }
+
+    /// Decodes an MMR, a proven leaf and a corruption from `data`, then asserts that:
+    /// - the genuine proof verifies,
+    /// - the corrupted proof (flipped bit, dropped, inserted or swapped element) is rejected,
+    /// - verifying arbitrary proofs, leaf indices and leaf counts never panics.
+    pub fn check_proof_verification(data: &[u8]) {
+        let mut input = Input(data);
+
+        let leaf_count = (input.u16() % MAX_FUZZ_LEAF_COUNT) as u64 + 1;
+        let leaf_index = input.u16() as u64 % leaf_count;
+        let salt = input.u8();
+        let leaves: Vec<[u8; 32]> = (0..leaf_count)
+            .map(|i| keccak::hashv(&[&[salt], &i.to_le_bytes()]).to_bytes())
+            .collect();
+        let leaf = leaves[leaf_index as usize];
+        let (root, proof) = build_proof(&leaves, leaf_index as usize);
+
+        assert!(
+            verify_proof(&root, &leaf, &leaf_index, &proof, leaf_count).is_ok(),
+            "Valid proof rejected: leaf {} of {}",
+            leaf_index,
+            leaf_count
+        );
+
+        let (mut bad_root, mut bad_leaf, mut bad_proof) = (root, leaf, proof.clone());
+        let (position, bit) = (input.u16() as usize, input.u8());
+        let flip = |hash: &mut [u8; 32]| hash[(bit / 8) as usize % 32] ^= 1 << (bit % 8);
+        match input.u8() % 6 {
+            0 => flip(&mut bad_leaf),
+            1 if !proof.is_empty() => flip(&mut bad_proof[position % proof.len()]),
+            2 if !proof.is_empty() => {
+                bad_proof.remove(position % proof.len());
+            }
+            3 => bad_proof.insert(position % (proof.len() + 1), input.bytes32()),
+            4 if proof.len() > 1 => {
+                let other = (position + 1 + input.u16() as usize % (proof.len() - 1)) % proof.len();
+                bad_proof.swap(position % proof.len(), other);
+            }
+            _ => flip(&mut bad_root),
+        }
+
+        assert!(
+            verify_proof(&bad_root, &bad_leaf, &leaf_index, &bad_proof, leaf_count).is_err(),
+            "Corrupted proof accepted: leaf {} of {}",
+            leaf_index,
+            leaf_count
+        );
+
+        // Arbitrary inputs may only fail, never panic
+        let garbage_index = input.u64();
+        let garbage_count = input.u64();
+        let garbage_proof: Vec<[u8; 32]> = (0..input.u8() % 80).map(|_| input.bytes32()).collect();
+        let _ = verify_proof(&root, &leaf, &garbage_index, &garbage_proof, garbage_count);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fuzzing::*, *};
+
+    use proptest::{
+        collection::vec,
+        prelude::any,
+        test_runner::{Config, RngAlgorithm, TestRng, TestRunner},
+    };
+
+    /// Fixed seed so that the property test is reproducible and never draws OS entropy.
+    const SEED: [u8; 32] = [42u8; 32];
+
+    fn leaves(count: usize) -> Vec<[u8; 32]> {
+        (0..count as u64)
+            .map(|i| keccak::hash(&i.to_le_bytes()).to_bytes())
+            .collect()
+    }
+
+    #[test]
+    fn test_verify_proof_accepts_every_leaf() {
+        for leaf_count in 1..=33 {
+            let leaves = leaves(leaf_count);
+            for leaf_index in 0..leaf_count {
+                let (root, proof) = build_proof(&leaves, leaf_index);
+                verify_proof(
+                    &root,
+                    &leaves[leaf_index],
+                    &(leaf_index as u64),
+                    &proof,
+                    leaf_count as u64,
+                )
+                .expect("Valid proof rejected");
+            }
+        }
+    }
+
+    #[test]
+    fn test_verify_proof_rejects_proof_for_other_leaf_count() {
+        let leaves = leaves(6);
+        let (root, proof) = build_proof(&leaves, 5);
+
+        let error = verify_proof(&root, &leaves[5], &5, &proof, 7).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .contains("InsufficientProofElementsForOtherMountainPeaks"),
+            "Expected InsufficientProofElementsForOtherMountainPeaks error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_verify_proof_against_later_roots() {
+        let leaves = leaves(33);
+        for leaf_count in 6..=33 {
+            let (root, proof) = build_proof(&leaves[..leaf_count], 5);
+            verify_proof(&root, &leaves[5], &5, &proof, leaf_count as u64)
+                .expect("Proof against later root rejected");
+        }
+    }
+
+    #[test]
+    fn test_verify_proof_rejects_corrupted_proofs() {
+        let config = Config {
+            cases: 512,
+            failure_persistence: None,
+            ..Config::default()
+        };
+        let mut runner =
+            TestRunner::new_with_rng(config, TestRng::from_seed(RngAlgorithm::ChaCha, &SEED));
+
+        runner
+            .run(&vec(any::<u8>(), 0..512), |data| {
+                check_proof_verification(&data);
+                Ok(())
+            })
+            .unwrap();
+    }
+}
@@ -1,6 +1,8 @@
+pub mod cpi_authority;
 pub mod ix;
 pub mod mmr;
 pub mod signatures;
 
+pub use cpi_authority::*;
 pub use ix::*;
 pub use signatures::*;
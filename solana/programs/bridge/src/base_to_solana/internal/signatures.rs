@@ -1,9 +1,42 @@
-use crate::BridgeError;
+use crate::{
+    base_to_solana::{
+        constants::{PARTNER_PROGRAM_ID, PARTNER_SIGNERS_ACCOUNT_SEED},
+        state::{OutputRootEntry, Signers},
+    },
+    common::{bridge::Bridge, MAX_SIGNATURE_COUNT},
+    BridgeError, EmergencyQuorumUsed,
+};
 use anchor_lang::{
     prelude::*,
-    solana_program::{keccak, secp256k1_recover::secp256k1_recover},
+    solana_program::{
+        keccak, secp256k1_program,
+        secp256k1_recover::secp256k1_recover,
+        sysvar::instructions::{load_current_index_checked, load_instruction_at_checked},
+    },
 };
 
+/// Size of the `SecpSignatureOffsets` entries following the signature count in a Secp256k1
+/// program instruction.
+const SECP256K1_OFFSETS_LEN: usize = 11;
+
+/// Domain tag prefixed to `confirm_relayed` attestations.
+const CONFIRM_RELAYED_DOMAIN: &[u8] = b"confirm_relayed";
+
+/// Domain tag prefixed to `record_relay_execution` attestations.
+const RELAY_EXECUTION_DOMAIN: &[u8] = b"record_relay_execution";
+
+/// Domain tag prefixed to `register_output_roots_batch` attestations.
+const OUTPUT_ROOTS_BATCH_DOMAIN: &[u8] = b"register_output_roots_batch";
+
+/// Domain tag prefixed to `supersede_output_root` attestations.
+const SUPERSEDE_OUTPUT_ROOT_DOMAIN: &[u8] = b"supersede_output_root";
+
+/// Domain tag prefixed to `post_liability_root` attestations.
+const LIABILITY_ROOT_DOMAIN: &[u8] = b"post_liability_root";
+
+/// Domain tag prefixed to `settle_relay_fees` attestations.
+const RELAY_FEE_SETTLEMENT_DOMAIN: &[u8] = b"settle_relay_fees";
+
 /// message = keccak256("\x19Ethereum Signed Message:\n" || len || (output_root || base_block_number_be || total_leaf_count_be))
 pub fn compute_output_root_message_hash(
     output_root: &[u8; 32],
@@ -16,6 +49,85 @@ pub fn compute_output_root_message_hash(
     message_bytes.extend_from_slice(&base_block_number.to_be_bytes());
     message_bytes.extend_from_slice(&total_leaf_count.to_be_bytes());
 
+    eth_signed_message_hash(&message_bytes)
+}
+
+/// message = keccak256("\x19Ethereum Signed Message:\n" || len || ("register_output_roots_batch" || (output_root || base_block_number_be || total_leaf_count_be) || ..))
+pub fn compute_output_roots_batch_message_hash(entries: &[OutputRootEntry]) -> [u8; 32] {
+    let mut message_bytes =
+        Vec::with_capacity(OUTPUT_ROOTS_BATCH_DOMAIN.len() + (32 + 8 + 8) * entries.len());
+    message_bytes.extend_from_slice(OUTPUT_ROOTS_BATCH_DOMAIN);
+    for entry in entries {
+        message_bytes.extend_from_slice(&entry.output_root);
+        message_bytes.extend_from_slice(&entry.base_block_number.to_be_bytes());
+        message_bytes.extend_from_slice(&entry.total_leaf_count.to_be_bytes());
+    }
+
+    eth_signed_message_hash(&message_bytes)
+}
+
+/// message = keccak256("\x19Ethereum Signed Message:\n" || len || ("supersede_output_root" || output_root || base_block_number_be || total_leaf_count_be || revision_be))
+pub fn compute_supersede_output_root_message_hash(
+    output_root: &[u8; 32],
+    base_block_number: u64,
+    total_leaf_count: u64,
+    revision: u32,
+) -> [u8; 32] {
+    let mut message_bytes = Vec::with_capacity(SUPERSEDE_OUTPUT_ROOT_DOMAIN.len() + 32 + 8 + 8 + 4);
+    message_bytes.extend_from_slice(SUPERSEDE_OUTPUT_ROOT_DOMAIN);
+    message_bytes.extend_from_slice(output_root);
+    message_bytes.extend_from_slice(&base_block_number.to_be_bytes());
+    message_bytes.extend_from_slice(&total_leaf_count.to_be_bytes());
+    message_bytes.extend_from_slice(&revision.to_be_bytes());
+
+    eth_signed_message_hash(&message_bytes)
+}
+
+/// message = keccak256("\x19Ethereum Signed Message:\n" || len || ("confirm_relayed" || nonce_be || ..))
+pub fn compute_relayed_nonces_message_hash(nonces: &[u64]) -> [u8; 32] {
+    let mut message_bytes = Vec::with_capacity(CONFIRM_RELAYED_DOMAIN.len() + 8 * nonces.len());
+    message_bytes.extend_from_slice(CONFIRM_RELAYED_DOMAIN);
+    for nonce in nonces {
+        message_bytes.extend_from_slice(&nonce.to_be_bytes());
+    }
+
+    eth_signed_message_hash(&message_bytes)
+}
+
+/// message = keccak256("\x19Ethereum Signed Message:\n" || len || ("record_relay_execution" || nonce_be || relayer))
+pub fn compute_relay_execution_message_hash(nonce: u64, relayer: &Pubkey) -> [u8; 32] {
+    let mut message_bytes = Vec::with_capacity(RELAY_EXECUTION_DOMAIN.len() + 8 + 32);
+    message_bytes.extend_from_slice(RELAY_EXECUTION_DOMAIN);
+    message_bytes.extend_from_slice(&nonce.to_be_bytes());
+    message_bytes.extend_from_slice(relayer.as_ref());
+
+    eth_signed_message_hash(&message_bytes)
+}
+
+/// message = keccak256("\x19Ethereum Signed Message:\n" || len || ("post_liability_root" || liability_root || base_block_number_be))
+pub fn compute_liability_root_message_hash(
+    liability_root: &[u8; 32],
+    base_block_number: u64,
+) -> [u8; 32] {
+    let mut message_bytes = Vec::with_capacity(LIABILITY_ROOT_DOMAIN.len() + 32 + 8);
+    message_bytes.extend_from_slice(LIABILITY_ROOT_DOMAIN);
+    message_bytes.extend_from_slice(liability_root);
+    message_bytes.extend_from_slice(&base_block_number.to_be_bytes());
+
+    eth_signed_message_hash(&message_bytes)
+}
+
+/// message = keccak256("\x19Ethereum Signed Message:\n" || len || ("settle_relay_fees" || settlement_nonce_be || amount_be))
+pub fn compute_relay_fee_settlement_message_hash(settlement_nonce: u64, amount: u64) -> [u8; 32] {
+    let mut message_bytes = Vec::with_capacity(RELAY_FEE_SETTLEMENT_DOMAIN.len() + 8 + 8);
+    message_bytes.extend_from_slice(RELAY_FEE_SETTLEMENT_DOMAIN);
+    message_bytes.extend_from_slice(&settlement_nonce.to_be_bytes());
+    message_bytes.extend_from_slice(&amount.to_be_bytes());
+
+    eth_signed_message_hash(&message_bytes)
+}
+
+fn eth_signed_message_hash(message_bytes: &[u8]) -> [u8; 32] {
     // Apply the Ethereum signed message prefix per EIP-191
     // "\x19Ethereum Signed Message:\n" + len(message) + message
     let prefix: &[u8] = b"\x19Ethereum Signed Message:\n";
@@ -25,26 +137,245 @@ pub fn compute_output_root_message_hash(
         Vec::with_capacity(prefix.len() + len_dec_string.len() + message_bytes.len());
     prefixed.extend_from_slice(prefix);
     prefixed.extend_from_slice(len_dec_string.as_bytes());
-    prefixed.extend_from_slice(&message_bytes);
+    prefixed.extend_from_slice(message_bytes);
 
     keccak::hash(&prefixed).0
 }
 
+/// Verifies that `signatures` over `message_hash` meet the Base oracle threshold and, when
+/// required, the partner oracle threshold read from `partner_config`.
+///
+/// While the oracle emergency quorum is active, emergency signers also count as Base oracle
+/// approvals and the emergency threshold applies instead of the Base oracle threshold.
+pub fn verify_oracle_approvals(
+    bridge: &Bridge,
+    partner_config: &AccountInfo,
+    signatures: &[[u8; 65]],
+    message_hash: &[u8; 32],
+) -> Result<()> {
+    // Recover unique EVM signers from provided signatures
+    let unique_signers = recover_unique_evm_addresses(signatures, message_hash)?;
+
+    verify_signer_approvals(bridge, partner_config, &unique_signers, message_hash)
+}
+
+/// Verifies that the already authenticated `unique_signers` of `message_hash` meet the Base oracle
+/// threshold and, when required, the partner oracle threshold read from `partner_config`.
+pub fn verify_signer_approvals(
+    bridge: &Bridge,
+    partner_config: &AccountInfo,
+    unique_signers: &[[u8; 20]],
+    message_hash: &[u8; 32],
+) -> Result<()> {
+    // Verify Base oracle approvals
+    let base_approved_count = bridge.base_oracle_config.count_approvals(unique_signers);
+    if (base_approved_count as u8) < bridge.base_oracle_config.threshold {
+        let failover = &bridge.oracle_failover;
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            failover.is_emergency_active(now),
+            BridgeError::InsufficientBaseSignatures
+        );
+
+        let emergency_approved_count =
+            failover.count_approvals(&bridge.base_oracle_config, unique_signers);
+        require!(
+            emergency_approved_count as u8 >= failover.emergency_threshold,
+            BridgeError::InsufficientBaseSignatures
+        );
+
+        emit!(EmergencyQuorumUsed {
+            message_hash: *message_hash,
+            approvals: emergency_approved_count as u8,
+            threshold: failover.emergency_threshold,
+            expires_at: failover.emergency_expires_at,
+        });
+    }
+
+    if let Some(partner_signers) = partner_signers(bridge, partner_config)? {
+        let partner_approved_count = partner_signers.count_approvals(unique_signers);
+        require!(
+            partner_approved_count as u8 >= bridge.partner_oracle_config.required_threshold,
+            BridgeError::InsufficientPartnerSignatures
+        );
+    }
+
+    Ok(())
+}
+
+/// Reads the partner oracle signers from `partner_config`, if partner approvals are required.
+fn partner_signers(bridge: &Bridge, partner_config: &AccountInfo) -> Result<Option<Signers>> {
+    if bridge.partner_oracle_config.required_threshold == 0 {
+        return Ok(None);
+    }
+
+    // Validate partner_config PDA using seed with the partner program id
+    let expected_partner_cfg =
+        Pubkey::find_program_address(&[PARTNER_SIGNERS_ACCOUNT_SEED], &PARTNER_PROGRAM_ID).0;
+    require_keys_eq!(
+        partner_config.key(),
+        expected_partner_cfg,
+        anchor_lang::error::ErrorCode::ConstraintSeeds
+    );
+
+    // Deserialize manually, the account being owned by the partner program
+    let partner_signers = Signers::try_deserialize(&mut &partner_config.data.borrow()[..])?;
+    Ok(Some(partner_signers))
+}
+
+/// Returns whether `unique_signers` meet the Base oracle (or active emergency) threshold and the
+/// partner oracle threshold, without failing or emitting events like `verify_signer_approvals`.
+fn is_quorum_met(
+    bridge: &Bridge,
+    partner_signers: Option<&Signers>,
+    now: i64,
+    unique_signers: &[[u8; 20]],
+) -> bool {
+    let base_config = &bridge.base_oracle_config;
+    let failover = &bridge.oracle_failover;
+    let base_met = base_config.count_approvals(unique_signers) as u8 >= base_config.threshold
+        || (failover.is_emergency_active(now)
+            && failover.count_approvals(base_config, unique_signers) as u8
+                >= failover.emergency_threshold);
+
+    let partner_met = match partner_signers {
+        Some(partner_signers) => {
+            partner_signers.count_approvals(unique_signers) as u8
+                >= bridge.partner_oracle_config.required_threshold
+        }
+        None => true,
+    };
+
+    base_met && partner_met
+}
+
 /// Recover unique 20-byte EVM addresses from signatures over the given message hash
 pub fn recover_unique_evm_addresses(
     signatures: &[[u8; 65]],
     message_hash: &[u8; 32],
 ) -> Result<Vec<[u8; 20]>> {
-    let mut unique_signers: Vec<[u8; 20]> = Vec::new();
-    for sig in signatures.iter() {
+    recover_evm_addresses_until(signatures, message_hash, |_| false)
+}
+
+/// Recovers unique EVM addresses from `signatures` over `message_hash` until they meet the oracle
+/// quorum checked by `verify_signer_approvals`, skipping the recovery of the remaining signatures.
+///
+/// Signers whose signatures follow the quorum are not returned, and their signatures are not
+/// checked.
+pub fn recover_quorum_evm_addresses(
+    bridge: &Bridge,
+    partner_config: &AccountInfo,
+    signatures: &[[u8; 65]],
+    message_hash: &[u8; 32],
+) -> Result<Vec<[u8; 20]>> {
+    let partner_signers = partner_signers(bridge, partner_config)?;
+    let now = Clock::get()?.unix_timestamp;
+
+    recover_evm_addresses_until(signatures, message_hash, |unique_signers| {
+        is_quorum_met(bridge, partner_signers.as_ref(), now, unique_signers)
+    })
+}
+
+/// Recovers unique EVM addresses from at most `MAX_SIGNATURE_COUNT` signatures, stopping as soon
+/// as `is_done` holds for the addresses recovered so far. Repeated signatures are skipped before
+/// being recovered.
+fn recover_evm_addresses_until(
+    signatures: &[[u8; 65]],
+    message_hash: &[u8; 32],
+    mut is_done: impl FnMut(&[[u8; 20]]) -> bool,
+) -> Result<Vec<[u8; 20]>> {
+    require!(
+        signatures.len() <= MAX_SIGNATURE_COUNT as usize,
+        BridgeError::TooManySignatures
+    );
+
+    let mut unique_signers: Vec<[u8; 20]> = Vec::with_capacity(signatures.len());
+    for (i, sig) in signatures.iter().enumerate() {
+        if signatures[..i].contains(sig) {
+            continue;
+        }
+
         let recovered = recover_eth_address(sig, message_hash)?;
         if !unique_signers.iter().any(|s| s == &recovered) {
             unique_signers.push(recovered);
+            if is_done(&unique_signers) {
+                break;
+            }
+        }
+    }
+    Ok(unique_signers)
+}
+
+/// Collects the unique EVM addresses whose signatures over `message_hash` were verified by the
+/// Secp256k1 native program instructions preceding the current instruction in the transaction.
+///
+/// The Secp256k1 program hashes the message with keccak256 itself, so each signed message must be
+/// the EIP-191 prefixed message whose hash is `message_hash`. Entries referencing data in other
+/// instructions are rejected so that every verified signature is fully described by its own
+/// precompile instruction.
+pub fn precompile_evm_addresses(
+    instructions_sysvar: &AccountInfo,
+    message_hash: &[u8; 32],
+) -> Result<Vec<[u8; 20]>> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+
+    let mut unique_signers: Vec<[u8; 20]> = Vec::new();
+    for index in 0..current_index {
+        let ix = load_instruction_at_checked(index as usize, instructions_sysvar)?;
+        if ix.program_id != secp256k1_program::ID {
+            continue;
+        }
+
+        for signer in secp256k1_instruction_signers(&ix.data, index as u8, message_hash)? {
+            if !unique_signers.iter().any(|s| s == &signer) {
+                unique_signers.push(signer);
+            }
         }
     }
     Ok(unique_signers)
 }
 
+/// Returns the EVM addresses of the signatures in the data of the Secp256k1 program instruction at
+/// `ix_index`, requiring each of them to sign the message hashing to `message_hash`.
+///
+/// Layout: count (1) | count * (signature_offset (2) | signature_ix (1) | eth_address_offset (2) |
+/// eth_address_ix (1) | message_offset (2) | message_size (2) | message_ix (1)) | ..
+fn secp256k1_instruction_signers(
+    data: &[u8],
+    ix_index: u8,
+    message_hash: &[u8; 32],
+) -> Result<Vec<[u8; 20]>> {
+    let count = *data
+        .first()
+        .ok_or(BridgeError::InvalidSecp256k1Instruction)? as usize;
+
+    let read_u16 = |offset: usize| u16::from_le_bytes([data[offset], data[offset + 1]]) as usize;
+    let read_slice = |offset: usize, len: usize| {
+        data.get(offset..offset + len)
+            .ok_or(BridgeError::InvalidSecp256k1Instruction)
+    };
+
+    let mut signers = Vec::with_capacity(count);
+    for i in 0..count {
+        let start = 1 + i * SECP256K1_OFFSETS_LEN;
+        let offsets = read_slice(start, SECP256K1_OFFSETS_LEN)?;
+        require!(
+            offsets[2] == ix_index && offsets[5] == ix_index && offsets[10] == ix_index,
+            BridgeError::InvalidSecp256k1Instruction
+        );
+
+        let eth_address = read_slice(read_u16(start + 3), 20)?;
+        let message = read_slice(read_u16(start + 6), read_u16(start + 8))?;
+        require!(
+            keccak::hash(message).0 == *message_hash,
+            BridgeError::InvalidMessageHash
+        );
+
+        signers.push(eth_address.try_into().unwrap());
+    }
+    Ok(signers)
+}
+
 /// Recovers the Ethereum address from a 65-byte Secp256k1 signature over the given message hash.
 /// Returns the 20-byte EVM address (keccak(pubkey)[12..32]).
 pub fn recover_eth_address(signature: &[u8; 65], message_hash: &[u8; 32]) -> Result<[u8; 20]> {
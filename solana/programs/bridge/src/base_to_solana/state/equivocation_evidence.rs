@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::common::MAX_SIGNER_COUNT;
+
+/// Evidence that Base oracle signers attested two conflicting output roots for the same Base
+/// block, recorded by `report_equivocation`.
+///
+/// Each offender is stored with its signatures over both output roots, so that the equivocation
+/// stays provable on-chain (e.g. for slashing) after the offenders have been disabled.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct EquivocationEvidence {
+    /// The Base block number both output roots were attested for.
+    pub base_block_number: u64,
+
+    /// The first attested output root and its total leaf count.
+    pub first_root: [u8; 32],
+    pub first_total_leaf_count: u64,
+
+    /// The second, conflicting, attested output root and its total leaf count.
+    pub second_root: [u8; 32],
+    pub second_total_leaf_count: u64,
+
+    /// Number of offenders in `offenders`.
+    pub offender_count: u8,
+
+    /// EVM addresses of the Base oracle signers that attested both output roots.
+    pub offenders: [[u8; 20]; MAX_SIGNER_COUNT as usize],
+
+    /// Signature of each offender over the first output root.
+    pub first_signatures: [[u8; 65]; MAX_SIGNER_COUNT as usize],
+
+    /// Signature of each offender over the second output root.
+    pub second_signatures: [[u8; 65]; MAX_SIGNER_COUNT as usize],
+
+    /// The account that reported the equivocation.
+    pub reporter: Pubkey,
+
+    /// Unix timestamp at which the equivocation was reported.
+    pub reported_at: i64,
+}
+
+impl EquivocationEvidence {
+    /// Returns the recorded offenders.
+    pub fn offenders(&self) -> &[[u8; 20]] {
+        &self.offenders[..self.offender_count as usize]
+    }
+
+    /// Records `offender` with its signatures over both output roots, unless already recorded.
+    pub fn add_offender(
+        &mut self,
+        offender: [u8; 20],
+        first_signature: [u8; 65],
+        second_signature: [u8; 65],
+    ) {
+        if self.offenders().contains(&offender) {
+            return;
+        }
+
+        let index = self.offender_count as usize;
+        self.offenders[index] = offender;
+        self.first_signatures[index] = first_signature;
+        self.second_signatures[index] = second_signature;
+        self.offender_count += 1;
+    }
+}
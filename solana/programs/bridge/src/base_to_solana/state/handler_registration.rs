@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::{base_to_solana::constants::HANDLER_REGISTRATION_SEED, ID};
+
+/// Handler registered by a Base sender, invoked by `relay_message` with the payload of the
+/// `CallHandler` messages of the sender instead of a list of raw instructions.
+/// Registered through `register_handler`, relayed from a message of the sender.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct HandlerRegistration {
+    /// The program invoked with the payload of the `CallHandler` messages of the sender
+    pub program_id: Pubkey,
+    /// Discriminator of the handler instruction, prepended to its `HandlerCall` arguments
+    pub discriminator: [u8; 8],
+}
+
+impl HandlerRegistration {
+    /// Returns the address of the handler registration of the Base `sender`.
+    pub fn pda(sender: &[u8; 20]) -> Pubkey {
+        Pubkey::find_program_address(&[HANDLER_REGISTRATION_SEED, sender.as_ref()], &ID).0
+    }
+
+    /// Returns the data of the handler instruction relaying `payload` from the message `nonce` of
+    /// `sender`.
+    pub fn handler_data(&self, sender: [u8; 20], nonce: u64, payload: Vec<u8>) -> Result<Vec<u8>> {
+        let mut data = self.discriminator.to_vec();
+        HandlerCall {
+            sender,
+            nonce,
+            payload,
+        }
+        .serialize(&mut data)?;
+        Ok(data)
+    }
+}
+
+/// Arguments of the handler instruction invoked for a `CallHandler` message, serialized after the
+/// registered discriminator: an Anchor handler takes them as `sender: [u8; 20], nonce: u64,
+/// payload: Vec<u8>`. The sender is verified by the bridge, which signs the handler instruction
+/// with the bridge CPI authority of the sender.
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct HandlerCall {
+    /// The Base address that sent the message
+    pub sender: [u8; 20],
+    /// Nonce of the message on Base
+    pub nonce: u64,
+    /// The payload of the message, decoded by the handler
+    pub payload: Vec<u8>,
+}
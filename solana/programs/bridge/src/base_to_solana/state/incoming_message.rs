@@ -1,8 +1,17 @@
 use anchor_lang::prelude::*;
 
-use crate::base_to_solana::{
-    token::{FinalizeBridgeSol, FinalizeBridgeSpl, FinalizeBridgeWrappedToken},
-    Ix,
+use crate::{
+    base_to_solana::{
+        constants::MAX_HANDLER_PAYLOAD_LEN,
+        token::{FinalizeBridgeSol, FinalizeBridgeSpl, FinalizeBridgeWrappedToken},
+        validate_ixs, Ix,
+    },
+    common::{
+        bridge::{FinalizationDelay, RootFreshnessConfig},
+        AddressBook,
+    },
+    solana_to_base::NATIVE_SOL_PUBKEY,
+    BridgeError,
 };
 
 /// Represents a cross-chain message sent from Base to Solana
@@ -21,6 +30,10 @@ pub struct IncomingMessage {
     /// This field does not restrict who can call the relay instruction.
     pub sender: [u8; 20],
 
+    /// Chain ID of the chain the message was sent from, recorded from the bridge configuration
+    /// when the message is proven. The message can only be relayed while it is still configured.
+    pub remote_chain_id: u64,
+
     /// The actual message payload containing either instruction calls or token transfer data.
     /// This enum determines what type of operation will be executed on Solana.
     pub message: Message,
@@ -28,6 +41,43 @@ pub struct IncomingMessage {
     /// Flag indicating whether this message has been successfully executed on Solana.
     /// Once set to true, the message cannot be executed again, preventing replay attacks.
     pub executed: bool,
+
+    /// Number of `ProvenChunk` accounts holding the instructions of a message proven with
+    /// `prove_message_chunked`, executed after the instructions stored in `message`.
+    /// Zero for messages proven in a single account.
+    pub chunk_count: u8,
+
+    /// The `OutputRoot` account the message was proven against.
+    pub output_root: Pubkey,
+
+    /// Revision of `output_root` the message was proven against. The message can only be relayed
+    /// while it is still the current revision.
+    pub output_root_revision: u32,
+
+    /// The account that paid the rent of this account when proving the message. The rent freed
+    /// when the message is relayed is refunded to it.
+    pub prover: Pubkey,
+
+    /// Nonce of the message on Base, i.e. its leaf index in the Base MMR. Reported in the
+    /// `MessageRelayed` event so that indexers can match relays against proofs.
+    pub nonce: u64,
+
+    /// Unix timestamp at which the message was proven, from which the finalization delay of
+    /// high-value transfers runs.
+    pub proven_at: i64,
+
+    /// Flag set by the guardian vetoing the message during its finalization delay. A vetoed
+    /// message can never be relayed.
+    pub vetoed: bool,
+
+    /// Flag set when relaying the transfer of the message tripped the outflow circuit breaker.
+    /// A held message never trips the breaker again: it can only be relayed once the guardian
+    /// approved its outflow.
+    pub outflow_held: bool,
+
+    /// Flag set by the guardian approving the outflow of a held message, which is then released
+    /// regardless of the outflow circuit breaker.
+    pub outflow_approved: bool,
 }
 
 impl IncomingMessage {
@@ -35,17 +85,38 @@ impl IncomingMessage {
     ///
     /// Layout:
     /// - `sender`: 20 bytes
+    /// - `remote_chain_id`: 8 bytes
     /// - `message`: 4-byte length prefix + `data_len` bytes (Anchor-serialized `Message`)
     /// - `executed`: 1 byte
+    /// - `chunk_count`: 1 byte
+    /// - `output_root`: 32 bytes
+    /// - `output_root_revision`: 4 bytes
+    /// - `prover`: 32 bytes
+    /// - `nonce`: 8 bytes
+    /// - `proven_at`: 8 bytes
+    /// - `vetoed`: 1 byte
+    /// - `outflow_held`: 1 byte
+    /// - `outflow_approved`: 1 byte
     pub fn space(data_len: usize) -> usize {
-        20 + (4 + data_len) + 1
+        20 + 8 + (4 + data_len) + 1 + 1 + 32 + 4 + 32 + 8 + 8 + 1 + 1 + 1
+    }
+
+    /// Returns the Unix timestamp from which the message can be relayed: the time it was proven,
+    /// delayed by `finalization_delay` for transfers exceeding the threshold of their token.
+    pub fn finalizable_at(&self, finalization_delay: &FinalizationDelay) -> i64 {
+        let delay = self.message.transfer().map_or(0, |transfer| {
+            let (local_token, amount) = transfer.outflow();
+            finalization_delay.delay_of(&local_token, amount)
+        });
+
+        self.proven_at.saturating_add(delay)
     }
 }
 
 /// Defines the type of cross-chain operation being performed from Base to Solana.
 ///
-/// This enum encapsulates the two main categories of bridge operations:
-/// general instruction calls and token transfers with optional additional instructions.
+/// This enum encapsulates the main categories of bridge operations: general instruction calls,
+/// token transfers with optional additional instructions, and returns of Solana → Base transfers.
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
 pub enum Message {
     /// A general cross-chain call containing a sequence of Solana instructions to execute.
@@ -61,6 +132,280 @@ pub enum Message {
         /// Additional Solana instructions to execute after the transfer is finalized
         ixs: Vec<Ix>,
     },
+
+    /// Tokens of a Solana → Base transfer sent back to Solana by the Base Bridge because the
+    /// call attached to the transfer reverted on Base.
+    /// The transfer releases vault funds (or re-mints wrapped tokens) to the original Solana sender.
+    ReturnTransfer {
+        /// Nonce of the original outgoing message. A transfer can be returned at most once.
+        outgoing_nonce: u64,
+        /// The transfer returning the tokens to the original Solana sender
+        transfer: Transfer,
+    },
+
+    /// A cross-chain call whose instructions are additionally signed by PDAs derived from the
+    /// sender's bridge CPI authority, for target programs requiring extra PDA signers.
+    /// Each extra signer is derived from `[BRIDGE_CPI_AUTHORITY_SEED, sender, ..seeds]`.
+    CallWithSigners {
+        /// The Solana instructions to execute
+        ixs: Vec<Ix>,
+        /// The custom seeds of each extra signer, namespaced under the sender's bridge CPI authority
+        signer_seeds: Vec<Vec<Vec<u8>>>,
+    },
+
+    /// Any other message that requires at least `compute_units` remaining compute units to be
+    /// executed. `relay_message` fails fast when the budget is lower, so that relayers can retry
+    /// with a higher compute unit limit instead of failing midway through the target CPIs.
+    WithComputeBudget {
+        /// The compute units that must remain when `relay_message` starts executing the message
+        compute_units: u32,
+        /// The message to execute, which cannot itself be a `WithComputeBudget` message
+        message: BoxedMessage,
+    },
+
+    /// Any other message whose Base sender prepaid a relay fee. `relay_message` pays `fee`
+    /// lamports from the relay fee vault to the relayer executing the message, up to the funds
+    /// settled in the vault.
+    WithRelayFee {
+        /// The relay fee in lamports, prepaid by the sender on Base
+        fee: u64,
+        /// The message to execute, which cannot be a `WithComputeBudget` or `WithRelayFee` message
+        message: BoxedMessage,
+    },
+
+    /// Any other message whose execution needs lamports, e.g. to fund the accounts created by the
+    /// target programs. `relay_message` lends `lamports` from the operational vault to the sender's
+    /// bridge CPI authority before executing the message and takes back the unspent lamports after.
+    WithLamportAllowance {
+        /// The lamports lent to the bridge CPI authority, bounded by the operational vault
+        lamports: u64,
+        /// The message to execute, which cannot be a `WithComputeBudget`, `WithRelayFee` or
+        /// `WithLamportAllowance` message
+        message: BoxedMessage,
+    },
+
+    /// Confirmation sent by the Base Bridge once it registered a wrapped token created by
+    /// `wrap_token`, echoing the metadata of the Base token. Relaying it records the
+    /// `WrappedTokenConfirmation` of the mint if the echoed metadata matches the mint.
+    ConfirmWrappedToken {
+        /// The confirmed wrapped token mint
+        mint: Pubkey,
+        /// The name of the Base token
+        name: String,
+        /// The symbol of the Base token
+        symbol: String,
+        /// The decimals of the Base token
+        decimals: u8,
+    },
+
+    /// A call to the handler registered by the sender with `register_handler`. `relay_message`
+    /// invokes the handler with the payload and the verified sender, signed by the bridge CPI
+    /// authority of the sender, sparing the sender from encoding the handler instruction on Base.
+    CallHandler {
+        /// The payload passed to the handler, of at most MAX_HANDLER_PAYLOAD_LEN bytes
+        payload: Vec<u8>,
+    },
+}
+
+impl Message {
+    /// Validates the message against its `sender` before it is stored.
+    ///
+    /// `ReturnTransfer` messages release vault funds on behalf of the Base Bridge itself and must be
+    /// sent by the canonical Base Bridge recorded in the address book, as must `ConfirmWrappedToken`
+    /// messages. Other messages, including transfers, are sent by the Base account initiating them
+    /// and are not restricted.
+    /// The instructions of every message must fit the structural limits checked by `validate_ixs`.
+    /// `WithComputeBudget` messages are validated through the message they wrap, which cannot be
+    /// another `WithComputeBudget` message. `WithRelayFee` messages are validated the same way and
+    /// can only be wrapped by, not wrap, a `WithComputeBudget` message. `WithLamportAllowance`
+    /// messages are validated the same way and cannot wrap any of these wrapper messages.
+    /// The payload of `CallHandler` messages must fit in a single CPI once prefixed by the handler
+    /// discriminator, sender and nonce.
+    pub fn validate(&self, sender: &[u8; 20], address_book: Option<&AddressBook>) -> Result<()> {
+        match self {
+            Message::Call(ixs)
+            | Message::Transfer { ixs, .. }
+            | Message::CallWithSigners { ixs, .. } => validate_ixs(ixs)?,
+            Message::ReturnTransfer { .. } => {
+                let address_book = address_book.ok_or(BridgeError::MissingAddressBook)?;
+                require!(
+                    *sender == address_book.bridge()?,
+                    BridgeError::UnauthorizedReturnTransfer
+                );
+            }
+            Message::ConfirmWrappedToken { .. } => {
+                let address_book = address_book.ok_or(BridgeError::MissingAddressBook)?;
+                require!(
+                    *sender == address_book.bridge()?,
+                    BridgeError::UnauthorizedWrappedTokenConfirmation
+                );
+            }
+            Message::CallHandler { payload } => require!(
+                payload.len() <= MAX_HANDLER_PAYLOAD_LEN as usize,
+                BridgeError::HandlerPayloadTooLong
+            ),
+            Message::WithComputeBudget { message, .. } => {
+                require!(
+                    !matches!(**message, Message::WithComputeBudget { .. }),
+                    BridgeError::NestedComputeBudget
+                );
+                message.validate(sender, address_book)?;
+            }
+            Message::WithRelayFee { message, .. } => {
+                require!(
+                    !matches!(
+                        **message,
+                        Message::WithComputeBudget { .. } | Message::WithRelayFee { .. }
+                    ),
+                    BridgeError::NestedRelayFee
+                );
+                message.validate(sender, address_book)?;
+            }
+            Message::WithLamportAllowance { message, .. } => {
+                require!(
+                    !matches!(
+                        **message,
+                        Message::WithComputeBudget { .. }
+                            | Message::WithRelayFee { .. }
+                            | Message::WithLamportAllowance { .. }
+                    ),
+                    BridgeError::NestedLamportAllowance
+                );
+                message.validate(sender, address_book)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Splits off the compute units required by a `WithComputeBudget` message, returning the
+    /// message to execute.
+    pub fn split_compute_budget(self) -> (Option<u32>, Message) {
+        match self {
+            Message::WithComputeBudget {
+                compute_units,
+                message,
+            } => (Some(compute_units), *message.0),
+            message => (None, message),
+        }
+    }
+
+    /// Returns the maximum age, in Base blocks, of the output root the message can be proven
+    /// against (0 accepts any root). Wrapper messages follow the policy of the message they wrap,
+    /// and `ConfirmWrappedToken` and `CallHandler` messages the policy of calls.
+    pub fn max_root_age(&self, freshness: &RootFreshnessConfig) -> u64 {
+        match self {
+            Message::Call(_)
+            | Message::ConfirmWrappedToken { .. }
+            | Message::CallHandler { .. } => freshness.call_max_root_age,
+            Message::Transfer { .. } => freshness.transfer_max_root_age,
+            Message::ReturnTransfer { .. } => freshness.return_transfer_max_root_age,
+            Message::CallWithSigners { .. } => freshness.call_with_signers_max_root_age,
+            Message::WithComputeBudget { message, .. }
+            | Message::WithRelayFee { message, .. }
+            | Message::WithLamportAllowance { message, .. } => message.max_root_age(freshness),
+        }
+    }
+
+    /// Takes the instructions executed by the message, leaving it without instructions.
+    /// Wrapper messages take the instructions of the message they wrap; `ReturnTransfer`,
+    /// `ConfirmWrappedToken` and `CallHandler` messages have none.
+    pub fn take_ixs(&mut self) -> Vec<Ix> {
+        match self {
+            Message::Call(ixs)
+            | Message::Transfer { ixs, .. }
+            | Message::CallWithSigners { ixs, .. } => std::mem::take(ixs),
+            Message::ReturnTransfer { .. }
+            | Message::ConfirmWrappedToken { .. }
+            | Message::CallHandler { .. } => vec![],
+            Message::WithComputeBudget { message, .. }
+            | Message::WithRelayFee { message, .. }
+            | Message::WithLamportAllowance { message, .. } => message.take_ixs(),
+        }
+    }
+
+    /// Returns the transfer finalized by the message, if any. Wrapper messages return the transfer
+    /// of the message they wrap.
+    pub fn transfer(&self) -> Option<&Transfer> {
+        match self {
+            Message::Transfer { transfer, .. } | Message::ReturnTransfer { transfer, .. } => {
+                Some(transfer)
+            }
+            Message::Call(_)
+            | Message::CallWithSigners { .. }
+            | Message::ConfirmWrappedToken { .. }
+            | Message::CallHandler { .. } => None,
+            Message::WithComputeBudget { message, .. }
+            | Message::WithRelayFee { message, .. }
+            | Message::WithLamportAllowance { message, .. } => message.transfer(),
+        }
+    }
+
+    /// Splits off the relay fee prepaid for a `WithRelayFee` message, returning the message to
+    /// execute.
+    pub fn split_relay_fee(self) -> (Option<u64>, Message) {
+        match self {
+            Message::WithRelayFee { fee, message } => (Some(fee), *message.0),
+            message => (None, message),
+        }
+    }
+
+    /// Splits off the lamports lent to the bridge CPI authority of a `WithLamportAllowance`
+    /// message, returning the message to execute.
+    pub fn split_lamport_allowance(self) -> (Option<u64>, Message) {
+        match self {
+            Message::WithLamportAllowance { lamports, message } => (Some(lamports), *message.0),
+            message => (None, message),
+        }
+    }
+}
+
+/// The message wrapped by the `WithComputeBudget`, `WithRelayFee` and `WithLamportAllowance`
+/// messages, serialized as a plain `Message`.
+///
+/// The Borsh derives bound every field type, so a `Box<Message>` field would make `Message`
+/// require its own serialization. Implementing Borsh by hand on this newtype breaks the cycle.
+#[derive(Debug, Clone)]
+pub struct BoxedMessage(pub Box<Message>);
+
+impl From<Message> for BoxedMessage {
+    fn from(message: Message) -> Self {
+        Self(Box::new(message))
+    }
+}
+
+impl std::ops::Deref for BoxedMessage {
+    type Target = Message;
+
+    fn deref(&self) -> &Message {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for BoxedMessage {
+    fn deref_mut(&mut self) -> &mut Message {
+        &mut self.0
+    }
+}
+
+impl AnchorSerialize for BoxedMessage {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+impl AnchorDeserialize for BoxedMessage {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        Message::deserialize_reader(reader).map(Self::from)
+    }
+}
+
+/// Described as the `Message` it wraps in the IDL, like a `Box<Message>` field would be.
+#[cfg(feature = "idl-build")]
+impl anchor_lang::IdlBuild for BoxedMessage {
+    fn get_full_path() -> String {
+        <Message as anchor_lang::IdlBuild>::get_full_path()
+    }
 }
 
 /// Specifies the type of token being finalized on Solana for a Base→Solana bridge
@@ -81,3 +426,23 @@ pub enum Transfer {
     /// Mints wrapped tokens on Solana to represent the Base asset.
     WrappedToken(FinalizeBridgeWrappedToken),
 }
+
+impl Transfer {
+    pub fn finalize<'info>(&self, account_infos: &'info [AccountInfo<'info>]) -> Result<()> {
+        match self {
+            Transfer::Sol(transfer) => transfer.finalize(account_infos),
+            Transfer::Spl(transfer) => transfer.finalize(account_infos),
+            Transfer::WrappedToken(transfer) => transfer.finalize(account_infos),
+        }
+    }
+
+    /// Returns the token released by the transfer, `NATIVE_SOL_PUBKEY` for SOL, and its amount as
+    /// carried by the message, i.e. in the remote token decimals for SPL vaults scaling amounts.
+    pub fn outflow(&self) -> (Pubkey, u64) {
+        match self {
+            Transfer::Sol(transfer) => (NATIVE_SOL_PUBKEY, transfer.amount),
+            Transfer::Spl(transfer) => (transfer.local_token, transfer.amount),
+            Transfer::WrappedToken(transfer) => (transfer.local_token, transfer.amount),
+        }
+    }
+}
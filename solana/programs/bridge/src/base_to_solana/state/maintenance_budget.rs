@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{base_to_solana::constants::MAX_MAINTENANCE_FEE_REIMBURSEMENT, BridgeError, ID};
+
+/// Protocol-funded budget reimbursing the payer of `register_output_root` for the rent of the
+/// output root account plus `fee_reimbursement` lamports of transaction fees, so that registrations
+/// keep up during fee spikes. It is funded by transferring lamports to it and only the lamports
+/// above its rent-exempt minimum are paid out. Nothing is reimbursed until this account is
+/// initialized.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct MaintenanceBudget {
+    /// Lamports reimbursed per registration on top of the output root rent
+    pub fee_reimbursement: u64,
+    /// Total lamports reimbursed
+    pub total_reimbursed: u64,
+}
+
+impl MaintenanceBudget {
+    /// Sets the lamports reimbursed per registration on top of the output root rent.
+    pub fn set_fee_reimbursement(&mut self, fee_reimbursement: u64) -> Result<()> {
+        require!(
+            fee_reimbursement <= MAX_MAINTENANCE_FEE_REIMBURSEMENT,
+            BridgeError::InvalidMaintenanceFeeReimbursement
+        );
+        self.fee_reimbursement = fee_reimbursement;
+        Ok(())
+    }
+
+    /// Loads the maintenance budget, returning `None` if it is not initialized.
+    pub fn load(maintenance_budget: &AccountInfo) -> Result<Option<Self>> {
+        if maintenance_budget.owner != &ID || maintenance_budget.data_is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self::try_deserialize(
+            &mut &maintenance_budget.try_borrow_data()?[..],
+        )?))
+    }
+
+    /// Reimburses `payer` for a registration whose output root account cost `rent` lamports, as far
+    /// as the budget allows. Returns the reimbursed lamports.
+    pub fn reimburse(
+        maintenance_budget: &AccountInfo,
+        payer: &AccountInfo,
+        rent: u64,
+    ) -> Result<u64> {
+        let Some(mut budget) = Self::load(maintenance_budget)? else {
+            return Ok(0);
+        };
+
+        let available = maintenance_budget
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(maintenance_budget.data_len()));
+        let amount = rent.saturating_add(budget.fee_reimbursement).min(available);
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        maintenance_budget.sub_lamports(amount)?;
+        payer.add_lamports(amount)?;
+
+        budget.total_reimbursed = budget.total_reimbursed.saturating_add(amount);
+        budget.try_serialize(&mut &mut maintenance_budget.try_borrow_mut_data()?[..])?;
+
+        Ok(amount)
+    }
+}
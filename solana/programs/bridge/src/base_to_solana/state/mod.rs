@@ -1,9 +1,25 @@
+pub mod equivocation_evidence;
+pub mod handler_registration;
 pub mod incoming_message;
+pub mod maintenance_budget;
+pub mod operational_vault;
 pub mod output_root;
 pub mod prove_buffer;
+pub mod proven_chunk;
+pub mod relay_deny_list;
+pub mod relay_fee_vault;
+pub mod returned_transfer;
 pub mod signers;
 
+pub use equivocation_evidence::*;
+pub use handler_registration::*;
 pub use incoming_message::*;
+pub use maintenance_budget::*;
+pub use operational_vault::*;
 pub use output_root::*;
 pub use prove_buffer::*;
+pub use proven_chunk::*;
+pub use relay_deny_list::*;
+pub use relay_fee_vault::*;
+pub use returned_transfer::*;
 pub use signers::*;
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{base_to_solana::constants::MAX_LAMPORT_ALLOWANCE, BridgeError};
+
+/// Protocol-funded vault lending lamports to the bridge CPI authority of `WithLamportAllowance`
+/// messages for the duration of their execution, e.g. to fund the accounts created by the target
+/// programs. The lamports the CPI authority did not spend are returned once the message executed.
+/// It is funded by transferring lamports to it and only the lamports above its rent-exempt minimum
+/// can be lent. No allowance is granted until this account is initialized.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct OperationalVault {
+    /// Maximum lamport allowance of a single message
+    pub max_allowance: u64,
+    /// Total lamports lent to bridge CPI authorities
+    pub total_lent: u64,
+    /// Total lamports returned unspent
+    pub total_returned: u64,
+}
+
+impl OperationalVault {
+    /// Sets the maximum lamport allowance of a single message.
+    pub fn set_max_allowance(&mut self, max_allowance: u64) -> Result<()> {
+        require!(
+            max_allowance <= MAX_LAMPORT_ALLOWANCE,
+            BridgeError::InvalidMaxLamportAllowance
+        );
+        self.max_allowance = max_allowance;
+        Ok(())
+    }
+}
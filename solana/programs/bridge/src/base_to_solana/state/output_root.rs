@@ -1,5 +1,11 @@
 use anchor_lang::prelude::*;
 
+use crate::{
+    base_to_solana::{constants::OUTPUT_ROOT_CHALLENGE_WINDOW_SECONDS, internal::mmr, Message},
+    common::bridge::Bridge,
+    BridgeError,
+};
+
 /// Represents a cryptographic commitment to the set of Base L2 bridge messages
 /// at a specific Base block number.
 ///
@@ -13,7 +19,7 @@ use anchor_lang::prelude::*;
 /// 2. Users prove their messages were included on Base using these roots and an MMR proof
 /// 3. Proven messages are then relayed and executed on Solana
 #[account]
-#[derive(InitSpace)]
+#[derive(Debug, InitSpace)]
 pub struct OutputRoot {
     /// The 32-byte MMR root that commits to all outgoing bridge messages on Base
     /// as of the specified Base block number.
@@ -23,4 +29,76 @@ pub struct OutputRoot {
     /// was generated. This is crucial for determining the MMR structure and
     /// mountain configuration at the time of proof validation.
     pub total_leaf_count: u64,
+
+    /// The Base block number the root was registered for, used to enforce the root freshness
+    /// policy of `prove_message`.
+    pub base_block_number: u64,
+
+    /// Unix timestamp at which the root was registered, opening its challenge window.
+    pub registered_at: i64,
+
+    /// Number of times the root was superseded by `supersede_output_root`. Messages record the
+    /// revision they were proven against and can only be relayed while it is current.
+    pub revision: u32,
+}
+
+impl OutputRoot {
+    /// Rejects proving `message` against this root when it is older than the freshness window
+    /// configured for the message kind.
+    pub fn check_freshness(&self, bridge: &Bridge, message: &Message) -> Result<()> {
+        let max_root_age = message.max_root_age(&bridge.root_freshness_config);
+        require!(
+            max_root_age == 0
+                || bridge
+                    .base_block_number
+                    .saturating_sub(self.base_block_number)
+                    <= max_root_age,
+            BridgeError::StaleOutputRoot
+        );
+        Ok(())
+    }
+
+    /// Verifies the MMR proof of the message with `message_hash` and `nonce` against this root.
+    ///
+    /// Any root registered for a Base block at or after the message's block covers it, so the
+    /// proof is built against this root's `total_leaf_count` rather than the leaf count of the
+    /// first root that included the message.
+    pub fn verify_message_proof(
+        &self,
+        message_hash: &[u8; 32],
+        nonce: u64,
+        proof: &[[u8; 32]],
+    ) -> Result<()> {
+        require!(
+            nonce < self.total_leaf_count,
+            BridgeError::MessageNotCoveredByOutputRoot
+        );
+        mmr::verify_proof(
+            &self.root,
+            message_hash,
+            &nonce,
+            proof,
+            self.total_leaf_count,
+        )
+    }
+
+    /// Returns whether the root can still be superseded at `now`.
+    pub fn is_challengeable(&self, now: i64) -> bool {
+        now < self
+            .registered_at
+            .saturating_add(OUTPUT_ROOT_CHALLENGE_WINDOW_SECONDS)
+    }
+}
+
+/// A single output root registered by the `register_output_roots_batch` instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct OutputRootEntry {
+    /// The 32-byte MMR root of Base messages for the given block
+    pub output_root: [u8; 32],
+
+    /// The Base block number this output root corresponds to
+    pub base_block_number: u64,
+
+    /// The total number of leaves in the MMR with this root
+    pub total_leaf_count: u64,
 }
@@ -0,0 +1,150 @@
+use anchor_lang::{prelude::*, solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE};
+
+use crate::{
+    base_to_solana::{
+        constants::{MAX_MESSAGE_IXS, MAX_MESSAGE_IXS_DATA_LEN, MAX_PROVEN_CHUNKS},
+        validate_ixs, Ix,
+    },
+    common::DISCRIMINATOR_LEN,
+    BridgeError,
+};
+
+/// Part of the instructions of an incoming message too large to be stored in a single account.
+/// Created by `prove_message_chunked` at `[PROVEN_CHUNK_SEED, message, index]` and executed, in
+/// index order, by `relay_message` after the instructions stored in the `IncomingMessage`.
+#[account]
+#[derive(Debug)]
+pub struct ProvenChunk {
+    /// The `IncomingMessage` account this chunk belongs to
+    pub message: Pubkey,
+
+    /// Position of the chunk within the message
+    pub index: u8,
+
+    /// The instructions of the chunk
+    pub ixs: Vec<Ix>,
+}
+
+impl ProvenChunk {
+    /// Returns the byte size for account allocation excluding the DISCRIMINATOR_LEN-byte Anchor discriminator.
+    ///
+    /// Layout:
+    /// - `message`: 32 bytes
+    /// - `index`: 1 byte
+    /// - `ixs`: 4-byte length prefix + the Anchor-serialized size of each instruction
+    pub fn space(ixs: &[Ix]) -> usize {
+        32 + 1 + 4 + ixs.iter().map(ix_len).sum::<usize>()
+    }
+
+    /// Splits `ixs`, in order, into the instructions of each chunk. Chunks are filled greedily up
+    /// to the account size the program can create in a single instruction, and each chunk must
+    /// fit the structural limits checked by `validate_ixs`.
+    pub fn pack(ixs: Vec<Ix>) -> Result<Vec<Vec<Ix>>> {
+        let mut chunks: Vec<Vec<Ix>> = vec![];
+        for ix in ixs {
+            match chunks.last_mut() {
+                Some(chunk) if fits(chunk, &ix) => chunk.push(ix),
+                _ => chunks.push(vec![ix]),
+            }
+        }
+
+        require!(
+            chunks.len() <= MAX_PROVEN_CHUNKS as usize,
+            BridgeError::TooManyProvenChunks
+        );
+
+        for chunk in &chunks {
+            validate_ixs(chunk)?;
+            require!(
+                DISCRIMINATOR_LEN + Self::space(chunk) <= MAX_PERMITTED_DATA_INCREASE,
+                BridgeError::ProvenChunkTooLarge
+            );
+        }
+
+        Ok(chunks)
+    }
+}
+
+/// Returns whether `ix` can be appended to `chunk` without exceeding the chunk limits.
+fn fits(chunk: &[Ix], ix: &Ix) -> bool {
+    let data_len = chunk.iter().map(|ix| ix.data.len()).sum::<usize>() + ix.data.len();
+
+    chunk.len() < MAX_MESSAGE_IXS as usize
+        && data_len <= MAX_MESSAGE_IXS_DATA_LEN as usize
+        && DISCRIMINATOR_LEN + ProvenChunk::space(chunk) + ix_len(ix) <= MAX_PERMITTED_DATA_INCREASE
+}
+
+/// Anchor-serialized size of an instruction: program ID, accounts and data.
+fn ix_len(ix: &Ix) -> usize {
+    32 + (4 + ix.accounts.len() * (32 + 1 + 1)) + (4 + ix.data.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::base_to_solana::{constants::MAX_IX_ACCOUNTS, IxAccount};
+
+    fn ix(accounts: usize, data_len: usize) -> Ix {
+        Ix {
+            program_id: Pubkey::new_unique(),
+            accounts: (0..accounts)
+                .map(|_| IxAccount {
+                    pubkey: Pubkey::new_unique(),
+                    is_writable: false,
+                    is_signer: false,
+                })
+                .collect(),
+            data: vec![0; data_len],
+        }
+    }
+
+    #[test]
+    fn test_ix_len_matches_serialized_size() {
+        let ix = ix(3, 100);
+        assert_eq!(ix_len(&ix), ix.try_to_vec().unwrap().len());
+    }
+
+    #[test]
+    fn test_pack_splits_ix_count() {
+        let ixs = (0..MAX_MESSAGE_IXS as usize + 1)
+            .map(|_| ix(1, 8))
+            .collect::<Vec<_>>();
+
+        let chunks = ProvenChunk::pack(ixs).unwrap();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].len(), MAX_MESSAGE_IXS as usize);
+        assert_eq!(chunks[1].len(), 1);
+    }
+
+    #[test]
+    fn test_pack_splits_account_size() {
+        let ixs = (0..8)
+            .map(|_| ix(MAX_IX_ACCOUNTS as usize, 0))
+            .collect::<Vec<_>>();
+
+        let chunks = ProvenChunk::pack(ixs).unwrap();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].len(), 4);
+        for chunk in &chunks {
+            assert!(DISCRIMINATOR_LEN + ProvenChunk::space(chunk) <= MAX_PERMITTED_DATA_INCREASE);
+        }
+    }
+
+    #[test]
+    fn test_pack_oversized_ix_fails() {
+        let err =
+            ProvenChunk::pack(vec![ix(0, MAX_MESSAGE_IXS_DATA_LEN as usize + 1)]).unwrap_err();
+        assert_eq!(err, BridgeError::MessageIxsDataTooLong.into());
+    }
+
+    #[test]
+    fn test_pack_too_many_chunks_fails() {
+        let ixs = (0..MAX_PROVEN_CHUNKS as usize + 1)
+            .map(|_| ix(0, 8 * 1024))
+            .collect::<Vec<_>>();
+
+        let err = ProvenChunk::pack(ixs).unwrap_err();
+        assert_eq!(err, BridgeError::TooManyProvenChunks.into());
+    }
+}
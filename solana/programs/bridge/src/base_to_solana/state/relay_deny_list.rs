@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::constants::{DENIED_RELAY_PROGRAMS, MAX_RELAY_DENY_LIST_PROGRAMS},
+    BridgeError, ID,
+};
+
+/// Guardian-maintained programs the bridge CPI authority never invokes from a relayed message, on
+/// top of the built-in `DENIED_RELAY_PROGRAMS`. Only the built-in programs are denied until this
+/// account is initialized.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct RelayDenyList {
+    /// Number of programs in `programs` array
+    pub program_count: u8,
+    /// Programs denied in addition to the built-in ones
+    pub programs: [Pubkey; MAX_RELAY_DENY_LIST_PROGRAMS as usize],
+}
+
+impl RelayDenyList {
+    /// Replaces the denied programs, validating the list.
+    pub fn set(&mut self, programs: &[Pubkey]) -> Result<()> {
+        require!(
+            programs.len() <= MAX_RELAY_DENY_LIST_PROGRAMS as usize,
+            BridgeError::TooManyDeniedRelayPrograms
+        );
+
+        self.program_count = programs.len() as u8;
+        self.programs = Default::default();
+        self.programs[..programs.len()].copy_from_slice(programs);
+
+        Ok(())
+    }
+
+    /// Loads the deny-list, returning `None` if it is not initialized.
+    pub fn load(relay_deny_list: &AccountInfo) -> Result<Option<Self>> {
+        if relay_deny_list.owner != &ID || relay_deny_list.data_is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self::try_deserialize(
+            &mut &relay_deny_list.try_borrow_data()?[..],
+        )?))
+    }
+
+    /// Rejects relayed instructions invoking a built-in or a guardian-denied program.
+    pub fn check_program(deny_list: Option<&Self>, program_id: &Pubkey) -> Result<()> {
+        let denied = DENIED_RELAY_PROGRAMS.contains(program_id)
+            || deny_list.is_some_and(|deny_list| {
+                deny_list.programs[..deny_list.program_count as usize].contains(program_id)
+            });
+        require!(!denied, BridgeError::DeniedRelayProgram);
+        Ok(())
+    }
+}
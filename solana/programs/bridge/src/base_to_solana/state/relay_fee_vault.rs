@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Bridge-held vault paying the relay fees prepaid by the Base senders of `WithRelayFee` messages
+/// to the relayers executing them. Funded by oracle-attested settlements of the fees collected on
+/// Base; the lamports above its rent-exempt minimum are available for payouts.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct RelayFeeVault {
+    /// Nonce of the latest applied settlement. Settlements are applied in order starting at 1.
+    pub settlement_nonce: u64,
+    /// Total lamports deposited by settlements.
+    pub total_settled: u64,
+    /// Total lamports paid to relayers.
+    pub total_paid: u64,
+    /// Total relay fees left unpaid because the vault was short of funds.
+    pub total_unpaid: u64,
+}
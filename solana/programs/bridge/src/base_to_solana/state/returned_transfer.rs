@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+/// Marker recording that the tokens of a Solana → Base transfer were returned to Solana.
+/// Derived from the original outgoing message nonce so that a transfer is returned at most once.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct ReturnedTransfer {
+    /// Nonce of the returned outgoing message.
+    pub outgoing_nonce: u64,
+}
@@ -23,7 +23,7 @@
 use anchor_lang::prelude::*;
 
 #[account]
-#[derive(InitSpace)]
+#[derive(Debug, InitSpace)]
 pub struct Signers {
     // Static list of partner signers, max_len 20 to facilitate max of 4 concurrent validator rotations
     // at regular operating capacity of 16 validators, while capping heap usage to 800b
@@ -0,0 +1,56 @@
+//! Generates the `relayMessages` calldata of representative Solana to Base messages, for the Base
+//! contract tests to relay exactly what the Solana programs emit.
+//!
+//! Usage, from the `solana` directory:
+//!
+//! ```sh
+//! cargo run -p bridge --features fixtures --bin fixtures > ../golden/relay_messages.json
+//! ```
+
+use bridge::fixtures::{
+    fixtures, incoming_message, inner_message_hash, message_hash, relay_messages_calldata,
+    FIXTURE_GAS_LIMIT,
+};
+use serde_json::{json, Value};
+
+fn hex(bytes: &[u8]) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+fn main() {
+    let fixtures = fixtures();
+
+    let messages: Vec<_> = fixtures
+        .iter()
+        .map(|f| incoming_message(&f.outgoing_message_pubkey, &f.message, FIXTURE_GAS_LIMIT))
+        .collect();
+
+    let vectors: Vec<Value> = fixtures
+        .iter()
+        .zip(&messages)
+        .map(|(f, message)| {
+            let inner_hash = inner_message_hash(&f.message.sender, message.ty, &message.data);
+            json!({
+                "name": f.name,
+                "outgoing_message_pubkey": hex(f.outgoing_message_pubkey.as_ref()),
+                "nonce": message.nonce,
+                "sender": hex(f.message.sender.as_ref()),
+                "gas_limit": message.gasLimit,
+                "ty": message.ty,
+                "data": hex(&message.data),
+                "message_hash": hex(&message_hash(
+                    message.nonce,
+                    &f.outgoing_message_pubkey.to_bytes(),
+                    &inner_hash,
+                )),
+                "calldata": hex(&relay_messages_calldata(vec![message.clone()])),
+            })
+        })
+        .collect();
+
+    let output = json!({
+        "vectors": vectors,
+        "batch_calldata": hex(&relay_messages_calldata(messages)),
+    });
+    println!("{}", serde_json::to_string_pretty(&output).unwrap());
+}
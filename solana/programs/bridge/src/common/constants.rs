@@ -7,10 +7,102 @@ pub const BRIDGE_SEED: &[u8] = b"bridge";
 #[constant]
 pub const SOL_VAULT_SEED: &[u8] = b"sol_vault";
 #[constant]
+pub const SOL_VAULT_STATE_SEED: &[u8] = b"sol_vault_state";
+#[constant]
 pub const TOKEN_VAULT_SEED: &[u8] = b"token_vault";
 #[constant]
+pub const TOKEN_DEPOSITS_SEED: &[u8] = b"token_deposits";
+#[constant]
 pub const WRAPPED_TOKEN_SEED: &[u8] = b"wrapped_token";
 #[constant]
+pub const ADDRESS_BOOK_SEED: &[u8] = b"address_book";
+#[constant]
+pub const GAS_FEE_ESCROW_SEED: &[u8] = b"gas_fee_escrow";
+#[constant]
+pub const PROTOCOL_FEE_CONFIG_SEED: &[u8] = b"protocol_fee_config";
+#[constant]
+pub const MINT_POLICY_SEED: &[u8] = b"mint_policy";
+#[constant]
+pub const MINT_ALLOW_LIST_SEED: &[u8] = b"mint_allow_list";
+#[constant]
+pub const DEX_ROUTER_LIST_SEED: &[u8] = b"dex_router_list";
+#[constant]
+pub const EMERGENCY_EXIT_SEED: &[u8] = b"emergency_exit";
+#[constant]
+pub const LIABILITY_ROOT_SEED: &[u8] = b"liability_root";
+#[constant]
+pub const CONFIG_HISTORY_SEED: &[u8] = b"config_history";
+#[constant]
+pub const WRAPPED_TOKEN_SUPPLY_CAP_SEED: &[u8] = b"wrapped_token_supply_cap";
+#[constant]
+pub const WRAPPED_TOKEN_CONFIRMATION_SEED: &[u8] = b"wrapped_token_confirmation";
+#[constant]
+pub const VAULT_DECIMALS_SEED: &[u8] = b"vault_decimals";
+#[constant]
+pub const INSURANCE_FUND_SEED: &[u8] = b"insurance_fund";
+#[constant]
+pub const PAYER_RATE_LIMIT_CONFIG_SEED: &[u8] = b"payer_rate_limit_config";
+#[constant]
 pub const MAX_PARTNER_VALIDATOR_THRESHOLD: u8 = 5;
 #[constant]
 pub const MAX_SIGNER_COUNT: u8 = 16;
+#[constant]
+pub const MAX_EMERGENCY_SIGNER_COUNT: u8 = 4;
+/// Maximum number of oracle signatures accepted by an instruction: a full Base oracle signer set
+/// plus as many partner signers.
+#[constant]
+pub const MAX_SIGNATURE_COUNT: u8 = 2 * MAX_SIGNER_COUNT;
+#[constant]
+pub const MAX_ADDRESS_BOOK_TOKENS: u8 = 16;
+#[constant]
+pub const MAX_PROTOCOL_FEE_OVERRIDES: u8 = 16;
+#[constant]
+pub const MAX_PROTOCOL_FEE_BPS: u16 = 1_000;
+#[constant]
+pub const MAX_ALLOWED_FREEZE_AUTHORITIES: u8 = 16;
+#[constant]
+pub const MAX_ALLOWED_MINTS: u8 = 32;
+#[constant]
+pub const MAX_DEX_ROUTERS: u8 = 16;
+#[constant]
+pub const MAX_RATE_LIMIT_EXEMPT_PAYERS: u8 = 16;
+#[constant]
+pub const MAX_OUTFLOW_LIMITS: u8 = 8;
+#[constant]
+pub const MAX_FINALIZATION_THRESHOLDS: u8 = 8;
+/// Maximum number of mountains of the outbox MMR, bounding it to `2^MAX_OUTBOX_PEAKS - 1` messages.
+#[constant]
+pub const MAX_OUTBOX_PEAKS: u8 = 32;
+/// Delay between `enable_emergency_exit` and the first emergency withdrawal.
+#[constant]
+pub const EMERGENCY_EXIT_TIMELOCK_SECONDS: i64 = 30 * 24 * 60 * 60;
+/// Delay between `request_insurance_payout` and the payout.
+#[constant]
+pub const INSURANCE_PAYOUT_TIMELOCK_SECONDS: i64 = 7 * 24 * 60 * 60;
+#[constant]
+pub const MAX_INSURANCE_SHARE_BPS: u16 = 5_000;
+#[constant]
+pub const CONFIG_HISTORY_LEN: u64 = 64;
+#[constant]
+pub const BRIDGE_LAYOUT_VERSION: u8 = 16;
+
+// Defaults applied by `initialize_devnet_defaults`: the EIP-1559 pricing, gas pricing, output
+// root interval and call buffer size of devnet deployments.
+#[constant]
+pub const DEVNET_GAS_TARGET: u64 = 5_000_000;
+#[constant]
+pub const DEVNET_ADJUSTMENT_DENOMINATOR: u64 = 2;
+#[constant]
+pub const DEVNET_WINDOW_DURATION_SECONDS: u64 = 1;
+#[constant]
+pub const DEVNET_MINIMUM_BASE_FEE: u64 = 1;
+#[constant]
+pub const DEVNET_GAS_COST_SCALER: u64 = 1_000_000;
+#[constant]
+pub const DEVNET_GAS_COST_SCALER_DP: u64 = 1_000_000;
+#[constant]
+pub const DEVNET_GAS_PER_CALL: u64 = 100_000;
+#[constant]
+pub const DEVNET_BLOCK_INTERVAL_REQUIREMENT: u64 = 300;
+#[constant]
+pub const DEVNET_MAX_CALL_BUFFER_SIZE: u64 = 8 * 1024;
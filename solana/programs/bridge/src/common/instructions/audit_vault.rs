@@ -0,0 +1,210 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+
+use crate::{
+    common::{TokenDeposits, SOL_VAULT_SEED, TOKEN_DEPOSITS_SEED},
+    solana_to_base::NATIVE_SOL_PUBKEY,
+    AuditReport, ID,
+};
+
+/// Accounts for the permissionless audit_vault instruction that compares a vault balance with
+/// the amount recorded in its `TokenDeposits` accounting.
+#[derive(Accounts)]
+pub struct AuditVault<'info> {
+    /// The audited vault: either the SOL vault or an SPL token vault.
+    /// CHECK: Authenticated by the `token_deposits` PDA which only exists for bridge vaults.
+    pub vault: UncheckedAccount<'info>,
+
+    /// The accounting account of the audited vault.
+    #[account(seeds = [TOKEN_DEPOSITS_SEED, vault.key().as_ref()], bump)]
+    pub token_deposits: Account<'info, TokenDeposits>,
+}
+
+/// Emits an `AuditReport` with the expected (accounted) and actual balances of the vault.
+/// The SOL vault balance is its lamports above the rent-exempt minimum funded by
+/// `init_sol_vault`; SPL token vault balances are their token amount.
+pub fn audit_vault_handler(ctx: Context<AuditVault>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let (_, actual_amount) = vault_holdings(vault)?;
+    let expected_amount = ctx.accounts.token_deposits.locked_amount;
+
+    emit!(AuditReport {
+        vault: vault.key(),
+        expected_amount,
+        actual_amount,
+        discrepancy: actual_amount as i128 - expected_amount as i128,
+    });
+
+    Ok(())
+}
+
+/// Returns the token held by a bridge vault (`NATIVE_SOL_PUBKEY` for the SOL vault) and its
+/// balance: the lamports of the SOL vault above its rent-exempt minimum, or the token amount of
+/// an SPL token vault.
+pub fn vault_holdings(vault: &AccountInfo) -> Result<(Pubkey, u64)> {
+    let (sol_vault, _) = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID);
+
+    if vault.key() == sol_vault {
+        let balance = vault
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(0));
+        Ok((NATIVE_SOL_PUBKEY, balance))
+    } else {
+        let token_account = TokenAccount::try_deserialize(&mut &vault.try_borrow_data()?[..])?;
+        Ok((token_account.mint, token_account.amount))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::{AuditVault as AuditVaultIx, BridgeSol as BridgeSolIx},
+        test_utils::{
+            create_outgoing_message, dex_router_list_pda, gas_fee_escrow_pda, insurance_fund_pda,
+            payer_rate_limit_config_pda, payer_rate_limit_pda, protocol_fee_config_pda,
+            setup_bridge, sol_vault_state_pda, token_deposits_pda, SetupBridgeResult,
+            TEST_GAS_FEE_RECEIVER, TEST_PROTOCOL_FEE_TREASURY,
+        },
+    };
+
+    fn bridge_sol(svm: &mut LiteSVM, payer: &Keypair, bridge_pda: Pubkey, amount: u64) {
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+
+        let accounts = accounts::BridgeSol {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
+            sol_vault_state: sol_vault_state_pda(),
+            sol_vault,
+            token_deposits: token_deposits_pda(&sol_vault),
+            bridge: bridge_pda,
+            outgoing_message,
+            system_program: system_program::ID,
+            memo_program: None,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeSolIx {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                amount,
+                call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer, &from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+    }
+
+    fn audit_vault(svm: &mut LiteSVM, payer: &Keypair, vault: Pubkey) -> Vec<String> {
+        let accounts = accounts::AuditVault {
+            vault,
+            token_deposits: token_deposits_pda(&vault),
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: AuditVaultIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("audit_vault should succeed")
+            .logs
+    }
+
+    #[test]
+    fn test_audit_vault_tracks_sol_deposits() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let amount = LAMPORTS_PER_SOL;
+        bridge_sol(&mut svm, &payer, bridge_pda, amount);
+
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        let token_deposits = svm.get_account(&token_deposits_pda(&sol_vault)).unwrap();
+        let token_deposits = TokenDeposits::try_deserialize(&mut &token_deposits.data[..]).unwrap();
+        assert_eq!(token_deposits.vault, sol_vault);
+        assert_eq!(token_deposits.locked_amount, amount);
+        assert_eq!(
+            svm.get_account(&sol_vault).unwrap().lamports,
+            svm.minimum_balance_for_rent_exemption(0) + token_deposits.locked_amount
+        );
+
+        let logs = audit_vault(&mut svm, &payer, sol_vault);
+        assert!(logs.iter().any(|log| log.starts_with("Program data:")));
+    }
+
+    #[test]
+    fn test_audit_vault_without_deposits_fails() {
+        let SetupBridgeResult { mut svm, payer, .. } = setup_bridge();
+
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        let accounts = accounts::AuditVault {
+            vault: sol_vault,
+            token_deposits: token_deposits_pda(&sol_vault),
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: AuditVaultIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(error_string.contains("AccountNotInitialized"));
+    }
+}
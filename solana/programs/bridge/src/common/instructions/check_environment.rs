@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::{program::Bridge as BridgeProgram, BridgeError, Environment, ENVIRONMENT};
+
+/// Accounts struct for the check_environment view instruction. Only the bridge program itself is
+/// required, as Anchor cannot generate the CPI client of an instruction without accounts.
+#[derive(Accounts)]
+pub struct CheckEnvironment<'info> {
+    /// The bridge program
+    pub program: Program<'info, BridgeProgram>,
+}
+
+/// Fails unless the program was built for `expected` and is deployed at its program ID.
+pub fn check_environment_handler(
+    ctx: Context<CheckEnvironment>,
+    expected: Environment,
+) -> Result<()> {
+    require!(ENVIRONMENT == expected, BridgeError::EnvironmentMismatch);
+    require_keys_eq!(
+        *ctx.program_id,
+        expected.program_id(),
+        BridgeError::EnvironmentMismatch
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::CheckEnvironment as CheckEnvironmentIx,
+        test_utils::{setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    #[test]
+    fn test_check_environment() {
+        let SetupBridgeResult { mut svm, payer, .. } = setup_bridge();
+
+        let mut check = |expected: Environment| {
+            let ix = Instruction {
+                program_id: ID,
+                accounts: accounts::CheckEnvironment { program: ID }.to_account_metas(None),
+                data: CheckEnvironmentIx { expected }.data(),
+            };
+            let tx = Transaction::new(
+                &[&payer],
+                Message::new(&[ix], Some(&payer.pubkey())),
+                svm.latest_blockhash(),
+            );
+            svm.send_transaction(tx)
+                .map(|_| ())
+                .map_err(|e| format!("{:?}", e))
+        };
+
+        check(Environment::Localnet).expect("Failed to check the compiled environment");
+
+        let error = check(Environment::DevnetAlpha).unwrap_err();
+        assert!(
+            error.contains("EnvironmentMismatch"),
+            "Expected EnvironmentMismatch error, got: {}",
+            error
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{
+        bridge::Bridge, record_config_change, AddressBook, AddressBookToken, ConfigHistory,
+        ConfigParam, ADDRESS_BOOK_SEED, BRIDGE_SEED, CONFIG_HISTORY_SEED, DISCRIMINATOR_LEN,
+    },
+    BridgeError,
+};
+
+/// Accounts struct for the guardian-only set_address_book instruction.
+/// The address book is created on first use.
+#[derive(Accounts)]
+pub struct SetAddressBook<'info> {
+    /// The guardian account authorized to maintain the address book.
+    /// Pays for the address book creation.
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The address book account
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        seeds = [ADDRESS_BOOK_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + AddressBook::INIT_SPACE,
+    )]
+    pub address_book: Account<'info, AddressBook>,
+
+    /// Log recording the configuration change
+    #[account(mut, seeds = [CONFIG_HISTORY_SEED], bump)]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+
+    /// System program required for creating the address book account
+    pub system_program: Program<'info, System>,
+}
+
+/// Set or update the canonical Base addresses stored in the address book.
+pub fn set_address_book_handler(
+    ctx: Context<SetAddressBook>,
+    bridge: [u8; 20],
+    relayer_orchestrator: [u8; 20],
+    tokens: Vec<AddressBookToken>,
+) -> Result<()> {
+    let address_book = &mut ctx.accounts.address_book;
+    let old_book = ConfigHistory::encode_value(&**address_book)?;
+    address_book.set(bridge, relayer_orchestrator, &tokens)?;
+    let new_book = ConfigHistory::encode_value(&**address_book)?;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::AddressBook,
+        &old_book,
+        &new_book,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::SetAddressBook as SetAddressBookIx,
+        solana_to_base::NATIVE_SOL_PUBKEY,
+        test_utils::{address_book_pda, config_history_pda, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn set_address_book(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        bridge: [u8; 20],
+        tokens: Vec<AddressBookToken>,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetAddressBook {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                address_book: address_book_pda(),
+                config_history: config_history_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: SetAddressBookIx {
+                bridge,
+                relayer_orchestrator: [2u8; 20],
+                tokens,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn get_address_book(svm: &LiteSVM) -> AddressBook {
+        let account = svm.get_account(&address_book_pda()).unwrap();
+        AddressBook::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    #[test]
+    fn test_set_address_book_creates_and_updates() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let sol = AddressBookToken {
+            local_token: NATIVE_SOL_PUBKEY,
+            remote_token: [3u8; 20],
+        };
+        let usdc = AddressBookToken {
+            local_token: Pubkey::new_unique(),
+            remote_token: [4u8; 20],
+        };
+
+        set_address_book(&mut svm, &guardian, bridge_pda, [1u8; 20], vec![sol, usdc]).unwrap();
+
+        let address_book = get_address_book(&svm);
+        assert_eq!(address_book.bridge().unwrap(), [1u8; 20]);
+        assert_eq!(address_book.relayer_orchestrator, [2u8; 20]);
+        assert_eq!(address_book.token_count, 2);
+        assert_eq!(
+            address_book.remote_token(&NATIVE_SOL_PUBKEY),
+            Some([3u8; 20])
+        );
+        assert_eq!(
+            address_book.remote_token(&usdc.local_token),
+            Some([4u8; 20])
+        );
+
+        // Updating replaces the previous content
+        svm.expire_blockhash();
+        set_address_book(&mut svm, &guardian, bridge_pda, [5u8; 20], vec![sol]).unwrap();
+
+        let address_book = get_address_book(&svm);
+        assert_eq!(address_book.bridge().unwrap(), [5u8; 20]);
+        assert_eq!(address_book.token_count, 1);
+        assert_eq!(address_book.remote_token(&usdc.local_token), None);
+        assert_eq!(address_book.tokens[1], AddressBookToken::default());
+    }
+
+    #[test]
+    fn test_set_address_book_unauthorized_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let fake_guardian = Keypair::new();
+        svm.airdrop(&fake_guardian.pubkey(), LAMPORTS_PER_SOL)
+            .unwrap();
+
+        let error =
+            set_address_book(&mut svm, &fake_guardian, bridge_pda, [1u8; 20], vec![]).unwrap_err();
+        assert!(
+            error.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_set_address_book_duplicate_token_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let sol = AddressBookToken {
+            local_token: NATIVE_SOL_PUBKEY,
+            remote_token: [3u8; 20],
+        };
+
+        let error = set_address_book(&mut svm, &guardian, bridge_pda, [1u8; 20], vec![sol, sol])
+            .unwrap_err();
+        assert!(
+            error.contains("DuplicateAddressBookToken"),
+            "Expected DuplicateAddressBookToken error, got: {}",
+            error
+        );
+    }
+}
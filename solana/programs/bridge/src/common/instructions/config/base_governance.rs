@@ -0,0 +1,182 @@
+use anchor_lang::prelude::*;
+
+use crate::common::{
+    record_config_change, ConfigParam, SetBridgeConfigFromBaseGovernance,
+    SetBridgeConfigFromUpgradeAuthority,
+};
+
+/// Designate the governance contract on Base allowed to update the critical configuration through
+/// relayed messages. Its control is not enforced until it relays `set_governance_mode`.
+/// Only the upgrade authority can call this function, and only while the control of the current
+/// governance contract is not enforced.
+pub fn set_base_governance_handler(
+    ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
+    governance: [u8; 20],
+) -> Result<()> {
+    let base_governance = &mut ctx.accounts.bridge.base_governance;
+    base_governance.check_not_enforced()?;
+    let old_governance = base_governance.clone();
+    base_governance.governance = governance;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.upgrade_authority,
+        ConfigParam::BaseGovernance,
+        &old_governance,
+        &ctx.accounts.bridge.base_governance,
+    )
+}
+
+/// Enforce (or release) the control of the Base governance contract over the critical
+/// configuration, completing the handshake started by `set_base_governance`.
+/// Only reachable through a message relayed from the Base governance contract
+pub fn set_governance_mode_handler(
+    ctx: Context<SetBridgeConfigFromBaseGovernance>,
+    enforced: bool,
+) -> Result<()> {
+    let base_governance = &mut ctx.accounts.bridge.base_governance;
+    let old_governance = base_governance.clone();
+    base_governance.enforced = enforced;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.governance_cpi_authority,
+        ConfigParam::BaseGovernance,
+        &old_governance,
+        &ctx.accounts.bridge.base_governance,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{bpf_loader_upgradeable, instruction::Instruction},
+        InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::bridge::{BaseGovernance, Bridge},
+        instruction::{SetBaseGovernance as SetBaseGovernanceIx, SetGovernanceMode},
+        test_utils::{
+            config_history_pda, relay_governance_call, set_mock_base_governance, setup_bridge,
+            SetupBridgeResult,
+        },
+        ID,
+    };
+
+    const GOVERNANCE: [u8; 20] = [7u8; 20];
+
+    fn set_base_governance(
+        svm: &mut LiteSVM,
+        upgrade_authority: &Keypair,
+        bridge_pda: Pubkey,
+        governance: [u8; 20],
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromUpgradeAuthority {
+                upgrade_authority: upgrade_authority.pubkey(),
+                bridge: bridge_pda,
+                program_data: Pubkey::find_program_address(
+                    &[ID.as_ref()],
+                    &bpf_loader_upgradeable::ID,
+                )
+                .0,
+                program: ID,
+                config_history: config_history_pda(),
+            }
+            .to_account_metas(None),
+            data: SetBaseGovernanceIx { governance }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[upgrade_authority],
+            Message::new(&[ix], Some(&upgrade_authority.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn read_base_governance(svm: &LiteSVM, bridge_pda: Pubkey) -> BaseGovernance {
+        let account = svm.get_account(&bridge_pda).unwrap();
+        Bridge::try_deserialize(&mut &account.data[..])
+            .unwrap()
+            .base_governance
+    }
+
+    #[test]
+    fn test_base_governance_handshake() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // The upgrade authority designates the governance contract
+        set_base_governance(&mut svm, &payer, bridge_pda, GOVERNANCE).unwrap();
+        assert_eq!(
+            read_base_governance(&svm, bridge_pda),
+            BaseGovernance {
+                governance: GOVERNANCE,
+                enforced: false,
+            }
+        );
+
+        // The governance contract confirms through a relayed message
+        relay_governance_call(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            GOVERNANCE,
+            SetGovernanceMode { enforced: true }.data(),
+        )
+        .expect("Failed to relay the governance mode");
+        assert!(read_base_governance(&svm, bridge_pda).enforced);
+
+        // The upgrade authority can no longer replace it
+        svm.expire_blockhash();
+        let error = set_base_governance(&mut svm, &payer, bridge_pda, [8u8; 20]).unwrap_err();
+        assert!(
+            error.contains("GovernedByBase"),
+            "Expected GovernedByBase error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_set_governance_mode_from_other_sender_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        set_mock_base_governance(&mut svm, bridge_pda, GOVERNANCE, false);
+
+        let error = relay_governance_call(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            [8u8; 20],
+            SetGovernanceMode { enforced: true }.data(),
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error
+        );
+        assert!(!read_base_governance(&svm, bridge_pda).enforced);
+    }
+}
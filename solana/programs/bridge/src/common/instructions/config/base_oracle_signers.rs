@@ -1,19 +1,49 @@
 use anchor_lang::prelude::*;
 
-use crate::common::{BaseOracleConfig, SetBridgeConfigFromUpgradeAuthority};
+use crate::common::{
+    record_config_change, BaseOracleConfig, ConfigParam, SetBridgeConfigFromBaseGovernance,
+    SetBridgeConfigFromUpgradeAuthority,
+};
 
 /// Set or update the oracle signer configuration.
 ///
 /// Updates the `oracle_signers` account with a new approval `threshold` and a
 /// new list of unique EVM signer addresses. This instruction is used to rotate
 /// oracle keys or adjust the required threshold for output root attestations.
+/// Rejected once the Base governance enforces its control over the oracle signers.
 pub fn set_oracle_signers_handler(
     ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
     cfg: BaseOracleConfig,
 ) -> Result<()> {
+    ctx.accounts.bridge.base_governance.check_not_enforced()?;
     cfg.validate()?;
-    ctx.accounts.bridge.base_oracle_config = cfg;
-    Ok(())
+    let old_cfg = std::mem::replace(&mut ctx.accounts.bridge.base_oracle_config, cfg);
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.upgrade_authority,
+        ConfigParam::BaseOracleConfig,
+        &old_cfg,
+        &ctx.accounts.bridge.base_oracle_config,
+    )
+}
+
+/// Set or update the oracle signer configuration from the Base governance.
+/// Only reachable through a message relayed from the Base governance contract
+pub fn set_oracle_signers_from_governance_handler(
+    ctx: Context<SetBridgeConfigFromBaseGovernance>,
+    cfg: BaseOracleConfig,
+) -> Result<()> {
+    cfg.validate()?;
+    let old_cfg = std::mem::replace(&mut ctx.accounts.bridge.base_oracle_config, cfg);
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.governance_cpi_authority,
+        ConfigParam::BaseOracleConfig,
+        &old_cfg,
+        &ctx.accounts.bridge.base_oracle_config,
+    )
 }
 
 #[cfg(test)]
@@ -28,8 +58,11 @@ mod tests {
     use solana_transaction::Transaction;
 
     use crate::{
-        accounts, common::bridge::Bridge, instruction::SetOracleSigners, test_utils::*, ID,
-        MAX_SIGNER_COUNT,
+        accounts,
+        common::bridge::Bridge,
+        instruction::{SetOracleSigners, SetOracleSignersFromGovernance},
+        test_utils::*,
+        ID, MAX_SIGNER_COUNT,
     };
 
     /// Helper to create a BaseOracleConfig for testing
@@ -64,6 +97,7 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            config_history: config_history_pda(),
         }
         .to_account_metas(None);
 
@@ -111,6 +145,7 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            config_history: config_history_pda(),
         }
         .to_account_metas(None);
 
@@ -161,6 +196,7 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            config_history: config_history_pda(),
         }
         .to_account_metas(None);
 
@@ -212,6 +248,7 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            config_history: config_history_pda(),
         }
         .to_account_metas(None);
 
@@ -253,4 +290,63 @@ mod tests {
             error_string
         );
     }
+
+    #[test]
+    fn test_set_oracle_signers_from_enforced_governance() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let governance = [7u8; 20];
+        set_mock_base_governance(&mut svm, bridge_pda, governance, true);
+
+        relay_governance_call(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            governance,
+            SetOracleSignersFromGovernance {
+                cfg: base_oracle_config(2, 2),
+            }
+            .data(),
+        )
+        .expect("Failed to relay the oracle signers");
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(bridge.base_oracle_config, base_oracle_config(2, 2));
+
+        // The upgrade authority can no longer set them
+        let (program_data_pda, _) =
+            Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID);
+        let accounts = accounts::SetBridgeConfigFromUpgradeAuthority {
+            upgrade_authority: payer.pubkey(),
+            bridge: bridge_pda,
+            program_data: program_data_pda,
+            program: ID,
+            config_history: config_history_pda(),
+        }
+        .to_account_metas(None);
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: SetOracleSigners {
+                cfg: base_oracle_config(1, 1),
+            }
+            .data(),
+        };
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let error_string = format!("{:?}", svm.send_transaction(tx).unwrap_err());
+        assert!(
+            error_string.contains("GovernedByBase"),
+            "Expected GovernedByBase error, got: {}",
+            error_string
+        );
+    }
 }
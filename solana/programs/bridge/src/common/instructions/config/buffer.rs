@@ -1,15 +1,57 @@
 use anchor_lang::prelude::*;
 
-use crate::common::SetBridgeConfigFromGuardian;
+use crate::common::{record_config_change, ConfigParam, SetBridgeConfigFromGuardian};
 
 /// Set the maximum call buffer size
 pub fn set_max_call_buffer_size_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_size: u64,
 ) -> Result<()> {
+    let old_size = ctx.accounts.bridge.buffer_config.max_call_buffer_size;
     ctx.accounts.bridge.buffer_config.max_call_buffer_size = new_size;
 
-    Ok(())
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::MaxCallBufferSize,
+        &old_size,
+        &new_size,
+    )
+}
+
+/// Set the idle time after which a call buffer can be expired (0 disables expiry)
+pub fn set_call_buffer_ttl_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    new_ttl_seconds: u64,
+) -> Result<()> {
+    let old_ttl_seconds = ctx.accounts.bridge.buffer_config.call_buffer_ttl_seconds;
+    ctx.accounts.bridge.buffer_config.call_buffer_ttl_seconds = new_ttl_seconds;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::CallBufferTtl,
+        &old_ttl_seconds,
+        &new_ttl_seconds,
+    )
+}
+
+/// Set the share of an expired call buffer's rent paid to the caller, in basis points
+pub fn set_expire_bounty_bps_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    new_bps: u64,
+) -> Result<()> {
+    let old_bps = ctx.accounts.bridge.buffer_config.expire_bounty_bps;
+    ctx.accounts.bridge.buffer_config.expire_bounty_bps = new_bps;
+    ctx.accounts.bridge.buffer_config.validate()?;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::ExpireBountyBps,
+        &old_bps,
+        &new_bps,
+    )
 }
 
 #[cfg(test)]
@@ -25,7 +67,7 @@ mod tests {
         accounts,
         common::bridge::Bridge,
         instruction::SetMaxCallBufferSize as SetMaxCallBufferSizeIx,
-        test_utils::{setup_bridge, SetupBridgeResult},
+        test_utils::{config_history_pda, setup_bridge, SetupBridgeResult},
         ID,
     };
 
@@ -45,6 +87,7 @@ mod tests {
         let accounts = accounts::SetBridgeConfigFromGuardian {
             bridge: bridge_pda,
             guardian: guardian.pubkey(),
+            config_history: config_history_pda(),
         }
         .to_account_metas(None);
 
@@ -95,6 +138,7 @@ mod tests {
         let accounts = accounts::SetBridgeConfigFromGuardian {
             bridge: bridge_pda,
             guardian: fake_guardian.pubkey(), // Wrong guardian
+            config_history: config_history_pda(),
         }
         .to_account_metas(None);
 
@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{
+        bridge::Bridge, record_config_change, ConfigHistory, ConfigParam, DexRouterList,
+        BRIDGE_SEED, CONFIG_HISTORY_SEED, DEX_ROUTER_LIST_SEED, DISCRIMINATOR_LEN,
+    },
+    BridgeError, DexRouterListUpdated,
+};
+
+/// Accounts struct for the guardian-only set_dex_router instruction.
+/// The DEX router list is created on first use.
+#[derive(Accounts)]
+pub struct SetDexRouter<'info> {
+    /// The guardian account authorized to maintain the DEX router list.
+    /// Pays for the DEX router list creation.
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The DEX router list account
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        seeds = [DEX_ROUTER_LIST_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + DexRouterList::INIT_SPACE,
+    )]
+    pub dex_router_list: Account<'info, DexRouterList>,
+
+    /// Log recording the configuration change
+    #[account(mut, seeds = [CONFIG_HISTORY_SEED], bump)]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+
+    /// System program required for creating the DEX router list account
+    pub system_program: Program<'info, System>,
+}
+
+/// Register `router`, or unregister it, as a Base DEX router whose calls require a swap guard.
+pub fn set_dex_router_handler(
+    ctx: Context<SetDexRouter>,
+    router: [u8; 20],
+    registered: bool,
+) -> Result<()> {
+    let dex_router_list = &mut ctx.accounts.dex_router_list;
+    let old_router_list = ConfigHistory::encode_value(&**dex_router_list)?;
+    if registered {
+        dex_router_list.register(router)?;
+    } else {
+        dex_router_list.unregister(&router);
+    }
+    let new_router_list = ConfigHistory::encode_value(&**dex_router_list)?;
+
+    emit!(DexRouterListUpdated { router, registered });
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::DexRouterList,
+        &old_router_list,
+        &new_router_list,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::SetDexRouter as SetDexRouterIx,
+        test_utils::{
+            config_history_pda, dex_router_list_pda, latest_config_change, setup_bridge,
+            SetupBridgeResult,
+        },
+        ID,
+    };
+
+    fn set_dex_router(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        router: [u8; 20],
+        registered: bool,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetDexRouter {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                dex_router_list: dex_router_list_pda(),
+                config_history: config_history_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: SetDexRouterIx { router, registered }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn read_dex_router_list(svm: &LiteSVM) -> DexRouterList {
+        let account = svm.get_account(&dex_router_list_pda()).unwrap();
+        DexRouterList::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    #[test]
+    fn test_set_dex_router() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let first = [1u8; 20];
+        let second = [2u8; 20];
+
+        set_dex_router(&mut svm, &guardian, bridge_pda, first, true).unwrap();
+        set_dex_router(&mut svm, &guardian, bridge_pda, second, true).unwrap();
+        set_dex_router(&mut svm, &guardian, bridge_pda, first, true).unwrap();
+
+        let dex_router_list = read_dex_router_list(&svm);
+        assert_eq!(dex_router_list.router_count, 2);
+        assert!(dex_router_list.contains(&first) && dex_router_list.contains(&second));
+
+        set_dex_router(&mut svm, &guardian, bridge_pda, first, false).unwrap();
+
+        let dex_router_list = read_dex_router_list(&svm);
+        assert_eq!(dex_router_list.router_count, 1);
+        assert_eq!(dex_router_list.routers[0], second);
+        let (_, change) = latest_config_change(&svm);
+        assert_eq!(change.param, ConfigParam::DexRouterList as u8);
+
+        let fake_guardian = Keypair::new();
+        svm.airdrop(&fake_guardian.pubkey(), LAMPORTS_PER_SOL)
+            .unwrap();
+        let error = set_dex_router(&mut svm, &fake_guardian, bridge_pda, first, true).unwrap_err();
+        assert!(
+            error.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error
+        );
+    }
+}
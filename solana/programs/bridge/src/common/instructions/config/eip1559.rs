@@ -1,14 +1,34 @@
 use anchor_lang::prelude::*;
 
-use crate::common::SetBridgeConfigFromGuardian;
+use crate::{
+    common::{
+        bridge::{Bridge, Eip1559, Eip1559Config},
+        record_config_change, ConfigParam, SetBridgeConfigFromGuardian,
+    },
+    BridgeError,
+};
 
 /// Set the minimum base fee parameter
 pub fn set_minimum_base_fee_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_fee: u64,
 ) -> Result<()> {
+    let old_fee = ctx.accounts.bridge.eip1559.config.minimum_base_fee;
     ctx.accounts.bridge.eip1559.config.minimum_base_fee = new_fee;
-    Ok(())
+    ctx.accounts
+        .bridge
+        .eip1559
+        .config
+        .validate()
+        .map_err(BridgeError::from)?;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::MinimumBaseFee,
+        &old_fee,
+        &new_fee,
+    )
 }
 
 /// Set the window duration parameter
@@ -16,9 +36,22 @@ pub fn set_window_duration_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_duration: u64,
 ) -> Result<()> {
+    let old_duration = ctx.accounts.bridge.eip1559.config.window_duration_seconds;
     ctx.accounts.bridge.eip1559.config.window_duration_seconds = new_duration;
-    ctx.accounts.bridge.eip1559.config.validate()?;
-    Ok(())
+    ctx.accounts
+        .bridge
+        .eip1559
+        .config
+        .validate()
+        .map_err(BridgeError::from)?;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::WindowDuration,
+        &old_duration,
+        &new_duration,
+    )
 }
 
 /// Set the gas target parameter
@@ -26,8 +59,56 @@ pub fn set_gas_target_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_target: u64,
 ) -> Result<()> {
+    let old_target = ctx.accounts.bridge.eip1559.config.target;
     ctx.accounts.bridge.eip1559.config.target = new_target;
-    Ok(())
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::GasTarget,
+        &old_target,
+        &new_target,
+    )
+}
+
+/// Set the maximum base fee parameter (0 disables the cap)
+pub fn set_max_base_fee_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    new_max_fee: u64,
+) -> Result<()> {
+    let old_max_fee = ctx.accounts.bridge.eip1559.config.max_base_fee;
+    ctx.accounts.bridge.eip1559.config.max_base_fee = new_max_fee;
+    ctx.accounts
+        .bridge
+        .eip1559
+        .config
+        .validate()
+        .map_err(BridgeError::from)?;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::MaxBaseFee,
+        &old_max_fee,
+        &new_max_fee,
+    )
+}
+
+/// Set the maximum per-window base fee increase, in basis points (0 disables the cap)
+pub fn set_max_window_increase_bps_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    new_bps: u64,
+) -> Result<()> {
+    let old_bps = ctx.accounts.bridge.eip1559.config.max_window_increase_bps;
+    ctx.accounts.bridge.eip1559.config.max_window_increase_bps = new_bps;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::MaxWindowIncreaseBps,
+        &old_bps,
+        &new_bps,
+    )
 }
 
 /// Set the adjustment denominator parameter
@@ -35,7 +116,230 @@ pub fn set_adjustment_denominator_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_denominator: u64,
 ) -> Result<()> {
+    let old_denominator = ctx.accounts.bridge.eip1559.config.denominator;
     ctx.accounts.bridge.eip1559.config.denominator = new_denominator;
-    ctx.accounts.bridge.eip1559.config.validate()?;
-    Ok(())
+    ctx.accounts
+        .bridge
+        .eip1559
+        .config
+        .validate()
+        .map_err(BridgeError::from)?;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::AdjustmentDenominator,
+        &old_denominator,
+        &new_denominator,
+    )
+}
+
+/// Set the EIP-1559 configuration of the transfer gas lane, pricing token transfers apart from
+/// calls. A zero target disables the lane, transfers then sharing the base fee of calls.
+/// The base fee of a newly enabled lane starts at its minimum base fee.
+pub fn set_transfer_gas_lane_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    new_config: Eip1559Config,
+) -> Result<()> {
+    let bridge: &mut Bridge = &mut ctx.accounts.bridge;
+    let transfer_gas_lane = &mut bridge.transfer_gas_lane;
+    let old_config = transfer_gas_lane.config.clone();
+
+    if new_config.target == 0 {
+        *transfer_gas_lane = Eip1559::default();
+        bridge.fee_quote_grace.previous_transfer_base_fee = 0;
+    } else {
+        new_config.validate().map_err(BridgeError::from)?;
+        if old_config.target == 0 {
+            bridge.fee_quote_grace.previous_transfer_base_fee = 0;
+            *transfer_gas_lane = Eip1559 {
+                current_base_fee: new_config.minimum_base_fee,
+                current_window_gas_used: 0,
+                window_start_time: Clock::get()?.unix_timestamp,
+                config: new_config.clone(),
+            };
+        } else {
+            transfer_gas_lane.config = new_config.clone();
+        }
+    }
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::TransferGasLane,
+        &old_config,
+        &new_config,
+    )
+}
+
+/// Set the grace period during which fee quotes remain valid after a base fee window rollover
+/// (0 disables the grace period)
+pub fn set_fee_quote_grace_period_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    new_grace_period_seconds: u64,
+) -> Result<()> {
+    let fee_quote_grace = &mut ctx.accounts.bridge.fee_quote_grace;
+    let old_grace_period_seconds = fee_quote_grace.grace_period_seconds;
+    fee_quote_grace.grace_period_seconds = new_grace_period_seconds;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::FeeQuoteGracePeriod,
+        &old_grace_period_seconds,
+        &new_grace_period_seconds,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::{
+            SetFeeQuoteGracePeriod as SetFeeQuoteGracePeriodIx,
+            SetTransferGasLane as SetTransferGasLaneIx,
+        },
+        test_utils::{
+            config_history_pda, latest_config_change, mock_clock, setup_bridge, SetupBridgeResult,
+        },
+        ID,
+    };
+
+    fn set_transfer_gas_lane(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        new_config: Eip1559Config,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromGuardian {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+                config_history: config_history_pda(),
+            }
+            .to_account_metas(None),
+            data: SetTransferGasLaneIx { new_config }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn read_transfer_gas_lane(svm: &LiteSVM, bridge_pda: Pubkey) -> Eip1559 {
+        let account = svm.get_account(&bridge_pda).unwrap();
+        Bridge::try_deserialize(&mut &account.data[..])
+            .unwrap()
+            .transfer_gas_lane
+    }
+
+    #[test]
+    fn test_set_transfer_gas_lane() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        mock_clock(&mut svm, 1_000);
+
+        let config = Eip1559Config {
+            minimum_base_fee: 50,
+            ..Eip1559Config::test_new()
+        };
+        set_transfer_gas_lane(&mut svm, &guardian, bridge_pda, config.clone()).unwrap();
+
+        assert_eq!(
+            read_transfer_gas_lane(&svm, bridge_pda),
+            Eip1559 {
+                config: config.clone(),
+                current_base_fee: 50,
+                current_window_gas_used: 0,
+                window_start_time: 1_000,
+            }
+        );
+        let (_, change) = latest_config_change(&svm);
+        assert_eq!(change.param, ConfigParam::TransferGasLane as u8);
+
+        // An invalid configuration is rejected
+        let error = set_transfer_gas_lane(
+            &mut svm,
+            &guardian,
+            bridge_pda,
+            Eip1559Config {
+                denominator: 0,
+                ..config
+            },
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("InvalidDenominator"),
+            "Expected InvalidDenominator error, got: {}",
+            error
+        );
+
+        // A zero target disables the lane
+        set_transfer_gas_lane(
+            &mut svm,
+            &guardian,
+            bridge_pda,
+            Eip1559Config {
+                target: 0,
+                ..Eip1559Config::test_new()
+            },
+        )
+        .unwrap();
+        assert_eq!(read_transfer_gas_lane(&svm, bridge_pda), Eip1559::default());
+    }
+
+    #[test]
+    fn test_set_fee_quote_grace_period() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromGuardian {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+                config_history: config_history_pda(),
+            }
+            .to_account_metas(None),
+            data: SetFeeQuoteGracePeriodIx {
+                new_grace_period_seconds: 30,
+            }
+            .data(),
+        };
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+
+        let account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(bridge.fee_quote_grace.grace_period_seconds, 30);
+        let (_, change) = latest_config_change(&svm);
+        assert_eq!(change.param, ConfigParam::FeeQuoteGracePeriod as u8);
+    }
 }
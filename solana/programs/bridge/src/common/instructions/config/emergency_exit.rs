@@ -0,0 +1,244 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{
+        bridge::Bridge, record_config_change, ConfigHistory, ConfigParam, EmergencyExit,
+        BRIDGE_SEED, CONFIG_HISTORY_SEED, DISCRIMINATOR_LEN, EMERGENCY_EXIT_SEED,
+        EMERGENCY_EXIT_TIMELOCK_SECONDS,
+    },
+    BridgeError, EmergencyExitCancelled, EmergencyExitEnabled,
+};
+
+/// Accounts struct for the guardian-only enable_emergency_exit instruction.
+#[derive(Accounts)]
+pub struct EnableEmergencyExit<'info> {
+    /// The guardian account authorized to enable the emergency exit.
+    /// Pays for the emergency exit creation.
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian, which must be paused
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The emergency exit account
+    #[account(
+        init,
+        payer = guardian,
+        seeds = [EMERGENCY_EXIT_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + EmergencyExit::INIT_SPACE,
+    )]
+    pub emergency_exit: Account<'info, EmergencyExit>,
+
+    /// Log recording the configuration change
+    #[account(mut, seeds = [CONFIG_HISTORY_SEED], bump)]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+
+    /// System program required for creating the emergency exit account
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts struct for the guardian-only cancel_emergency_exit instruction.
+#[derive(Accounts)]
+pub struct CancelEmergencyExit<'info> {
+    /// The guardian account authorized to cancel the emergency exit.
+    /// Receives the rent of the emergency exit account.
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The emergency exit account, closed by this instruction
+    #[account(mut, close = guardian, seeds = [EMERGENCY_EXIT_SEED], bump)]
+    pub emergency_exit: Account<'info, EmergencyExit>,
+
+    /// Log recording the configuration change
+    #[account(mut, seeds = [CONFIG_HISTORY_SEED], bump)]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+}
+
+/// Enable the emergency exit of a paused bridge, letting depositors withdraw the tokens locked by
+/// their unrelayed outgoing messages once `EMERGENCY_EXIT_TIMELOCK_SECONDS` have elapsed.
+pub fn enable_emergency_exit_handler(ctx: Context<EnableEmergencyExit>) -> Result<()> {
+    require!(
+        ctx.accounts.bridge.paused,
+        BridgeError::EmergencyExitRequiresPause
+    );
+
+    let enabled_at = Clock::get()?.unix_timestamp;
+    let withdrawable_at = enabled_at + EMERGENCY_EXIT_TIMELOCK_SECONDS;
+    *ctx.accounts.emergency_exit = EmergencyExit {
+        enabled_at,
+        withdrawable_at,
+    };
+
+    emit!(EmergencyExitEnabled { withdrawable_at });
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::EmergencyExit,
+        &None::<i64>,
+        &Some(withdrawable_at),
+    )
+}
+
+/// Cancel the emergency exit, e.g. before unpausing the bridge.
+pub fn cancel_emergency_exit_handler(ctx: Context<CancelEmergencyExit>) -> Result<()> {
+    let withdrawable_at = ctx.accounts.emergency_exit.withdrawable_at;
+
+    emit!(EmergencyExitCancelled { withdrawable_at });
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::EmergencyExit,
+        &Some(withdrawable_at),
+        &None::<i64>,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, system_program, InstructionData};
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::{
+            CancelEmergencyExit as CancelEmergencyExitIx,
+            EnableEmergencyExit as EnableEmergencyExitIx,
+        },
+        test_utils::{
+            config_history_pda, emergency_exit_pda, mock_clock, set_mock_pause, setup_bridge,
+            SetupBridgeResult,
+        },
+        ID,
+    };
+
+    fn enable_emergency_exit(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::EnableEmergencyExit {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                emergency_exit: emergency_exit_pda(),
+                config_history: config_history_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: EnableEmergencyExitIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_enable_and_cancel_emergency_exit() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        set_mock_pause(&mut svm, bridge_pda, true);
+        mock_clock(&mut svm, 1_000);
+        enable_emergency_exit(&mut svm, &guardian, bridge_pda).unwrap();
+
+        let account = svm.get_account(&emergency_exit_pda()).unwrap();
+        let emergency_exit = EmergencyExit::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(
+            emergency_exit,
+            EmergencyExit {
+                enabled_at: 1_000,
+                withdrawable_at: 1_000 + EMERGENCY_EXIT_TIMELOCK_SECONDS,
+            }
+        );
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::CancelEmergencyExit {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                emergency_exit: emergency_exit_pda(),
+                config_history: config_history_pda(),
+            }
+            .to_account_metas(None),
+            data: CancelEmergencyExitIx {}.data(),
+        };
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+
+        let account = svm.get_account(&emergency_exit_pda()).unwrap();
+        assert_eq!(account.lamports, 0);
+        assert_eq!(account.owner, system_program::ID);
+    }
+
+    #[test]
+    fn test_enable_emergency_exit_requires_pause() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let error = enable_emergency_exit(&mut svm, &guardian, bridge_pda).unwrap_err();
+        assert!(
+            error.contains("EmergencyExitRequiresPause"),
+            "Expected EmergencyExitRequiresPause error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_enable_emergency_exit_unauthorized_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        set_mock_pause(&mut svm, bridge_pda, true);
+        let error = enable_emergency_exit(&mut svm, &payer, bridge_pda).unwrap_err();
+        assert!(
+            error.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use anchor_lang::prelude::*;
+use fee_config::FeeConfigV2;
+
+use crate::{
+    common::{
+        bridge::Bridge, record_config_change, ConfigHistory, ConfigParam, BRIDGE_SEED,
+        CONFIG_HISTORY_SEED,
+    },
+    BridgeError,
+};
+
+/// Accounts struct for the guardian-only set_fee_config instruction.
+#[derive(Accounts)]
+#[instruction(fee_config: FeeConfigV2)]
+pub struct SetFeeConfig<'info> {
+    /// The bridge account containing configuration
+    #[account(
+        mut,
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The guardian account authorized to update configuration
+    pub guardian: Signer<'info>,
+
+    /// The gas fee receiver of the new configuration. Must be a system account.
+    /// CHECK: Checked against `fee_config.gas_fee_receiver` and validated in the handler.
+    #[account(address = fee_config.gas_fee_receiver @ BridgeError::IncorrectGasFeeReceiver)]
+    pub gas_fee_receiver: AccountInfo<'info>,
+
+    /// Log recording the configuration change
+    #[account(mut, seeds = [CONFIG_HISTORY_SEED], bump)]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+}
+
+/// Set every pricing parameter shared with the relayer program at once
+pub fn set_fee_config_handler(ctx: Context<SetFeeConfig>, fee_config: FeeConfigV2) -> Result<()> {
+    let bridge = &mut ctx.accounts.bridge;
+    let old_fee_config = bridge.fee_config();
+    bridge.apply_fee_config(&fee_config);
+    bridge
+        .eip1559
+        .config
+        .validate()
+        .map_err(BridgeError::from)?;
+    bridge.gas_config.validate()?;
+    bridge
+        .gas_config
+        .validate_gas_fee_receiver(&ctx.accounts.gas_fee_receiver)?;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::FeeConfig,
+        &old_fee_config,
+        &fee_config,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::SetFeeConfig as SetFeeConfigIx,
+        test_utils::{config_history_pda, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn new_fee_config(gas_fee_receiver: Pubkey) -> FeeConfigV2 {
+        FeeConfigV2 {
+            target: 10_000_000,
+            denominator: 4,
+            window_duration_seconds: 10,
+            minimum_base_fee: 5,
+            max_base_fee: 1_000,
+            max_window_increase_bps: 2_500,
+            gas_cost_scaler: 2_000_000,
+            gas_cost_scaler_dp: 10u64.pow(7),
+            gas_fee_receiver,
+        }
+    }
+
+    fn set_fee_config(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        fee_config: FeeConfigV2,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetFeeConfig {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+                gas_fee_receiver: fee_config.gas_fee_receiver,
+                config_history: config_history_pda(),
+            }
+            .to_account_metas(None),
+            data: SetFeeConfigIx { fee_config }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_set_fee_config_success() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let new_receiver = Pubkey::new_unique();
+        svm.airdrop(&new_receiver, LAMPORTS_PER_SOL).unwrap();
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let old_gas_per_call = Bridge::try_deserialize(&mut &bridge_account.data[..])
+            .unwrap()
+            .gas_config
+            .gas_per_call;
+
+        let fee_config = new_fee_config(new_receiver);
+        set_fee_config(&mut svm, &guardian, bridge_pda, fee_config.clone())
+            .expect("Failed to set fee config");
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(bridge.fee_config(), fee_config);
+        assert_eq!(bridge.gas_config.gas_per_call, old_gas_per_call);
+    }
+
+    #[test]
+    fn test_set_fee_config_invalid_denominator_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let new_receiver = Pubkey::new_unique();
+        svm.airdrop(&new_receiver, LAMPORTS_PER_SOL).unwrap();
+
+        let fee_config = FeeConfigV2 {
+            denominator: 0,
+            ..new_fee_config(new_receiver)
+        };
+        let error = set_fee_config(&mut svm, &guardian, bridge_pda, fee_config).unwrap_err();
+        assert!(
+            error.contains("InvalidDenominator"),
+            "Expected InvalidDenominator error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_set_fee_config_non_guardian_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let fake_guardian = Keypair::new();
+        svm.airdrop(&fake_guardian.pubkey(), LAMPORTS_PER_SOL)
+            .unwrap();
+        let new_receiver = Pubkey::new_unique();
+        svm.airdrop(&new_receiver, LAMPORTS_PER_SOL).unwrap();
+
+        let error = set_fee_config(
+            &mut svm,
+            &fake_guardian,
+            bridge_pda,
+            new_fee_config(new_receiver),
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+
+use crate::common::{
+    bridge::FinalizationThreshold, record_config_change, ConfigHistory, ConfigParam,
+    SetBridgeConfigFromGuardian,
+};
+
+/// Set the delay and the per-token thresholds of the finalization delay holding high-value
+/// incoming transfers between `prove_message` and `relay_message`.
+/// Only the guardian can call this function
+pub fn set_finalization_delay_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    delay_seconds: u64,
+    thresholds: Vec<FinalizationThreshold>,
+) -> Result<()> {
+    let finalization_delay = &mut ctx.accounts.bridge.finalization_delay;
+    let old_delay = ConfigHistory::encode_value(&*finalization_delay)?;
+    finalization_delay.set(delay_seconds, &thresholds)?;
+    let new_delay = ConfigHistory::encode_value(&*finalization_delay)?;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::FinalizationDelay,
+        &old_delay,
+        &new_delay,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::bridge::Bridge,
+        instruction::SetFinalizationDelay as SetFinalizationDelayIx,
+        solana_to_base::NATIVE_SOL_PUBKEY,
+        test_utils::{config_history_pda, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn set_finalization_delay(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        thresholds: Vec<FinalizationThreshold>,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromGuardian {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+                config_history: config_history_pda(),
+            }
+            .to_account_metas(None),
+            data: SetFinalizationDelayIx {
+                delay_seconds: 1_800,
+                thresholds,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_set_finalization_delay_success() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let sol_threshold = FinalizationThreshold {
+            local_token: NATIVE_SOL_PUBKEY,
+            threshold: 1_000,
+        };
+        set_finalization_delay(&mut svm, &guardian, bridge_pda, vec![sol_threshold]).unwrap();
+
+        let account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(bridge.finalization_delay.delay_seconds, 1_800);
+        assert_eq!(bridge.finalization_delay.thresholds[0], sol_threshold);
+        assert_eq!(
+            bridge
+                .finalization_delay
+                .delay_of(&NATIVE_SOL_PUBKEY, 1_001),
+            1_800
+        );
+        assert_eq!(
+            bridge
+                .finalization_delay
+                .delay_of(&NATIVE_SOL_PUBKEY, 1_000),
+            0
+        );
+    }
+
+    #[test]
+    fn test_set_finalization_delay_duplicate_token_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let sol_threshold = FinalizationThreshold {
+            local_token: NATIVE_SOL_PUBKEY,
+            threshold: 1_000,
+        };
+        let error = set_finalization_delay(
+            &mut svm,
+            &guardian,
+            bridge_pda,
+            vec![sol_threshold, sol_threshold],
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("InvalidFinalizationThreshold"),
+            "Expected InvalidFinalizationThreshold error, got: {}",
+            error
+        );
+    }
+}
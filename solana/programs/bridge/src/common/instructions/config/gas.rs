@@ -1,14 +1,54 @@
 use anchor_lang::prelude::*;
 
-use crate::common::SetBridgeConfigFromGuardian;
+use crate::{
+    common::{
+        bridge::Bridge, record_config_change, ConfigHistory, ConfigParam,
+        SetBridgeConfigFromGuardian, BRIDGE_SEED, CONFIG_HISTORY_SEED,
+    },
+    BridgeError,
+};
+
+/// Accounts struct for the guardian-only set_gas_fee_receiver instruction.
+#[derive(Accounts)]
+#[instruction(new_receiver: Pubkey)]
+pub struct SetGasFeeReceiver<'info> {
+    /// The bridge account containing configuration
+    #[account(
+        mut,
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The guardian account authorized to update configuration
+    pub guardian: Signer<'info>,
+
+    /// The new gas fee receiver. Must be a system account.
+    /// CHECK: Checked against `new_receiver` and validated in the handler.
+    #[account(address = new_receiver @ BridgeError::IncorrectGasFeeReceiver)]
+    pub gas_fee_receiver: AccountInfo<'info>,
+
+    /// Log recording the configuration change
+    #[account(mut, seeds = [CONFIG_HISTORY_SEED], bump)]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+}
 
 /// Set the gas cost scaler
 pub fn set_gas_cost_scaler_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_scaler: u64,
 ) -> Result<()> {
+    let old_scaler = ctx.accounts.bridge.gas_config.gas_cost_scaler;
     ctx.accounts.bridge.gas_config.gas_cost_scaler = new_scaler;
-    Ok(())
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::GasCostScaler,
+        &old_scaler,
+        &new_scaler,
+    )
 }
 
 /// Set the gas cost scaler decimal precision
@@ -16,18 +56,36 @@ pub fn set_gas_cost_scaler_dp_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_dp: u64,
 ) -> Result<()> {
+    let old_dp = ctx.accounts.bridge.gas_config.gas_cost_scaler_dp;
     ctx.accounts.bridge.gas_config.gas_cost_scaler_dp = new_dp;
     ctx.accounts.bridge.gas_config.validate()?;
-    Ok(())
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::GasCostScalerDp,
+        &old_dp,
+        &new_dp,
+    )
 }
 
 /// Set the gas fee receiver
 pub fn set_gas_fee_receiver_handler(
-    ctx: Context<SetBridgeConfigFromGuardian>,
+    ctx: Context<SetGasFeeReceiver>,
     new_receiver: Pubkey,
 ) -> Result<()> {
-    ctx.accounts.bridge.gas_config.gas_fee_receiver = new_receiver;
-    Ok(())
+    let gas_config = &mut ctx.accounts.bridge.gas_config;
+    let old_receiver = gas_config.gas_fee_receiver;
+    gas_config.gas_fee_receiver = new_receiver;
+    gas_config.validate_gas_fee_receiver(&ctx.accounts.gas_fee_receiver)?;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::GasFeeReceiver,
+        &old_receiver,
+        &new_receiver,
+    )
 }
 
 /// Set the expected gas amount per cross-chain message
@@ -35,6 +93,116 @@ pub fn set_gas_per_call_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_val: u64,
 ) -> Result<()> {
+    let old_val = ctx.accounts.bridge.gas_config.gas_per_call;
     ctx.accounts.bridge.gas_config.gas_per_call = new_val;
-    Ok(())
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::GasPerCall,
+        &old_val,
+        &new_val,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_account::Account as SvmAccount;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::SetGasFeeReceiver as SetGasFeeReceiverIx,
+        test_utils::{config_history_pda, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn set_gas_fee_receiver(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        new_receiver: Pubkey,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetGasFeeReceiver {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+                gas_fee_receiver: new_receiver,
+                config_history: config_history_pda(),
+            }
+            .to_account_metas(None),
+            data: SetGasFeeReceiverIx { new_receiver }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_set_gas_fee_receiver_to_system_account() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let new_receiver = Pubkey::new_unique();
+        svm.airdrop(&new_receiver, LAMPORTS_PER_SOL).unwrap();
+
+        set_gas_fee_receiver(&mut svm, &guardian, bridge_pda, new_receiver)
+            .expect("Failed to set gas fee receiver");
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(bridge.gas_config.gas_fee_receiver, new_receiver);
+    }
+
+    #[test]
+    fn test_set_gas_fee_receiver_to_program_owned_account_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let new_receiver = Pubkey::new_unique();
+        svm.set_account(
+            new_receiver,
+            SvmAccount {
+                lamports: LAMPORTS_PER_SOL,
+                data: vec![0u8; 8],
+                owner: Pubkey::new_unique(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        let error =
+            set_gas_fee_receiver(&mut svm, &guardian, bridge_pda, new_receiver).unwrap_err();
+        assert!(
+            error.contains("InvalidGasFeeReceiver"),
+            "Expected InvalidGasFeeReceiver error, got: {}",
+            error
+        );
+    }
 }
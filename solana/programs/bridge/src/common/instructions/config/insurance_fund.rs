@@ -0,0 +1,279 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{
+        bridge::Bridge, record_config_change, ConfigHistory, ConfigParam, InsuranceFund,
+        InsurancePayout, BRIDGE_SEED, CONFIG_HISTORY_SEED, DISCRIMINATOR_LEN, INSURANCE_FUND_SEED,
+        INSURANCE_PAYOUT_TIMELOCK_SECONDS,
+    },
+    BridgeError, InsurancePayoutCancelled, InsurancePayoutRequested,
+};
+
+/// Accounts struct for the guardian-only set_insurance_share instruction.
+#[derive(Accounts)]
+pub struct SetInsuranceShare<'info> {
+    /// The guardian account authorized to configure the insurance fund.
+    /// Pays for the insurance fund creation.
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The insurance fund account
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        seeds = [INSURANCE_FUND_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + InsuranceFund::INIT_SPACE,
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    /// Log recording the configuration change
+    #[account(mut, seeds = [CONFIG_HISTORY_SEED], bump)]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+
+    /// System program required for creating the insurance fund account
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts struct for the guardian-only request_insurance_payout and cancel_insurance_payout
+/// instructions.
+#[derive(Accounts)]
+pub struct ManageInsurancePayout<'info> {
+    /// The guardian account authorized to manage the insurance payouts.
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The insurance fund account
+    #[account(mut, seeds = [INSURANCE_FUND_SEED], bump)]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+}
+
+/// Set the share of every gas fee credited to the insurance fund, creating the fund on first use.
+pub fn set_insurance_share_handler(ctx: Context<SetInsuranceShare>, share_bps: u16) -> Result<()> {
+    let insurance_fund = &mut ctx.accounts.insurance_fund;
+    let old_share_bps = insurance_fund.share_bps;
+    insurance_fund.set_share_bps(share_bps)?;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::InsuranceShare,
+        &old_share_bps,
+        &share_bps,
+    )
+}
+
+/// Request a payout of `amount` lamports from the insurance fund to `recipient`, executable by
+/// anyone once `INSURANCE_PAYOUT_TIMELOCK_SECONDS` have elapsed.
+pub fn request_insurance_payout_handler(
+    ctx: Context<ManageInsurancePayout>,
+    recipient: Pubkey,
+    amount: u64,
+) -> Result<()> {
+    let insurance_fund = &mut ctx.accounts.insurance_fund;
+    require!(
+        insurance_fund.pending_payout.is_none(),
+        BridgeError::InsurancePayoutPending
+    );
+
+    let executable_at = Clock::get()?.unix_timestamp + INSURANCE_PAYOUT_TIMELOCK_SECONDS;
+    insurance_fund.pending_payout = Some(InsurancePayout {
+        recipient,
+        amount,
+        executable_at,
+    });
+
+    emit!(InsurancePayoutRequested {
+        recipient,
+        amount,
+        executable_at,
+    });
+
+    Ok(())
+}
+
+/// Cancel the pending insurance payout.
+pub fn cancel_insurance_payout_handler(ctx: Context<ManageInsurancePayout>) -> Result<()> {
+    let Some(payout) = ctx.accounts.insurance_fund.pending_payout.take() else {
+        return err!(BridgeError::NoPendingInsurancePayout);
+    };
+
+    emit!(InsurancePayoutCancelled {
+        recipient: payout.recipient,
+        amount: payout.amount,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::{BridgeCall, SetInsuranceShare as SetInsuranceShareIx},
+        solana_to_base::{Call, CallType},
+        test_utils::{
+            config_history_pda, create_outgoing_message, gas_fee_escrow_pda, insurance_fund_pda,
+            latest_config_change, payer_rate_limit_config_pda, payer_rate_limit_pda, setup_bridge,
+            SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+        },
+        ID,
+    };
+
+    fn set_insurance_share(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        share_bps: u16,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetInsuranceShare {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                insurance_fund: insurance_fund_pda(),
+                config_history: config_history_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: SetInsuranceShareIx { share_bps }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn bridge_call(svm: &mut LiteSVM, payer: &Keypair, bridge_pda: Pubkey) {
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::BridgeCall {
+                payer: payer.pubkey(),
+                from: payer.pubkey(),
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                gas_fee_escrow: gas_fee_escrow_pda(),
+                insurance_fund: insurance_fund_pda(),
+                payer_rate_limit_config: payer_rate_limit_config_pda(),
+                payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+                bridge: bridge_pda,
+                outgoing_message,
+                system_program: system_program::ID,
+                memo_program: None,
+            }
+            .to_account_metas(None),
+            data: BridgeCall {
+                outgoing_message_salt,
+                call: Call {
+                    ty: CallType::Call,
+                    to: [1u8; 20],
+                    value: 0,
+                    data: vec![],
+                },
+                spl_memo: false,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Failed to bridge call");
+    }
+
+    fn lamports(svm: &LiteSVM, key: &Pubkey) -> u64 {
+        svm.get_account(key).map_or(0, |account| account.lamports)
+    }
+
+    #[test]
+    fn test_insurance_fund_credited_with_gas_fee_share() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        svm.airdrop(&TEST_GAS_FEE_RECEIVER, LAMPORTS_PER_SOL)
+            .unwrap();
+
+        set_insurance_share(&mut svm, &guardian, bridge_pda, 2_000).unwrap();
+        let (_, change) = latest_config_change(&svm);
+        assert_eq!(change.param, ConfigParam::InsuranceShare as u8);
+
+        let fund_before = lamports(&svm, &insurance_fund_pda());
+        let receiver_before = lamports(&svm, &TEST_GAS_FEE_RECEIVER);
+        bridge_call(&mut svm, &payer, bridge_pda);
+        let share = lamports(&svm, &insurance_fund_pda()) - fund_before;
+        let rest = lamports(&svm, &TEST_GAS_FEE_RECEIVER) - receiver_before;
+
+        assert!(share > 0);
+        assert_eq!(share, (share + rest) * 2_000 / 10_000);
+
+        let account = svm.get_account(&insurance_fund_pda()).unwrap();
+        let insurance_fund = InsuranceFund::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(insurance_fund.share_bps, 2_000);
+        assert_eq!(insurance_fund.total_accrued, share);
+    }
+
+    #[test]
+    fn test_set_insurance_share_above_maximum_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let error = set_insurance_share(&mut svm, &guardian, bridge_pda, 5_001).unwrap_err();
+        assert!(
+            error.contains("InvalidInsuranceShare"),
+            "Expected InvalidInsuranceShare error, got: {}",
+            error
+        );
+
+        let fake_guardian = Keypair::new();
+        svm.airdrop(&fake_guardian.pubkey(), LAMPORTS_PER_SOL)
+            .unwrap();
+        let error = set_insurance_share(&mut svm, &fake_guardian, bridge_pda, 1_000).unwrap_err();
+        assert!(
+            error.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error
+        );
+    }
+}
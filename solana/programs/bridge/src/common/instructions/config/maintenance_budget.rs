@@ -0,0 +1,160 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::{constants::MAINTENANCE_BUDGET_SEED, MaintenanceBudget},
+    common::{
+        bridge::Bridge, record_config_change, ConfigHistory, ConfigParam, BRIDGE_SEED,
+        CONFIG_HISTORY_SEED, DISCRIMINATOR_LEN,
+    },
+    BridgeError,
+};
+
+/// Accounts struct for the guardian-only set_maintenance_budget instruction.
+/// The maintenance budget is created on first use.
+#[derive(Accounts)]
+pub struct SetMaintenanceBudget<'info> {
+    /// The guardian account authorized to configure the maintenance budget.
+    /// Pays for the maintenance budget creation.
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The maintenance budget account
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        seeds = [MAINTENANCE_BUDGET_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + MaintenanceBudget::INIT_SPACE,
+    )]
+    pub maintenance_budget: Account<'info, MaintenanceBudget>,
+
+    /// Log recording the configuration change
+    #[account(mut, seeds = [CONFIG_HISTORY_SEED], bump)]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+
+    /// System program required for creating the maintenance budget account
+    pub system_program: Program<'info, System>,
+}
+
+/// Set the lamports reimbursed to the payer of each output root registration on top of its rent.
+pub fn set_maintenance_budget_handler(
+    ctx: Context<SetMaintenanceBudget>,
+    fee_reimbursement: u64,
+) -> Result<()> {
+    let maintenance_budget = &mut ctx.accounts.maintenance_budget;
+    let old_fee_reimbursement = maintenance_budget.fee_reimbursement;
+    maintenance_budget.set_fee_reimbursement(fee_reimbursement)?;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::MaintenanceBudget,
+        &old_fee_reimbursement,
+        &fee_reimbursement,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::constants::MAX_MAINTENANCE_FEE_REIMBURSEMENT,
+        instruction::SetMaintenanceBudget as SetMaintenanceBudgetIx,
+        test_utils::{
+            config_history_pda, latest_config_change, maintenance_budget_pda, setup_bridge,
+            SetupBridgeResult,
+        },
+        ID,
+    };
+
+    fn set_maintenance_budget(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        fee_reimbursement: u64,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetMaintenanceBudget {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                maintenance_budget: maintenance_budget_pda(),
+                config_history: config_history_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: SetMaintenanceBudgetIx { fee_reimbursement }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_set_maintenance_budget() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        set_maintenance_budget(&mut svm, &guardian, bridge_pda, 10_000).unwrap();
+
+        let account = svm.get_account(&maintenance_budget_pda()).unwrap();
+        let maintenance_budget =
+            MaintenanceBudget::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(maintenance_budget.fee_reimbursement, 10_000);
+        let (_, change) = latest_config_change(&svm);
+        assert_eq!(change.param, ConfigParam::MaintenanceBudget as u8);
+
+        let error = set_maintenance_budget(
+            &mut svm,
+            &guardian,
+            bridge_pda,
+            MAX_MAINTENANCE_FEE_REIMBURSEMENT + 1,
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("InvalidMaintenanceFeeReimbursement"),
+            "Expected InvalidMaintenanceFeeReimbursement error, got: {}",
+            error
+        );
+
+        let fake_guardian = Keypair::new();
+        svm.airdrop(&fake_guardian.pubkey(), LAMPORTS_PER_SOL)
+            .unwrap();
+        let error = set_maintenance_budget(&mut svm, &fake_guardian, bridge_pda, 0).unwrap_err();
+        assert!(
+            error.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error
+        );
+    }
+}
@@ -0,0 +1,226 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{
+        bridge::Bridge, record_config_change, ConfigHistory, ConfigParam, MintAllowList,
+        SetBridgeConfigFromGuardian, BRIDGE_SEED, CONFIG_HISTORY_SEED, DISCRIMINATOR_LEN,
+        MINT_ALLOW_LIST_SEED,
+    },
+    BridgeError, GuardedLaunchSet, MintAllowListUpdated,
+};
+
+/// Accounts struct for the guardian-only set_mint_allowed instruction.
+/// The mint allow-list is created on first use.
+#[derive(Accounts)]
+pub struct SetMintAllowed<'info> {
+    /// The guardian account authorized to maintain the mint allow-list.
+    /// Pays for the mint allow-list creation.
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The mint allow-list account
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        seeds = [MINT_ALLOW_LIST_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + MintAllowList::INIT_SPACE,
+    )]
+    pub mint_allow_list: Account<'info, MintAllowList>,
+
+    /// Log recording the configuration change
+    #[account(mut, seeds = [CONFIG_HISTORY_SEED], bump)]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+
+    /// System program required for creating the mint allow-list account
+    pub system_program: Program<'info, System>,
+}
+
+/// Add `mint` to, or remove it from, the mints accepted by `bridge_spl` during guarded launch.
+pub fn set_mint_allowed_handler(
+    ctx: Context<SetMintAllowed>,
+    mint: Pubkey,
+    allowed: bool,
+) -> Result<()> {
+    let mint_allow_list = &mut ctx.accounts.mint_allow_list;
+    let old_allow_list = ConfigHistory::encode_value(&**mint_allow_list)?;
+    if allowed {
+        mint_allow_list.allow(mint)?;
+    } else {
+        mint_allow_list.disallow(&mint);
+    }
+    let new_allow_list = ConfigHistory::encode_value(&**mint_allow_list)?;
+
+    emit!(MintAllowListUpdated { mint, allowed });
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::MintAllowList,
+        &old_allow_list,
+        &new_allow_list,
+    )
+}
+
+/// Turn guarded launch on or off. While it is on, `bridge_spl` only accepts allow-listed mints.
+/// Only the guardian can call this function
+pub fn set_guarded_launch_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    enabled: bool,
+) -> Result<()> {
+    let bridge = &mut ctx.accounts.bridge;
+    let was_enabled = bridge.guarded_launch;
+    bridge.guarded_launch = enabled;
+
+    emit!(GuardedLaunchSet { enabled });
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::GuardedLaunch,
+        &was_enabled,
+        &enabled,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::{SetGuardedLaunch as SetGuardedLaunchIx, SetMintAllowed as SetMintAllowedIx},
+        test_utils::{
+            config_history_pda, latest_config_change, mint_allow_list_pda, setup_bridge,
+            SetupBridgeResult,
+        },
+        ID,
+    };
+
+    fn send(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        ix: Instruction,
+    ) -> std::result::Result<(), String> {
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn set_mint_allowed(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        mint: Pubkey,
+        allowed: bool,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetMintAllowed {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                mint_allow_list: mint_allow_list_pda(),
+                config_history: config_history_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: SetMintAllowedIx { mint, allowed }.data(),
+        };
+        send(svm, guardian, ix)
+    }
+
+    fn read_mint_allow_list(svm: &LiteSVM) -> MintAllowList {
+        let account = svm.get_account(&mint_allow_list_pda()).unwrap();
+        MintAllowList::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    #[test]
+    fn test_set_mint_allowed() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+
+        set_mint_allowed(&mut svm, &guardian, bridge_pda, first, true).unwrap();
+        set_mint_allowed(&mut svm, &guardian, bridge_pda, second, true).unwrap();
+        set_mint_allowed(&mut svm, &guardian, bridge_pda, first, true).unwrap();
+
+        let mint_allow_list = read_mint_allow_list(&svm);
+        assert_eq!(mint_allow_list.mint_count, 2);
+        assert!(mint_allow_list.allows(&first) && mint_allow_list.allows(&second));
+
+        set_mint_allowed(&mut svm, &guardian, bridge_pda, first, false).unwrap();
+
+        let mint_allow_list = read_mint_allow_list(&svm);
+        assert_eq!(mint_allow_list.mint_count, 1);
+        assert_eq!(mint_allow_list.mints[0], second);
+        let (_, change) = latest_config_change(&svm);
+        assert_eq!(change.param, ConfigParam::MintAllowList as u8);
+
+        let fake_guardian = Keypair::new();
+        svm.airdrop(&fake_guardian.pubkey(), LAMPORTS_PER_SOL)
+            .unwrap();
+        let error =
+            set_mint_allowed(&mut svm, &fake_guardian, bridge_pda, first, true).unwrap_err();
+        assert!(
+            error.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_set_guarded_launch() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromGuardian {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+                config_history: config_history_pda(),
+            }
+            .to_account_metas(None),
+            data: SetGuardedLaunchIx { enabled: true }.data(),
+        };
+        send(&mut svm, &guardian, ix).unwrap();
+
+        let account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &account.data[..]).unwrap();
+        assert!(bridge.guarded_launch);
+        let (_, change) = latest_config_change(&svm);
+        assert_eq!(change.param, ConfigParam::GuardedLaunch as u8);
+    }
+}
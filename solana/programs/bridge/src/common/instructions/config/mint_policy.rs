@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{
+        bridge::Bridge, record_config_change, ConfigHistory, ConfigParam, MintPolicy, BRIDGE_SEED,
+        CONFIG_HISTORY_SEED, DISCRIMINATOR_LEN, MINT_POLICY_SEED,
+    },
+    BridgeError,
+};
+
+/// Accounts struct for the guardian-only set_mint_policy instruction.
+/// The mint policy is created on first use.
+#[derive(Accounts)]
+pub struct SetMintPolicy<'info> {
+    /// The guardian account authorized to configure the mint policy.
+    /// Pays for the mint policy creation.
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The mint policy account
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        seeds = [MINT_POLICY_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + MintPolicy::INIT_SPACE,
+    )]
+    pub mint_policy: Account<'info, MintPolicy>,
+
+    /// Log recording the configuration change
+    #[account(mut, seeds = [CONFIG_HISTORY_SEED], bump)]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+
+    /// System program required for creating the mint policy account
+    pub system_program: Program<'info, System>,
+}
+
+/// Set or update whether mints with a freeze authority are rejected and the allowed authorities.
+pub fn set_mint_policy_handler(
+    ctx: Context<SetMintPolicy>,
+    reject_freeze_authority: bool,
+    allowed_freeze_authorities: Vec<Pubkey>,
+) -> Result<()> {
+    let mint_policy = &mut ctx.accounts.mint_policy;
+    let old_policy = ConfigHistory::encode_value(&**mint_policy)?;
+    mint_policy.set(reject_freeze_authority, &allowed_freeze_authorities)?;
+    let new_policy = ConfigHistory::encode_value(&**mint_policy)?;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::MintPolicy,
+        &old_policy,
+        &new_policy,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::SetMintPolicy as SetMintPolicyIx,
+        test_utils::{config_history_pda, mint_policy_pda, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn set_mint_policy(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        reject_freeze_authority: bool,
+        allowed_freeze_authorities: Vec<Pubkey>,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetMintPolicy {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                mint_policy: mint_policy_pda(),
+                config_history: config_history_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: SetMintPolicyIx {
+                reject_freeze_authority,
+                allowed_freeze_authorities,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_set_mint_policy_creates_and_updates() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let allowed = Pubkey::new_unique();
+        set_mint_policy(&mut svm, &guardian, bridge_pda, true, vec![allowed]).unwrap();
+
+        let account = svm.get_account(&mint_policy_pda()).unwrap();
+        let policy = MintPolicy::try_deserialize(&mut &account.data[..]).unwrap();
+        assert!(policy.reject_freeze_authority);
+        assert!(policy.allows_freeze_authority(None));
+        assert!(policy.allows_freeze_authority(Some(&allowed)));
+        assert!(!policy.allows_freeze_authority(Some(&Pubkey::new_unique())));
+
+        // Updating replaces the previous content
+        svm.expire_blockhash();
+        set_mint_policy(&mut svm, &guardian, bridge_pda, false, vec![]).unwrap();
+
+        let account = svm.get_account(&mint_policy_pda()).unwrap();
+        let policy = MintPolicy::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(policy.allowed_freeze_authority_count, 0);
+        assert!(policy.allows_freeze_authority(Some(&Pubkey::new_unique())));
+    }
+
+    #[test]
+    fn test_set_mint_policy_unauthorized_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let fake_guardian = Keypair::new();
+        svm.airdrop(&fake_guardian.pubkey(), LAMPORTS_PER_SOL)
+            .unwrap();
+
+        let error =
+            set_mint_policy(&mut svm, &fake_guardian, bridge_pda, true, vec![]).unwrap_err();
+        assert!(
+            error.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error
+        );
+    }
+}
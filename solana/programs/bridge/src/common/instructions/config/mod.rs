@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED},
+    common::{bridge::Bridge, ConfigHistory, ConfigParam, BRIDGE_SEED, CONFIG_HISTORY_SEED},
     program::Bridge as BridgeProgram,
     BridgeError,
 };
@@ -12,6 +12,9 @@ pub use eip1559::*;
 pub mod gas;
 pub use gas::*;
 
+pub mod fee;
+pub use fee::*;
+
 pub mod protocol;
 pub use protocol::*;
 
@@ -27,6 +30,60 @@ pub use base_oracle_signers::*;
 pub mod partner_config;
 pub use partner_config::*;
 
+mod address_book;
+pub use address_book::*;
+
+pub mod protocol_fee;
+pub use protocol_fee::*;
+
+mod mint_policy;
+pub use mint_policy::*;
+
+mod emergency_exit;
+pub use emergency_exit::*;
+
+pub mod oracle_failover;
+pub use oracle_failover::*;
+
+pub mod root_freshness;
+pub use root_freshness::*;
+
+pub mod outflow_breaker;
+pub use outflow_breaker::*;
+
+pub mod finalization_delay;
+pub use finalization_delay::*;
+
+mod vault_decimals;
+pub use vault_decimals::*;
+
+pub mod base_governance;
+pub use base_governance::*;
+
+mod insurance_fund;
+pub use insurance_fund::*;
+
+pub mod relay_deny_list;
+pub use relay_deny_list::*;
+
+pub mod maintenance_budget;
+pub use maintenance_budget::*;
+
+pub mod operational_vault;
+pub use operational_vault::*;
+
+mod mint_allow_list;
+pub use mint_allow_list::*;
+
+mod dex_router_list;
+pub use dex_router_list::*;
+
+pub mod payer_rate_limit;
+pub use payer_rate_limit::*;
+
+mod wrapped_token_confirmation;
+pub use wrapped_token_confirmation::*;
+
 /// Accounts struct for non-sensitive bridge configuration setter instructions
 /// Only the guardian can update these parameters
 #[derive(Accounts)]
@@ -42,6 +99,10 @@ pub struct SetBridgeConfigFromGuardian<'info> {
 
     /// The guardian account authorized to update configuration
     pub guardian: Signer<'info>,
+
+    /// Log recording the configuration change
+    #[account(mut, seeds = [CONFIG_HISTORY_SEED], bump)]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
 }
 
 /// Accounts struct for sensitive bridge configuration setter instructions
@@ -60,4 +121,44 @@ pub struct SetBridgeConfigFromUpgradeAuthority<'info> {
 
     #[account(constraint = program.programdata_address()? == Some(program_data.key()) @ BridgeError::IncorrectBridgeProgram)]
     pub program: Program<'info, BridgeProgram>,
+
+    /// Log recording the configuration change
+    #[account(mut, seeds = [CONFIG_HISTORY_SEED], bump)]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+}
+
+/// Accounts struct for critical bridge configuration setter instructions relayed from the Base
+/// governance contract. They can only be reached through `relay_message`, which signs with the
+/// bridge CPI authority of the message sender.
+#[derive(Accounts)]
+pub struct SetBridgeConfigFromBaseGovernance<'info> {
+    /// The bridge CPI authority of the Base governance contract
+    #[account(address = bridge.base_governance.cpi_authority()? @ BridgeError::UnauthorizedConfigUpdate)]
+    pub governance_cpi_authority: Signer<'info>,
+
+    /// The bridge account containing configuration
+    #[account(mut, seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// Log recording the configuration change
+    #[account(mut, seeds = [CONFIG_HISTORY_SEED], bump)]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+}
+
+/// Records in `config_history` that `authority` changed `param` from `old_value` to `new_value`.
+pub fn record_config_change<T: AnchorSerialize>(
+    config_history: &AccountLoader<ConfigHistory>,
+    authority: &Signer,
+    param: ConfigParam,
+    old_value: &T,
+    new_value: &T,
+) -> Result<()> {
+    config_history.load_mut()?.record(
+        param,
+        ConfigHistory::encode_value(old_value)?,
+        ConfigHistory::encode_value(new_value)?,
+        authority.key(),
+        Clock::get()?.slot,
+    );
+    Ok(())
 }
@@ -0,0 +1,155 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::{constants::OPERATIONAL_VAULT_SEED, OperationalVault},
+    common::{
+        bridge::Bridge, record_config_change, ConfigHistory, ConfigParam, BRIDGE_SEED,
+        CONFIG_HISTORY_SEED, DISCRIMINATOR_LEN,
+    },
+    BridgeError,
+};
+
+/// Accounts struct for the guardian-only set_max_lamport_allowance instruction.
+/// The operational vault is created on first use.
+#[derive(Accounts)]
+pub struct SetMaxLamportAllowance<'info> {
+    /// The guardian account authorized to configure the operational vault.
+    /// Pays for the operational vault creation.
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The operational vault account
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        seeds = [OPERATIONAL_VAULT_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + OperationalVault::INIT_SPACE,
+    )]
+    pub operational_vault: Account<'info, OperationalVault>,
+
+    /// Log recording the configuration change
+    #[account(mut, seeds = [CONFIG_HISTORY_SEED], bump)]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+
+    /// System program required for creating the operational vault account
+    pub system_program: Program<'info, System>,
+}
+
+/// Set the maximum lamport allowance lent to the bridge CPI authority of a single message.
+pub fn set_max_lamport_allowance_handler(
+    ctx: Context<SetMaxLamportAllowance>,
+    max_allowance: u64,
+) -> Result<()> {
+    let operational_vault = &mut ctx.accounts.operational_vault;
+    let old_max_allowance = operational_vault.max_allowance;
+    operational_vault.set_max_allowance(max_allowance)?;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::MaxLamportAllowance,
+        &old_max_allowance,
+        &max_allowance,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::constants::MAX_LAMPORT_ALLOWANCE,
+        instruction::SetMaxLamportAllowance as SetMaxLamportAllowanceIx,
+        test_utils::{
+            config_history_pda, latest_config_change, operational_vault_pda, setup_bridge,
+            SetupBridgeResult,
+        },
+        ID,
+    };
+
+    fn set_max_lamport_allowance(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        max_allowance: u64,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetMaxLamportAllowance {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                operational_vault: operational_vault_pda(),
+                config_history: config_history_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: SetMaxLamportAllowanceIx { max_allowance }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_set_max_lamport_allowance() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        set_max_lamport_allowance(&mut svm, &guardian, bridge_pda, LAMPORTS_PER_SOL).unwrap();
+
+        let account = svm.get_account(&operational_vault_pda()).unwrap();
+        let operational_vault = OperationalVault::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(operational_vault.max_allowance, LAMPORTS_PER_SOL);
+        let (_, change) = latest_config_change(&svm);
+        assert_eq!(change.param, ConfigParam::MaxLamportAllowance as u8);
+
+        let error =
+            set_max_lamport_allowance(&mut svm, &guardian, bridge_pda, MAX_LAMPORT_ALLOWANCE + 1)
+                .unwrap_err();
+        assert!(
+            error.contains("InvalidMaxLamportAllowance"),
+            "Expected InvalidMaxLamportAllowance error, got: {}",
+            error
+        );
+
+        let fake_guardian = Keypair::new();
+        svm.airdrop(&fake_guardian.pubkey(), LAMPORTS_PER_SOL)
+            .unwrap();
+        let error = set_max_lamport_allowance(&mut svm, &fake_guardian, bridge_pda, 0).unwrap_err();
+        assert!(
+            error.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error
+        );
+    }
+}
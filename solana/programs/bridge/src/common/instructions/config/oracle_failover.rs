@@ -0,0 +1,307 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{
+        record_config_change, ConfigParam, SetBridgeConfigFromGuardian,
+        SetBridgeConfigFromUpgradeAuthority, MAX_EMERGENCY_SIGNER_COUNT,
+    },
+    BridgeError, OracleEmergencyActivated, OracleEmergencyDeactivated,
+};
+
+/// Set how long output roots must stall before the guardian can activate the emergency quorum
+/// (`stall_seconds`, 0 disables emergency mode) and how long it then stays active.
+/// Only the upgrade authority can call this function so that the guardian cannot shorten the
+/// stall period it is subject to.
+pub fn set_oracle_failover_config_handler(
+    ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
+    stall_seconds: u64,
+    emergency_duration_seconds: u64,
+) -> Result<()> {
+    require!(
+        stall_seconds <= i64::MAX as u64
+            && emergency_duration_seconds <= i64::MAX as u64
+            && (stall_seconds == 0 || emergency_duration_seconds > 0),
+        BridgeError::InvalidEmergencyDuration
+    );
+
+    let failover = &mut ctx.accounts.bridge.oracle_failover;
+    let old_config = (failover.stall_seconds, failover.emergency_duration_seconds);
+    failover.stall_seconds = stall_seconds;
+    failover.emergency_duration_seconds = emergency_duration_seconds;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.upgrade_authority,
+        ConfigParam::OracleFailoverConfig,
+        &old_config,
+        &(stall_seconds, emergency_duration_seconds),
+    )
+}
+
+/// Activate the oracle emergency quorum once output roots stalled for `stall_seconds`.
+/// Until it expires, oracle attestations are accepted when `threshold` unique Base oracle or
+/// `emergency_signers` signatures approve them.
+/// Only the guardian can call this function
+pub fn activate_oracle_emergency_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    threshold: u8,
+    emergency_signers: Vec<[u8; 20]>,
+) -> Result<()> {
+    let bridge = &mut ctx.accounts.bridge;
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        bridge.oracle_failover.is_stalled(now),
+        BridgeError::OracleNotStalled
+    );
+
+    require!(
+        emergency_signers.len() <= MAX_EMERGENCY_SIGNER_COUNT as usize,
+        BridgeError::TooManyEmergencySigners
+    );
+    for (i, signer) in emergency_signers.iter().enumerate() {
+        require!(
+            !emergency_signers[..i].contains(signer) && !bridge.base_oracle_config.contains(signer),
+            BridgeError::DuplicateSigner
+        );
+    }
+
+    let signer_count = bridge.base_oracle_config.signer_count as usize + emergency_signers.len();
+    require!(
+        threshold > 0 && threshold as usize <= signer_count,
+        BridgeError::InvalidEmergencyThreshold
+    );
+
+    let old_failover = bridge.oracle_failover.clone();
+    let failover = &mut bridge.oracle_failover;
+    failover.emergency_threshold = threshold;
+    failover.emergency_signer_count = emergency_signers.len() as u8;
+    failover.emergency_signers = Default::default();
+    failover.emergency_signers[..emergency_signers.len()].copy_from_slice(&emergency_signers);
+    failover.emergency_expires_at = now.saturating_add(failover.emergency_duration_seconds as i64);
+
+    emit!(OracleEmergencyActivated {
+        threshold,
+        emergency_signers,
+        last_output_root_at: failover.last_output_root_at,
+        expires_at: failover.emergency_expires_at,
+    });
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::OracleEmergency,
+        &old_failover,
+        &*failover,
+    )
+}
+
+/// Deactivate the oracle emergency quorum before it expires.
+/// Only the guardian can call this function
+pub fn deactivate_oracle_emergency_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+) -> Result<()> {
+    let failover = &mut ctx.accounts.bridge.oracle_failover;
+    let old_failover = failover.clone();
+
+    emit!(OracleEmergencyDeactivated {
+        expires_at: failover.emergency_expires_at,
+    });
+
+    failover.emergency_expires_at = 0;
+    failover.emergency_threshold = 0;
+    failover.emergency_signer_count = 0;
+    failover.emergency_signers = Default::default();
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::OracleEmergency,
+        &old_failover,
+        &*failover,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::bridge::Bridge,
+        instruction::{
+            ActivateOracleEmergency as ActivateOracleEmergencyIx,
+            DeactivateOracleEmergency as DeactivateOracleEmergencyIx,
+        },
+        test_utils::{config_history_pda, mock_clock, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    const LAST_OUTPUT_ROOT_AT: i64 = 1_000;
+    const STALL_SECONDS: u64 = 3_600;
+    const EMERGENCY_DURATION_SECONDS: u64 = 600;
+
+    fn read_bridge(svm: &LiteSVM, bridge_pda: Pubkey) -> Bridge {
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap()
+    }
+
+    /// Enables the failover with a single Base oracle signer and a registered output root.
+    fn setup_failover(svm: &mut LiteSVM, bridge_pda: Pubkey) {
+        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
+        bridge.base_oracle_config.threshold = 1;
+        bridge.base_oracle_config.signer_count = 1;
+        bridge.base_oracle_config.signers = Default::default();
+        bridge.base_oracle_config.signers[0] = [1u8; 20];
+        bridge.oracle_failover.last_output_root_at = LAST_OUTPUT_ROOT_AT;
+        bridge.oracle_failover.stall_seconds = STALL_SECONDS;
+        bridge.oracle_failover.emergency_duration_seconds = EMERGENCY_DURATION_SECONDS;
+        let mut new_data = Vec::new();
+        bridge.try_serialize(&mut new_data).unwrap();
+        bridge_acc.data = new_data;
+        svm.set_account(bridge_pda, bridge_acc).unwrap();
+    }
+
+    fn send_guardian_ix(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        data: Vec<u8>,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromGuardian {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+                config_history: config_history_pda(),
+            }
+            .to_account_metas(None),
+            data,
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_activate_oracle_emergency_success() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_failover(&mut svm, bridge_pda);
+
+        let now = LAST_OUTPUT_ROOT_AT + STALL_SECONDS as i64;
+        mock_clock(&mut svm, now);
+
+        send_guardian_ix(
+            &mut svm,
+            &guardian,
+            bridge_pda,
+            ActivateOracleEmergencyIx {
+                threshold: 1,
+                emergency_signers: vec![[2u8; 20]],
+            }
+            .data(),
+        )
+        .expect("Failed to activate oracle emergency");
+
+        let failover = read_bridge(&svm, bridge_pda).oracle_failover;
+        assert_eq!(failover.emergency_threshold, 1);
+        assert!(failover.contains(&[2u8; 20]));
+        assert_eq!(
+            failover.emergency_expires_at,
+            now + EMERGENCY_DURATION_SECONDS as i64
+        );
+        assert!(failover.is_emergency_active(now));
+        assert!(!failover.is_emergency_active(failover.emergency_expires_at));
+
+        send_guardian_ix(
+            &mut svm,
+            &guardian,
+            bridge_pda,
+            DeactivateOracleEmergencyIx {}.data(),
+        )
+        .expect("Failed to deactivate oracle emergency");
+
+        let failover = read_bridge(&svm, bridge_pda).oracle_failover;
+        assert!(!failover.is_emergency_active(now));
+        assert!(!failover.contains(&[2u8; 20]));
+    }
+
+    #[test]
+    fn test_activate_oracle_emergency_requires_stall() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_failover(&mut svm, bridge_pda);
+
+        mock_clock(&mut svm, LAST_OUTPUT_ROOT_AT + STALL_SECONDS as i64 - 1);
+
+        let error_string = send_guardian_ix(
+            &mut svm,
+            &guardian,
+            bridge_pda,
+            ActivateOracleEmergencyIx {
+                threshold: 1,
+                emergency_signers: vec![[2u8; 20]],
+            }
+            .data(),
+        )
+        .unwrap_err();
+        assert!(
+            error_string.contains("OracleNotStalled"),
+            "Expected OracleNotStalled error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_activate_oracle_emergency_invalid_threshold_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_failover(&mut svm, bridge_pda);
+
+        mock_clock(&mut svm, LAST_OUTPUT_ROOT_AT + STALL_SECONDS as i64);
+
+        // One Base oracle signer and one emergency signer cannot reach a threshold of three
+        let error_string = send_guardian_ix(
+            &mut svm,
+            &guardian,
+            bridge_pda,
+            ActivateOracleEmergencyIx {
+                threshold: 3,
+                emergency_signers: vec![[2u8; 20]],
+            }
+            .data(),
+        )
+        .unwrap_err();
+        assert!(
+            error_string.contains("InvalidEmergencyThreshold"),
+            "Expected InvalidEmergencyThreshold error, got: {}",
+            error_string
+        );
+    }
+}
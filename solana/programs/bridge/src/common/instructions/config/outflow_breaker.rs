@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+
+use crate::common::{
+    bridge::OutflowThreshold, record_config_change, ConfigHistory, ConfigParam,
+    SetBridgeConfigFromGuardian,
+};
+
+/// Set the sliding window and the per-token thresholds of the outflow circuit breaker,
+/// restarting the tracking of every token.
+/// Only the guardian can call this function
+pub fn set_outflow_breaker_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    window_seconds: u64,
+    thresholds: Vec<OutflowThreshold>,
+) -> Result<()> {
+    let outflow_breaker = &mut ctx.accounts.bridge.outflow_breaker;
+    let old_breaker = ConfigHistory::encode_value(&*outflow_breaker)?;
+    outflow_breaker.set(window_seconds, &thresholds)?;
+    let new_breaker = ConfigHistory::encode_value(&*outflow_breaker)?;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::OutflowBreaker,
+        &old_breaker,
+        &new_breaker,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::bridge::Bridge,
+        instruction::SetOutflowBreaker as SetOutflowBreakerIx,
+        solana_to_base::NATIVE_SOL_PUBKEY,
+        test_utils::{config_history_pda, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn set_outflow_breaker(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        thresholds: Vec<OutflowThreshold>,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromGuardian {
+                bridge: bridge_pda,
+                guardian: guardian.pubkey(),
+                config_history: config_history_pda(),
+            }
+            .to_account_metas(None),
+            data: SetOutflowBreakerIx {
+                window_seconds: 3_600,
+                thresholds,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_set_outflow_breaker_success() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let sol_threshold = OutflowThreshold {
+            local_token: NATIVE_SOL_PUBKEY,
+            threshold: 1_000,
+        };
+        set_outflow_breaker(&mut svm, &guardian, bridge_pda, vec![sol_threshold]).unwrap();
+
+        let account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(bridge.outflow_breaker.window_seconds, 3_600);
+        assert_eq!(
+            bridge.outflow_breaker.limits[0].local_token,
+            NATIVE_SOL_PUBKEY
+        );
+        assert_eq!(bridge.outflow_breaker.limits[0].threshold, 1_000);
+    }
+
+    #[test]
+    fn test_set_outflow_breaker_duplicate_token_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let sol_threshold = OutflowThreshold {
+            local_token: NATIVE_SOL_PUBKEY,
+            threshold: 1_000,
+        };
+        let error = set_outflow_breaker(
+            &mut svm,
+            &guardian,
+            bridge_pda,
+            vec![sol_threshold, sol_threshold],
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("InvalidOutflowThreshold"),
+            "Expected InvalidOutflowThreshold error, got: {}",
+            error
+        );
+    }
+}
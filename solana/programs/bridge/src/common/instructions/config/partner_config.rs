@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 
-use crate::common::{PartnerOracleConfig, SetBridgeConfigFromUpgradeAuthority};
+use crate::common::{
+    record_config_change, ConfigParam, PartnerOracleConfig, SetBridgeConfigFromUpgradeAuthority,
+};
 
 /// Set or update the oracle signer configuration.
 ///
@@ -12,8 +14,15 @@ pub fn set_partner_config_handler(
     partner_cfg: PartnerOracleConfig,
 ) -> Result<()> {
     partner_cfg.validate()?;
-    ctx.accounts.bridge.partner_oracle_config = partner_cfg;
-    Ok(())
+    let old_cfg = std::mem::replace(&mut ctx.accounts.bridge.partner_oracle_config, partner_cfg);
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.upgrade_authority,
+        ConfigParam::PartnerOracleConfig,
+        &old_cfg,
+        &ctx.accounts.bridge.partner_oracle_config,
+    )
 }
 
 #[cfg(test)]
@@ -50,6 +59,7 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            config_history: config_history_pda(),
         }
         .to_account_metas(None);
 
@@ -98,6 +108,7 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            config_history: config_history_pda(),
         }
         .to_account_metas(None);
 
@@ -150,6 +161,7 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            config_history: config_history_pda(),
         }
         .to_account_metas(None);
 
@@ -1,15 +1,53 @@
 use anchor_lang::prelude::*;
 
-use crate::common::SetBridgeConfigFromGuardian;
+use crate::common::{
+    bridge::Bridge, record_config_change, ConfigParam, SetBridgeConfigFromBaseGovernance,
+    SetBridgeConfigFromGuardian,
+};
 
 /// Set the pause status of the bridge
+/// Unpausing forgets the outflows tracked by the outflow circuit breaker
 /// Only the guardian can call this function
 pub fn set_pause_status_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     paused: bool,
 ) -> Result<()> {
-    ctx.accounts.bridge.paused = paused;
-    Ok(())
+    let was_paused = set_paused(&mut ctx.accounts.bridge, paused);
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::Paused,
+        &was_paused,
+        &paused,
+    )
+}
+
+/// Pause the bridge from the Base governance
+/// Unpausing stays with the guardian since no message can be relayed while the bridge is paused
+/// Only reachable through a message relayed from the Base governance contract
+pub fn pause_from_governance_handler(
+    ctx: Context<SetBridgeConfigFromBaseGovernance>,
+) -> Result<()> {
+    let was_paused = set_paused(&mut ctx.accounts.bridge, true);
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.governance_cpi_authority,
+        ConfigParam::Paused,
+        &was_paused,
+        &true,
+    )
+}
+
+/// Sets the pause status of `bridge`, returning the previous one.
+fn set_paused(bridge: &mut Bridge, paused: bool) -> bool {
+    let was_paused = bridge.paused;
+    bridge.paused = paused;
+    if !paused {
+        bridge.outflow_breaker.reset();
+    }
+    was_paused
 }
 
 #[cfg(test)]
@@ -23,9 +61,12 @@ mod tests {
 
     use crate::{
         accounts,
-        common::bridge::Bridge,
-        instruction::SetPauseStatus as SetPauseStatusIx,
-        test_utils::{setup_bridge, SetupBridgeResult},
+        common::{bridge::Bridge, ConfigHistory},
+        instruction::{PauseFromGovernance, SetPauseStatus as SetPauseStatusIx},
+        test_utils::{
+            config_history_pda, latest_config_change, relay_governance_call,
+            set_mock_base_governance, setup_bridge, SetupBridgeResult,
+        },
         ID,
     };
 
@@ -45,6 +86,7 @@ mod tests {
         let accounts = accounts::SetBridgeConfigFromGuardian {
             bridge: bridge_pda,
             guardian: guardian.pubkey(),
+            config_history: config_history_pda(),
         }
         .to_account_metas(None);
 
@@ -74,6 +116,20 @@ mod tests {
             "Pause status should be updated to {}",
             new_paused
         );
+
+        // Verify the change was recorded in the config history
+        let (count, change) = latest_config_change(&svm);
+        assert_eq!(count, 1);
+        assert_eq!(change.param, ConfigParam::Paused as u8);
+        assert_eq!(change.authority, guardian.pubkey());
+        assert_eq!(
+            change.old_value,
+            ConfigHistory::encode_value(&false).unwrap()
+        );
+        assert_eq!(
+            change.new_value,
+            ConfigHistory::encode_value(&true).unwrap()
+        );
     }
 
     #[test]
@@ -95,6 +151,7 @@ mod tests {
         let accounts = accounts::SetBridgeConfigFromGuardian {
             bridge: bridge_pda,
             guardian: fake_guardian.pubkey(), // Wrong guardian
+            config_history: config_history_pda(),
         }
         .to_account_metas(None);
 
@@ -127,4 +184,36 @@ mod tests {
             error_string
         );
     }
+
+    #[test]
+    fn test_pause_from_governance() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let governance = [7u8; 20];
+        set_mock_base_governance(&mut svm, bridge_pda, governance, true);
+
+        relay_governance_call(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            governance,
+            PauseFromGovernance {}.data(),
+        )
+        .expect("Failed to relay the pause");
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge_data = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert!(bridge_data.paused);
+
+        let (_, change) = latest_config_change(&svm);
+        assert_eq!(change.param, ConfigParam::Paused as u8);
+        assert_eq!(
+            change.new_value,
+            ConfigHistory::encode_value(&true).unwrap()
+        );
+    }
 }
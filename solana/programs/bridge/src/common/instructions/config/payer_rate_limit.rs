@@ -0,0 +1,242 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{
+        bridge::Bridge, record_config_change, ConfigHistory, ConfigParam, PayerRateLimitConfig,
+        BRIDGE_SEED, CONFIG_HISTORY_SEED, DISCRIMINATOR_LEN, PAYER_RATE_LIMIT_CONFIG_SEED,
+    },
+    BridgeError, PayerRateLimitExemptionUpdated, PayerRateLimitSet,
+};
+
+/// Accounts struct for the guardian-only set_payer_rate_limit and set_rate_limit_exempt_payer
+/// instructions. The payer rate limit config is created on first use.
+#[derive(Accounts)]
+pub struct SetPayerRateLimitConfig<'info> {
+    /// The guardian account authorized to maintain the payer rate limit config.
+    /// Pays for the payer rate limit config creation.
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The payer rate limit config account
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        seeds = [PAYER_RATE_LIMIT_CONFIG_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + PayerRateLimitConfig::INIT_SPACE,
+    )]
+    pub payer_rate_limit_config: Account<'info, PayerRateLimitConfig>,
+
+    /// Log recording the configuration change
+    #[account(mut, seeds = [CONFIG_HISTORY_SEED], bump)]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+
+    /// System program required for creating the payer rate limit config account
+    pub system_program: Program<'info, System>,
+}
+
+/// Set the outgoing messages and gas each payer can pay for per window of `window_seconds`
+/// (0 disables rate limiting). A zero maximum does not limit the messages or gas.
+pub fn set_payer_rate_limit_handler(
+    ctx: Context<SetPayerRateLimitConfig>,
+    window_seconds: u64,
+    max_messages: u64,
+    max_gas: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.payer_rate_limit_config;
+    let old_limits = (config.window_seconds, config.max_messages, config.max_gas);
+    config.window_seconds = window_seconds;
+    config.max_messages = max_messages;
+    config.max_gas = max_gas;
+
+    emit!(PayerRateLimitSet {
+        window_seconds,
+        max_messages,
+        max_gas,
+    });
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::PayerRateLimit,
+        &old_limits,
+        &(window_seconds, max_messages, max_gas),
+    )
+}
+
+/// Exempt `payer` from the payer rate limits, or subject it to them again.
+pub fn set_rate_limit_exempt_payer_handler(
+    ctx: Context<SetPayerRateLimitConfig>,
+    payer: Pubkey,
+    exempt: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.payer_rate_limit_config;
+    let old_exempt_payers = ConfigHistory::encode_value(&config.exempt_payers)?;
+    if exempt {
+        config.exempt(payer)?;
+    } else {
+        config.unexempt(&payer);
+    }
+    let new_exempt_payers = ConfigHistory::encode_value(&config.exempt_payers)?;
+
+    emit!(PayerRateLimitExemptionUpdated { payer, exempt });
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::RateLimitExemptPayers,
+        &old_exempt_payers,
+        &new_exempt_payers,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::{
+            SetPayerRateLimit as SetPayerRateLimitIx,
+            SetRateLimitExemptPayer as SetRateLimitExemptPayerIx,
+        },
+        test_utils::{
+            config_history_pda, latest_config_change, payer_rate_limit_config_pda, setup_bridge,
+            SetupBridgeResult,
+        },
+        ID,
+    };
+
+    fn send_config_ix(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        data: Vec<u8>,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetPayerRateLimitConfig {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                payer_rate_limit_config: payer_rate_limit_config_pda(),
+                config_history: config_history_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data,
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn read_payer_rate_limit_config(svm: &LiteSVM) -> PayerRateLimitConfig {
+        let account = svm.get_account(&payer_rate_limit_config_pda()).unwrap();
+        PayerRateLimitConfig::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    #[test]
+    fn test_set_payer_rate_limit() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let data = SetPayerRateLimitIx {
+            window_seconds: 60,
+            max_messages: 10,
+            max_gas: 5_000_000,
+        }
+        .data();
+        send_config_ix(&mut svm, &guardian, bridge_pda, data).unwrap();
+
+        let config = read_payer_rate_limit_config(&svm);
+        assert_eq!(config.window_seconds, 60);
+        assert_eq!(config.max_messages, 10);
+        assert_eq!(config.max_gas, 5_000_000);
+        let (_, change) = latest_config_change(&svm);
+        assert_eq!(change.param, ConfigParam::PayerRateLimit as u8);
+
+        let fake_guardian = Keypair::new();
+        svm.airdrop(&fake_guardian.pubkey(), LAMPORTS_PER_SOL)
+            .unwrap();
+        let data = SetPayerRateLimitIx {
+            window_seconds: 0,
+            max_messages: 0,
+            max_gas: 0,
+        }
+        .data();
+        let error = send_config_ix(&mut svm, &fake_guardian, bridge_pda, data).unwrap_err();
+        assert!(
+            error.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_set_rate_limit_exempt_payer() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+
+        for payer in [first, second, first] {
+            let data = SetRateLimitExemptPayerIx {
+                payer,
+                exempt: true,
+            }
+            .data();
+            send_config_ix(&mut svm, &guardian, bridge_pda, data).unwrap();
+        }
+
+        let config = read_payer_rate_limit_config(&svm);
+        assert_eq!(config.exempt_payer_count, 2);
+        assert!(config.is_exempt(&first) && config.is_exempt(&second));
+        // Exempting payers does not enable rate limiting
+        assert!(!config.limits(&Pubkey::new_unique()));
+
+        let data = SetRateLimitExemptPayerIx {
+            payer: first,
+            exempt: false,
+        }
+        .data();
+        send_config_ix(&mut svm, &guardian, bridge_pda, data).unwrap();
+
+        let config = read_payer_rate_limit_config(&svm);
+        assert_eq!(config.exempt_payer_count, 1);
+        assert_eq!(config.exempt_payers[0], second);
+        let (_, change) = latest_config_change(&svm);
+        assert_eq!(change.param, ConfigParam::RateLimitExemptPayers as u8);
+    }
+}
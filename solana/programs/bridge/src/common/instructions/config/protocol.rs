@@ -1,12 +1,17 @@
 use anchor_lang::prelude::*;
 
-use crate::common::SetBridgeConfigFromGuardian;
+use crate::common::{record_config_change, ConfigParam, SetBridgeConfigFromGuardian};
 
 /// Set the block interval requirement
 pub fn set_block_interval_requirement_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_interval: u64,
 ) -> Result<()> {
+    let old_interval = ctx
+        .accounts
+        .bridge
+        .protocol_config
+        .block_interval_requirement;
     ctx.accounts
         .bridge
         .protocol_config
@@ -14,7 +19,13 @@ pub fn set_block_interval_requirement_handler(
 
     ctx.accounts.bridge.protocol_config.validate()?;
 
-    Ok(())
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::BlockIntervalRequirement,
+        &old_interval,
+        &new_interval,
+    )
 }
 
 #[cfg(test)]
@@ -30,7 +41,7 @@ mod tests {
         accounts,
         common::bridge::Bridge,
         instruction::SetBlockIntervalRequirement as SetBlockIntervalRequirementIx,
-        test_utils::{setup_bridge, SetupBridgeResult},
+        test_utils::{config_history_pda, setup_bridge, SetupBridgeResult},
         ID,
     };
 
@@ -50,6 +61,7 @@ mod tests {
         let accounts = accounts::SetBridgeConfigFromGuardian {
             bridge: bridge_pda,
             guardian: guardian.pubkey(),
+            config_history: config_history_pda(),
         }
         .to_account_metas(None);
 
@@ -100,6 +112,7 @@ mod tests {
         let accounts = accounts::SetBridgeConfigFromGuardian {
             bridge: bridge_pda,
             guardian: fake_guardian.pubkey(), // Wrong guardian
+            config_history: config_history_pda(),
         }
         .to_account_metas(None);
 
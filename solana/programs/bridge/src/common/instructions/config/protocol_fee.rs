@@ -0,0 +1,242 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{
+        bridge::Bridge, record_config_change, ConfigHistory, ConfigParam, ProtocolFeeConfig,
+        ProtocolFeeOverride, BRIDGE_SEED, CONFIG_HISTORY_SEED, DISCRIMINATOR_LEN,
+        PROTOCOL_FEE_CONFIG_SEED,
+    },
+    BridgeError,
+};
+
+/// Accounts struct for the guardian-only set_protocol_fee_config instruction.
+/// The protocol fee config is created on first use.
+#[derive(Accounts)]
+pub struct SetProtocolFeeConfig<'info> {
+    /// The guardian account authorized to configure the protocol fee.
+    /// Pays for the protocol fee config creation.
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The protocol fee config account
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        seeds = [PROTOCOL_FEE_CONFIG_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + ProtocolFeeConfig::INIT_SPACE,
+    )]
+    pub protocol_fee_config: Account<'info, ProtocolFeeConfig>,
+
+    /// Log recording the configuration change
+    #[account(mut, seeds = [CONFIG_HISTORY_SEED], bump)]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+
+    /// System program required for creating the protocol fee config account
+    pub system_program: Program<'info, System>,
+}
+
+/// Set or update the protocol fee treasury, the default fee and the per-token overrides.
+pub fn set_protocol_fee_config_handler(
+    ctx: Context<SetProtocolFeeConfig>,
+    treasury: Pubkey,
+    default_bps: u16,
+    overrides: Vec<ProtocolFeeOverride>,
+) -> Result<()> {
+    let protocol_fee_config = &mut ctx.accounts.protocol_fee_config;
+    let old_config = ConfigHistory::encode_value(&**protocol_fee_config)?;
+    protocol_fee_config.set(treasury, default_bps, &overrides)?;
+    let new_config = ConfigHistory::encode_value(&**protocol_fee_config)?;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::ProtocolFeeConfig,
+        &old_config,
+        &new_config,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::MAX_PROTOCOL_FEE_BPS,
+        instruction::SetProtocolFeeConfig as SetProtocolFeeConfigIx,
+        solana_to_base::NATIVE_SOL_PUBKEY,
+        test_utils::{
+            config_history_pda, protocol_fee_config_pda, setup_bridge, SetupBridgeResult,
+            TEST_PROTOCOL_FEE_TREASURY,
+        },
+        ID,
+    };
+
+    fn set_protocol_fee_config(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        default_bps: u16,
+        overrides: Vec<ProtocolFeeOverride>,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetProtocolFeeConfig {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                protocol_fee_config: protocol_fee_config_pda(),
+                config_history: config_history_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: SetProtocolFeeConfigIx {
+                treasury: TEST_PROTOCOL_FEE_TREASURY,
+                default_bps,
+                overrides,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn get_protocol_fee_config(svm: &LiteSVM) -> ProtocolFeeConfig {
+        let account = svm.get_account(&protocol_fee_config_pda()).unwrap();
+        ProtocolFeeConfig::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    #[test]
+    fn test_set_protocol_fee_config_creates_and_updates() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let usdc = Pubkey::new_unique();
+        let sol_override = ProtocolFeeOverride {
+            local_token: NATIVE_SOL_PUBKEY,
+            bps: 0,
+        };
+
+        set_protocol_fee_config(&mut svm, &guardian, bridge_pda, 5, vec![sol_override]).unwrap();
+
+        let config = get_protocol_fee_config(&svm);
+        assert_eq!(config.treasury, TEST_PROTOCOL_FEE_TREASURY);
+        assert_eq!(config.default_bps, 5);
+        assert_eq!(config.override_count, 1);
+        assert_eq!(config.bps(&NATIVE_SOL_PUBKEY), 0);
+        assert_eq!(config.bps(&usdc), 5);
+        assert_eq!(config.fee(&usdc, 1_000_000), 500);
+
+        // Updating replaces the previous content
+        svm.expire_blockhash();
+        set_protocol_fee_config(&mut svm, &guardian, bridge_pda, 10, vec![]).unwrap();
+
+        let config = get_protocol_fee_config(&svm);
+        assert_eq!(config.override_count, 0);
+        assert_eq!(config.bps(&NATIVE_SOL_PUBKEY), 10);
+        assert_eq!(config.overrides[0], ProtocolFeeOverride::default());
+    }
+
+    #[test]
+    fn test_set_protocol_fee_config_unauthorized_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let fake_guardian = Keypair::new();
+        svm.airdrop(&fake_guardian.pubkey(), LAMPORTS_PER_SOL)
+            .unwrap();
+
+        let error =
+            set_protocol_fee_config(&mut svm, &fake_guardian, bridge_pda, 5, vec![]).unwrap_err();
+        assert!(
+            error.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_set_protocol_fee_config_invalid_bps_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let error = set_protocol_fee_config(
+            &mut svm,
+            &guardian,
+            bridge_pda,
+            MAX_PROTOCOL_FEE_BPS + 1,
+            vec![],
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("InvalidProtocolFeeBps"),
+            "Expected InvalidProtocolFeeBps error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_set_protocol_fee_config_duplicate_override_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let sol_override = ProtocolFeeOverride {
+            local_token: NATIVE_SOL_PUBKEY,
+            bps: 1,
+        };
+
+        let error = set_protocol_fee_config(
+            &mut svm,
+            &guardian,
+            bridge_pda,
+            5,
+            vec![sol_override, sol_override],
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("DuplicateProtocolFeeOverride"),
+            "Expected DuplicateProtocolFeeOverride error, got: {}",
+            error
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::{constants::RELAY_DENY_LIST_SEED, RelayDenyList},
+    common::{
+        bridge::Bridge, record_config_change, ConfigHistory, ConfigParam, BRIDGE_SEED,
+        CONFIG_HISTORY_SEED, DISCRIMINATOR_LEN,
+    },
+    BridgeError,
+};
+
+/// Accounts struct for the guardian-only set_relay_deny_list instruction.
+/// The relay deny-list is created on first use.
+#[derive(Accounts)]
+pub struct SetRelayDenyList<'info> {
+    /// The guardian account authorized to configure the relay deny-list.
+    /// Pays for the relay deny-list creation.
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The relay deny-list account
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        seeds = [RELAY_DENY_LIST_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + RelayDenyList::INIT_SPACE,
+    )]
+    pub relay_deny_list: Account<'info, RelayDenyList>,
+
+    /// Log recording the configuration change
+    #[account(mut, seeds = [CONFIG_HISTORY_SEED], bump)]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+
+    /// System program required for creating the relay deny-list account
+    pub system_program: Program<'info, System>,
+}
+
+/// Set the programs that relayed messages can never invoke, on top of the built-in ones.
+pub fn set_relay_deny_list_handler(
+    ctx: Context<SetRelayDenyList>,
+    programs: Vec<Pubkey>,
+) -> Result<()> {
+    let relay_deny_list = &mut ctx.accounts.relay_deny_list;
+    let old_deny_list = ConfigHistory::encode_value(&**relay_deny_list)?;
+    relay_deny_list.set(&programs)?;
+    let new_deny_list = ConfigHistory::encode_value(&**relay_deny_list)?;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::RelayDenyList,
+        &old_deny_list,
+        &new_deny_list,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        base_to_solana::constants::MAX_RELAY_DENY_LIST_PROGRAMS,
+        instruction::SetRelayDenyList as SetRelayDenyListIx,
+        test_utils::{config_history_pda, relay_deny_list_pda, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn set_relay_deny_list(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        programs: Vec<Pubkey>,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetRelayDenyList {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                relay_deny_list: relay_deny_list_pda(),
+                config_history: config_history_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: SetRelayDenyListIx { programs }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_set_relay_deny_list() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let denied = Pubkey::new_unique();
+
+        set_relay_deny_list(&mut svm, &guardian, bridge_pda, vec![denied]).unwrap();
+
+        let account = svm.get_account(&relay_deny_list_pda()).unwrap();
+        let relay_deny_list = RelayDenyList::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(relay_deny_list.program_count, 1);
+        assert_eq!(relay_deny_list.programs[0], denied);
+
+        let error = set_relay_deny_list(
+            &mut svm,
+            &guardian,
+            bridge_pda,
+            vec![denied; MAX_RELAY_DENY_LIST_PROGRAMS as usize + 1],
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("TooManyDeniedRelayPrograms"),
+            "Expected TooManyDeniedRelayPrograms error, got: {}",
+            error
+        );
+
+        let fake_guardian = Keypair::new();
+        svm.airdrop(&fake_guardian.pubkey(), LAMPORTS_PER_SOL)
+            .unwrap();
+        let error = set_relay_deny_list(&mut svm, &fake_guardian, bridge_pda, vec![]).unwrap_err();
+        assert!(
+            error.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error
+        );
+    }
+}
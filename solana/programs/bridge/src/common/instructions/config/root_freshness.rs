@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use crate::common::{
+    bridge::RootFreshnessConfig, record_config_change, ConfigParam,
+    SetBridgeConfigFromUpgradeAuthority,
+};
+
+/// Set the maximum age of the output roots each message kind can be proven against.
+/// Only the upgrade authority can call this function so that the guardian cannot loosen the
+/// freshness policy of high-risk messages.
+pub fn set_root_freshness_config_handler(
+    ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
+    cfg: RootFreshnessConfig,
+) -> Result<()> {
+    let old_cfg = std::mem::replace(&mut ctx.accounts.bridge.root_freshness_config, cfg);
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.upgrade_authority,
+        ConfigParam::RootFreshnessConfig,
+        &old_cfg,
+        &ctx.accounts.bridge.root_freshness_config,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::{
+        solana_program::{bpf_loader_upgradeable, instruction::Instruction},
+        InstructionData,
+    };
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts, common::bridge::Bridge, instruction::SetRootFreshnessConfig, test_utils::*, ID,
+    };
+
+    fn set_root_freshness_config(
+        svm: &mut litesvm::LiteSVM,
+        authority: &Keypair,
+        bridge_pda: Pubkey,
+        new_config: RootFreshnessConfig,
+    ) -> std::result::Result<(), String> {
+        let (program_data_pda, _) =
+            Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromUpgradeAuthority {
+                upgrade_authority: authority.pubkey(),
+                bridge: bridge_pda,
+                program_data: program_data_pda,
+                program: ID,
+                config_history: config_history_pda(),
+            }
+            .to_account_metas(None),
+            data: SetRootFreshnessConfig { new_config }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[authority],
+            Message::new(&[ix], Some(&authority.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_set_root_freshness_config_with_upgrade_authority_succeeds() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let new_config = RootFreshnessConfig {
+            call_with_signers_max_root_age: 300,
+            ..Default::default()
+        };
+        set_root_freshness_config(&mut svm, &payer, bridge_pda, new_config.clone())
+            .expect("Transaction should succeed with upgrade authority");
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(bridge.root_freshness_config, new_config);
+
+        let (_, change) = latest_config_change(&svm);
+        assert_eq!(change.param, ConfigParam::RootFreshnessConfig as u8);
+    }
+
+    #[test]
+    fn test_set_root_freshness_config_with_guardian_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let error_string = set_root_freshness_config(
+            &mut svm,
+            &guardian,
+            bridge_pda,
+            RootFreshnessConfig::default(),
+        )
+        .unwrap_err();
+        assert!(
+            error_string.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error_string
+        );
+    }
+}
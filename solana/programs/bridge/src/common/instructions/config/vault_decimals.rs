@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::{
+    common::{
+        bridge::Bridge, record_config_change, ConfigHistory, ConfigParam, TokenDeposits,
+        VaultDecimals, BRIDGE_SEED, CONFIG_HISTORY_SEED, DISCRIMINATOR_LEN, TOKEN_DEPOSITS_SEED,
+        TOKEN_VAULT_SEED, VAULT_DECIMALS_SEED,
+    },
+    BridgeError, ID,
+};
+
+/// Accounts struct for the guardian-only set_vault_decimals instruction.
+/// The vault decimals are created on first use and can only change while the vault is empty.
+#[derive(Accounts)]
+#[instruction(remote_token: [u8; 20])]
+pub struct SetVaultDecimals<'info> {
+    /// The guardian account authorized to configure the vault decimals.
+    /// Pays for the vault decimals creation.
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The SPL mint locked in the token vault
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The token vault of the mint and the remote token, possibly not created yet.
+    ///
+    /// CHECK: PDA with TOKEN_VAULT_SEED, only used to derive the vault decimals address.
+    #[account(seeds = [TOKEN_VAULT_SEED, mint.key().as_ref(), remote_token.as_ref()], bump)]
+    pub token_vault: UncheckedAccount<'info>,
+
+    /// Accounting for the amount locked in the token vault, possibly not created yet.
+    ///
+    /// CHECK: PDA with TOKEN_DEPOSITS_SEED, deserialized only if owned by this program.
+    #[account(seeds = [TOKEN_DEPOSITS_SEED, token_vault.key().as_ref()], bump)]
+    pub token_deposits: UncheckedAccount<'info>,
+
+    /// The vault decimals account
+    #[account(
+        init_if_needed,
+        payer = guardian,
+        seeds = [VAULT_DECIMALS_SEED, token_vault.key().as_ref()],
+        bump,
+        space = DISCRIMINATOR_LEN + VaultDecimals::INIT_SPACE,
+    )]
+    pub vault_decimals: Account<'info, VaultDecimals>,
+
+    /// Log recording the configuration change
+    #[account(mut, seeds = [CONFIG_HISTORY_SEED], bump)]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+
+    /// System program required for creating the vault decimals account
+    pub system_program: Program<'info, System>,
+}
+
+/// Set the decimals of the Base token that the token vault of `mint` and `remote_token` is bridged
+/// to. Amounts are carried in the remote token's smallest unit from then on.
+pub fn set_vault_decimals_handler(
+    ctx: Context<SetVaultDecimals>,
+    _remote_token: [u8; 20],
+    remote_decimals: u8,
+) -> Result<()> {
+    let local_decimals = ctx.accounts.mint.decimals;
+    require!(
+        remote_decimals <= local_decimals,
+        BridgeError::InvalidRemoteDecimals
+    );
+
+    // Locked deposits and in-flight messages were accounted for with the previous decimals
+    let token_deposits = &ctx.accounts.token_deposits;
+    if token_deposits.owner == &ID && !token_deposits.data_is_empty() {
+        let token_deposits =
+            TokenDeposits::try_deserialize(&mut &token_deposits.try_borrow_data()?[..])?;
+        require!(
+            token_deposits.locked_amount == 0,
+            BridgeError::VaultHasDeposits
+        );
+    }
+
+    let vault_decimals = &mut ctx.accounts.vault_decimals;
+    let old_decimals = ConfigHistory::encode_value(&**vault_decimals)?;
+    vault_decimals.local_decimals = local_decimals;
+    vault_decimals.remote_decimals = remote_decimals;
+    let new_decimals = ConfigHistory::encode_value(&**vault_decimals)?;
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::VaultDecimals,
+        &old_decimals,
+        &new_decimals,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::SetVaultDecimals as SetVaultDecimalsIx,
+        test_utils::{
+            config_history_pda, create_mock_mint, set_program_account, setup_bridge,
+            token_deposits_pda, vault_decimals_pda, SetupBridgeResult,
+        },
+    };
+
+    const REMOTE_TOKEN: [u8; 20] = [2; 20];
+
+    fn token_vault_pda(mint: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[TOKEN_VAULT_SEED, mint.as_ref(), REMOTE_TOKEN.as_ref()],
+            &ID,
+        )
+        .0
+    }
+
+    fn set_vault_decimals(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        mint: Pubkey,
+        remote_decimals: u8,
+    ) -> std::result::Result<(), String> {
+        let token_vault = token_vault_pda(&mint);
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetVaultDecimals {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                mint,
+                token_vault,
+                token_deposits: token_deposits_pda(&token_vault),
+                vault_decimals: vault_decimals_pda(&token_vault),
+                config_history: config_history_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: SetVaultDecimalsIx {
+                remote_token: REMOTE_TOKEN,
+                remote_decimals,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_set_vault_decimals_sets_scaler_exponent() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let mint = Pubkey::new_unique();
+        create_mock_mint(&mut svm, mint, 12, anchor_spl::token_interface::ID);
+
+        set_vault_decimals(&mut svm, &guardian, bridge_pda, mint, 6).unwrap();
+
+        let account = svm
+            .get_account(&vault_decimals_pda(&token_vault_pda(&mint)))
+            .unwrap();
+        let vault_decimals = VaultDecimals::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(
+            vault_decimals,
+            VaultDecimals {
+                local_decimals: 12,
+                remote_decimals: 6,
+            }
+        );
+        assert_eq!(vault_decimals.scaler_exponent(), 6);
+
+        // Remote decimals above the mint decimals are handled by Base
+        svm.expire_blockhash();
+        let error = set_vault_decimals(&mut svm, &guardian, bridge_pda, mint, 13).unwrap_err();
+        assert!(
+            error.contains("InvalidRemoteDecimals"),
+            "Expected InvalidRemoteDecimals error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_set_vault_decimals_with_deposits_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        let mint = Pubkey::new_unique();
+        create_mock_mint(&mut svm, mint, 12, anchor_spl::token_interface::ID);
+        let token_vault = token_vault_pda(&mint);
+        set_program_account(
+            &mut svm,
+            token_deposits_pda(&token_vault),
+            &TokenDeposits {
+                vault: token_vault,
+                locked_amount: 1,
+            },
+        );
+
+        let error = set_vault_decimals(&mut svm, &guardian, bridge_pda, mint, 6).unwrap_err();
+        assert!(
+            error.contains("VaultHasDeposits"),
+            "Expected VaultHasDeposits error, got: {}",
+            error
+        );
+
+        let fake_guardian = Keypair::new();
+        svm.airdrop(&fake_guardian.pubkey(), LAMPORTS_PER_SOL)
+            .unwrap();
+        let error = set_vault_decimals(&mut svm, &fake_guardian, bridge_pda, mint, 6).unwrap_err();
+        assert!(
+            error.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{record_config_change, ConfigParam, SetBridgeConfigFromGuardian},
+    WrappedTokenConfirmationRequiredSet,
+};
+
+/// Require, or no longer require, wrapped tokens to be confirmed by the Base Bridge before
+/// `bridge_wrapped_token` accepts them.
+/// Only the guardian can call this function
+pub fn set_wrapped_token_confirmation_required_handler(
+    ctx: Context<SetBridgeConfigFromGuardian>,
+    required: bool,
+) -> Result<()> {
+    let bridge = &mut ctx.accounts.bridge;
+    let was_required = bridge.require_wrapped_token_confirmation;
+    bridge.require_wrapped_token_confirmation = required;
+
+    emit!(WrappedTokenConfirmationRequiredSet { required });
+
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::WrappedTokenConfirmation,
+        &was_required,
+        &required,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        InstructionData,
+    };
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::bridge::Bridge,
+        instruction::SetWrappedTokenConfirmationRequired as SetWrappedTokenConfirmationRequiredIx,
+        test_utils::{config_history_pda, latest_config_change, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn set_required_ix(bridge_pda: Pubkey, guardian: Pubkey, required: bool) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::SetBridgeConfigFromGuardian {
+                bridge: bridge_pda,
+                guardian,
+                config_history: config_history_pda(),
+            }
+            .to_account_metas(None),
+            data: SetWrappedTokenConfirmationRequiredIx { required }.data(),
+        }
+    }
+
+    #[test]
+    fn test_set_wrapped_token_confirmation_required() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(
+                &[set_required_ix(bridge_pda, guardian.pubkey(), true)],
+                Some(&guardian.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+
+        let account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &account.data[..]).unwrap();
+        assert!(bridge.require_wrapped_token_confirmation);
+        let (_, change) = latest_config_change(&svm);
+        assert_eq!(change.param, ConfigParam::WrappedTokenConfirmation as u8);
+
+        let fake_guardian = Keypair::new();
+        svm.airdrop(&fake_guardian.pubkey(), LAMPORTS_PER_SOL)
+            .unwrap();
+        let tx = Transaction::new(
+            &[&fake_guardian],
+            Message::new(
+                &[set_required_ix(bridge_pda, fake_guardian.pubkey(), false)],
+                Some(&fake_guardian.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let error = format!("{:?}", svm.send_transaction(tx).unwrap_err());
+        assert!(
+            error.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{InsuranceFund, INSURANCE_FUND_SEED},
+    BridgeError, InsurancePayoutExecuted,
+};
+
+/// Accounts for the permissionless execute_insurance_payout instruction that pays out the
+/// insurance payout requested by the guardian once its timelock elapsed.
+#[derive(Accounts)]
+pub struct ExecuteInsurancePayout<'info> {
+    /// The insurance fund holding the pending payout
+    #[account(mut, seeds = [INSURANCE_FUND_SEED], bump)]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    /// The recipient of the pending payout.
+    /// CHECK: Validated to be the recipient of the pending payout.
+    #[account(mut)]
+    pub recipient: AccountInfo<'info>,
+}
+
+/// Transfers the pending payout to its recipient. The fund keeps its rent-exempt minimum.
+pub fn execute_insurance_payout_handler(ctx: Context<ExecuteInsurancePayout>) -> Result<()> {
+    let insurance_fund = &mut ctx.accounts.insurance_fund;
+    let Some(payout) = insurance_fund.pending_payout else {
+        return err!(BridgeError::NoPendingInsurancePayout);
+    };
+    require_keys_eq!(
+        ctx.accounts.recipient.key(),
+        payout.recipient,
+        BridgeError::IncorrectInsurancePayoutRecipient
+    );
+    require!(
+        Clock::get()?.unix_timestamp >= payout.executable_at,
+        BridgeError::InsurancePayoutTimelocked
+    );
+
+    let fund_info = insurance_fund.to_account_info();
+    let available = fund_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(fund_info.data_len()));
+    require!(
+        payout.amount <= available,
+        BridgeError::InsufficientInsuranceFund
+    );
+
+    fund_info.sub_lamports(payout.amount)?;
+    ctx.accounts.recipient.add_lamports(payout.amount)?;
+
+    insurance_fund.pending_payout = None;
+    insurance_fund.total_paid = insurance_fund.total_paid.saturating_add(payout.amount);
+
+    emit!(InsurancePayoutExecuted {
+        recipient: payout.recipient,
+        amount: payout.amount,
+        total_paid: insurance_fund.total_paid,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::INSURANCE_PAYOUT_TIMELOCK_SECONDS,
+        instruction::{
+            ExecuteInsurancePayout as ExecuteInsurancePayoutIx, RequestInsurancePayout,
+            SetInsuranceShare,
+        },
+        test_utils::{
+            config_history_pda, insurance_fund_pda, mock_clock, setup_bridge, SetupBridgeResult,
+        },
+        ID,
+    };
+
+    fn send(
+        svm: &mut LiteSVM,
+        signer: &Keypair,
+        ix: Instruction,
+    ) -> std::result::Result<(), String> {
+        let tx = Transaction::new(
+            &[signer],
+            Message::new(&[ix], Some(&signer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn execute_insurance_payout(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        recipient: Pubkey,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::ExecuteInsurancePayout {
+                insurance_fund: insurance_fund_pda(),
+                recipient,
+            }
+            .to_account_metas(None),
+            data: ExecuteInsurancePayoutIx {}.data(),
+        };
+        send(svm, payer, ix)
+    }
+
+    #[test]
+    fn test_execute_insurance_payout_after_timelock() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+        } = setup_bridge();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetInsuranceShare {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                insurance_fund: insurance_fund_pda(),
+                config_history: config_history_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: SetInsuranceShare { share_bps: 1_000 }.data(),
+        };
+        send(&mut svm, &guardian, ix).unwrap();
+        svm.airdrop(&insurance_fund_pda(), LAMPORTS_PER_SOL)
+            .unwrap();
+
+        mock_clock(&mut svm, 1_000);
+        let recipient = Pubkey::new_unique();
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::ManageInsurancePayout {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                insurance_fund: insurance_fund_pda(),
+            }
+            .to_account_metas(None),
+            data: RequestInsurancePayout {
+                recipient,
+                amount: LAMPORTS_PER_SOL,
+            }
+            .data(),
+        };
+        send(&mut svm, &guardian, ix).unwrap();
+
+        let error = execute_insurance_payout(&mut svm, &payer, recipient).unwrap_err();
+        assert!(
+            error.contains("InsurancePayoutTimelocked"),
+            "Expected InsurancePayoutTimelocked error, got: {}",
+            error
+        );
+
+        mock_clock(&mut svm, 1_000 + INSURANCE_PAYOUT_TIMELOCK_SECONDS);
+        svm.expire_blockhash();
+        let error = execute_insurance_payout(&mut svm, &payer, payer.pubkey()).unwrap_err();
+        assert!(
+            error.contains("IncorrectInsurancePayoutRecipient"),
+            "Expected IncorrectInsurancePayoutRecipient error, got: {}",
+            error
+        );
+
+        execute_insurance_payout(&mut svm, &payer, recipient).expect("Failed to execute payout");
+        assert_eq!(
+            svm.get_account(&recipient).unwrap().lamports,
+            LAMPORTS_PER_SOL
+        );
+
+        let account = svm.get_account(&insurance_fund_pda()).unwrap();
+        let insurance_fund = InsuranceFund::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(insurance_fund.total_paid, LAMPORTS_PER_SOL);
+        assert_eq!(insurance_fund.pending_payout, None);
+    }
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{program::Bridge as BridgeProgram, Environment, ENVIRONMENT};
+
+/// Accounts struct for the get_environment view instruction. Only the bridge program itself is
+/// required, as Anchor cannot generate the CPI client of an instruction without accounts.
+#[derive(Accounts)]
+pub struct GetEnvironment<'info> {
+    /// The bridge program
+    pub program: Program<'info, BridgeProgram>,
+}
+
+pub fn get_environment_handler(_ctx: Context<GetEnvironment>) -> Result<Environment> {
+    Ok(ENVIRONMENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::GetEnvironment as GetEnvironmentIx,
+        test_utils::{setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    #[test]
+    fn test_get_environment_returns_compiled_environment() {
+        let SetupBridgeResult { mut svm, payer, .. } = setup_bridge();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::GetEnvironment { program: ID }.to_account_metas(None),
+            data: GetEnvironmentIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let meta = svm.send_transaction(tx).expect("Failed to get environment");
+
+        assert_eq!(meta.return_data.program_id, ID);
+        let environment = Environment::try_from_slice(&meta.return_data.data).unwrap();
+        assert_eq!(environment, Environment::Localnet);
+    }
+}
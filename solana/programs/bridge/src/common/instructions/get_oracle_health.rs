@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+
+use crate::common::{
+    bridge::{Bridge, OracleHeartbeat},
+    BRIDGE_SEED,
+};
+
+/// Liveness of the Base oracle quorum returned by `get_oracle_health`.
+#[derive(Debug, Clone, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct OracleHealthReport {
+    /// Number of required valid unique Base oracle signatures
+    pub threshold: u8,
+    /// Number of Base oracle signers that signed an output root within the requested age
+    pub live_signer_count: u8,
+    /// Whether the live signers alone still meet the threshold
+    pub quorum_live: bool,
+    /// Last heartbeat of each Base oracle signer, in signer order (0 if it never signed)
+    pub heartbeats: Vec<OracleHeartbeat>,
+}
+
+/// Accounts struct for the get_oracle_health view instruction.
+#[derive(Accounts)]
+pub struct GetOracleHealth<'info> {
+    /// The bridge account holding the Base oracle signers and their heartbeats
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+}
+
+pub fn get_oracle_health_handler(
+    ctx: Context<GetOracleHealth>,
+    max_age_seconds: u64,
+) -> Result<OracleHealthReport> {
+    let bridge = &ctx.accounts.bridge;
+    let base_oracle_config = &bridge.base_oracle_config;
+    let now = Clock::get()?.unix_timestamp;
+
+    let active_len = core::cmp::min(
+        base_oracle_config.signer_count as usize,
+        base_oracle_config.signers.len(),
+    );
+    let heartbeats: Vec<OracleHeartbeat> = base_oracle_config.signers[..active_len]
+        .iter()
+        .map(|signer| OracleHeartbeat {
+            signer: *signer,
+            last_signed_at: bridge.oracle_health.last_signed_at(signer),
+        })
+        .collect();
+
+    let live_signer_count = heartbeats
+        .iter()
+        .filter(|heartbeat| {
+            heartbeat.last_signed_at > 0
+                && now.saturating_sub(heartbeat.last_signed_at).max(0) as u64 <= max_age_seconds
+        })
+        .count() as u8;
+
+    Ok(OracleHealthReport {
+        threshold: base_oracle_config.threshold,
+        live_signer_count,
+        quorum_live: live_signer_count >= base_oracle_config.threshold,
+        heartbeats,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::GetOracleHealth as GetOracleHealthIx,
+        test_utils::{mock_clock, set_program_account, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    #[test]
+    fn test_get_oracle_health_reports_live_signers() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // Two of three signers are required; only the first one signed recently
+        let account = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &account.data[..]).unwrap();
+        bridge.base_oracle_config.threshold = 2;
+        bridge.base_oracle_config.signer_count = 3;
+        bridge.base_oracle_config.signers[0] = [1u8; 20];
+        bridge.base_oracle_config.signers[1] = [2u8; 20];
+        bridge.base_oracle_config.signers[2] = [3u8; 20];
+        bridge
+            .oracle_health
+            .record(&bridge.base_oracle_config, &[[1u8; 20], [2u8; 20]], 1_000);
+        bridge
+            .oracle_health
+            .record(&bridge.base_oracle_config, &[[1u8; 20]], 5_000);
+        set_program_account(&mut svm, bridge_pda, &bridge);
+        mock_clock(&mut svm, 5_500);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::GetOracleHealth { bridge: bridge_pda }.to_account_metas(None),
+            data: GetOracleHealthIx {
+                max_age_seconds: 3_600,
+            }
+            .data(),
+        };
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("Failed to get oracle health");
+
+        assert_eq!(meta.return_data.program_id, ID);
+        let report = OracleHealthReport::try_from_slice(&meta.return_data.data).unwrap();
+        assert_eq!(
+            report,
+            OracleHealthReport {
+                threshold: 2,
+                live_signer_count: 1,
+                quorum_live: false,
+                heartbeats: vec![
+                    OracleHeartbeat {
+                        signer: [1u8; 20],
+                        last_signed_at: 5_000,
+                    },
+                    OracleHeartbeat {
+                        signer: [2u8; 20],
+                        last_signed_at: 1_000,
+                    },
+                    OracleHeartbeat {
+                        signer: [3u8; 20],
+                        last_signed_at: 0,
+                    },
+                ],
+            }
+        );
+    }
+}
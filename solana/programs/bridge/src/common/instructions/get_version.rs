@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::{program::Bridge as BridgeProgram, Version, VERSION};
+
+/// Accounts struct for the get_version view instruction. Only the bridge program itself is
+/// required, as Anchor cannot generate the CPI client of an instruction without accounts.
+#[derive(Accounts)]
+pub struct GetVersion<'info> {
+    /// The bridge program
+    pub program: Program<'info, BridgeProgram>,
+}
+
+pub fn get_version_handler(_ctx: Context<GetVersion>) -> Result<Version> {
+    Ok(VERSION)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::GetVersion as GetVersionIx,
+        test_utils::{setup_bridge, SetupBridgeResult},
+        CAPABILITY_BATCHED_OUTPUT_ROOTS, CAPABILITY_BUFFERED_CALLS, CAPABILITY_TRANSFER_MEMO, ID,
+    };
+
+    #[test]
+    fn test_get_version_returns_compiled_version() {
+        let SetupBridgeResult { mut svm, payer, .. } = setup_bridge();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::GetVersion { program: ID }.to_account_metas(None),
+            data: GetVersionIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let meta = svm.send_transaction(tx).expect("Failed to get version");
+
+        assert_eq!(meta.return_data.program_id, ID);
+        let version = Version::try_from_slice(&meta.return_data.data).unwrap();
+        assert_eq!(version, VERSION);
+        assert!(version.supports(CAPABILITY_BUFFERED_CALLS | CAPABILITY_BATCHED_OUTPUT_ROOTS));
+        assert!(version.supports(CAPABILITY_TRANSFER_MEMO));
+        assert!(!version.supports(1 << 63));
+    }
+
+    #[test]
+    fn test_version_matches_crate_version() {
+        assert_eq!(
+            format!("{}.{}.{}", VERSION.major, VERSION.minor, VERSION.patch),
+            env!("CARGO_PKG_VERSION")
+        );
+    }
+}
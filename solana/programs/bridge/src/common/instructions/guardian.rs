@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
 
-use crate::common::config::SetBridgeConfigFromGuardian;
+use crate::common::{
+    config::{record_config_change, SetBridgeConfigFromGuardian},
+    ConfigParam,
+};
 
 /// Transfer guardian authority to a new pubkey.
 /// Only the current guardian can call this function.
@@ -10,9 +13,16 @@ pub fn transfer_guardian_handler(
     ctx: Context<SetBridgeConfigFromGuardian>,
     new_guardian: Pubkey,
 ) -> Result<()> {
+    let old_guardian = ctx.accounts.bridge.guardian;
     ctx.accounts.bridge.guardian = new_guardian;
 
-    Ok(())
+    record_config_change(
+        &ctx.accounts.config_history,
+        &ctx.accounts.guardian,
+        ConfigParam::Guardian,
+        &old_guardian,
+        &new_guardian,
+    )
 }
 
 #[cfg(test)]
@@ -29,7 +39,7 @@ mod tests {
         accounts,
         common::bridge::Bridge,
         instruction::TransferGuardian as TransferGuardianIx,
-        test_utils::{setup_bridge, SetupBridgeResult},
+        test_utils::{config_history_pda, setup_bridge, SetupBridgeResult},
         ID,
     };
 
@@ -49,6 +59,7 @@ mod tests {
         let accounts = accounts::SetBridgeConfigFromGuardian {
             bridge: bridge_pda,
             guardian: guardian.pubkey(),
+            config_history: config_history_pda(),
         }
         .to_account_metas(None);
 
@@ -102,6 +113,7 @@ mod tests {
         let accounts = accounts::SetBridgeConfigFromGuardian {
             bridge: bridge_pda,
             guardian: fake_guardian.pubkey(), // Wrong guardian
+            config_history: config_history_pda(),
         }
         .to_account_metas(None);
 
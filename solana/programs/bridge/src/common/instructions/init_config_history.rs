@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+use crate::common::{ConfigHistory, CONFIG_HISTORY_SEED, DISCRIMINATOR_LEN};
+
+/// Accounts for the permissionless init_config_history instruction that creates the
+/// `ConfigHistory` log written by every configuration setter.
+#[derive(Accounts)]
+pub struct InitConfigHistory<'info> {
+    /// The account that pays for the config history account creation.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The config history account.
+    /// Can only be created once.
+    #[account(
+        init,
+        payer = payer,
+        seeds = [CONFIG_HISTORY_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + std::mem::size_of::<ConfigHistory>()
+    )]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+
+    /// System program required for creating the config history account.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn init_config_history_handler(ctx: Context<InitConfigHistory>) -> Result<()> {
+    ctx.accounts.config_history.load_init()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, system_program, InstructionData};
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::InitConfigHistory as InitConfigHistoryIx,
+        test_utils::{config_history_pda, latest_config_change, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    #[test]
+    fn test_init_config_history_starts_empty() {
+        // `setup_bridge` already initializes the config history.
+        let svm = setup_bridge().svm;
+
+        let account = svm.get_account(&config_history_pda()).unwrap();
+        assert_eq!(
+            account.data.len(),
+            DISCRIMINATOR_LEN + std::mem::size_of::<ConfigHistory>()
+        );
+        assert_eq!(latest_config_change(&svm).0, 0);
+    }
+
+    #[test]
+    fn test_init_config_history_twice_fails() {
+        let SetupBridgeResult { mut svm, payer, .. } = setup_bridge();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::InitConfigHistory {
+                payer: payer.pubkey(),
+                config_history: config_history_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: InitConfigHistoryIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("already in use"),
+            "Expected account already in use error, got: {}",
+            error_string
+        );
+    }
+}
@@ -0,0 +1,141 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+};
+
+use crate::common::{SolVaultState, DISCRIMINATOR_LEN, SOL_VAULT_SEED, SOL_VAULT_STATE_SEED};
+
+/// Accounts for the permissionless init_sol_vault instruction that explicitly initializes the
+/// SOL vault before any SOL can be bridged.
+#[derive(Accounts)]
+pub struct InitSolVault<'info> {
+    /// The account that pays for the vault rent and the state account creation.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The SOL vault that holds the locked SOL.
+    /// - Funded up to rent exemption so it always exists once initialized
+    ///
+    /// CHECK: This is the SOL vault account.
+    #[account(mut, seeds = [SOL_VAULT_SEED], bump)]
+    pub sol_vault: AccountInfo<'info>,
+
+    /// The SOL vault state recording the vault bump.
+    /// Can only be created once.
+    #[account(
+        init,
+        payer = payer,
+        seeds = [SOL_VAULT_STATE_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + SolVaultState::INIT_SPACE
+    )]
+    pub sol_vault_state: Account<'info, SolVaultState>,
+
+    /// System program required for funding the vault and creating the state account.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn init_sol_vault_handler(ctx: Context<InitSolVault>) -> Result<()> {
+    fund_sol_vault(
+        &ctx.accounts.payer,
+        &ctx.accounts.sol_vault,
+        &ctx.accounts.system_program,
+    )?;
+
+    ctx.accounts.sol_vault_state.bump = ctx.bumps.sol_vault;
+
+    Ok(())
+}
+
+/// Tops up the SOL vault to rent exemption so that it always exists once initialized.
+pub fn fund_sol_vault<'info>(
+    payer: &Signer<'info>,
+    sol_vault: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let required_lamports = Rent::get()?.minimum_balance(0);
+    let current_lamports = sol_vault.lamports();
+    if required_lamports > current_lamports {
+        let cpi_ctx = CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: payer.to_account_info(),
+                to: sol_vault.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_ctx, required_lamports - current_lamports)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::InitSolVault as InitSolVaultIx,
+        test_utils::{deploy_bridge, setup_bridge, sol_vault_state_pda, DeployBridgeResult},
+        ID,
+    };
+
+    #[test]
+    fn test_init_sol_vault_records_bump() {
+        // `setup_bridge` already initializes the SOL vault.
+        let svm = setup_bridge().svm;
+
+        let (sol_vault, bump) = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID);
+        let state = svm.get_account(&sol_vault_state_pda()).unwrap();
+        let state = SolVaultState::try_deserialize(&mut &state.data[..]).unwrap();
+        assert_eq!(state, SolVaultState { bump });
+
+        let vault = svm.get_account(&sol_vault).unwrap();
+        assert_eq!(vault.lamports, svm.minimum_balance_for_rent_exemption(0));
+    }
+
+    #[test]
+    fn test_init_sol_vault_twice_fails() {
+        let DeployBridgeResult { mut svm, payer, .. } = deploy_bridge();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::InitSolVault {
+                payer: payer.pubkey(),
+                sol_vault: Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0,
+                sol_vault_state: sol_vault_state_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: InitSolVaultIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(std::slice::from_ref(&ix), Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to initialize SOL vault");
+        svm.expire_blockhash();
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("already in use"),
+            "Expected account already in use error, got: {}",
+            error_string
+        );
+    }
+}
@@ -2,8 +2,11 @@ use anchor_lang::prelude::*;
 
 use crate::{
     common::{
-        bridge::{Bridge, Eip1559},
-        Config, BRIDGE_SEED, DISCRIMINATOR_LEN,
+        bridge::{
+            BaseGovernance, Bridge, Eip1559, FeeQuoteGrace, FinalizationDelay, OracleFailover,
+            OracleHealth, Outbox, OutflowBreaker, RootFreshnessConfig,
+        },
+        Config, BRIDGE_LAYOUT_VERSION, BRIDGE_SEED, DISCRIMINATOR_LEN,
     },
     program::Bridge as BridgeProgram,
     BridgeError,
@@ -14,6 +17,7 @@ use crate::{
 /// Solana, using the provided configuration values and initializing counters/state to zero.
 /// Only the upgrade authority can initialize the bridge for security.
 #[derive(Accounts)]
+#[instruction(guardian: Pubkey, cfg: Config)]
 pub struct Initialize<'info> {
     /// The upgrade authority that is authorized to initialize the bridge.
     /// This ensures only the program deployer can set the initial configuration.
@@ -54,6 +58,11 @@ pub struct Initialize<'info> {
     )]
     pub program: Program<'info, BridgeProgram>,
 
+    /// The gas fee receiver configured in `cfg.gas_config`. Must be a system account.
+    /// CHECK: Checked against `cfg.gas_config.gas_fee_receiver` and validated in the handler.
+    #[account(address = cfg.gas_config.gas_fee_receiver @ BridgeError::IncorrectGasFeeReceiver)]
+    pub gas_fee_receiver: AccountInfo<'info>,
+
     /// System program required for creating new accounts.
     /// Used internally by Anchor for account initialization.
     pub system_program: Program<'info, System>,
@@ -63,12 +72,21 @@ pub struct Initialize<'info> {
 /// starts unpaused, zeros counters, sets the EIP-1559 base fee to `eip1559_config.minimum_base_fee`,
 /// and records the current timestamp as the window start.
 pub fn initialize_handler(ctx: Context<Initialize>, guardian: Pubkey, cfg: Config) -> Result<()> {
-    let current_timestamp = Clock::get()?.unix_timestamp;
-    let minimum_base_fee = cfg.eip1559_config.minimum_base_fee;
-
     cfg.validate()?;
+    cfg.gas_config
+        .validate_gas_fee_receiver(&ctx.accounts.gas_fee_receiver)?;
+
+    *ctx.accounts.bridge = new_bridge(guardian, cfg, Clock::get()?.unix_timestamp);
+
+    Ok(())
+}
+
+/// Builds the initial `Bridge` state for the given guardian and (already validated) configs.
+pub fn new_bridge(guardian: Pubkey, cfg: Config, current_timestamp: i64) -> Bridge {
+    let minimum_base_fee = cfg.eip1559_config.minimum_base_fee;
 
-    *ctx.accounts.bridge = Bridge {
+    Bridge {
+        version: BRIDGE_LAYOUT_VERSION,
         base_block_number: 0,
         nonce: 0,
         guardian,
@@ -84,9 +102,18 @@ pub fn initialize_handler(ctx: Context<Initialize>, guardian: Pubkey, cfg: Confi
         buffer_config: cfg.buffer_config,
         partner_oracle_config: cfg.partner_oracle_config,
         base_oracle_config: cfg.base_oracle_config,
-    };
-
-    Ok(())
+        oracle_failover: OracleFailover::default(),
+        root_freshness_config: RootFreshnessConfig::default(),
+        oracle_health: OracleHealth::default(),
+        outflow_breaker: OutflowBreaker::default(),
+        finalization_delay: FinalizationDelay::default(),
+        base_governance: BaseGovernance::default(),
+        outbox: Outbox::default(),
+        guarded_launch: false,
+        transfer_gas_lane: Eip1559::default(),
+        require_wrapped_token_confirmation: false,
+        fee_quote_grace: FeeQuoteGrace::default(),
+    }
 }
 
 #[cfg(test)]
@@ -129,6 +156,8 @@ mod tests {
         // Mock the clock to ensure we get a proper timestamp
         mock_clock(&mut svm, TEST_TIMESTAMP);
 
+        let gas_fee_receiver = Pubkey::new_unique();
+
         // Build the Initialize instruction accounts
         let accounts = accounts::Initialize {
             upgrade_authority: payer_pk,
@@ -136,12 +165,12 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            gas_fee_receiver,
             system_program: system_program::ID,
         }
         .to_account_metas(None);
 
         // Build the Initialize instruction
-        let gas_fee_receiver = Pubkey::new_unique();
         let ix = Instruction {
             program_id: ID,
             accounts,
@@ -179,6 +208,7 @@ mod tests {
         assert_eq!(
             bridge,
             Bridge {
+                version: BRIDGE_LAYOUT_VERSION,
                 base_block_number: 0,
                 nonce: 0,
                 guardian: guardian_pk,
@@ -194,6 +224,17 @@ mod tests {
                 buffer_config: BufferConfig::test_new(),
                 partner_oracle_config: PartnerOracleConfig::default(),
                 base_oracle_config: BaseOracleConfig::test_new(),
+                oracle_failover: OracleFailover::default(),
+                root_freshness_config: RootFreshnessConfig::default(),
+                oracle_health: OracleHealth::default(),
+                outflow_breaker: OutflowBreaker::default(),
+                finalization_delay: FinalizationDelay::default(),
+                base_governance: BaseGovernance::default(),
+                outbox: Outbox::default(),
+                guarded_launch: false,
+                transfer_gas_lane: Eip1559::default(),
+                require_wrapped_token_confirmation: false,
+                fee_quote_grace: FeeQuoteGrace::default(),
             }
         );
     }
@@ -210,6 +251,8 @@ mod tests {
         let payer_pk = payer.pubkey();
         let guardian_pk = guardian.pubkey();
 
+        let gas_fee_receiver = Pubkey::new_unique();
+
         // Build the Initialize instruction accounts
         let accounts = accounts::Initialize {
             upgrade_authority: payer_pk,
@@ -217,12 +260,12 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            gas_fee_receiver,
             system_program: system_program::ID,
         }
         .to_account_metas(None);
 
         // Build the Initialize instruction with an invalid partner threshold (> 5)
-        let gas_fee_receiver = Pubkey::new_unique();
         let ix = Instruction {
             program_id: ID,
             accounts,
@@ -266,6 +309,8 @@ mod tests {
         let payer_pk = payer.pubkey();
         let guardian_pk = guardian.pubkey();
 
+        let gas_fee_receiver = Pubkey::new_unique();
+
         // Build the Initialize instruction accounts
         let accounts = accounts::Initialize {
             upgrade_authority: payer_pk,
@@ -273,12 +318,12 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            gas_fee_receiver,
             system_program: system_program::ID,
         }
         .to_account_metas(None);
 
         // Build the Initialize instruction with an invalid base oracle threshold (== 0)
-        let gas_fee_receiver = Pubkey::new_unique();
         let mut base_oracle_config = BaseOracleConfig::test_new();
         base_oracle_config.threshold = 0;
 
@@ -323,6 +368,8 @@ mod tests {
         let payer_pk = payer.pubkey();
         let guardian_pk = guardian.pubkey();
 
+        let gas_fee_receiver = Pubkey::new_unique();
+
         // Build the Initialize instruction accounts
         let accounts = accounts::Initialize {
             upgrade_authority: payer_pk,
@@ -330,12 +377,12 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            gas_fee_receiver,
             system_program: system_program::ID,
         }
         .to_account_metas(None);
 
         // Build the Initialize instruction with threshold > signer_count
-        let gas_fee_receiver = Pubkey::new_unique();
         let mut base_oracle_config = BaseOracleConfig::test_new();
         base_oracle_config.threshold = base_oracle_config.signer_count + 1; // 2 > 1
 
@@ -380,6 +427,8 @@ mod tests {
         let payer_pk = payer.pubkey();
         let guardian_pk = guardian.pubkey();
 
+        let gas_fee_receiver = Pubkey::new_unique();
+
         // Build the Initialize instruction accounts
         let accounts = accounts::Initialize {
             upgrade_authority: payer_pk,
@@ -387,12 +436,12 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            gas_fee_receiver,
             system_program: system_program::ID,
         }
         .to_account_metas(None);
 
         // Build the Initialize instruction with signer_count > signers.len()
-        let gas_fee_receiver = Pubkey::new_unique();
         let mut base_oracle_config = BaseOracleConfig::test_new();
         base_oracle_config.signer_count = (base_oracle_config.signers.len() + 1) as u8; // exceed fixed array length
         base_oracle_config.threshold = 1; // keep valid threshold
@@ -438,6 +487,8 @@ mod tests {
         let payer_pk = payer.pubkey();
         let guardian_pk = guardian.pubkey();
 
+        let gas_fee_receiver = Pubkey::new_unique();
+
         // Build the Initialize instruction accounts
         let accounts = accounts::Initialize {
             upgrade_authority: payer_pk,
@@ -445,12 +496,12 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            gas_fee_receiver,
             system_program: system_program::ID,
         }
         .to_account_metas(None);
 
         // Build the Initialize instruction with duplicate signer addresses among the provided entries
-        let gas_fee_receiver = Pubkey::new_unique();
         let mut base_oracle_config = BaseOracleConfig::test_new();
         base_oracle_config.signer_count = 2; // consider first two entries
         base_oracle_config.threshold = 1; // keep valid threshold
@@ -503,6 +554,8 @@ mod tests {
         svm.airdrop(&unauthorized.pubkey(), 10_000_000_000)
             .expect("Failed to airdrop to unauthorized user");
 
+        let gas_fee_receiver = Pubkey::new_unique();
+
         // Build the Initialize instruction accounts with unauthorized user
         let accounts = accounts::Initialize {
             upgrade_authority: unauthorized.pubkey(), // Wrong upgrade authority
@@ -510,12 +563,12 @@ mod tests {
             bridge: bridge_pda,
             program_data: program_data_pda,
             program: ID,
+            gas_fee_receiver,
             system_program: system_program::ID,
         }
         .to_account_metas(None);
 
         // Build the Initialize instruction
-        let gas_fee_receiver = Pubkey::new_unique();
         let ix = Instruction {
             program_id: ID,
             accounts,
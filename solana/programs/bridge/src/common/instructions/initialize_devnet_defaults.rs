@@ -0,0 +1,303 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{
+        bridge::{
+            BaseOracleConfig, Bridge, BufferConfig, Eip1559Config, GasConfig, PartnerOracleConfig,
+            ProtocolConfig,
+        },
+        fund_sol_vault, new_bridge, AddressBook, AddressBookToken, Config, ConfigHistory,
+        SolVaultState, ADDRESS_BOOK_SEED, BRIDGE_SEED, CONFIG_HISTORY_SEED,
+        DEVNET_ADJUSTMENT_DENOMINATOR, DEVNET_BLOCK_INTERVAL_REQUIREMENT, DEVNET_GAS_COST_SCALER,
+        DEVNET_GAS_COST_SCALER_DP, DEVNET_GAS_PER_CALL, DEVNET_GAS_TARGET,
+        DEVNET_MAX_CALL_BUFFER_SIZE, DEVNET_MINIMUM_BASE_FEE, DEVNET_WINDOW_DURATION_SECONDS,
+        DISCRIMINATOR_LEN, MAX_SIGNER_COUNT, SOL_VAULT_SEED, SOL_VAULT_STATE_SEED,
+    },
+    program::Bridge as BridgeProgram,
+    solana_to_base::NATIVE_SOL_PUBKEY,
+    BridgeError,
+};
+
+/// Accounts for the initialize_devnet_defaults instruction that bootstraps a fully configured
+/// devnet bridge in a single transaction: the bridge state, the SOL vault, the config history and
+/// the address book. Not available in mainnet builds.
+/// Only the upgrade authority can bootstrap the bridge.
+#[derive(Accounts)]
+pub struct InitializeDevnetDefaults<'info> {
+    /// The upgrade authority that is authorized to initialize the bridge.
+    pub upgrade_authority: Signer<'info>,
+
+    /// The account that pays for every account creation.
+    /// Also configured as the gas fee receiver.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The bridge state account being initialized.
+    #[account(
+        init,
+        payer = payer,
+        seeds = [BRIDGE_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + Bridge::INIT_SPACE
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The SOL vault that holds the locked SOL.
+    /// CHECK: This is the SOL vault account.
+    #[account(mut, seeds = [SOL_VAULT_SEED], bump)]
+    pub sol_vault: AccountInfo<'info>,
+
+    /// The SOL vault state recording the vault bump.
+    #[account(
+        init,
+        payer = payer,
+        seeds = [SOL_VAULT_STATE_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + SolVaultState::INIT_SPACE
+    )]
+    pub sol_vault_state: Account<'info, SolVaultState>,
+
+    /// The config history account.
+    #[account(
+        init,
+        payer = payer,
+        seeds = [CONFIG_HISTORY_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + std::mem::size_of::<ConfigHistory>()
+    )]
+    pub config_history: AccountLoader<'info, ConfigHistory>,
+
+    /// The address book, registering the Base Bridge and the SOL remote token.
+    #[account(
+        init,
+        payer = payer,
+        seeds = [ADDRESS_BOOK_SEED],
+        bump,
+        space = DISCRIMINATOR_LEN + AddressBook::INIT_SPACE,
+    )]
+    pub address_book: Account<'info, AddressBook>,
+
+    /// Program data account containing the upgrade authority.
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key())
+            @ BridgeError::UnauthorizedInitialization
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// The bridge program itself.
+    /// Validates that program_data is the correct ProgramData account for this program.
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+            @ BridgeError::IncorrectBridgeProgram
+    )]
+    pub program: Program<'info, BridgeProgram>,
+
+    /// System program required for creating new accounts and funding the SOL vault.
+    pub system_program: Program<'info, System>,
+}
+
+/// Initializes the bridge with the devnet defaults and a 1-of-n `base_oracle_signers` quorum,
+/// then creates the SOL vault, the config history and the address book mapping SOL to
+/// `remote_sol_address`.
+pub fn initialize_devnet_defaults_handler(
+    ctx: Context<InitializeDevnetDefaults>,
+    guardian: Pubkey,
+    base_oracle_signers: Vec<[u8; 20]>,
+    base_bridge: [u8; 20],
+    remote_sol_address: [u8; 20],
+) -> Result<()> {
+    require!(
+        base_oracle_signers.len() <= MAX_SIGNER_COUNT as usize,
+        BridgeError::TooManySigners
+    );
+
+    let mut signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+    signers[..base_oracle_signers.len()].copy_from_slice(&base_oracle_signers);
+
+    let cfg = Config {
+        eip1559_config: Eip1559Config {
+            target: DEVNET_GAS_TARGET,
+            denominator: DEVNET_ADJUSTMENT_DENOMINATOR,
+            window_duration_seconds: DEVNET_WINDOW_DURATION_SECONDS,
+            minimum_base_fee: DEVNET_MINIMUM_BASE_FEE,
+            max_base_fee: 0,
+            max_window_increase_bps: 0,
+        },
+        gas_config: GasConfig {
+            gas_cost_scaler: DEVNET_GAS_COST_SCALER,
+            gas_cost_scaler_dp: DEVNET_GAS_COST_SCALER_DP,
+            gas_fee_receiver: ctx.accounts.payer.key(),
+            gas_per_call: DEVNET_GAS_PER_CALL,
+        },
+        protocol_config: ProtocolConfig {
+            block_interval_requirement: DEVNET_BLOCK_INTERVAL_REQUIREMENT,
+            remote_sol_address,
+            remote_chain_id: 0,
+        },
+        buffer_config: BufferConfig {
+            max_call_buffer_size: DEVNET_MAX_CALL_BUFFER_SIZE,
+            call_buffer_ttl_seconds: 0,
+            expire_bounty_bps: 0,
+        },
+        partner_oracle_config: PartnerOracleConfig::default(),
+        base_oracle_config: BaseOracleConfig {
+            threshold: 1,
+            signer_count: base_oracle_signers.len() as u8,
+            signers,
+        },
+    };
+    cfg.validate()?;
+    cfg.gas_config
+        .validate_gas_fee_receiver(&ctx.accounts.payer)?;
+
+    *ctx.accounts.bridge = new_bridge(guardian, cfg, Clock::get()?.unix_timestamp);
+
+    fund_sol_vault(
+        &ctx.accounts.payer,
+        &ctx.accounts.sol_vault,
+        &ctx.accounts.system_program,
+    )?;
+    ctx.accounts.sol_vault_state.bump = ctx.bumps.sol_vault;
+
+    ctx.accounts.config_history.load_init()?;
+
+    ctx.accounts.address_book.set(
+        base_bridge,
+        [0u8; 20],
+        &[AddressBookToken {
+            local_token: NATIVE_SOL_PUBKEY,
+            remote_token: remote_sol_address,
+        }],
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, system_program},
+        InstructionData,
+    };
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::InitializeDevnetDefaults as InitializeDevnetDefaultsIx,
+        test_utils::{
+            address_book_pda, config_history_pda, deploy_bridge, sol_vault_state_pda,
+            DeployBridgeResult,
+        },
+        ID,
+    };
+
+    fn initialize_devnet_defaults_ix(
+        upgrade_authority: Pubkey,
+        bridge_pda: Pubkey,
+        program_data_pda: Pubkey,
+        base_oracle_signers: Vec<[u8; 20]>,
+    ) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::InitializeDevnetDefaults {
+                upgrade_authority,
+                payer: upgrade_authority,
+                bridge: bridge_pda,
+                sol_vault: Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0,
+                sol_vault_state: sol_vault_state_pda(),
+                config_history: config_history_pda(),
+                address_book: address_book_pda(),
+                program_data: program_data_pda,
+                program: ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: InitializeDevnetDefaultsIx {
+                guardian: upgrade_authority,
+                base_oracle_signers,
+                base_bridge: [0xBB; 20],
+                remote_sol_address: [0x50; 20],
+            }
+            .data(),
+        }
+    }
+
+    #[test]
+    fn test_initialize_devnet_defaults_bootstraps_bridge() {
+        let DeployBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            program_data_pda,
+            ..
+        } = deploy_bridge();
+
+        let ix = initialize_devnet_defaults_ix(
+            payer.pubkey(),
+            bridge_pda,
+            program_data_pda,
+            vec![[1u8; 20], [2u8; 20]],
+        );
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to bootstrap the devnet bridge");
+
+        let account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(bridge.guardian, payer.pubkey());
+        assert_eq!(bridge.gas_config.gas_fee_receiver, payer.pubkey());
+        assert_eq!(bridge.protocol_config.remote_sol_address, [0x50; 20]);
+        assert_eq!(bridge.base_oracle_config.threshold, 1);
+        assert_eq!(bridge.base_oracle_config.signer_count, 2);
+
+        let account = svm.get_account(&sol_vault_state_pda()).unwrap();
+        let sol_vault_state = SolVaultState::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(
+            sol_vault_state.bump,
+            Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).1
+        );
+        assert!(svm.get_account(&config_history_pda()).is_some());
+
+        let account = svm.get_account(&address_book_pda()).unwrap();
+        let address_book = AddressBook::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(address_book.bridge().unwrap(), [0xBB; 20]);
+        assert_eq!(
+            address_book.remote_token(&NATIVE_SOL_PUBKEY),
+            Some([0x50; 20])
+        );
+    }
+
+    #[test]
+    fn test_initialize_devnet_defaults_without_oracle_signers_fails() {
+        let DeployBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            program_data_pda,
+            ..
+        } = deploy_bridge();
+
+        let ix =
+            initialize_devnet_defaults_ix(payer.pubkey(), bridge_pda, program_data_pda, vec![]);
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let error_string = format!("{:?}", svm.send_transaction(tx).unwrap_err());
+        assert!(
+            error_string.contains("InvalidThreshold"),
+            "Expected InvalidThreshold error, got: {}",
+            error_string
+        );
+        assert!(svm.get_account(&bridge_pda).is_none());
+    }
+}
@@ -0,0 +1,241 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+};
+
+use crate::{
+    common::{
+        bridge::Bridge,
+        migration::{detect_bridge_version, migrate_bridge_data},
+        BRIDGE_LAYOUT_VERSION, BRIDGE_SEED, DISCRIMINATOR_LEN,
+    },
+    program::Bridge as BridgeProgram,
+    BridgeError, ID,
+};
+
+/// Accounts for the migrate_bridge_state instruction that upgrades the bridge account to the
+/// layout expected by the currently deployed program.
+/// Only the upgrade authority can migrate the bridge state.
+#[derive(Accounts)]
+pub struct MigrateBridgeState<'info> {
+    /// The upgrade authority that is authorized to migrate the bridge state.
+    pub upgrade_authority: Signer<'info>,
+
+    /// The account that pays for the additional rent required by the new layout.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: The bridge state account being migrated. It cannot be loaded as
+    /// `Account<Bridge>` because its data may still be in an older layout. The PDA and
+    /// owner are validated here and the layout is validated by the handler.
+    #[account(mut, seeds = [BRIDGE_SEED], bump, owner = ID)]
+    pub bridge: UncheckedAccount<'info>,
+
+    /// Program data account containing the upgrade authority.
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(upgrade_authority.key())
+            @ BridgeError::UnauthorizedConfigUpdate
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    /// The bridge program itself.
+    /// Validates that program_data is the correct ProgramData account for this program.
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+            @ BridgeError::IncorrectBridgeProgram
+    )]
+    pub program: Program<'info, BridgeProgram>,
+
+    /// System program required for topping up the bridge account rent.
+    pub system_program: Program<'info, System>,
+}
+
+/// Migrates the `Bridge` account from any registered older layout to `BRIDGE_LAYOUT_VERSION`.
+/// Tops up rent from the payer, reallocs the account and applies the layout steps in order.
+pub fn migrate_bridge_state_handler(ctx: Context<MigrateBridgeState>) -> Result<()> {
+    let bridge = ctx.accounts.bridge.to_account_info();
+
+    let from_version = {
+        let data = bridge.try_borrow_data()?;
+        require!(
+            data.len() > DISCRIMINATOR_LEN && data[..DISCRIMINATOR_LEN] == *Bridge::DISCRIMINATOR,
+            BridgeError::InvalidBridgeLayout
+        );
+        detect_bridge_version(&data[DISCRIMINATOR_LEN..])?
+    };
+    require!(
+        from_version < BRIDGE_LAYOUT_VERSION,
+        BridgeError::BridgeAlreadyMigrated
+    );
+
+    let new_len = DISCRIMINATOR_LEN + Bridge::INIT_SPACE;
+    let required_lamports = Rent::get()?.minimum_balance(new_len);
+    let current_lamports = bridge.lamports();
+    if required_lamports > current_lamports {
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: bridge.clone(),
+            },
+        );
+        system_program::transfer(cpi_ctx, required_lamports - current_lamports)?;
+    }
+
+    bridge.realloc(new_len, true)?;
+
+    let mut data = bridge.try_borrow_mut_data()?;
+    migrate_bridge_data(&mut data[DISCRIMINATOR_LEN..], from_version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{
+            bpf_loader_upgradeable, example_mocks::solana_sdk::system_program,
+            instruction::Instruction,
+        },
+        InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_account::Account as SvmAccount;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts, common::migration::BRIDGE_LAYOUTS,
+        instruction::MigrateBridgeState as MigrateBridgeStateIx, test_utils::*,
+    };
+
+    /// Rewrites the initialized bridge account into the v1 layout by stripping every field
+    /// added since. Those fields must be zero in the initialized bridge.
+    fn downgrade_bridge_to_v1(svm: &mut LiteSVM, bridge_pda: Pubkey) -> Bridge {
+        let account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &account.data[..]).unwrap();
+
+        let mut data = account.data.clone();
+        for layout in BRIDGE_LAYOUTS.iter().rev() {
+            for insertion in layout.insertions.iter().rev() {
+                let start = DISCRIMINATOR_LEN + insertion.offset;
+                data.drain(start..start + insertion.len);
+            }
+        }
+        let lamports = svm.minimum_balance_for_rent_exemption(data.len());
+
+        svm.set_account(
+            bridge_pda,
+            SvmAccount {
+                lamports,
+                data,
+                ..account
+            },
+        )
+        .unwrap();
+
+        bridge
+    }
+
+    fn migrate_ix(upgrade_authority: Pubkey, bridge_pda: Pubkey) -> Instruction {
+        let (program_data_pda, _) =
+            Pubkey::find_program_address(&[ID.as_ref()], &bpf_loader_upgradeable::ID);
+
+        let accounts = accounts::MigrateBridgeState {
+            upgrade_authority,
+            payer: upgrade_authority,
+            bridge: bridge_pda,
+            program_data: program_data_pda,
+            program: ID,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+
+        Instruction {
+            program_id: ID,
+            accounts,
+            data: MigrateBridgeStateIx {}.data(),
+        }
+    }
+
+    #[test]
+    fn test_migrate_bridge_state_from_v1_succeeds() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let expected = downgrade_bridge_to_v1(&mut svm, bridge_pda);
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[migrate_ix(payer.pubkey(), bridge_pda)], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to migrate bridge state");
+
+        let account = svm.get_account(&bridge_pda).unwrap();
+        assert_eq!(account.data.len(), DISCRIMINATOR_LEN + Bridge::INIT_SPACE);
+        assert!(account.lamports >= svm.minimum_balance_for_rent_exemption(account.data.len()));
+
+        let bridge = Bridge::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(bridge, expected);
+    }
+
+    #[test]
+    fn test_migrate_bridge_state_already_migrated_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[migrate_ix(payer.pubkey(), bridge_pda)], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("BridgeAlreadyMigrated"),
+            "Expected BridgeAlreadyMigrated error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_migrate_bridge_state_with_guardian_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        downgrade_bridge_to_v1(&mut svm, bridge_pda);
+
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(
+                &[migrate_ix(guardian.pubkey(), bridge_pda)],
+                Some(&guardian.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("UnauthorizedConfigUpdate"),
+            "Expected UnauthorizedConfigUpdate error, got: {}",
+            error_string
+        );
+    }
+}
@@ -1,7 +1,45 @@
 pub mod initialize;
 pub use initialize::*;
 
+#[cfg(not(feature = "mainnet"))]
+pub mod initialize_devnet_defaults;
+#[cfg(not(feature = "mainnet"))]
+pub use initialize_devnet_defaults::*;
+
 pub mod guardian;
 
+pub mod migrate;
+pub use migrate::*;
+
+pub mod init_sol_vault;
+pub use init_sol_vault::*;
+
+pub mod init_config_history;
+pub use init_config_history::*;
+
+pub mod audit_vault;
+pub use audit_vault::*;
+
+pub mod reconcile_vault;
+pub use reconcile_vault::*;
+
+pub mod sweep_gas_fee_escrow;
+pub use sweep_gas_fee_escrow::*;
+
+pub mod execute_insurance_payout;
+pub use execute_insurance_payout::*;
+
+pub mod get_environment;
+pub use get_environment::*;
+
+pub mod check_environment;
+pub use check_environment::*;
+
+pub mod get_version;
+pub use get_version::*;
+
+pub mod get_oracle_health;
+pub use get_oracle_health::*;
+
 pub mod config;
 pub use config::*;
@@ -0,0 +1,317 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::internal::mmr,
+    common::{
+        bridge::Bridge, vault_holdings, LiabilityRoot, TokenDeposits, BRIDGE_SEED,
+        LIABILITY_ROOT_SEED, TOKEN_DEPOSITS_SEED,
+    },
+    BridgeError, VaultReconciled,
+};
+
+/// Accounts for the permissionless reconcile_vault instruction that checks a vault balance
+/// against its liability proven in the posted `LiabilityRoot`.
+#[derive(Accounts)]
+pub struct ReconcileVault<'info> {
+    /// The reconciled vault: either the SOL vault or an SPL token vault.
+    /// CHECK: Authenticated by the `token_deposits` PDA which only exists for bridge vaults.
+    pub vault: UncheckedAccount<'info>,
+
+    /// The accounting account of the reconciled vault, only used to authenticate it.
+    #[account(seeds = [TOKEN_DEPOSITS_SEED, vault.key().as_ref()], bump)]
+    pub token_deposits: Account<'info, TokenDeposits>,
+
+    /// The oracle-posted liability commitment, flagged on shortfall.
+    #[account(mut, seeds = [LIABILITY_ROOT_SEED], bump)]
+    pub liability_root: Account<'info, LiabilityRoot>,
+}
+
+/// Accounts for the clear_solvency_alert instruction that resets the `solvency_alert` flag of the
+/// liability root once the guardian investigated the shortfall.
+#[derive(Accounts)]
+pub struct ClearSolvencyAlert<'info> {
+    /// The guardian account authorized to update configuration
+    pub guardian: Signer<'info>,
+
+    /// The bridge account holding the guardian
+    #[account(
+        has_one = guardian @ BridgeError::UnauthorizedConfigUpdate,
+        seeds = [BRIDGE_SEED],
+        bump
+    )]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The liability root flagged with a solvency alert
+    #[account(mut, seeds = [LIABILITY_ROOT_SEED], bump)]
+    pub liability_root: Account<'info, LiabilityRoot>,
+}
+
+/// Verifies `proof` of the vault token `liability` against the liability root and emits a
+/// `VaultReconciled` event. Raises the solvency alert when the vault holds less than `liability`.
+pub fn reconcile_vault_handler(
+    ctx: Context<ReconcileVault>,
+    liability: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let (local_token, actual_amount) = vault_holdings(vault)?;
+
+    let liability_root = &mut ctx.accounts.liability_root;
+    mmr::verify_merkle_proof(
+        &liability_root.root,
+        &LiabilityRoot::leaf_hash(&local_token, liability),
+        &proof,
+    )?;
+
+    let solvent = actual_amount >= liability;
+    if !solvent {
+        liability_root.solvency_alert = true;
+    }
+
+    emit!(VaultReconciled {
+        vault: vault.key(),
+        local_token,
+        base_block_number: liability_root.base_block_number,
+        liability,
+        actual_amount,
+        solvent,
+    });
+
+    Ok(())
+}
+
+pub fn clear_solvency_alert_handler(ctx: Context<ClearSolvencyAlert>) -> Result<()> {
+    ctx.accounts.liability_root.solvency_alert = false;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, keccak, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_account::Account as SvmAccount;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::{DISCRIMINATOR_LEN, SOL_VAULT_SEED},
+        instruction::{
+            BridgeSol as BridgeSolIx, ClearSolvencyAlert as ClearSolvencyAlertIx,
+            ReconcileVault as ReconcileVaultIx,
+        },
+        solana_to_base::NATIVE_SOL_PUBKEY,
+        test_utils::{
+            create_outgoing_message, dex_router_list_pda, gas_fee_escrow_pda, insurance_fund_pda,
+            liability_root_pda, payer_rate_limit_config_pda, payer_rate_limit_pda,
+            protocol_fee_config_pda, setup_bridge, sol_vault_state_pda, token_deposits_pda,
+            SetupBridgeResult, TEST_GAS_FEE_RECEIVER, TEST_PROTOCOL_FEE_TREASURY,
+        },
+        ID,
+    };
+
+    fn sol_vault() -> Pubkey {
+        Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0
+    }
+
+    fn bridge_sol(svm: &mut LiteSVM, payer: &Keypair, bridge_pda: Pubkey, amount: u64) {
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+
+        let accounts = accounts::BridgeSol {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
+            sol_vault_state: sol_vault_state_pda(),
+            sol_vault: sol_vault(),
+            token_deposits: token_deposits_pda(&sol_vault()),
+            bridge: bridge_pda,
+            outgoing_message,
+            system_program: system_program::ID,
+            memo_program: None,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeSolIx {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                amount,
+                call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer, &from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).unwrap();
+    }
+
+    /// Writes a liability root committing to the SOL liability and a sibling leaf.
+    fn mock_liability_root(svm: &mut LiteSVM, sol_liability: u64, sibling: [u8; 32]) {
+        let sol_leaf = LiabilityRoot::leaf_hash(&NATIVE_SOL_PUBKEY, sol_liability);
+        let root = if sol_leaf < sibling {
+            keccak::hashv(&[&sol_leaf, &sibling]).to_bytes()
+        } else {
+            keccak::hashv(&[&sibling, &sol_leaf]).to_bytes()
+        };
+
+        let liability_root = LiabilityRoot {
+            root,
+            base_block_number: 100,
+            solvency_alert: false,
+        };
+        let mut data = Vec::with_capacity(DISCRIMINATOR_LEN + LiabilityRoot::INIT_SPACE);
+        liability_root.try_serialize(&mut data).unwrap();
+
+        svm.set_account(
+            liability_root_pda(),
+            SvmAccount {
+                lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+                data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+    }
+
+    fn reconcile_vault(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        liability: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::ReconcileVault {
+                vault: sol_vault(),
+                token_deposits: token_deposits_pda(&sol_vault()),
+                liability_root: liability_root_pda(),
+            }
+            .to_account_metas(None),
+            data: ReconcileVaultIx { liability, proof }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn solvency_alert(svm: &LiteSVM) -> bool {
+        let account = svm.get_account(&liability_root_pda()).unwrap();
+        LiabilityRoot::try_deserialize(&mut &account.data[..])
+            .unwrap()
+            .solvency_alert
+    }
+
+    #[test]
+    fn test_reconcile_vault_solvent() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        bridge_sol(&mut svm, &payer, bridge_pda, LAMPORTS_PER_SOL);
+
+        let sibling = [5u8; 32];
+        mock_liability_root(&mut svm, LAMPORTS_PER_SOL, sibling);
+
+        reconcile_vault(&mut svm, &payer, LAMPORTS_PER_SOL, vec![sibling])
+            .expect("Failed to reconcile vault");
+        assert!(!solvency_alert(&svm));
+    }
+
+    #[test]
+    fn test_reconcile_vault_shortfall_raises_alert() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+        } = setup_bridge();
+        bridge_sol(&mut svm, &payer, bridge_pda, LAMPORTS_PER_SOL);
+
+        let sibling = [5u8; 32];
+        mock_liability_root(&mut svm, 2 * LAMPORTS_PER_SOL, sibling);
+
+        reconcile_vault(&mut svm, &payer, 2 * LAMPORTS_PER_SOL, vec![sibling])
+            .expect("Failed to reconcile vault");
+        assert!(solvency_alert(&svm));
+
+        // Only the guardian can clear the alert
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::ClearSolvencyAlert {
+                guardian: guardian.pubkey(),
+                bridge: bridge_pda,
+                liability_root: liability_root_pda(),
+            }
+            .to_account_metas(None),
+            data: ClearSolvencyAlertIx {}.data(),
+        };
+        let tx = Transaction::new(
+            &[&guardian],
+            Message::new(&[ix], Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to clear solvency alert");
+        assert!(!solvency_alert(&svm));
+    }
+
+    #[test]
+    fn test_reconcile_vault_rejects_invalid_proof() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        bridge_sol(&mut svm, &payer, bridge_pda, LAMPORTS_PER_SOL);
+
+        let sibling = [5u8; 32];
+        mock_liability_root(&mut svm, 2 * LAMPORTS_PER_SOL, sibling);
+
+        // Claiming a lower liability than committed does not verify
+        let error_string =
+            reconcile_vault(&mut svm, &payer, LAMPORTS_PER_SOL, vec![sibling]).unwrap_err();
+        assert!(
+            error_string.contains("InvalidProof"),
+            "Expected InvalidProof error, got: {}",
+            error_string
+        );
+        assert!(!solvency_alert(&svm));
+    }
+}
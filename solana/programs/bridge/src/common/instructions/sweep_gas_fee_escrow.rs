@@ -0,0 +1,227 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+};
+
+use crate::{
+    common::{bridge::Bridge, BRIDGE_SEED, GAS_FEE_ESCROW_SEED},
+    solana_to_base::can_receive_lamports,
+    BridgeError,
+};
+
+/// Accounts for the permissionless sweep_gas_fee_escrow instruction that forwards the gas fees
+/// collected by the fallback escrow to the configured gas fee receiver.
+#[derive(Accounts)]
+pub struct SweepGasFeeEscrow<'info> {
+    /// The bridge account holding the gas fee receiver
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The configured gas fee receiver, credited with the whole escrow balance.
+    /// CHECK: This account is validated to be the same as bridge.gas_config.gas_fee_receiver
+    #[account(mut, address = bridge.gas_config.gas_fee_receiver @ BridgeError::IncorrectGasFeeReceiver)]
+    pub gas_fee_receiver: AccountInfo<'info>,
+
+    /// The fallback gas fee escrow being emptied.
+    /// CHECK: PDA with GAS_FEE_ESCROW_SEED; only holds lamports.
+    #[account(mut, seeds = [GAS_FEE_ESCROW_SEED], bump)]
+    pub gas_fee_escrow: AccountInfo<'info>,
+
+    /// System program required for transferring the escrowed lamports.
+    pub system_program: Program<'info, System>,
+}
+
+/// Transfers the whole escrow balance to the gas fee receiver. Fails if the receiver still
+/// cannot be credited.
+pub fn sweep_gas_fee_escrow_handler(ctx: Context<SweepGasFeeEscrow>) -> Result<()> {
+    let amount = ctx.accounts.gas_fee_escrow.lamports();
+    require!(
+        can_receive_lamports(&ctx.accounts.gas_fee_receiver, amount)?,
+        BridgeError::InvalidGasFeeReceiver
+    );
+
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.gas_fee_escrow.to_account_info(),
+                to: ctx.accounts.gas_fee_receiver.to_account_info(),
+            },
+            &[&[GAS_FEE_ESCROW_SEED, &[ctx.bumps.gas_fee_escrow]]],
+        ),
+        amount,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_account::Account as SvmAccount;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::SOL_VAULT_SEED,
+        instruction::{BridgeSol, SweepGasFeeEscrow as SweepGasFeeEscrowIx},
+        test_utils::{
+            create_outgoing_message, dex_router_list_pda, gas_fee_escrow_pda, insurance_fund_pda,
+            payer_rate_limit_config_pda, payer_rate_limit_pda, protocol_fee_config_pda,
+            setup_bridge, sol_vault_state_pda, token_deposits_pda, SetupBridgeResult,
+            TEST_GAS_FEE_RECEIVER, TEST_PROTOCOL_FEE_TREASURY,
+        },
+        ID,
+    };
+
+    /// Replaces the gas fee receiver with an account owned by another program.
+    fn set_program_owned_gas_fee_receiver(svm: &mut LiteSVM) {
+        svm.set_account(
+            TEST_GAS_FEE_RECEIVER,
+            SvmAccount {
+                lamports: LAMPORTS_PER_SOL,
+                data: vec![0u8; 8],
+                owner: Pubkey::new_unique(),
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+    }
+
+    fn bridge_sol(svm: &mut LiteSVM, payer: &Keypair, bridge_pda: Pubkey) {
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::BridgeSol {
+                payer: payer.pubkey(),
+                from: from.pubkey(),
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                gas_fee_escrow: gas_fee_escrow_pda(),
+                insurance_fund: insurance_fund_pda(),
+                payer_rate_limit_config: payer_rate_limit_config_pda(),
+                payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+                protocol_fee_config: protocol_fee_config_pda(),
+                protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+                dex_router_list: dex_router_list_pda(),
+                sol_vault_state: sol_vault_state_pda(),
+                sol_vault,
+                token_deposits: token_deposits_pda(&sol_vault),
+                bridge: bridge_pda,
+                outgoing_message,
+                system_program: system_program::ID,
+                memo_program: None,
+            }
+            .to_account_metas(None),
+            data: BridgeSol {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                amount: LAMPORTS_PER_SOL / 10,
+                call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer, &from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx).expect("Failed to bridge SOL");
+    }
+
+    fn sweep_gas_fee_escrow(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SweepGasFeeEscrow {
+                bridge: bridge_pda,
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                gas_fee_escrow: gas_fee_escrow_pda(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: SweepGasFeeEscrowIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_gas_fee_falls_back_to_escrow_and_is_swept() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // A misconfigured receiver does not brick bridging: the gas fee goes to the escrow
+        set_program_owned_gas_fee_receiver(&mut svm);
+        bridge_sol(&mut svm, &payer, bridge_pda);
+
+        assert_eq!(
+            svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports,
+            LAMPORTS_PER_SOL
+        );
+        let escrowed = svm.get_account(&gas_fee_escrow_pda()).unwrap().lamports;
+        assert!(escrowed >= svm.minimum_balance_for_rent_exemption(0));
+
+        // The escrow cannot be swept while the receiver is misconfigured
+        let error = sweep_gas_fee_escrow(&mut svm, &payer, bridge_pda).unwrap_err();
+        assert!(
+            error.contains("InvalidGasFeeReceiver"),
+            "Expected InvalidGasFeeReceiver error, got: {}",
+            error
+        );
+
+        // Once the receiver is a system account, the escrow is swept to it
+        svm.set_account(
+            TEST_GAS_FEE_RECEIVER,
+            SvmAccount {
+                lamports: LAMPORTS_PER_SOL,
+                data: vec![],
+                owner: system_program::ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+        sweep_gas_fee_escrow(&mut svm, &payer, bridge_pda).expect("Failed to sweep escrow");
+
+        assert_eq!(
+            svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports,
+            LAMPORTS_PER_SOL + escrowed
+        );
+        assert_eq!(
+            svm.get_account(&gas_fee_escrow_pda())
+                .map_or(0, |account| account.lamports),
+            0
+        );
+    }
+}
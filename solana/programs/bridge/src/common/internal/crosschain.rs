@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    token_2022::{MintToChecked, Token2022},
+    token_interface::{self, BurnChecked, Mint, TokenAccount},
+};
+
+use crate::{
+    common::{PartialTokenMetadata, WrappedTokenSupplyCap, WRAPPED_TOKEN_SEED},
+    BridgeError, CrosschainBurn, CrosschainMint, ID,
+};
+
+/// Mints `amount` wrapped tokens to `to_token_account`, signed by the wrapped token mint PDA, and
+/// emits `CrosschainMint`. Fails if the mint would exceed the max supply stored in `supply_cap`.
+/// `token_program` must be a canonical token program, as checked by `Program<Token2022>` or
+/// `Interface<TokenInterface>`, since it is invoked with the mint authority signature.
+pub fn mint_wrapped_tokens<'info>(
+    mint: &InterfaceAccount<'info, Mint>,
+    to_token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &AccountInfo<'info>,
+    supply_cap: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    if let Some(max_supply) = WrappedTokenSupplyCap::load(supply_cap, &mint.key())? {
+        let supply = mint
+            .supply
+            .checked_add(amount)
+            .ok_or(BridgeError::WrappedSupplyCapExceeded)?;
+        require!(supply <= max_supply, BridgeError::WrappedSupplyCapExceeded);
+    }
+
+    // Derive the seeds of the wrapped token mint, which is its own mint authority
+    let partial_token_metadata = PartialTokenMetadata::try_from(&mint.to_account_info())?;
+    let decimals_bytes = mint.decimals.to_le_bytes();
+    let metadata_hash = partial_token_metadata.hash();
+    let seeds: &[&[u8]] = &[
+        WRAPPED_TOKEN_SEED,
+        decimals_bytes.as_ref(),
+        metadata_hash.as_ref(),
+    ];
+    let (_, mint_bump) = Pubkey::find_program_address(seeds, &ID);
+
+    let seeds: &[&[&[u8]]] = &[&[
+        WRAPPED_TOKEN_SEED,
+        decimals_bytes.as_ref(),
+        metadata_hash.as_ref(),
+        &[mint_bump],
+    ]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        MintToChecked {
+            mint: mint.to_account_info(),
+            to: to_token_account.to_account_info(),
+            authority: mint.to_account_info(),
+        },
+        seeds,
+    );
+    token_interface::mint_to_checked(cpi_ctx, amount, mint.decimals)?;
+
+    emit!(CrosschainMint {
+        mint: mint.key(),
+        to: to_token_account.owner,
+        amount,
+        sender: ID,
+    });
+
+    Ok(())
+}
+
+/// Burns `amount` wrapped tokens from `from_token_account`, authorized by `authority`, and emits
+/// `CrosschainBurn`.
+pub fn burn_wrapped_tokens<'info>(
+    mint: &InterfaceAccount<'info, Mint>,
+    from_token_account: &InterfaceAccount<'info, TokenAccount>,
+    token_program: &Program<'info, Token2022>,
+    authority: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        BurnChecked {
+            mint: mint.to_account_info(),
+            from: from_token_account.to_account_info(),
+            authority,
+        },
+        signer_seeds,
+    );
+    token_interface::burn_checked(cpi_ctx, amount, mint.decimals)?;
+
+    emit!(CrosschainBurn {
+        mint: mint.key(),
+        from: from_token_account.owner,
+        amount,
+        sender: ID,
+    });
+
+    Ok(())
+}
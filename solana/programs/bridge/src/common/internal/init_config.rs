@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
 
-use crate::common::{
-    BaseOracleConfig, BufferConfig, Eip1559Config, GasConfig, PartnerOracleConfig, ProtocolConfig,
+use crate::{
+    common::{
+        BaseOracleConfig, BufferConfig, Eip1559Config, GasConfig, PartnerOracleConfig,
+        ProtocolConfig,
+    },
+    BridgeError,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
@@ -22,9 +26,10 @@ pub struct Config {
 
 impl Config {
     pub fn validate(&self) -> Result<()> {
-        self.eip1559_config.validate()?;
+        self.eip1559_config.validate().map_err(BridgeError::from)?;
         self.gas_config.validate()?;
         self.protocol_config.validate()?;
+        self.buffer_config.validate()?;
         self.partner_oracle_config.validate()?;
         self.base_oracle_config.validate()?;
         Ok(())
@@ -1,9 +1,13 @@
-use crate::{common::WRAPPED_TOKEN_SEED, BridgeError, ID};
+use crate::{
+    common::WRAPPED_TOKEN_SEED,
+    solana_to_base::{REMOTE_TOKEN_METADATA_KEY, SCALER_EXPONENT_METADATA_KEY},
+    BridgeError, ID,
+};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::keccak;
 use anchor_spl::{
     token_2022::spl_token_2022::{
-        extension::{BaseStateWithExtensions, PodStateWithExtensions},
+        extension::{BaseStateWithExtensions, ExtensionType, PodStateWithExtensions},
         pod::PodMint,
     },
     token_interface::spl_token_metadata_interface::state::TokenMetadata,
@@ -34,16 +38,11 @@ pub struct PartialTokenMetadata {
     /// This handles cases where tokens have differing decimal precision on Base vs Solana.
     /// For example, when Base token has 18 decimals and the Solana wrapped mint has 9,
     /// this value conveys the decimal relationship so bridging logic can scale amounts.
-    /// The exact conversion is performed by the EVM-side contract; Solana propagates this
-    /// value but does not apply arithmetic with it.
+    /// The conversion is applied by the EVM-side contract; Solana only uses it to validate
+    /// amounts (see `common::internal::scaling`).
     pub scaler_exponent: u8,
 }
 
-/// Key used in `additional_metadata` for the Base (EVM) token address bytes, hex-encoded.
-pub const REMOTE_TOKEN_METADATA_KEY: &str = "remote_token";
-/// Key used in `additional_metadata` for the decimal scaling exponent.
-pub const SCALER_EXPONENT_METADATA_KEY: &str = "scaler_exponent";
-
 impl From<&PartialTokenMetadata> for TokenMetadata {
     fn from(value: &PartialTokenMetadata) -> Self {
         TokenMetadata {
@@ -122,28 +121,36 @@ impl TryFrom<&AccountInfo<'_>> for PartialTokenMetadata {
     type Error = Error;
 
     fn try_from(mint: &AccountInfo<'_>) -> Result<Self> {
-        let (token_metadata, decimals) = mint_info_to_token_metadata(mint)?;
-        let partial = Self::try_from(token_metadata)?;
-
-        // Ensure the provided mint is a PDA derived by this program for wrapped tokens.
-        let decimals_bytes = decimals.to_le_bytes();
-        let metadata_hash = partial.hash();
-        let seeds: &[&[u8]] = &[
-            WRAPPED_TOKEN_SEED,
-            decimals_bytes.as_ref(),
-            metadata_hash.as_ref(),
-        ];
-        let (expected_mint, _bump) = Pubkey::find_program_address(seeds, &ID);
-        require_keys_eq!(
-            mint.key(),
-            expected_mint,
-            BridgeError::MintIsNotWrappedTokenPda
-        );
-
+        let (partial, _) = wrapped_token_metadata(mint)?;
         Ok(partial)
     }
 }
 
+/// Reads the `PartialTokenMetadata` and `decimals` of a wrapped token mint.
+///
+/// Fails if the mint is not the wrapped token PDA derived from its decimals and metadata.
+pub fn wrapped_token_metadata(mint: &AccountInfo<'_>) -> Result<(PartialTokenMetadata, u8)> {
+    let (token_metadata, decimals) = mint_info_to_token_metadata(mint)?;
+    let partial = PartialTokenMetadata::try_from(token_metadata)?;
+
+    // Ensure the provided mint is a PDA derived by this program for wrapped tokens.
+    let decimals_bytes = decimals.to_le_bytes();
+    let metadata_hash = partial.hash();
+    let seeds: &[&[u8]] = &[
+        WRAPPED_TOKEN_SEED,
+        decimals_bytes.as_ref(),
+        metadata_hash.as_ref(),
+    ];
+    let (expected_mint, _bump) = Pubkey::find_program_address(seeds, &ID);
+    require_keys_eq!(
+        mint.key(),
+        expected_mint,
+        BridgeError::MintIsNotWrappedTokenPda
+    );
+
+    Ok((partial, decimals))
+}
+
 impl PartialTokenMetadata {
     /// Computes a keccak256 hash of the metadata fields as:
     /// `keccak(len(name) || name || len(symbol) || symbol || remote_token || scaler_exponent_le)`,
@@ -177,3 +184,13 @@ fn mint_info_to_token_metadata(mint: &AccountInfo<'_>) -> Result<(TokenMetadata,
     let decimals = mint_with_extension.base.decimals;
     Ok((token_metadata, decimals))
 }
+
+/// Returns whether the Token-2022 `mint` holds a `TokenMetadata` extension, i.e. whether a
+/// wrapped mint was fully initialized by `wrap_token`.
+pub fn has_token_metadata(mint: &AccountInfo<'_>) -> Result<bool> {
+    let mint_data = mint.data.borrow();
+    let mint_with_extension = PodStateWithExtensions::<PodMint>::unpack(&mint_data)?;
+    Ok(mint_with_extension
+        .get_extension_types()?
+        .contains(&ExtensionType::TokenMetadata))
+}
@@ -0,0 +1,438 @@
+use anchor_lang::prelude::*;
+
+use crate::{common::BRIDGE_LAYOUT_VERSION, BridgeError};
+
+/// A zero-filled byte range inserted into the serialized `Bridge` when upgrading to a layout.
+/// `offset` is expressed in the coordinates of the new layout (discriminator excluded).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LayoutInsertion {
+    pub offset: usize,
+    pub len: usize,
+}
+
+/// Serialized size of a historical `Bridge` layout and how to reach it from the previous one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BridgeLayout {
+    pub version: u8,
+    /// Size of the account data, excluding the discriminator.
+    pub space: usize,
+    /// Insertions applied, in ascending offset order, to the previous layout.
+    pub insertions: &'static [LayoutInsertion],
+}
+
+/// Every `Bridge` layout ever deployed, ordered by version.
+///
+/// Layouts only ever grow and new fields must treat an all-zero encoding as their
+/// disabled / default value, so that migrating is a matter of inserting zeroed bytes.
+/// When changing the `Bridge` layout, bump `BRIDGE_LAYOUT_VERSION` and append an entry here.
+pub const BRIDGE_LAYOUTS: &[BridgeLayout] = &[
+    // v1: initial layout, without a version byte.
+    BridgeLayout {
+        version: 1,
+        space: 520,
+        insertions: &[],
+    },
+    // v2: `version` prepended.
+    BridgeLayout {
+        version: 2,
+        space: 521,
+        insertions: &[LayoutInsertion { offset: 0, len: 1 }],
+    },
+    // v3: `eip1559.config.max_base_fee` and `eip1559.config.max_window_increase_bps` appended
+    // to `Eip1559Config`.
+    BridgeLayout {
+        version: 3,
+        space: 537,
+        insertions: &[LayoutInsertion {
+            offset: 82,
+            len: 16,
+        }],
+    },
+    // v4: `buffer_config.call_buffer_ttl_seconds` and `buffer_config.expire_bounty_bps` appended
+    // to `BufferConfig`.
+    BridgeLayout {
+        version: 4,
+        space: 553,
+        insertions: &[LayoutInsertion {
+            offset: 214,
+            len: 16,
+        }],
+    },
+    // v5: `protocol_config.remote_chain_id` appended to `ProtocolConfig`.
+    BridgeLayout {
+        version: 5,
+        space: 561,
+        insertions: &[LayoutInsertion {
+            offset: 206,
+            len: 8,
+        }],
+    },
+    // v6: `oracle_failover` appended to `Bridge`.
+    BridgeLayout {
+        version: 6,
+        space: 675,
+        insertions: &[LayoutInsertion {
+            offset: 561,
+            len: 114,
+        }],
+    },
+    // v7: `root_freshness_config` appended to `Bridge`.
+    BridgeLayout {
+        version: 7,
+        space: 707,
+        insertions: &[LayoutInsertion {
+            offset: 675,
+            len: 32,
+        }],
+    },
+    // v8: `oracle_health` appended to `Bridge`.
+    BridgeLayout {
+        version: 8,
+        space: 1155,
+        insertions: &[LayoutInsertion {
+            offset: 707,
+            len: 448,
+        }],
+    },
+    // v9: `outflow_breaker` appended to `Bridge`.
+    BridgeLayout {
+        version: 9,
+        space: 1675,
+        insertions: &[LayoutInsertion {
+            offset: 1155,
+            len: 520,
+        }],
+    },
+    // v10: `finalization_delay` appended to `Bridge`.
+    BridgeLayout {
+        version: 10,
+        space: 2003,
+        insertions: &[LayoutInsertion {
+            offset: 1675,
+            len: 328,
+        }],
+    },
+    // v11: `base_governance` appended to `Bridge`.
+    BridgeLayout {
+        version: 11,
+        space: 2024,
+        insertions: &[LayoutInsertion {
+            offset: 2003,
+            len: 21,
+        }],
+    },
+    // v12: `outbox` appended to `Bridge`.
+    BridgeLayout {
+        version: 12,
+        space: 3056,
+        insertions: &[LayoutInsertion {
+            offset: 2024,
+            len: 1032,
+        }],
+    },
+    // v13: `guarded_launch` appended to `Bridge`.
+    BridgeLayout {
+        version: 13,
+        space: 3057,
+        insertions: &[LayoutInsertion {
+            offset: 3056,
+            len: 1,
+        }],
+    },
+    // v14: `transfer_gas_lane` appended to `Bridge`.
+    BridgeLayout {
+        version: 14,
+        space: 3129,
+        insertions: &[LayoutInsertion {
+            offset: 3057,
+            len: 72,
+        }],
+    },
+    // v15: `require_wrapped_token_confirmation` appended to `Bridge`.
+    BridgeLayout {
+        version: 15,
+        space: 3130,
+        insertions: &[LayoutInsertion {
+            offset: 3129,
+            len: 1,
+        }],
+    },
+    // v16: `fee_quote_grace` appended to `Bridge`.
+    BridgeLayout {
+        version: 16,
+        space: 3154,
+        insertions: &[LayoutInsertion {
+            offset: 3130,
+            len: 24,
+        }],
+    },
+];
+
+/// Returns the registered layout for `version`, if any.
+pub fn bridge_layout(version: u8) -> Option<&'static BridgeLayout> {
+    BRIDGE_LAYOUTS
+        .iter()
+        .find(|layout| layout.version == version)
+}
+
+/// Detects the layout version of serialized `Bridge` data (discriminator excluded).
+///
+/// v1 predates the version byte and is identified by its size; later layouts store
+/// their version in the first byte.
+pub fn detect_bridge_version(data: &[u8]) -> Result<u8> {
+    if data.len() == BRIDGE_LAYOUTS[0].space {
+        return Ok(BRIDGE_LAYOUTS[0].version);
+    }
+
+    let version = *data.first().ok_or(BridgeError::InvalidBridgeLayout)?;
+    let layout = bridge_layout(version).ok_or(BridgeError::InvalidBridgeLayout)?;
+    require!(data.len() == layout.space, BridgeError::InvalidBridgeLayout);
+
+    Ok(version)
+}
+
+/// Migrates serialized `Bridge` data (discriminator excluded) from `from_version` to
+/// `BRIDGE_LAYOUT_VERSION` in place.
+///
+/// `data` must already be sized for the current layout; the old content is expected at
+/// the start of the buffer.
+pub fn migrate_bridge_data(data: &mut [u8], from_version: u8) -> Result<()> {
+    let mut used = bridge_layout(from_version)
+        .ok_or(BridgeError::InvalidBridgeLayout)?
+        .space;
+    let current = bridge_layout(BRIDGE_LAYOUT_VERSION).ok_or(BridgeError::InvalidBridgeLayout)?;
+    require!(
+        data.len() == current.space,
+        BridgeError::InvalidBridgeLayout
+    );
+
+    for layout in BRIDGE_LAYOUTS
+        .iter()
+        .filter(|layout| layout.version > from_version && layout.version <= BRIDGE_LAYOUT_VERSION)
+    {
+        for insertion in layout.insertions {
+            data.copy_within(insertion.offset..used, insertion.offset + insertion.len);
+            data[insertion.offset..insertion.offset + insertion.len].fill(0);
+            used += insertion.len;
+        }
+        require!(used == layout.space, BridgeError::InvalidBridgeLayout);
+    }
+
+    data[0] = BRIDGE_LAYOUT_VERSION;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::{
+        common::bridge::{
+            BaseGovernance, BaseOracleConfig, Bridge, BufferConfig, Eip1559, Eip1559Config,
+            FeeQuoteGrace, FinalizationDelay, GasConfig, OracleFailover, OracleHealth, Outbox,
+            OutflowBreaker, PartnerOracleConfig, ProtocolConfig, RootFreshnessConfig,
+        },
+        test_utils::TEST_GAS_FEE_RECEIVER,
+    };
+
+    // Snapshots of historical layouts.
+    // Each layout change adds snapshots of the structs it modified so migrations can be tested.
+
+    #[derive(AnchorSerialize)]
+    struct Eip1559ConfigV1 {
+        target: u64,
+        denominator: u64,
+        window_duration_seconds: u64,
+        minimum_base_fee: u64,
+    }
+
+    #[derive(AnchorSerialize)]
+    struct Eip1559V1 {
+        config: Eip1559ConfigV1,
+        current_base_fee: u64,
+        current_window_gas_used: u64,
+        window_start_time: i64,
+    }
+
+    #[derive(AnchorSerialize)]
+    struct BufferConfigV1 {
+        max_call_buffer_size: u64,
+    }
+
+    #[derive(AnchorSerialize)]
+    struct ProtocolConfigV1 {
+        block_interval_requirement: u64,
+        remote_sol_address: [u8; 20],
+    }
+
+    #[derive(AnchorSerialize)]
+    struct BridgeV1 {
+        base_block_number: u64,
+        nonce: u64,
+        guardian: Pubkey,
+        paused: bool,
+        eip1559: Eip1559V1,
+        gas_config: GasConfig,
+        protocol_config: ProtocolConfigV1,
+        buffer_config: BufferConfigV1,
+        partner_oracle_config: PartnerOracleConfig,
+        base_oracle_config: BaseOracleConfig,
+    }
+
+    /// A bridge where every field that exists since v1 is set and every later field is zero.
+    fn sample_bridge() -> Bridge {
+        let config = Eip1559Config::test_new();
+        Bridge {
+            version: BRIDGE_LAYOUT_VERSION,
+            base_block_number: 42,
+            nonce: 7,
+            guardian: Pubkey::new_unique(),
+            paused: true,
+            eip1559: Eip1559 {
+                config: Eip1559Config {
+                    max_base_fee: 0,
+                    max_window_increase_bps: 0,
+                    ..config
+                },
+                current_base_fee: 3,
+                current_window_gas_used: 100,
+                window_start_time: 1747440000,
+            },
+            gas_config: GasConfig::test_new(TEST_GAS_FEE_RECEIVER),
+            protocol_config: ProtocolConfig {
+                remote_chain_id: 0,
+                ..ProtocolConfig::test_new()
+            },
+            buffer_config: BufferConfig {
+                call_buffer_ttl_seconds: 0,
+                expire_bounty_bps: 0,
+                ..BufferConfig::test_new()
+            },
+            partner_oracle_config: PartnerOracleConfig::default(),
+            base_oracle_config: BaseOracleConfig::test_new(),
+            oracle_failover: OracleFailover::default(),
+            root_freshness_config: RootFreshnessConfig::default(),
+            oracle_health: OracleHealth::default(),
+            outflow_breaker: OutflowBreaker::default(),
+            finalization_delay: FinalizationDelay::default(),
+            base_governance: BaseGovernance::default(),
+            outbox: Outbox::default(),
+            guarded_launch: false,
+            transfer_gas_lane: Eip1559::default(),
+            require_wrapped_token_confirmation: false,
+            fee_quote_grace: FeeQuoteGrace::default(),
+        }
+    }
+
+    /// Inverse of the migration: strips the insertions of every layout above `to_version`.
+    fn downgrade(mut data: Vec<u8>, to_version: u8) -> Vec<u8> {
+        for layout in BRIDGE_LAYOUTS
+            .iter()
+            .rev()
+            .filter(|layout| layout.version > to_version)
+        {
+            for insertion in layout.insertions.iter().rev() {
+                data.drain(insertion.offset..insertion.offset + insertion.len);
+            }
+        }
+        if to_version > 1 {
+            data[0] = to_version;
+        }
+        data
+    }
+
+    fn migrate(old_data: &[u8]) -> Bridge {
+        let from_version = detect_bridge_version(old_data).unwrap();
+
+        let mut data = vec![0u8; Bridge::INIT_SPACE];
+        data[..old_data.len()].copy_from_slice(old_data);
+        migrate_bridge_data(&mut data, from_version).unwrap();
+
+        assert_eq!(detect_bridge_version(&data).unwrap(), BRIDGE_LAYOUT_VERSION);
+        Bridge::deserialize(&mut &data[..]).unwrap()
+    }
+
+    #[test]
+    fn test_current_layout_matches_bridge_space() {
+        let current = bridge_layout(BRIDGE_LAYOUT_VERSION).unwrap();
+        assert_eq!(current.space, Bridge::INIT_SPACE);
+        assert_eq!(
+            BRIDGE_LAYOUTS.last().unwrap().version,
+            BRIDGE_LAYOUT_VERSION
+        );
+    }
+
+    #[test]
+    fn test_layout_spaces_are_consistent() {
+        for pair in BRIDGE_LAYOUTS.windows(2) {
+            let inserted: usize = pair[1].insertions.iter().map(|i| i.len).sum();
+            assert_eq!(pair[1].version, pair[0].version + 1);
+            assert_eq!(pair[0].space + inserted, pair[1].space);
+        }
+    }
+
+    #[test]
+    fn test_migrate_v1_to_current() {
+        let expected = sample_bridge();
+        let v1 = BridgeV1 {
+            base_block_number: expected.base_block_number,
+            nonce: expected.nonce,
+            guardian: expected.guardian,
+            paused: expected.paused,
+            eip1559: Eip1559V1 {
+                config: Eip1559ConfigV1 {
+                    target: expected.eip1559.config.target,
+                    denominator: expected.eip1559.config.denominator,
+                    window_duration_seconds: expected.eip1559.config.window_duration_seconds,
+                    minimum_base_fee: expected.eip1559.config.minimum_base_fee,
+                },
+                current_base_fee: expected.eip1559.current_base_fee,
+                current_window_gas_used: expected.eip1559.current_window_gas_used,
+                window_start_time: expected.eip1559.window_start_time,
+            },
+            gas_config: expected.gas_config.clone(),
+            protocol_config: ProtocolConfigV1 {
+                block_interval_requirement: expected.protocol_config.block_interval_requirement,
+                remote_sol_address: expected.protocol_config.remote_sol_address,
+            },
+            buffer_config: BufferConfigV1 {
+                max_call_buffer_size: expected.buffer_config.max_call_buffer_size,
+            },
+            partner_oracle_config: expected.partner_oracle_config.clone(),
+            base_oracle_config: expected.base_oracle_config.clone(),
+        };
+
+        let mut v1_data = Vec::new();
+        v1.serialize(&mut v1_data).unwrap();
+        assert_eq!(v1_data.len(), 520);
+        assert_eq!(detect_bridge_version(&v1_data).unwrap(), 1);
+
+        assert_eq!(migrate(&v1_data), expected);
+    }
+
+    #[test]
+    fn test_migrate_every_version_to_current() {
+        let expected = sample_bridge();
+        let mut data = Vec::new();
+        expected.serialize(&mut data).unwrap();
+
+        for layout in &BRIDGE_LAYOUTS[..BRIDGE_LAYOUTS.len() - 1] {
+            let old_data = downgrade(data.clone(), layout.version);
+            assert_eq!(old_data.len(), layout.space);
+            assert_eq!(detect_bridge_version(&old_data).unwrap(), layout.version);
+
+            assert_eq!(migrate(&old_data), expected);
+        }
+    }
+
+    #[test]
+    fn test_detect_unknown_layout_fails() {
+        let data = vec![0u8; 100];
+        assert!(detect_bridge_version(&data).is_err());
+
+        let mut data = vec![0u8; Bridge::INIT_SPACE];
+        data[0] = u8::MAX;
+        assert!(detect_bridge_version(&data).is_err());
+    }
+}
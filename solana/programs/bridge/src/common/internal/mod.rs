@@ -1,6 +1,9 @@
+pub mod crosschain;
 pub mod init_config;
-pub mod math;
 pub mod metadata;
+pub mod migration;
+pub mod scaling;
 
+pub use crosschain::*;
 pub use init_config::*;
 pub use metadata::*;
@@ -0,0 +1,109 @@
+use alloy_primitives::U256;
+use anchor_lang::prelude::*;
+
+use crate::BridgeError;
+
+/// Returns the scalar `10^scaler_exponent` applied by Base to the amounts of a wrapped token.
+/// Fails when it does not fit in a u256.
+pub fn scalar(scaler_exponent: u8) -> Result<U256> {
+    U256::from(10)
+        .checked_pow(U256::from(scaler_exponent))
+        .ok_or_else(|| error!(BridgeError::ScaledAmountOverflow))
+}
+
+/// Converts an `amount` of a wrapped token in Solana (local) precision into the Base (remote)
+/// precision, i.e. `amount * 10^scaler_exponent`. Fails when the result does not fit in a u256.
+pub fn to_remote_amount(amount: u64, scaler_exponent: u8) -> Result<U256> {
+    scalar(scaler_exponent)?
+        .checked_mul(U256::from(amount))
+        .ok_or_else(|| error!(BridgeError::ScaledAmountOverflow))
+}
+
+/// Converts an `amount` of a wrapped token in Base (remote) precision into the Solana (local)
+/// precision, i.e. `amount / 10^scaler_exponent`. Fails when `amount` is not a multiple of the
+/// scalar (the remainder would be lost) or when the result does not fit in a u64.
+pub fn to_local_amount(amount: U256, scaler_exponent: u8) -> Result<u64> {
+    let scalar = scalar(scaler_exponent)?;
+    require!(
+        (amount % scalar).is_zero(),
+        BridgeError::InexactScaledAmount
+    );
+
+    u64::try_from(amount / scalar).map_err(|_| error!(BridgeError::ScaledAmountOverflow))
+}
+
+/// Converts an `amount` of an SPL token locked in its vault into the smallest unit of the Base
+/// token it is bridged to, i.e. `amount / 10^scaler_exponent`. Fails when `amount` is not a
+/// multiple of the scalar (the remainder would be locked without being bridged).
+pub fn to_remote_spl_amount(amount: u64, scaler_exponent: u8) -> Result<u64> {
+    to_local_amount(U256::from(amount), scaler_exponent)
+}
+
+/// Converts an `amount` of an SPL token carried in the smallest unit of the Base token it is
+/// bridged to back into the mint precision, i.e. `amount * 10^scaler_exponent`. Fails when the
+/// result does not fit in a u64.
+pub fn to_local_spl_amount(amount: u64, scaler_exponent: u8) -> Result<u64> {
+    u64::try_from(to_remote_amount(amount, scaler_exponent)?)
+        .map_err(|_| error!(BridgeError::ScaledAmountOverflow))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_remote_amount() {
+        assert_eq!(to_remote_amount(5, 0).unwrap(), U256::from(5));
+        assert_eq!(
+            to_remote_amount(1_000_000_000, 9).unwrap(),
+            U256::from(10).pow(U256::from(18))
+        );
+        assert_eq!(
+            to_remote_amount(u64::MAX, 57).unwrap(),
+            U256::from(u64::MAX) * U256::from(10).pow(U256::from(57))
+        );
+    }
+
+    #[test]
+    fn test_to_remote_amount_overflow() {
+        // 10^78 does not fit in a u256
+        assert!(to_remote_amount(0, 78).is_err());
+        // 10^77 fits, but not once multiplied by 12
+        assert!(to_remote_amount(1, 77).is_ok());
+        assert!(to_remote_amount(12, 77).is_err());
+    }
+
+    #[test]
+    fn test_to_local_amount() {
+        assert_eq!(
+            to_local_amount(U256::from(10).pow(U256::from(18)), 9).unwrap(),
+            1_000_000_000
+        );
+        assert_eq!(
+            to_local_amount(to_remote_amount(u64::MAX, 9).unwrap(), 9).unwrap(),
+            u64::MAX
+        );
+    }
+
+    #[test]
+    fn test_to_local_amount_rejects_remainder_and_overflow() {
+        assert!(to_local_amount(U256::from(1_000_000_001u64), 9).is_err());
+        assert!(to_local_amount(U256::from(u64::MAX) + U256::from(1), 0).is_err());
+        assert!(to_local_amount(U256::ZERO, 78).is_err());
+    }
+
+    #[test]
+    fn test_spl_amount_round_trip() {
+        assert_eq!(
+            to_remote_spl_amount(1_000_000_000_000, 6).unwrap(),
+            1_000_000
+        );
+        assert_eq!(
+            to_local_spl_amount(1_000_000, 6).unwrap(),
+            1_000_000_000_000
+        );
+        assert_eq!(to_remote_spl_amount(42, 0).unwrap(), 42);
+        assert!(to_remote_spl_amount(1_000_001, 6).is_err());
+        assert!(to_local_spl_amount(u64::MAX, 1).is_err());
+    }
+}
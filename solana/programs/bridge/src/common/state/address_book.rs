@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::constants::BRIDGE_CPI_AUTHORITY_SEED, common::MAX_ADDRESS_BOOK_TOKENS,
+    BridgeError, ID,
+};
+
+/// Guardian-maintained registry of canonical Base addresses, so that instructions and SDKs
+/// share a single on-chain source of truth instead of hardcoded constants.
+/// Unset addresses are zero.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct AddressBook {
+    /// The Base Bridge contract.
+    pub bridge: [u8; 20],
+    /// The Base RelayerOrchestrator contract.
+    pub relayer_orchestrator: [u8; 20],
+    /// Number of tokens in `tokens` array
+    pub token_count: u8,
+    /// Standard tokens bridged between Solana and Base
+    pub tokens: [AddressBookToken; MAX_ADDRESS_BOOK_TOKENS as usize],
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize,
+)]
+pub struct AddressBookToken {
+    /// The token on Solana (a mint, or `NATIVE_SOL_PUBKEY` for SOL)
+    pub local_token: Pubkey,
+    /// The token address on Base
+    pub remote_token: [u8; 20],
+}
+
+impl AddressBook {
+    /// Replaces the address book content, validating the token list.
+    pub fn set(
+        &mut self,
+        bridge: [u8; 20],
+        relayer_orchestrator: [u8; 20],
+        tokens: &[AddressBookToken],
+    ) -> Result<()> {
+        require!(
+            tokens.len() <= MAX_ADDRESS_BOOK_TOKENS as usize,
+            BridgeError::TooManyAddressBookTokens
+        );
+
+        let mut local_tokens: Vec<Pubkey> = tokens.iter().map(|t| t.local_token).collect();
+        local_tokens.sort();
+        local_tokens.dedup();
+        require!(
+            local_tokens.len() == tokens.len(),
+            BridgeError::DuplicateAddressBookToken
+        );
+
+        self.bridge = bridge;
+        self.relayer_orchestrator = relayer_orchestrator;
+        self.token_count = tokens.len() as u8;
+        self.tokens = Default::default();
+        self.tokens[..tokens.len()].copy_from_slice(tokens);
+
+        Ok(())
+    }
+
+    /// Returns the Base Bridge contract address, failing if it is not set.
+    pub fn bridge(&self) -> Result<[u8; 20]> {
+        require!(
+            self.bridge != [0u8; 20],
+            BridgeError::AddressBookEntryNotSet
+        );
+        Ok(self.bridge)
+    }
+
+    /// Returns the bridge CPI authority of the Base Bridge, which signs the instructions relayed
+    /// from messages sent by the Base Bridge.
+    pub fn bridge_cpi_authority(&self) -> Result<Pubkey> {
+        let bridge = self.bridge()?;
+        Ok(Pubkey::find_program_address(&[BRIDGE_CPI_AUTHORITY_SEED, bridge.as_ref()], &ID).0)
+    }
+
+    /// Returns the Base address of a standard token, if registered.
+    pub fn remote_token(&self, local_token: &Pubkey) -> Option<[u8; 20]> {
+        self.tokens[..self.token_count as usize]
+            .iter()
+            .find(|t| t.local_token == *local_token)
+            .map(|t| t.remote_token)
+    }
+}
@@ -1,14 +1,23 @@
 use anchor_lang::prelude::*;
+use fee_config::FeeConfigV2;
 
+use crate::base_to_solana::constants::BRIDGE_CPI_AUTHORITY_SEED;
+use crate::base_to_solana::internal::mmr::{commutative_keccak256, ordered_keccak256};
 use crate::common::{
-    internal::math::{fixed_pow, SCALE},
+    BRIDGE_LAYOUT_VERSION, DISCRIMINATOR_LEN, MAX_EMERGENCY_SIGNER_COUNT,
+    MAX_FINALIZATION_THRESHOLDS, MAX_OUTBOX_PEAKS, MAX_OUTFLOW_LIMITS,
     MAX_PARTNER_VALIDATOR_THRESHOLD, MAX_SIGNER_COUNT,
 };
-use crate::BridgeError;
+use crate::{BridgeError, BASE_CHAIN_ID, ID};
+
+pub use eip1559::{Eip1559, Eip1559Config};
 
 #[account]
 #[derive(Debug, PartialEq, Eq, InitSpace)]
 pub struct Bridge {
+    /// Layout version of this account (see `BRIDGE_LAYOUT_VERSION`).
+    /// Must remain the first field so older layouts can be detected before deserialization.
+    pub version: u8,
     /// The Base block number associated with the latest registered output root.
     pub base_block_number: u64,
     /// Incremental nonce assigned to each outgoing message.
@@ -29,134 +38,192 @@ pub struct Bridge {
     pub partner_oracle_config: PartnerOracleConfig,
     /// Configuration parameters for Base oracle signers
     pub base_oracle_config: BaseOracleConfig,
+    /// Emergency oracle quorum used when output roots stop being registered
+    pub oracle_failover: OracleFailover,
+    /// Maximum output root age accepted by `prove_message` for each message kind
+    pub root_freshness_config: RootFreshnessConfig,
+    /// Latest output root signed by each Base oracle signer
+    pub oracle_health: OracleHealth,
+    /// Circuit breaker pausing the bridge on anomalous outflows
+    pub outflow_breaker: OutflowBreaker,
+    /// Delay before high-value incoming transfers can be relayed
+    pub finalization_delay: FinalizationDelay,
+    /// Governance contract on Base controlling the critical configuration
+    pub base_governance: BaseGovernance,
+    /// Merkle mountain range committing to every outgoing message, proven against on Base
+    pub outbox: Outbox,
+    /// Whether `bridge_spl` only accepts the mints of the guardian-maintained allow-list, while
+    /// the bridge is gradually opened after launch
+    pub guarded_launch: bool,
+    /// EIP-1559 state and configuration pricing token transfers apart from calls, so that demand
+    /// in one lane does not distort pricing in the other. Transfers are priced by `eip1559` while
+    /// its target is zero.
+    pub transfer_gas_lane: Eip1559,
+    /// Whether `bridge_wrapped_token` only accepts wrapped tokens whose registration was confirmed
+    /// by the Base Bridge, so that tokens are not burned for a token Base does not know yet
+    pub require_wrapped_token_confirmation: bool,
+    /// Grace period keeping gas fee quotes valid across EIP-1559 window rollovers
+    pub fee_quote_grace: FeeQuoteGrace,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
-pub struct Eip1559 {
-    /// Configuration parameters for EIP-1559-inspired fee calculations
-    pub config: Eip1559Config,
-    /// Current base fee used in fee computation (runtime state).
-    /// Unitless value combined with `gas_per_call` and gas cost scaler to produce lamports.
-    pub current_base_fee: u64,
-    /// Gas used in the current time window (runtime state)
-    pub current_window_gas_used: u64,
-    /// Unix timestamp when the current window started (runtime state)
-    pub window_start_time: i64,
-}
+impl Bridge {
+    /// Returns the nonce of the next outgoing message from `account`, the `Bridge` PDA, without
+    /// deserializing the whole account. The account must be in the current layout, whose header
+    /// is `version (1) | base_block_number (8) | nonce (8)`.
+    pub fn nonce_in(account: &AccountInfo) -> Result<u64> {
+        require_keys_eq!(
+            *account.owner,
+            ID,
+            anchor_lang::error::ErrorCode::AccountOwnedByWrongProgram
+        );
 
-#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
-pub struct Eip1559Config {
-    /// Gas target per window
-    pub target: u64,
-    /// Adjustment denominator (controls rate of change)
-    pub denominator: u64,
-    /// Window duration in seconds
-    pub window_duration_seconds: u64,
-    /// Minimum base fee. Used to seed `current_base_fee` at initialization
-    /// and as an underflow clamp during decreases; not enforced as a strict lower bound
-    /// on every step.
-    pub minimum_base_fee: u64,
-}
-
-impl Eip1559Config {
-    pub fn validate(&self) -> Result<()> {
-        require!(self.denominator > 0, BridgeError::InvalidDenominator);
+        let data = account.try_borrow_data()?;
         require!(
-            self.window_duration_seconds > 0,
-            BridgeError::InvalidWindowDurationSeconds
+            data.starts_with(Self::DISCRIMINATOR),
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+        require!(
+            data.get(DISCRIMINATOR_LEN) == Some(&BRIDGE_LAYOUT_VERSION),
+            BridgeError::InvalidBridgeLayout
         );
-        Ok(())
-    }
-}
 
-impl Eip1559 {
-    /// Refresh the base fee if window has expired, reset window tracking
-    /// Handles multiple expired windows by processing each empty window
-    pub fn refresh_base_fee(&mut self, current_timestamp: i64) -> u64 {
-        let expired_windows_count = self.expired_windows_count(current_timestamp);
-        if expired_windows_count == 0 {
-            return self.current_base_fee;
-        }
+        let offset = DISCRIMINATOR_LEN + 1 + 8;
+        let nonce = data
+            .get(offset..offset + 8)
+            .ok_or(anchor_lang::error::ErrorCode::AccountDidNotDeserialize)?;
+        Ok(u64::from_le_bytes(nonce.try_into().unwrap()))
+    }
 
-        // Process the first window with actual gas usage
-        let mut current_base_fee = self.calc_base_fee(self.current_window_gas_used);
-        let remaining_windows_count = expired_windows_count - 1;
-
-        // Process the remaining empty windows (if any)
-        //
-        // This corresponds to applying this formula (because gas_used is 0):
-        //      base_fee_n+1 = base_fee_n - (base_fee_n / denom)
-        //                   = base_fee_n * (1 - 1 / denom)
-        //                   = base_fee_n * (denom - 1) / denom
-        // Thus:
-        //      base_fee_n = base_fee_0 * [(denom - 1) / denom]^n
-        if remaining_windows_count > 0 {
-            // Scale up as we're going to do some arithmetic
-            let scaled_denominator = self.config.denominator as u128 * SCALE;
-
-            // [(denom - 1) / denom]
-            // Guaranteed to be < SCALE.
-            // NOTE: scaled_denominator is in SCALE units while self.denominator is not
-            //       so the returned ratio is also in SCALE units
-            let ratio = (scaled_denominator - SCALE) / (self.config.denominator as u128);
-
-            // [(denom - 1) / denom]^(n-1)
-            // Guaranteed to be < SCALE because ratio < SCALE.
-            let factor = fixed_pow(ratio, remaining_windows_count);
-
-            // base_fee_0 * [(denom - 1) / denom]^n
-            // NOTE: multiply first in u128 and divide to scale back and fit into u64 while
-            //       preserving the best precision
-            current_base_fee = ((current_base_fee as u128 * factor) / SCALE) as u64;
+    /// Returns the chain ID of the remote chain, stamped on outgoing messages and required from
+    /// incoming messages. Only Base is supported for now.
+    pub fn remote_chain_id(&self) -> u64 {
+        match self.protocol_config.remote_chain_id {
+            0 => BASE_CHAIN_ID,
+            remote_chain_id => remote_chain_id,
         }
+    }
 
-        // Update state for new window
-        self.current_base_fee = current_base_fee.max(self.config.minimum_base_fee);
-        self.current_window_gas_used = 0;
-        self.window_start_time +=
-            (expired_windows_count * self.config.window_duration_seconds) as i64;
-
-        self.current_base_fee
+    /// Returns the EIP-1559 controller pricing the messages of `lane`.
+    pub fn gas_lane_mut(&mut self, lane: GasLane) -> &mut Eip1559 {
+        self.gas_lane_and_previous_base_fee_mut(lane).0
     }
 
-    /// Add gas usage to current window
-    pub fn add_gas_usage(&mut self, gas_amount: u64) {
-        self.current_window_gas_used += gas_amount;
+    /// Refreshes the base fee of `lane` and returns the base fee to charge, applying the
+    /// `fee_quote_grace` after a window rollover. `on_capped` is called as by
+    /// `Eip1559::refresh_base_fee_with`.
+    pub fn refresh_base_fee_with(
+        &mut self,
+        lane: GasLane,
+        current_timestamp: i64,
+        on_capped: impl FnOnce(u64, u64),
+    ) -> u64 {
+        let grace_period_seconds = self.fee_quote_grace.grace_period_seconds;
+        let (gas_lane, previous_base_fee) = self.gas_lane_and_previous_base_fee_mut(lane);
+        refresh_quoted_base_fee(
+            gas_lane,
+            previous_base_fee,
+            grace_period_seconds,
+            current_timestamp,
+            on_capped,
+        )
     }
 
-    /// Calculate the base fee for the next window based on current window gas usage
-    fn calc_base_fee(&self, gas_used: u64) -> u64 {
-        if gas_used == self.config.target {
-            return self.current_base_fee;
+    fn gas_lane_and_previous_base_fee_mut(&mut self, lane: GasLane) -> (&mut Eip1559, &mut u64) {
+        match lane {
+            GasLane::Transfer if self.transfer_gas_lane.config.target != 0 => (
+                &mut self.transfer_gas_lane,
+                &mut self.fee_quote_grace.previous_transfer_base_fee,
+            ),
+            _ => (
+                &mut self.eip1559,
+                &mut self.fee_quote_grace.previous_base_fee,
+            ),
         }
+    }
 
-        if gas_used > self.config.target {
-            // If the current window used more gas than target, the base fee should increase.
-            // max(1, baseFee * gasUsedDelta / target / denominator)
-            let gas_used_delta = gas_used - self.config.target;
-            let base_fee_delta = (gas_used_delta * self.current_base_fee)
-                / self.config.target
-                / self.config.denominator;
-
-            // Ensure minimum increase of 1
-            let base_fee_delta = base_fee_delta.max(1);
-            self.current_base_fee + base_fee_delta
-        } else {
-            // If the current window used less gas than target, the base fee should decrease
-            // by (baseFee * gasUsedDelta / target / denominator).
-            let gas_used_delta = self.config.target - gas_used;
-            let base_fee_delta = (gas_used_delta * self.current_base_fee)
-                / self.config.target
-                / self.config.denominator;
-
-            self.current_base_fee.saturating_sub(base_fee_delta)
+    /// Returns the pricing parameters shared with the relayer program.
+    pub fn fee_config(&self) -> FeeConfigV2 {
+        let config = &self.eip1559.config;
+        FeeConfigV2 {
+            target: config.target,
+            denominator: config.denominator,
+            window_duration_seconds: config.window_duration_seconds,
+            minimum_base_fee: config.minimum_base_fee,
+            max_base_fee: config.max_base_fee,
+            max_window_increase_bps: config.max_window_increase_bps,
+            gas_cost_scaler: self.gas_config.gas_cost_scaler,
+            gas_cost_scaler_dp: self.gas_config.gas_cost_scaler_dp,
+            gas_fee_receiver: self.gas_config.gas_fee_receiver,
         }
     }
 
-    /// Check if the current window has expired based on current timestamp
-    fn expired_windows_count(&self, current_timestamp: i64) -> u64 {
-        (current_timestamp as u64 - self.window_start_time as u64)
-            / self.config.window_duration_seconds
+    /// Writes `fee_config` into the EIP-1559 and gas configurations. `gas_per_call` is bridge
+    /// specific and left unchanged.
+    pub fn apply_fee_config(&mut self, fee_config: &FeeConfigV2) {
+        self.eip1559.config = Eip1559Config {
+            target: fee_config.target,
+            denominator: fee_config.denominator,
+            window_duration_seconds: fee_config.window_duration_seconds,
+            minimum_base_fee: fee_config.minimum_base_fee,
+            max_base_fee: fee_config.max_base_fee,
+            max_window_increase_bps: fee_config.max_window_increase_bps,
+        };
+        self.gas_config.gas_cost_scaler = fee_config.gas_cost_scaler;
+        self.gas_config.gas_cost_scaler_dp = fee_config.gas_cost_scaler_dp;
+        self.gas_config.gas_fee_receiver = fee_config.gas_fee_receiver;
+    }
+}
+
+/// Gas accounting lane of an outgoing message, each with its own gas target and base fee.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GasLane {
+    /// Calls and the other messages not transferring tokens
+    Call,
+    /// Messages transferring tokens, with or without a call
+    Transfer,
+}
+
+/// Grace period keeping gas fee quotes valid across EIP-1559 window rollovers.
+///
+/// The base fee is fixed within a window, so a fee simulated in one window only goes stale once
+/// the next window starts. For `grace_period_seconds` after a rollover, messages are charged the
+/// lower of the base fees of the current and previous windows, so that a quote remains an upper
+/// bound of the fee until the end of its window plus the grace period.
+#[derive(Debug, Clone, Default, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct FeeQuoteGrace {
+    /// Seconds after a window rollover during which the previous base fee still applies, capped
+    /// at the window duration of the gas lane (0 disables the grace period)
+    pub grace_period_seconds: u64,
+    /// Base fee of the window preceding the current window of `eip1559` (runtime state)
+    pub previous_base_fee: u64,
+    /// Base fee of the window preceding the current window of `transfer_gas_lane` (runtime state)
+    pub previous_transfer_base_fee: u64,
+}
+
+/// Refreshes the base fee of `gas_lane`, recording the base fee of the window preceding the new
+/// one in `previous_base_fee` on rollovers, and returns the base fee to charge.
+fn refresh_quoted_base_fee(
+    gas_lane: &mut Eip1559,
+    previous_base_fee: &mut u64,
+    grace_period_seconds: u64,
+    current_timestamp: i64,
+    on_capped: impl FnOnce(u64, u64),
+) -> u64 {
+    let window_duration = gas_lane.config.window_duration_seconds as i64;
+    let mut before_refresh = gas_lane.clone();
+    let base_fee = gas_lane.refresh_base_fee_with(current_timestamp, on_capped);
+
+    if gas_lane.window_start_time != before_refresh.window_start_time {
+        // Empty windows skipped by the rollover are priced the same way as by the refresh
+        *previous_base_fee = before_refresh.refresh_base_fee(current_timestamp - window_duration);
+    }
+
+    let grace_period_end =
+        gas_lane.window_start_time + (grace_period_seconds as i64).min(window_duration);
+    if current_timestamp < grace_period_end && *previous_base_fee != 0 {
+        base_fee.min(*previous_base_fee)
+    } else {
+        base_fee
     }
 }
 
@@ -180,6 +247,22 @@ impl GasConfig {
         );
         Ok(())
     }
+
+    /// Checks that the `gas_fee_receiver` account is a system account, so that gas fees can
+    /// be credited to it by System Program transfers.
+    pub fn validate_gas_fee_receiver(&self, gas_fee_receiver: &AccountInfo) -> Result<()> {
+        require_keys_eq!(
+            gas_fee_receiver.key(),
+            self.gas_fee_receiver,
+            BridgeError::IncorrectGasFeeReceiver
+        );
+        require!(
+            !gas_fee_receiver.executable
+                && gas_fee_receiver.owner == &anchor_lang::system_program::ID,
+            BridgeError::InvalidGasFeeReceiver
+        );
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
@@ -190,6 +273,10 @@ pub struct ProtocolConfig {
 
     /// The Base evm address of SOL
     pub remote_sol_address: [u8; 20],
+
+    /// Chain ID of the remote chain messages are sent to and received from.
+    /// 0 selects the `BASE_CHAIN_ID` of the compiled environment (see `Bridge::remote_chain_id`).
+    pub remote_chain_id: u64,
 }
 
 impl ProtocolConfig {
@@ -216,6 +303,20 @@ impl ProtocolConfig {
 pub struct BufferConfig {
     /// Maximum call buffer size. This caps the max size of a Solana → Base message.
     pub max_call_buffer_size: u64,
+    /// Seconds a call buffer must stay unmodified before anyone can expire it (0 disables expiry).
+    pub call_buffer_ttl_seconds: u64,
+    /// Share of an expired call buffer's rent paid to the caller, in basis points.
+    pub expire_bounty_bps: u64,
+}
+
+impl BufferConfig {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.expire_bounty_bps <= 10_000,
+            BridgeError::InvalidExpireBountyBps
+        );
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default)]
@@ -272,6 +373,22 @@ impl BaseOracleConfig {
         self.signers[..active_len].iter().any(|s| s == evm_addr)
     }
 
+    /// Removes `signers` from the active signers, keeping the order of the remaining ones.
+    /// The threshold is clamped to the remaining signers, and never drops below one.
+    pub fn remove_signers(&mut self, signers: &[[u8; 20]]) {
+        let active_len = core::cmp::min(self.signer_count as usize, self.signers.len());
+        let remaining: Vec<[u8; 20]> = self.signers[..active_len]
+            .iter()
+            .filter(|s| !signers.contains(s))
+            .copied()
+            .collect();
+
+        self.signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+        self.signers[..remaining.len()].copy_from_slice(&remaining);
+        self.signer_count = remaining.len() as u8;
+        self.threshold = self.threshold.min(self.signer_count.max(1));
+    }
+
     pub fn count_approvals(&self, signers: &[[u8; 20]]) -> u32 {
         let mut count: u32 = 0;
         for signer in signers.iter() {
@@ -283,200 +400,610 @@ impl BaseOracleConfig {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// Failover for the Base oracle quorum. When no output root has been registered for
+/// `stall_seconds`, the guardian can temporarily lower the Base oracle threshold and accept
+/// additional emergency signers until `emergency_expires_at`.
+#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default)]
+pub struct OracleFailover {
+    /// Unix timestamp at which the latest output root was registered (0 before the first one)
+    pub last_output_root_at: i64,
+    /// Seconds without a new output root after which the emergency quorum can be activated
+    /// (0 disables emergency mode)
+    pub stall_seconds: u64,
+    /// Seconds the emergency quorum stays active once activated
+    pub emergency_duration_seconds: u64,
+    /// Unix timestamp at which the emergency quorum expires (0 if it was never activated)
+    pub emergency_expires_at: i64,
+    /// Number of required valid unique signatures while the emergency quorum is active
+    pub emergency_threshold: u8,
+    /// Number of signers in `emergency_signers` array
+    pub emergency_signer_count: u8,
+    /// Signers accepted in addition to the Base oracle signers while the emergency quorum is active
+    pub emergency_signers: [[u8; 20]; MAX_EMERGENCY_SIGNER_COUNT as usize],
+}
 
-    #[test]
-    fn test_new_state_creation() {
-        let timestamp = 1234567890;
-        let state = Eip1559 {
-            config: Eip1559Config::test_new(),
-            current_base_fee: 1000,
-            current_window_gas_used: 0,
-            window_start_time: timestamp,
-        };
+impl OracleFailover {
+    /// Returns whether output roots stalled for long enough to activate the emergency quorum.
+    /// Never stalled before the first output root is registered.
+    pub fn is_stalled(&self, now: i64) -> bool {
+        let stalled_at = self
+            .last_output_root_at
+            .saturating_add(self.stall_seconds as i64);
+        self.stall_seconds > 0 && self.last_output_root_at > 0 && now >= stalled_at
+    }
 
-        assert_eq!(state.config, Eip1559Config::test_new());
-        assert_eq!(state.current_base_fee, 1000);
-        assert_eq!(state.current_window_gas_used, 0);
-        assert_eq!(state.window_start_time, timestamp);
+    /// Returns whether the emergency quorum is active.
+    pub fn is_emergency_active(&self, now: i64) -> bool {
+        now < self.emergency_expires_at
     }
 
-    #[test]
-    fn test_calc_base_fee_gas_equals_target() {
-        let state = Eip1559 {
-            config: Eip1559Config::test_new(),
-            current_base_fee: 1000,
-            current_window_gas_used: 5_000_000,
-            window_start_time: 0,
-        };
-        let gas_used = state.config.target; // Exactly at target
+    pub fn contains(&self, evm_addr: &[u8; 20]) -> bool {
+        let active_len = core::cmp::min(
+            self.emergency_signer_count as usize,
+            self.emergency_signers.len(),
+        );
+        self.emergency_signers[..active_len]
+            .iter()
+            .any(|s| s == evm_addr)
+    }
 
-        let new_fee = state.calc_base_fee(gas_used);
-        assert_eq!(new_fee, state.current_base_fee); // Should remain unchanged
+    /// Counts the `signers` that are either Base oracle signers or emergency signers.
+    pub fn count_approvals(
+        &self,
+        base_oracle_config: &BaseOracleConfig,
+        signers: &[[u8; 20]],
+    ) -> u32 {
+        signers
+            .iter()
+            .filter(|signer| base_oracle_config.contains(signer) || self.contains(signer))
+            .count() as u32
     }
+}
 
-    #[test]
-    fn test_calc_base_fee_gas_above_target() {
-        let state = Eip1559 {
-            config: Eip1559Config::test_new(),
-            current_base_fee: 1000,
-            current_window_gas_used: 0,
-            window_start_time: 0,
-        };
-        let gas_used = state.config.target + 3_000_000; // 3M above target (5M)
+/// Freshness policy of the output roots messages are proven against, per message kind.
+/// Each field is the maximum number of Base blocks between the output root a message is proven
+/// against and the latest registered output root (0 accepts any root). `WithComputeBudget`,
+/// `WithRelayFee` and `WithLamportAllowance` messages follow the policy of the message they wrap.
+#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default)]
+pub struct RootFreshnessConfig {
+    /// Maximum output root age of `Call` messages
+    pub call_max_root_age: u64,
+    /// Maximum output root age of `Transfer` messages
+    pub transfer_max_root_age: u64,
+    /// Maximum output root age of `ReturnTransfer` messages
+    pub return_transfer_max_root_age: u64,
+    /// Maximum output root age of `CallWithSigners` messages
+    pub call_with_signers_max_root_age: u64,
+}
 
-        let new_fee = state.calc_base_fee(gas_used);
+/// Liveness of the Base oracle signers: the last time each of them signed a registered output root.
+/// Heartbeats are keyed by signer so they survive signer set updates; the slots of removed signers
+/// are reused by new ones.
+#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default)]
+pub struct OracleHealth {
+    pub heartbeats: [OracleHeartbeat; MAX_SIGNER_COUNT as usize],
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default,
+)]
+pub struct OracleHeartbeat {
+    /// EVM address of the signer (zero for an unused slot)
+    pub signer: [u8; 20],
+    /// Unix timestamp at which the latest output root signed by `signer` was registered
+    pub last_signed_at: i64,
+}
 
-        // Expected: (3_000_000 * 1000) / 5_000_000 / 2 = 3_000_000_000 / 5_000_000 / 2 = 600 / 2 = 300
-        let expected_adjustment = 300;
-        assert_eq!(new_fee, 1000 + expected_adjustment);
+impl OracleHealth {
+    /// Returns the last heartbeat of `signer` (0 if it never signed a registered output root).
+    pub fn last_signed_at(&self, signer: &[u8; 20]) -> i64 {
+        self.heartbeats
+            .iter()
+            .find(|heartbeat| &heartbeat.signer == signer)
+            .map_or(0, |heartbeat| heartbeat.last_signed_at)
     }
 
-    #[test]
-    fn test_calc_base_fee_gas_below_target() {
-        let state = Eip1559 {
-            config: Eip1559Config::test_new(),
-            current_base_fee: 1000,
-            current_window_gas_used: 0,
-            window_start_time: 0,
-        };
-        let gas_used = state.config.target - 3_000_000; // 3M below target (5M)
+    /// Records a heartbeat at `now` for each of the `signers` that is a Base oracle signer.
+    pub fn record(
+        &mut self,
+        base_oracle_config: &BaseOracleConfig,
+        signers: &[[u8; 20]],
+        now: i64,
+    ) {
+        for signer in signers.iter().filter(|s| base_oracle_config.contains(s)) {
+            // There are never more active signers than slots, so a slot is always available
+            let slot = self
+                .heartbeats
+                .iter()
+                .position(|heartbeat| &heartbeat.signer == signer)
+                .or_else(|| {
+                    self.heartbeats
+                        .iter()
+                        .position(|heartbeat| !base_oracle_config.contains(&heartbeat.signer))
+                });
+
+            if let Some(slot) = slot {
+                self.heartbeats[slot] = OracleHeartbeat {
+                    signer: *signer,
+                    last_signed_at: now,
+                };
+            }
+        }
+    }
+}
+
+/// Circuit breaker pausing the bridge when the outflow of a token over a sliding window exceeds the
+/// threshold set by the guardian, limiting the damage of a key compromise before anyone can react.
+/// Only tokens with a threshold are tracked and a zero window disables the breaker.
+#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default)]
+pub struct OutflowBreaker {
+    /// Length of the sliding window in seconds
+    pub window_seconds: u64,
+    /// Tracked tokens (zero `local_token` for an unused slot)
+    pub limits: [OutflowLimit; MAX_OUTFLOW_LIMITS as usize],
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default,
+)]
+pub struct OutflowLimit {
+    /// The tracked token, `NATIVE_SOL_PUBKEY` for SOL
+    pub local_token: Pubkey,
+    /// Maximum outflow over the sliding window
+    pub threshold: u64,
+    /// Unix timestamp at which the current window started
+    pub window_start: i64,
+    /// Outflow of the current window
+    pub current_outflow: u64,
+    /// Outflow of the previous window
+    pub previous_outflow: u64,
+}
+
+/// Threshold of a token tracked by the outflow circuit breaker.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct OutflowThreshold {
+    /// The tracked token, `NATIVE_SOL_PUBKEY` for SOL
+    pub local_token: Pubkey,
+    /// Maximum outflow over the sliding window
+    pub threshold: u64,
+}
 
-        let new_fee = state.calc_base_fee(gas_used);
+impl OutflowBreaker {
+    /// Replaces the window and thresholds, restarting the tracking of every token.
+    pub fn set(&mut self, window_seconds: u64, thresholds: &[OutflowThreshold]) -> Result<()> {
+        require!(
+            thresholds.len() <= MAX_OUTFLOW_LIMITS as usize,
+            BridgeError::TooManyOutflowThresholds
+        );
+        for (i, threshold) in thresholds.iter().enumerate() {
+            require!(
+                threshold.threshold > 0
+                    && threshold.local_token != Pubkey::default()
+                    && !thresholds[..i]
+                        .iter()
+                        .any(|other| other.local_token == threshold.local_token),
+                BridgeError::InvalidOutflowThreshold
+            );
+        }
 
-        // Expected: (-3_000_000 * 1000) / 5_000_000 / 2 = -3_000_000_000 / 5_000_000 / 2 = -600 / 2 = -300
-        let expected_adjustment = 300; // This is the reduction amount
-        assert_eq!(new_fee, 1000 - expected_adjustment);
+        self.window_seconds = window_seconds;
+        self.limits = Default::default();
+        for (limit, threshold) in self.limits.iter_mut().zip(thresholds) {
+            limit.local_token = threshold.local_token;
+            limit.threshold = threshold.threshold;
+        }
+
+        Ok(())
     }
 
-    #[test]
-    fn test_calc_base_fee_small_changes_have_effect() {
-        let state = Eip1559 {
-            config: Eip1559Config::test_new(),
-            current_base_fee: 10_000_000, // Large base fee to amplify small changes
-            current_window_gas_used: 0,
-            window_start_time: 0,
-        };
-        let gas_used = state.config.target + 1; // Just 1 gas above target
+    /// Forgets the tracked outflows, e.g. once the guardian unpauses a tripped bridge.
+    pub fn reset(&mut self) {
+        for limit in self.limits.iter_mut() {
+            *limit = OutflowLimit {
+                local_token: limit.local_token,
+                threshold: limit.threshold,
+                ..Default::default()
+            };
+        }
+    }
+
+    /// Records an outflow of `amount` of `local_token` at `now`.
+    /// Returns the outflow over the sliding window, `amount` included, if it exceeds the threshold
+    /// of the token, in which case `amount` is not recorded.
+    pub fn record(&mut self, local_token: &Pubkey, amount: u64, now: i64) -> Option<u64> {
+        if self.window_seconds == 0 {
+            return None;
+        }
+
+        let window = i64::try_from(self.window_seconds).unwrap_or(i64::MAX);
+        let limit = self
+            .limits
+            .iter_mut()
+            .find(|limit| limit.threshold > 0 && &limit.local_token == local_token)?;
 
-        let new_fee = state.calc_base_fee(gas_used);
+        let window_outflow = limit.window_outflow(window, now).saturating_add(amount);
+        if window_outflow > limit.threshold {
+            return Some(window_outflow);
+        }
 
-        // Should increase by minimum of 1
-        assert!(new_fee > state.current_base_fee);
+        limit.current_outflow = limit.current_outflow.saturating_add(amount);
+        None
     }
 
-    #[test]
-    fn test_expired_windows_count() {
-        let start_time = 1000;
-        let state = Eip1559 {
-            config: Eip1559Config {
-                target: 5_000_000,
-                denominator: 2,
-                window_duration_seconds: 1,
-                minimum_base_fee: 1,
-            },
-            current_base_fee: 1000,
-            current_window_gas_used: 0,
-            window_start_time: start_time,
-        };
+    /// Records an outflow of `amount` of `local_token` at `now` approved by the guardian, whatever
+    /// the threshold of the token.
+    pub fn record_approved(&mut self, local_token: &Pubkey, amount: u64, now: i64) {
+        if self.window_seconds == 0 {
+            return;
+        }
 
-        // Window should not be expired at start time
-        assert_eq!(state.expired_windows_count(start_time), 0);
+        let window = i64::try_from(self.window_seconds).unwrap_or(i64::MAX);
+        if let Some(limit) = self
+            .limits
+            .iter_mut()
+            .find(|limit| limit.threshold > 0 && &limit.local_token == local_token)
+        {
+            limit.window_outflow(window, now);
+            limit.current_outflow = limit.current_outflow.saturating_add(amount);
+        }
+    }
+}
 
-        // Window should not be expired before duration
-        let before_expiry = start_time + (state.config.window_duration_seconds as i64) - 1;
-        assert_eq!(state.expired_windows_count(before_expiry), 0);
+impl OutflowLimit {
+    /// Rolls the windows forward to `now` and returns the outflow over the sliding window,
+    /// estimated by weighting the previous window by its share still covered by the sliding one.
+    fn window_outflow(&mut self, window: i64, now: i64) -> u64 {
+        let elapsed = now.saturating_sub(self.window_start);
+        if elapsed >= window.saturating_mul(2) {
+            self.previous_outflow = 0;
+            self.current_outflow = 0;
+            self.window_start = now;
+        } else if elapsed >= window {
+            self.previous_outflow = self.current_outflow;
+            self.current_outflow = 0;
+            self.window_start += window;
+        }
 
-        // Window should be expired after duration
-        let after_expiry = start_time + (state.config.window_duration_seconds as i64);
-        assert_eq!(state.expired_windows_count(after_expiry), 1);
+        let elapsed = now.saturating_sub(self.window_start).clamp(0, window) as u128;
+        let window = window as u128;
+        let previous_share = self.previous_outflow as u128 * (window - elapsed) / window;
 
-        // Window should be expired after 2 durations
-        let after_two_expiry = start_time + (2 * state.config.window_duration_seconds as i64);
-        assert_eq!(state.expired_windows_count(after_two_expiry), 2);
+        (previous_share as u64).saturating_add(self.current_outflow)
     }
+}
+
+/// Delay between proving and relaying transfers whose amount exceeds the threshold set by the
+/// guardian for their token, during which the guardian can veto the message. It gives monitoring
+/// time to catch a fraudulent output root before the funds leave. A zero delay disables it.
+#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default)]
+pub struct FinalizationDelay {
+    /// Delay in seconds after the message is proven
+    pub delay_seconds: u64,
+    /// Delayed tokens (zero `local_token` for an unused slot)
+    pub thresholds: [FinalizationThreshold; MAX_FINALIZATION_THRESHOLDS as usize],
+}
+
+/// Threshold of a token whose larger transfers are held by the finalization delay.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default,
+)]
+pub struct FinalizationThreshold {
+    /// The delayed token, `NATIVE_SOL_PUBKEY` for SOL
+    pub local_token: Pubkey,
+    /// Transfers of a larger amount are delayed
+    pub threshold: u64,
+}
+
+impl FinalizationDelay {
+    /// Replaces the delay and thresholds.
+    pub fn set(&mut self, delay_seconds: u64, thresholds: &[FinalizationThreshold]) -> Result<()> {
+        require!(
+            thresholds.len() <= MAX_FINALIZATION_THRESHOLDS as usize,
+            BridgeError::TooManyFinalizationThresholds
+        );
+        for (i, threshold) in thresholds.iter().enumerate() {
+            require!(
+                threshold.local_token != Pubkey::default()
+                    && !thresholds[..i]
+                        .iter()
+                        .any(|other| other.local_token == threshold.local_token),
+                BridgeError::InvalidFinalizationThreshold
+            );
+        }
+
+        self.delay_seconds = delay_seconds;
+        self.thresholds = Default::default();
+        self.thresholds[..thresholds.len()].copy_from_slice(thresholds);
+
+        Ok(())
+    }
+
+    /// Returns the delay applying to a transfer of `amount` of `local_token`, zero if it is not
+    /// delayed.
+    pub fn delay_of(&self, local_token: &Pubkey, amount: u64) -> i64 {
+        let delayed = self.thresholds.iter().any(|threshold| {
+            threshold.local_token != Pubkey::default()
+                && &threshold.local_token == local_token
+                && amount > threshold.threshold
+        });
+
+        if delayed {
+            i64::try_from(self.delay_seconds).unwrap_or(i64::MAX)
+        } else {
+            0
+        }
+    }
+}
+
+/// Governance contract on Base (e.g. the security council) controlling the critical configuration
+/// of the bridge through relayed messages, so that both sides are governed consistently. The
+/// upgrade authority designates the contract, which then enforces its control by relaying
+/// `set_governance_mode`.
+///
+/// While enforced, the Base oracle signers can only be set by the contract. The guardian keeps
+/// its pause switch: nothing can be proven or relayed while the bridge is paused, so the contract
+/// can pause the bridge but not unpause it.
+#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default)]
+pub struct BaseGovernance {
+    /// The governance contract on Base (zero when unset)
+    pub governance: [u8; 20],
+    /// Whether the critical configuration can only be updated by the governance contract
+    pub enforced: bool,
+}
+
+impl BaseGovernance {
+    /// Returns the bridge CPI authority of the governance contract, which signs the instructions
+    /// relayed from its messages, failing if the contract is not set.
+    pub fn cpi_authority(&self) -> Result<Pubkey> {
+        require!(
+            self.governance != [0u8; 20],
+            BridgeError::BaseGovernanceNotSet
+        );
+        Ok(Pubkey::find_program_address(
+            &[BRIDGE_CPI_AUTHORITY_SEED, self.governance.as_ref()],
+            &ID,
+        )
+        .0)
+    }
+
+    /// Fails when the critical configuration can only be updated by the governance contract.
+    pub fn check_not_enforced(&self) -> Result<()> {
+        require!(!self.enforced, BridgeError::GovernedByBase);
+        Ok(())
+    }
+}
+
+/// Merkle mountain range (MMR) of the outgoing message hashes, mirroring the MMR of the output
+/// roots proven against in the Base to Solana direction: mountains hash their nodes commutatively
+/// and the root bags the peaks left to right, so that Base can verify a message against a root
+/// attested once rather than attesting every message account.
+///
+/// Only the peaks are stored: appending a leaf merges the mountains of equal height in place.
+/// Leaves are appended from the layout upgrade introducing the outbox onwards, so the leaf index
+/// of a message is not its nonce on bridges deployed before it.
+#[derive(Debug, Clone, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize, Default)]
+pub struct Outbox {
+    /// Number of leaves appended
+    pub leaf_count: u64,
+    /// Peaks of the mountains from the highest to the lowest, the first `leaf_count.count_ones()`
+    /// being set
+    pub peaks: [[u8; 32]; MAX_OUTBOX_PEAKS as usize],
+}
+
+impl Outbox {
+    /// Appends `leaf` and returns its index.
+    pub fn append(&mut self, leaf: [u8; 32]) -> Result<u64> {
+        let leaf_index = self.leaf_count;
+        require!(
+            leaf_index < (1u64 << MAX_OUTBOX_PEAKS) - 1,
+            BridgeError::OutboxFull
+        );
+
+        // Every trailing one of the leaf count is a mountain as high as the one being built
+        let mut peak_count = leaf_index.count_ones() as usize;
+        let mut node = leaf;
+        for _ in 0..leaf_index.trailing_ones() {
+            peak_count -= 1;
+            node = commutative_keccak256(self.peaks[peak_count], node);
+        }
+
+        self.peaks[peak_count] = node;
+        self.peaks[peak_count + 1..].fill([0u8; 32]);
+        self.leaf_count += 1;
+
+        Ok(leaf_index)
+    }
+
+    /// Returns the root of the MMR, `[0u8; 32]` when it is empty.
+    pub fn root(&self) -> [u8; 32] {
+        let peak_count = self.leaf_count.count_ones() as usize;
+        self.peaks[..peak_count]
+            .iter()
+            .copied()
+            .reduce(ordered_keccak256)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::base_to_solana::internal::mmr::{fuzzing::build_proof, verify_proof};
 
     #[test]
-    fn test_add_gas_usage() {
-        let mut state = Eip1559 {
-            config: Eip1559Config::test_new(),
-            current_base_fee: 1000,
-            current_window_gas_used: 0,
+    fn test_refresh_quoted_base_fee_applies_grace_period() {
+        let mut gas_lane = Eip1559 {
+            config: Eip1559Config {
+                window_duration_seconds: 10,
+                ..Eip1559Config::test_new()
+            },
+            current_base_fee: 100,
+            current_window_gas_used: 15_000_000,
             window_start_time: 0,
         };
-        assert_eq!(state.current_window_gas_used, 0);
-
-        state.add_gas_usage(1000);
-        assert_eq!(state.current_window_gas_used, 1000);
+        let mut previous_base_fee = 0;
 
-        state.add_gas_usage(500);
-        assert_eq!(state.current_window_gas_used, 1500);
+        // The rollover doubles the base fee, but the previous one applies during the grace period
+        let mut refresh = |gas_lane: &mut Eip1559, timestamp| {
+            refresh_quoted_base_fee(gas_lane, &mut previous_base_fee, 3, timestamp, |_, _| {})
+        };
+        assert_eq!(refresh(&mut gas_lane, 5), 100);
+        assert_eq!(refresh(&mut gas_lane, 10), 100);
+        assert_eq!(refresh(&mut gas_lane, 12), 100);
+        assert_eq!(refresh(&mut gas_lane, 13), 200);
+        assert_eq!(gas_lane.current_base_fee, 200);
+        assert_eq!(previous_base_fee, 100);
+
+        // Without grace period, the new base fee applies right away
+        let mut gas_lane = Eip1559 {
+            current_base_fee: 100,
+            current_window_gas_used: 15_000_000,
+            window_start_time: 0,
+            ..gas_lane
+        };
+        let mut previous_base_fee = 0;
+        assert_eq!(
+            refresh_quoted_base_fee(&mut gas_lane, &mut previous_base_fee, 0, 10, |_, _| {}),
+            200
+        );
+        assert_eq!(previous_base_fee, 100);
     }
 
     #[test]
-    fn test_refresh_base_fee_no_expiry() {
-        let mut state = Eip1559 {
-            config: Eip1559Config::test_new(),
-            current_base_fee: 1000,
-            current_window_gas_used: 0,
-            window_start_time: 1000,
+    fn test_refresh_quoted_base_fee_records_skipped_window() {
+        let mut gas_lane = Eip1559 {
+            config: Eip1559Config {
+                window_duration_seconds: 10,
+                ..Eip1559Config::test_new()
+            },
+            current_base_fee: 100,
+            current_window_gas_used: 15_000_000,
+            window_start_time: 0,
         };
-        let original_base_fee = state.current_base_fee;
-        state.add_gas_usage(2_000_000);
+        let mut previous_base_fee = 0;
 
-        // Update with current time (no expiry)
-        state.refresh_base_fee(1000);
-
-        // Base fee should not change, gas usage should remain
-        assert_eq!(state.current_base_fee, original_base_fee);
-        assert_eq!(state.current_window_gas_used, 2_000_000);
-        assert_eq!(state.window_start_time, 1000);
+        // The window [10, 20) had no message: its base fee of 200 decayed to 100 in [20, 30)
+        assert_eq!(
+            refresh_quoted_base_fee(&mut gas_lane, &mut previous_base_fee, 3, 21, |_, _| {}),
+            100
+        );
+        assert_eq!(gas_lane.window_start_time, 20);
+        assert_eq!(previous_base_fee, 200);
     }
 
     #[test]
-    fn test_refresh_base_fee_with_expiry() {
-        let mut state = Eip1559 {
-            config: Eip1559Config::test_new(),
-            current_base_fee: 1000,
-            current_window_gas_used: 0,
-            window_start_time: 1000,
+    fn test_oracle_failover_stall_and_approvals() {
+        let mut failover = OracleFailover {
+            last_output_root_at: 1000,
+            stall_seconds: 600,
+            emergency_signer_count: 1,
+            ..Default::default()
         };
-        state.add_gas_usage(8_000_000); // Above target, should increase fee
+        failover.emergency_signers[0] = [2u8; 20];
 
-        // Update with expired window
-        let new_time = 1000 + state.config.window_duration_seconds as i64;
-        state.refresh_base_fee(new_time);
+        assert!(!failover.is_stalled(1599));
+        assert!(failover.is_stalled(1600));
 
-        // Base fee should increase, gas usage should reset, window should restart
-        assert!(state.current_base_fee > 1000);
-        assert_eq!(state.current_window_gas_used, 0);
-        assert_eq!(state.window_start_time, new_time);
+        // Never stalled when disabled or before the first output root
+        assert!(!OracleFailover {
+            stall_seconds: 0,
+            ..failover.clone()
+        }
+        .is_stalled(i64::MAX));
+        assert!(!OracleFailover {
+            last_output_root_at: 0,
+            ..failover.clone()
+        }
+        .is_stalled(i64::MAX));
+
+        let mut base_oracle_config = BaseOracleConfig::test_new();
+        base_oracle_config.signer_count = 1;
+        base_oracle_config.signers[0] = [1u8; 20];
+        assert_eq!(
+            failover.count_approvals(&base_oracle_config, &[[1u8; 20], [2u8; 20], [3u8; 20]]),
+            2
+        );
     }
 
     #[test]
-    fn test_refresh_base_fee_multiple_empty_windows() {
-        let mut state = Eip1559 {
-            config: Eip1559Config::test_new(),
-            current_base_fee: 8000, // High base fee
-            current_window_gas_used: 0,
-            window_start_time: 1000,
-        };
-        state.add_gas_usage(10_000_000); // High usage in first window
-
-        // Jump 1 window into the future
-        let new_time = 1000 + state.config.window_duration_seconds as i64;
-        let base_fee_immediately_after_first_window = state.refresh_base_fee(new_time);
+    fn test_oracle_health_record_reuses_removed_signer_slots() {
+        let mut base_oracle_config = BaseOracleConfig::test_new();
+        base_oracle_config.signer_count = 2;
+        base_oracle_config.signers[1] = [2u8; 20];
+
+        let mut health = OracleHealth::default();
+        health.record(&base_oracle_config, &[[1u8; 20], [2u8; 20], [3u8; 20]], 100);
+        health.record(&base_oracle_config, &[[2u8; 20]], 200);
+
+        assert_eq!(health.last_signed_at(&[1u8; 20]), 100);
+        assert_eq!(health.last_signed_at(&[2u8; 20]), 200);
+        // Not a Base oracle signer
+        assert_eq!(health.last_signed_at(&[3u8; 20]), 0);
+
+        // The slot of a removed signer is reused by its replacement
+        base_oracle_config.remove_signers(&[[1u8; 20]]);
+        base_oracle_config.signers[1] = [4u8; 20];
+        base_oracle_config.signer_count = 2;
+        health.record(&base_oracle_config, &[[4u8; 20]], 300);
+
+        assert_eq!(health.heartbeats[0].signer, [4u8; 20]);
+        assert_eq!(health.last_signed_at(&[4u8; 20]), 300);
+        assert_eq!(health.last_signed_at(&[1u8; 20]), 0);
+    }
 
-        // Jump 100 windows into the future
-        let windows_passed = 100;
-        let new_time = 1000 + (windows_passed * state.config.window_duration_seconds as i64);
-        let base_fee_after_all_empty_windows = state.refresh_base_fee(new_time);
+    #[test]
+    fn test_outflow_breaker_record_over_sliding_window() {
+        let token = Pubkey::new_unique();
+        let mut breaker = OutflowBreaker::default();
+        breaker
+            .set(
+                100,
+                &[OutflowThreshold {
+                    local_token: token,
+                    threshold: 1_000,
+                }],
+            )
+            .unwrap();
+
+        assert_eq!(breaker.record(&token, 800, 1_000), None);
+        assert_eq!(breaker.record(&token, 300, 1_050), Some(1_100));
+        // Untracked tokens are not limited
+        assert_eq!(breaker.record(&Pubkey::new_unique(), 5_000, 1_050), None);
+
+        // Half of the previous window is still covered by the sliding window
+        assert_eq!(breaker.record(&token, 500, 1_150), None);
+        assert_eq!(breaker.record(&token, 200, 1_150), Some(1_100));
+
+        // Outflows older than two windows are forgotten
+        assert_eq!(breaker.record(&token, 1_000, 1_300), None);
+
+        breaker.reset();
+        assert_eq!(breaker.record(&token, 1_000, 1_300), None);
+    }
 
-        // Base fee should decrease, gas usage should reset, window should restart
-        assert!(base_fee_after_all_empty_windows < base_fee_immediately_after_first_window);
-        assert_eq!(state.current_window_gas_used, 0);
-        assert_eq!(state.window_start_time, new_time);
+    #[test]
+    fn test_outbox_root_matches_mmr_proofs() {
+        let mut outbox = Outbox::default();
+        assert_eq!(outbox.root(), [0u8; 32]);
+
+        let leaves: Vec<[u8; 32]> = (0..=20u8).map(|i| [i + 1; 32]).collect();
+        for (i, leaf) in leaves.iter().enumerate() {
+            assert_eq!(outbox.append(*leaf).unwrap(), i as u64);
+
+            let appended = &leaves[..=i];
+            for (leaf_index, leaf) in appended.iter().enumerate() {
+                let (root, proof) = build_proof(appended, leaf_index);
+                assert_eq!(outbox.root(), root);
+                verify_proof(
+                    &root,
+                    leaf,
+                    &(leaf_index as u64),
+                    &proof,
+                    appended.len() as u64,
+                )
+                .unwrap();
+            }
+        }
     }
 }
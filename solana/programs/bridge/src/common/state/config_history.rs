@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+
+use crate::common::CONFIG_HISTORY_LEN;
+
+/// Identifies the configuration parameter updated by a `ConfigChange`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ConfigParam {
+    Guardian,
+    Paused,
+    MinimumBaseFee,
+    WindowDuration,
+    GasTarget,
+    MaxBaseFee,
+    MaxWindowIncreaseBps,
+    AdjustmentDenominator,
+    GasCostScaler,
+    GasCostScalerDp,
+    GasFeeReceiver,
+    GasPerCall,
+    BlockIntervalRequirement,
+    MaxCallBufferSize,
+    CallBufferTtl,
+    ExpireBountyBps,
+    BaseOracleConfig,
+    PartnerOracleConfig,
+    OracleFailoverConfig,
+    OracleEmergency,
+    AddressBook,
+    ProtocolFeeConfig,
+    RootFreshnessConfig,
+    FeeConfig,
+    MintPolicy,
+    EmergencyExit,
+    OutflowBreaker,
+    FinalizationDelay,
+    VaultDecimals,
+    BaseGovernance,
+    InsuranceShare,
+    RelayDenyList,
+    MaintenanceBudget,
+    MaxLamportAllowance,
+    GuardedLaunch,
+    MintAllowList,
+    TransferGasLane,
+    WrappedTokenConfirmation,
+    DexRouterList,
+    FeeQuoteGracePeriod,
+    PayerRateLimit,
+    RateLimitExemptPayers,
+}
+
+/// A configuration change recorded in the `ConfigHistory`.
+#[zero_copy]
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConfigChange {
+    /// Slot at which the change was made
+    pub slot: u64,
+    /// The guardian or upgrade authority that made the change
+    pub authority: Pubkey,
+    /// Value before the change (see `ConfigHistory::encode_value`)
+    pub old_value: [u8; 32],
+    /// Value after the change (see `ConfigHistory::encode_value`)
+    pub new_value: [u8; 32],
+    /// The updated parameter (a `ConfigParam`)
+    pub param: u8,
+    pub _padding: [u8; 7],
+}
+
+/// Append-only ring buffer of the last `CONFIG_HISTORY_LEN` configuration changes, letting
+/// auditors reconstruct every change without relying on RPC log retention. Accessed zero-copy so
+/// that recording a change has a small fixed cost.
+#[account(zero_copy)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct ConfigHistory {
+    /// Total number of recorded changes. The latest change is at `(count - 1) % CONFIG_HISTORY_LEN`.
+    pub count: u64,
+    /// The recorded changes, overwritten oldest first once full
+    pub changes: [ConfigChange; CONFIG_HISTORY_LEN as usize],
+}
+
+impl ConfigHistory {
+    /// Appends a change, overwriting the oldest one once the buffer is full.
+    pub fn record(
+        &mut self,
+        param: ConfigParam,
+        old_value: [u8; 32],
+        new_value: [u8; 32],
+        authority: Pubkey,
+        slot: u64,
+    ) {
+        self.changes[(self.count % CONFIG_HISTORY_LEN) as usize] = ConfigChange {
+            slot,
+            authority,
+            old_value,
+            new_value,
+            param: param as u8,
+            _padding: [0; 7],
+        };
+        self.count += 1;
+    }
+
+    /// Encodes a configuration value: its Borsh serialization zero-padded to 32 bytes when it
+    /// fits (integers, booleans, pubkeys), or the keccak256 of the serialization otherwise.
+    pub fn encode_value<T: AnchorSerialize>(value: &T) -> Result<[u8; 32]> {
+        let data = value.try_to_vec()?;
+        if data.len() > 32 {
+            return Ok(keccak::hash(&data).to_bytes());
+        }
+
+        let mut encoded = [0u8; 32];
+        encoded[..data.len()].copy_from_slice(&data);
+        Ok(encoded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_wraps_around() {
+        let mut history = ConfigHistory {
+            count: 0,
+            changes: [ConfigChange {
+                slot: 0,
+                authority: Pubkey::default(),
+                old_value: [0; 32],
+                new_value: [0; 32],
+                param: 0,
+                _padding: [0; 7],
+            }; CONFIG_HISTORY_LEN as usize],
+        };
+        let authority = Pubkey::new_unique();
+
+        for i in 0..CONFIG_HISTORY_LEN + 2 {
+            history.record(
+                ConfigParam::GasPerCall,
+                ConfigHistory::encode_value(&i).unwrap(),
+                ConfigHistory::encode_value(&(i + 1)).unwrap(),
+                authority,
+                i,
+            );
+        }
+
+        assert_eq!(history.count, CONFIG_HISTORY_LEN + 2);
+        // The two oldest changes were overwritten
+        assert_eq!(history.changes[0].slot, CONFIG_HISTORY_LEN);
+        assert_eq!(history.changes[1].slot, CONFIG_HISTORY_LEN + 1);
+        assert_eq!(history.changes[2].slot, 2);
+        assert_eq!(history.changes[1].param, ConfigParam::GasPerCall as u8);
+    }
+
+    #[test]
+    fn test_encode_value() {
+        let mut expected = [0u8; 32];
+        expected[..8].copy_from_slice(&42u64.to_le_bytes());
+        assert_eq!(ConfigHistory::encode_value(&42u64).unwrap(), expected);
+
+        let pubkey = Pubkey::new_unique();
+        assert_eq!(
+            ConfigHistory::encode_value(&pubkey).unwrap(),
+            pubkey.to_bytes()
+        );
+
+        let large = [7u8; 40];
+        assert_eq!(
+            ConfigHistory::encode_value(&large).unwrap(),
+            keccak::hash(&large).to_bytes()
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{common::MAX_DEX_ROUTERS, BridgeError, ID};
+
+/// Guardian-maintained list of the Base DEX routers whose calls must carry a `SwapGuard`, so that
+/// bridge-and-swap transfers cannot be sent without a minimum output and a deadline. Calls are not
+/// checked while the list is uninitialized.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct DexRouterList {
+    /// Number of routers in `routers` array
+    pub router_count: u8,
+    /// Router addresses on Base
+    pub routers: [[u8; 20]; MAX_DEX_ROUTERS as usize],
+}
+
+impl DexRouterList {
+    /// Registers `router`. Registering a registered router is a no-op.
+    pub fn register(&mut self, router: [u8; 20]) -> Result<()> {
+        if self.contains(&router) {
+            return Ok(());
+        }
+        require!(
+            self.router_count < MAX_DEX_ROUTERS,
+            BridgeError::TooManyDexRouters
+        );
+
+        self.routers[self.router_count as usize] = router;
+        self.router_count += 1;
+
+        Ok(())
+    }
+
+    /// Unregisters `router`. Unregistering a router that is not registered is a no-op.
+    pub fn unregister(&mut self, router: &[u8; 20]) {
+        let len = self.router_count as usize;
+        let Some(position) = self.routers[..len].iter().position(|r| r == router) else {
+            return;
+        };
+
+        self.routers.copy_within(position + 1..len, position);
+        self.routers[len - 1] = [0u8; 20];
+        self.router_count -= 1;
+    }
+
+    /// Loads the DEX router list, returning `None` if it is not initialized.
+    pub fn load(dex_router_list: &AccountInfo) -> Result<Option<Self>> {
+        if dex_router_list.owner != &ID || dex_router_list.data_is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self::try_deserialize(
+            &mut &dex_router_list.try_borrow_data()?[..],
+        )?))
+    }
+
+    /// Returns whether `router` is registered.
+    pub fn contains(&self, router: &[u8; 20]) -> bool {
+        self.routers[..self.router_count as usize].contains(router)
+    }
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Emergency exit enabled by the guardian when the bridge is sunset or catastrophically paused.
+/// Once `withdrawable_at` is reached, and while the bridge stays paused, depositors can withdraw
+/// the SOL and SPL tokens locked by their outgoing messages not relayed to Base, without the
+/// Base side. The timelock leaves time to confirm every message relayed before the pause.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct EmergencyExit {
+    /// Unix timestamp at which the guardian enabled the emergency exit
+    pub enabled_at: i64,
+    /// Unix timestamp from which depositors can withdraw
+    pub withdrawable_at: i64,
+}
+
+impl EmergencyExit {
+    /// Returns whether depositors can withdraw at `now`.
+    pub fn is_withdrawable(&self, now: i64) -> bool {
+        now >= self.withdrawable_at
+    }
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::{common::MAX_INSURANCE_SHARE_BPS, BridgeError, ID};
+
+/// Bridge-held fund covering losses of bridge users, credited with `share_bps` of every gas fee
+/// charged on outgoing messages. The lamports above its rent-exempt minimum can be paid out by the
+/// guardian after `INSURANCE_PAYOUT_TIMELOCK_SECONDS`. No share is taken until this account is
+/// initialized.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct InsuranceFund {
+    /// Share of the gas fees credited to the fund, in basis points
+    pub share_bps: u16,
+    /// Total lamports credited from gas fees
+    pub total_accrued: u64,
+    /// Total lamports paid out
+    pub total_paid: u64,
+    /// Payout requested by the guardian, executable once its timelock elapsed
+    pub pending_payout: Option<InsurancePayout>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize)]
+pub struct InsurancePayout {
+    /// The account receiving the payout
+    pub recipient: Pubkey,
+    /// Lamports paid out
+    pub amount: u64,
+    /// Unix timestamp from which the payout can be executed
+    pub executable_at: i64,
+}
+
+impl InsuranceFund {
+    /// Sets the share of the gas fees credited to the fund.
+    pub fn set_share_bps(&mut self, share_bps: u16) -> Result<()> {
+        require!(
+            share_bps <= MAX_INSURANCE_SHARE_BPS,
+            BridgeError::InvalidInsuranceShare
+        );
+        self.share_bps = share_bps;
+        Ok(())
+    }
+
+    /// Returns the share of `gas_cost` credited to the fund.
+    pub fn share_of(&self, gas_cost: u64) -> u64 {
+        (gas_cost as u128 * self.share_bps as u128 / 10_000) as u64
+    }
+
+    /// Loads the insurance fund, returning `None` if it is not initialized.
+    pub fn load(insurance_fund: &AccountInfo) -> Result<Option<Self>> {
+        if insurance_fund.owner != &ID || insurance_fund.data_is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self::try_deserialize(
+            &mut &insurance_fund.try_borrow_data()?[..],
+        )?))
+    }
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+
+/// Oracle-posted commitment to the outstanding supply on Base of the tokens locked in the bridge
+/// vaults, i.e. the liabilities the vaults must cover.
+///
+/// `root` is the root of a Merkle tree whose leaves are `keccak256(local_token || liability_be)`
+/// (see `LiabilityRoot::leaf_hash`), `liability` being expressed in the vault's local units.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct LiabilityRoot {
+    /// Merkle root of the per-token liabilities
+    pub root: [u8; 32],
+    /// The Base block number at which the liabilities were measured
+    pub base_block_number: u64,
+    /// Set by `reconcile_vault` when a vault holds less than its proven liability. Monitoring
+    /// alarms on it until the guardian clears it.
+    pub solvency_alert: bool,
+}
+
+impl LiabilityRoot {
+    /// Returns the Merkle leaf committing to the `liability` of `local_token`.
+    pub fn leaf_hash(local_token: &Pubkey, liability: u64) -> [u8; 32] {
+        keccak::hashv(&[local_token.as_ref(), &liability.to_be_bytes()]).to_bytes()
+    }
+}
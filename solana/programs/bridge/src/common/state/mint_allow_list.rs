@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{common::MAX_ALLOWED_MINTS, BridgeError, ID};
+
+/// Guardian-maintained list of the SPL mints accepted by `bridge_spl` while the bridge is in
+/// guarded launch (see `Bridge::guarded_launch`). It is ignored once guarded launch is turned off,
+/// so the bridge can be opened to every mint without a program upgrade.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct MintAllowList {
+    /// Number of mints in `mints` array
+    pub mint_count: u8,
+    /// Mints accepted by `bridge_spl` during guarded launch
+    pub mints: [Pubkey; MAX_ALLOWED_MINTS as usize],
+}
+
+impl MintAllowList {
+    /// Adds `mint` to the allow-list. Adding an allow-listed mint is a no-op.
+    pub fn allow(&mut self, mint: Pubkey) -> Result<()> {
+        if self.allows(&mint) {
+            return Ok(());
+        }
+        require!(
+            self.mint_count < MAX_ALLOWED_MINTS,
+            BridgeError::TooManyAllowedMints
+        );
+
+        self.mints[self.mint_count as usize] = mint;
+        self.mint_count += 1;
+
+        Ok(())
+    }
+
+    /// Removes `mint` from the allow-list. Removing a mint that is not allow-listed is a no-op.
+    pub fn disallow(&mut self, mint: &Pubkey) {
+        let len = self.mint_count as usize;
+        let Some(position) = self.mints[..len].iter().position(|m| m == mint) else {
+            return;
+        };
+
+        self.mints.copy_within(position + 1..len, position);
+        self.mints[len - 1] = Pubkey::default();
+        self.mint_count -= 1;
+    }
+
+    /// Loads the mint allow-list, returning `None` if it is not initialized.
+    pub fn load(mint_allow_list: &AccountInfo) -> Result<Option<Self>> {
+        if mint_allow_list.owner != &ID || mint_allow_list.data_is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self::try_deserialize(
+            &mut &mint_allow_list.try_borrow_data()?[..],
+        )?))
+    }
+
+    /// Returns whether `mint` is allow-listed.
+    pub fn allows(&self, mint: &Pubkey) -> bool {
+        self.mints[..self.mint_count as usize].contains(mint)
+    }
+}
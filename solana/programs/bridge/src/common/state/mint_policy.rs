@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{common::MAX_ALLOWED_FREEZE_AUTHORITIES, BridgeError, ID};
+
+/// Guardian-maintained policy restricting the SPL mints accepted by `bridge_spl`. A mint with a
+/// hostile freeze authority could freeze the token vault after tokens are locked, stranding the
+/// bridged users. No freeze authority policy is enforced until this account is initialized.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct MintPolicy {
+    /// Whether mints with a freeze authority are rejected, unless it is allow-listed
+    pub reject_freeze_authority: bool,
+    /// Number of authorities in `allowed_freeze_authorities` array
+    pub allowed_freeze_authority_count: u8,
+    /// Freeze authorities accepted while `reject_freeze_authority` is set
+    pub allowed_freeze_authorities: [Pubkey; MAX_ALLOWED_FREEZE_AUTHORITIES as usize],
+}
+
+impl MintPolicy {
+    /// Replaces the mint policy, validating the allow-list.
+    pub fn set(
+        &mut self,
+        reject_freeze_authority: bool,
+        allowed_freeze_authorities: &[Pubkey],
+    ) -> Result<()> {
+        require!(
+            allowed_freeze_authorities.len() <= MAX_ALLOWED_FREEZE_AUTHORITIES as usize,
+            BridgeError::TooManyAllowedFreezeAuthorities
+        );
+
+        self.reject_freeze_authority = reject_freeze_authority;
+        self.allowed_freeze_authority_count = allowed_freeze_authorities.len() as u8;
+        self.allowed_freeze_authorities = Default::default();
+        self.allowed_freeze_authorities[..allowed_freeze_authorities.len()]
+            .copy_from_slice(allowed_freeze_authorities);
+
+        Ok(())
+    }
+
+    /// Loads the mint policy, returning `None` if it is not initialized.
+    pub fn load(mint_policy: &AccountInfo) -> Result<Option<Self>> {
+        if mint_policy.owner != &ID || mint_policy.data_is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self::try_deserialize(
+            &mut &mint_policy.try_borrow_data()?[..],
+        )?))
+    }
+
+    /// Returns whether `freeze_authority` is accepted by this policy.
+    pub fn allows_freeze_authority(&self, freeze_authority: Option<&Pubkey>) -> bool {
+        match freeze_authority {
+            None => true,
+            Some(_) if !self.reject_freeze_authority => true,
+            Some(authority) => self.allowed_freeze_authorities
+                [..self.allowed_freeze_authority_count as usize]
+                .contains(authority),
+        }
+    }
+}
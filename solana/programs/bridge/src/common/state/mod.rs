@@ -1,3 +1,33 @@
+pub mod address_book;
 pub mod bridge;
+pub mod config_history;
+pub mod dex_router_list;
+pub mod emergency_exit;
+pub mod insurance_fund;
+pub mod liability_root;
+pub mod mint_allow_list;
+pub mod mint_policy;
+pub mod payer_rate_limit_config;
+pub mod protocol_fee_config;
+pub mod sol_vault_state;
+pub mod token_deposits;
+pub mod vault_decimals;
+pub mod wrapped_token_confirmation;
+pub mod wrapped_token_supply_cap;
 
+pub use address_book::*;
 pub use bridge::*;
+pub use config_history::*;
+pub use dex_router_list::*;
+pub use emergency_exit::*;
+pub use insurance_fund::*;
+pub use liability_root::*;
+pub use mint_allow_list::*;
+pub use mint_policy::*;
+pub use payer_rate_limit_config::*;
+pub use protocol_fee_config::*;
+pub use sol_vault_state::*;
+pub use token_deposits::*;
+pub use vault_decimals::*;
+pub use wrapped_token_confirmation::*;
+pub use wrapped_token_supply_cap::*;
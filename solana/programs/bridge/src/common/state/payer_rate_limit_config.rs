@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::{common::MAX_RATE_LIMIT_EXEMPT_PAYERS, BridgeError, ID};
+
+/// Guardian-maintained limits on the outgoing messages each payer can pay for per window, so that
+/// a single spammer cannot flood the outbox and drive up the base fee for everyone. Payers are not
+/// limited while the config is uninitialized or its window is zero.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct PayerRateLimitConfig {
+    /// Duration of a rate limiting window, in seconds (0 disables rate limiting)
+    pub window_seconds: u64,
+    /// Maximum number of outgoing messages a payer can pay for per window (0 for no limit)
+    pub max_messages: u64,
+    /// Maximum gas a payer can pay for per window (0 for no limit)
+    pub max_gas: u64,
+    /// Number of payers in `exempt_payers` array
+    pub exempt_payer_count: u8,
+    /// Payers exempted from the limits, e.g. integrators sending messages on behalf of their users
+    pub exempt_payers: [Pubkey; MAX_RATE_LIMIT_EXEMPT_PAYERS as usize],
+}
+
+impl PayerRateLimitConfig {
+    /// Exempts `payer` from the limits. Exempting an exempt payer is a no-op.
+    pub fn exempt(&mut self, payer: Pubkey) -> Result<()> {
+        if self.is_exempt(&payer) {
+            return Ok(());
+        }
+        require!(
+            self.exempt_payer_count < MAX_RATE_LIMIT_EXEMPT_PAYERS,
+            BridgeError::TooManyRateLimitExemptPayers
+        );
+
+        self.exempt_payers[self.exempt_payer_count as usize] = payer;
+        self.exempt_payer_count += 1;
+
+        Ok(())
+    }
+
+    /// Subjects `payer` to the limits again. Unexempting a payer that is not exempt is a no-op.
+    pub fn unexempt(&mut self, payer: &Pubkey) {
+        let len = self.exempt_payer_count as usize;
+        let Some(position) = self.exempt_payers[..len].iter().position(|p| p == payer) else {
+            return;
+        };
+
+        self.exempt_payers.copy_within(position + 1..len, position);
+        self.exempt_payers[len - 1] = Pubkey::default();
+        self.exempt_payer_count -= 1;
+    }
+
+    /// Loads the payer rate limit config, returning `None` if it is not initialized.
+    pub fn load(payer_rate_limit_config: &AccountInfo) -> Result<Option<Self>> {
+        if payer_rate_limit_config.owner != &ID || payer_rate_limit_config.data_is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self::try_deserialize(
+            &mut &payer_rate_limit_config.try_borrow_data()?[..],
+        )?))
+    }
+
+    /// Returns whether `payer` is subject to the limits.
+    pub fn limits(&self, payer: &Pubkey) -> bool {
+        self.window_seconds > 0 && !self.is_exempt(payer)
+    }
+
+    /// Returns whether `payer` is exempt from the limits.
+    pub fn is_exempt(&self, payer: &Pubkey) -> bool {
+        self.exempt_payers[..self.exempt_payer_count as usize].contains(payer)
+    }
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{MAX_PROTOCOL_FEE_BPS, MAX_PROTOCOL_FEE_OVERRIDES},
+    BridgeError, ID,
+};
+
+/// Guardian-maintained protocol fee skimmed from bridged token amounts (SOL, SPL and wrapped
+/// tokens) into the treasury. Protocol fees are disabled until this account is initialized.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct ProtocolFeeConfig {
+    /// Treasury collecting protocol fees: receives SOL fees directly and token fees in its
+    /// token accounts.
+    pub treasury: Pubkey,
+    /// Protocol fee in basis points applied to tokens without an override
+    pub default_bps: u16,
+    /// Number of overrides in `overrides` array
+    pub override_count: u8,
+    /// Per-token protocol fees taking precedence over `default_bps`
+    pub overrides: [ProtocolFeeOverride; MAX_PROTOCOL_FEE_OVERRIDES as usize],
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, InitSpace, AnchorSerialize, AnchorDeserialize,
+)]
+pub struct ProtocolFeeOverride {
+    /// The token on Solana (a mint, or `NATIVE_SOL_PUBKEY` for SOL)
+    pub local_token: Pubkey,
+    /// Protocol fee in basis points for this token
+    pub bps: u16,
+}
+
+impl ProtocolFeeConfig {
+    /// Replaces the protocol fee configuration, validating the fees and the override list.
+    pub fn set(
+        &mut self,
+        treasury: Pubkey,
+        default_bps: u16,
+        overrides: &[ProtocolFeeOverride],
+    ) -> Result<()> {
+        require!(
+            overrides.len() <= MAX_PROTOCOL_FEE_OVERRIDES as usize,
+            BridgeError::TooManyProtocolFeeOverrides
+        );
+        require!(
+            default_bps <= MAX_PROTOCOL_FEE_BPS
+                && overrides.iter().all(|o| o.bps <= MAX_PROTOCOL_FEE_BPS),
+            BridgeError::InvalidProtocolFeeBps
+        );
+
+        let mut local_tokens: Vec<Pubkey> = overrides.iter().map(|o| o.local_token).collect();
+        local_tokens.sort();
+        local_tokens.dedup();
+        require!(
+            local_tokens.len() == overrides.len(),
+            BridgeError::DuplicateProtocolFeeOverride
+        );
+
+        self.treasury = treasury;
+        self.default_bps = default_bps;
+        self.override_count = overrides.len() as u8;
+        self.overrides = Default::default();
+        self.overrides[..overrides.len()].copy_from_slice(overrides);
+
+        Ok(())
+    }
+
+    /// Loads the protocol fee configuration, returning `None` if it is not initialized.
+    pub fn load(protocol_fee_config: &AccountInfo) -> Result<Option<Self>> {
+        if protocol_fee_config.owner != &ID || protocol_fee_config.data_is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Self::try_deserialize(
+            &mut &protocol_fee_config.try_borrow_data()?[..],
+        )?))
+    }
+
+    /// Returns the protocol fee in basis points for `local_token`.
+    pub fn bps(&self, local_token: &Pubkey) -> u16 {
+        self.overrides[..self.override_count as usize]
+            .iter()
+            .find(|o| o.local_token == *local_token)
+            .map_or(self.default_bps, |o| o.bps)
+    }
+
+    /// Returns the protocol fee charged on bridging `amount` of `local_token`.
+    pub fn fee(&self, local_token: &Pubkey, amount: u64) -> u64 {
+        (amount as u128 * self.bps(local_token) as u128 / 10_000) as u64
+    }
+}
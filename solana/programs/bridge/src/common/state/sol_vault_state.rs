@@ -0,0 +1,11 @@
+use anchor_lang::prelude::*;
+
+/// State of the SOL vault, created once by `init_sol_vault`.
+/// Bridging instructions require it so the vault is always rent-exempt and derived from the
+/// recorded bump rather than searching for it.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct SolVaultState {
+    /// Bump of the SOL vault PDA (`[SOL_VAULT_SEED]`).
+    pub bump: u8,
+}
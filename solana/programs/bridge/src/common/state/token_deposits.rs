@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+/// Accounting for the amount locked in a bridge vault (the SOL vault or an SPL token vault).
+/// Incremented when tokens are locked for Base and decremented when they are released back
+/// to Solana, so that it can be compared against the actual vault balance by `audit_vault`.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct TokenDeposits {
+    /// The vault this accounting is tracking.
+    pub vault: Pubkey,
+    /// Amount the vault is expected to hold (lamports for the SOL vault, base units otherwise).
+    pub locked_amount: u64,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::{common::VAULT_DECIMALS_SEED, BridgeError, ID};
+
+/// Decimals of the Base token an SPL token vault is bridged to, when lower than the mint
+/// decimals. Amounts leaving the vault are carried in the remote token's smallest unit, i.e.
+/// divided by `10^(local_decimals - remote_decimals)`. Amounts are not scaled until this account
+/// is initialized.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct VaultDecimals {
+    /// Decimals of the SPL mint locked in the vault.
+    pub local_decimals: u8,
+    /// Decimals in which the amounts are carried to Base.
+    pub remote_decimals: u8,
+}
+
+impl VaultDecimals {
+    /// Returns the address of the decimals account of `vault`.
+    pub fn pda(vault: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[VAULT_DECIMALS_SEED, vault.as_ref()], &ID).0
+    }
+
+    /// Returns the exponent of the scalar between the local and the remote amounts.
+    pub fn scaler_exponent(&self) -> u8 {
+        self.local_decimals - self.remote_decimals
+    }
+
+    /// Loads the scaler exponent of `vault`, returning 0 (no scaling) if the decimals account is
+    /// not initialized.
+    pub fn load_scaler_exponent(vault_decimals: &AccountInfo, vault: &Pubkey) -> Result<u8> {
+        require_keys_eq!(
+            vault_decimals.key(),
+            Self::pda(vault),
+            BridgeError::IncorrectVaultDecimalsAccount
+        );
+
+        if vault_decimals.owner != &ID || vault_decimals.data_is_empty() {
+            return Ok(0);
+        }
+
+        Ok(Self::try_deserialize(&mut &vault_decimals.try_borrow_data()?[..])?.scaler_exponent())
+    }
+}
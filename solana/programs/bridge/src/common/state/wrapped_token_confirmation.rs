@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{common::WRAPPED_TOKEN_CONFIRMATION_SEED, BridgeError, ID};
+
+/// Marker recording that the Base Bridge accepted the registration of a wrapped token sent by
+/// `wrap_token`. Created when a `ConfirmWrappedToken` message from the Base Bridge, echoing the
+/// name, symbol and decimals of the Base token, is relayed.
+///
+/// A wrapped mint is `initialized` by `wrap_token` and becomes `active` once confirmed. While
+/// confirmations are required, only active mints can be bridged back to Base.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct WrappedTokenConfirmation {
+    /// The confirmed wrapped token mint.
+    pub mint: Pubkey,
+    /// Unix timestamp at which the confirmation was relayed.
+    pub confirmed_at: i64,
+}
+
+impl WrappedTokenConfirmation {
+    /// Returns the address of the confirmation account of `mint`.
+    pub fn pda(mint: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[WRAPPED_TOKEN_CONFIRMATION_SEED, mint.as_ref()], &ID).0
+    }
+
+    /// Returns whether the registration of `mint` was confirmed, i.e. whether its confirmation
+    /// account is initialized.
+    pub fn is_confirmed(confirmation: &AccountInfo, mint: &Pubkey) -> Result<bool> {
+        require_keys_eq!(
+            confirmation.key(),
+            Self::pda(mint),
+            BridgeError::IncorrectWrappedTokenConfirmation
+        );
+
+        Ok(confirmation.owner == &ID && !confirmation.data_is_empty())
+    }
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{common::WRAPPED_TOKEN_SUPPLY_CAP_SEED, BridgeError, ID};
+
+/// Maximum supply of a wrapped token mirrored from Base, set through a message relayed from the
+/// Base Bridge. Mints of a wrapped token are uncapped until this account is initialized.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct WrappedTokenSupplyCap {
+    /// Maximum supply of the wrapped token, in its local units. `None` lifts the cap.
+    pub max_supply: Option<u64>,
+}
+
+impl WrappedTokenSupplyCap {
+    /// Returns the address of the supply cap account of `mint`.
+    pub fn pda(mint: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[WRAPPED_TOKEN_SUPPLY_CAP_SEED, mint.as_ref()], &ID).0
+    }
+
+    /// Loads the max supply of `mint`, returning `None` if the supply cap account is not
+    /// initialized.
+    pub fn load(supply_cap: &AccountInfo, mint: &Pubkey) -> Result<Option<u64>> {
+        require_keys_eq!(
+            supply_cap.key(),
+            Self::pda(mint),
+            BridgeError::IncorrectWrappedSupplyCapAccount
+        );
+
+        if supply_cap.owner != &ID || supply_cap.data_is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Self::try_deserialize(&mut &supply_cap.try_borrow_data()?[..])?.max_supply)
+    }
+}
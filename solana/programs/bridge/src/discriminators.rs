@@ -0,0 +1,327 @@
+//! Discriminators of the accounts declared by the program, for indexers and SDKs decoding raw
+//! account data.
+//!
+//! The constants are a stable public API: they never change, even if an account type is renamed.
+//! Anchor derives discriminators from type names, so a renamed account must keep its previous
+//! discriminator through `#[account(discriminator = ..)]`. The tests of this module fail otherwise.
+
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::{
+        EquivocationEvidence, HandlerRegistration, IncomingMessage, MaintenanceBudget,
+        OperationalVault, OutputRoot, ProveBuffer, ProvenChunk, RelayDenyList, RelayFeeVault,
+        ReturnedTransfer, Signers,
+    },
+    common::{
+        AddressBook, Bridge, ConfigHistory, DexRouterList, EmergencyExit, InsuranceFund,
+        LiabilityRoot, MintAllowList, MintPolicy, PayerRateLimitConfig, ProtocolFeeConfig,
+        SolVaultState, TokenDeposits, VaultDecimals, WrappedTokenConfirmation,
+        WrappedTokenSupplyCap, DISCRIMINATOR_LEN,
+    },
+    solana_to_base::{
+        BridgeSession, BurnReceipt, CallBuffer, OutgoingMessage, PayerRateLimit, RelayExecution,
+        RelayedNonces,
+    },
+};
+#[cfg(not(target_os = "solana"))]
+use crate::{
+    common::{
+        migration::{bridge_layout, detect_bridge_version, migrate_bridge_data},
+        BRIDGE_LAYOUT_VERSION,
+    },
+    BridgeError,
+};
+
+macro_rules! discriminators {
+    ($($constant:ident = $value:expr => $account:ident,)*) => {
+        $(
+            #[doc = concat!("Discriminator of `", stringify!($account), "` accounts.")]
+            pub const $constant: [u8; DISCRIMINATOR_LEN] = $value;
+        )*
+
+        /// The name and discriminator of every account type declared by the program.
+        pub const ALL: &[(&str, [u8; DISCRIMINATOR_LEN])] = &[
+            $((stringify!($account), $constant),)*
+        ];
+
+        /// The discriminators Anchor derives for the account types, in the order of `ALL`.
+        #[cfg(test)]
+        fn anchor_discriminators() -> Vec<&'static [u8]> {
+            vec![$(<$account as Discriminator>::DISCRIMINATOR,)*]
+        }
+    };
+}
+
+discriminators! {
+    ADDRESS_BOOK = [112, 200, 175, 39, 249, 237, 44, 141] => AddressBook,
+    BRIDGE = [231, 232, 31, 98, 110, 3, 23, 59] => Bridge,
+    BRIDGE_SESSION = [235, 118, 90, 227, 230, 173, 141, 184] => BridgeSession,
+    BURN_RECEIPT = [209, 39, 231, 253, 164, 70, 105, 174] => BurnReceipt,
+    CALL_BUFFER = [134, 143, 168, 251, 163, 216, 180, 113] => CallBuffer,
+    CONFIG_HISTORY = [228, 148, 11, 248, 20, 187, 94, 127] => ConfigHistory,
+    DEX_ROUTER_LIST = [67, 61, 221, 98, 51, 77, 110, 141] => DexRouterList,
+    EMERGENCY_EXIT = [87, 180, 23, 110, 235, 1, 235, 126] => EmergencyExit,
+    EQUIVOCATION_EVIDENCE = [70, 115, 183, 155, 104, 245, 215, 118] => EquivocationEvidence,
+    HANDLER_REGISTRATION = [244, 68, 84, 81, 139, 64, 75, 70] => HandlerRegistration,
+    INCOMING_MESSAGE = [30, 144, 125, 111, 211, 223, 91, 170] => IncomingMessage,
+    INSURANCE_FUND = [43, 134, 170, 87, 102, 16, 142, 147] => InsuranceFund,
+    LIABILITY_ROOT = [136, 64, 12, 73, 131, 121, 226, 74] => LiabilityRoot,
+    MAINTENANCE_BUDGET = [10, 93, 117, 190, 152, 237, 50, 134] => MaintenanceBudget,
+    MINT_ALLOW_LIST = [248, 183, 35, 91, 119, 253, 109, 22] => MintAllowList,
+    MINT_POLICY = [191, 78, 230, 47, 121, 49, 66, 222] => MintPolicy,
+    OPERATIONAL_VAULT = [192, 181, 243, 150, 176, 228, 45, 226] => OperationalVault,
+    OUTGOING_MESSAGE = [150, 255, 197, 226, 200, 215, 31, 29] => OutgoingMessage,
+    OUTPUT_ROOT = [11, 31, 168, 201, 229, 8, 180, 198] => OutputRoot,
+    PAYER_RATE_LIMIT = [92, 192, 189, 53, 254, 82, 49, 62] => PayerRateLimit,
+    PAYER_RATE_LIMIT_CONFIG = [56, 1, 177, 164, 58, 43, 214, 135] => PayerRateLimitConfig,
+    PROTOCOL_FEE_CONFIG = [81, 109, 12, 19, 140, 182, 21, 238] => ProtocolFeeConfig,
+    PROVE_BUFFER = [78, 160, 227, 163, 97, 140, 40, 149] => ProveBuffer,
+    PROVEN_CHUNK = [93, 25, 11, 219, 21, 164, 145, 223] => ProvenChunk,
+    RELAY_DENY_LIST = [75, 12, 144, 243, 127, 216, 45, 173] => RelayDenyList,
+    RELAY_EXECUTION = [164, 61, 70, 13, 77, 65, 148, 150] => RelayExecution,
+    RELAY_FEE_VAULT = [27, 215, 66, 254, 221, 129, 165, 143] => RelayFeeVault,
+    RELAYED_NONCES = [245, 149, 24, 172, 58, 163, 78, 141] => RelayedNonces,
+    RETURNED_TRANSFER = [243, 207, 182, 175, 153, 226, 153, 17] => ReturnedTransfer,
+    SIGNERS = [252, 201, 210, 52, 28, 254, 51, 99] => Signers,
+    SOL_VAULT_STATE = [73, 192, 113, 251, 77, 219, 213, 95] => SolVaultState,
+    TOKEN_DEPOSITS = [217, 76, 219, 239, 3, 87, 134, 38] => TokenDeposits,
+    VAULT_DECIMALS = [94, 21, 18, 166, 217, 100, 206, 203] => VaultDecimals,
+    WRAPPED_TOKEN_CONFIRMATION = [90, 16, 22, 203, 95, 30, 193, 48] => WrappedTokenConfirmation,
+    WRAPPED_TOKEN_SUPPLY_CAP = [106, 219, 43, 96, 118, 136, 251, 165] => WrappedTokenSupplyCap,
+}
+
+/// Any account declared by the program, as decoded by `try_deserialize_any`.
+#[cfg(not(target_os = "solana"))]
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug)]
+pub enum BridgeAccount {
+    AddressBook(AddressBook),
+    Bridge(Box<Bridge>),
+    BridgeSession(BridgeSession),
+    BurnReceipt(BurnReceipt),
+    CallBuffer(CallBuffer),
+    ConfigHistory(Box<ConfigHistory>),
+    DexRouterList(DexRouterList),
+    EmergencyExit(EmergencyExit),
+    EquivocationEvidence(EquivocationEvidence),
+    HandlerRegistration(HandlerRegistration),
+    IncomingMessage(IncomingMessage),
+    InsuranceFund(InsuranceFund),
+    LiabilityRoot(LiabilityRoot),
+    MaintenanceBudget(MaintenanceBudget),
+    MintAllowList(MintAllowList),
+    MintPolicy(MintPolicy),
+    OperationalVault(OperationalVault),
+    OutgoingMessage(OutgoingMessage),
+    OutputRoot(OutputRoot),
+    PayerRateLimit(PayerRateLimit),
+    PayerRateLimitConfig(PayerRateLimitConfig),
+    ProtocolFeeConfig(ProtocolFeeConfig),
+    ProveBuffer(ProveBuffer),
+    ProvenChunk(ProvenChunk),
+    RelayDenyList(RelayDenyList),
+    RelayExecution(RelayExecution),
+    RelayFeeVault(RelayFeeVault),
+    RelayedNonces(Box<RelayedNonces>),
+    ReturnedTransfer(ReturnedTransfer),
+    Signers(Signers),
+    SolVaultState(SolVaultState),
+    TokenDeposits(TokenDeposits),
+    VaultDecimals(VaultDecimals),
+    WrappedTokenConfirmation(WrappedTokenConfirmation),
+    WrappedTokenSupplyCap(WrappedTokenSupplyCap),
+}
+
+/// Decodes the data of any account declared by the program, selecting its type from its
+/// discriminator.
+///
+/// `Bridge` accounts in an older layout are migrated in memory to the current one, so indexers
+/// can decode them before `migrate_bridge_state` runs. Off-chain only.
+#[cfg(not(target_os = "solana"))]
+pub fn try_deserialize_any(data: &[u8]) -> Result<BridgeAccount> {
+    let discriminator: [u8; DISCRIMINATOR_LEN] = data
+        .get(..DISCRIMINATOR_LEN)
+        .and_then(|discriminator| discriminator.try_into().ok())
+        .ok_or(ErrorCode::AccountDiscriminatorNotFound)?;
+
+    let mut data = data;
+    let account = match discriminator {
+        ADDRESS_BOOK => BridgeAccount::AddressBook(AddressBook::try_deserialize(&mut data)?),
+        BRIDGE => BridgeAccount::Bridge(Box::new(deserialize_bridge(data)?)),
+        BRIDGE_SESSION => BridgeAccount::BridgeSession(BridgeSession::try_deserialize(&mut data)?),
+        BURN_RECEIPT => BridgeAccount::BurnReceipt(BurnReceipt::try_deserialize(&mut data)?),
+        CALL_BUFFER => BridgeAccount::CallBuffer(CallBuffer::try_deserialize(&mut data)?),
+        CONFIG_HISTORY => BridgeAccount::ConfigHistory(Box::new(read_zero_copy(data)?)),
+        DEX_ROUTER_LIST => BridgeAccount::DexRouterList(DexRouterList::try_deserialize(&mut data)?),
+        EMERGENCY_EXIT => BridgeAccount::EmergencyExit(EmergencyExit::try_deserialize(&mut data)?),
+        EQUIVOCATION_EVIDENCE => {
+            BridgeAccount::EquivocationEvidence(EquivocationEvidence::try_deserialize(&mut data)?)
+        }
+        HANDLER_REGISTRATION => {
+            BridgeAccount::HandlerRegistration(HandlerRegistration::try_deserialize(&mut data)?)
+        }
+        INCOMING_MESSAGE => {
+            BridgeAccount::IncomingMessage(IncomingMessage::try_deserialize(&mut data)?)
+        }
+        INSURANCE_FUND => BridgeAccount::InsuranceFund(InsuranceFund::try_deserialize(&mut data)?),
+        LIABILITY_ROOT => BridgeAccount::LiabilityRoot(LiabilityRoot::try_deserialize(&mut data)?),
+        MAINTENANCE_BUDGET => {
+            BridgeAccount::MaintenanceBudget(MaintenanceBudget::try_deserialize(&mut data)?)
+        }
+        MINT_ALLOW_LIST => BridgeAccount::MintAllowList(MintAllowList::try_deserialize(&mut data)?),
+        MINT_POLICY => BridgeAccount::MintPolicy(MintPolicy::try_deserialize(&mut data)?),
+        OPERATIONAL_VAULT => {
+            BridgeAccount::OperationalVault(OperationalVault::try_deserialize(&mut data)?)
+        }
+        OUTGOING_MESSAGE => {
+            BridgeAccount::OutgoingMessage(OutgoingMessage::try_deserialize(&mut data)?)
+        }
+        OUTPUT_ROOT => BridgeAccount::OutputRoot(OutputRoot::try_deserialize(&mut data)?),
+        PAYER_RATE_LIMIT => {
+            BridgeAccount::PayerRateLimit(PayerRateLimit::try_deserialize(&mut data)?)
+        }
+        PAYER_RATE_LIMIT_CONFIG => {
+            BridgeAccount::PayerRateLimitConfig(PayerRateLimitConfig::try_deserialize(&mut data)?)
+        }
+        PROTOCOL_FEE_CONFIG => {
+            BridgeAccount::ProtocolFeeConfig(ProtocolFeeConfig::try_deserialize(&mut data)?)
+        }
+        PROVE_BUFFER => BridgeAccount::ProveBuffer(ProveBuffer::try_deserialize(&mut data)?),
+        PROVEN_CHUNK => BridgeAccount::ProvenChunk(ProvenChunk::try_deserialize(&mut data)?),
+        RELAY_DENY_LIST => BridgeAccount::RelayDenyList(RelayDenyList::try_deserialize(&mut data)?),
+        RELAY_EXECUTION => {
+            BridgeAccount::RelayExecution(RelayExecution::try_deserialize(&mut data)?)
+        }
+        RELAY_FEE_VAULT => BridgeAccount::RelayFeeVault(RelayFeeVault::try_deserialize(&mut data)?),
+        RELAYED_NONCES => BridgeAccount::RelayedNonces(Box::new(read_zero_copy(data)?)),
+        RETURNED_TRANSFER => {
+            BridgeAccount::ReturnedTransfer(ReturnedTransfer::try_deserialize(&mut data)?)
+        }
+        SIGNERS => BridgeAccount::Signers(Signers::try_deserialize(&mut data)?),
+        SOL_VAULT_STATE => BridgeAccount::SolVaultState(SolVaultState::try_deserialize(&mut data)?),
+        TOKEN_DEPOSITS => BridgeAccount::TokenDeposits(TokenDeposits::try_deserialize(&mut data)?),
+        VAULT_DECIMALS => BridgeAccount::VaultDecimals(VaultDecimals::try_deserialize(&mut data)?),
+        WRAPPED_TOKEN_CONFIRMATION => BridgeAccount::WrappedTokenConfirmation(
+            WrappedTokenConfirmation::try_deserialize(&mut data)?,
+        ),
+        WRAPPED_TOKEN_SUPPLY_CAP => {
+            BridgeAccount::WrappedTokenSupplyCap(WrappedTokenSupplyCap::try_deserialize(&mut data)?)
+        }
+        _ => return err!(ErrorCode::AccountDiscriminatorMismatch),
+    };
+
+    Ok(account)
+}
+
+/// Decodes a `Bridge` account, migrating older layouts to `BRIDGE_LAYOUT_VERSION` first.
+#[cfg(not(target_os = "solana"))]
+fn deserialize_bridge(data: &[u8]) -> Result<Bridge> {
+    let version = detect_bridge_version(&data[DISCRIMINATOR_LEN..])?;
+    if version == BRIDGE_LAYOUT_VERSION {
+        return Bridge::try_deserialize(&mut &data[..]);
+    }
+
+    let current = bridge_layout(BRIDGE_LAYOUT_VERSION).ok_or(BridgeError::InvalidBridgeLayout)?;
+    let mut migrated = vec![0u8; DISCRIMINATOR_LEN + current.space];
+    migrated[..data.len()].copy_from_slice(data);
+    migrate_bridge_data(&mut migrated[DISCRIMINATOR_LEN..], version)?;
+    Bridge::try_deserialize(&mut &migrated[..])
+}
+
+/// Copies a zero-copy account out of its (possibly unaligned) data.
+#[cfg(not(target_os = "solana"))]
+fn read_zero_copy<T: bytemuck::Pod>(data: &[u8]) -> Result<T> {
+    let bytes = data
+        .get(DISCRIMINATOR_LEN..DISCRIMINATOR_LEN + std::mem::size_of::<T>())
+        .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+    bytemuck::try_pod_read_unaligned(bytes).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashSet;
+
+    use crate::{
+        common::migration::BRIDGE_LAYOUTS,
+        test_utils::{setup_bridge, SetupBridgeResult},
+    };
+
+    #[test]
+    fn test_discriminators_match_account_types() {
+        for ((name, discriminator), anchor_discriminator) in ALL.iter().zip(anchor_discriminators())
+        {
+            assert_eq!(
+                discriminator.as_slice(),
+                anchor_discriminator,
+                "Discriminator of {} changed",
+                name
+            );
+        }
+        assert_eq!(ALL.len(), anchor_discriminators().len());
+
+        let unique: HashSet<_> = ALL.iter().map(|(_, discriminator)| discriminator).collect();
+        assert_eq!(unique.len(), ALL.len());
+    }
+
+    #[test]
+    fn test_try_deserialize_any_decodes_accounts() {
+        let deposits = TokenDeposits {
+            vault: Pubkey::new_unique(),
+            locked_amount: 42,
+        };
+        let mut data = Vec::new();
+        deposits.try_serialize(&mut data).unwrap();
+        let BridgeAccount::TokenDeposits(decoded) = try_deserialize_any(&data).unwrap() else {
+            panic!("Expected a TokenDeposits account");
+        };
+        assert_eq!(decoded, deposits);
+
+        let mut relayed_nonces = RelayedNonces {
+            page: 3,
+            bitmap: [0; 1024],
+        };
+        relayed_nonces.mark_relayed(3 * 65_536 + 7);
+        let mut data = RELAYED_NONCES.to_vec();
+        data.extend_from_slice(bytemuck::bytes_of(&relayed_nonces));
+        let BridgeAccount::RelayedNonces(decoded) = try_deserialize_any(&data).unwrap() else {
+            panic!("Expected a RelayedNonces account");
+        };
+        assert_eq!(*decoded, relayed_nonces);
+    }
+
+    #[test]
+    fn test_try_deserialize_any_migrates_older_bridge_layouts() {
+        let SetupBridgeResult {
+            svm, bridge_pda, ..
+        } = setup_bridge();
+        let data = svm.get_account(&bridge_pda).unwrap().data;
+        let bridge = Bridge::try_deserialize(&mut &data[..]).unwrap();
+
+        // Strip the fields added by the latest layout, which are zero in the initialized bridge
+        let previous = &BRIDGE_LAYOUTS[BRIDGE_LAYOUTS.len() - 2];
+        let mut old_data = data.clone();
+        for insertion in BRIDGE_LAYOUTS.last().unwrap().insertions.iter().rev() {
+            let start = DISCRIMINATOR_LEN + insertion.offset;
+            old_data.drain(start..start + insertion.len);
+        }
+        old_data[DISCRIMINATOR_LEN] = previous.version;
+        assert_eq!(old_data.len(), DISCRIMINATOR_LEN + previous.space);
+
+        let BridgeAccount::Bridge(decoded) = try_deserialize_any(&old_data).unwrap() else {
+            panic!("Expected a Bridge account");
+        };
+        assert_eq!(*decoded, bridge);
+    }
+
+    #[test]
+    fn test_try_deserialize_any_rejects_unknown_discriminators() {
+        let error = try_deserialize_any(&[0u8; 4]).unwrap_err();
+        assert_eq!(error, ErrorCode::AccountDiscriminatorNotFound.into());
+
+        let error = try_deserialize_any(&[0u8; 64]).unwrap_err();
+        assert_eq!(error, ErrorCode::AccountDiscriminatorMismatch.into());
+    }
+}
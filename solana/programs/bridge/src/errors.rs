@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use eip1559::Eip1559ConfigError;
 
 #[error_code]
 pub enum BridgeError {
@@ -12,6 +13,24 @@ pub enum BridgeError {
     #[msg("Incorrect gas fee receiver")]
     IncorrectGasFeeReceiver,
 
+    #[msg("Gas fee receiver must be a system account")]
+    InvalidGasFeeReceiver,
+
+    #[msg("Incorrect gas fee escrow")]
+    IncorrectGasFeeEscrow,
+
+    #[msg("SPL Memo program is required to log an SPL memo")]
+    MissingMemoProgram,
+
+    #[msg("Program was not built for the expected environment")]
+    EnvironmentMismatch,
+
+    #[msg("Incorrect payer rate limit")]
+    IncorrectPayerRateLimit,
+
+    #[msg("Payer exceeded its outgoing message rate limit")]
+    PayerRateLimited,
+
     // Authorization & Access Control (6100-6199)
     #[msg("Only the upgrade authority can initialize the bridge")]
     UnauthorizedInitialization = 6100,
@@ -19,6 +38,27 @@ pub enum BridgeError {
     #[msg("Unauthorized to update configuration")]
     UnauthorizedConfigUpdate,
 
+    #[msg("Signer is not the delegate of the bridge session")]
+    IncorrectSessionDelegate,
+
+    #[msg("Token account is not owned by the bridge session owner")]
+    IncorrectSessionOwner,
+
+    #[msg("Bridge session has expired")]
+    SessionExpired,
+
+    #[msg("Bridge session does not allow this remote token")]
+    SessionRemoteTokenNotAllowed,
+
+    #[msg("Amount exceeds the remaining bridge session budget")]
+    SessionBudgetExceeded,
+
+    #[msg("Too many bridge session remote tokens")]
+    TooManySessionRemoteTokens,
+
+    #[msg("Bridge session expiry must be in the future")]
+    InvalidSessionExpiry,
+
     // Buffer Management (6200-6299)
     #[msg("Only the owner can close this buffer")]
     BufferUnauthorizedClose = 6200,
@@ -29,6 +69,21 @@ pub enum BridgeError {
     #[msg("Call buffer size exceeds maximum allowed size")]
     BufferMaxSizeExceeded,
 
+    #[msg("Call buffer expiry is disabled")]
+    BufferExpiryDisabled,
+
+    #[msg("Call buffer has not expired yet")]
+    BufferNotExpired,
+
+    #[msg("Incorrect call buffer owner")]
+    IncorrectBufferOwner,
+
+    #[msg("Cannot append to a reusable call buffer")]
+    BufferReusable,
+
+    #[msg("Only the owner can clone a call buffer that is not reusable")]
+    BufferNotCloneable,
+
     // Signature & Cryptography (6300-6399)
     #[msg("Invalid recovery ID")]
     InvalidRecoveryId = 6300,
@@ -42,6 +97,21 @@ pub enum BridgeError {
     #[msg("Insufficient partner oracle signatures to meet threshold")]
     InsufficientPartnerSignatures,
 
+    #[msg("Instructions sysvar is required to verify signatures through the Secp256k1 program")]
+    MissingInstructionsSysvar,
+
+    #[msg("Invalid Secp256k1 program instruction")]
+    InvalidSecp256k1Instruction,
+
+    #[msg("Output roots are not conflicting")]
+    OutputRootsNotConflicting,
+
+    #[msg("No Base oracle signer attested both output roots")]
+    NoEquivocatingSigner,
+
+    #[msg("Too many signatures (max 32)")]
+    TooManySignatures,
+
     // MMR Proofs (6400-6499)
     #[msg("Invalid proof")]
     InvalidProof = 6400,
@@ -67,6 +137,9 @@ pub enum BridgeError {
     #[msg("No peaks found for non-empty MMR")]
     NoPeaksFoundForNonEmptyMmr,
 
+    #[msg("Outbox MMR is full")]
+    OutboxFull,
+
     // Message Proving & Relaying (6500-6599)
     #[msg("Invalid message hash")]
     InvalidMessageHash = 6500,
@@ -77,6 +150,158 @@ pub enum BridgeError {
     #[msg("Incorrect block number")]
     IncorrectBlockNumber,
 
+    #[msg("Return transfers can only be sent by the Base Bridge")]
+    UnauthorizedReturnTransfer,
+
+    #[msg("Incorrect returned transfer account")]
+    IncorrectReturnedTransfer,
+
+    #[msg("Incorrect address book account")]
+    IncorrectAddressBook,
+
+    #[msg("Transfer already returned")]
+    TransferAlreadyReturned,
+
+    #[msg("Relayed nonce is outside of the page or not yet assigned")]
+    InvalidRelayedNonce,
+
+    #[msg("Outgoing message has not been relayed on Base")]
+    MessageNotRelayed,
+
+    #[msg("Incorrect outgoing message sender")]
+    IncorrectOutgoingMessageSender,
+
+    #[msg("Too many extra signers")]
+    TooManyExtraSigners,
+
+    #[msg("Too many seeds for an extra signer")]
+    TooManyExtraSignerSeeds,
+
+    #[msg("Extra signer seed too long")]
+    ExtraSignerSeedTooLong,
+
+    #[msg("Output roots batch is empty")]
+    EmptyOutputRootsBatch,
+
+    #[msg("Too many output roots in batch")]
+    TooManyOutputRoots,
+
+    #[msg("Incorrect output root account")]
+    IncorrectOutputRootAccount,
+
+    #[msg("Address book account is required to prove this message")]
+    MissingAddressBook,
+
+    #[msg("Remaining compute units are below the amount required by the message")]
+    InsufficientComputeBudget,
+
+    #[msg("Compute budget messages cannot be nested")]
+    NestedComputeBudget,
+
+    #[msg("Message remote chain ID does not match the configured remote chain")]
+    IncorrectRemoteChainId,
+
+    #[msg("Relay fee messages cannot wrap compute budget or relay fee messages")]
+    NestedRelayFee,
+
+    #[msg("Relay fee vault and relayer accounts are required to relay this message")]
+    MissingRelayFeeAccounts,
+
+    #[msg("Relay fee settlement nonce must follow the latest settlement")]
+    InvalidRelayFeeSettlementNonce,
+
+    #[msg("Output root is older than the freshness window of the message kind")]
+    StaleOutputRoot,
+
+    #[msg("Too many instructions in message")]
+    TooManyMessageIxs,
+
+    #[msg("Too many accounts in message instruction")]
+    TooManyIxAccounts,
+
+    #[msg("Message instruction data too long")]
+    MessageIxsDataTooLong,
+
+    #[msg("Message instruction targets a program that cannot be invoked")]
+    InvalidIxProgramId,
+
+    #[msg("Message instructions span too many proven chunks")]
+    TooManyProvenChunks,
+
+    #[msg("Message instruction does not fit in a proven chunk")]
+    ProvenChunkTooLarge,
+
+    #[msg("Incorrect proven chunk account")]
+    IncorrectProvenChunkAccount,
+
+    #[msg("Outgoing message has been relayed on Base")]
+    MessageAlreadyRelayed,
+
+    #[msg("Outgoing message did not lock SOL or SPL tokens")]
+    NoLockedTokens,
+
+    #[msg("Output root challenge window has closed")]
+    OutputRootChallengeWindowClosed,
+
+    #[msg("Message was proven against a superseded output root revision")]
+    SupersededOutputRoot,
+
+    #[msg("Message was proven against the current output root revision")]
+    OutputRootNotSuperseded,
+
+    #[msg("Incorrect prover account")]
+    IncorrectProver,
+
+    #[msg("Transfer is still held by the finalization delay")]
+    FinalizationDelayNotElapsed,
+
+    #[msg("Message was vetoed by the guardian")]
+    MessageVetoed,
+
+    #[msg("Message is not held by the finalization delay")]
+    MessageNotInFinalizationDelay,
+
+    #[msg("Output root does not cover the message nonce")]
+    MessageNotCoveredByOutputRoot,
+
+    #[msg("Relayed message invokes a denied program")]
+    DeniedRelayProgram,
+
+    #[msg("Lamport allowance messages cannot wrap other wrapper messages")]
+    NestedLamportAllowance,
+
+    #[msg(
+        "Operational vault and bridge CPI authority accounts are required to relay this message"
+    )]
+    MissingLamportAllowanceAccounts,
+
+    #[msg("Lamport allowance exceeds the maximum allowed by the operational vault")]
+    LamportAllowanceTooHigh,
+
+    #[msg("Operational vault balance is insufficient for the lamport allowance")]
+    InsufficientOperationalVault,
+
+    #[msg("Wrapped token registrations can only be confirmed by the Base Bridge")]
+    UnauthorizedWrappedTokenConfirmation,
+
+    #[msg("Handler payload exceeds MAX_HANDLER_PAYLOAD_LEN")]
+    HandlerPayloadTooLong,
+
+    #[msg("No handler is registered for the message sender")]
+    HandlerNotRegistered,
+
+    #[msg("Incorrect handler program or bridge CPI authority account")]
+    IncorrectHandlerAccounts,
+
+    #[msg("Handlers can only be registered by their Base sender")]
+    UnauthorizedHandlerRegistration,
+
+    #[msg("Message tripped the outflow circuit breaker and awaits the guardian approval")]
+    OutflowApprovalRequired,
+
+    #[msg("Message is not held by the outflow circuit breaker")]
+    OutflowNotHeld,
+
     // Token Validation (6600-6699)
     #[msg("Mint does not match local token")]
     MintDoesNotMatchLocalToken = 6600,
@@ -96,6 +321,63 @@ pub enum BridgeError {
     #[msg("Incorrect sol vault")]
     IncorrectSolVault,
 
+    #[msg("Incorrect token deposits account")]
+    IncorrectTokenDeposits,
+
+    #[msg("Incorrect protocol fee treasury")]
+    IncorrectProtocolFeeTreasury,
+
+    #[msg("Transfer memo exceeds MAX_TRANSFER_MEMO_LEN")]
+    TransferMemoTooLong,
+
+    #[msg("Liability root must be for a newer Base block")]
+    StaleLiabilityRoot,
+
+    #[msg("Scaled wrapped token amount overflows")]
+    ScaledAmountOverflow,
+
+    #[msg("Remote amount is not a multiple of the wrapped token scalar")]
+    InexactScaledAmount,
+
+    #[msg("Transfer batch has no recipients")]
+    EmptyTransferBatch,
+
+    #[msg("Transfer batch exceeds MAX_TRANSFER_BATCH_RECIPIENTS")]
+    TooManyBatchRecipients,
+
+    #[msg("Mint is not owned by the token program")]
+    MintNotOwnedByTokenProgram,
+
+    #[msg("Mint freeze authority is not allowed by the mint policy")]
+    FreezeAuthorityNotAllowed,
+
+    #[msg("Crosschain mints can only be requested by the Base Bridge")]
+    UnauthorizedCrosschainMint,
+
+    #[msg("Mint would exceed the wrapped token max supply")]
+    WrappedSupplyCapExceeded,
+
+    #[msg("Incorrect wrapped token supply cap account")]
+    IncorrectWrappedSupplyCapAccount,
+
+    #[msg("Wrapped token max supplies can only be set by the Base Bridge")]
+    UnauthorizedSupplyCapUpdate,
+
+    #[msg("Incorrect vault decimals account")]
+    IncorrectVaultDecimalsAccount,
+
+    #[msg("Mint is not on the allow-list during guarded launch")]
+    MintNotAllowListed,
+
+    #[msg("Incorrect wrapped token confirmation account")]
+    IncorrectWrappedTokenConfirmation,
+
+    #[msg("Wrapped token registration has not been confirmed by Base")]
+    WrappedTokenNotConfirmed,
+
+    #[msg("Confirmed metadata does not match the wrapped token")]
+    WrappedTokenMetadataMismatch,
+
     // Token Metadata (6700-6799)
     #[msg("Remote token not found")]
     RemoteTokenNotFound = 6700,
@@ -115,6 +397,27 @@ pub enum BridgeError {
     #[msg("Mint is not a valid wrapped token PDA")]
     MintIsNotWrappedTokenPda,
 
+    #[msg("Incorrect Metaplex metadata account")]
+    IncorrectMetaplexMetadata,
+
+    #[msg("Metaplex program is required to create Metaplex metadata")]
+    MissingMetaplexProgram,
+
+    #[msg("Token name and symbol must not be empty")]
+    EmptyTokenMetadata,
+
+    #[msg("Token name exceeds MAX_WRAPPED_TOKEN_NAME_LEN")]
+    TokenNameTooLong,
+
+    #[msg("Token symbol exceeds MAX_WRAPPED_TOKEN_SYMBOL_LEN")]
+    TokenSymbolTooLong,
+
+    #[msg("Token name and symbol must not start or end with whitespace")]
+    UntrimmedTokenMetadata,
+
+    #[msg("Token name or symbol contains a disallowed character")]
+    InvalidTokenMetadataCharacter,
+
     // Bridge Configuration (6800-6899)
     #[msg("Threshold must be <= number of signers")]
     InvalidThreshold = 6800,
@@ -140,10 +443,156 @@ pub enum BridgeError {
     #[msg("Invalid block interval requirement")]
     InvalidBlockIntervalRequirement,
 
+    #[msg("Unrecognized bridge account layout")]
+    InvalidBridgeLayout,
+
+    #[msg("Bridge account is already at the current layout version")]
+    BridgeAlreadyMigrated,
+
+    #[msg("Max base fee must be >= minimum base fee")]
+    InvalidMaxBaseFee,
+
+    #[msg("Expire bounty must be <= 10000 bps")]
+    InvalidExpireBountyBps,
+
+    #[msg("Too many address book tokens")]
+    TooManyAddressBookTokens,
+
+    #[msg("Duplicate address book token")]
+    DuplicateAddressBookToken,
+
+    #[msg("Address book entry is not set")]
+    AddressBookEntryNotSet,
+
+    #[msg("Too many protocol fee overrides")]
+    TooManyProtocolFeeOverrides,
+
+    #[msg("Duplicate protocol fee override")]
+    DuplicateProtocolFeeOverride,
+
+    #[msg("Protocol fee must be <= MAX_PROTOCOL_FEE_BPS")]
+    InvalidProtocolFeeBps,
+
+    #[msg("Output roots have not stalled long enough to activate the emergency quorum")]
+    OracleNotStalled,
+
+    #[msg("Too many emergency signers")]
+    TooManyEmergencySigners,
+
+    #[msg("Emergency threshold must be > 0 and <= number of Base oracle and emergency signers")]
+    InvalidEmergencyThreshold,
+
+    #[msg("Emergency duration must be > 0 when the oracle failover is enabled")]
+    InvalidEmergencyDuration,
+
+    #[msg("Too many allowed freeze authorities")]
+    TooManyAllowedFreezeAuthorities,
+
+    #[msg("Emergency exit requires the bridge to be paused")]
+    EmergencyExitRequiresPause,
+
+    #[msg("Emergency exit timelock has not elapsed")]
+    EmergencyExitTimelocked,
+
+    #[msg("Too many outflow thresholds")]
+    TooManyOutflowThresholds,
+
+    #[msg("Outflow thresholds must be > 0 and set once per token")]
+    InvalidOutflowThreshold,
+
+    #[msg("Invalid gas target")]
+    InvalidGasTarget,
+
+    #[msg("Too many finalization delay thresholds")]
+    TooManyFinalizationThresholds,
+
+    #[msg("Finalization delay thresholds must be set once per token")]
+    InvalidFinalizationThreshold,
+
+    #[msg("Remote decimals cannot exceed the mint decimals")]
+    InvalidRemoteDecimals,
+
+    #[msg("Vault decimals cannot change while the vault holds deposits")]
+    VaultHasDeposits,
+
+    #[msg("Base governance is not set")]
+    BaseGovernanceNotSet,
+
+    #[msg("This configuration is governed from Base")]
+    GovernedByBase,
+
+    #[msg("Insurance share exceeds the maximum")]
+    InvalidInsuranceShare,
+
+    #[msg("An insurance payout is already pending")]
+    InsurancePayoutPending,
+
+    #[msg("No insurance payout is pending")]
+    NoPendingInsurancePayout,
+
+    #[msg("Insurance payout timelock has not elapsed")]
+    InsurancePayoutTimelocked,
+
+    #[msg("Insurance fund balance is insufficient for the payout")]
+    InsufficientInsuranceFund,
+
+    #[msg("Incorrect insurance payout recipient")]
+    IncorrectInsurancePayoutRecipient,
+
+    #[msg("Too many denied relay programs")]
+    TooManyDeniedRelayPrograms,
+
+    #[msg("Maintenance fee reimbursement exceeds the maximum")]
+    InvalidMaintenanceFeeReimbursement,
+
+    #[msg("Maximum lamport allowance exceeds the limit")]
+    InvalidMaxLamportAllowance,
+
+    #[msg("Too many allowed mints")]
+    TooManyAllowedMints,
+
+    #[msg("Too many DEX routers")]
+    TooManyDexRouters,
+
+    #[msg("Too many payers exempted from rate limiting")]
+    TooManyRateLimitExemptPayers,
+
     // Call Type Validation (6900-6999)
     #[msg("Creation with non-zero target")]
     CreationWithNonZeroTarget = 6900,
 
     #[msg("Zero address")]
     ZeroAddress,
+
+    #[msg("Encrypted call has no ciphertext")]
+    EmptyEncryptedCall,
+
+    #[msg("Calls to a registered DEX router require a swap guard")]
+    MissingSwapGuard,
+
+    #[msg("Swap guard requires a call")]
+    SwapGuardWithoutCall,
+
+    #[msg("Swap guard minimum output must be non-zero")]
+    InvalidSwapGuardMinOut,
+
+    #[msg("Swap guard deadline has passed")]
+    SwapGuardExpired,
+
+    #[msg("Protocol fee exceeds the bridged amount")]
+    ProtocolFeeExceedsAmount,
+
+    #[msg("DEX router list required to check the call")]
+    MissingDexRouterList,
+}
+
+impl From<Eip1559ConfigError> for BridgeError {
+    fn from(error: Eip1559ConfigError) -> Self {
+        match error {
+            Eip1559ConfigError::InvalidGasTarget => BridgeError::InvalidGasTarget,
+            Eip1559ConfigError::InvalidDenominator => BridgeError::InvalidDenominator,
+            Eip1559ConfigError::InvalidWindowDuration => BridgeError::InvalidWindowDurationSeconds,
+            Eip1559ConfigError::InvalidMaxBaseFee => BridgeError::InvalidMaxBaseFee,
+        }
+    }
 }
@@ -0,0 +1,524 @@
+use anchor_lang::prelude::*;
+
+/// Emitted by `audit_vault` with the expected and actual balances of a bridge vault.
+#[event]
+pub struct AuditReport {
+    /// The audited vault (the SOL vault or an SPL token vault).
+    pub vault: Pubkey,
+    /// Amount expected to be locked according to the `TokenDeposits` accounting.
+    pub expected_amount: u64,
+    /// Amount actually held by the vault.
+    pub actual_amount: u64,
+    /// `actual_amount - expected_amount`; negative values indicate a shortfall.
+    pub discrepancy: i128,
+}
+
+/// Emitted when the EIP-1559 base fee adjustment is bounded by `max_base_fee` or
+/// `max_window_increase_bps`, signalling abnormal demand.
+#[event]
+pub struct BaseFeeCapped {
+    /// Base fee the adjustment would have produced without caps.
+    pub uncapped_base_fee: u64,
+    /// Base fee after applying the caps.
+    pub base_fee: u64,
+}
+
+/// Emitted by `initialize_call_buffer` when a new call buffer is created.
+#[event]
+pub struct CallBufferInitialized {
+    /// The call buffer account.
+    pub call_buffer: Pubkey,
+    /// Owner of the call buffer.
+    pub owner: Pubkey,
+    /// Number of bytes written by the initial data.
+    pub bytes_appended: u64,
+    /// Total length of the buffered call data.
+    pub total_len: u64,
+    /// Maximum data length the buffer was allocated for.
+    pub max_data_len: u64,
+}
+
+/// Emitted by `append_to_call_buffer` so clients can track upload progress.
+#[event]
+pub struct CallBufferAppended {
+    /// The call buffer account.
+    pub call_buffer: Pubkey,
+    /// Owner of the call buffer.
+    pub owner: Pubkey,
+    /// Number of bytes appended by this instruction.
+    pub bytes_appended: u64,
+    /// Total length of the buffered call data after the append.
+    pub total_len: u64,
+}
+
+/// Emitted by `close_call_buffer` when a call buffer is closed without being bridged.
+#[event]
+pub struct CallBufferClosed {
+    /// The call buffer account.
+    pub call_buffer: Pubkey,
+    /// Owner of the call buffer, which receives the rent refund.
+    pub owner: Pubkey,
+    /// Total length of the buffered call data at close time.
+    pub total_len: u64,
+}
+
+/// Emitted by `expire_call_buffer` when an idle call buffer is garbage collected.
+#[event]
+pub struct CallBufferExpired {
+    /// The call buffer account.
+    pub call_buffer: Pubkey,
+    /// Owner of the call buffer, which receives the remaining rent.
+    pub owner: Pubkey,
+    /// Account that expired the buffer and received the bounty.
+    pub caller: Pubkey,
+    /// Lamports paid to the caller.
+    pub bounty: u64,
+}
+
+/// Emitted by the buffered bridge instructions when a call buffer is read.
+#[event]
+pub struct CallBufferUsed {
+    /// The call buffer account.
+    pub call_buffer: Pubkey,
+    /// Owner of the call buffer.
+    pub owner: Pubkey,
+    /// Number of times the buffer was used, including this use.
+    pub use_count: u64,
+    /// Length of the bridged call data.
+    pub total_len: u64,
+    /// Whether the buffer was kept for reuse instead of being closed.
+    pub reusable: bool,
+}
+
+/// Emitted by `set_call_buffer_reusable` when a call buffer is marked or unmarked as reusable.
+#[event]
+pub struct CallBufferReusableSet {
+    /// The call buffer account.
+    pub call_buffer: Pubkey,
+    /// Owner of the call buffer.
+    pub owner: Pubkey,
+    /// Whether the buffer is now reusable.
+    pub reusable: bool,
+}
+
+/// Emitted by `clone_call_buffer` when a call buffer is copied into a new one.
+#[event]
+pub struct CallBufferCloned {
+    /// The copied call buffer.
+    pub source: Pubkey,
+    /// The new call buffer account.
+    pub call_buffer: Pubkey,
+    /// Owner of the new call buffer.
+    pub owner: Pubkey,
+    /// Length of the copied call data.
+    pub total_len: u64,
+    /// Maximum data length the new buffer was allocated for.
+    pub max_data_len: u64,
+}
+
+/// Emitted when a protocol fee is skimmed from a bridged amount.
+#[event]
+pub struct ProtocolFeeCharged {
+    /// The account bridging the tokens.
+    pub from: Pubkey,
+    /// The bridged token (a mint, or `NATIVE_SOL_PUBKEY` for SOL).
+    pub local_token: Pubkey,
+    /// The account credited with the fee (the treasury, or one of its token accounts).
+    pub treasury: Pubkey,
+    /// Amount requested to be bridged, before the protocol fee.
+    pub amount: u64,
+    /// Protocol fee deducted from `amount`.
+    pub fee: u64,
+    /// Amount actually bridged after the protocol fee, net of any token transfer fee.
+    pub net_amount: u64,
+    /// Protocol fee rate in basis points.
+    pub bps: u16,
+}
+
+/// Emitted when a transfer carries a payment memo, so that payment processors can match the
+/// outgoing message against their invoices.
+#[event]
+pub struct TransferMemo {
+    /// The outgoing message carrying the transfer.
+    pub outgoing_message: Pubkey,
+    /// Nonce of the outgoing message.
+    pub nonce: u64,
+    /// The account bridging the tokens.
+    pub from: Pubkey,
+    /// The bridged token (a mint, or `NATIVE_SOL_PUBKEY` for SOL).
+    pub local_token: Pubkey,
+    /// Amount bridged to Base, after the protocol fee.
+    pub amount: u64,
+    /// The memo, also appended to the message data relayed to Base.
+    pub memo: Vec<u8>,
+}
+
+/// Emitted by `reconcile_vault` with the balance of a bridge vault and its liability proven
+/// against the posted `LiabilityRoot`.
+#[event]
+pub struct VaultReconciled {
+    /// The reconciled vault (the SOL vault or an SPL token vault).
+    pub vault: Pubkey,
+    /// The vault token (a mint, or `NATIVE_SOL_PUBKEY` for SOL).
+    pub local_token: Pubkey,
+    /// The Base block number at which the liability was measured.
+    pub base_block_number: u64,
+    /// Outstanding supply on Base the vault must cover.
+    pub liability: u64,
+    /// Amount actually held by the vault.
+    pub actual_amount: u64,
+    /// Whether `actual_amount` covers `liability`; raises the solvency alert otherwise.
+    pub solvent: bool,
+}
+
+/// Emitted by the guardian activating the oracle emergency quorum after output roots stalled.
+#[event]
+pub struct OracleEmergencyActivated {
+    /// Number of required valid unique signatures while the emergency quorum is active.
+    pub threshold: u8,
+    /// Signers accepted in addition to the Base oracle signers.
+    pub emergency_signers: Vec<[u8; 20]>,
+    /// Unix timestamp of the latest registered output root.
+    pub last_output_root_at: i64,
+    /// Unix timestamp at which the emergency quorum expires.
+    pub expires_at: i64,
+}
+
+/// Emitted by the guardian deactivating the oracle emergency quorum before it expires.
+#[event]
+pub struct OracleEmergencyDeactivated {
+    /// Unix timestamp at which the emergency quorum would have expired.
+    pub expires_at: i64,
+}
+
+/// Emitted for every oracle attestation accepted by the emergency quorum rather than the Base
+/// oracle threshold.
+#[event]
+pub struct EmergencyQuorumUsed {
+    /// The attested message hash.
+    pub message_hash: [u8; 32],
+    /// Number of Base oracle and emergency signers that approved the message.
+    pub approvals: u8,
+    /// The emergency threshold in effect.
+    pub threshold: u8,
+    /// Unix timestamp at which the emergency quorum expires.
+    pub expires_at: i64,
+}
+
+/// Emitted by `settle_relay_fees` when relay fees collected on Base are deposited into the relay
+/// fee vault.
+#[event]
+pub struct RelayFeesSettled {
+    /// Nonce of the settlement.
+    pub settlement_nonce: u64,
+    /// Lamports deposited by the settlement.
+    pub amount: u64,
+    /// Total lamports deposited by all settlements.
+    pub total_settled: u64,
+}
+
+/// Emitted by `relay_message` when the relay fee of a `WithRelayFee` message is paid.
+#[event]
+pub struct RelayFeePaid {
+    /// The relayed incoming message.
+    pub message: Pubkey,
+    /// The relayer credited with the fee.
+    pub relayer: Pubkey,
+    /// Relay fee prepaid on Base.
+    pub fee: u64,
+    /// Lamports actually paid, lower than `fee` when the vault is short of funds.
+    pub paid: u64,
+}
+
+/// Emitted by `relay_message` when the unspent lamport allowance of a `WithLamportAllowance`
+/// message is returned to the operational vault.
+#[event]
+pub struct LamportAllowanceReturned {
+    /// The relayed incoming message.
+    pub message: Pubkey,
+    /// The bridge CPI authority lent the allowance.
+    pub bridge_cpi_authority: Pubkey,
+    /// Lamports lent to the bridge CPI authority.
+    pub lamports: u64,
+    /// Lamports returned unspent to the operational vault.
+    pub returned: u64,
+}
+
+/// Emitted by the guardian enabling the emergency exit of a paused bridge.
+#[event]
+pub struct EmergencyExitEnabled {
+    /// Unix timestamp from which depositors can withdraw.
+    pub withdrawable_at: i64,
+}
+
+/// Emitted by the guardian cancelling the emergency exit.
+#[event]
+pub struct EmergencyExitCancelled {
+    /// Unix timestamp from which depositors could have withdrawn.
+    pub withdrawable_at: i64,
+}
+
+/// Emitted when a depositor withdraws the tokens locked by an outgoing message not relayed to
+/// Base during the emergency exit.
+#[event]
+pub struct EmergencyWithdrawal {
+    /// Nonce of the outgoing message.
+    pub nonce: u64,
+    /// The depositor, sender of the outgoing message.
+    pub sender: Pubkey,
+    /// The withdrawn token, `NATIVE_SOL_PUBKEY` for SOL.
+    pub local_token: Pubkey,
+    /// The withdrawn amount.
+    pub amount: u64,
+}
+
+/// Emitted by `report_equivocation` when Base oracle signers are disabled for attesting two
+/// conflicting output roots for the same Base block.
+#[event]
+pub struct OracleEquivocationReported {
+    /// The Base block number both output roots were attested for.
+    pub base_block_number: u64,
+    /// EVM addresses of the disabled Base oracle signers.
+    pub offenders: Vec<[u8; 20]>,
+    /// The account that reported the equivocation.
+    pub reporter: Pubkey,
+}
+
+/// Emitted by `supersede_output_root` when the oracles replace a registered output root within
+/// its challenge window, e.g. after a Base reorg below it.
+#[event]
+pub struct OutputRootSuperseded {
+    /// The Base block number of the output root.
+    pub base_block_number: u64,
+    /// The replaced MMR root.
+    pub previous_root: [u8; 32],
+    /// The new MMR root.
+    pub root: [u8; 32],
+    /// The new revision of the output root.
+    pub revision: u32,
+}
+
+/// Emitted by `relay_message` when releasing a transfer would exceed the outflow threshold of its
+/// token. The bridge is paused and the message held until the guardian approves its outflow with
+/// `approve_outflow`.
+#[event]
+pub struct OutflowCircuitBreakerTripped {
+    /// The incoming message whose transfer was withheld.
+    pub message: Pubkey,
+    /// The transferred token, `NATIVE_SOL_PUBKEY` for SOL.
+    pub local_token: Pubkey,
+    /// The withheld amount.
+    pub amount: u64,
+    /// Outflow of the token over the sliding window, `amount` included.
+    pub window_outflow: u64,
+}
+
+/// Emitted when the bridge mints wrapped tokens, named after the ERC-7802 `CrosschainMint` event
+/// so that token issuers can track bridged supply the same way on both chains.
+#[event]
+pub struct CrosschainMint {
+    /// The wrapped token mint.
+    pub mint: Pubkey,
+    /// Owner of the token account credited with the minted tokens.
+    pub to: Pubkey,
+    /// The minted amount.
+    pub amount: u64,
+    /// The bridge program, which requested the mint.
+    pub sender: Pubkey,
+}
+
+/// Emitted when the bridge burns wrapped tokens, named after the ERC-7802 `CrosschainBurn` event.
+#[event]
+pub struct CrosschainBurn {
+    /// The wrapped token mint.
+    pub mint: Pubkey,
+    /// Owner of the token account debited with the burned tokens.
+    pub from: Pubkey,
+    /// The burned amount.
+    pub amount: u64,
+    /// The bridge program, which requested the burn.
+    pub sender: Pubkey,
+}
+
+/// Emitted when the max supply of a wrapped token is mirrored from Base.
+#[event]
+pub struct WrappedTokenMaxSupplyUpdated {
+    /// The wrapped token mint.
+    pub mint: Pubkey,
+    /// The new max supply, `None` when the cap is lifted.
+    pub max_supply: Option<u64>,
+}
+
+/// Emitted when a Base sender registers the handler of its `CallHandler` messages.
+#[event]
+pub struct HandlerRegistered {
+    /// The Base address that registered the handler.
+    pub sender: [u8; 20],
+    /// The program invoked with the payload of the `CallHandler` messages of the sender.
+    pub program_id: Pubkey,
+    /// Discriminator of the handler instruction.
+    pub discriminator: [u8; 8],
+}
+
+/// Emitted when a message from Base is proven. Fields are fixed-size so that indexers can filter
+/// logs on the nonce and sender at fixed offsets.
+#[event]
+pub struct MessageProven {
+    /// Nonce of the message on Base.
+    pub nonce: u64,
+    /// The Base address that sent the message.
+    pub sender_base: [u8; 20],
+    /// Hash of the message, seed of its `IncomingMessage` account.
+    pub message_hash: [u8; 32],
+    /// The account that proved the message and paid its rent.
+    pub prover: Pubkey,
+    /// Unix timestamp from which the message can be relayed under the finalization delay
+    /// configured when it was proven. Later than the proving time for high-value transfers.
+    pub finalizable_at: i64,
+    /// Bridge CPI authority of `sender_base`, signing the instructions relayed from the message.
+    pub cpi_authority: Pubkey,
+}
+
+/// Emitted by `relay_message` with the execution receipt of a message from Base. Fields are
+/// fixed-size and laid out like `MessageProven` so that both events filter on the same offsets.
+#[event]
+pub struct MessageRelayed {
+    /// Nonce of the message on Base.
+    pub nonce: u64,
+    /// The Base address that sent the message.
+    pub sender_base: [u8; 20],
+    /// Whether the message was executed. False when it was withheld by the outflow circuit
+    /// breaker and is left to be relayed again.
+    pub success: bool,
+    /// Keccak256 hash of the return data left by the last executed CPI, zero when none.
+    pub return_data_hash: [u8; 32],
+    /// The relayer that signed the relay, the default pubkey when none did.
+    pub relayer: Pubkey,
+}
+
+/// Emitted when the guardian vetoes a message held by the finalization delay.
+#[event]
+pub struct MessageVetoed {
+    /// Hash of the message, seed of its `IncomingMessage` account.
+    pub message_hash: [u8; 32],
+    /// The guardian that vetoed the message.
+    pub guardian: Pubkey,
+}
+
+/// Emitted when the guardian approves the outflow of a message held by the outflow circuit
+/// breaker.
+#[event]
+pub struct OutflowApproved {
+    /// Hash of the message, seed of its `IncomingMessage` account.
+    pub message_hash: [u8; 32],
+    /// The guardian that approved the outflow.
+    pub guardian: Pubkey,
+}
+
+/// Emitted by the guardian requesting a payout from the insurance fund.
+#[event]
+pub struct InsurancePayoutRequested {
+    /// The account receiving the payout.
+    pub recipient: Pubkey,
+    /// Lamports paid out.
+    pub amount: u64,
+    /// Unix timestamp from which the payout can be executed.
+    pub executable_at: i64,
+}
+
+/// Emitted by the guardian cancelling the pending insurance payout.
+#[event]
+pub struct InsurancePayoutCancelled {
+    /// The account that would have received the payout.
+    pub recipient: Pubkey,
+    /// Lamports that would have been paid out.
+    pub amount: u64,
+}
+
+/// Emitted when the pending insurance payout is executed after its timelock.
+#[event]
+pub struct InsurancePayoutExecuted {
+    /// The account receiving the payout.
+    pub recipient: Pubkey,
+    /// Lamports paid out.
+    pub amount: u64,
+    /// Total lamports paid out by the insurance fund.
+    pub total_paid: u64,
+}
+
+/// Emitted when an outgoing message is appended to the outbox MMR, with the root committing to it.
+#[event]
+pub struct OutgoingMessageCommitted {
+    /// The outgoing message account.
+    pub outgoing_message: Pubkey,
+    /// Nonce of the message.
+    pub nonce: u64,
+    /// Index of the message leaf in the outbox MMR.
+    pub leaf_index: u64,
+    /// Root of the outbox MMR after appending the message.
+    pub root: [u8; 32],
+}
+
+/// Emitted by the guardian turning guarded launch on or off.
+#[event]
+pub struct GuardedLaunchSet {
+    /// Whether `bridge_spl` only accepts allow-listed mints.
+    pub enabled: bool,
+}
+
+/// Emitted by the guardian adding a mint to, or removing it from, the guarded launch allow-list.
+#[event]
+pub struct MintAllowListUpdated {
+    /// The mint.
+    pub mint: Pubkey,
+    /// Whether the mint is now allow-listed.
+    pub allowed: bool,
+}
+
+/// Emitted by the guardian registering a Base DEX router, or unregistering it.
+#[event]
+pub struct DexRouterListUpdated {
+    /// The router address on Base.
+    pub router: [u8; 20],
+    /// Whether calls to the router now require a swap guard.
+    pub registered: bool,
+}
+
+/// Emitted when the guardian sets the outgoing messages and gas each payer can pay for per window.
+#[event]
+pub struct PayerRateLimitSet {
+    /// Duration of a rate limiting window, in seconds (0 disables rate limiting).
+    pub window_seconds: u64,
+    /// Maximum number of outgoing messages a payer can pay for per window (0 for no limit).
+    pub max_messages: u64,
+    /// Maximum gas a payer can pay for per window (0 for no limit).
+    pub max_gas: u64,
+}
+
+/// Emitted by the guardian exempting a payer from the payer rate limits, or subjecting it to them
+/// again.
+#[event]
+pub struct PayerRateLimitExemptionUpdated {
+    /// The payer.
+    pub payer: Pubkey,
+    /// Whether the payer is now exempt from the rate limits.
+    pub exempt: bool,
+}
+
+/// Emitted when a message relayed from the Base Bridge confirms that Base registered a wrapped
+/// token created by `wrap_token`.
+#[event]
+pub struct WrappedTokenConfirmed {
+    /// The wrapped token mint.
+    pub mint: Pubkey,
+    /// The incoming message carrying the confirmation.
+    pub message: Pubkey,
+}
+
+/// Emitted by the guardian requiring, or no longer requiring, wrapped tokens to be confirmed by
+/// Base before they can be bridged.
+#[event]
+pub struct WrappedTokenConfirmationRequiredSet {
+    /// Whether `bridge_wrapped_token` only accepts confirmed wrapped tokens.
+    pub required: bool,
+}
@@ -0,0 +1,307 @@
+//! Base-side encoding of Solana to Base messages, shared by the golden tests and the `fixtures`
+//! binary generating `relayMessages` calldata for the Base contract tests.
+//!
+//! The encoders mirror `MessageLib.sol`, `CallLib.sol` and `TokenLib.sol`: any change to the
+//! messages relayed to Base must be reflected here and in `golden/messages.json`.
+
+use alloy_primitives::{Address, Bytes, FixedBytes};
+use alloy_sol_types::{sol, sol_data, SolCall, SolType, SolValue};
+use anchor_lang::{prelude::*, solana_program::keccak};
+
+use crate::solana_to_base::{
+    Call, CallType, Message, OutgoingMessage, SwapGuard, Transfer, TransferBatch, NATIVE_SOL_PUBKEY,
+};
+
+/// Mirrors the `MessageType` enum of `MessageLib.sol`.
+pub const MESSAGE_TYPE_CALL: u8 = 0;
+pub const MESSAGE_TYPE_TRANSFER: u8 = 1;
+pub const MESSAGE_TYPE_TRANSFER_AND_CALL: u8 = 2;
+pub const MESSAGE_TYPE_TRANSFER_BATCH: u8 = 3;
+
+/// Gas limit of the fixture messages, enough for any of them to execute on Base.
+pub const FIXTURE_GAS_LIMIT: u64 = 1_000_000;
+
+sol! {
+    /// Mirrors the `IncomingMessage` struct of `MessageLib.sol`, `Pubkey` being a `bytes32`.
+    struct IncomingMessage {
+        bytes32 outgoingMessagePubkey;
+        uint64 nonce;
+        bytes32 sender;
+        uint64 gasLimit;
+        uint8 ty;
+        bytes data;
+    }
+
+    /// Mirrors the `Call` struct of `CallLib.sol`. `uint8` fields have no `SolValue` tuple
+    /// encoding, as `u8` is reserved for `bytes`.
+    struct BaseCall {
+        uint8 ty;
+        address to;
+        uint128 value;
+        bytes data;
+    }
+
+    /// `Bridge.relayMessages`.
+    function relayMessages(IncomingMessage[] messages);
+}
+
+/// ABI encodes the `Call` struct of `CallLib.sol`.
+pub fn call_tuple(call: &Call) -> BaseCall {
+    BaseCall {
+        ty: call.ty as u8,
+        to: Address::from(call.to),
+        value: call.value,
+        data: Bytes::copy_from_slice(&call.data),
+    }
+}
+
+/// ABI encodes the `Transfer` struct of `TokenLib.sol`. The local and remote tokens are swapped
+/// and `to` is right padded so that `bytes20(to)` yields the recipient.
+pub fn transfer_tuple(transfer: &Transfer) -> (Address, FixedBytes<32>, FixedBytes<32>, u64) {
+    let mut to = [0u8; 32];
+    to[..20].copy_from_slice(&transfer.to);
+
+    (
+        Address::from(transfer.remote_token),
+        FixedBytes::from(transfer.local_token.to_bytes()),
+        FixedBytes::from(to),
+        transfer.amount,
+    )
+}
+
+/// ABI encodes the `SwapGuard` struct of `CallLib.sol`.
+pub fn swap_guard_tuple(swap_guard: &SwapGuard) -> (Address, u128, u64) {
+    (
+        Address::from(swap_guard.token_out),
+        swap_guard.min_out,
+        swap_guard.deadline as u64,
+    )
+}
+
+/// ABI encodes a `TransferBatch` as the `Transfer[]` of `TokenLib.sol`, one per recipient.
+pub fn transfer_batch_tuples(
+    batch: &TransferBatch,
+) -> Vec<(Address, FixedBytes<32>, FixedBytes<32>, u64)> {
+    batch
+        .recipients
+        .iter()
+        .map(|recipient| {
+            transfer_tuple(&Transfer {
+                to: recipient.to,
+                local_token: batch.local_token,
+                remote_token: batch.remote_token,
+                amount: recipient.amount,
+                call: None,
+                memo: None,
+                swap_guard: None,
+            })
+        })
+        .collect()
+}
+
+/// Builds the `(ty, data)` pair of the `IncomingMessage` relayed on Base.
+pub fn incoming_payload(message: &OutgoingMessage) -> (u8, Vec<u8>) {
+    match &message.message {
+        Message::Call(call) => (MESSAGE_TYPE_CALL, call_tuple(call).abi_encode()),
+        Message::Transfer(transfer) => {
+            let memo = transfer.memo.as_deref().map(Bytes::copy_from_slice);
+            match (&transfer.call, memo, &transfer.swap_guard) {
+                (None, None, _) => (MESSAGE_TYPE_TRANSFER, transfer_tuple(transfer).abi_encode()),
+                (None, Some(memo), _) => (
+                    MESSAGE_TYPE_TRANSFER,
+                    (transfer_tuple(transfer), memo).abi_encode_params(),
+                ),
+                (Some(call), None, None) => (
+                    MESSAGE_TYPE_TRANSFER_AND_CALL,
+                    (transfer_tuple(transfer), call_tuple(call)).abi_encode_params(),
+                ),
+                (Some(call), Some(memo), None) => (
+                    MESSAGE_TYPE_TRANSFER_AND_CALL,
+                    (transfer_tuple(transfer), call_tuple(call), memo).abi_encode_params(),
+                ),
+                // The swap guard follows the memo, which is then encoded even if empty
+                (Some(call), memo, Some(swap_guard)) => (
+                    MESSAGE_TYPE_TRANSFER_AND_CALL,
+                    (
+                        transfer_tuple(transfer),
+                        call_tuple(call),
+                        memo.unwrap_or_default(),
+                        swap_guard_tuple(swap_guard),
+                    )
+                        .abi_encode_params(),
+                ),
+            }
+        }
+        Message::Encrypted(_) => unreachable!("encrypted calls have no plaintext Base encoding"),
+        Message::TransferBatch(batch) => (
+            MESSAGE_TYPE_TRANSFER_BATCH,
+            transfer_batch_tuples(batch).abi_encode(),
+        ),
+    }
+}
+
+/// keccak256(abi.encode(sender, ty, data)) as computed by `MessageLib.getInnerMessageHash`.
+pub fn inner_message_hash(sender: &Pubkey, ty: u8, data: &[u8]) -> [u8; 32] {
+    let encoded =
+        <(sol_data::FixedBytes<32>, sol_data::Uint<8>, sol_data::Bytes)>::abi_encode_params(&(
+            FixedBytes::from(sender.to_bytes()),
+            ty,
+            Bytes::copy_from_slice(data),
+        ));
+    keccak::hash(&encoded).0
+}
+
+/// keccak256(bytes32(nonce) || outgoing_message_pubkey || inner_hash) as computed by
+/// `MessageLib.getMessageHash`.
+pub fn message_hash(
+    nonce: u64,
+    outgoing_message_pubkey: &[u8; 32],
+    inner_hash: &[u8; 32],
+) -> [u8; 32] {
+    let mut data_to_hash = [0u8; 96];
+    data_to_hash[24..32].copy_from_slice(&nonce.to_be_bytes());
+    data_to_hash[32..64].copy_from_slice(outgoing_message_pubkey);
+    data_to_hash[64..].copy_from_slice(inner_hash);
+    keccak::hash(&data_to_hash).0
+}
+
+/// Builds the `IncomingMessage` relayed on Base for the `OutgoingMessage` account at
+/// `outgoing_message_pubkey`.
+pub fn incoming_message(
+    outgoing_message_pubkey: &Pubkey,
+    message: &OutgoingMessage,
+    gas_limit: u64,
+) -> IncomingMessage {
+    let (ty, data) = incoming_payload(message);
+
+    IncomingMessage {
+        outgoingMessagePubkey: FixedBytes::from(outgoing_message_pubkey.to_bytes()),
+        nonce: message.nonce,
+        sender: FixedBytes::from(message.sender.to_bytes()),
+        gasLimit: gas_limit,
+        ty,
+        data: data.into(),
+    }
+}
+
+/// ABI encodes the `Bridge.relayMessages` calldata relaying `messages`.
+pub fn relay_messages_calldata(messages: Vec<IncomingMessage>) -> Vec<u8> {
+    relayMessagesCall { messages }.abi_encode()
+}
+
+/// A representative outgoing message, relayed on Base by the fixtures.
+pub struct Fixture {
+    pub name: &'static str,
+    pub outgoing_message_pubkey: Pubkey,
+    pub message: OutgoingMessage,
+}
+
+/// Returns the representative outgoing messages of each kind relayed to Base, with consecutive
+/// nonces so that they can be relayed in a single `relayMessages` call.
+pub fn fixtures() -> Vec<Fixture> {
+    const SENDER: Pubkey = Pubkey::new_from_array([0xaa; 32]);
+    const CREATED_AT: i64 = 1_700_000_000;
+    const REMOTE_CHAIN_ID: u64 = 8453;
+
+    let counter_call = Call {
+        ty: CallType::Call,
+        to: hex_literal("5fbdb2315678afecb367f032d93f642f64180aa3"),
+        value: 0,
+        // increment()
+        data: vec![0xd0, 0x9d, 0xe0, 0x8a],
+    };
+    let transfer = Transfer {
+        to: hex_literal("70997970c51812dc3a010c7d01b50e0d17dc79c8"),
+        local_token: NATIVE_SOL_PUBKEY,
+        remote_token: hex_literal("c5981f461d74c46eb4b0cf3f4ec79f025573b0ea"),
+        amount: 1_000_000_000,
+        call: None,
+        memo: None,
+        swap_guard: None,
+    };
+    // abi.encode(bytes32 salt, bytes creationCode), deploying a contract returning 42
+    let creation_code = hex::decode("600a600c600039600a6000f3602a60005260206000f3").unwrap();
+    let create2_call = Call {
+        ty: CallType::Create2,
+        to: [0; 20],
+        value: 0,
+        data: (
+            FixedBytes::<32>::from([0x01; 32]),
+            Bytes::from(creation_code),
+        )
+            .abi_encode_params(),
+    };
+
+    let messages = [
+        (
+            "call",
+            OutgoingMessage::new_call(0, SENDER, CREATED_AT, REMOTE_CHAIN_ID, counter_call.clone()),
+        ),
+        (
+            "transfer",
+            OutgoingMessage::new_transfer(1, SENDER, CREATED_AT, REMOTE_CHAIN_ID, transfer.clone()),
+        ),
+        (
+            "transfer_and_call",
+            OutgoingMessage::new_transfer(
+                2,
+                SENDER,
+                CREATED_AT,
+                REMOTE_CHAIN_ID,
+                Transfer {
+                    call: Some(counter_call),
+                    ..transfer
+                },
+            ),
+        ),
+        (
+            "create2",
+            OutgoingMessage::new_call(3, SENDER, CREATED_AT, REMOTE_CHAIN_ID, create2_call),
+        ),
+    ];
+
+    messages
+        .into_iter()
+        .enumerate()
+        .map(|(i, (name, message))| Fixture {
+            name,
+            outgoing_message_pubkey: Pubkey::new_from_array([0x11 + i as u8; 32]),
+            message,
+        })
+        .collect()
+}
+
+fn hex_literal<const N: usize>(s: &str) -> [u8; N] {
+    hex::decode(s).unwrap().try_into().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relay_messages_calldata_matches_base_bridge() {
+        assert_eq!(
+            relayMessagesCall::SIGNATURE,
+            "relayMessages((bytes32,uint64,bytes32,uint64,uint8,bytes)[])"
+        );
+
+        let fixtures = fixtures();
+        let messages: Vec<_> = fixtures
+            .iter()
+            .map(|f| incoming_message(&f.outgoing_message_pubkey, &f.message, FIXTURE_GAS_LIMIT))
+            .collect();
+        let calldata = relay_messages_calldata(messages);
+        assert_eq!(calldata[..4], relayMessagesCall::SELECTOR);
+
+        let decoded = relayMessagesCall::abi_decode(&calldata, true).unwrap();
+        assert_eq!(decoded.messages.len(), fixtures.len());
+        for (message, fixture) in decoded.messages.iter().zip(&fixtures) {
+            let (ty, data) = incoming_payload(&fixture.message);
+            assert_eq!(message.nonce, fixture.message.nonce);
+            assert_eq!(message.ty, ty);
+            assert_eq!(message.data.as_ref(), data.as_slice());
+        }
+        assert_eq!(decoded.messages[3].ty, MESSAGE_TYPE_CALL);
+    }
+}
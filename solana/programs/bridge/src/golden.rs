@@ -0,0 +1,317 @@
+//! Golden tests pinning the encoding of Solana to Base messages against the vectors in
+//! `golden/messages.json`, which are shared with the Base contracts (`MessageLib.t.sol`).
+//!
+//! Each vector records the Borsh encoding of the `OutgoingMessage` account (without the
+//! discriminator), the ABI encoded `data` of the matching `IncomingMessage` on Base, and the
+//! inner and outer message hashes computed by `MessageLib`.
+
+use alloy_sol_types::SolValue;
+use anchor_lang::prelude::*;
+use serde_json::Value;
+
+use crate::{
+    fixtures::{
+        incoming_payload, inner_message_hash, message_hash, transfer_tuple, MESSAGE_TYPE_CALL,
+        MESSAGE_TYPE_TRANSFER, MESSAGE_TYPE_TRANSFER_AND_CALL, MESSAGE_TYPE_TRANSFER_BATCH,
+    },
+    solana_to_base::{
+        BatchRecipient, Call, CallType, Message, MessageKind, OutgoingMessage, SwapGuard, Transfer,
+        TransferBatch, NATIVE_SOL_PUBKEY,
+    },
+};
+
+const GOLDEN_MESSAGES: &str = include_str!("../../../../golden/messages.json");
+
+struct Vector {
+    name: String,
+    outgoing_message_pubkey: [u8; 32],
+    message: OutgoingMessage,
+    borsh: Vec<u8>,
+    space: usize,
+    ty: u8,
+    data: Vec<u8>,
+    inner_hash: [u8; 32],
+    message_hash: [u8; 32],
+}
+
+fn golden() -> Value {
+    serde_json::from_str(GOLDEN_MESSAGES).expect("Invalid golden messages file")
+}
+
+fn bytes(value: &Value) -> Vec<u8> {
+    let s = value.as_str().expect("Expected a hex string");
+    hex::decode(s.strip_prefix("0x").expect("Expected a 0x prefix")).expect("Invalid hex")
+}
+
+fn fixed<const N: usize>(value: &Value) -> [u8; N] {
+    bytes(value).try_into().expect("Unexpected length")
+}
+
+fn number<T: std::str::FromStr>(value: &Value) -> T {
+    let s = value.as_str().expect("Expected a decimal string");
+    s.parse().unwrap_or_else(|_| panic!("Invalid number {s}"))
+}
+
+fn call(value: &Value) -> Call {
+    let ty = match value["ty"].as_u64().expect("Expected a call type") {
+        0 => CallType::Call,
+        1 => CallType::DelegateCall,
+        2 => CallType::Create,
+        3 => CallType::Create2,
+        ty => panic!("Unknown call type {ty}"),
+    };
+
+    Call {
+        ty,
+        to: fixed(&value["to"]),
+        value: number(&value["value"]),
+        data: bytes(&value["data"]),
+    }
+}
+
+fn transfer(value: &Value, call: Option<Call>) -> Transfer {
+    Transfer {
+        to: fixed(&value["to"]),
+        local_token: Pubkey::new_from_array(fixed(&value["local_token"])),
+        remote_token: fixed(&value["remote_token"]),
+        amount: number(&value["amount"]),
+        call,
+        memo: (!value["memo"].is_null()).then(|| bytes(&value["memo"])),
+        swap_guard: (!value["swap_guard"].is_null()).then(|| swap_guard(&value["swap_guard"])),
+    }
+}
+
+fn swap_guard(value: &Value) -> SwapGuard {
+    SwapGuard {
+        token_out: fixed(&value["token_out"]),
+        min_out: number(&value["min_out"]),
+        deadline: number(&value["deadline"]),
+    }
+}
+
+fn transfer_batch(value: &Value) -> TransferBatch {
+    TransferBatch {
+        local_token: Pubkey::new_from_array(fixed(&value["local_token"])),
+        remote_token: fixed(&value["remote_token"]),
+        recipients: value["recipients"]
+            .as_array()
+            .expect("Expected a recipients array")
+            .iter()
+            .map(|recipient| BatchRecipient {
+                to: fixed(&recipient["to"]),
+                amount: number(&recipient["amount"]),
+            })
+            .collect(),
+    }
+}
+
+fn vectors() -> Vec<Vector> {
+    golden()["vectors"]
+        .as_array()
+        .expect("Expected a vectors array")
+        .iter()
+        .map(|v| {
+            let nonce = v["nonce"].as_u64().expect("Expected a nonce");
+            let sender = Pubkey::new_from_array(fixed(&v["sender"]));
+            let created_at = v["created_at"].as_i64().expect("Expected a timestamp");
+            let remote_chain_id = v["remote_chain_id"].as_u64().expect("Expected a chain ID");
+            let call = (!v["call"].is_null()).then(|| call(&v["call"]));
+            let message = if !v["transfer_batch"].is_null() {
+                let batch = transfer_batch(&v["transfer_batch"]);
+                OutgoingMessage::new_transfer_batch(
+                    nonce,
+                    sender,
+                    created_at,
+                    remote_chain_id,
+                    batch,
+                )
+            } else if v["transfer"].is_null() {
+                let call = call.expect("Expected a call");
+                OutgoingMessage::new_call(nonce, sender, created_at, remote_chain_id, call)
+            } else {
+                let transfer = transfer(&v["transfer"], call);
+                OutgoingMessage::new_transfer(nonce, sender, created_at, remote_chain_id, transfer)
+            };
+
+            Vector {
+                name: v["name"].as_str().expect("Expected a name").to_string(),
+                outgoing_message_pubkey: fixed(&v["outgoing_message_pubkey"]),
+                message,
+                borsh: bytes(&v["borsh"]),
+                space: v["space"].as_u64().expect("Expected a space") as usize,
+                ty: v["ty"].as_u64().expect("Expected a message type") as u8,
+                data: bytes(&v["data"]),
+                inner_hash: fixed(&v["inner_hash"]),
+                message_hash: fixed(&v["message_hash"]),
+            }
+        })
+        .collect()
+}
+
+fn vector(name: &str) -> Vector {
+    vectors()
+        .into_iter()
+        .find(|v| v.name == name)
+        .unwrap_or_else(|| panic!("Missing golden vector {name}"))
+}
+
+fn space(message: &OutgoingMessage) -> usize {
+    OutgoingMessage::space(MessageKind::from(&message.message))
+}
+
+fn assert_vector(name: &str, expected_ty: u8) {
+    let v = vector(name);
+    assert_eq!(v.ty, expected_ty, "{name}: unexpected message type");
+
+    // Solana side: the OutgoingMessage account payload
+    let mut borsh = Vec::new();
+    v.message.serialize(&mut borsh).unwrap();
+    assert_eq!(borsh, v.borsh, "{name}: Borsh encoding mismatch");
+    assert_eq!(
+        OutgoingMessage::deserialize(&mut &v.borsh[..]).unwrap(),
+        v.message,
+        "{name}: Borsh decoding mismatch"
+    );
+    assert_eq!(space(&v.message), v.space, "{name}: space mismatch");
+    assert_eq!(borsh.len(), v.space, "{name}: space is not exact");
+
+    // Base side: the IncomingMessage payload and hashes
+    let (ty, data) = incoming_payload(&v.message);
+    assert_eq!(ty, v.ty, "{name}: message type mismatch");
+    assert_eq!(data, v.data, "{name}: ABI encoding mismatch");
+
+    let inner_hash = inner_message_hash(&v.message.sender, ty, &data);
+    assert_eq!(inner_hash, v.inner_hash, "{name}: inner hash mismatch");
+    assert_eq!(
+        message_hash(v.message.nonce, &v.outgoing_message_pubkey, &inner_hash),
+        v.message_hash,
+        "{name}: message hash mismatch"
+    );
+}
+
+#[test]
+fn test_golden_call() {
+    assert_vector("call", MESSAGE_TYPE_CALL);
+}
+
+#[test]
+fn test_golden_transfer() {
+    assert_vector("transfer", MESSAGE_TYPE_TRANSFER);
+
+    let v = vector("transfer");
+    match &v.message.message {
+        Message::Transfer(transfer) => {
+            assert_eq!(transfer.local_token, NATIVE_SOL_PUBKEY)
+        }
+        _ => panic!("Expected a transfer"),
+    }
+}
+
+#[test]
+fn test_golden_transfer_and_call() {
+    assert_vector("transfer_and_call", MESSAGE_TYPE_TRANSFER_AND_CALL);
+}
+
+#[test]
+fn test_golden_transfer_with_memo() {
+    assert_vector("transfer_with_memo", MESSAGE_TYPE_TRANSFER);
+
+    // The memo is appended after the transfer, which Base decodes from the first words
+    let v = vector("transfer_with_memo");
+    let transfer_only = match &v.message.message {
+        Message::Transfer(transfer) => transfer_tuple(transfer).abi_encode(),
+        _ => panic!("Expected a transfer"),
+    };
+    assert_eq!(v.data[..transfer_only.len()], transfer_only);
+}
+
+#[test]
+fn test_golden_swap_guard_call_offset() {
+    // Base detects the swap guard from the offset of the call (`Bridge.SWAP_GUARD_CALL_OFFSET`)
+    let call_offset = |memo: Option<Vec<u8>>, swap_guard: Option<SwapGuard>| {
+        let mut message = vector("transfer_and_call").message;
+        match &mut message.message {
+            Message::Transfer(transfer) => {
+                transfer.memo = memo;
+                transfer.swap_guard = swap_guard;
+            }
+            _ => panic!("Expected a transfer"),
+        }
+        let (ty, data) = incoming_payload(&message);
+        assert_eq!(ty, MESSAGE_TYPE_TRANSFER_AND_CALL);
+        u64::from_be_bytes(data[152..160].try_into().unwrap())
+    };
+    let swap_guard = SwapGuard {
+        token_out: [0x42; 20],
+        min_out: 1,
+        deadline: 1_700_000_000,
+    };
+
+    assert_eq!(call_offset(None, None), 160);
+    assert_eq!(call_offset(Some(b"memo".to_vec()), None), 192);
+    assert_eq!(call_offset(None, Some(swap_guard)), 288);
+    assert_eq!(call_offset(Some(b"memo".to_vec()), Some(swap_guard)), 288);
+}
+
+#[test]
+fn test_golden_transfer_batch() {
+    assert_vector("transfer_batch", MESSAGE_TYPE_TRANSFER_BATCH);
+
+    let v = vector("transfer_batch");
+    match &v.message.message {
+        Message::TransferBatch(batch) => {
+            assert_eq!(batch.local_token, NATIVE_SOL_PUBKEY);
+            assert_eq!(batch.recipients.len(), 2);
+        }
+        _ => panic!("Expected a transfer batch"),
+    }
+}
+
+#[test]
+fn test_golden_overhead_constants() {
+    let overhead = &golden()["overhead"];
+    let outgoing_message = overhead["outgoing_message"].as_u64().unwrap() as usize;
+    let call = overhead["call"].as_u64().unwrap() as usize;
+    let transfer = overhead["transfer"].as_u64().unwrap() as usize;
+    let transfer_batch = overhead["transfer_batch"].as_u64().unwrap() as usize;
+    let batch_recipient = overhead["batch_recipient"].as_u64().unwrap() as usize;
+
+    assert_eq!(Call::space(0), call);
+    assert_eq!(Transfer::space(None, None, false), transfer);
+    assert_eq!(Transfer::space(Some(0), None, false), transfer + call);
+    assert_eq!(
+        TransferBatch::space(3),
+        transfer_batch + 3 * batch_recipient
+    );
+    assert_eq!(
+        OutgoingMessage::space(MessageKind::Call { data_len: 0 }),
+        outgoing_message + call
+    );
+    assert_eq!(
+        OutgoingMessage::space(MessageKind::Transfer {
+            call_data_len: None,
+            memo_len: None,
+            swap_guard: false,
+        }),
+        outgoing_message + transfer
+    );
+
+    // Both variants grow by exactly the call data length
+    assert_eq!(
+        OutgoingMessage::space(MessageKind::Call { data_len: 100 })
+            - OutgoingMessage::space(MessageKind::Call { data_len: 0 }),
+        100
+    );
+    assert_eq!(
+        OutgoingMessage::space(MessageKind::Transfer {
+            call_data_len: Some(100),
+            memo_len: None,
+            swap_guard: false,
+        }) - OutgoingMessage::space(MessageKind::Transfer {
+            call_data_len: Some(0),
+            memo_len: None,
+            swap_guard: false,
+        }),
+        100
+    );
+}
@@ -1,33 +1,52 @@
 #![allow(unexpected_cfgs)]
+// The CPI client generated for the bridging instructions takes as many arguments as they do
+#![allow(clippy::too_many_arguments)]
 
 use anchor_lang::prelude::*;
+use fee_config::FeeConfigV2;
 
-mod base_to_solana;
-mod common;
+#[macro_use]
+mod logging;
+
+pub mod base_to_solana;
+pub mod common;
+pub mod discriminators;
+mod env;
 mod errors;
-mod solana_to_base;
+mod events;
+pub mod solana_to_base;
+mod version;
 
 use base_to_solana::*;
 use common::*;
+pub use env::*;
 pub use errors::*;
+pub use events::*;
+pub use version::*;
 
 use common::{
     config::{
         set_adjustment_denominator_handler, set_block_interval_requirement_handler,
-        set_gas_cost_scaler_dp_handler, set_gas_cost_scaler_handler, set_gas_fee_receiver_handler,
-        set_gas_target_handler, set_max_call_buffer_size_handler, set_minimum_base_fee_handler,
-        set_pause_status_handler, set_window_duration_handler,
+        set_call_buffer_ttl_handler, set_expire_bounty_bps_handler,
+        set_fee_quote_grace_period_handler, set_gas_cost_scaler_dp_handler,
+        set_gas_cost_scaler_handler, set_gas_fee_receiver_handler, set_gas_target_handler,
+        set_max_base_fee_handler, set_max_call_buffer_size_handler,
+        set_max_window_increase_bps_handler, set_minimum_base_fee_handler,
+        set_pause_status_handler, set_transfer_gas_lane_handler, set_window_duration_handler,
     },
     guardian::transfer_guardian_handler,
     initialize::initialize_handler,
+    migrate::migrate_bridge_state_handler,
 };
 use solana_to_base::*;
 
+#[cfg(any(test, feature = "fixtures"))]
+pub mod fixtures;
+#[cfg(test)]
+mod golden;
 #[cfg(test)]
 mod test_utils;
 
-declare_id!("GaxAZQ3BSYjfG65e8mGnBnNpmhqRHDJ33aKEASHh3A3P");
-
 #[program]
 pub mod bridge {
 
@@ -46,6 +65,163 @@ pub mod bridge {
         initialize_handler(ctx, guardian, cfg)
     }
 
+    /// Bootstraps a devnet bridge in a single transaction: initializes the bridge with well-known
+    /// devnet defaults, the SOL vault, the config history and the address book, so that no
+    /// partially configured state is left behind. The payer receives the gas fees. Test wrapped
+    /// tokens can be created with `wrap_token` in the same transaction. Not available in mainnet
+    /// builds.
+    ///
+    /// # Arguments
+    /// * `ctx`                 - The context containing the upgrade authority, the payer and every
+    ///                           account being created
+    /// * `guardian`            - The guardian account that will have administrative authority over the bridge
+    /// * `base_oracle_signers` - The Base oracle signers, any one of which can attest output roots
+    /// * `base_bridge`         - The Base Bridge contract registered in the address book
+    /// * `remote_sol_address`  - The Base token representing SOL
+    #[cfg(not(feature = "mainnet"))]
+    pub fn initialize_devnet_defaults(
+        ctx: Context<InitializeDevnetDefaults>,
+        guardian: Pubkey,
+        base_oracle_signers: Vec<[u8; 20]>,
+        base_bridge: [u8; 20],
+        remote_sol_address: [u8; 20],
+    ) -> Result<()> {
+        initialize_devnet_defaults_handler(
+            ctx,
+            guardian,
+            base_oracle_signers,
+            base_bridge,
+            remote_sol_address,
+        )
+    }
+
+    /// Permissionlessly initializes the SOL vault and records its bump.
+    /// Must be called once before any SOL can be bridged.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the payer, the SOL vault and its state account
+    pub fn init_sol_vault(ctx: Context<InitSolVault>) -> Result<()> {
+        init_sol_vault_handler(ctx)
+    }
+
+    /// Permissionlessly initializes the configuration history log.
+    /// Must be called once before any configuration setter can be called.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the payer and the config history account
+    pub fn init_config_history(ctx: Context<InitConfigHistory>) -> Result<()> {
+        init_config_history_handler(ctx)
+    }
+
+    /// Returns the environment (localnet, devnet-alpha, devnet-prod or mainnet) the program was
+    /// compiled for, letting clients detect mixed deployments.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context; no accounts are required
+    pub fn get_environment(ctx: Context<GetEnvironment>) -> Result<Environment> {
+        get_environment_handler(ctx)
+    }
+
+    /// Fails with `EnvironmentMismatch` unless the program was compiled for `expected` and is
+    /// deployed at the program ID of that environment, letting deployment scripts catch builds
+    /// made for another environment.
+    ///
+    /// # Arguments
+    /// * `ctx`      - The context; no accounts are required
+    /// * `expected` - The environment the program is expected to be built for
+    pub fn check_environment(ctx: Context<CheckEnvironment>, expected: Environment) -> Result<()> {
+        check_environment_handler(ctx, expected)
+    }
+
+    /// Returns the program version and a bitmask of the capabilities it supports, letting SDKs
+    /// branch on features instead of guessing from the program ID.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context; no accounts are required
+    pub fn get_version(ctx: Context<GetVersion>) -> Result<Version> {
+        get_version_handler(ctx)
+    }
+
+    /// Returns the bridge CPI authority of a Base sender, i.e. the PDA signing the instructions
+    /// relayed from its messages, letting target programs pre-authorize it.
+    ///
+    /// # Arguments
+    /// * `ctx`    - The context; no accounts are required
+    /// * `sender` - The 20-byte address of the Base contract sending messages
+    pub fn derive_cpi_authority(
+        ctx: Context<DeriveCpiAuthority>,
+        sender: [u8; 20],
+    ) -> Result<Pubkey> {
+        derive_cpi_authority_handler(ctx, sender)
+    }
+
+    /// Returns the last time each Base oracle signer signed a registered output root and whether
+    /// enough of them signed within `max_age_seconds` to meet the threshold, letting monitoring
+    /// detect a degrading quorum before output roots stop being registered.
+    ///
+    /// # Arguments
+    /// * `ctx`             - The context containing the bridge account
+    /// * `max_age_seconds` - Maximum age of a heartbeat for its signer to be considered live
+    pub fn get_oracle_health(
+        ctx: Context<GetOracleHealth>,
+        max_age_seconds: u64,
+    ) -> Result<OracleHealthReport> {
+        get_oracle_health_handler(ctx, max_age_seconds)
+    }
+
+    /// Permissionlessly audits a bridge vault by comparing its actual balance with the amount
+    /// recorded in its `TokenDeposits` accounting, emitting an `AuditReport` event.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the vault (SOL vault or SPL token vault) and its accounting
+    pub fn audit_vault(ctx: Context<AuditVault>) -> Result<()> {
+        audit_vault_handler(ctx)
+    }
+
+    /// Permissionlessly reconciles a bridge vault against its liability proven in the
+    /// oracle-posted liability root, emitting a `VaultReconciled` event and raising the
+    /// solvency alert when the vault balance does not cover the liability.
+    ///
+    /// # Arguments
+    /// * `ctx`       - The context containing the vault, its accounting and the liability root
+    /// * `liability` - The outstanding Base-side supply of the vault token, in local units
+    /// * `proof`     - Merkle proof of the liability leaf against the liability root
+    pub fn reconcile_vault(
+        ctx: Context<ReconcileVault>,
+        liability: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        reconcile_vault_handler(ctx, liability, proof)
+    }
+
+    /// Permissionlessly forwards the gas fees collected by the fallback gas fee escrow (used when
+    /// the gas fee receiver cannot be credited) to the configured gas fee receiver.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge, the gas fee receiver and the gas fee escrow
+    pub fn sweep_gas_fee_escrow(ctx: Context<SweepGasFeeEscrow>) -> Result<()> {
+        sweep_gas_fee_escrow_handler(ctx)
+    }
+
+    /// Permissionlessly pays out the insurance payout requested by the guardian, once
+    /// `INSURANCE_PAYOUT_TIMELOCK_SECONDS` have elapsed since the request.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the insurance fund and the payout recipient
+    pub fn execute_insurance_payout(ctx: Context<ExecuteInsurancePayout>) -> Result<()> {
+        execute_insurance_payout_handler(ctx)
+    }
+
+    /// Migrates the bridge state account to the layout expected by the deployed program.
+    /// This function must be called by the upgrade authority after any program upgrade that
+    /// changes the `Bridge` account layout, and reallocs the account as needed.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account, upgrade authority and rent payer
+    pub fn migrate_bridge_state(ctx: Context<MigrateBridgeState>) -> Result<()> {
+        migrate_bridge_state_handler(ctx)
+    }
+
     // Base -> Solana
 
     /// Registers an output root from Base to enable message verification.
@@ -59,7 +235,9 @@ pub mod bridge {
     /// * `output_root`       - The 32-byte MMR root of Base messages for the given block
     /// * `base_block_number` - The Base block number this output root corresponds to
     /// * `total_leaf_count`  - The total number of leaves in the MMR with this root
-    /// * `signatures`        - A list of ECDSA signatures from authorized oracles attesting to the output root
+    /// * `signatures`        - A list of ECDSA signatures from authorized oracles attesting to the output root,
+    ///                         or empty when the signatures are verified by Secp256k1 program instructions
+    ///                         preceding this one in the transaction (requires `instructions_sysvar`)
     pub fn register_output_root(
         ctx: Context<RegisterOutputRoot>,
         output_root: [u8; 32],
@@ -76,9 +254,115 @@ pub mod bridge {
         )
     }
 
+    /// Registers several output roots from Base, covered by a single set of oracle signatures.
+    /// Lets the oracle register multiple block intervals in one transaction (e.g. after downtime).
+    /// The `OutputRoot` PDAs to create are passed as remaining accounts, in the order of `entries`.
+    ///
+    /// # Arguments
+    /// * `ctx`        - The context containing accounts for storing the output roots (payer signs for fees; authorization is provided via EVM signatures)
+    /// * `entries`    - The output roots to register, in strictly increasing Base block number order
+    /// * `signatures` - A list of ECDSA signatures from authorized oracles attesting to the whole batch
+    pub fn register_output_roots_batch<'a, 'info>(
+        ctx: Context<'a, '_, 'info, 'info, RegisterOutputRootsBatch<'info>>,
+        entries: Vec<OutputRootEntry>,
+        signatures: Vec<[u8; 65]>,
+    ) -> Result<()> {
+        register_output_roots_batch_handler(ctx, entries, signatures)
+    }
+
+    /// Replaces a registered output root within `OUTPUT_ROOT_CHALLENGE_WINDOW_SECONDS` of its
+    /// registration, e.g. after Base reorged below it, and increments its revision. Messages
+    /// proven against a superseded revision can no longer be relayed.
+    /// Authorization is enforced via EVM signatures from authorized Base oracles and partner
+    /// signers per configured thresholds over the new root and revision.
+    ///
+    /// # Arguments
+    /// * `ctx`               - The context containing the output root, the bridge and the partner config
+    /// * `output_root`       - The new 32-byte MMR root of Base messages for the given block
+    /// * `base_block_number` - The Base block number of the superseded output root
+    /// * `total_leaf_count`  - The total number of leaves in the MMR with the new root
+    /// * `signatures`        - A list of ECDSA signatures from authorized oracles attesting to the new root and revision
+    pub fn supersede_output_root(
+        ctx: Context<SupersedeOutputRoot>,
+        output_root: [u8; 32],
+        base_block_number: u64,
+        total_leaf_count: u64,
+        signatures: Vec<[u8; 65]>,
+    ) -> Result<()> {
+        supersede_output_root_handler(
+            ctx,
+            output_root,
+            base_block_number,
+            total_leaf_count,
+            signatures,
+        )
+    }
+
+    /// Permissionlessly reports Base oracle signers that attested two conflicting output roots
+    /// for the same Base block. Records their signatures over both roots in an
+    /// `EquivocationEvidence` PDA and removes them from the Base oracle signers pending guardian
+    /// review; the threshold is clamped to the remaining signers.
+    ///
+    /// # Arguments
+    /// * `ctx`               - The context containing the reporter paying for the evidence, the bridge,
+    ///                         the evidence account and the config history
+    /// * `first`             - The first attested output root
+    /// * `first_signatures`  - Oracle signatures over `first`
+    /// * `second`            - The conflicting output root attested for the same Base block
+    /// * `second_signatures` - Oracle signatures over `second`
+    pub fn report_equivocation(
+        ctx: Context<ReportEquivocation>,
+        first: OutputRootEntry,
+        first_signatures: Vec<[u8; 65]>,
+        second: OutputRootEntry,
+        second_signatures: Vec<[u8; 65]>,
+    ) -> Result<()> {
+        report_equivocation_handler(ctx, first, first_signatures, second, second_signatures)
+    }
+
+    /// Posts the oracle-attested Merkle root of the outstanding Base-side supply of the tokens
+    /// locked in the bridge vaults, against which `reconcile_vault` checks vault balances.
+    /// Authorization is enforced via EVM signatures from authorized Base oracles and partner
+    /// signers per configured thresholds; the Solana payer only funds account creation.
+    ///
+    /// # Arguments
+    /// * `ctx`               - The context containing the payer, the bridge and the liability root account
+    /// * `root`              - The Merkle root of the per-token liabilities
+    /// * `base_block_number` - The Base block number at which the liabilities were measured, newer than the current one
+    /// * `signatures`        - A list of ECDSA signatures from authorized oracles attesting to the root
+    pub fn post_liability_root(
+        ctx: Context<PostLiabilityRoot>,
+        root: [u8; 32],
+        base_block_number: u64,
+        signatures: Vec<[u8; 65]>,
+    ) -> Result<()> {
+        post_liability_root_handler(ctx, root, base_block_number, signatures)
+    }
+
+    /// Deposits the relay fees collected on Base into the relay fee vault, from which
+    /// `relay_message` pays the relayers of `WithRelayFee` messages.
+    /// Authorization is enforced via EVM signatures from authorized Base oracles and partner
+    /// signers per configured thresholds; the Solana payer provides the settled lamports.
+    ///
+    /// # Arguments
+    /// * `ctx`              - The context containing the payer, the bridge and the relay fee vault
+    /// * `settlement_nonce` - The nonce of the settlement, following the latest applied one
+    /// * `amount`           - The lamports deposited into the relay fee vault
+    /// * `signatures`       - A list of ECDSA signatures from authorized oracles attesting to the settlement
+    pub fn settle_relay_fees(
+        ctx: Context<SettleRelayFees>,
+        settlement_nonce: u64,
+        amount: u64,
+        signatures: Vec<[u8; 65]>,
+    ) -> Result<()> {
+        settle_relay_fees_handler(ctx, settlement_nonce, amount, signatures)
+    }
+
     /// Proves that a cross-chain message exists in the Base Bridge contract using an MMR proof.
     /// This function verifies the message was included in a previously registered output root
     /// and stores the proven message state for later relay execution.
+    /// `ReturnTransfer` and `ConfirmWrappedToken` messages are rejected unless sent by the Base
+    /// Bridge recorded in the address book.
     ///
     /// # Arguments
     /// * `ctx`          - The transaction context
@@ -140,6 +424,19 @@ pub mod bridge {
         append_to_prove_buffer_proof_handler(ctx, proof_chunk)
     }
 
+    /// Grows a prove buffer beyond the capacity it was initialized with, by at most 10 KiB per
+    /// call. Only the owner of the prove buffer can extend it and pays the added rent.
+    ///
+    /// # Arguments
+    /// * `ctx`            - The context containing the prove buffer account (owned by signer)
+    /// * `additional_len` - Number of bytes to add to the buffer account
+    pub fn extend_prove_buffer(
+        ctx: Context<ExtendProveBuffer>,
+        additional_len: u64,
+    ) -> Result<()> {
+        extend_prove_buffer_handler(ctx, additional_len)
+    }
+
     /// Closes a prove buffer account and returns the rent to the owner.
     /// Only the owner of the prove buffer can close it. This is useful if the user
     /// cannot complete proving and wants to recover the rent.
@@ -154,6 +451,8 @@ pub mod bridge {
     /// This function reads the serialized message and MMR proof from a `ProveBuffer`,
     /// verifies inclusion against a previously registered output root, and stores the
     /// proven message for later relay execution. The prove buffer is closed on success.
+    /// `ReturnTransfer` and `ConfirmWrappedToken` messages are rejected unless sent by the Base
+    /// Bridge recorded in the address book.
     ///
     /// # Arguments
     /// * `ctx`          - The context containing accounts for verification and message creation
@@ -169,9 +468,50 @@ pub mod bridge {
         prove_message_buffered_handler(ctx, nonce, sender, message_hash)
     }
 
+    /// Proves a cross-chain message from a `ProveBuffer` like `prove_message_buffered`, storing
+    /// its instructions in `ProvenChunk` accounts instead of the incoming message. This delivers
+    /// messages whose instructions exceed a single account or the per-message instruction limits.
+    /// The chunk PDAs are passed as remaining accounts, in index order.
+    ///
+    /// # Arguments
+    /// * `ctx`          - The context containing accounts for verification and message creation
+    /// * `nonce`        - Unique identifier for the cross-chain message
+    /// * `sender`       - The 20-byte Ethereum address that sent the message on Base
+    /// * `message_hash` - The 32-byte hash of the message for verification
+    pub fn prove_message_chunked<'a, 'info>(
+        ctx: Context<'a, '_, 'info, 'info, ProveMessageChunked<'info>>,
+        nonce: u64,
+        sender: [u8; 20],
+        message_hash: [u8; 32],
+    ) -> Result<()> {
+        prove_message_chunked_handler(ctx, nonce, sender, message_hash)
+    }
+
     /// Executes a previously proven cross-chain message on Solana.
     /// This function takes a message that has been proven via `prove_message` and executes
     /// its payload using a bridge CPI authority derived from the message sender.
+    /// `CallWithSigners` messages are additionally signed by PDAs derived from that authority
+    /// with the custom seeds declared in the message.
+    /// `WithComputeBudget` messages fail fast with `InsufficientComputeBudget` when fewer compute
+    /// units than they declare remain, letting relayers retry with a higher compute unit limit.
+    /// `WithRelayFee` messages pay their prepaid relay fee from the relay fee vault to the relayer.
+    /// `WithLamportAllowance` messages are lent their lamport allowance from the operational vault
+    /// for the duration of their execution; the lamports left unspent are returned to the vault.
+    /// Messages proven with `prove_message_chunked` take their `ProvenChunk` accounts as the last
+    /// remaining accounts, in index order; large chunked messages may require a larger heap frame.
+    /// Messages proven against an output root revision superseded since are rejected.
+    /// Once executed, the message payload is dropped and the freed rent refunded to its prover.
+    /// Transfers exceeding the outflow threshold of their token pause the bridge instead, holding
+    /// the message until the guardian approves its outflow with `approve_outflow`.
+    /// Transfers exceeding the finalization threshold of their token can only be relayed once the
+    /// finalization delay elapsed since they were proven, and never if the guardian vetoed them.
+    /// `ConfirmWrappedToken` messages record the `WrappedTokenConfirmation` of their mint once
+    /// the echoed Base token metadata matches it, from the address book, confirmation PDA, mint,
+    /// payer and system program remaining accounts.
+    /// `CallHandler` messages invoke the handler registered by the sender with `register_handler`,
+    /// passing the `HandlerCall` arguments after the handler discriminator. Their remaining
+    /// accounts are the handler registration, the handler program, then the handler accounts,
+    /// starting with the bridge CPI authority of the sender which signs the handler instruction.
     ///
     /// # Arguments
     /// * `ctx` - The transaction context
@@ -181,24 +521,95 @@ pub mod bridge {
         relay_message_handler(ctx)
     }
 
+    /// Mints wrapped tokens on behalf of the Base Bridge, the Solana counterpart of the ERC-7802
+    /// `crosschainMint` function. Only reachable through `relay_message` relaying a message sent
+    /// by the Base Bridge, whose bridge CPI authority must sign.
+    /// Emits `CrosschainMint`, as do wrapped token transfers from Base, while wrapped tokens
+    /// bridged back to Base emit `CrosschainBurn`.
+    ///
+    /// # Arguments
+    /// * `ctx`    - The context containing the bridge CPI authority, the wrapped mint and the
+    ///   recipient token account
+    /// * `amount` - The amount of wrapped tokens to mint
+    pub fn crosschain_mint(ctx: Context<RelayedCrosschainMint>, amount: u64) -> Result<()> {
+        crosschain_mint_handler(ctx, amount)
+    }
+
+    /// Mirrors the max supply of a wrapped token set on Base. Only reachable through
+    /// `relay_message` relaying a message sent by the Base Bridge, whose bridge CPI authority must
+    /// sign and pays for the supply cap account on first use. Mints of the wrapped token, both
+    /// wrapped token transfers and `crosschain_mint`, are then rejected past the max supply.
+    /// Emits `WrappedTokenMaxSupplyUpdated`.
+    ///
+    /// # Arguments
+    /// * `ctx`        - The context containing the bridge CPI authority, the wrapped mint and its
+    ///   supply cap account
+    /// * `max_supply` - The max supply of the wrapped token, or `None` to lift the cap
+    pub fn set_wrapped_token_max_supply(
+        ctx: Context<RelayedSetWrappedTokenMaxSupply>,
+        max_supply: Option<u64>,
+    ) -> Result<()> {
+        set_wrapped_token_max_supply_handler(ctx, max_supply)
+    }
+
+    /// Registers the handler invoked with the payload of the `CallHandler` messages of `sender`,
+    /// replacing any previous registration. Only reachable through `relay_message` relaying a
+    /// message sent by `sender`, whose bridge CPI authority must sign and pays for the handler
+    /// registration account on first use. The bridge and the privileged programs denied to relayed
+    /// messages cannot be registered. Emits `HandlerRegistered`.
+    ///
+    /// # Arguments
+    /// * `ctx`           - The context containing the bridge CPI authority and the handler
+    ///   registration account
+    /// * `sender`        - The Base address registering the handler
+    /// * `program_id`    - The program invoked with the payload of the messages of `sender`
+    /// * `discriminator` - The discriminator of the handler instruction
+    pub fn register_handler(
+        ctx: Context<RelayedRegisterHandler>,
+        sender: [u8; 20],
+        program_id: Pubkey,
+        discriminator: [u8; 8],
+    ) -> Result<()> {
+        register_handler_handler(ctx, sender, program_id, discriminator)
+    }
+
+    /// Permissionlessly closes an unexecuted message proven against a superseded output root
+    /// revision, together with its `ProvenChunk` accounts passed as remaining accounts in index
+    /// order, so that the message can be proven again against the current revision.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the prover receiving the rent, the message and its output root
+    pub fn close_superseded_message<'a, 'info>(
+        ctx: Context<'a, '_, 'info, 'info, CloseSupersededMessage<'info>>,
+    ) -> Result<()> {
+        close_superseded_message_handler(ctx)
+    }
+
     // Solana -> Base
 
     /// Creates a wrapped version of a Base token.
     /// This function creates a new SPL mint account on Solana that represents the Base token,
     /// enabling users to bridge the token between the two chains. It will also trigger a message
     /// to Base to register the wrapped token in the Base Bridge contract.
+    /// If the optional Metaplex accounts are provided, a Metaplex metadata account mirroring
+    /// the token name and symbol is also created for wallets that don't read Token-2022 metadata.
     ///
     /// # Arguments
     /// * `ctx`                    - The transaction context
     /// * `outgoing_message_salt`  - The salt for the outgoing message account
     /// * `decimals`               - Number of decimal places for the token
     /// * `partial_token_metadata` - Token name, symbol, remote Base token address, and scaler exponent
+    ///
+    /// # Returns
+    /// * `WrapTokenResult::Wrapped` with the new mint, or `WrapTokenResult::AlreadyWrapped` with
+    ///   the existing mint, without sending a duplicate registration message, if the token was
+    ///   already wrapped with the same decimals and metadata
     pub fn wrap_token(
         ctx: Context<WrapToken>,
         outgoing_message_salt: [u8; 32],
         decimals: u8,
         partial_token_metadata: PartialTokenMetadata,
-    ) -> Result<()> {
+    ) -> Result<WrapTokenResult> {
         wrap_token_handler(ctx, outgoing_message_salt, decimals, partial_token_metadata)
     }
 
@@ -210,26 +621,57 @@ pub mod bridge {
     /// * `ctx`                   - The context containing accounts for the bridge operation
     /// * `outgoing_message_salt` - The salt for the outgoing message account
     /// * `call`                  - The contract call details including call type, target address, value, and calldata
+    /// * `spl_memo`              - Whether to log an SPL memo summarizing the operation, requires the SPL Memo program account
+    ///
+    /// # Returns
+    /// * The `OutgoingMessageReceipt` with the assigned nonce and the fees charged
     pub fn bridge_call(
         ctx: Context<BridgeCall>,
         outgoing_message_salt: [u8; 32],
         call: Call,
-    ) -> Result<()> {
-        bridge_call_handler(ctx, outgoing_message_salt, call)
+        spl_memo: bool,
+    ) -> Result<OutgoingMessageReceipt> {
+        bridge_call_handler(ctx, outgoing_message_salt, call, spl_memo)
+    }
+
+    /// Initiates a cross-chain function call from Solana to Base with encrypted calldata.
+    /// The ciphertext is stored as-is and decrypted off-chain by the Base-side executor holding
+    /// the key; it is charged ENCRYPTED_CALL_GAS_PER_BYTE gas per byte on top of the call gas.
+    ///
+    /// # Arguments
+    /// * `ctx`                   - The context containing accounts for the bridge operation
+    /// * `outgoing_message_salt` - The salt for the outgoing message account
+    /// * `call`                  - The contract call details, whose `data` is the ciphertext of the calldata
+    /// * `spl_memo`              - Whether to log an SPL memo summarizing the operation, requires the SPL Memo program account
+    ///
+    /// # Returns
+    /// * The `OutgoingMessageReceipt` with the assigned nonce and the fees charged
+    pub fn bridge_encrypted_call(
+        ctx: Context<BridgeCall>,
+        outgoing_message_salt: [u8; 32],
+        call: Call,
+        spl_memo: bool,
+    ) -> Result<OutgoingMessageReceipt> {
+        bridge_encrypted_call_handler(ctx, outgoing_message_salt, call, spl_memo)
     }
 
     /// Bridges a call using data from a call buffer account.
-    /// This instruction consumes the call buffer and creates an outgoing message
-    /// for execution on Base.
+    /// This instruction consumes the call buffer, unless it is reusable, and creates an outgoing
+    /// message for execution on Base.
     ///
     /// # Arguments
     /// * `ctx`                   - The context containing accounts for the bridge operation
     /// * `outgoing_message_salt` - The salt for the outgoing message account
+    /// * `spl_memo`              - Whether to log an SPL memo summarizing the operation, requires the SPL Memo program account
+    ///
+    /// # Returns
+    /// * The `OutgoingMessageReceipt` with the assigned nonce and the fees charged
     pub fn bridge_call_buffered<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, BridgeCallBuffered<'info>>,
         outgoing_message_salt: [u8; 32],
-    ) -> Result<()> {
-        bridge_call_buffered_handler(ctx, outgoing_message_salt)
+        spl_memo: bool,
+    ) -> Result<OutgoingMessageReceipt> {
+        bridge_call_buffered_handler(ctx, outgoing_message_salt, spl_memo)
     }
 
     /// Bridges native SOL tokens from Solana to Base.
@@ -240,16 +682,36 @@ pub mod bridge {
     /// * `ctx`                   - The context containing accounts for the SOL bridge operation
     /// * `outgoing_message_salt` - The salt for the outgoing message account
     /// * `to`                    - The 20-byte Ethereum address that will receive tokens on Base
-    /// * `amount`                - Amount of SOL to bridge (in lamports)
+    /// * `amount`                - Amount of SOL to bridge (in lamports), including the protocol fee
     /// * `call`                  - Optional additional contract call to execute with the token transfer
+    /// * `memo`                  - Optional payment memo of at most MAX_TRANSFER_MEMO_LEN bytes, relayed to Base and emitted in `TransferMemo`
+    /// * `swap_guard`            - Optional minimum output and deadline enforced on Base around `call`, required when it targets a
+    ///                             router of the DEX router list
+    /// * `spl_memo`              - Whether to log an SPL memo summarizing the operation, requires the SPL Memo program account
+    ///
+    /// # Returns
+    /// * The `OutgoingMessageReceipt` with the assigned nonce and the fees charged
+    #[allow(clippy::too_many_arguments)]
     pub fn bridge_sol(
         ctx: Context<BridgeSol>,
         outgoing_message_salt: [u8; 32],
         to: [u8; 20],
         amount: u64,
         call: Option<Call>,
-    ) -> Result<()> {
-        bridge_sol_handler(ctx, outgoing_message_salt, to, amount, call)
+        memo: Option<Vec<u8>>,
+        swap_guard: Option<SwapGuard>,
+        spl_memo: bool,
+    ) -> Result<OutgoingMessageReceipt> {
+        bridge_sol_handler(
+            ctx,
+            outgoing_message_salt,
+            to,
+            amount,
+            call,
+            memo,
+            swap_guard,
+            spl_memo,
+        )
     }
 
     /// Bridges native SOL tokens from Solana to Base with a call using buffered data.
@@ -260,14 +722,39 @@ pub mod bridge {
     /// * `ctx`                   - The context containing accounts for the SOL bridge operation
     /// * `outgoing_message_salt` - The salt for the outgoing message account
     /// * `to`                    - The 20-byte Ethereum address that will receive tokens on Base
-    /// * `amount`                - Amount of SOL to bridge (in lamports)
+    /// * `amount`                - Amount of SOL to bridge (in lamports), including the protocol fee
+    /// * `spl_memo`              - Whether to log an SPL memo summarizing the operation, requires the SPL Memo program account
+    ///
+    /// # Returns
+    /// * The `OutgoingMessageReceipt` with the assigned nonce and the fees charged
     pub fn bridge_sol_with_buffered_call<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, BridgeSolWithBufferedCall<'info>>,
         outgoing_message_salt: [u8; 32],
         to: [u8; 20],
         amount: u64,
-    ) -> Result<()> {
-        bridge_sol_with_buffered_call_handler(ctx, outgoing_message_salt, to, amount)
+        spl_memo: bool,
+    ) -> Result<OutgoingMessageReceipt> {
+        bridge_sol_with_buffered_call_handler(ctx, outgoing_message_salt, to, amount, spl_memo)
+    }
+
+    /// Bridges native SOL tokens from Solana to several recipients on Base at once.
+    /// This function locks the SOL of every recipient on Solana in a single transfer and initiates
+    /// a single message to mint the equivalent tokens to each recipient on Base.
+    ///
+    /// # Arguments
+    /// * `ctx`                   - The context containing accounts for the SOL batch bridge operation
+    /// * `outgoing_message_salt` - The salt for the outgoing message account
+    /// * `recipients`            - The Base recipients and the amount of SOL (in lamports) each of them receives,
+    ///                             including the protocol fee; at most MAX_TRANSFER_BATCH_RECIPIENTS
+    ///
+    /// # Returns
+    /// * The `OutgoingMessageReceipt` with the assigned nonce and the fees charged
+    pub fn bridge_sol_batch(
+        ctx: Context<BridgeSolBatch>,
+        outgoing_message_salt: [u8; 32],
+        recipients: Vec<BatchRecipient>,
+    ) -> Result<OutgoingMessageReceipt> {
+        bridge_sol_batch_handler(ctx, outgoing_message_salt, recipients)
     }
 
     /// Bridges SPL tokens from Solana to Base.
@@ -279,8 +766,21 @@ pub mod bridge {
     /// * `outgoing_message_salt` - The salt for the outgoing message account
     /// * `to`                    - The 20-byte Ethereum address that will receive tokens on Base
     /// * `remote_token`          - The 20-byte address of the ERC20 token contract on Base
-    /// * `amount`                - Amount of SPL tokens to bridge (in the token's smallest units)
+    /// * `amount`                - Amount of SPL tokens to bridge (in the token's smallest units), including the protocol fee,
+    ///                             or `BRIDGE_FULL_BALANCE` to bridge the whole balance of `from_token_account`
     /// * `call`                  - Optional additional contract call to execute with the token transfer
+    /// * `memo`                  - Optional payment memo of at most MAX_TRANSFER_MEMO_LEN bytes, relayed to Base and emitted in `TransferMemo`
+    /// * `swap_guard`            - Optional minimum output and deadline enforced on Base around `call`, required when it targets a
+    ///                             router of the DEX router list
+    /// * `spl_memo`              - Whether to log an SPL memo summarizing the operation, requires the SPL Memo program account
+    ///
+    /// The mint must be owned by `token_program` and, once the guardian configured a mint policy,
+    /// its freeze authority must be unset or allowed by the policy. During guarded launch, the mint
+    /// must also be on the guardian's mint allow-list.
+    #[allow(clippy::too_many_arguments)]
+    ///
+    /// # Returns
+    /// * The `OutgoingMessageReceipt` with the assigned nonce and the fees charged
     pub fn bridge_spl(
         ctx: Context<BridgeSpl>,
         outgoing_message_salt: [u8; 32],
@@ -288,8 +788,21 @@ pub mod bridge {
         remote_token: [u8; 20],
         amount: u64,
         call: Option<Call>,
-    ) -> Result<()> {
-        bridge_spl_handler(ctx, outgoing_message_salt, to, remote_token, amount, call)
+        memo: Option<Vec<u8>>,
+        swap_guard: Option<SwapGuard>,
+        spl_memo: bool,
+    ) -> Result<OutgoingMessageReceipt> {
+        bridge_spl_handler(
+            ctx,
+            outgoing_message_salt,
+            to,
+            remote_token,
+            amount,
+            call,
+            memo,
+            swap_guard,
+            spl_memo,
+        )
     }
 
     /// Bridges SPL tokens from Solana to Base with a call using buffered data.
@@ -301,53 +814,143 @@ pub mod bridge {
     /// * `outgoing_message_salt` - The salt for the outgoing message account
     /// * `to`                    - The 20-byte Ethereum address that will receive tokens on Base
     /// * `remote_token`          - The 20-byte address of the ERC20 token contract on Base
-    /// * `amount`                - Amount of SPL tokens to bridge (in the token's smallest units)
+    /// * `amount`                - Amount of SPL tokens to bridge (in the token's smallest units), including the protocol fee,
+    ///                             or `BRIDGE_FULL_BALANCE` to bridge the whole balance of `from_token_account`
+    /// * `spl_memo`              - Whether to log an SPL memo summarizing the operation, requires the SPL Memo program account
+    ///
+    /// # Returns
+    /// * The `OutgoingMessageReceipt` with the assigned nonce and the fees charged
     pub fn bridge_spl_with_buffered_call<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, BridgeSplWithBufferedCall<'info>>,
         outgoing_message_salt: [u8; 32],
         to: [u8; 20],
         remote_token: [u8; 20],
         amount: u64,
-    ) -> Result<()> {
-        bridge_spl_with_buffered_call_handler(ctx, outgoing_message_salt, to, remote_token, amount)
+        spl_memo: bool,
+    ) -> Result<OutgoingMessageReceipt> {
+        bridge_spl_with_buffered_call_handler(
+            ctx,
+            outgoing_message_salt,
+            to,
+            remote_token,
+            amount,
+            spl_memo,
+        )
     }
 
     /// Bridges wrapped tokens from Solana back to their native form on Base.
     /// This function burns wrapped tokens on Solana and initiates a message to release
     /// or mint the original tokens on Base for the specified recipient.
+    /// The burn is recorded in an immutable `BurnReceipt` derived from the outgoing message.
     ///
     /// # Arguments
     /// * `ctx`                   - The context containing accounts for the wrapped token bridge operation
     /// * `outgoing_message_salt` - The salt for the outgoing message account
     /// * `to`                    - The 20-byte Ethereum address that will receive the original tokens on Base
-    /// * `amount`                - Amount of wrapped tokens to bridge back (in the token's smallest units)
+    /// * `amount`                - Amount of wrapped tokens to bridge back (in the token's smallest units), including the protocol fee,
+    ///                             or `BRIDGE_FULL_BALANCE` to bridge the whole balance of `from_token_account`
     /// * `call`                  - Optional additional contract call to execute with the token transfer
+    /// * `memo`                  - Optional payment memo of at most MAX_TRANSFER_MEMO_LEN bytes, relayed to Base and emitted in `TransferMemo`
+    /// * `swap_guard`            - Optional minimum output and deadline enforced on Base around `call`, required when it targets a
+    ///                             router of the DEX router list
+    /// * `spl_memo`              - Whether to log an SPL memo summarizing the operation, requires the SPL Memo program account
+    ///
+    /// # Returns
+    /// * The `OutgoingMessageReceipt` with the assigned nonce and the fees charged
+    #[allow(clippy::too_many_arguments)]
     pub fn bridge_wrapped_token(
         ctx: Context<BridgeWrappedToken>,
         outgoing_message_salt: [u8; 32],
         to: [u8; 20],
         amount: u64,
         call: Option<Call>,
-    ) -> Result<()> {
-        bridge_wrapped_token_handler(ctx, outgoing_message_salt, to, amount, call)
+        memo: Option<Vec<u8>>,
+        swap_guard: Option<SwapGuard>,
+        spl_memo: bool,
+    ) -> Result<OutgoingMessageReceipt> {
+        bridge_wrapped_token_handler(
+            ctx,
+            outgoing_message_salt,
+            to,
+            amount,
+            call,
+            memo,
+            swap_guard,
+            spl_memo,
+        )
+    }
+
+    /// Returns the big-endian u256 amount credited on Base for `amount` of a wrapped token,
+    /// scaled by the `scaler_exponent` of its metadata. Fails when the scaled amount overflows.
+    ///
+    /// # Arguments
+    /// * `ctx`    - The context containing the wrapped token mint
+    /// * `amount` - Amount of wrapped tokens (in the token's smallest units)
+    pub fn scale_wrapped_token_amount(
+        ctx: Context<ScaleWrappedTokenAmount>,
+        amount: u64,
+    ) -> Result<[u8; 32]> {
+        scale_wrapped_token_amount_handler(ctx, amount)
     }
 
     /// Bridges wrapped tokens from Solana back to Base with a call using buffered data.
     /// This function burns wrapped tokens on Solana and initiates a message to release
     /// the original tokens on Base, then executes a call using data from a call buffer.
+    /// The burn is recorded in an immutable `BurnReceipt` derived from the outgoing message.
     ///
     /// # Arguments
     /// * `ctx`                   - The context containing accounts for the wrapped token bridge operation
     /// * `outgoing_message_salt` - The salt for the outgoing message account
     /// * `to`                    - The 20-byte Ethereum address that will receive tokens on Base
-    /// * `amount`                - Amount of wrapped tokens to bridge back (in the token's smallest units)
+    /// * `amount`                - Amount of wrapped tokens to bridge back (in the token's smallest units), including the protocol fee,
+    ///                             or `BRIDGE_FULL_BALANCE` to bridge the whole balance of `from_token_account`
+    /// * `spl_memo`              - Whether to log an SPL memo summarizing the operation, requires the SPL Memo program account
+    ///
+    /// # Returns
+    /// * The `OutgoingMessageReceipt` with the assigned nonce and the fees charged
     pub fn bridge_wrapped_token_with_buffered_call<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, BridgeWrappedTokenWithBufferedCall<'info>>,
         outgoing_message_salt: [u8; 32],
         to: [u8; 20],
         amount: u64,
+        spl_memo: bool,
+    ) -> Result<OutgoingMessageReceipt> {
+        bridge_wrapped_token_with_buffered_call_handler(
+            ctx,
+            outgoing_message_salt,
+            to,
+            amount,
+            spl_memo,
+        )
+    }
+
+    /// Creates or replaces the bridge session authorizing `delegate` to bridge the owner's SPL
+    /// and wrapped tokens through `bridge_spl` and `bridge_wrapped_token` without the owner's
+    /// signature. The owner must approve the session PDA as SPL delegate of the bridged token
+    /// accounts.
+    ///
+    /// # Arguments
+    /// * `ctx`                   - The context containing the owner and the session account
+    /// * `delegate`              - The key allowed to bridge on behalf of the owner
+    /// * `budget`                - Total amount that can be bridged through the session (in the tokens' smallest units), including protocol fees
+    /// * `expires_at`            - Unix timestamp from which the session can no longer be used
+    /// * `allowed_remote_tokens` - The Base tokens the session can bridge to, at most MAX_SESSION_REMOTE_TOKENS
+    pub fn create_bridge_session(
+        ctx: Context<CreateBridgeSession>,
+        delegate: Pubkey,
+        budget: u64,
+        expires_at: i64,
+        allowed_remote_tokens: Vec<[u8; 20]>,
     ) -> Result<()> {
-        bridge_wrapped_token_with_buffered_call_handler(ctx, outgoing_message_salt, to, amount)
+        create_bridge_session_handler(ctx, delegate, budget, expires_at, allowed_remote_tokens)
+    }
+
+    /// Revokes a bridge session, returning its rent to the owner.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the owner and the session account
+    pub fn revoke_bridge_session(ctx: Context<RevokeBridgeSession>) -> Result<()> {
+        revoke_bridge_session_handler(ctx)
     }
 
     /// Initializes a call buffer account that can store large call data.
@@ -392,6 +995,108 @@ pub mod bridge {
         close_call_buffer_handler(ctx)
     }
 
+    /// Permissionlessly closes a call buffer that has not been modified for longer than the
+    /// configured TTL. Most of the rent is returned to the owner and a bounty is paid to the caller.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the caller, the call buffer to expire and its owner
+    pub fn expire_call_buffer(ctx: Context<ExpireCallBuffer>) -> Result<()> {
+        expire_call_buffer_handler(ctx)
+    }
+
+    /// Marks a call buffer as reusable, so that the buffered bridge instructions read it without
+    /// closing it, or back as single-use. Only the owner of the call buffer can update it, and
+    /// reusable buffers cannot be appended to.
+    ///
+    /// # Arguments
+    /// * `ctx`      - The context containing the call buffer and its owner
+    /// * `reusable` - Whether the buffer is kept after being bridged
+    pub fn set_call_buffer_reusable(
+        ctx: Context<SetCallBufferReusable>,
+        reusable: bool,
+    ) -> Result<()> {
+        set_call_buffer_reusable_handler(ctx, reusable)
+    }
+
+    /// Copies a call buffer into a new single-use call buffer owned by the payer. Reusable
+    /// buffers can be copied by any account; other buffers only by their owner.
+    ///
+    /// # Arguments
+    /// * `ctx`          - The context containing the source buffer and the new call buffer
+    /// * `max_data_len` - Maximum total length of data that will be stored in the copy
+    pub fn clone_call_buffer(ctx: Context<CloneCallBuffer>, max_data_len: u64) -> Result<()> {
+        clone_call_buffer_handler(ctx, max_data_len)
+    }
+
+    /// Records outgoing message nonces executed on Base in the relayed nonces registry.
+    /// Base may execute messages out of order, so each nonce is tracked individually in a bitmap
+    /// account covering `RELAYED_NONCES_PER_ACCOUNT` nonces.
+    /// Authorization is enforced via EVM signatures from authorized Base oracles and partner
+    /// signers per configured thresholds; the Solana payer only funds account creation.
+    ///
+    /// # Arguments
+    /// * `ctx`        - The context containing the payer, the bridge and the registry account for `page`
+    /// * `page`       - The registry page covering every confirmed nonce
+    /// * `nonces`     - The outgoing message nonces executed on Base
+    /// * `signatures` - A list of ECDSA signatures from authorized oracles attesting to the nonces
+    pub fn confirm_relayed(
+        ctx: Context<ConfirmRelayed>,
+        page: u64,
+        nonces: Vec<u64>,
+        signatures: Vec<[u8; 65]>,
+    ) -> Result<()> {
+        confirm_relayed_handler(ctx, page, nonces, signatures)
+    }
+
+    /// Records the relayer that executed an outgoing message on Base, so that relay tips escrowed
+    /// for the message can be settled to it. The record of a nonce is created once and never
+    /// changes.
+    /// Authorization is enforced via EVM signatures from authorized Base oracles and partner
+    /// signers per configured thresholds; the Solana payer only funds account creation.
+    ///
+    /// # Arguments
+    /// * `ctx`        - The context containing the payer, the bridge and the record account for `nonce`
+    /// * `nonce`      - The outgoing message nonce executed on Base
+    /// * `relayer`    - The Solana account of the relayer that executed the message
+    /// * `signatures` - A list of ECDSA signatures from authorized oracles attesting to the relayer
+    pub fn record_relay_execution(
+        ctx: Context<RecordRelayExecution>,
+        nonce: u64,
+        relayer: Pubkey,
+        signatures: Vec<[u8; 65]>,
+    ) -> Result<()> {
+        record_relay_execution_handler(ctx, nonce, relayer, signatures)
+    }
+
+    /// Permissionlessly closes an outgoing message once the relayed nonces registry confirms it
+    /// was executed on Base, returning its rent to the message sender.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the outgoing message, its sender and the registry account
+    pub fn close_relayed_outgoing_message(
+        ctx: Context<CloseRelayedOutgoingMessage>,
+    ) -> Result<()> {
+        close_relayed_outgoing_message_handler(ctx)
+    }
+
+    /// Withdraws the SOL locked by an outgoing message not relayed to Base back to its sender,
+    /// once the emergency exit timelock of a paused bridge elapsed. Closes the message.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the outgoing message, its sender and the SOL vault
+    pub fn emergency_withdraw_sol(ctx: Context<EmergencyWithdrawSol>) -> Result<()> {
+        emergency_withdraw_sol_handler(ctx)
+    }
+
+    /// Withdraws the SPL tokens locked by an outgoing message not relayed to Base back to its
+    /// sender, once the emergency exit timelock of a paused bridge elapsed. Closes the message.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the outgoing message, its sender and the token vault
+    pub fn emergency_withdraw_spl(ctx: Context<EmergencyWithdrawSpl>) -> Result<()> {
+        emergency_withdraw_spl_handler(ctx)
+    }
+
     /// Transfer guardian authority to a new pubkey
     /// Only the current guardian can call this function
     ///
@@ -419,6 +1124,57 @@ pub mod bridge {
         set_oracle_signers_handler(ctx, cfg)
     }
 
+    /// Sets the Base oracle signers from the Base governance contract
+    /// Only reachable through a message relayed from the Base governance contract
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge and the governance bridge CPI authority
+    /// * `cfg` - Configuration parameters for Base oracle signers
+    pub fn set_oracle_signers_from_governance(
+        ctx: Context<SetBridgeConfigFromBaseGovernance>,
+        cfg: BaseOracleConfig,
+    ) -> Result<()> {
+        set_oracle_signers_from_governance_handler(ctx, cfg)
+    }
+
+    /// Pauses the bridge from the Base governance contract
+    /// Only reachable through a message relayed from the Base governance contract
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge and the governance bridge CPI authority
+    pub fn pause_from_governance(ctx: Context<SetBridgeConfigFromBaseGovernance>) -> Result<()> {
+        pause_from_governance_handler(ctx)
+    }
+
+    /// Designates the Base governance contract allowed to update the critical configuration
+    /// (the Base oracle signers and the pause) through relayed messages
+    /// Only the upgrade authority can call this function, until the governance contract enforces
+    /// its control with `set_governance_mode`
+    ///
+    /// # Arguments
+    /// * `ctx`        - The context containing the bridge and upgrade authority
+    /// * `governance` - The governance contract on Base
+    pub fn set_base_governance(
+        ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
+        governance: [u8; 20],
+    ) -> Result<()> {
+        set_base_governance_handler(ctx, governance)
+    }
+
+    /// Enforces or releases the control of the Base governance contract over the critical
+    /// configuration. While enforced, `set_oracle_signers` and `set_base_governance` are rejected
+    /// Only reachable through a message relayed from the Base governance contract
+    ///
+    /// # Arguments
+    /// * `ctx`      - The context containing the bridge and the governance bridge CPI authority
+    /// * `enforced` - Whether only the governance contract can update the critical configuration
+    pub fn set_governance_mode(
+        ctx: Context<SetBridgeConfigFromBaseGovernance>,
+        enforced: bool,
+    ) -> Result<()> {
+        set_governance_mode_handler(ctx, enforced)
+    }
+
     // EIP-1559 Configuration Management
 
     /// Set the minimum base fee for EIP-1559 pricing
@@ -473,6 +1229,62 @@ pub mod bridge {
         set_adjustment_denominator_handler(ctx, new_denominator)
     }
 
+    /// Set the maximum base fee for EIP-1559 pricing (0 disables the cap)
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and guardian
+    /// * `new_max_fee` - The new maximum base fee value
+    pub fn set_max_base_fee(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        new_max_fee: u64,
+    ) -> Result<()> {
+        set_max_base_fee_handler(ctx, new_max_fee)
+    }
+
+    /// Set the maximum per-window base fee increase for EIP-1559 pricing (0 disables the cap)
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and guardian
+    /// * `new_bps` - The new maximum increase, in basis points of the current base fee
+    pub fn set_max_window_increase_bps(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        new_bps: u64,
+    ) -> Result<()> {
+        set_max_window_increase_bps_handler(ctx, new_bps)
+    }
+
+    /// Set the EIP-1559 configuration of the gas lane pricing token transfers apart from calls,
+    /// so that demand in one lane does not distort pricing in the other (a zero target makes
+    /// transfers share the base fee of calls again)
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and guardian
+    /// * `new_config` - The new transfer gas lane configuration
+    pub fn set_transfer_gas_lane(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        new_config: Eip1559Config,
+    ) -> Result<()> {
+        set_transfer_gas_lane_handler(ctx, new_config)
+    }
+
+    /// Set the grace period during which gas fee quotes remain valid after an EIP-1559 window
+    /// rollover, messages being charged the lower of the current and previous base fees meanwhile
+    /// (0 disables the grace period)
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and guardian
+    /// * `new_grace_period_seconds` - The new grace period, capped at the window duration
+    pub fn set_fee_quote_grace_period(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        new_grace_period_seconds: u64,
+    ) -> Result<()> {
+        set_fee_quote_grace_period_handler(ctx, new_grace_period_seconds)
+    }
+
     /// Set the gas cost scaler for Gas Cost Config
     /// Only the guardian can call this function
     ///
@@ -500,13 +1312,13 @@ pub mod bridge {
     }
 
     /// Set the gas fee receiver for Gas Cost Config
-    /// Only the guardian can call this function
+    /// Only the guardian can call this function. The new receiver must be a system account.
     ///
     /// # Arguments
-    /// * `ctx` - The context containing the bridge account and guardian
+    /// * `ctx` - The context containing the bridge account, guardian and new gas fee receiver account
     /// * `new_receiver` - The new gas fee receiver
     pub fn set_gas_fee_receiver(
-        ctx: Context<SetBridgeConfigFromGuardian>,
+        ctx: Context<SetGasFeeReceiver>,
         new_receiver: Pubkey,
     ) -> Result<()> {
         set_gas_fee_receiver_handler(ctx, new_receiver)
@@ -522,6 +1334,18 @@ pub mod bridge {
         set_gas_per_call_handler(ctx, new_val)
     }
 
+    /// Set every pricing parameter shared with the relayer program (EIP-1559 controller, gas
+    /// cost scaler and gas fee receiver) at once. `gas_per_call` is left unchanged.
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`        - The context containing the bridge account, guardian and new gas fee
+    ///                  receiver account
+    /// * `fee_config` - The new fee configuration
+    pub fn set_fee_config(ctx: Context<SetFeeConfig>, fee_config: FeeConfigV2) -> Result<()> {
+        set_fee_config_handler(ctx, fee_config)
+    }
+
     /// Set the block interval requirement for Protocol Config
     /// Only the guardian can call this function
     ///
@@ -548,6 +1372,32 @@ pub mod bridge {
         set_max_call_buffer_size_handler(ctx, new_size)
     }
 
+    /// Set the call buffer TTL for Buffer Config (0 disables call buffer expiry)
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and guardian
+    /// * `new_ttl_seconds` - The new idle time, in seconds, after which a call buffer can be expired
+    pub fn set_call_buffer_ttl(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        new_ttl_seconds: u64,
+    ) -> Result<()> {
+        set_call_buffer_ttl_handler(ctx, new_ttl_seconds)
+    }
+
+    /// Set the expire bounty for Buffer Config
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and guardian
+    /// * `new_bps` - The new share of an expired call buffer's rent paid to the caller, in basis points
+    pub fn set_expire_bounty_bps(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        new_bps: u64,
+    ) -> Result<()> {
+        set_expire_bounty_bps_handler(ctx, new_bps)
+    }
+
     /// Set the pause status for the bridge
     /// Only the guardian can call this function
     ///
@@ -572,4 +1422,381 @@ pub mod bridge {
     ) -> Result<()> {
         set_partner_config_handler(ctx, new_config)
     }
+
+    /// Set the oracle failover configuration
+    /// Only the upgrade authority can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`                        - The context containing the bridge account and upgrade authority
+    /// * `stall_seconds`              - Seconds without a new output root before the guardian can
+    ///                                  activate the emergency quorum (0 disables it)
+    /// * `emergency_duration_seconds` - Seconds the emergency quorum stays active
+    pub fn set_oracle_failover_config(
+        ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
+        stall_seconds: u64,
+        emergency_duration_seconds: u64,
+    ) -> Result<()> {
+        set_oracle_failover_config_handler(ctx, stall_seconds, emergency_duration_seconds)
+    }
+
+    /// Temporarily lower the Base oracle threshold and accept emergency signers after output
+    /// roots stalled. Expires automatically after the configured emergency duration.
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`               - The context containing the bridge account and guardian
+    /// * `threshold`         - Required unique Base oracle or emergency signer approvals
+    /// * `emergency_signers` - EVM addresses accepted in addition to the Base oracle signers
+    pub fn activate_oracle_emergency(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        threshold: u8,
+        emergency_signers: Vec<[u8; 20]>,
+    ) -> Result<()> {
+        activate_oracle_emergency_handler(ctx, threshold, emergency_signers)
+    }
+
+    /// Deactivate the oracle emergency quorum before it expires
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the bridge account and guardian
+    pub fn deactivate_oracle_emergency(ctx: Context<SetBridgeConfigFromGuardian>) -> Result<()> {
+        deactivate_oracle_emergency_handler(ctx)
+    }
+
+    /// Set the maximum age, in Base blocks, of the output roots each message kind can be proven
+    /// against (0 accepts any root)
+    /// Only the upgrade authority can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`        - The context containing the bridge account and upgrade authority
+    /// * `new_config` - The new root freshness configuration
+    pub fn set_root_freshness_config(
+        ctx: Context<SetBridgeConfigFromUpgradeAuthority>,
+        new_config: RootFreshnessConfig,
+    ) -> Result<()> {
+        set_root_freshness_config_handler(ctx, new_config)
+    }
+
+    /// Clear the solvency alert raised by `reconcile_vault`
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the liability root, bridge account and guardian
+    pub fn clear_solvency_alert(ctx: Context<ClearSolvencyAlert>) -> Result<()> {
+        clear_solvency_alert_handler(ctx)
+    }
+
+    /// Set the canonical Base addresses stored in the address book, creating it on first use
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`                  - The context containing the address book, bridge account and guardian
+    /// * `bridge`               - The Base Bridge contract address
+    /// * `relayer_orchestrator` - The Base RelayerOrchestrator contract address
+    /// * `tokens`               - The standard tokens and their Base addresses
+    pub fn set_address_book(
+        ctx: Context<SetAddressBook>,
+        bridge: [u8; 20],
+        relayer_orchestrator: [u8; 20],
+        tokens: Vec<AddressBookToken>,
+    ) -> Result<()> {
+        set_address_book_handler(ctx, bridge, relayer_orchestrator, tokens)
+    }
+
+    /// Set the protocol fee skimmed from bridged token amounts, creating its config on first use
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`         - The context containing the protocol fee config, bridge account and guardian
+    /// * `treasury`    - The treasury collecting SOL fees and owning the token accounts collecting token fees
+    /// * `default_bps` - The protocol fee in basis points applied to tokens without an override
+    /// * `overrides`   - The per-token protocol fees taking precedence over `default_bps`
+    pub fn set_protocol_fee_config(
+        ctx: Context<SetProtocolFeeConfig>,
+        treasury: Pubkey,
+        default_bps: u16,
+        overrides: Vec<ProtocolFeeOverride>,
+    ) -> Result<()> {
+        set_protocol_fee_config_handler(ctx, treasury, default_bps, overrides)
+    }
+
+    /// Set the policy restricting the freeze authority of mints bridged with `bridge_spl`,
+    /// creating it on first use
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`                        - The context containing the mint policy, bridge account and guardian
+    /// * `reject_freeze_authority`    - Whether mints with a freeze authority are rejected
+    /// * `allowed_freeze_authorities` - The freeze authorities still accepted while rejecting
+    pub fn set_mint_policy(
+        ctx: Context<SetMintPolicy>,
+        reject_freeze_authority: bool,
+        allowed_freeze_authorities: Vec<Pubkey>,
+    ) -> Result<()> {
+        set_mint_policy_handler(ctx, reject_freeze_authority, allowed_freeze_authorities)
+    }
+
+    /// Add a mint to, or remove it from, the mints accepted by `bridge_spl` during guarded launch,
+    /// creating the allow-list on first use
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`     - The context containing the mint allow-list, bridge account and guardian
+    /// * `mint`    - The mint to add or remove
+    /// * `allowed` - Whether the mint is allow-listed
+    pub fn set_mint_allowed(
+        ctx: Context<SetMintAllowed>,
+        mint: Pubkey,
+        allowed: bool,
+    ) -> Result<()> {
+        set_mint_allowed_handler(ctx, mint, allowed)
+    }
+
+    /// Turn guarded launch on or off. While it is on, `bridge_spl` only accepts the mints of the
+    /// mint allow-list
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`     - The context containing the bridge account and guardian
+    /// * `enabled` - Whether guarded launch is on
+    pub fn set_guarded_launch(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        enabled: bool,
+    ) -> Result<()> {
+        set_guarded_launch_handler(ctx, enabled)
+    }
+
+    /// Require, or no longer require, wrapped tokens to be confirmed by the Base Bridge before
+    /// `bridge_wrapped_token` accepts them
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`      - The context containing the bridge account and guardian
+    /// * `required` - Whether wrapped tokens must be confirmed before being bridged
+    pub fn set_wrapped_token_confirmation_required(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        required: bool,
+    ) -> Result<()> {
+        set_wrapped_token_confirmation_required_handler(ctx, required)
+    }
+
+    /// Register a Base DEX router, or unregister it, creating the DEX router list on first use.
+    /// Transfers whose call targets a registered router must carry a swap guard
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`        - The context containing the DEX router list, bridge account and guardian
+    /// * `router`     - The router address on Base
+    /// * `registered` - Whether calls to the router require a swap guard
+    pub fn set_dex_router(
+        ctx: Context<SetDexRouter>,
+        router: [u8; 20],
+        registered: bool,
+    ) -> Result<()> {
+        set_dex_router_handler(ctx, router, registered)
+    }
+
+    /// Set the outgoing messages and gas each payer can pay for per rate limiting window, creating
+    /// the payer rate limit config on first use. A zero window disables rate limiting
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`            - The context containing the payer rate limit config, bridge account and
+    ///                      guardian
+    /// * `window_seconds` - The duration of a rate limiting window, in seconds
+    /// * `max_messages`   - The outgoing messages a payer can pay for per window (0 for no limit)
+    /// * `max_gas`        - The gas a payer can pay for per window (0 for no limit)
+    pub fn set_payer_rate_limit(
+        ctx: Context<SetPayerRateLimitConfig>,
+        window_seconds: u64,
+        max_messages: u64,
+        max_gas: u64,
+    ) -> Result<()> {
+        set_payer_rate_limit_handler(ctx, window_seconds, max_messages, max_gas)
+    }
+
+    /// Exempt a payer from the payer rate limits, or subject it to them again, creating the payer
+    /// rate limit config on first use
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`    - The context containing the payer rate limit config, bridge account and guardian
+    /// * `payer`  - The payer
+    /// * `exempt` - Whether the payer is exempt from the rate limits
+    pub fn set_rate_limit_exempt_payer(
+        ctx: Context<SetPayerRateLimitConfig>,
+        payer: Pubkey,
+        exempt: bool,
+    ) -> Result<()> {
+        set_rate_limit_exempt_payer_handler(ctx, payer, exempt)
+    }
+
+    /// Set the programs that relayed messages can never invoke, on top of the built-in
+    /// `DENIED_RELAY_PROGRAMS`, creating the deny-list on first use
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`      - The context containing the relay deny-list, bridge account and guardian
+    /// * `programs` - The programs denied in addition to the built-in ones
+    pub fn set_relay_deny_list(
+        ctx: Context<SetRelayDenyList>,
+        programs: Vec<Pubkey>,
+    ) -> Result<()> {
+        set_relay_deny_list_handler(ctx, programs)
+    }
+
+    /// Set the lamports reimbursed by the maintenance budget to the payer of each output root
+    /// registration on top of its rent, creating the budget on first use
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`               - The context containing the maintenance budget, bridge account and
+    ///                         guardian
+    /// * `fee_reimbursement` - The lamports reimbursed for the transaction fees
+    pub fn set_maintenance_budget(
+        ctx: Context<SetMaintenanceBudget>,
+        fee_reimbursement: u64,
+    ) -> Result<()> {
+        set_maintenance_budget_handler(ctx, fee_reimbursement)
+    }
+
+    /// Set the maximum lamport allowance the operational vault lends to the bridge CPI authority
+    /// of a single `WithLamportAllowance` message, creating the vault on first use
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`           - The context containing the operational vault, bridge account and guardian
+    /// * `max_allowance` - The maximum lamport allowance of a single message
+    pub fn set_max_lamport_allowance(
+        ctx: Context<SetMaxLamportAllowance>,
+        max_allowance: u64,
+    ) -> Result<()> {
+        set_max_lamport_allowance_handler(ctx, max_allowance)
+    }
+
+    /// Set the decimals of the Base token that an SPL token vault is bridged to, when lower than
+    /// the mint decimals, so that amounts are carried in the remote token's smallest unit
+    /// Only the guardian can call this function, while the vault holds no deposits
+    ///
+    /// # Arguments
+    /// * `ctx`             - The context containing the vault decimals, bridge account and guardian
+    /// * `remote_token`    - The Base token the vault is bridged to
+    /// * `remote_decimals` - The decimals of the remote token
+    pub fn set_vault_decimals(
+        ctx: Context<SetVaultDecimals>,
+        remote_token: [u8; 20],
+        remote_decimals: u8,
+    ) -> Result<()> {
+        set_vault_decimals_handler(ctx, remote_token, remote_decimals)
+    }
+
+    /// Set the sliding window and the per-token thresholds of the outflow circuit breaker, which
+    /// pauses the bridge when relaying a transfer would exceed the threshold of its token
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`            - The context containing the bridge account and guardian
+    /// * `window_seconds` - The length of the sliding window (0 disables the breaker)
+    /// * `thresholds`     - The maximum outflow over the window of each tracked token
+    pub fn set_outflow_breaker(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        window_seconds: u64,
+        thresholds: Vec<OutflowThreshold>,
+    ) -> Result<()> {
+        set_outflow_breaker_handler(ctx, window_seconds, thresholds)
+    }
+
+    /// Set the delay and the per-token thresholds of the finalization delay, which holds transfers
+    /// exceeding the threshold of their token between `prove_message` and `relay_message`
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`           - The context containing the bridge account and guardian
+    /// * `delay_seconds` - The delay after proving a high-value transfer (0 disables the delay)
+    /// * `thresholds`    - The amount above which transfers of each token are delayed
+    pub fn set_finalization_delay(
+        ctx: Context<SetBridgeConfigFromGuardian>,
+        delay_seconds: u64,
+        thresholds: Vec<FinalizationThreshold>,
+    ) -> Result<()> {
+        set_finalization_delay_handler(ctx, delay_seconds, thresholds)
+    }
+
+    /// Veto an incoming message held by the finalization delay, so that it can never be relayed.
+    /// Emits `MessageVetoed`.
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`          - The context containing the message, bridge account and guardian
+    /// * `message_hash` - The hash of the vetoed message
+    pub fn veto_message(ctx: Context<VetoMessage>, message_hash: [u8; 32]) -> Result<()> {
+        veto_message_handler(ctx, message_hash)
+    }
+
+    /// Approve the outflow of an incoming message held by the outflow circuit breaker, so that it
+    /// is relayed regardless of the outflow threshold of its token. Emits `OutflowApproved`.
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`          - The context containing the message, bridge account and guardian
+    /// * `message_hash` - The hash of the approved message
+    pub fn approve_outflow(ctx: Context<ApproveOutflow>, message_hash: [u8; 32]) -> Result<()> {
+        approve_outflow_handler(ctx, message_hash)
+    }
+
+    /// Enable the emergency exit of a paused bridge, opening withdrawals of unrelayed deposits
+    /// after `EMERGENCY_EXIT_TIMELOCK_SECONDS`
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the emergency exit, bridge account and guardian
+    pub fn enable_emergency_exit(ctx: Context<EnableEmergencyExit>) -> Result<()> {
+        enable_emergency_exit_handler(ctx)
+    }
+
+    /// Cancel the emergency exit
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the emergency exit, bridge account and guardian
+    pub fn cancel_emergency_exit(ctx: Context<CancelEmergencyExit>) -> Result<()> {
+        cancel_emergency_exit_handler(ctx)
+    }
+
+    /// Set the share of every gas fee credited to the insurance fund, creating the fund on first
+    /// use
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`       - The context containing the insurance fund, bridge account and guardian
+    /// * `share_bps` - The share of the gas fees credited to the fund, in basis points
+    pub fn set_insurance_share(ctx: Context<SetInsuranceShare>, share_bps: u16) -> Result<()> {
+        set_insurance_share_handler(ctx, share_bps)
+    }
+
+    /// Request a payout from the insurance fund, executable after
+    /// `INSURANCE_PAYOUT_TIMELOCK_SECONDS`
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx`       - The context containing the insurance fund, bridge account and guardian
+    /// * `recipient` - The account receiving the payout
+    /// * `amount`    - The lamports paid out
+    pub fn request_insurance_payout(
+        ctx: Context<ManageInsurancePayout>,
+        recipient: Pubkey,
+        amount: u64,
+    ) -> Result<()> {
+        request_insurance_payout_handler(ctx, recipient, amount)
+    }
+
+    /// Cancel the pending insurance payout
+    /// Only the guardian can call this function
+    ///
+    /// # Arguments
+    /// * `ctx` - The context containing the insurance fund, bridge account and guardian
+    pub fn cancel_insurance_payout(ctx: Context<ManageInsurancePayout>) -> Result<()> {
+        cancel_insurance_payout_handler(ctx)
+    }
 }
@@ -0,0 +1,28 @@
+//! Structured program logs.
+//!
+//! `log_event!` writes a single line of `key=value` fields per operation, prefixed with its level,
+//! so that Geyser plugins and indexers can parse program logs deterministically:
+//!
+//! ```text
+//! Program log: level=info op=bridge_sol nonce=42 sender=<pubkey> amount=1000000000
+//! ```
+//!
+//! Values are formatted with `Display` and keys are logged in the order they are given. `info`
+//! logs are always emitted, while `debug` logs are compiled out by the `no-debug-logs` feature to
+//! save compute units in production builds.
+
+macro_rules! log_event {
+    (info, $($key:ident = $value:expr),+ $(,)?) => {
+        log_event!(@emit "info", $($key = $value),+)
+    };
+    (debug, $($key:ident = $value:expr),+ $(,)?) => {{
+        #[cfg(not(feature = "no-debug-logs"))]
+        log_event!(@emit "debug", $($key = $value),+);
+    }};
+    (@emit $level:literal, $($key:ident = $value:expr),+) => {
+        anchor_lang::prelude::msg!(
+            concat!("level=", $level, $(" ", stringify!($key), "={}"),+),
+            $($value),+
+        )
+    };
+}
@@ -1,12 +1,88 @@
 use anchor_lang::prelude::*;
 
+use crate::common::DISCRIMINATOR_LEN;
+
 #[constant]
 pub const NATIVE_SOL_PUBKEY: Pubkey = pubkey!("SoL1111111111111111111111111111111111111111");
 
 #[constant]
 pub const OUTGOING_MESSAGE_SEED: &[u8] = b"outgoing_message";
 
+/// Offset of `OutgoingMessage.nonce`, after the Anchor discriminator.
+pub const OUTGOING_MESSAGE_NONCE_OFFSET: usize = DISCRIMINATOR_LEN;
+/// Offset of `OutgoingMessage.sender`.
+pub const OUTGOING_MESSAGE_SENDER_OFFSET: usize = OUTGOING_MESSAGE_NONCE_OFFSET + 8;
+/// Offset of `OutgoingMessage.created_at`.
+pub const OUTGOING_MESSAGE_CREATED_AT_OFFSET: usize = OUTGOING_MESSAGE_SENDER_OFFSET + 32;
+/// Offset of `OutgoingMessage.remote_chain_id`.
+pub const OUTGOING_MESSAGE_REMOTE_CHAIN_ID_OFFSET: usize = OUTGOING_MESSAGE_CREATED_AT_OFFSET + 8;
+/// Length of the fixed `OutgoingMessage` header, i.e. the offset of the message variant.
+pub const OUTGOING_MESSAGE_HEADER_LEN: usize = OUTGOING_MESSAGE_REMOTE_CHAIN_ID_OFFSET + 8;
+
+#[constant]
+pub const RELAYED_NONCES_SEED: &[u8] = b"relayed_nonces";
+#[constant]
+pub const RELAY_EXECUTION_SEED: &[u8] = b"relay_execution";
+#[constant]
+pub const BURN_RECEIPT_SEED: &[u8] = b"burn_receipt";
+#[constant]
+pub const PAYER_RATE_LIMIT_SEED: &[u8] = b"payer_rate_limit";
+#[constant]
+pub const RELAYED_NONCES_PER_ACCOUNT: u64 = 65_536;
+
+/// Maximum length of the optional payment memo attached to a transfer.
+#[constant]
+pub const MAX_TRANSFER_MEMO_LEN: u8 = 64;
+
+/// Sentinel `amount` for `bridge_spl` and `bridge_wrapped_token` that bridges the whole balance of
+/// the source token account, resolved at execution time.
+#[constant]
+pub const BRIDGE_FULL_BALANCE: u64 = u64::MAX;
+
+/// Gas charged per ciphertext byte of an encrypted call, on top of `gas_per_call`: ciphertext is
+/// incompressible and costs the non-zero calldata byte price on Base.
+#[constant]
+pub const ENCRYPTED_CALL_GAS_PER_BYTE: u64 = 16;
+
+/// Maximum number of recipients of a transfer batch.
+#[constant]
+pub const MAX_TRANSFER_BATCH_RECIPIENTS: u8 = 16;
+
+/// Gas charged per recipient of a transfer batch beyond the first, on top of `gas_per_call`: an
+/// extra recipient only costs a token transfer on Base rather than the relay of a whole message.
+#[constant]
+pub const TRANSFER_BATCH_GAS_PER_RECIPIENT: u64 = 30_000;
+
+/// Key used in `additional_metadata` for the Base (EVM) token address bytes, hex-encoded.
 #[constant]
 pub const REMOTE_TOKEN_METADATA_KEY: &str = "remote_token";
+/// Key used in `additional_metadata` for the decimal scaling exponent.
 #[constant]
 pub const SCALER_EXPONENT_METADATA_KEY: &str = "scaler_exponent";
+
+/// Maximum length, in bytes, of a wrapped token name (the Metaplex metadata limit).
+#[constant]
+pub const MAX_WRAPPED_TOKEN_NAME_LEN: u8 = 32;
+/// Maximum length, in bytes, of a wrapped token symbol (the Metaplex metadata limit).
+#[constant]
+pub const MAX_WRAPPED_TOKEN_SYMBOL_LEN: u8 = 10;
+/// Inclusive unicode ranges allowed in wrapped token names and symbols on top of visible ASCII:
+/// the Latin-1 Supplement and Latin Extended-A letters. Control and formatting characters (such as
+/// RTL overrides) and emoji stay rejected.
+pub const ALLOWED_TOKEN_METADATA_UNICODE_RANGES: &[(char, char)] = &[('\u{00C0}', '\u{017F}')];
+
+#[constant]
+pub const METAPLEX_TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+#[constant]
+pub const METAPLEX_METADATA_SEED: &[u8] = b"metadata";
+
+#[constant]
+pub const SPL_MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+#[constant]
+pub const BRIDGE_SESSION_SEED: &[u8] = b"bridge_session";
+
+/// Maximum number of remote tokens a bridge session can be restricted to.
+#[constant]
+pub const MAX_SESSION_REMOTE_TOKENS: u8 = 8;
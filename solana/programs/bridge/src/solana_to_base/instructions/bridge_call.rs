@@ -1,9 +1,17 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    common::{
+        bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, INSURANCE_FUND_SEED,
+        PAYER_RATE_LIMIT_CONFIG_SEED,
+    },
     solana_to_base::{
-        internal::bridge_call::bridge_call_internal, Call, OutgoingMessage, OUTGOING_MESSAGE_SEED,
+        internal::{
+            bridge_call::bridge_call_internal,
+            spl_memo::{call_summary, log_spl_memo},
+        },
+        Call, MessageKind, OutgoingMessage, OutgoingMessageReceipt, OUTGOING_MESSAGE_SEED,
+        SPL_MEMO_PROGRAM_ID,
     },
     BridgeError,
 };
@@ -30,6 +38,28 @@ pub struct BridgeCall<'info> {
     #[account(mut, address = bridge.gas_config.gas_fee_receiver @ BridgeError::IncorrectGasFeeReceiver)]
     pub gas_fee_receiver: AccountInfo<'info>,
 
+    /// Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited.
+    /// CHECK: PDA with GAS_FEE_ESCROW_SEED, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub gas_fee_escrow: AccountInfo<'info>,
+
+    /// Insurance fund credited with its share of the gas fee. No share is taken while it is
+    /// uninitialized.
+    /// CHECK: PDA with INSURANCE_FUND_SEED, deserialized only if owned by this program.
+    #[account(mut, seeds = [INSURANCE_FUND_SEED], bump)]
+    pub insurance_fund: AccountInfo<'info>,
+
+    /// Payer rate limiting configuration. Payers are not rate limited while it is uninitialized.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_CONFIG_SEED, deserialized only if owned by this program.
+    #[account(seeds = [PAYER_RATE_LIMIT_CONFIG_SEED], bump)]
+    pub payer_rate_limit_config: AccountInfo<'info>,
+
+    /// Outgoing messages paid for by the payer in the current rate limiting window, created on its
+    /// first message while rate limiting is enabled.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_SEED and the payer, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub payer_rate_limit: AccountInfo<'info>,
+
     /// The main bridge state account containing global bridge configuration.
     /// - Uses PDA with BRIDGE_SEED for deterministic address
     /// - Mutable to increment the nonce and update EIP-1559 gas pricing
@@ -40,21 +70,26 @@ pub struct BridgeCall<'info> {
     /// The outgoing message account that stores the cross-chain call data.
     /// - Created fresh for each bridge call seeded by a client-provided salt
     /// - Payer funds the account creation
-    /// - Space is DISCRIMINATOR_LEN + OutgoingMessage::space(...)` and is sized using
-    ///   the worst-case message variant to ensure sufficient capacity even for large payloads
+    /// - Space is `DISCRIMINATOR_LEN + OutgoingMessage::space(...)`, sized exactly for the call
+    ///   (an encrypted call serializes to the same size)
     /// - Contains all information needed for execution on Base
     #[account(
         init,
         payer = payer,
         seeds = [OUTGOING_MESSAGE_SEED, outgoing_message_salt.as_ref()],
         bump,
-        space = DISCRIMINATOR_LEN + OutgoingMessage::space::<Call>(call.data.len()),
+        space = DISCRIMINATOR_LEN + OutgoingMessage::space(MessageKind::Call { data_len: call.data.len() }),
     )]
     pub outgoing_message: Account<'info, OutgoingMessage>,
 
     /// System program required for creating the outgoing message account.
     /// Used internally by Anchor for account initialization.
     pub system_program: Program<'info, System>,
+
+    /// SPL Memo program, required when `spl_memo` is set to log a summary of the operation.
+    /// CHECK: Validated against the SPL Memo program ID.
+    #[account(address = SPL_MEMO_PROGRAM_ID)]
+    pub memo_program: Option<UncheckedAccount<'info>>,
 }
 
 /// Handler for `bridge_call`.
@@ -62,22 +97,73 @@ pub struct BridgeCall<'info> {
 /// - Validates the call
 /// - Charges gas and updates EIP-1559 state
 /// - Persists the `OutgoingMessage` and increments the nonce
+/// - Logs an SPL memo summarizing the call when `spl_memo` is set
 pub fn bridge_call_handler(
     ctx: Context<BridgeCall>,
     _outgoing_message_salt: [u8; 32],
     call: Call,
-) -> Result<()> {
+    spl_memo: bool,
+) -> Result<OutgoingMessageReceipt> {
     // Check if bridge is paused
     require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
-    bridge_call_internal(
+
+    let to = call.to;
+
+    let receipt = bridge_call_internal(
         &ctx.accounts.payer,
         &ctx.accounts.from,
         &ctx.accounts.gas_fee_receiver,
+        &ctx.accounts.gas_fee_escrow,
+        &ctx.accounts.insurance_fund,
+        &ctx.accounts.payer_rate_limit_config,
+        &ctx.accounts.payer_rate_limit,
         &mut ctx.accounts.bridge,
         &mut ctx.accounts.outgoing_message,
         &ctx.accounts.system_program,
         call,
-    )
+        false,
+    )?;
+
+    log_spl_memo(spl_memo, ctx.accounts.memo_program.as_ref(), || {
+        call_summary(&to)
+    })?;
+
+    Ok(receipt)
+}
+
+/// Handler for `bridge_encrypted_call`. Same as `bridge_call`, except that `call.data` is
+/// ciphertext stored as a `Message::Encrypted` and charged `ENCRYPTED_CALL_GAS_PER_BYTE` per byte.
+pub fn bridge_encrypted_call_handler(
+    ctx: Context<BridgeCall>,
+    _outgoing_message_salt: [u8; 32],
+    call: Call,
+    spl_memo: bool,
+) -> Result<OutgoingMessageReceipt> {
+    // Check if bridge is paused
+    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+
+    let to = call.to;
+
+    let receipt = bridge_call_internal(
+        &ctx.accounts.payer,
+        &ctx.accounts.from,
+        &ctx.accounts.gas_fee_receiver,
+        &ctx.accounts.gas_fee_escrow,
+        &ctx.accounts.insurance_fund,
+        &ctx.accounts.payer_rate_limit_config,
+        &ctx.accounts.payer_rate_limit,
+        &mut ctx.accounts.bridge,
+        &mut ctx.accounts.outgoing_message,
+        &ctx.accounts.system_program,
+        call,
+        true,
+    )?;
+
+    log_spl_memo(spl_memo, ctx.accounts.memo_program.as_ref(), || {
+        call_summary(&to)
+    })?;
+
+    Ok(receipt)
 }
 
 #[cfg(test)]
@@ -96,12 +182,18 @@ mod tests {
     use crate::{
         accounts,
         common::bridge::Bridge,
-        instruction::BridgeCall as BridgeCallIx,
-        solana_to_base::CallType,
+        instruction::{BridgeCall as BridgeCallIx, BridgeEncryptedCall as BridgeEncryptedCallIx},
+        solana_to_base::{
+            CallType, ENCRYPTED_CALL_GAS_PER_BYTE, OUTGOING_MESSAGE_CREATED_AT_OFFSET,
+            OUTGOING_MESSAGE_HEADER_LEN, OUTGOING_MESSAGE_NONCE_OFFSET,
+            OUTGOING_MESSAGE_REMOTE_CHAIN_ID_OFFSET, OUTGOING_MESSAGE_SENDER_OFFSET,
+        },
         test_utils::{
-            create_outgoing_message, setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            create_outgoing_message, gas_fee_escrow_pda, insurance_fund_pda, mock_clock,
+            payer_rate_limit_config_pda, payer_rate_limit_pda, setup_bridge, SetupBridgeResult,
+            TEST_GAS_FEE_RECEIVER,
         },
-        ID,
+        BASE_CHAIN_ID, ID,
     };
 
     #[test]
@@ -137,9 +229,14 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
             bridge: bridge_pda,
             outgoing_message,
             system_program: system_program::ID,
+            memo_program: None,
         }
         .to_account_metas(None);
 
@@ -150,6 +247,7 @@ mod tests {
             data: BridgeCallIx {
                 outgoing_message_salt,
                 call: call.clone(),
+                spl_memo: false,
             }
             .data(),
         };
@@ -162,9 +260,26 @@ mod tests {
         );
 
         // Send the transaction
-        svm.send_transaction(tx)
+        mock_clock(&mut svm, 1_700_000_000);
+        let receiver_before = svm.get_balance(&TEST_GAS_FEE_RECEIVER).unwrap();
+        let meta = svm
+            .send_transaction(tx)
             .expect("Failed to send bridge_call transaction");
 
+        // Verify the receipt returned to the caller
+        assert_eq!(meta.return_data.program_id, ID);
+        let receipt = OutgoingMessageReceipt::try_from_slice(&meta.return_data.data).unwrap();
+        assert_eq!(
+            receipt,
+            OutgoingMessageReceipt {
+                outgoing_message,
+                nonce: 0,
+                leaf_index: 0,
+                gas_fee: svm.get_balance(&TEST_GAS_FEE_RECEIVER).unwrap() - receiver_before,
+                protocol_fee: 0,
+            }
+        );
+
         // Assert the OutgoingMessage account was created correctly
         let outgoing_message_account = svm.get_account(&outgoing_message).unwrap();
         assert_eq!(outgoing_message_account.owner, ID);
@@ -175,6 +290,29 @@ mod tests {
         // Verify the message fields
         assert_eq!(outgoing_message_data.nonce, 0);
         assert_eq!(outgoing_message_data.sender, from.pubkey());
+        assert_eq!(outgoing_message_data.created_at, 1_700_000_000);
+        assert_eq!(outgoing_message_data.remote_chain_id, BASE_CHAIN_ID);
+        let leaf = outgoing_message_data.leaf_hash(&outgoing_message).unwrap();
+
+        // Verify the header fields sit at their documented offsets
+        let data = &outgoing_message_account.data;
+        assert_eq!(
+            data[OUTGOING_MESSAGE_NONCE_OFFSET..OUTGOING_MESSAGE_SENDER_OFFSET],
+            0u64.to_le_bytes()
+        );
+        assert_eq!(
+            data[OUTGOING_MESSAGE_SENDER_OFFSET..OUTGOING_MESSAGE_CREATED_AT_OFFSET],
+            from.pubkey().to_bytes()
+        );
+        assert_eq!(
+            data[OUTGOING_MESSAGE_CREATED_AT_OFFSET..OUTGOING_MESSAGE_REMOTE_CHAIN_ID_OFFSET],
+            1_700_000_000i64.to_le_bytes()
+        );
+        assert_eq!(
+            data[OUTGOING_MESSAGE_REMOTE_CHAIN_ID_OFFSET..OUTGOING_MESSAGE_HEADER_LEN],
+            BASE_CHAIN_ID.to_le_bytes()
+        );
+        assert_eq!(data[OUTGOING_MESSAGE_HEADER_LEN], 0); // Message::Call variant
 
         // Verify the message content
         match outgoing_message_data.message {
@@ -191,6 +329,10 @@ mod tests {
         let bridge_account = svm.get_account(&bridge_pda).unwrap();
         let bridge_data = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
         assert_eq!(bridge_data.nonce, 1);
+
+        // Verify the message was appended to the outbox
+        assert_eq!(bridge_data.outbox.leaf_count, 1);
+        assert_eq!(bridge_data.outbox.root(), leaf);
     }
 
     #[test]
@@ -227,9 +369,14 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: wrong_gas_fee_receiver.pubkey(), // Wrong receiver
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
             bridge: bridge_pda,
             outgoing_message,
             system_program: system_program::ID,
+            memo_program: None,
         }
         .to_account_metas(None);
 
@@ -240,6 +387,7 @@ mod tests {
             data: BridgeCallIx {
                 outgoing_message_salt,
                 call,
+                spl_memo: false,
             }
             .data(),
         };
@@ -305,9 +453,14 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
             bridge: bridge_pda,
             outgoing_message,
             system_program: system_program::ID,
+            memo_program: None,
         }
         .to_account_metas(None);
 
@@ -318,6 +471,7 @@ mod tests {
             data: BridgeCallIx {
                 outgoing_message_salt,
                 call,
+                spl_memo: false,
             }
             .data(),
         };
@@ -344,4 +498,118 @@ mod tests {
             error_string
         );
     }
+
+    fn bridge_encrypted_call(
+        svm: &mut litesvm::LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        call: Call,
+    ) -> std::result::Result<Pubkey, String> {
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+
+        let accounts = accounts::BridgeCall {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            bridge: bridge_pda,
+            outgoing_message,
+            system_program: system_program::ID,
+            memo_program: None,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeEncryptedCallIx {
+                outgoing_message_salt,
+                call,
+                spl_memo: false,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer, &from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| outgoing_message)
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_bridge_encrypted_call_charges_ciphertext_gas() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let call = Call {
+            ty: CallType::Call,
+            to: [1u8; 20],
+            value: 0,
+            data: vec![0xab; 100], // Ciphertext
+        };
+
+        let receiver_balance_before = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
+        let outgoing_message = bridge_encrypted_call(&mut svm, &payer, bridge_pda, call.clone())
+            .expect("Failed to send bridge_encrypted_call transaction");
+        let receiver_balance_after = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
+
+        let outgoing_message_account = svm.get_account(&outgoing_message).unwrap();
+        assert_eq!(
+            outgoing_message_account.data[OUTGOING_MESSAGE_HEADER_LEN],
+            2
+        ); // Message::Encrypted variant
+        let outgoing_message_data =
+            OutgoingMessage::try_deserialize(&mut &outgoing_message_account.data[..]).unwrap();
+        assert_eq!(
+            outgoing_message_data.message,
+            crate::solana_to_base::Message::Encrypted(call)
+        );
+
+        // With a base fee of 1 and a unit gas cost scaler, the fee is the gas amount
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge_data = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(
+            receiver_balance_after - receiver_balance_before,
+            bridge_data.gas_config.gas_per_call + 100 * ENCRYPTED_CALL_GAS_PER_BYTE
+        );
+        assert_eq!(bridge_data.nonce, 1);
+    }
+
+    #[test]
+    fn test_bridge_encrypted_call_rejects_empty_ciphertext() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let call = Call {
+            ty: CallType::Call,
+            to: [1u8; 20],
+            value: 0,
+            data: vec![],
+        };
+
+        let error_string = bridge_encrypted_call(&mut svm, &payer, bridge_pda, call).unwrap_err();
+        assert!(
+            error_string.contains("EmptyEncryptedCall"),
+            "Expected EmptyEncryptedCall error, got: {}",
+            error_string
+        );
+    }
 }
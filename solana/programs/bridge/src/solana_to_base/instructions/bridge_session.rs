@@ -0,0 +1,411 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::DISCRIMINATOR_LEN,
+    solana_to_base::{BridgeSession, BRIDGE_SESSION_SEED, MAX_SESSION_REMOTE_TOKENS},
+    BridgeError,
+};
+
+/// Accounts struct for the `create_bridge_session` instruction that authorizes a delegate key to
+/// bridge the owner's tokens within a budget. Calling it again for the same delegate replaces the
+/// session.
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct CreateBridgeSession<'info> {
+    /// The owner of the bridged tokens, funding the session account.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The session account.
+    /// - Uses PDA with BRIDGE_SESSION_SEED, the owner and the delegate for deterministic address
+    /// - Must be approved by the owner as SPL delegate of the token accounts to bridge from
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = DISCRIMINATOR_LEN + BridgeSession::INIT_SPACE,
+        seeds = [BRIDGE_SESSION_SEED, owner.key().as_ref(), delegate.as_ref()],
+        bump
+    )]
+    pub bridge_session: Account<'info, BridgeSession>,
+
+    /// System program required for creating the session account.
+    pub system_program: Program<'info, System>,
+}
+
+/// Accounts struct for the `revoke_bridge_session` instruction that closes a session, returning
+/// its rent to the owner.
+#[derive(Accounts)]
+pub struct RevokeBridgeSession<'info> {
+    /// The owner of the session.
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// The revoked session account.
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ BridgeError::IncorrectSessionOwner,
+        seeds = [BRIDGE_SESSION_SEED, owner.key().as_ref(), bridge_session.delegate.as_ref()],
+        bump = bridge_session.bump
+    )]
+    pub bridge_session: Account<'info, BridgeSession>,
+}
+
+pub fn create_bridge_session_handler(
+    ctx: Context<CreateBridgeSession>,
+    delegate: Pubkey,
+    budget: u64,
+    expires_at: i64,
+    allowed_remote_tokens: Vec<[u8; 20]>,
+) -> Result<()> {
+    require!(
+        expires_at > Clock::get()?.unix_timestamp,
+        BridgeError::InvalidSessionExpiry
+    );
+    require!(
+        allowed_remote_tokens.len() <= MAX_SESSION_REMOTE_TOKENS as usize,
+        BridgeError::TooManySessionRemoteTokens
+    );
+
+    *ctx.accounts.bridge_session = BridgeSession {
+        owner: ctx.accounts.owner.key(),
+        delegate,
+        remaining_budget: budget,
+        expires_at,
+        allowed_remote_tokens,
+        bump: ctx.bumps.bridge_session,
+    };
+
+    Ok(())
+}
+
+pub fn revoke_bridge_session_handler(_ctx: Context<RevokeBridgeSession>) -> Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use anchor_spl::token_interface::spl_token_2022::{
+        solana_program::{program_option::COption, program_pack::Pack},
+        state::Account as SplTokenAccount,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::TOKEN_VAULT_SEED,
+        instruction::{
+            BridgeSpl as BridgeSplIx, CreateBridgeSession as CreateBridgeSessionIx,
+            RevokeBridgeSession as RevokeBridgeSessionIx,
+        },
+        solana_to_base::OutgoingMessage,
+        test_utils::{
+            create_mock_mint, create_mock_token_account, create_outgoing_message,
+            dex_router_list_pda, gas_fee_escrow_pda, insurance_fund_pda, mint_allow_list_pda,
+            mint_policy_pda, mock_clock, payer_rate_limit_config_pda, payer_rate_limit_pda,
+            protocol_fee_config_pda, setup_bridge, token_deposits_pda, vault_decimals_pda,
+            SetupBridgeResult, TEST_GAS_FEE_RECEIVER, TEST_PROTOCOL_FEE_TREASURY,
+        },
+        ID,
+    };
+
+    const REMOTE_TOKEN: [u8; 20] = [2u8; 20];
+    const BUDGET: u64 = 600_000;
+    const EXPIRES_AT: i64 = 1_000;
+
+    fn bridge_session_pda(owner: &Pubkey, delegate: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[BRIDGE_SESSION_SEED, owner.as_ref(), delegate.as_ref()],
+            &ID,
+        )
+        .0
+    }
+
+    fn read_bridge_session(svm: &LiteSVM, bridge_session: &Pubkey) -> BridgeSession {
+        let account = svm.get_account(bridge_session).unwrap();
+        BridgeSession::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    fn create_bridge_session(
+        svm: &mut LiteSVM,
+        owner: &Keypair,
+        delegate: Pubkey,
+        allowed_remote_tokens: Vec<[u8; 20]>,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::CreateBridgeSession {
+                owner: owner.pubkey(),
+                bridge_session: bridge_session_pda(&owner.pubkey(), &delegate),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: CreateBridgeSessionIx {
+                delegate,
+                budget: BUDGET,
+                expires_at: EXPIRES_AT,
+                allowed_remote_tokens,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[owner],
+            Message::new(&[ix], Some(&owner.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    struct SessionSetup {
+        svm: LiteSVM,
+        payer: Keypair,
+        bridge_pda: Pubkey,
+        owner: Keypair,
+        delegate: Keypair,
+        mint: Pubkey,
+        from_token_account: Pubkey,
+    }
+
+    /// Creates a session for a fresh owner and delegate, and a token account of the owner that
+    /// approves the session PDA as SPL delegate.
+    fn setup_session() -> SessionSetup {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let owner = Keypair::new();
+        let delegate = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        svm.airdrop(&delegate.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        create_bridge_session(&mut svm, &owner, delegate.pubkey(), vec![REMOTE_TOKEN])
+            .expect("Failed to create bridge session");
+
+        let mint = Keypair::new().pubkey();
+        create_mock_mint(
+            &mut svm,
+            mint,
+            6,
+            anchor_spl::token_interface::spl_token_2022::ID,
+        );
+
+        let from_token_account = Keypair::new().pubkey();
+        create_mock_token_account(
+            &mut svm,
+            from_token_account,
+            mint,
+            owner.pubkey(),
+            1_000_000,
+        );
+        let mut account = svm.get_account(&from_token_account).unwrap();
+        let mut token_account = SplTokenAccount::unpack(&account.data).unwrap();
+        token_account.delegate =
+            COption::Some(bridge_session_pda(&owner.pubkey(), &delegate.pubkey()));
+        token_account.delegated_amount = 1_000_000;
+        token_account.pack_into_slice(&mut account.data);
+        svm.set_account(from_token_account, account).unwrap();
+
+        SessionSetup {
+            svm,
+            payer,
+            bridge_pda,
+            owner,
+            delegate,
+            mint,
+            from_token_account,
+        }
+    }
+
+    fn bridge_spl_with_session(
+        setup: &mut SessionSetup,
+        remote_token: [u8; 20],
+        amount: u64,
+    ) -> std::result::Result<(), String> {
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let token_vault = Pubkey::find_program_address(
+            &[TOKEN_VAULT_SEED, setup.mint.as_ref(), remote_token.as_ref()],
+            &ID,
+        )
+        .0;
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::BridgeSpl {
+                payer: setup.payer.pubkey(),
+                from: setup.delegate.pubkey(),
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                gas_fee_escrow: Some(gas_fee_escrow_pda()),
+                insurance_fund: insurance_fund_pda(),
+                payer_rate_limit_config: payer_rate_limit_config_pda(),
+                payer_rate_limit: Some(payer_rate_limit_pda(&setup.payer.pubkey())),
+                protocol_fee_config: protocol_fee_config_pda(),
+                protocol_fee_treasury: Some(TEST_PROTOCOL_FEE_TREASURY),
+                dex_router_list: Some(dex_router_list_pda()),
+                mint_policy: mint_policy_pda(),
+                mint_allow_list: Some(mint_allow_list_pda()),
+                mint: setup.mint,
+                from_token_account: setup.from_token_account,
+                bridge: setup.bridge_pda,
+                token_vault,
+                token_deposits: token_deposits_pda(&token_vault),
+                vault_decimals: vault_decimals_pda(&token_vault),
+                outgoing_message,
+                token_program: anchor_spl::token_interface::ID,
+                system_program: system_program::ID,
+                memo_program: None,
+                bridge_session: Some(bridge_session_pda(
+                    &setup.owner.pubkey(),
+                    &setup.delegate.pubkey(),
+                )),
+            }
+            .to_account_metas(None),
+            data: BridgeSplIx {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                remote_token,
+                amount,
+                call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&setup.payer, &setup.delegate],
+            Message::new(&[ix], Some(&setup.payer.pubkey())),
+            setup.svm.latest_blockhash(),
+        );
+        setup
+            .svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_create_and_revoke_bridge_session() {
+        let SessionSetup {
+            mut svm,
+            owner,
+            delegate,
+            ..
+        } = setup_session();
+
+        let bridge_session = bridge_session_pda(&owner.pubkey(), &delegate.pubkey());
+        let session = read_bridge_session(&svm, &bridge_session);
+        assert_eq!(session.owner, owner.pubkey());
+        assert_eq!(session.delegate, delegate.pubkey());
+        assert_eq!(session.remaining_budget, BUDGET);
+        assert_eq!(session.expires_at, EXPIRES_AT);
+        assert_eq!(session.allowed_remote_tokens, vec![REMOTE_TOKEN]);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::RevokeBridgeSession {
+                owner: owner.pubkey(),
+                bridge_session,
+            }
+            .to_account_metas(None),
+            data: RevokeBridgeSessionIx {}.data(),
+        };
+        let tx = Transaction::new(
+            &[&owner],
+            Message::new(&[ix], Some(&owner.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to revoke bridge session");
+        assert!(svm.get_account(&bridge_session).is_none());
+    }
+
+    #[test]
+    fn test_create_bridge_session_too_many_remote_tokens() {
+        let SetupBridgeResult { mut svm, .. } = setup_bridge();
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let error_string = create_bridge_session(
+            &mut svm,
+            &owner,
+            Pubkey::new_unique(),
+            vec![REMOTE_TOKEN; MAX_SESSION_REMOTE_TOKENS as usize + 1],
+        )
+        .unwrap_err();
+        assert!(
+            error_string.contains("TooManySessionRemoteTokens"),
+            "Expected TooManySessionRemoteTokens error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_bridge_spl_with_session() {
+        let mut setup = setup_session();
+
+        bridge_spl_with_session(&mut setup, REMOTE_TOKEN, 500_000)
+            .expect("Failed to bridge with session");
+
+        let (_, outgoing_message) = create_outgoing_message();
+        let account = setup.svm.get_account(&outgoing_message).unwrap();
+        let message = OutgoingMessage::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(message.sender, setup.owner.pubkey());
+
+        let bridge_session = bridge_session_pda(&setup.owner.pubkey(), &setup.delegate.pubkey());
+        assert_eq!(
+            read_bridge_session(&setup.svm, &bridge_session).remaining_budget,
+            BUDGET - 500_000
+        );
+
+        let account = setup.svm.get_account(&setup.from_token_account).unwrap();
+        assert_eq!(
+            SplTokenAccount::unpack(&account.data).unwrap().amount,
+            500_000
+        );
+    }
+
+    #[test]
+    fn test_bridge_spl_with_session_rejects_unauthorized_transfers() {
+        let mut setup = setup_session();
+
+        let error_string =
+            bridge_spl_with_session(&mut setup, REMOTE_TOKEN, BUDGET + 1).unwrap_err();
+        assert!(
+            error_string.contains("SessionBudgetExceeded"),
+            "Expected SessionBudgetExceeded error, got: {}",
+            error_string
+        );
+
+        let error_string = bridge_spl_with_session(&mut setup, [3u8; 20], 1).unwrap_err();
+        assert!(
+            error_string.contains("SessionRemoteTokenNotAllowed"),
+            "Expected SessionRemoteTokenNotAllowed error, got: {}",
+            error_string
+        );
+
+        mock_clock(&mut setup.svm, EXPIRES_AT);
+        let error_string = bridge_spl_with_session(&mut setup, REMOTE_TOKEN, 1).unwrap_err();
+        assert!(
+            error_string.contains("SessionExpired"),
+            "Expected SessionExpired error, got: {}",
+            error_string
+        );
+    }
+}
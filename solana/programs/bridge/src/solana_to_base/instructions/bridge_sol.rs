@@ -1,10 +1,18 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, SOL_VAULT_SEED},
+    common::{
+        bridge::Bridge, SolVaultState, TokenDeposits, BRIDGE_SEED, DEX_ROUTER_LIST_SEED,
+        DISCRIMINATOR_LEN, INSURANCE_FUND_SEED, PAYER_RATE_LIMIT_CONFIG_SEED,
+        PROTOCOL_FEE_CONFIG_SEED, SOL_VAULT_SEED, SOL_VAULT_STATE_SEED, TOKEN_DEPOSITS_SEED,
+    },
     solana_to_base::{
-        internal::bridge_sol::bridge_sol_internal, Call, OutgoingMessage, Transfer,
-        OUTGOING_MESSAGE_SEED,
+        internal::{
+            bridge_sol::bridge_sol_internal,
+            spl_memo::{log_spl_memo, sol_transfer_summary},
+        },
+        Call, MessageKind, OutgoingMessage, OutgoingMessageReceipt, SwapGuard,
+        OUTGOING_MESSAGE_SEED, SPL_MEMO_PROGRAM_ID,
     },
     BridgeError,
 };
@@ -15,7 +23,7 @@ use crate::{
 /// The bridged SOLs are locked in a vault on Solana and an outgoing message is created to mint
 /// the corresponding tokens and execute the optional call on Base.
 #[derive(Accounts)]
-#[instruction(outgoing_message_salt: [u8; 32], _to: [u8; 20], _amount: u64, call: Option<Call>)]
+#[instruction(outgoing_message_salt: [u8; 32], _to: [u8; 20], _amount: u64, call: Option<Call>, memo: Option<Vec<u8>>, swap_guard: Option<SwapGuard>)]
 pub struct BridgeSol<'info> {
     /// The account that pays for transaction fees and account creation.
     /// Must be mutable to deduct lamports for account rent and gas fees.
@@ -32,15 +40,69 @@ pub struct BridgeSol<'info> {
     #[account(mut, address = bridge.gas_config.gas_fee_receiver @ BridgeError::IncorrectGasFeeReceiver)]
     pub gas_fee_receiver: AccountInfo<'info>,
 
-    /// The SOL vault account that holds locked tokens for the specific remote token.
-    /// - Uses PDA with SOL_VAULT_SEED for deterministic address
-    /// - Mutable to receive the locked SOL tokens
-    /// - Each remote token has its own dedicated vault
+    /// Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited.
+    /// CHECK: PDA with GAS_FEE_ESCROW_SEED, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub gas_fee_escrow: AccountInfo<'info>,
+
+    /// Insurance fund credited with its share of the gas fee. No share is taken while it is
+    /// uninitialized.
+    /// CHECK: PDA with INSURANCE_FUND_SEED, deserialized only if owned by this program.
+    #[account(mut, seeds = [INSURANCE_FUND_SEED], bump)]
+    pub insurance_fund: AccountInfo<'info>,
+
+    /// Payer rate limiting configuration. Payers are not rate limited while it is uninitialized.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_CONFIG_SEED, deserialized only if owned by this program.
+    #[account(seeds = [PAYER_RATE_LIMIT_CONFIG_SEED], bump)]
+    pub payer_rate_limit_config: AccountInfo<'info>,
+
+    /// Outgoing messages paid for by the payer in the current rate limiting window, created on its
+    /// first message while rate limiting is enabled.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_SEED and the payer, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub payer_rate_limit: AccountInfo<'info>,
+
+    /// Protocol fee configuration. No protocol fee is charged while it is uninitialized.
+    /// CHECK: PDA with PROTOCOL_FEE_CONFIG_SEED, deserialized only if owned by this program.
+    #[account(seeds = [PROTOCOL_FEE_CONFIG_SEED], bump)]
+    pub protocol_fee_config: AccountInfo<'info>,
+
+    /// The treasury credited with the protocol fee. Ignored when no protocol fee is charged.
+    /// CHECK: Validated against `protocol_fee_config.treasury` when a protocol fee is charged.
+    #[account(mut)]
+    pub protocol_fee_treasury: AccountInfo<'info>,
+
+    /// DEX router list whose routers may only be called with a swap guard. Calls are not checked
+    /// while it is uninitialized.
+    /// CHECK: PDA with DEX_ROUTER_LIST_SEED, deserialized only if owned by this program.
+    #[account(seeds = [DEX_ROUTER_LIST_SEED], bump)]
+    pub dex_router_list: AccountInfo<'info>,
+
+    /// The SOL vault state recording the vault bump.
+    /// Bridging SOL requires the vault to have been initialized with `init_sol_vault`.
+    #[account(seeds = [SOL_VAULT_STATE_SEED], bump)]
+    pub sol_vault_state: Account<'info, SolVaultState>,
+
+    /// The SOL vault account that holds the locked SOL.
+    /// - Uses PDA with SOL_VAULT_SEED and the bump recorded in `sol_vault_state`
+    /// - Mutable to receive the locked SOL
     ///
     /// CHECK: This is the SOL vault account.
-    #[account(mut, seeds = [SOL_VAULT_SEED], bump)]
+    #[account(mut, seeds = [SOL_VAULT_SEED], bump = sol_vault_state.bump)]
     pub sol_vault: AccountInfo<'info>,
 
+    /// Accounting for the amount locked in the SOL vault.
+    /// - PDA of this program using `[TOKEN_DEPOSITS_SEED, sol_vault]`
+    /// - Created on the first deposit; `locked_amount` is incremented by the bridged amount
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [TOKEN_DEPOSITS_SEED, sol_vault.key().as_ref()],
+        bump,
+        space = DISCRIMINATOR_LEN + TokenDeposits::INIT_SPACE
+    )]
+    pub token_deposits: Account<'info, TokenDeposits>,
+
     /// The main bridge state account that tracks nonces and fee parameters.
     /// - Uses PDA with BRIDGE_SEED for deterministic address
     /// - Mutable to increment nonce and update EIP1559 fee data
@@ -50,43 +112,70 @@ pub struct BridgeSol<'info> {
     /// The outgoing message account that stores cross-chain transfer details.
     /// - Created fresh for each bridge operation
     /// - Payer funds the account creation
-    /// - Space allocated dynamically based on optional call data size
+    /// - Space allocated dynamically based on optional call data and memo sizes
     #[account(
         init,
         payer = payer,
         seeds = [OUTGOING_MESSAGE_SEED, outgoing_message_salt.as_ref()],
         bump,
-        space = DISCRIMINATOR_LEN + OutgoingMessage::space::<Transfer>(call.map(|c| c.data.len()).unwrap_or_default()),
+        space = DISCRIMINATOR_LEN + OutgoingMessage::space(MessageKind::Transfer { call_data_len: call.as_ref().map(|c| c.data.len()), memo_len: memo.as_ref().map(Vec::len), swap_guard: swap_guard.is_some() }),
     )]
     pub outgoing_message: Account<'info, OutgoingMessage>,
 
     /// System program required for SOL transfers and account creation.
     /// Used for transferring SOL from user to vault and creating outgoing message accounts.
     pub system_program: Program<'info, System>,
+
+    /// SPL Memo program, required when `spl_memo` is set to log a summary of the operation.
+    /// CHECK: Validated against the SPL Memo program ID.
+    #[account(address = SPL_MEMO_PROGRAM_ID)]
+    pub memo_program: Option<UncheckedAccount<'info>>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn bridge_sol_handler(
     ctx: Context<BridgeSol>,
     _outgoing_message_salt: [u8; 32],
     to: [u8; 20],
     amount: u64,
     call: Option<Call>,
-) -> Result<()> {
+    memo: Option<Vec<u8>>,
+    swap_guard: Option<SwapGuard>,
+    spl_memo: bool,
+) -> Result<OutgoingMessageReceipt> {
     // Check if bridge is paused
     require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
 
-    bridge_sol_internal(
+    let with_call = call.is_some();
+
+    let receipt = bridge_sol_internal(
         &ctx.accounts.payer,
         &ctx.accounts.from,
         &ctx.accounts.gas_fee_receiver,
+        &ctx.accounts.gas_fee_escrow,
+        &ctx.accounts.insurance_fund,
+        &ctx.accounts.payer_rate_limit_config,
+        &ctx.accounts.payer_rate_limit,
+        &ctx.accounts.protocol_fee_config,
+        &ctx.accounts.protocol_fee_treasury,
+        &ctx.accounts.dex_router_list,
         &ctx.accounts.sol_vault,
+        &mut ctx.accounts.token_deposits,
         &mut ctx.accounts.bridge,
         &mut ctx.accounts.outgoing_message,
         &ctx.accounts.system_program,
         to,
         amount,
         call,
-    )
+        memo,
+        swap_guard,
+    )?;
+
+    log_spl_memo(spl_memo, ctx.accounts.memo_program.as_ref(), || {
+        sol_transfer_summary(amount, &to, with_call)
+    })?;
+
+    Ok(receipt)
 }
 
 #[cfg(test)]
@@ -97,6 +186,8 @@ mod tests {
         solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
         system_program, InstructionData,
     };
+    use litesvm::LiteSVM;
+    use solana_account::Account as SvmAccount;
     use solana_keypair::Keypair;
     use solana_message::Message;
     use solana_signer::Signer;
@@ -106,9 +197,17 @@ mod tests {
         accounts,
         common::{bridge::Bridge, SOL_VAULT_SEED},
         instruction::BridgeSol as BridgeSolIx,
-        solana_to_base::{Call, CallType, NATIVE_SOL_PUBKEY},
+        solana_to_base::{
+            Call, CallType, PayerRateLimit, MAX_TRANSFER_MEMO_LEN, NATIVE_SOL_PUBKEY,
+            SPL_MEMO_PROGRAM_ID,
+        },
         test_utils::{
-            create_outgoing_message, setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            create_outgoing_message, dex_router_list_pda, gas_fee_escrow_pda, insurance_fund_pda,
+            mock_clock, mock_dex_router_list, mock_payer_rate_limit_config,
+            mock_protocol_fee_config, mock_transfer_gas_lane, payer_rate_limit_config_pda,
+            payer_rate_limit_pda, protocol_fee_config_pda, setup_bridge, sol_vault_state_pda,
+            token_deposits_pda, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            TEST_PROTOCOL_FEE_TREASURY,
         },
         ID,
     };
@@ -141,10 +240,20 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
+            sol_vault_state: sol_vault_state_pda(),
             sol_vault,
+            token_deposits: token_deposits_pda(&sol_vault),
             bridge: bridge_pda,
             outgoing_message,
             system_program: system_program::ID,
+            memo_program: None,
         }
         .to_account_metas(None);
 
@@ -157,6 +266,9 @@ mod tests {
                 to,
                 amount,
                 call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
             }
             .data(),
         };
@@ -257,10 +369,20 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
+            sol_vault_state: sol_vault_state_pda(),
             sol_vault,
+            token_deposits: token_deposits_pda(&sol_vault),
             bridge: bridge_pda,
             outgoing_message,
             system_program: system_program::ID,
+            memo_program: None,
         }
         .to_account_metas(None);
 
@@ -273,6 +395,9 @@ mod tests {
                 to,
                 amount,
                 call: Some(call.clone()),
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
             }
             .data(),
         };
@@ -317,6 +442,85 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bridge_sol_payer_is_from() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // The owner of the SOL funds the rent and the gas fee itself
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let amount = LAMPORTS_PER_SOL;
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+
+        let accounts = accounts::BridgeSol {
+            payer: payer.pubkey(),
+            from: payer.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
+            sol_vault_state: sol_vault_state_pda(),
+            sol_vault,
+            token_deposits: token_deposits_pda(&sol_vault),
+            bridge: bridge_pda,
+            outgoing_message,
+            system_program: system_program::ID,
+            memo_program: None,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeSolIx {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                amount,
+                call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
+            }
+            .data(),
+        };
+
+        let payer_initial_balance = svm.get_account(&payer.pubkey()).unwrap().lamports;
+        let vault_initial_balance = svm
+            .get_account(&sol_vault)
+            .map(|acc| acc.lamports)
+            .unwrap_or(0);
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to send bridge_sol transaction");
+
+        let outgoing_message_account = svm.get_account(&outgoing_message).unwrap();
+        let outgoing_message_data =
+            OutgoingMessage::try_deserialize(&mut &outgoing_message_account.data[..]).unwrap();
+        assert_eq!(outgoing_message_data.sender, payer.pubkey());
+
+        // Both the bridged amount and the rent of the message come out of the single signer
+        assert_eq!(
+            svm.get_account(&sol_vault).unwrap().lamports,
+            vault_initial_balance + amount
+        );
+        assert!(
+            svm.get_account(&payer.pubkey()).unwrap().lamports
+                < payer_initial_balance - amount - outgoing_message_account.lamports
+        );
+    }
+
     #[test]
     fn test_bridge_sol_incorrect_gas_fee_receiver() {
         let SetupBridgeResult {
@@ -350,10 +554,20 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: wrong_gas_fee_receiver.pubkey(), // Wrong receiver
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
+            sol_vault_state: sol_vault_state_pda(),
             sol_vault,
+            token_deposits: token_deposits_pda(&sol_vault),
             bridge: bridge_pda,
             outgoing_message,
             system_program: system_program::ID,
+            memo_program: None,
         }
         .to_account_metas(None);
 
@@ -366,6 +580,9 @@ mod tests {
                 to,
                 amount,
                 call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
             }
             .data(),
         };
@@ -430,10 +647,20 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
+            sol_vault_state: sol_vault_state_pda(),
             sol_vault,
+            token_deposits: token_deposits_pda(&sol_vault),
             bridge: bridge_pda,
             outgoing_message,
             system_program: system_program::ID,
+            memo_program: None,
         }
         .to_account_metas(None);
 
@@ -446,6 +673,9 @@ mod tests {
                 to,
                 amount,
                 call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
             }
             .data(),
         };
@@ -472,4 +702,659 @@ mod tests {
             error_string
         );
     }
+
+    #[test]
+    fn test_bridge_sol_fails_when_sol_vault_not_initialized() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // Remove the SOL vault state created by `setup_bridge`
+        svm.set_account(sol_vault_state_pda(), SvmAccount::default())
+            .unwrap();
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+
+        let accounts = accounts::BridgeSol {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
+            sol_vault_state: sol_vault_state_pda(),
+            sol_vault,
+            token_deposits: token_deposits_pda(&sol_vault),
+            bridge: bridge_pda,
+            outgoing_message,
+            system_program: system_program::ID,
+            memo_program: None,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeSolIx {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                amount: LAMPORTS_PER_SOL,
+                call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer, &from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("AccountNotInitialized"),
+            "Expected AccountNotInitialized error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_bridge_sol_priced_by_transfer_gas_lane() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        mock_transfer_gas_lane(&mut svm, bridge_pda, 40);
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+
+        let accounts = accounts::BridgeSol {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
+            sol_vault_state: sol_vault_state_pda(),
+            sol_vault,
+            token_deposits: token_deposits_pda(&sol_vault),
+            bridge: bridge_pda,
+            outgoing_message,
+            system_program: system_program::ID,
+            memo_program: None,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeSolIx {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                amount: LAMPORTS_PER_SOL,
+                call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer, &from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let receiver_initial_balance = svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports;
+        svm.send_transaction(tx)
+            .expect("Failed to send bridge_sol transaction");
+
+        // The transfer is charged and accounted for in the transfer lane only
+        let bridge = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge.data[..]).unwrap();
+        let gas = bridge.gas_config.gas_per_call;
+        assert_eq!(bridge.transfer_gas_lane.current_window_gas_used, gas);
+        assert_eq!(bridge.eip1559.current_window_gas_used, 0);
+        assert_eq!(
+            svm.get_account(&TEST_GAS_FEE_RECEIVER).unwrap().lamports,
+            receiver_initial_balance
+                + gas * 40 * bridge.gas_config.gas_cost_scaler
+                    / bridge.gas_config.gas_cost_scaler_dp
+        );
+    }
+
+    fn bridge_sol_with_protocol_fee(
+        protocol_fee_treasury: Pubkey,
+    ) -> (LiteSVM, Keypair, Pubkey, std::result::Result<(), String>) {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        svm.airdrop(&TEST_PROTOCOL_FEE_TREASURY, LAMPORTS_PER_SOL)
+            .unwrap();
+        mock_protocol_fee_config(&mut svm, 5, &[]);
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+
+        let accounts = accounts::BridgeSol {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury,
+            dex_router_list: dex_router_list_pda(),
+            sol_vault_state: sol_vault_state_pda(),
+            sol_vault,
+            token_deposits: token_deposits_pda(&sol_vault),
+            bridge: bridge_pda,
+            outgoing_message,
+            system_program: system_program::ID,
+            memo_program: None,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeSolIx {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                amount: LAMPORTS_PER_SOL,
+                call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer, &from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let result = svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e));
+
+        (svm, from, outgoing_message, result)
+    }
+
+    #[test]
+    fn test_bridge_sol_charges_protocol_fee() {
+        let (svm, from, outgoing_message, result) =
+            bridge_sol_with_protocol_fee(TEST_PROTOCOL_FEE_TREASURY);
+        result.expect("Failed to send bridge_sol transaction");
+
+        // 5 bps of 1 SOL
+        let fee = LAMPORTS_PER_SOL * 5 / 10_000;
+        let net_amount = LAMPORTS_PER_SOL - fee;
+
+        let outgoing_message_account = svm.get_account(&outgoing_message).unwrap();
+        let outgoing_message_data =
+            OutgoingMessage::try_deserialize(&mut &outgoing_message_account.data[..]).unwrap();
+        match outgoing_message_data.message {
+            crate::solana_to_base::Message::Transfer(transfer) => {
+                assert_eq!(transfer.amount, net_amount);
+            }
+            _ => panic!("Expected Transfer message"),
+        }
+
+        assert_eq!(
+            svm.get_account(&from.pubkey()).unwrap().lamports,
+            LAMPORTS_PER_SOL * 4
+        );
+        assert_eq!(
+            svm.get_account(&TEST_PROTOCOL_FEE_TREASURY)
+                .unwrap()
+                .lamports,
+            LAMPORTS_PER_SOL + fee
+        );
+
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        let token_deposits = svm.get_account(&token_deposits_pda(&sol_vault)).unwrap();
+        let token_deposits = TokenDeposits::try_deserialize(&mut &token_deposits.data[..]).unwrap();
+        assert_eq!(token_deposits.locked_amount, net_amount);
+    }
+
+    #[test]
+    fn test_bridge_sol_incorrect_protocol_fee_treasury() {
+        let (_, _, _, result) = bridge_sol_with_protocol_fee(Pubkey::new_unique());
+
+        let error_string = result.unwrap_err();
+        assert!(
+            error_string.contains("IncorrectProtocolFeeTreasury"),
+            "Expected IncorrectProtocolFeeTreasury error, got: {}",
+            error_string
+        );
+    }
+
+    fn bridge_sol_with_memo(
+        memo: Option<Vec<u8>>,
+        spl_memo: bool,
+        memo_program: Option<Pubkey>,
+    ) -> (LiteSVM, Pubkey, std::result::Result<Vec<String>, String>) {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+
+        let accounts = accounts::BridgeSol {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
+            sol_vault_state: sol_vault_state_pda(),
+            sol_vault,
+            token_deposits: token_deposits_pda(&sol_vault),
+            bridge: bridge_pda,
+            outgoing_message,
+            system_program: system_program::ID,
+            memo_program,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeSolIx {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                amount: LAMPORTS_PER_SOL,
+                call: None,
+                memo,
+                swap_guard: None,
+                spl_memo,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer, &from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let result = svm
+            .send_transaction(tx)
+            .map(|meta| meta.logs)
+            .map_err(|e| format!("{:?}", e));
+
+        (svm, outgoing_message, result)
+    }
+
+    #[test]
+    fn test_bridge_sol_with_memo() {
+        let memo = b"invoice-2024-0042".to_vec();
+        let (svm, outgoing_message, result) = bridge_sol_with_memo(Some(memo.clone()), false, None);
+        let logs = result.expect("Failed to send bridge_sol transaction");
+        assert!(logs.iter().any(|log| log.starts_with("Program data:")));
+
+        let outgoing_message_account = svm.get_account(&outgoing_message).unwrap();
+        assert_eq!(
+            outgoing_message_account.data.len(),
+            DISCRIMINATOR_LEN
+                + OutgoingMessage::space(MessageKind::Transfer {
+                    call_data_len: None,
+                    memo_len: Some(memo.len()),
+                    swap_guard: false,
+                })
+        );
+
+        let outgoing_message_data =
+            OutgoingMessage::try_deserialize(&mut &outgoing_message_account.data[..]).unwrap();
+        match outgoing_message_data.message {
+            crate::solana_to_base::Message::Transfer(transfer) => {
+                assert_eq!(transfer.memo, Some(memo));
+            }
+            _ => panic!("Expected Transfer message"),
+        }
+    }
+
+    #[test]
+    fn test_bridge_sol_logs_structured_event() {
+        let (_, _, result) = bridge_sol_with_memo(None, false, None);
+        let logs = result.expect("Failed to send bridge_sol transaction");
+
+        let log = logs
+            .iter()
+            .find(|log| log.starts_with("Program log: level=info op=bridge_sol nonce=0 sender="))
+            .unwrap_or_else(|| panic!("Expected a structured bridge_sol log, got: {logs:?}"));
+        assert!(log.ends_with(&format!(" amount={LAMPORTS_PER_SOL}")));
+    }
+
+    #[test]
+    fn test_bridge_sol_memo_too_long() {
+        let (_, _, result) = bridge_sol_with_memo(
+            Some(vec![0xab; MAX_TRANSFER_MEMO_LEN as usize + 1]),
+            false,
+            None,
+        );
+
+        let error_string = result.unwrap_err();
+        assert!(
+            error_string.contains("TransferMemoTooLong"),
+            "Expected TransferMemoTooLong error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_bridge_sol_with_spl_memo() {
+        let (_, _, result) = bridge_sol_with_memo(None, true, Some(SPL_MEMO_PROGRAM_ID));
+        let logs = result.expect("Failed to send bridge_sol transaction");
+
+        let summary = format!("Bridge 1 SOL → 0x{}", "01".repeat(20));
+        assert!(
+            logs.iter()
+                .any(|log| log.starts_with("Program log: Memo") && log.contains(&summary)),
+            "Expected an SPL memo with {summary}, got: {logs:?}"
+        );
+    }
+
+    #[test]
+    fn test_bridge_sol_without_spl_memo_skips_memo_program() {
+        let (_, _, result) = bridge_sol_with_memo(None, false, Some(SPL_MEMO_PROGRAM_ID));
+        let logs = result.expect("Failed to send bridge_sol transaction");
+        assert!(!logs
+            .iter()
+            .any(|log| log.contains(&SPL_MEMO_PROGRAM_ID.to_string())));
+    }
+
+    #[test]
+    fn test_bridge_sol_spl_memo_requires_memo_program() {
+        let (_, _, result) = bridge_sol_with_memo(None, true, None);
+
+        let error_string = result.unwrap_err();
+        assert!(
+            error_string.contains("MissingMemoProgram"),
+            "Expected MissingMemoProgram error, got: {}",
+            error_string
+        );
+    }
+
+    const DEX_ROUTER: [u8; 20] = [9u8; 20];
+
+    fn bridge_sol_with_swap_guard(
+        swap_guard: Option<SwapGuard>,
+    ) -> (LiteSVM, Pubkey, std::result::Result<(), String>) {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        mock_dex_router_list(&mut svm, &[DEX_ROUTER]);
+        mock_clock(&mut svm, 1_000);
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+
+        let accounts = accounts::BridgeSol {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
+            sol_vault_state: sol_vault_state_pda(),
+            sol_vault,
+            token_deposits: token_deposits_pda(&sol_vault),
+            bridge: bridge_pda,
+            outgoing_message,
+            system_program: system_program::ID,
+            memo_program: None,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeSolIx {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                amount: LAMPORTS_PER_SOL,
+                call: Some(Call {
+                    ty: CallType::Call,
+                    to: DEX_ROUTER,
+                    value: 0,
+                    data: vec![0x38, 0xed, 0x17, 0x39],
+                }),
+                memo: None,
+                swap_guard,
+                spl_memo: false,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer, &from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let result = svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e));
+
+        (svm, outgoing_message, result)
+    }
+
+    #[test]
+    fn test_bridge_sol_router_call_requires_swap_guard() {
+        let (_, _, result) = bridge_sol_with_swap_guard(None);
+
+        let error_string = result.unwrap_err();
+        assert!(
+            error_string.contains("MissingSwapGuard"),
+            "Expected MissingSwapGuard error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_bridge_sol_with_swap_guard() {
+        let swap_guard = SwapGuard {
+            token_out: [7u8; 20],
+            min_out: 500,
+            deadline: 1_060,
+        };
+        let (svm, outgoing_message, result) = bridge_sol_with_swap_guard(Some(swap_guard));
+        result.expect("Failed to send bridge_sol transaction");
+
+        let outgoing_message_account = svm.get_account(&outgoing_message).unwrap();
+        let outgoing_message_data =
+            OutgoingMessage::try_deserialize(&mut &outgoing_message_account.data[..]).unwrap();
+        match outgoing_message_data.message {
+            crate::solana_to_base::Message::Transfer(transfer) => {
+                assert_eq!(transfer.swap_guard, Some(swap_guard));
+            }
+            _ => panic!("Expected Transfer message"),
+        }
+    }
+
+    #[test]
+    fn test_bridge_sol_swap_guard_expired() {
+        let (_, _, result) = bridge_sol_with_swap_guard(Some(SwapGuard {
+            token_out: [7u8; 20],
+            min_out: 500,
+            deadline: 1_000,
+        }));
+
+        let error_string = result.unwrap_err();
+        assert!(
+            error_string.contains("SwapGuardExpired"),
+            "Expected SwapGuardExpired error, got: {}",
+            error_string
+        );
+    }
+
+    fn send_bridge_sol_from_payer(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+    ) -> std::result::Result<(), String> {
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::BridgeSol {
+                payer: payer.pubkey(),
+                from: payer.pubkey(),
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                gas_fee_escrow: gas_fee_escrow_pda(),
+                insurance_fund: insurance_fund_pda(),
+                payer_rate_limit_config: payer_rate_limit_config_pda(),
+                payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+                protocol_fee_config: protocol_fee_config_pda(),
+                protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+                dex_router_list: dex_router_list_pda(),
+                sol_vault_state: sol_vault_state_pda(),
+                sol_vault,
+                token_deposits: token_deposits_pda(&sol_vault),
+                bridge: bridge_pda,
+                outgoing_message,
+                system_program: system_program::ID,
+                memo_program: None,
+            }
+            .to_account_metas(None),
+            data: BridgeSolIx {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                amount: LAMPORTS_PER_SOL / 10,
+                call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_bridge_sol_rate_limits_payer() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        mock_payer_rate_limit_config(&mut svm, 60, 2, &[]);
+
+        send_bridge_sol_from_payer(&mut svm, &payer, bridge_pda).unwrap();
+        send_bridge_sol_from_payer(&mut svm, &payer, bridge_pda).unwrap();
+
+        // The rate limit of the payer is created on its first message
+        let rate_limit_account = svm
+            .get_account(&payer_rate_limit_pda(&payer.pubkey()))
+            .unwrap();
+        assert_eq!(rate_limit_account.owner, ID);
+        let rate_limit =
+            PayerRateLimit::try_deserialize(&mut &rate_limit_account.data[..]).unwrap();
+        assert_eq!(rate_limit.message_count, 2);
+        assert_eq!(rate_limit.gas_used, 2 * 100_000);
+
+        let error = send_bridge_sol_from_payer(&mut svm, &payer, bridge_pda).unwrap_err();
+        assert!(
+            error.contains("PayerRateLimited"),
+            "Expected PayerRateLimited error, got: {}",
+            error
+        );
+
+        // Other payers are limited separately
+        let other_payer = Keypair::new();
+        svm.airdrop(&other_payer.pubkey(), LAMPORTS_PER_SOL)
+            .unwrap();
+        send_bridge_sol_from_payer(&mut svm, &other_payer, bridge_pda).unwrap();
+
+        // A new window starts once the current one has elapsed
+        mock_clock(&mut svm, rate_limit.window_start + 60);
+        send_bridge_sol_from_payer(&mut svm, &payer, bridge_pda).unwrap();
+    }
+
+    #[test]
+    fn test_bridge_sol_rate_limit_exempt_payer() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        mock_payer_rate_limit_config(&mut svm, 60, 1, &[payer.pubkey()]);
+
+        send_bridge_sol_from_payer(&mut svm, &payer, bridge_pda).unwrap();
+        send_bridge_sol_from_payer(&mut svm, &payer, bridge_pda).unwrap();
+
+        // Exempt payers do not pay for a rate limit account
+        assert!(svm
+            .get_account(&payer_rate_limit_pda(&payer.pubkey()))
+            .is_none());
+    }
 }
@@ -0,0 +1,461 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+};
+
+use crate::{
+    common::{
+        bridge::{Bridge, GasLane},
+        ProtocolFeeConfig, SolVaultState, TokenDeposits, BRIDGE_SEED, DISCRIMINATOR_LEN,
+        INSURANCE_FUND_SEED, PAYER_RATE_LIMIT_CONFIG_SEED, PROTOCOL_FEE_CONFIG_SEED,
+        SOL_VAULT_SEED, SOL_VAULT_STATE_SEED, TOKEN_DEPOSITS_SEED,
+    },
+    solana_to_base::{
+        commit_outgoing_message, pay_for_gas, BatchRecipient, MessageKind, OutgoingMessage,
+        OutgoingMessageReceipt, TransferBatch, MAX_TRANSFER_BATCH_RECIPIENTS, NATIVE_SOL_PUBKEY,
+        OUTGOING_MESSAGE_SEED, TRANSFER_BATCH_GAS_PER_RECIPIENT,
+    },
+    BridgeError, ProtocolFeeCharged,
+};
+
+/// Accounts struct for the bridge_sol_batch instruction that transfers native SOL from Solana to
+/// several recipients on Base at once.
+///
+/// The bridged SOLs are locked in the SOL vault in a single transfer and a single outgoing message
+/// is created to mint the corresponding tokens to every recipient on Base.
+#[derive(Accounts)]
+#[instruction(outgoing_message_salt: [u8; 32], recipients: Vec<BatchRecipient>)]
+pub struct BridgeSolBatch<'info> {
+    /// The account that pays for transaction fees and account creation.
+    /// Must be mutable to deduct lamports for account rent and gas fees.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The account that owns the SOL tokens being bridged.
+    /// Must sign the transaction to authorize the transfer of their SOL.
+    #[account(mut)]
+    pub from: Signer<'info>,
+
+    /// The account that receives payment for the gas costs of bridging SOL to Base.
+    /// CHECK: This account is validated to be the same as bridge.gas_config.gas_fee_receiver
+    #[account(mut, address = bridge.gas_config.gas_fee_receiver @ BridgeError::IncorrectGasFeeReceiver)]
+    pub gas_fee_receiver: AccountInfo<'info>,
+
+    /// Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited.
+    /// CHECK: PDA with GAS_FEE_ESCROW_SEED, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub gas_fee_escrow: AccountInfo<'info>,
+
+    /// Insurance fund credited with its share of the gas fee. No share is taken while it is
+    /// uninitialized.
+    /// CHECK: PDA with INSURANCE_FUND_SEED, deserialized only if owned by this program.
+    #[account(mut, seeds = [INSURANCE_FUND_SEED], bump)]
+    pub insurance_fund: AccountInfo<'info>,
+
+    /// Payer rate limiting configuration. Payers are not rate limited while it is uninitialized.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_CONFIG_SEED, deserialized only if owned by this program.
+    #[account(seeds = [PAYER_RATE_LIMIT_CONFIG_SEED], bump)]
+    pub payer_rate_limit_config: AccountInfo<'info>,
+
+    /// Outgoing messages paid for by the payer in the current rate limiting window, created on its
+    /// first message while rate limiting is enabled.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_SEED and the payer, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub payer_rate_limit: AccountInfo<'info>,
+
+    /// Protocol fee configuration. No protocol fee is charged while it is uninitialized.
+    /// CHECK: PDA with PROTOCOL_FEE_CONFIG_SEED, deserialized only if owned by this program.
+    #[account(seeds = [PROTOCOL_FEE_CONFIG_SEED], bump)]
+    pub protocol_fee_config: AccountInfo<'info>,
+
+    /// The treasury credited with the protocol fee. Ignored when no protocol fee is charged.
+    /// CHECK: Validated against `protocol_fee_config.treasury` when a protocol fee is charged.
+    #[account(mut)]
+    pub protocol_fee_treasury: AccountInfo<'info>,
+
+    /// The SOL vault state recording the vault bump.
+    /// Bridging SOL requires the vault to have been initialized with `init_sol_vault`.
+    #[account(seeds = [SOL_VAULT_STATE_SEED], bump)]
+    pub sol_vault_state: Account<'info, SolVaultState>,
+
+    /// The SOL vault account that holds the locked SOL.
+    /// - Uses PDA with SOL_VAULT_SEED and the bump recorded in `sol_vault_state`
+    /// - Mutable to receive the locked SOL
+    ///
+    /// CHECK: This is the SOL vault account.
+    #[account(mut, seeds = [SOL_VAULT_SEED], bump = sol_vault_state.bump)]
+    pub sol_vault: AccountInfo<'info>,
+
+    /// Accounting for the amount locked in the SOL vault.
+    /// - PDA of this program using `[TOKEN_DEPOSITS_SEED, sol_vault]`
+    /// - Created on the first deposit; `locked_amount` is incremented by the bridged amount
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [TOKEN_DEPOSITS_SEED, sol_vault.key().as_ref()],
+        bump,
+        space = DISCRIMINATOR_LEN + TokenDeposits::INIT_SPACE
+    )]
+    pub token_deposits: Account<'info, TokenDeposits>,
+
+    /// The main bridge state account that tracks nonces and fee parameters.
+    /// - Uses PDA with BRIDGE_SEED for deterministic address
+    /// - Mutable to increment nonce and update EIP1559 fee data
+    #[account(mut, seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The outgoing message account that stores the transfer batch.
+    /// - Created fresh for each bridge operation
+    /// - Payer funds the account creation
+    /// - Space allocated dynamically based on the number of recipients
+    #[account(
+        init,
+        payer = payer,
+        seeds = [OUTGOING_MESSAGE_SEED, outgoing_message_salt.as_ref()],
+        bump,
+        space = DISCRIMINATOR_LEN + OutgoingMessage::space(MessageKind::TransferBatch { recipients: recipients.len() }),
+    )]
+    pub outgoing_message: Account<'info, OutgoingMessage>,
+
+    /// System program required for SOL transfers and account creation.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn bridge_sol_batch_handler(
+    ctx: Context<BridgeSolBatch>,
+    _outgoing_message_salt: [u8; 32],
+    recipients: Vec<BatchRecipient>,
+) -> Result<OutgoingMessageReceipt> {
+    // Check if bridge is paused
+    require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
+
+    require!(!recipients.is_empty(), BridgeError::EmptyTransferBatch);
+    require!(
+        recipients.len() <= MAX_TRANSFER_BATCH_RECIPIENTS as usize,
+        BridgeError::TooManyBatchRecipients
+    );
+
+    // Skim the protocol fee, if any, from each recipient so that only the net amounts are bridged.
+    let protocol_fee_config = ProtocolFeeConfig::load(&ctx.accounts.protocol_fee_config)?;
+    let mut amount = 0;
+    let mut fee = 0;
+    let recipients = recipients
+        .into_iter()
+        .map(|recipient| {
+            let recipient_fee = protocol_fee_config
+                .as_ref()
+                .map_or(0, |config| config.fee(&NATIVE_SOL_PUBKEY, recipient.amount));
+            amount += recipient.amount;
+            fee += recipient_fee;
+
+            BatchRecipient {
+                to: recipient.to,
+                amount: recipient.amount - recipient_fee,
+            }
+        })
+        .collect::<Vec<_>>();
+    let net_amount = amount
+        .checked_sub(fee)
+        .ok_or(BridgeError::ProtocolFeeExceedsAmount)?;
+
+    let bridge = &mut ctx.accounts.bridge;
+    let extra_recipients = recipients.len() as u64 - 1;
+    let message = OutgoingMessage::new_transfer_batch(
+        bridge.nonce,
+        ctx.accounts.from.key(),
+        Clock::get()?.unix_timestamp,
+        bridge.remote_chain_id(),
+        TransferBatch {
+            local_token: NATIVE_SOL_PUBKEY,
+            remote_token: bridge.protocol_config.remote_sol_address,
+            recipients,
+        },
+    );
+
+    // The first recipient costs a whole message, the others only their own transfer on Base.
+    let gas_fee = pay_for_gas(
+        &ctx.accounts.system_program,
+        &ctx.accounts.payer,
+        &ctx.accounts.gas_fee_receiver,
+        Some(&ctx.accounts.gas_fee_escrow),
+        &ctx.accounts.insurance_fund,
+        &ctx.accounts.payer_rate_limit_config,
+        Some(&ctx.accounts.payer_rate_limit),
+        bridge,
+        GasLane::Transfer,
+        TRANSFER_BATCH_GAS_PER_RECIPIENT * extra_recipients,
+    )?;
+
+    if let Some(config) = protocol_fee_config.filter(|_| fee > 0) {
+        require_keys_eq!(
+            ctx.accounts.protocol_fee_treasury.key(),
+            config.treasury,
+            BridgeError::IncorrectProtocolFeeTreasury
+        );
+
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.from.to_account_info(),
+                to: ctx.accounts.protocol_fee_treasury.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_ctx, fee)?;
+
+        emit!(ProtocolFeeCharged {
+            from: ctx.accounts.from.key(),
+            local_token: NATIVE_SOL_PUBKEY,
+            treasury: ctx.accounts.protocol_fee_treasury.key(),
+            amount,
+            fee,
+            net_amount,
+            bps: config.bps(&NATIVE_SOL_PUBKEY),
+        });
+    }
+
+    // Lock the sol of every recipient from the user into the SOL vault at once.
+    let cpi_ctx = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.from.to_account_info(),
+            to: ctx.accounts.sol_vault.to_account_info(),
+        },
+    );
+    system_program::transfer(cpi_ctx, net_amount)?;
+
+    let token_deposits = &mut ctx.accounts.token_deposits;
+    token_deposits.vault = ctx.accounts.sol_vault.key();
+    token_deposits.locked_amount += net_amount;
+
+    log_event!(
+        info,
+        op = "bridge_sol_batch",
+        nonce = ctx.accounts.bridge.nonce,
+        sender = ctx.accounts.from.key(),
+        amount = net_amount,
+    );
+
+    let receipt = commit_outgoing_message(
+        &mut ctx.accounts.bridge,
+        &ctx.accounts.outgoing_message.key(),
+        &message,
+        gas_fee,
+        fee,
+    )?;
+    *ctx.accounts.outgoing_message = message;
+    ctx.accounts.bridge.nonce += 1;
+
+    Ok(receipt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::BridgeSolBatch as BridgeSolBatchIx,
+        test_utils::{
+            create_outgoing_message, gas_fee_escrow_pda, insurance_fund_pda,
+            mock_protocol_fee_config, payer_rate_limit_config_pda, payer_rate_limit_pda,
+            protocol_fee_config_pda, setup_bridge, sol_vault_state_pda, token_deposits_pda,
+            SetupBridgeResult, TEST_GAS_FEE_RECEIVER, TEST_PROTOCOL_FEE_TREASURY,
+        },
+        ID,
+    };
+
+    fn recipients(count: usize) -> Vec<BatchRecipient> {
+        (0..count)
+            .map(|i| BatchRecipient {
+                to: [i as u8 + 1; 20],
+                amount: LAMPORTS_PER_SOL / 10 * (i as u64 + 1),
+            })
+            .collect()
+    }
+
+    fn bridge_sol_batch(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        from: &Keypair,
+        bridge_pda: Pubkey,
+        recipients: Vec<BatchRecipient>,
+    ) -> (Pubkey, std::result::Result<(), String>) {
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+
+        let accounts = accounts::BridgeSolBatch {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            sol_vault_state: sol_vault_state_pda(),
+            sol_vault,
+            token_deposits: token_deposits_pda(&sol_vault),
+            bridge: bridge_pda,
+            outgoing_message,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeSolBatchIx {
+                outgoing_message_salt,
+                recipients,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer, from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let result = svm
+            .send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e));
+
+        (outgoing_message, result)
+    }
+
+    #[test]
+    fn test_bridge_sol_batch_success() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        svm.airdrop(&TEST_PROTOCOL_FEE_TREASURY, LAMPORTS_PER_SOL)
+            .unwrap();
+        mock_protocol_fee_config(&mut svm, 5, &[]);
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        let recipients = recipients(3);
+        let amount: u64 = recipients.iter().map(|r| r.amount).sum();
+        let (outgoing_message, result) =
+            bridge_sol_batch(&mut svm, &payer, &from, bridge_pda, recipients.clone());
+        result.expect("Failed to send bridge_sol_batch transaction");
+
+        // 5 bps of each recipient amount
+        let fees = recipients
+            .iter()
+            .map(|r| r.amount * 5 / 10_000)
+            .collect::<Vec<_>>();
+        let fee: u64 = fees.iter().sum();
+
+        let outgoing_message_account = svm.get_account(&outgoing_message).unwrap();
+        let outgoing_message_data =
+            OutgoingMessage::try_deserialize(&mut &outgoing_message_account.data[..]).unwrap();
+        assert_eq!(outgoing_message_data.sender, from.pubkey());
+
+        let bridge = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge.data[..]).unwrap();
+        match outgoing_message_data.message {
+            crate::solana_to_base::Message::TransferBatch(batch) => {
+                assert_eq!(batch.local_token, NATIVE_SOL_PUBKEY);
+                assert_eq!(
+                    batch.remote_token,
+                    bridge.protocol_config.remote_sol_address
+                );
+                assert_eq!(batch.recipients.len(), recipients.len());
+                for ((sent, requested), fee) in batch.recipients.iter().zip(&recipients).zip(&fees)
+                {
+                    assert_eq!(sent.to, requested.to);
+                    assert_eq!(sent.amount, requested.amount - fee);
+                }
+            }
+            _ => panic!("Expected TransferBatch message"),
+        }
+
+        assert_eq!(bridge.nonce, 1);
+        assert_eq!(
+            bridge.eip1559.current_window_gas_used,
+            bridge.gas_config.gas_per_call + 2 * TRANSFER_BATCH_GAS_PER_RECIPIENT
+        );
+
+        assert_eq!(
+            svm.get_account(&from.pubkey()).unwrap().lamports,
+            LAMPORTS_PER_SOL * 5 - amount
+        );
+        assert_eq!(
+            svm.get_account(&TEST_PROTOCOL_FEE_TREASURY)
+                .unwrap()
+                .lamports,
+            LAMPORTS_PER_SOL + fee
+        );
+
+        let sol_vault = Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0;
+        let token_deposits = svm.get_account(&token_deposits_pda(&sol_vault)).unwrap();
+        let token_deposits = TokenDeposits::try_deserialize(&mut &token_deposits.data[..]).unwrap();
+        assert_eq!(token_deposits.locked_amount, amount - fee);
+    }
+
+    #[test]
+    fn test_bridge_sol_batch_empty_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        let (_, result) = bridge_sol_batch(&mut svm, &payer, &from, bridge_pda, vec![]);
+        let error_string = result.unwrap_err();
+        assert!(
+            error_string.contains("EmptyTransferBatch"),
+            "Expected EmptyTransferBatch error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_bridge_sol_batch_too_many_recipients_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        let recipients = vec![
+            BatchRecipient {
+                to: [1u8; 20],
+                amount: 1,
+            };
+            MAX_TRANSFER_BATCH_RECIPIENTS as usize + 1
+        ];
+        let (_, result) = bridge_sol_batch(&mut svm, &payer, &from, bridge_pda, recipients);
+        let error_string = result.unwrap_err();
+        assert!(
+            error_string.contains("TooManyBatchRecipients"),
+            "Expected TooManyBatchRecipients error, got: {}",
+            error_string
+        );
+    }
+}
@@ -2,10 +2,18 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, TOKEN_VAULT_SEED},
+    common::{
+        bridge::Bridge, TokenDeposits, BRIDGE_SEED, DEX_ROUTER_LIST_SEED, DISCRIMINATOR_LEN,
+        INSURANCE_FUND_SEED, MINT_ALLOW_LIST_SEED, MINT_POLICY_SEED, PAYER_RATE_LIMIT_CONFIG_SEED,
+        PROTOCOL_FEE_CONFIG_SEED, TOKEN_DEPOSITS_SEED, TOKEN_VAULT_SEED, VAULT_DECIMALS_SEED,
+    },
     solana_to_base::{
-        internal::bridge_spl::bridge_spl_internal, Call, OutgoingMessage, Transfer,
-        OUTGOING_MESSAGE_SEED,
+        internal::{
+            bridge_spl::bridge_spl_internal,
+            spl_memo::{log_spl_memo, token_transfer_summary},
+        },
+        resolve_bridge_amount, BridgeSession, Call, MessageKind, OutgoingMessage,
+        OutgoingMessageReceipt, SwapGuard, OUTGOING_MESSAGE_SEED, SPL_MEMO_PROGRAM_ID,
     },
     BridgeError,
 };
@@ -17,7 +25,7 @@ use crate::{
 /// to mint corresponding tokens and execute the optional call on Base. If the token charges
 /// transfer fees, the outgoing message records the net amount actually received by the vault.
 #[derive(Accounts)]
-#[instruction(outgoing_message_salt: [u8; 32], _to: [u8; 20], remote_token: [u8; 20], _amount: u64, call: Option<Call>)]
+#[instruction(outgoing_message_salt: [u8; 32], _to: [u8; 20], remote_token: [u8; 20], _amount: u64, call: Option<Call>, memo: Option<Vec<u8>>, swap_guard: Option<SwapGuard>)]
 pub struct BridgeSpl<'info> {
     /// The account that pays for transaction fees and account creation.
     /// Must be mutable to deduct lamports for gas fees and new account rent.
@@ -26,7 +34,7 @@ pub struct BridgeSpl<'info> {
 
     /// The token authority authorizing the transfer of SPL tokens.
     /// This signer must be the owner or an approved delegate for the source token account.
-    #[account(mut)]
+    /// It pays for nothing, so it needs no lamports when `payer` sponsors the transaction.
     pub from: Signer<'info>,
 
     /// The account that receives payment for the gas costs of bridging the SPL token to Base.
@@ -34,6 +42,58 @@ pub struct BridgeSpl<'info> {
     #[account(mut, address = bridge.gas_config.gas_fee_receiver @ BridgeError::IncorrectGasFeeReceiver)]
     pub gas_fee_receiver: AccountInfo<'info>,
 
+    /// Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited.
+    /// Only required when it is used.
+    /// CHECK: PDA with GAS_FEE_ESCROW_SEED, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub gas_fee_escrow: Option<UncheckedAccount<'info>>,
+
+    /// Insurance fund credited with its share of the gas fee. No share is taken while it is
+    /// uninitialized.
+    /// CHECK: PDA with INSURANCE_FUND_SEED, deserialized only if owned by this program.
+    #[account(mut, seeds = [INSURANCE_FUND_SEED], bump)]
+    pub insurance_fund: AccountInfo<'info>,
+
+    /// Payer rate limiting configuration. Payers are not rate limited while it is uninitialized.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_CONFIG_SEED, deserialized only if owned by this program.
+    #[account(seeds = [PAYER_RATE_LIMIT_CONFIG_SEED], bump)]
+    pub payer_rate_limit_config: AccountInfo<'info>,
+
+    /// Outgoing messages paid for by the payer in the current rate limiting window, created on its
+    /// first message while rate limiting is enabled. Only required while the payer is rate limited.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_SEED and the payer, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub payer_rate_limit: Option<UncheckedAccount<'info>>,
+
+    /// Protocol fee configuration. No protocol fee is charged while it is uninitialized.
+    /// CHECK: PDA with PROTOCOL_FEE_CONFIG_SEED, deserialized only if owned by this program.
+    #[account(seeds = [PROTOCOL_FEE_CONFIG_SEED], bump)]
+    pub protocol_fee_config: AccountInfo<'info>,
+
+    /// The treasury token account of `mint` credited with the protocol fee.
+    /// Only required when a protocol fee is charged.
+    /// CHECK: Validated against `protocol_fee_config.treasury` when a protocol fee is charged.
+    #[account(mut)]
+    pub protocol_fee_treasury: Option<UncheckedAccount<'info>>,
+
+    /// DEX router list whose routers may only be called with a swap guard. Calls are not checked
+    /// while it is uninitialized. Only required for calls without a swap guard.
+    /// CHECK: PDA with DEX_ROUTER_LIST_SEED, deserialized only if owned by this program.
+    #[account(seeds = [DEX_ROUTER_LIST_SEED], bump)]
+    pub dex_router_list: Option<UncheckedAccount<'info>>,
+
+    /// Guardian-maintained policy restricting the freeze authority of bridged mints.
+    /// No freeze authority policy is enforced while it is uninitialized.
+    /// CHECK: PDA with MINT_POLICY_SEED, deserialized only if owned by this program.
+    #[account(seeds = [MINT_POLICY_SEED], bump)]
+    pub mint_policy: AccountInfo<'info>,
+
+    /// Guardian-maintained allow-list of the mints accepted during guarded launch.
+    /// Only required while `bridge.guarded_launch` is set.
+    /// CHECK: PDA with MINT_ALLOW_LIST_SEED, deserialized only if owned by this program.
+    #[account(seeds = [MINT_ALLOW_LIST_SEED], bump)]
+    pub mint_allow_list: Option<UncheckedAccount<'info>>,
+
     /// The SPL token mint account for the token being bridged.
     /// - Must not be a wrapped token (wrapped tokens use bridge_wrapped_token)
     /// - Used to read token decimals and validate it is not a wrapped token
@@ -68,17 +128,38 @@ pub struct BridgeSpl<'info> {
     )]
     pub token_vault: InterfaceAccount<'info, TokenAccount>,
 
+    /// Accounting for the amount locked in the token vault.
+    /// - PDA of this program using `[TOKEN_DEPOSITS_SEED, token_vault]`
+    /// - Created on the first deposit; `locked_amount` is incremented by the received amount
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [TOKEN_DEPOSITS_SEED, token_vault.key().as_ref()],
+        bump,
+        space = DISCRIMINATOR_LEN + TokenDeposits::INIT_SPACE
+    )]
+    pub token_deposits: Account<'info, TokenDeposits>,
+
+    /// Decimals of the Base token the vault is bridged to.
+    /// - The transfer amount is carried in the smallest unit of the remote token
+    /// - Amounts are not scaled while it is uninitialized
+    ///
+    /// CHECK: PDA with VAULT_DECIMALS_SEED, deserialized only if owned by this program.
+    #[account(seeds = [VAULT_DECIMALS_SEED, token_vault.key().as_ref()], bump)]
+    pub vault_decimals: AccountInfo<'info>,
+
     /// The outgoing message account that represents this bridge operation.
     /// - Contains transfer details and optional call data for the destination chain
     /// - Space is calculated based on the size of optional call data
     /// - Used by relayers to execute the bridge operation on Base
-    /// - The recorded transfer amount equals the net increase in `token_vault` balance
+    /// - The recorded transfer amount equals the net increase in `token_vault` balance, scaled
+    ///   down to the remote token decimals
     #[account(
         init,
         payer = payer,
         seeds = [OUTGOING_MESSAGE_SEED, outgoing_message_salt.as_ref()],
         bump,
-        space = DISCRIMINATOR_LEN + OutgoingMessage::space::<Transfer>(call.as_ref().map(|c| c.data.len()).unwrap_or_default()),
+        space = DISCRIMINATOR_LEN + OutgoingMessage::space(MessageKind::Transfer { call_data_len: call.as_ref().map(|c| c.data.len()), memo_len: memo.as_ref().map(Vec::len), swap_guard: swap_guard.is_some() }),
     )]
     pub outgoing_message: Account<'info, OutgoingMessage>,
 
@@ -89,8 +170,19 @@ pub struct BridgeSpl<'info> {
     /// System program required for creating the outgoing message account and
     /// initializing the token vault when needed.
     pub system_program: Program<'info, System>,
+
+    /// SPL Memo program, required when `spl_memo` is set to log a summary of the operation.
+    /// CHECK: Validated against the SPL Memo program ID.
+    #[account(address = SPL_MEMO_PROGRAM_ID)]
+    pub memo_program: Option<UncheckedAccount<'info>>,
+
+    /// Optional session letting `from` bridge as the delegate of the `from_token_account` owner.
+    /// The tokens are transferred by the session PDA and `amount` is debited from its budget.
+    #[account(mut)]
+    pub bridge_session: Option<Account<'info, BridgeSession>>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn bridge_spl_handler(
     ctx: Context<BridgeSpl>,
     _outgoing_message_salt: [u8; 32],
@@ -98,18 +190,36 @@ pub fn bridge_spl_handler(
     remote_token: [u8; 20],
     amount: u64,
     call: Option<Call>,
-) -> Result<()> {
+    memo: Option<Vec<u8>>,
+    swap_guard: Option<SwapGuard>,
+    spl_memo: bool,
+) -> Result<OutgoingMessageReceipt> {
     // Check if bridge is paused
     require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
 
-    bridge_spl_internal(
+    let amount = resolve_bridge_amount(amount, &ctx.accounts.from_token_account);
+
+    let with_call = call.is_some();
+
+    let receipt = bridge_spl_internal(
         &ctx.accounts.payer,
         &ctx.accounts.from,
         &ctx.accounts.gas_fee_receiver,
+        ctx.accounts.gas_fee_escrow.as_deref(),
+        &ctx.accounts.insurance_fund,
+        &ctx.accounts.payer_rate_limit_config,
+        ctx.accounts.payer_rate_limit.as_deref(),
+        &ctx.accounts.protocol_fee_config,
+        ctx.accounts.protocol_fee_treasury.as_deref(),
+        ctx.accounts.dex_router_list.as_deref(),
+        &ctx.accounts.mint_policy,
+        ctx.accounts.mint_allow_list.as_deref(),
         &ctx.accounts.mint,
         &ctx.accounts.from_token_account,
         &mut ctx.accounts.bridge,
         &mut ctx.accounts.token_vault,
+        &mut ctx.accounts.token_deposits,
+        &ctx.accounts.vault_decimals,
         &mut ctx.accounts.outgoing_message,
         &ctx.accounts.token_program,
         &ctx.accounts.system_program,
@@ -117,7 +227,22 @@ pub fn bridge_spl_handler(
         remote_token,
         amount,
         call,
-    )
+        memo,
+        swap_guard,
+        ctx.accounts.bridge_session.as_mut(),
+    )?;
+
+    log_spl_memo(spl_memo, ctx.accounts.memo_program.as_ref(), || {
+        token_transfer_summary(
+            amount,
+            ctx.accounts.mint.decimals,
+            &ctx.accounts.mint.key(),
+            &to,
+            with_call,
+        )
+    })?;
+
+    Ok(receipt)
 }
 
 #[cfg(test)]
@@ -136,12 +261,17 @@ mod tests {
 
     use crate::{
         accounts,
-        common::{bridge::Bridge, TOKEN_VAULT_SEED},
+        common::{bridge::Bridge, ProtocolFeeOverride, VaultDecimals, TOKEN_VAULT_SEED},
         instruction::BridgeSpl as BridgeSplIx,
-        solana_to_base::{Call, CallType},
+        solana_to_base::{Call, CallType, BRIDGE_FULL_BALANCE},
         test_utils::{
-            create_mock_mint, create_mock_token_account, create_outgoing_message, setup_bridge,
-            SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            create_mock_mint, create_mock_token_account, create_outgoing_message,
+            dex_router_list_pda, gas_fee_escrow_pda, insurance_fund_pda, mint_allow_list_pda,
+            mint_policy_pda, mock_guarded_launch, mock_mint_policy, mock_protocol_fee_config,
+            payer_rate_limit_config_pda, payer_rate_limit_pda, protocol_fee_config_pda,
+            set_mock_mint_freeze_authority, set_program_account, setup_bridge, token_deposits_pda,
+            vault_decimals_pda, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            TEST_PROTOCOL_FEE_TREASURY,
         },
         ID,
     };
@@ -194,18 +324,32 @@ mod tests {
         )
         .0;
 
-        // Build the BridgeSpl instruction accounts
+        // Build the BridgeSpl instruction accounts, omitting those of the features a plain transfer
+        // does not use
         let accounts = accounts::BridgeSpl {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: None,
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: None,
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: None,
+            dex_router_list: None,
+            mint_policy: mint_policy_pda(),
+            mint_allow_list: None,
             mint,
             from_token_account,
             bridge: bridge_pda,
             token_vault,
+            token_deposits: token_deposits_pda(&token_vault),
+            vault_decimals: vault_decimals_pda(&token_vault),
             outgoing_message,
             token_program: anchor_spl::token_interface::ID,
             system_program: system_program::ID,
+            memo_program: None,
+            bridge_session: None,
         }
         .to_account_metas(None);
 
@@ -219,6 +363,9 @@ mod tests {
                 remote_token,
                 amount,
                 call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
             }
             .data(),
         };
@@ -332,39 +479,72 @@ mod tests {
         )
         .0;
 
-        // Build the BridgeSpl instruction accounts
-        let accounts = accounts::BridgeSpl {
-            payer: payer.pubkey(),
-            from: from.pubkey(),
-            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
-            mint,
-            from_token_account,
-            bridge: bridge_pda,
-            token_vault,
-            outgoing_message,
-            token_program: anchor_spl::token_interface::ID,
-            system_program: system_program::ID,
-        }
-        .to_account_metas(None);
-
-        // Build the BridgeSpl instruction
-        let ix = Instruction {
-            program_id: ID,
-            accounts,
-            data: BridgeSplIx {
-                outgoing_message_salt,
-                to,
-                remote_token,
-                amount,
-                call: Some(call.clone()),
+        // Build the BridgeSpl instruction, with the DEX router list checking the call if provided
+        let bridge_spl_ix = |dex_router_list: Option<Pubkey>| {
+            let accounts = accounts::BridgeSpl {
+                payer: payer.pubkey(),
+                from: from.pubkey(),
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                gas_fee_escrow: Some(gas_fee_escrow_pda()),
+                insurance_fund: insurance_fund_pda(),
+                payer_rate_limit_config: payer_rate_limit_config_pda(),
+                payer_rate_limit: Some(payer_rate_limit_pda(&payer.pubkey())),
+                protocol_fee_config: protocol_fee_config_pda(),
+                protocol_fee_treasury: Some(TEST_PROTOCOL_FEE_TREASURY),
+                dex_router_list,
+                mint_policy: mint_policy_pda(),
+                mint_allow_list: Some(mint_allow_list_pda()),
+                mint,
+                from_token_account,
+                bridge: bridge_pda,
+                token_vault,
+                token_deposits: token_deposits_pda(&token_vault),
+                vault_decimals: vault_decimals_pda(&token_vault),
+                outgoing_message,
+                token_program: anchor_spl::token_interface::ID,
+                system_program: system_program::ID,
+                memo_program: None,
+                bridge_session: None,
+            }
+            .to_account_metas(None);
+
+            Instruction {
+                program_id: ID,
+                accounts,
+                data: BridgeSplIx {
+                    outgoing_message_salt,
+                    to,
+                    remote_token,
+                    amount,
+                    call: Some(call.clone()),
+                    memo: None,
+                    swap_guard: None,
+                    spl_memo: false,
+                }
+                .data(),
             }
-            .data(),
         };
 
+        // The DEX router list is required to check an unguarded call
+        let tx = Transaction::new(
+            &[&payer, &from],
+            Message::new(&[bridge_spl_ix(None)], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let error_string = format!("{:?}", svm.send_transaction(tx).unwrap_err());
+        assert!(
+            error_string.contains("MissingDexRouterList"),
+            "Expected MissingDexRouterList error, got: {}",
+            error_string
+        );
+
         // Build the transaction
         let tx = Transaction::new(
             &[&payer, &from],
-            Message::new(&[ix], Some(&payer.pubkey())),
+            Message::new(
+                &[bridge_spl_ix(Some(dex_router_list_pda()))],
+                Some(&payer.pubkey()),
+            ),
             svm.latest_blockhash(),
         );
 
@@ -451,13 +631,26 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: wrong_gas_fee_receiver.pubkey(), // Wrong receiver
+            gas_fee_escrow: Some(gas_fee_escrow_pda()),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: Some(payer_rate_limit_pda(&payer.pubkey())),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: Some(TEST_PROTOCOL_FEE_TREASURY),
+            dex_router_list: Some(dex_router_list_pda()),
+            mint_policy: mint_policy_pda(),
+            mint_allow_list: Some(mint_allow_list_pda()),
             mint,
             from_token_account,
             bridge: bridge_pda,
             token_vault,
+            token_deposits: token_deposits_pda(&token_vault),
+            vault_decimals: vault_decimals_pda(&token_vault),
             outgoing_message,
             token_program: anchor_spl::token_interface::ID,
             system_program: system_program::ID,
+            memo_program: None,
+            bridge_session: None,
         }
         .to_account_metas(None);
 
@@ -471,6 +664,9 @@ mod tests {
                 remote_token,
                 amount,
                 call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
             }
             .data(),
         };
@@ -560,13 +756,26 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: Some(gas_fee_escrow_pda()),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: Some(payer_rate_limit_pda(&payer.pubkey())),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: Some(TEST_PROTOCOL_FEE_TREASURY),
+            dex_router_list: Some(dex_router_list_pda()),
+            mint_policy: mint_policy_pda(),
+            mint_allow_list: Some(mint_allow_list_pda()),
             from_token_account,
             token_vault,
+            token_deposits: token_deposits_pda(&token_vault),
+            vault_decimals: vault_decimals_pda(&token_vault),
             mint,
             bridge: bridge_pda,
             outgoing_message,
             token_program: anchor_spl::token_interface::spl_token_2022::ID,
             system_program: system_program::ID,
+            memo_program: None,
+            bridge_session: None,
         }
         .to_account_metas(None);
 
@@ -580,6 +789,9 @@ mod tests {
                 remote_token,
                 amount,
                 call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
             }
             .data(),
         };
@@ -606,4 +818,647 @@ mod tests {
             error_string
         );
     }
+
+    #[test]
+    fn test_bridge_spl_charges_protocol_fee_override() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        let mint = Keypair::new().pubkey();
+        create_mock_mint(
+            &mut svm,
+            mint,
+            6,
+            anchor_spl::token_interface::spl_token_2022::ID,
+        );
+
+        let from_token_account = Keypair::new().pubkey();
+        create_mock_token_account(&mut svm, from_token_account, mint, from.pubkey(), 1_000_000);
+        let treasury_token_account = Keypair::new().pubkey();
+        create_mock_token_account(
+            &mut svm,
+            treasury_token_account,
+            mint,
+            TEST_PROTOCOL_FEE_TREASURY,
+            0,
+        );
+
+        // The per-mint override takes precedence over the default fee
+        mock_protocol_fee_config(
+            &mut svm,
+            5,
+            &[ProtocolFeeOverride {
+                local_token: mint,
+                bps: 100,
+            }],
+        );
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let remote_token = [2u8; 20];
+        let amount = 500_000u64;
+        let token_vault = Pubkey::find_program_address(
+            &[TOKEN_VAULT_SEED, mint.as_ref(), remote_token.as_ref()],
+            &ID,
+        )
+        .0;
+
+        let accounts = accounts::BridgeSpl {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: Some(gas_fee_escrow_pda()),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: Some(payer_rate_limit_pda(&payer.pubkey())),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: Some(treasury_token_account),
+            dex_router_list: Some(dex_router_list_pda()),
+            mint_policy: mint_policy_pda(),
+            mint_allow_list: Some(mint_allow_list_pda()),
+            mint,
+            from_token_account,
+            bridge: bridge_pda,
+            token_vault,
+            token_deposits: token_deposits_pda(&token_vault),
+            vault_decimals: vault_decimals_pda(&token_vault),
+            outgoing_message,
+            token_program: anchor_spl::token_interface::ID,
+            system_program: system_program::ID,
+            memo_program: None,
+            bridge_session: None,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeSplIx {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                remote_token,
+                amount,
+                call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer, &from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to send bridge_spl transaction");
+
+        // 100 bps of 500_000
+        let fee = 5_000u64;
+
+        let outgoing_message_account = svm.get_account(&outgoing_message).unwrap();
+        let outgoing_message_data =
+            OutgoingMessage::try_deserialize(&mut &outgoing_message_account.data[..]).unwrap();
+        match outgoing_message_data.message {
+            crate::solana_to_base::Message::Transfer(transfer) => {
+                assert_eq!(transfer.amount, amount - fee);
+            }
+            _ => panic!("Expected Transfer message"),
+        }
+
+        let token_amount = |account: &Pubkey| {
+            let account = svm.get_account(account).unwrap();
+            TokenAccount::try_deserialize(&mut &account.data[..])
+                .unwrap()
+                .amount
+        };
+        assert_eq!(token_amount(&from_token_account), 1_000_000 - amount);
+        assert_eq!(token_amount(&treasury_token_account), fee);
+        assert_eq!(token_amount(&token_vault), amount - fee);
+    }
+
+    #[test]
+    fn test_bridge_spl_full_balance() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        let mint = Keypair::new().pubkey();
+        create_mock_mint(
+            &mut svm,
+            mint,
+            6,
+            anchor_spl::token_interface::spl_token_2022::ID,
+        );
+
+        let from_token_account = Keypair::new().pubkey();
+        create_mock_token_account(&mut svm, from_token_account, mint, from.pubkey(), 1_000_000);
+        let treasury_token_account = Keypair::new().pubkey();
+        create_mock_token_account(
+            &mut svm,
+            treasury_token_account,
+            mint,
+            TEST_PROTOCOL_FEE_TREASURY,
+            0,
+        );
+
+        mock_protocol_fee_config(&mut svm, 100, &[]);
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let remote_token = [2u8; 20];
+        let token_vault = Pubkey::find_program_address(
+            &[TOKEN_VAULT_SEED, mint.as_ref(), remote_token.as_ref()],
+            &ID,
+        )
+        .0;
+
+        let accounts = accounts::BridgeSpl {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: Some(gas_fee_escrow_pda()),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: Some(payer_rate_limit_pda(&payer.pubkey())),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: Some(treasury_token_account),
+            dex_router_list: Some(dex_router_list_pda()),
+            mint_policy: mint_policy_pda(),
+            mint_allow_list: Some(mint_allow_list_pda()),
+            mint,
+            from_token_account,
+            bridge: bridge_pda,
+            token_vault,
+            token_deposits: token_deposits_pda(&token_vault),
+            vault_decimals: vault_decimals_pda(&token_vault),
+            outgoing_message,
+            token_program: anchor_spl::token_interface::ID,
+            system_program: system_program::ID,
+            memo_program: None,
+            bridge_session: None,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeSplIx {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                remote_token,
+                amount: BRIDGE_FULL_BALANCE,
+                call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer, &from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to send bridge_spl transaction");
+
+        // The whole balance is bridged, 100 bps of it being skimmed as the protocol fee
+        let amount = 1_000_000u64;
+        let fee = 10_000u64;
+
+        let outgoing_message_account = svm.get_account(&outgoing_message).unwrap();
+        let outgoing_message_data =
+            OutgoingMessage::try_deserialize(&mut &outgoing_message_account.data[..]).unwrap();
+        match outgoing_message_data.message {
+            crate::solana_to_base::Message::Transfer(transfer) => {
+                assert_eq!(transfer.amount, amount - fee);
+            }
+            _ => panic!("Expected Transfer message"),
+        }
+
+        let token_amount = |account: &Pubkey| {
+            let account = svm.get_account(account).unwrap();
+            TokenAccount::try_deserialize(&mut &account.data[..])
+                .unwrap()
+                .amount
+        };
+        assert_eq!(token_amount(&from_token_account), 0);
+        assert_eq!(token_amount(&treasury_token_account), fee);
+        assert_eq!(token_amount(&token_vault), amount - fee);
+    }
+
+    #[test]
+    fn test_bridge_spl_sponsored_by_payer() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // The token owner holds no lamports at all: the payer funds the rent and the gas fee
+        let from = Keypair::new();
+
+        let mint = Keypair::new().pubkey();
+        create_mock_mint(
+            &mut svm,
+            mint,
+            6,
+            anchor_spl::token_interface::spl_token_2022::ID,
+        );
+
+        let from_token_account = Keypair::new().pubkey();
+        create_mock_token_account(&mut svm, from_token_account, mint, from.pubkey(), 1_000_000);
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let remote_token = [2u8; 20];
+        let token_vault = Pubkey::find_program_address(
+            &[TOKEN_VAULT_SEED, mint.as_ref(), remote_token.as_ref()],
+            &ID,
+        )
+        .0;
+
+        let accounts = accounts::BridgeSpl {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: Some(gas_fee_escrow_pda()),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: Some(payer_rate_limit_pda(&payer.pubkey())),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: Some(TEST_PROTOCOL_FEE_TREASURY),
+            dex_router_list: Some(dex_router_list_pda()),
+            mint_policy: mint_policy_pda(),
+            mint_allow_list: Some(mint_allow_list_pda()),
+            mint,
+            from_token_account,
+            bridge: bridge_pda,
+            token_vault,
+            token_deposits: token_deposits_pda(&token_vault),
+            vault_decimals: vault_decimals_pda(&token_vault),
+            outgoing_message,
+            token_program: anchor_spl::token_interface::ID,
+            system_program: system_program::ID,
+            memo_program: None,
+            bridge_session: None,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeSplIx {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                remote_token,
+                amount: 500_000,
+                call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
+            }
+            .data(),
+        };
+
+        let payer_initial_balance = svm.get_account(&payer.pubkey()).unwrap().lamports;
+        let tx = Transaction::new(
+            &[&payer, &from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to send sponsored bridge_spl transaction");
+
+        // The message is sent by the token owner, at the expense of the payer
+        let outgoing_message_account = svm.get_account(&outgoing_message).unwrap();
+        let outgoing_message_data =
+            OutgoingMessage::try_deserialize(&mut &outgoing_message_account.data[..]).unwrap();
+        assert_eq!(outgoing_message_data.sender, from.pubkey());
+        let from_lamports = svm
+            .get_account(&from.pubkey())
+            .map_or(0, |account| account.lamports);
+        assert_eq!(from_lamports, 0);
+        assert!(
+            svm.get_account(&payer.pubkey()).unwrap().lamports
+                < payer_initial_balance - outgoing_message_account.lamports
+        );
+    }
+
+    /// Bridges tokens of a fresh `mint` whose freeze authority is `freeze_authority`.
+    fn bridge_spl_with_freeze_authority(
+        svm: &mut litesvm::LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        mint: Pubkey,
+        freeze_authority: Pubkey,
+    ) -> std::result::Result<(), String> {
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        create_mock_mint(
+            svm,
+            mint,
+            6,
+            anchor_spl::token_interface::spl_token_2022::ID,
+        );
+        set_mock_mint_freeze_authority(svm, mint, freeze_authority);
+
+        let from_token_account = Keypair::new().pubkey();
+        create_mock_token_account(svm, from_token_account, mint, from.pubkey(), 1_000_000);
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let remote_token = [2u8; 20];
+        let token_vault = Pubkey::find_program_address(
+            &[TOKEN_VAULT_SEED, mint.as_ref(), remote_token.as_ref()],
+            &ID,
+        )
+        .0;
+
+        let accounts = accounts::BridgeSpl {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: Some(gas_fee_escrow_pda()),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: Some(payer_rate_limit_pda(&payer.pubkey())),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: Some(TEST_PROTOCOL_FEE_TREASURY),
+            dex_router_list: Some(dex_router_list_pda()),
+            mint_policy: mint_policy_pda(),
+            mint_allow_list: Some(mint_allow_list_pda()),
+            mint,
+            from_token_account,
+            bridge: bridge_pda,
+            token_vault,
+            token_deposits: token_deposits_pda(&token_vault),
+            vault_decimals: vault_decimals_pda(&token_vault),
+            outgoing_message,
+            token_program: anchor_spl::token_interface::ID,
+            system_program: system_program::ID,
+            memo_program: None,
+            bridge_session: None,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeSplIx {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                remote_token,
+                amount: 500_000,
+                call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer, &from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_bridge_spl_rejects_freeze_authority_not_allowed() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        // Without a mint policy, mints with a freeze authority are accepted
+        bridge_spl_with_freeze_authority(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        )
+        .expect("Mint should be accepted without a mint policy");
+
+        mock_mint_policy(&mut svm, true, &[]);
+
+        let error_string = bridge_spl_with_freeze_authority(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        )
+        .unwrap_err();
+        assert!(
+            error_string.contains("FreezeAuthorityNotAllowed"),
+            "Expected FreezeAuthorityNotAllowed error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_bridge_spl_accepts_allowed_freeze_authority() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let freeze_authority = Pubkey::new_unique();
+        mock_mint_policy(&mut svm, true, &[freeze_authority]);
+
+        bridge_spl_with_freeze_authority(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            Pubkey::new_unique(),
+            freeze_authority,
+        )
+        .expect("Allowed freeze authority should be accepted");
+    }
+
+    #[test]
+    fn test_bridge_spl_during_guarded_launch_requires_allow_listed_mint() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let allowed_mint = Pubkey::new_unique();
+        mock_guarded_launch(&mut svm, bridge_pda, &[allowed_mint]);
+
+        let error_string = bridge_spl_with_freeze_authority(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        )
+        .unwrap_err();
+        assert!(
+            error_string.contains("MintNotAllowListed"),
+            "Expected MintNotAllowListed error, got: {}",
+            error_string
+        );
+
+        bridge_spl_with_freeze_authority(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            allowed_mint,
+            Pubkey::new_unique(),
+        )
+        .expect("Allow-listed mint should be accepted during guarded launch");
+    }
+
+    #[test]
+    fn test_bridge_spl_scales_amount_to_remote_decimals() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        // A 9 decimals mint bridged to a 6 decimals token on Base
+        let mint = Keypair::new().pubkey();
+        create_mock_mint(
+            &mut svm,
+            mint,
+            9,
+            anchor_spl::token_interface::spl_token_2022::ID,
+        );
+        let from_token_account = Keypair::new().pubkey();
+        create_mock_token_account(
+            &mut svm,
+            from_token_account,
+            mint,
+            from.pubkey(),
+            2_000_000_000,
+        );
+
+        let remote_token = [2u8; 20];
+        let token_vault = Pubkey::find_program_address(
+            &[TOKEN_VAULT_SEED, mint.as_ref(), remote_token.as_ref()],
+            &ID,
+        )
+        .0;
+        set_program_account(
+            &mut svm,
+            vault_decimals_pda(&token_vault),
+            &VaultDecimals {
+                local_decimals: 9,
+                remote_decimals: 6,
+            },
+        );
+
+        let mut bridge_spl = |amount: u64| {
+            let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+            let accounts = accounts::BridgeSpl {
+                payer: payer.pubkey(),
+                from: from.pubkey(),
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                gas_fee_escrow: Some(gas_fee_escrow_pda()),
+                insurance_fund: insurance_fund_pda(),
+                payer_rate_limit_config: payer_rate_limit_config_pda(),
+                payer_rate_limit: Some(payer_rate_limit_pda(&payer.pubkey())),
+                protocol_fee_config: protocol_fee_config_pda(),
+                protocol_fee_treasury: Some(TEST_PROTOCOL_FEE_TREASURY),
+                dex_router_list: Some(dex_router_list_pda()),
+                mint_policy: mint_policy_pda(),
+                mint_allow_list: Some(mint_allow_list_pda()),
+                mint,
+                from_token_account,
+                bridge: bridge_pda,
+                token_vault,
+                token_deposits: token_deposits_pda(&token_vault),
+                vault_decimals: vault_decimals_pda(&token_vault),
+                outgoing_message,
+                token_program: anchor_spl::token_interface::ID,
+                system_program: system_program::ID,
+                memo_program: None,
+                bridge_session: None,
+            }
+            .to_account_metas(None);
+
+            let ix = Instruction {
+                program_id: ID,
+                accounts,
+                data: BridgeSplIx {
+                    outgoing_message_salt,
+                    to: [1u8; 20],
+                    remote_token,
+                    amount,
+                    call: None,
+                    memo: None,
+                    swap_guard: None,
+                    spl_memo: false,
+                }
+                .data(),
+            };
+
+            let tx = Transaction::new(
+                &[&payer, &from],
+                Message::new(&[ix], Some(&payer.pubkey())),
+                svm.latest_blockhash(),
+            );
+            svm.send_transaction(tx)
+                .map(|_| outgoing_message)
+                .map_err(|e| format!("{:?}", e))
+        };
+
+        // Digits below the remote token's smallest unit cannot be bridged
+        let error = bridge_spl(1_500).unwrap_err();
+        assert!(
+            error.contains("InexactScaledAmount"),
+            "Expected InexactScaledAmount error, got: {}",
+            error
+        );
+
+        let outgoing_message = bridge_spl(1_500_000_000).expect("Failed to bridge scaled amount");
+
+        let account = svm.get_account(&outgoing_message).unwrap();
+        match OutgoingMessage::try_deserialize(&mut &account.data[..])
+            .unwrap()
+            .message
+        {
+            crate::solana_to_base::Message::Transfer(transfer) => {
+                assert_eq!(transfer.amount, 1_500_000);
+            }
+            _ => panic!("Expected Transfer message"),
+        }
+
+        // The vault accounting stays in the mint decimals
+        let account = svm.get_account(&token_deposits_pda(&token_vault)).unwrap();
+        let token_deposits = TokenDeposits::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(token_deposits.locked_amount, 1_500_000_000);
+    }
 }
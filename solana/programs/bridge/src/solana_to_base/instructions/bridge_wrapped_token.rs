@@ -5,10 +5,18 @@ use anchor_spl::{
 };
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    common::{
+        bridge::Bridge, BRIDGE_SEED, DEX_ROUTER_LIST_SEED, DISCRIMINATOR_LEN, INSURANCE_FUND_SEED,
+        PAYER_RATE_LIMIT_CONFIG_SEED, PROTOCOL_FEE_CONFIG_SEED, WRAPPED_TOKEN_CONFIRMATION_SEED,
+    },
     solana_to_base::{
-        internal::bridge_wrapped_token::bridge_wrapped_token_internal, Call, OutgoingMessage,
-        Transfer, OUTGOING_MESSAGE_SEED,
+        internal::{
+            bridge_wrapped_token::bridge_wrapped_token_internal,
+            spl_memo::{log_spl_memo, token_transfer_summary},
+        },
+        resolve_bridge_amount, BridgeSession, BurnReceipt, Call, MessageKind, OutgoingMessage,
+        OutgoingMessageReceipt, SwapGuard, BURN_RECEIPT_SEED, OUTGOING_MESSAGE_SEED,
+        SPL_MEMO_PROGRAM_ID,
     },
     BridgeError,
 };
@@ -19,7 +27,7 @@ use crate::{
 /// This instruction burns wrapped tokens on Solana and creates an outgoing message to transfer equivalent
 /// tokens and execute the optional call on Base.
 #[derive(Accounts)]
-#[instruction(outgoing_message_salt: [u8; 32], _to: [u8; 20], _amount: u64, call: Option<Call>)]
+#[instruction(outgoing_message_salt: [u8; 32], _to: [u8; 20], _amount: u64, call: Option<Call>, memo: Option<Vec<u8>>, swap_guard: Option<SwapGuard>)]
 pub struct BridgeWrappedToken<'info> {
     /// The account that pays for transaction fees and outgoing message account creation.
     /// Must be mutable to deduct lamports for account rent and gas fees.
@@ -35,12 +43,57 @@ pub struct BridgeWrappedToken<'info> {
     #[account(mut, address = bridge.gas_config.gas_fee_receiver @ BridgeError::IncorrectGasFeeReceiver)]
     pub gas_fee_receiver: AccountInfo<'info>,
 
+    /// Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited.
+    /// CHECK: PDA with GAS_FEE_ESCROW_SEED, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub gas_fee_escrow: AccountInfo<'info>,
+
+    /// Insurance fund credited with its share of the gas fee. No share is taken while it is
+    /// uninitialized.
+    /// CHECK: PDA with INSURANCE_FUND_SEED, deserialized only if owned by this program.
+    #[account(mut, seeds = [INSURANCE_FUND_SEED], bump)]
+    pub insurance_fund: AccountInfo<'info>,
+
+    /// Payer rate limiting configuration. Payers are not rate limited while it is uninitialized.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_CONFIG_SEED, deserialized only if owned by this program.
+    #[account(seeds = [PAYER_RATE_LIMIT_CONFIG_SEED], bump)]
+    pub payer_rate_limit_config: AccountInfo<'info>,
+
+    /// Outgoing messages paid for by the payer in the current rate limiting window, created on its
+    /// first message while rate limiting is enabled.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_SEED and the payer, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub payer_rate_limit: AccountInfo<'info>,
+
+    /// Protocol fee configuration. No protocol fee is charged while it is uninitialized.
+    /// CHECK: PDA with PROTOCOL_FEE_CONFIG_SEED, deserialized only if owned by this program.
+    #[account(seeds = [PROTOCOL_FEE_CONFIG_SEED], bump)]
+    pub protocol_fee_config: AccountInfo<'info>,
+
+    /// The treasury token account of `mint` credited with the protocol fee.
+    /// Ignored when no protocol fee is charged.
+    /// CHECK: Validated against `protocol_fee_config.treasury` when a protocol fee is charged.
+    #[account(mut)]
+    pub protocol_fee_treasury: AccountInfo<'info>,
+
+    /// DEX router list whose routers may only be called with a swap guard. Calls are not checked
+    /// while it is uninitialized.
+    /// CHECK: PDA with DEX_ROUTER_LIST_SEED, deserialized only if owned by this program.
+    #[account(seeds = [DEX_ROUTER_LIST_SEED], bump)]
+    pub dex_router_list: AccountInfo<'info>,
+
     /// The wrapped token mint account representing the original Base token.
     /// - Contains metadata linking to the original token on Base
     /// - Tokens will be burned from this mint
     #[account(mut)]
     pub mint: InterfaceAccount<'info, Mint>,
 
+    /// Confirmation that Base registered the wrapped token.
+    /// Only required to exist while `bridge.require_wrapped_token_confirmation` is set.
+    /// CHECK: PDA with WRAPPED_TOKEN_CONFIRMATION_SEED and the mint, checked for existence only.
+    #[account(seeds = [WRAPPED_TOKEN_CONFIRMATION_SEED, mint.key().as_ref()], bump)]
+    pub wrapped_token_confirmation: AccountInfo<'info>,
+
     /// The user's token account holding the wrapped tokens to be bridged.
     /// - Must contain sufficient token balance for the bridge amount
     /// - Tokens will be burned from this account
@@ -55,17 +108,28 @@ pub struct BridgeWrappedToken<'info> {
 
     /// The outgoing message account being created to store bridge transfer data.
     /// - Contains transfer details and optional call data for Base execution
-    /// - Space allocated based on call data size
+    /// - Space allocated based on call data and memo sizes
     /// - Will be read by Base relayers to complete the bridge operation
     #[account(
         init,
         payer = payer,
         seeds = [OUTGOING_MESSAGE_SEED, outgoing_message_salt.as_ref()],
         bump,
-        space = DISCRIMINATOR_LEN + OutgoingMessage::space::<Transfer>(call.as_ref().map(|c| c.data.len()).unwrap_or_default()),
+        space = DISCRIMINATOR_LEN + OutgoingMessage::space(MessageKind::Transfer { call_data_len: call.as_ref().map(|c| c.data.len()), memo_len: memo.as_ref().map(Vec::len), swap_guard: swap_guard.is_some() }),
     )]
     pub outgoing_message: Account<'info, OutgoingMessage>,
 
+    /// Immutable receipt of the burn, linking it to the Base recipient for compliance accounting.
+    /// - PDA with BURN_RECEIPT_SEED and the outgoing message address
+    #[account(
+        init,
+        payer = payer,
+        seeds = [BURN_RECEIPT_SEED, outgoing_message.key().as_ref()],
+        bump,
+        space = DISCRIMINATOR_LEN + BurnReceipt::INIT_SPACE,
+    )]
+    pub burn_receipt: Account<'info, BurnReceipt>,
+
     /// Token2022 program used for burning the wrapped tokens.
     /// Required for all token operations including burn_checked.
     pub token_program: Program<'info, Token2022>,
@@ -73,32 +137,74 @@ pub struct BridgeWrappedToken<'info> {
     /// System program required for creating the outgoing message account
     /// and transferring the gas payment to the `gas_fee_receiver`.
     pub system_program: Program<'info, System>,
+
+    /// SPL Memo program, required when `spl_memo` is set to log a summary of the operation.
+    /// CHECK: Validated against the SPL Memo program ID.
+    #[account(address = SPL_MEMO_PROGRAM_ID)]
+    pub memo_program: Option<UncheckedAccount<'info>>,
+
+    /// Optional session letting `from` bridge as the delegate of the `from_token_account` owner.
+    /// The tokens are burned by the session PDA and `amount` is debited from its budget.
+    #[account(mut)]
+    pub bridge_session: Option<Account<'info, BridgeSession>>,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn bridge_wrapped_token_handler(
     ctx: Context<BridgeWrappedToken>,
     _outgoing_message_salt: [u8; 32],
     to: [u8; 20],
     amount: u64,
     call: Option<Call>,
-) -> Result<()> {
+    memo: Option<Vec<u8>>,
+    swap_guard: Option<SwapGuard>,
+    spl_memo: bool,
+) -> Result<OutgoingMessageReceipt> {
     // Check if bridge is paused
     require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
 
-    bridge_wrapped_token_internal(
+    let amount = resolve_bridge_amount(amount, &ctx.accounts.from_token_account);
+
+    let with_call = call.is_some();
+
+    let receipt = bridge_wrapped_token_internal(
         &ctx.accounts.payer,
         &ctx.accounts.from,
         &ctx.accounts.gas_fee_receiver,
+        &ctx.accounts.gas_fee_escrow,
+        &ctx.accounts.insurance_fund,
+        &ctx.accounts.payer_rate_limit_config,
+        &ctx.accounts.payer_rate_limit,
+        &ctx.accounts.protocol_fee_config,
+        &ctx.accounts.protocol_fee_treasury,
+        &ctx.accounts.dex_router_list,
         &ctx.accounts.mint,
+        &ctx.accounts.wrapped_token_confirmation,
         &ctx.accounts.from_token_account,
         &mut ctx.accounts.bridge,
         &mut ctx.accounts.outgoing_message,
+        &mut ctx.accounts.burn_receipt,
         &ctx.accounts.token_program,
         &ctx.accounts.system_program,
         to,
         amount,
         call,
-    )
+        memo,
+        swap_guard,
+        ctx.accounts.bridge_session.as_mut(),
+    )?;
+
+    log_spl_memo(spl_memo, ctx.accounts.memo_program.as_ref(), || {
+        token_transfer_summary(
+            amount,
+            ctx.accounts.mint.decimals,
+            &ctx.accounts.mint.key(),
+            &to,
+            with_call,
+        )
+    })?;
+
+    Ok(receipt)
 }
 
 #[cfg(test)]
@@ -117,12 +223,15 @@ mod tests {
 
     use crate::{
         accounts,
-        common::{bridge::Bridge, PartialTokenMetadata},
+        common::{bridge::Bridge, PartialTokenMetadata, WrappedTokenConfirmation},
         instruction::BridgeWrappedToken as BridgeWrappedTokenIx,
         solana_to_base::{Call, CallType},
         test_utils::{
             create_mock_token_account, create_mock_wrapped_mint, create_outgoing_message,
-            setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            dex_router_list_pda, gas_fee_escrow_pda, insurance_fund_pda,
+            mock_require_wrapped_token_confirmation, payer_rate_limit_config_pda,
+            payer_rate_limit_pda, protocol_fee_config_pda, set_program_account, setup_bridge,
+            SetupBridgeResult, TEST_GAS_FEE_RECEIVER, TEST_PROTOCOL_FEE_TREASURY,
         },
         ID,
     };
@@ -175,12 +284,23 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
             mint: wrapped_mint,
+            wrapped_token_confirmation: WrappedTokenConfirmation::pda(&wrapped_mint),
             from_token_account,
             bridge: bridge_pda,
             outgoing_message,
+            burn_receipt: BurnReceipt::pda(&outgoing_message),
             token_program: anchor_spl::token_2022::ID,
             system_program: system_program::ID,
+            memo_program: None,
+            bridge_session: None,
         }
         .to_account_metas(None);
 
@@ -193,6 +313,9 @@ mod tests {
                 to,
                 amount,
                 call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
             }
             .data(),
         };
@@ -238,6 +361,25 @@ mod tests {
             .amount;
         assert_eq!(from_final_amount, initial_amount - amount);
 
+        // Verify the burn receipt links the burn to the Base recipient
+        let burn_receipt_account = svm
+            .get_account(&BurnReceipt::pda(&outgoing_message))
+            .unwrap();
+        assert_eq!(burn_receipt_account.owner, ID);
+        let burn_receipt =
+            BurnReceipt::try_deserialize(&mut &burn_receipt_account.data[..]).unwrap();
+        assert_eq!(
+            burn_receipt,
+            BurnReceipt {
+                burner: from.pubkey(),
+                mint: wrapped_mint,
+                amount,
+                to,
+                nonce: 0,
+                timestamp: outgoing_message_data.created_at,
+            }
+        );
+
         // Verify bridge nonce was incremented
         let bridge_account = svm.get_account(&bridge_pda).unwrap();
         let bridge_data = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
@@ -300,12 +442,23 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
             mint: wrapped_mint,
+            wrapped_token_confirmation: WrappedTokenConfirmation::pda(&wrapped_mint),
             from_token_account,
             bridge: bridge_pda,
             outgoing_message,
+            burn_receipt: BurnReceipt::pda(&outgoing_message),
             token_program: anchor_spl::token_2022::ID,
             system_program: system_program::ID,
+            memo_program: None,
+            bridge_session: None,
         }
         .to_account_metas(None);
 
@@ -318,6 +471,9 @@ mod tests {
                 to,
                 amount,
                 call: Some(call.clone()),
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
             }
             .data(),
         };
@@ -407,12 +563,23 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: wrong_gas_fee_receiver.pubkey(), // Wrong receiver
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
             mint: wrapped_mint,
+            wrapped_token_confirmation: WrappedTokenConfirmation::pda(&wrapped_mint),
             from_token_account,
             bridge: bridge_pda,
             outgoing_message,
+            burn_receipt: BurnReceipt::pda(&outgoing_message),
             token_program: anchor_spl::token_2022::ID,
             system_program: system_program::ID,
+            memo_program: None,
+            bridge_session: None,
         }
         .to_account_metas(None);
 
@@ -425,6 +592,9 @@ mod tests {
                 to,
                 amount,
                 call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
             }
             .data(),
         };
@@ -509,12 +679,23 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
             mint: wrapped_mint,
+            wrapped_token_confirmation: WrappedTokenConfirmation::pda(&wrapped_mint),
             from_token_account,
             bridge: bridge_pda,
             outgoing_message,
+            burn_receipt: BurnReceipt::pda(&outgoing_message),
             token_program: anchor_spl::token_2022::ID,
             system_program: system_program::ID,
+            memo_program: None,
+            bridge_session: None,
         }
         .to_account_metas(None);
 
@@ -527,6 +708,9 @@ mod tests {
                 to,
                 amount,
                 call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
             }
             .data(),
         };
@@ -553,4 +737,198 @@ mod tests {
             error_string
         );
     }
+
+    #[test]
+    fn test_bridge_wrapped_token_scaled_amount_overflow_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        // 500_000 * 10^77 does not fit in a u256 on Base
+        let partial_token_metadata = PartialTokenMetadata {
+            name: "Test Token".to_string(),
+            symbol: "TEST".to_string(),
+            remote_token: [1u8; 20],
+            scaler_exponent: 77,
+        };
+
+        let initial_amount = 1_000_000u64;
+        let wrapped_mint =
+            create_mock_wrapped_mint(&mut svm, initial_amount, 6, &partial_token_metadata);
+
+        let from_token_account = Keypair::new().pubkey();
+        create_mock_token_account(
+            &mut svm,
+            from_token_account,
+            wrapped_mint,
+            from.pubkey(),
+            initial_amount,
+        );
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+
+        let accounts = accounts::BridgeWrappedToken {
+            payer: payer.pubkey(),
+            from: from.pubkey(),
+            gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
+            mint: wrapped_mint,
+            wrapped_token_confirmation: WrappedTokenConfirmation::pda(&wrapped_mint),
+            from_token_account,
+            bridge: bridge_pda,
+            outgoing_message,
+            burn_receipt: BurnReceipt::pda(&outgoing_message),
+            token_program: anchor_spl::token_2022::ID,
+            system_program: system_program::ID,
+            memo_program: None,
+            bridge_session: None,
+        }
+        .to_account_metas(None);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts,
+            data: BridgeWrappedTokenIx {
+                outgoing_message_salt,
+                to: [1u8; 20],
+                amount: 500_000,
+                call: None,
+                memo: None,
+                swap_guard: None,
+                spl_memo: false,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer, &from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        let error_string = format!("{:?}", svm.send_transaction(tx).unwrap_err());
+        assert!(
+            error_string.contains("ScaledAmountOverflow"),
+            "Expected ScaledAmountOverflow error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_bridge_wrapped_token_requires_confirmation() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        mock_require_wrapped_token_confirmation(&mut svm, bridge_pda);
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL * 5).unwrap();
+
+        let partial_token_metadata = PartialTokenMetadata {
+            name: "Test Token".to_string(),
+            symbol: "TEST".to_string(),
+            remote_token: [1u8; 20],
+            scaler_exponent: 0,
+        };
+        let initial_amount = 1_000_000u64;
+        let wrapped_mint =
+            create_mock_wrapped_mint(&mut svm, initial_amount, 6, &partial_token_metadata);
+
+        let from_token_account = Keypair::new().pubkey();
+        create_mock_token_account(
+            &mut svm,
+            from_token_account,
+            wrapped_mint,
+            from.pubkey(),
+            initial_amount,
+        );
+
+        let bridge_wrapped_token = |svm: &mut litesvm::LiteSVM| {
+            let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+            let accounts = accounts::BridgeWrappedToken {
+                payer: payer.pubkey(),
+                from: from.pubkey(),
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                gas_fee_escrow: gas_fee_escrow_pda(),
+                insurance_fund: insurance_fund_pda(),
+                payer_rate_limit_config: payer_rate_limit_config_pda(),
+                payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+                protocol_fee_config: protocol_fee_config_pda(),
+                protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+                dex_router_list: dex_router_list_pda(),
+                mint: wrapped_mint,
+                wrapped_token_confirmation: WrappedTokenConfirmation::pda(&wrapped_mint),
+                from_token_account,
+                bridge: bridge_pda,
+                outgoing_message,
+                burn_receipt: BurnReceipt::pda(&outgoing_message),
+                token_program: anchor_spl::token_2022::ID,
+                system_program: system_program::ID,
+                memo_program: None,
+                bridge_session: None,
+            }
+            .to_account_metas(None);
+
+            let ix = Instruction {
+                program_id: ID,
+                accounts,
+                data: BridgeWrappedTokenIx {
+                    outgoing_message_salt,
+                    to: [1u8; 20],
+                    amount: 500_000,
+                    call: None,
+                    memo: None,
+                    swap_guard: None,
+                    spl_memo: false,
+                }
+                .data(),
+            };
+
+            let tx = Transaction::new(
+                &[&payer, &from],
+                Message::new(&[ix], Some(&payer.pubkey())),
+                svm.latest_blockhash(),
+            );
+            svm.send_transaction(tx)
+                .map(|_| ())
+                .map_err(|e| format!("{:?}", e))
+        };
+
+        // Bridging is rejected until Base confirmed the registration
+        let error = bridge_wrapped_token(&mut svm).unwrap_err();
+        assert!(
+            error.contains("WrappedTokenNotConfirmed"),
+            "Expected WrappedTokenNotConfirmed error, got: {}",
+            error
+        );
+
+        set_program_account(
+            &mut svm,
+            WrappedTokenConfirmation::pda(&wrapped_mint),
+            &WrappedTokenConfirmation {
+                mint: wrapped_mint,
+                confirmed_at: 0,
+            },
+        );
+        bridge_wrapped_token(&mut svm).unwrap();
+
+        let token_account = svm.get_account(&from_token_account).unwrap();
+        let token_account = TokenAccount::try_deserialize(&mut &token_account.data[..]).unwrap();
+        assert_eq!(token_account.amount, initial_amount - 500_000);
+    }
 }
@@ -1,10 +1,11 @@
 use anchor_lang::prelude::*;
 
-use crate::{solana_to_base::CallBuffer, BridgeError};
+use crate::{solana_to_base::CallBuffer, BridgeError, CallBufferAppended};
 
 /// Accounts struct for appending data to an existing call buffer account.
 /// This allows building up large call data over multiple transactions.
-/// Ownership is enforced via `has_one = owner` on the `call_buffer` account.
+/// Ownership is enforced via `has_one = owner` on the `call_buffer` account, and reusable buffers
+/// are rejected so that the call cannot change between uses.
 #[derive(Accounts)]
 pub struct AppendToCallBuffer<'info> {
     /// The signer authorized to modify this call buffer.
@@ -18,6 +19,7 @@ pub struct AppendToCallBuffer<'info> {
     #[account(
         mut,
         has_one = owner @ BridgeError::BufferUnauthorizedAppend,
+        constraint = !call_buffer.reusable @ BridgeError::BufferReusable,
     )]
     pub call_buffer: Account<'info, CallBuffer>,
 }
@@ -33,6 +35,14 @@ pub fn append_to_call_buffer_handler(
 ) -> Result<()> {
     let call_buffer = &mut ctx.accounts.call_buffer;
     call_buffer.data.extend_from_slice(&data);
+    call_buffer.last_modified = Clock::get()?.unix_timestamp;
+
+    emit!(CallBufferAppended {
+        call_buffer: call_buffer.key(),
+        owner: call_buffer.owner,
+        bytes_appended: data.len() as u64,
+        total_len: call_buffer.data.len() as u64,
+    });
 
     Ok(())
 }
@@ -141,9 +151,13 @@ mod tests {
         );
 
         // Send the transaction
-        svm.send_transaction(tx)
+        let meta = svm
+            .send_transaction(tx)
             .expect("Failed to send append_to_call_buffer transaction");
 
+        // Verify the CallBufferAppended event was emitted
+        assert!(meta.logs.iter().any(|log| log.starts_with("Program data:")));
+
         // Verify the data was appended correctly
         let call_buffer_account = svm.get_account(&call_buffer.pubkey()).unwrap();
         let call_buffer_data =
@@ -1,10 +1,18 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    common::{
+        bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, INSURANCE_FUND_SEED,
+        PAYER_RATE_LIMIT_CONFIG_SEED,
+    },
     solana_to_base::{
-        internal::bridge_call::bridge_call_internal, Call, CallBuffer, OutgoingMessage,
-        OUTGOING_MESSAGE_SEED,
+        internal::{
+            bridge_call::bridge_call_internal,
+            call_buffer::{release_call_buffer, use_call_buffer},
+            spl_memo::{call_summary, log_spl_memo},
+        },
+        CallBuffer, MessageKind, OutgoingMessage, OutgoingMessageReceipt, OUTGOING_MESSAGE_SEED,
+        SPL_MEMO_PROGRAM_ID,
     },
     BridgeError,
 };
@@ -30,6 +38,28 @@ pub struct BridgeCallBuffered<'info> {
     #[account(mut, address = bridge.gas_config.gas_fee_receiver @ BridgeError::IncorrectGasFeeReceiver)]
     pub gas_fee_receiver: AccountInfo<'info>,
 
+    /// Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited.
+    /// CHECK: PDA with GAS_FEE_ESCROW_SEED, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub gas_fee_escrow: AccountInfo<'info>,
+
+    /// Insurance fund credited with its share of the gas fee. No share is taken while it is
+    /// uninitialized.
+    /// CHECK: PDA with INSURANCE_FUND_SEED, deserialized only if owned by this program.
+    #[account(mut, seeds = [INSURANCE_FUND_SEED], bump)]
+    pub insurance_fund: AccountInfo<'info>,
+
+    /// Payer rate limiting configuration. Payers are not rate limited while it is uninitialized.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_CONFIG_SEED, deserialized only if owned by this program.
+    #[account(seeds = [PAYER_RATE_LIMIT_CONFIG_SEED], bump)]
+    pub payer_rate_limit_config: AccountInfo<'info>,
+
+    /// Outgoing messages paid for by the payer in the current rate limiting window, created on its
+    /// first message while rate limiting is enabled.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_SEED and the payer, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub payer_rate_limit: AccountInfo<'info>,
+
     /// The main bridge state account containing global configuration and runtime state.
     /// - PDA with `BRIDGE_SEED`
     /// - Mutable to charge gas (EIP-1559 accounting) and increment the message nonce
@@ -43,10 +73,9 @@ pub struct BridgeCallBuffered<'info> {
 
     /// The call buffer account that stores the call parameters and data.
     /// Its contents are copied into the outgoing message. The account is then
-    /// closed by Anchor (via `close = owner`), refunding its rent to `owner`.
+    /// closed, refunding its rent to `owner`, unless it is reusable.
     #[account(
         mut,
-        close = owner,
         has_one = owner @ BridgeError::BufferUnauthorizedClose,
     )]
     pub call_buffer: Account<'info, CallBuffer>,
@@ -55,48 +84,60 @@ pub struct BridgeCallBuffered<'info> {
     /// - Created fresh for each call; the provided keypair determines its address
     /// - Funded by `payer`
     /// - Space: DISCRIMINATOR_LEN + serialized `OutgoingMessage`
-    ///   Sizing uses `OutgoingMessage::space(Some(call_buffer.data.len()))`, which
-    ///   intentionally allocates for the Transfer variant (worst case) to safely
-    ///   cover the Call variant
+    ///   Sizing uses `OutgoingMessage::space` for a call of `call_buffer.data.len()` bytes
     /// - Includes `nonce` and `sender` metadata used on Base
     #[account(
         init,
         payer = payer,
         seeds = [OUTGOING_MESSAGE_SEED, outgoing_message_salt.as_ref()],
         bump,
-        space = DISCRIMINATOR_LEN + OutgoingMessage::space::<Call>(call_buffer.data.len()),
+        space = DISCRIMINATOR_LEN + OutgoingMessage::space(MessageKind::Call { data_len: call_buffer.data.len() }),
     )]
     pub outgoing_message: Account<'info, OutgoingMessage>,
 
     /// System program required for creating the outgoing message account.
     /// Used internally by Anchor for account initialization.
     pub system_program: Program<'info, System>,
+
+    /// SPL Memo program, required when `spl_memo` is set to log a summary of the operation.
+    /// CHECK: Validated against the SPL Memo program ID.
+    #[account(address = SPL_MEMO_PROGRAM_ID)]
+    pub memo_program: Option<UncheckedAccount<'info>>,
 }
 
 pub fn bridge_call_buffered_handler<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, BridgeCallBuffered<'info>>,
     _outgoing_message_salt: [u8; 32],
-) -> Result<()> {
+    spl_memo: bool,
+) -> Result<OutgoingMessageReceipt> {
     // Check if bridge is paused
     require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
 
-    let call_buffer = &ctx.accounts.call_buffer;
-    let call = Call {
-        ty: call_buffer.ty,
-        to: call_buffer.to,
-        value: call_buffer.value,
-        data: call_buffer.data.clone(),
-    };
+    let call = use_call_buffer(&mut ctx.accounts.call_buffer)?;
+    let to = call.to;
 
-    bridge_call_internal(
+    let receipt = bridge_call_internal(
         &ctx.accounts.payer,
         &ctx.accounts.from,
         &ctx.accounts.gas_fee_receiver,
+        &ctx.accounts.gas_fee_escrow,
+        &ctx.accounts.insurance_fund,
+        &ctx.accounts.payer_rate_limit_config,
+        &ctx.accounts.payer_rate_limit,
         &mut ctx.accounts.bridge,
         &mut ctx.accounts.outgoing_message,
         &ctx.accounts.system_program,
         call,
-    )
+        false,
+    )?;
+
+    release_call_buffer(&ctx.accounts.call_buffer, &ctx.accounts.owner)?;
+
+    log_spl_memo(spl_memo, ctx.accounts.memo_program.as_ref(), || {
+        call_summary(&to)
+    })?;
+
+    Ok(receipt)
 }
 
 #[cfg(test)]
@@ -107,6 +148,8 @@ mod tests {
         solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
         system_program, InstructionData,
     };
+    use litesvm::LiteSVM;
+    use solana_account::Account;
     use solana_keypair::Keypair;
     use solana_message::Message;
     use solana_signer::Signer;
@@ -118,7 +161,9 @@ mod tests {
         instruction::{BridgeCallBuffered as BridgeCallBufferedIx, InitializeCallBuffer},
         solana_to_base::CallType,
         test_utils::{
-            create_outgoing_message, setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            create_outgoing_message, gas_fee_escrow_pda, insurance_fund_pda,
+            payer_rate_limit_config_pda, payer_rate_limit_pda, setup_bridge, SetupBridgeResult,
+            TEST_GAS_FEE_RECEIVER,
         },
         ID,
     };
@@ -188,11 +233,16 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
             bridge: bridge_pda,
             owner: owner.pubkey(),
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
             system_program: system_program::ID,
+            memo_program: None,
         }
         .to_account_metas(None);
 
@@ -202,6 +252,7 @@ mod tests {
             accounts,
             data: BridgeCallBufferedIx {
                 outgoing_message_salt,
+                spl_memo: false,
             }
             .data(),
         };
@@ -325,11 +376,16 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
             bridge: bridge_pda,
             owner: unauthorized.pubkey(), // Wrong owner
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
             system_program: system_program::ID,
+            memo_program: None,
         }
         .to_account_metas(None);
 
@@ -339,6 +395,7 @@ mod tests {
             accounts,
             data: BridgeCallBufferedIx {
                 outgoing_message_salt,
+                spl_memo: false,
             }
             .data(),
         };
@@ -427,11 +484,16 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: wrong_gas_fee_receiver.pubkey(), // Wrong receiver
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
             bridge: bridge_pda,
             owner: owner.pubkey(),
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
             system_program: system_program::ID,
+            memo_program: None,
         }
         .to_account_metas(None);
 
@@ -441,6 +503,7 @@ mod tests {
             accounts,
             data: BridgeCallBufferedIx {
                 outgoing_message_salt,
+                spl_memo: false,
             }
             .data(),
         };
@@ -467,4 +530,202 @@ mod tests {
             error_string
         );
     }
+
+    /// Writes a call buffer holding `data` directly into the SVM, bypassing the
+    /// `max_call_buffer_size` cap so heap and compute usage can be measured on large buffers.
+    fn mock_call_buffer(svm: &mut LiteSVM, owner: Pubkey, data: Vec<u8>, reusable: bool) -> Pubkey {
+        let call_buffer = Pubkey::new_unique();
+
+        let mut account_data = Vec::new();
+        CallBuffer {
+            owner,
+            ty: CallType::Call,
+            to: [1u8; 20],
+            value: 0,
+            last_modified: 0,
+            reusable,
+            use_count: 0,
+            data,
+        }
+        .try_serialize(&mut account_data)
+        .unwrap();
+
+        svm.set_account(
+            call_buffer,
+            Account {
+                lamports: svm.minimum_balance_for_rent_exemption(account_data.len()),
+                data: account_data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        call_buffer
+    }
+
+    /// Bridges a call buffer holding `data_len` bytes and returns the compute units consumed.
+    fn bridge_call_buffered_compute_units(data_len: usize) -> u64 {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let call_buffer = mock_call_buffer(&mut svm, owner.pubkey(), vec![0xab; data_len], false);
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::BridgeCallBuffered {
+                payer: payer.pubkey(),
+                from: from.pubkey(),
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                gas_fee_escrow: gas_fee_escrow_pda(),
+                insurance_fund: insurance_fund_pda(),
+                payer_rate_limit_config: payer_rate_limit_config_pda(),
+                payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+                bridge: bridge_pda,
+                owner: owner.pubkey(),
+                call_buffer,
+                outgoing_message,
+                system_program: system_program::ID,
+                memo_program: None,
+            }
+            .to_account_metas(None),
+            data: BridgeCallBufferedIx {
+                outgoing_message_salt,
+                spl_memo: false,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer, &from, &owner],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let compute_units = svm
+            .send_transaction(tx)
+            .expect("Failed to send bridge_call_buffered transaction")
+            .compute_units_consumed;
+
+        // Verify the whole buffer made it into the outgoing message
+        let outgoing_message_account = svm.get_account(&outgoing_message).unwrap();
+        let outgoing_message_data =
+            OutgoingMessage::try_deserialize(&mut &outgoing_message_account.data[..]).unwrap();
+        match outgoing_message_data.message {
+            crate::solana_to_base::Message::Call(message_call) => {
+                assert_eq!(message_call.data, vec![0xab; data_len]);
+            }
+            _ => panic!("Expected Call message"),
+        }
+
+        compute_units
+    }
+
+    #[test]
+    fn test_bridge_call_buffered_large_buffer_fits_heap() {
+        // Holding two copies of a 24KB buffer would exceed the 32KB program heap
+        bridge_call_buffered_compute_units(24 * 1024);
+    }
+
+    #[test]
+    fn test_bridge_call_buffered_compute_units_regression() {
+        let small = bridge_call_buffered_compute_units(4);
+        let large = bridge_call_buffered_compute_units(16 * 1024);
+
+        // The buffer data is moved rather than copied, so compute barely grows with its size
+        assert!(
+            large - small < 10_000,
+            "Compute units grew from {} to {} for a 16KB buffer",
+            small,
+            large
+        );
+    }
+
+    #[test]
+    fn test_bridge_call_buffered_keeps_reusable_buffer() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let call_data = vec![0x12, 0x34, 0x56, 0x78];
+        let call_buffer = mock_call_buffer(&mut svm, owner.pubkey(), call_data.clone(), true);
+
+        for use_count in 1..=2 {
+            let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+            let ix = Instruction {
+                program_id: ID,
+                accounts: accounts::BridgeCallBuffered {
+                    payer: payer.pubkey(),
+                    from: from.pubkey(),
+                    gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                    gas_fee_escrow: gas_fee_escrow_pda(),
+                    insurance_fund: insurance_fund_pda(),
+                    payer_rate_limit_config: payer_rate_limit_config_pda(),
+                    payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+                    bridge: bridge_pda,
+                    owner: owner.pubkey(),
+                    call_buffer,
+                    outgoing_message,
+                    system_program: system_program::ID,
+                    memo_program: None,
+                }
+                .to_account_metas(None),
+                data: BridgeCallBufferedIx {
+                    outgoing_message_salt,
+                    spl_memo: false,
+                }
+                .data(),
+            };
+
+            let tx = Transaction::new(
+                &[&payer, &from, &owner],
+                Message::new(&[ix], Some(&payer.pubkey())),
+                svm.latest_blockhash(),
+            );
+            svm.send_transaction(tx)
+                .expect("Failed to send bridge_call_buffered transaction");
+
+            // Every use bridges the full call
+            let outgoing_message_account = svm.get_account(&outgoing_message).unwrap();
+            let outgoing_message_data =
+                OutgoingMessage::try_deserialize(&mut &outgoing_message_account.data[..]).unwrap();
+            match outgoing_message_data.message {
+                crate::solana_to_base::Message::Call(message_call) => {
+                    assert_eq!(message_call.data, call_data);
+                }
+                _ => panic!("Expected Call message"),
+            }
+
+            // The buffer is kept with its data and the use counted
+            let call_buffer_account = svm.get_account(&call_buffer).unwrap();
+            assert_eq!(call_buffer_account.owner, ID);
+            let call_buffer_data =
+                CallBuffer::try_deserialize(&mut &call_buffer_account.data[..]).unwrap();
+            assert!(call_buffer_data.reusable);
+            assert_eq!(call_buffer_data.use_count, use_count);
+            assert_eq!(call_buffer_data.data, call_data);
+        }
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge_data = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        assert_eq!(bridge_data.nonce, 2);
+    }
 }
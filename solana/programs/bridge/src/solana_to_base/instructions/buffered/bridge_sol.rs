@@ -1,10 +1,19 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, SOL_VAULT_SEED},
+    common::{
+        bridge::Bridge, SolVaultState, TokenDeposits, BRIDGE_SEED, DEX_ROUTER_LIST_SEED,
+        DISCRIMINATOR_LEN, INSURANCE_FUND_SEED, PAYER_RATE_LIMIT_CONFIG_SEED,
+        PROTOCOL_FEE_CONFIG_SEED, SOL_VAULT_SEED, SOL_VAULT_STATE_SEED, TOKEN_DEPOSITS_SEED,
+    },
     solana_to_base::{
-        internal::bridge_sol::bridge_sol_internal, Call, CallBuffer, OutgoingMessage, Transfer,
-        OUTGOING_MESSAGE_SEED,
+        internal::{
+            bridge_sol::bridge_sol_internal,
+            call_buffer::{release_call_buffer, use_call_buffer},
+            spl_memo::{log_spl_memo, sol_transfer_summary},
+        },
+        CallBuffer, MessageKind, OutgoingMessage, OutgoingMessageReceipt, OUTGOING_MESSAGE_SEED,
+        SPL_MEMO_PROGRAM_ID,
     },
     BridgeError,
 };
@@ -33,15 +42,69 @@ pub struct BridgeSolWithBufferedCall<'info> {
     #[account(mut, address = bridge.gas_config.gas_fee_receiver @ BridgeError::IncorrectGasFeeReceiver)]
     pub gas_fee_receiver: AccountInfo<'info>,
 
-    /// The SOL vault account that holds locked tokens for the specific remote token.
-    /// - PDA of this program using `[SOL_VAULT_SEED]`
+    /// Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited.
+    /// CHECK: PDA with GAS_FEE_ESCROW_SEED, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub gas_fee_escrow: AccountInfo<'info>,
+
+    /// Insurance fund credited with its share of the gas fee. No share is taken while it is
+    /// uninitialized.
+    /// CHECK: PDA with INSURANCE_FUND_SEED, deserialized only if owned by this program.
+    #[account(mut, seeds = [INSURANCE_FUND_SEED], bump)]
+    pub insurance_fund: AccountInfo<'info>,
+
+    /// Payer rate limiting configuration. Payers are not rate limited while it is uninitialized.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_CONFIG_SEED, deserialized only if owned by this program.
+    #[account(seeds = [PAYER_RATE_LIMIT_CONFIG_SEED], bump)]
+    pub payer_rate_limit_config: AccountInfo<'info>,
+
+    /// Outgoing messages paid for by the payer in the current rate limiting window, created on its
+    /// first message while rate limiting is enabled.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_SEED and the payer, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub payer_rate_limit: AccountInfo<'info>,
+
+    /// Protocol fee configuration. No protocol fee is charged while it is uninitialized.
+    /// CHECK: PDA with PROTOCOL_FEE_CONFIG_SEED, deserialized only if owned by this program.
+    #[account(seeds = [PROTOCOL_FEE_CONFIG_SEED], bump)]
+    pub protocol_fee_config: AccountInfo<'info>,
+
+    /// The treasury credited with the protocol fee. Ignored when no protocol fee is charged.
+    /// CHECK: Validated against `protocol_fee_config.treasury` when a protocol fee is charged.
+    #[account(mut)]
+    pub protocol_fee_treasury: AccountInfo<'info>,
+
+    /// DEX router list whose routers may only be called with a swap guard. Calls are not checked
+    /// while it is uninitialized.
+    /// CHECK: PDA with DEX_ROUTER_LIST_SEED, deserialized only if owned by this program.
+    #[account(seeds = [DEX_ROUTER_LIST_SEED], bump)]
+    pub dex_router_list: AccountInfo<'info>,
+
+    /// The SOL vault state recording the vault bump.
+    /// Bridging SOL requires the vault to have been initialized with `init_sol_vault`.
+    #[account(seeds = [SOL_VAULT_STATE_SEED], bump)]
+    pub sol_vault_state: Account<'info, SolVaultState>,
+
+    /// The SOL vault account that holds the locked SOL.
+    /// - Uses PDA with SOL_VAULT_SEED and the bump recorded in `sol_vault_state`
     /// - Mutable to receive the locked SOL
-    /// - Each remote token has its own dedicated vault
     ///
     /// CHECK: This is the SOL vault account.
-    #[account(mut, seeds = [SOL_VAULT_SEED], bump)]
+    #[account(mut, seeds = [SOL_VAULT_SEED], bump = sol_vault_state.bump)]
     pub sol_vault: AccountInfo<'info>,
 
+    /// Accounting for the amount locked in the SOL vault.
+    /// - PDA of this program using `[TOKEN_DEPOSITS_SEED, sol_vault]`
+    /// - Created on the first deposit; `locked_amount` is incremented by the bridged amount
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [TOKEN_DEPOSITS_SEED, sol_vault.key().as_ref()],
+        bump,
+        space = DISCRIMINATOR_LEN + TokenDeposits::INIT_SPACE
+    )]
+    pub token_deposits: Account<'info, TokenDeposits>,
+
     /// The main bridge state account that tracks nonces and fee parameters.
     /// - PDA with `BRIDGE_SEED`
     /// - Mutable to charge gas (EIP-1559 accounting) and increment the message nonce
@@ -54,10 +117,9 @@ pub struct BridgeSolWithBufferedCall<'info> {
 
     /// The call buffer account that stores the call parameters and data.
     /// Its contents are copied into the outgoing message, then the account is closed
-    /// (rent refunded to `owner`).
+    /// (rent refunded to `owner`) unless it is reusable.
     #[account(
         mut,
-        close = owner,
         has_one = owner @ BridgeError::BufferUnauthorizedClose,
     )]
     pub call_buffer: Account<'info, CallBuffer>,
@@ -71,12 +133,17 @@ pub struct BridgeSolWithBufferedCall<'info> {
         payer = payer,
         seeds = [OUTGOING_MESSAGE_SEED, outgoing_message_salt.as_ref()],
         bump,
-        space = DISCRIMINATOR_LEN + OutgoingMessage::space::<Transfer>(call_buffer.data.len())
+        space = DISCRIMINATOR_LEN + OutgoingMessage::space(MessageKind::Transfer { call_data_len: Some(call_buffer.data.len()), memo_len: None, swap_guard: false })
     )]
     pub outgoing_message: Account<'info, OutgoingMessage>,
 
     /// System program required for account creation and the SOL transfer CPI.
     pub system_program: Program<'info, System>,
+
+    /// SPL Memo program, required when `spl_memo` is set to log a summary of the operation.
+    /// CHECK: Validated against the SPL Memo program ID.
+    #[account(address = SPL_MEMO_PROGRAM_ID)]
+    pub memo_program: Option<UncheckedAccount<'info>>,
 }
 
 pub fn bridge_sol_with_buffered_call_handler<'a, 'b, 'c, 'info>(
@@ -84,30 +151,43 @@ pub fn bridge_sol_with_buffered_call_handler<'a, 'b, 'c, 'info>(
     _outgoing_message_salt: [u8; 32],
     to: [u8; 20],
     amount: u64,
-) -> Result<()> {
+    spl_memo: bool,
+) -> Result<OutgoingMessageReceipt> {
     // Check if bridge is paused
     require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
 
-    let call_buffer = &ctx.accounts.call_buffer;
-    let call = Some(Call {
-        ty: call_buffer.ty,
-        to: call_buffer.to,
-        value: call_buffer.value,
-        data: call_buffer.data.clone(),
-    });
+    let call = Some(use_call_buffer(&mut ctx.accounts.call_buffer)?);
 
-    bridge_sol_internal(
+    let receipt = bridge_sol_internal(
         &ctx.accounts.payer,
         &ctx.accounts.from,
         &ctx.accounts.gas_fee_receiver,
+        &ctx.accounts.gas_fee_escrow,
+        &ctx.accounts.insurance_fund,
+        &ctx.accounts.payer_rate_limit_config,
+        &ctx.accounts.payer_rate_limit,
+        &ctx.accounts.protocol_fee_config,
+        &ctx.accounts.protocol_fee_treasury,
+        &ctx.accounts.dex_router_list,
         &ctx.accounts.sol_vault,
+        &mut ctx.accounts.token_deposits,
         &mut ctx.accounts.bridge,
         &mut ctx.accounts.outgoing_message,
         &ctx.accounts.system_program,
         to,
         amount,
         call,
-    )
+        None,
+        None,
+    )?;
+
+    release_call_buffer(&ctx.accounts.call_buffer, &ctx.accounts.owner)?;
+
+    log_spl_memo(spl_memo, ctx.accounts.memo_program.as_ref(), || {
+        sol_transfer_summary(amount, &to, true)
+    })?;
+
+    Ok(receipt)
 }
 
 #[cfg(test)]
@@ -131,7 +211,10 @@ mod tests {
         },
         solana_to_base::{CallType, NATIVE_SOL_PUBKEY},
         test_utils::{
-            create_outgoing_message, setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            create_outgoing_message, dex_router_list_pda, gas_fee_escrow_pda, insurance_fund_pda,
+            payer_rate_limit_config_pda, payer_rate_limit_pda, protocol_fee_config_pda,
+            setup_bridge, sol_vault_state_pda, token_deposits_pda, SetupBridgeResult,
+            TEST_GAS_FEE_RECEIVER, TEST_PROTOCOL_FEE_TREASURY,
         },
         ID,
     };
@@ -209,12 +292,22 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
+            sol_vault_state: sol_vault_state_pda(),
             sol_vault,
+            token_deposits: token_deposits_pda(&sol_vault),
             bridge: bridge_pda,
             owner: owner.pubkey(),
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
             system_program: system_program::ID,
+            memo_program: None,
         }
         .to_account_metas(None);
 
@@ -226,6 +319,7 @@ mod tests {
                 outgoing_message_salt,
                 to,
                 amount,
+                spl_memo: false,
             }
             .data(),
         };
@@ -382,12 +476,22 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
+            sol_vault_state: sol_vault_state_pda(),
             sol_vault,
+            token_deposits: token_deposits_pda(&sol_vault),
             bridge: bridge_pda,
             owner: unauthorized.pubkey(), // Wrong owner
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
             system_program: system_program::ID,
+            memo_program: None,
         }
         .to_account_metas(None);
 
@@ -399,6 +503,7 @@ mod tests {
                 outgoing_message_salt,
                 to,
                 amount,
+                spl_memo: false,
             }
             .data(),
         };
@@ -494,12 +599,22 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: wrong_gas_fee_receiver.pubkey(), // Wrong receiver
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
+            sol_vault_state: sol_vault_state_pda(),
             sol_vault,
+            token_deposits: token_deposits_pda(&sol_vault),
             bridge: bridge_pda,
             owner: owner.pubkey(),
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
             system_program: system_program::ID,
+            memo_program: None,
         }
         .to_account_metas(None);
 
@@ -511,6 +626,7 @@ mod tests {
                 outgoing_message_salt,
                 to,
                 amount,
+                spl_memo: false,
             }
             .data(),
         };
@@ -2,10 +2,19 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN, TOKEN_VAULT_SEED},
+    common::{
+        bridge::Bridge, TokenDeposits, BRIDGE_SEED, DEX_ROUTER_LIST_SEED, DISCRIMINATOR_LEN,
+        INSURANCE_FUND_SEED, MINT_ALLOW_LIST_SEED, MINT_POLICY_SEED, PAYER_RATE_LIMIT_CONFIG_SEED,
+        PROTOCOL_FEE_CONFIG_SEED, TOKEN_DEPOSITS_SEED, TOKEN_VAULT_SEED, VAULT_DECIMALS_SEED,
+    },
     solana_to_base::{
-        internal::bridge_spl::bridge_spl_internal, Call, CallBuffer, OutgoingMessage, Transfer,
-        OUTGOING_MESSAGE_SEED,
+        internal::{
+            bridge_spl::bridge_spl_internal,
+            call_buffer::{release_call_buffer, use_call_buffer},
+            spl_memo::{log_spl_memo, token_transfer_summary},
+        },
+        resolve_bridge_amount, CallBuffer, MessageKind, OutgoingMessage, OutgoingMessageReceipt,
+        OUTGOING_MESSAGE_SEED, SPL_MEMO_PROGRAM_ID,
     },
     BridgeError,
 };
@@ -27,7 +36,7 @@ pub struct BridgeSplWithBufferedCall<'info> {
 
     /// The token authority authorizing the transfer of SPL tokens.
     /// This signer must be the owner or an approved delegate for the source token account.
-    #[account(mut)]
+    /// It pays for nothing, so it needs no lamports when `payer` sponsors the transaction.
     pub from: Signer<'info>,
 
     /// The account that receives payment for the gas costs of bridging the SPL token to Base.
@@ -35,6 +44,57 @@ pub struct BridgeSplWithBufferedCall<'info> {
     #[account(mut, address = bridge.gas_config.gas_fee_receiver @ BridgeError::IncorrectGasFeeReceiver)]
     pub gas_fee_receiver: AccountInfo<'info>,
 
+    /// Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited.
+    /// CHECK: PDA with GAS_FEE_ESCROW_SEED, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub gas_fee_escrow: AccountInfo<'info>,
+
+    /// Insurance fund credited with its share of the gas fee. No share is taken while it is
+    /// uninitialized.
+    /// CHECK: PDA with INSURANCE_FUND_SEED, deserialized only if owned by this program.
+    #[account(mut, seeds = [INSURANCE_FUND_SEED], bump)]
+    pub insurance_fund: AccountInfo<'info>,
+
+    /// Payer rate limiting configuration. Payers are not rate limited while it is uninitialized.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_CONFIG_SEED, deserialized only if owned by this program.
+    #[account(seeds = [PAYER_RATE_LIMIT_CONFIG_SEED], bump)]
+    pub payer_rate_limit_config: AccountInfo<'info>,
+
+    /// Outgoing messages paid for by the payer in the current rate limiting window, created on its
+    /// first message while rate limiting is enabled.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_SEED and the payer, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub payer_rate_limit: AccountInfo<'info>,
+
+    /// Protocol fee configuration. No protocol fee is charged while it is uninitialized.
+    /// CHECK: PDA with PROTOCOL_FEE_CONFIG_SEED, deserialized only if owned by this program.
+    #[account(seeds = [PROTOCOL_FEE_CONFIG_SEED], bump)]
+    pub protocol_fee_config: AccountInfo<'info>,
+
+    /// The treasury token account of `mint` credited with the protocol fee.
+    /// Ignored when no protocol fee is charged.
+    /// CHECK: Validated against `protocol_fee_config.treasury` when a protocol fee is charged.
+    #[account(mut)]
+    pub protocol_fee_treasury: AccountInfo<'info>,
+
+    /// DEX router list whose routers may only be called with a swap guard. Calls are not checked
+    /// while it is uninitialized.
+    /// CHECK: PDA with DEX_ROUTER_LIST_SEED, deserialized only if owned by this program.
+    #[account(seeds = [DEX_ROUTER_LIST_SEED], bump)]
+    pub dex_router_list: AccountInfo<'info>,
+
+    /// Guardian-maintained policy restricting the freeze authority of bridged mints.
+    /// No freeze authority policy is enforced while it is uninitialized.
+    /// CHECK: PDA with MINT_POLICY_SEED, deserialized only if owned by this program.
+    #[account(seeds = [MINT_POLICY_SEED], bump)]
+    pub mint_policy: AccountInfo<'info>,
+
+    /// Guardian-maintained allow-list of the mints accepted during guarded launch.
+    /// Only checked while `bridge.guarded_launch` is set.
+    /// CHECK: PDA with MINT_ALLOW_LIST_SEED, deserialized only if owned by this program.
+    #[account(seeds = [MINT_ALLOW_LIST_SEED], bump)]
+    pub mint_allow_list: AccountInfo<'info>,
+
     /// The SPL token mint account for the token being bridged.
     /// - Must not be a wrapped token (wrapped tokens use bridge_wrapped_token)
     /// - Used to validate transfer amounts and get token metadata
@@ -69,15 +129,34 @@ pub struct BridgeSplWithBufferedCall<'info> {
     )]
     pub token_vault: InterfaceAccount<'info, TokenAccount>,
 
+    /// Accounting for the amount locked in the token vault.
+    /// - PDA of this program using `[TOKEN_DEPOSITS_SEED, token_vault]`
+    /// - Created on the first deposit; `locked_amount` is incremented by the received amount
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [TOKEN_DEPOSITS_SEED, token_vault.key().as_ref()],
+        bump,
+        space = DISCRIMINATOR_LEN + TokenDeposits::INIT_SPACE
+    )]
+    pub token_deposits: Account<'info, TokenDeposits>,
+
+    /// Decimals of the Base token the vault is bridged to.
+    /// - The transfer amount is carried in the smallest unit of the remote token
+    /// - Amounts are not scaled while it is uninitialized
+    ///
+    /// CHECK: PDA with VAULT_DECIMALS_SEED, deserialized only if owned by this program.
+    #[account(seeds = [VAULT_DECIMALS_SEED, token_vault.key().as_ref()], bump)]
+    pub vault_decimals: AccountInfo<'info>,
+
     /// The owner of the call buffer who will receive the rent refund.
     #[account(mut)]
     pub owner: Signer<'info>,
 
     /// The call buffer account that stores the call data.
-    /// This account will be closed and rent returned to the owner.
+    /// This account will be closed and rent returned to the owner, unless it is reusable.
     #[account(
         mut,
-        close = owner,
         has_one = owner @ BridgeError::BufferUnauthorizedClose,
     )]
     pub call_buffer: Account<'info, CallBuffer>,
@@ -88,7 +167,7 @@ pub struct BridgeSplWithBufferedCall<'info> {
         payer = payer,
         seeds = [OUTGOING_MESSAGE_SEED, outgoing_message_salt.as_ref()],
         bump,
-        space = DISCRIMINATOR_LEN + OutgoingMessage::space::<Transfer>(call_buffer.data.len()),
+        space = DISCRIMINATOR_LEN + OutgoingMessage::space(MessageKind::Transfer { call_data_len: Some(call_buffer.data.len()), memo_len: None, swap_guard: false }),
     )]
     pub outgoing_message: Account<'info, OutgoingMessage>,
 
@@ -99,6 +178,11 @@ pub struct BridgeSplWithBufferedCall<'info> {
     /// System program required for creating the outgoing message account and
     /// initializing the token vault when needed.
     pub system_program: Program<'info, System>,
+
+    /// SPL Memo program, required when `spl_memo` is set to log a summary of the operation.
+    /// CHECK: Validated against the SPL Memo program ID.
+    #[account(address = SPL_MEMO_PROGRAM_ID)]
+    pub memo_program: Option<UncheckedAccount<'info>>,
 }
 
 pub fn bridge_spl_with_buffered_call_handler<'a, 'b, 'c, 'info>(
@@ -107,26 +191,34 @@ pub fn bridge_spl_with_buffered_call_handler<'a, 'b, 'c, 'info>(
     to: [u8; 20],
     remote_token: [u8; 20],
     amount: u64,
-) -> Result<()> {
+    spl_memo: bool,
+) -> Result<OutgoingMessageReceipt> {
     // Check if bridge is paused
     require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
 
-    let call_buffer = &ctx.accounts.call_buffer;
-    let call = Some(Call {
-        ty: call_buffer.ty,
-        to: call_buffer.to,
-        value: call_buffer.value,
-        data: call_buffer.data.clone(),
-    });
+    let amount = resolve_bridge_amount(amount, &ctx.accounts.from_token_account);
+
+    let call = Some(use_call_buffer(&mut ctx.accounts.call_buffer)?);
 
-    bridge_spl_internal(
+    let receipt = bridge_spl_internal(
         &ctx.accounts.payer,
         &ctx.accounts.from,
         &ctx.accounts.gas_fee_receiver,
+        Some(&ctx.accounts.gas_fee_escrow),
+        &ctx.accounts.insurance_fund,
+        &ctx.accounts.payer_rate_limit_config,
+        Some(&ctx.accounts.payer_rate_limit),
+        &ctx.accounts.protocol_fee_config,
+        Some(&ctx.accounts.protocol_fee_treasury),
+        Some(&ctx.accounts.dex_router_list),
+        &ctx.accounts.mint_policy,
+        Some(&ctx.accounts.mint_allow_list),
         &ctx.accounts.mint,
         &ctx.accounts.from_token_account,
         &mut ctx.accounts.bridge,
         &mut ctx.accounts.token_vault,
+        &mut ctx.accounts.token_deposits,
+        &ctx.accounts.vault_decimals,
         &mut ctx.accounts.outgoing_message,
         &ctx.accounts.token_program,
         &ctx.accounts.system_program,
@@ -134,7 +226,24 @@ pub fn bridge_spl_with_buffered_call_handler<'a, 'b, 'c, 'info>(
         remote_token,
         amount,
         call,
-    )
+        None,
+        None,
+        None,
+    )?;
+
+    release_call_buffer(&ctx.accounts.call_buffer, &ctx.accounts.owner)?;
+
+    log_spl_memo(spl_memo, ctx.accounts.memo_program.as_ref(), || {
+        token_transfer_summary(
+            amount,
+            ctx.accounts.mint.decimals,
+            &ctx.accounts.mint.key(),
+            &to,
+            true,
+        )
+    })?;
+
+    Ok(receipt)
 }
 
 #[cfg(test)]
@@ -159,8 +268,11 @@ mod tests {
         },
         solana_to_base::CallType,
         test_utils::{
-            create_mock_mint, create_mock_token_account, create_outgoing_message, setup_bridge,
-            SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            create_mock_mint, create_mock_token_account, create_outgoing_message,
+            dex_router_list_pda, gas_fee_escrow_pda, insurance_fund_pda, mint_allow_list_pda,
+            mint_policy_pda, payer_rate_limit_config_pda, payer_rate_limit_pda,
+            protocol_fee_config_pda, setup_bridge, token_deposits_pda, vault_decimals_pda,
+            SetupBridgeResult, TEST_GAS_FEE_RECEIVER, TEST_PROTOCOL_FEE_TREASURY,
         },
         ID,
     };
@@ -263,15 +375,27 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
+            mint_policy: mint_policy_pda(),
+            mint_allow_list: mint_allow_list_pda(),
             mint,
             from_token_account,
             bridge: bridge_pda,
             token_vault,
+            token_deposits: token_deposits_pda(&token_vault),
+            vault_decimals: vault_decimals_pda(&token_vault),
             owner: owner.pubkey(),
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
             token_program: anchor_spl::token_interface::ID,
             system_program: system_program::ID,
+            memo_program: None,
         }
         .to_account_metas(None);
 
@@ -284,6 +408,7 @@ mod tests {
                 to,
                 remote_token,
                 amount,
+                spl_memo: false,
             }
             .data(),
         };
@@ -458,15 +583,27 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
+            mint_policy: mint_policy_pda(),
+            mint_allow_list: mint_allow_list_pda(),
             mint,
             from_token_account,
             bridge: bridge_pda,
             token_vault,
+            token_deposits: token_deposits_pda(&token_vault),
+            vault_decimals: vault_decimals_pda(&token_vault),
             owner: unauthorized.pubkey(), // Wrong owner
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
             token_program: anchor_spl::token_interface::ID,
             system_program: system_program::ID,
+            memo_program: None,
         }
         .to_account_metas(None);
 
@@ -479,6 +616,7 @@ mod tests {
                 to,
                 remote_token,
                 amount,
+                spl_memo: false,
             }
             .data(),
         };
@@ -599,15 +737,27 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: wrong_gas_fee_receiver.pubkey(), // Wrong receiver
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
+            mint_policy: mint_policy_pda(),
+            mint_allow_list: mint_allow_list_pda(),
             mint,
             from_token_account,
             bridge: bridge_pda,
             token_vault,
+            token_deposits: token_deposits_pda(&token_vault),
+            vault_decimals: vault_decimals_pda(&token_vault),
             owner: owner.pubkey(),
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
             token_program: anchor_spl::token_interface::ID,
             system_program: system_program::ID,
+            memo_program: None,
         }
         .to_account_metas(None);
 
@@ -620,6 +770,7 @@ mod tests {
                 to,
                 remote_token,
                 amount,
+                spl_memo: false,
             }
             .data(),
         };
@@ -5,10 +5,18 @@ use anchor_spl::{
 };
 
 use crate::{
-    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    common::{
+        bridge::Bridge, BRIDGE_SEED, DEX_ROUTER_LIST_SEED, DISCRIMINATOR_LEN, INSURANCE_FUND_SEED,
+        PAYER_RATE_LIMIT_CONFIG_SEED, PROTOCOL_FEE_CONFIG_SEED, WRAPPED_TOKEN_CONFIRMATION_SEED,
+    },
     solana_to_base::{
-        internal::bridge_wrapped_token::bridge_wrapped_token_internal, Call, CallBuffer,
-        OutgoingMessage, Transfer, OUTGOING_MESSAGE_SEED,
+        internal::{
+            bridge_wrapped_token::bridge_wrapped_token_internal,
+            call_buffer::{release_call_buffer, use_call_buffer},
+            spl_memo::{log_spl_memo, token_transfer_summary},
+        },
+        resolve_bridge_amount, BurnReceipt, CallBuffer, MessageKind, OutgoingMessage,
+        OutgoingMessageReceipt, BURN_RECEIPT_SEED, OUTGOING_MESSAGE_SEED, SPL_MEMO_PROGRAM_ID,
     },
     BridgeError,
 };
@@ -39,12 +47,57 @@ pub struct BridgeWrappedTokenWithBufferedCall<'info> {
     )]
     pub gas_fee_receiver: AccountInfo<'info>,
 
+    /// Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited.
+    /// CHECK: PDA with GAS_FEE_ESCROW_SEED, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub gas_fee_escrow: AccountInfo<'info>,
+
+    /// Insurance fund credited with its share of the gas fee. No share is taken while it is
+    /// uninitialized.
+    /// CHECK: PDA with INSURANCE_FUND_SEED, deserialized only if owned by this program.
+    #[account(mut, seeds = [INSURANCE_FUND_SEED], bump)]
+    pub insurance_fund: AccountInfo<'info>,
+
+    /// Payer rate limiting configuration. Payers are not rate limited while it is uninitialized.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_CONFIG_SEED, deserialized only if owned by this program.
+    #[account(seeds = [PAYER_RATE_LIMIT_CONFIG_SEED], bump)]
+    pub payer_rate_limit_config: AccountInfo<'info>,
+
+    /// Outgoing messages paid for by the payer in the current rate limiting window, created on its
+    /// first message while rate limiting is enabled.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_SEED and the payer, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub payer_rate_limit: AccountInfo<'info>,
+
+    /// Protocol fee configuration. No protocol fee is charged while it is uninitialized.
+    /// CHECK: PDA with PROTOCOL_FEE_CONFIG_SEED, deserialized only if owned by this program.
+    #[account(seeds = [PROTOCOL_FEE_CONFIG_SEED], bump)]
+    pub protocol_fee_config: AccountInfo<'info>,
+
+    /// The treasury token account of `mint` credited with the protocol fee.
+    /// Ignored when no protocol fee is charged.
+    /// CHECK: Validated against `protocol_fee_config.treasury` when a protocol fee is charged.
+    #[account(mut)]
+    pub protocol_fee_treasury: AccountInfo<'info>,
+
+    /// DEX router list whose routers may only be called with a swap guard. Calls are not checked
+    /// while it is uninitialized.
+    /// CHECK: PDA with DEX_ROUTER_LIST_SEED, deserialized only if owned by this program.
+    #[account(seeds = [DEX_ROUTER_LIST_SEED], bump)]
+    pub dex_router_list: AccountInfo<'info>,
+
     /// The wrapped token mint account representing the original Base token.
     /// - Contains metadata linking to the original token on Base
     /// - Supply will be reduced by burning tokens from the user's token account for this mint
     #[account(mut)]
     pub mint: InterfaceAccount<'info, Mint>,
 
+    /// Confirmation that Base registered the wrapped token.
+    /// Only required to exist while `bridge.require_wrapped_token_confirmation` is set.
+    /// CHECK: PDA with WRAPPED_TOKEN_CONFIRMATION_SEED and the mint, checked for existence only.
+    #[account(seeds = [WRAPPED_TOKEN_CONFIRMATION_SEED, mint.key().as_ref()], bump)]
+    pub wrapped_token_confirmation: AccountInfo<'info>,
+
     /// The user's token account holding the wrapped tokens to be bridged.
     /// - Must contain sufficient token balance for the bridge amount
     /// - Tokens will be burned from this account
@@ -63,10 +116,9 @@ pub struct BridgeWrappedTokenWithBufferedCall<'info> {
     pub owner: Signer<'info>,
 
     /// The call buffer account that stores the call data.
-    /// This account will be closed and rent returned to the owner.
+    /// This account will be closed and rent returned to the owner, unless it is reusable.
     #[account(
         mut,
-        close = owner,
         has_one = owner @ BridgeError::BufferUnauthorizedClose,
     )]
     pub call_buffer: Account<'info, CallBuffer>,
@@ -78,15 +130,31 @@ pub struct BridgeWrappedTokenWithBufferedCall<'info> {
         payer = payer,
         seeds = [OUTGOING_MESSAGE_SEED, outgoing_message_salt.as_ref()],
         bump,
-        space = DISCRIMINATOR_LEN + OutgoingMessage::space::<Transfer>(call_buffer.data.len()),
+        space = DISCRIMINATOR_LEN + OutgoingMessage::space(MessageKind::Transfer { call_data_len: Some(call_buffer.data.len()), memo_len: None, swap_guard: false }),
     )]
     pub outgoing_message: Account<'info, OutgoingMessage>,
 
+    /// Immutable receipt of the burn, linking it to the Base recipient for compliance accounting.
+    /// - PDA with BURN_RECEIPT_SEED and the outgoing message address
+    #[account(
+        init,
+        payer = payer,
+        seeds = [BURN_RECEIPT_SEED, outgoing_message.key().as_ref()],
+        bump,
+        space = DISCRIMINATOR_LEN + BurnReceipt::INIT_SPACE,
+    )]
+    pub burn_receipt: Account<'info, BurnReceipt>,
+
     /// Token2022 program used for burning the wrapped tokens (burn_checked).
     pub token_program: Program<'info, Token2022>,
 
     /// System program required for creating the outgoing message account and transferring gas fees.
     pub system_program: Program<'info, System>,
+
+    /// SPL Memo program, required when `spl_memo` is set to log a summary of the operation.
+    /// CHECK: Validated against the SPL Memo program ID.
+    #[account(address = SPL_MEMO_PROGRAM_ID)]
+    pub memo_program: Option<UncheckedAccount<'info>>,
 }
 
 pub fn bridge_wrapped_token_with_buffered_call_handler<'a, 'b, 'c, 'info>(
@@ -94,32 +162,55 @@ pub fn bridge_wrapped_token_with_buffered_call_handler<'a, 'b, 'c, 'info>(
     _outgoing_message_salt: [u8; 32],
     to: [u8; 20],
     amount: u64,
-) -> Result<()> {
+    spl_memo: bool,
+) -> Result<OutgoingMessageReceipt> {
     // Check if bridge is paused
     require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
 
-    let call_buffer = &ctx.accounts.call_buffer;
-    let call = Some(Call {
-        ty: call_buffer.ty,
-        to: call_buffer.to,
-        value: call_buffer.value,
-        data: call_buffer.data.clone(),
-    });
+    let amount = resolve_bridge_amount(amount, &ctx.accounts.from_token_account);
+
+    let call = Some(use_call_buffer(&mut ctx.accounts.call_buffer)?);
 
-    bridge_wrapped_token_internal(
+    let receipt = bridge_wrapped_token_internal(
         &ctx.accounts.payer,
         &ctx.accounts.from,
         &ctx.accounts.gas_fee_receiver,
+        &ctx.accounts.gas_fee_escrow,
+        &ctx.accounts.insurance_fund,
+        &ctx.accounts.payer_rate_limit_config,
+        &ctx.accounts.payer_rate_limit,
+        &ctx.accounts.protocol_fee_config,
+        &ctx.accounts.protocol_fee_treasury,
+        &ctx.accounts.dex_router_list,
         &ctx.accounts.mint,
+        &ctx.accounts.wrapped_token_confirmation,
         &ctx.accounts.from_token_account,
         &mut ctx.accounts.bridge,
         &mut ctx.accounts.outgoing_message,
+        &mut ctx.accounts.burn_receipt,
         &ctx.accounts.token_program,
         &ctx.accounts.system_program,
         to,
         amount,
         call,
-    )
+        None,
+        None,
+        None,
+    )?;
+
+    release_call_buffer(&ctx.accounts.call_buffer, &ctx.accounts.owner)?;
+
+    log_spl_memo(spl_memo, ctx.accounts.memo_program.as_ref(), || {
+        token_transfer_summary(
+            amount,
+            ctx.accounts.mint.decimals,
+            &ctx.accounts.mint.key(),
+            &to,
+            true,
+        )
+    })?;
+
+    Ok(receipt)
 }
 
 #[cfg(test)]
@@ -138,7 +229,7 @@ mod tests {
 
     use crate::{
         accounts,
-        common::{bridge::Bridge, PartialTokenMetadata},
+        common::{bridge::Bridge, PartialTokenMetadata, WrappedTokenConfirmation},
         instruction::{
             BridgeWrappedTokenWithBufferedCall as BridgeWrappedTokenWithBufferedCallIx,
             InitializeCallBuffer,
@@ -146,7 +237,9 @@ mod tests {
         solana_to_base::CallType,
         test_utils::{
             create_mock_token_account, create_mock_wrapped_mint, create_outgoing_message,
-            setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+            dex_router_list_pda, gas_fee_escrow_pda, insurance_fund_pda,
+            payer_rate_limit_config_pda, payer_rate_limit_pda, protocol_fee_config_pda,
+            setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER, TEST_PROTOCOL_FEE_TREASURY,
         },
         ID,
     };
@@ -244,14 +337,24 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
             mint: wrapped_mint,
+            wrapped_token_confirmation: WrappedTokenConfirmation::pda(&wrapped_mint),
             from_token_account,
             bridge: bridge_pda,
             owner: owner.pubkey(),
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
+            burn_receipt: BurnReceipt::pda(&outgoing_message),
             token_program: anchor_spl::token_2022::ID,
             system_program: system_program::ID,
+            memo_program: None,
         }
         .to_account_metas(None);
 
@@ -263,6 +366,7 @@ mod tests {
                 outgoing_message_salt,
                 to,
                 amount,
+                spl_memo: false,
             }
             .data(),
         };
@@ -426,14 +530,24 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
             mint: wrapped_mint,
+            wrapped_token_confirmation: WrappedTokenConfirmation::pda(&wrapped_mint),
             from_token_account,
             bridge: bridge_pda,
             owner: unauthorized.pubkey(), // Wrong owner
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
+            burn_receipt: BurnReceipt::pda(&outgoing_message),
             token_program: anchor_spl::token_2022::ID,
             system_program: system_program::ID,
+            memo_program: None,
         }
         .to_account_metas(None);
 
@@ -445,6 +559,7 @@ mod tests {
                 outgoing_message_salt,
                 to,
                 amount,
+                spl_memo: false,
             }
             .data(),
         };
@@ -560,14 +675,24 @@ mod tests {
             payer: payer.pubkey(),
             from: from.pubkey(),
             gas_fee_receiver: wrong_gas_fee_receiver.pubkey(), // Wrong receiver
+            gas_fee_escrow: gas_fee_escrow_pda(),
+            insurance_fund: insurance_fund_pda(),
+            payer_rate_limit_config: payer_rate_limit_config_pda(),
+            payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+            protocol_fee_config: protocol_fee_config_pda(),
+            protocol_fee_treasury: TEST_PROTOCOL_FEE_TREASURY,
+            dex_router_list: dex_router_list_pda(),
             mint: wrapped_mint,
+            wrapped_token_confirmation: WrappedTokenConfirmation::pda(&wrapped_mint),
             from_token_account,
             bridge: bridge_pda,
             owner: owner.pubkey(),
             call_buffer: call_buffer.pubkey(),
             outgoing_message,
+            burn_receipt: BurnReceipt::pda(&outgoing_message),
             token_program: anchor_spl::token_2022::ID,
             system_program: system_program::ID,
+            memo_program: None,
         }
         .to_account_metas(None);
 
@@ -579,6 +704,7 @@ mod tests {
                 outgoing_message_salt,
                 to,
                 amount,
+                spl_memo: false,
             }
             .data(),
         };
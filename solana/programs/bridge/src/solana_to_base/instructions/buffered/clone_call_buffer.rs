@@ -0,0 +1,271 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    solana_to_base::CallBuffer,
+    BridgeError, CallBufferCloned,
+};
+
+/// Accounts for copying a `CallBuffer` into a new single-use buffer owned by `payer`, so that a
+/// reusable template can be bridged without rebuilding its data over several transactions.
+/// Allocation is sized by `max_data_len` and capped by `bridge.buffer_config.max_call_buffer_size`.
+#[derive(Accounts)]
+#[instruction(max_data_len: u64)]
+pub struct CloneCallBuffer<'info> {
+    /// The account that pays for the new call buffer account creation.
+    /// This signer becomes the owner of the new buffer.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The bridge account containing configuration including max buffer size
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The call buffer to copy. Buffers that are not reusable can only be copied by their owner.
+    #[account(
+        constraint = source.reusable || source.owner == payer.key() @ BridgeError::BufferNotCloneable,
+    )]
+    pub source: Account<'info, CallBuffer>,
+
+    /// The call buffer account being initialized with the contents of `source`.
+    /// Space is allocated for up to `max_data_len` bytes of `data`, which must fit the copied data.
+    #[account(
+        init,
+        payer = payer,
+        space = DISCRIMINATOR_LEN + CallBuffer::space(max_data_len as usize),
+        constraint = bridge.buffer_config.max_call_buffer_size >= max_data_len @ BridgeError::BufferMaxSizeExceeded,
+    )]
+    pub call_buffer: Account<'info, CallBuffer>,
+
+    /// System program required for creating new accounts
+    pub system_program: Program<'info, System>,
+}
+
+/// Copies the call of `source` into the new `call_buffer`. The copy starts single-use with a zero
+/// `use_count`, and can be appended to up to `max_data_len` bytes. If the copied data exceeds
+/// the allocated capacity, the transaction will fail due to insufficient account space.
+pub fn clone_call_buffer_handler(ctx: Context<CloneCallBuffer>, max_data_len: u64) -> Result<()> {
+    let source = &ctx.accounts.source;
+    *ctx.accounts.call_buffer = CallBuffer {
+        owner: ctx.accounts.payer.key(),
+        ty: source.ty,
+        to: source.to,
+        value: source.value,
+        last_modified: Clock::get()?.unix_timestamp,
+        reusable: false,
+        use_count: 0,
+        data: source.data.clone(),
+    };
+
+    emit!(CallBufferCloned {
+        source: source.key(),
+        call_buffer: ctx.accounts.call_buffer.key(),
+        owner: ctx.accounts.payer.key(),
+        total_len: source.data.len() as u64,
+        max_data_len,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::{
+            CloneCallBuffer as CloneCallBufferIx, InitializeCallBuffer,
+            SetCallBufferReusable as SetCallBufferReusableIx,
+        },
+        solana_to_base::CallType,
+        test_utils::{setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn setup_call_buffer(
+        svm: &mut LiteSVM,
+        bridge_pda: Pubkey,
+        owner: &Keypair,
+        reusable: bool,
+    ) -> Pubkey {
+        let call_buffer = Keypair::new();
+        let mut ixs = vec![Instruction {
+            program_id: ID,
+            accounts: accounts::InitializeCallBuffer {
+                payer: owner.pubkey(),
+                bridge: bridge_pda,
+                call_buffer: call_buffer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: InitializeCallBuffer {
+                ty: CallType::Call,
+                to: [1u8; 20],
+                value: 2,
+                initial_data: vec![0x12, 0x34, 0x56],
+                max_data_len: 1024,
+            }
+            .data(),
+        }];
+        if reusable {
+            ixs.push(Instruction {
+                program_id: ID,
+                accounts: accounts::SetCallBufferReusable {
+                    owner: owner.pubkey(),
+                    call_buffer: call_buffer.pubkey(),
+                }
+                .to_account_metas(None),
+                data: SetCallBufferReusableIx { reusable: true }.data(),
+            });
+        }
+
+        let tx = Transaction::new(
+            &[owner, &call_buffer],
+            Message::new(&ixs, Some(&owner.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to initialize call buffer");
+
+        call_buffer.pubkey()
+    }
+
+    fn clone_call_buffer(
+        svm: &mut LiteSVM,
+        bridge_pda: Pubkey,
+        payer: &Keypair,
+        source: Pubkey,
+        call_buffer: &Keypair,
+        max_data_len: u64,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::CloneCallBuffer {
+                payer: payer.pubkey(),
+                bridge: bridge_pda,
+                source,
+                call_buffer: call_buffer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: CloneCallBufferIx { max_data_len }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer, call_buffer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_clone_reusable_call_buffer_success() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let source = setup_call_buffer(&mut svm, bridge_pda, &owner, true);
+
+        // Any account can copy a reusable buffer
+        let integrator = Keypair::new();
+        svm.airdrop(&integrator.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let call_buffer = Keypair::new();
+        clone_call_buffer(&mut svm, bridge_pda, &integrator, source, &call_buffer, 64)
+            .expect("Failed to clone call buffer");
+
+        let account = svm.get_account(&call_buffer.pubkey()).unwrap();
+        assert_eq!(
+            account.data.len(),
+            DISCRIMINATOR_LEN + CallBuffer::space(64)
+        );
+        let clone = CallBuffer::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(clone.owner, integrator.pubkey());
+        assert_eq!(clone.ty, CallType::Call);
+        assert_eq!(clone.to, [1u8; 20]);
+        assert_eq!(clone.value, 2);
+        assert_eq!(clone.data, vec![0x12, 0x34, 0x56]);
+        assert!(!clone.reusable);
+        assert_eq!(clone.use_count, 0);
+
+        // The template is left untouched
+        let account = svm.get_account(&source).unwrap();
+        let source = CallBuffer::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(source.owner, owner.pubkey());
+        assert!(source.reusable);
+    }
+
+    #[test]
+    fn test_clone_single_use_call_buffer_by_non_owner_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let source = setup_call_buffer(&mut svm, bridge_pda, &owner, false);
+
+        let attacker = Keypair::new();
+        svm.airdrop(&attacker.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let error = clone_call_buffer(&mut svm, bridge_pda, &attacker, source, &Keypair::new(), 64)
+            .unwrap_err();
+        assert!(
+            error.contains("BufferNotCloneable"),
+            "Expected BufferNotCloneable error, got: {}",
+            error
+        );
+
+        // The owner can still copy its own buffer
+        clone_call_buffer(&mut svm, bridge_pda, &owner, source, &Keypair::new(), 64)
+            .expect("Failed to clone own call buffer");
+    }
+
+    #[test]
+    fn test_clone_call_buffer_max_size_exceeded() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let source = setup_call_buffer(&mut svm, bridge_pda, &owner, true);
+
+        let bridge_account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+        let error = clone_call_buffer(
+            &mut svm,
+            bridge_pda,
+            &owner,
+            source,
+            &Keypair::new(),
+            bridge.buffer_config.max_call_buffer_size + 1,
+        )
+        .unwrap_err();
+        assert!(
+            error.contains("BufferMaxSizeExceeded"),
+            "Expected BufferMaxSizeExceeded error, got: {}",
+            error
+        );
+    }
+}
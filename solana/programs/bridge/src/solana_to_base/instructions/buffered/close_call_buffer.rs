@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{solana_to_base::CallBuffer, BridgeError};
+use crate::{solana_to_base::CallBuffer, BridgeError, CallBufferClosed};
 
 /// Accounts struct for closing a call buffer account.
 #[derive(Accounts)]
@@ -18,7 +18,14 @@ pub struct CloseCallBuffer<'info> {
     pub call_buffer: Account<'info, CallBuffer>,
 }
 
-pub fn close_call_buffer_handler(_ctx: Context<CloseCallBuffer>) -> Result<()> {
+pub fn close_call_buffer_handler(ctx: Context<CloseCallBuffer>) -> Result<()> {
+    let call_buffer = &ctx.accounts.call_buffer;
+    emit!(CallBufferClosed {
+        call_buffer: call_buffer.key(),
+        owner: call_buffer.owner,
+        total_len: call_buffer.data.len() as u64,
+    });
+
     // The account will be closed automatically by Anchor due to the `close = owner` constraint
     Ok(())
 }
@@ -0,0 +1,337 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    common::{bridge::Bridge, BRIDGE_SEED},
+    solana_to_base::CallBuffer,
+    BridgeError, CallBufferExpired,
+};
+
+/// Accounts struct for the permissionless expire_call_buffer instruction that garbage collects
+/// call buffers left idle for longer than `bridge.buffer_config.call_buffer_ttl_seconds`.
+#[derive(Accounts)]
+pub struct ExpireCallBuffer<'info> {
+    /// The account expiring the buffer. Receives `expire_bounty_bps` of the buffer rent.
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    /// The owner of the call buffer, receiving the remaining rent.
+    ///
+    /// CHECK: Checked against `call_buffer.owner`.
+    #[account(mut)]
+    pub owner: AccountInfo<'info>,
+
+    /// The bridge account containing the buffer expiry configuration
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The call buffer account to expire
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner @ BridgeError::IncorrectBufferOwner,
+    )]
+    pub call_buffer: Account<'info, CallBuffer>,
+}
+
+/// Pays the bounty to the caller out of the buffer rent. The remaining rent is returned to the
+/// owner when Anchor closes the account via the `close = owner` constraint.
+pub fn expire_call_buffer_handler(ctx: Context<ExpireCallBuffer>) -> Result<()> {
+    let buffer_config = &ctx.accounts.bridge.buffer_config;
+    require!(
+        buffer_config.call_buffer_ttl_seconds > 0,
+        BridgeError::BufferExpiryDisabled
+    );
+
+    let call_buffer = &ctx.accounts.call_buffer;
+    let current_timestamp = Clock::get()?.unix_timestamp;
+    let expires_at = call_buffer
+        .last_modified
+        .saturating_add(buffer_config.call_buffer_ttl_seconds as i64);
+    require!(
+        current_timestamp >= expires_at,
+        BridgeError::BufferNotExpired
+    );
+
+    let call_buffer_info = call_buffer.to_account_info();
+    let bounty = (call_buffer_info.lamports() as u128 * buffer_config.expire_bounty_bps as u128
+        / 10_000) as u64;
+    **call_buffer_info.try_borrow_mut_lamports()? -= bounty;
+    **ctx.accounts.caller.try_borrow_mut_lamports()? += bounty;
+
+    emit!(CallBufferExpired {
+        call_buffer: call_buffer.key(),
+        owner: call_buffer.owner,
+        caller: ctx.accounts.caller.key(),
+        bounty,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::{
+            ExpireCallBuffer as ExpireCallBufferIx, InitializeCallBuffer, SetCallBufferTtl,
+            SetExpireBountyBps,
+        },
+        solana_to_base::CallType,
+        test_utils::{config_history_pda, mock_clock, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    const TTL_SECONDS: u64 = 3600;
+    const BOUNTY_BPS: u64 = 500;
+
+    fn set_buffer_expiry(
+        svm: &mut LiteSVM,
+        guardian: &Keypair,
+        bridge_pda: Pubkey,
+        ttl_seconds: u64,
+        bounty_bps: u64,
+    ) {
+        let accounts = accounts::SetBridgeConfigFromGuardian {
+            bridge: bridge_pda,
+            guardian: guardian.pubkey(),
+            config_history: config_history_pda(),
+        }
+        .to_account_metas(None);
+
+        let ixs = [
+            Instruction {
+                program_id: ID,
+                accounts: accounts.clone(),
+                data: SetCallBufferTtl {
+                    new_ttl_seconds: ttl_seconds,
+                }
+                .data(),
+            },
+            Instruction {
+                program_id: ID,
+                accounts,
+                data: SetExpireBountyBps {
+                    new_bps: bounty_bps,
+                }
+                .data(),
+            },
+        ];
+
+        let tx = Transaction::new(
+            &[guardian],
+            Message::new(&ixs, Some(&guardian.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to set call buffer expiry config");
+    }
+
+    fn setup_call_buffer(
+        svm: &mut LiteSVM,
+        bridge_pda: Pubkey,
+        owner: &Keypair,
+        call_buffer: &Keypair,
+    ) {
+        let init_ix = Instruction {
+            program_id: ID,
+            accounts: accounts::InitializeCallBuffer {
+                payer: owner.pubkey(),
+                bridge: bridge_pda,
+                call_buffer: call_buffer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: InitializeCallBuffer {
+                ty: CallType::Call,
+                to: [1u8; 20],
+                value: 0u128,
+                initial_data: vec![0x12, 0x34],
+                max_data_len: 1024,
+            }
+            .data(),
+        };
+
+        let init_tx = Transaction::new(
+            &[owner, call_buffer],
+            Message::new(&[init_ix], Some(&owner.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(init_tx)
+            .expect("Failed to initialize call buffer");
+    }
+
+    /// Builds an expire_call_buffer transaction whose fees are paid by `payer` so the caller
+    /// balance only reflects the bounty.
+    fn expire_call_buffer_tx(
+        svm: &LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        caller: &Keypair,
+        owner: Pubkey,
+        call_buffer: Pubkey,
+    ) -> Transaction {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::ExpireCallBuffer {
+                caller: caller.pubkey(),
+                owner,
+                bridge: bridge_pda,
+                call_buffer,
+            }
+            .to_account_metas(None),
+            data: ExpireCallBufferIx {}.data(),
+        };
+
+        Transaction::new(
+            &[payer, caller],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        )
+    }
+
+    #[test]
+    fn test_expire_call_buffer_success() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        set_buffer_expiry(&mut svm, &guardian, bridge_pda, TTL_SECONDS, BOUNTY_BPS);
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let caller = Keypair::new();
+        svm.airdrop(&caller.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let call_buffer = Keypair::new();
+        setup_call_buffer(&mut svm, bridge_pda, &owner, &call_buffer);
+
+        let rent = svm.get_account(&call_buffer.pubkey()).unwrap().lamports;
+        let owner_balance = svm.get_account(&owner.pubkey()).unwrap().lamports;
+        let caller_balance = svm.get_account(&caller.pubkey()).unwrap().lamports;
+
+        let clock = svm.get_sysvar::<Clock>();
+        mock_clock(&mut svm, clock.unix_timestamp + TTL_SECONDS as i64);
+
+        let tx = expire_call_buffer_tx(
+            &svm,
+            &payer,
+            bridge_pda,
+            &caller,
+            owner.pubkey(),
+            call_buffer.pubkey(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("Failed to expire call buffer");
+        assert!(meta.logs.iter().any(|log| log.starts_with("Program data:")));
+
+        // Verify the call buffer was closed
+        let call_buffer_account = svm.get_account(&call_buffer.pubkey()).unwrap();
+        assert_eq!(call_buffer_account.lamports, 0);
+        assert_eq!(call_buffer_account.owner, system_program::ID);
+
+        // Verify the rent was split between the owner and the caller
+        let bounty = rent * BOUNTY_BPS / 10_000;
+        assert_eq!(
+            svm.get_account(&owner.pubkey()).unwrap().lamports,
+            owner_balance + rent - bounty
+        );
+        assert_eq!(
+            svm.get_account(&caller.pubkey()).unwrap().lamports,
+            caller_balance + bounty
+        );
+    }
+
+    #[test]
+    fn test_expire_call_buffer_not_expired_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            guardian,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        set_buffer_expiry(&mut svm, &guardian, bridge_pda, TTL_SECONDS, BOUNTY_BPS);
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let caller = Keypair::new();
+        svm.airdrop(&caller.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let call_buffer = Keypair::new();
+        setup_call_buffer(&mut svm, bridge_pda, &owner, &call_buffer);
+
+        let clock = svm.get_sysvar::<Clock>();
+        mock_clock(&mut svm, clock.unix_timestamp + TTL_SECONDS as i64 - 1);
+
+        let tx = expire_call_buffer_tx(
+            &svm,
+            &payer,
+            bridge_pda,
+            &caller,
+            owner.pubkey(),
+            call_buffer.pubkey(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("BufferNotExpired"),
+            "Expected BufferNotExpired error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_expire_call_buffer_disabled_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let caller = Keypair::new();
+        svm.airdrop(&caller.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let call_buffer = Keypair::new();
+        setup_call_buffer(&mut svm, bridge_pda, &owner, &call_buffer);
+
+        let clock = svm.get_sysvar::<Clock>();
+        mock_clock(&mut svm, clock.unix_timestamp + TTL_SECONDS as i64);
+
+        let tx = expire_call_buffer_tx(
+            &svm,
+            &payer,
+            bridge_pda,
+            &caller,
+            owner.pubkey(),
+            call_buffer.pubkey(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("BufferExpiryDisabled"),
+            "Expected BufferExpiryDisabled error, got: {}",
+            error_string
+        );
+    }
+}
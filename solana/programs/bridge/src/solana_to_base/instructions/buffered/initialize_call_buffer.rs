@@ -3,7 +3,7 @@ use anchor_lang::prelude::*;
 use crate::{
     common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
     solana_to_base::{CallBuffer, CallType},
-    BridgeError,
+    BridgeError, CallBufferInitialized,
 };
 
 /// Accounts for initializing a `CallBuffer` that can store large call data.
@@ -28,6 +28,9 @@ pub struct InitializeCallBuffer<'info> {
     /// The call buffer account being initialized.
     /// Space is allocated for up to `max_data_len` bytes of `data` (plus the Vec length prefix).
     /// The bridge configuration enforces an upper bound via `buffer_config.max_call_buffer_size`.
+    /// As a non-PDA `init` account it must sign the transaction, so no one else can create a
+    /// buffer at its address, and accounts already owned by this program are rejected. Lamports
+    /// sent to the address beforehand are kept and do not prevent initialization.
     #[account(
         init,
         payer = payer,
@@ -41,8 +44,8 @@ pub struct InitializeCallBuffer<'info> {
 }
 
 /// Initializes a `CallBuffer` with the provided parameters.
-/// Note: `max_data_len` is used only for account allocation (via the accounts macro) and is not
-/// stored in the account state. If `initial_data.len()` exceeds the allocated capacity, the
+/// Note: `max_data_len` is used only for account allocation (via the accounts macro) and the
+/// emitted `CallBufferInitialized` event; it is not stored in the account state. If `initial_data.len()` exceeds the allocated capacity, the
 /// transaction will fail due to insufficient account space.
 pub fn initialize_call_buffer_handler(
     ctx: Context<InitializeCallBuffer>,
@@ -50,16 +53,28 @@ pub fn initialize_call_buffer_handler(
     to: [u8; 20],
     value: u128,
     initial_data: Vec<u8>,
-    _max_data_len: u64,
+    max_data_len: u64,
 ) -> Result<()> {
+    let data_len = initial_data.len() as u64;
     *ctx.accounts.call_buffer = CallBuffer {
         owner: ctx.accounts.payer.key(),
         ty,
         to,
         value,
+        last_modified: Clock::get()?.unix_timestamp,
+        reusable: false,
+        use_count: 0,
         data: initial_data,
     };
 
+    emit!(CallBufferInitialized {
+        call_buffer: ctx.accounts.call_buffer.key(),
+        owner: ctx.accounts.payer.key(),
+        bytes_appended: data_len,
+        total_len: data_len,
+        max_data_len,
+    });
+
     Ok(())
 }
 
@@ -84,6 +99,31 @@ mod tests {
         ID,
     };
 
+    fn initialize_call_buffer_ix(
+        payer: Pubkey,
+        bridge_pda: Pubkey,
+        call_buffer: Pubkey,
+    ) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::InitializeCallBuffer {
+                payer,
+                bridge: bridge_pda,
+                call_buffer,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: InitializeCallBufferIx {
+                ty: CallType::Call,
+                to: [1u8; 20],
+                value: 0,
+                initial_data: vec![0x12, 0x34],
+                max_data_len: 1024,
+            }
+            .data(),
+        }
+    }
+
     #[test]
     fn test_initialize_call_buffer_success() {
         let SetupBridgeResult {
@@ -222,4 +262,121 @@ mod tests {
             error_string
         );
     }
+
+    #[test]
+    fn test_initialize_call_buffer_requires_call_buffer_signature() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let attacker = Keypair::new();
+        svm.airdrop(&attacker.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let call_buffer = Keypair::new();
+
+        // The attacker tries to claim the buffer address without its signature
+        let mut ix = initialize_call_buffer_ix(attacker.pubkey(), bridge_pda, call_buffer.pubkey());
+        ix.accounts[2].is_signer = false;
+        let tx = Transaction::new(
+            &[&attacker],
+            Message::new(&[ix], Some(&attacker.pubkey())),
+            svm.latest_blockhash(),
+        );
+        assert!(svm.send_transaction(tx).is_err());
+        assert!(svm.get_account(&call_buffer.pubkey()).is_none());
+
+        // The key holder can still initialize it
+        let ix = initialize_call_buffer_ix(owner.pubkey(), bridge_pda, call_buffer.pubkey());
+        let tx = Transaction::new(
+            &[&owner, &call_buffer],
+            Message::new(&[ix], Some(&owner.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to initialize call buffer");
+
+        let call_buffer_account = svm.get_account(&call_buffer.pubkey()).unwrap();
+        let call_buffer_data =
+            CallBuffer::try_deserialize(&mut &call_buffer_account.data[..]).unwrap();
+        assert_eq!(call_buffer_data.owner, owner.pubkey());
+    }
+
+    #[test]
+    fn test_initialize_call_buffer_pre_funded_account() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let call_buffer = Keypair::new();
+
+        // Someone sends lamports to the buffer address ahead of its initialization
+        svm.airdrop(&call_buffer.pubkey(), 1_000).unwrap();
+
+        let ix = initialize_call_buffer_ix(owner.pubkey(), bridge_pda, call_buffer.pubkey());
+        let tx = Transaction::new(
+            &[&owner, &call_buffer],
+            Message::new(&[ix], Some(&owner.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to initialize pre-funded call buffer");
+
+        let call_buffer_account = svm.get_account(&call_buffer.pubkey()).unwrap();
+        assert_eq!(call_buffer_account.owner, ID);
+        let call_buffer_data =
+            CallBuffer::try_deserialize(&mut &call_buffer_account.data[..]).unwrap();
+        assert_eq!(call_buffer_data.owner, owner.pubkey());
+    }
+
+    #[test]
+    fn test_initialize_call_buffer_rejects_existing_buffer() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let other = Keypair::new();
+        svm.airdrop(&other.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let call_buffer = Keypair::new();
+
+        let ix = initialize_call_buffer_ix(owner.pubkey(), bridge_pda, call_buffer.pubkey());
+        let tx = Transaction::new(
+            &[&owner, &call_buffer],
+            Message::new(&[ix], Some(&owner.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to initialize call buffer");
+
+        // Re-initializing the buffer for another owner fails, even with the buffer signature
+        let ix = initialize_call_buffer_ix(other.pubkey(), bridge_pda, call_buffer.pubkey());
+        let tx = Transaction::new(
+            &[&other, &call_buffer],
+            Message::new(&[ix], Some(&other.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("already in use"),
+            "Expected account already in use error, got: {}",
+            error_string
+        );
+
+        let call_buffer_account = svm.get_account(&call_buffer.pubkey()).unwrap();
+        let call_buffer_data =
+            CallBuffer::try_deserialize(&mut &call_buffer_account.data[..]).unwrap();
+        assert_eq!(call_buffer_data.owner, owner.pubkey());
+    }
 }
@@ -1,9 +1,15 @@
 pub mod append_to_call_buffer;
 pub use append_to_call_buffer::*;
+pub mod clone_call_buffer;
+pub use clone_call_buffer::*;
 pub mod close_call_buffer;
 pub use close_call_buffer::*;
+pub mod expire_call_buffer;
+pub use expire_call_buffer::*;
 pub mod initialize_call_buffer;
 pub use initialize_call_buffer::*;
+pub mod set_call_buffer_reusable;
+pub use set_call_buffer_reusable::*;
 
 pub mod bridge_call;
 pub use bridge_call::*;
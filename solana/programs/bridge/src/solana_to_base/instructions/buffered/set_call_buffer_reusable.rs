@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+
+use crate::{solana_to_base::CallBuffer, BridgeError, CallBufferReusableSet};
+
+/// Accounts struct for marking a call buffer as reusable or single-use.
+#[derive(Accounts)]
+pub struct SetCallBufferReusable<'info> {
+    /// The owner of the call buffer.
+    pub owner: Signer<'info>,
+
+    /// The call buffer account to update
+    #[account(
+        mut,
+        has_one = owner @ BridgeError::IncorrectBufferOwner,
+    )]
+    pub call_buffer: Account<'info, CallBuffer>,
+}
+
+/// Sets whether buffered bridge instructions keep `call_buffer` after reading it. Unmarking a
+/// buffer makes the next buffered bridge instruction consume it.
+pub fn set_call_buffer_reusable_handler(
+    ctx: Context<SetCallBufferReusable>,
+    reusable: bool,
+) -> Result<()> {
+    let call_buffer = &mut ctx.accounts.call_buffer;
+    call_buffer.reusable = reusable;
+    call_buffer.last_modified = Clock::get()?.unix_timestamp;
+
+    emit!(CallBufferReusableSet {
+        call_buffer: call_buffer.key(),
+        owner: call_buffer.owner,
+        reusable,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::{
+            AppendToCallBuffer as AppendToCallBufferIx, InitializeCallBuffer,
+            SetCallBufferReusable as SetCallBufferReusableIx,
+        },
+        solana_to_base::CallType,
+        test_utils::{setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn setup_call_buffer(svm: &mut LiteSVM, bridge_pda: Pubkey, owner: &Keypair) -> Pubkey {
+        let call_buffer = Keypair::new();
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::InitializeCallBuffer {
+                payer: owner.pubkey(),
+                bridge: bridge_pda,
+                call_buffer: call_buffer.pubkey(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: InitializeCallBuffer {
+                ty: CallType::Call,
+                to: [1u8; 20],
+                value: 0,
+                initial_data: vec![0x12, 0x34],
+                max_data_len: 1024,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[owner, &call_buffer],
+            Message::new(&[ix], Some(&owner.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to initialize call buffer");
+
+        call_buffer.pubkey()
+    }
+
+    fn set_call_buffer_reusable(
+        svm: &mut LiteSVM,
+        owner: &Keypair,
+        call_buffer: Pubkey,
+        reusable: bool,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::SetCallBufferReusable {
+                owner: owner.pubkey(),
+                call_buffer,
+            }
+            .to_account_metas(None),
+            data: SetCallBufferReusableIx { reusable }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[owner],
+            Message::new(&[ix], Some(&owner.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn read_call_buffer(svm: &LiteSVM, call_buffer: Pubkey) -> CallBuffer {
+        let account = svm.get_account(&call_buffer).unwrap();
+        CallBuffer::try_deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    #[test]
+    fn test_set_call_buffer_reusable_success() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let call_buffer = setup_call_buffer(&mut svm, bridge_pda, &owner);
+        assert!(!read_call_buffer(&svm, call_buffer).reusable);
+
+        set_call_buffer_reusable(&mut svm, &owner, call_buffer, true)
+            .expect("Failed to mark call buffer reusable");
+        assert!(read_call_buffer(&svm, call_buffer).reusable);
+
+        svm.expire_blockhash();
+        set_call_buffer_reusable(&mut svm, &owner, call_buffer, false)
+            .expect("Failed to unmark call buffer reusable");
+        assert!(!read_call_buffer(&svm, call_buffer).reusable);
+    }
+
+    #[test]
+    fn test_set_call_buffer_reusable_unauthorized() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let call_buffer = setup_call_buffer(&mut svm, bridge_pda, &owner);
+
+        let attacker = Keypair::new();
+        svm.airdrop(&attacker.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let error = set_call_buffer_reusable(&mut svm, &attacker, call_buffer, true).unwrap_err();
+        assert!(
+            error.contains("IncorrectBufferOwner"),
+            "Expected IncorrectBufferOwner error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_append_to_reusable_call_buffer_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let owner = Keypair::new();
+        svm.airdrop(&owner.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let call_buffer = setup_call_buffer(&mut svm, bridge_pda, &owner);
+        set_call_buffer_reusable(&mut svm, &owner, call_buffer, true)
+            .expect("Failed to mark call buffer reusable");
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::AppendToCallBuffer {
+                owner: owner.pubkey(),
+                call_buffer,
+            }
+            .to_account_metas(None),
+            data: AppendToCallBufferIx {
+                data: vec![0x56, 0x78],
+            }
+            .data(),
+        };
+        let tx = Transaction::new(
+            &[&owner],
+            Message::new(&[ix], Some(&owner.pubkey())),
+            svm.latest_blockhash(),
+        );
+
+        let error = format!("{:?}", svm.send_transaction(tx).unwrap_err());
+        assert!(
+            error.contains("BufferReusable"),
+            "Expected BufferReusable error, got: {}",
+            error
+        );
+        assert_eq!(read_call_buffer(&svm, call_buffer).data, vec![0x12, 0x34]);
+    }
+}
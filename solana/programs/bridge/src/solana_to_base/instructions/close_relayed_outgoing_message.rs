@@ -0,0 +1,206 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    solana_to_base::{OutgoingMessage, RelayedNonces, RELAYED_NONCES_SEED},
+    BridgeError,
+};
+
+/// Accounts struct for the permissionless `close_relayed_outgoing_message` instruction that
+/// reclaims the rent of an outgoing message once the relayed nonces registry confirms it was
+/// executed on Base, regardless of the order in which Base executed messages.
+#[derive(Accounts)]
+pub struct CloseRelayedOutgoingMessage<'info> {
+    /// The sender of the outgoing message, receiving its rent.
+    ///
+    /// CHECK: Checked against `outgoing_message.sender`.
+    #[account(mut)]
+    pub sender: AccountInfo<'info>,
+
+    /// The outgoing message to close
+    #[account(
+        mut,
+        close = sender,
+        constraint = outgoing_message.sender == sender.key() @ BridgeError::IncorrectOutgoingMessageSender,
+    )]
+    pub outgoing_message: Account<'info, OutgoingMessage>,
+
+    /// The relayed nonces registry covering the outgoing message nonce
+    #[account(
+        seeds = [
+            RELAYED_NONCES_SEED,
+            &RelayedNonces::page_of(outgoing_message.nonce).to_le_bytes(),
+        ],
+        bump
+    )]
+    pub relayed_nonces: AccountLoader<'info, RelayedNonces>,
+}
+
+pub fn close_relayed_outgoing_message_handler(
+    ctx: Context<CloseRelayedOutgoingMessage>,
+) -> Result<()> {
+    let relayed_nonces = ctx.accounts.relayed_nonces.load()?;
+    require!(
+        relayed_nonces.is_relayed(ctx.accounts.outgoing_message.nonce),
+        BridgeError::MessageNotRelayed
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::{BridgeCall, CloseRelayedOutgoingMessage as CloseRelayedOutgoingMessageIx},
+        solana_to_base::{Call, CallType},
+        test_utils::{
+            create_outgoing_message, gas_fee_escrow_pda, insurance_fund_pda, mock_relayed_nonces,
+            payer_rate_limit_config_pda, payer_rate_limit_pda, relayed_nonces_pda, setup_bridge,
+            SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+        },
+        ID,
+    };
+
+    /// Sends a bridge call from `from`, creating the outgoing message with nonce 0.
+    fn send_bridge_call(
+        svm: &mut litesvm::LiteSVM,
+        payer: &Keypair,
+        from: &Keypair,
+        bridge_pda: Pubkey,
+    ) -> Pubkey {
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::BridgeCall {
+                payer: payer.pubkey(),
+                from: from.pubkey(),
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                gas_fee_escrow: gas_fee_escrow_pda(),
+                insurance_fund: insurance_fund_pda(),
+                payer_rate_limit_config: payer_rate_limit_config_pda(),
+                payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+                bridge: bridge_pda,
+                outgoing_message,
+                system_program: system_program::ID,
+                memo_program: None,
+            }
+            .to_account_metas(None),
+            data: BridgeCall {
+                outgoing_message_salt,
+                call: Call {
+                    ty: CallType::Call,
+                    to: [1u8; 20],
+                    value: 0,
+                    data: vec![0x12, 0x34],
+                },
+                spl_memo: false,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer, from],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to send bridge_call transaction");
+
+        outgoing_message
+    }
+
+    fn close_ix(sender: Pubkey, outgoing_message: Pubkey) -> Instruction {
+        Instruction {
+            program_id: ID,
+            accounts: accounts::CloseRelayedOutgoingMessage {
+                sender,
+                outgoing_message,
+                relayed_nonces: relayed_nonces_pda(0),
+            }
+            .to_account_metas(None),
+            data: CloseRelayedOutgoingMessageIx {}.data(),
+        }
+    }
+
+    #[test]
+    fn test_close_relayed_outgoing_message_success() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let outgoing_message = send_bridge_call(&mut svm, &payer, &from, bridge_pda);
+        mock_relayed_nonces(&mut svm, 0, &[0]);
+
+        let rent = svm.get_account(&outgoing_message).unwrap().lamports;
+        let sender_balance = svm.get_account(&from.pubkey()).unwrap().lamports;
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(
+                &[close_ix(from.pubkey(), outgoing_message)],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .expect("Failed to close relayed outgoing message");
+
+        let outgoing_message_account = svm.get_account(&outgoing_message).unwrap();
+        assert_eq!(outgoing_message_account.lamports, 0);
+        assert_eq!(outgoing_message_account.owner, system_program::ID);
+        assert_eq!(
+            svm.get_account(&from.pubkey()).unwrap().lamports,
+            sender_balance + rent
+        );
+    }
+
+    #[test]
+    fn test_close_relayed_outgoing_message_not_relayed_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let from = Keypair::new();
+        svm.airdrop(&from.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let outgoing_message = send_bridge_call(&mut svm, &payer, &from, bridge_pda);
+        // Only a later nonce was relayed
+        mock_relayed_nonces(&mut svm, 0, &[1]);
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(
+                &[close_ix(from.pubkey(), outgoing_message)],
+                Some(&payer.pubkey()),
+            ),
+            svm.latest_blockhash(),
+        );
+        let result = svm.send_transaction(tx);
+        assert!(result.is_err());
+        let error_string = format!("{:?}", result.unwrap_err());
+        assert!(
+            error_string.contains("MessageNotRelayed"),
+            "Expected MessageNotRelayed error, got: {}",
+            error_string
+        );
+    }
+}
@@ -0,0 +1,266 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::{compute_relayed_nonces_message_hash, verify_oracle_approvals},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    solana_to_base::{RelayedNonces, RELAYED_NONCES_SEED},
+    BridgeError,
+};
+
+/// Accounts struct for the `confirm_relayed` instruction that records outgoing message nonces
+/// executed on Base in the relayed nonces registry. Authorization is enforced via oracle EVM
+/// signatures over the confirmed nonces; the Solana payer only funds account creation.
+#[derive(Accounts)]
+#[instruction(page: u64)]
+pub struct ConfirmRelayed<'info> {
+    /// Payer funds the registry account creation. Authorization is enforced via oracle EVM
+    /// signatures.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The main bridge state account providing the oracle configuration and the next nonce.
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The registry account covering the confirmed nonces.
+    /// - Uses PDA with RELAYED_NONCES_SEED and `page` for deterministic address
+    /// - Created on the first confirmation of a nonce in the page
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = DISCRIMINATOR_LEN + std::mem::size_of::<RelayedNonces>(),
+        seeds = [RELAYED_NONCES_SEED, &page.to_le_bytes()],
+        bump
+    )]
+    pub relayed_nonces: AccountLoader<'info, RelayedNonces>,
+
+    /// Partner `Config` account (PDA with seed "config") owned by partner program.
+    /// Unchecked to avoid Anchor pre-handler owner checks; PDA address is validated in the handler.
+    /// CHECK: This is validated in the handler.
+    pub partner_config: AccountInfo<'info>,
+
+    /// System program required for creating the registry account.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn confirm_relayed_handler(
+    ctx: Context<ConfirmRelayed>,
+    page: u64,
+    nonces: Vec<u64>,
+    signatures: Vec<[u8; 65]>,
+) -> Result<()> {
+    // Confirmations are accepted while paused: they only record facts from Base and move no
+    // funds, and must keep the registry complete during the emergency exit timelock.
+    let message_hash = compute_relayed_nonces_message_hash(&nonces);
+    verify_oracle_approvals(
+        &ctx.accounts.bridge,
+        &ctx.accounts.partner_config,
+        &signatures,
+        &message_hash,
+    )?;
+
+    // A freshly created registry still has a zeroed discriminator
+    let is_new = ctx.accounts.relayed_nonces.as_ref().try_borrow_data()?[..DISCRIMINATOR_LEN]
+        == [0; DISCRIMINATOR_LEN];
+    let mut relayed_nonces = if is_new {
+        let mut relayed_nonces = ctx.accounts.relayed_nonces.load_init()?;
+        relayed_nonces.page = page;
+        relayed_nonces
+    } else {
+        ctx.accounts.relayed_nonces.load_mut()?
+    };
+
+    for nonce in nonces {
+        require!(
+            RelayedNonces::page_of(nonce) == page && nonce < ctx.accounts.bridge.nonce,
+            BridgeError::InvalidRelayedNonce
+        );
+        relayed_nonces.mark_relayed(nonce);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, keccak::hash as keccak_hash},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use secp256k1::{Message as SecpMessage, Secp256k1, SecretKey};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::MAX_SIGNER_COUNT,
+        instruction::ConfirmRelayed as ConfirmRelayedIx,
+        solana_to_base::RELAYED_NONCES_PER_ACCOUNT,
+        test_utils::{is_relayed, relayed_nonces_pda, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    const ORACLE_SK: [u8; 32] = [7u8; 32];
+
+    /// Registers a single Base oracle with threshold one and bumps the bridge nonce to
+    /// `next_nonce`.
+    fn setup_oracle(svm: &mut LiteSVM, bridge_pda: Pubkey, next_nonce: u64) {
+        let secp = Secp256k1::new();
+        let sk = SecretKey::from_slice(&ORACLE_SK).unwrap();
+        let pk = secp256k1::PublicKey::from_secret_key(&secp, &sk);
+        let hashed = keccak_hash(&pk.serialize_uncompressed()[1..]);
+        let mut addr = [0u8; 20];
+        addr.copy_from_slice(&hashed.to_bytes()[12..]);
+
+        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
+        bridge.nonce = next_nonce;
+        bridge.base_oracle_config.threshold = 1;
+        bridge.base_oracle_config.signer_count = 1;
+        bridge.base_oracle_config.signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+        bridge.base_oracle_config.signers[0] = addr;
+        let mut new_data = Vec::new();
+        bridge.try_serialize(&mut new_data).unwrap();
+        bridge_acc.data = new_data;
+        svm.set_account(bridge_pda, bridge_acc).unwrap();
+    }
+
+    fn sign_relayed_nonces(sk_bytes: [u8; 32], nonces: &[u64]) -> [u8; 65] {
+        let msg_hash = compute_relayed_nonces_message_hash(nonces);
+
+        let secp = Secp256k1::new();
+        let sk = SecretKey::from_slice(&sk_bytes).unwrap();
+        let msg = SecpMessage::from_digest_slice(&msg_hash).unwrap();
+        let (rec_id, sig_bytes64) = secp.sign_ecdsa_recoverable(&msg, &sk).serialize_compact();
+
+        let mut sig65 = [0u8; 65];
+        sig65[..64].copy_from_slice(&sig_bytes64);
+        sig65[64] = 27 + rec_id.to_i32() as u8;
+        sig65
+    }
+
+    fn confirm_relayed(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        page: u64,
+        nonces: Vec<u64>,
+        sk_bytes: [u8; 32],
+    ) -> std::result::Result<(), String> {
+        let signatures = vec![sign_relayed_nonces(sk_bytes, &nonces)];
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::ConfirmRelayed {
+                payer: payer.pubkey(),
+                bridge: bridge_pda,
+                relayed_nonces: relayed_nonces_pda(page),
+                partner_config: Pubkey::new_unique(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: ConfirmRelayedIx {
+                page,
+                nonces,
+                signatures,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_confirm_relayed_out_of_order() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_oracle(&mut svm, bridge_pda, 10);
+
+        confirm_relayed(&mut svm, &payer, bridge_pda, 0, vec![5, 2], ORACLE_SK)
+            .expect("Failed to confirm relayed nonces");
+        confirm_relayed(&mut svm, &payer, bridge_pda, 0, vec![0], ORACLE_SK)
+            .expect("Failed to confirm relayed nonces");
+
+        for nonce in 0..10 {
+            assert_eq!(is_relayed(&svm, nonce), [0, 2, 5].contains(&nonce));
+        }
+    }
+
+    #[test]
+    fn test_confirm_relayed_rejects_invalid_nonces() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_oracle(&mut svm, bridge_pda, RELAYED_NONCES_PER_ACCOUNT + 1);
+
+        // Nonce outside of the page
+        let error_string = confirm_relayed(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            0,
+            vec![RELAYED_NONCES_PER_ACCOUNT],
+            ORACLE_SK,
+        )
+        .unwrap_err();
+        assert!(
+            error_string.contains("InvalidRelayedNonce"),
+            "Expected InvalidRelayedNonce error, got: {}",
+            error_string
+        );
+
+        // Nonce not yet assigned to an outgoing message
+        let error_string = confirm_relayed(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            1,
+            vec![RELAYED_NONCES_PER_ACCOUNT + 1],
+            ORACLE_SK,
+        )
+        .unwrap_err();
+        assert!(
+            error_string.contains("InvalidRelayedNonce"),
+            "Expected InvalidRelayedNonce error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_confirm_relayed_requires_oracle_signature() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_oracle(&mut svm, bridge_pda, 10);
+
+        let error_string =
+            confirm_relayed(&mut svm, &payer, bridge_pda, 0, vec![1], [9u8; 32]).unwrap_err();
+        assert!(
+            error_string.contains("InsufficientBaseSignatures"),
+            "Expected InsufficientBaseSignatures error, got: {}",
+            error_string
+        );
+    }
+}
@@ -0,0 +1,351 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+};
+
+use crate::{
+    base_to_solana::{constants::RETURNED_TRANSFER_SEED, ReturnedTransfer},
+    common::{
+        bridge::Bridge, EmergencyExit, SolVaultState, TokenDeposits, BRIDGE_SEED,
+        DISCRIMINATOR_LEN, EMERGENCY_EXIT_SEED, SOL_VAULT_SEED, SOL_VAULT_STATE_SEED,
+        TOKEN_DEPOSITS_SEED,
+    },
+    solana_to_base::{
+        check_emergency_withdrawal, OutgoingMessage, RelayedNonces, NATIVE_SOL_PUBKEY,
+        RELAYED_NONCES_SEED,
+    },
+    BridgeError, EmergencyWithdrawal,
+};
+
+/// Accounts struct for the `emergency_withdraw_sol` instruction that returns the SOL locked by an
+/// outgoing message not relayed to Base to its sender while the emergency exit is open.
+#[derive(Accounts)]
+pub struct EmergencyWithdrawSol<'info> {
+    /// The sender of the outgoing message, receiving the withdrawn SOL and the message rent.
+    /// Pays for the returned transfer marker.
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    /// The main bridge state account, which must be paused
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The emergency exit enabled by the guardian
+    #[account(seeds = [EMERGENCY_EXIT_SEED], bump)]
+    pub emergency_exit: Account<'info, EmergencyExit>,
+
+    /// The outgoing message whose SOL is withdrawn, closed by this instruction
+    #[account(
+        mut,
+        close = sender,
+        constraint = outgoing_message.sender == sender.key() @ BridgeError::IncorrectOutgoingMessageSender,
+    )]
+    pub outgoing_message: Account<'info, OutgoingMessage>,
+
+    /// The relayed nonces registry covering the outgoing message nonce, possibly not created yet.
+    ///
+    /// CHECK: PDA with RELAYED_NONCES_SEED, deserialized only if owned by this program.
+    #[account(
+        seeds = [
+            RELAYED_NONCES_SEED,
+            &RelayedNonces::page_of(outgoing_message.nonce).to_le_bytes(),
+        ],
+        bump
+    )]
+    pub relayed_nonces: AccountInfo<'info>,
+
+    /// Marker shared with `ReturnTransfer` messages so that the transfer of the outgoing message
+    /// is returned to Solana at most once.
+    #[account(
+        init,
+        payer = sender,
+        seeds = [RETURNED_TRANSFER_SEED, &outgoing_message.nonce.to_le_bytes()],
+        bump,
+        space = DISCRIMINATOR_LEN + ReturnedTransfer::INIT_SPACE,
+    )]
+    pub returned_transfer: Account<'info, ReturnedTransfer>,
+
+    /// The SOL vault state recording the vault bump
+    #[account(seeds = [SOL_VAULT_STATE_SEED], bump)]
+    pub sol_vault_state: Account<'info, SolVaultState>,
+
+    /// The SOL vault holding the locked SOL.
+    ///
+    /// CHECK: This is the SOL vault account.
+    #[account(mut, seeds = [SOL_VAULT_SEED], bump = sol_vault_state.bump)]
+    pub sol_vault: AccountInfo<'info>,
+
+    /// Accounting for the amount locked in the SOL vault
+    #[account(mut, seeds = [TOKEN_DEPOSITS_SEED, sol_vault.key().as_ref()], bump)]
+    pub token_deposits: Account<'info, TokenDeposits>,
+
+    /// System program required for the SOL transfer and the marker creation
+    pub system_program: Program<'info, System>,
+}
+
+pub fn emergency_withdraw_sol_handler(ctx: Context<EmergencyWithdrawSol>) -> Result<()> {
+    let outgoing_message = &ctx.accounts.outgoing_message;
+    let (_, amount) = check_emergency_withdrawal(
+        &ctx.accounts.bridge,
+        &ctx.accounts.emergency_exit,
+        outgoing_message,
+        &ctx.accounts.relayed_nonces,
+        &NATIVE_SOL_PUBKEY,
+    )?;
+
+    ctx.accounts.returned_transfer.outgoing_nonce = outgoing_message.nonce;
+
+    let token_deposits = &mut ctx.accounts.token_deposits;
+    token_deposits.locked_amount = token_deposits.locked_amount.saturating_sub(amount);
+
+    let seeds: &[&[&[u8]]] = &[&[SOL_VAULT_SEED, &[ctx.accounts.sol_vault_state.bump]]];
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.system_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.sol_vault.to_account_info(),
+            to: ctx.accounts.sender.to_account_info(),
+        },
+        seeds,
+    );
+    system_program::transfer(cpi_ctx, amount)?;
+
+    log_event!(
+        info,
+        op = "emergency_withdraw_sol",
+        nonce = outgoing_message.nonce,
+        sender = outgoing_message.sender,
+        amount = amount,
+    );
+
+    emit!(EmergencyWithdrawal {
+        nonce: outgoing_message.nonce,
+        sender: outgoing_message.sender,
+        local_token: NATIVE_SOL_PUBKEY,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::EmergencyWithdrawSol as EmergencyWithdrawSolIx,
+        solana_to_base::Transfer as TransferMessage,
+        test_utils::{
+            emergency_exit_pda, mock_clock, mock_emergency_exit, mock_relayed_nonces,
+            relayed_nonces_pda, set_mock_pause, set_program_account, setup_bridge,
+            sol_vault_state_pda, token_deposits_pda, SetupBridgeResult,
+        },
+        ID,
+    };
+
+    const AMOUNT: u64 = LAMPORTS_PER_SOL;
+    const NONCE: u64 = 3;
+
+    fn sol_vault() -> Pubkey {
+        Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0
+    }
+
+    /// Pauses the bridge behind an open emergency exit and writes an outgoing message from
+    /// `sender` whose `AMOUNT` lamports are locked in the SOL vault.
+    fn setup_withdrawal(svm: &mut LiteSVM, bridge_pda: Pubkey, sender: &Keypair) -> Pubkey {
+        set_mock_pause(svm, bridge_pda, true);
+        mock_emergency_exit(svm, 1_000);
+        mock_clock(svm, 1_000);
+
+        let outgoing_message = Pubkey::new_unique();
+        set_program_account(
+            svm,
+            outgoing_message,
+            &OutgoingMessage::new_transfer(
+                NONCE,
+                sender.pubkey(),
+                0,
+                0,
+                TransferMessage {
+                    to: [1; 20],
+                    local_token: NATIVE_SOL_PUBKEY,
+                    remote_token: [2; 20],
+                    amount: AMOUNT,
+                    call: None,
+                    memo: None,
+                    swap_guard: None,
+                },
+            ),
+        );
+
+        let mut vault = svm.get_account(&sol_vault()).unwrap();
+        vault.lamports += AMOUNT;
+        svm.set_account(sol_vault(), vault).unwrap();
+        set_program_account(
+            svm,
+            token_deposits_pda(&sol_vault()),
+            &TokenDeposits {
+                vault: sol_vault(),
+                locked_amount: AMOUNT,
+            },
+        );
+
+        outgoing_message
+    }
+
+    fn emergency_withdraw_sol(
+        svm: &mut LiteSVM,
+        sender: &Keypair,
+        outgoing_message: Pubkey,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::EmergencyWithdrawSol {
+                sender: sender.pubkey(),
+                bridge: Pubkey::find_program_address(&[BRIDGE_SEED], &ID).0,
+                emergency_exit: emergency_exit_pda(),
+                outgoing_message,
+                relayed_nonces: relayed_nonces_pda(RelayedNonces::page_of(NONCE)),
+                returned_transfer: Pubkey::find_program_address(
+                    &[RETURNED_TRANSFER_SEED, &NONCE.to_le_bytes()],
+                    &ID,
+                )
+                .0,
+                sol_vault_state: sol_vault_state_pda(),
+                sol_vault: sol_vault(),
+                token_deposits: token_deposits_pda(&sol_vault()),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: EmergencyWithdrawSolIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[sender],
+            Message::new(&[ix], Some(&sender.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_emergency_withdraw_sol_success() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let sender = Keypair::new();
+        svm.airdrop(&sender.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let outgoing_message = setup_withdrawal(&mut svm, bridge_pda, &sender);
+        let vault_balance = svm.get_account(&sol_vault()).unwrap().lamports;
+        let message_rent = svm.get_account(&outgoing_message).unwrap().lamports;
+        let sender_balance = svm.get_account(&sender.pubkey()).unwrap().lamports;
+
+        emergency_withdraw_sol(&mut svm, &sender, outgoing_message).unwrap();
+
+        assert_eq!(
+            svm.get_account(&sol_vault()).unwrap().lamports,
+            vault_balance - AMOUNT
+        );
+        let marker_rent = svm
+            .minimum_balance_for_rent_exemption(DISCRIMINATOR_LEN + ReturnedTransfer::INIT_SPACE);
+        assert_eq!(
+            svm.get_account(&sender.pubkey()).unwrap().lamports,
+            sender_balance + AMOUNT + message_rent - marker_rent - 5_000
+        );
+        let token_deposits = svm.get_account(&token_deposits_pda(&sol_vault())).unwrap();
+        let token_deposits = TokenDeposits::try_deserialize(&mut &token_deposits.data[..]).unwrap();
+        assert_eq!(token_deposits.locked_amount, 0);
+
+        let outgoing_message_account = svm.get_account(&outgoing_message).unwrap();
+        assert_eq!(outgoing_message_account.lamports, 0);
+        assert_eq!(outgoing_message_account.owner, system_program::ID);
+
+        // Withdrawing the same message again is rejected by the returned transfer marker
+        svm.expire_blockhash();
+        let replayed_message = setup_withdrawal(&mut svm, bridge_pda, &sender);
+        let error = emergency_withdraw_sol(&mut svm, &sender, replayed_message).unwrap_err();
+        assert!(
+            error.contains("already in use"),
+            "Expected returned transfer creation error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_emergency_withdraw_sol_timelocked_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let sender = Keypair::new();
+        svm.airdrop(&sender.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let outgoing_message = setup_withdrawal(&mut svm, bridge_pda, &sender);
+        mock_clock(&mut svm, 999);
+
+        let error = emergency_withdraw_sol(&mut svm, &sender, outgoing_message).unwrap_err();
+        assert!(
+            error.contains("EmergencyExitTimelocked"),
+            "Expected EmergencyExitTimelocked error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_emergency_withdraw_sol_relayed_message_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let sender = Keypair::new();
+        svm.airdrop(&sender.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let outgoing_message = setup_withdrawal(&mut svm, bridge_pda, &sender);
+        mock_relayed_nonces(&mut svm, RelayedNonces::page_of(NONCE), &[NONCE]);
+
+        let error = emergency_withdraw_sol(&mut svm, &sender, outgoing_message).unwrap_err();
+        assert!(
+            error.contains("MessageAlreadyRelayed"),
+            "Expected MessageAlreadyRelayed error, got: {}",
+            error
+        );
+    }
+
+    #[test]
+    fn test_emergency_withdraw_sol_unpaused_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let sender = Keypair::new();
+        svm.airdrop(&sender.pubkey(), LAMPORTS_PER_SOL).unwrap();
+        let outgoing_message = setup_withdrawal(&mut svm, bridge_pda, &sender);
+        set_mock_pause(&mut svm, bridge_pda, false);
+
+        let error = emergency_withdraw_sol(&mut svm, &sender, outgoing_message).unwrap_err();
+        assert!(
+            error.contains("EmergencyExitRequiresPause"),
+            "Expected EmergencyExitRequiresPause error, got: {}",
+            error
+        );
+    }
+}
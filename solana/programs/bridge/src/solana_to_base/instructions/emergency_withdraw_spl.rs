@@ -0,0 +1,375 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::{
+    base_to_solana::{constants::RETURNED_TRANSFER_SEED, ReturnedTransfer},
+    common::{
+        bridge::Bridge, internal::scaling::to_local_spl_amount, EmergencyExit, TokenDeposits,
+        VaultDecimals, BRIDGE_SEED, DISCRIMINATOR_LEN, EMERGENCY_EXIT_SEED, TOKEN_DEPOSITS_SEED,
+        TOKEN_VAULT_SEED, VAULT_DECIMALS_SEED,
+    },
+    solana_to_base::{
+        check_emergency_withdrawal, OutgoingMessage, RelayedNonces, RELAYED_NONCES_SEED,
+    },
+    BridgeError, EmergencyWithdrawal, ID,
+};
+
+/// Accounts struct for the `emergency_withdraw_spl` instruction that returns the SPL tokens locked
+/// by an outgoing message not relayed to Base to its sender while the emergency exit is open.
+#[derive(Accounts)]
+pub struct EmergencyWithdrawSpl<'info> {
+    /// The sender of the outgoing message, receiving the message rent.
+    /// Pays for the returned transfer marker.
+    #[account(mut)]
+    pub sender: Signer<'info>,
+
+    /// The main bridge state account, which must be paused
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The emergency exit enabled by the guardian
+    #[account(seeds = [EMERGENCY_EXIT_SEED], bump)]
+    pub emergency_exit: Account<'info, EmergencyExit>,
+
+    /// The outgoing message whose tokens are withdrawn, closed by this instruction
+    #[account(
+        mut,
+        close = sender,
+        constraint = outgoing_message.sender == sender.key() @ BridgeError::IncorrectOutgoingMessageSender,
+    )]
+    pub outgoing_message: Account<'info, OutgoingMessage>,
+
+    /// The relayed nonces registry covering the outgoing message nonce, possibly not created yet.
+    ///
+    /// CHECK: PDA with RELAYED_NONCES_SEED, deserialized only if owned by this program.
+    #[account(
+        seeds = [
+            RELAYED_NONCES_SEED,
+            &RelayedNonces::page_of(outgoing_message.nonce).to_le_bytes(),
+        ],
+        bump
+    )]
+    pub relayed_nonces: AccountInfo<'info>,
+
+    /// Marker shared with `ReturnTransfer` messages so that the transfer of the outgoing message
+    /// is returned to Solana at most once.
+    #[account(
+        init,
+        payer = sender,
+        seeds = [RETURNED_TRANSFER_SEED, &outgoing_message.nonce.to_le_bytes()],
+        bump,
+        space = DISCRIMINATOR_LEN + ReturnedTransfer::INIT_SPACE,
+    )]
+    pub returned_transfer: Account<'info, ReturnedTransfer>,
+
+    /// The SPL mint locked by the outgoing message
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// The token vault holding the locked tokens.
+    /// - PDA with TOKEN_VAULT_SEED, the mint and the remote token of the message, validated in
+    ///   the handler
+    #[account(mut)]
+    pub token_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// The sender token account receiving the withdrawn tokens
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = sender,
+        token::token_program = token_program,
+    )]
+    pub to_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Accounting for the amount locked in the token vault
+    #[account(mut, seeds = [TOKEN_DEPOSITS_SEED, token_vault.key().as_ref()], bump)]
+    pub token_deposits: Account<'info, TokenDeposits>,
+
+    /// Decimals of the Base token the vault is bridged to, scaling the message amount back to
+    /// the mint decimals.
+    ///
+    /// CHECK: PDA with VAULT_DECIMALS_SEED, deserialized only if owned by this program.
+    #[account(seeds = [VAULT_DECIMALS_SEED, token_vault.key().as_ref()], bump)]
+    pub vault_decimals: AccountInfo<'info>,
+
+    /// Token program of the mint
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// System program required for the marker creation
+    pub system_program: Program<'info, System>,
+}
+
+pub fn emergency_withdraw_spl_handler(ctx: Context<EmergencyWithdrawSpl>) -> Result<()> {
+    let outgoing_message = &ctx.accounts.outgoing_message;
+    let mint_key = ctx.accounts.mint.key();
+    let (remote_token, remote_amount) = check_emergency_withdrawal(
+        &ctx.accounts.bridge,
+        &ctx.accounts.emergency_exit,
+        outgoing_message,
+        &ctx.accounts.relayed_nonces,
+        &mint_key,
+    )?;
+
+    // Check that the token vault is the expected PDA
+    let (token_vault_pda, token_vault_bump) = Pubkey::find_program_address(
+        &[TOKEN_VAULT_SEED, mint_key.as_ref(), remote_token.as_ref()],
+        &ID,
+    );
+    require_keys_eq!(
+        ctx.accounts.token_vault.key(),
+        token_vault_pda,
+        BridgeError::IncorrectTokenVault
+    );
+    let scaler_exponent =
+        VaultDecimals::load_scaler_exponent(&ctx.accounts.vault_decimals, &token_vault_pda)?;
+    let amount = to_local_spl_amount(remote_amount, scaler_exponent)?;
+
+    ctx.accounts.returned_transfer.outgoing_nonce = outgoing_message.nonce;
+
+    let token_deposits = &mut ctx.accounts.token_deposits;
+    token_deposits.locked_amount = token_deposits.locked_amount.saturating_sub(amount);
+
+    let seeds: &[&[&[u8]]] = &[&[
+        TOKEN_VAULT_SEED,
+        mint_key.as_ref(),
+        remote_token.as_ref(),
+        &[token_vault_bump],
+    ]];
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            mint: ctx.accounts.mint.to_account_info(),
+            from: ctx.accounts.token_vault.to_account_info(),
+            to: ctx.accounts.to_token_account.to_account_info(),
+            authority: ctx.accounts.token_vault.to_account_info(),
+        },
+        seeds,
+    );
+    token_interface::transfer_checked(cpi_ctx, amount, ctx.accounts.mint.decimals)?;
+
+    log_event!(
+        info,
+        op = "emergency_withdraw_spl",
+        nonce = outgoing_message.nonce,
+        sender = outgoing_message.sender,
+        amount = amount,
+    );
+
+    emit!(EmergencyWithdrawal {
+        nonce: outgoing_message.nonce,
+        sender: outgoing_message.sender,
+        local_token: mint_key,
+        amount,
+    });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, native_token::LAMPORTS_PER_SOL},
+        system_program, InstructionData,
+    };
+    use anchor_spl::token_interface::spl_token_2022::{
+        self, solana_program::program_pack::Pack, state::Account as SplTokenAccount,
+    };
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::EmergencyWithdrawSpl as EmergencyWithdrawSplIx,
+        solana_to_base::{BatchRecipient, TransferBatch},
+        test_utils::{
+            create_mock_mint, create_mock_token_account, emergency_exit_pda, mock_clock,
+            mock_emergency_exit, relayed_nonces_pda, set_mock_pause, set_program_account,
+            setup_bridge, token_deposits_pda, vault_decimals_pda, SetupBridgeResult,
+        },
+    };
+
+    const NONCE: u64 = 7;
+    const REMOTE_TOKEN: [u8; 20] = [2; 20];
+
+    struct Withdrawal {
+        sender: Keypair,
+        mint: Pubkey,
+        token_vault: Pubkey,
+        to_token_account: Pubkey,
+        outgoing_message: Pubkey,
+    }
+
+    /// Pauses the bridge behind an open emergency exit and writes a transfer batch whose tokens,
+    /// 100 in total, are locked in the token vault alongside 50 tokens of other depositors.
+    fn setup_withdrawal(svm: &mut LiteSVM, bridge_pda: Pubkey) -> Withdrawal {
+        set_mock_pause(svm, bridge_pda, true);
+        mock_emergency_exit(svm, 1_000);
+        mock_clock(svm, 1_000);
+
+        let sender = Keypair::new();
+        svm.airdrop(&sender.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+        let mint = Pubkey::new_unique();
+        create_mock_mint(svm, mint, 6, spl_token_2022::ID);
+        let token_vault = Pubkey::find_program_address(
+            &[TOKEN_VAULT_SEED, mint.as_ref(), REMOTE_TOKEN.as_ref()],
+            &ID,
+        )
+        .0;
+        create_mock_token_account(svm, token_vault, mint, token_vault, 150);
+        set_program_account(
+            svm,
+            token_deposits_pda(&token_vault),
+            &TokenDeposits {
+                vault: token_vault,
+                locked_amount: 150,
+            },
+        );
+        let to_token_account = Pubkey::new_unique();
+        create_mock_token_account(svm, to_token_account, mint, sender.pubkey(), 0);
+
+        let outgoing_message = Pubkey::new_unique();
+        set_program_account(
+            svm,
+            outgoing_message,
+            &OutgoingMessage::new_transfer_batch(
+                NONCE,
+                sender.pubkey(),
+                0,
+                0,
+                TransferBatch {
+                    local_token: mint,
+                    remote_token: REMOTE_TOKEN,
+                    recipients: vec![
+                        BatchRecipient {
+                            to: [3; 20],
+                            amount: 60,
+                        },
+                        BatchRecipient {
+                            to: [4; 20],
+                            amount: 40,
+                        },
+                    ],
+                },
+            ),
+        );
+
+        Withdrawal {
+            sender,
+            mint,
+            token_vault,
+            to_token_account,
+            outgoing_message,
+        }
+    }
+
+    fn emergency_withdraw_spl(
+        svm: &mut LiteSVM,
+        withdrawal: &Withdrawal,
+        token_vault: Pubkey,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::EmergencyWithdrawSpl {
+                sender: withdrawal.sender.pubkey(),
+                bridge: Pubkey::find_program_address(&[BRIDGE_SEED], &ID).0,
+                emergency_exit: emergency_exit_pda(),
+                outgoing_message: withdrawal.outgoing_message,
+                relayed_nonces: relayed_nonces_pda(RelayedNonces::page_of(NONCE)),
+                returned_transfer: Pubkey::find_program_address(
+                    &[RETURNED_TRANSFER_SEED, &NONCE.to_le_bytes()],
+                    &ID,
+                )
+                .0,
+                mint: withdrawal.mint,
+                token_vault,
+                to_token_account: withdrawal.to_token_account,
+                token_deposits: token_deposits_pda(&token_vault),
+                vault_decimals: vault_decimals_pda(&token_vault),
+                token_program: spl_token_2022::ID,
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: EmergencyWithdrawSplIx {}.data(),
+        };
+
+        let tx = Transaction::new(
+            &[&withdrawal.sender],
+            Message::new(&[ix], Some(&withdrawal.sender.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    fn token_balance(svm: &LiteSVM, token_account: &Pubkey) -> u64 {
+        SplTokenAccount::unpack(&svm.get_account(token_account).unwrap().data)
+            .unwrap()
+            .amount
+    }
+
+    #[test]
+    fn test_emergency_withdraw_spl_success() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let withdrawal = setup_withdrawal(&mut svm, bridge_pda);
+        emergency_withdraw_spl(&mut svm, &withdrawal, withdrawal.token_vault).unwrap();
+
+        assert_eq!(token_balance(&svm, &withdrawal.to_token_account), 100);
+        assert_eq!(token_balance(&svm, &withdrawal.token_vault), 50);
+
+        let token_deposits = svm
+            .get_account(&token_deposits_pda(&withdrawal.token_vault))
+            .unwrap();
+        let token_deposits = TokenDeposits::try_deserialize(&mut &token_deposits.data[..]).unwrap();
+        assert_eq!(token_deposits.locked_amount, 50);
+
+        let outgoing_message_account = svm.get_account(&withdrawal.outgoing_message).unwrap();
+        assert_eq!(outgoing_message_account.lamports, 0);
+        assert_eq!(outgoing_message_account.owner, system_program::ID);
+    }
+
+    #[test]
+    fn test_emergency_withdraw_spl_incorrect_vault_fails() {
+        let SetupBridgeResult {
+            mut svm,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let withdrawal = setup_withdrawal(&mut svm, bridge_pda);
+
+        // A vault of the same mint for another remote token
+        let other_vault = Pubkey::find_program_address(
+            &[TOKEN_VAULT_SEED, withdrawal.mint.as_ref(), [5; 20].as_ref()],
+            &ID,
+        )
+        .0;
+        create_mock_token_account(&mut svm, other_vault, withdrawal.mint, other_vault, 100);
+        set_program_account(
+            &mut svm,
+            token_deposits_pda(&other_vault),
+            &TokenDeposits {
+                vault: other_vault,
+                locked_amount: 100,
+            },
+        );
+
+        let error = emergency_withdraw_spl(&mut svm, &withdrawal, other_vault).unwrap_err();
+        assert!(
+            error.contains("IncorrectTokenVault"),
+            "Expected IncorrectTokenVault error, got: {}",
+            error
+        );
+    }
+}
@@ -1,9 +1,18 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
 
 use crate::{
-    common::bridge::Bridge,
-    solana_to_base::{Call, CallType},
-    BridgeError,
+    base_to_solana::token::create_marker,
+    common::{
+        bridge::{Bridge, GasLane},
+        DexRouterList, EmergencyExit, InsuranceFund, PayerRateLimitConfig, ProtocolFeeConfig,
+        DISCRIMINATOR_LEN, GAS_FEE_ESCROW_SEED,
+    },
+    solana_to_base::{
+        Call, CallType, OutgoingMessage, PayerRateLimit, RelayedNonces, SwapGuard,
+        BRIDGE_FULL_BALANCE, MAX_TRANSFER_MEMO_LEN, PAYER_RATE_LIMIT_SEED,
+    },
+    BaseFeeCapped, BridgeError, OutgoingMessageCommitted,
 };
 
 pub mod wrap_token;
@@ -11,16 +20,33 @@ pub use wrap_token::*;
 
 pub mod bridge_call;
 pub use bridge_call::*;
+mod bridge_session;
+pub use bridge_session::*;
 pub mod bridge_sol;
 pub use bridge_sol::*;
+pub mod bridge_sol_batch;
+pub use bridge_sol_batch::*;
 pub mod bridge_spl;
 pub use bridge_spl::*;
 pub mod bridge_wrapped_token;
 pub use bridge_wrapped_token::*;
+pub mod scale_wrapped_token_amount;
+pub use scale_wrapped_token_amount::*;
 
 pub mod buffered;
 pub use buffered::*;
 
+pub mod confirm_relayed;
+pub use confirm_relayed::*;
+pub mod record_relay_execution;
+pub use record_relay_execution::*;
+pub mod close_relayed_outgoing_message;
+pub use close_relayed_outgoing_message::*;
+pub mod emergency_withdraw_sol;
+pub use emergency_withdraw_sol::*;
+pub mod emergency_withdraw_spl;
+pub use emergency_withdraw_spl::*;
+
 pub fn check_call(call: &Call) -> Result<()> {
     require!(
         matches!(call.ty, CallType::Call | CallType::DelegateCall) || call.to == [0; 20],
@@ -29,31 +55,374 @@ pub fn check_call(call: &Call) -> Result<()> {
     Ok(())
 }
 
+/// Checks an encrypted call, whose `data` is the ciphertext of the calldata.
+pub fn check_encrypted_call(call: &Call) -> Result<()> {
+    check_call(call)?;
+    require!(!call.data.is_empty(), BridgeError::EmptyEncryptedCall);
+    Ok(())
+}
+
+pub fn check_memo(memo: &[u8]) -> Result<()> {
+    require!(
+        memo.len() <= MAX_TRANSFER_MEMO_LEN as usize,
+        BridgeError::TransferMemoTooLong
+    );
+    Ok(())
+}
+
+/// Checks the swap guard of a transfer carrying `call`: a guard only bounds a call, must expect a
+/// non-zero output and must not have expired, and calls to routers of the `dex_router_list` must
+/// be guarded. The `dex_router_list` is only required for unguarded calls.
+pub fn check_swap_guard(
+    call: Option<&Call>,
+    swap_guard: Option<&SwapGuard>,
+    dex_router_list: Option<&AccountInfo>,
+) -> Result<()> {
+    if let Some(swap_guard) = swap_guard {
+        require!(call.is_some(), BridgeError::SwapGuardWithoutCall);
+        require!(swap_guard.min_out > 0, BridgeError::InvalidSwapGuardMinOut);
+        require!(
+            swap_guard.deadline > Clock::get()?.unix_timestamp,
+            BridgeError::SwapGuardExpired
+        );
+        return Ok(());
+    }
+
+    let Some(call) = call.filter(|call| call.ty == CallType::Call) else {
+        return Ok(());
+    };
+    let dex_router_list = dex_router_list.ok_or(BridgeError::MissingDexRouterList)?;
+    if let Some(dex_router_list) = DexRouterList::load(dex_router_list)? {
+        require!(
+            !dex_router_list.contains(&call.to),
+            BridgeError::MissingSwapGuard
+        );
+    }
+    Ok(())
+}
+
+/// Resolves the token amount to bridge: `BRIDGE_FULL_BALANCE` stands for the current balance of
+/// `from_token_account`, on which the protocol fee is then skimmed as for any other amount.
+pub fn resolve_bridge_amount(amount: u64, from_token_account: &TokenAccount) -> u64 {
+    if amount == BRIDGE_FULL_BALANCE {
+        from_token_account.amount
+    } else {
+        amount
+    }
+}
+
+/// Receipt of an outgoing message, returned by the instructions sending one so that programs
+/// invoking them through CPI can read the assigned nonce and the fees charged from the return data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct OutgoingMessageReceipt {
+    /// The outgoing message account
+    pub outgoing_message: Pubkey,
+    /// Nonce assigned to the message
+    pub nonce: u64,
+    /// Index of the message leaf in the outbox MMR
+    pub leaf_index: u64,
+    /// Gas fee charged to the payer, in lamports
+    pub gas_fee: u64,
+    /// Protocol fee skimmed from the bridged amount, in the smallest unit of the local token
+    pub protocol_fee: u64,
+}
+
+/// Appends `message`, persisted in the `outgoing_message` account, to the outbox MMR of the bridge
+/// and returns its receipt.
+pub fn commit_outgoing_message(
+    bridge: &mut Bridge,
+    outgoing_message: &Pubkey,
+    message: &OutgoingMessage,
+    gas_fee: u64,
+    protocol_fee: u64,
+) -> Result<OutgoingMessageReceipt> {
+    let leaf_index = bridge.outbox.append(message.leaf_hash(outgoing_message)?)?;
+
+    emit!(OutgoingMessageCommitted {
+        outgoing_message: *outgoing_message,
+        nonce: message.nonce,
+        leaf_index,
+        root: bridge.outbox.root(),
+    });
+
+    Ok(OutgoingMessageReceipt {
+        outgoing_message: *outgoing_message,
+        nonce: message.nonce,
+        leaf_index,
+        gas_fee,
+        protocol_fee,
+    })
+}
+
+/// Charges the gas fee of an outgoing message to `payer`, for `gas_per_call` plus the
+/// `payload_gas` its payload costs on top of it (e.g. for encrypted calls), at the base fee of its
+/// gas `lane`.
+///
+/// The fee is sent to `gas_fee_receiver`, or to the `gas_fee_escrow` PDA if the receiver cannot be
+/// credited (e.g. it is misconfigured as a program-owned account) so that bridging never fails because
+/// of the receiver. The escrow is topped up to rent exemption by `payer` on its first credit.
+///
+/// Once initialized, the `insurance_fund` is credited with its share of the fee beforehand.
+/// Returns the gas fee charged, excluding the escrow top-up.
+///
+/// The message is first recorded against the rate limits of `payer` (see
+/// `record_payer_rate_limit`). `gas_fee_escrow` and `payer_rate_limit` are only required when used.
+#[allow(clippy::too_many_arguments)]
 pub fn pay_for_gas<'info>(
     system_program: &Program<'info, System>,
     payer: &Signer<'info>,
     gas_fee_receiver: &AccountInfo<'info>,
+    gas_fee_escrow: Option<&AccountInfo<'info>>,
+    insurance_fund: &AccountInfo<'info>,
+    payer_rate_limit_config: &AccountInfo<'info>,
+    payer_rate_limit: Option<&AccountInfo<'info>>,
     bridge: &mut Bridge,
-) -> Result<()> {
-    // Get the base fee for the current window
+    lane: GasLane,
+    payload_gas: u64,
+) -> Result<u64> {
+    let gas = bridge.gas_config.gas_per_call + payload_gas;
     let current_timestamp = Clock::get()?.unix_timestamp;
-    let base_fee = bridge.eip1559.refresh_base_fee(current_timestamp);
+
+    record_payer_rate_limit(
+        system_program,
+        payer,
+        payer_rate_limit_config,
+        payer_rate_limit,
+        current_timestamp,
+        gas,
+    )?;
+
+    // Get the base fee for the current window, honoring the fee quote grace period
+    let base_fee =
+        bridge.refresh_base_fee_with(lane, current_timestamp, |uncapped_base_fee, base_fee| {
+            emit!(BaseFeeCapped {
+                uncapped_base_fee,
+                base_fee,
+            })
+        });
 
     // Record gas usage for this transaction
-    bridge.eip1559.add_gas_usage(bridge.gas_config.gas_per_call);
+    bridge.gas_lane_mut(lane).add_gas_usage(gas);
+
+    let gas_cost =
+        gas * base_fee * bridge.gas_config.gas_cost_scaler / bridge.gas_config.gas_cost_scaler_dp;
+    log_event!(
+        debug,
+        op = "pay_for_gas",
+        gas = gas,
+        base_fee = base_fee,
+        gas_cost = gas_cost,
+    );
+
+    let insurance_share = credit_insurance_fund(system_program, payer, insurance_fund, gas_cost)?;
+    let receiver_share = gas_cost - insurance_share;
+
+    let (to, amount) = if can_receive_lamports(gas_fee_receiver, receiver_share)? {
+        (gas_fee_receiver, receiver_share)
+    } else {
+        let gas_fee_escrow = gas_fee_escrow.ok_or(BridgeError::IncorrectGasFeeEscrow)?;
+        let (gas_fee_escrow_pda, _) =
+            Pubkey::find_program_address(&[GAS_FEE_ESCROW_SEED], &crate::ID);
+        require_keys_eq!(
+            gas_fee_escrow.key(),
+            gas_fee_escrow_pda,
+            BridgeError::IncorrectGasFeeEscrow
+        );
 
-    let gas_cost = bridge.gas_config.gas_per_call * base_fee * bridge.gas_config.gas_cost_scaler
-        / bridge.gas_config.gas_cost_scaler_dp;
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+        let top_up = rent_exempt_minimum.saturating_sub(gas_fee_escrow.lamports() + receiver_share);
+        (gas_fee_escrow, receiver_share + top_up)
+    };
 
     let cpi_ctx = CpiContext::new(
         system_program.to_account_info(),
         anchor_lang::system_program::Transfer {
             from: payer.to_account_info(),
-            to: gas_fee_receiver.to_account_info(),
+            to: to.to_account_info(),
         },
     );
 
-    anchor_lang::system_program::transfer(cpi_ctx, gas_cost)?;
+    anchor_lang::system_program::transfer(cpi_ctx, amount)?;
+
+    Ok(gas_cost)
+}
+
+/// Records an outgoing message costing `gas` against the limits of the `payer_rate_limit_config`,
+/// creating the `payer_rate_limit` PDA of `payer` on its first message. Payers are not limited
+/// while the config is uninitialized or disabled, nor when they are exempt.
+fn record_payer_rate_limit<'info>(
+    system_program: &Program<'info, System>,
+    payer: &Signer<'info>,
+    payer_rate_limit_config: &AccountInfo<'info>,
+    payer_rate_limit: Option<&AccountInfo<'info>>,
+    now: i64,
+    gas: u64,
+) -> Result<()> {
+    let Some(config) = PayerRateLimitConfig::load(payer_rate_limit_config)? else {
+        return Ok(());
+    };
+    if !config.limits(payer.key) {
+        return Ok(());
+    }
+
+    let payer_rate_limit = payer_rate_limit.ok_or(BridgeError::IncorrectPayerRateLimit)?;
+    let (payer_rate_limit_pda, bump) =
+        Pubkey::find_program_address(&[PAYER_RATE_LIMIT_SEED, payer.key.as_ref()], &crate::ID);
+    require_keys_eq!(
+        payer_rate_limit.key(),
+        payer_rate_limit_pda,
+        BridgeError::IncorrectPayerRateLimit
+    );
+
+    let mut rate_limit = if payer_rate_limit.owner == &crate::ID {
+        PayerRateLimit::try_deserialize(&mut &payer_rate_limit.try_borrow_data()?[..])?
+    } else {
+        create_marker(
+            payer_rate_limit,
+            payer,
+            system_program,
+            &[PAYER_RATE_LIMIT_SEED, payer.key.as_ref(), &[bump]],
+            DISCRIMINATOR_LEN + PayerRateLimit::INIT_SPACE,
+        )?;
+        PayerRateLimit::default()
+    };
+
+    rate_limit.record(&config, now, gas)?;
+    rate_limit.try_serialize(&mut &mut payer_rate_limit.try_borrow_mut_data()?[..])
+}
+
+/// Transfers the share of `gas_cost` owed to the insurance fund from `payer`, if the fund is
+/// initialized. Returns the credited share.
+fn credit_insurance_fund<'info>(
+    system_program: &Program<'info, System>,
+    payer: &Signer<'info>,
+    insurance_fund: &AccountInfo<'info>,
+    gas_cost: u64,
+) -> Result<u64> {
+    let Some(mut fund) = InsuranceFund::load(insurance_fund)? else {
+        return Ok(0);
+    };
+
+    let share = fund.share_of(gas_cost);
+    if share == 0 {
+        return Ok(0);
+    }
+
+    let cpi_ctx = CpiContext::new(
+        system_program.to_account_info(),
+        anchor_lang::system_program::Transfer {
+            from: payer.to_account_info(),
+            to: insurance_fund.to_account_info(),
+        },
+    );
+    anchor_lang::system_program::transfer(cpi_ctx, share)?;
+
+    fund.total_accrued = fund.total_accrued.saturating_add(share);
+    fund.try_serialize(&mut &mut insurance_fund.try_borrow_mut_data()?[..])?;
+
+    Ok(share)
+}
+
+/// Returns whether a System Program transfer of `amount` lamports to `receiver` succeeds:
+/// the receiver must be a writable system account that is rent exempt after the transfer.
+pub fn can_receive_lamports(receiver: &AccountInfo, amount: u64) -> Result<bool> {
+    if !receiver.is_writable
+        || receiver.executable
+        || receiver.owner != &anchor_lang::system_program::ID
+    {
+        return Ok(false);
+    }
+
+    let lamports = receiver.lamports() + amount;
+    Ok(lamports == 0 || lamports >= Rent::get()?.minimum_balance(receiver.data_len()))
+}
+
+/// Protocol fee charged on a bridged amount.
+pub struct ProtocolFee {
+    /// Treasury collecting the fee
+    pub treasury: Pubkey,
+    /// Protocol fee rate in basis points
+    pub bps: u16,
+    /// Protocol fee deducted from the bridged amount
+    pub fee: u64,
+}
+
+/// Returns the protocol fee charged on bridging `amount` of `local_token`, or `None` if no fee
+/// applies (the protocol fee config is uninitialized or the fee rounds down to zero).
+pub fn protocol_fee(
+    protocol_fee_config: &AccountInfo,
+    local_token: &Pubkey,
+    amount: u64,
+) -> Result<Option<ProtocolFee>> {
+    let Some(config) = ProtocolFeeConfig::load(protocol_fee_config)? else {
+        return Ok(None);
+    };
+
+    let fee = config.fee(local_token, amount);
+    if fee == 0 {
+        return Ok(None);
+    }
+
+    Ok(Some(ProtocolFee {
+        treasury: config.treasury,
+        bps: config.bps(local_token),
+        fee,
+    }))
+}
+
+/// Checks that `treasury_token_account` is a `mint` token account owned by the protocol fee
+/// `treasury`.
+pub fn check_protocol_fee_token_account(
+    treasury_token_account: &AccountInfo,
+    treasury: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Result<()> {
+    require_keys_eq!(
+        *treasury_token_account.owner,
+        *token_program,
+        BridgeError::IncorrectProtocolFeeTreasury
+    );
+
+    let token_account =
+        TokenAccount::try_deserialize(&mut &treasury_token_account.try_borrow_data()?[..])?;
+    require!(
+        token_account.owner == *treasury && token_account.mint == *mint,
+        BridgeError::IncorrectProtocolFeeTreasury
+    );
 
     Ok(())
 }
+
+/// Checks that `outgoing_message` can be withdrawn through the emergency exit: the bridge is paused,
+/// the exit timelock elapsed, and the message locked `local_token` and is not confirmed as relayed
+/// by `relayed_nonces`. Returns the remote token and the amount locked by the message.
+pub fn check_emergency_withdrawal(
+    bridge: &Bridge,
+    emergency_exit: &EmergencyExit,
+    outgoing_message: &OutgoingMessage,
+    relayed_nonces: &AccountInfo,
+    local_token: &Pubkey,
+) -> Result<([u8; 20], u64)> {
+    require!(bridge.paused, BridgeError::EmergencyExitRequiresPause);
+    require!(
+        emergency_exit.is_withdrawable(Clock::get()?.unix_timestamp),
+        BridgeError::EmergencyExitTimelocked
+    );
+    require!(
+        !RelayedNonces::is_relayed_in(relayed_nonces, outgoing_message.nonce)?,
+        BridgeError::MessageAlreadyRelayed
+    );
+
+    let Some((message_local_token, remote_token, amount)) = outgoing_message.transferred_tokens()
+    else {
+        return err!(BridgeError::NoLockedTokens);
+    };
+    require_keys_eq!(
+        message_local_token,
+        *local_token,
+        BridgeError::MintDoesNotMatchLocalToken
+    );
+
+    Ok((remote_token, amount))
+}
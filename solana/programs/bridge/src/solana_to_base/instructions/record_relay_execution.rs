@@ -0,0 +1,244 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    base_to_solana::{compute_relay_execution_message_hash, verify_oracle_approvals},
+    common::{bridge::Bridge, BRIDGE_SEED, DISCRIMINATOR_LEN},
+    solana_to_base::{RelayExecution, RELAY_EXECUTION_SEED},
+    BridgeError,
+};
+
+/// Accounts struct for the `record_relay_execution` instruction that records the relayer which
+/// executed an outgoing message on Base. Authorization is enforced via oracle EVM signatures over
+/// the nonce and the relayer; the Solana payer only funds account creation.
+#[derive(Accounts)]
+#[instruction(nonce: u64)]
+pub struct RecordRelayExecution<'info> {
+    /// Payer funds the record account creation. Authorization is enforced via oracle EVM
+    /// signatures.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The main bridge state account providing the oracle configuration and the next nonce.
+    #[account(seeds = [BRIDGE_SEED], bump)]
+    pub bridge: Account<'info, Bridge>,
+
+    /// The record of the relayer that executed the message.
+    /// - Uses PDA with RELAY_EXECUTION_SEED and `nonce` for deterministic address
+    /// - Created once, so the executing relayer of a nonce can never be changed
+    #[account(
+        init,
+        payer = payer,
+        space = DISCRIMINATOR_LEN + RelayExecution::INIT_SPACE,
+        seeds = [RELAY_EXECUTION_SEED, &nonce.to_le_bytes()],
+        bump
+    )]
+    pub relay_execution: Account<'info, RelayExecution>,
+
+    /// Partner `Config` account (PDA with seed "config") owned by partner program.
+    /// Unchecked to avoid Anchor pre-handler owner checks; PDA address is validated in the handler.
+    /// CHECK: This is validated in the handler.
+    pub partner_config: AccountInfo<'info>,
+
+    /// System program required for creating the record account.
+    pub system_program: Program<'info, System>,
+}
+
+pub fn record_relay_execution_handler(
+    ctx: Context<RecordRelayExecution>,
+    nonce: u64,
+    relayer: Pubkey,
+    signatures: Vec<[u8; 65]>,
+) -> Result<()> {
+    // Like confirmations, records are accepted while paused: they only state a fact from Base and
+    // move no funds.
+    let message_hash = compute_relay_execution_message_hash(nonce, &relayer);
+    verify_oracle_approvals(
+        &ctx.accounts.bridge,
+        &ctx.accounts.partner_config,
+        &signatures,
+        &message_hash,
+    )?;
+
+    require!(
+        nonce < ctx.accounts.bridge.nonce,
+        BridgeError::InvalidRelayedNonce
+    );
+
+    *ctx.accounts.relay_execution = RelayExecution { nonce, relayer };
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{
+        solana_program::{instruction::Instruction, keccak::hash as keccak_hash},
+        system_program, InstructionData,
+    };
+    use litesvm::LiteSVM;
+    use secp256k1::{Message as SecpMessage, Secp256k1, SecretKey};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        common::MAX_SIGNER_COUNT,
+        instruction::RecordRelayExecution as RecordRelayExecutionIx,
+        test_utils::{setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    const ORACLE_SK: [u8; 32] = [7u8; 32];
+
+    /// Registers a single Base oracle with threshold one and bumps the bridge nonce to
+    /// `next_nonce`.
+    fn setup_oracle(svm: &mut LiteSVM, bridge_pda: Pubkey, next_nonce: u64) {
+        let secp = Secp256k1::new();
+        let sk = SecretKey::from_slice(&ORACLE_SK).unwrap();
+        let pk = secp256k1::PublicKey::from_secret_key(&secp, &sk);
+        let hashed = keccak_hash(&pk.serialize_uncompressed()[1..]);
+        let mut addr = [0u8; 20];
+        addr.copy_from_slice(&hashed.to_bytes()[12..]);
+
+        let mut bridge_acc = svm.get_account(&bridge_pda).unwrap();
+        let mut bridge = Bridge::try_deserialize(&mut &bridge_acc.data[..]).unwrap();
+        bridge.nonce = next_nonce;
+        bridge.base_oracle_config.threshold = 1;
+        bridge.base_oracle_config.signer_count = 1;
+        bridge.base_oracle_config.signers = [[0u8; 20]; MAX_SIGNER_COUNT as usize];
+        bridge.base_oracle_config.signers[0] = addr;
+        let mut new_data = Vec::new();
+        bridge.try_serialize(&mut new_data).unwrap();
+        bridge_acc.data = new_data;
+        svm.set_account(bridge_pda, bridge_acc).unwrap();
+    }
+
+    fn sign_relay_execution(sk_bytes: [u8; 32], nonce: u64, relayer: &Pubkey) -> [u8; 65] {
+        let msg_hash = compute_relay_execution_message_hash(nonce, relayer);
+
+        let secp = Secp256k1::new();
+        let sk = SecretKey::from_slice(&sk_bytes).unwrap();
+        let msg = SecpMessage::from_digest_slice(&msg_hash).unwrap();
+        let (rec_id, sig_bytes64) = secp.sign_ecdsa_recoverable(&msg, &sk).serialize_compact();
+
+        let mut sig65 = [0u8; 65];
+        sig65[..64].copy_from_slice(&sig_bytes64);
+        sig65[64] = 27 + rec_id.to_i32() as u8;
+        sig65
+    }
+
+    fn record_relay_execution(
+        svm: &mut LiteSVM,
+        payer: &Keypair,
+        bridge_pda: Pubkey,
+        nonce: u64,
+        relayer: Pubkey,
+        signatures: Vec<[u8; 65]>,
+    ) -> std::result::Result<(), String> {
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::RecordRelayExecution {
+                payer: payer.pubkey(),
+                bridge: bridge_pda,
+                relay_execution: RelayExecution::pda(nonce),
+                partner_config: Pubkey::new_unique(),
+                system_program: system_program::ID,
+            }
+            .to_account_metas(None),
+            data: RecordRelayExecutionIx {
+                nonce,
+                relayer,
+                signatures,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|_| ())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_record_relay_execution() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_oracle(&mut svm, bridge_pda, 10);
+
+        let relayer = Pubkey::new_unique();
+        let signatures = vec![sign_relay_execution(ORACLE_SK, 3, &relayer)];
+        record_relay_execution(&mut svm, &payer, bridge_pda, 3, relayer, signatures.clone())
+            .expect("Failed to record relay execution");
+
+        let account = svm.get_account(&RelayExecution::pda(3)).unwrap();
+        let relay_execution = RelayExecution::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(relay_execution, RelayExecution { nonce: 3, relayer });
+
+        // The executing relayer of a nonce is recorded once
+        svm.expire_blockhash();
+        record_relay_execution(&mut svm, &payer, bridge_pda, 3, relayer, signatures)
+            .expect_err("Recording a relay execution twice should fail");
+    }
+
+    #[test]
+    fn test_record_relay_execution_rejects_unassigned_nonce() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_oracle(&mut svm, bridge_pda, 10);
+
+        let relayer = Pubkey::new_unique();
+        let signatures = vec![sign_relay_execution(ORACLE_SK, 10, &relayer)];
+        let error_string =
+            record_relay_execution(&mut svm, &payer, bridge_pda, 10, relayer, signatures)
+                .unwrap_err();
+        assert!(
+            error_string.contains("InvalidRelayedNonce"),
+            "Expected InvalidRelayedNonce error, got: {}",
+            error_string
+        );
+    }
+
+    #[test]
+    fn test_record_relay_execution_requires_oracle_signature_over_relayer() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+        setup_oracle(&mut svm, bridge_pda, 10);
+
+        // The attestation binds the relayer: it cannot be redirected to another account
+        let signatures = vec![sign_relay_execution(ORACLE_SK, 3, &Pubkey::new_unique())];
+        let error_string = record_relay_execution(
+            &mut svm,
+            &payer,
+            bridge_pda,
+            3,
+            Pubkey::new_unique(),
+            signatures,
+        )
+        .unwrap_err();
+        assert!(
+            error_string.contains("InsufficientBaseSignatures"),
+            "Expected InsufficientBaseSignatures error, got: {}",
+            error_string
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::common::{internal::scaling::to_remote_amount, PartialTokenMetadata};
+
+/// Accounts struct for the scale_wrapped_token_amount view instruction.
+#[derive(Accounts)]
+pub struct ScaleWrappedTokenAmount<'info> {
+    /// The wrapped token mint whose metadata provides the scaler exponent.
+    /// Validated to be a wrapped token PDA of this program when reading its metadata.
+    pub mint: InterfaceAccount<'info, Mint>,
+}
+
+/// Returns the big-endian u256 Base amount corresponding to `amount` of the wrapped token, as
+/// credited on Base by `bridge_wrapped_token`. Fails when the scaled amount overflows.
+pub fn scale_wrapped_token_amount_handler(
+    ctx: Context<ScaleWrappedTokenAmount>,
+    amount: u64,
+) -> Result<[u8; 32]> {
+    let partial_token_metadata =
+        PartialTokenMetadata::try_from(&ctx.accounts.mint.to_account_info())?;
+    let remote_amount = to_remote_amount(amount, partial_token_metadata.scaler_exponent)?;
+    Ok(remote_amount.to_be_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use alloy_primitives::U256;
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::ScaleWrappedTokenAmount as ScaleWrappedTokenAmountIx,
+        test_utils::{create_mock_wrapped_mint, setup_bridge, SetupBridgeResult},
+        ID,
+    };
+
+    fn scale_wrapped_token_amount(
+        scaler_exponent: u8,
+        amount: u64,
+    ) -> std::result::Result<[u8; 32], String> {
+        let SetupBridgeResult { mut svm, payer, .. } = setup_bridge();
+
+        let partial_token_metadata = PartialTokenMetadata {
+            name: "Test Token".to_string(),
+            symbol: "TEST".to_string(),
+            remote_token: [1u8; 20],
+            scaler_exponent,
+        };
+        let mint = create_mock_wrapped_mint(&mut svm, 0, 6, &partial_token_metadata);
+
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::ScaleWrappedTokenAmount { mint }.to_account_metas(None),
+            data: ScaleWrappedTokenAmountIx { amount }.data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        svm.send_transaction(tx)
+            .map(|meta| <[u8; 32]>::try_from_slice(&meta.return_data.data).unwrap())
+            .map_err(|e| format!("{:?}", e))
+    }
+
+    #[test]
+    fn test_scale_wrapped_token_amount() {
+        let remote_amount = scale_wrapped_token_amount(12, 1_500_000).unwrap();
+        assert_eq!(
+            U256::from_be_bytes(remote_amount),
+            U256::from(15u64) * U256::from(10).pow(U256::from(17))
+        );
+    }
+
+    #[test]
+    fn test_scale_wrapped_token_amount_overflow_fails() {
+        let error_string = scale_wrapped_token_amount(77, 12).unwrap_err();
+        assert!(
+            error_string.contains("ScaledAmountOverflow"),
+            "Expected ScaledAmountOverflow error, got: {}",
+            error_string
+        );
+    }
+}
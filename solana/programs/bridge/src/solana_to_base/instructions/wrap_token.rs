@@ -4,7 +4,7 @@ use anchor_lang::prelude::*;
 use anchor_lang::solana_program::rent::{
     DEFAULT_EXEMPTION_THRESHOLD, DEFAULT_LAMPORTS_PER_BYTE_YEAR,
 };
-use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::system_program::{create_account, transfer, CreateAccount, Transfer};
 use anchor_spl::token_2022::spl_token_2022::extension::{ExtensionType, Length};
 use anchor_spl::token_interface::spl_pod::bytemuck::pod_get_packed_len;
 use anchor_spl::token_interface::{
@@ -15,9 +15,23 @@ use anchor_spl::token_interface::{
 use spl_type_length_value::variable_len_pack::VariableLenPack;
 
 use crate::common::DISCRIMINATOR_LEN;
-use crate::common::{bridge::Bridge, PartialTokenMetadata, BRIDGE_SEED, WRAPPED_TOKEN_SEED};
-use crate::solana_to_base::{pay_for_gas, Call, CallType, OutgoingMessage, OUTGOING_MESSAGE_SEED};
-use crate::solana_to_base::{REMOTE_TOKEN_METADATA_KEY, SCALER_EXPONENT_METADATA_KEY};
+use crate::common::{
+    bridge::{Bridge, GasLane},
+    has_token_metadata, AddressBook, PartialTokenMetadata, ADDRESS_BOOK_SEED, BRIDGE_SEED,
+    INSURANCE_FUND_SEED, PAYER_RATE_LIMIT_CONFIG_SEED, WRAPPED_TOKEN_SEED,
+};
+use crate::solana_to_base::internal::metaplex::{
+    create_metaplex_metadata, metaplex_metadata_address,
+};
+use crate::solana_to_base::{
+    commit_outgoing_message, pay_for_gas, Call, CallType, MessageKind, OutgoingMessage,
+    OUTGOING_MESSAGE_SEED,
+};
+use crate::solana_to_base::{
+    ALLOWED_TOKEN_METADATA_UNICODE_RANGES, MAX_WRAPPED_TOKEN_NAME_LEN,
+    MAX_WRAPPED_TOKEN_SYMBOL_LEN, METAPLEX_TOKEN_METADATA_PROGRAM_ID, REMOTE_TOKEN_METADATA_KEY,
+    SCALER_EXPONENT_METADATA_KEY,
+};
 use crate::BridgeError;
 use crate::ID;
 
@@ -25,15 +39,27 @@ const REGISTER_REMOTE_TOKEN_DATA_LEN: usize = {
     32 + 32 + 32 // abi.encode(address, bytes32, uint8) = 96 bytes
 };
 
+/// Outcome of the wrap token instruction, returned to the caller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub enum WrapTokenResult {
+    /// The wrapped mint was created and its registration message sent to Base.
+    Wrapped { mint: Pubkey },
+    /// The wrapped mint already existed for this metadata; nothing was created or sent.
+    AlreadyWrapped { mint: Pubkey },
+}
+
 /// Accounts struct for the wrap token instruction that creates a wrapped representation
 /// of a Base token on Solana. This instruction initializes a new SPL token
 /// with Token-2022 extensions and registers it with Base for cross-chain
 /// token transfers. The wrapped token maintains metadata linking it to its Base counterpart.
+/// Wrapping an already wrapped token is a no-op returning `WrapTokenResult::AlreadyWrapped`.
 #[derive(Accounts)]
 #[instruction(outgoing_message_salt: [u8; 32], decimals: u8, metadata: PartialTokenMetadata)]
 pub struct WrapToken<'info> {
     /// The account that pays for the transaction and all account creation costs.
     /// Must be mutable to deduct lamports for mint creation, metadata storage, and gas fees.
+    /// Unlike the bridging instructions, there is no `from`: no asset is moved and the
+    /// registration message is sent by the bridge program itself, so any payer can wrap a token.
     #[account(mut)]
     pub payer: Signer<'info>,
 
@@ -42,12 +68,35 @@ pub struct WrapToken<'info> {
     #[account(mut, address = bridge.gas_config.gas_fee_receiver @ BridgeError::IncorrectGasFeeReceiver)]
     pub gas_fee_receiver: AccountInfo<'info>,
 
+    /// Fallback escrow receiving the gas fee when `gas_fee_receiver` cannot be credited.
+    /// CHECK: PDA with GAS_FEE_ESCROW_SEED, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub gas_fee_escrow: AccountInfo<'info>,
+
+    /// Insurance fund credited with its share of the gas fee. No share is taken while it is
+    /// uninitialized.
+    /// CHECK: PDA with INSURANCE_FUND_SEED, deserialized only if owned by this program.
+    #[account(mut, seeds = [INSURANCE_FUND_SEED], bump)]
+    pub insurance_fund: AccountInfo<'info>,
+
+    /// Payer rate limiting configuration. Payers are not rate limited while it is uninitialized.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_CONFIG_SEED, deserialized only if owned by this program.
+    #[account(seeds = [PAYER_RATE_LIMIT_CONFIG_SEED], bump)]
+    pub payer_rate_limit_config: AccountInfo<'info>,
+
+    /// Outgoing messages paid for by the payer in the current rate limiting window, created on its
+    /// first message while rate limiting is enabled.
+    /// CHECK: PDA with PAYER_RATE_LIMIT_SEED and the payer, validated in `pay_for_gas` when used.
+    #[account(mut)]
+    pub payer_rate_limit: AccountInfo<'info>,
+
     /// The new SPL Token-2022 mint being created for the wrapped token.
     /// - Uses PDA with token metadata hash and decimals for deterministic address
     /// - Mint authority set to itself (mint account) for controlled minting
     /// - Includes metadata pointer extension to store token information onchain
+    /// - Left untouched if it already exists
     #[account(
-        init,
+        init_if_needed,
         payer = payer,
         // NOTE: Suboptimal to compute the seeds here but it allows to use `init`.
         seeds = [
@@ -68,17 +117,17 @@ pub struct WrapToken<'info> {
     #[account(mut, seeds = [BRIDGE_SEED], bump)]
     pub bridge: Account<'info, Bridge>,
 
+    /// The address book providing the Base Bridge address targeted by the registration call.
+    #[account(seeds = [ADDRESS_BOOK_SEED], bump)]
+    pub address_book: Account<'info, AddressBook>,
+
     /// The outgoing message account that stores the cross-chain call to register
     /// the wrapped token on the Base blockchain. Contains the encoded function call
     /// with token address, local mint address, and scaling parameters.
-    #[account(
-        init,
-        payer = payer,
-        seeds = [OUTGOING_MESSAGE_SEED, outgoing_message_salt.as_ref()],
-        bump,
-        space = DISCRIMINATOR_LEN + OutgoingMessage::space::<Call>(REGISTER_REMOTE_TOKEN_DATA_LEN),
-    )]
-    pub outgoing_message: Account<'info, OutgoingMessage>,
+    /// CHECK: Only created by the handler when the mint is newly wrapped, so that wrapping an
+    /// already wrapped token does not send a duplicate registration.
+    #[account(mut, seeds = [OUTGOING_MESSAGE_SEED, outgoing_message_salt.as_ref()], bump)]
+    pub outgoing_message: UncheckedAccount<'info>,
 
     /// SPL Token-2022 program for creating the mint with metadata extensions.
     /// Required for initializing tokens with advanced features like metadata pointers.
@@ -87,28 +136,84 @@ pub struct WrapToken<'info> {
     /// System program required for creating new accounts and transferring lamports.
     /// Used internally by Anchor for account initialization and rent payments.
     pub system_program: Program<'info, System>,
+
+    /// Optional Metaplex Token Metadata account mirroring the Token-2022 metadata, for wallets
+    /// that do not read Token-2022 TLV metadata. Only created when provided.
+    /// CHECK: Validated to be the Metaplex metadata PDA of `mint`; created by the Metaplex program.
+    #[account(mut)]
+    pub metaplex_metadata: Option<UncheckedAccount<'info>>,
+
+    /// Metaplex Token Metadata program, required when `metaplex_metadata` is provided.
+    /// CHECK: Validated against the Metaplex Token Metadata program ID.
+    #[account(address = METAPLEX_TOKEN_METADATA_PROGRAM_ID)]
+    pub metaplex_program: Option<UncheckedAccount<'info>>,
 }
 
 pub fn wrap_token_handler(
     ctx: Context<WrapToken>,
-    _outgoing_message_salt: [u8; 32],
+    outgoing_message_salt: [u8; 32],
     decimals: u8,
     partial_token_metadata: PartialTokenMetadata,
-) -> Result<()> {
+) -> Result<WrapTokenResult> {
     // Check if bridge is paused
     require!(!ctx.accounts.bridge.paused, BridgeError::BridgePaused);
 
+    let mint = ctx.accounts.mint.key();
+    if has_token_metadata(&ctx.accounts.mint.to_account_info())? {
+        return Ok(WrapTokenResult::AlreadyWrapped { mint });
+    }
+
+    validate_token_metadata(&partial_token_metadata)?;
+
     initialize_metadata(&ctx, decimals, &partial_token_metadata)?;
 
+    initialize_metaplex_metadata(&ctx, decimals, &partial_token_metadata)?;
+
     register_remote_token(
         ctx,
+        &outgoing_message_salt,
         &partial_token_metadata.remote_token,
         partial_token_metadata.scaler_exponent,
     )?;
 
+    Ok(WrapTokenResult::Wrapped { mint })
+}
+
+/// Rejects names and symbols that could be used to impersonate other tokens: they must be
+/// non-empty, within the Metaplex length limits, trimmed and made of visible ASCII or
+/// `ALLOWED_TOKEN_METADATA_UNICODE_RANGES` characters.
+fn validate_token_metadata(metadata: &PartialTokenMetadata) -> Result<()> {
+    require!(
+        metadata.name.len() <= MAX_WRAPPED_TOKEN_NAME_LEN as usize,
+        BridgeError::TokenNameTooLong
+    );
+    require!(
+        metadata.symbol.len() <= MAX_WRAPPED_TOKEN_SYMBOL_LEN as usize,
+        BridgeError::TokenSymbolTooLong
+    );
+
+    for field in [&metadata.name, &metadata.symbol] {
+        require!(!field.is_empty(), BridgeError::EmptyTokenMetadata);
+        require!(
+            field.trim() == field.as_str(),
+            BridgeError::UntrimmedTokenMetadata
+        );
+        require!(
+            field.chars().all(is_allowed_token_metadata_char),
+            BridgeError::InvalidTokenMetadataCharacter
+        );
+    }
+
     Ok(())
 }
 
+fn is_allowed_token_metadata_char(c: char) -> bool {
+    matches!(c, ' '..='~')
+        || ALLOWED_TOKEN_METADATA_UNICODE_RANGES
+            .iter()
+            .any(|(start, end)| (*start..=*end).contains(&c))
+}
+
 fn initialize_metadata(
     ctx: &Context<WrapToken>,
     decimals: u8,
@@ -195,8 +300,53 @@ fn initialize_metadata(
     Ok(())
 }
 
+fn initialize_metaplex_metadata(
+    ctx: &Context<WrapToken>,
+    decimals: u8,
+    partial_token_metadata: &PartialTokenMetadata,
+) -> Result<()> {
+    let Some(metaplex_metadata) = &ctx.accounts.metaplex_metadata else {
+        return Ok(());
+    };
+    let metaplex_program = ctx
+        .accounts
+        .metaplex_program
+        .as_ref()
+        .ok_or(BridgeError::MissingMetaplexProgram)?;
+
+    require_keys_eq!(
+        metaplex_metadata.key(),
+        metaplex_metadata_address(&ctx.accounts.mint.key()),
+        BridgeError::IncorrectMetaplexMetadata
+    );
+
+    let decimals_bytes = decimals.to_le_bytes();
+    let metadata_hash = partial_token_metadata.hash();
+
+    let seeds: &[&[u8]] = &[
+        WRAPPED_TOKEN_SEED,
+        &decimals_bytes,
+        &metadata_hash,
+        &[ctx.bumps.mint],
+    ];
+
+    // Mirror the Token-2022 metadata (the URI is left empty, as it is there)
+    create_metaplex_metadata(
+        &metaplex_metadata.to_account_info(),
+        &ctx.accounts.mint.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &metaplex_program.to_account_info(),
+        seeds,
+        &partial_token_metadata.name,
+        &partial_token_metadata.symbol,
+        "",
+    )
+}
+
 fn register_remote_token(
     ctx: Context<WrapToken>,
+    outgoing_message_salt: &[u8; 32],
     remote_token: &[u8; 20],
     scaler_exponent: u8,
 ) -> Result<()> {
@@ -206,21 +356,62 @@ fn register_remote_token(
 
     let call = Call {
         ty: CallType::Call,
-        to: [0; 20],
+        to: ctx.accounts.address_book.bridge()?,
         value: 0,
         data: (address, local_token, scaler_exponent).abi_encode(),
     };
 
-    let message = OutgoingMessage::new_call(ctx.accounts.bridge.nonce, ID, call);
+    let message = OutgoingMessage::new_call(
+        ctx.accounts.bridge.nonce,
+        ID,
+        Clock::get()?.unix_timestamp,
+        ctx.accounts.bridge.remote_chain_id(),
+        call,
+    );
 
-    pay_for_gas(
+    let gas_fee = pay_for_gas(
         &ctx.accounts.system_program,
         &ctx.accounts.payer,
         &ctx.accounts.gas_fee_receiver,
+        Some(&ctx.accounts.gas_fee_escrow),
+        &ctx.accounts.insurance_fund,
+        &ctx.accounts.payer_rate_limit_config,
+        Some(&ctx.accounts.payer_rate_limit),
         &mut ctx.accounts.bridge,
+        GasLane::Call,
+        0,
     )?;
 
-    *ctx.accounts.outgoing_message = message;
+    let space = DISCRIMINATOR_LEN
+        + OutgoingMessage::space(MessageKind::Call {
+            data_len: REGISTER_REMOTE_TOKEN_DATA_LEN,
+        });
+    let outgoing_message = ctx.accounts.outgoing_message.to_account_info();
+    create_account(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            CreateAccount {
+                from: ctx.accounts.payer.to_account_info(),
+                to: outgoing_message.clone(),
+            },
+            &[&[
+                OUTGOING_MESSAGE_SEED,
+                outgoing_message_salt,
+                &[ctx.bumps.outgoing_message],
+            ]],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &ID,
+    )?;
+    commit_outgoing_message(
+        &mut ctx.accounts.bridge,
+        &outgoing_message.key(),
+        &message,
+        gas_fee,
+        0,
+    )?;
+    message.try_serialize(&mut &mut outgoing_message.try_borrow_mut_data()?[..])?;
     ctx.accounts.bridge.nonce += 1;
 
     Ok(())
@@ -234,3 +425,185 @@ const fn add_type_and_length_to_len(value_len: usize) -> usize {
         .saturating_add(std::mem::size_of::<ExtensionType>())
         .saturating_add(pod_get_packed_len::<Length>())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use anchor_lang::{solana_program::instruction::Instruction, InstructionData};
+    use solana_account::Account as SvmAccount;
+    use solana_message::Message;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::{
+        accounts,
+        instruction::WrapToken as WrapTokenIx,
+        test_utils::{
+            address_book_pda, create_mock_wrapped_mint, create_outgoing_message,
+            gas_fee_escrow_pda, insurance_fund_pda, payer_rate_limit_config_pda,
+            payer_rate_limit_pda, setup_bridge, SetupBridgeResult, TEST_GAS_FEE_RECEIVER,
+        },
+    };
+
+    fn metadata(name: &str, symbol: &str) -> PartialTokenMetadata {
+        PartialTokenMetadata {
+            name: name.to_string(),
+            symbol: symbol.to_string(),
+            remote_token: [1u8; 20],
+            scaler_exponent: 9,
+        }
+    }
+
+    fn assert_rejected(metadata: PartialTokenMetadata, expected: BridgeError) {
+        assert_eq!(
+            validate_token_metadata(&metadata).unwrap_err(),
+            expected.into()
+        );
+    }
+
+    #[test]
+    fn test_validate_token_metadata_accepts_allowed_characters() {
+        validate_token_metadata(&metadata("Wrapped Ether", "WETH")).unwrap();
+        validate_token_metadata(&metadata("Société Générale Ŝ", "SGÉ")).unwrap();
+    }
+
+    #[test]
+    fn test_validate_token_metadata_rejects_lengths() {
+        assert_rejected(metadata("", "WETH"), BridgeError::EmptyTokenMetadata);
+        assert_rejected(
+            metadata("Wrapped Ether", ""),
+            BridgeError::EmptyTokenMetadata,
+        );
+        assert_rejected(
+            metadata(&"a".repeat(MAX_WRAPPED_TOKEN_NAME_LEN as usize + 1), "WETH"),
+            BridgeError::TokenNameTooLong,
+        );
+        assert_rejected(
+            metadata(
+                "Wrapped Ether",
+                &"A".repeat(MAX_WRAPPED_TOKEN_SYMBOL_LEN as usize + 1),
+            ),
+            BridgeError::TokenSymbolTooLong,
+        );
+    }
+
+    #[test]
+    fn test_validate_token_metadata_rejects_untrimmed() {
+        assert_rejected(
+            metadata(" Wrapped Ether", "WETH"),
+            BridgeError::UntrimmedTokenMetadata,
+        );
+        assert_rejected(
+            metadata("Wrapped Ether", "WETH\n"),
+            BridgeError::UntrimmedTokenMetadata,
+        );
+    }
+
+    #[test]
+    fn test_validate_token_metadata_rejects_disallowed_characters() {
+        // Right-to-left override used to spoof the displayed name
+        assert_rejected(
+            metadata("Wrapped \u{202E}rehtE", "WETH"),
+            BridgeError::InvalidTokenMetadataCharacter,
+        );
+        assert_rejected(
+            metadata("Wrapped Ether", "WETH\u{1F680}"),
+            BridgeError::InvalidTokenMetadataCharacter,
+        );
+        assert_rejected(
+            metadata("Wrapped\tEther", "WETH"),
+            BridgeError::InvalidTokenMetadataCharacter,
+        );
+    }
+
+    #[test]
+    fn test_wrap_token_already_wrapped_returns_existing_mint() {
+        let SetupBridgeResult {
+            mut svm,
+            payer,
+            bridge_pda,
+            ..
+        } = setup_bridge();
+
+        let decimals = 9;
+        let partial_token_metadata = metadata("Wrapped Ether", "WETH");
+        let mint = create_mock_wrapped_mint(&mut svm, 0, decimals, &partial_token_metadata);
+
+        let mut address_book_data = Vec::new();
+        AddressBook {
+            bridge: [2u8; 20],
+            relayer_orchestrator: [0u8; 20],
+            token_count: 0,
+            tokens: Default::default(),
+        }
+        .try_serialize(&mut address_book_data)
+        .unwrap();
+        svm.set_account(
+            address_book_pda(),
+            SvmAccount {
+                lamports: svm.minimum_balance_for_rent_exemption(address_book_data.len()),
+                data: address_book_data,
+                owner: ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+        let nonce_before = {
+            let account = svm.get_account(&bridge_pda).unwrap();
+            Bridge::try_deserialize(&mut &account.data[..])
+                .unwrap()
+                .nonce
+        };
+
+        let (outgoing_message_salt, outgoing_message) = create_outgoing_message();
+        let ix = Instruction {
+            program_id: ID,
+            accounts: accounts::WrapToken {
+                payer: payer.pubkey(),
+                gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
+                gas_fee_escrow: gas_fee_escrow_pda(),
+                insurance_fund: insurance_fund_pda(),
+                payer_rate_limit_config: payer_rate_limit_config_pda(),
+                payer_rate_limit: payer_rate_limit_pda(&payer.pubkey()),
+                mint,
+                bridge: bridge_pda,
+                address_book: address_book_pda(),
+                outgoing_message,
+                token_program: anchor_spl::token_2022::ID,
+                system_program: anchor_lang::system_program::ID,
+                metaplex_metadata: None,
+                metaplex_program: None,
+            }
+            .to_account_metas(None),
+            data: WrapTokenIx {
+                outgoing_message_salt,
+                decimals,
+                partial_token_metadata,
+            }
+            .data(),
+        };
+
+        let tx = Transaction::new(
+            &[&payer],
+            Message::new(&[ix], Some(&payer.pubkey())),
+            svm.latest_blockhash(),
+        );
+        let meta = svm
+            .send_transaction(tx)
+            .expect("Failed to wrap an already wrapped token");
+
+        let result = WrapTokenResult::try_from_slice(&meta.return_data.data).unwrap();
+        assert_eq!(result, WrapTokenResult::AlreadyWrapped { mint });
+
+        // No registration message was sent to Base
+        assert!(svm
+            .get_account(&outgoing_message)
+            .is_none_or(|account| account.lamports == 0));
+        let account = svm.get_account(&bridge_pda).unwrap();
+        let bridge = Bridge::try_deserialize(&mut &account.data[..]).unwrap();
+        assert_eq!(bridge.nonce, nonce_before);
+    }
+}
@@ -1,28 +1,76 @@
 use anchor_lang::prelude::*;
 
 use crate::{
-    common::bridge::Bridge,
-    solana_to_base::{check_call, pay_for_gas, Call, OutgoingMessage},
+    common::bridge::{Bridge, GasLane},
+    solana_to_base::{
+        check_call, check_encrypted_call, commit_outgoing_message, pay_for_gas, Call,
+        OutgoingMessage, OutgoingMessageReceipt, ENCRYPTED_CALL_GAS_PER_BYTE,
+    },
 };
 
+/// Persists `call` as an outgoing message and charges its gas. Encrypted calls carry ciphertext
+/// in `call.data` and are additionally charged `ENCRYPTED_CALL_GAS_PER_BYTE` per ciphertext byte.
 #[allow(clippy::too_many_arguments)]
 pub fn bridge_call_internal<'info>(
     payer: &Signer<'info>,
     from: &Signer<'info>,
     gas_fee_receiver: &AccountInfo<'info>,
+    gas_fee_escrow: &AccountInfo<'info>,
+    insurance_fund: &AccountInfo<'info>,
+    payer_rate_limit_config: &AccountInfo<'info>,
+    payer_rate_limit: &AccountInfo<'info>,
     bridge: &mut Account<'info, Bridge>,
     outgoing_message: &mut Account<'info, OutgoingMessage>,
     system_program: &Program<'info, System>,
     call: Call,
-) -> Result<()> {
-    check_call(&call)?;
+    encrypted: bool,
+) -> Result<OutgoingMessageReceipt> {
+    let nonce = bridge.nonce;
+    let created_at = Clock::get()?.unix_timestamp;
+    let remote_chain_id = bridge.remote_chain_id();
 
-    let message = OutgoingMessage::new_call(bridge.nonce, from.key(), call);
+    let (message, payload_gas) = if encrypted {
+        check_encrypted_call(&call)?;
+        let payload_gas = ENCRYPTED_CALL_GAS_PER_BYTE * call.data.len() as u64;
+        let message = OutgoingMessage::new_encrypted_call(
+            nonce,
+            from.key(),
+            created_at,
+            remote_chain_id,
+            call,
+        );
+        (message, payload_gas)
+    } else {
+        check_call(&call)?;
+        let message =
+            OutgoingMessage::new_call(nonce, from.key(), created_at, remote_chain_id, call);
+        (message, 0)
+    };
 
-    pay_for_gas(system_program, payer, gas_fee_receiver, bridge)?;
+    let gas_fee = pay_for_gas(
+        system_program,
+        payer,
+        gas_fee_receiver,
+        Some(gas_fee_escrow),
+        insurance_fund,
+        payer_rate_limit_config,
+        Some(payer_rate_limit),
+        bridge,
+        GasLane::Call,
+        payload_gas,
+    )?;
 
+    log_event!(
+        info,
+        op = "bridge_call",
+        nonce = nonce,
+        sender = from.key(),
+        encrypted = encrypted,
+    );
+
+    let receipt = commit_outgoing_message(bridge, &outgoing_message.key(), &message, gas_fee, 0)?;
     **outgoing_message = message;
     bridge.nonce += 1;
 
-    Ok(())
+    Ok(receipt)
 }
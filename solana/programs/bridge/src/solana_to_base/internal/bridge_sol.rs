@@ -4,10 +4,16 @@ use anchor_lang::{
 };
 
 use crate::{
-    common::bridge::Bridge,
+    common::{
+        bridge::{Bridge, GasLane},
+        TokenDeposits,
+    },
     solana_to_base::{
-        check_call, pay_for_gas, Call, OutgoingMessage, Transfer as TransferOp, NATIVE_SOL_PUBKEY,
+        check_call, check_memo, check_swap_guard, commit_outgoing_message, pay_for_gas,
+        protocol_fee, Call, OutgoingMessage, OutgoingMessageReceipt, SwapGuard,
+        Transfer as TransferOp, NATIVE_SOL_PUBKEY,
     },
+    BridgeError, ProtocolFeeCharged, TransferMemo,
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -15,31 +21,105 @@ pub fn bridge_sol_internal<'info>(
     payer: &Signer<'info>,
     from: &Signer<'info>,
     gas_fee_receiver: &AccountInfo<'info>,
+    gas_fee_escrow: &AccountInfo<'info>,
+    insurance_fund: &AccountInfo<'info>,
+    payer_rate_limit_config: &AccountInfo<'info>,
+    payer_rate_limit: &AccountInfo<'info>,
+    protocol_fee_config: &AccountInfo<'info>,
+    protocol_fee_treasury: &AccountInfo<'info>,
+    dex_router_list: &AccountInfo<'info>,
     sol_vault: &AccountInfo<'info>,
+    token_deposits: &mut Account<'info, TokenDeposits>,
     bridge: &mut Account<'info, Bridge>,
     outgoing_message: &mut Account<'info, OutgoingMessage>,
     system_program: &Program<'info, System>,
     to: [u8; 20],
     amount: u64,
     call: Option<Call>,
-) -> Result<()> {
+    memo: Option<Vec<u8>>,
+    swap_guard: Option<SwapGuard>,
+) -> Result<OutgoingMessageReceipt> {
     if let Some(call) = &call {
         check_call(call)?;
     }
+    if let Some(memo) = &memo {
+        check_memo(memo)?;
+    }
+    check_swap_guard(call.as_ref(), swap_guard.as_ref(), Some(dex_router_list))?;
+
+    // Skim the protocol fee, if any, so that only the net amount is bridged.
+    let protocol_fee = protocol_fee(protocol_fee_config, &NATIVE_SOL_PUBKEY, amount)?;
+    let fee = protocol_fee.as_ref().map_or(0, |p| p.fee);
+    let net_amount = amount
+        .checked_sub(fee)
+        .ok_or(BridgeError::ProtocolFeeExceedsAmount)?;
+
+    if let Some(memo) = &memo {
+        emit!(TransferMemo {
+            outgoing_message: outgoing_message.key(),
+            nonce: bridge.nonce,
+            from: from.key(),
+            local_token: NATIVE_SOL_PUBKEY,
+            amount: net_amount,
+            memo: memo.clone(),
+        });
+    }
 
     let message = OutgoingMessage::new_transfer(
         bridge.nonce,
         from.key(),
+        Clock::get()?.unix_timestamp,
+        bridge.remote_chain_id(),
         TransferOp {
             to,
             local_token: NATIVE_SOL_PUBKEY,
             remote_token: bridge.protocol_config.remote_sol_address,
-            amount,
+            amount: net_amount,
             call,
+            memo,
+            swap_guard,
         },
     );
 
-    pay_for_gas(system_program, payer, gas_fee_receiver, bridge)?;
+    let gas_fee = pay_for_gas(
+        system_program,
+        payer,
+        gas_fee_receiver,
+        Some(gas_fee_escrow),
+        insurance_fund,
+        payer_rate_limit_config,
+        Some(payer_rate_limit),
+        bridge,
+        GasLane::Transfer,
+        0,
+    )?;
+
+    if let Some(protocol_fee) = protocol_fee {
+        require_keys_eq!(
+            protocol_fee_treasury.key(),
+            protocol_fee.treasury,
+            BridgeError::IncorrectProtocolFeeTreasury
+        );
+
+        let cpi_ctx = CpiContext::new(
+            system_program.to_account_info(),
+            Transfer {
+                from: from.to_account_info(),
+                to: protocol_fee_treasury.to_account_info(),
+            },
+        );
+        system_program::transfer(cpi_ctx, protocol_fee.fee)?;
+
+        emit!(ProtocolFeeCharged {
+            from: from.key(),
+            local_token: NATIVE_SOL_PUBKEY,
+            treasury: protocol_fee_treasury.key(),
+            amount,
+            fee,
+            net_amount,
+            bps: protocol_fee.bps,
+        });
+    }
 
     // Lock the sol from the user into the SOL vault.
     let cpi_ctx = CpiContext::new(
@@ -49,10 +129,22 @@ pub fn bridge_sol_internal<'info>(
             to: sol_vault.to_account_info(),
         },
     );
-    system_program::transfer(cpi_ctx, amount)?;
+    system_program::transfer(cpi_ctx, net_amount)?;
+
+    token_deposits.vault = sol_vault.key();
+    token_deposits.locked_amount += net_amount;
+
+    log_event!(
+        info,
+        op = "bridge_sol",
+        nonce = bridge.nonce,
+        sender = from.key(),
+        amount = net_amount,
+    );
 
+    let receipt = commit_outgoing_message(bridge, &outgoing_message.key(), &message, gas_fee, fee)?;
     **outgoing_message = message;
     bridge.nonce += 1;
 
-    Ok(())
+    Ok(receipt)
 }
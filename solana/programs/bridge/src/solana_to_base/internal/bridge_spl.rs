@@ -2,11 +2,18 @@ use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{transfer_checked, TransferChecked};
 use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface};
 
-use crate::common::PartialTokenMetadata;
+use crate::common::{internal::scaling::to_remote_spl_amount, PartialTokenMetadata};
 use crate::{
-    common::bridge::Bridge,
-    solana_to_base::{check_call, pay_for_gas, Call, OutgoingMessage, Transfer as TransferOp},
-    BridgeError,
+    common::{
+        bridge::{Bridge, GasLane},
+        MintAllowList, MintPolicy, TokenDeposits, VaultDecimals,
+    },
+    solana_to_base::{
+        check_call, check_memo, check_protocol_fee_token_account, check_swap_guard,
+        commit_outgoing_message, pay_for_gas, protocol_fee, BridgeSession, Call, OutgoingMessage,
+        OutgoingMessageReceipt, SwapGuard, Transfer as TransferOp,
+    },
+    BridgeError, ProtocolFeeCharged, TransferMemo,
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -14,10 +21,21 @@ pub fn bridge_spl_internal<'info>(
     payer: &Signer<'info>,
     from: &Signer<'info>,
     gas_fee_receiver: &AccountInfo<'info>,
+    gas_fee_escrow: Option<&AccountInfo<'info>>,
+    insurance_fund: &AccountInfo<'info>,
+    payer_rate_limit_config: &AccountInfo<'info>,
+    payer_rate_limit: Option<&AccountInfo<'info>>,
+    protocol_fee_config: &AccountInfo<'info>,
+    protocol_fee_treasury: Option<&AccountInfo<'info>>,
+    dex_router_list: Option<&AccountInfo<'info>>,
+    mint_policy: &AccountInfo<'info>,
+    mint_allow_list: Option<&AccountInfo<'info>>,
     mint: &InterfaceAccount<'info, Mint>,
     from_token_account: &InterfaceAccount<'info, TokenAccount>,
     bridge: &mut Account<'info, Bridge>,
     token_vault: &mut InterfaceAccount<'info, TokenAccount>,
+    token_deposits: &mut Account<'info, TokenDeposits>,
+    vault_decimals: &AccountInfo<'info>,
     outgoing_message: &mut Account<'info, OutgoingMessage>,
     token_program: &Interface<'info, TokenInterface>,
     system_program: &Program<'info, System>,
@@ -25,10 +43,17 @@ pub fn bridge_spl_internal<'info>(
     remote_token: [u8; 20],
     amount: u64,
     call: Option<Call>,
-) -> Result<()> {
+    memo: Option<Vec<u8>>,
+    swap_guard: Option<SwapGuard>,
+    mut bridge_session: Option<&mut Account<'info, BridgeSession>>,
+) -> Result<OutgoingMessageReceipt> {
     if let Some(call) = &call {
         check_call(call)?;
     }
+    if let Some(memo) = &memo {
+        check_memo(memo)?;
+    }
+    check_swap_guard(call.as_ref(), swap_guard.as_ref(), dex_router_list)?;
 
     // Check that the provided mint is not a wrapped token.
     // Wrapped tokens should be handled by the wrapped_token_transfer_operation branch which burns the token from the user.
@@ -36,21 +61,85 @@ pub fn bridge_spl_internal<'info>(
         PartialTokenMetadata::try_from(&mint.to_account_info()).is_err(),
         BridgeError::MintIsWrappedToken
     );
+    check_mint_policy(mint_policy, mint, &token_program.key())?;
+    check_mint_allow_list(bridge, mint_allow_list, &mint.key())?;
+
+    // Through a session, the session PDA moves the owner's tokens as their SPL delegate.
+    let sender = match bridge_session.as_deref_mut() {
+        Some(session) => {
+            session.debit(
+                &from.key(),
+                &from_token_account.owner,
+                &remote_token,
+                amount,
+                Clock::get()?.unix_timestamp,
+            )?;
+            session.owner
+        }
+        None => from.key(),
+    };
+    let authority = bridge_session.as_ref().map_or_else(
+        || from.to_account_info(),
+        |session| session.to_account_info(),
+    );
+    let session_seeds = bridge_session
+        .as_ref()
+        .map(|session| session.signer_seeds());
+    let signer_seeds: Vec<&[&[u8]]> = session_seeds.iter().map(|seeds| &seeds[..]).collect();
+
+    // Skim the protocol fee, if any, into the treasury token account so that only the net amount
+    // is bridged.
+    let protocol_fee = protocol_fee(protocol_fee_config, &mint.key(), amount)?;
+    let fee = protocol_fee.as_ref().map_or(0, |p| p.fee);
+    let net_amount = amount
+        .checked_sub(fee)
+        .ok_or(BridgeError::ProtocolFeeExceedsAmount)?;
+
+    // The treasury token account is only required when a protocol fee is charged.
+    let protocol_fee = protocol_fee
+        .map(|protocol_fee| {
+            protocol_fee_treasury
+                .map(|protocol_fee_treasury| (protocol_fee, protocol_fee_treasury))
+                .ok_or(BridgeError::IncorrectProtocolFeeTreasury)
+        })
+        .transpose()?;
+
+    if let Some((protocol_fee, protocol_fee_treasury)) = &protocol_fee {
+        check_protocol_fee_token_account(
+            protocol_fee_treasury,
+            &protocol_fee.treasury,
+            &mint.key(),
+            &token_program.key(),
+        )?;
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                mint: mint.to_account_info(),
+                from: from_token_account.to_account_info(),
+                to: protocol_fee_treasury.to_account_info(),
+                authority: authority.clone(),
+            },
+            &signer_seeds,
+        );
+        transfer_checked(cpi_ctx, protocol_fee.fee, mint.decimals)?;
+    }
 
     // Get the token vault balance before the transfer.
     let token_vault_balance = token_vault.amount;
 
     // Lock the token from the user into the token vault.
-    let cpi_ctx = CpiContext::new(
+    let cpi_ctx = CpiContext::new_with_signer(
         token_program.to_account_info(),
         TransferChecked {
             mint: mint.to_account_info(),
             from: from_token_account.to_account_info(),
             to: token_vault.to_account_info(),
-            authority: from.to_account_info(),
+            authority,
         },
+        &signer_seeds,
     );
-    transfer_checked(cpi_ctx, amount, mint.decimals)?;
+    transfer_checked(cpi_ctx, net_amount, mint.decimals)?;
 
     // Get the token vault balance after the transfer.
     token_vault.reload()?;
@@ -59,22 +148,124 @@ pub fn bridge_spl_internal<'info>(
     // Compute the real received amount in case the token has transfer fees.
     let received_amount = token_vault_balance_after - token_vault_balance;
 
+    if let Some((protocol_fee, protocol_fee_treasury)) = protocol_fee {
+        emit!(ProtocolFeeCharged {
+            from: sender,
+            local_token: mint.key(),
+            treasury: protocol_fee_treasury.key(),
+            amount,
+            fee,
+            net_amount: received_amount,
+            bps: protocol_fee.bps,
+        });
+    }
+
+    // Carry the amount in the smallest unit of the remote token. The received amount must not
+    // have digits below it, as they would stay locked without being credited on Base.
+    let scaler_exponent = VaultDecimals::load_scaler_exponent(vault_decimals, &token_vault.key())?;
+    let remote_amount = to_remote_spl_amount(received_amount, scaler_exponent)?;
+
+    token_deposits.vault = token_vault.key();
+    token_deposits.locked_amount += received_amount;
+
+    if let Some(memo) = &memo {
+        emit!(TransferMemo {
+            outgoing_message: outgoing_message.key(),
+            nonce: bridge.nonce,
+            from: sender,
+            local_token: mint.key(),
+            amount: received_amount,
+            memo: memo.clone(),
+        });
+    }
+
     let message = OutgoingMessage::new_transfer(
         bridge.nonce,
-        from.key(),
+        sender,
+        Clock::get()?.unix_timestamp,
+        bridge.remote_chain_id(),
         TransferOp {
             to,
             local_token: mint.key(),
             remote_token,
-            amount: received_amount,
+            amount: remote_amount,
             call,
+            memo,
+            swap_guard,
         },
     );
 
-    pay_for_gas(system_program, payer, gas_fee_receiver, bridge)?;
+    let gas_fee = pay_for_gas(
+        system_program,
+        payer,
+        gas_fee_receiver,
+        gas_fee_escrow,
+        insurance_fund,
+        payer_rate_limit_config,
+        payer_rate_limit,
+        bridge,
+        GasLane::Transfer,
+        0,
+    )?;
+
+    log_event!(
+        info,
+        op = "bridge_spl",
+        nonce = bridge.nonce,
+        sender = sender,
+        mint = mint.key(),
+        amount = received_amount,
+    );
 
+    let receipt = commit_outgoing_message(bridge, &outgoing_message.key(), &message, gas_fee, fee)?;
     **outgoing_message = message;
     bridge.nonce += 1;
 
+    Ok(receipt)
+}
+
+/// Checks that `mint` is owned by `token_program` (one of the two canonical token programs, as
+/// enforced by `TokenInterface`) and that its freeze authority is accepted by the mint policy,
+/// if initialized.
+fn check_mint_policy(
+    mint_policy: &AccountInfo,
+    mint: &InterfaceAccount<Mint>,
+    token_program: &Pubkey,
+) -> Result<()> {
+    require_keys_eq!(
+        *mint.to_account_info().owner,
+        *token_program,
+        BridgeError::MintNotOwnedByTokenProgram
+    );
+
+    if let Some(mint_policy) = MintPolicy::load(mint_policy)? {
+        let freeze_authority: Option<Pubkey> = mint.freeze_authority.into();
+        require!(
+            mint_policy.allows_freeze_authority(freeze_authority.as_ref()),
+            BridgeError::FreezeAuthorityNotAllowed
+        );
+    }
+
+    Ok(())
+}
+
+/// Checks that `mint` is allow-listed while the bridge is in guarded launch. Every mint is rejected
+/// if guarded launch is on before the allow-list is initialized or without the allow-list.
+fn check_mint_allow_list(
+    bridge: &Bridge,
+    mint_allow_list: Option<&AccountInfo>,
+    mint: &Pubkey,
+) -> Result<()> {
+    if !bridge.guarded_launch {
+        return Ok(());
+    }
+
+    let allowed = match mint_allow_list {
+        Some(mint_allow_list) => MintAllowList::load(mint_allow_list)?
+            .is_some_and(|mint_allow_list| mint_allow_list.allows(mint)),
+        None => false,
+    };
+    require!(allowed, BridgeError::MintNotAllowListed);
+
     Ok(())
 }
@@ -1,13 +1,24 @@
 use anchor_lang::prelude::*;
 use anchor_spl::{
     token_2022::Token2022,
-    token_interface::{self, BurnChecked, Mint, TokenAccount},
+    token_interface::{self, Mint, TokenAccount, TransferChecked},
 };
 
-use crate::solana_to_base::{check_call, pay_for_gas};
+use crate::solana_to_base::{
+    check_call, check_memo, check_protocol_fee_token_account, check_swap_guard,
+    commit_outgoing_message, pay_for_gas, protocol_fee, OutgoingMessageReceipt,
+};
 use crate::{
-    common::{bridge::Bridge, PartialTokenMetadata},
-    solana_to_base::{Call, OutgoingMessage, Transfer as TransferOp},
+    common::{
+        bridge::{Bridge, GasLane},
+        burn_wrapped_tokens,
+        internal::scaling::to_remote_amount,
+        PartialTokenMetadata, WrappedTokenConfirmation,
+    },
+    solana_to_base::{
+        BridgeSession, BurnReceipt, Call, OutgoingMessage, SwapGuard, Transfer as TransferOp,
+    },
+    BridgeError, ProtocolFeeCharged, TransferMemo,
 };
 
 #[allow(clippy::too_many_arguments)]
@@ -15,50 +26,196 @@ pub fn bridge_wrapped_token_internal<'info>(
     payer: &Signer<'info>,
     from: &Signer<'info>,
     gas_fee_receiver: &AccountInfo<'info>,
+    gas_fee_escrow: &AccountInfo<'info>,
+    insurance_fund: &AccountInfo<'info>,
+    payer_rate_limit_config: &AccountInfo<'info>,
+    payer_rate_limit: &AccountInfo<'info>,
+    protocol_fee_config: &AccountInfo<'info>,
+    protocol_fee_treasury: &AccountInfo<'info>,
+    dex_router_list: &AccountInfo<'info>,
     mint: &InterfaceAccount<'info, Mint>,
+    wrapped_token_confirmation: &AccountInfo<'info>,
     from_token_account: &InterfaceAccount<'info, TokenAccount>,
     bridge: &mut Account<'info, Bridge>,
     outgoing_message: &mut Account<'info, OutgoingMessage>,
+    burn_receipt: &mut Account<'info, BurnReceipt>,
     token_program: &Program<'info, Token2022>,
     system_program: &Program<'info, System>,
     to: [u8; 20],
     amount: u64,
     call: Option<Call>,
-) -> Result<()> {
+    memo: Option<Vec<u8>>,
+    swap_guard: Option<SwapGuard>,
+    mut bridge_session: Option<&mut Account<'info, BridgeSession>>,
+) -> Result<OutgoingMessageReceipt> {
     if let Some(call) = &call {
         check_call(call)?;
     }
+    if let Some(memo) = &memo {
+        check_memo(memo)?;
+    }
+    check_swap_guard(call.as_ref(), swap_guard.as_ref(), Some(dex_router_list))?;
+
+    check_wrapped_token_confirmation(bridge, wrapped_token_confirmation, &mint.key())?;
 
     // Get the token metadata from the mint.
     let partial_token_metadata = PartialTokenMetadata::try_from(&mint.to_account_info())?;
 
+    // Through a session, the session PDA burns the owner's tokens as their SPL delegate.
+    let sender = match bridge_session.as_deref_mut() {
+        Some(session) => {
+            session.debit(
+                &from.key(),
+                &from_token_account.owner,
+                &partial_token_metadata.remote_token,
+                amount,
+                Clock::get()?.unix_timestamp,
+            )?;
+            session.owner
+        }
+        None => from.key(),
+    };
+    let authority = bridge_session.as_ref().map_or_else(
+        || from.to_account_info(),
+        |session| session.to_account_info(),
+    );
+    let session_seeds = bridge_session
+        .as_ref()
+        .map(|session| session.signer_seeds());
+    let signer_seeds: Vec<&[&[u8]]> = session_seeds.iter().map(|seeds| &seeds[..]).collect();
+
+    // Skim the protocol fee, if any, so that only the net amount is bridged.
+    let protocol_fee = protocol_fee(protocol_fee_config, &mint.key(), amount)?;
+    let fee = protocol_fee.as_ref().map_or(0, |p| p.fee);
+    let net_amount = amount
+        .checked_sub(fee)
+        .ok_or(BridgeError::ProtocolFeeExceedsAmount)?;
+
+    // Reject amounts that Base cannot represent once scaled to the remote token precision.
+    to_remote_amount(net_amount, partial_token_metadata.scaler_exponent)?;
+
+    if let Some(memo) = &memo {
+        emit!(TransferMemo {
+            outgoing_message: outgoing_message.key(),
+            nonce: bridge.nonce,
+            from: sender,
+            local_token: mint.key(),
+            amount: net_amount,
+            memo: memo.clone(),
+        });
+    }
+
     let message = OutgoingMessage::new_transfer(
         bridge.nonce,
-        from.key(),
+        sender,
+        Clock::get()?.unix_timestamp,
+        bridge.remote_chain_id(),
         TransferOp {
             to,
             local_token: mint.key(),
             remote_token: partial_token_metadata.remote_token,
-            amount,
+            amount: net_amount,
             call,
+            memo,
+            swap_guard,
         },
     );
 
-    pay_for_gas(system_program, payer, gas_fee_receiver, bridge)?;
+    let gas_fee = pay_for_gas(
+        system_program,
+        payer,
+        gas_fee_receiver,
+        Some(gas_fee_escrow),
+        insurance_fund,
+        payer_rate_limit_config,
+        Some(payer_rate_limit),
+        bridge,
+        GasLane::Transfer,
+        0,
+    )?;
 
-    // Burn the token from the user.
-    let cpi_ctx = CpiContext::new(
-        token_program.to_account_info(),
-        BurnChecked {
-            mint: mint.to_account_info(),
-            from: from_token_account.to_account_info(),
-            authority: from.to_account_info(),
-        },
+    // Transfer the protocol fee to the treasury token account.
+    if let Some(protocol_fee) = protocol_fee {
+        check_protocol_fee_token_account(
+            protocol_fee_treasury,
+            &protocol_fee.treasury,
+            &mint.key(),
+            &token_program.key(),
+        )?;
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            TransferChecked {
+                mint: mint.to_account_info(),
+                from: from_token_account.to_account_info(),
+                to: protocol_fee_treasury.to_account_info(),
+                authority: authority.clone(),
+            },
+            &signer_seeds,
+        );
+        token_interface::transfer_checked(cpi_ctx, protocol_fee.fee, mint.decimals)?;
+
+        emit!(ProtocolFeeCharged {
+            from: sender,
+            local_token: mint.key(),
+            treasury: protocol_fee_treasury.key(),
+            amount,
+            fee,
+            net_amount,
+            bps: protocol_fee.bps,
+        });
+    }
+
+    // Burn the net amount from the user.
+    burn_wrapped_tokens(
+        mint,
+        from_token_account,
+        token_program,
+        authority,
+        &signer_seeds,
+        net_amount,
+    )?;
+
+    **burn_receipt = BurnReceipt {
+        burner: sender,
+        mint: mint.key(),
+        amount: net_amount,
+        to,
+        nonce: bridge.nonce,
+        timestamp: Clock::get()?.unix_timestamp,
+    };
+
+    log_event!(
+        info,
+        op = "bridge_wrapped_token",
+        nonce = bridge.nonce,
+        sender = sender,
+        mint = mint.key(),
+        amount = net_amount,
     );
-    token_interface::burn_checked(cpi_ctx, amount, mint.decimals)?;
 
+    let receipt = commit_outgoing_message(bridge, &outgoing_message.key(), &message, gas_fee, fee)?;
     **outgoing_message = message;
     bridge.nonce += 1;
 
+    Ok(receipt)
+}
+
+/// Checks that Base confirmed the registration of `mint` while the bridge requires wrapped tokens
+/// to be confirmed, so that tokens are not burned for a token Base cannot release.
+fn check_wrapped_token_confirmation(
+    bridge: &Bridge,
+    wrapped_token_confirmation: &AccountInfo,
+    mint: &Pubkey,
+) -> Result<()> {
+    if !bridge.require_wrapped_token_confirmation {
+        return Ok(());
+    }
+
+    require!(
+        WrappedTokenConfirmation::is_confirmed(wrapped_token_confirmation, mint)?,
+        BridgeError::WrappedTokenNotConfirmed
+    );
+
     Ok(())
 }
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    solana_to_base::{Call, CallBuffer},
+    CallBufferUsed,
+};
+
+/// Reads the call of a buffered bridge instruction out of `call_buffer`, counting the use.
+pub fn use_call_buffer(call_buffer: &mut Account<CallBuffer>) -> Result<Call> {
+    let call = call_buffer.use_call(Clock::get()?.unix_timestamp);
+
+    emit!(CallBufferUsed {
+        call_buffer: call_buffer.key(),
+        owner: call_buffer.owner,
+        use_count: call_buffer.use_count,
+        total_len: call.data.len() as u64,
+        reusable: call_buffer.reusable,
+    });
+
+    Ok(call)
+}
+
+/// Closes `call_buffer` once its call was bridged, refunding its rent to `owner`. Reusable
+/// buffers are kept for the next use.
+pub fn release_call_buffer<'info>(
+    call_buffer: &Account<'info, CallBuffer>,
+    owner: &Signer<'info>,
+) -> Result<()> {
+    if call_buffer.reusable {
+        return Ok(());
+    }
+
+    call_buffer.close(owner.to_account_info())
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::invoke_signed,
+};
+
+use crate::solana_to_base::{METAPLEX_METADATA_SEED, METAPLEX_TOKEN_METADATA_PROGRAM_ID};
+
+/// Instruction discriminator of Metaplex Token Metadata `CreateMetadataAccountV3`.
+const CREATE_METADATA_ACCOUNT_V3_DISCRIMINATOR: u8 = 33;
+
+/// Returns the Metaplex Token Metadata PDA of `mint`.
+pub fn metaplex_metadata_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            METAPLEX_METADATA_SEED,
+            METAPLEX_TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            mint.as_ref(),
+        ],
+        &METAPLEX_TOKEN_METADATA_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Encodes the `CreateMetadataAccountV3` instruction data.
+///
+/// The Metaplex crate is not a dependency, so the borsh layout is written out by hand:
+/// `DataV2 { name, symbol, uri, seller_fee_basis_points, creators, collection, uses }`
+/// followed by `is_mutable` and `collection_details`. Creators, collection, uses and
+/// collection details are left unset.
+pub fn create_metadata_account_v3_data(name: &str, symbol: &str, uri: &str) -> Result<Vec<u8>> {
+    let mut data = vec![CREATE_METADATA_ACCOUNT_V3_DISCRIMINATOR];
+    name.to_string().serialize(&mut data)?;
+    symbol.to_string().serialize(&mut data)?;
+    uri.to_string().serialize(&mut data)?;
+    0u16.serialize(&mut data)?; // seller_fee_basis_points
+    data.extend_from_slice(&[0, 0, 0]); // creators, collection, uses: None
+    true.serialize(&mut data)?; // is_mutable
+    data.push(0); // collection_details: None
+    Ok(data)
+}
+
+/// Creates the Metaplex metadata account of `mint` via CPI.
+/// The mint PDA signs as both the mint authority and the update authority.
+#[allow(clippy::too_many_arguments)]
+pub fn create_metaplex_metadata<'info>(
+    metadata: &AccountInfo<'info>,
+    mint: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    metaplex_program: &AccountInfo<'info>,
+    mint_signer_seeds: &[&[u8]],
+    name: &str,
+    symbol: &str,
+    uri: &str,
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: METAPLEX_TOKEN_METADATA_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(metadata.key(), false),
+            AccountMeta::new_readonly(mint.key(), false),
+            AccountMeta::new_readonly(mint.key(), true), // mint authority
+            AccountMeta::new(payer.key(), true),
+            AccountMeta::new_readonly(mint.key(), true), // update authority
+            AccountMeta::new_readonly(system_program.key(), false),
+        ],
+        data: create_metadata_account_v3_data(name, symbol, uri)?,
+    };
+
+    invoke_signed(
+        &ix,
+        &[
+            metadata.clone(),
+            mint.clone(),
+            payer.clone(),
+            system_program.clone(),
+            metaplex_program.clone(),
+        ],
+        &[mint_signer_seeds],
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_create_metadata_account_v3_data_layout() {
+        let data = create_metadata_account_v3_data("Wrapped Ether", "WETH", "").unwrap();
+
+        let mut expected = vec![33];
+        expected.extend_from_slice(&13u32.to_le_bytes());
+        expected.extend_from_slice(b"Wrapped Ether");
+        expected.extend_from_slice(&4u32.to_le_bytes());
+        expected.extend_from_slice(b"WETH");
+        expected.extend_from_slice(&0u32.to_le_bytes());
+        expected.extend_from_slice(&[0, 0]); // seller_fee_basis_points
+        expected.extend_from_slice(&[0, 0, 0]); // creators, collection, uses
+        expected.push(1); // is_mutable
+        expected.push(0); // collection_details
+
+        assert_eq!(data, expected);
+    }
+
+}
@@ -2,3 +2,6 @@ pub mod bridge_call;
 pub mod bridge_sol;
 pub mod bridge_spl;
 pub mod bridge_wrapped_token;
+pub mod call_buffer;
+pub mod metaplex;
+pub mod spl_memo;
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{instruction::Instruction, program::invoke};
+
+use crate::{solana_to_base::SPL_MEMO_PROGRAM_ID, BridgeError};
+
+/// Decimals of native SOL amounts, expressed in lamports.
+const SOL_DECIMALS: u8 = 9;
+
+/// Logs a human-readable summary of a bridge operation with an SPL Memo CPI, so that wallets and
+/// explorers display something more meaningful than the bare bridge instruction.
+///
+/// Does nothing unless `spl_memo` is set; `summary` is only built when it is, so that callers
+/// opting out do not pay for the formatting either.
+pub fn log_spl_memo<'info>(
+    spl_memo: bool,
+    memo_program: Option<&UncheckedAccount<'info>>,
+    summary: impl FnOnce() -> String,
+) -> Result<()> {
+    if !spl_memo {
+        return Ok(());
+    }
+
+    let memo_program = memo_program.ok_or(BridgeError::MissingMemoProgram)?;
+    let ix = Instruction {
+        program_id: SPL_MEMO_PROGRAM_ID,
+        accounts: vec![],
+        data: summary().into_bytes(),
+    };
+
+    invoke(&ix, &[memo_program.to_account_info()])?;
+
+    Ok(())
+}
+
+/// Summarizes a SOL transfer, e.g. `Bridge 0.1 SOL → 0xa0b8…`.
+pub fn sol_transfer_summary(amount: u64, to: &[u8; 20], with_call: bool) -> String {
+    transfer_summary(&format_amount(amount, SOL_DECIMALS), "SOL", to, with_call)
+}
+
+/// Summarizes a token transfer, identifying the token by its `mint`.
+pub fn token_transfer_summary(
+    amount: u64,
+    decimals: u8,
+    mint: &Pubkey,
+    to: &[u8; 20],
+    with_call: bool,
+) -> String {
+    transfer_summary(
+        &format_amount(amount, decimals),
+        &mint.to_string(),
+        to,
+        with_call,
+    )
+}
+
+/// Summarizes a call, e.g. `Call 0xa0b8… on Base`.
+pub fn call_summary(to: &[u8; 20]) -> String {
+    format!("Call 0x{} on Base", hex::encode(to))
+}
+
+fn transfer_summary(amount: &str, token: &str, to: &[u8; 20], with_call: bool) -> String {
+    let summary = format!("Bridge {amount} {token} → 0x{}", hex::encode(to));
+    if with_call {
+        summary + " with call"
+    } else {
+        summary
+    }
+}
+
+/// Formats a raw token `amount` with `decimals` decimals, without trailing zeros.
+fn format_amount(amount: u64, decimals: u8) -> String {
+    let decimals = decimals as usize;
+    let digits = format!("{amount:0>width$}", width = decimals + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+
+    if fraction.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{whole}.{fraction}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_amount() {
+        assert_eq!(format_amount(100_000_000, 9), "0.1");
+        assert_eq!(format_amount(1_500_000_000, 9), "1.5");
+        assert_eq!(format_amount(2_000_000, 6), "2");
+        assert_eq!(format_amount(1, 6), "0.000001");
+        assert_eq!(format_amount(0, 6), "0");
+        assert_eq!(format_amount(42, 0), "42");
+    }
+
+    #[test]
+    fn test_summaries() {
+        let to = [0xa0; 20];
+        let to_hex = "a0".repeat(20);
+
+        assert_eq!(
+            sol_transfer_summary(100_000_000, &to, false),
+            format!("Bridge 0.1 SOL → 0x{to_hex}")
+        );
+        assert_eq!(
+            token_transfer_summary(2_500_000, 6, &Pubkey::default(), &to, true),
+            format!("Bridge 2.5 {} → 0x{to_hex} with call", Pubkey::default())
+        );
+        assert_eq!(call_summary(&to), format!("Call 0x{to_hex} on Base"));
+    }
+}
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+
+use crate::{solana_to_base::BRIDGE_SESSION_SEED, BridgeError};
+
+/// Pre-authorized bridging budget letting `delegate` bridge the SPL and wrapped tokens of `owner`
+/// without the owner signing each transaction.
+///
+/// The session PDA, derived from `[BRIDGE_SESSION_SEED, owner, delegate]`, must be approved by the
+/// owner as the SPL delegate of the bridged token accounts, as it signs the token transfers and
+/// burns on behalf of the owner. Messages bridged through a session are sent by `owner`.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct BridgeSession {
+    /// The owner of the bridged tokens and sender of the outgoing messages.
+    pub owner: Pubkey,
+
+    /// The key allowed to bridge on behalf of `owner`.
+    pub delegate: Pubkey,
+
+    /// Amount, in the smallest units of the bridged token and including the protocol fee, that
+    /// can still be bridged through the session.
+    pub remaining_budget: u64,
+
+    /// Unix timestamp from which the session can no longer be used.
+    pub expires_at: i64,
+
+    /// Base tokens the session can bridge to, at most `MAX_SESSION_REMOTE_TOKENS`.
+    #[max_len(8)]
+    pub allowed_remote_tokens: Vec<[u8; 20]>,
+
+    /// Bump of the session PDA, used to sign as the SPL delegate.
+    pub bump: u8,
+}
+
+impl BridgeSession {
+    /// Debits `amount` bridged to `remote_token` by `delegate` from the tokens of `token_owner`.
+    pub fn debit(
+        &mut self,
+        delegate: &Pubkey,
+        token_owner: &Pubkey,
+        remote_token: &[u8; 20],
+        amount: u64,
+        now: i64,
+    ) -> Result<()> {
+        require_keys_eq!(
+            *delegate,
+            self.delegate,
+            BridgeError::IncorrectSessionDelegate
+        );
+        require_keys_eq!(*token_owner, self.owner, BridgeError::IncorrectSessionOwner);
+        require!(now < self.expires_at, BridgeError::SessionExpired);
+        require!(
+            self.allowed_remote_tokens.contains(remote_token),
+            BridgeError::SessionRemoteTokenNotAllowed
+        );
+
+        self.remaining_budget = self
+            .remaining_budget
+            .checked_sub(amount)
+            .ok_or(BridgeError::SessionBudgetExceeded)?;
+
+        Ok(())
+    }
+
+    /// Returns the seeds of the session PDA, signing the token transfers of `owner`.
+    pub fn signer_seeds(&self) -> [&[u8]; 4] {
+        [
+            BRIDGE_SESSION_SEED,
+            self.owner.as_ref(),
+            self.delegate.as_ref(),
+            std::slice::from_ref(&self.bump),
+        ]
+    }
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::{solana_to_base::BURN_RECEIPT_SEED, ID};
+
+/// Immutable record of a wrapped token burn, linking it to the Base address the tokens are
+/// released to. Created by `bridge_wrapped_token` and never closed, so that auditors can account
+/// for burns without relying on event retention or on the outgoing message, which is closed once
+/// relayed.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct BurnReceipt {
+    /// The owner of the burned tokens (the session owner when bridging through a session)
+    pub burner: Pubkey,
+    /// The wrapped token mint
+    pub mint: Pubkey,
+    /// The amount burned, net of the protocol fee
+    pub amount: u64,
+    /// The Base address receiving the tokens
+    pub to: [u8; 20],
+    /// Nonce of the outgoing message bridging the burned tokens
+    pub nonce: u64,
+    /// Unix timestamp of the burn
+    pub timestamp: i64,
+}
+
+impl BurnReceipt {
+    /// Returns the address of the burn receipt of `outgoing_message`.
+    pub fn pda(outgoing_message: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[BURN_RECEIPT_SEED, outgoing_message.as_ref()], &ID).0
+    }
+}
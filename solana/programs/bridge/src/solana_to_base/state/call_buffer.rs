@@ -1,13 +1,13 @@
 use anchor_lang::prelude::*;
 
-use crate::solana_to_base::CallType;
+use crate::solana_to_base::{Call, CallType};
 
 /// A buffer account that stores call parameters which can be built up over multiple transactions
 /// to bypass Solana's transaction size limits. The `data` field can be appended incrementally, and
-/// the account is typically consumed (closed via `close = owner`) by the buffered bridge
-/// instructions when the call is bridged to Base.
+/// the account is consumed (closed to `owner`) by the buffered bridge instructions when the call is
+/// bridged to Base, unless the owner marked it `reusable`.
 #[account]
-#[derive(Debug)]
+#[derive(Debug, InitSpace)]
 pub struct CallBuffer {
     /// The owner who can modify this call buffer
     pub owner: Pubkey,
@@ -23,9 +23,22 @@ pub struct CallBuffer {
     /// The amount of Base native currency (ETH) to send with this call, in wei.
     pub value: u128,
 
+    /// Unix timestamp of the last initialization, append or reuse.
+    /// Used to expire abandoned buffers after `buffer_config.call_buffer_ttl_seconds`.
+    pub last_modified: i64,
+
+    /// Whether buffered bridge instructions keep the buffer instead of closing it, so that the
+    /// same call can be bridged repeatedly. Reusable buffers cannot be appended to.
+    pub reusable: bool,
+
+    /// Number of times the buffer was read by a buffered bridge instruction.
+    pub use_count: u64,
+
     /// The encoded function call data or contract bytecode.
     /// For regular calls: ABI-encoded function signature and parameters.
     /// For contract creation: the contract's initialization bytecode.
+    /// `INIT_SPACE` only accounts for its length prefix, see `CallBuffer::space`.
+    #[max_len(0)]
     pub data: Vec<u8>,
 }
 
@@ -36,10 +49,105 @@ impl CallBuffer {
     ///
     /// This reserves capacity for `data` so it can be appended without reallocation.
     pub fn space(max_data_len: usize) -> usize {
-        32 + // owner
-        1 + // ty (CallType enum)
-        20 + // to
-        16 + // value
-        4 + max_data_len // data vec (length prefix + max data)
+        Self::INIT_SPACE + max_data_len
+    }
+
+    /// Reads the buffered call for a buffered bridge instruction and counts the use.
+    ///
+    /// Single-use buffers are closed by the instruction, so their data is moved into the outgoing
+    /// message instead of cloned. This avoids holding two copies of up to `max_call_buffer_size`
+    /// bytes on the 32KB program heap; Anchor then serializes the outgoing message straight into
+    /// its account data on exit. Reusable buffers keep their data, which is cloned instead, and
+    /// are kept from expiring while in use.
+    pub fn use_call(&mut self, timestamp: i64) -> Call {
+        self.use_count += 1;
+        if !self.reusable {
+            return self.take_call();
+        }
+
+        self.last_modified = timestamp;
+        Call {
+            ty: self.ty,
+            to: self.to,
+            value: self.value,
+            data: self.data.clone(),
+        }
+    }
+
+    /// Moves the buffered call out of the account, leaving `data` empty.
+    fn take_call(&mut self) -> Call {
+        Call {
+            ty: self.ty,
+            to: self.to,
+            value: self.value,
+            data: std::mem::take(&mut self.data),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_space_matches_serialized_size() {
+        for max_data_len in [0, 1, 1024] {
+            let call_buffer = CallBuffer {
+                owner: Pubkey::new_unique(),
+                ty: CallType::Create2,
+                to: [1u8; 20],
+                value: 2,
+                last_modified: 3,
+                reusable: true,
+                use_count: 4,
+                data: vec![5u8; max_data_len],
+            };
+            assert_eq!(
+                call_buffer.try_to_vec().unwrap().len(),
+                CallBuffer::space(max_data_len)
+            );
+        }
+    }
+
+    fn sample_call_buffer(reusable: bool) -> CallBuffer {
+        CallBuffer {
+            owner: Pubkey::new_unique(),
+            ty: CallType::Call,
+            to: [1u8; 20],
+            value: 2,
+            last_modified: 3,
+            reusable,
+            use_count: 0,
+            data: vec![4u8; 16],
+        }
+    }
+
+    #[test]
+    fn test_use_call_takes_data_of_single_use_buffer() {
+        let mut call_buffer = sample_call_buffer(false);
+
+        let call = call_buffer.use_call(10);
+
+        assert_eq!(call.data, vec![4u8; 16]);
+        assert!(call_buffer.data.is_empty());
+        assert_eq!(call_buffer.use_count, 1);
+        assert_eq!(call_buffer.last_modified, 3);
+    }
+
+    #[test]
+    fn test_use_call_keeps_data_of_reusable_buffer() {
+        let mut call_buffer = sample_call_buffer(true);
+
+        for use_count in 1..=3 {
+            let call = call_buffer.use_call(10 + use_count as i64);
+            assert_eq!(call.ty, CallType::Call);
+            assert_eq!(call.to, [1u8; 20]);
+            assert_eq!(call.value, 2);
+            assert_eq!(call.data, vec![4u8; 16]);
+            assert_eq!(call_buffer.use_count, use_count);
+        }
+
+        assert_eq!(call_buffer.data, vec![4u8; 16]);
+        assert_eq!(call_buffer.last_modified, 13);
     }
 }
@@ -1,5 +1,15 @@
+pub mod bridge_session;
+pub mod burn_receipt;
 pub mod call_buffer;
 pub mod outgoing_message;
+pub mod payer_rate_limit;
+pub mod relay_execution;
+pub mod relayed_nonces;
 
+pub use bridge_session::*;
+pub use burn_receipt::*;
 pub use call_buffer::*;
 pub use outgoing_message::*;
+pub use payer_rate_limit::*;
+pub use relay_execution::*;
+pub use relayed_nonces::*;
@@ -1,9 +1,6 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::keccak};
 
-/// Trait for calculating the space required for a message.
-pub trait MessageSpace {
-    fn space(data_len: usize) -> usize;
-}
+use crate::{common::DISCRIMINATOR_LEN, solana_to_base::OUTGOING_MESSAGE_HEADER_LEN};
 
 /// Represents a token transfer from Solana to Base with optional contract execution.
 /// This struct contains all the information needed to bridge tokens between chains
@@ -29,15 +26,81 @@ pub struct Transfer {
     /// Optional contract call to execute on Base after the token transfer completes.
     /// Allows for complex cross-chain operations that combine token transfers with logic execution.
     pub call: Option<Call>,
+
+    /// Optional opaque payment memo of at most `MAX_TRANSFER_MEMO_LEN` bytes, used by payment
+    /// processors to match invoices. It is appended to the message data relayed to Base
+    /// (`abi.encode(transfer, memo)` or `abi.encode(transfer, call, memo)`), where it is covered
+    /// by the message hash and ignored when decoding the transfer.
+    pub memo: Option<Vec<u8>>,
+
+    /// Optional slippage protection for a `call` swapping the bridged tokens on Base, required
+    /// when the call targets a router of the `DexRouterList`. It is appended to the message data
+    /// relayed to Base after the memo (`abi.encode(transfer, call, memo, swap_guard)`, with an
+    /// empty memo if there is none), where the executor enforces it around the call.
+    pub swap_guard: Option<SwapGuard>,
 }
 
-impl MessageSpace for Transfer {
-    fn space(data_len: usize) -> usize {
+impl Transfer {
+    /// Returns the serialized size of a transfer carrying a call with `call_data_len` bytes of
+    /// data, a memo of `memo_len` bytes and a swap guard if `swap_guard` is set. Absent options
+    /// only take their flag.
+    pub fn space(call_data_len: Option<usize>, memo_len: Option<usize>, swap_guard: bool) -> usize {
         20 + // to
         32 + // local_token
         20 + // remote_token
         8 + // amount
-        1 + Call::space(data_len) // option_flag + call
+        1 + call_data_len.map_or(0, Call::space) + // option_flag + call
+        1 + memo_len.map_or(0, |len| 4 + len) + // option_flag + len_prefix + memo
+        1 + if swap_guard { SwapGuard::INIT_SPACE } else { 0 } // option_flag + swap_guard
+    }
+}
+
+/// Bounds enforced by the Base executor around the call of a bridge-and-swap `Transfer`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct SwapGuard {
+    /// The token the call swaps into on Base, or the zero address for ETH.
+    pub token_out: [u8; 20],
+
+    /// The minimum amount of `token_out` the call must credit to the sender's Twin on Base, in
+    /// the token's smallest unit. Must be non-zero.
+    pub min_out: u128,
+
+    /// Unix timestamp after which the call is skipped on Base, the tokens being delivered to
+    /// `to` without being swapped. Must be in the future when the transfer is sent.
+    pub deadline: i64,
+}
+
+/// A recipient on Base of a `TransferBatch`, along with the amount it receives.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct BatchRecipient {
+    /// The recipient address on Base.
+    pub to: [u8; 20],
+
+    /// The amount the recipient receives, in the token's smallest unit.
+    pub amount: u64,
+}
+
+/// Represents transfers of a single token from Solana to several recipients on Base, funded by a
+/// single lock on Solana. Each recipient is credited on Base as if it received its own `Transfer`.
+#[derive(Debug, Clone, Eq, PartialEq, AnchorSerialize, AnchorDeserialize)]
+pub struct TransferBatch {
+    /// The token mint address on Solana that is being bridged.
+    /// For SOL this is `NATIVE_SOL_PUBKEY`.
+    pub local_token: Pubkey,
+
+    /// The corresponding token contract address on Base.
+    pub remote_token: [u8; 20],
+
+    /// The recipients on Base, at most `MAX_TRANSFER_BATCH_RECIPIENTS`.
+    pub recipients: Vec<BatchRecipient>,
+}
+
+impl TransferBatch {
+    /// Returns the serialized size of a batch with `recipients` recipients.
+    pub fn space(recipients: usize) -> usize {
+        32 + // local_token
+        20 + // remote_token
+        4 + recipients * BatchRecipient::INIT_SPACE // len_prefix + recipients
     }
 }
 
@@ -52,7 +115,7 @@ pub enum CallType {
 /// Represents a contract call to be executed on Base.
 /// Contains all the necessary information to perform various types of contract interactions,
 /// including regular calls, delegate calls, and contract creation operations.
-#[derive(Debug, Clone, Eq, PartialEq, AnchorSerialize, AnchorDeserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, AnchorSerialize, AnchorDeserialize, InitSpace)]
 pub struct Call {
     /// The type of call operation to perform (Call, DelegateCall, Create, or Create2).
     /// Determines how the call will be executed on the Base side.
@@ -68,21 +131,22 @@ pub struct Call {
     /// The encoded function call data or contract bytecode.
     /// For regular calls: ABI-encoded function signature and parameters.
     /// For contract creation: the contract's initialization bytecode.
+    /// `INIT_SPACE` only accounts for its length prefix, see `Call::space`.
+    #[max_len(0)]
     pub data: Vec<u8>,
 }
 
-impl MessageSpace for Call {
-    fn space(data_len: usize) -> usize {
-        CallType::INIT_SPACE + // call type
-        20 + // to
-        16 + // value
-        4 + data_len // len_prefix + data
+impl Call {
+    /// Returns the serialized size of a call with `data_len` bytes of data.
+    pub fn space(data_len: usize) -> usize {
+        Self::INIT_SPACE + data_len
     }
 }
 
 /// Represents the type of cross-chain operation to be executed on Base.
-/// This enum encapsulates the two main types of operations supported by the bridge:
-/// direct contract calls and token transfers with optional contract calls.
+/// This enum encapsulates the main types of operations supported by the bridge:
+/// direct contract calls, token transfers with optional contract calls, encrypted calls and
+/// transfer batches.
 #[derive(Debug, Clone, Eq, PartialEq, AnchorSerialize, AnchorDeserialize)]
 pub enum Message {
     /// A direct contract call to be executed on Base.
@@ -92,11 +156,91 @@ pub enum Message {
     /// A token transfer from Solana to Base, with an optional contract call.
     /// Handles bridging of tokens between chains and can trigger additional logic on Base.
     Transfer(Transfer),
+
+    /// A contract call whose `data` is ciphertext only the Base-side executor can decrypt,
+    /// keeping sensitive calldata private until execution. Transfers are never encrypted so that
+    /// bridged amounts remain publicly auditable.
+    Encrypted(Call),
+
+    /// Transfers of a single token to several recipients on Base, without any contract call.
+    /// Amortizes the per-message overhead of bridging to many recipients at once.
+    TransferBatch(TransferBatch),
+}
+
+/// The variant of a `Message` along with the length of its variable-size fields, which is all
+/// that is needed to size an `OutgoingMessage` account before the message is built.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MessageKind {
+    Call {
+        data_len: usize,
+    },
+    Transfer {
+        call_data_len: Option<usize>,
+        memo_len: Option<usize>,
+        swap_guard: bool,
+    },
+    Encrypted {
+        data_len: usize,
+    },
+    TransferBatch {
+        recipients: usize,
+    },
+}
+
+impl MessageKind {
+    /// Returns the serialized size of a message of this kind, including its variant.
+    pub fn space(&self) -> usize {
+        1 + match *self {
+            Self::Call { data_len } | Self::Encrypted { data_len } => Call::space(data_len),
+            Self::Transfer {
+                call_data_len,
+                memo_len,
+                swap_guard,
+            } => Transfer::space(call_data_len, memo_len, swap_guard),
+            Self::TransferBatch { recipients } => TransferBatch::space(recipients),
+        }
+    }
+}
+
+impl From<&Message> for MessageKind {
+    fn from(message: &Message) -> Self {
+        match message {
+            Message::Call(call) => Self::Call {
+                data_len: call.data.len(),
+            },
+            Message::Transfer(transfer) => Self::Transfer {
+                call_data_len: transfer.call.as_ref().map(|call| call.data.len()),
+                memo_len: transfer.memo.as_ref().map(Vec::len),
+                swap_guard: transfer.swap_guard.is_some(),
+            },
+            Message::Encrypted(call) => Self::Encrypted {
+                data_len: call.data.len(),
+            },
+            Message::TransferBatch(batch) => Self::TransferBatch {
+                recipients: batch.recipients.len(),
+            },
+        }
+    }
 }
 
 /// Represents a message being sent from Solana to Base through the bridge.
 /// This struct contains all the necessary information to execute a cross-chain operation
 /// on the Base side, including the message content and execution parameters.
+///
+/// The fixed-size fields form a header at stable offsets, ahead of the variable-length message,
+/// so that relayers can discover messages with `getProgramAccounts` memcmp filters and data
+/// slices without decoding the message itself. The offsets are part of the account API:
+///
+/// | Field           | Offset                                    | Size |
+/// | --------------- | ----------------------------------------- | ---- |
+/// | nonce           | `OUTGOING_MESSAGE_NONCE_OFFSET`           | 8    |
+/// | sender          | `OUTGOING_MESSAGE_SENDER_OFFSET`          | 32   |
+/// | created_at      | `OUTGOING_MESSAGE_CREATED_AT_OFFSET`      | 8    |
+/// | remote_chain_id | `OUTGOING_MESSAGE_REMOTE_CHAIN_ID_OFFSET` | 8    |
+/// | message         | `OUTGOING_MESSAGE_HEADER_LEN`             | ..   |
+///
+/// The relay status is not stored in the message: relayed nonces are tracked by the
+/// `RelayedNonces` registry, after which the message can be closed.
 #[account]
 #[derive(Debug, Eq, PartialEq)]
 pub struct OutgoingMessage {
@@ -109,33 +253,212 @@ pub struct OutgoingMessage {
     /// via signer constraints.
     pub sender: Pubkey,
 
+    /// Unix timestamp at which the message was sent.
+    pub created_at: i64,
+
+    /// Chain ID of the chain the message is sent to, taken from the bridge configuration.
+    pub remote_chain_id: u64,
+
     /// The actual message payload that will be executed on Base.
-    /// Can be either a direct contract call or a token transfer (with optional call).
+    /// Can be a direct contract call, a token transfer (with optional call), an encrypted call or a
+    /// transfer batch.
     pub message: Message,
 }
 
 impl OutgoingMessage {
-    pub fn new_call(nonce: u64, sender: Pubkey, call: Call) -> Self {
+    pub fn new_call(
+        nonce: u64,
+        sender: Pubkey,
+        created_at: i64,
+        remote_chain_id: u64,
+        call: Call,
+    ) -> Self {
         Self {
             nonce,
             sender,
+            created_at,
+            remote_chain_id,
             message: Message::Call(call),
         }
     }
 
-    pub fn new_transfer(nonce: u64, sender: Pubkey, transfer: Transfer) -> Self {
+    pub fn new_transfer(
+        nonce: u64,
+        sender: Pubkey,
+        created_at: i64,
+        remote_chain_id: u64,
+        transfer: Transfer,
+    ) -> Self {
         Self {
             nonce,
             sender,
+            created_at,
+            remote_chain_id,
             message: Message::Transfer(transfer),
         }
     }
 
-    /// Returns the serialized size of an `OutgoingMessage` payload, excluding the DISCRIMINATOR_LEN-byte Anchor
-    /// account discriminator.
-    pub fn space<T: MessageSpace>(data_len: usize) -> usize {
-        8 + // nonce
-        32 + // sender
-        1 + T::space(data_len) // message (variant + space)
+    pub fn new_encrypted_call(
+        nonce: u64,
+        sender: Pubkey,
+        created_at: i64,
+        remote_chain_id: u64,
+        call: Call,
+    ) -> Self {
+        Self {
+            nonce,
+            sender,
+            created_at,
+            remote_chain_id,
+            message: Message::Encrypted(call),
+        }
+    }
+
+    pub fn new_transfer_batch(
+        nonce: u64,
+        sender: Pubkey,
+        created_at: i64,
+        remote_chain_id: u64,
+        batch: TransferBatch,
+    ) -> Self {
+        Self {
+            nonce,
+            sender,
+            created_at,
+            remote_chain_id,
+            message: Message::TransferBatch(batch),
+        }
+    }
+
+    /// Returns the leaf committing to the message in the outbox MMR:
+    /// `keccak256(outgoing_message || borsh(message))`, where `outgoing_message` is the address of
+    /// the account holding it.
+    pub fn leaf_hash(&self, outgoing_message: &Pubkey) -> Result<[u8; 32]> {
+        Ok(keccak::hashv(&[outgoing_message.as_ref(), &self.try_to_vec()?]).to_bytes())
+    }
+
+    /// Returns the local token, the remote token and the total amount transferred by the message,
+    /// or `None` if it does not transfer tokens.
+    pub fn transferred_tokens(&self) -> Option<(Pubkey, [u8; 20], u64)> {
+        match &self.message {
+            Message::Transfer(transfer) => {
+                Some((transfer.local_token, transfer.remote_token, transfer.amount))
+            }
+            Message::TransferBatch(batch) => Some((
+                batch.local_token,
+                batch.remote_token,
+                batch
+                    .recipients
+                    .iter()
+                    .map(|recipient| recipient.amount)
+                    .sum(),
+            )),
+            Message::Call(_) | Message::Encrypted(_) => None,
+        }
+    }
+
+    /// Returns the exact serialized size of an `OutgoingMessage` payload carrying a message of
+    /// `kind`, excluding the DISCRIMINATOR_LEN-byte Anchor account discriminator.
+    pub fn space(kind: MessageKind) -> usize {
+        OUTGOING_MESSAGE_HEADER_LEN - DISCRIMINATOR_LEN + kind.space()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(data_len: usize) -> Call {
+        Call {
+            ty: CallType::Call,
+            to: [1u8; 20],
+            value: 2,
+            data: vec![3u8; data_len],
+        }
+    }
+
+    fn transfer(call: Option<Call>, memo: Option<Vec<u8>>) -> Transfer {
+        Transfer {
+            to: [4u8; 20],
+            local_token: Pubkey::new_unique(),
+            remote_token: [5u8; 20],
+            amount: 6,
+            call,
+            memo,
+            swap_guard: None,
+        }
+    }
+
+    fn assert_exact_space(message: Message) {
+        let outgoing_message = OutgoingMessage {
+            nonce: 7,
+            sender: Pubkey::new_unique(),
+            created_at: 8,
+            remote_chain_id: 9,
+            message,
+        };
+        let space = OutgoingMessage::space(MessageKind::from(&outgoing_message.message));
+        assert_eq!(
+            outgoing_message.try_to_vec().unwrap().len(),
+            space,
+            "space mismatch for {:?}",
+            outgoing_message.message
+        );
+    }
+
+    #[test]
+    fn test_space_matches_serialized_size() {
+        for data_len in [0, 1, 100] {
+            assert_exact_space(Message::Call(call(data_len)));
+            assert_exact_space(Message::Encrypted(call(data_len)));
+            assert_exact_space(Message::Transfer(transfer(Some(call(data_len)), None)));
+            assert_exact_space(Message::Transfer(transfer(
+                Some(call(data_len)),
+                Some(vec![0xab; data_len]),
+            )));
+            assert_exact_space(Message::Transfer(transfer(
+                None,
+                Some(vec![0xab; data_len]),
+            )));
+        }
+        assert_exact_space(Message::Transfer(transfer(None, None)));
+        assert_exact_space(Message::Transfer(Transfer {
+            swap_guard: Some(SwapGuard {
+                token_out: [10u8; 20],
+                min_out: 11,
+                deadline: 12,
+            }),
+            ..transfer(Some(call(100)), None)
+        }));
+
+        for recipients in [1, 2, 16] {
+            assert_exact_space(Message::TransferBatch(TransferBatch {
+                local_token: Pubkey::new_unique(),
+                remote_token: [5u8; 20],
+                recipients: vec![
+                    BatchRecipient {
+                        to: [4u8; 20],
+                        amount: 6,
+                    };
+                    recipients
+                ],
+            }));
+        }
+    }
+
+    #[test]
+    fn test_transfer_without_call_does_not_reserve_call_space() {
+        assert_eq!(
+            OutgoingMessage::space(MessageKind::Transfer {
+                call_data_len: Some(0),
+                memo_len: None,
+                swap_guard: false,
+            }) - OutgoingMessage::space(MessageKind::Transfer {
+                call_data_len: None,
+                memo_len: None,
+                swap_guard: false,
+            }),
+            Call::space(0)
+        );
     }
 }
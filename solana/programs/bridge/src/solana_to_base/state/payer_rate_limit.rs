@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use crate::{common::PayerRateLimitConfig, solana_to_base::PAYER_RATE_LIMIT_SEED, BridgeError, ID};
+
+/// Outgoing messages paid for by a payer in the current rate limiting window (see
+/// `PayerRateLimitConfig`). Created on the first message of the payer while rate limiting is
+/// enabled.
+#[account]
+#[derive(Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct PayerRateLimit {
+    /// Unix timestamp at which the current window started
+    pub window_start: i64,
+    /// Number of outgoing messages paid for in the current window
+    pub message_count: u64,
+    /// Gas paid for in the current window
+    pub gas_used: u64,
+}
+
+impl PayerRateLimit {
+    /// Returns the address of the rate limit of `payer`.
+    pub fn pda(payer: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[PAYER_RATE_LIMIT_SEED, payer.as_ref()], &ID).0
+    }
+
+    /// Records an outgoing message costing `gas` at `now`, starting a new window once the current
+    /// one has elapsed. Fails if the message exceeds the limits of `config` for the window.
+    pub fn record(&mut self, config: &PayerRateLimitConfig, now: i64, gas: u64) -> Result<()> {
+        let window_seconds = i64::try_from(config.window_seconds).unwrap_or(i64::MAX);
+        if now >= self.window_start.saturating_add(window_seconds) {
+            *self = Self {
+                window_start: now,
+                ..Self::default()
+            };
+        }
+
+        let message_count = self.message_count + 1;
+        let gas_used = self.gas_used.saturating_add(gas);
+        require!(
+            (config.max_messages == 0 || message_count <= config.max_messages)
+                && (config.max_gas == 0 || gas_used <= config.max_gas),
+            BridgeError::PayerRateLimited
+        );
+
+        self.message_count = message_count;
+        self.gas_used = gas_used;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::common::MAX_RATE_LIMIT_EXEMPT_PAYERS;
+
+    fn config(max_messages: u64, max_gas: u64) -> PayerRateLimitConfig {
+        PayerRateLimitConfig {
+            window_seconds: 60,
+            max_messages,
+            max_gas,
+            exempt_payer_count: 0,
+            exempt_payers: [Pubkey::default(); MAX_RATE_LIMIT_EXEMPT_PAYERS as usize],
+        }
+    }
+
+    #[test]
+    fn test_record_limits_messages_per_window() {
+        let config = config(2, 0);
+        let mut rate_limit = PayerRateLimit::default();
+
+        rate_limit.record(&config, 1_000, 100_000).unwrap();
+        rate_limit.record(&config, 1_059, 100_000).unwrap();
+        let error = rate_limit.record(&config, 1_059, 100_000).unwrap_err();
+        assert!(format!("{:?}", error).contains("PayerRateLimited"));
+        assert_eq!(rate_limit.message_count, 2);
+
+        // A new window starts once the current one has elapsed
+        rate_limit.record(&config, 1_060, 100_000).unwrap();
+        assert_eq!(
+            rate_limit,
+            PayerRateLimit {
+                window_start: 1_060,
+                message_count: 1,
+                gas_used: 100_000,
+            }
+        );
+    }
+
+    #[test]
+    fn test_record_limits_gas_per_window() {
+        let config = config(0, 250_000);
+        let mut rate_limit = PayerRateLimit::default();
+
+        rate_limit.record(&config, 1_000, 100_000).unwrap();
+        rate_limit.record(&config, 1_000, 150_000).unwrap();
+        let error = rate_limit.record(&config, 1_000, 1).unwrap_err();
+        assert!(format!("{:?}", error).contains("PayerRateLimited"));
+        assert_eq!(rate_limit.gas_used, 250_000);
+    }
+}
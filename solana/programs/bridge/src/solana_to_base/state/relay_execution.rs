@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::{solana_to_base::RELAY_EXECUTION_SEED, ID};
+
+/// Oracle-attested record of the relayer that executed an outgoing message on Base, so that relay
+/// tips escrowed on Solana can be settled to the account that did the work. Created once per nonce
+/// by `record_relay_execution` and never closed.
+#[account]
+#[derive(Debug, PartialEq, Eq, InitSpace)]
+pub struct RelayExecution {
+    /// Nonce of the executed outgoing message
+    pub nonce: u64,
+    /// The Solana account of the relayer that executed the message on Base
+    pub relayer: Pubkey,
+}
+
+impl RelayExecution {
+    /// Returns the address of the relay execution record of `nonce`.
+    pub fn pda(nonce: u64) -> Pubkey {
+        Pubkey::find_program_address(&[RELAY_EXECUTION_SEED, &nonce.to_le_bytes()], &ID).0
+    }
+
+    /// Returns the relayer recorded by `account`, the record PDA of a nonce, or `None` if the
+    /// execution of the nonce was not recorded yet.
+    pub fn relayer_in(account: &AccountInfo) -> Result<Option<Pubkey>> {
+        if account.owner != &ID || account.data_is_empty() {
+            return Ok(None);
+        }
+
+        let relay_execution = Self::try_deserialize(&mut &account.try_borrow_data()?[..])?;
+        Ok(Some(relay_execution.relayer))
+    }
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::{common::DISCRIMINATOR_LEN, solana_to_base::RELAYED_NONCES_PER_ACCOUNT, ID};
+
+/// Bitmap of the outgoing message nonces confirmed as relayed on Base.
+///
+/// Each account covers `RELAYED_NONCES_PER_ACCOUNT` consecutive nonces starting at
+/// `page * RELAYED_NONCES_PER_ACCOUNT`, so relays executed out of order on Base can be tracked
+/// individually. The bitmap is too large for the stack and is therefore accessed zero-copy.
+#[account(zero_copy)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct RelayedNonces {
+    /// Index of the nonce range covered by this account
+    pub page: u64,
+    /// One bit per nonce of the page, set once the nonce is confirmed as relayed
+    pub bitmap: [u64; (RELAYED_NONCES_PER_ACCOUNT / 64) as usize],
+}
+
+impl RelayedNonces {
+    /// Returns the page holding `nonce`.
+    pub fn page_of(nonce: u64) -> u64 {
+        nonce / RELAYED_NONCES_PER_ACCOUNT
+    }
+
+    /// Returns whether `nonce`, which must belong to this page, is confirmed as relayed.
+    pub fn is_relayed(&self, nonce: u64) -> bool {
+        let (word, mask) = Self::position(nonce);
+        self.bitmap[word] & mask != 0
+    }
+
+    /// Returns whether `nonce` is confirmed as relayed by `account`, the registry PDA covering its
+    /// page. A registry that was not created yet has no confirmed nonce.
+    pub fn is_relayed_in(account: &AccountInfo, nonce: u64) -> Result<bool> {
+        if account.owner != &ID || account.data_is_empty() {
+            return Ok(false);
+        }
+
+        let data = account.try_borrow_data()?;
+        require!(
+            data.starts_with(Self::DISCRIMINATOR),
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+        let relayed_nonces: &Self = bytemuck::try_from_bytes(
+            &data[DISCRIMINATOR_LEN..DISCRIMINATOR_LEN + std::mem::size_of::<Self>()],
+        )
+        .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize)?;
+
+        Ok(relayed_nonces.is_relayed(nonce))
+    }
+
+    /// Marks `nonce`, which must belong to this page, as relayed.
+    pub fn mark_relayed(&mut self, nonce: u64) {
+        let (word, mask) = Self::position(nonce);
+        self.bitmap[word] |= mask;
+    }
+
+    fn position(nonce: u64) -> (usize, u64) {
+        let index = nonce % RELAYED_NONCES_PER_ACCOUNT;
+        ((index / 64) as usize, 1 << (index % 64))
+    }
+}
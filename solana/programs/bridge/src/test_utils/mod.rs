@@ -32,28 +32,38 @@ use solana_transaction::Transaction;
 
 use crate::{
     accounts,
-    base_to_solana::signers::PartnerSigner,
+    base_to_solana::{
+        constants::{
+            BRIDGE_CPI_AUTHORITY_SEED, MAINTENANCE_BUDGET_SEED, OPERATIONAL_VAULT_SEED,
+            OUTPUT_ROOT_SEED, RELAY_DENY_LIST_SEED, RELAY_FEE_VAULT_SEED,
+        },
+        signers::PartnerSigner,
+        IncomingMessage, Message as IncomingPayload, OutputRoot,
+    },
     common::{
-        bridge::{BufferConfig, Eip1559Config, GasConfig, PartnerOracleConfig, ProtocolConfig},
-        BaseOracleConfig, Config, PartialTokenMetadata, BRIDGE_SEED, MAX_SIGNER_COUNT,
-        WRAPPED_TOKEN_SEED,
+        bridge::{
+            Bridge, BufferConfig, Eip1559, Eip1559Config, GasConfig, PartnerOracleConfig,
+            ProtocolConfig,
+        },
+        BaseOracleConfig, Config, ConfigChange, DexRouterList, EmergencyExit, MintAllowList,
+        MintPolicy, PartialTokenMetadata, PayerRateLimitConfig, ProtocolFeeConfig,
+        ProtocolFeeOverride, ADDRESS_BOOK_SEED, BRIDGE_SEED, CONFIG_HISTORY_LEN,
+        CONFIG_HISTORY_SEED, DEX_ROUTER_LIST_SEED, DISCRIMINATOR_LEN, EMERGENCY_EXIT_SEED,
+        GAS_FEE_ESCROW_SEED, INSURANCE_FUND_SEED, LIABILITY_ROOT_SEED, MAX_SIGNER_COUNT,
+        MINT_ALLOW_LIST_SEED, MINT_POLICY_SEED, PAYER_RATE_LIMIT_CONFIG_SEED,
+        PROTOCOL_FEE_CONFIG_SEED, SOL_VAULT_SEED, SOL_VAULT_STATE_SEED, TOKEN_DEPOSITS_SEED,
+        VAULT_DECIMALS_SEED, WRAPPED_TOKEN_SEED,
+    },
+    instruction::{InitConfigHistory, InitSolVault, Initialize},
+    solana_to_base::{
+        PayerRateLimit, RelayedNonces, OUTGOING_MESSAGE_SEED, RELAYED_NONCES_PER_ACCOUNT,
+        RELAYED_NONCES_SEED,
     },
-    instruction::Initialize,
-    solana_to_base::OUTGOING_MESSAGE_SEED,
-    ID,
+    BASE_CHAIN_ID, ID,
 };
 pub const TEST_GAS_FEE_RECEIVER: Pubkey = pubkey!("eEwCrQLBdQchykrkYitkYUZskd7MPrU2YxBXcPDPnMt");
-
-impl Eip1559Config {
-    pub fn test_new() -> Self {
-        Self {
-            target: 5_000_000,
-            denominator: 2,
-            window_duration_seconds: 1,
-            minimum_base_fee: 1,
-        }
-    }
-}
+pub const TEST_PROTOCOL_FEE_TREASURY: Pubkey =
+    pubkey!("3zSi56GGQ5HUNajqeLXYgVL4MWTY6GafmHTUjQpKC6of");
 
 impl GasConfig {
     pub fn test_new(gas_fee_receiver: Pubkey) -> Self {
@@ -71,6 +81,7 @@ impl ProtocolConfig {
         Self {
             block_interval_requirement: 300,
             remote_sol_address: hex!("C5b9112382f3c87AFE8e1A28fa52452aF81085AD"),
+            remote_chain_id: 0,
         }
     }
 }
@@ -79,6 +90,8 @@ impl BufferConfig {
     pub fn test_new() -> Self {
         Self {
             max_call_buffer_size: 8 * 1024, // 8KB
+            call_buffer_ttl_seconds: 0,
+            expire_bounty_bps: 0,
         }
     }
 }
@@ -217,7 +230,7 @@ pub struct SetupBridgeResult {
     pub bridge_pda: Pubkey,
 }
 
-/// Deploys the bridge program AND initializes it with default test config.
+/// Deploys the bridge program AND initializes it with default test config and the SOL vault.
 pub fn setup_bridge() -> SetupBridgeResult {
     let DeployBridgeResult {
         mut svm,
@@ -237,6 +250,7 @@ pub fn setup_bridge() -> SetupBridgeResult {
         bridge: bridge_pda,
         program_data: program_data_pda,
         program: ID,
+        gas_fee_receiver: TEST_GAS_FEE_RECEIVER,
         system_program: system_program::ID,
     }
     .to_account_metas(None);
@@ -258,14 +272,49 @@ pub fn setup_bridge() -> SetupBridgeResult {
         .data(),
     };
 
+    // Initialize the SOL vault
+    let accounts = accounts::InitSolVault {
+        payer: payer_pk,
+        sol_vault: Pubkey::find_program_address(&[SOL_VAULT_SEED], &ID).0,
+        sol_vault_state: sol_vault_state_pda(),
+        system_program: system_program::ID,
+    }
+    .to_account_metas(None);
+
+    let init_sol_vault_ix = Instruction {
+        program_id: ID,
+        accounts,
+        data: InitSolVault {}.data(),
+    };
+
+    let accounts = accounts::InitConfigHistory {
+        payer: payer_pk,
+        config_history: config_history_pda(),
+        system_program: system_program::ID,
+    }
+    .to_account_metas(None);
+
+    let init_config_history_ix = Instruction {
+        program_id: ID,
+        accounts,
+        data: InitConfigHistory {}.data(),
+    };
+
     let tx = Transaction::new(
         &[&payer],
-        Message::new(&[ix], Some(&payer_pk)),
+        Message::new(
+            &[ix, init_sol_vault_ix, init_config_history_ix],
+            Some(&payer_pk),
+        ),
         svm.latest_blockhash(),
     );
 
     svm.send_transaction(tx).unwrap();
 
+    // Fund the gas fee receiver so that it can be credited gas fees
+    svm.airdrop(&TEST_GAS_FEE_RECEIVER, LAMPORTS_PER_SOL)
+        .unwrap();
+
     SetupBridgeResult {
         svm,
         payer,
@@ -286,12 +335,507 @@ pub fn create_outgoing_message() -> ([u8; 32], Pubkey) {
     )
 }
 
+pub fn gas_fee_escrow_pda() -> Pubkey {
+    Pubkey::find_program_address(&[GAS_FEE_ESCROW_SEED], &ID).0
+}
+
+pub fn insurance_fund_pda() -> Pubkey {
+    Pubkey::find_program_address(&[INSURANCE_FUND_SEED], &ID).0
+}
+
+pub fn payer_rate_limit_config_pda() -> Pubkey {
+    Pubkey::find_program_address(&[PAYER_RATE_LIMIT_CONFIG_SEED], &ID).0
+}
+
+pub fn payer_rate_limit_pda(payer: &Pubkey) -> Pubkey {
+    PayerRateLimit::pda(payer)
+}
+
+pub fn protocol_fee_config_pda() -> Pubkey {
+    Pubkey::find_program_address(&[PROTOCOL_FEE_CONFIG_SEED], &ID).0
+}
+
+pub fn mint_policy_pda() -> Pubkey {
+    Pubkey::find_program_address(&[MINT_POLICY_SEED], &ID).0
+}
+
+pub fn mint_allow_list_pda() -> Pubkey {
+    Pubkey::find_program_address(&[MINT_ALLOW_LIST_SEED], &ID).0
+}
+
+pub fn dex_router_list_pda() -> Pubkey {
+    Pubkey::find_program_address(&[DEX_ROUTER_LIST_SEED], &ID).0
+}
+
+pub fn emergency_exit_pda() -> Pubkey {
+    Pubkey::find_program_address(&[EMERGENCY_EXIT_SEED], &ID).0
+}
+
+pub fn address_book_pda() -> Pubkey {
+    Pubkey::find_program_address(&[ADDRESS_BOOK_SEED], &ID).0
+}
+
+pub fn sol_vault_state_pda() -> Pubkey {
+    Pubkey::find_program_address(&[SOL_VAULT_STATE_SEED], &ID).0
+}
+
+pub fn token_deposits_pda(vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[TOKEN_DEPOSITS_SEED, vault.as_ref()], &ID).0
+}
+
+pub fn vault_decimals_pda(vault: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[VAULT_DECIMALS_SEED, vault.as_ref()], &ID).0
+}
+
+pub fn liability_root_pda() -> Pubkey {
+    Pubkey::find_program_address(&[LIABILITY_ROOT_SEED], &ID).0
+}
+
+pub fn relay_fee_vault_pda() -> Pubkey {
+    Pubkey::find_program_address(&[RELAY_FEE_VAULT_SEED], &ID).0
+}
+
+pub fn maintenance_budget_pda() -> Pubkey {
+    Pubkey::find_program_address(&[MAINTENANCE_BUDGET_SEED], &ID).0
+}
+
+pub fn operational_vault_pda() -> Pubkey {
+    Pubkey::find_program_address(&[OPERATIONAL_VAULT_SEED], &ID).0
+}
+
+pub fn relay_deny_list_pda() -> Pubkey {
+    Pubkey::find_program_address(&[RELAY_DENY_LIST_SEED], &ID).0
+}
+
+pub fn config_history_pda() -> Pubkey {
+    Pubkey::find_program_address(&[CONFIG_HISTORY_SEED], &ID).0
+}
+
+/// Reads the number of recorded changes and the latest change of the config history.
+pub fn latest_config_change(svm: &LiteSVM) -> (u64, ConfigChange) {
+    let account = svm.get_account(&config_history_pda()).unwrap();
+
+    // Layout: discriminator (8) | count (8) | changes of
+    // slot (8) | authority (32) | old_value (32) | new_value (32) | param (1) | padding (7)
+    let count = u64::from_le_bytes(account.data[8..16].try_into().unwrap());
+    let index = (count + CONFIG_HISTORY_LEN - 1) % CONFIG_HISTORY_LEN;
+    let change = &account.data[16 + index as usize * 112..][..112];
+    (
+        count,
+        ConfigChange {
+            slot: u64::from_le_bytes(change[..8].try_into().unwrap()),
+            authority: Pubkey::try_from(&change[8..40]).unwrap(),
+            old_value: change[40..72].try_into().unwrap(),
+            new_value: change[72..104].try_into().unwrap(),
+            param: change[104],
+            _padding: [0; 7],
+        },
+    )
+}
+
+pub fn output_root_pda(base_block_number: u64) -> Pubkey {
+    Pubkey::find_program_address(&[OUTPUT_ROOT_SEED, &base_block_number.to_le_bytes()], &ID).0
+}
+
+/// Writes the output root of `base_block_number` at `revision`, registered at the current time.
+pub fn mock_output_root(svm: &mut LiteSVM, base_block_number: u64, revision: u32) -> Pubkey {
+    let output_root = output_root_pda(base_block_number);
+    let registered_at = svm.get_sysvar::<Clock>().unix_timestamp;
+    set_program_account(
+        svm,
+        output_root,
+        &OutputRoot {
+            root: [0u8; 32],
+            total_leaf_count: 1,
+            base_block_number,
+            registered_at,
+            revision,
+        },
+    );
+    output_root
+}
+
+pub fn relayed_nonces_pda(page: u64) -> Pubkey {
+    Pubkey::find_program_address(&[RELAYED_NONCES_SEED, &page.to_le_bytes()], &ID).0
+}
+
+/// Reads whether `nonce` is marked in its relayed nonces registry account.
+pub fn is_relayed(svm: &LiteSVM, nonce: u64) -> bool {
+    let Some(account) = svm.get_account(&relayed_nonces_pda(RelayedNonces::page_of(nonce))) else {
+        return false;
+    };
+
+    // Layout: discriminator (8) | page (8) | bitmap words
+    let index = nonce % RELAYED_NONCES_PER_ACCOUNT;
+    let offset = 16 + (index / 64) as usize * 8;
+    let word = u64::from_le_bytes(account.data[offset..offset + 8].try_into().unwrap());
+    word & (1 << (index % 64)) != 0
+}
+
+/// Writes a relayed nonces registry account for `page` with `nonces` marked as relayed.
+pub fn mock_relayed_nonces(svm: &mut LiteSVM, page: u64, nonces: &[u64]) {
+    let mut data = vec![0u8; 8 + std::mem::size_of::<RelayedNonces>()];
+    data[..8].copy_from_slice(RelayedNonces::DISCRIMINATOR);
+    data[8..16].copy_from_slice(&page.to_le_bytes());
+    for nonce in nonces {
+        let index = nonce % RELAYED_NONCES_PER_ACCOUNT;
+        data[16 + (index / 64) as usize * 8 + (index % 64 / 8) as usize] |= 1 << (index % 8);
+    }
+
+    svm.set_account(
+        relayed_nonces_pda(page),
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+/// Writes the protocol fee config, collecting fees into `TEST_PROTOCOL_FEE_TREASURY`.
+pub fn mock_protocol_fee_config(
+    svm: &mut LiteSVM,
+    default_bps: u16,
+    overrides: &[ProtocolFeeOverride],
+) {
+    let mut protocol_fee_config = ProtocolFeeConfig {
+        treasury: TEST_PROTOCOL_FEE_TREASURY,
+        default_bps: 0,
+        override_count: 0,
+        overrides: Default::default(),
+    };
+    protocol_fee_config
+        .set(TEST_PROTOCOL_FEE_TREASURY, default_bps, overrides)
+        .unwrap();
+
+    let mut data = Vec::with_capacity(DISCRIMINATOR_LEN + ProtocolFeeConfig::INIT_SPACE);
+    protocol_fee_config.try_serialize(&mut data).unwrap();
+
+    svm.set_account(
+        protocol_fee_config_pda(),
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+/// Writes `value` to a rent-exempt account owned by the bridge program.
+pub fn set_program_account<T: AccountSerialize>(svm: &mut LiteSVM, key: Pubkey, value: &T) {
+    let mut data = Vec::new();
+    value.try_serialize(&mut data).unwrap();
+    svm.set_account(
+        key,
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
 pub fn mock_clock(svm: &mut LiteSVM, timestamp: i64) {
     let mut clock = svm.get_sysvar::<Clock>();
     clock.unix_timestamp = timestamp;
     svm.set_sysvar::<Clock>(&clock);
 }
 
+pub fn set_mock_pause(svm: &mut LiteSVM, bridge_pda: Pubkey, paused: bool) {
+    let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
+    let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+    bridge.paused = paused;
+    let mut data = Vec::new();
+    bridge.try_serialize(&mut data).unwrap();
+    bridge_account.data = data;
+    svm.set_account(bridge_pda, bridge_account).unwrap();
+}
+
+/// Enables the transfer gas lane with `Eip1559Config::test_new` and a base fee of `base_fee`.
+pub fn mock_transfer_gas_lane(svm: &mut LiteSVM, bridge_pda: Pubkey, base_fee: u64) {
+    let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
+    let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+    bridge.transfer_gas_lane = Eip1559 {
+        config: Eip1559Config::test_new(),
+        current_base_fee: base_fee,
+        current_window_gas_used: 0,
+        window_start_time: svm.get_sysvar::<Clock>().unix_timestamp,
+    };
+    let mut data = Vec::new();
+    bridge.try_serialize(&mut data).unwrap();
+    bridge_account.data = data;
+    svm.set_account(bridge_pda, bridge_account).unwrap();
+}
+
+pub fn mock_require_wrapped_token_confirmation(svm: &mut LiteSVM, bridge_pda: Pubkey) {
+    let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
+    let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+    bridge.require_wrapped_token_confirmation = true;
+    let mut data = Vec::new();
+    bridge.try_serialize(&mut data).unwrap();
+    bridge_account.data = data;
+    svm.set_account(bridge_pda, bridge_account).unwrap();
+}
+
+pub fn set_mock_base_governance(
+    svm: &mut LiteSVM,
+    bridge_pda: Pubkey,
+    governance: [u8; 20],
+    enforced: bool,
+) {
+    let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
+    let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+    bridge.base_governance.governance = governance;
+    bridge.base_governance.enforced = enforced;
+    let mut data = Vec::new();
+    bridge.try_serialize(&mut data).unwrap();
+    bridge_account.data = data;
+    svm.set_account(bridge_pda, bridge_account).unwrap();
+}
+
+/// Relays a message sent by `sender` calling, with the instruction `data`, a bridge configuration
+/// setter reserved to the Base governance.
+pub fn relay_governance_call(
+    svm: &mut LiteSVM,
+    payer: &Keypair,
+    bridge_pda: Pubkey,
+    sender: [u8; 20],
+    data: Vec<u8>,
+) -> std::result::Result<(), String> {
+    let governance_cpi_authority =
+        Pubkey::find_program_address(&[BRIDGE_CPI_AUTHORITY_SEED, sender.as_ref()], &ID).0;
+    let setter_ix = Instruction {
+        program_id: ID,
+        accounts: accounts::SetBridgeConfigFromBaseGovernance {
+            governance_cpi_authority,
+            bridge: bridge_pda,
+            config_history: config_history_pda(),
+        }
+        .to_account_metas(None),
+        data,
+    };
+
+    let output_root = mock_output_root(svm, 0, 0);
+    let message = Pubkey::new_unique();
+    set_program_account(
+        svm,
+        message,
+        &IncomingMessage {
+            sender,
+            remote_chain_id: BASE_CHAIN_ID,
+            message: IncomingPayload::Call(vec![setter_ix.clone().into()]),
+            executed: false,
+            chunk_count: 0,
+            output_root,
+            output_root_revision: 0,
+            prover: payer.pubkey(),
+            nonce: 0,
+            proven_at: 0,
+            vetoed: false,
+            outflow_held: false,
+            outflow_approved: false,
+        },
+    );
+
+    let mut accounts = accounts::RelayMessage {
+        message,
+        output_root,
+        prover: payer.pubkey(),
+        bridge: bridge_pda,
+        relay_deny_list: relay_deny_list_pda(),
+        relay_fee_vault: None,
+        relayer: None,
+        operational_vault: None,
+        bridge_cpi_authority: None,
+        system_program: None,
+    }
+    .to_account_metas(None);
+    accounts.push(AccountMeta::new_readonly(ID, false));
+    accounts.extend(setter_ix.accounts.into_iter().map(|account| AccountMeta {
+        is_signer: false,
+        ..account
+    }));
+
+    let ix = Instruction {
+        program_id: ID,
+        accounts,
+        data: crate::instruction::RelayMessage {}.data(),
+    };
+    let tx = Transaction::new(
+        &[payer],
+        Message::new(&[ix], Some(&payer.pubkey())),
+        svm.latest_blockhash(),
+    );
+    svm.send_transaction(tx)
+        .map(|_| ())
+        .map_err(|e| format!("{:?}", e))
+}
+
+pub fn mock_emergency_exit(svm: &mut LiteSVM, withdrawable_at: i64) {
+    let mut data = Vec::with_capacity(DISCRIMINATOR_LEN + EmergencyExit::INIT_SPACE);
+    EmergencyExit {
+        enabled_at: 0,
+        withdrawable_at,
+    }
+    .try_serialize(&mut data)
+    .unwrap();
+
+    svm.set_account(
+        emergency_exit_pda(),
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+pub fn mock_mint_policy(
+    svm: &mut LiteSVM,
+    reject_freeze_authority: bool,
+    allowed_freeze_authorities: &[Pubkey],
+) {
+    let mut mint_policy = MintPolicy {
+        reject_freeze_authority: false,
+        allowed_freeze_authority_count: 0,
+        allowed_freeze_authorities: Default::default(),
+    };
+    mint_policy
+        .set(reject_freeze_authority, allowed_freeze_authorities)
+        .unwrap();
+
+    let mut data = Vec::with_capacity(DISCRIMINATOR_LEN + MintPolicy::INIT_SPACE);
+    mint_policy.try_serialize(&mut data).unwrap();
+
+    svm.set_account(
+        mint_policy_pda(),
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+/// Turns guarded launch on, with `allowed_mints` as the mint allow-list.
+pub fn mock_guarded_launch(svm: &mut LiteSVM, bridge_pda: Pubkey, allowed_mints: &[Pubkey]) {
+    let mut bridge_account = svm.get_account(&bridge_pda).unwrap();
+    let mut bridge = Bridge::try_deserialize(&mut &bridge_account.data[..]).unwrap();
+    bridge.guarded_launch = true;
+    let mut data = Vec::new();
+    bridge.try_serialize(&mut data).unwrap();
+    bridge_account.data = data;
+    svm.set_account(bridge_pda, bridge_account).unwrap();
+
+    let mut mint_allow_list = MintAllowList {
+        mint_count: 0,
+        mints: Default::default(),
+    };
+    for mint in allowed_mints {
+        mint_allow_list.allow(*mint).unwrap();
+    }
+
+    let mut data = Vec::with_capacity(DISCRIMINATOR_LEN + MintAllowList::INIT_SPACE);
+    mint_allow_list.try_serialize(&mut data).unwrap();
+
+    svm.set_account(
+        mint_allow_list_pda(),
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+/// Writes a DEX router list registering `routers`.
+pub fn mock_dex_router_list(svm: &mut LiteSVM, routers: &[[u8; 20]]) {
+    let mut dex_router_list = DexRouterList {
+        router_count: 0,
+        routers: Default::default(),
+    };
+    for router in routers {
+        dex_router_list.register(*router).unwrap();
+    }
+
+    let mut data = Vec::with_capacity(DISCRIMINATOR_LEN + DexRouterList::INIT_SPACE);
+    dex_router_list.try_serialize(&mut data).unwrap();
+
+    svm.set_account(
+        dex_router_list_pda(),
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+/// Writes a payer rate limit config allowing `max_messages` per window of `window_seconds`,
+/// without gas limit, and exempting `exempt_payers`.
+pub fn mock_payer_rate_limit_config(
+    svm: &mut LiteSVM,
+    window_seconds: u64,
+    max_messages: u64,
+    exempt_payers: &[Pubkey],
+) {
+    let mut config = PayerRateLimitConfig {
+        window_seconds,
+        max_messages,
+        max_gas: 0,
+        exempt_payer_count: 0,
+        exempt_payers: Default::default(),
+    };
+    for payer in exempt_payers {
+        config.exempt(*payer).unwrap();
+    }
+
+    let mut data = Vec::with_capacity(DISCRIMINATOR_LEN + PayerRateLimitConfig::INIT_SPACE);
+    config.try_serialize(&mut data).unwrap();
+
+    svm.set_account(
+        payer_rate_limit_config_pda(),
+        Account {
+            lamports: svm.minimum_balance_for_rent_exemption(data.len()),
+            data,
+            owner: ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    )
+    .unwrap();
+}
+
+/// Sets the freeze authority of a mock mint created with `create_mock_mint`.
+pub fn set_mock_mint_freeze_authority(svm: &mut LiteSVM, mint: Pubkey, freeze_authority: Pubkey) {
+    let mut account = svm.get_account(&mint).unwrap();
+    let mut state = Mint::unpack_from_slice(&account.data).unwrap();
+    state.freeze_authority = COption::Some(freeze_authority);
+    state.pack_into_slice(&mut account.data);
+    svm.set_account(mint, account).unwrap();
+}
+
 pub fn create_mock_mint(svm: &mut LiteSVM, mint: Pubkey, decimals: u8, token_program: Pubkey) {
     let mut mint_data = vec![0u8; 82]; // Mint account size
     Mint {
@@ -0,0 +1,291 @@
+//! Program version and capability bitmask, returned by `get_version` so that SDKs can detect
+//! which instructions a deployment supports before building transactions for it.
+
+use anchor_lang::prelude::*;
+
+/// Semantic version of the program, kept in sync with the crate version.
+#[constant]
+pub const VERSION_MAJOR: u16 = 0;
+#[constant]
+pub const VERSION_MINOR: u16 = 3;
+#[constant]
+pub const VERSION_PATCH: u16 = 0;
+
+/// `bridge_call_buffered` and the `*_with_buffered_call` instructions.
+#[constant]
+pub const CAPABILITY_BUFFERED_CALLS: u64 = 1 << 0;
+/// `prove_message_buffered` and the prove buffer instructions.
+#[constant]
+pub const CAPABILITY_BUFFERED_PROVE: u64 = 1 << 1;
+/// `register_output_roots_batch`.
+#[constant]
+pub const CAPABILITY_BATCHED_OUTPUT_ROOTS: u64 = 1 << 2;
+/// `expire_call_buffer` with a caller bounty.
+#[constant]
+pub const CAPABILITY_CALL_BUFFER_EXPIRY: u64 = 1 << 3;
+/// `confirm_relayed` and `close_relayed_outgoing_message`.
+#[constant]
+pub const CAPABILITY_RELAYED_NONCES: u64 = 1 << 4;
+/// `ReturnTransfer` incoming messages.
+#[constant]
+pub const CAPABILITY_RETURN_TRANSFER: u64 = 1 << 5;
+/// Protocol fee charged on bridged amounts.
+#[constant]
+pub const CAPABILITY_PROTOCOL_FEE: u64 = 1 << 6;
+/// Optional memo on transfers.
+#[constant]
+pub const CAPABILITY_TRANSFER_MEMO: u64 = 1 << 7;
+/// `WithComputeBudget` incoming messages.
+#[constant]
+pub const CAPABILITY_COMPUTE_BUDGET_HINTS: u64 = 1 << 8;
+/// `spl_memo` argument of the bridge instructions.
+#[constant]
+pub const CAPABILITY_SPL_MEMO: u64 = 1 << 9;
+/// `post_liability_root`, `reconcile_vault` and `clear_solvency_alert`.
+#[constant]
+pub const CAPABILITY_VAULT_RECONCILIATION: u64 = 1 << 10;
+/// `bridge_encrypted_call` and `Encrypted` outgoing messages.
+#[constant]
+pub const CAPABILITY_ENCRYPTED_CALLS: u64 = 1 << 11;
+/// `set_oracle_failover_config`, `activate_oracle_emergency` and `deactivate_oracle_emergency`.
+#[constant]
+pub const CAPABILITY_ORACLE_FAILOVER: u64 = 1 << 12;
+/// `scale_wrapped_token_amount`.
+#[constant]
+pub const CAPABILITY_WRAPPED_TOKEN_SCALING: u64 = 1 << 13;
+/// `init_config_history` and the `ConfigHistory` log written by every configuration setter.
+#[constant]
+pub const CAPABILITY_CONFIG_HISTORY: u64 = 1 << 14;
+/// `settle_relay_fees` and `WithRelayFee` incoming messages.
+#[constant]
+pub const CAPABILITY_RELAY_FEES: u64 = 1 << 15;
+/// `set_root_freshness_config` and the per-message-kind output root freshness policy.
+#[constant]
+pub const CAPABILITY_ROOT_FRESHNESS: u64 = 1 << 16;
+/// `create_bridge_session`, `revoke_bridge_session` and session delegates in `bridge_spl` and
+/// `bridge_wrapped_token`.
+#[constant]
+pub const CAPABILITY_BRIDGE_SESSIONS: u64 = 1 << 17;
+/// `wrap_token` returning `WrapTokenResult::AlreadyWrapped` for already wrapped tokens.
+#[constant]
+pub const CAPABILITY_IDEMPOTENT_WRAP_TOKEN: u64 = 1 << 18;
+/// `BRIDGE_FULL_BALANCE` amounts in `bridge_spl` and `bridge_wrapped_token`.
+#[constant]
+pub const CAPABILITY_BRIDGE_FULL_BALANCE: u64 = 1 << 19;
+/// `bridge_sol_batch` and `TransferBatch` outgoing messages.
+#[constant]
+pub const CAPABILITY_TRANSFER_BATCH: u64 = 1 << 20;
+/// `report_equivocation` and the `EquivocationEvidence` it records.
+#[constant]
+pub const CAPABILITY_EQUIVOCATION_REPORTS: u64 = 1 << 21;
+/// `set_mint_policy` and the freeze authority checks of `bridge_spl`.
+#[constant]
+pub const CAPABILITY_MINT_POLICY: u64 = 1 << 22;
+/// `prove_message_chunked`, `extend_prove_buffer` and the `ProvenChunk` accounts executed by
+/// `relay_message`.
+#[constant]
+pub const CAPABILITY_CHUNKED_MESSAGES: u64 = 1 << 23;
+/// `enable_emergency_exit`, `cancel_emergency_exit`, `emergency_withdraw_sol` and
+/// `emergency_withdraw_spl`.
+#[constant]
+pub const CAPABILITY_EMERGENCY_EXIT: u64 = 1 << 24;
+/// Oracle signer heartbeats recorded by output root registrations and `get_oracle_health`.
+#[constant]
+pub const CAPABILITY_ORACLE_HEALTH: u64 = 1 << 25;
+/// `supersede_output_root`, `close_superseded_message` and the output root revision checked by
+/// `relay_message`.
+#[constant]
+pub const CAPABILITY_OUTPUT_ROOT_SUPERSESSION: u64 = 1 << 26;
+/// The `prover` account of `relay_message`, refunded the rent freed by executed messages.
+#[constant]
+pub const CAPABILITY_PROVER_RENT_REFUND: u64 = 1 << 27;
+/// The outflow circuit breaker configured with `set_outflow_breaker`.
+#[constant]
+pub const CAPABILITY_OUTFLOW_BREAKER: u64 = 1 << 28;
+/// `crosschain_mint` and the ERC-7802 style `CrosschainMint` and `CrosschainBurn` events.
+#[constant]
+pub const CAPABILITY_CROSSCHAIN_TOKEN: u64 = 1 << 29;
+/// The `MessageProven` and `MessageRelayed` events and the `nonce` recorded in incoming messages.
+#[constant]
+pub const CAPABILITY_MESSAGE_RECEIPTS: u64 = 1 << 30;
+/// `set_wrapped_token_max_supply` and the wrapped token max supply enforced on mints.
+#[constant]
+pub const CAPABILITY_WRAPPED_SUPPLY_CAP: u64 = 1u64 << 31;
+/// The finalization delay of high-value transfers set with `set_finalization_delay`,
+/// `veto_message` and the `finalizable_at` reported in `MessageProven`.
+#[constant]
+pub const CAPABILITY_FINALIZATION_DELAY: u64 = 1u64 << 32;
+/// SPL transfer amounts carried in the decimals of the Base token set with `set_vault_decimals`.
+#[constant]
+pub const CAPABILITY_SPL_DECIMAL_SCALING: u64 = 1u64 << 33;
+/// Critical configuration governed from Base through `set_base_governance`,
+/// `set_governance_mode`, `set_oracle_signers_from_governance` and `pause_from_governance`.
+#[constant]
+pub const CAPABILITY_BASE_GOVERNANCE: u64 = 1u64 << 34;
+
+/// Share of the gas fees credited to an insurance fund through `set_insurance_share`, paid out by
+/// the guardian after a timelock through `request_insurance_payout` and `execute_insurance_payout`.
+#[constant]
+pub const CAPABILITY_INSURANCE_FUND: u64 = 1u64 << 35;
+
+/// Relayed messages cannot invoke the loaders and other privileged programs, nor the programs
+/// denied through `set_relay_deny_list`.
+#[constant]
+pub const CAPABILITY_RELAY_DENY_LIST: u64 = 1u64 << 36;
+
+/// Outgoing messages are appended to the outbox MMR stored in the bridge, whose root Base can
+/// verify them against.
+#[constant]
+pub const CAPABILITY_OUTBOX_MMR: u64 = 1u64 << 37;
+
+/// The maintenance budget reimburses the payer of `register_output_root` for its rent and fees.
+#[constant]
+pub const CAPABILITY_MAINTENANCE_BUDGET: u64 = 1u64 << 38;
+
+/// `WithLamportAllowance` incoming messages, lent lamports from the operational vault configured
+/// through `set_max_lamport_allowance`.
+#[constant]
+pub const CAPABILITY_LAMPORT_ALLOWANCE: u64 = 1u64 << 39;
+
+/// Guarded launch, restricting `bridge_spl` to the mints allow-listed through `set_mint_allowed`.
+#[constant]
+pub const CAPABILITY_GUARDED_LAUNCH: u64 = 1u64 << 40;
+
+/// Separate EIP-1559 gas lane pricing token transfers apart from calls, configured through
+/// `set_transfer_gas_lane`.
+#[constant]
+pub const CAPABILITY_TRANSFER_GAS_LANE: u64 = 1u64 << 42;
+
+/// `ConfirmWrappedToken` incoming messages confirming wrapped token registrations, which
+/// `bridge_wrapped_token` can be required to wait for through
+/// `set_wrapped_token_confirmation_required`.
+#[constant]
+pub const CAPABILITY_WRAPPED_TOKEN_CONFIRMATION: u64 = 1u64 << 43;
+
+/// Reusable call buffers, marked through `set_call_buffer_reusable` and copied through
+/// `clone_call_buffer`, which buffered bridge instructions read without closing.
+#[constant]
+pub const CAPABILITY_REUSABLE_CALL_BUFFERS: u64 = 1u64 << 44;
+
+/// `derive_cpi_authority` and the `cpi_authority` reported in `MessageProven`.
+#[constant]
+pub const CAPABILITY_CPI_AUTHORITY_DERIVATION: u64 = 1u64 << 45;
+
+/// `SwapGuard`s on transfers, required for calls to the routers registered through
+/// `set_dex_router`.
+#[constant]
+pub const CAPABILITY_SWAP_GUARD: u64 = 1u64 << 46;
+
+/// `ConfirmWrappedToken` incoming messages echoing the name, symbol and decimals of the Base
+/// token, which must match the metadata of the confirmed wrapped mint.
+#[constant]
+pub const CAPABILITY_WRAPPED_TOKEN_METADATA_CONFIRMATION: u64 = 1u64 << 47;
+
+/// `set_fee_quote_grace_period`: gas fees charged right after an EIP-1559 window rollover do not
+/// exceed the base fee of the previous window during the configured grace period.
+#[constant]
+pub const CAPABILITY_FEE_QUOTE_GRACE: u64 = 1u64 << 48;
+
+/// `bridge_wrapped_token` writes an immutable `BurnReceipt` (PDA with `BURN_RECEIPT_SEED` and the
+/// outgoing message) linking each burn to its Base recipient.
+#[constant]
+pub const CAPABILITY_BURN_RECEIPTS: u64 = 1u64 << 49;
+
+/// `set_payer_rate_limit`: instructions sending outgoing messages take the
+/// `payer_rate_limit_config` and `payer_rate_limit` (PDA with `PAYER_RATE_LIMIT_SEED` and the
+/// payer) accounts, bounding the messages and gas each payer can pay for per window.
+#[constant]
+pub const CAPABILITY_PAYER_RATE_LIMIT: u64 = 1u64 << 50;
+
+/// `register_handler` and `CallHandler` incoming messages, relayed by invoking the handler
+/// registered by the sender with the message payload (PDA with `HANDLER_REGISTRATION_SEED` and the
+/// sender, handler program and handler accounts remaining accounts).
+#[constant]
+pub const CAPABILITY_HANDLER_CALLS: u64 = 1u64 << 51;
+/// Transfers tripping the outflow circuit breaker held until the guardian releases them with
+/// `approve_outflow`, instead of tripping the breaker again once the bridge is unpaused.
+#[constant]
+pub const CAPABILITY_OUTFLOW_APPROVAL: u64 = 1u64 << 52;
+/// `record_relay_execution` and `RelayExecution` records of the relayer that executed an outgoing
+/// message on Base.
+#[constant]
+pub const CAPABILITY_RELAY_EXECUTION: u64 = 1u64 << 53;
+
+/// Capabilities of this build. Bits are only ever added, so clients must ignore unknown ones.
+#[constant]
+pub const CAPABILITIES: u64 = CAPABILITY_BUFFERED_CALLS
+    | CAPABILITY_BUFFERED_PROVE
+    | CAPABILITY_BATCHED_OUTPUT_ROOTS
+    | CAPABILITY_CALL_BUFFER_EXPIRY
+    | CAPABILITY_RELAYED_NONCES
+    | CAPABILITY_RETURN_TRANSFER
+    | CAPABILITY_PROTOCOL_FEE
+    | CAPABILITY_TRANSFER_MEMO
+    | CAPABILITY_COMPUTE_BUDGET_HINTS
+    | CAPABILITY_SPL_MEMO
+    | CAPABILITY_VAULT_RECONCILIATION
+    | CAPABILITY_ENCRYPTED_CALLS
+    | CAPABILITY_ORACLE_FAILOVER
+    | CAPABILITY_WRAPPED_TOKEN_SCALING
+    | CAPABILITY_CONFIG_HISTORY
+    | CAPABILITY_RELAY_FEES
+    | CAPABILITY_ROOT_FRESHNESS
+    | CAPABILITY_BRIDGE_SESSIONS
+    | CAPABILITY_IDEMPOTENT_WRAP_TOKEN
+    | CAPABILITY_BRIDGE_FULL_BALANCE
+    | CAPABILITY_TRANSFER_BATCH
+    | CAPABILITY_EQUIVOCATION_REPORTS
+    | CAPABILITY_MINT_POLICY
+    | CAPABILITY_CHUNKED_MESSAGES
+    | CAPABILITY_EMERGENCY_EXIT
+    | CAPABILITY_ORACLE_HEALTH
+    | CAPABILITY_OUTPUT_ROOT_SUPERSESSION
+    | CAPABILITY_PROVER_RENT_REFUND
+    | CAPABILITY_OUTFLOW_BREAKER
+    | CAPABILITY_CROSSCHAIN_TOKEN
+    | CAPABILITY_MESSAGE_RECEIPTS
+    | CAPABILITY_WRAPPED_SUPPLY_CAP
+    | CAPABILITY_FINALIZATION_DELAY
+    | CAPABILITY_SPL_DECIMAL_SCALING
+    | CAPABILITY_BASE_GOVERNANCE
+    | CAPABILITY_INSURANCE_FUND
+    | CAPABILITY_RELAY_DENY_LIST
+    | CAPABILITY_OUTBOX_MMR
+    | CAPABILITY_MAINTENANCE_BUDGET
+    | CAPABILITY_LAMPORT_ALLOWANCE
+    | CAPABILITY_GUARDED_LAUNCH
+    | CAPABILITY_TRANSFER_GAS_LANE
+    | CAPABILITY_WRAPPED_TOKEN_CONFIRMATION
+    | CAPABILITY_REUSABLE_CALL_BUFFERS
+    | CAPABILITY_CPI_AUTHORITY_DERIVATION
+    | CAPABILITY_SWAP_GUARD
+    | CAPABILITY_WRAPPED_TOKEN_METADATA_CONFIRMATION
+    | CAPABILITY_FEE_QUOTE_GRACE
+    | CAPABILITY_BURN_RECEIPTS
+    | CAPABILITY_PAYER_RATE_LIMIT
+    | CAPABILITY_HANDLER_CALLS
+    | CAPABILITY_OUTFLOW_APPROVAL
+    | CAPABILITY_RELAY_EXECUTION;
+
+/// The version and capabilities the program was compiled with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, AnchorSerialize, AnchorDeserialize)]
+pub struct Version {
+    pub major: u16,
+    pub minor: u16,
+    pub patch: u16,
+    pub capabilities: u64,
+}
+
+impl Version {
+    /// Returns whether all the bits of `capability` are supported.
+    pub fn supports(&self, capability: u64) -> bool {
+        self.capabilities & capability == capability
+    }
+}
+
+pub const VERSION: Version = Version {
+    major: VERSION_MAJOR,
+    minor: VERSION_MINOR,
+    patch: VERSION_PATCH,
+    capabilities: CAPABILITIES,
+};